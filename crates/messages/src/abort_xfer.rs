@@ -0,0 +1,60 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+
+// ID: 157
+// Frequency: Low
+
+impl Packet {
+    pub fn new_abort_xfer(abort_xfer: AbortXfer) -> Self {
+        Packet {
+            header: Header {
+                id: 157,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AbortXfer(Box::new(abort_xfer)),
+        }
+    }
+}
+
+/// Stops a transfer with the Xfer system. Either side can send it.
+/// https://wiki.secondlife.com/wiki/AbortXfer
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbortXfer {
+    pub id: u64,
+    /// the error code for why the transfer stopped
+    pub result: i32,
+}
+impl AbortXfer {
+    /// the result sent when the other side stopped confirming packets
+    pub const RESULT_TIMEOUT: i32 = -23016;
+}
+
+impl PacketData for AbortXfer {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AbortXfer {
+            id: cursor.read_u64::<LittleEndian>()?,
+            result: cursor.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12);
+        bytes.extend_from_slice(&self.id.to_le_bytes());
+        bytes.extend_from_slice(&self.result.to_le_bytes());
+        bytes
+    }
+}
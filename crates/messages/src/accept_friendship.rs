@@ -0,0 +1,91 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 297
+// Frequency: Low
+
+impl Packet {
+    pub fn new_accept_friendship(accept_friendship: AcceptFriendship) -> Self {
+        Packet {
+            header: Header {
+                id: 297,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AcceptFriendship(Box::new(accept_friendship)),
+        }
+    }
+}
+
+/// Accepts a friendship offered with an ImprovedInstantMessage. The transaction id is the id of
+/// the offer's message. The simulator tells the agent that offered with a FriendshipAccepted
+/// message, and puts a calling card for each of them in the folders given.
+/// https://wiki.secondlife.com/wiki/AcceptFriendship
+#[derive(Debug, Clone, PartialEq)]
+pub struct AcceptFriendship {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub transaction_id: Uuid,
+    /// the folders to put the new calling card in
+    pub folder_ids: Vec<Uuid>,
+}
+
+impl AcceptFriendship {
+    /// accept the offer with the message id, without a calling card. The agent and session ids
+    /// are left nil, for the session to fill in.
+    pub fn new(transaction_id: Uuid) -> Self {
+        AcceptFriendship {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            transaction_id,
+            folder_ids: Vec::new(),
+        }
+    }
+}
+
+impl PacketData for AcceptFriendship {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let transaction_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut folder_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            folder_ids.push(read_uuid(&mut cursor)?);
+        }
+        Ok(AcceptFriendship {
+            agent_id,
+            session_id,
+            transaction_id,
+            folder_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let folder_ids = &self.folder_ids[..self.folder_ids.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(49 + folder_ids.len() * 16);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.push(folder_ids.len() as u8);
+        for folder_id in folder_ids {
+            bytes.extend_from_slice(folder_id.as_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,44 @@
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_accept_script_teleport(accept_script_teleport: AcceptScriptTeleport) -> Self {
+        Packet {
+            header: Header {
+                id: 72,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AcceptScriptTeleport(Box::new(accept_script_teleport)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to accept a destination a script offered with a
+/// ScriptTeleportRequest. ScriptTeleportRequest::accept builds one from the offer. The session
+/// finds the region handle of the region by its name with a MapNameRequest, and then teleports
+/// with a TeleportLocationRequest. The teleport is answered like any other, and a region that
+/// can't be found is sent back as a TeleportFailed.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AcceptScriptTeleport {
+    /// the name of the region to teleport to
+    pub sim_name: String,
+    /// the position in the region, in region coordinates
+    pub sim_position: Vec3,
+    /// the direction the avatar faces on arrival
+    pub look_at: Vec3,
+}
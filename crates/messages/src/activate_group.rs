@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 260
+// Frequency: Low
+
+impl Packet {
+    pub fn new_activate_group(activate_group: ActivateGroup) -> Self {
+        Packet {
+            header: Header {
+                id: 260,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ActivateGroup(Box::new(activate_group)),
+        }
+    }
+}
+
+/// Makes one of the agent's groups its active group, whose title is shown above the avatar and
+/// which objects it makes belong to. The simulator answers with an AgentDataUpdate.
+/// https://wiki.secondlife.com/wiki/ActivateGroup
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivateGroup {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group to activate, nil for none
+    pub group_id: Uuid,
+}
+
+impl ActivateGroup {
+    /// activate a group, or nil for no group. The agent and session ids are left nil, for the
+    /// session to fill in.
+    pub fn new(group_id: Uuid) -> Self {
+        ActivateGroup {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            group_id,
+        }
+    }
+}
+
+impl PacketData for ActivateGroup {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ActivateGroup {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            group_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,139 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_variable_1, write_variable_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 5
+// Frequency: High
+
+impl Packet {
+    pub fn new_agent_animation(agent_animation: AgentAnimation) -> Self {
+        Packet {
+            header: Header {
+                id: 5,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentAnimation(Box::new(agent_animation)),
+        }
+    }
+}
+
+/// Starts and stops animations on the agent's avatar, like the typing animation while the agent
+/// writes in chat. The simulator answers with an AvatarAnimation with everything the avatar
+/// plays. The built in animations are in utils::animations.
+/// https://wiki.secondlife.com/wiki/AgentAnimation
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentAnimation {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub animations: Vec<AnimationChange>,
+    /// data about the avatar's physics, one block per event
+    pub physical_avatar_events: Vec<Vec<u8>>,
+}
+
+/// an animation to start or stop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationChange {
+    pub anim_id: Uuid,
+    /// true to start the animation, false to stop it
+    pub start: bool,
+}
+
+impl AgentAnimation {
+    /// start and stop animations. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(animations: Vec<AnimationChange>) -> Self {
+        AgentAnimation {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            animations,
+            physical_avatar_events: Vec::new(),
+        }
+    }
+
+    /// start animations by their ids
+    pub fn start(anim_ids: &[Uuid]) -> Self {
+        Self::new(changes(anim_ids, true))
+    }
+
+    /// stop animations by their ids
+    pub fn stop(anim_ids: &[Uuid]) -> Self {
+        Self::new(changes(anim_ids, false))
+    }
+}
+
+fn changes(anim_ids: &[Uuid], start: bool) -> Vec<AnimationChange> {
+    anim_ids
+        .iter()
+        .map(|anim_id| AnimationChange {
+            anim_id: *anim_id,
+            start,
+        })
+        .collect()
+}
+
+impl PacketData for AgentAnimation {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+
+        let count = cursor.read_u8()?;
+        let mut animations = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            animations.push(AnimationChange {
+                anim_id: read_uuid(&mut cursor)?,
+                start: cursor.read_u8()? != 0,
+            });
+        }
+
+        let count = if (cursor.position() as usize) < bytes.len() {
+            cursor.read_u8()?
+        } else {
+            0
+        };
+        let mut physical_avatar_events = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            physical_avatar_events.push(read_variable_1(&mut cursor)?);
+        }
+
+        Ok(AgentAnimation {
+            agent_id,
+            session_id,
+            animations,
+            physical_avatar_events,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let animations = &self.animations[..self.animations.len().min(u8::MAX as usize)];
+        let events =
+            &self.physical_avatar_events[..self.physical_avatar_events.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(34 + animations.len() * 17);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(animations.len() as u8);
+        for animation in animations {
+            bytes.extend_from_slice(animation.anim_id.as_bytes());
+            bytes.push(animation.start as u8);
+        }
+        bytes.push(events.len() as u8);
+        for event in events {
+            write_variable_1(&mut bytes, event);
+        }
+        bytes
+    }
+}
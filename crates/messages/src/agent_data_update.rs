@@ -0,0 +1,82 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 387
+// Frequency: Low
+
+impl Packet {
+    pub fn new_agent_data_update(agent_data_update: AgentDataUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 387,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentDataUpdate(Box::new(agent_data_update)),
+        }
+    }
+}
+
+/// The agent's name and active group. Sent after login, and whenever the active group changes,
+/// like after an ActivateGroup or leaving the active group.
+/// https://wiki.secondlife.com/wiki/AgentDataUpdate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentDataUpdate {
+    pub agent_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    /// the title the agent has in its active group, shown above the avatar
+    pub group_title: String,
+    /// nil if the agent has no active group
+    pub active_group_id: Uuid,
+    /// what the agent can do in its active group
+    pub group_powers: u64,
+    pub group_name: String,
+}
+
+impl PacketData for AgentDataUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AgentDataUpdate {
+            agent_id: read_uuid(&mut cursor)?,
+            first_name: read_string_1(&mut cursor)?,
+            last_name: read_string_1(&mut cursor)?,
+            group_title: read_string_1(&mut cursor)?,
+            active_group_id: read_uuid(&mut cursor)?,
+            group_powers: cursor.read_u64::<LittleEndian>()?,
+            group_name: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            44 + self.first_name.len()
+                + self.last_name.len()
+                + self.group_title.len()
+                + self.group_name.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        write_string_1(&mut bytes, &self.first_name);
+        write_string_1(&mut bytes, &self.last_name);
+        write_string_1(&mut bytes, &self.group_title);
+        bytes.extend_from_slice(self.active_group_id.as_bytes());
+        bytes.extend_from_slice(&self.group_powers.to_le_bytes());
+        write_string_1(&mut bytes, &self.group_name);
+        bytes
+    }
+}
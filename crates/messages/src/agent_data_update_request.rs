@@ -0,0 +1,59 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 386
+// Frequency: Low
+
+impl Packet {
+    pub fn new_agent_data_update_request(
+        agent_data_update_request: AgentDataUpdateRequest,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 386,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentDataUpdateRequest(Box::new(agent_data_update_request)),
+        }
+    }
+}
+
+/// Asks the simulator for the agent's name and active group. The simulator answers with an
+/// AgentDataUpdate.
+/// https://wiki.secondlife.com/wiki/AgentDataUpdateRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentDataUpdateRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl PacketData for AgentDataUpdateRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AgentDataUpdateRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes
+    }
+}
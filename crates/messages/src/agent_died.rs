@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Sent from the session to the UI when a HealthMessage brings the agent's health down to 0.
+/// It is sent once for each death, not for every HealthMessage at 0. The simulator sends the agent
+/// home on its own, which arrives as a teleport.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentDied {
+    /// the health the agent died with, which can be below 0
+    pub health: f32,
+}
@@ -0,0 +1,83 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 82
+// Frequency: Low
+
+impl Packet {
+    pub fn new_agent_fov(agent_fov: AgentFOV) -> Self {
+        Packet {
+            header: Header {
+                id: 82,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentFOV(Box::new(agent_fov)),
+        }
+    }
+}
+
+/// Tells the simulator the viewer's vertical field of view, which it uses with the window size
+/// to decide which objects are worth sending first.
+/// https://wiki.secondlife.com/wiki/AgentFOV
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentFOV {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub circuit_code: u32,
+    /// incremented for every AgentFOV sent, so the simulator can ignore stale updates
+    pub gen_counter: u32,
+    /// the vertical field of view, in radians
+    pub vertical_angle: f32,
+}
+
+impl AgentFOV {
+    /// report the vertical field of view, in radians. The ids, circuit code and gen counter are
+    /// left zeroed, for the session to fill in.
+    pub fn new(vertical_angle: f32) -> Self {
+        AgentFOV {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            circuit_code: 0,
+            gen_counter: 0,
+            vertical_angle,
+        }
+    }
+}
+
+impl PacketData for AgentFOV {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AgentFOV {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            circuit_code: cursor.read_u32::<LittleEndian>()?,
+            gen_counter: cursor.read_u32::<LittleEndian>()?,
+            vertical_angle: cursor.read_f32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(44);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.circuit_code.to_le_bytes());
+        bytes.extend_from_slice(&self.gen_counter.to_le_bytes());
+        bytes.extend_from_slice(&self.vertical_angle.to_le_bytes());
+        bytes
+    }
+}
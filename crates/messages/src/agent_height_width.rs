@@ -0,0 +1,88 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 83
+// Frequency: Low
+
+impl Packet {
+    pub fn new_agent_height_width(agent_height_width: AgentHeightWidth) -> Self {
+        Packet {
+            header: Header {
+                id: 83,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentHeightWidth(Box::new(agent_height_width)),
+        }
+    }
+}
+
+/// Tells the simulator the size of the viewer's window, which it uses to decide which objects
+/// are worth sending first.
+/// https://wiki.secondlife.com/wiki/AgentHeightWidth
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentHeightWidth {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub circuit_code: u32,
+    /// incremented for every AgentHeightWidth sent, so the simulator can ignore stale updates
+    pub gen_counter: u32,
+    /// the height of the window, in pixels
+    pub height: u16,
+    /// the width of the window, in pixels
+    pub width: u16,
+}
+
+impl AgentHeightWidth {
+    /// report the size of the window. The ids, circuit code and gen counter are left zeroed,
+    /// for the session to fill in.
+    pub fn new(height: u16, width: u16) -> Self {
+        AgentHeightWidth {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            circuit_code: 0,
+            gen_counter: 0,
+            height,
+            width,
+        }
+    }
+}
+
+impl PacketData for AgentHeightWidth {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AgentHeightWidth {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            circuit_code: cursor.read_u32::<LittleEndian>()?,
+            gen_counter: cursor.read_u32::<LittleEndian>()?,
+            height: cursor.read_u16::<LittleEndian>()?,
+            width: cursor.read_u16::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(44);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.circuit_code.to_le_bytes());
+        bytes.extend_from_slice(&self.gen_counter.to_le_bytes());
+        bytes.extend_from_slice(&self.height.to_le_bytes());
+        bytes.extend_from_slice(&self.width.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 78
+// Frequency: Low
+
+impl Packet {
+    pub fn new_agent_pause(agent_pause: AgentPause) -> Self {
+        Packet {
+            header: Header {
+                id: 78,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentPause(Box::new(agent_pause)),
+        }
+    }
+}
+
+/// Tells the simulator the UI has stopped drawing, like when it is minimized or loading, so it
+/// stops streaming object updates until an AgentResume. The serial number has to be higher than
+/// that of the last AgentPause or AgentResume, or the simulator ignores the packet.
+/// https://wiki.secondlife.com/wiki/AgentPause
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentPause {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub serial_num: u32,
+}
+
+impl AgentPause {
+    /// pause the agent. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(serial_num: u32) -> Self {
+        AgentPause {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            serial_num,
+        }
+    }
+}
+
+impl PacketData for AgentPause {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AgentPause {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            serial_num: cursor.read_u32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(36);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.serial_num.to_le_bytes());
+        bytes
+    }
+}
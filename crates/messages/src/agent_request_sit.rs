@@ -0,0 +1,78 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_vec3, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use glam::Vec3;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 6
+// Frequency: High
+
+impl Packet {
+    pub fn new_agent_request_sit(agent_request_sit: AgentRequestSit) -> Self {
+        Packet {
+            header: Header {
+                id: 6,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentRequestSit(Box::new(agent_request_sit)),
+        }
+    }
+}
+
+/// Asks to sit on an object. The simulator answers with an AvatarSitResponse saying where the
+/// avatar will sit, which is confirmed with an AgentSit.
+/// https://wiki.secondlife.com/wiki/AgentRequestSit
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentRequestSit {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the object to sit on
+    pub target_id: Uuid,
+    /// where on the object to sit, relative to the object. Objects with a sit target ignore it.
+    pub offset: Vec3,
+}
+
+impl AgentRequestSit {
+    /// ask to sit on an object. The agent and session ids are left nil.
+    pub fn new(target_id: Uuid, offset: Vec3) -> Self {
+        AgentRequestSit {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            target_id,
+            offset,
+        }
+    }
+}
+
+impl PacketData for AgentRequestSit {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AgentRequestSit {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            target_id: read_uuid(&mut cursor)?,
+            offset: read_vec3(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(60);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.target_id.as_bytes());
+        write_vec3(&mut bytes, &self.offset);
+        bytes
+    }
+}
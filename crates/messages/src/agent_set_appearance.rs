@@ -0,0 +1,130 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_uuid, read_variable_2, read_vec3, write_variable_2, write_vec3,
+};
+use crate::utils::texture_entry::TextureEntry;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+// ID: 84
+// Frequency: Low
+
+impl Packet {
+    pub fn new_agent_set_appearance(agent_set_appearance: AgentSetAppearance) -> Self {
+        Packet {
+            header: Header {
+                id: 84,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentSetAppearance(Box::new(agent_set_appearance)),
+        }
+    }
+}
+
+/// Publishes the agent's own appearance, so other agents see it instead of a cloud.
+/// The simulator sends it on to everyone nearby as an AvatarAppearance.
+/// https://wiki.secondlife.com/wiki/AgentSetAppearance
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentSetAppearance {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// increases with every appearance sent, so the simulator can ignore older ones
+    pub serial_num: u32,
+    /// the size of the avatar's bounding box
+    pub size: Vec3,
+    /// the cache ids of the baked textures
+    pub wearable_data: Vec<WearableCache>,
+    /// the packed TextureEntry with the baked textures
+    pub texture_entry: Vec<u8>,
+    pub visual_params: Vec<u8>,
+}
+
+/// the cache id of one baked texture
+#[derive(Debug, Clone, PartialEq)]
+pub struct WearableCache {
+    pub cache_id: Uuid,
+    /// the baked texture the cache id is for
+    pub texture_index: u8,
+}
+
+impl AgentSetAppearance {
+    /// parse the TextureEntry of the appearance
+    pub fn texture_entry(&self) -> io::Result<TextureEntry> {
+        TextureEntry::from_bytes(&self.texture_entry)
+    }
+}
+
+impl PacketData for AgentSetAppearance {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AgentData
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let serial_num = cursor.read_u32::<LittleEndian>()?;
+        let size = read_vec3(&mut cursor)?;
+
+        // WearableData
+        let count = cursor.read_u8()?;
+        let mut wearable_data = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            wearable_data.push(WearableCache {
+                cache_id: read_uuid(&mut cursor)?,
+                texture_index: cursor.read_u8()?,
+            });
+        }
+
+        // ObjectData
+        let texture_entry = read_variable_2(&mut cursor)?;
+
+        // VisualParam
+        let param_count = cursor.read_u8()?;
+        let mut visual_params = vec![0u8; param_count as usize];
+        cursor.read_exact(&mut visual_params)?;
+
+        Ok(AgentSetAppearance {
+            agent_id,
+            session_id,
+            serial_num,
+            size,
+            wearable_data,
+            texture_entry,
+            visual_params,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            51 + self.wearable_data.len() * 17
+                + self.texture_entry.len()
+                + self.visual_params.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.serial_num.to_le_bytes());
+        write_vec3(&mut bytes, &self.size);
+        bytes.push(self.wearable_data.len() as u8);
+        for wearable in &self.wearable_data {
+            bytes.extend_from_slice(wearable.cache_id.as_bytes());
+            bytes.push(wearable.texture_index);
+        }
+        write_variable_2(&mut bytes, &self.texture_entry);
+        bytes.push(self.visual_params.len() as u8);
+        bytes.extend_from_slice(&self.visual_params);
+        bytes
+    }
+}
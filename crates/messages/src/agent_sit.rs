@@ -0,0 +1,56 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 7
+// Frequency: High
+
+impl Packet {
+    pub fn new_agent_sit(agent_sit: AgentSit) -> Self {
+        Packet {
+            header: Header {
+                id: 7,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentSit(Box::new(agent_sit)),
+        }
+    }
+}
+
+/// Confirms sitting on the object of an AvatarSitResponse.
+/// https://wiki.secondlife.com/wiki/AgentSit
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentSit {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl PacketData for AgentSit {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AgentSit {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes
+    }
+}
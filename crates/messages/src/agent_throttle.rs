@@ -0,0 +1,147 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_variable_1, write_variable_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 81
+// Frequency: Low
+
+impl Packet {
+    pub fn new_agent_throttle(agent_throttle: AgentThrottle) -> Self {
+        Packet {
+            header: Header {
+                id: 81,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentThrottle(Box::new(agent_throttle)),
+        }
+    }
+}
+
+/// Tells the simulator how much bandwidth to use for each category of data.
+/// The simulator sends very little until it receives one of these.
+/// https://wiki.secondlife.com/wiki/AgentThrottle
+#[derive(Debug, Clone)]
+pub struct AgentThrottle {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub circuit_code: u32,
+    /// incremented for every throttle sent, so the simulator can ignore stale updates
+    pub gen_counter: u32,
+    pub throttles: Throttles,
+}
+
+/// per-channel bandwidth allocations, in bits per second
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Throttles {
+    pub resend: f32,
+    pub land: f32,
+    pub wind: f32,
+    pub cloud: f32,
+    pub task: f32,
+    pub texture: f32,
+    pub asset: f32,
+}
+impl Default for Throttles {
+    fn default() -> Self {
+        Throttles {
+            resend: 100_000.0,
+            land: 100_000.0,
+            wind: 20_000.0,
+            cloud: 20_000.0,
+            task: 310_000.0,
+            texture: 1_000_000.0,
+            asset: 100_000.0,
+        }
+    }
+}
+impl Throttles {
+    /// the Throttles field is always seven floats
+    pub const LENGTH: usize = 28;
+
+    pub fn total(&self) -> f32 {
+        self.resend + self.land + self.wind + self.cloud + self.task + self.texture + self.asset
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() != Self::LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid byte length for Throttles: {}", bytes.len()),
+            ));
+        }
+        let mut cursor = Cursor::new(bytes);
+        Ok(Throttles {
+            resend: cursor.read_f32::<LittleEndian>()?,
+            land: cursor.read_f32::<LittleEndian>()?,
+            wind: cursor.read_f32::<LittleEndian>()?,
+            cloud: cursor.read_f32::<LittleEndian>()?,
+            task: cursor.read_f32::<LittleEndian>()?,
+            texture: cursor.read_f32::<LittleEndian>()?,
+            asset: cursor.read_f32::<LittleEndian>()?,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::LENGTH);
+        for value in [
+            self.resend,
+            self.land,
+            self.wind,
+            self.cloud,
+            self.task,
+            self.texture,
+            self.asset,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+impl PacketData for AgentThrottle {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AgentData
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let circuit_code = cursor.read_u32::<LittleEndian>()?;
+
+        // Throttle
+        let gen_counter = cursor.read_u32::<LittleEndian>()?;
+        let throttles = Throttles::from_bytes(&read_variable_1(&mut cursor)?)?;
+
+        Ok(AgentThrottle {
+            agent_id,
+            session_id,
+            circuit_code,
+            gen_counter,
+            throttles,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(69);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.circuit_code.to_le_bytes());
+        bytes.extend_from_slice(&self.gen_counter.to_le_bytes());
+        write_variable_1(&mut bytes, &self.throttles.to_bytes());
+        bytes
+    }
+}
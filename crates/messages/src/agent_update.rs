@@ -1,8 +1,13 @@
 use glam::{Quat, Vec3};
 
+use bitflags::bitflags;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::io::{self, Cursor};
 use uuid::Uuid;
 
 use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_quat, read_uuid, read_vec3, write_quat, write_vec3};
 
 use super::{
     header::{Header, PacketFrequency},
@@ -12,39 +17,6 @@ use super::{
 const AGENT_STATE_TYPING: u8 = 0x04; // 00000100 in binary
 const AGENT_STATE_EDITING: u8 = 0x10; // 00010000 in binary
 
-const AGENT_CONTROL_AT_POS: u32 = 0x00000001;
-const AGENT_CONTROL_AT_NEG: u32 = 0x00000002;
-const AGENT_CONTROL_LEFT_POS: u32 = 0x00000004;
-const AGENT_CONTROL_LEFT_NEG: u32 = 0x00000008;
-const AGENT_CONTROL_UP_POS: u32 = 0x00000010;
-const AGENT_CONTROL_UP_NEG: u32 = 0x00000020;
-const AGENT_CONTROL_PITCH_POS: u32 = 0x00000040;
-const AGENT_CONTROL_PITCH_NEG: u32 = 0x00000080;
-const AGENT_CONTROL_YAW_POS: u32 = 0x00000100;
-const AGENT_CONTROL_YAW_NEG: u32 = 0x00000200;
-const AGENT_CONTROL_FAST_AT: u32 = 0x00000400;
-const AGENT_CONTROL_FAST_LEFT: u32 = 0x00000800;
-const AGENT_CONTROL_FAST_UP: u32 = 0x00001000;
-const AGENT_CONTROL_FLY: u32 = 0x00002000;
-const AGENT_CONTROL_STOP: u32 = 0x00004000;
-const AGENT_CONTROL_FINISH_ANIM: u32 = 0x00008000;
-const AGENT_CONTROL_STAND_UP: u32 = 0x00010000;
-const AGENT_CONTROL_SIT_ON_GROUND: u32 = 0x00020000;
-const AGENT_CONTROL_MOUSELOOK: u32 = 0x00040000;
-const AGENT_CONTROL_NUDGE_AT_POS: u32 = 0x00080000;
-const AGENT_CONTROL_NUDGE_AT_NEG: u32 = 0x00100000;
-const AGENT_CONTROL_NUDGE_LEFT_POS: u32 = 0x00200000;
-const AGENT_CONTROL_NUDGE_LEFT_NEG: u32 = 0x00400000;
-const AGENT_CONTROL_NUDGE_UP_POS: u32 = 0x00800000;
-const AGENT_CONTROL_NUDGE_UP_NEG: u32 = 0x01000000;
-const AGENT_CONTROL_TURN_LEFT: u32 = 0x02000000;
-const AGENT_CONTROL_TURN_RIGHT: u32 = 0x04000000;
-const AGENT_CONTROL_AWAY: u32 = 0x08000000;
-const AGENT_CONTROL_LBUTTON_DOWN: u32 = 0x10000000;
-const AGENT_CONTROL_LBUTTON_UP: u32 = 0x20000000;
-const AGENT_CONTROL_ML_LBUTTON_DOWN: u32 = 0x40000000;
-const AGENT_CONTROL_ML_LBUTTON_UP: u32 = 0x80000000;
-
 const AU_FLAGS_NONE: u8 = 0x00;
 const AU_FLAGS_HIDETITLE: u8 = 0x01;
 
@@ -67,7 +39,7 @@ impl Packet {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct State {
     pub typing: bool,
     pub editing: bool,
@@ -91,181 +63,69 @@ impl State {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct ControlFlags {
-    pub at_pos: bool,
-    pub at_neg: bool,
-    pub left_pos: bool,
-    pub left_neg: bool,
-    pub up_pos: bool,
-    pub up_neg: bool,
-    pub pitch_pos: bool,
-    pub pitch_neg: bool,
-    pub yaw_pos: bool,
-    pub yaw_neg: bool,
-    pub fast_at: bool,
-    pub fast_left: bool,
-    pub fast_up: bool,
-    pub fly: bool,
-    pub stop: bool,
-    pub finish_anim: bool,
-    pub stand_up: bool,
-    pub sit_on_ground: bool,
-    pub mouselook: bool,
-    pub nudge_at_pos: bool,
-    pub nudge_at_neg: bool,
-    pub nudge_left_pos: bool,
-    pub nudge_left_neg: bool,
-    pub nudge_up_pos: bool,
-    pub nudge_up_neg: bool,
-    pub turn_left: bool,
-    pub turn_right: bool,
-    pub away: bool,
-    pub l_button_down: bool,
-    pub l_button_up: bool,
-    pub ml_button_down: bool,
-    pub ml_button_up: bool,
+bitflags! {
+    /// The controls the agent is holding down, which the simulator moves the avatar with.
+    /// Movement controls have to be sent again in every AgentUpdate for as long as they are
+    /// held.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct ControlFlags: u32 {
+        /// move forward
+        const AT_POS = 0x00000001;
+        /// move backward
+        const AT_NEG = 0x00000002;
+        /// move left
+        const LEFT_POS = 0x00000004;
+        /// move right
+        const LEFT_NEG = 0x00000008;
+        /// jump, or fly up
+        const UP_POS = 0x00000010;
+        /// crouch, or fly down
+        const UP_NEG = 0x00000020;
+        const PITCH_POS = 0x00000040;
+        const PITCH_NEG = 0x00000080;
+        const YAW_POS = 0x00000100;
+        const YAW_NEG = 0x00000200;
+        /// run forward
+        const FAST_AT = 0x00000400;
+        const FAST_LEFT = 0x00000800;
+        const FAST_UP = 0x00001000;
+        /// fly, or hover in place
+        const FLY = 0x00002000;
+        const STOP = 0x00004000;
+        const FINISH_ANIM = 0x00008000;
+        /// stand up from the object the agent is sitting on
+        const STAND_UP = 0x00010000;
+        const SIT_ON_GROUND = 0x00020000;
+        const MOUSELOOK = 0x00040000;
+        const NUDGE_AT_POS = 0x00080000;
+        const NUDGE_AT_NEG = 0x00100000;
+        const NUDGE_LEFT_POS = 0x00200000;
+        const NUDGE_LEFT_NEG = 0x00400000;
+        const NUDGE_UP_POS = 0x00800000;
+        const NUDGE_UP_NEG = 0x01000000;
+        const TURN_LEFT = 0x02000000;
+        const TURN_RIGHT = 0x04000000;
+        const AWAY = 0x08000000;
+        const LBUTTON_DOWN = 0x10000000;
+        const LBUTTON_UP = 0x20000000;
+        const ML_LBUTTON_DOWN = 0x40000000;
+        const ML_LBUTTON_UP = 0x80000000;
+    }
 }
-impl ControlFlags {
-    pub fn from_bytes(bits: u32) -> Self {
-        Self {
-            at_pos: bits & AGENT_CONTROL_AT_POS != 0,
-            at_neg: bits & AGENT_CONTROL_AT_NEG != 0,
-            left_pos: bits & AGENT_CONTROL_LEFT_POS != 0,
-            left_neg: bits & AGENT_CONTROL_LEFT_NEG != 0,
-            up_pos: bits & AGENT_CONTROL_UP_POS != 0,
-            up_neg: bits & AGENT_CONTROL_UP_NEG != 0,
-            pitch_pos: bits & AGENT_CONTROL_PITCH_POS != 0,
-            pitch_neg: bits & AGENT_CONTROL_PITCH_NEG != 0,
-            yaw_pos: bits & AGENT_CONTROL_YAW_POS != 0,
-            yaw_neg: bits & AGENT_CONTROL_YAW_NEG != 0,
-            fast_at: bits & AGENT_CONTROL_FAST_AT != 0,
-            fast_left: bits & AGENT_CONTROL_FAST_LEFT != 0,
-            fast_up: bits & AGENT_CONTROL_FAST_UP != 0,
-            fly: bits & AGENT_CONTROL_FLY != 0,
-            stop: bits & AGENT_CONTROL_STOP != 0,
-            finish_anim: bits & AGENT_CONTROL_FINISH_ANIM != 0,
-            stand_up: bits & AGENT_CONTROL_STAND_UP != 0,
-            sit_on_ground: bits & AGENT_CONTROL_SIT_ON_GROUND != 0,
-            mouselook: bits & AGENT_CONTROL_MOUSELOOK != 0,
-            nudge_at_pos: bits & AGENT_CONTROL_NUDGE_AT_POS != 0,
-            nudge_at_neg: bits & AGENT_CONTROL_NUDGE_AT_NEG != 0,
-            nudge_left_pos: bits & AGENT_CONTROL_NUDGE_LEFT_POS != 0,
-            nudge_left_neg: bits & AGENT_CONTROL_NUDGE_LEFT_NEG != 0,
-            nudge_up_pos: bits & AGENT_CONTROL_NUDGE_UP_POS != 0,
-            nudge_up_neg: bits & AGENT_CONTROL_NUDGE_UP_NEG != 0,
-            turn_left: bits & AGENT_CONTROL_TURN_LEFT != 0,
-            turn_right: bits & AGENT_CONTROL_TURN_RIGHT != 0,
-            away: bits & AGENT_CONTROL_AWAY != 0,
-            l_button_down: bits & AGENT_CONTROL_LBUTTON_DOWN != 0,
-            l_button_up: bits & AGENT_CONTROL_LBUTTON_UP != 0,
-            ml_button_down: bits & AGENT_CONTROL_ML_LBUTTON_DOWN != 0,
-            ml_button_up: bits & AGENT_CONTROL_ML_LBUTTON_UP != 0,
-        }
+
+impl Serialize for ControlFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
     }
-    pub fn to_bytes(&self) -> [u8; 4] {
-        let mut bits = 0u32;
-        if self.at_pos {
-            bits |= AGENT_CONTROL_AT_POS;
-        }
-        if self.at_neg {
-            bits |= AGENT_CONTROL_AT_NEG;
-        }
-        if self.left_pos {
-            bits |= AGENT_CONTROL_LEFT_POS;
-        }
-        if self.left_neg {
-            bits |= AGENT_CONTROL_LEFT_NEG;
-        }
-        if self.up_pos {
-            bits |= AGENT_CONTROL_UP_POS;
-        }
-        if self.up_neg {
-            bits |= AGENT_CONTROL_UP_NEG;
-        }
-        if self.pitch_pos {
-            bits |= AGENT_CONTROL_PITCH_POS;
-        }
-        if self.pitch_neg {
-            bits |= AGENT_CONTROL_PITCH_NEG;
-        }
-        if self.yaw_pos {
-            bits |= AGENT_CONTROL_YAW_POS;
-        }
-        if self.yaw_neg {
-            bits |= AGENT_CONTROL_YAW_NEG;
-        }
-        if self.fast_at {
-            bits |= AGENT_CONTROL_FAST_AT;
-        }
-        if self.fast_left {
-            bits |= AGENT_CONTROL_FAST_LEFT;
-        }
-        if self.fast_up {
-            bits |= AGENT_CONTROL_FAST_UP;
-        }
-        if self.fly {
-            bits |= AGENT_CONTROL_FLY;
-        }
-        if self.stop {
-            bits |= AGENT_CONTROL_STOP;
-        }
-        if self.finish_anim {
-            bits |= AGENT_CONTROL_FINISH_ANIM;
-        }
-        if self.stand_up {
-            bits |= AGENT_CONTROL_STAND_UP;
-        }
-        if self.sit_on_ground {
-            bits |= AGENT_CONTROL_SIT_ON_GROUND;
-        }
-        if self.mouselook {
-            bits |= AGENT_CONTROL_MOUSELOOK;
-        }
-        if self.nudge_at_pos {
-            bits |= AGENT_CONTROL_NUDGE_AT_POS;
-        }
-        if self.nudge_at_neg {
-            bits |= AGENT_CONTROL_NUDGE_AT_NEG;
-        }
-        if self.nudge_left_pos {
-            bits |= AGENT_CONTROL_NUDGE_LEFT_POS;
-        }
-        if self.nudge_left_neg {
-            bits |= AGENT_CONTROL_NUDGE_LEFT_NEG;
-        }
-        if self.nudge_up_pos {
-            bits |= AGENT_CONTROL_NUDGE_UP_POS;
-        }
-        if self.nudge_up_neg {
-            bits |= AGENT_CONTROL_NUDGE_UP_NEG;
-        }
-        if self.turn_left {
-            bits |= AGENT_CONTROL_TURN_LEFT;
-        }
-        if self.turn_right {
-            bits |= AGENT_CONTROL_TURN_RIGHT;
-        }
-        if self.away {
-            bits |= AGENT_CONTROL_AWAY;
-        }
-        if self.l_button_down {
-            bits |= AGENT_CONTROL_LBUTTON_DOWN;
-        }
-        if self.l_button_up {
-            bits |= AGENT_CONTROL_LBUTTON_UP;
-        }
-        if self.ml_button_down {
-            bits |= AGENT_CONTROL_ML_LBUTTON_DOWN;
-        }
-        if self.ml_button_up {
-            bits |= AGENT_CONTROL_ML_LBUTTON_UP;
-        }
-        bits.to_le_bytes()
+}
+
+impl<'de> Deserialize<'de> for ControlFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_retain)
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Flags {
     pub none: bool,
     pub hide_title: bool,
@@ -289,7 +149,7 @@ impl Flags {
         bits
     }
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AgentUpdate {
     pub agent_id: Uuid,
     pub session_id: Uuid,
@@ -305,113 +165,143 @@ pub struct AgentUpdate {
     pub flags: Flags,
 }
 
-pub trait ToFromBytes {
-    fn from_bytes(bytes: &[u8]) -> Self;
-    fn to_bytes(&self) -> [u8; 16];
+/// Where the agent's camera is. The simulator sends the objects the camera can see, and the
+/// avatar moves in the direction it faces, which follows the camera.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    /// the position of the camera in the region
+    pub center: Vec3,
+    /// the direction the camera looks
+    pub at_axis: Vec3,
+    pub left_axis: Vec3,
+    pub up_axis: Vec3,
+    /// the draw distance, in meters
+    pub far: f32,
 }
 
-impl ToFromBytes for Quat {
-    fn from_bytes(bytes: &[u8]) -> Self {
-        let x = f32::from_le_bytes(bytes[0..4].try_into().unwrap());
-        let y = f32::from_le_bytes(bytes[4..8].try_into().unwrap());
-        let z = f32::from_le_bytes(bytes[8..12].try_into().unwrap());
-        let w = f32::from_le_bytes(bytes[12..16].try_into().unwrap());
-        Quat::from_xyzw(x, y, z, w)
-    }
-    fn to_bytes(&self) -> [u8; 16] {
-        let mut bytes = [0u8; 16];
-        bytes[0..4].copy_from_slice(&self.w.to_le_bytes());
-        bytes[4..8].copy_from_slice(&self.x.to_le_bytes());
-        bytes[8..12].copy_from_slice(&self.y.to_le_bytes());
-        bytes[12..16].copy_from_slice(&self.z.to_le_bytes());
-        bytes
+impl Default for Camera {
+    /// a camera at the region's origin, looking east
+    fn default() -> Self {
+        Camera {
+            center: Vec3::ZERO,
+            at_axis: Vec3::X,
+            left_axis: Vec3::Y,
+            up_axis: Vec3::Z,
+            far: AgentUpdate::DEFAULT_FAR,
+        }
     }
 }
 
-impl PacketData for AgentUpdate {
-    fn from_bytes(bytes: &[u8]) -> std::io::Result<Self> {
-        // THIS DOES NOT WORK AT ALL
-        // THIS WILL CRASH AND BREAK YOUR SHIT
-        let agent_id = Uuid::from_slice(&bytes[0..16]).unwrap();
-        let session_id = Uuid::from_slice(&bytes[16..32]).unwrap();
-        let body_rotation = Quat::from_bytes(&bytes[32..48]);
-        let head_rotation = Quat::from_bytes(&bytes[48..64]);
+impl AgentUpdate {
+    /// the draw distance sent when the UI hasn't set one
+    pub const DEFAULT_FAR: f32 = 64.0;
+
+    /// an update with no controls held, the default camera, and the agent and session ids left
+    /// nil
+    pub fn new() -> Self {
+        Self::with_controls(Camera::default(), ControlFlags::empty())
+    }
 
-        let state = State::from_bytes(bytes[64]);
-        let camera_center = Vec3 {
-            x: f32::from_le_bytes(bytes[65..69].try_into().unwrap()),
-            y: f32::from_le_bytes(bytes[69..73].try_into().unwrap()),
-            z: f32::from_le_bytes(bytes[73..77].try_into().unwrap()),
-        };
-        let camera_at_axis = Vec3 {
-            x: f32::from_le_bytes(bytes[77..81].try_into().unwrap()),
-            y: f32::from_le_bytes(bytes[81..85].try_into().unwrap()),
-            z: f32::from_le_bytes(bytes[85..89].try_into().unwrap()),
-        };
-        let camera_left_axis = Vec3 {
-            x: f32::from_le_bytes(bytes[89..93].try_into().unwrap()),
-            y: f32::from_le_bytes(bytes[93..97].try_into().unwrap()),
-            z: f32::from_le_bytes(bytes[97..101].try_into().unwrap()),
-        };
-        let camera_up_axis = Vec3 {
-            x: f32::from_le_bytes(bytes[101..105].try_into().unwrap()),
-            y: f32::from_le_bytes(bytes[105..109].try_into().unwrap()),
-            z: f32::from_le_bytes(bytes[109..113].try_into().unwrap()),
-        };
-        let far = f32::from_le_bytes(bytes[113..117].try_into().unwrap());
-        let control_flags =
-            ControlFlags::from_bytes(u32::from_le_bytes(bytes[117..121].try_into().unwrap()));
-        let flags = Flags::from_bytes(bytes[121]);
-        Ok(Self {
-            agent_id,
-            session_id,
-            body_rotation,
-            head_rotation,
-            state,
-            camera_center,
-            camera_at_axis,
-            camera_left_axis,
-            camera_up_axis,
-            far,
+    /// an update holding down controls, with the avatar facing the way the camera looks along
+    /// the ground. The agent and session ids are left nil.
+    pub fn with_controls(camera: Camera, control_flags: ControlFlags) -> Self {
+        AgentUpdate {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            body_rotation: Quat::from_rotation_z(camera.at_axis.y.atan2(camera.at_axis.x)),
+            head_rotation: Quat::IDENTITY,
+            state: State::default(),
+            camera_center: camera.center,
+            camera_at_axis: camera.at_axis,
+            camera_left_axis: camera.left_axis,
+            camera_up_axis: camera.up_axis,
+            far: camera.far,
             control_flags,
-            flags,
-        })
+            flags: Flags::default(),
+        }
     }
 
-    fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(121); // Total byte length
+    /// walk the way the camera looks
+    pub fn walking_forward(camera: Camera) -> Self {
+        Self::with_controls(camera, ControlFlags::AT_POS)
+    }
 
-        // Serialize UUIDs
-        bytes.extend_from_slice(self.agent_id.as_bytes());
-        bytes.extend_from_slice(self.session_id.as_bytes());
+    /// run the way the camera looks
+    pub fn running_forward(camera: Camera) -> Self {
+        Self::with_controls(camera, ControlFlags::AT_POS | ControlFlags::FAST_AT)
+    }
 
-        // Serialize Quaternions
-        bytes.extend_from_slice(&self.body_rotation.to_bytes());
-        bytes.extend_from_slice(&self.head_rotation.to_bytes());
+    /// fly, hovering in place
+    pub fn flying(camera: Camera) -> Self {
+        Self::with_controls(camera, ControlFlags::FLY)
+    }
 
-        // Serialize State
-        bytes.push(self.state.to_bytes());
+    /// fly the way the camera looks
+    pub fn flying_forward(camera: Camera) -> Self {
+        Self::with_controls(camera, ControlFlags::FLY | ControlFlags::AT_POS)
+    }
 
-        // Serialize Vector3s
-        bytes.extend_from_slice(&self.camera_center.x.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_center.y.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_center.z.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_at_axis.x.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_at_axis.y.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_at_axis.z.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_left_axis.x.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_left_axis.y.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_left_axis.z.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_up_axis.x.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_up_axis.y.to_le_bytes());
-        bytes.extend_from_slice(&self.camera_up_axis.z.to_le_bytes());
+    /// let go of every control
+    pub fn stopped(camera: Camera) -> Self {
+        Self::with_controls(camera, ControlFlags::empty())
+    }
 
-        bytes.extend_from_slice(&self.far.to_le_bytes());
+    /// an update that stands the agent up from whatever it is sitting on
+    pub fn stand_up() -> Self {
+        Self::with_controls(Camera::default(), ControlFlags::STAND_UP)
+    }
 
-        bytes.extend_from_slice(&self.control_flags.to_bytes());
+    /// the camera the update was sent with
+    pub fn camera(&self) -> Camera {
+        Camera {
+            center: self.camera_center,
+            at_axis: self.camera_at_axis,
+            left_axis: self.camera_left_axis,
+            up_axis: self.camera_up_axis,
+            far: self.far,
+        }
+    }
+}
 
-        bytes.push(self.flags.to_bytes());
+impl Default for AgentUpdate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PacketData for AgentUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AgentUpdate {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            body_rotation: read_quat(&mut cursor)?,
+            head_rotation: read_quat(&mut cursor)?,
+            state: State::from_bytes(cursor.read_u8()?),
+            camera_center: read_vec3(&mut cursor)?,
+            camera_at_axis: read_vec3(&mut cursor)?,
+            camera_left_axis: read_vec3(&mut cursor)?,
+            camera_up_axis: read_vec3(&mut cursor)?,
+            far: cursor.read_f32::<LittleEndian>()?,
+            control_flags: ControlFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?),
+            flags: Flags::from_bytes(cursor.read_u8()?),
+        })
+    }
 
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(114);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        write_quat(&mut bytes, &self.body_rotation);
+        write_quat(&mut bytes, &self.head_rotation);
+        bytes.push(self.state.to_bytes());
+        write_vec3(&mut bytes, &self.camera_center);
+        write_vec3(&mut bytes, &self.camera_at_axis);
+        write_vec3(&mut bytes, &self.camera_left_axis);
+        write_vec3(&mut bytes, &self.camera_up_axis);
+        bytes.extend_from_slice(&self.far.to_le_bytes());
+        bytes.extend_from_slice(&self.control_flags.bits().to_le_bytes());
+        bytes.push(self.flags.to_bytes());
         bytes
     }
 }
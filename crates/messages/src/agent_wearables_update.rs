@@ -0,0 +1,102 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::wearable_type::WearableType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 382
+// Frequency: Low
+
+impl Packet {
+    pub fn new_agent_wearables_update(agent_wearables_update: AgentWearablesUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 382,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AgentWearablesUpdate(Box::new(agent_wearables_update)),
+        }
+    }
+}
+
+/// The wearables the agent has on, sent in answer to an AgentWearablesRequest.
+/// Empty slots have nil item and asset ids.
+/// https://wiki.secondlife.com/wiki/AgentWearablesUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentWearablesUpdate {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// increases with every change to the agent's wearables, so older updates can be ignored
+    pub serial_num: u32,
+    pub wearables: Vec<Wearable>,
+}
+
+/// one worn item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Wearable {
+    pub wearable_type: WearableType,
+    /// the inventory item of the wearable
+    pub item_id: Uuid,
+    /// the asset the item points to
+    pub asset_id: Uuid,
+}
+
+impl PacketData for AgentWearablesUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AgentData
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let serial_num = cursor.read_u32::<LittleEndian>()?;
+
+        // WearableData
+        let count = cursor.read_u8()?;
+        let mut wearables = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let item_id = read_uuid(&mut cursor)?;
+            let asset_id = read_uuid(&mut cursor)?;
+            let wearable_type = WearableType::from_bytes(cursor.read_u8()?);
+            wearables.push(Wearable {
+                wearable_type,
+                item_id,
+                asset_id,
+            });
+        }
+
+        Ok(AgentWearablesUpdate {
+            agent_id,
+            session_id,
+            serial_num,
+            wearables,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(37 + self.wearables.len() * 33);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.serial_num.to_le_bytes());
+        bytes.push(self.wearables.len() as u8);
+        for wearable in &self.wearables {
+            bytes.extend_from_slice(wearable.item_id.as_bytes());
+            bytes.extend_from_slice(wearable.asset_id.as_bytes());
+            bytes.push(wearable.wearable_type.to_bytes());
+        }
+        bytes
+    }
+}
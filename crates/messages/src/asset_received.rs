@@ -0,0 +1,44 @@
+use crate::packet::PacketData;
+use crate::utils::asset_type::AssetType;
+use crate::utils::packet_fields::read_uuid;
+
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+/// An asset that has finished downloading with the Transfer system, sent from the session to
+/// the UI. Assets can be large, so this is sent in binary instead of JSON.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetReceived {
+    pub transfer_id: Uuid,
+    pub asset_id: Uuid,
+    pub asset_type: AssetType,
+    pub data: Vec<u8>,
+}
+
+impl PacketData for AssetReceived {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let transfer_id = read_uuid(&mut cursor)?;
+        let asset_id = read_uuid(&mut cursor)?;
+        let asset_type = AssetType::from_bytes(cursor.read_i8()?);
+        let mut data = Vec::new();
+        cursor.read_to_end(&mut data)?;
+        Ok(AssetReceived {
+            transfer_id,
+            asset_id,
+            asset_type,
+            data,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(33 + self.data.len());
+        bytes.extend_from_slice(self.transfer_id.as_bytes());
+        bytes.extend_from_slice(self.asset_id.as_bytes());
+        bytes.push(self.asset_type.to_bytes() as u8);
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::asset_type::AssetType;
+
+/// Sent from the session to the UI when an asset download was aborted by the simulator or timed
+/// out.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssetTransferFailed {
+    pub transfer_id: Uuid,
+    pub asset_id: Uuid,
+    pub asset_type: AssetType,
+    /// human readable reason the download failed
+    pub reason: String,
+}
@@ -0,0 +1,68 @@
+use crate::packet_types::PacketType;
+use crate::utils::asset_type::AssetType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 334
+// Frequency: Low
+
+impl Packet {
+    pub fn new_asset_upload_complete(asset_upload_complete: AssetUploadComplete) -> Self {
+        Packet {
+            header: Header {
+                id: 334,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AssetUploadComplete(Box::new(asset_upload_complete)),
+        }
+    }
+}
+
+/// The simulator's answer to an AssetUploadRequest, once the asset has been stored or has failed.
+/// https://wiki.secondlife.com/wiki/AssetUploadComplete
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetUploadComplete {
+    /// the id of the new asset
+    pub asset_id: Uuid,
+    pub asset_type: AssetType,
+    pub success: bool,
+}
+
+impl PacketData for AssetUploadComplete {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AssetBlock
+        let asset_id = read_uuid(&mut cursor)?;
+        let asset_type = AssetType::from_bytes(cursor.read_i8()?);
+        let success = cursor.read_u8()? != 0;
+
+        Ok(AssetUploadComplete {
+            asset_id,
+            asset_type,
+            success,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(18);
+        bytes.extend_from_slice(self.asset_id.as_bytes());
+        bytes.push(self.asset_type.to_bytes() as u8);
+        bytes.push(self.success as u8);
+        bytes
+    }
+}
@@ -0,0 +1,92 @@
+use crate::packet_types::PacketType;
+use crate::utils::asset_type::AssetType;
+use crate::utils::packet_fields::{read_uuid, read_variable_2, write_variable_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use md5::{Digest, Md5};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 333
+// Frequency: Low
+
+impl Packet {
+    pub fn new_asset_upload_request(asset_upload_request: AssetUploadRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 333,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AssetUploadRequest(Box::new(asset_upload_request)),
+        }
+    }
+}
+
+/// Uploads a new asset to the simulator. Small assets are sent inline in the asset data. For
+/// larger assets the asset data is left empty, and the simulator pulls the asset with the Xfer
+/// system, requesting it by the asset id.
+/// The simulator answers with an AssetUploadComplete.
+/// https://wiki.secondlife.com/wiki/AssetUploadRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetUploadRequest {
+    /// chosen by the uploader. The asset id is derived from it.
+    pub transaction_id: Uuid,
+    pub asset_type: AssetType,
+    /// temporary assets are not stored permanently
+    pub temp_file: bool,
+    pub store_local: bool,
+    /// the asset itself, or empty if it is sent with the Xfer system
+    pub asset_data: Vec<u8>,
+}
+impl AssetUploadRequest {
+    /// the id the asset is stored under. This is the md5 hash of the transaction id followed by
+    /// the secure session id.
+    pub fn asset_id(transaction_id: Uuid, secure_session_id: Uuid) -> Uuid {
+        let mut hasher = Md5::new();
+        hasher.update(transaction_id.as_bytes());
+        hasher.update(secure_session_id.as_bytes());
+        Uuid::from_bytes(hasher.finalize().into())
+    }
+}
+
+impl PacketData for AssetUploadRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AssetBlock
+        let transaction_id = read_uuid(&mut cursor)?;
+        let asset_type = AssetType::from_bytes(cursor.read_i8()?);
+        let temp_file = cursor.read_u8()? != 0;
+        let store_local = cursor.read_u8()? != 0;
+        let asset_data = read_variable_2(&mut cursor)?;
+
+        Ok(AssetUploadRequest {
+            transaction_id,
+            asset_type,
+            temp_file,
+            store_local,
+            asset_data,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(21 + self.asset_data.len());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.push(self.asset_type.to_bytes() as u8);
+        bytes.push(self.temp_file as u8);
+        bytes.push(self.store_local as u8);
+        write_variable_2(&mut bytes, &self.asset_data);
+        bytes
+    }
+}
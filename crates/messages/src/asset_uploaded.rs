@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::asset_type::AssetType;
+
+/// Sent from the session to the UI when an asset upload finishes or fails. The transaction id
+/// is the one the UI sent in its AssetUploadRequest.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssetUploaded {
+    pub transaction_id: Uuid,
+    /// the id of the new asset
+    pub asset_id: Uuid,
+    pub asset_type: AssetType,
+    pub success: bool,
+    /// human readable reason the upload failed
+    pub reason: Option<String>,
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::packet_types::PacketType;
+use crate::utils::attachment_point::AttachmentPoint;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_attach_from_inventory(attach_from_inventory: AttachFromInventory) -> Self {
+        Packet {
+            header: Header {
+                id: 68,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AttachFromInventory(Box::new(attach_from_inventory)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to wear an object from inventory. The session looks the item
+/// up in the inventory it has fetched, and sends a RezSingleAttachmentFromInv with the item's
+/// owner, name and permissions.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttachFromInventory {
+    pub item_id: Uuid,
+    pub attachment_point: AttachmentPoint,
+    /// add the object to the point, instead of replacing what is already attached there
+    pub add: bool,
+}
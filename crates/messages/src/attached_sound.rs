@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::sound_flags::SoundFlags;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 13
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_attached_sound(attached_sound: AttachedSound) -> Self {
+        Packet {
+            header: Header {
+                id: 13,
+                frequency: PacketFrequency::Medium,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AttachedSound(Box::new(attached_sound)),
+        }
+    }
+}
+
+/// Plays a sound on an object, following the object as it moves.
+/// An object plays one attached sound at a time, so a new one replaces the last unless it is
+/// queued. A sound with the STOP flag stops the object's sound.
+/// https://wiki.secondlife.com/wiki/AttachedSound
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttachedSound {
+    /// the sound asset
+    pub sound_id: Uuid,
+    /// the object the sound plays on
+    pub object_id: Uuid,
+    pub owner_id: Uuid,
+    /// the volume, from 0 to 1
+    pub gain: f32,
+    pub flags: SoundFlags,
+}
+
+impl PacketData for AttachedSound {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AttachedSound {
+            sound_id: read_uuid(&mut cursor)?,
+            object_id: read_uuid(&mut cursor)?,
+            owner_id: read_uuid(&mut cursor)?,
+            gain: cursor.read_f32::<LittleEndian>()?,
+            flags: SoundFlags::from_bits_retain(cursor.read_u8()?),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(53);
+        bytes.extend_from_slice(self.sound_id.as_bytes());
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.extend_from_slice(&self.gain.to_le_bytes());
+        bytes.push(self.flags.bits());
+        bytes
+    }
+}
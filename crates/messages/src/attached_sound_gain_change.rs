@@ -0,0 +1,61 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 14
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_attached_sound_gain_change(
+        attached_sound_gain_change: AttachedSoundGainChange,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 14,
+                frequency: PacketFrequency::Medium,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AttachedSoundGainChange(Box::new(attached_sound_gain_change)),
+        }
+    }
+}
+
+/// Changes the volume of the sound attached to an object, with llAdjustSoundVolume.
+/// https://wiki.secondlife.com/wiki/AttachedSoundGainChange
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttachedSoundGainChange {
+    pub object_id: Uuid,
+    /// the new volume, from 0 to 1
+    pub gain: f32,
+}
+
+impl PacketData for AttachedSoundGainChange {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AttachedSoundGainChange {
+            object_id: read_uuid(&mut cursor)?,
+            gain: cursor.read_f32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(20);
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(&self.gain.to_le_bytes());
+        bytes
+    }
+}
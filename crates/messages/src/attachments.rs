@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::attachment_point::AttachmentPoint;
+
+/// Sent from the session to the UI with the objects the agent is wearing, as a JSON array.
+/// The session learns them from the ObjectUpdates of objects attached to the agent's avatar,
+/// and sends the list again whenever something is attached or detached.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Attachments {
+    pub attachments: Vec<Attachment>,
+}
+
+/// An object the agent is wearing
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// the local id of the object in the region, which changes when the agent changes regions
+    pub local_id: u32,
+    pub object_id: Uuid,
+    /// the inventory item the object was attached from
+    pub item_id: Uuid,
+    pub attachment_point: AttachmentPoint,
+}
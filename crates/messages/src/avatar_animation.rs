@@ -0,0 +1,119 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_variable_1, write_variable_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 20
+// Frequency: High
+
+impl Packet {
+    pub fn new_avatar_animation(avatar_animation: AvatarAnimation) -> Self {
+        Packet {
+            header: Header {
+                id: 20,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarAnimation(Box::new(avatar_animation)),
+        }
+    }
+}
+
+/// Sent by the simulator with every animation an avatar is playing, whenever one starts or
+/// stops, including the agent's own. Animations not in the list have stopped.
+/// https://wiki.secondlife.com/wiki/AvatarAnimation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarAnimation {
+    /// the avatar playing the animations
+    pub sender_id: Uuid,
+    pub animations: Vec<PlayingAnimation>,
+    /// the objects whose scripts started the animations, with llStartAnimation. Only the
+    /// animations started by objects have one, and they come first in the animations.
+    pub sources: Vec<Uuid>,
+    /// data about the avatar's physics, one block per event
+    pub physical_avatar_events: Vec<Vec<u8>>,
+}
+
+/// an animation an avatar is playing
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlayingAnimation {
+    pub anim_id: Uuid,
+    /// counts up as animations are started, so the newest ones can be played on top
+    pub sequence_id: i32,
+}
+
+impl PacketData for AvatarAnimation {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let sender_id = read_uuid(&mut cursor)?;
+
+        let count = cursor.read_u8()?;
+        let mut animations = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            animations.push(PlayingAnimation {
+                anim_id: read_uuid(&mut cursor)?,
+                sequence_id: cursor.read_i32::<LittleEndian>()?,
+            });
+        }
+
+        let count = cursor.read_u8()?;
+        let mut sources = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            sources.push(read_uuid(&mut cursor)?);
+        }
+
+        // older simulators end the packet before the physical avatar events
+        let count = if (cursor.position() as usize) < bytes.len() {
+            cursor.read_u8()?
+        } else {
+            0
+        };
+        let mut physical_avatar_events = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            physical_avatar_events.push(read_variable_1(&mut cursor)?);
+        }
+
+        Ok(AvatarAnimation {
+            sender_id,
+            animations,
+            sources,
+            physical_avatar_events,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let animations = &self.animations[..self.animations.len().min(u8::MAX as usize)];
+        let sources = &self.sources[..self.sources.len().min(u8::MAX as usize)];
+        let events =
+            &self.physical_avatar_events[..self.physical_avatar_events.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(19 + animations.len() * 20 + sources.len() * 16);
+        bytes.extend_from_slice(self.sender_id.as_bytes());
+        bytes.push(animations.len() as u8);
+        for animation in animations {
+            bytes.extend_from_slice(animation.anim_id.as_bytes());
+            bytes.extend_from_slice(&animation.sequence_id.to_le_bytes());
+        }
+        bytes.push(sources.len() as u8);
+        for source in sources {
+            bytes.extend_from_slice(source.as_bytes());
+        }
+        bytes.push(events.len() as u8);
+        for event in events {
+            write_variable_1(&mut bytes, event);
+        }
+        bytes
+    }
+}
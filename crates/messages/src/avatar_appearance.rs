@@ -0,0 +1,144 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_uuid, read_variable_2, read_vec3, write_variable_2, write_vec3,
+};
+use crate::utils::texture_entry::TextureEntry;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+// ID: 158
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_appearance(avatar_appearance: AvatarAppearance) -> Self {
+        Packet {
+            header: Header {
+                id: 158,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarAppearance(Box::new(avatar_appearance)),
+        }
+    }
+}
+
+/// Sent by the simulator with the appearance of an avatar: the textures of its body and baked
+/// layers, and the visual params that shape it.
+/// Each visual param is a byte, in the order of the avatar definition file. The TextureEntry
+/// can be parsed with texture_entry().
+/// https://wiki.secondlife.com/wiki/AvatarAppearance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarAppearance {
+    /// the avatar this appearance is for
+    pub sender_id: Uuid,
+    pub is_trial: bool,
+    /// the packed TextureEntry of the avatar's faces
+    pub texture_entry: Vec<u8>,
+    pub visual_params: Vec<u8>,
+    /// only sent by newer simulators
+    pub appearance_data: Vec<AppearanceData>,
+    /// how far the avatar hovers above the ground. Only sent by newer simulators.
+    pub hover_heights: Vec<Vec3>,
+}
+
+/// the version of the appearance, sent by newer simulators
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppearanceData {
+    pub appearance_version: u8,
+    /// the version of the current outfit folder the appearance was baked from
+    pub cof_version: i32,
+    pub flags: u32,
+}
+
+impl AvatarAppearance {
+    /// parse the TextureEntry of the avatar
+    pub fn texture_entry(&self) -> io::Result<TextureEntry> {
+        TextureEntry::from_bytes(&self.texture_entry)
+    }
+}
+
+impl PacketData for AvatarAppearance {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // Sender
+        let sender_id = read_uuid(&mut cursor)?;
+        let is_trial = cursor.read_u8()? != 0;
+
+        // ObjectData
+        let texture_entry = read_variable_2(&mut cursor)?;
+
+        // VisualParam
+        let param_count = cursor.read_u8()?;
+        let mut visual_params = vec![0u8; param_count as usize];
+        cursor.read_exact(&mut visual_params)?;
+
+        // AppearanceData and AppearanceHover are missing from older simulators
+        let mut appearance_data = Vec::new();
+        let mut hover_heights = Vec::new();
+        if (cursor.position() as usize) < bytes.len() {
+            let count = cursor.read_u8()?;
+            for _ in 0..count {
+                appearance_data.push(AppearanceData {
+                    appearance_version: cursor.read_u8()?,
+                    cof_version: cursor.read_i32::<LittleEndian>()?,
+                    flags: cursor.read_u32::<LittleEndian>()?,
+                });
+            }
+        }
+        if (cursor.position() as usize) < bytes.len() {
+            let count = cursor.read_u8()?;
+            for _ in 0..count {
+                hover_heights.push(read_vec3(&mut cursor)?);
+            }
+        }
+
+        Ok(AvatarAppearance {
+            sender_id,
+            is_trial,
+            texture_entry,
+            visual_params,
+            appearance_data,
+            hover_heights,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            22 + self.texture_entry.len()
+                + self.visual_params.len()
+                + self.appearance_data.len() * 9
+                + self.hover_heights.len() * 12,
+        );
+        bytes.extend_from_slice(self.sender_id.as_bytes());
+        bytes.push(self.is_trial as u8);
+        write_variable_2(&mut bytes, &self.texture_entry);
+        bytes.push(self.visual_params.len() as u8);
+        bytes.extend_from_slice(&self.visual_params);
+        bytes.push(self.appearance_data.len() as u8);
+        for data in &self.appearance_data {
+            bytes.push(data.appearance_version);
+            bytes.extend_from_slice(&data.cof_version.to_le_bytes());
+            bytes.extend_from_slice(&data.flags.to_le_bytes());
+        }
+        bytes.push(self.hover_heights.len() as u8);
+        for hover_height in &self.hover_heights {
+            write_vec3(&mut bytes, hover_height);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,86 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 42
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_classified_reply(avatar_classified_reply: AvatarClassifiedReply) -> Self {
+        Packet {
+            header: Header {
+                id: 42,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarClassifiedReply(Box::new(avatar_classified_reply)),
+        }
+    }
+}
+
+/// The classifieds in an avatar's profile, by id and name, sent in answer to the
+/// avatarclassifiedsrequest GenericMessage sent when a profile is opened. The details of each
+/// classified are asked for with a ClassifiedInfoRequest.
+/// https://wiki.secondlife.com/wiki/AvatarClassifiedReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarClassifiedReply {
+    pub agent_id: Uuid,
+    /// the avatar the classifieds are for
+    pub target_id: Uuid,
+    pub classifieds: Vec<ClassifiedListing>,
+}
+
+/// a classified in an avatar's profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassifiedListing {
+    pub classified_id: Uuid,
+    pub name: String,
+}
+
+impl PacketData for AvatarClassifiedReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let target_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut classifieds = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            classifieds.push(ClassifiedListing {
+                classified_id: read_uuid(&mut cursor)?,
+                name: read_string_1(&mut cursor)?,
+            });
+        }
+        Ok(AvatarClassifiedReply {
+            agent_id,
+            target_id,
+            classifieds,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let classifieds = &self.classifieds[..self.classifieds.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + classifieds.len() * 48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.target_id.as_bytes());
+        bytes.push(classifieds.len() as u8);
+        for classified in classifieds {
+            bytes.extend_from_slice(classified.classified_id.as_bytes());
+            write_string_1(&mut bytes, &classified.name);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,109 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 173
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_groups_reply(avatar_groups_reply: AvatarGroupsReply) -> Self {
+        Packet {
+            header: Header {
+                id: 173,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarGroupsReply(Box::new(avatar_groups_reply)),
+        }
+    }
+}
+
+/// The groups an avatar is in, sent in answer to an AvatarPropertiesRequest. Avatars that aren't
+/// in any groups get a reply with no groups.
+/// https://wiki.secondlife.com/wiki/AvatarGroupsReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarGroupsReply {
+    pub agent_id: Uuid,
+    /// the avatar the groups are for
+    pub avatar_id: Uuid,
+    pub groups: Vec<AvatarGroup>,
+    /// whether the avatar shows its groups in its profile. False if the simulator leaves it off.
+    pub list_in_profile: bool,
+}
+
+/// a group an avatar is in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarGroup {
+    /// what the avatar can do in the group
+    pub group_powers: u64,
+    pub accept_notices: bool,
+    /// the title the avatar has in the group
+    pub group_title: String,
+    pub group_id: Uuid,
+    pub group_name: String,
+    /// the texture of the group's insignia
+    pub group_insignia_id: Uuid,
+}
+
+impl PacketData for AvatarGroupsReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let avatar_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut groups = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            groups.push(AvatarGroup {
+                group_powers: cursor.read_u64::<LittleEndian>()?,
+                accept_notices: cursor.read_u8()? != 0,
+                group_title: read_string_1(&mut cursor)?,
+                group_id: read_uuid(&mut cursor)?,
+                group_name: read_string_1(&mut cursor)?,
+                group_insignia_id: read_uuid(&mut cursor)?,
+            });
+        }
+        let list_in_profile = if (cursor.position() as usize) < bytes.len() {
+            cursor.read_u8()? != 0
+        } else {
+            false
+        };
+        Ok(AvatarGroupsReply {
+            agent_id,
+            avatar_id,
+            groups,
+            list_in_profile,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let groups = &self.groups[..self.groups.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(34 + groups.len() * 45);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.avatar_id.as_bytes());
+        bytes.push(groups.len() as u8);
+        for group in groups {
+            bytes.extend_from_slice(&group.group_powers.to_le_bytes());
+            bytes.push(group.accept_notices as u8);
+            write_string_1(&mut bytes, &group.group_title);
+            bytes.extend_from_slice(group.group_id.as_bytes());
+            write_string_1(&mut bytes, &group.group_name);
+            bytes.extend_from_slice(group.group_insignia_id.as_bytes());
+        }
+        bytes.push(self.list_in_profile as u8);
+        bytes
+    }
+}
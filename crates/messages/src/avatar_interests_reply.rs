@@ -0,0 +1,99 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 172
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_interests_reply(avatar_interests_reply: AvatarInterestsReply) -> Self {
+        Packet {
+            header: Header {
+                id: 172,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarInterestsReply(Box::new(avatar_interests_reply)),
+        }
+    }
+}
+
+/// The interests part of an avatar's profile, sent in answer to an AvatarPropertiesRequest.
+/// https://wiki.secondlife.com/wiki/AvatarInterestsReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarInterestsReply {
+    pub agent_id: Uuid,
+    /// the avatar the interests are for
+    pub avatar_id: Uuid,
+    pub interests: AvatarInterests,
+}
+
+/// what an avatar says it is interested in, on the interests tab of its profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AvatarInterests {
+    /// the "I want to" checkboxes
+    pub want_to_mask: u32,
+    pub want_to_text: String,
+    /// the "Skills" checkboxes
+    pub skills_mask: u32,
+    pub skills_text: String,
+    pub languages_text: String,
+}
+
+impl AvatarInterests {
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        Ok(AvatarInterests {
+            want_to_mask: cursor.read_u32::<LittleEndian>()?,
+            want_to_text: read_string_1(cursor)?,
+            skills_mask: cursor.read_u32::<LittleEndian>()?,
+            skills_text: read_string_1(cursor)?,
+            languages_text: read_string_1(cursor)?,
+        })
+    }
+
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.want_to_mask.to_le_bytes());
+        write_string_1(bytes, &self.want_to_text);
+        bytes.extend_from_slice(&self.skills_mask.to_le_bytes());
+        write_string_1(bytes, &self.skills_text);
+        write_string_1(bytes, &self.languages_text);
+    }
+}
+
+impl PacketData for AvatarInterestsReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AvatarInterestsReply {
+            agent_id: read_uuid(&mut cursor)?,
+            avatar_id: read_uuid(&mut cursor)?,
+            interests: AvatarInterests::from_bytes(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let interests = &self.interests;
+        let mut bytes = Vec::with_capacity(
+            43 + interests.want_to_text.len()
+                + interests.skills_text.len()
+                + interests.languages_text.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.avatar_id.as_bytes());
+        self.interests.to_bytes(&mut bytes);
+        bytes
+    }
+}
@@ -0,0 +1,77 @@
+use crate::avatar_interests_reply::AvatarInterests;
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 175
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_interests_update(avatar_interests_update: AvatarInterestsUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 175,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarInterestsUpdate(Box::new(avatar_interests_update)),
+        }
+    }
+}
+
+/// Changes the interests in the agent's own profile.
+/// https://wiki.secondlife.com/wiki/AvatarInterestsUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvatarInterestsUpdate {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub interests: AvatarInterests,
+}
+
+impl AvatarInterestsUpdate {
+    /// set the agent's interests. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(interests: AvatarInterests) -> Self {
+        AvatarInterestsUpdate {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            interests,
+        }
+    }
+}
+
+impl PacketData for AvatarInterestsUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AvatarInterestsUpdate {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            interests: AvatarInterests::from_bytes(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let interests = &self.interests;
+        let mut bytes = Vec::with_capacity(
+            43 + interests.want_to_text.len()
+                + interests.skills_text.len()
+                + interests.languages_text.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        self.interests.to_bytes(&mut bytes);
+        bytes
+    }
+}
@@ -0,0 +1,88 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 28
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_picker_reply(avatar_picker_reply: AvatarPickerReply) -> Self {
+        Packet {
+            header: Header {
+                id: 28,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarPickerReply(Box::new(avatar_picker_reply)),
+        }
+    }
+}
+
+/// The residents found by an AvatarPickerRequest. A search that found nobody is answered with
+/// no residents, or on some grids a single resident with a nil id.
+/// https://wiki.secondlife.com/wiki/AvatarPickerReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvatarPickerReply {
+    pub agent_id: Uuid,
+    /// the query id of the search
+    pub query_id: Uuid,
+    pub avatars: Vec<AvatarMatch>,
+}
+
+/// a resident found by a search
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarMatch {
+    pub avatar_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+impl PacketData for AvatarPickerReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let query_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut avatars = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            avatars.push(AvatarMatch {
+                avatar_id: read_uuid(&mut cursor)?,
+                first_name: read_string_1(&mut cursor)?,
+                last_name: read_string_1(&mut cursor)?,
+            });
+        }
+        Ok(AvatarPickerReply {
+            agent_id,
+            query_id,
+            avatars,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let avatars = &self.avatars[..self.avatars.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + avatars.len() * 32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.query_id.as_bytes());
+        bytes.push(avatars.len() as u8);
+        for avatar in avatars {
+            bytes.extend_from_slice(avatar.avatar_id.as_bytes());
+            write_string_1(&mut bytes, &avatar.first_name);
+            write_string_1(&mut bytes, &avatar.last_name);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,89 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 26
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_picker_request(avatar_picker_request: AvatarPickerRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 26,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarPickerRequest(Box::new(avatar_picker_request)),
+        }
+    }
+}
+
+/// Searches for residents by name, or part of a name. The simulator answers with an
+/// AvatarPickerReply with the same query id.
+/// https://wiki.secondlife.com/wiki/AvatarPickerRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvatarPickerRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// sent back in the AvatarPickerReply, to tell searches apart
+    pub query_id: Uuid,
+    /// the name to search for
+    pub name: String,
+}
+
+impl AvatarPickerRequest {
+    /// the shortest name the simulator searches for
+    pub const MIN_NAME_LENGTH: usize = 3;
+    /// the longest name that fits in the packet, in bytes
+    pub const MAX_NAME_LENGTH: usize = u8::MAX as usize - 1;
+
+    /// search for a name. The agent and session ids and the query id are left nil, for the
+    /// session to fill in.
+    pub fn new(name: String) -> Self {
+        AvatarPickerRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            query_id: Uuid::nil(),
+            name,
+        }
+    }
+
+    /// whether the name is long enough to search for, and short enough to send
+    pub fn name_is_valid(&self) -> bool {
+        let name = self.name.trim();
+        name.chars().count() >= Self::MIN_NAME_LENGTH && name.len() <= Self::MAX_NAME_LENGTH
+    }
+}
+
+impl PacketData for AvatarPickerRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AvatarPickerRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            query_id: read_uuid(&mut cursor)?,
+            name: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(50 + self.name.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.query_id.as_bytes());
+        write_string_1(&mut bytes, &self.name);
+        bytes
+    }
+}
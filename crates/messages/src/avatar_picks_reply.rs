@@ -0,0 +1,86 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 178
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_picks_reply(avatar_picks_reply: AvatarPicksReply) -> Self {
+        Packet {
+            header: Header {
+                id: 178,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarPicksReply(Box::new(avatar_picks_reply)),
+        }
+    }
+}
+
+/// The picks in an avatar's profile, by id and name, sent in answer to the avatarpicksrequest
+/// GenericMessage sent when a profile is opened. The details of each pick are asked for with a
+/// PickInfoRequest.
+/// https://wiki.secondlife.com/wiki/AvatarPicksReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarPicksReply {
+    pub agent_id: Uuid,
+    /// the avatar the picks are for
+    pub target_id: Uuid,
+    pub picks: Vec<PickListing>,
+}
+
+/// a pick in an avatar's profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PickListing {
+    pub pick_id: Uuid,
+    pub pick_name: String,
+}
+
+impl PacketData for AvatarPicksReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let target_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut picks = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            picks.push(PickListing {
+                pick_id: read_uuid(&mut cursor)?,
+                pick_name: read_string_1(&mut cursor)?,
+            });
+        }
+        Ok(AvatarPicksReply {
+            agent_id,
+            target_id,
+            picks,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let picks = &self.picks[..self.picks.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + picks.len() * 48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.target_id.as_bytes());
+        bytes.push(picks.len() as u8);
+        for pick in picks {
+            bytes.extend_from_slice(pick.pick_id.as_bytes());
+            write_string_1(&mut bytes, &pick.pick_name);
+        }
+        bytes
+    }
+}
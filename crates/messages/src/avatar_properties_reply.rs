@@ -0,0 +1,111 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_string_1, read_string_2, read_uuid, read_variable_1, write_string_1, write_string_2,
+    write_variable_1,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 171
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_properties_reply(avatar_properties_reply: AvatarPropertiesReply) -> Self {
+        Packet {
+            header: Header {
+                id: 171,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarPropertiesReply(Box::new(avatar_properties_reply)),
+        }
+    }
+}
+
+/// An avatar's profile, sent in answer to an AvatarPropertiesRequest.
+/// https://wiki.secondlife.com/wiki/AvatarPropertiesReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarPropertiesReply {
+    pub agent_id: Uuid,
+    /// the avatar the profile is for
+    pub avatar_id: Uuid,
+    /// the texture of the profile picture
+    pub image_id: Uuid,
+    /// the texture of the first life picture
+    pub first_life_image_id: Uuid,
+    /// the avatar's partner, nil for none
+    pub partner_id: Uuid,
+    /// the profile text. This can be several KB long.
+    pub about_text: String,
+    pub first_life_about_text: String,
+    /// when the account was made, like "1/31/2007"
+    pub born_on: String,
+    pub profile_url: String,
+    /// the kind of account. Usually a single byte, though some grids send a name instead.
+    pub charter_member: Vec<u8>,
+    /// whether the profile is shown in search, and other AVATAR_ flags
+    pub flags: u32,
+}
+
+impl AvatarPropertiesReply {
+    pub const ALLOW_PUBLISH: u32 = 1 << 0;
+    pub const MATURE_PUBLISH: u32 = 1 << 1;
+    pub const IDENTIFIED: u32 = 1 << 2;
+    pub const TRANSACTED: u32 = 1 << 3;
+    pub const ONLINE: u32 = 1 << 4;
+    pub const AGEVERIFIED: u32 = 1 << 5;
+}
+
+impl PacketData for AvatarPropertiesReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AvatarPropertiesReply {
+            agent_id: read_uuid(&mut cursor)?,
+            avatar_id: read_uuid(&mut cursor)?,
+            image_id: read_uuid(&mut cursor)?,
+            first_life_image_id: read_uuid(&mut cursor)?,
+            partner_id: read_uuid(&mut cursor)?,
+            about_text: read_string_2(&mut cursor)?,
+            first_life_about_text: read_string_1(&mut cursor)?,
+            born_on: read_string_1(&mut cursor)?,
+            profile_url: read_string_1(&mut cursor)?,
+            charter_member: read_variable_1(&mut cursor)?,
+            flags: cursor.read_u32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            88 + self.about_text.len()
+                + self.first_life_about_text.len()
+                + self.born_on.len()
+                + self.profile_url.len()
+                + self.charter_member.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.avatar_id.as_bytes());
+        bytes.extend_from_slice(self.image_id.as_bytes());
+        bytes.extend_from_slice(self.first_life_image_id.as_bytes());
+        bytes.extend_from_slice(self.partner_id.as_bytes());
+        write_string_2(&mut bytes, &self.about_text);
+        write_string_1(&mut bytes, &self.first_life_about_text);
+        write_string_1(&mut bytes, &self.born_on);
+        write_string_1(&mut bytes, &self.profile_url);
+        write_variable_1(&mut bytes, &self.charter_member);
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes
+    }
+}
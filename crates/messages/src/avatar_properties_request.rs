@@ -0,0 +1,75 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 169
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_properties_request(
+        avatar_properties_request: AvatarPropertiesRequest,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 169,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarPropertiesRequest(Box::new(avatar_properties_request)),
+        }
+    }
+}
+
+/// Asks for an avatar's profile. Among other replies, the simulator answers with an
+/// AvatarGroupsReply listing the groups the avatar is in.
+/// https://wiki.secondlife.com/wiki/AvatarPropertiesRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvatarPropertiesRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the avatar whose profile to get
+    pub avatar_id: Uuid,
+}
+
+impl AvatarPropertiesRequest {
+    /// ask for an avatar's profile. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(avatar_id: Uuid) -> Self {
+        AvatarPropertiesRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            avatar_id,
+        }
+    }
+}
+
+impl PacketData for AvatarPropertiesRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AvatarPropertiesRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            avatar_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.avatar_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,107 @@
+use crate::avatar_properties_reply::AvatarPropertiesReply;
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_string_1, read_string_2, read_uuid, write_string_1, write_string_2,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 174
+// Frequency: Low
+
+impl Packet {
+    pub fn new_avatar_properties_update(avatar_properties_update: AvatarPropertiesUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 174,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarPropertiesUpdate(Box::new(avatar_properties_update)),
+        }
+    }
+}
+
+/// Changes the agent's own profile. Everything in it is replaced, so to change one field, send
+/// the rest as they were in the AvatarPropertiesReply.
+/// https://wiki.secondlife.com/wiki/AvatarPropertiesUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvatarPropertiesUpdate {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the texture of the profile picture
+    pub image_id: Uuid,
+    /// the texture of the first life picture
+    pub first_life_image_id: Uuid,
+    /// the profile text. This can be several KB long.
+    pub about_text: String,
+    pub first_life_about_text: String,
+    /// whether the profile is shown in search
+    pub allow_publish: bool,
+    /// whether the profile is shown in mature search
+    pub mature_publish: bool,
+    pub profile_url: String,
+}
+
+impl AvatarPropertiesUpdate {
+    /// set the agent's profile to the one in a reply, to change some of it. The agent and session
+    /// ids are left nil, for the session to fill in.
+    pub fn from_reply(reply: &AvatarPropertiesReply) -> Self {
+        AvatarPropertiesUpdate {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            image_id: reply.image_id,
+            first_life_image_id: reply.first_life_image_id,
+            about_text: reply.about_text.clone(),
+            first_life_about_text: reply.first_life_about_text.clone(),
+            allow_publish: reply.flags & AvatarPropertiesReply::ALLOW_PUBLISH != 0,
+            mature_publish: reply.flags & AvatarPropertiesReply::MATURE_PUBLISH != 0,
+            profile_url: reply.profile_url.clone(),
+        }
+    }
+}
+
+impl PacketData for AvatarPropertiesUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AvatarPropertiesUpdate {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            image_id: read_uuid(&mut cursor)?,
+            first_life_image_id: read_uuid(&mut cursor)?,
+            about_text: read_string_2(&mut cursor)?,
+            first_life_about_text: read_string_1(&mut cursor)?,
+            allow_publish: cursor.read_u8()? != 0,
+            mature_publish: cursor.read_u8()? != 0,
+            profile_url: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            70 + self.about_text.len() + self.first_life_about_text.len() + self.profile_url.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.image_id.as_bytes());
+        bytes.extend_from_slice(self.first_life_image_id.as_bytes());
+        write_string_2(&mut bytes, &self.about_text);
+        write_string_1(&mut bytes, &self.first_life_about_text);
+        bytes.push(self.allow_publish as u8);
+        bytes.push(self.mature_publish as u8);
+        write_string_1(&mut bytes, &self.profile_url);
+        bytes
+    }
+}
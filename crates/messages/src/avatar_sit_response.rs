@@ -0,0 +1,82 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_quat, read_uuid, read_vec3, write_quat, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use glam::{Quat, Vec3};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 21
+// Frequency: High
+
+impl Packet {
+    pub fn new_avatar_sit_response(avatar_sit_response: AvatarSitResponse) -> Self {
+        Packet {
+            header: Header {
+                id: 21,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::AvatarSitResponse(Box::new(avatar_sit_response)),
+        }
+    }
+}
+
+/// The simulator's answer to an AgentRequestSit, saying where the avatar will sit and where to
+/// put the camera. The object can be a different one than was asked for, like when a linkset
+/// has a sit target on another prim.
+/// https://wiki.secondlife.com/wiki/AvatarSitResponse
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarSitResponse {
+    /// the object the avatar sits on
+    pub object_id: Uuid,
+    /// true if the avatar has to walk to the object before it can sit
+    pub autopilot: bool,
+    /// where the avatar sits, relative to the object
+    pub sit_position: Vec3,
+    pub sit_rotation: Quat,
+    /// where to put the camera, relative to the object. Zero means the camera isn't moved.
+    pub camera_eye_offset: Vec3,
+    /// where to point the camera, relative to the object
+    pub camera_at_offset: Vec3,
+    /// true if the viewer should go into mouselook while sitting
+    pub force_mouselook: bool,
+}
+
+impl PacketData for AvatarSitResponse {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(AvatarSitResponse {
+            object_id: read_uuid(&mut cursor)?,
+            autopilot: cursor.read_u8()? != 0,
+            sit_position: read_vec3(&mut cursor)?,
+            sit_rotation: read_quat(&mut cursor)?,
+            camera_eye_offset: read_vec3(&mut cursor)?,
+            camera_at_offset: read_vec3(&mut cursor)?,
+            force_mouselook: cursor.read_u8()? != 0,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(66);
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.push(self.autopilot as u8);
+        write_vec3(&mut bytes, &self.sit_position);
+        write_quat(&mut bytes, &self.sit_rotation);
+        write_vec3(&mut bytes, &self.camera_eye_offset);
+        write_vec3(&mut bytes, &self.camera_at_offset);
+        bytes.push(self.force_mouselook as u8);
+        bytes
+    }
+}
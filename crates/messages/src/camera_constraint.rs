@@ -0,0 +1,64 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec4;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 22
+// Frequency: High
+
+impl Packet {
+    pub fn new_camera_constraint(camera_constraint: CameraConstraint) -> Self {
+        Packet {
+            header: Header {
+                id: 22,
+                frequency: PacketFrequency::High,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::CameraConstraint(Box::new(camera_constraint)),
+        }
+    }
+}
+
+/// A plane the camera has to stay in front of, sent when the camera would otherwise clip into
+/// an object or the terrain behind the avatar.
+/// https://wiki.secondlife.com/wiki/CameraConstraint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CameraConstraint {
+    /// the plane as its normal and distance, in region coordinates. The camera is kept on the
+    /// side the normal points to.
+    pub plane: Vec4,
+}
+
+impl PacketData for CameraConstraint {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(CameraConstraint {
+            plane: Vec4::new(
+                cursor.read_f32::<LittleEndian>()?,
+                cursor.read_f32::<LittleEndian>()?,
+                cursor.read_f32::<LittleEndian>()?,
+                cursor.read_f32::<LittleEndian>()?,
+            ),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(16);
+        for value in self.plane.to_array() {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,67 @@
+use crate::grant_user_rights::UserRights;
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 321
+// Frequency: Low
+
+impl Packet {
+    pub fn new_change_user_rights(change_user_rights: ChangeUserRights) -> Self {
+        Packet {
+            header: Header {
+                id: 321,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ChangeUserRights(Box::new(change_user_rights)),
+        }
+    }
+}
+
+/// Sent by the simulator when rights between friends change. If the agent id is the agent's own,
+/// the rights are the ones it gave the related friends. Otherwise they are the rights the friend
+/// with the agent id gave the agent.
+/// https://wiki.secondlife.com/wiki/ChangeUserRights
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeUserRights {
+    pub agent_id: Uuid,
+    pub rights: Vec<UserRights>,
+}
+
+impl PacketData for ChangeUserRights {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut rights = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            rights.push(UserRights::from_bytes(&mut cursor)?);
+        }
+        Ok(ChangeUserRights { agent_id, rights })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let rights = &self.rights[..self.rights.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(17 + rights.len() * 20);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.push(rights.len() as u8);
+        for user_rights in rights {
+            user_rights.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
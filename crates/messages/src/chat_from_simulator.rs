@@ -1,4 +1,5 @@
 use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
 
 use super::{
     header::Header,
@@ -6,6 +7,7 @@ use super::{
 };
 use byteorder::ReadBytesExt;
 use glam::Vec3;
+use serde::{Deserialize, Serialize};
 use std::io::Read;
 use std::io::{self, BufRead, Cursor};
 use uuid::Uuid;
@@ -44,7 +46,7 @@ pub struct ChatFromSimulator {
     pub message: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SourceType {
     System,
     Agent,
@@ -52,7 +54,7 @@ pub enum SourceType {
     Unknown,
 }
 impl SourceType {
-    fn from_bytes(bytes: u8) -> Self {
+    pub fn from_bytes(bytes: u8) -> Self {
         match bytes {
             0 => SourceType::System,
             1 => SourceType::Agent,
@@ -60,7 +62,7 @@ impl SourceType {
             _ => SourceType::Unknown,
         }
     }
-    fn to_bytes(&self) -> u8 {
+    pub fn to_bytes(&self) -> u8 {
         match self {
             SourceType::System => 0,
             SourceType::Agent => 1,
@@ -96,7 +98,7 @@ impl Audible {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ChatType {
     Whisper,
     Normal,
@@ -153,18 +155,10 @@ impl PacketData for ChatFromSimulator {
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
         // SourceID
-        let source_id = Uuid::from_slice(
-            &cursor.get_ref()[cursor.position() as usize..cursor.position() as usize + 16],
-        )
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        cursor.set_position(cursor.position() + 16);
+        let source_id = read_uuid(&mut cursor)?;
 
         // OwnerID
-        let owner_id = Uuid::from_slice(
-            &cursor.get_ref()[cursor.position() as usize..cursor.position() as usize + 16],
-        )
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        cursor.set_position(cursor.position() + 16);
+        let owner_id = read_uuid(&mut cursor)?;
 
         // SourceType
         let source_type_byte = cursor.read_u8()?;
@@ -4,7 +4,7 @@ use super::{
     header::{Header, PacketFrequency},
     packet::{Packet, PacketData},
 };
-use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt};
 use std::io::Read;
 use std::io::{self, Cursor};
 use uuid::Uuid;
@@ -37,7 +37,7 @@ pub struct ChatFromViewer {
     pub channel: i32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ClientChatType {
     Whisper,
     Normal,
@@ -89,17 +89,22 @@ impl PacketData for ChatFromViewer {
         cursor.read_exact(&mut session_id_bytes)?;
         let session_id = Uuid::from_bytes(session_id_bytes);
 
+        // Message is a Variable 2 field. The length prefix includes the null terminator.
         let message_length = cursor.read_u16::<LittleEndian>()? as usize;
 
         let mut message_bytes = vec![0u8; message_length];
         cursor.read_exact(&mut message_bytes)?;
+        if message_bytes.last() == Some(&0) {
+            message_bytes.pop();
+        }
 
-        let message = String::from_utf8(message_bytes).unwrap();
+        let message = String::from_utf8(message_bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
         let message_type_byte = cursor.read_u8()?;
         let message_type = ClientChatType::from_bytes(message_type_byte);
 
-        let channel = cursor.read_i32::<BigEndian>()?;
+        let channel = cursor.read_i32::<LittleEndian>()?;
 
         Ok(ChatFromViewer {
             agent_id,
@@ -116,16 +121,15 @@ impl PacketData for ChatFromViewer {
         bytes.extend_from_slice(self.agent_id.as_bytes());
         bytes.extend_from_slice(self.session_id.as_bytes());
 
+        // the length prefix counts the null terminator
         let message_bytes = self.message.as_bytes();
-
-        bytes.extend_from_slice(&(message_bytes.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&((message_bytes.len() + 1) as u16).to_le_bytes());
         bytes.extend_from_slice(message_bytes);
+        bytes.push(0);
 
         bytes.push(self.message_type.to_bytes());
         bytes.extend_from_slice(&self.channel.to_le_bytes());
 
-        bytes.push(0);
-
         bytes
     }
 }
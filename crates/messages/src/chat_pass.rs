@@ -0,0 +1,95 @@
+use crate::chat_from_simulator::{ChatType, SourceType};
+use crate::packet_types::PacketType;
+use crate::utils::agent_access::AgentAccess;
+use crate::utils::packet_fields::{
+    read_string_1, read_string_2, read_uuid, read_vec3, write_string_1, write_string_2, write_vec3,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 239
+// Frequency: Low
+
+/// the channel scripts send debug messages and errors on
+pub const DEBUG_CHANNEL: i32 = i32::MAX;
+
+impl Packet {
+    pub fn new_chat_pass(chat_pass: ChatPass) -> Self {
+        Packet {
+            header: Header {
+                id: 239,
+                frequency: PacketFrequency::Low,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ChatPass(Box::new(chat_pass)),
+        }
+    }
+}
+
+/// Chat relayed across an estate, with the same fields as a ChatFromSimulator plus the channel
+/// it was said on.
+/// https://wiki.secondlife.com/wiki/ChatPass
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatPass {
+    pub channel: i32,
+    /// where the chat was said, in region coordinates
+    pub position: Vec3,
+    /// the avatar or object that said it
+    pub source_id: Uuid,
+    pub owner_id: Uuid,
+    pub from_name: String,
+    pub source_type: SourceType,
+    pub chat_type: ChatType,
+    /// how far away the chat can be heard
+    pub radius: f32,
+    /// the maturity of the region it was said in
+    pub sim_access: AgentAccess,
+    pub message: String,
+}
+
+impl PacketData for ChatPass {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ChatPass {
+            channel: cursor.read_i32::<LittleEndian>()?,
+            position: read_vec3(&mut cursor)?,
+            source_id: read_uuid(&mut cursor)?,
+            owner_id: read_uuid(&mut cursor)?,
+            from_name: read_string_1(&mut cursor)?,
+            source_type: SourceType::from_bytes(cursor.read_u8()?),
+            chat_type: ChatType::from_bytes(cursor.read_u8()?),
+            radius: cursor.read_f32::<LittleEndian>()?,
+            sim_access: AgentAccess::from_bytes(&cursor.read_u8()?),
+            message: read_string_2(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(56 + self.from_name.len() + self.message.len());
+        bytes.extend_from_slice(&self.channel.to_le_bytes());
+        write_vec3(&mut bytes, &self.position);
+        bytes.extend_from_slice(self.source_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        write_string_1(&mut bytes, &self.from_name);
+        bytes.push(self.source_type.to_bytes());
+        bytes.push(self.chat_type.to_bytes());
+        bytes.extend_from_slice(&self.radius.to_le_bytes());
+        bytes.push(self.sim_access.to_bytes());
+        write_string_2(&mut bytes, &self.message);
+        bytes
+    }
+}
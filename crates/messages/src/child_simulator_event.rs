@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{packet_types::PacketType, ui_events::UiEventTypes};
+
+/// An event that came from a child agent circuit instead of the current simulator.
+/// The original event is wrapped with the handle of the region it came from, so the UI can
+/// tell neighbouring regions apart.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChildSimulatorEvent {
+    /// handle of the region the event came from
+    pub region_handle: u64,
+    /// the type of the wrapped event
+    pub event: UiEventTypes,
+    /// the wrapped event, encoded the same way it would be for the current simulator
+    pub data: Vec<u8>,
+}
+
+impl ChildSimulatorEvent {
+    /// decode the wrapped event
+    pub fn packet(&self) -> Option<PacketType> {
+        self.event.packet_type_from_bytes(&self.data)
+    }
+}
@@ -1,7 +1,9 @@
 use crate::header::{Header, PacketFrequency};
 use crate::packet::{Packet, PacketData};
 use crate::packet_types::PacketType;
-use std::io;
+use crate::utils::packet_fields::read_uuid;
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
 use uuid::Uuid;
 
 impl Packet {
@@ -32,9 +34,10 @@ pub struct CircuitCodeData {
 
 impl PacketData for CircuitCodeData {
     fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
-        let code = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
-        let session_id = Uuid::from_slice(&bytes[4..20]).unwrap();
-        let id = Uuid::from_slice(&bytes[20..36]).unwrap();
+        let mut cursor = Cursor::new(bytes);
+        let code = cursor.read_u32::<LittleEndian>()?;
+        let session_id = read_uuid(&mut cursor)?;
+        let id = read_uuid(&mut cursor)?;
 
         Ok(Self {
             code,
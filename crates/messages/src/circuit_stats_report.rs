@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Sent from the session to the UI with the statistics of the circuits to simulators, on an
+/// interval and whenever the UI sends a QueryCircuitStats. Meant for debugging connection
+/// problems.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CircuitStatsReport {
+    /// the round trip time of the latest ping to the current simulator, in milliseconds
+    pub ping_ms: f64,
+    /// the statistics of each circuit, ordered by the simulator's address
+    pub circuits: Vec<CircuitReport>,
+}
+
+/// the statistics of one circuit
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CircuitReport {
+    /// the address of the simulator, like "127.0.0.1:13000"
+    pub address: String,
+    /// the datagrams sent to the simulator, resends included
+    pub packets_sent: u64,
+    /// the packets that arrived from the simulator and were handled
+    pub packets_received: u64,
+    /// the size of every datagram sent to the simulator
+    pub bytes_sent: u64,
+    /// the size of every datagram that arrived from the simulator, handled or not
+    pub bytes_received: u64,
+    /// the reliable packets that were sent again because their acks were late
+    pub resends: u64,
+    /// the packets that arrived more than once, and were dropped after the first
+    pub duplicates: u64,
+    /// the acks sent to the simulator, in PacketAcks or appended to other packets
+    pub acks_sent: u64,
+    /// the acks that arrived from the simulator
+    pub acks_received: u64,
+    /// the datagrams that were dropped because they were cut off or didn't parse
+    pub parse_failures: u64,
+    /// how many packets of each type were handled, the most common first
+    pub received_by_type: Vec<PacketTypeCount>,
+}
+
+/// how many packets with one message number arrived
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PacketTypeCount {
+    /// the frequency of the message number, like "Low"
+    pub frequency: String,
+    /// the message number, without its frequency marker
+    pub id: u16,
+    /// how many of them arrived
+    pub count: u64,
+}
@@ -0,0 +1,119 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_dvec3, read_string_1, read_string_2, read_uuid, write_dvec3, write_string_1,
+    write_string_2,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::DVec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 44
+// Frequency: Low
+
+impl Packet {
+    pub fn new_classified_info_reply(classified_info_reply: ClassifiedInfoReply) -> Self {
+        Packet {
+            header: Header {
+                id: 44,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ClassifiedInfoReply(Box::new(classified_info_reply)),
+        }
+    }
+}
+
+/// The details of a classified, sent in answer to a ClassifiedInfoRequest.
+/// https://wiki.secondlife.com/wiki/ClassifiedInfoReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassifiedInfoReply {
+    pub agent_id: Uuid,
+    pub classified_id: Uuid,
+    /// the avatar who placed the classified
+    pub creator_id: Uuid,
+    /// when the classified was placed, in seconds since the unix epoch
+    pub creation_date: u32,
+    /// when the classified stops being listed, in seconds since the unix epoch
+    pub expiration_date: u32,
+    /// the category the classified is listed in, like shopping or rentals
+    pub category: u32,
+    pub name: String,
+    pub description: String,
+    /// the parcel the classified is for
+    pub parcel_id: Uuid,
+    /// the estate the parcel is in
+    pub parent_estate: u32,
+    /// the texture of the classified's picture
+    pub snapshot_id: Uuid,
+    pub sim_name: String,
+    /// where the classified is, in global coordinates
+    pub pos_global: DVec3,
+    pub parcel_name: String,
+    /// the maturity of the classified, and whether it renews itself
+    pub classified_flags: u8,
+    /// how much was paid for the listing, which orders the search results
+    pub price_for_listing: i32,
+}
+
+impl PacketData for ClassifiedInfoReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ClassifiedInfoReply {
+            agent_id: read_uuid(&mut cursor)?,
+            classified_id: read_uuid(&mut cursor)?,
+            creator_id: read_uuid(&mut cursor)?,
+            creation_date: cursor.read_u32::<LittleEndian>()?,
+            expiration_date: cursor.read_u32::<LittleEndian>()?,
+            category: cursor.read_u32::<LittleEndian>()?,
+            name: read_string_1(&mut cursor)?,
+            description: read_string_2(&mut cursor)?,
+            parcel_id: read_uuid(&mut cursor)?,
+            parent_estate: cursor.read_u32::<LittleEndian>()?,
+            snapshot_id: read_uuid(&mut cursor)?,
+            sim_name: read_string_1(&mut cursor)?,
+            pos_global: read_dvec3(&mut cursor)?,
+            parcel_name: read_string_1(&mut cursor)?,
+            classified_flags: cursor.read_u8()?,
+            price_for_listing: cursor.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            134 + self.name.len()
+                + self.description.len()
+                + self.sim_name.len()
+                + self.parcel_name.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.classified_id.as_bytes());
+        bytes.extend_from_slice(self.creator_id.as_bytes());
+        bytes.extend_from_slice(&self.creation_date.to_le_bytes());
+        bytes.extend_from_slice(&self.expiration_date.to_le_bytes());
+        bytes.extend_from_slice(&self.category.to_le_bytes());
+        write_string_1(&mut bytes, &self.name);
+        write_string_2(&mut bytes, &self.description);
+        bytes.extend_from_slice(self.parcel_id.as_bytes());
+        bytes.extend_from_slice(&self.parent_estate.to_le_bytes());
+        bytes.extend_from_slice(self.snapshot_id.as_bytes());
+        write_string_1(&mut bytes, &self.sim_name);
+        write_dvec3(&mut bytes, &self.pos_global);
+        write_string_1(&mut bytes, &self.parcel_name);
+        bytes.push(self.classified_flags);
+        bytes.extend_from_slice(&self.price_for_listing.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,71 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 43
+// Frequency: Low
+
+impl Packet {
+    pub fn new_classified_info_request(classified_info_request: ClassifiedInfoRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 43,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ClassifiedInfoRequest(Box::new(classified_info_request)),
+        }
+    }
+}
+
+/// Asks for the details of a classified, like one listed in an AvatarClassifiedReply. The
+/// simulator answers with a ClassifiedInfoReply.
+/// https://wiki.secondlife.com/wiki/ClassifiedInfoRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassifiedInfoRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub classified_id: Uuid,
+}
+
+impl ClassifiedInfoRequest {
+    /// ask for a classified. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(classified_id: Uuid) -> Self {
+        ClassifiedInfoRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            classified_id,
+        }
+    }
+}
+
+impl PacketData for ClassifiedInfoRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ClassifiedInfoRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            classified_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.classified_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,56 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 160
+// Frequency: Low
+
+impl Packet {
+    pub fn new_clear_follow_cam_properties(
+        clear_follow_cam_properties: ClearFollowCamProperties,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 160,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ClearFollowCamProperties(Box::new(clear_follow_cam_properties)),
+        }
+    }
+}
+
+/// Tells the viewer to stop following the camera properties a script set with
+/// SetFollowCamProperties, and go back to the default camera.
+/// https://wiki.secondlife.com/wiki/ClearFollowCamProperties
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClearFollowCamProperties {
+    /// the object whose script set the properties
+    pub object_id: Uuid,
+}
+
+impl PacketData for ClearFollowCamProperties {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ClearFollowCamProperties {
+            object_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.object_id.as_bytes().to_vec()
+    }
+}
@@ -1,6 +1,8 @@
 use super::packet::PacketData;
+use crate::utils::packet_fields::read_uuid;
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use std::io::{self, Cursor, Write};
+use std::io::{self, Cursor};
+use uuid::Uuid;
 
 /// ID: 6
 /// Frequency: Medium
@@ -12,20 +14,17 @@ pub struct MinimapEntities {
     z: u8,
 }
 impl MinimapEntities {
-    pub fn from_bytes(bytes: &[u8], i: &mut usize) -> io::Result<Self> {
-        let cursor = Cursor::new(&bytes[*i..]);
-        let x = cursor.get_ref()[0];
-        let y = cursor.get_ref()[1];
-        let z = cursor.get_ref()[2];
-        *i += 3; // Move index forward
+    /// read a location, failing if the packet ends before it does
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        let x = cursor.read_u8()?;
+        let y = cursor.read_u8()?;
+        let z = cursor.read_u8()?;
         Ok(Self { x, y, z })
     }
 
-    pub fn to_bytes(&self, bytes: &mut [u8], i: &mut usize) -> io::Result<()> {
-        let mut cursor = Cursor::new(&mut bytes[*i..]);
-        cursor.write_all(&[self.x, self.y, self.z])?;
-        *i += 3; // Move index forward
-        Ok(())
+    /// write the location
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&[self.x, self.y, self.z]);
     }
 }
 #[derive(Debug, Clone)]
@@ -33,6 +32,8 @@ pub struct CoarseLocationUpdate {
     locations: Vec<MinimapEntities>,
     you: i16,
     prey: i16,
+    /// the avatars the locations are for, in the same order. Older simulators leave this off.
+    pub agent_ids: Vec<Uuid>,
 }
 
 impl PacketData for CoarseLocationUpdate {
@@ -42,20 +43,27 @@ impl PacketData for CoarseLocationUpdate {
         let mut locations = Vec::with_capacity(location_count);
 
         for _ in 0..location_count {
-            let x = cursor.read_u8()?;
-            let y = cursor.read_u8()?;
-            let z = cursor.read_u8()?;
-            locations.push(MinimapEntities { x, y, z });
+            locations.push(MinimapEntities::from_bytes(&mut cursor)?);
         }
 
         // Deserialize IndexBlock
         let you = cursor.read_i16::<LittleEndian>()?;
         let prey = cursor.read_i16::<LittleEndian>()?;
 
+        // Deserialize AgentData
+        let mut agent_ids = Vec::new();
+        if (cursor.position() as usize) < bytes.len() {
+            let agent_count = cursor.read_u8()?;
+            for _ in 0..agent_count {
+                agent_ids.push(read_uuid(&mut cursor)?);
+            }
+        }
+
         Ok(CoarseLocationUpdate {
             locations,
             you,
             prey,
+            agent_ids,
         })
     }
 
@@ -65,15 +73,19 @@ impl PacketData for CoarseLocationUpdate {
         // Serialize LocationBlocks
         bytes.push(self.locations.len() as u8);
         for location in &self.locations {
-            bytes.push(location.x);
-            bytes.push(location.y);
-            bytes.push(location.z);
+            location.to_bytes(&mut bytes);
         }
 
         // Serialize IndexBlock
         bytes.write_i16::<LittleEndian>(self.you).unwrap();
         bytes.write_i16::<LittleEndian>(self.prey).unwrap();
 
+        // Serialize AgentData
+        bytes.push(self.agent_ids.len() as u8);
+        for agent_id in &self.agent_ids {
+            bytes.extend_from_slice(agent_id.as_bytes());
+        }
+
         bytes
     }
 }
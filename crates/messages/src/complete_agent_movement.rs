@@ -1,6 +1,9 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::Cursor;
 use uuid::Uuid;
 
 use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
 
 use super::{
     header::{Header, PacketFrequency},
@@ -37,9 +40,10 @@ pub struct CompleteAgentMovementData {
 
 impl PacketData for CompleteAgentMovementData {
     fn from_bytes(bytes: &[u8]) -> std::io::Result<Self> {
-        let circuit_code = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
-        let session_id = Uuid::from_slice(&bytes[4..20]).unwrap();
-        let agent_id = Uuid::from_slice(&bytes[20..36]).unwrap();
+        let mut cursor = Cursor::new(bytes);
+        let circuit_code = cursor.read_u32::<LittleEndian>()?;
+        let session_id = read_uuid(&mut cursor)?;
+        let agent_id = read_uuid(&mut cursor)?;
 
         Ok(CompleteAgentMovementData {
             agent_id,
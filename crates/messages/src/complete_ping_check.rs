@@ -3,6 +3,11 @@ use super::{
     packet::{Packet, PacketData},
 };
 use crate::packet_types::PacketType;
+use byteorder::ReadBytesExt;
+use std::io::Cursor;
+
+// ID: 2
+// Frequency: High
 
 impl Packet {
     pub fn new_complete_ping_check(complete_ping_check: CompletePingCheck) -> Self {
@@ -30,7 +35,7 @@ pub struct CompletePingCheck {
 
 impl PacketData for CompletePingCheck {
     fn from_bytes(bytes: &[u8]) -> std::io::Result<Self> {
-        let ping_id = bytes[0];
+        let ping_id = Cursor::new(bytes).read_u8()?;
 
         Ok(CompletePingCheck { ping_id })
     }
@@ -0,0 +1,57 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+
+// ID: 19
+// Frequency: High
+
+impl Packet {
+    pub fn new_confirm_xfer_packet(confirm_xfer_packet: ConfirmXferPacket) -> Self {
+        Packet {
+            header: Header {
+                id: 19,
+                frequency: PacketFrequency::High,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ConfirmXferPacket(Box::new(confirm_xfer_packet)),
+        }
+    }
+}
+
+/// Confirms that a SendXferPacket arrived, so the sender can send the next one.
+/// https://wiki.secondlife.com/wiki/ConfirmXferPacket
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfirmXferPacket {
+    pub id: u64,
+    /// the packet number being confirmed. Receivers clear the last packet flag before
+    /// confirming, but some don't, so it should be masked off.
+    pub packet: u32,
+}
+
+impl PacketData for ConfirmXferPacket {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ConfirmXferPacket {
+            id: cursor.read_u64::<LittleEndian>()?,
+            packet: cursor.read_u32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12);
+        bytes.extend_from_slice(&self.id.to_le_bytes());
+        bytes.extend_from_slice(&self.packet.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,78 @@
+use crate::inventory_descendents::InventoryFolder;
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 273
+// Frequency: Low
+
+impl Packet {
+    pub fn new_create_inventory_folder(create_inventory_folder: CreateInventoryFolder) -> Self {
+        Packet {
+            header: Header {
+                id: 273,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::CreateInventoryFolder(Box::new(create_inventory_folder)),
+        }
+    }
+}
+
+/// Makes a new inventory folder. The viewer picks the id of the folder, so it can use the
+/// folder before the simulator has made it.
+/// https://wiki.secondlife.com/wiki/CreateInventoryFolder
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateInventoryFolder {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub folder: InventoryFolder,
+}
+
+impl CreateInventoryFolder {
+    /// make a folder the agent can put anything in. The folder id is left nil, for the session
+    /// to pick, and so are the agent and session ids.
+    pub fn new(parent_id: Uuid, name: String) -> Self {
+        CreateInventoryFolder {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            folder: InventoryFolder {
+                folder_id: Uuid::nil(),
+                parent_id,
+                folder_type: -1,
+                name,
+            },
+        }
+    }
+}
+
+impl PacketData for CreateInventoryFolder {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(CreateInventoryFolder {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            folder: InventoryFolder::from_bytes(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(67 + self.folder.name.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        self.folder.to_bytes(&mut bytes);
+        bytes
+    }
+}
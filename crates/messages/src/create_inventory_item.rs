@@ -0,0 +1,124 @@
+use crate::packet_types::PacketType;
+use crate::utils::asset_type::AssetType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+use crate::utils::permissions::Permissions;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 269
+// Frequency: Low
+
+impl Packet {
+    pub fn new_create_inventory_item(create_inventory_item: CreateInventoryItem) -> Self {
+        Packet {
+            header: Header {
+                id: 269,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::CreateInventoryItem(Box::new(create_inventory_item)),
+        }
+    }
+}
+
+/// Makes a new inventory item, like a notecard, script or landmark. The simulator picks the id
+/// of the item, and sends it back in an UpdateCreateInventoryItem with the same callback id.
+/// https://wiki.secondlife.com/wiki/CreateInventoryItem
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateInventoryItem {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// sent back in the UpdateCreateInventoryItem with the new item
+    pub callback_id: u32,
+    /// the folder to put the item in
+    pub folder_id: Uuid,
+    /// the upload that holds the item's asset, nil for the simulator to make a default one
+    pub transaction_id: Uuid,
+    pub next_owner_mask: Permissions,
+    pub asset_type: AssetType,
+    /// the viewer's LLInventoryType
+    pub inventory_type: i8,
+    /// the kind of clothing or body part, for wearables
+    pub wearable_type: u8,
+    pub name: String,
+    pub description: String,
+}
+
+impl CreateInventoryItem {
+    /// make an item with a default asset, that the next owner can move and transfer. The
+    /// callback id is left 0, for the session to pick, and the agent and session ids are left
+    /// nil.
+    pub fn new(
+        folder_id: Uuid,
+        asset_type: AssetType,
+        inventory_type: i8,
+        wearable_type: u8,
+        name: String,
+        description: String,
+    ) -> Self {
+        CreateInventoryItem {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            callback_id: 0,
+            folder_id,
+            transaction_id: Uuid::nil(),
+            next_owner_mask: Permissions {
+                transfer: true,
+                r#move: true,
+                ..Default::default()
+            },
+            asset_type,
+            inventory_type,
+            wearable_type,
+            name,
+            description,
+        }
+    }
+}
+
+impl PacketData for CreateInventoryItem {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(CreateInventoryItem {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            callback_id: cursor.read_u32::<LittleEndian>()?,
+            folder_id: read_uuid(&mut cursor)?,
+            transaction_id: read_uuid(&mut cursor)?,
+            next_owner_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            asset_type: AssetType::from_bytes(cursor.read_i8()?),
+            inventory_type: cursor.read_i8()?,
+            wearable_type: cursor.read_u8()?,
+            name: read_string_1(&mut cursor)?,
+            description: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(91 + self.name.len() + self.description.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.callback_id.to_le_bytes());
+        bytes.extend_from_slice(self.folder_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.extend_from_slice(&self.next_owner_mask.to_bits().to_le_bytes());
+        bytes.push(self.asset_type.to_bytes() as u8);
+        bytes.push(self.inventory_type as u8);
+        bytes.push(self.wearable_type);
+        write_string_1(&mut bytes, &self.name);
+        write_string_1(&mut bytes, &self.description);
+        bytes
+    }
+}
@@ -0,0 +1,98 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_string_2, read_uuid, read_vec3, write_string_2, write_vec3,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor};
+use std::net::Ipv4Addr;
+use uuid::Uuid;
+
+// ID: 7
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_crossed_region(crossed_region: CrossedRegion) -> Self {
+        Packet {
+            header: Header {
+                id: 7,
+                frequency: PacketFrequency::Medium,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::CrossedRegion(Box::new(crossed_region)),
+        }
+    }
+}
+
+/// Sent by the simulator when the agent walks or flies over a region border. The client
+/// makes the neighbouring region's circuit the current one.
+/// https://wiki.secondlife.com/wiki/CrossedRegion
+#[derive(Debug, Clone)]
+pub struct CrossedRegion {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// address of the simulator the agent crossed into
+    pub sim_ip: Ipv4Addr,
+    pub sim_port: u16,
+    pub region_handle: u64,
+    /// capabilities url of the new region
+    pub seed_capability: String,
+    /// position in the new region's coordinates
+    pub position: Vec3,
+    pub look_at: Vec3,
+}
+
+impl PacketData for CrossedRegion {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AgentData
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+
+        // RegionData. IP addresses and ports are sent in network byte order.
+        let sim_ip = Ipv4Addr::from(cursor.read_u32::<BigEndian>()?);
+        let sim_port = cursor.read_u16::<BigEndian>()?;
+        let region_handle = cursor.read_u64::<LittleEndian>()?;
+        let seed_capability = read_string_2(&mut cursor)?;
+
+        // Info
+        let position = read_vec3(&mut cursor)?;
+        let look_at = read_vec3(&mut cursor)?;
+
+        Ok(CrossedRegion {
+            agent_id,
+            session_id,
+            sim_ip,
+            sim_port,
+            region_handle,
+            seed_capability,
+            position,
+            look_at,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(73 + self.seed_capability.len() + 1);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.sim_ip.octets());
+        bytes.extend_from_slice(&self.sim_port.to_be_bytes());
+        bytes.extend_from_slice(&self.region_handle.to_le_bytes());
+        write_string_2(&mut bytes, &self.seed_capability);
+        write_vec3(&mut bytes, &self.position);
+        write_vec3(&mut bytes, &self.look_at);
+        bytes
+    }
+}
@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 298
+// Frequency: Low
+
+impl Packet {
+    pub fn new_decline_friendship(decline_friendship: DeclineFriendship) -> Self {
+        Packet {
+            header: Header {
+                id: 298,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::DeclineFriendship(Box::new(decline_friendship)),
+        }
+    }
+}
+
+/// Declines a friendship offered with an ImprovedInstantMessage. The transaction id is the id
+/// of the offer's message. The simulator tells the agent that offered with a FriendshipDeclined
+/// message.
+/// https://wiki.secondlife.com/wiki/DeclineFriendship
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclineFriendship {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub transaction_id: Uuid,
+}
+
+impl DeclineFriendship {
+    /// decline the offer with the message id. The agent and session ids are left nil, for the
+    /// session to fill in.
+    pub fn new(transaction_id: Uuid) -> Self {
+        DeclineFriendship {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            transaction_id,
+        }
+    }
+}
+
+impl PacketData for DeclineFriendship {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(DeclineFriendship {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            transaction_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes
+    }
+}
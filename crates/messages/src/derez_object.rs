@@ -0,0 +1,131 @@
+use crate::packet_types::PacketType;
+use crate::utils::derez_destination::DeRezDestination;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 291
+// Frequency: Low
+
+impl Packet {
+    pub fn new_derez_object(derez_object: DeRezObject) -> Self {
+        Packet {
+            header: Header {
+                id: 291,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::DeRezObject(Box::new(derez_object)),
+        }
+    }
+}
+
+/// Removes objects from the world, taking them into inventory, deleting them to the trash or
+/// returning them to their owners.
+/// More objects than fit in one packet are sent as several packets with the same transaction id,
+/// numbered with packet_number out of packet_count.
+/// https://wiki.secondlife.com/wiki/DeRezObject
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeRezObject {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group the agent is acting as, nil for none
+    pub group_id: Uuid,
+    pub destination: DeRezDestination,
+    /// the inventory folder, or the object for SaveIntoTaskInventory. Nil for the default folder.
+    pub destination_id: Uuid,
+    /// the same for every packet of the derez
+    pub transaction_id: Uuid,
+    pub packet_count: u8,
+    pub packet_number: u8,
+    /// the local ids of the objects in the region
+    pub local_ids: Vec<u32>,
+}
+
+impl DeRezObject {
+    /// the most ObjectData blocks one packet can hold
+    pub const MAX_OBJECTS: usize = MAX_OBJECT_BLOCKS;
+
+    /// split a derez of any number of objects into packets, filling in the local ids, the
+    /// packet count and the packet numbers. The local ids of self are ignored.
+    /// A derez of more than 255 packets worth of objects is truncated, since the packet count
+    /// is a single byte.
+    pub fn split(self, local_ids: &[u32]) -> Vec<DeRezObject> {
+        let chunks: Vec<&[u32]> = chunk_object_blocks(local_ids)
+            .into_iter()
+            .take(u8::MAX as usize)
+            .collect();
+        let packet_count = chunks.len() as u8;
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(packet_number, local_ids)| DeRezObject {
+                packet_count,
+                packet_number: packet_number as u8,
+                local_ids: local_ids.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for DeRezObject {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let group_id = read_uuid(&mut cursor)?;
+        let destination = DeRezDestination::from_bytes(cursor.read_u8()?);
+        let destination_id = read_uuid(&mut cursor)?;
+        let transaction_id = read_uuid(&mut cursor)?;
+        let packet_count = cursor.read_u8()?;
+        let packet_number = cursor.read_u8()?;
+        let count = cursor.read_u8()?;
+        let mut local_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            local_ids.push(cursor.read_u32::<LittleEndian>()?);
+        }
+        Ok(DeRezObject {
+            agent_id,
+            session_id,
+            group_id,
+            destination,
+            destination_id,
+            transaction_id,
+            packet_count,
+            packet_number,
+            local_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let local_ids = &self.local_ids[..self.local_ids.len().min(Self::MAX_OBJECTS)];
+        let mut bytes = Vec::with_capacity(84 + local_ids.len() * 4);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes.push(self.destination.to_bytes());
+        bytes.extend_from_slice(self.destination_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.push(self.packet_count);
+        bytes.push(self.packet_number);
+        bytes.push(local_ids.len() as u8);
+        for local_id in local_ids {
+            bytes.extend_from_slice(&local_id.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_detach_attachment(detach_attachment: DetachAttachment) -> Self {
+        Packet {
+            header: Header {
+                id: 69,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::DetachAttachment(Box::new(detach_attachment)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to take off an object the agent is wearing, named either by
+/// its local id in the region or by the inventory item it was attached from. The session finds
+/// the local id of an item among the attachments it has seen, and sends an ObjectDetach.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DetachAttachment {
+    /// the local id of the attached object
+    Object(u32),
+    /// the inventory item the object was attached from
+    Item(Uuid),
+}
@@ -0,0 +1,145 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use bitflags::bitflags;
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 31
+// Frequency: Low
+
+impl Packet {
+    pub fn new_dir_find_query(dir_find_query: DirFindQuery) -> Self {
+        Packet {
+            header: Header {
+                id: 31,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::DirFindQuery(Box::new(dir_find_query)),
+        }
+    }
+}
+
+bitflags! {
+    /// What a DirFindQuery searches for, and which results it includes.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct DirFindFlags: u32 {
+        /// search for residents, answered with DirPeopleReply
+        const PEOPLE_SEARCH = 0x00000001;
+        /// only residents that are online
+        const ONLINE = 0x00000002;
+        /// search for places, answered with DirPlacesReply
+        const PLACES_SEARCH = 0x00000004;
+        const EVENTS = 0x00000008;
+        const GROUPS = 0x00000010;
+        /// include places in general regions
+        const INCLUDE_PG = 0x01000000;
+        /// include places in moderate regions
+        const INCLUDE_MATURE = 0x02000000;
+        /// include places in adult regions
+        const INCLUDE_ADULT = 0x04000000;
+    }
+}
+
+/// what a directory search looks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectorySearchKind {
+    People,
+    Places,
+}
+
+impl DirectorySearchKind {
+    /// the flags to search for this kind of result with
+    pub fn flags(&self) -> DirFindFlags {
+        match self {
+            DirectorySearchKind::People => DirFindFlags::PEOPLE_SEARCH,
+            DirectorySearchKind::Places => DirFindFlags::PLACES_SEARCH | DirFindFlags::INCLUDE_PG,
+        }
+    }
+
+    /// the kind of result a query with these flags looks for
+    pub fn from_flags(flags: DirFindFlags) -> Self {
+        if flags.contains(DirFindFlags::PEOPLE_SEARCH) {
+            DirectorySearchKind::People
+        } else {
+            DirectorySearchKind::Places
+        }
+    }
+}
+
+/// Searches the directory for residents or places. Results come back a page at a time, starting
+/// at the query start. A page that has more than PAGE_SIZE results has more pages after it,
+/// which are asked for with the same query and the next start index.
+/// https://wiki.secondlife.com/wiki/DirFindQuery
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirFindQuery {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// sent back in the replies, to tell searches apart
+    pub query_id: Uuid,
+    /// the text to search for
+    pub query_text: String,
+    pub query_flags: DirFindFlags,
+    /// the index of the first result of the page
+    pub query_start: i32,
+}
+
+impl DirFindQuery {
+    /// the number of results on a page
+    pub const PAGE_SIZE: usize = 100;
+
+    /// search for a page of results, starting at the start index. The agent and session ids and
+    /// the query id are left nil, for the session to fill in.
+    pub fn search(kind: DirectorySearchKind, text: String, start_index: i32) -> Self {
+        DirFindQuery {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            query_id: Uuid::nil(),
+            query_text: text,
+            query_flags: kind.flags(),
+            query_start: start_index,
+        }
+    }
+
+    /// what the query searches for
+    pub fn kind(&self) -> DirectorySearchKind {
+        DirectorySearchKind::from_flags(self.query_flags)
+    }
+}
+
+impl PacketData for DirFindQuery {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(DirFindQuery {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            query_id: read_uuid(&mut cursor)?,
+            query_text: read_string_1(&mut cursor)?,
+            query_flags: DirFindFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?),
+            query_start: cursor.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(58 + self.query_text.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.query_id.as_bytes());
+        write_string_1(&mut bytes, &self.query_text);
+        bytes.extend_from_slice(&self.query_flags.bits().to_le_bytes());
+        bytes.extend_from_slice(&self.query_start.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,97 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 36
+// Frequency: Low
+
+impl Packet {
+    pub fn new_dir_people_reply(dir_people_reply: DirPeopleReply) -> Self {
+        Packet {
+            header: Header {
+                id: 36,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::DirPeopleReply(Box::new(dir_people_reply)),
+        }
+    }
+}
+
+/// Residents found by a DirFindQuery. The results of one page can be split over several
+/// replies.
+/// https://wiki.secondlife.com/wiki/DirPeopleReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirPeopleReply {
+    pub agent_id: Uuid,
+    /// the query id of the search
+    pub query_id: Uuid,
+    pub people: Vec<DirPerson>,
+}
+
+/// a resident found by a directory search
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DirPerson {
+    pub agent_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub group: String,
+    pub online: bool,
+    pub reputation: i32,
+}
+
+impl PacketData for DirPeopleReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let query_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut people = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            people.push(DirPerson {
+                agent_id: read_uuid(&mut cursor)?,
+                first_name: read_string_1(&mut cursor)?,
+                last_name: read_string_1(&mut cursor)?,
+                group: read_string_1(&mut cursor)?,
+                online: cursor.read_u8()? != 0,
+                reputation: cursor.read_i32::<LittleEndian>()?,
+            });
+        }
+        Ok(DirPeopleReply {
+            agent_id,
+            query_id,
+            people,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let people = &self.people[..self.people.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + people.len() * 40);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.query_id.as_bytes());
+        bytes.push(people.len() as u8);
+        for person in people {
+            bytes.extend_from_slice(person.agent_id.as_bytes());
+            write_string_1(&mut bytes, &person.first_name);
+            write_string_1(&mut bytes, &person.last_name);
+            write_string_1(&mut bytes, &person.group);
+            bytes.push(person.online as u8);
+            bytes.extend_from_slice(&person.reputation.to_le_bytes());
+        }
+        bytes
+    }
+}
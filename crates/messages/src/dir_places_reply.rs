@@ -0,0 +1,116 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 35
+// Frequency: Low
+
+impl Packet {
+    pub fn new_dir_places_reply(dir_places_reply: DirPlacesReply) -> Self {
+        Packet {
+            header: Header {
+                id: 35,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::DirPlacesReply(Box::new(dir_places_reply)),
+        }
+    }
+}
+
+/// Places found by a DirFindQuery. The results of one page can be split over several replies.
+/// The query id is sent in a block of its own, and each place has a status block after all of
+/// the places.
+/// https://wiki.secondlife.com/wiki/DirPlacesReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirPlacesReply {
+    pub agent_id: Uuid,
+    /// the query id of the search
+    pub query_id: Uuid,
+    pub places: Vec<DirPlace>,
+}
+
+/// a parcel found by a directory search
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DirPlace {
+    pub parcel_id: Uuid,
+    pub name: String,
+    pub for_sale: bool,
+    pub auction: bool,
+    /// how much traffic the parcel gets
+    pub dwell: f32,
+    pub status: u32,
+}
+
+impl PacketData for DirPlacesReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let query_count = cursor.read_u8()?;
+        let mut query_id = Uuid::nil();
+        for i in 0..query_count {
+            let id = read_uuid(&mut cursor)?;
+            if i == 0 {
+                query_id = id;
+            }
+        }
+        let count = cursor.read_u8()?;
+        let mut places = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            places.push(DirPlace {
+                parcel_id: read_uuid(&mut cursor)?,
+                name: read_string_1(&mut cursor)?,
+                for_sale: cursor.read_u8()? != 0,
+                auction: cursor.read_u8()? != 0,
+                dwell: cursor.read_f32::<LittleEndian>()?,
+                status: 0,
+            });
+        }
+        // older simulators leave the status blocks out
+        if let Ok(status_count) = cursor.read_u8() {
+            for place in places.iter_mut().take(status_count as usize) {
+                place.status = cursor.read_u32::<LittleEndian>()?;
+            }
+        }
+        Ok(DirPlacesReply {
+            agent_id,
+            query_id,
+            places,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let places = &self.places[..self.places.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(51 + places.len() * 36);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.push(1);
+        bytes.extend_from_slice(self.query_id.as_bytes());
+        bytes.push(places.len() as u8);
+        for place in places {
+            bytes.extend_from_slice(place.parcel_id.as_bytes());
+            write_string_1(&mut bytes, &place.name);
+            bytes.push(place.for_sale as u8);
+            bytes.push(place.auction as u8);
+            bytes.extend_from_slice(&place.dwell.to_le_bytes());
+        }
+        bytes.push(places.len() as u8);
+        for place in places {
+            bytes.extend_from_slice(&place.status.to_le_bytes());
+        }
+        bytes
+    }
+}
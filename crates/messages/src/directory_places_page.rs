@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dir_places_reply::DirPlace;
+
+/// One page of the places found by a DirFindQuery from the UI, gathered from all of its
+/// DirPlacesReply packets and sent from the session to the UI. If there are more pages, the
+/// next one is asked for by sending the query again with the next start index.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DirectoryPlacesPage {
+    /// the query id of the search
+    pub query_id: Uuid,
+    /// the text that was searched for
+    pub text: String,
+    /// the index of the first result on the page
+    pub start_index: i32,
+    /// the places on the page, empty if none were found
+    pub places: Vec<DirPlace>,
+    /// the start index of the next page, if there is one
+    pub next_start_index: Option<i32>,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Sent from the session to the UI when the connection to the simulator has ended.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Disconnect {
+    /// human readable reason for the disconnect
+    pub reason: String,
+}
@@ -0,0 +1,105 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 25
+// Frequency: Low
+
+impl Packet {
+    pub fn new_economy_data(economy_data: EconomyData) -> Self {
+        Packet {
+            header: Header {
+                id: 25,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::EconomyData(Box::new(economy_data)),
+        }
+    }
+}
+
+/// The prices of the grid, in its currency, sent in answer to an EconomyDataRequest. Newer
+/// simulators can send more fields after these, which are ignored.
+/// https://wiki.secondlife.com/wiki/EconomyData
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EconomyData {
+    pub object_capacity: i32,
+    pub object_count: i32,
+    pub price_energy_unit: i32,
+    pub price_object_claim: i32,
+    pub price_public_object_decay: i32,
+    pub price_public_object_delete: i32,
+    pub price_parcel_claim: i32,
+    pub price_parcel_claim_factor: f32,
+    /// the price of uploading an asset
+    pub price_upload: i32,
+    pub price_rent_light: i32,
+    /// the least a teleport costs
+    pub teleport_min_price: i32,
+    pub teleport_price_exponent: f32,
+    pub energy_efficiency: f32,
+    pub price_object_rent: f32,
+    pub price_object_scale_factor: f32,
+    pub price_parcel_rent: i32,
+    /// the price of creating a group
+    pub price_group_create: i32,
+}
+
+impl PacketData for EconomyData {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(EconomyData {
+            object_capacity: cursor.read_i32::<LittleEndian>()?,
+            object_count: cursor.read_i32::<LittleEndian>()?,
+            price_energy_unit: cursor.read_i32::<LittleEndian>()?,
+            price_object_claim: cursor.read_i32::<LittleEndian>()?,
+            price_public_object_decay: cursor.read_i32::<LittleEndian>()?,
+            price_public_object_delete: cursor.read_i32::<LittleEndian>()?,
+            price_parcel_claim: cursor.read_i32::<LittleEndian>()?,
+            price_parcel_claim_factor: cursor.read_f32::<LittleEndian>()?,
+            price_upload: cursor.read_i32::<LittleEndian>()?,
+            price_rent_light: cursor.read_i32::<LittleEndian>()?,
+            teleport_min_price: cursor.read_i32::<LittleEndian>()?,
+            teleport_price_exponent: cursor.read_f32::<LittleEndian>()?,
+            energy_efficiency: cursor.read_f32::<LittleEndian>()?,
+            price_object_rent: cursor.read_f32::<LittleEndian>()?,
+            price_object_scale_factor: cursor.read_f32::<LittleEndian>()?,
+            price_parcel_rent: cursor.read_i32::<LittleEndian>()?,
+            price_group_create: cursor.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(68);
+        bytes.extend_from_slice(&self.object_capacity.to_le_bytes());
+        bytes.extend_from_slice(&self.object_count.to_le_bytes());
+        bytes.extend_from_slice(&self.price_energy_unit.to_le_bytes());
+        bytes.extend_from_slice(&self.price_object_claim.to_le_bytes());
+        bytes.extend_from_slice(&self.price_public_object_decay.to_le_bytes());
+        bytes.extend_from_slice(&self.price_public_object_delete.to_le_bytes());
+        bytes.extend_from_slice(&self.price_parcel_claim.to_le_bytes());
+        bytes.extend_from_slice(&self.price_parcel_claim_factor.to_le_bytes());
+        bytes.extend_from_slice(&self.price_upload.to_le_bytes());
+        bytes.extend_from_slice(&self.price_rent_light.to_le_bytes());
+        bytes.extend_from_slice(&self.teleport_min_price.to_le_bytes());
+        bytes.extend_from_slice(&self.teleport_price_exponent.to_le_bytes());
+        bytes.extend_from_slice(&self.energy_efficiency.to_le_bytes());
+        bytes.extend_from_slice(&self.price_object_rent.to_le_bytes());
+        bytes.extend_from_slice(&self.price_object_scale_factor.to_le_bytes());
+        bytes.extend_from_slice(&self.price_parcel_rent.to_le_bytes());
+        bytes.extend_from_slice(&self.price_group_create.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,44 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io;
+
+// ID: 24
+// Frequency: Low
+
+impl Packet {
+    pub fn new_economy_data_request(economy_data_request: EconomyDataRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 24,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::EconomyDataRequest(Box::new(economy_data_request)),
+        }
+    }
+}
+
+/// Asks the simulator for the prices of the grid, which it answers with EconomyData. It has no
+/// blocks.
+/// https://wiki.secondlife.com/wiki/EconomyDataRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct EconomyDataRequest {}
+
+impl PacketData for EconomyDataRequest {
+    fn from_bytes(_: &[u8]) -> io::Result<Self> {
+        Ok(EconomyDataRequest {})
+    }
+    fn to_bytes(&self) -> Vec<u8> {
+        vec![]
+    }
+}
@@ -0,0 +1,65 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use std::net::Ipv4Addr;
+
+// ID: 151
+// Frequency: Low
+
+impl Packet {
+    pub fn new_enable_simulator(enable_simulator: EnableSimulator) -> Self {
+        Packet {
+            header: Header {
+                id: 151,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::EnableSimulator(Box::new(enable_simulator)),
+        }
+    }
+}
+
+/// Sent by the simulator to announce a neighbouring region. The client opens a child agent
+/// circuit to it so it can see across the region border.
+/// https://wiki.secondlife.com/wiki/EnableSimulator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnableSimulator {
+    pub region_handle: u64,
+    pub ip: Ipv4Addr,
+    pub port: u16,
+}
+
+impl PacketData for EnableSimulator {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let region_handle = cursor.read_u64::<LittleEndian>()?;
+        // IP addresses and ports are sent in network byte order
+        let ip = Ipv4Addr::from(cursor.read_u32::<BigEndian>()?);
+        let port = cursor.read_u16::<BigEndian>()?;
+        Ok(EnableSimulator {
+            region_handle,
+            ip,
+            port,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(14);
+        bytes.extend_from_slice(&self.region_handle.to_le_bytes());
+        bytes.extend_from_slice(&self.ip.octets());
+        bytes.extend_from_slice(&self.port.to_be_bytes());
+        bytes
+    }
+}
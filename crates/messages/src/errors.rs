@@ -73,6 +73,114 @@ impl MailboxError {
     }
 }
 
+/// This represents errors that can arise from uploading a file with the Xfer system.
+/// The simulator pulls the file from the client in chunks, which the client resends until they
+/// are confirmed.
+/// https://wiki.secondlife.com/wiki/Xfer
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[error("{message}")]
+pub struct XferError {
+    /// String message that contains error information
+    pub message: String,
+}
+impl XferError {
+    /// Function for creating a new XferError
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+/// This represents errors that can arise from uploading an asset with AssetUploadRequest.
+/// https://wiki.secondlife.com/wiki/AssetUploadRequest
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[error("{message}")]
+pub struct AssetUploadError {
+    /// String message that contains error information
+    pub message: String,
+}
+impl AssetUploadError {
+    /// Function for creating a new AssetUploadError
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+/// This represents errors that can arise from making an inventory item with
+/// CreateInventoryItem.
+/// https://wiki.secondlife.com/wiki/CreateInventoryItem
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[error("{message}")]
+pub struct CreateInventoryItemError {
+    /// String message that contains error information
+    pub message: String,
+}
+impl CreateInventoryItemError {
+    /// Function for creating a new CreateInventoryItemError
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+/// This represents errors that can arise from using god powers the agent hasn't been granted,
+/// like sending a GodKickUser.
+/// https://wiki.secondlife.com/wiki/GodKickUser
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[error("{message}")]
+pub struct GodPowersError {
+    /// String message that contains error information
+    pub message: String,
+}
+impl GodPowersError {
+    /// Function for creating a new GodPowersError
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+/// This represents errors that can arise from linking objects with ObjectLink, which are caught
+/// before the packet is sent, because the simulator refuses a bad link without saying so.
+/// https://wiki.secondlife.com/wiki/ObjectLink
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[error("{message}")]
+pub struct LinkObjectsError {
+    /// String message that contains error information
+    pub message: String,
+}
+impl LinkObjectsError {
+    /// Function for creating a new LinkObjectsError
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+/// This represents errors that can arise from asking whether a script is running with
+/// GetScriptRunning.
+/// https://wiki.secondlife.com/wiki/GetScriptRunning
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[error("{message}")]
+pub struct ScriptRunningError {
+    /// String message that contains error information
+    pub message: String,
+}
+impl ScriptRunningError {
+    /// Function for creating a new ScriptRunningError
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
 /// Represents errors that arise from failures within the session
 #[derive(Clone, Debug, Error, Serialize, Deserialize)]
 pub enum SessionError {
@@ -91,6 +199,24 @@ pub enum SessionError {
     /// This is sent when Acknowledgement packets fail
     #[error("AckError: {0}")]
     AckError(#[from] AckError),
+    /// This is sent when an Xfer upload fails
+    #[error("XferError: {0}")]
+    Xfer(#[from] XferError),
+    /// This is sent when an asset upload fails
+    #[error("AssetUploadError: {0}")]
+    AssetUpload(#[from] AssetUploadError),
+    /// This is sent when making an inventory item fails
+    #[error("CreateInventoryItemError: {0}")]
+    CreateInventoryItem(#[from] CreateInventoryItemError),
+    /// This is sent when god powers are used without being granted
+    #[error("GodPowersError: {0}")]
+    GodPowers(#[from] GodPowersError),
+    /// This is sent when objects can't be linked
+    #[error("LinkObjectsError: {0}")]
+    LinkObjects(#[from] LinkObjectsError),
+    /// This is sent when the simulator doesn't say whether a script is running
+    #[error("ScriptRunningError: {0}")]
+    ScriptRunning(#[from] ScriptRunningError),
 }
 impl SessionError {
     /// Create a new LoginError from the message's login error
@@ -0,0 +1,245 @@
+use crate::packet_types::PacketType;
+use crate::utils::agent_access::AgentAccess;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1, write_variable_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 261
+// Frequency: Low
+
+impl Packet {
+    pub fn new_estate_owner_message(estate_owner_message: EstateOwnerMessage) -> Self {
+        Packet {
+            header: Header {
+                id: 261,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::EstateOwnerMessage(Box::new(estate_owner_message)),
+        }
+    }
+}
+
+/// Estate management, like kicking users or changing the settings of a region. Every command
+/// is a method name with a list of string parameters, and the simulator answers some of them
+/// with an EstateOwnerMessage of its own, with the same invoice.
+/// https://wiki.secondlife.com/wiki/EstateOwnerMessage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EstateOwnerMessage {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub transaction_id: Uuid,
+    /// the command, like setregioninfo
+    pub method: String,
+    /// sent back in the simulator's answer, to tell commands apart
+    pub invoice: Uuid,
+    pub params: Vec<String>,
+}
+
+/// the settings of a region that setregioninfo changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionInfoSettings {
+    pub block_terraform: bool,
+    pub block_fly: bool,
+    pub allow_damage: bool,
+    pub allow_land_resell: bool,
+    /// the most avatars the region lets in
+    pub agent_limit: f32,
+    /// how many times more objects the parcels of the region can hold
+    pub object_bonus: f32,
+    /// the maturity rating of the region
+    pub sim_access: AgentAccess,
+    pub restrict_pushobject: bool,
+    pub allow_parcel_changes: bool,
+}
+
+impl EstateOwnerMessage {
+    /// send a message to everyone in the estate
+    pub const INSTANT_MESSAGE: &'static str = "instantmessage";
+    /// send a message to everyone in the region
+    pub const SIMULATOR_MESSAGE: &'static str = "simulatormessage";
+    /// send a user home
+    pub const TELEPORT_HOME_USER: &'static str = "teleporthomeuser";
+    /// change the settings of the region
+    pub const SET_REGION_INFO: &'static str = "setregioninfo";
+    /// manage the telehub of the region. The first parameter is the telehub command.
+    pub const TELEHUB: &'static str = "telehub";
+    /// ask for the telehub of the region, answered with a TelehubInfo
+    pub const TELEHUB_INFO: &'static str = "info ui";
+    /// connect the telehub to an object
+    pub const TELEHUB_CONNECT: &'static str = "connect";
+    /// disconnect the telehub from its object
+    pub const TELEHUB_DELETE: &'static str = "delete";
+    /// add an object's position as a spawn point
+    pub const TELEHUB_SPAWN_POINT_ADD: &'static str = "spawnpoint add";
+    /// remove a spawn point by its index
+    pub const TELEHUB_SPAWN_POINT_REMOVE: &'static str = "spawnpoint remove";
+
+    /// run any method with its parameters. The agent and session ids are left nil, for the
+    /// session to fill in, and the invoice is made up.
+    pub fn new(method: String, params: Vec<String>) -> Self {
+        EstateOwnerMessage {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            transaction_id: Uuid::nil(),
+            method,
+            invoice: Uuid::new_v4(),
+            params,
+        }
+    }
+
+    /// send a message to everyone in the estate, from the sender
+    pub fn instant_message(sender_id: Uuid, sender_name: &str, message: &str) -> Self {
+        Self::new(
+            Self::INSTANT_MESSAGE.to_string(),
+            Self::message_params(sender_id, sender_name, message),
+        )
+    }
+
+    /// send a message to everyone in the region, from the sender
+    pub fn simulator_message(sender_id: Uuid, sender_name: &str, message: &str) -> Self {
+        Self::new(
+            Self::SIMULATOR_MESSAGE.to_string(),
+            Self::message_params(sender_id, sender_name, message),
+        )
+    }
+
+    /// send a user home. The invoker is the agent sending the command.
+    pub fn teleport_home_user(invoker_id: Uuid, user_id: Uuid) -> Self {
+        Self::new(
+            Self::TELEPORT_HOME_USER.to_string(),
+            vec![invoker_id.to_string(), user_id.to_string()],
+        )
+    }
+
+    /// change the settings of the region
+    pub fn set_region_info(settings: &RegionInfoSettings) -> Self {
+        let flag = |value: bool| if value { "Y" } else { "N" }.to_string();
+        Self::new(
+            Self::SET_REGION_INFO.to_string(),
+            vec![
+                flag(settings.block_terraform),
+                flag(settings.block_fly),
+                flag(settings.allow_damage),
+                flag(settings.allow_land_resell),
+                format!("{:.6}", settings.agent_limit),
+                format!("{:.6}", settings.object_bonus),
+                settings.sim_access.to_bytes().to_string(),
+                flag(settings.restrict_pushobject),
+                flag(settings.allow_parcel_changes),
+            ],
+        )
+    }
+
+    /// ask for the telehub of the region and its spawn points
+    pub fn telehub_info() -> Self {
+        Self::telehub(Self::TELEHUB_INFO, None)
+    }
+
+    /// make the object with the local id the telehub of the region
+    pub fn telehub_connect(local_id: u32) -> Self {
+        Self::telehub(Self::TELEHUB_CONNECT, Some(local_id))
+    }
+
+    /// disconnect the telehub of the region from its object
+    pub fn telehub_delete() -> Self {
+        Self::telehub(Self::TELEHUB_DELETE, None)
+    }
+
+    /// add the position of the object with the local id as a spawn point of the telehub
+    pub fn telehub_add_spawn_point(local_id: u32) -> Self {
+        Self::telehub(Self::TELEHUB_SPAWN_POINT_ADD, Some(local_id))
+    }
+
+    /// remove a spawn point, by its index in the spawn points of the TelehubInfo
+    pub fn telehub_remove_spawn_point(index: u32) -> Self {
+        Self::telehub(Self::TELEHUB_SPAWN_POINT_REMOVE, Some(index))
+    }
+
+    /// true if this is a telehub command that changes the telehub, rather than asking for it
+    pub fn is_telehub_change(&self) -> bool {
+        self.method == Self::TELEHUB
+            && self
+                .params
+                .first()
+                .is_some_and(|command| command != Self::TELEHUB_INFO)
+    }
+
+    fn telehub(command: &str, argument: Option<u32>) -> Self {
+        let mut params = vec![command.to_string()];
+        if let Some(argument) = argument {
+            params.push(argument.to_string());
+        }
+        Self::new(Self::TELEHUB.to_string(), params)
+    }
+
+    // the first two parameters are unused, and always -1
+    fn message_params(sender_id: Uuid, sender_name: &str, message: &str) -> Vec<String> {
+        vec![
+            "-1".to_string(),
+            "-1".to_string(),
+            sender_id.to_string(),
+            sender_name.to_string(),
+            message.to_string(),
+        ]
+    }
+}
+
+impl PacketData for EstateOwnerMessage {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let transaction_id = read_uuid(&mut cursor)?;
+        let method = read_string_1(&mut cursor)?;
+        let invoice = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut params = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            params.push(read_string_1(&mut cursor)?);
+        }
+        Ok(EstateOwnerMessage {
+            agent_id,
+            session_id,
+            transaction_id,
+            method,
+            invoice,
+            params,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let params = &self.params[..self.params.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(
+            66 + self.method.len() + params.iter().map(|p| p.len() + 2).sum::<usize>(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        write_string_1(&mut bytes, &self.method);
+        bytes.extend_from_slice(self.invoice.as_bytes());
+        bytes.push(params.len() as u8);
+        for param in params {
+            // the simulator reads the parameters as C strings, so even empty ones are terminated
+            if param.is_empty() {
+                write_variable_1(&mut bytes, &[0]);
+            } else {
+                write_string_1(&mut bytes, param);
+            }
+        }
+        bytes
+    }
+}
@@ -0,0 +1,114 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_dvec3, read_string_1, read_string_2, read_uuid, write_dvec3, write_string_1,
+    write_string_2,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::DVec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 180
+// Frequency: Low
+
+impl Packet {
+    pub fn new_event_info_reply(event_info_reply: EventInfoReply) -> Self {
+        Packet {
+            header: Header {
+                id: 180,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::EventInfoReply(Box::new(event_info_reply)),
+        }
+    }
+}
+
+/// The details of an event from the events directory, sent in answer to an EventInfoRequest.
+/// https://wiki.secondlife.com/wiki/EventInfoReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventInfoReply {
+    pub agent_id: Uuid,
+    pub event_id: u32,
+    /// the id of the avatar running the event, as text
+    pub creator: String,
+    pub name: String,
+    /// the name of the event's category, like live music
+    pub category: String,
+    pub description: String,
+    /// when the event starts, as text in the grid's time zone
+    pub date: String,
+    /// when the event starts, in seconds since the unix epoch
+    pub date_utc: u32,
+    /// how long the event lasts, in minutes
+    pub duration: u32,
+    /// true if the event has a cover charge
+    pub cover: u32,
+    /// the cover charge
+    pub amount: u32,
+    pub sim_name: String,
+    /// where the event is, in global coordinates
+    pub global_pos: DVec3,
+    /// the maturity of the event
+    pub event_flags: u32,
+}
+
+impl PacketData for EventInfoReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(EventInfoReply {
+            agent_id: read_uuid(&mut cursor)?,
+            event_id: cursor.read_u32::<LittleEndian>()?,
+            creator: read_string_1(&mut cursor)?,
+            name: read_string_1(&mut cursor)?,
+            category: read_string_1(&mut cursor)?,
+            description: read_string_2(&mut cursor)?,
+            date: read_string_1(&mut cursor)?,
+            date_utc: cursor.read_u32::<LittleEndian>()?,
+            duration: cursor.read_u32::<LittleEndian>()?,
+            cover: cursor.read_u32::<LittleEndian>()?,
+            amount: cursor.read_u32::<LittleEndian>()?,
+            sim_name: read_string_1(&mut cursor)?,
+            global_pos: read_dvec3(&mut cursor)?,
+            event_flags: cursor.read_u32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            71 + self.creator.len()
+                + self.name.len()
+                + self.category.len()
+                + self.description.len()
+                + self.date.len()
+                + self.sim_name.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(&self.event_id.to_le_bytes());
+        write_string_1(&mut bytes, &self.creator);
+        write_string_1(&mut bytes, &self.name);
+        write_string_1(&mut bytes, &self.category);
+        write_string_2(&mut bytes, &self.description);
+        write_string_1(&mut bytes, &self.date);
+        bytes.extend_from_slice(&self.date_utc.to_le_bytes());
+        bytes.extend_from_slice(&self.duration.to_le_bytes());
+        bytes.extend_from_slice(&self.cover.to_le_bytes());
+        bytes.extend_from_slice(&self.amount.to_le_bytes());
+        write_string_1(&mut bytes, &self.sim_name);
+        write_dvec3(&mut bytes, &self.global_pos);
+        bytes.extend_from_slice(&self.event_flags.to_le_bytes());
+        bytes
+    }
+}
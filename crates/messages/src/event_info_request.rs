@@ -0,0 +1,72 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 179
+// Frequency: Low
+
+impl Packet {
+    pub fn new_event_info_request(event_info_request: EventInfoRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 179,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::EventInfoRequest(Box::new(event_info_request)),
+        }
+    }
+}
+
+/// Asks for the details of an event from the events directory, by its id. The simulator answers
+/// with an EventInfoReply.
+/// https://wiki.secondlife.com/wiki/EventInfoRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventInfoRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub event_id: u32,
+}
+
+impl EventInfoRequest {
+    /// ask for an event. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(event_id: u32) -> Self {
+        EventInfoRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            event_id,
+        }
+    }
+}
+
+impl PacketData for EventInfoRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(EventInfoRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            event_id: cursor.read_u32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(36);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.event_id.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,62 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 19
+// Frequency: Low
+
+impl Packet {
+    pub fn new_feature_disabled(feature_disabled: FeatureDisabled) -> Self {
+        Packet {
+            header: Header {
+                id: 19,
+                frequency: PacketFrequency::Low,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::FeatureDisabled(Box::new(feature_disabled)),
+        }
+    }
+}
+
+/// Sent by the simulator when the agent tries something the region has turned off, like
+/// uploading an asset, with the transaction id of the request it refused.
+/// https://wiki.secondlife.com/wiki/FeatureDisabled
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureDisabled {
+    /// the simulator's explanation
+    pub error_message: String,
+    pub agent_id: Uuid,
+    /// the transaction id of the request that was refused
+    pub transaction_id: Uuid,
+}
+
+impl PacketData for FeatureDisabled {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(FeatureDisabled {
+            error_message: read_string_1(&mut cursor)?,
+            agent_id: read_uuid(&mut cursor)?,
+            transaction_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(34 + self.error_message.len());
+        write_string_1(&mut bytes, &self.error_message);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes
+    }
+}
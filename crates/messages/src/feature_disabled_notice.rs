@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Sent from the session to the UI when the simulator refuses a request with a FeatureDisabled,
+/// with the simulator's explanation and what the session was doing with that transaction, if it
+/// knows. Requests that are waiting for an answer, like uploads, fail at the same time.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureDisabledNotice {
+    /// the transaction id of the request that was refused
+    pub transaction_id: Uuid,
+    /// human readable description of the request, like "uploading a Texture asset". None if the
+    /// session didn't send the request, or has forgotten it.
+    pub operation: Option<String>,
+    /// the simulator's explanation
+    pub message: String,
+}
@@ -0,0 +1,96 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 279
+// Frequency: Low
+
+impl Packet {
+    pub fn new_fetch_inventory(fetch_inventory: FetchInventory) -> Self {
+        Packet {
+            header: Header {
+                id: 279,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::FetchInventory(Box::new(fetch_inventory)),
+        }
+    }
+}
+
+/// Asks for inventory items by ID. The simulator answers with a FetchInventoryReply, and
+/// doesn't answer at all for items it can't find.
+/// https://wiki.secondlife.com/wiki/FetchInventory
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchInventory {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub items: Vec<ItemRequest>,
+}
+
+/// an item to fetch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ItemRequest {
+    /// the owner of the item. The agent for its own inventory, or the library's owner.
+    pub owner_id: Uuid,
+    pub item_id: Uuid,
+}
+
+impl FetchInventory {
+    /// fetch items by ID. The agent and session IDs are left nil, for the session to fill in,
+    /// and a nil owner is the agent.
+    pub fn new(items: Vec<ItemRequest>) -> Self {
+        FetchInventory {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            items,
+        }
+    }
+}
+
+impl PacketData for FetchInventory {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut items = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            items.push(ItemRequest {
+                owner_id: read_uuid(&mut cursor)?,
+                item_id: read_uuid(&mut cursor)?,
+            });
+        }
+        Ok(FetchInventory {
+            agent_id,
+            session_id,
+            items,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let items = &self.items[..self.items.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + items.len() * 32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(items.len() as u8);
+        for item in items {
+            bytes.extend_from_slice(item.owner_id.as_bytes());
+            bytes.extend_from_slice(item.item_id.as_bytes());
+        }
+        bytes
+    }
+}
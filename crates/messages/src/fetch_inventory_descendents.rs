@@ -0,0 +1,102 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 277
+// Frequency: Low
+
+impl Packet {
+    pub fn new_fetch_inventory_descendents(
+        fetch_inventory_descendents: FetchInventoryDescendents,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 277,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::FetchInventoryDescendents(Box::new(fetch_inventory_descendents)),
+        }
+    }
+}
+
+/// Asks for the folders and items directly inside an inventory folder. The simulator answers
+/// with one or more InventoryDescendents.
+/// https://wiki.secondlife.com/wiki/FetchInventoryDescendents
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchInventoryDescendents {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub folder_id: Uuid,
+    /// the owner of the folder. The agent for its own inventory, or the library's owner.
+    pub owner_id: Uuid,
+    /// how to sort the contents, from the SORT constants
+    pub sort_order: i32,
+    pub fetch_folders: bool,
+    pub fetch_items: bool,
+}
+
+impl FetchInventoryDescendents {
+    /// sort by name
+    pub const SORT_BY_NAME: i32 = 0;
+    /// sort by date, newest first
+    pub const SORT_BY_DATE: i32 = 1;
+    /// sort folders by name, even when sorting items by date
+    pub const SORT_FOLDERS_BY_NAME: i32 = 2;
+    /// put the system folders before the others
+    pub const SORT_SYSTEM_FOLDERS_TO_TOP: i32 = 4;
+
+    /// fetch the folders and items in a folder, sorted by name. The agent and session IDs are
+    /// left nil, for the session to fill in.
+    pub fn new(folder_id: Uuid, owner_id: Uuid) -> Self {
+        FetchInventoryDescendents {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            folder_id,
+            owner_id,
+            sort_order: Self::SORT_BY_NAME,
+            fetch_folders: true,
+            fetch_items: true,
+        }
+    }
+}
+
+impl PacketData for FetchInventoryDescendents {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(FetchInventoryDescendents {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            folder_id: read_uuid(&mut cursor)?,
+            owner_id: read_uuid(&mut cursor)?,
+            sort_order: cursor.read_i32::<LittleEndian>()?,
+            fetch_folders: cursor.read_u8()? != 0,
+            fetch_items: cursor.read_u8()? != 0,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(70);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.folder_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.extend_from_slice(&self.sort_order.to_le_bytes());
+        bytes.push(self.fetch_folders as u8);
+        bytes.push(self.fetch_items as u8);
+        bytes
+    }
+}
@@ -0,0 +1,65 @@
+use crate::packet_types::PacketType;
+use crate::utils::inventory_item::ItemData;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 280
+// Frequency: Low
+
+impl Packet {
+    pub fn new_fetch_inventory_reply(fetch_inventory_reply: FetchInventoryReply) -> Self {
+        Packet {
+            header: Header {
+                id: 280,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::FetchInventoryReply(Box::new(fetch_inventory_reply)),
+        }
+    }
+}
+
+/// Answers a FetchInventory with the items that were found.
+/// https://wiki.secondlife.com/wiki/FetchInventoryReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchInventoryReply {
+    pub agent_id: Uuid,
+    pub items: Vec<ItemData>,
+}
+
+impl PacketData for FetchInventoryReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut items = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            items.push(ItemData::from_bytes(&mut cursor)?);
+        }
+        Ok(FetchInventoryReply { agent_id, items })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let items = &self.items[..self.items.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(17 + items.len() * 150);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.push(items.len() as u8);
+        for item in items {
+            item.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
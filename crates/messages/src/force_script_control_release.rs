@@ -0,0 +1,75 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 192
+// Frequency: Low
+
+impl Packet {
+    pub fn new_force_script_control_release(
+        force_script_control_release: ForceScriptControlRelease,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 192,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ForceScriptControlRelease(Box::new(force_script_control_release)),
+        }
+    }
+}
+
+/// Takes the agent's controls back from every script that took them with ScriptControlChange,
+/// for when a scripted object won't let go.
+/// https://wiki.secondlife.com/wiki/ForceScriptControlRelease
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForceScriptControlRelease {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl ForceScriptControlRelease {
+    /// release the controls. The agent and session ids are left nil, for the session to fill in.
+    pub fn new() -> Self {
+        ForceScriptControlRelease {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+        }
+    }
+}
+
+impl Default for ForceScriptControlRelease {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PacketData for ForceScriptControlRelease {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ForceScriptControlRelease {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::login_system::login_response::FriendsRights;
+
+/// The agent's friends and which of them are online, sent from the session to the UI once the
+/// buddy list arrives with the login response. After that, the UI is kept up to date with
+/// FriendOnlineEvent and FriendOfflineEvent.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FriendList {
+    pub friends: Vec<Friend>,
+}
+
+/// a friend of the agent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Friend {
+    pub id: Uuid,
+    /// whether the friend is logged in. Friends that hide their presence always look offline.
+    pub online: bool,
+    /// the rights the agent has given the friend
+    pub rights_given: FriendsRights,
+    /// the rights the friend has given the agent
+    pub rights_has: FriendsRights,
+}
+
+impl FriendList {
+    /// the friends that are online
+    pub fn online(&self) -> impl Iterator<Item = &Friend> {
+        self.friends.iter().filter(|friend| friend.online)
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Sent from the session to the UI when friendships are offered, answered or ended. Offers
+/// carry the IM session id, which is what the UI answers them with.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FriendshipStatus {
+    /// another agent offered the agent friendship
+    Offered {
+        /// the id of the offer's message, to accept or decline it with
+        im_session_id: Uuid,
+        agent_id: Uuid,
+        name: String,
+        message: String,
+    },
+    /// an agent accepted the agent's offer
+    Accepted { agent_id: Uuid, name: String },
+    /// an agent declined the agent's offer
+    Declined { agent_id: Uuid, name: String },
+    /// a friendship was ended, by the agent or the friend
+    Terminated { agent_id: Uuid },
+}
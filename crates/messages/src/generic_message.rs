@@ -0,0 +1,115 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_string_1, read_uuid, read_variable_1, write_string_1, write_variable_1,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 262
+// Frequency: Low
+
+impl Packet {
+    pub fn new_generic_message(generic_message: GenericMessageData) -> Self {
+        Packet {
+            header: Header {
+                id: 262,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::GenericMessage(Box::new(generic_message)),
+        }
+    }
+}
+
+/// A method name with a list of parameters, for the commands that don't have packets of their
+/// own, like autopilot, freezing avatars and many OpenSimulator extensions. Both the viewer and
+/// the simulator send them. The parameters are raw bytes, because each method decides what they
+/// hold. Most are null terminated strings.
+/// https://wiki.secondlife.com/wiki/GenericMessage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenericMessageData {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub transaction_id: Uuid,
+    /// the command, like autopilot
+    pub method: String,
+    /// sent back in answers, to tell messages apart
+    pub invoice: Uuid,
+    pub params: Vec<Vec<u8>>,
+}
+
+impl GenericMessageData {
+    /// send any method with its parameters. The agent and session ids are left nil, for the
+    /// session to fill in, and the invoice is made up.
+    pub fn new(method: String, params: Vec<Vec<u8>>) -> Self {
+        GenericMessageData {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            transaction_id: Uuid::nil(),
+            method,
+            invoice: Uuid::new_v4(),
+            params,
+        }
+    }
+
+    /// a string parameter, with the null terminator the simulator expects
+    pub fn string_param(param: &str) -> Vec<u8> {
+        let mut bytes = param.as_bytes().to_vec();
+        bytes.push(0);
+        bytes
+    }
+}
+
+impl PacketData for GenericMessageData {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let transaction_id = read_uuid(&mut cursor)?;
+        let method = read_string_1(&mut cursor)?;
+        let invoice = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut params = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            params.push(read_variable_1(&mut cursor)?);
+        }
+        Ok(GenericMessageData {
+            agent_id,
+            session_id,
+            transaction_id,
+            method,
+            invoice,
+            params,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let params = &self.params[..self.params.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(
+            66 + self.method.len() + params.iter().map(|p| p.len() + 1).sum::<usize>(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        write_string_1(&mut bytes, &self.method);
+        bytes.extend_from_slice(self.invoice.as_bytes());
+        bytes.push(params.len() as u8);
+        for param in params {
+            write_variable_1(&mut bytes, param);
+        }
+        bytes
+    }
+}
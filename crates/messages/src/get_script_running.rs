@@ -0,0 +1,58 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 243
+// Frequency: Low
+
+impl Packet {
+    pub fn new_get_script_running(get_script_running: GetScriptRunning) -> Self {
+        Packet {
+            header: Header {
+                id: 243,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::GetScriptRunning(Box::new(get_script_running)),
+        }
+    }
+}
+
+/// Asks whether a script in an object is running. The simulator answers with a
+/// ScriptRunningReply.
+/// https://wiki.secondlife.com/wiki/GetScriptRunning
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetScriptRunning {
+    /// the full id of the object the script is in
+    pub object_id: Uuid,
+    /// the id of the script in the object's contents
+    pub item_id: Uuid,
+}
+
+impl PacketData for GetScriptRunning {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let object_id = read_uuid(&mut cursor)?;
+        let item_id = read_uuid(&mut cursor)?;
+        Ok(GetScriptRunning { object_id, item_id })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(self.item_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,90 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_2, read_uuid, write_string_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 165
+// Frequency: Low
+
+impl Packet {
+    pub fn new_god_kick_user(god_kick_user: GodKickUser) -> Self {
+        Packet {
+            header: Header {
+                id: 165,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::GodKickUser(Box::new(god_kick_user)),
+        }
+    }
+}
+
+/// Kicks a user off the grid, or freezes or unfreezes them, with the agent's god powers. The
+/// simulator ignores it from agents that haven't been granted god powers.
+/// https://wiki.secondlife.com/wiki/GodKickUser
+#[derive(Debug, Clone, PartialEq)]
+pub struct GodKickUser {
+    /// the agent doing the kicking
+    pub god_id: Uuid,
+    pub god_session_id: Uuid,
+    /// the user being kicked
+    pub agent_id: Uuid,
+    pub kick_flags: u32,
+    /// the reason shown to the user
+    pub reason: String,
+}
+
+impl GodKickUser {
+    /// kick the user off the grid
+    pub const KICK: u32 = 0;
+    /// freeze the user in place
+    pub const FREEZE: u32 = 1 << 0;
+    /// let a frozen user move again
+    pub const UNFREEZE: u32 = 1 << 1;
+
+    /// kick a user, with a reason. The god ids are left nil, for the session to fill in.
+    pub fn new(agent_id: Uuid, reason: String) -> Self {
+        GodKickUser {
+            god_id: Uuid::nil(),
+            god_session_id: Uuid::nil(),
+            agent_id,
+            kick_flags: Self::KICK,
+            reason,
+        }
+    }
+}
+
+impl PacketData for GodKickUser {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(GodKickUser {
+            god_id: read_uuid(&mut cursor)?,
+            god_session_id: read_uuid(&mut cursor)?,
+            agent_id: read_uuid(&mut cursor)?,
+            kick_flags: cursor.read_u32::<LittleEndian>()?,
+            reason: read_string_2(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(55 + self.reason.len());
+        bytes.extend_from_slice(self.god_id.as_bytes());
+        bytes.extend_from_slice(self.god_session_id.as_bytes());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(&self.kick_flags.to_le_bytes());
+        write_string_2(&mut bytes, &self.reason);
+        bytes
+    }
+}
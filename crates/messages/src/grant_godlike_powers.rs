@@ -0,0 +1,65 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 259
+// Frequency: Low
+
+impl Packet {
+    pub fn new_grant_godlike_powers(grant_godlike_powers: GrantGodlikePowers) -> Self {
+        Packet {
+            header: Header {
+                id: 259,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::GrantGodlikePowers(Box::new(grant_godlike_powers)),
+        }
+    }
+}
+
+/// The simulator's answer to a RequestGodlikePowers. A god level of 0 means the agent has no
+/// god powers, either because they were given up or because the agent isn't allowed them.
+/// https://wiki.secondlife.com/wiki/GrantGodlikePowers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrantGodlikePowers {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub god_level: u8,
+    pub token: Uuid,
+}
+
+impl PacketData for GrantGodlikePowers {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(GrantGodlikePowers {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            god_level: cursor.read_u8()?,
+            token: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(49);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(self.god_level);
+        bytes.extend_from_slice(self.token.as_bytes());
+        bytes
+    }
+}
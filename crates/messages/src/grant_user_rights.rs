@@ -0,0 +1,106 @@
+use crate::login_system::login_response::FriendsRights;
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 320
+// Frequency: Low
+
+impl Packet {
+    pub fn new_grant_user_rights(grant_user_rights: GrantUserRights) -> Self {
+        Packet {
+            header: Header {
+                id: 320,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::GrantUserRights(Box::new(grant_user_rights)),
+        }
+    }
+}
+
+/// Changes the rights the agent gives its friends, like seeing it on the map. The whole set of
+/// rights is sent for each friend. The simulator answers with a ChangeUserRights.
+/// https://wiki.secondlife.com/wiki/GrantUserRights
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrantUserRights {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub rights: Vec<UserRights>,
+}
+
+/// the rights given to a friend, or by one
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserRights {
+    /// the friend the rights are for
+    pub agent_related: Uuid,
+    pub rights: FriendsRights,
+}
+
+impl GrantUserRights {
+    /// give friends rights. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(rights: Vec<UserRights>) -> Self {
+        GrantUserRights {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            rights,
+        }
+    }
+}
+
+impl UserRights {
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        Ok(UserRights {
+            agent_related: read_uuid(cursor)?,
+            rights: FriendsRights::from_bits(cursor.read_i32::<LittleEndian>()?),
+        })
+    }
+
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(self.agent_related.as_bytes());
+        bytes.extend_from_slice(&self.rights.to_bits().to_le_bytes());
+    }
+}
+
+impl PacketData for GrantUserRights {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut rights = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            rights.push(UserRights::from_bytes(&mut cursor)?);
+        }
+        Ok(GrantUserRights {
+            agent_id,
+            session_id,
+            rights,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let rights = &self.rights[..self.rights.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + rights.len() * 20);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(rights.len() as u8);
+        for user_rights in rights {
+            user_rights.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
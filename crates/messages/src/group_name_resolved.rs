@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::uuid_group_name_reply::GroupName;
+
+/// Sent from the session to the UI with group names as they are resolved, as a JSON array.
+/// Group names are kept apart from avatar names, which are sent as a NameResolvedEvent.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GroupNameResolved {
+    pub names: Vec<GroupName>,
+}
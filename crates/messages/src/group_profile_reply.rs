@@ -0,0 +1,115 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_string_1, read_string_2, read_uuid, write_string_1, write_string_2,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 352
+// Frequency: Low
+
+impl Packet {
+    pub fn new_group_profile_reply(group_profile_reply: GroupProfileReply) -> Self {
+        Packet {
+            header: Header {
+                id: 352,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::GroupProfileReply(Box::new(group_profile_reply)),
+        }
+    }
+}
+
+/// The details of a group, sent in answer to a GroupProfileRequest.
+/// https://wiki.secondlife.com/wiki/GroupProfileReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupProfileReply {
+    pub agent_id: Uuid,
+    pub group_id: Uuid,
+    pub name: String,
+    /// the group's description
+    pub charter: String,
+    /// whether the group shows up in search
+    pub show_in_list: bool,
+    /// the agent's title in the group, empty if the agent isn't a member
+    pub member_title: String,
+    /// what the agent can do in the group
+    pub powers_mask: u64,
+    /// the texture of the group's insignia
+    pub insignia_id: Uuid,
+    pub founder_id: Uuid,
+    /// how much it costs to join the group
+    pub membership_fee: i32,
+    /// whether anyone can join the group, without an invitation
+    pub open_enrollment: bool,
+    pub money: i32,
+    pub group_membership_count: i32,
+    pub group_roles_count: i32,
+    pub allow_publish: bool,
+    pub mature_publish: bool,
+    /// the role of the group's owners
+    pub owner_role: Uuid,
+}
+
+impl PacketData for GroupProfileReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(GroupProfileReply {
+            agent_id: read_uuid(&mut cursor)?,
+            group_id: read_uuid(&mut cursor)?,
+            name: read_string_1(&mut cursor)?,
+            charter: read_string_2(&mut cursor)?,
+            show_in_list: cursor.read_u8()? != 0,
+            member_title: read_string_1(&mut cursor)?,
+            powers_mask: cursor.read_u64::<LittleEndian>()?,
+            insignia_id: read_uuid(&mut cursor)?,
+            founder_id: read_uuid(&mut cursor)?,
+            membership_fee: cursor.read_i32::<LittleEndian>()?,
+            open_enrollment: cursor.read_u8()? != 0,
+            money: cursor.read_i32::<LittleEndian>()?,
+            group_membership_count: cursor.read_i32::<LittleEndian>()?,
+            group_roles_count: cursor.read_i32::<LittleEndian>()?,
+            allow_publish: cursor.read_u8()? != 0,
+            mature_publish: cursor.read_u8()? != 0,
+            owner_role: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            117 + self.name.len() + self.charter.len() + self.member_title.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        write_string_1(&mut bytes, &self.name);
+        write_string_2(&mut bytes, &self.charter);
+        bytes.push(self.show_in_list as u8);
+        write_string_1(&mut bytes, &self.member_title);
+        bytes.extend_from_slice(&self.powers_mask.to_le_bytes());
+        bytes.extend_from_slice(self.insignia_id.as_bytes());
+        bytes.extend_from_slice(self.founder_id.as_bytes());
+        bytes.extend_from_slice(&self.membership_fee.to_le_bytes());
+        bytes.push(self.open_enrollment as u8);
+        bytes.extend_from_slice(&self.money.to_le_bytes());
+        bytes.extend_from_slice(&self.group_membership_count.to_le_bytes());
+        bytes.extend_from_slice(&self.group_roles_count.to_le_bytes());
+        bytes.push(self.allow_publish as u8);
+        bytes.push(self.mature_publish as u8);
+        bytes.extend_from_slice(self.owner_role.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,71 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 351
+// Frequency: Low
+
+impl Packet {
+    pub fn new_group_profile_request(group_profile_request: GroupProfileRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 351,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::GroupProfileRequest(Box::new(group_profile_request)),
+        }
+    }
+}
+
+/// Asks for the details of a group. The simulator answers with a GroupProfileReply.
+/// https://wiki.secondlife.com/wiki/GroupProfileRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupProfileRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub group_id: Uuid,
+}
+
+impl GroupProfileRequest {
+    /// ask for a group's details. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(group_id: Uuid) -> Self {
+        GroupProfileRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            group_id,
+        }
+    }
+}
+
+impl PacketData for GroupProfileRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(GroupProfileRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            group_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes
+    }
+}
@@ -20,56 +20,45 @@ pub struct Header {
     pub size: Option<usize>,
 }
 impl Header {
+    /// read the header from the start of a packet. The flags and sequence number are followed by
+    /// the length of the extra header, which is skipped, and then the message number, which is
+    /// zerocoded along with the body if the packet is. Appended acks are read from the end of
+    /// the packet. Bytes that are too short to hold a header are an error, not a panic.
     pub fn try_from_bytes(bytes: &[u8]) -> Result<Header, std::io::Error> {
-        let mut pos = 0;
-
-        let flags = bytes[pos];
+        if bytes.len() < 6 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("packet is too short for a header: {} bytes", bytes.len()),
+            ));
+        }
+        let flags = bytes[0];
         let appended_acks = (flags & MSG_APPENDED_ACKS) != 0;
         let reliable = (flags & MSG_RELIABLE) != 0;
         let resent = (flags & MSG_RESENT) != 0;
         let zerocoded = (flags & MSG_ZEROCODED) != 0;
 
-        // the flags live in one byte
-        pos += 1;
-        let sequence_number =
-            u32::from_be_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]]);
-        // the sequence number lives in 4 bytes
-        pos += 4;
+        // the flags live in one byte, and the sequence number in the next four
+        let sequence_number = u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
 
-        let available_bytes = bytes.len() - pos;
-        // take at least six bytes from the header
-        // there should be a pos += 1 here but it's fine
-        let slice_length = available_bytes.min(6);
+        // the extra header is never used, but its length says where the message number starts
+        let mut pos = 6 + bytes[5] as usize;
+        if pos > bytes.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "packet is too short for its extra header",
+            ));
+        }
 
         let (frequency, id, frequency_size) =
-            PacketFrequency::from_bytes(&bytes[pos..pos + slice_length], zerocoded)
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-
+            PacketFrequency::from_bytes(&bytes[pos..], zerocoded)?;
         pos += frequency_size;
 
         let ack_list = if appended_acks {
-            let count = bytes[pos];
-            pos -= 1;
-
-            let mut acks = Vec::with_capacity(count as usize);
-
-            for _ in 0..count {
-                let offset = pos - 3;
-                let ack = u32::from_be_bytes([
-                    bytes[offset],
-                    bytes[offset + 1],
-                    bytes[offset + 2],
-                    bytes[offset + 3],
-                ]);
-
-                acks.push(ack);
-                pos -= 4;
-            }
-            Some(acks)
+            Some(read_appended_acks(&bytes[pos..])?)
         } else {
             None
         };
-        //info!("HEADER: id:{:?}, frequency:{:?}", id, frequency);
+
         let header = Header {
             appended_acks,
             reliable,
@@ -83,11 +72,12 @@ impl Header {
         };
         Ok(header)
     }
+    /// write the header, up to the end of the message number. The appended acks come after the
+    /// body, so the packet writes them.
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(10);
 
         // Add the flags byte
-        // TODO fix this
         let mut flags = 0;
         if self.appended_acks {
             flags |= MSG_APPENDED_ACKS;
@@ -106,32 +96,49 @@ impl Header {
         // Add the sequence number (4 bytes, big-endian)
         bytes.extend_from_slice(&self.sequence_number.to_be_bytes());
 
-        // Add the extra byte
+        // Add the length of the extra header, which is always empty
         bytes.push(0);
 
         // Add the ID and frequency
         bytes.extend_from_slice(&self.frequency.to_bytes(self));
 
-        // Append the ack list if appended_acks is true
-        if self.appended_acks {
-            if let Some(ref ack_list) = self.ack_list {
-                bytes.push(ack_list.len() as u8);
-                for ack in ack_list {
-                    bytes.extend_from_slice(&ack.to_be_bytes());
-                }
-            }
-        }
-
         bytes
     }
 }
 
+/// read the acks appended to the end of a packet. The last byte is the number of acks, and the
+/// acks are the four byte sequence numbers before it.
+fn read_appended_acks(bytes: &[u8]) -> io::Result<Vec<u32>> {
+    let count = match bytes.last() {
+        Some(count) => *count as usize,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "packet has appended acks but no ack count",
+            ))
+        }
+    };
+    let start = match (bytes.len() - 1).checked_sub(count * 4) {
+        Some(start) => start,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("packet is too short for {} appended acks", count),
+            ))
+        }
+    };
+    Ok(bytes[start..bytes.len() - 1]
+        .chunks_exact(4)
+        .map(|ack| u32::from_be_bytes([ack[0], ack[1], ack[2], ack[3]]))
+        .collect())
+}
+
 // Utility function to convert a u16 to a big-endian byte array
 fn uint16_to_bytes_big(value: u16) -> [u8; 2] {
     [(value >> 8) as u8, (value & 0xFF) as u8]
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PacketFrequency {
     High,
     Medium,
@@ -150,6 +157,10 @@ impl fmt::Display for PacketFrequency {
 }
 
 impl PacketFrequency {
+    /// the message number of a header. High frequency ids are one byte, Medium ones are marked
+    /// with one 0xFF, Low ones with two and are two bytes long, and Fixed ones are marked with
+    /// three. Fixed ids are kept as their last byte, so PacketAck, which is 0xFFFFFFFB, is 251.
+    /// In a zerocoded packet the zero bytes of a Low id are written as a zero and a count of 1.
     pub fn to_bytes(&self, header: &Header) -> Vec<u8> {
         let mut bytes = Vec::new();
         match self {
@@ -166,75 +177,80 @@ impl PacketFrequency {
                 // 4 byte ID
                 bytes.push(0xFF);
                 bytes.push(0xFF);
-                let id_bytes = uint16_to_bytes_big(header.id);
-                bytes.extend_from_slice(&id_bytes);
+                for byte in uint16_to_bytes_big(header.id) {
+                    bytes.push(byte);
+                    if byte == 0 && header.zerocoded {
+                        bytes.push(1);
+                    }
+                }
             }
             PacketFrequency::Fixed => {
+                // 4 byte ID
                 bytes.push(0xFF);
                 bytes.push(0xFF);
                 bytes.push(0xFF);
-                bytes.push(header.id as u8); // THIS IS PROBABLY INCORRECT
+                bytes.push(header.id as u8);
             }
         };
         bytes
     }
-    pub fn from_bytes(bytes: &[u8], zerocoded: bool) -> io::Result<(Self, u16, usize)> {
-        if bytes.is_empty() {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Empty PacketFrequency",
-            ));
-        }
 
-        let id;
-        let frequency;
-        let size;
-
-        // this match against variable sizes is dangerous and doesn't really give that many
-        // benefits
-        // this should probably be rewritten so it accepts a fixed size to the end zeroed out, and
-        // then does operations on that.
-        match bytes.len() {
-            2 => {
-                frequency = PacketFrequency::High;
-                id = bytes[0] as u16;
-                size = 1;
-            }
-            3 => {
-                frequency = PacketFrequency::Medium;
-                id = bytes[2] as u16;
-                size = 3;
+    /// read the message number from the bytes after the extra header. Returns the frequency, the
+    /// id, and the number of bytes the message number took up.
+    pub fn from_bytes(bytes: &[u8], zerocoded: bool) -> io::Result<(Self, u16, usize)> {
+        let (frequency, id, size) = match bytes {
+            [0xFF, 0xFF, 0xFF, id, ..] => (PacketFrequency::Fixed, *id as u16, 4),
+            [0xFF, 0xFF, rest @ ..] => {
+                let (id, size) = read_low_id(rest, zerocoded)?;
+                (PacketFrequency::Low, id, size + 2)
             }
-            5 | 6 => {
-                if bytes[1] == 0xFF && bytes[2] == 0xFF && bytes[3] == 0xFF {
-                    frequency = PacketFrequency::Fixed;
-                    id = bytes[4] as u16;
-                    size = 5;
-                } else if bytes[1] == 0xFF && bytes[2] == 0xFF {
-                    frequency = PacketFrequency::Low;
-                    id = if zerocoded && bytes[3] == 0 {
-                        bytes[5] as u16
-                    } else {
-                        u16::from_be_bytes([bytes[3], bytes[4]])
-                    };
-                    size = 5;
-                } else if bytes[1] == 0xFF {
-                    frequency = PacketFrequency::Medium;
-                    id = bytes[2] as u16;
-                    size = 3; // First 2 bytes considered for Medium frequency
-                } else {
-                    frequency = PacketFrequency::High;
-                    id = bytes[1] as u16;
-                    size = 2; // First byte considered for High frequency
-                }
+            [0xFF, id, ..] => (PacketFrequency::Medium, *id as u16, 2),
+            [id, ..] => (PacketFrequency::High, *id as u16, 1),
+            [] => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "packet has no message number",
+                ));
             }
-            _ => {
-                return Err(std::io::Error::new(
-                    std::io::ErrorKind::InvalidData,
-                    "Unsupported packet length",
+        };
+        Ok((frequency, id, size))
+    }
+}
+
+/// read the two bytes of a Low id. Returns the id and the number of bytes it took up, which is
+/// more than two if its zero bytes are zerocoded.
+fn read_low_id(bytes: &[u8], zerocoded: bool) -> io::Result<(u16, usize)> {
+    let mut id = [0u8; 2];
+    let mut read = 0;
+    let mut pos = 0;
+    while read < 2 {
+        let byte = *bytes.get(pos).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "packet is too short for a Low id",
+            )
+        })?;
+        pos += 1;
+        if byte == 0 && zerocoded {
+            let count = *bytes.get(pos).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "zerocoded id has no zero count",
+                )
+            })? as usize;
+            pos += 1;
+            if read + count > 2 {
+                // the zeros run on into the body, which is decoded on its own
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "zerocoded id runs into the body",
                 ));
             }
+            read += count;
+        } else {
+            id[read] = byte;
+            read += 1;
         }
-        Ok((frequency, id, size))
     }
+    Ok((u16::from_be_bytes(id), pos))
 }
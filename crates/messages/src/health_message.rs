@@ -0,0 +1,52 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 138
+// Frequency: Low
+
+impl Packet {
+    pub fn new_health_message(health_message: HealthMessage) -> Self {
+        Packet {
+            header: Header {
+                id: 138,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::HealthMessage(Box::new(health_message)),
+        }
+    }
+}
+
+/// Sent by the simulator when the agent's health changes, in regions where damage is enabled.
+/// Health goes from 100 down to 0, when the agent dies and is sent home.
+/// https://wiki.secondlife.com/wiki/HealthMessage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthMessage {
+    pub health: f32,
+}
+
+impl PacketData for HealthMessage {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(HealthMessage {
+            health: cursor.read_f32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.health.to_le_bytes().to_vec()
+    }
+}
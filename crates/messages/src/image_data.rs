@@ -0,0 +1,126 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_variable_2, write_variable_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 9
+// Frequency: High
+
+impl Packet {
+    pub fn new_image_data(image_data: ImageData) -> Self {
+        Packet {
+            header: Header {
+                id: 9,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ImageData(Box::new(image_data)),
+        }
+    }
+}
+
+/// The first packet of a texture download. Describes the whole image and carries the first
+/// chunk of it. The rest of the image follows in ImagePackets.
+/// https://wiki.secondlife.com/wiki/ImageData
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageData {
+    pub texture_id: Uuid,
+    pub codec: ImageCodec,
+    /// size of the whole image in bytes
+    pub size: u32,
+    /// number of packets in the image, including this one
+    pub packets: u16,
+    pub data: Vec<u8>,
+}
+impl ImageData {
+    /// the most image data the simulator puts in an ImageData
+    pub const FIRST_PACKET_SIZE: usize = 600;
+}
+
+/// the format of a texture
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageCodec {
+    Invalid,
+    Rgb,
+    J2c,
+    Bmp,
+    Tga,
+    Jpeg,
+    Dxt,
+    Png,
+    Unknown(u8),
+}
+impl ImageCodec {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => ImageCodec::Invalid,
+            1 => ImageCodec::Rgb,
+            2 => ImageCodec::J2c,
+            3 => ImageCodec::Bmp,
+            4 => ImageCodec::Tga,
+            5 => ImageCodec::Jpeg,
+            6 => ImageCodec::Dxt,
+            7 => ImageCodec::Png,
+            byte => ImageCodec::Unknown(byte),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            ImageCodec::Invalid => 0,
+            ImageCodec::Rgb => 1,
+            ImageCodec::J2c => 2,
+            ImageCodec::Bmp => 3,
+            ImageCodec::Tga => 4,
+            ImageCodec::Jpeg => 5,
+            ImageCodec::Dxt => 6,
+            ImageCodec::Png => 7,
+            ImageCodec::Unknown(byte) => *byte,
+        }
+    }
+}
+
+impl PacketData for ImageData {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // ImageID
+        let texture_id = read_uuid(&mut cursor)?;
+        let codec = ImageCodec::from_bytes(cursor.read_u8()?);
+        let size = cursor.read_u32::<LittleEndian>()?;
+        let packets = cursor.read_u16::<LittleEndian>()?;
+
+        // ImageData
+        let data = read_variable_2(&mut cursor)?;
+
+        Ok(ImageData {
+            texture_id,
+            codec,
+            size,
+            packets,
+            data,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(25 + self.data.len());
+        bytes.extend_from_slice(self.texture_id.as_bytes());
+        bytes.push(self.codec.to_bytes());
+        bytes.extend_from_slice(&self.size.to_le_bytes());
+        bytes.extend_from_slice(&self.packets.to_le_bytes());
+        write_variable_2(&mut bytes, &self.data);
+        bytes
+    }
+}
@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_variable_2, write_variable_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 10
+// Frequency: High
+
+impl Packet {
+    pub fn new_image_packet(image_packet: ImagePacket) -> Self {
+        Packet {
+            header: Header {
+                id: 10,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ImagePacket(Box::new(image_packet)),
+        }
+    }
+}
+
+/// A chunk of a texture after the ImageData. Packets are numbered from 1, because the ImageData
+/// is packet 0.
+/// https://wiki.secondlife.com/wiki/ImagePacket
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImagePacket {
+    pub texture_id: Uuid,
+    pub packet: u16,
+    pub data: Vec<u8>,
+}
+impl ImagePacket {
+    /// the most image data the simulator puts in an ImagePacket
+    pub const PACKET_SIZE: usize = 1000;
+}
+
+impl PacketData for ImagePacket {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // ImageID
+        let texture_id = read_uuid(&mut cursor)?;
+        let packet = cursor.read_u16::<LittleEndian>()?;
+
+        // ImageData
+        let data = read_variable_2(&mut cursor)?;
+
+        Ok(ImagePacket {
+            texture_id,
+            packet,
+            data,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(20 + self.data.len());
+        bytes.extend_from_slice(self.texture_id.as_bytes());
+        bytes.extend_from_slice(&self.packet.to_le_bytes());
+        write_variable_2(&mut bytes, &self.data);
+        bytes
+    }
+}
@@ -0,0 +1,263 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_string_1, read_string_2, read_uuid, read_variable_2, read_vec3, write_string_1,
+    write_string_2, write_variable_2, write_vec3,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 254
+// Frequency: Low
+
+impl Packet {
+    pub fn new_improved_instant_message(improved_instant_message: ImprovedInstantMessage) -> Self {
+        Packet {
+            header: Header {
+                id: 254,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ImprovedInstantMessage(Box::new(improved_instant_message)),
+        }
+    }
+}
+
+/// Instant messages are used for direct messages between agents, and also carry friendship
+/// offers, inventory offers, group invites and teleport lures, distinguished by the dialog.
+/// https://wiki.secondlife.com/wiki/ImprovedInstantMessage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImprovedInstantMessage {
+    pub from_agent_id: Uuid,
+    pub session_id: Uuid,
+    pub from_group: bool,
+    pub to_agent_id: Uuid,
+    pub parent_estate_id: u32,
+    pub region_id: Uuid,
+    pub position: Vec3,
+    pub offline: u8,
+    pub dialog: InstantMessageDialog,
+    /// the IM session ID. For one to one messages this is the two agent ids XORed together.
+    pub id: Uuid,
+    pub timestamp: u32,
+    pub from_agent_name: String,
+    pub message: String,
+    pub binary_bucket: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InstantMessageDialog {
+    MessageFromAgent,
+    MessageBox,
+    GroupInvitation,
+    InventoryOffered,
+    InventoryAccepted,
+    InventoryDeclined,
+    GroupVote,
+    TaskInventoryOffered,
+    TaskInventoryAccepted,
+    TaskInventoryDeclined,
+    NewUserDefault,
+    SessionAdd,
+    SessionOfflineAdd,
+    SessionGroupStart,
+    SessionCardlessStart,
+    SessionSend,
+    SessionDrop,
+    MessageFromObject,
+    BusyAutoResponse,
+    ConsoleAndChatHistory,
+    RequestTeleport,
+    AcceptTeleport,
+    DenyTeleport,
+    GodLikeRequestTeleport,
+    RequestLure,
+    GotoUrl,
+    FromTaskAsAlert,
+    GroupNotice,
+    GroupNoticeInventoryAccepted,
+    GroupNoticeInventoryDeclined,
+    GroupInvitationAccept,
+    GroupInvitationDecline,
+    GroupNoticeRequested,
+    FriendshipOffered,
+    FriendshipAccepted,
+    FriendshipDeclined,
+    StartTyping,
+    StopTyping,
+    Unknown(u8),
+}
+impl InstantMessageDialog {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => InstantMessageDialog::MessageFromAgent,
+            1 => InstantMessageDialog::MessageBox,
+            3 => InstantMessageDialog::GroupInvitation,
+            4 => InstantMessageDialog::InventoryOffered,
+            5 => InstantMessageDialog::InventoryAccepted,
+            6 => InstantMessageDialog::InventoryDeclined,
+            7 => InstantMessageDialog::GroupVote,
+            9 => InstantMessageDialog::TaskInventoryOffered,
+            10 => InstantMessageDialog::TaskInventoryAccepted,
+            11 => InstantMessageDialog::TaskInventoryDeclined,
+            12 => InstantMessageDialog::NewUserDefault,
+            13 => InstantMessageDialog::SessionAdd,
+            14 => InstantMessageDialog::SessionOfflineAdd,
+            15 => InstantMessageDialog::SessionGroupStart,
+            16 => InstantMessageDialog::SessionCardlessStart,
+            17 => InstantMessageDialog::SessionSend,
+            18 => InstantMessageDialog::SessionDrop,
+            19 => InstantMessageDialog::MessageFromObject,
+            20 => InstantMessageDialog::BusyAutoResponse,
+            21 => InstantMessageDialog::ConsoleAndChatHistory,
+            22 => InstantMessageDialog::RequestTeleport,
+            23 => InstantMessageDialog::AcceptTeleport,
+            24 => InstantMessageDialog::DenyTeleport,
+            25 => InstantMessageDialog::GodLikeRequestTeleport,
+            26 => InstantMessageDialog::RequestLure,
+            28 => InstantMessageDialog::GotoUrl,
+            31 => InstantMessageDialog::FromTaskAsAlert,
+            32 => InstantMessageDialog::GroupNotice,
+            33 => InstantMessageDialog::GroupNoticeInventoryAccepted,
+            34 => InstantMessageDialog::GroupNoticeInventoryDeclined,
+            35 => InstantMessageDialog::GroupInvitationAccept,
+            36 => InstantMessageDialog::GroupInvitationDecline,
+            37 => InstantMessageDialog::GroupNoticeRequested,
+            38 => InstantMessageDialog::FriendshipOffered,
+            39 => InstantMessageDialog::FriendshipAccepted,
+            40 => InstantMessageDialog::FriendshipDeclined,
+            41 => InstantMessageDialog::StartTyping,
+            42 => InstantMessageDialog::StopTyping,
+            byte => InstantMessageDialog::Unknown(byte),
+        }
+    }
+    /// whether the message offers friendship, or answers an offer
+    pub fn is_friendship(&self) -> bool {
+        matches!(
+            self,
+            InstantMessageDialog::FriendshipOffered
+                | InstantMessageDialog::FriendshipAccepted
+                | InstantMessageDialog::FriendshipDeclined
+        )
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            InstantMessageDialog::MessageFromAgent => 0,
+            InstantMessageDialog::MessageBox => 1,
+            InstantMessageDialog::GroupInvitation => 3,
+            InstantMessageDialog::InventoryOffered => 4,
+            InstantMessageDialog::InventoryAccepted => 5,
+            InstantMessageDialog::InventoryDeclined => 6,
+            InstantMessageDialog::GroupVote => 7,
+            InstantMessageDialog::TaskInventoryOffered => 9,
+            InstantMessageDialog::TaskInventoryAccepted => 10,
+            InstantMessageDialog::TaskInventoryDeclined => 11,
+            InstantMessageDialog::NewUserDefault => 12,
+            InstantMessageDialog::SessionAdd => 13,
+            InstantMessageDialog::SessionOfflineAdd => 14,
+            InstantMessageDialog::SessionGroupStart => 15,
+            InstantMessageDialog::SessionCardlessStart => 16,
+            InstantMessageDialog::SessionSend => 17,
+            InstantMessageDialog::SessionDrop => 18,
+            InstantMessageDialog::MessageFromObject => 19,
+            InstantMessageDialog::BusyAutoResponse => 20,
+            InstantMessageDialog::ConsoleAndChatHistory => 21,
+            InstantMessageDialog::RequestTeleport => 22,
+            InstantMessageDialog::AcceptTeleport => 23,
+            InstantMessageDialog::DenyTeleport => 24,
+            InstantMessageDialog::GodLikeRequestTeleport => 25,
+            InstantMessageDialog::RequestLure => 26,
+            InstantMessageDialog::GotoUrl => 28,
+            InstantMessageDialog::FromTaskAsAlert => 31,
+            InstantMessageDialog::GroupNotice => 32,
+            InstantMessageDialog::GroupNoticeInventoryAccepted => 33,
+            InstantMessageDialog::GroupNoticeInventoryDeclined => 34,
+            InstantMessageDialog::GroupInvitationAccept => 35,
+            InstantMessageDialog::GroupInvitationDecline => 36,
+            InstantMessageDialog::GroupNoticeRequested => 37,
+            InstantMessageDialog::FriendshipOffered => 38,
+            InstantMessageDialog::FriendshipAccepted => 39,
+            InstantMessageDialog::FriendshipDeclined => 40,
+            InstantMessageDialog::StartTyping => 41,
+            InstantMessageDialog::StopTyping => 42,
+            InstantMessageDialog::Unknown(byte) => *byte,
+        }
+    }
+}
+
+impl PacketData for ImprovedInstantMessage {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AgentData
+        let from_agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+
+        // MessageBlock
+        let from_group = cursor.read_u8()? != 0;
+        let to_agent_id = read_uuid(&mut cursor)?;
+        let parent_estate_id = cursor.read_u32::<LittleEndian>()?;
+        let region_id = read_uuid(&mut cursor)?;
+        let position = read_vec3(&mut cursor)?;
+        let offline = cursor.read_u8()?;
+        let dialog = InstantMessageDialog::from_bytes(cursor.read_u8()?);
+        let id = read_uuid(&mut cursor)?;
+        let timestamp = cursor.read_u32::<LittleEndian>()?;
+        let from_agent_name = read_string_1(&mut cursor)?;
+        let message = read_string_2(&mut cursor)?;
+        let binary_bucket = read_variable_2(&mut cursor)?;
+
+        Ok(ImprovedInstantMessage {
+            from_agent_id,
+            session_id,
+            from_group,
+            to_agent_id,
+            parent_estate_id,
+            region_id,
+            position,
+            offline,
+            dialog,
+            id,
+            timestamp,
+            from_agent_name,
+            message,
+            binary_bucket,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(self.from_agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+
+        bytes.push(self.from_group as u8);
+        bytes.extend_from_slice(self.to_agent_id.as_bytes());
+        bytes.extend_from_slice(&self.parent_estate_id.to_le_bytes());
+        bytes.extend_from_slice(self.region_id.as_bytes());
+        write_vec3(&mut bytes, &self.position);
+        bytes.push(self.offline);
+        bytes.push(self.dialog.to_bytes());
+        bytes.extend_from_slice(self.id.as_bytes());
+        bytes.extend_from_slice(&self.timestamp.to_le_bytes());
+        write_string_1(&mut bytes, &self.from_agent_name);
+        write_string_2(&mut bytes, &self.message);
+        write_variable_2(&mut bytes, &self.binary_bucket);
+
+        bytes
+    }
+}
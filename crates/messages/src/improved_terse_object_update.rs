@@ -0,0 +1,194 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_variable_1, read_variable_2, read_vec3, write_variable_1, write_variable_2, write_vec3,
+};
+use crate::utils::quantize::{
+    read_quantized_quat, read_quantized_vec3, write_quantized_quat, write_quantized_vec3,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::{Quat, Vec3, Vec4};
+use std::io::{self, Cursor};
+
+// ID: 15
+// Frequency: High
+
+// the ranges the quantized motion values are packed into
+pub const VELOCITY_RANGE: (f32, f32) = (-128.0, 128.0);
+pub const ACCELERATION_RANGE: (f32, f32) = (-64.0, 64.0);
+pub const ANGULAR_VELOCITY_RANGE: (f32, f32) = (-64.0, 64.0);
+
+// the length of the packed Data blob for prims and avatars. Avatars prepend a collision plane.
+pub const PRIM_DATA_LENGTH: usize = 44;
+pub const AVATAR_DATA_LENGTH: usize = 60;
+
+impl Packet {
+    pub fn new_improved_terse_object_update(
+        improved_terse_object_update: ImprovedTerseObjectUpdate,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 15,
+                frequency: PacketFrequency::High,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ImprovedTerseObjectUpdate(Box::new(improved_terse_object_update)),
+        }
+    }
+}
+
+/// Compact movement updates for objects and avatars that are already known from an ObjectUpdate.
+/// https://wiki.secondlife.com/wiki/ImprovedTerseObjectUpdate
+#[derive(Debug, Clone)]
+pub struct ImprovedTerseObjectUpdate {
+    pub region_handle: u64,
+    pub time_dilation: u16,
+    pub objects: Vec<TerseObjectUpdate>,
+}
+
+/// One decoded ObjectData block of an ImprovedTerseObjectUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerseObjectUpdate {
+    pub local_id: u32,
+    pub state: u8,
+    /// only sent for avatars
+    pub collision_plane: Option<Vec4>,
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub acceleration: Vec3,
+    pub rotation: Quat,
+    pub angular_velocity: Vec3,
+    pub texture_entry: Vec<u8>,
+}
+impl TerseObjectUpdate {
+    pub fn is_avatar(&self) -> bool {
+        self.collision_plane.is_some()
+    }
+
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        let data = read_variable_1(cursor)?;
+        let texture_entry = read_variable_2(cursor)?;
+
+        let mut data_cursor = Cursor::new(data.as_slice());
+        let local_id = data_cursor.read_u32::<LittleEndian>()?;
+        let state = data_cursor.read_u8()?;
+        // the avatar flag is sent as well, but the length of the blob is what decides the layout
+        let _is_avatar = data_cursor.read_u8()?;
+        let collision_plane = match data.len() {
+            PRIM_DATA_LENGTH => None,
+            AVATAR_DATA_LENGTH => Some(Vec4::new(
+                data_cursor.read_f32::<LittleEndian>()?,
+                data_cursor.read_f32::<LittleEndian>()?,
+                data_cursor.read_f32::<LittleEndian>()?,
+                data_cursor.read_f32::<LittleEndian>()?,
+            )),
+            length => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Unsupported terse update length: {}", length),
+                ))
+            }
+        };
+        let position = read_vec3(&mut data_cursor)?;
+        let velocity = read_quantized_vec3(&mut data_cursor, VELOCITY_RANGE.0, VELOCITY_RANGE.1)?;
+        let acceleration =
+            read_quantized_vec3(&mut data_cursor, ACCELERATION_RANGE.0, ACCELERATION_RANGE.1)?;
+        let rotation = read_quantized_quat(&mut data_cursor)?;
+        let angular_velocity = read_quantized_vec3(
+            &mut data_cursor,
+            ANGULAR_VELOCITY_RANGE.0,
+            ANGULAR_VELOCITY_RANGE.1,
+        )?;
+
+        Ok(TerseObjectUpdate {
+            local_id,
+            state,
+            collision_plane,
+            position,
+            velocity,
+            acceleration,
+            rotation,
+            angular_velocity,
+            texture_entry,
+        })
+    }
+
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        let mut data = Vec::with_capacity(AVATAR_DATA_LENGTH);
+        data.extend_from_slice(&self.local_id.to_le_bytes());
+        data.push(self.state);
+        data.push(self.is_avatar() as u8);
+        if let Some(plane) = self.collision_plane {
+            for value in plane.to_array() {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        write_vec3(&mut data, &self.position);
+        write_quantized_vec3(
+            &mut data,
+            &self.velocity,
+            VELOCITY_RANGE.0,
+            VELOCITY_RANGE.1,
+        );
+        write_quantized_vec3(
+            &mut data,
+            &self.acceleration,
+            ACCELERATION_RANGE.0,
+            ACCELERATION_RANGE.1,
+        );
+        write_quantized_quat(&mut data, &self.rotation);
+        write_quantized_vec3(
+            &mut data,
+            &self.angular_velocity,
+            ANGULAR_VELOCITY_RANGE.0,
+            ANGULAR_VELOCITY_RANGE.1,
+        );
+
+        write_variable_1(bytes, &data);
+        write_variable_2(bytes, &self.texture_entry);
+    }
+}
+
+impl PacketData for ImprovedTerseObjectUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // RegionData
+        let region_handle = cursor.read_u64::<LittleEndian>()?;
+        let time_dilation = cursor.read_u16::<LittleEndian>()?;
+
+        // ObjectData
+        let count = cursor.read_u8()? as usize;
+        let mut objects = Vec::with_capacity(count);
+        for _ in 0..count {
+            objects.push(TerseObjectUpdate::from_bytes(&mut cursor)?);
+        }
+
+        Ok(ImprovedTerseObjectUpdate {
+            region_handle,
+            time_dilation,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.region_handle.to_le_bytes());
+        bytes.extend_from_slice(&self.time_dilation.to_le_bytes());
+        bytes.push(self.objects.len() as u8);
+        for object in &self.objects {
+            object.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,144 @@
+use crate::packet_types::PacketType;
+use crate::utils::inventory_item::ItemData;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 278
+// Frequency: Low
+
+impl Packet {
+    pub fn new_inventory_descendents(inventory_descendents: InventoryDescendents) -> Self {
+        Packet {
+            header: Header {
+                id: 278,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::InventoryDescendents(Box::new(inventory_descendents)),
+        }
+    }
+}
+
+/// Answers a FetchInventoryDescendents with the contents of a folder. Folders too big for one
+/// packet are split over several, and descendents tells how many folders and items there are
+/// in total.
+/// The simulator sends a single block with a nil ID when there are no folders or no items,
+/// which is skipped when reading.
+/// https://wiki.secondlife.com/wiki/InventoryDescendents
+#[derive(Debug, Clone, PartialEq)]
+pub struct InventoryDescendents {
+    pub agent_id: Uuid,
+    pub folder_id: Uuid,
+    pub owner_id: Uuid,
+    /// goes up whenever the contents of the folder change
+    pub version: i32,
+    /// how many folders and items are in the folder, over every packet of the reply
+    pub descendents: i32,
+    pub folders: Vec<InventoryFolder>,
+    pub items: Vec<ItemData>,
+}
+
+/// a folder inside the fetched folder
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventoryFolder {
+    pub folder_id: Uuid,
+    pub parent_id: Uuid,
+    /// the viewer's LLFolderType, like 8 for the root folder or 46 for the current outfit. -1
+    /// for folders the agent made.
+    pub folder_type: i8,
+    pub name: String,
+}
+
+impl InventoryFolder {
+    /// read a folder block, which CreateInventoryFolder lays out the same way
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        Ok(InventoryFolder {
+            folder_id: read_uuid(cursor)?,
+            parent_id: read_uuid(cursor)?,
+            folder_type: cursor.read_i8()?,
+            name: read_string_1(cursor)?,
+        })
+    }
+
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(self.folder_id.as_bytes());
+        bytes.extend_from_slice(self.parent_id.as_bytes());
+        bytes.push(self.folder_type as u8);
+        write_string_1(bytes, &self.name);
+    }
+}
+
+impl PacketData for InventoryDescendents {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let folder_id = read_uuid(&mut cursor)?;
+        let owner_id = read_uuid(&mut cursor)?;
+        let version = cursor.read_i32::<LittleEndian>()?;
+        let descendents = cursor.read_i32::<LittleEndian>()?;
+
+        let folder_count = cursor.read_u8()?;
+        let mut folders = Vec::with_capacity(folder_count as usize);
+        for _ in 0..folder_count {
+            let folder = InventoryFolder::from_bytes(&mut cursor)?;
+            if !folder.folder_id.is_nil() {
+                folders.push(folder);
+            }
+        }
+
+        let item_count = cursor.read_u8()?;
+        let mut items = Vec::with_capacity(item_count as usize);
+        for _ in 0..item_count {
+            let item = ItemData::from_bytes(&mut cursor)?;
+            if !item.item.item_id.is_nil() {
+                items.push(item);
+            }
+        }
+
+        Ok(InventoryDescendents {
+            agent_id,
+            folder_id,
+            owner_id,
+            version,
+            descendents,
+            folders,
+            items,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(58 + self.items.len() * 150);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.folder_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bytes.extend_from_slice(&self.descendents.to_le_bytes());
+
+        let folders = &self.folders[..self.folders.len().min(u8::MAX as usize)];
+        bytes.push(folders.len() as u8);
+        for folder in folders {
+            folder.to_bytes(&mut bytes);
+        }
+
+        let items = &self.items[..self.items.len().min(u8::MAX as usize)];
+        bytes.push(items.len() as u8);
+        for item in items {
+            item.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::inventory_descendents::InventoryFolder;
+use crate::utils::inventory_item::InventoryItem;
+
+/// The folders and items directly inside an inventory folder, sent from the session to the UI
+/// for each folder of a fetched inventory tree, once every packet of its InventoryDescendents
+/// has arrived. Folders arrive breadth first, so a folder is always sent after its parent.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InventoryFolderContents {
+    pub folder_id: Uuid,
+    pub owner_id: Uuid,
+    /// goes up whenever the contents of the folder change
+    pub version: i32,
+    pub folders: Vec<InventoryFolder>,
+    pub items: Vec<InventoryItem>,
+}
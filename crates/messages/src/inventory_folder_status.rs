@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::inventory_descendents::InventoryFolder;
+
+/// Sent from the session to the UI for each change the agent makes to its inventory folders,
+/// straight away, before the simulator has made it.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InventoryFolderStatus {
+    /// a folder was made, with the id the session picked for it
+    Created(InventoryFolder),
+    /// a folder was moved into another folder
+    Moved { folder_id: Uuid, parent_id: Uuid },
+    /// a folder was deleted with everything inside it
+    Removed { folder_id: Uuid },
+    /// everything inside a folder was deleted
+    Purged { folder_id: Uuid },
+    /// a purge was refused, because it wasn't confirmed
+    PurgeNotConfirmed { folder_id: Uuid },
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::inventory_item::InventoryItem;
+
+/// Sent from the session to the UI when an item it asked for with CreateInventoryItem is made,
+/// or the simulator never answers. The callback id is the one the UI sent in its request.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventoryItemCreated {
+    pub callback_id: u32,
+    /// the new item, None if it wasn't made
+    pub item: Option<InventoryItem>,
+    /// human readable reason the item wasn't made
+    pub reason: Option<String>,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::inventory_item::InventoryItem;
+
+/// Sent from the session to the UI for each item it asked for with a FetchInventory, once the
+/// item arrives, or once the simulator has had long enough to answer. Items the agent changes,
+/// moves or removes are sent straight away, before the simulator confirms them.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InventoryItemStatus {
+    /// the item arrived
+    Found(InventoryItem),
+    /// the simulator didn't send the item, because it doesn't exist or isn't the agent's to see
+    NotFound { item_id: Uuid },
+    /// the agent removed the item
+    Removed { item_id: Uuid },
+}
@@ -0,0 +1,62 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 344
+// Frequency: Low
+
+impl Packet {
+    pub fn new_join_group_reply(join_group_reply: JoinGroupReply) -> Self {
+        Packet {
+            header: Header {
+                id: 344,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::JoinGroupReply(Box::new(join_group_reply)),
+        }
+    }
+}
+
+/// The answer to a JoinGroupRequest.
+/// https://wiki.secondlife.com/wiki/JoinGroupReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JoinGroupReply {
+    pub agent_id: Uuid,
+    pub group_id: Uuid,
+    /// whether the agent is now a member of the group
+    pub success: bool,
+}
+
+impl PacketData for JoinGroupReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(JoinGroupReply {
+            agent_id: read_uuid(&mut cursor)?,
+            group_id: read_uuid(&mut cursor)?,
+            success: cursor.read_u8()? != 0,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(33);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes.push(self.success as u8);
+        bytes
+    }
+}
@@ -0,0 +1,72 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 343
+// Frequency: Low
+
+impl Packet {
+    pub fn new_join_group_request(join_group_request: JoinGroupRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 343,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::JoinGroupRequest(Box::new(join_group_request)),
+        }
+    }
+}
+
+/// Asks to join a group. Only open groups, or groups the agent was invited to, can be joined,
+/// and the simulator answers with a JoinGroupReply.
+/// https://wiki.secondlife.com/wiki/JoinGroupRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinGroupRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group to join
+    pub group_id: Uuid,
+}
+
+impl JoinGroupRequest {
+    /// join a group. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(group_id: Uuid) -> Self {
+        JoinGroupRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            group_id,
+        }
+    }
+}
+
+impl PacketData for JoinGroupRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(JoinGroupRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            group_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,81 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_2, read_uuid, write_string_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{BigEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use std::net::Ipv4Addr;
+use uuid::Uuid;
+
+// ID: 163
+// Frequency: Low
+
+impl Packet {
+    pub fn new_kick_user(kick_user: KickUser) -> Self {
+        Packet {
+            header: Header {
+                id: 163,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::KickUser(Box::new(kick_user)),
+        }
+    }
+}
+
+/// Sent by the simulator when the agent is being forcibly removed, for example by an estate
+/// manager or because the region is restarting. The circuit is closed afterwards.
+/// https://wiki.secondlife.com/wiki/KickUser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KickUser {
+    /// address of the circuit being kicked
+    pub target_ip: Ipv4Addr,
+    pub target_port: u16,
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// human readable reason shown to the user
+    pub reason: String,
+}
+
+impl PacketData for KickUser {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // TargetBlock. IP addresses and ports are sent in network byte order.
+        let target_ip = Ipv4Addr::from(cursor.read_u32::<BigEndian>()?);
+        let target_port = cursor.read_u16::<BigEndian>()?;
+
+        // UserInfo
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let reason = read_string_2(&mut cursor)?;
+
+        Ok(KickUser {
+            target_ip,
+            target_port,
+            agent_id,
+            session_id,
+            reason,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(40 + self.reason.len() + 1);
+        bytes.extend_from_slice(&self.target_ip.octets());
+        bytes.extend_from_slice(&self.target_port.to_be_bytes());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        write_string_2(&mut bytes, &self.reason);
+        bytes
+    }
+}
@@ -0,0 +1,64 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 16
+// Frequency: High
+
+impl Packet {
+    pub fn new_kill_object(kill_object: KillObjectData) -> Self {
+        Packet {
+            header: Header {
+                id: 16,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::KillObject(Box::new(kill_object)),
+        }
+    }
+}
+
+/// Sent by the simulator when objects are derezzed or avatars leave the region.
+/// Any object with one of these local IDs should be removed from the scene.
+/// https://wiki.secondlife.com/wiki/KillObject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KillObjectData {
+    pub local_ids: Vec<u32>,
+}
+
+impl PacketData for KillObjectData {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // ObjectData is a variable block, prefixed with the number of blocks
+        let count = cursor.read_u8()? as usize;
+        let mut local_ids = Vec::with_capacity(count);
+        for _ in 0..count {
+            local_ids.push(cursor.read_u32::<LittleEndian>()?);
+        }
+
+        Ok(KillObjectData { local_ids })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.local_ids.len() * 4);
+        bytes.push(self.local_ids.len() as u8);
+        for local_id in &self.local_ids {
+            bytes.extend_from_slice(&local_id.to_le_bytes());
+        }
+        bytes
+    }
+}
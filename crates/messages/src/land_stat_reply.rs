@@ -0,0 +1,112 @@
+use crate::packet_types::PacketType;
+use crate::utils::land_stat::{LandStatEntry, LandStatFlags, LandStatReportType};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+
+// ID: 422
+// Frequency: Low
+
+impl Packet {
+    pub fn new_land_stat_reply(land_stat_reply: LandStatReply) -> Self {
+        Packet {
+            header: Header {
+                id: 422,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::LandStatReply(Box::new(land_stat_reply)),
+        }
+    }
+}
+
+/// One page of a report asked for with a LandStatRequest. A report with many objects is split
+/// over several replies, which all carry the total number of objects in the report.
+/// Newer simulators send a DataExtended block for each entry after the entries. Older ones
+/// don't send it at all.
+/// https://wiki.secondlife.com/wiki/LandStatReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandStatReply {
+    pub report_type: LandStatReportType,
+    pub request_flags: LandStatFlags,
+    /// how many objects the whole report has
+    pub total_object_count: u32,
+    pub entries: Vec<LandStatEntry>,
+    /// the DataExtended blocks, one for each entry in the same order, if the simulator sent them
+    pub extended: Vec<LandStatExtended>,
+}
+
+/// the DataExtended block of an entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandStatExtended {
+    /// when the object was last measured, in seconds since the epoch
+    pub time_stamp: u32,
+    /// the time its Mono scripts take in milliseconds
+    pub mono_score: f32,
+}
+
+impl PacketData for LandStatReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let report_type = LandStatReportType::from_bytes(cursor.read_u32::<LittleEndian>()?);
+        let request_flags = LandStatFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?);
+        let total_object_count = cursor.read_u32::<LittleEndian>()?;
+        let count = cursor.read_u8()?;
+        let mut entries = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            entries.push(LandStatEntry::from_bytes(&mut cursor)?);
+        }
+
+        let count = if (cursor.position() as usize) < bytes.len() {
+            cursor.read_u8()?
+        } else {
+            0
+        };
+        let mut extended = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            extended.push(LandStatExtended {
+                time_stamp: cursor.read_u32::<LittleEndian>()?,
+                mono_score: cursor.read_f32::<LittleEndian>()?,
+            });
+        }
+
+        Ok(LandStatReply {
+            report_type,
+            request_flags,
+            total_object_count,
+            entries,
+            extended,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let entries = &self.entries[..self.entries.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.report_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.request_flags.bits().to_le_bytes());
+        bytes.extend_from_slice(&self.total_object_count.to_le_bytes());
+        bytes.push(entries.len() as u8);
+        for entry in entries {
+            bytes.extend(entry.to_bytes());
+        }
+        if !self.extended.is_empty() {
+            let extended = &self.extended[..self.extended.len().min(u8::MAX as usize)];
+            bytes.push(extended.len() as u8);
+            for block in extended {
+                bytes.extend_from_slice(&block.time_stamp.to_le_bytes());
+                bytes.extend_from_slice(&block.mono_score.to_le_bytes());
+            }
+        }
+        bytes
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::land_stat::{LandStatEntry, LandStatReportType};
+
+/// A whole report asked for with a LandStatRequest from the UI, gathered from all of its
+/// LandStatReply pages and sent from the session to the UI. The entries are ranked by score,
+/// highest first.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LandStatReport {
+    pub report_type: LandStatReportType,
+    /// how many objects the simulator said the report has
+    pub total_object_count: u32,
+    /// the objects of the report, highest score first
+    pub entries: Vec<LandStatEntry>,
+    /// false if the report stopped getting pages before all of its objects arrived
+    pub complete: bool,
+}
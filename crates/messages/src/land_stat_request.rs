@@ -0,0 +1,93 @@
+use crate::packet_types::PacketType;
+use crate::utils::land_stat::{LandStatFlags, LandStatReportType};
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 421
+// Frequency: Low
+
+impl Packet {
+    pub fn new_land_stat_request(land_stat_request: LandStatRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 421,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::LandStatRequest(Box::new(land_stat_request)),
+        }
+    }
+}
+
+/// Asks for a report of the objects slowing the region down, for region owners and estate
+/// managers. The simulator answers with one or more LandStatReply packets.
+/// https://wiki.secondlife.com/wiki/LandStatRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandStatRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub report_type: LandStatReportType,
+    /// what the filter is matched against
+    pub request_flags: LandStatFlags,
+    pub filter: String,
+    /// the parcel to report on, with FILTER_BY_PARCEL
+    pub parcel_local_id: i32,
+}
+
+impl LandStatRequest {
+    /// ask for a report, only including objects whose name contains the filter if it isn't
+    /// empty. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(report_type: LandStatReportType, filter: String) -> Self {
+        let request_flags = if filter.is_empty() {
+            LandStatFlags::empty()
+        } else {
+            LandStatFlags::FILTER_BY_OBJECT
+        };
+        LandStatRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            report_type,
+            request_flags,
+            filter,
+            parcel_local_id: 0,
+        }
+    }
+}
+
+impl PacketData for LandStatRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(LandStatRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            report_type: LandStatReportType::from_bytes(cursor.read_u32::<LittleEndian>()?),
+            request_flags: LandStatFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?),
+            filter: read_string_1(&mut cursor)?,
+            parcel_local_id: cursor.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.report_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.request_flags.bits().to_le_bytes());
+        write_string_1(&mut bytes, &self.filter);
+        bytes.extend_from_slice(&self.parcel_local_id.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,396 @@
+use crate::packet_types::PacketType;
+use crate::utils::bit_reader::BitReader;
+use crate::utils::layer_patch::{decompress_patch, read_coefficients, GroupHeader, PatchHeader};
+use crate::utils::packet_fields::{read_variable_2, write_variable_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec2;
+use std::io::{self, Cursor};
+
+// ID: 11
+// Frequency: High
+
+impl Packet {
+    pub fn new_layer_data(layer_data: LayerData) -> Self {
+        Packet {
+            header: Header {
+                id: 11,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::LayerData(Box::new(layer_data)),
+        }
+    }
+}
+
+/// the kinds of layer a LayerData packet can contain
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LayerType {
+    Land,
+    Water,
+    Wind,
+    Cloud,
+    Unknown(u8),
+}
+impl LayerType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            b'L' => LayerType::Land,
+            b'W' => LayerType::Water,
+            b'7' => LayerType::Wind,
+            b'8' => LayerType::Cloud,
+            byte => LayerType::Unknown(byte),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            LayerType::Land => b'L',
+            LayerType::Water => b'W',
+            LayerType::Wind => b'7',
+            LayerType::Cloud => b'8',
+            LayerType::Unknown(byte) => *byte,
+        }
+    }
+}
+
+/// Compressed terrain, water, wind and cloud data for the region. The data is a group of DCT
+/// compressed patches, which are decoded on demand.
+/// https://wiki.secondlife.com/wiki/LayerData
+#[derive(Debug, Clone)]
+pub struct LayerData {
+    pub layer_type: LayerType,
+    /// the bit packed patches
+    pub data: Vec<u8>,
+}
+
+impl LayerData {
+    /// decode the patches of a Land layer into heights
+    pub fn terrain_patches(&self) -> io::Result<Vec<TerrainPatch>> {
+        Ok(self
+            .decode_patches(LayerType::Land, TerrainPatch::SIZE)?
+            .into_iter()
+            .map(|(header, values)| {
+                let mut heights = [0.0; 256];
+                heights.copy_from_slice(&values);
+                TerrainPatch {
+                    x: header.x(),
+                    y: header.y(),
+                    heights,
+                }
+            })
+            .collect())
+    }
+
+    /// decode the patches of a Wind layer into wind vectors.
+    /// Wind is sent as pairs of patches, the first holding the x component and the second the y
+    /// component.
+    pub fn wind_patches(&self) -> io::Result<Vec<WindPatch>> {
+        let patches = self.decode_patches(LayerType::Wind, WindPatch::SIZE)?;
+        if patches.len() % 2 != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Wind layer is missing the y component of a patch",
+            ));
+        }
+        Ok(patches
+            .chunks_exact(2)
+            .map(|pair| {
+                let (header, x_values) = &pair[0];
+                let (_, y_values) = &pair[1];
+                let mut velocities = [Vec2::ZERO; 256];
+                for (i, velocity) in velocities.iter_mut().enumerate() {
+                    *velocity = Vec2::new(x_values[i], y_values[i]);
+                }
+                WindPatch {
+                    x: header.x(),
+                    y: header.y(),
+                    velocities,
+                }
+            })
+            .collect())
+    }
+
+    /// decode the patches of a Cloud layer into cloud densities
+    pub fn cloud_patches(&self) -> io::Result<Vec<CloudPatch>> {
+        Ok(self
+            .decode_patches(LayerType::Cloud, CloudPatch::SIZE)?
+            .into_iter()
+            .map(|(header, values)| {
+                let mut density = [0.0; 256];
+                density.copy_from_slice(&values);
+                CloudPatch {
+                    x: header.x(),
+                    y: header.y(),
+                    density,
+                }
+            })
+            .collect())
+    }
+
+    // every layer uses the same patch bit stream, only the patch size and what the values mean
+    // are different
+    fn decode_patches(
+        &self,
+        layer_type: LayerType,
+        patch_size: u32,
+    ) -> io::Result<Vec<(PatchHeader, Vec<f32>)>> {
+        if self.layer_type != layer_type {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{:?} layer does not contain {:?} patches",
+                    self.layer_type, layer_type
+                ),
+            ));
+        }
+        let mut reader = BitReader::new(&self.data);
+        let group = GroupHeader::read(&mut reader)?;
+        if group.patch_size != patch_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid {:?} patch size: {}", layer_type, group.patch_size),
+            ));
+        }
+
+        let mut patches = Vec::new();
+        while let Some(header) = PatchHeader::read(&mut reader)? {
+            // every layer places its patches on the terrain grid
+            if header.x() >= TerrainPatch::PATCHES_PER_EDGE
+                || header.y() >= TerrainPatch::PATCHES_PER_EDGE
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "Invalid {:?} patch: {}, {}",
+                        layer_type,
+                        header.x(),
+                        header.y()
+                    ),
+                ));
+            }
+            let coefficients = read_coefficients(&mut reader, &header, group.patch_size)?;
+            let values = decompress_patch(&coefficients, &header, group.patch_size);
+            patches.push((header, values));
+        }
+        Ok(patches)
+    }
+}
+
+impl PacketData for LayerData {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let layer_type = LayerType::from_bytes(cursor.read_u8()?);
+        let data = read_variable_2(&mut cursor)?;
+        Ok(LayerData { layer_type, data })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(3 + self.data.len());
+        bytes.push(self.layer_type.to_bytes());
+        write_variable_2(&mut bytes, &self.data);
+        bytes
+    }
+}
+
+/// a 16x16 square of terrain heights
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerrainPatch {
+    /// position of the patch in the region, in patches
+    pub x: u32,
+    pub y: u32,
+    /// heights in meters, in rows of 16 from south to north
+    pub heights: [f32; 256],
+}
+impl TerrainPatch {
+    pub const SIZE: u32 = 16;
+    pub const PATCHES_PER_EDGE: u32 = 16;
+    /// x, y and the heights
+    pub const LENGTH: usize = 8 + 256 * 4;
+
+    pub fn height(&self, x: usize, y: usize) -> f32 {
+        self.heights[y * Self::SIZE as usize + x]
+    }
+}
+
+/// Decoded terrain patches sent to the UI. A region is 256 patches, so these are sent in a
+/// compact binary form instead of JSON.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerrainPatches {
+    pub patches: Vec<TerrainPatch>,
+}
+
+impl PacketData for TerrainPatches {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u16::<LittleEndian>()? as usize;
+        if bytes.len() != 2 + count * TerrainPatch::LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid byte length for TerrainPatches: {}", bytes.len()),
+            ));
+        }
+        let mut patches = Vec::with_capacity(count);
+        for _ in 0..count {
+            let x = cursor.read_u32::<LittleEndian>()?;
+            let y = cursor.read_u32::<LittleEndian>()?;
+            let mut heights = [0.0; 256];
+            cursor.read_f32_into::<LittleEndian>(&mut heights)?;
+            patches.push(TerrainPatch { x, y, heights });
+        }
+        Ok(TerrainPatches { patches })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.patches.len() * TerrainPatch::LENGTH);
+        bytes.extend_from_slice(&(self.patches.len() as u16).to_le_bytes());
+        for patch in &self.patches {
+            bytes.extend_from_slice(&patch.x.to_le_bytes());
+            bytes.extend_from_slice(&patch.y.to_le_bytes());
+            for height in patch.heights {
+                bytes.extend_from_slice(&height.to_le_bytes());
+            }
+        }
+        bytes
+    }
+}
+
+/// a 16x16 grid of wind vectors. Unlike terrain, one wind patch covers the whole region, so each
+/// vector is the wind over a 16x16 meter square.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindPatch {
+    /// position of the patch, in patches
+    pub x: u32,
+    pub y: u32,
+    /// wind velocity in meters per second, in rows of 16 from south to north
+    pub velocities: [Vec2; 256],
+}
+impl WindPatch {
+    pub const SIZE: u32 = 16;
+    /// x, y and the two components of each velocity
+    pub const LENGTH: usize = 8 + 256 * 8;
+
+    pub fn velocity(&self, x: usize, y: usize) -> Vec2 {
+        self.velocities[y * Self::SIZE as usize + x]
+    }
+}
+
+/// a 16x16 grid of cloud densities
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloudPatch {
+    /// position of the patch, in patches
+    pub x: u32,
+    pub y: u32,
+    /// cloud density, in rows of 16 from south to north
+    pub density: [f32; 256],
+}
+impl CloudPatch {
+    pub const SIZE: u32 = 16;
+    /// x, y and the densities
+    pub const LENGTH: usize = 8 + 256 * 4;
+
+    pub fn density(&self, x: usize, y: usize) -> f32 {
+        self.density[y * Self::SIZE as usize + x]
+    }
+}
+
+/// Decoded wind patches sent to the UI, in the same binary form as TerrainPatches.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindPatches {
+    pub patches: Vec<WindPatch>,
+}
+
+impl PacketData for WindPatches {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u16::<LittleEndian>()? as usize;
+        if bytes.len() != 2 + count * WindPatch::LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid byte length for WindPatches: {}", bytes.len()),
+            ));
+        }
+        let mut patches = Vec::with_capacity(count);
+        for _ in 0..count {
+            let x = cursor.read_u32::<LittleEndian>()?;
+            let y = cursor.read_u32::<LittleEndian>()?;
+            let mut velocities = [Vec2::ZERO; 256];
+            for velocity in velocities.iter_mut() {
+                *velocity = Vec2::new(
+                    cursor.read_f32::<LittleEndian>()?,
+                    cursor.read_f32::<LittleEndian>()?,
+                );
+            }
+            patches.push(WindPatch { x, y, velocities });
+        }
+        Ok(WindPatches { patches })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.patches.len() * WindPatch::LENGTH);
+        bytes.extend_from_slice(&(self.patches.len() as u16).to_le_bytes());
+        for patch in &self.patches {
+            bytes.extend_from_slice(&patch.x.to_le_bytes());
+            bytes.extend_from_slice(&patch.y.to_le_bytes());
+            for velocity in patch.velocities {
+                bytes.extend_from_slice(&velocity.x.to_le_bytes());
+                bytes.extend_from_slice(&velocity.y.to_le_bytes());
+            }
+        }
+        bytes
+    }
+}
+
+/// Decoded cloud patches sent to the UI, in the same binary form as TerrainPatches.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloudPatches {
+    pub patches: Vec<CloudPatch>,
+}
+
+impl PacketData for CloudPatches {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u16::<LittleEndian>()? as usize;
+        if bytes.len() != 2 + count * CloudPatch::LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid byte length for CloudPatches: {}", bytes.len()),
+            ));
+        }
+        let mut patches = Vec::with_capacity(count);
+        for _ in 0..count {
+            let x = cursor.read_u32::<LittleEndian>()?;
+            let y = cursor.read_u32::<LittleEndian>()?;
+            let mut density = [0.0; 256];
+            cursor.read_f32_into::<LittleEndian>(&mut density)?;
+            patches.push(CloudPatch { x, y, density });
+        }
+        Ok(CloudPatches { patches })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.patches.len() * CloudPatch::LENGTH);
+        bytes.extend_from_slice(&(self.patches.len() as u16).to_le_bytes());
+        for patch in &self.patches {
+            bytes.extend_from_slice(&patch.x.to_le_bytes());
+            bytes.extend_from_slice(&patch.y.to_le_bytes());
+            for density in patch.density {
+                bytes.extend_from_slice(&density.to_le_bytes());
+            }
+        }
+        bytes
+    }
+}
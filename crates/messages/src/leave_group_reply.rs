@@ -0,0 +1,62 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 348
+// Frequency: Low
+
+impl Packet {
+    pub fn new_leave_group_reply(leave_group_reply: LeaveGroupReply) -> Self {
+        Packet {
+            header: Header {
+                id: 348,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::LeaveGroupReply(Box::new(leave_group_reply)),
+        }
+    }
+}
+
+/// The answer to a LeaveGroupRequest.
+/// https://wiki.secondlife.com/wiki/LeaveGroupReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaveGroupReply {
+    pub agent_id: Uuid,
+    pub group_id: Uuid,
+    /// whether the agent is no longer a member of the group
+    pub success: bool,
+}
+
+impl PacketData for LeaveGroupReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(LeaveGroupReply {
+            agent_id: read_uuid(&mut cursor)?,
+            group_id: read_uuid(&mut cursor)?,
+            success: cursor.read_u8()? != 0,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(33);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes.push(self.success as u8);
+        bytes
+    }
+}
@@ -0,0 +1,72 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 347
+// Frequency: Low
+
+impl Packet {
+    pub fn new_leave_group_request(leave_group_request: LeaveGroupRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 347,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::LeaveGroupRequest(Box::new(leave_group_request)),
+        }
+    }
+}
+
+/// Asks to leave a group. The simulator answers with a LeaveGroupReply, and with an
+/// AgentDataUpdate if it was the agent's active group.
+/// https://wiki.secondlife.com/wiki/LeaveGroupRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaveGroupRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group to leave
+    pub group_id: Uuid,
+}
+
+impl LeaveGroupRequest {
+    /// leave a group. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(group_id: Uuid) -> Self {
+        LeaveGroupRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            group_id,
+        }
+    }
+}
+
+impl PacketData for LeaveGroupRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(LeaveGroupRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            group_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes
+    }
+}
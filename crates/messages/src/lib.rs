@@ -1,20 +1,263 @@
+pub mod abort_xfer;
+pub mod accept_friendship;
+pub mod accept_script_teleport;
+pub mod activate_group;
+pub mod agent_animation;
+pub mod agent_data_update;
+pub mod agent_data_update_request;
+pub mod agent_died;
+pub mod agent_fov;
+pub mod agent_height_width;
+pub mod agent_pause;
+pub mod agent_request_sit;
+pub mod agent_resume;
+pub mod agent_set_appearance;
+pub mod agent_sit;
+pub mod agent_throttle;
 pub mod agent_update;
+pub mod agent_wearables_request;
+pub mod agent_wearables_update;
+pub mod asset_received;
+pub mod asset_transfer_failed;
+pub mod asset_upload_complete;
+pub mod asset_upload_request;
+pub mod asset_uploaded;
+pub mod attach_from_inventory;
+pub mod attached_sound;
+pub mod attached_sound_gain_change;
+pub mod attachments;
+pub mod avatar_animation;
+pub mod avatar_appearance;
+pub mod avatar_classified_reply;
+pub mod avatar_groups_reply;
+pub mod avatar_interests_reply;
+pub mod avatar_interests_update;
+pub mod avatar_picker_reply;
+pub mod avatar_picker_request;
+pub mod avatar_picks_reply;
+pub mod avatar_properties_reply;
+pub mod avatar_properties_request;
+pub mod avatar_properties_update;
+pub mod avatar_sit_response;
+pub mod camera_constraint;
+pub mod change_user_rights;
 pub mod chat_from_simulator;
 pub mod chat_from_viewer;
+pub mod chat_pass;
+pub mod child_simulator_event;
 pub mod circuit_code;
+pub mod circuit_stats_report;
+pub mod classified_info_reply;
+pub mod classified_info_request;
+pub mod clear_follow_cam_properties;
 pub mod coarse_location_update;
 pub mod complete_agent_movement;
 pub mod complete_ping_check;
+pub mod confirm_xfer_packet;
+pub mod create_inventory_folder;
+pub mod create_inventory_item;
+pub mod crossed_region;
+pub mod decline_friendship;
+pub mod derez_object;
+pub mod detach_attachment;
+pub mod dir_find_query;
+pub mod dir_people_reply;
+pub mod dir_places_reply;
+pub mod directory_people_page;
+pub mod directory_places_page;
 pub mod disable_simulator;
+pub mod disconnect;
+pub mod economy_data;
+pub mod economy_data_request;
+pub mod enable_simulator;
 pub mod errors;
+pub mod estate_owner_message;
+pub mod event_info_reply;
+pub mod event_info_request;
+pub mod feature_disabled;
+pub mod feature_disabled_notice;
+pub mod fetch_inventory;
+pub mod fetch_inventory_descendents;
+pub mod fetch_inventory_reply;
+pub mod force_script_control_release;
+pub mod friend_list;
+pub mod friendship_status;
+pub mod generic_message;
+pub mod get_script_running;
+pub mod god_kick_user;
+pub mod grant_godlike_powers;
+pub mod grant_user_rights;
+pub mod group_name_resolved;
+pub mod group_profile_reply;
+pub mod group_profile_request;
 pub mod header;
+pub mod health_message;
+pub mod image_data;
+pub mod image_packet;
+pub mod improved_instant_message;
+pub mod improved_terse_object_update;
+pub mod inventory_descendents;
+pub mod inventory_folder_contents;
+pub mod inventory_folder_status;
+pub mod inventory_item_created;
+pub mod inventory_item_status;
+pub mod join_group_reply;
+pub mod join_group_request;
+pub mod kick_user;
+pub mod kill_object;
+pub mod land_stat_reply;
+pub mod land_stat_report;
+pub mod land_stat_request;
+pub mod layer_data;
+pub mod leave_group_reply;
+pub mod leave_group_request;
+pub mod load_url;
 pub mod login_system;
+pub mod logout_reply;
+pub mod logout_request;
+pub mod map_block_reply;
+pub mod map_block_request;
+pub mod map_blocks;
+pub mod map_item_reply;
+pub mod map_item_request;
+pub mod map_items;
+pub mod map_name_request;
+pub mod map_search_empty;
+pub mod map_search_results;
+pub mod money_balance_reply;
+pub mod money_balance_request;
+pub mod move_inventory_folder;
+pub mod move_inventory_item;
+pub mod multiple_object_update;
+pub mod mute_list;
+pub mod mute_list_request;
+pub mod mute_list_update;
+pub mod name_resolved;
+pub mod object_add;
+pub mod object_attach;
+pub mod object_buy;
+pub mod object_category;
+pub mod object_click_action;
+pub mod object_de_grab;
+pub mod object_delete;
+pub mod object_delink;
+pub mod object_description;
+pub mod object_deselect;
+pub mod object_detach;
+pub mod object_drop;
+pub mod object_duplicate;
+pub mod object_duplicate_on_ray;
+pub mod object_edit_ack;
+pub mod object_extra_params;
+pub mod object_family_status;
+pub mod object_flag_update;
+pub mod object_grab;
+pub mod object_grab_update;
+pub mod object_image;
+pub mod object_link;
+pub mod object_material;
+pub mod object_name;
+pub mod object_permissions;
+pub mod object_properties;
+pub mod object_properties_family;
+pub mod object_sale_info;
+pub mod object_select;
+pub mod object_update;
+pub mod offline_notification;
+pub mod online_notification;
 pub mod packet;
 pub mod packet_ack;
 pub mod packet_types;
+pub mod parcel_access_list;
+pub mod parcel_access_list_reply;
+pub mod parcel_access_list_request;
+pub mod parcel_access_list_update;
+pub mod parcel_dwell_reply;
+pub mod parcel_dwell_request;
+pub mod parcel_media_command_message;
+pub mod parcel_media_update;
+pub mod parcel_overlay;
+pub mod parcel_overlay_grid;
+pub mod parcel_properties;
+pub mod parcel_properties_request;
+pub mod people_search_empty;
+pub mod people_search_results;
+pub mod pick_info_reply;
+pub mod pick_info_request;
+pub mod ping_stats;
+pub mod preload_sound;
+pub mod purchase_failed;
+pub mod purge_inventory_descendents;
+pub mod purge_inventory_folder;
+pub mod query_circuit_stats;
+pub mod query_object_family;
+pub mod query_self_agent_info;
+pub mod raw_packet;
+pub mod region_crossed;
 pub mod region_handshake;
 pub mod region_handshake_reply;
+pub mod region_info;
+pub mod region_info_request;
+pub mod remove_inventory_folder;
+pub mod remove_inventory_item;
+pub mod remove_mute_list_entry;
+pub mod report_unknown_packets;
+pub mod request_godlike_powers;
+pub mod request_image;
+pub mod request_object_properties_family;
+pub mod request_xfer;
+pub mod revoke_permissions;
+pub mod rez_object;
+pub mod rez_script;
+pub mod rez_single_attachment_from_inv;
+pub mod script_answer_yes;
+pub mod script_control_change;
+pub mod script_controls;
+pub mod script_dialog;
+pub mod script_dialog_reply;
+pub mod script_question;
+pub mod script_reset;
+pub mod script_running_reply;
+pub mod script_running_status;
+pub mod script_teleport_request;
+pub mod self_agent_info;
+pub mod send_xfer_packet;
+pub mod set_always_run;
+pub mod set_chat_filter;
+pub mod set_extra_params;
+pub mod set_follow_cam_properties;
+pub mod set_script_running;
+pub mod set_velocity_interpolation;
+pub mod sim_stats;
+pub mod simulator_viewer_time_message;
+pub mod sit_status;
+pub mod sound_trigger;
 pub mod start_ping_check;
+pub mod telehub_info;
+pub mod teleport_failed;
+pub mod teleport_finish;
+pub mod teleport_location_request;
+pub mod teleport_progress;
+pub mod teleport_start;
+pub mod terminate_friendship;
+pub mod texture_ready;
+pub mod transfer_info;
+pub mod transfer_packet;
+pub mod transfer_request;
 pub mod ui_events;
+pub mod unknown_packet_notice;
+pub mod update_create_inventory_item;
+pub mod update_inventory_item;
+pub mod update_mute_list_entry;
+pub mod use_cached_mute_list;
 
 pub mod utils;
+pub mod uuid_group_name_reply;
+pub mod uuid_group_name_request;
+pub mod uuid_name_reply;
+pub mod uuid_name_request;
+pub mod velocity_interpolate_off;
+pub mod velocity_interpolate_on;
+pub mod velocity_interpolation_status;
+pub mod viewer_effect;
+pub mod wearables;
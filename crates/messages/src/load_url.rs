@@ -0,0 +1,97 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_string_1_lossy, read_uuid, read_variable_1, write_string_1, write_variable_1,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 194
+// Frequency: Low
+
+impl Packet {
+    pub fn new_load_url(load_url: LoadURL) -> Self {
+        Packet {
+            header: Header {
+                id: 194,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::LoadURL(Box::new(load_url)),
+        }
+    }
+}
+
+/// Sent when a script offers to open a web page, with llLoadURL.
+/// The page should only be opened once the user has agreed to it. Anyone can script an object
+/// to send this, so the owner is kept as it was sent, to show the user where the offer came
+/// from.
+/// Everything in it comes from the script, so the strings are read leniently, with invalid
+/// UTF-8 replaced. The URL is also kept as the bytes that were sent.
+/// https://wiki.secondlife.com/wiki/LoadURL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoadURL {
+    pub object_name: String,
+    pub object_id: Uuid,
+    /// the owner of the object, which is a group if owner_is_group is set
+    pub owner_id: Uuid,
+    pub owner_is_group: bool,
+    /// the message the script shows with the URL
+    pub message: String,
+    /// the URL, with invalid UTF-8 replaced
+    pub url: String,
+    /// the URL as it was sent, without the null terminator
+    pub raw_url: Vec<u8>,
+}
+
+impl PacketData for LoadURL {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let object_name = read_string_1_lossy(&mut cursor)?;
+        let object_id = read_uuid(&mut cursor)?;
+        let owner_id = read_uuid(&mut cursor)?;
+        let owner_is_group = cursor.read_u8()? != 0;
+        let message = read_string_1_lossy(&mut cursor)?;
+        let mut raw_url = read_variable_1(&mut cursor)?;
+        if raw_url.last() == Some(&0) {
+            raw_url.pop();
+        }
+        Ok(LoadURL {
+            object_name,
+            object_id,
+            owner_id,
+            owner_is_group,
+            message,
+            url: String::from_utf8_lossy(&raw_url).into_owned(),
+            raw_url,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_string_1(&mut bytes, &self.object_name);
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.push(self.owner_is_group as u8);
+        write_string_1(&mut bytes, &self.message);
+        // the URL is sent as it arrived, so a URL that isn't valid UTF-8 survives the round trip
+        let mut url = self.raw_url[..self.raw_url.len().min(u8::MAX as usize - 1)].to_vec();
+        if !url.is_empty() {
+            url.push(0);
+        }
+        write_variable_1(&mut bytes, &url);
+        bytes
+    }
+}
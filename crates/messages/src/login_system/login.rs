@@ -166,7 +166,11 @@ impl SimulatorLoginProtocol {
             host_id: "".to_string(),  // Set a default value if needed
             mfa_hash: "".to_string(), // Set a default value if needed
             token: "".to_string(),    // Set a default value if needed
-            options: SimulatorLoginOptions::default(), // Use default options
+            // the buddy list is where the session learns who the agent's friends are
+            options: SimulatorLoginOptions {
+                buddy_list: Some(true),
+                ..Default::default()
+            },
         }
     }
 }
@@ -599,7 +599,7 @@ impl From<BuddyListValues> for Value {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct FriendsRights {
     /// true if friends can see if you are online
     pub can_see_online: bool,
@@ -607,6 +607,35 @@ pub struct FriendsRights {
     pub can_see_on_map: bool,
     pub can_modify_objects: bool,
 }
+impl FriendsRights {
+    pub const ONLINE: i32 = 1;
+    pub const MAP: i32 = 2;
+    pub const MODIFY: i32 = 4;
+
+    /// the rights of a rights mask, like the RelatedRights of GrantUserRights. Unknown bits are
+    /// dropped.
+    pub fn from_bits(bits: i32) -> Self {
+        FriendsRights {
+            can_see_online: bits & Self::ONLINE != 0,
+            can_see_on_map: bits & Self::MAP != 0,
+            can_modify_objects: bits & Self::MODIFY != 0,
+        }
+    }
+
+    pub fn to_bits(&self) -> i32 {
+        let mut bits = 0;
+        if self.can_see_online {
+            bits |= Self::ONLINE;
+        }
+        if self.can_see_on_map {
+            bits |= Self::MAP;
+        }
+        if self.can_modify_objects {
+            bits |= Self::MODIFY;
+        }
+        bits
+    }
+}
 impl From<FriendsRights> for Value {
     fn from(val: FriendsRights) -> Self {
         let mut map = BTreeMap::new();
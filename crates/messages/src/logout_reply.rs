@@ -0,0 +1,74 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 253
+// Frequency: Low
+
+impl Packet {
+    pub fn new_logout_reply(logout_reply: LogoutReply) -> Self {
+        Packet {
+            header: Header {
+                id: 253,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::LogoutReply(Box::new(logout_reply)),
+        }
+    }
+}
+
+/// Sent by the simulator once the agent has been logged out. The circuit can be closed after
+/// this arrives.
+/// https://wiki.secondlife.com/wiki/LogoutReply
+#[derive(Debug, Clone)]
+pub struct LogoutReply {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// inventory items that changed during the session
+    pub item_ids: Vec<Uuid>,
+}
+
+impl PacketData for LogoutReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+
+        let count = cursor.read_u8()? as usize;
+        let mut item_ids = Vec::with_capacity(count);
+        for _ in 0..count {
+            item_ids.push(read_uuid(&mut cursor)?);
+        }
+
+        Ok(LogoutReply {
+            agent_id,
+            session_id,
+            item_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(33 + self.item_ids.len() * 16);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(self.item_ids.len() as u8);
+        for item_id in &self.item_ids {
+            bytes.extend_from_slice(item_id.as_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,58 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 252
+// Frequency: Low
+
+impl Packet {
+    pub fn new_logout_request(logout_request: LogoutRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 252,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::LogoutRequest(Box::new(logout_request)),
+        }
+    }
+}
+
+/// Asks the simulator to log the agent out. The simulator responds with a LogoutReply.
+/// https://wiki.secondlife.com/wiki/LogoutRequest
+#[derive(Debug, Clone)]
+pub struct LogoutRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl PacketData for LogoutRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        Ok(LogoutRequest {
+            agent_id,
+            session_id,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes
+    }
+}
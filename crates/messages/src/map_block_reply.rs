@@ -0,0 +1,147 @@
+use crate::packet_types::PacketType;
+use crate::utils::agent_access::AgentAccess;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+use crate::utils::region_handle::region_handle;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 409
+// Frequency: Low
+
+/// the size of a region, in meters, when the simulator doesn't say
+pub const DEFAULT_REGION_SIZE: u16 = 256;
+
+impl Packet {
+    pub fn new_map_block_reply(map_block_reply: MapBlockReply) -> Self {
+        Packet {
+            header: Header {
+                id: 409,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MapBlockReply(Box::new(map_block_reply)),
+        }
+    }
+}
+
+/// The regions of the grid, sent in answer to a MapBlockRequest.
+/// Large rectangles are answered with several packets, which all carry the flags of the request.
+/// The sizes of the regions are sent in a block after the regions, which older simulators leave
+/// off.
+/// https://wiki.secondlife.com/wiki/MapBlockReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapBlockReply {
+    pub agent_id: Uuid,
+    /// the flags of the request this answers
+    pub flags: u32,
+    pub blocks: Vec<MapBlock>,
+}
+
+/// one region on the map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapBlock {
+    /// the region's global position divided by 256
+    pub x: u16,
+    pub y: u16,
+    pub name: String,
+    /// the maturity rating of the region. Spots without a region are NonExistent.
+    pub access: AgentAccess,
+    pub region_flags: u32,
+    pub water_height: u8,
+    /// how many avatars are in the region
+    pub agents: u8,
+    /// the texture of the region's map tile
+    pub map_image_id: Uuid,
+    /// the size of the region in meters
+    pub size_x: u16,
+    pub size_y: u16,
+}
+
+impl MapBlock {
+    /// false for the blocks that stand in for spots without a region
+    pub fn exists(&self) -> bool {
+        self.access != AgentAccess::NonExistent
+    }
+
+    /// the region handle of the region
+    pub fn region_handle(&self) -> u64 {
+        region_handle(self.x as u32 * 256, self.y as u32 * 256)
+    }
+}
+
+impl PacketData for MapBlockReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let flags = cursor.read_u32::<LittleEndian>()?;
+        let count = cursor.read_u8()?;
+        let mut blocks = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            blocks.push(MapBlock {
+                x: cursor.read_u16::<LittleEndian>()?,
+                y: cursor.read_u16::<LittleEndian>()?,
+                name: read_string_1(&mut cursor)?,
+                access: AgentAccess::from_bytes(&cursor.read_u8()?),
+                region_flags: cursor.read_u32::<LittleEndian>()?,
+                water_height: cursor.read_u8()?,
+                agents: cursor.read_u8()?,
+                map_image_id: read_uuid(&mut cursor)?,
+                size_x: DEFAULT_REGION_SIZE,
+                size_y: DEFAULT_REGION_SIZE,
+            });
+        }
+        if (cursor.position() as usize) < bytes.len() {
+            let size_count = cursor.read_u8()?;
+            for index in 0..size_count as usize {
+                let size_x = cursor.read_u16::<LittleEndian>()?;
+                let size_y = cursor.read_u16::<LittleEndian>()?;
+                if let Some(block) = blocks.get_mut(index) {
+                    block.size_x = size_x;
+                    block.size_y = size_y;
+                }
+            }
+        }
+        Ok(MapBlockReply {
+            agent_id,
+            flags,
+            blocks,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let blocks = &self.blocks[..self.blocks.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.push(blocks.len() as u8);
+        for block in blocks {
+            bytes.extend_from_slice(&block.x.to_le_bytes());
+            bytes.extend_from_slice(&block.y.to_le_bytes());
+            write_string_1(&mut bytes, &block.name);
+            bytes.push(block.access.to_bytes());
+            bytes.extend_from_slice(&block.region_flags.to_le_bytes());
+            bytes.push(block.water_height);
+            bytes.push(block.agents);
+            bytes.extend_from_slice(block.map_image_id.as_bytes());
+        }
+        bytes.push(blocks.len() as u8);
+        for block in blocks {
+            bytes.extend_from_slice(&block.size_x.to_le_bytes());
+            bytes.extend_from_slice(&block.size_y.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,123 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 407
+// Frequency: Low
+
+/// the bits of the flags of map requests that hold the map layer
+pub const LAYER_MASK: u32 = 0xFFFF;
+
+impl Packet {
+    pub fn new_map_block_request(map_block_request: MapBlockRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 407,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MapBlockRequest(Box::new(map_block_request)),
+        }
+    }
+}
+
+/// Asks for the regions in a rectangle of the grid, for the world map.
+/// The rectangle is in region coordinates, which are a region's global position divided by 256,
+/// and includes its max edges. The simulator answers with one or more MapBlockReply packets
+/// carrying the same flags.
+/// https://wiki.secondlife.com/wiki/MapBlockRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapBlockRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the map layer, combined with RETURN_NONEXISTENT
+    pub flags: u32,
+    pub estate_id: u32,
+    pub godlike: bool,
+    pub min_x: u16,
+    pub max_x: u16,
+    pub min_y: u16,
+    pub max_y: u16,
+}
+
+impl MapBlockRequest {
+    /// also answer with a block for every spot of the rectangle without a region, with an
+    /// access level of NonExistent
+    pub const RETURN_NONEXISTENT: u32 = 0x10000;
+}
+
+/// The layer of the map that map requests ask for, which decides which map tiles the
+/// map_image_id of the answers points to. Grids don't agree on which layers they have, and some
+/// answer with their own layer in the flags instead of the one asked for.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MapLayer {
+    /// map tiles showing the objects in the regions
+    Objects,
+    /// map tiles showing only the terrain
+    Terrain,
+    Unknown(u32),
+}
+impl MapLayer {
+    /// the layer in the flags of a map request or reply
+    pub fn from_flags(flags: u32) -> Self {
+        match flags & LAYER_MASK {
+            0 => MapLayer::Objects,
+            1 => MapLayer::Terrain,
+            other => MapLayer::Unknown(other),
+        }
+    }
+
+    /// the flag bits of the layer
+    pub fn to_flags(&self) -> u32 {
+        match self {
+            MapLayer::Objects => 0,
+            MapLayer::Terrain => 1,
+            MapLayer::Unknown(other) => *other & LAYER_MASK,
+        }
+    }
+}
+
+impl PacketData for MapBlockRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(MapBlockRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            flags: cursor.read_u32::<LittleEndian>()?,
+            estate_id: cursor.read_u32::<LittleEndian>()?,
+            godlike: cursor.read_u8()? != 0,
+            min_x: cursor.read_u16::<LittleEndian>()?,
+            max_x: cursor.read_u16::<LittleEndian>()?,
+            min_y: cursor.read_u16::<LittleEndian>()?,
+            max_y: cursor.read_u16::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(49);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.extend_from_slice(&self.estate_id.to_le_bytes());
+        bytes.push(self.godlike as u8);
+        bytes.extend_from_slice(&self.min_x.to_le_bytes());
+        bytes.extend_from_slice(&self.max_x.to_le_bytes());
+        bytes.extend_from_slice(&self.min_y.to_le_bytes());
+        bytes.extend_from_slice(&self.max_y.to_le_bytes());
+        bytes
+    }
+}
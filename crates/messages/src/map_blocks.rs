@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::map_block_reply::MapBlock;
+
+/// The regions the simulator sent in answer to one MapBlockRequest, gathered from all of its
+/// MapBlockReply packets and sent from the session to the UI.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapBlocks {
+    /// the flags of the request
+    pub flags: u32,
+    pub blocks: Vec<MapBlock>,
+}
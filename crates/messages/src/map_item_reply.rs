@@ -0,0 +1,107 @@
+use crate::map_item_request::MapItemType;
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 411
+// Frequency: Low
+
+impl Packet {
+    pub fn new_map_item_reply(map_item_reply: MapItemReply) -> Self {
+        Packet {
+            header: Header {
+                id: 411,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MapItemReply(Box::new(map_item_reply)),
+        }
+    }
+}
+
+/// The markers of one kind on a region of the world map, sent in answer to a MapItemRequest.
+/// Regions with many markers are answered with several packets, which all carry the flags and
+/// item type of the request.
+/// https://wiki.secondlife.com/wiki/MapItemReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapItemReply {
+    pub agent_id: Uuid,
+    /// the flags of the request this answers
+    pub flags: u32,
+    pub item_type: MapItemType,
+    pub items: Vec<MapItem>,
+}
+
+/// one marker on the map
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapItem {
+    /// the global position of the marker, in meters
+    pub x: u32,
+    pub y: u32,
+    /// what the marker is for, like the parcel or event. Agent locations leave it nil.
+    pub id: Uuid,
+    /// depends on the item type. For AgentLocations it is how many avatars are at the spot.
+    pub extra: i32,
+    /// depends on the item type
+    pub extra2: i32,
+    pub name: String,
+}
+
+impl PacketData for MapItemReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let flags = cursor.read_u32::<LittleEndian>()?;
+        let item_type = MapItemType::from_bytes(cursor.read_u32::<LittleEndian>()?);
+        let count = cursor.read_u8()?;
+        let mut items = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            items.push(MapItem {
+                x: cursor.read_u32::<LittleEndian>()?,
+                y: cursor.read_u32::<LittleEndian>()?,
+                id: read_uuid(&mut cursor)?,
+                extra: cursor.read_i32::<LittleEndian>()?,
+                extra2: cursor.read_i32::<LittleEndian>()?,
+                name: read_string_1(&mut cursor)?,
+            });
+        }
+        Ok(MapItemReply {
+            agent_id,
+            flags,
+            item_type,
+            items,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let items = &self.items[..self.items.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.extend_from_slice(&self.item_type.to_bytes().to_le_bytes());
+        bytes.push(items.len() as u8);
+        for item in items {
+            bytes.extend_from_slice(&item.x.to_le_bytes());
+            bytes.extend_from_slice(&item.y.to_le_bytes());
+            bytes.extend_from_slice(item.id.as_bytes());
+            bytes.extend_from_slice(&item.extra.to_le_bytes());
+            bytes.extend_from_slice(&item.extra2.to_le_bytes());
+            write_string_1(&mut bytes, &item.name);
+        }
+        bytes
+    }
+}
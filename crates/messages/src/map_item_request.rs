@@ -0,0 +1,128 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 410
+// Frequency: Low
+
+impl Packet {
+    pub fn new_map_item_request(map_item_request: MapItemRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 410,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MapItemRequest(Box::new(map_item_request)),
+        }
+    }
+}
+
+/// Asks for the markers of one kind that are shown on top of a region of the world map, like
+/// the avatars in it or the land for sale. The simulator answers with one or more MapItemReply
+/// packets carrying the same flags and item type.
+/// https://wiki.secondlife.com/wiki/MapItemRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapItemRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the map layer
+    pub flags: u32,
+    pub estate_id: u32,
+    pub godlike: bool,
+    /// the kind of markers to send
+    pub item_type: MapItemType,
+    /// the region to send the markers of
+    pub region_handle: u64,
+}
+
+/// the kinds of markers on the world map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MapItemType {
+    Telehub,
+    PgEvent,
+    MatureEvent,
+    Popular,
+    /// spots with avatars on them. The extra field of each item is how many avatars are there.
+    AgentLocations,
+    /// land for sale. The extra field of each item is its area in square meters, and extra2 is
+    /// its price.
+    LandForSale,
+    Classified,
+    AdultEvent,
+    /// land for sale in adult regions, with the same extra fields as LandForSale
+    AdultLandForSale,
+    Unknown(u32),
+}
+impl MapItemType {
+    pub fn from_bytes(value: u32) -> Self {
+        match value {
+            1 => MapItemType::Telehub,
+            2 => MapItemType::PgEvent,
+            3 => MapItemType::MatureEvent,
+            4 => MapItemType::Popular,
+            6 => MapItemType::AgentLocations,
+            7 => MapItemType::LandForSale,
+            8 => MapItemType::Classified,
+            9 => MapItemType::AdultEvent,
+            10 => MapItemType::AdultLandForSale,
+            other => MapItemType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u32 {
+        match self {
+            MapItemType::Telehub => 1,
+            MapItemType::PgEvent => 2,
+            MapItemType::MatureEvent => 3,
+            MapItemType::Popular => 4,
+            MapItemType::AgentLocations => 6,
+            MapItemType::LandForSale => 7,
+            MapItemType::Classified => 8,
+            MapItemType::AdultEvent => 9,
+            MapItemType::AdultLandForSale => 10,
+            MapItemType::Unknown(other) => *other,
+        }
+    }
+}
+
+impl PacketData for MapItemRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(MapItemRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            flags: cursor.read_u32::<LittleEndian>()?,
+            estate_id: cursor.read_u32::<LittleEndian>()?,
+            godlike: cursor.read_u8()? != 0,
+            item_type: MapItemType::from_bytes(cursor.read_u32::<LittleEndian>()?),
+            region_handle: cursor.read_u64::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(53);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.extend_from_slice(&self.estate_id.to_le_bytes());
+        bytes.push(self.godlike as u8);
+        bytes.extend_from_slice(&self.item_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.region_handle.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::map_item_reply::MapItem;
+use crate::map_item_request::MapItemType;
+
+/// The markers the simulator sent in answer to one MapItemRequest, gathered from all of its
+/// MapItemReply packets and sent from the session to the UI, tagged with their item type.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapItems {
+    /// the flags of the request
+    pub flags: u32,
+    pub item_type: MapItemType,
+    pub items: Vec<MapItem>,
+}
+
+impl MapItems {
+    /// how many avatars the markers add up to, for AgentLocations. Other item types have none.
+    pub fn agent_count(&self) -> i32 {
+        match self.item_type {
+            MapItemType::AgentLocations => self.items.iter().map(|item| item.extra).sum(),
+            _ => 0,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 408
+// Frequency: Low
+
+impl Packet {
+    pub fn new_map_name_request(map_name_request: MapNameRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 408,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MapNameRequest(Box::new(map_name_request)),
+        }
+    }
+}
+
+/// Searches the grid for regions by name, for the world map.
+/// The simulator answers with MapBlockReply packets carrying the same flags, with a block for
+/// every region whose name starts with the search. A search that finds nothing is answered
+/// with a single block at 0, 0.
+/// https://wiki.secondlife.com/wiki/MapNameRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapNameRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the map layer
+    pub flags: u32,
+    pub estate_id: u32,
+    pub godlike: bool,
+    /// the start of the region names to find
+    pub name: String,
+}
+
+impl PacketData for MapNameRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(MapNameRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            flags: cursor.read_u32::<LittleEndian>()?,
+            estate_id: cursor.read_u32::<LittleEndian>()?,
+            godlike: cursor.read_u8()? != 0,
+            name: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(43 + self.name.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.extend_from_slice(&self.estate_id.to_le_bytes());
+        bytes.push(self.godlike as u8);
+        write_string_1(&mut bytes, &self.name);
+        bytes
+    }
+}
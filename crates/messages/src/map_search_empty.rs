@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::map_block_request::MapLayer;
+
+/// Sent from the session to the UI when a MapNameRequest from the UI found no regions, or
+/// was never answered.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapSearchEmpty {
+    /// the name that was searched for
+    pub name: String,
+    /// the layer the search asked for
+    pub layer: MapLayer,
+}
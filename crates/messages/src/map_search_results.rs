@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::map_block_reply::MapBlock;
+use crate::map_block_request::MapLayer;
+
+/// The regions found by a MapNameRequest from the UI, gathered from all of its MapBlockReply
+/// packets and sent from the session to the UI.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapSearchResults {
+    /// the name that was searched for
+    pub name: String,
+    /// the layer the search asked for
+    pub layer: MapLayer,
+    pub blocks: Vec<MapBlock>,
+}
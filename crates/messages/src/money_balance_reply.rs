@@ -0,0 +1,125 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 314
+// Frequency: Low
+
+impl Packet {
+    pub fn new_money_balance_reply(money_balance_reply: MoneyBalanceReply) -> Self {
+        Packet {
+            header: Header {
+                id: 314,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MoneyBalanceReply(Box::new(money_balance_reply)),
+        }
+    }
+}
+
+/// The agent's balance. This is sent in answer to a MoneyBalanceRequest, and also on its own
+/// whenever the balance changes, like after a purchase.
+/// https://wiki.secondlife.com/wiki/MoneyBalanceReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MoneyBalanceReply {
+    pub agent_id: Uuid,
+    /// the transaction id of the request, or of the transaction that changed the balance
+    pub transaction_id: Uuid,
+    pub transaction_success: bool,
+    pub money_balance: i32,
+    pub square_meters_credit: i32,
+    pub square_meters_committed: i32,
+    /// human readable description of the transaction. Usually empty.
+    pub description: String,
+    /// details of the transaction that changed the balance. Older simulators leave this off.
+    pub transaction_info: Option<TransactionInfo>,
+}
+
+/// what a transaction was for
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionInfo {
+    pub transaction_type: i32,
+    pub source_id: Uuid,
+    pub is_source_group: bool,
+    pub dest_id: Uuid,
+    pub is_dest_group: bool,
+    pub amount: i32,
+    pub item_description: String,
+}
+
+impl PacketData for MoneyBalanceReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // MoneyData
+        let agent_id = read_uuid(&mut cursor)?;
+        let transaction_id = read_uuid(&mut cursor)?;
+        let transaction_success = cursor.read_u8()? != 0;
+        let money_balance = cursor.read_i32::<LittleEndian>()?;
+        let square_meters_credit = cursor.read_i32::<LittleEndian>()?;
+        let square_meters_committed = cursor.read_i32::<LittleEndian>()?;
+        let description = read_string_1(&mut cursor)?;
+
+        // TransactionInfo
+        let transaction_info = if (cursor.position() as usize) < bytes.len() {
+            Some(TransactionInfo {
+                transaction_type: cursor.read_i32::<LittleEndian>()?,
+                source_id: read_uuid(&mut cursor)?,
+                is_source_group: cursor.read_u8()? != 0,
+                dest_id: read_uuid(&mut cursor)?,
+                is_dest_group: cursor.read_u8()? != 0,
+                amount: cursor.read_i32::<LittleEndian>()?,
+                item_description: read_string_1(&mut cursor)?,
+            })
+        } else {
+            None
+        };
+
+        Ok(MoneyBalanceReply {
+            agent_id,
+            transaction_id,
+            transaction_success,
+            money_balance,
+            square_meters_credit,
+            square_meters_committed,
+            description,
+            transaction_info,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(46 + self.description.len() + 1);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.push(self.transaction_success as u8);
+        bytes.extend_from_slice(&self.money_balance.to_le_bytes());
+        bytes.extend_from_slice(&self.square_meters_credit.to_le_bytes());
+        bytes.extend_from_slice(&self.square_meters_committed.to_le_bytes());
+        write_string_1(&mut bytes, &self.description);
+        if let Some(info) = &self.transaction_info {
+            bytes.extend_from_slice(&info.transaction_type.to_le_bytes());
+            bytes.extend_from_slice(info.source_id.as_bytes());
+            bytes.push(info.is_source_group as u8);
+            bytes.extend_from_slice(info.dest_id.as_bytes());
+            bytes.push(info.is_dest_group as u8);
+            bytes.extend_from_slice(&info.amount.to_le_bytes());
+            write_string_1(&mut bytes, &info.item_description);
+        }
+        bytes
+    }
+}
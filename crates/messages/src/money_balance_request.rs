@@ -0,0 +1,60 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 313
+// Frequency: Low
+
+impl Packet {
+    pub fn new_money_balance_request(money_balance_request: MoneyBalanceRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 313,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MoneyBalanceRequest(Box::new(money_balance_request)),
+        }
+    }
+}
+
+/// Asks the simulator for the agent's balance. The simulator answers with a MoneyBalanceReply
+/// with the same transaction id.
+/// https://wiki.secondlife.com/wiki/MoneyBalanceRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoneyBalanceRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub transaction_id: Uuid,
+}
+
+impl PacketData for MoneyBalanceRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(MoneyBalanceRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            transaction_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes
+    }
+}
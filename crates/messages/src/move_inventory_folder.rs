@@ -0,0 +1,101 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 275
+// Frequency: Low
+
+impl Packet {
+    pub fn new_move_inventory_folder(move_inventory_folder: MoveInventoryFolder) -> Self {
+        Packet {
+            header: Header {
+                id: 275,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MoveInventoryFolder(Box::new(move_inventory_folder)),
+        }
+    }
+}
+
+/// Moves inventory folders into other folders, with everything inside them.
+/// https://wiki.secondlife.com/wiki/MoveInventoryFolder
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveInventoryFolder {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// bump the version of the folders the folders move between
+    pub stamp: bool,
+    pub folders: Vec<FolderMove>,
+}
+
+/// a folder and where it goes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FolderMove {
+    pub folder_id: Uuid,
+    /// the folder it goes into
+    pub parent_id: Uuid,
+}
+
+impl MoveInventoryFolder {
+    /// move folders, stamping the folders they move between. The agent and session ids are left
+    /// nil, for the session to fill in.
+    pub fn new(folders: Vec<FolderMove>) -> Self {
+        MoveInventoryFolder {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            stamp: true,
+            folders,
+        }
+    }
+}
+
+impl PacketData for MoveInventoryFolder {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let stamp = cursor.read_u8()? != 0;
+        let count = cursor.read_u8()?;
+        let mut folders = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            folders.push(FolderMove {
+                folder_id: read_uuid(&mut cursor)?,
+                parent_id: read_uuid(&mut cursor)?,
+            });
+        }
+        Ok(MoveInventoryFolder {
+            agent_id,
+            session_id,
+            stamp,
+            folders,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let folders = &self.folders[..self.folders.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(34 + folders.len() * 32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(self.stamp as u8);
+        bytes.push(folders.len() as u8);
+        for folder in folders {
+            bytes.extend_from_slice(folder.folder_id.as_bytes());
+            bytes.extend_from_slice(folder.parent_id.as_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,108 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 268
+// Frequency: Low
+
+impl Packet {
+    pub fn new_move_inventory_item(move_inventory_item: MoveInventoryItem) -> Self {
+        Packet {
+            header: Header {
+                id: 268,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MoveInventoryItem(Box::new(move_inventory_item)),
+        }
+    }
+}
+
+/// Moves inventory items into other folders, renaming them on the way if asked to.
+/// https://wiki.secondlife.com/wiki/MoveInventoryItem
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveInventoryItem {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// bump the version of the folders the items move between
+    pub stamp: bool,
+    pub items: Vec<ItemMove>,
+}
+
+/// an item and where it goes
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemMove {
+    pub item_id: Uuid,
+    /// the folder the item goes into
+    pub folder_id: Uuid,
+    /// the name the item gets, None to keep its name. Sent as an empty name.
+    pub new_name: Option<String>,
+}
+
+impl MoveInventoryItem {
+    /// move items, stamping the folders. The agent and session ids are left nil, for the session
+    /// to fill in.
+    pub fn new(items: Vec<ItemMove>) -> Self {
+        MoveInventoryItem {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            stamp: true,
+            items,
+        }
+    }
+}
+
+impl PacketData for MoveInventoryItem {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let stamp = cursor.read_u8()? != 0;
+        let count = cursor.read_u8()?;
+        let mut items = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let item_id = read_uuid(&mut cursor)?;
+            let folder_id = read_uuid(&mut cursor)?;
+            let new_name = read_string_1(&mut cursor)?;
+            items.push(ItemMove {
+                item_id,
+                folder_id,
+                new_name: (!new_name.is_empty()).then_some(new_name),
+            });
+        }
+        Ok(MoveInventoryItem {
+            agent_id,
+            session_id,
+            stamp,
+            items,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let items = &self.items[..self.items.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(34 + items.len() * 40);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(self.stamp as u8);
+        bytes.push(items.len() as u8);
+        for item in items {
+            bytes.extend_from_slice(item.item_id.as_bytes());
+            bytes.extend_from_slice(item.folder_id.as_bytes());
+            write_string_1(&mut bytes, item.new_name.as_deref().unwrap_or_default());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,231 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_quat, read_uuid, read_variable_1, read_vec3, write_quat, write_variable_1, write_vec3,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::{Quat, Vec3};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 2
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_multiple_object_update(multiple_object_update: MultipleObjectUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 2,
+                frequency: PacketFrequency::Medium,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MultipleObjectUpdate(Box::new(multiple_object_update)),
+        }
+    }
+}
+
+/// Moves, rotates and scales objects, like dragging them with the viewer's edit tools.
+/// https://wiki.secondlife.com/wiki/MultipleObjectUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultipleObjectUpdate {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub objects: Vec<ObjectTransformUpdate>,
+}
+
+/// The new transform of one object.
+/// The type byte says which of the position, rotation and scale are in the data, which holds
+/// them in that order. Each of them is 12 bytes, with the rotation packed as x, y, z.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectTransformUpdate {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// the new position, in region coordinates, or relative to the root for a child prim
+    pub position: Option<Vec3>,
+    pub rotation: Option<Quat>,
+    pub scale: Option<Vec3>,
+    /// move the whole link set instead of only this prim
+    pub linked_set: bool,
+    /// scale the object from its center, instead of from the opposite corner
+    pub uniform: bool,
+}
+
+impl ObjectTransformUpdate {
+    pub const POSITION: u8 = 0x01;
+    pub const ROTATION: u8 = 0x02;
+    pub const SCALE: u8 = 0x04;
+    pub const LINKED_SETS: u8 = 0x08;
+    pub const UNIFORM: u8 = 0x10;
+
+    fn new(local_id: u32) -> Self {
+        ObjectTransformUpdate {
+            local_id,
+            position: None,
+            rotation: None,
+            scale: None,
+            linked_set: false,
+            uniform: false,
+        }
+    }
+
+    /// move an object
+    pub fn position(local_id: u32, position: Vec3) -> Self {
+        Self::new(local_id).with_position(position)
+    }
+
+    /// rotate an object
+    pub fn rotation(local_id: u32, rotation: Quat) -> Self {
+        Self::new(local_id).with_rotation(rotation)
+    }
+
+    /// scale an object
+    pub fn scale(local_id: u32, scale: Vec3) -> Self {
+        Self::new(local_id).with_scale(scale)
+    }
+
+    /// also move the object
+    pub fn with_position(mut self, position: Vec3) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// also rotate the object
+    pub fn with_rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = Some(rotation);
+        self
+    }
+
+    /// also scale the object
+    pub fn with_scale(mut self, scale: Vec3) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// the type byte for the update
+    pub fn update_type(&self) -> u8 {
+        let mut update_type = 0;
+        if self.position.is_some() {
+            update_type |= Self::POSITION;
+        }
+        if self.rotation.is_some() {
+            update_type |= Self::ROTATION;
+        }
+        if self.scale.is_some() {
+            update_type |= Self::SCALE;
+        }
+        if self.linked_set {
+            update_type |= Self::LINKED_SETS;
+        }
+        if self.uniform {
+            update_type |= Self::UNIFORM;
+        }
+        update_type
+    }
+
+    /// the packed position, rotation and scale, for the types that are set
+    pub fn data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(36);
+        if let Some(position) = &self.position {
+            write_vec3(&mut data, position);
+        }
+        if let Some(rotation) = &self.rotation {
+            write_quat(&mut data, rotation);
+        }
+        if let Some(scale) = &self.scale {
+            write_vec3(&mut data, scale);
+        }
+        data
+    }
+
+    fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        let local_id = cursor.read_u32::<LittleEndian>()?;
+        let update_type = cursor.read_u8()?;
+        let data = read_variable_1(cursor)?;
+        let expected = [Self::POSITION, Self::ROTATION, Self::SCALE]
+            .iter()
+            .filter(|flag| update_type & *flag != 0)
+            .count()
+            * 12;
+        if data.len() != expected {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "MultipleObjectUpdate type {:#04x} needs {} bytes of data, got {}",
+                    update_type,
+                    expected,
+                    data.len()
+                ),
+            ));
+        }
+        let mut data = Cursor::new(data.as_slice());
+        let position = if update_type & Self::POSITION != 0 {
+            Some(read_vec3(&mut data)?)
+        } else {
+            None
+        };
+        let rotation = if update_type & Self::ROTATION != 0 {
+            Some(read_quat(&mut data)?)
+        } else {
+            None
+        };
+        let scale = if update_type & Self::SCALE != 0 {
+            Some(read_vec3(&mut data)?)
+        } else {
+            None
+        };
+        Ok(ObjectTransformUpdate {
+            local_id,
+            position,
+            rotation,
+            scale,
+            linked_set: update_type & Self::LINKED_SETS != 0,
+            uniform: update_type & Self::UNIFORM != 0,
+        })
+    }
+
+    fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes.push(self.update_type());
+        write_variable_1(bytes, &self.data());
+    }
+}
+
+impl PacketData for MultipleObjectUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectTransformUpdate::from_bytes(&mut cursor)?);
+        }
+        Ok(MultipleObjectUpdate {
+            agent_id,
+            session_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + objects.len() * 42);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            object.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::mute::MuteEntry;
+
+/// The agent's mute list, sent from the session to the UI once it has been downloaded, and
+/// again whenever the UI changes it.
+/// The simulator sends the list as a text file with one entry per line, which parse reads.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MuteList {
+    pub entries: Vec<MuteEntry>,
+}
+
+impl MuteList {
+    /// read the mute list file. Lines that aren't entries are skipped, and the file is read
+    /// leniently, as names can contain anything.
+    pub fn parse(file: &[u8]) -> Self {
+        MuteList {
+            entries: String::from_utf8_lossy(file)
+                .lines()
+                .filter_map(MuteEntry::from_line)
+                .collect(),
+        }
+    }
+
+    /// write the mute list file, with a line per entry
+    pub fn to_file(&self) -> Vec<u8> {
+        self.entries
+            .iter()
+            .map(|entry| entry.to_line() + "\n")
+            .collect::<String>()
+            .into_bytes()
+    }
+}
@@ -0,0 +1,63 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 263
+// Frequency: Low
+
+impl Packet {
+    pub fn new_mute_list_request(mute_list_request: MuteListRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 263,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MuteListRequest(Box::new(mute_list_request)),
+        }
+    }
+}
+
+/// Asks the simulator for the agent's mute list.
+/// If the CRC matches the list the simulator has, it answers with a UseCachedMuteList.
+/// Otherwise it answers with a MuteListUpdate naming the file to download the list from.
+/// https://wiki.secondlife.com/wiki/MuteListRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct MuteListRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the CRC of the cached mute list file, or 0 if there is none
+    pub mute_crc: u32,
+}
+
+impl PacketData for MuteListRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(MuteListRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            mute_crc: cursor.read_u32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(36);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.mute_crc.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,57 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 318
+// Frequency: Low
+
+impl Packet {
+    pub fn new_mute_list_update(mute_list_update: MuteListUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 318,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::MuteListUpdate(Box::new(mute_list_update)),
+        }
+    }
+}
+
+/// Answers a MuteListRequest with the name of the file the mute list is in.
+/// The file is downloaded with the Xfer system, by sending a RequestXfer for the file name.
+/// https://wiki.secondlife.com/wiki/MuteListUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct MuteListUpdate {
+    pub agent_id: Uuid,
+    pub filename: String,
+}
+
+impl PacketData for MuteListUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(MuteListUpdate {
+            agent_id: read_uuid(&mut cursor)?,
+            filename: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(18 + self.filename.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        write_string_1(&mut bytes, &self.filename);
+        bytes
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::uuid_name_reply::AvatarName;
+
+/// Sent from the session to the UI with avatar names as they are resolved, as a JSON array.
+/// Names the session already knew are sent as soon as the UI asks for them.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NameResolved {
+    pub names: Vec<AvatarName>,
+}
@@ -0,0 +1,249 @@
+use crate::object_update::{PCode, PrimShape};
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_quat, read_uuid, read_vec3, write_quat, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::{Quat, Vec3};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 1
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_object_add(object_add: ObjectAdd) -> Self {
+        Packet {
+            header: Header {
+                id: 1,
+                frequency: PacketFrequency::Medium,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectAdd(Box::new(object_add)),
+        }
+    }
+}
+
+/// Rezzes a new object. The simulator places it where a ray from ray_start to ray_end hits
+/// something, or at ray_end if bypass_raycast is set, and answers with an ObjectUpdate for it.
+/// The object is created with the default texture. Use PrimBuilder to fill in a basic prim.
+/// https://wiki.secondlife.com/wiki/ObjectAdd
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectAdd {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group the object is set to, nil for none
+    pub group_id: Uuid,
+    pub pcode: PCode,
+    /// what the object is made of, which changes its sounds and physics. See the MATERIAL consts.
+    pub material: u8,
+    /// see the ADD_FLAG consts
+    pub add_flags: u32,
+    pub shape: PrimShape,
+    /// place the object at ray_end instead of casting the ray
+    pub bypass_raycast: bool,
+    pub ray_start: Vec3,
+    pub ray_end: Vec3,
+    /// the object the ray was aimed at, nil for none
+    pub ray_target_id: Uuid,
+    /// ray_end is already on the surface that was hit
+    pub ray_end_is_intersection: bool,
+    pub scale: Vec3,
+    pub rotation: Quat,
+    /// the attachment point, for objects rezzed as attachments. Zero otherwise.
+    pub state: u8,
+}
+
+impl ObjectAdd {
+    /// select the object once it has been created
+    pub const ADD_FLAG_CREATE_SELECTED: u32 = 0x02;
+    /// the object uses the group of the agent
+    pub const ADD_FLAG_USE_GROUP: u32 = 0x04;
+
+    pub const MATERIAL_STONE: u8 = 0;
+    pub const MATERIAL_METAL: u8 = 1;
+    pub const MATERIAL_GLASS: u8 = 2;
+    pub const MATERIAL_WOOD: u8 = 3;
+    pub const MATERIAL_FLESH: u8 = 4;
+    pub const MATERIAL_PLASTIC: u8 = 5;
+    pub const MATERIAL_RUBBER: u8 = 6;
+}
+
+impl PacketData for ObjectAdd {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let group_id = read_uuid(&mut cursor)?;
+        let pcode = PCode::from_bytes(cursor.read_u8()?);
+        let material = cursor.read_u8()?;
+        let add_flags = cursor.read_u32::<LittleEndian>()?;
+        let shape = PrimShape::from_bytes(&mut cursor)?;
+        let bypass_raycast = cursor.read_u8()? != 0;
+        let ray_start = read_vec3(&mut cursor)?;
+        let ray_end = read_vec3(&mut cursor)?;
+        let ray_target_id = read_uuid(&mut cursor)?;
+        let ray_end_is_intersection = cursor.read_u8()? != 0;
+        let scale = read_vec3(&mut cursor)?;
+        let rotation = read_quat(&mut cursor)?;
+        let state = cursor.read_u8()?;
+        Ok(ObjectAdd {
+            agent_id,
+            session_id,
+            group_id,
+            pcode,
+            material,
+            add_flags,
+            shape,
+            bypass_raycast,
+            ray_start,
+            ray_end,
+            ray_target_id,
+            ray_end_is_intersection,
+            scale,
+            rotation,
+            state,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(160);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes.push(self.pcode.to_bytes());
+        bytes.push(self.material);
+        bytes.extend_from_slice(&self.add_flags.to_le_bytes());
+        self.shape.to_bytes(&mut bytes);
+        bytes.push(self.bypass_raycast as u8);
+        write_vec3(&mut bytes, &self.ray_start);
+        write_vec3(&mut bytes, &self.ray_end);
+        bytes.extend_from_slice(self.ray_target_id.as_bytes());
+        bytes.push(self.ray_end_is_intersection as u8);
+        write_vec3(&mut bytes, &self.scale);
+        write_quat(&mut bytes, &self.rotation);
+        bytes.push(self.state);
+        bytes
+    }
+}
+
+// path and profile curves, from the viewer's LL_PCODE_PATH and LL_PCODE_PROFILE values
+const PATH_LINE: u8 = 0x10;
+const PATH_CIRCLE: u8 = 0x20;
+const PROFILE_CIRCLE: u8 = 0x00;
+const PROFILE_SQUARE: u8 = 0x01;
+const PROFILE_HALF_CIRCLE: u8 = 0x05;
+/// path scales are sent as 200 - scale * 100, so this is a scale of 1
+const PATH_SCALE_ONE: u8 = 100;
+
+/// Builds the ObjectAdd for a basic prim, like the viewer's build tool does.
+/// Prims start as a half meter wood prim with no rotation, placed at the origin without a
+/// raycast. The agent and session ids are left nil, for the session to fill in.
+/// ```
+/// use glam::Vec3;
+/// use metaverse_messages::object_add::PrimBuilder;
+///
+/// let object_add = PrimBuilder::cube().at(Vec3::new(128.0, 128.0, 25.0)).build();
+/// assert_eq!(object_add.ray_end, Vec3::new(128.0, 128.0, 25.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrimBuilder {
+    object_add: ObjectAdd,
+}
+
+impl PrimBuilder {
+    fn new(path_curve: u8, profile_curve: u8) -> Self {
+        PrimBuilder {
+            object_add: ObjectAdd {
+                agent_id: Uuid::nil(),
+                session_id: Uuid::nil(),
+                group_id: Uuid::nil(),
+                pcode: PCode::Primitive,
+                material: ObjectAdd::MATERIAL_WOOD,
+                add_flags: 0,
+                shape: PrimShape {
+                    path_curve,
+                    profile_curve,
+                    path_scale_x: PATH_SCALE_ONE,
+                    path_scale_y: PATH_SCALE_ONE,
+                    ..Default::default()
+                },
+                bypass_raycast: true,
+                ray_start: Vec3::ZERO,
+                ray_end: Vec3::ZERO,
+                ray_target_id: Uuid::nil(),
+                ray_end_is_intersection: false,
+                scale: Vec3::splat(0.5),
+                rotation: Quat::IDENTITY,
+                state: 0,
+            },
+        }
+    }
+
+    pub fn cube() -> Self {
+        Self::new(PATH_LINE, PROFILE_SQUARE)
+    }
+
+    pub fn cylinder() -> Self {
+        Self::new(PATH_LINE, PROFILE_CIRCLE)
+    }
+
+    pub fn sphere() -> Self {
+        Self::new(PATH_CIRCLE, PROFILE_HALF_CIRCLE)
+    }
+
+    /// place the prim at a position in the region, without a raycast
+    pub fn at(mut self, position: Vec3) -> Self {
+        self.object_add.bypass_raycast = true;
+        self.object_add.ray_start = position;
+        self.object_add.ray_end = position;
+        self
+    }
+
+    /// place the prim where a ray hits, like rezzing it on the object that was clicked
+    pub fn raycast(mut self, ray_start: Vec3, ray_end: Vec3, ray_target_id: Uuid) -> Self {
+        self.object_add.bypass_raycast = false;
+        self.object_add.ray_start = ray_start;
+        self.object_add.ray_end = ray_end;
+        self.object_add.ray_target_id = ray_target_id;
+        self
+    }
+
+    pub fn scale(mut self, scale: Vec3) -> Self {
+        self.object_add.scale = scale;
+        self
+    }
+
+    pub fn rotation(mut self, rotation: Quat) -> Self {
+        self.object_add.rotation = rotation;
+        self
+    }
+
+    pub fn material(mut self, material: u8) -> Self {
+        self.object_add.material = material;
+        self
+    }
+
+    pub fn group(mut self, group_id: Uuid) -> Self {
+        self.object_add.group_id = group_id;
+        self
+    }
+
+    pub fn add_flags(mut self, add_flags: u32) -> Self {
+        self.object_add.add_flags = add_flags;
+        self
+    }
+
+    pub fn build(self) -> ObjectAdd {
+        self.object_add
+    }
+}
@@ -0,0 +1,119 @@
+use crate::packet_types::PacketType;
+use crate::utils::attachment_point::AttachmentPoint;
+use crate::utils::object_blocks::MAX_OBJECT_BLOCKS;
+use crate::utils::packet_fields::{read_quat, read_uuid, write_quat};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Quat;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 112
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_attach(object_attach: ObjectAttach) -> Self {
+        Packet {
+            header: Header {
+                id: 112,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectAttach(Box::new(object_attach)),
+        }
+    }
+}
+
+/// Attaches objects in the region to the agent's avatar, taking them out of the region. The
+/// simulator sends the attached objects back as ObjectUpdates parented to the avatar.
+/// https://wiki.secondlife.com/wiki/ObjectAttach
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectAttach {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub attachment_point: AttachmentPoint,
+    /// add the objects to the point, instead of replacing what is already attached there
+    pub add: bool,
+    pub objects: Vec<ObjectAttachData>,
+}
+
+/// One ObjectData block of an ObjectAttach
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObjectAttachData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// the rotation of the object on the attachment point
+    pub rotation: Quat,
+}
+
+impl ObjectAttach {
+    /// attach objects to a point, replacing what is already attached there. The agent and
+    /// session ids are left nil, for the session to fill in.
+    pub fn new(attachment_point: AttachmentPoint, local_ids: &[u32]) -> Self {
+        ObjectAttach {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            attachment_point,
+            add: false,
+            objects: local_ids
+                .iter()
+                .map(|local_id| ObjectAttachData {
+                    local_id: *local_id,
+                    rotation: Quat::IDENTITY,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl PacketData for ObjectAttach {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let point = cursor.read_u8()?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectAttachData {
+                local_id: cursor.read_u32::<LittleEndian>()?,
+                rotation: read_quat(&mut cursor)?,
+            });
+        }
+        Ok(ObjectAttach {
+            agent_id,
+            session_id,
+            attachment_point: AttachmentPoint::from_bytes(point & !AttachmentPoint::ADD),
+            add: point & AttachmentPoint::ADD != 0,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(34 + objects.len() * 16);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        let mut point = self.attachment_point.to_bytes();
+        if self.add {
+            point |= AttachmentPoint::ADD;
+        }
+        bytes.push(point);
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            write_quat(&mut bytes, &object.rotation);
+        }
+        bytes
+    }
+}
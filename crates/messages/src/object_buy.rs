@@ -0,0 +1,99 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::sale_type::SaleType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 102
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_buy(object_buy: ObjectBuy) -> Self {
+        Packet {
+            header: Header {
+                id: 102,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectBuy(Box::new(object_buy)),
+        }
+    }
+}
+
+/// Buys objects that are for sale. The sale type and price have to match the object's, so a
+/// purchase fails if they were changed after the buyer looked at them.
+/// The simulator answers with a MoneyBalanceReply saying whether the purchase went through.
+/// https://wiki.secondlife.com/wiki/ObjectBuy
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectBuy {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group the agent is acting as, nil for none
+    pub group_id: Uuid,
+    /// the inventory folder bought objects and contents go into, nil for the default folder
+    pub category_id: Uuid,
+    pub objects: Vec<ObjectBuyData>,
+}
+
+/// one object to buy
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectBuyData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    pub sale_type: SaleType,
+    pub sale_price: i32,
+}
+
+impl PacketData for ObjectBuy {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let group_id = read_uuid(&mut cursor)?;
+        let category_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectBuyData {
+                local_id: cursor.read_u32::<LittleEndian>()?,
+                sale_type: SaleType::from_bytes(cursor.read_u8()?),
+                sale_price: cursor.read_i32::<LittleEndian>()?,
+            });
+        }
+        Ok(ObjectBuy {
+            agent_id,
+            session_id,
+            group_id,
+            category_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(65 + objects.len() * 9);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes.extend_from_slice(self.category_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            bytes.push(object.sale_type.to_bytes());
+            bytes.extend_from_slice(&object.sale_price.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,108 @@
+use crate::packet_types::PacketType;
+use crate::utils::click_action::ClickAction;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 95
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_click_action(object_click_action: ObjectClickAction) -> Self {
+        Packet {
+            header: Header {
+                id: 95,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectClickAction(Box::new(object_click_action)),
+        }
+    }
+}
+
+/// Sets what happens when objects are clicked, like sitting on them or paying them.
+/// https://wiki.secondlife.com/wiki/ObjectClickAction
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectClickAction {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub objects: Vec<ObjectClickActionData>,
+}
+
+/// the new click action of one object
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectClickActionData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    pub click_action: ClickAction,
+}
+
+impl ObjectClickAction {
+    /// set the click actions of objects. The agent and session ids are left nil, for the
+    /// session to fill in.
+    pub fn new(objects: Vec<ObjectClickActionData>) -> Self {
+        ObjectClickAction {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            objects,
+        }
+    }
+
+    /// split an edit of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectClickAction> {
+        chunk_object_blocks(&self.objects)
+            .into_iter()
+            .map(|objects| ObjectClickAction {
+                objects: objects.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectClickAction {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectClickActionData {
+                local_id: cursor.read_u32::<LittleEndian>()?,
+                click_action: ClickAction::from_bytes(cursor.read_u8()?),
+            });
+        }
+        Ok(ObjectClickAction {
+            agent_id,
+            session_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(33 + objects.len() * 5);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            bytes.push(object.click_action.to_bytes());
+        }
+        bytes
+    }
+}
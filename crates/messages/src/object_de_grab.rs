@@ -0,0 +1,70 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::surface_info::{read_surface_info, write_surface_info, SurfaceInfo};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 119
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_de_grab(object_de_grab: ObjectDeGrab) -> Self {
+        Packet {
+            header: Header {
+                id: 119,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectDeGrab(Box::new(object_de_grab)),
+        }
+    }
+}
+
+/// Releases an object grabbed with ObjectGrab. Scripts get a touch_end event.
+/// https://wiki.secondlife.com/wiki/ObjectDeGrab
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDeGrab {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// where on the object it was released. Empty if it isn't known.
+    pub surface_info: Vec<SurfaceInfo>,
+}
+
+impl PacketData for ObjectDeGrab {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let local_id = cursor.read_u32::<LittleEndian>()?;
+        let surface_info = read_surface_info(&mut cursor)?;
+        Ok(ObjectDeGrab {
+            agent_id,
+            session_id,
+            local_id,
+            surface_info,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(37);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        write_surface_info(&mut bytes, &self.surface_info);
+        bytes
+    }
+}
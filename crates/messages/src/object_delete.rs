@@ -0,0 +1,78 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 89
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_delete(object_delete: ObjectDelete) -> Self {
+        Packet {
+            header: Header {
+                id: 89,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectDelete(Box::new(object_delete)),
+        }
+    }
+}
+
+/// Deletes objects outright, without moving them to the trash. Only gods can use this, everyone
+/// else deletes objects with a DeRezObject.
+/// https://wiki.secondlife.com/wiki/ObjectDelete
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDelete {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// delete the objects even if they are locked
+    pub force: bool,
+    /// the local ids of the objects in the region
+    pub local_ids: Vec<u32>,
+}
+
+impl PacketData for ObjectDelete {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let force = cursor.read_u8()? != 0;
+        let count = cursor.read_u8()?;
+        let mut local_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            local_ids.push(cursor.read_u32::<LittleEndian>()?);
+        }
+        Ok(ObjectDelete {
+            agent_id,
+            session_id,
+            force,
+            local_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let local_ids = &self.local_ids[..self.local_ids.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(34 + local_ids.len() * 4);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(self.force as u8);
+        bytes.push(local_ids.len() as u8);
+        for local_id in local_ids {
+            bytes.extend_from_slice(&local_id.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,136 @@
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_sized_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::{read_string_1, read_uuid, truncate_str, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 108
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_description(object_description: ObjectDescription) -> Self {
+        Packet {
+            header: Header {
+                id: 108,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectDescription(Box::new(object_description)),
+        }
+    }
+}
+
+/// Sets the descriptions of objects. Descriptions longer than the simulator keeps have to be
+/// cut down with truncate first.
+/// https://wiki.secondlife.com/wiki/ObjectDescription
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDescription {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub objects: Vec<ObjectDescriptionData>,
+}
+
+/// the new description of one object
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDescriptionData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    pub description: String,
+}
+
+impl ObjectDescription {
+    /// the longest description the simulator keeps, in bytes
+    pub const MAX_DESCRIPTION_BYTES: usize = 127;
+
+    /// set the descriptions of objects. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(objects: Vec<ObjectDescriptionData>) -> Self {
+        ObjectDescription {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            objects,
+        }
+    }
+
+    /// cut the descriptions longer than MAX_DESCRIPTION_BYTES down to size, on a character
+    /// boundary, returning a warning for each one that was cut
+    pub fn truncate(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for object in self.objects.iter_mut() {
+            if object.description.len() > Self::MAX_DESCRIPTION_BYTES {
+                let truncated =
+                    truncate_str(&object.description, Self::MAX_DESCRIPTION_BYTES).to_string();
+                warnings.push(format!(
+                    "description of object {} cut from {} to {} bytes",
+                    object.local_id,
+                    object.description.len(),
+                    truncated.len()
+                ));
+                object.description = truncated;
+            }
+        }
+        warnings
+    }
+
+    /// split an edit of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectDescription> {
+        chunk_sized_object_blocks(&self.objects, |object| 6 + object.description.len())
+            .into_iter()
+            .map(|objects| ObjectDescription {
+                objects: objects.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectDescription {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectDescriptionData {
+                local_id: cursor.read_u32::<LittleEndian>()?,
+                description: read_string_1(&mut cursor)?,
+            });
+        }
+        Ok(ObjectDescription {
+            agent_id,
+            session_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(
+            33 + objects
+                .iter()
+                .map(|object| 6 + object.description.len())
+                .sum::<usize>(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            write_string_1(&mut bytes, &object.description);
+        }
+        bytes
+    }
+}
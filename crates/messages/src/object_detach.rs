@@ -0,0 +1,96 @@
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 113
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_detach(object_detach: ObjectDetach) -> Self {
+        Packet {
+            header: Header {
+                id: 113,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectDetach(Box::new(object_detach)),
+        }
+    }
+}
+
+/// Detaches objects from the agent's avatar, taking them back into inventory. The objects are
+/// named by their local ids in the region.
+/// https://wiki.secondlife.com/wiki/ObjectDetach
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDetach {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the local ids of the attached objects in the region
+    pub local_ids: Vec<u32>,
+}
+
+impl ObjectDetach {
+    /// detach objects. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(local_ids: Vec<u32>) -> Self {
+        ObjectDetach {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            local_ids,
+        }
+    }
+
+    /// split a detach of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectDetach> {
+        chunk_object_blocks(&self.local_ids)
+            .into_iter()
+            .map(|local_ids| ObjectDetach {
+                local_ids: local_ids.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectDetach {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut local_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            local_ids.push(cursor.read_u32::<LittleEndian>()?);
+        }
+        Ok(ObjectDetach {
+            agent_id,
+            session_id,
+            local_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let local_ids = &self.local_ids[..self.local_ids.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(33 + local_ids.len() * 4);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(local_ids.len() as u8);
+        for local_id in local_ids {
+            bytes.extend_from_slice(&local_id.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,95 @@
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 114
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_drop(object_drop: ObjectDrop) -> Self {
+        Packet {
+            header: Header {
+                id: 114,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectDrop(Box::new(object_drop)),
+        }
+    }
+}
+
+/// Drops objects attached to the agent's avatar, rezzing them into the region where they are.
+/// https://wiki.secondlife.com/wiki/ObjectDrop
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDrop {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the local ids of the attached objects in the region
+    pub local_ids: Vec<u32>,
+}
+
+impl ObjectDrop {
+    /// drop objects. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(local_ids: Vec<u32>) -> Self {
+        ObjectDrop {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            local_ids,
+        }
+    }
+
+    /// split a drop of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectDrop> {
+        chunk_object_blocks(&self.local_ids)
+            .into_iter()
+            .map(|local_ids| ObjectDrop {
+                local_ids: local_ids.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectDrop {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut local_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            local_ids.push(cursor.read_u32::<LittleEndian>()?);
+        }
+        Ok(ObjectDrop {
+            agent_id,
+            session_id,
+            local_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let local_ids = &self.local_ids[..self.local_ids.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(33 + local_ids.len() * 4);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(local_ids.len() as u8);
+        for local_id in local_ids {
+            bytes.extend_from_slice(&local_id.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,125 @@
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::{read_uuid, read_vec3, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use bitflags::bitflags;
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 90
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_duplicate(object_duplicate: ObjectDuplicate) -> Self {
+        Packet {
+            header: Header {
+                id: 90,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectDuplicate(Box::new(object_duplicate)),
+        }
+    }
+}
+
+bitflags! {
+    /// How the copies made by ObjectDuplicate and ObjectDuplicateOnRay are created.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct DuplicateFlags: u32 {
+        /// select the copies once they are made, like the viewer does
+        const CREATE_SELECTED = 0x2;
+    }
+}
+
+/// Copies objects, placing each copy at an offset from its original, like shift-dragging in the
+/// viewer. The simulator sends the copies back as ObjectUpdates.
+/// https://wiki.secondlife.com/wiki/ObjectDuplicate
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDuplicate {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group that owns the copies, nil for the agent
+    pub group_id: Uuid,
+    /// how far from its original each copy is placed, in meters
+    pub offset: Vec3,
+    pub duplicate_flags: DuplicateFlags,
+    /// the local ids of the objects in the region
+    pub local_ids: Vec<u32>,
+}
+
+impl ObjectDuplicate {
+    /// copy objects to an offset, selecting the copies. The agent, session and group ids are
+    /// left nil, for the session to fill in.
+    pub fn new(offset: Vec3, local_ids: Vec<u32>) -> Self {
+        ObjectDuplicate {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            group_id: Uuid::nil(),
+            offset,
+            duplicate_flags: DuplicateFlags::CREATE_SELECTED,
+            local_ids,
+        }
+    }
+
+    /// split a copy of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectDuplicate> {
+        chunk_object_blocks(&self.local_ids)
+            .into_iter()
+            .map(|local_ids| ObjectDuplicate {
+                local_ids: local_ids.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectDuplicate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let group_id = read_uuid(&mut cursor)?;
+        let offset = read_vec3(&mut cursor)?;
+        let duplicate_flags = DuplicateFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?);
+        let count = cursor.read_u8()?;
+        let mut local_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            local_ids.push(cursor.read_u32::<LittleEndian>()?);
+        }
+        Ok(ObjectDuplicate {
+            agent_id,
+            session_id,
+            group_id,
+            offset,
+            duplicate_flags,
+            local_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let local_ids = &self.local_ids[..self.local_ids.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(65 + local_ids.len() * 4);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        write_vec3(&mut bytes, &self.offset);
+        bytes.extend_from_slice(&self.duplicate_flags.bits().to_le_bytes());
+        bytes.push(local_ids.len() as u8);
+        for local_id in local_ids {
+            bytes.extend_from_slice(&local_id.to_le_bytes());
+        }
+        bytes
+    }
+}
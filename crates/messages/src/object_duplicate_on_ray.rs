@@ -0,0 +1,154 @@
+use crate::object_duplicate::DuplicateFlags;
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::{read_uuid, read_vec3, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 91
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_duplicate_on_ray(object_duplicate_on_ray: ObjectDuplicateOnRay) -> Self {
+        Packet {
+            header: Header {
+                id: 91,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectDuplicateOnRay(Box::new(object_duplicate_on_ray)),
+        }
+    }
+}
+
+/// Copies objects to where a ray from the camera hits, like the viewer's create tool does with
+/// copy selection turned on. The simulator casts the ray the same way it does for ObjectAdd, and
+/// sends the copies back as ObjectUpdates.
+/// https://wiki.secondlife.com/wiki/ObjectDuplicateOnRay
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDuplicateOnRay {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group that owns the copies, nil for the agent
+    pub group_id: Uuid,
+    /// where the ray starts, usually the camera, in region coordinates
+    pub ray_start: Vec3,
+    /// where the ray ends, in region coordinates
+    pub ray_end: Vec3,
+    /// place the copies at ray_end without casting the ray
+    pub bypass_raycast: bool,
+    /// ray_end is already where the ray hits
+    pub ray_end_is_intersection: bool,
+    /// place the centers of the copies where the ray hits, instead of resting them on the surface
+    pub copy_centers: bool,
+    /// rotate the copies to the surface the ray hits
+    pub copy_rotates: bool,
+    /// the object the ray is aimed at, nil for none
+    pub ray_target_id: Uuid,
+    pub duplicate_flags: DuplicateFlags,
+    /// the local ids of the objects in the region
+    pub local_ids: Vec<u32>,
+}
+
+impl ObjectDuplicateOnRay {
+    /// copy objects to where a ray hits, with the viewer's defaults of copying centers and
+    /// selecting the copies. The agent, session and group ids are left nil, for the session to
+    /// fill in.
+    pub fn new(ray_start: Vec3, ray_end: Vec3, ray_target_id: Uuid, local_ids: Vec<u32>) -> Self {
+        ObjectDuplicateOnRay {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            group_id: Uuid::nil(),
+            ray_start,
+            ray_end,
+            bypass_raycast: false,
+            ray_end_is_intersection: false,
+            copy_centers: true,
+            copy_rotates: false,
+            ray_target_id,
+            duplicate_flags: DuplicateFlags::CREATE_SELECTED,
+            local_ids,
+        }
+    }
+
+    /// split a copy of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectDuplicateOnRay> {
+        chunk_object_blocks(&self.local_ids)
+            .into_iter()
+            .map(|local_ids| ObjectDuplicateOnRay {
+                local_ids: local_ids.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectDuplicateOnRay {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let group_id = read_uuid(&mut cursor)?;
+        let ray_start = read_vec3(&mut cursor)?;
+        let ray_end = read_vec3(&mut cursor)?;
+        let bypass_raycast = cursor.read_u8()? != 0;
+        let ray_end_is_intersection = cursor.read_u8()? != 0;
+        let copy_centers = cursor.read_u8()? != 0;
+        let copy_rotates = cursor.read_u8()? != 0;
+        let ray_target_id = read_uuid(&mut cursor)?;
+        let duplicate_flags = DuplicateFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?);
+        let count = cursor.read_u8()?;
+        let mut local_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            local_ids.push(cursor.read_u32::<LittleEndian>()?);
+        }
+        Ok(ObjectDuplicateOnRay {
+            agent_id,
+            session_id,
+            group_id,
+            ray_start,
+            ray_end,
+            bypass_raycast,
+            ray_end_is_intersection,
+            copy_centers,
+            copy_rotates,
+            ray_target_id,
+            duplicate_flags,
+            local_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let local_ids = &self.local_ids[..self.local_ids.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(97 + local_ids.len() * 4);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        write_vec3(&mut bytes, &self.ray_start);
+        write_vec3(&mut bytes, &self.ray_end);
+        bytes.push(self.bypass_raycast as u8);
+        bytes.push(self.ray_end_is_intersection as u8);
+        bytes.push(self.copy_centers as u8);
+        bytes.push(self.copy_rotates as u8);
+        bytes.extend_from_slice(self.ray_target_id.as_bytes());
+        bytes.extend_from_slice(&self.duplicate_flags.bits().to_le_bytes());
+        bytes.push(local_ids.len() as u8);
+        for local_id in local_ids {
+            bytes.extend_from_slice(&local_id.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Sent from the session to the UI once an edit of objects it sent, like an ObjectImage or
+/// ObjectName, has gone to the simulator, with a warning for everything that had to be changed
+/// on the way, like names cut down to the length the simulator keeps.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectEditAck {
+    /// the local ids of the objects that were edited
+    pub local_ids: Vec<u32>,
+    /// human readable warnings, empty if the edit was sent as it was
+    pub warnings: Vec<String>,
+}
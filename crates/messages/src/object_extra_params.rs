@@ -0,0 +1,145 @@
+use crate::packet_types::PacketType;
+use crate::utils::extra_params::ExtraParam;
+use crate::utils::object_blocks::{chunk_sized_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::{read_uuid, read_variable_1, write_variable_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 99
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_extra_params(object_extra_params: ObjectExtraParams) -> Self {
+        Packet {
+            header: Header {
+                id: 99,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectExtraParams(Box::new(object_extra_params)),
+        }
+    }
+}
+
+/// Sets or removes one extra param, like flexible or light, on each of a list of objects.
+/// https://wiki.secondlife.com/wiki/ObjectExtraParams
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectExtraParams {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub objects: Vec<ObjectExtraParamsData>,
+}
+
+/// the change to one param of one object
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectExtraParamsData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    pub param_type: u16,
+    /// false removes the param from the object
+    pub in_use: bool,
+    pub param_data: Vec<u8>,
+}
+
+impl ObjectExtraParamsData {
+    /// set a param on an object, or remove the param of its type if in_use is false
+    pub fn new(local_id: u32, param: &ExtraParam, in_use: bool) -> Self {
+        ObjectExtraParamsData {
+            local_id,
+            param_type: param.param_type(),
+            in_use,
+            // the simulator ignores the data of a param being removed
+            param_data: if in_use { param.to_data() } else { Vec::new() },
+        }
+    }
+
+    /// parse the param data
+    pub fn param(&self) -> io::Result<ExtraParam> {
+        ExtraParam::from_data(self.param_type, &self.param_data)
+    }
+}
+
+impl ObjectExtraParams {
+    /// set extra params on objects. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(objects: Vec<ObjectExtraParamsData>) -> Self {
+        ObjectExtraParams {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            objects,
+        }
+    }
+
+    /// split an edit of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectExtraParams> {
+        chunk_sized_object_blocks(&self.objects, |object| 12 + object.param_data.len())
+            .into_iter()
+            .map(|objects| ObjectExtraParams {
+                objects: objects.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectExtraParams {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let local_id = cursor.read_u32::<LittleEndian>()?;
+            let param_type = cursor.read_u16::<LittleEndian>()?;
+            let in_use = cursor.read_u8()? != 0;
+            // the size repeats the length of the data that follows it
+            let _param_size = cursor.read_u32::<LittleEndian>()?;
+            let param_data = read_variable_1(&mut cursor)?;
+            objects.push(ObjectExtraParamsData {
+                local_id,
+                param_type,
+                in_use,
+                param_data,
+            });
+        }
+        Ok(ObjectExtraParams {
+            agent_id,
+            session_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(
+            33 + objects
+                .iter()
+                .map(|object| 12 + object.param_data.len())
+                .sum::<usize>(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            bytes.extend_from_slice(&object.param_type.to_le_bytes());
+            bytes.push(object.in_use as u8);
+            bytes.extend_from_slice(&(object.param_data.len() as u32).to_le_bytes());
+            write_variable_1(&mut bytes, &object.param_data);
+        }
+        bytes
+    }
+}
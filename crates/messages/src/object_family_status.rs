@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::object_properties_family::ObjectPropertiesFamily;
+
+/// Sent from the session to the UI when a QueryObjectFamily it sent is answered, or the object
+/// wasn't found. The simulator answers objects it doesn't know with a nil object id, and those
+/// answers are reported as not found, as are queries it never answers.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectFamilyStatus {
+    /// the token of the QueryObjectFamily this answers
+    pub token: u32,
+    /// the full id of the object that was asked about
+    pub object_id: Uuid,
+    /// the properties of the object, None if it wasn't found
+    pub family: Option<ObjectPropertiesFamily>,
+    /// human readable reason the object wasn't found
+    pub reason: Option<String>,
+}
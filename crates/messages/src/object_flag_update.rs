@@ -0,0 +1,185 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::prim_flags::PrimFlags;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 94
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_flag_update(object_flag_update: ObjectFlagUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 94,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectFlagUpdate(Box::new(object_flag_update)),
+        }
+    }
+}
+
+/// Turns physics, phantom and temporary on or off for an object, and optionally overrides how
+/// the physics engine treats it. The flags are all sent every time, so the ones that aren't
+/// being changed have to be sent as they are.
+/// https://wiki.secondlife.com/wiki/ObjectFlagUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectFlagUpdate {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the local id of the object in the region
+    pub local_id: u32,
+    pub use_physics: bool,
+    pub is_temporary: bool,
+    pub is_phantom: bool,
+    /// unused, the viewer always sends false
+    pub casts_shadows: bool,
+    /// physics overrides. Older simulators reject the packet if the ExtraPhysics block is sent
+    /// at all, so it is only written when this is set.
+    pub extra_physics: Option<ExtraPhysics>,
+}
+
+/// how the physics engine treats an object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtraPhysics {
+    pub shape_type: PhysicsShapeType,
+    /// in kg/m³
+    pub density: f32,
+    pub friction: f32,
+    /// how much the object bounces, from 0 to 1
+    pub restitution: f32,
+    /// how strongly gravity pulls the object, 1 being normal
+    pub gravity_multiplier: f32,
+}
+
+/// the shape the physics engine uses for an object
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PhysicsShapeType {
+    /// the shape of the prim
+    Prim,
+    /// no shape, which only child prims of a linkset can have
+    None,
+    /// a convex hull around the object
+    ConvexHull,
+    Unknown(u8),
+}
+
+impl PhysicsShapeType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => PhysicsShapeType::Prim,
+            1 => PhysicsShapeType::None,
+            2 => PhysicsShapeType::ConvexHull,
+            other => PhysicsShapeType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            PhysicsShapeType::Prim => 0,
+            PhysicsShapeType::None => 1,
+            PhysicsShapeType::ConvexHull => 2,
+            PhysicsShapeType::Unknown(other) => *other,
+        }
+    }
+}
+
+impl ObjectFlagUpdate {
+    /// set the physics, phantom and temporary flags of an object from its flags, ignoring the
+    /// ones that can't be changed. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(local_id: u32, flags: PrimFlags) -> Self {
+        ObjectFlagUpdate {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            local_id,
+            use_physics: flags.contains(PrimFlags::USE_PHYSICS),
+            is_temporary: flags.contains(PrimFlags::TEMPORARY_ON_REZ),
+            is_phantom: flags.contains(PrimFlags::PHANTOM),
+            casts_shadows: false,
+            extra_physics: None,
+        }
+    }
+
+    /// the flags being set
+    pub fn flags(&self) -> PrimFlags {
+        let mut flags = PrimFlags::empty();
+        flags.set(PrimFlags::USE_PHYSICS, self.use_physics);
+        flags.set(PrimFlags::TEMPORARY_ON_REZ, self.is_temporary);
+        flags.set(PrimFlags::PHANTOM, self.is_phantom);
+        flags
+    }
+}
+
+impl PacketData for ObjectFlagUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let local_id = cursor.read_u32::<LittleEndian>()?;
+        let use_physics = cursor.read_u8()? != 0;
+        let is_temporary = cursor.read_u8()? != 0;
+        let is_phantom = cursor.read_u8()? != 0;
+        let casts_shadows = cursor.read_u8()? != 0;
+        // the short form ends here, without even a block count
+        let count = if (cursor.position() as usize) < bytes.len() {
+            cursor.read_u8()?
+        } else {
+            0
+        };
+        let mut extra_physics = None;
+        for _ in 0..count {
+            extra_physics = Some(ExtraPhysics {
+                shape_type: PhysicsShapeType::from_bytes(cursor.read_u8()?),
+                density: cursor.read_f32::<LittleEndian>()?,
+                friction: cursor.read_f32::<LittleEndian>()?,
+                restitution: cursor.read_f32::<LittleEndian>()?,
+                gravity_multiplier: cursor.read_f32::<LittleEndian>()?,
+            });
+        }
+        Ok(ObjectFlagUpdate {
+            agent_id,
+            session_id,
+            local_id,
+            use_physics,
+            is_temporary,
+            is_phantom,
+            casts_shadows,
+            extra_physics,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(58);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes.push(self.use_physics as u8);
+        bytes.push(self.is_temporary as u8);
+        bytes.push(self.is_phantom as u8);
+        bytes.push(self.casts_shadows as u8);
+        if let Some(extra_physics) = &self.extra_physics {
+            bytes.push(1);
+            bytes.push(extra_physics.shape_type.to_bytes());
+            bytes.extend_from_slice(&extra_physics.density.to_le_bytes());
+            bytes.extend_from_slice(&extra_physics.friction.to_le_bytes());
+            bytes.extend_from_slice(&extra_physics.restitution.to_le_bytes());
+            bytes.extend_from_slice(&extra_physics.gravity_multiplier.to_le_bytes());
+        }
+        bytes
+    }
+}
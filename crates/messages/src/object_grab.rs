@@ -0,0 +1,77 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_vec3, write_vec3};
+use crate::utils::surface_info::{read_surface_info, write_surface_info, SurfaceInfo};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 117
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_grab(object_grab: ObjectGrab) -> Self {
+        Packet {
+            header: Header {
+                id: 117,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectGrab(Box::new(object_grab)),
+        }
+    }
+}
+
+/// Starts grabbing an object, which is how objects are touched. Scripts get a touch_start event.
+/// The grab is held until an ObjectDeGrab releases it, and can be dragged with ObjectGrabUpdate.
+/// https://wiki.secondlife.com/wiki/ObjectGrab
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectGrab {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// where the object was grabbed, relative to its position
+    pub grab_offset: Vec3,
+    /// where on the object it was grabbed. Empty if it isn't known.
+    pub surface_info: Vec<SurfaceInfo>,
+}
+
+impl PacketData for ObjectGrab {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let local_id = cursor.read_u32::<LittleEndian>()?;
+        let grab_offset = read_vec3(&mut cursor)?;
+        let surface_info = read_surface_info(&mut cursor)?;
+        Ok(ObjectGrab {
+            agent_id,
+            session_id,
+            local_id,
+            grab_offset,
+            surface_info,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(49);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        write_vec3(&mut bytes, &self.grab_offset);
+        write_surface_info(&mut bytes, &self.surface_info);
+        bytes
+    }
+}
@@ -0,0 +1,86 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_vec3, write_vec3};
+use crate::utils::surface_info::{read_surface_info, write_surface_info, SurfaceInfo};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 118
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_grab_update(object_grab_update: ObjectGrabUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 118,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectGrabUpdate(Box::new(object_grab_update)),
+        }
+    }
+}
+
+/// Drags an object that is being grabbed. Scripts get a touch event for each update.
+/// Unlike ObjectGrab and ObjectDeGrab, the object is named by its full id.
+/// https://wiki.secondlife.com/wiki/ObjectGrabUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectGrabUpdate {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub object_id: Uuid,
+    /// the grab offset sent in the ObjectGrab
+    pub grab_offset_initial: Vec3,
+    /// where the object is being dragged to, in region coordinates
+    pub grab_position: Vec3,
+    /// milliseconds since the last update
+    pub time_since_last: u32,
+    /// where on the object it is being grabbed. Empty if it isn't known.
+    pub surface_info: Vec<SurfaceInfo>,
+}
+
+impl PacketData for ObjectGrabUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let object_id = read_uuid(&mut cursor)?;
+        let grab_offset_initial = read_vec3(&mut cursor)?;
+        let grab_position = read_vec3(&mut cursor)?;
+        let time_since_last = cursor.read_u32::<LittleEndian>()?;
+        let surface_info = read_surface_info(&mut cursor)?;
+        Ok(ObjectGrabUpdate {
+            agent_id,
+            session_id,
+            object_id,
+            grab_offset_initial,
+            grab_position,
+            time_since_last,
+            surface_info,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(77);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        write_vec3(&mut bytes, &self.grab_offset_initial);
+        write_vec3(&mut bytes, &self.grab_position);
+        bytes.extend_from_slice(&self.time_since_last.to_le_bytes());
+        write_surface_info(&mut bytes, &self.surface_info);
+        bytes
+    }
+}
@@ -0,0 +1,143 @@
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_sized_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::{
+    read_string_1, read_uuid, read_variable_2, write_string_1, write_variable_2,
+};
+use crate::utils::texture_entry::TextureEntry;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 96
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_image(object_image: ObjectImage) -> Self {
+        Packet {
+            header: Header {
+                id: 96,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectImage(Box::new(object_image)),
+        }
+    }
+}
+
+/// Sets the textures, colors and other face properties of objects, and their media URL. The
+/// simulator sends the objects back as ObjectUpdates with the new TextureEntry.
+/// https://wiki.secondlife.com/wiki/ObjectImage
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectImage {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub objects: Vec<ObjectImageData>,
+}
+
+/// the new faces of one object
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectImageData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// the URL of the object's media, empty for none
+    pub media_url: String,
+    /// the packed TextureEntry with the new faces
+    pub texture_entry: Vec<u8>,
+}
+
+impl ObjectImageData {
+    /// set the faces of an object, without media
+    pub fn new(local_id: u32, texture_entry: &TextureEntry) -> Self {
+        ObjectImageData {
+            local_id,
+            media_url: String::new(),
+            texture_entry: texture_entry.to_bytes(),
+        }
+    }
+
+    /// parse the TextureEntry of the object
+    pub fn texture_entry(&self) -> io::Result<TextureEntry> {
+        TextureEntry::from_bytes(&self.texture_entry)
+    }
+
+    // how many bytes the block takes in the packet
+    fn size(&self) -> usize {
+        let media_url = match self.media_url.len() {
+            0 => 0,
+            length => (length + 1).min(u8::MAX as usize),
+        };
+        4 + 1 + media_url + 2 + self.texture_entry.len()
+    }
+}
+
+impl ObjectImage {
+    /// set the faces of objects. The agent and session ids are left nil, for the session to fill
+    /// in.
+    pub fn new(objects: Vec<ObjectImageData>) -> Self {
+        ObjectImage {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            objects,
+        }
+    }
+
+    /// split an edit of any number of objects into packets. TextureEntries can be large, so a
+    /// packet holds as many objects as fit in it.
+    pub fn split(&self) -> Vec<ObjectImage> {
+        chunk_sized_object_blocks(&self.objects, ObjectImageData::size)
+            .into_iter()
+            .map(|objects| ObjectImage {
+                objects: objects.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectImage {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectImageData {
+                local_id: cursor.read_u32::<LittleEndian>()?,
+                media_url: read_string_1(&mut cursor)?,
+                texture_entry: read_variable_2(&mut cursor)?,
+            });
+        }
+        Ok(ObjectImage {
+            agent_id,
+            session_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes =
+            Vec::with_capacity(33 + objects.iter().map(ObjectImageData::size).sum::<usize>());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            write_string_1(&mut bytes, &object.media_url);
+            write_variable_2(&mut bytes, &object.texture_entry);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,130 @@
+use crate::errors::LinkObjectsError;
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::MAX_OBJECT_BLOCKS;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 123
+// Frequency: Low
+
+/// the most prims a link set can have, the root and 255 children
+pub const MAX_LINK_SET_PRIMS: usize = 256;
+
+impl Packet {
+    pub fn new_object_link(object_link: ObjectLink) -> Self {
+        Packet {
+            header: Header {
+                id: 123,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectLink(Box::new(object_link)),
+        }
+    }
+}
+
+/// Links objects into one link set. The order of the local ids matters: the first becomes the
+/// root of the link set, and the rest become its children. The simulator doesn't check which
+/// object was meant to be the root, so ObjectLink::new takes the root separately.
+/// https://wiki.secondlife.com/wiki/ObjectLink
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectLink {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the local ids of the objects in the region, root first
+    pub local_ids: Vec<u32>,
+}
+
+impl ObjectLink {
+    /// link children to a root. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(root_id: u32, child_ids: Vec<u32>) -> Self {
+        let mut local_ids = Vec::with_capacity(child_ids.len() + 1);
+        local_ids.push(root_id);
+        local_ids.extend(child_ids);
+        ObjectLink {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            local_ids,
+        }
+    }
+
+    /// the object that becomes the root of the link set
+    pub fn root_id(&self) -> Option<u32> {
+        self.local_ids.first().copied()
+    }
+
+    /// the objects that become children of the root
+    pub fn child_ids(&self) -> &[u32] {
+        self.local_ids.get(1..).unwrap_or_default()
+    }
+
+    /// check the link before sending it. The simulator ignores a link without children, and
+    /// refuses a link set of more than 256 prims without saying so. One packet also only has
+    /// room for 255 objects.
+    pub fn check(&self) -> Result<(), LinkObjectsError> {
+        if self.local_ids.len() < 2 {
+            return Err(LinkObjectsError::new(
+                "cannot link without a root and at least one child",
+            ));
+        }
+        if self.local_ids.len() > MAX_LINK_SET_PRIMS {
+            return Err(LinkObjectsError::new(format!(
+                "cannot link {} prims, a link set can have at most {}",
+                self.local_ids.len(),
+                MAX_LINK_SET_PRIMS
+            )));
+        }
+        if self.local_ids.len() > MAX_OBJECT_BLOCKS {
+            return Err(LinkObjectsError::new(format!(
+                "cannot link {} prims in one ObjectLink, it has room for {}",
+                self.local_ids.len(),
+                MAX_OBJECT_BLOCKS
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl PacketData for ObjectLink {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut local_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            local_ids.push(cursor.read_u32::<LittleEndian>()?);
+        }
+        Ok(ObjectLink {
+            agent_id,
+            session_id,
+            local_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let local_ids = &self.local_ids[..self.local_ids.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(33 + local_ids.len() * 4);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(local_ids.len() as u8);
+        for local_id in local_ids {
+            bytes.extend_from_slice(&local_id.to_le_bytes());
+        }
+        bytes
+    }
+}
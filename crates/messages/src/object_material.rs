@@ -0,0 +1,108 @@
+use crate::packet_types::PacketType;
+use crate::utils::material::Material;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 97
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_material(object_material: ObjectMaterial) -> Self {
+        Packet {
+            header: Header {
+                id: 97,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectMaterial(Box::new(object_material)),
+        }
+    }
+}
+
+/// Sets the physical material of objects.
+/// https://wiki.secondlife.com/wiki/ObjectMaterial
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectMaterial {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub objects: Vec<ObjectMaterialData>,
+}
+
+/// the new material of one object
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectMaterialData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    pub material: Material,
+}
+
+impl ObjectMaterial {
+    /// set the materials of objects. The agent and session ids are left nil, for the session
+    /// to fill in.
+    pub fn new(objects: Vec<ObjectMaterialData>) -> Self {
+        ObjectMaterial {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            objects,
+        }
+    }
+
+    /// split an edit of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectMaterial> {
+        chunk_object_blocks(&self.objects)
+            .into_iter()
+            .map(|objects| ObjectMaterial {
+                objects: objects.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectMaterial {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectMaterialData {
+                local_id: cursor.read_u32::<LittleEndian>()?,
+                material: Material::from_bytes(cursor.read_u8()?),
+            });
+        }
+        Ok(ObjectMaterial {
+            agent_id,
+            session_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(33 + objects.len() * 5);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            bytes.push(object.material.to_bytes());
+        }
+        bytes
+    }
+}
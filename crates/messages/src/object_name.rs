@@ -0,0 +1,134 @@
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_sized_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::{read_string_1, read_uuid, truncate_str, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 107
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_name(object_name: ObjectName) -> Self {
+        Packet {
+            header: Header {
+                id: 107,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectName(Box::new(object_name)),
+        }
+    }
+}
+
+/// Renames objects. Names longer than the simulator keeps have to be cut down with truncate first.
+/// https://wiki.secondlife.com/wiki/ObjectName
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectName {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub objects: Vec<ObjectNameData>,
+}
+
+/// the new name of one object
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectNameData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    pub name: String,
+}
+
+impl ObjectName {
+    /// the longest name the simulator keeps, in bytes
+    pub const MAX_NAME_BYTES: usize = 63;
+
+    /// set the names of objects. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(objects: Vec<ObjectNameData>) -> Self {
+        ObjectName {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            objects,
+        }
+    }
+
+    /// cut the names longer than MAX_NAME_BYTES down to size, on a character boundary,
+    /// returning a warning for each one that was cut
+    pub fn truncate(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for object in self.objects.iter_mut() {
+            if object.name.len() > Self::MAX_NAME_BYTES {
+                let truncated = truncate_str(&object.name, Self::MAX_NAME_BYTES).to_string();
+                warnings.push(format!(
+                    "name of object {} cut from {} to {} bytes",
+                    object.local_id,
+                    object.name.len(),
+                    truncated.len()
+                ));
+                object.name = truncated;
+            }
+        }
+        warnings
+    }
+
+    /// split an edit of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectName> {
+        chunk_sized_object_blocks(&self.objects, |object| 6 + object.name.len())
+            .into_iter()
+            .map(|objects| ObjectName {
+                objects: objects.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectName {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectNameData {
+                local_id: cursor.read_u32::<LittleEndian>()?,
+                name: read_string_1(&mut cursor)?,
+            });
+        }
+        Ok(ObjectName {
+            agent_id,
+            session_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(
+            33 + objects
+                .iter()
+                .map(|object| 6 + object.name.len())
+                .sum::<usize>(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            write_string_1(&mut bytes, &object.name);
+        }
+        bytes
+    }
+}
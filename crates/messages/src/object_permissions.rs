@@ -0,0 +1,177 @@
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::permissions::PermissionMask;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 105
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_permissions(object_permissions: ObjectPermissions) -> Self {
+        Packet {
+            header: Header {
+                id: 105,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectPermissions(Box::new(object_permissions)),
+        }
+    }
+}
+
+/// Changes the permissions of objects, like what the next owner can do with them. Each object
+/// sets or clears the bits of one of its permission masks.
+/// https://wiki.secondlife.com/wiki/ObjectPermissions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectPermissions {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// change the permissions as a god, ignoring what the agent is allowed to change
+    pub override_permissions: bool,
+    pub objects: Vec<ObjectPermissionsData>,
+}
+
+/// the change to the permissions of one object
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectPermissionsData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// the mask being changed
+    pub field: PermissionField,
+    /// true to set the bits of the mask, false to clear them
+    pub set: bool,
+    /// the bits to set or clear. The others are left as they are.
+    pub mask: PermissionMask,
+}
+
+/// the permission masks of an object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PermissionField {
+    /// the most permissions anyone can have
+    Base,
+    Owner,
+    Group,
+    Everyone,
+    /// the permissions the next owner will get
+    NextOwner,
+}
+
+impl PermissionField {
+    /// the field for a byte, or None if it isn't one of the permission masks
+    pub fn from_bytes(byte: u8) -> Option<Self> {
+        match byte {
+            0x01 => Some(PermissionField::Base),
+            0x02 => Some(PermissionField::Owner),
+            0x04 => Some(PermissionField::Group),
+            0x08 => Some(PermissionField::Everyone),
+            0x10 => Some(PermissionField::NextOwner),
+            _ => None,
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            PermissionField::Base => 0x01,
+            PermissionField::Owner => 0x02,
+            PermissionField::Group => 0x04,
+            PermissionField::Everyone => 0x08,
+            PermissionField::NextOwner => 0x10,
+        }
+    }
+}
+
+impl ObjectPermissions {
+    /// set or clear the same bits of the same mask on every object. The agent and session ids
+    /// are left nil, for the session to fill in.
+    pub fn new(local_ids: &[u32], field: PermissionField, set: bool, mask: PermissionMask) -> Self {
+        ObjectPermissions {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            override_permissions: false,
+            objects: local_ids
+                .iter()
+                .map(|local_id| ObjectPermissionsData {
+                    local_id: *local_id,
+                    field,
+                    set,
+                    mask,
+                })
+                .collect(),
+        }
+    }
+
+    /// split an edit of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectPermissions> {
+        chunk_object_blocks(&self.objects)
+            .into_iter()
+            .map(|objects| ObjectPermissions {
+                objects: objects.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectPermissions {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let override_permissions = cursor.read_u8()? != 0;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let local_id = cursor.read_u32::<LittleEndian>()?;
+            let field_byte = cursor.read_u8()?;
+            let field = PermissionField::from_bytes(field_byte).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown permission field: {:#04x}", field_byte),
+                )
+            })?;
+            objects.push(ObjectPermissionsData {
+                local_id,
+                field,
+                set: cursor.read_u8()? != 0,
+                mask: PermissionMask::from_bits_retain(cursor.read_u32::<LittleEndian>()?),
+            });
+        }
+        Ok(ObjectPermissions {
+            agent_id,
+            session_id,
+            override_permissions,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(34 + objects.len() * 10);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(self.override_permissions as u8);
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            bytes.push(object.field.to_bytes());
+            bytes.push(object.set as u8);
+            bytes.extend_from_slice(&object.mask.bits().to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,174 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_string_1, read_uuid, read_variable_1, write_string_1, write_variable_1,
+};
+use crate::utils::permissions::Permissions;
+use crate::utils::sale_type::SaleType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 9
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_object_properties(object_properties: ObjectProperties) -> Self {
+        Packet {
+            header: Header {
+                id: 9,
+                frequency: PacketFrequency::Medium,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectProperties(Box::new(object_properties)),
+        }
+    }
+}
+
+/// The properties of selected objects, sent in answer to an ObjectSelect.
+/// A single packet can contain many ObjectData blocks, one for each object in a link set.
+/// https://wiki.secondlife.com/wiki/ObjectProperties
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ObjectProperties {
+    pub objects: Vec<ObjectPropertiesData>,
+}
+
+/// the properties of one object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectPropertiesData {
+    pub object_id: Uuid,
+    pub creator_id: Uuid,
+    pub owner_id: Uuid,
+    pub group_id: Uuid,
+    /// when the object was created, in microseconds since the unix epoch
+    pub creation_date: u64,
+    /// the most permissions anyone can have
+    pub base_mask: Permissions,
+    pub owner_mask: Permissions,
+    pub group_mask: Permissions,
+    pub everyone_mask: Permissions,
+    /// the permissions the next owner will get
+    pub next_owner_mask: Permissions,
+    pub ownership_cost: i32,
+    pub sale_type: SaleType,
+    pub sale_price: i32,
+    /// the combined permissions of the object's inventory
+    pub aggregate_perms: u8,
+    pub aggregate_perm_textures: u8,
+    pub aggregate_perm_textures_owner: u8,
+    pub category: u32,
+    /// increases every time the object's inventory changes
+    pub inventory_serial: i16,
+    /// the inventory item the object was rezzed from
+    pub item_id: Uuid,
+    pub folder_id: Uuid,
+    /// the object that rezzed this one
+    pub from_task_id: Uuid,
+    pub last_owner_id: Uuid,
+    pub name: String,
+    pub description: String,
+    /// the text shown in the pie menu instead of "Touch"
+    pub touch_name: String,
+    /// the text shown in the pie menu instead of "Sit Here"
+    pub sit_name: String,
+    /// the texture ids of the object's faces, packed one after another
+    pub texture_id: Vec<u8>,
+}
+
+impl ObjectPropertiesData {
+    fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        Ok(ObjectPropertiesData {
+            object_id: read_uuid(cursor)?,
+            creator_id: read_uuid(cursor)?,
+            owner_id: read_uuid(cursor)?,
+            group_id: read_uuid(cursor)?,
+            creation_date: cursor.read_u64::<LittleEndian>()?,
+            base_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            owner_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            group_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            everyone_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            next_owner_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            ownership_cost: cursor.read_i32::<LittleEndian>()?,
+            sale_type: SaleType::from_bytes(cursor.read_u8()?),
+            sale_price: cursor.read_i32::<LittleEndian>()?,
+            aggregate_perms: cursor.read_u8()?,
+            aggregate_perm_textures: cursor.read_u8()?,
+            aggregate_perm_textures_owner: cursor.read_u8()?,
+            category: cursor.read_u32::<LittleEndian>()?,
+            inventory_serial: cursor.read_i16::<LittleEndian>()?,
+            item_id: read_uuid(cursor)?,
+            folder_id: read_uuid(cursor)?,
+            from_task_id: read_uuid(cursor)?,
+            last_owner_id: read_uuid(cursor)?,
+            name: read_string_1(cursor)?,
+            description: read_string_1(cursor)?,
+            touch_name: read_string_1(cursor)?,
+            sit_name: read_string_1(cursor)?,
+            texture_id: read_variable_1(cursor)?,
+        })
+    }
+
+    fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(self.creator_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes.extend_from_slice(&self.creation_date.to_le_bytes());
+        bytes.extend_from_slice(&self.base_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.owner_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.group_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.everyone_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.next_owner_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.ownership_cost.to_le_bytes());
+        bytes.push(self.sale_type.to_bytes());
+        bytes.extend_from_slice(&self.sale_price.to_le_bytes());
+        bytes.push(self.aggregate_perms);
+        bytes.push(self.aggregate_perm_textures);
+        bytes.push(self.aggregate_perm_textures_owner);
+        bytes.extend_from_slice(&self.category.to_le_bytes());
+        bytes.extend_from_slice(&self.inventory_serial.to_le_bytes());
+        bytes.extend_from_slice(self.item_id.as_bytes());
+        bytes.extend_from_slice(self.folder_id.as_bytes());
+        bytes.extend_from_slice(self.from_task_id.as_bytes());
+        bytes.extend_from_slice(self.last_owner_id.as_bytes());
+        write_string_1(bytes, &self.name);
+        write_string_1(bytes, &self.description);
+        write_string_1(bytes, &self.touch_name);
+        write_string_1(bytes, &self.sit_name);
+        write_variable_1(bytes, &self.texture_id);
+    }
+}
+
+impl PacketData for ObjectProperties {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectPropertiesData::from_bytes(&mut cursor)?);
+        }
+        Ok(ObjectProperties { objects })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::new();
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            object.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,107 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+use crate::utils::permissions::Permissions;
+use crate::utils::sale_type::SaleType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 10
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_object_properties_family(object_properties_family: ObjectPropertiesFamily) -> Self {
+        Packet {
+            header: Header {
+                id: 10,
+                frequency: PacketFrequency::Medium,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectPropertiesFamily(Box::new(object_properties_family)),
+        }
+    }
+}
+
+/// The simulator's answer to a RequestObjectPropertiesFamily, with the properties of an object
+/// shown when hovering over it. An object the simulator doesn't know is answered with a nil
+/// object id.
+/// https://wiki.secondlife.com/wiki/ObjectPropertiesFamily
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectPropertiesFamily {
+    /// the request flags of the request this answers
+    pub request_flags: u32,
+    pub object_id: Uuid,
+    pub owner_id: Uuid,
+    pub group_id: Uuid,
+    /// the most permissions anyone can have
+    pub base_mask: Permissions,
+    pub owner_mask: Permissions,
+    pub group_mask: Permissions,
+    pub everyone_mask: Permissions,
+    /// the permissions the next owner will get
+    pub next_owner_mask: Permissions,
+    pub ownership_cost: i32,
+    pub sale_type: SaleType,
+    pub sale_price: i32,
+    pub category: u32,
+    pub last_owner_id: Uuid,
+    pub name: String,
+    pub description: String,
+}
+
+impl PacketData for ObjectPropertiesFamily {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ObjectPropertiesFamily {
+            request_flags: cursor.read_u32::<LittleEndian>()?,
+            object_id: read_uuid(&mut cursor)?,
+            owner_id: read_uuid(&mut cursor)?,
+            group_id: read_uuid(&mut cursor)?,
+            base_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            owner_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            group_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            everyone_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            next_owner_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            ownership_cost: cursor.read_i32::<LittleEndian>()?,
+            sale_type: SaleType::from_bytes(cursor.read_u8()?),
+            sale_price: cursor.read_i32::<LittleEndian>()?,
+            category: cursor.read_u32::<LittleEndian>()?,
+            last_owner_id: read_uuid(&mut cursor)?,
+            name: read_string_1(&mut cursor)?,
+            description: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(103 + self.name.len() + self.description.len());
+        bytes.extend_from_slice(&self.request_flags.to_le_bytes());
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes.extend_from_slice(&self.base_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.owner_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.group_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.everyone_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.next_owner_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.ownership_cost.to_le_bytes());
+        bytes.push(self.sale_type.to_bytes());
+        bytes.extend_from_slice(&self.sale_price.to_le_bytes());
+        bytes.extend_from_slice(&self.category.to_le_bytes());
+        bytes.extend_from_slice(self.last_owner_id.as_bytes());
+        write_string_1(&mut bytes, &self.name);
+        write_string_1(&mut bytes, &self.description);
+        bytes
+    }
+}
@@ -0,0 +1,112 @@
+use crate::packet_types::PacketType;
+use crate::utils::object_blocks::{chunk_object_blocks, MAX_OBJECT_BLOCKS};
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::sale_type::SaleType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 106
+// Frequency: Low
+
+impl Packet {
+    pub fn new_object_sale_info(object_sale_info: ObjectSaleInfo) -> Self {
+        Packet {
+            header: Header {
+                id: 106,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectSaleInfo(Box::new(object_sale_info)),
+        }
+    }
+}
+
+/// Puts objects up for sale, or takes them off sale, with the way they are sold and their price.
+/// https://wiki.secondlife.com/wiki/ObjectSaleInfo
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectSaleInfo {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub objects: Vec<ObjectSaleInfoData>,
+}
+
+/// the new sale info of one object
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectSaleInfoData {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    pub sale_type: SaleType,
+    /// the price in L$
+    pub sale_price: i32,
+}
+
+impl ObjectSaleInfo {
+    /// set the sale info of objects. The agent and session ids are left nil, for the session
+    /// to fill in.
+    pub fn new(objects: Vec<ObjectSaleInfoData>) -> Self {
+        ObjectSaleInfo {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            objects,
+        }
+    }
+
+    /// split an edit of any number of objects into packets
+    pub fn split(&self) -> Vec<ObjectSaleInfo> {
+        chunk_object_blocks(&self.objects)
+            .into_iter()
+            .map(|objects| ObjectSaleInfo {
+                objects: objects.to_vec(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ObjectSaleInfo {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut objects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            objects.push(ObjectSaleInfoData {
+                local_id: cursor.read_u32::<LittleEndian>()?,
+                sale_type: SaleType::from_bytes(cursor.read_u8()?),
+                sale_price: cursor.read_i32::<LittleEndian>()?,
+            });
+        }
+        Ok(ObjectSaleInfo {
+            agent_id,
+            session_id,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let objects = &self.objects[..self.objects.len().min(MAX_OBJECT_BLOCKS)];
+        let mut bytes = Vec::with_capacity(33 + objects.len() * 9);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(objects.len() as u8);
+        for object in objects {
+            bytes.extend_from_slice(&object.local_id.to_le_bytes());
+            bytes.push(object.sale_type.to_bytes());
+            bytes.extend_from_slice(&object.sale_price.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,467 @@
+use crate::packet_types::PacketType;
+use crate::utils::attachment_point::AttachmentPoint;
+use crate::utils::extra_params::{
+    read_extra_params, ExtraParam, FlexibleParams, LightParams, MeshParams, SculptParams,
+};
+use crate::utils::packet_fields::{
+    read_string_1, read_string_2, read_uuid, read_variable_1, read_variable_2, read_vec3,
+    write_string_1, write_string_2, write_variable_1, write_variable_2, write_vec3,
+};
+use crate::utils::prim_flags::PrimFlags;
+use crate::utils::texture_entry::TextureEntry;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::{Vec3, Vec4};
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+// ID: 12
+// Frequency: High
+
+impl Packet {
+    pub fn new_object_update(object_update: ObjectUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 12,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ObjectUpdate(Box::new(object_update)),
+        }
+    }
+}
+
+/// Full updates for objects and avatars in the region.
+/// A single packet can contain many ObjectData blocks.
+/// https://wiki.secondlife.com/wiki/ObjectUpdate
+#[derive(Debug, Clone)]
+pub struct ObjectUpdate {
+    pub region_handle: u64,
+    pub time_dilation: u16,
+    pub objects: Vec<ObjectUpdateData>,
+}
+
+/// The type of object being updated
+#[derive(Debug, Clone, PartialEq)]
+pub enum PCode {
+    Primitive,
+    Avatar,
+    Grass,
+    NewTree,
+    ParticleSystem,
+    Tree,
+    Unknown(u8),
+}
+impl PCode {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            9 => PCode::Primitive,
+            47 => PCode::Avatar,
+            95 => PCode::Grass,
+            111 => PCode::NewTree,
+            143 => PCode::ParticleSystem,
+            255 => PCode::Tree,
+            byte => PCode::Unknown(byte),
+        }
+    }
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            PCode::Primitive => 9,
+            PCode::Avatar => 47,
+            PCode::Grass => 95,
+            PCode::NewTree => 111,
+            PCode::ParticleSystem => 143,
+            PCode::Tree => 255,
+            PCode::Unknown(byte) => *byte,
+        }
+    }
+}
+
+/// the shape parameters of a prim
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrimShape {
+    pub path_curve: u8,
+    pub profile_curve: u8,
+    pub path_begin: u16,
+    pub path_end: u16,
+    pub path_scale_x: u8,
+    pub path_scale_y: u8,
+    pub path_shear_x: u8,
+    pub path_shear_y: u8,
+    pub path_twist: i8,
+    pub path_twist_begin: i8,
+    pub path_radius_offset: i8,
+    pub path_taper_x: i8,
+    pub path_taper_y: i8,
+    pub path_revolutions: u8,
+    pub path_skew: i8,
+    pub profile_begin: u16,
+    pub profile_end: u16,
+    pub profile_hollow: u16,
+}
+impl PrimShape {
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        Ok(PrimShape {
+            path_curve: cursor.read_u8()?,
+            profile_curve: cursor.read_u8()?,
+            path_begin: cursor.read_u16::<LittleEndian>()?,
+            path_end: cursor.read_u16::<LittleEndian>()?,
+            path_scale_x: cursor.read_u8()?,
+            path_scale_y: cursor.read_u8()?,
+            path_shear_x: cursor.read_u8()?,
+            path_shear_y: cursor.read_u8()?,
+            path_twist: cursor.read_i8()?,
+            path_twist_begin: cursor.read_i8()?,
+            path_radius_offset: cursor.read_i8()?,
+            path_taper_x: cursor.read_i8()?,
+            path_taper_y: cursor.read_i8()?,
+            path_revolutions: cursor.read_u8()?,
+            path_skew: cursor.read_i8()?,
+            profile_begin: cursor.read_u16::<LittleEndian>()?,
+            profile_end: cursor.read_u16::<LittleEndian>()?,
+            profile_hollow: cursor.read_u16::<LittleEndian>()?,
+        })
+    }
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.push(self.path_curve);
+        bytes.push(self.profile_curve);
+        bytes.extend_from_slice(&self.path_begin.to_le_bytes());
+        bytes.extend_from_slice(&self.path_end.to_le_bytes());
+        bytes.push(self.path_scale_x);
+        bytes.push(self.path_scale_y);
+        bytes.push(self.path_shear_x);
+        bytes.push(self.path_shear_y);
+        bytes.push(self.path_twist as u8);
+        bytes.push(self.path_twist_begin as u8);
+        bytes.push(self.path_radius_offset as u8);
+        bytes.push(self.path_taper_x as u8);
+        bytes.push(self.path_taper_y as u8);
+        bytes.push(self.path_revolutions);
+        bytes.push(self.path_skew as u8);
+        bytes.extend_from_slice(&self.profile_begin.to_le_bytes());
+        bytes.extend_from_slice(&self.profile_end.to_le_bytes());
+        bytes.extend_from_slice(&self.profile_hollow.to_le_bytes());
+    }
+}
+
+/// One ObjectData block of an ObjectUpdate
+#[derive(Debug, Clone)]
+pub struct ObjectUpdateData {
+    pub local_id: u32,
+    pub state: u8,
+    pub full_id: Uuid,
+    pub crc: u32,
+    pub pcode: PCode,
+    pub material: u8,
+    pub click_action: u8,
+    pub scale: Vec3,
+    /// packed position, velocity, acceleration, rotation and angular velocity.
+    /// see ObjectMotion for decoding.
+    pub object_data: Vec<u8>,
+    pub parent_id: u32,
+    pub update_flags: u32,
+    pub shape: PrimShape,
+    pub texture_entry: Vec<u8>,
+    pub texture_anim: Vec<u8>,
+    pub name_value: String,
+    pub data: Vec<u8>,
+    pub text: String,
+    pub text_color: [u8; 4],
+    pub media_url: String,
+    pub ps_block: Vec<u8>,
+    pub extra_params: Vec<u8>,
+    pub sound: Uuid,
+    pub owner_id: Uuid,
+    pub gain: f32,
+    pub flags: u8,
+    pub radius: f32,
+    pub joint_type: u8,
+    pub joint_pivot: Vec3,
+    pub joint_axis_or_anchor: Vec3,
+}
+
+impl ObjectUpdateData {
+    /// parse the TextureEntry of the object's faces
+    pub fn texture_entry(&self) -> io::Result<TextureEntry> {
+        TextureEntry::from_bytes(&self.texture_entry)
+    }
+
+    /// the flags of the object, like whether it is physical or phantom
+    pub fn prim_flags(&self) -> PrimFlags {
+        PrimFlags::from_bits_retain(self.update_flags)
+    }
+
+    /// parse the extra params of the object, like flexible, light, sculpt or mesh
+    pub fn extra_params(&self) -> io::Result<Vec<ExtraParam>> {
+        read_extra_params(&self.extra_params)
+    }
+
+    /// the flexible params of the object, if it has them and they parse
+    pub fn flexible(&self) -> Option<FlexibleParams> {
+        self.extra_params()
+            .ok()?
+            .into_iter()
+            .find_map(|param| match param {
+                ExtraParam::Flexible(flexible) => Some(flexible),
+                _ => None,
+            })
+    }
+
+    /// the light params of the object, if it has them and they parse
+    pub fn light(&self) -> Option<LightParams> {
+        self.extra_params()
+            .ok()?
+            .into_iter()
+            .find_map(|param| match param {
+                ExtraParam::Light(light) => Some(light),
+                _ => None,
+            })
+    }
+
+    /// the sculpt params of the object, if it is a sculpted prim
+    pub fn sculpt(&self) -> Option<SculptParams> {
+        self.extra_params()
+            .ok()?
+            .into_iter()
+            .find_map(|param| match param {
+                ExtraParam::Sculpt(sculpt) => Some(sculpt),
+                _ => None,
+            })
+    }
+
+    /// the mesh params of the object, if it is a mesh
+    pub fn mesh(&self) -> Option<MeshParams> {
+        self.extra_params()
+            .ok()?
+            .into_iter()
+            .find_map(|param| match param {
+                ExtraParam::Mesh(mesh) => Some(mesh),
+                _ => None,
+            })
+    }
+
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        let local_id = cursor.read_u32::<LittleEndian>()?;
+        let state = cursor.read_u8()?;
+        let full_id = read_uuid(cursor)?;
+        let crc = cursor.read_u32::<LittleEndian>()?;
+        let pcode = PCode::from_bytes(cursor.read_u8()?);
+        let material = cursor.read_u8()?;
+        let click_action = cursor.read_u8()?;
+        let scale = read_vec3(cursor)?;
+        let object_data = read_variable_1(cursor)?;
+        let parent_id = cursor.read_u32::<LittleEndian>()?;
+        let update_flags = cursor.read_u32::<LittleEndian>()?;
+        let shape = PrimShape::from_bytes(cursor)?;
+        let texture_entry = read_variable_2(cursor)?;
+        let texture_anim = read_variable_1(cursor)?;
+        let name_value = read_string_2(cursor)?;
+        let data = read_variable_2(cursor)?;
+        let text = read_string_1(cursor)?;
+        let mut text_color = [0u8; 4];
+        cursor.read_exact(&mut text_color)?;
+        let media_url = read_string_1(cursor)?;
+        let ps_block = read_variable_1(cursor)?;
+        let extra_params = read_variable_1(cursor)?;
+        let sound = read_uuid(cursor)?;
+        let owner_id = read_uuid(cursor)?;
+        let gain = cursor.read_f32::<LittleEndian>()?;
+        let flags = cursor.read_u8()?;
+        let radius = cursor.read_f32::<LittleEndian>()?;
+        let joint_type = cursor.read_u8()?;
+        let joint_pivot = read_vec3(cursor)?;
+        let joint_axis_or_anchor = read_vec3(cursor)?;
+
+        Ok(ObjectUpdateData {
+            local_id,
+            state,
+            full_id,
+            crc,
+            pcode,
+            material,
+            click_action,
+            scale,
+            object_data,
+            parent_id,
+            update_flags,
+            shape,
+            texture_entry,
+            texture_anim,
+            name_value,
+            data,
+            text,
+            text_color,
+            media_url,
+            ps_block,
+            extra_params,
+            sound,
+            owner_id,
+            gain,
+            flags,
+            radius,
+            joint_type,
+            joint_pivot,
+            joint_axis_or_anchor,
+        })
+    }
+
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes.push(self.state);
+        bytes.extend_from_slice(self.full_id.as_bytes());
+        bytes.extend_from_slice(&self.crc.to_le_bytes());
+        bytes.push(self.pcode.to_bytes());
+        bytes.push(self.material);
+        bytes.push(self.click_action);
+        write_vec3(bytes, &self.scale);
+        write_variable_1(bytes, &self.object_data);
+        bytes.extend_from_slice(&self.parent_id.to_le_bytes());
+        bytes.extend_from_slice(&self.update_flags.to_le_bytes());
+        self.shape.to_bytes(bytes);
+        write_variable_2(bytes, &self.texture_entry);
+        write_variable_1(bytes, &self.texture_anim);
+        write_string_2(bytes, &self.name_value);
+        write_variable_2(bytes, &self.data);
+        write_string_1(bytes, &self.text);
+        bytes.extend_from_slice(&self.text_color);
+        write_string_1(bytes, &self.media_url);
+        write_variable_1(bytes, &self.ps_block);
+        write_variable_1(bytes, &self.extra_params);
+        bytes.extend_from_slice(self.sound.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.extend_from_slice(&self.gain.to_le_bytes());
+        bytes.push(self.flags);
+        bytes.extend_from_slice(&self.radius.to_le_bytes());
+        bytes.push(self.joint_type);
+        write_vec3(bytes, &self.joint_pivot);
+        write_vec3(bytes, &self.joint_axis_or_anchor);
+    }
+
+    /// decode the packed motion data. Returns None if the blob is not one of the known layouts.
+    pub fn motion(&self) -> Option<ObjectMotion> {
+        ObjectMotion::from_bytes(&self.object_data).ok()
+    }
+
+    /// the value of a name value, like "FirstName" of avatars. The NameValue field has one per
+    /// line, each written as its name, type, class, sendto and value.
+    pub fn name_value(&self, name: &str) -> Option<&str> {
+        self.name_value.lines().find_map(|line| {
+            let mut fields = line.trim().splitn(5, [' ', '\t']);
+            if fields.next()? != name {
+                return None;
+            }
+            fields.nth(3).map(str::trim)
+        })
+    }
+
+    /// the inventory item an object the agent is wearing was attached from. The simulator only
+    /// sends it with the agent's own attachments.
+    pub fn attach_item_id(&self) -> Option<Uuid> {
+        self.name_value("AttachItemID")
+            .and_then(|value| Uuid::parse_str(value).ok())
+    }
+
+    /// the point an attached object is attached to
+    pub fn attachment_point(&self) -> AttachmentPoint {
+        AttachmentPoint::from_object_state(self.state)
+    }
+}
+
+/// The full precision motion data sent in the ObjectData field of an ObjectUpdate.
+/// Prims send 60 bytes, avatars prepend a 16 byte collision plane for 76 bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectMotion {
+    pub collision_plane: Option<Vec4>,
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub acceleration: Vec3,
+    /// packed quaternion, only x, y and z are sent
+    pub rotation: Vec3,
+    pub angular_velocity: Vec3,
+}
+impl ObjectMotion {
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let collision_plane = match bytes.len() {
+            60 => None,
+            76 => Some(Vec4::new(
+                cursor.read_f32::<LittleEndian>()?,
+                cursor.read_f32::<LittleEndian>()?,
+                cursor.read_f32::<LittleEndian>()?,
+                cursor.read_f32::<LittleEndian>()?,
+            )),
+            length => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Unsupported ObjectData length: {}", length),
+                ))
+            }
+        };
+        Ok(ObjectMotion {
+            collision_plane,
+            position: read_vec3(&mut cursor)?,
+            velocity: read_vec3(&mut cursor)?,
+            acceleration: read_vec3(&mut cursor)?,
+            rotation: read_vec3(&mut cursor)?,
+            angular_velocity: read_vec3(&mut cursor)?,
+        })
+    }
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(76);
+        if let Some(plane) = self.collision_plane {
+            for value in plane.to_array() {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        write_vec3(&mut bytes, &self.position);
+        write_vec3(&mut bytes, &self.velocity);
+        write_vec3(&mut bytes, &self.acceleration);
+        write_vec3(&mut bytes, &self.rotation);
+        write_vec3(&mut bytes, &self.angular_velocity);
+        bytes
+    }
+}
+
+impl PacketData for ObjectUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // RegionData
+        let region_handle = cursor.read_u64::<LittleEndian>()?;
+        let time_dilation = cursor.read_u16::<LittleEndian>()?;
+
+        // ObjectData is a variable block, prefixed with the number of blocks
+        let count = cursor.read_u8()? as usize;
+        let mut objects = Vec::with_capacity(count);
+        for _ in 0..count {
+            objects.push(ObjectUpdateData::from_bytes(&mut cursor)?);
+        }
+
+        Ok(ObjectUpdate {
+            region_handle,
+            time_dilation,
+            objects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.region_handle.to_le_bytes());
+        bytes.extend_from_slice(&self.time_dilation.to_le_bytes());
+        bytes.push(self.objects.len() as u8);
+        for object in &self.objects {
+            object.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
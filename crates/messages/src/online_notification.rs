@@ -0,0 +1,63 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 322
+// Frequency: Low
+
+impl Packet {
+    pub fn new_online_notification(online_notification: OnlineNotification) -> Self {
+        Packet {
+            header: Header {
+                id: 322,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::OnlineNotification(Box::new(online_notification)),
+        }
+    }
+}
+
+/// Tells the viewer that friends who let the agent see their presence have logged in. The
+/// simulator also sends one for the friends already online right after login.
+/// https://wiki.secondlife.com/wiki/OnlineNotification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OnlineNotification {
+    pub agent_ids: Vec<Uuid>,
+}
+
+impl PacketData for OnlineNotification {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u8()?;
+        let mut agent_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            agent_ids.push(read_uuid(&mut cursor)?);
+        }
+        Ok(OnlineNotification { agent_ids })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let agent_ids = &self.agent_ids[..self.agent_ids.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(1 + agent_ids.len() * 16);
+        bytes.push(agent_ids.len() as u8);
+        for agent_id in agent_ids {
+            bytes.extend_from_slice(agent_id.as_bytes());
+        }
+        bytes
+    }
+}
@@ -1,10 +1,15 @@
 use super::packet_types::PacketType;
-use crate::header::Header;
+use crate::header::{Header, MSG_APPENDED_ACKS, MSG_ZEROCODED};
+use crate::utils::zerocoding::{zero_decode, zero_encode};
 use actix::prelude::*;
 use log::warn;
 use std::any::Any;
 use std::io;
-use std::io::{Cursor, Read};
+
+/// the biggest packet the protocol sends. Acks are only appended to a packet while they fit.
+pub const MAX_PACKET_SIZE: usize = 1200;
+/// the most acks one packet can carry, since they are counted with a single byte
+pub const MAX_APPENDED_ACKS: usize = u8::MAX as usize;
 
 #[derive(Debug, Message, Clone)]
 #[rtype(result = "()")]
@@ -37,21 +42,39 @@ pub trait PacketData: std::fmt::Debug + Send + Sync + 'static + Any {
 }
 
 impl Packet {
+    /// read a packet from a datagram. A zerocoded packet is expanded before its message number
+    /// and body are read, since a run of zeros can cover the end of one and the start of the
+    /// other. The appended acks at the end are never zerocoded, and aren't part of the body.
     pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
-        let header = Header::try_from_bytes(bytes)?;
-        // if the packet has a body, add the body to the packet
-        let body = if header.size.unwrap_or(0) < bytes.len() {
-            &bytes[header.size.unwrap_or(0)..]
-        } else {
-            &[]
+        let zerocoded = bytes
+            .first()
+            .is_some_and(|flags| flags & MSG_ZEROCODED != 0);
+        let decoded;
+        let bytes = match zerocoded_range(bytes) {
+            Some((start, end)) if zerocoded => {
+                let mut expanded = Vec::with_capacity(bytes.len() * 2);
+                expanded.extend_from_slice(&bytes[..start]);
+                expanded.extend(zero_decode(&bytes[start..end]));
+                expanded.extend_from_slice(&bytes[end..]);
+                // the header reads the expanded message number as it is
+                expanded[0] &= !MSG_ZEROCODED;
+                decoded = expanded;
+                &decoded
+            }
+            _ => bytes,
         };
-        let body_bytes = if header.zerocoded {
-            zero_decode(body)
-        } else {
-            body.to_vec() // Convert slice to Vec<u8>
+
+        let mut header = Header::try_from_bytes(bytes)?;
+        header.zerocoded = zerocoded;
+        let acks_size = match (header.appended_acks, &header.ack_list) {
+            (true, Some(acks)) => acks.len() * 4 + 1,
+            _ => 0,
         };
+        // if the packet has a body, add the body to the packet
+        let end = bytes.len() - acks_size;
+        let body_bytes = &bytes[header.size.unwrap_or(0).min(end)..end];
 
-        let body = match PacketType::from_id(header.id, header.frequency, body_bytes.as_slice()) {
+        let body = match PacketType::from_id(header.id, header.frequency, body_bytes) {
             Ok(parsed_body) => parsed_body, // If parsing succeeds, use the parsed body
             Err(e) => {
                 warn!(
@@ -64,59 +87,73 @@ impl Packet {
         Ok(Self { header, body })
     }
 
-    pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::new();
-        bytes.extend(self.header.to_bytes());
-        bytes.extend(self.body.to_bytes());
-        bytes
-    }
-}
-
-fn zero_decode(bytes: &[u8]) -> Vec<u8> {
-    let mut cursor = Cursor::new(bytes);
-    let mut dest = Vec::new();
-
-    while cursor.position() < bytes.len() as u64 {
-        let mut byte = [0u8; 1];
-        cursor.read_exact(&mut byte).unwrap();
-        let byte = byte[0];
-
-        if byte == 0x00 {
-            let mut repeat_count = [0u8; 1];
-            cursor.read_exact(&mut repeat_count).unwrap();
-            let repeat_count = repeat_count[0] as usize;
-
-            dest.extend(vec![0x00; repeat_count]);
-        } else {
-            dest.push(byte);
+    /// move as many acks from the front of pending into the ack list as fit in the packet. The
+    /// packet is kept under MAX_PACKET_SIZE, and never carries more than MAX_APPENDED_ACKS.
+    pub fn append_acks(&mut self, pending: &mut Vec<u32>) {
+        let appended = match &self.header.ack_list {
+            Some(acks) if self.header.appended_acks => acks.len(),
+            _ => 0,
+        };
+        if pending.is_empty() || appended >= MAX_APPENDED_ACKS {
+            return;
         }
+        // the count byte is only there once there is an ack
+        let size = self.to_bytes().len() + if appended == 0 { 1 } else { 0 };
+        let room = (MAX_PACKET_SIZE.saturating_sub(size) / 4)
+            .min(MAX_APPENDED_ACKS - appended)
+            .min(pending.len());
+        if room == 0 {
+            return;
+        }
+        let mut acks = if appended == 0 {
+            Vec::with_capacity(room)
+        } else {
+            self.header.ack_list.take().unwrap_or_default()
+        };
+        acks.extend(pending.drain(..room));
+        self.header.ack_list = Some(acks);
+        self.header.appended_acks = true;
     }
-    dest
-}
-
-// not implemented yet
-fn _zero_encode(src: &[u8]) -> Vec<u8> {
-    let mut dest = Vec::new();
-    let mut i = 0;
 
-    while i < src.len() {
-        if src[i] == 0x00 {
-            // Count consecutive zeros
-            let mut count = 1;
-            while i + count < src.len() && src[i + count] == 0x00 {
-                count += 1;
+    /// write a packet as a datagram. The body is zerocoded if the header asks for it, or the
+    /// packet type is zerocoded by default, unless that wouldn't make it any shorter. Acks in the
+    /// ack list are appended after the body, up to MAX_APPENDED_ACKS of them.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut header = self.header.clone();
+        let mut body = self.body.to_bytes();
+        if header.zerocoded || self.body.zerocoded() {
+            let encoded = zero_encode(&body);
+            header.zerocoded = encoded.len() < body.len();
+            if header.zerocoded {
+                body = encoded;
             }
+        }
+        let acks: &[u32] = match &header.ack_list {
+            Some(acks) if header.appended_acks => &acks[..acks.len().min(MAX_APPENDED_ACKS)],
+            _ => &[],
+        };
+        header.appended_acks = !acks.is_empty();
 
-            // Add a zero byte and the repeat count to the destination
-            dest.push(0x00);
-            dest.push(count as u8);
-            i += count;
-        } else {
-            // Add non-zero byte to the destination
-            dest.push(src[i]);
-            i += 1;
+        let mut bytes = header.to_bytes();
+        bytes.extend(body);
+        for ack in acks {
+            bytes.extend_from_slice(&ack.to_be_bytes());
         }
+        if !acks.is_empty() {
+            bytes.push(acks.len() as u8);
+        }
+        bytes
     }
+}
 
-    dest
+/// the part of a datagram that is zerocoded, which is everything after the extra header apart
+/// from the appended acks. None if the datagram is too short to say, which the header reports.
+fn zerocoded_range(bytes: &[u8]) -> Option<(usize, usize)> {
+    let start = 6 + *bytes.get(5)? as usize;
+    let end = if bytes[0] & MSG_APPENDED_ACKS != 0 {
+        bytes.len().checked_sub(*bytes.last()? as usize * 4 + 1)?
+    } else {
+        bytes.len()
+    };
+    (start <= end).then_some((start, end))
 }
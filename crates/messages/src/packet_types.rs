@@ -1,21 +1,265 @@
+use crate::child_simulator_event::ChildSimulatorEvent;
+use crate::disconnect::Disconnect;
 use crate::errors::SessionError;
 use crate::login_system::login::Login;
 use crate::login_system::login_response::LoginResponse;
 use crate::packet::MessageType;
+use crate::ping_stats::PingStats;
+use crate::region_crossed::RegionCrossed;
 use crate::region_handshake::RegionHandshake;
 use crate::region_handshake_reply::RegionHandshakeReply;
 use crate::ui_events::UiEventTypes;
 
+use super::abort_xfer::AbortXfer;
+use super::accept_friendship::AcceptFriendship;
+use super::accept_script_teleport::AcceptScriptTeleport;
+use super::activate_group::ActivateGroup;
+use super::agent_animation::AgentAnimation;
+use super::agent_data_update::AgentDataUpdate;
+use super::agent_data_update_request::AgentDataUpdateRequest;
+use super::agent_died::AgentDied;
+use super::agent_fov::AgentFOV;
+use super::agent_height_width::AgentHeightWidth;
+use super::agent_pause::AgentPause;
+use super::agent_request_sit::AgentRequestSit;
+use super::agent_resume::AgentResume;
+use super::agent_set_appearance::AgentSetAppearance;
+use super::agent_sit::AgentSit;
+use super::agent_throttle::AgentThrottle;
 use super::agent_update::AgentUpdate;
+use super::agent_wearables_request::AgentWearablesRequest;
+use super::agent_wearables_update::AgentWearablesUpdate;
+use super::asset_received::AssetReceived;
+use super::asset_transfer_failed::AssetTransferFailed;
+use super::asset_upload_complete::AssetUploadComplete;
+use super::asset_upload_request::AssetUploadRequest;
+use super::asset_uploaded::AssetUploaded;
+use super::attach_from_inventory::AttachFromInventory;
+use super::attached_sound::AttachedSound;
+use super::attached_sound_gain_change::AttachedSoundGainChange;
+use super::attachments::Attachments;
+use super::avatar_animation::AvatarAnimation;
+use super::avatar_appearance::AvatarAppearance;
+use super::avatar_classified_reply::AvatarClassifiedReply;
+use super::avatar_groups_reply::AvatarGroupsReply;
+use super::avatar_interests_reply::AvatarInterestsReply;
+use super::avatar_interests_update::AvatarInterestsUpdate;
+use super::avatar_picker_reply::AvatarPickerReply;
+use super::avatar_picker_request::AvatarPickerRequest;
+use super::avatar_picks_reply::AvatarPicksReply;
+use super::avatar_properties_reply::AvatarPropertiesReply;
+use super::avatar_properties_request::AvatarPropertiesRequest;
+use super::avatar_properties_update::AvatarPropertiesUpdate;
+use super::avatar_sit_response::AvatarSitResponse;
+use super::camera_constraint::CameraConstraint;
+use super::change_user_rights::ChangeUserRights;
 use super::chat_from_simulator::ChatFromSimulator;
 use super::chat_from_viewer::ChatFromViewer;
+use super::chat_pass::ChatPass;
+use super::circuit_stats_report::CircuitStatsReport;
+use super::classified_info_reply::ClassifiedInfoReply;
+use super::classified_info_request::ClassifiedInfoRequest;
+use super::clear_follow_cam_properties::ClearFollowCamProperties;
 use super::complete_agent_movement::CompleteAgentMovementData;
+use super::confirm_xfer_packet::ConfirmXferPacket;
+use super::create_inventory_folder::CreateInventoryFolder;
+use super::create_inventory_item::CreateInventoryItem;
+use super::crossed_region::CrossedRegion;
+use super::decline_friendship::DeclineFriendship;
+use super::derez_object::DeRezObject;
+use super::detach_attachment::DetachAttachment;
+use super::dir_find_query::DirFindQuery;
+use super::dir_people_reply::DirPeopleReply;
+use super::dir_places_reply::DirPlacesReply;
+use super::directory_people_page::DirectoryPeoplePage;
+use super::directory_places_page::DirectoryPlacesPage;
+use super::economy_data::EconomyData;
+use super::economy_data_request::EconomyDataRequest;
+use super::enable_simulator::EnableSimulator;
+use super::estate_owner_message::EstateOwnerMessage;
+use super::event_info_reply::EventInfoReply;
+use super::event_info_request::EventInfoRequest;
+use super::feature_disabled::FeatureDisabled;
+use super::feature_disabled_notice::FeatureDisabledNotice;
+use super::fetch_inventory::FetchInventory;
+use super::fetch_inventory_descendents::FetchInventoryDescendents;
+use super::fetch_inventory_reply::FetchInventoryReply;
+use super::force_script_control_release::ForceScriptControlRelease;
+use super::friend_list::FriendList;
+use super::friendship_status::FriendshipStatus;
+use super::generic_message::GenericMessageData;
+use super::get_script_running::GetScriptRunning;
+use super::god_kick_user::GodKickUser;
+use super::grant_godlike_powers::GrantGodlikePowers;
+use super::grant_user_rights::GrantUserRights;
+use super::group_name_resolved::GroupNameResolved;
+use super::group_profile_reply::GroupProfileReply;
+use super::group_profile_request::GroupProfileRequest;
+use super::health_message::HealthMessage;
+use super::image_data::ImageData;
+use super::image_packet::ImagePacket;
+use super::improved_instant_message::ImprovedInstantMessage;
+use super::improved_terse_object_update::ImprovedTerseObjectUpdate;
+use super::inventory_descendents::InventoryDescendents;
+use super::inventory_folder_contents::InventoryFolderContents;
+use super::inventory_folder_status::InventoryFolderStatus;
+use super::inventory_item_created::InventoryItemCreated;
+use super::inventory_item_status::InventoryItemStatus;
+use super::join_group_reply::JoinGroupReply;
+use super::join_group_request::JoinGroupRequest;
+use super::kick_user::KickUser;
+use super::kill_object::KillObjectData;
+use super::land_stat_reply::LandStatReply;
+use super::land_stat_report::LandStatReport;
+use super::land_stat_request::LandStatRequest;
+use super::layer_data::{CloudPatches, LayerData, LayerType, TerrainPatches, WindPatches};
+use super::leave_group_reply::LeaveGroupReply;
+use super::leave_group_request::LeaveGroupRequest;
+use super::load_url::LoadURL;
+use super::logout_reply::LogoutReply;
+use super::logout_request::LogoutRequest;
+use super::map_block_reply::MapBlockReply;
+use super::map_block_request::MapBlockRequest;
+use super::map_blocks::MapBlocks;
+use super::map_item_reply::MapItemReply;
+use super::map_item_request::MapItemRequest;
+use super::map_items::MapItems;
+use super::map_name_request::MapNameRequest;
+use super::map_search_empty::MapSearchEmpty;
+use super::map_search_results::MapSearchResults;
+use super::money_balance_reply::MoneyBalanceReply;
+use super::money_balance_request::MoneyBalanceRequest;
+use super::move_inventory_folder::MoveInventoryFolder;
+use super::move_inventory_item::MoveInventoryItem;
+use super::multiple_object_update::MultipleObjectUpdate;
+use super::mute_list::MuteList;
+use super::mute_list_request::MuteListRequest;
+use super::mute_list_update::MuteListUpdate;
+use super::name_resolved::NameResolved;
+use super::object_add::ObjectAdd;
+use super::object_attach::ObjectAttach;
+use super::object_buy::ObjectBuy;
+use super::object_category::ObjectCategory;
+use super::object_click_action::ObjectClickAction;
+use super::object_de_grab::ObjectDeGrab;
+use super::object_delete::ObjectDelete;
+use super::object_delink::ObjectDelink;
+use super::object_description::ObjectDescription;
+use super::object_deselect::ObjectDeselect;
+use super::object_detach::ObjectDetach;
+use super::object_drop::ObjectDrop;
+use super::object_duplicate::ObjectDuplicate;
+use super::object_duplicate_on_ray::ObjectDuplicateOnRay;
+use super::object_edit_ack::ObjectEditAck;
+use super::object_extra_params::ObjectExtraParams;
+use super::object_family_status::ObjectFamilyStatus;
+use super::object_flag_update::ObjectFlagUpdate;
+use super::object_grab::ObjectGrab;
+use super::object_grab_update::ObjectGrabUpdate;
+use super::object_image::ObjectImage;
+use super::object_link::ObjectLink;
+use super::object_material::ObjectMaterial;
+use super::object_name::ObjectName;
+use super::object_permissions::ObjectPermissions;
+use super::object_properties::ObjectProperties;
+use super::object_properties_family::ObjectPropertiesFamily;
+use super::object_sale_info::ObjectSaleInfo;
+use super::object_select::ObjectSelect;
+use super::object_update::ObjectUpdate;
+use super::offline_notification::OfflineNotification;
+use super::online_notification::OnlineNotification;
+use super::parcel_access_list::ParcelAccessList;
+use super::parcel_access_list_reply::ParcelAccessListReply;
+use super::parcel_access_list_request::ParcelAccessListRequest;
+use super::parcel_access_list_update::ParcelAccessListUpdate;
+use super::parcel_dwell_reply::ParcelDwellReply;
+use super::parcel_dwell_request::ParcelDwellRequest;
+use super::parcel_media_command_message::ParcelMediaCommandMessage;
+use super::parcel_media_update::ParcelMediaUpdate;
+use super::parcel_overlay::ParcelOverlay;
+use super::parcel_overlay_grid::ParcelOverlayGrid;
+use super::parcel_properties::ParcelProperties;
+use super::parcel_properties_request::ParcelPropertiesRequest;
+use super::people_search_empty::PeopleSearchEmpty;
+use super::people_search_results::PeopleSearchResults;
+use super::pick_info_reply::PickInfoReply;
+use super::pick_info_request::PickInfoRequest;
+use super::preload_sound::PreloadSound;
+use super::purchase_failed::PurchaseFailed;
+use super::purge_inventory_descendents::PurgeInventoryDescendents;
+use super::purge_inventory_folder::PurgeInventoryFolder;
+use super::query_circuit_stats::QueryCircuitStats;
+use super::query_object_family::QueryObjectFamily;
+use super::query_self_agent_info::QuerySelfAgentInfo;
+use super::raw_packet::RawPacketData;
+use super::region_info::RegionInfo;
+use super::region_info_request::RegionInfoRequest;
+use super::remove_inventory_folder::RemoveInventoryFolder;
+use super::remove_inventory_item::RemoveInventoryItem;
+use super::remove_mute_list_entry::RemoveMuteListEntry;
+use super::report_unknown_packets::ReportUnknownPackets;
+use super::request_godlike_powers::RequestGodlikePowers;
+use super::request_image::RequestImage;
+use super::request_object_properties_family::RequestObjectPropertiesFamily;
+use super::request_xfer::RequestXfer;
+use super::revoke_permissions::RevokePermissions;
+use super::rez_object::RezObject;
+use super::rez_script::RezScript;
+use super::rez_single_attachment_from_inv::RezSingleAttachmentFromInv;
+use super::script_answer_yes::ScriptAnswerYes;
+use super::script_control_change::ScriptControlChange;
+use super::script_controls::ScriptControls;
+use super::script_dialog::ScriptDialog;
+use super::script_dialog_reply::ScriptDialogReply;
+use super::script_question::ScriptQuestion;
+use super::script_reset::ScriptReset;
+use super::script_running_reply::ScriptRunningReply;
+use super::script_running_status::ScriptRunningStatus;
+use super::script_teleport_request::ScriptTeleportRequest;
+use super::self_agent_info::SelfAgentInfo;
+use super::send_xfer_packet::SendXferPacket;
+use super::set_always_run::SetAlwaysRun;
+use super::set_chat_filter::SetChatFilter;
+use super::set_extra_params::SetExtraParams;
+use super::set_follow_cam_properties::SetFollowCamProperties;
+use super::set_script_running::SetScriptRunning;
+use super::set_velocity_interpolation::SetVelocityInterpolation;
+use super::sim_stats::SimStats;
+use super::simulator_viewer_time_message::SimulatorViewerTimeMessage;
+use super::sit_status::SitStatus;
+use super::sound_trigger::SoundTrigger;
+use super::telehub_info::TelehubInfo;
+use super::teleport_failed::TeleportFailed;
+use super::teleport_finish::TeleportFinish;
+use super::teleport_location_request::TeleportLocationRequest;
+use super::teleport_progress::TeleportProgress;
+use super::teleport_start::TeleportStart;
+use super::terminate_friendship::TerminateFriendship;
+use super::texture_ready::TextureReady;
+use super::transfer_info::TransferInfo;
+use super::transfer_packet::TransferPacket;
+use super::transfer_request::TransferRequest;
+use super::unknown_packet_notice::UnknownPacketNotice;
+use super::update_create_inventory_item::UpdateCreateInventoryItem;
+use super::update_inventory_item::UpdateInventoryItem;
+use super::update_mute_list_entry::UpdateMuteListEntry;
+use super::use_cached_mute_list::UseCachedMuteList;
+use super::uuid_group_name_reply::UUIDGroupNameReply;
+use super::uuid_group_name_request::UUIDGroupNameRequest;
+use super::uuid_name_reply::UUIDNameReply;
+use super::uuid_name_request::UUIDNameRequest;
+use super::velocity_interpolate_off::VelocityInterpolateOff;
+use super::velocity_interpolate_on::VelocityInterpolateOn;
+use super::velocity_interpolation_status::VelocityInterpolationStatus;
+use super::viewer_effect::ViewerEffect;
+use super::wearables::Wearables;
 use super::{
     circuit_code::CircuitCodeData, coarse_location_update::CoarseLocationUpdate,
     complete_ping_check::CompletePingCheck, disable_simulator::DisableSimulator,
     header::PacketFrequency, packet::PacketData, packet_ack::PacketAck,
     start_ping_check::StartPingCheck,
 };
+use log::warn;
 use std::io;
 
 // IntoArc provides a macro that allows all of these to be contained within arcs
@@ -37,12 +281,279 @@ pub enum PacketType {
     CompletePingCheck(Box<CompletePingCheck>),
     RegionHandshake(Box<RegionHandshake>),
     RegionHandshakeReply(Box<RegionHandshakeReply>),
+    ImprovedInstantMessage(Box<ImprovedInstantMessage>),
+    ObjectUpdate(Box<ObjectUpdate>),
+    ImprovedTerseObjectUpdate(Box<ImprovedTerseObjectUpdate>),
+    KillObject(Box<KillObjectData>),
+    AgentThrottle(Box<AgentThrottle>),
+    LogoutRequest(Box<LogoutRequest>),
+    LogoutReply(Box<LogoutReply>),
+    KickUser(Box<KickUser>),
+    TeleportLocationRequest(Box<TeleportLocationRequest>),
+    TeleportStart(Box<TeleportStart>),
+    TeleportProgress(Box<TeleportProgress>),
+    TeleportFailed(Box<TeleportFailed>),
+    TeleportFinish(Box<TeleportFinish>),
+    EnableSimulator(Box<EnableSimulator>),
+    CrossedRegion(Box<CrossedRegion>),
+    LayerData(Box<LayerData>),
+    RequestImage(Box<RequestImage>),
+    ImageData(Box<ImageData>),
+    ImagePacket(Box<ImagePacket>),
+    TransferRequest(Box<TransferRequest>),
+    AssetUploadRequest(Box<AssetUploadRequest>),
+    AssetUploadComplete(Box<AssetUploadComplete>),
+    AvatarAppearance(Box<AvatarAppearance>),
+    AgentWearablesRequest(Box<AgentWearablesRequest>),
+    AgentWearablesUpdate(Box<AgentWearablesUpdate>),
+    AgentSetAppearance(Box<AgentSetAppearance>),
+    TransferInfo(Box<TransferInfo>),
+    TransferPacket(Box<TransferPacket>),
+    RequestXfer(Box<RequestXfer>),
+    SendXferPacket(Box<SendXferPacket>),
+    ConfirmXferPacket(Box<ConfirmXferPacket>),
+    AbortXfer(Box<AbortXfer>),
+    UUIDNameRequest(Box<UUIDNameRequest>),
+    UUIDNameReply(Box<UUIDNameReply>),
+    UUIDGroupNameRequest(Box<UUIDGroupNameRequest>),
+    UUIDGroupNameReply(Box<UUIDGroupNameReply>),
+    MoneyBalanceRequest(Box<MoneyBalanceRequest>),
+    MoneyBalanceReply(Box<MoneyBalanceReply>),
+    ObjectSelect(Box<ObjectSelect>),
+    ObjectDeselect(Box<ObjectDeselect>),
+    ObjectGrab(Box<ObjectGrab>),
+    ObjectGrabUpdate(Box<ObjectGrabUpdate>),
+    ObjectDeGrab(Box<ObjectDeGrab>),
+    ObjectAdd(Box<ObjectAdd>),
+    DeRezObject(Box<DeRezObject>),
+    ObjectDelete(Box<ObjectDelete>),
+    MultipleObjectUpdate(Box<MultipleObjectUpdate>),
+    RezObject(Box<RezObject>),
+    ObjectBuy(Box<ObjectBuy>),
+    ObjectProperties(Box<ObjectProperties>),
+    ParcelPropertiesRequest(Box<ParcelPropertiesRequest>),
+    ParcelProperties(Box<ParcelProperties>),
+    ParcelOverlay(Box<ParcelOverlay>),
+    MapBlockRequest(Box<MapBlockRequest>),
+    MapBlockReply(Box<MapBlockReply>),
+    MapItemRequest(Box<MapItemRequest>),
+    MapItemReply(Box<MapItemReply>),
+    MapNameRequest(Box<MapNameRequest>),
+    ScriptDialog(Box<ScriptDialog>),
+    ScriptDialogReply(Box<ScriptDialogReply>),
+    LoadURL(Box<LoadURL>),
+    ScriptQuestion(Box<ScriptQuestion>),
+    ScriptAnswerYes(Box<ScriptAnswerYes>),
+    RevokePermissions(Box<RevokePermissions>),
+    SoundTrigger(Box<SoundTrigger>),
+    AttachedSound(Box<AttachedSound>),
+    AttachedSoundGainChange(Box<AttachedSoundGainChange>),
+    PreloadSound(Box<PreloadSound>),
+    ViewerEffect(Box<ViewerEffect>),
+    AgentRequestSit(Box<AgentRequestSit>),
+    AvatarSitResponse(Box<AvatarSitResponse>),
+    AgentSit(Box<AgentSit>),
+    SetAlwaysRun(Box<SetAlwaysRun>),
+    AgentHeightWidth(Box<AgentHeightWidth>),
+    AgentFOV(Box<AgentFOV>),
+    MuteListRequest(Box<MuteListRequest>),
+    MuteListUpdate(Box<MuteListUpdate>),
+    UseCachedMuteList(Box<UseCachedMuteList>),
+    UpdateMuteListEntry(Box<UpdateMuteListEntry>),
+    RemoveMuteListEntry(Box<RemoveMuteListEntry>),
+    OnlineNotification(Box<OnlineNotification>),
+    OfflineNotification(Box<OfflineNotification>),
+    FetchInventoryDescendents(Box<FetchInventoryDescendents>),
+    InventoryDescendents(Box<InventoryDescendents>),
+    FetchInventory(Box<FetchInventory>),
+    FetchInventoryReply(Box<FetchInventoryReply>),
+    UpdateInventoryItem(Box<UpdateInventoryItem>),
+    MoveInventoryItem(Box<MoveInventoryItem>),
+    RemoveInventoryItem(Box<RemoveInventoryItem>),
+    CreateInventoryFolder(Box<CreateInventoryFolder>),
+    MoveInventoryFolder(Box<MoveInventoryFolder>),
+    RemoveInventoryFolder(Box<RemoveInventoryFolder>),
+    PurgeInventoryDescendents(Box<PurgeInventoryDescendents>),
+    CreateInventoryItem(Box<CreateInventoryItem>),
+    UpdateCreateInventoryItem(Box<UpdateCreateInventoryItem>),
+    AcceptFriendship(Box<AcceptFriendship>),
+    DeclineFriendship(Box<DeclineFriendship>),
+    TerminateFriendship(Box<TerminateFriendship>),
+    GrantUserRights(Box<GrantUserRights>),
+    ChangeUserRights(Box<ChangeUserRights>),
+    JoinGroupRequest(Box<JoinGroupRequest>),
+    JoinGroupReply(Box<JoinGroupReply>),
+    LeaveGroupRequest(Box<LeaveGroupRequest>),
+    LeaveGroupReply(Box<LeaveGroupReply>),
+    ActivateGroup(Box<ActivateGroup>),
+    AgentDataUpdate(Box<AgentDataUpdate>),
+    AvatarPropertiesRequest(Box<AvatarPropertiesRequest>),
+    AvatarGroupsReply(Box<AvatarGroupsReply>),
+    GroupProfileRequest(Box<GroupProfileRequest>),
+    GroupProfileReply(Box<GroupProfileReply>),
+    AvatarPropertiesReply(Box<AvatarPropertiesReply>),
+    AvatarInterestsReply(Box<AvatarInterestsReply>),
+    AvatarPropertiesUpdate(Box<AvatarPropertiesUpdate>),
+    AvatarInterestsUpdate(Box<AvatarInterestsUpdate>),
+    AvatarPickerRequest(Box<AvatarPickerRequest>),
+    AvatarPickerReply(Box<AvatarPickerReply>),
+    DirFindQuery(Box<DirFindQuery>),
+    DirPlacesReply(Box<DirPlacesReply>),
+    DirPeopleReply(Box<DirPeopleReply>),
+    EstateOwnerMessage(Box<EstateOwnerMessage>),
+    GodKickUser(Box<GodKickUser>),
+    RequestGodlikePowers(Box<RequestGodlikePowers>),
+    GrantGodlikePowers(Box<GrantGodlikePowers>),
+    GenericMessage(Box<GenericMessageData>),
+    EconomyDataRequest(Box<EconomyDataRequest>),
+    EconomyData(Box<EconomyData>),
+    RegionInfoRequest(Box<RegionInfoRequest>),
+    RegionInfo(Box<RegionInfo>),
+    ParcelAccessListRequest(Box<ParcelAccessListRequest>),
+    ParcelAccessListReply(Box<ParcelAccessListReply>),
+    ParcelAccessListUpdate(Box<ParcelAccessListUpdate>),
+    ParcelDwellRequest(Box<ParcelDwellRequest>),
+    ParcelDwellReply(Box<ParcelDwellReply>),
+    LandStatRequest(Box<LandStatRequest>),
+    LandStatReply(Box<LandStatReply>),
+    ObjectDuplicate(Box<ObjectDuplicate>),
+    ObjectDuplicateOnRay(Box<ObjectDuplicateOnRay>),
+    ObjectLink(Box<ObjectLink>),
+    ObjectDelink(Box<ObjectDelink>),
+    ObjectAttach(Box<ObjectAttach>),
+    ObjectDetach(Box<ObjectDetach>),
+    ObjectDrop(Box<ObjectDrop>),
+    RezSingleAttachmentFromInv(Box<RezSingleAttachmentFromInv>),
+    GetScriptRunning(Box<GetScriptRunning>),
+    ScriptRunningReply(Box<ScriptRunningReply>),
+    SetScriptRunning(Box<SetScriptRunning>),
+    ScriptReset(Box<ScriptReset>),
+    RezScript(Box<RezScript>),
+    ObjectImage(Box<ObjectImage>),
+    ObjectName(Box<ObjectName>),
+    ObjectDescription(Box<ObjectDescription>),
+    ObjectExtraParams(Box<ObjectExtraParams>),
+    ObjectMaterial(Box<ObjectMaterial>),
+    ObjectFlagUpdate(Box<ObjectFlagUpdate>),
+    ObjectClickAction(Box<ObjectClickAction>),
+    ObjectPermissions(Box<ObjectPermissions>),
+    ObjectSaleInfo(Box<ObjectSaleInfo>),
+    ObjectCategory(Box<ObjectCategory>),
+    RequestObjectPropertiesFamily(Box<RequestObjectPropertiesFamily>),
+    ObjectPropertiesFamily(Box<ObjectPropertiesFamily>),
+    AgentPause(Box<AgentPause>),
+    AgentResume(Box<AgentResume>),
+    CameraConstraint(Box<CameraConstraint>),
+    SetFollowCamProperties(Box<SetFollowCamProperties>),
+    ClearFollowCamProperties(Box<ClearFollowCamProperties>),
+    ScriptControlChange(Box<ScriptControlChange>),
+    ForceScriptControlRelease(Box<ForceScriptControlRelease>),
+    ScriptTeleportRequest(Box<ScriptTeleportRequest>),
+    HealthMessage(Box<HealthMessage>),
+    SimStats(Box<SimStats>),
+    SimulatorViewerTimeMessage(Box<SimulatorViewerTimeMessage>),
+    AgentAnimation(Box<AgentAnimation>),
+    AvatarAnimation(Box<AvatarAnimation>),
+    AgentDataUpdateRequest(Box<AgentDataUpdateRequest>),
+    AvatarClassifiedReply(Box<AvatarClassifiedReply>),
+    ClassifiedInfoRequest(Box<ClassifiedInfoRequest>),
+    ClassifiedInfoReply(Box<ClassifiedInfoReply>),
+    AvatarPicksReply(Box<AvatarPicksReply>),
+    EventInfoRequest(Box<EventInfoRequest>),
+    EventInfoReply(Box<EventInfoReply>),
+    PickInfoReply(Box<PickInfoReply>),
+    TelehubInfo(Box<TelehubInfo>),
+    ParcelMediaCommandMessage(Box<ParcelMediaCommandMessage>),
+    ParcelMediaUpdate(Box<ParcelMediaUpdate>),
+    ChatPass(Box<ChatPass>),
+    VelocityInterpolateOn(Box<VelocityInterpolateOn>),
+    VelocityInterpolateOff(Box<VelocityInterpolateOff>),
+    FeatureDisabled(Box<FeatureDisabled>),
     // these do not exist in the packet spec! Used as utilities for communicating with server and
     // client.
     Login(Box<Login>),
     LoginResponse(Box<LoginResponse>),
     Error(Box<SessionError>),
+    PingStats(Box<PingStats>),
+    Disconnect(Box<Disconnect>),
+    ChildSimulatorEvent(Box<ChildSimulatorEvent>),
+    RegionCrossed(Box<RegionCrossed>),
+    TerrainPatches(Box<TerrainPatches>),
+    WindPatches(Box<WindPatches>),
+    CloudPatches(Box<CloudPatches>),
+    TextureReady(Box<TextureReady>),
+    AssetReceived(Box<AssetReceived>),
+    AssetTransferFailed(Box<AssetTransferFailed>),
+    AssetUploaded(Box<AssetUploaded>),
+    Wearables(Box<Wearables>),
+    NameResolved(Box<NameResolved>),
+    GroupNameResolved(Box<GroupNameResolved>),
+    PurchaseFailed(Box<PurchaseFailed>),
+    ParcelOverlayGrid(Box<ParcelOverlayGrid>),
+    MapBlocks(Box<MapBlocks>),
+    MapItems(Box<MapItems>),
+    MapSearchResults(Box<MapSearchResults>),
+    MapSearchEmpty(Box<MapSearchEmpty>),
+    SitStatus(Box<SitStatus>),
+    MuteList(Box<MuteList>),
+    FriendList(Box<FriendList>),
+    InventoryFolderContents(Box<InventoryFolderContents>),
+    InventoryItemStatus(Box<InventoryItemStatus>),
+    InventoryFolderStatus(Box<InventoryFolderStatus>),
+    PurgeInventoryFolder(Box<PurgeInventoryFolder>),
+    InventoryItemCreated(Box<InventoryItemCreated>),
+    FriendshipStatus(Box<FriendshipStatus>),
+    PeopleSearchResults(Box<PeopleSearchResults>),
+    PeopleSearchEmpty(Box<PeopleSearchEmpty>),
+    DirectoryPeoplePage(Box<DirectoryPeoplePage>),
+    DirectoryPlacesPage(Box<DirectoryPlacesPage>),
+    ParcelAccessList(Box<ParcelAccessList>),
+    LandStatReport(Box<LandStatReport>),
+    Attachments(Box<Attachments>),
+    AttachFromInventory(Box<AttachFromInventory>),
+    DetachAttachment(Box<DetachAttachment>),
+    SetExtraParams(Box<SetExtraParams>),
+    QueryObjectFamily(Box<QueryObjectFamily>),
+    AcceptScriptTeleport(Box<AcceptScriptTeleport>),
+    QuerySelfAgentInfo(Box<QuerySelfAgentInfo>),
+    PickInfoRequest(Box<PickInfoRequest>),
+    SetChatFilter(Box<SetChatFilter>),
+    SetVelocityInterpolation(Box<SetVelocityInterpolation>),
+    ReportUnknownPackets(Box<ReportUnknownPackets>),
+    QueryCircuitStats(Box<QueryCircuitStats>),
+    ScriptRunningStatus(Box<ScriptRunningStatus>),
+    ObjectEditAck(Box<ObjectEditAck>),
+    ObjectFamilyStatus(Box<ObjectFamilyStatus>),
+    ScriptControls(Box<ScriptControls>),
+    AgentDied(Box<AgentDied>),
+    SelfAgentInfo(Box<SelfAgentInfo>),
+    VelocityInterpolationStatus(Box<VelocityInterpolationStatus>),
+    FeatureDisabledNotice(Box<FeatureDisabledNotice>),
+    UnknownPacketNotice(Box<UnknownPacketNotice>),
+    CircuitStatsReport(Box<CircuitStatsReport>),
+    Unknown(Box<RawPacketData>),
 }
+impl PacketType {
+    /// whether the packet is zerocoded when it is sent, even if its header doesn't ask for it.
+    /// These are the packets the protocol marks as zerocoded that are big and full of zeros, like
+    /// object updates, and are worth the extra work.
+    pub fn zerocoded(&self) -> bool {
+        matches!(
+            self,
+            PacketType::AgentUpdate(_)
+                | PacketType::ObjectUpdate(_)
+                | PacketType::ObjectAdd(_)
+                | PacketType::MultipleObjectUpdate(_)
+                | PacketType::ObjectProperties(_)
+                | PacketType::ViewerEffect(_)
+                | PacketType::AvatarAppearance(_)
+                | PacketType::AgentSetAppearance(_)
+                | PacketType::RegionHandshake(_)
+                | PacketType::ImprovedInstantMessage(_)
+        )
+    }
+}
+
 // I think I should remove MessageTypes entirely. They don't exist in the spec
 // I think I was using a different project's design and didn't think it through.
 impl PacketType {
@@ -51,22 +562,272 @@ impl PacketType {
             PacketType::ChatFromSimulator(_) => MessageType::Event,
             PacketType::CoarseLocationUpdate(_) => MessageType::Event,
             PacketType::DisableSimulator(_) => MessageType::Event,
+            PacketType::ImprovedInstantMessage(_) => MessageType::Event,
+            PacketType::ObjectUpdate(_) => MessageType::Event,
+            PacketType::ImprovedTerseObjectUpdate(_) => MessageType::Event,
+            PacketType::KillObject(_) => MessageType::Event,
+            PacketType::RegionHandshake(_) => MessageType::Event,
 
             PacketType::AgentUpdate(_) => MessageType::Outgoing,
             PacketType::CompleteAgentMovementData(_) => MessageType::Outgoing,
             PacketType::ChatFromViewer(_) => MessageType::Outgoing,
             PacketType::CircuitCode(_) => MessageType::Outgoing,
+            PacketType::AgentThrottle(_) => MessageType::Outgoing,
+            PacketType::LogoutRequest(_) => MessageType::Outgoing,
+            PacketType::TeleportLocationRequest(_) => MessageType::Outgoing,
+            PacketType::RequestImage(_) => MessageType::Outgoing,
+            PacketType::TransferRequest(_) => MessageType::Outgoing,
+            PacketType::AssetUploadRequest(_) => MessageType::Outgoing,
+            PacketType::AgentWearablesRequest(_) => MessageType::Outgoing,
+            PacketType::AgentSetAppearance(_) => MessageType::Outgoing,
+            PacketType::AgentWearablesUpdate(_) => MessageType::Request,
+            PacketType::AssetUploadComplete(_) => MessageType::Request,
+            PacketType::SendXferPacket(_) => MessageType::Outgoing,
+            PacketType::UUIDNameRequest(_) => MessageType::Outgoing,
+            PacketType::UUIDGroupNameRequest(_) => MessageType::Outgoing,
+            PacketType::MoneyBalanceRequest(_) => MessageType::Outgoing,
+            PacketType::ObjectSelect(_) => MessageType::Outgoing,
+            PacketType::ObjectDeselect(_) => MessageType::Outgoing,
+            PacketType::ObjectGrab(_) => MessageType::Outgoing,
+            PacketType::ObjectGrabUpdate(_) => MessageType::Outgoing,
+            PacketType::ObjectDeGrab(_) => MessageType::Outgoing,
+            PacketType::ObjectAdd(_) => MessageType::Outgoing,
+            PacketType::DeRezObject(_) => MessageType::Outgoing,
+            PacketType::ObjectDelete(_) => MessageType::Outgoing,
+            PacketType::MultipleObjectUpdate(_) => MessageType::Outgoing,
+            PacketType::RezObject(_) => MessageType::Outgoing,
+            PacketType::ObjectBuy(_) => MessageType::Outgoing,
+            PacketType::ParcelPropertiesRequest(_) => MessageType::Outgoing,
+            PacketType::MapBlockRequest(_) => MessageType::Outgoing,
+            PacketType::MapItemRequest(_) => MessageType::Outgoing,
+            PacketType::MapNameRequest(_) => MessageType::Outgoing,
+            PacketType::ScriptDialogReply(_) => MessageType::Outgoing,
+            PacketType::ScriptAnswerYes(_) => MessageType::Outgoing,
+            PacketType::RevokePermissions(_) => MessageType::Outgoing,
 
             PacketType::StartPingCheck(_) => MessageType::Request,
             PacketType::CompletePingCheck(_) => MessageType::Request,
-            PacketType::RegionHandshake(_) => MessageType::Request,
             PacketType::RegionHandshakeReply(_) => MessageType::Request,
+            PacketType::LogoutReply(_) => MessageType::Request,
+            PacketType::TeleportFinish(_) => MessageType::Request,
+            PacketType::EnableSimulator(_) => MessageType::Request,
+            PacketType::CrossedRegion(_) => MessageType::Request,
+            PacketType::RequestXfer(_) => MessageType::Request,
+            PacketType::ConfirmXferPacket(_) => MessageType::Request,
+            PacketType::AbortXfer(_) => MessageType::Request,
+            PacketType::UUIDNameReply(_) => MessageType::Request,
+            PacketType::UUIDGroupNameReply(_) => MessageType::Request,
+            // the session only sends the parcel the agent is on to the UI
+            PacketType::ParcelProperties(_) => MessageType::Request,
+            // the session puts the chunks together before sending the overlay to the UI
+            PacketType::ParcelOverlay(_) => MessageType::Request,
+            // the session gathers the replies to a request before sending them to the UI
+            PacketType::MapBlockReply(_) => MessageType::Request,
+            PacketType::MapItemReply(_) => MessageType::Request,
+
+            PacketType::ImageData(_) => MessageType::Data,
+            PacketType::ImagePacket(_) => MessageType::Data,
+            PacketType::TransferInfo(_) => MessageType::Data,
+            PacketType::TransferPacket(_) => MessageType::Data,
 
             PacketType::PacketAck(_) => MessageType::Acknowledgment,
 
             PacketType::Login(_) => MessageType::Login,
             PacketType::LoginResponse(_) => MessageType::Login,
             PacketType::Error(_) => MessageType::Error,
+            PacketType::PingStats(_) => MessageType::Event,
+            PacketType::Disconnect(_) => MessageType::Event,
+            PacketType::ChildSimulatorEvent(_) => MessageType::Event,
+            PacketType::RegionCrossed(_) => MessageType::Event,
+            PacketType::TerrainPatches(_) => MessageType::Event,
+            PacketType::WindPatches(_) => MessageType::Event,
+            PacketType::CloudPatches(_) => MessageType::Event,
+            PacketType::TextureReady(_) => MessageType::Event,
+            PacketType::AssetReceived(_) => MessageType::Event,
+            PacketType::AssetTransferFailed(_) => MessageType::Event,
+            PacketType::AssetUploaded(_) => MessageType::Event,
+            PacketType::AvatarAppearance(_) => MessageType::Event,
+            PacketType::Wearables(_) => MessageType::Event,
+            PacketType::NameResolved(_) => MessageType::Event,
+            PacketType::GroupNameResolved(_) => MessageType::Event,
+            PacketType::PurchaseFailed(_) => MessageType::Event,
+            PacketType::ParcelOverlayGrid(_) => MessageType::Event,
+            PacketType::MapBlocks(_) => MessageType::Event,
+            PacketType::MapItems(_) => MessageType::Event,
+            PacketType::MapSearchResults(_) => MessageType::Event,
+            PacketType::MapSearchEmpty(_) => MessageType::Event,
+            PacketType::SitStatus(_) => MessageType::Event,
+            PacketType::MuteList(_) => MessageType::Event,
+            PacketType::FriendList(_) => MessageType::Event,
+            PacketType::InventoryFolderContents(_) => MessageType::Event,
+            PacketType::InventoryItemStatus(_) => MessageType::Event,
+            PacketType::InventoryFolderStatus(_) => MessageType::Event,
+            PacketType::PurgeInventoryFolder(_) => MessageType::Command,
+            PacketType::InventoryItemCreated(_) => MessageType::Event,
+            PacketType::FriendshipStatus(_) => MessageType::Event,
+            PacketType::PeopleSearchResults(_) => MessageType::Event,
+            PacketType::PeopleSearchEmpty(_) => MessageType::Event,
+            PacketType::DirectoryPeoplePage(_) => MessageType::Event,
+            PacketType::DirectoryPlacesPage(_) => MessageType::Event,
+            PacketType::ParcelAccessList(_) => MessageType::Event,
+            PacketType::LandStatReport(_) => MessageType::Event,
+            PacketType::Attachments(_) => MessageType::Event,
+            PacketType::ScriptRunningStatus(_) => MessageType::Event,
+            PacketType::ObjectEditAck(_) => MessageType::Event,
+            PacketType::ObjectFamilyStatus(_) => MessageType::Event,
+            PacketType::ScriptControls(_) => MessageType::Event,
+            PacketType::AgentDied(_) => MessageType::Event,
+            PacketType::SelfAgentInfo(_) => MessageType::Event,
+            PacketType::VelocityInterpolationStatus(_) => MessageType::Event,
+            PacketType::FeatureDisabledNotice(_) => MessageType::Event,
+            PacketType::UnknownPacketNotice(_) => MessageType::Event,
+            PacketType::CircuitStatsReport(_) => MessageType::Event,
+            PacketType::Unknown(_) => MessageType::Data,
+            PacketType::AttachFromInventory(_) => MessageType::Command,
+            PacketType::DetachAttachment(_) => MessageType::Command,
+            PacketType::SetExtraParams(_) => MessageType::Command,
+            PacketType::QueryObjectFamily(_) => MessageType::Command,
+            PacketType::AcceptScriptTeleport(_) => MessageType::Command,
+            PacketType::QuerySelfAgentInfo(_) => MessageType::Command,
+            PacketType::PickInfoRequest(_) => MessageType::Command,
+            PacketType::SetChatFilter(_) => MessageType::Command,
+            PacketType::SetVelocityInterpolation(_) => MessageType::Command,
+            PacketType::ReportUnknownPackets(_) => MessageType::Command,
+            PacketType::QueryCircuitStats(_) => MessageType::Command,
+            PacketType::KickUser(_) => MessageType::Event,
+            PacketType::TeleportStart(_) => MessageType::Event,
+            PacketType::TeleportProgress(_) => MessageType::Event,
+            PacketType::TeleportFailed(_) => MessageType::Event,
+            PacketType::LayerData(_) => MessageType::Event,
+            PacketType::MoneyBalanceReply(_) => MessageType::Event,
+            PacketType::ObjectProperties(_) => MessageType::Event,
+            PacketType::ScriptDialog(_) => MessageType::Event,
+            PacketType::LoadURL(_) => MessageType::Event,
+            PacketType::ScriptQuestion(_) => MessageType::Event,
+            PacketType::SoundTrigger(_) => MessageType::Event,
+            PacketType::AttachedSound(_) => MessageType::Event,
+            PacketType::AttachedSoundGainChange(_) => MessageType::Event,
+            PacketType::PreloadSound(_) => MessageType::Request,
+            PacketType::ViewerEffect(_) => MessageType::Event,
+            PacketType::AgentRequestSit(_) => MessageType::Outgoing,
+            PacketType::AvatarSitResponse(_) => MessageType::Request,
+            PacketType::AgentSit(_) => MessageType::Outgoing,
+            PacketType::SetAlwaysRun(_) => MessageType::Outgoing,
+            PacketType::AgentHeightWidth(_) => MessageType::Outgoing,
+            PacketType::AgentFOV(_) => MessageType::Outgoing,
+            PacketType::MuteListRequest(_) => MessageType::Outgoing,
+            PacketType::MuteListUpdate(_) => MessageType::Request,
+            PacketType::UseCachedMuteList(_) => MessageType::Request,
+            PacketType::UpdateMuteListEntry(_) => MessageType::Outgoing,
+            PacketType::RemoveMuteListEntry(_) => MessageType::Outgoing,
+            PacketType::OnlineNotification(_) => MessageType::Request,
+            PacketType::OfflineNotification(_) => MessageType::Request,
+            PacketType::FetchInventoryDescendents(_) => MessageType::Outgoing,
+            PacketType::InventoryDescendents(_) => MessageType::Request,
+            PacketType::FetchInventory(_) => MessageType::Outgoing,
+            PacketType::FetchInventoryReply(_) => MessageType::Request,
+            PacketType::UpdateInventoryItem(_) => MessageType::Outgoing,
+            PacketType::MoveInventoryItem(_) => MessageType::Outgoing,
+            PacketType::RemoveInventoryItem(_) => MessageType::Outgoing,
+            PacketType::CreateInventoryFolder(_) => MessageType::Outgoing,
+            PacketType::MoveInventoryFolder(_) => MessageType::Outgoing,
+            PacketType::RemoveInventoryFolder(_) => MessageType::Outgoing,
+            PacketType::PurgeInventoryDescendents(_) => MessageType::Outgoing,
+            PacketType::CreateInventoryItem(_) => MessageType::Outgoing,
+            PacketType::UpdateCreateInventoryItem(_) => MessageType::Request,
+            PacketType::AcceptFriendship(_) => MessageType::Outgoing,
+            PacketType::DeclineFriendship(_) => MessageType::Outgoing,
+            PacketType::TerminateFriendship(_) => MessageType::Request,
+            PacketType::GrantUserRights(_) => MessageType::Outgoing,
+            PacketType::ChangeUserRights(_) => MessageType::Request,
+            PacketType::JoinGroupRequest(_) => MessageType::Outgoing,
+            PacketType::JoinGroupReply(_) => MessageType::Event,
+            PacketType::LeaveGroupRequest(_) => MessageType::Outgoing,
+            PacketType::LeaveGroupReply(_) => MessageType::Event,
+            PacketType::ActivateGroup(_) => MessageType::Outgoing,
+            PacketType::AgentDataUpdate(_) => MessageType::Event,
+            PacketType::AvatarPropertiesRequest(_) => MessageType::Outgoing,
+            PacketType::AvatarGroupsReply(_) => MessageType::Event,
+            PacketType::GroupProfileRequest(_) => MessageType::Outgoing,
+            PacketType::GroupProfileReply(_) => MessageType::Event,
+            PacketType::AvatarPropertiesReply(_) => MessageType::Event,
+            PacketType::AvatarInterestsReply(_) => MessageType::Event,
+            PacketType::AvatarPropertiesUpdate(_) => MessageType::Outgoing,
+            PacketType::AvatarInterestsUpdate(_) => MessageType::Outgoing,
+            PacketType::AvatarPickerRequest(_) => MessageType::Outgoing,
+            PacketType::AvatarPickerReply(_) => MessageType::Request,
+            PacketType::DirFindQuery(_) => MessageType::Outgoing,
+            PacketType::DirPlacesReply(_) => MessageType::Request,
+            PacketType::DirPeopleReply(_) => MessageType::Request,
+            PacketType::EstateOwnerMessage(_) => MessageType::Event,
+            PacketType::GodKickUser(_) => MessageType::Outgoing,
+            PacketType::RequestGodlikePowers(_) => MessageType::Outgoing,
+            PacketType::GrantGodlikePowers(_) => MessageType::Event,
+            PacketType::GenericMessage(_) => MessageType::Event,
+            PacketType::EconomyDataRequest(_) => MessageType::Outgoing,
+            PacketType::EconomyData(_) => MessageType::Event,
+            PacketType::RegionInfoRequest(_) => MessageType::Outgoing,
+            PacketType::RegionInfo(_) => MessageType::Event,
+            PacketType::ParcelAccessListRequest(_) => MessageType::Outgoing,
+            PacketType::ParcelAccessListReply(_) => MessageType::Request,
+            PacketType::ParcelAccessListUpdate(_) => MessageType::Outgoing,
+            PacketType::ParcelDwellRequest(_) => MessageType::Outgoing,
+            PacketType::ParcelDwellReply(_) => MessageType::Event,
+            PacketType::LandStatRequest(_) => MessageType::Outgoing,
+            PacketType::LandStatReply(_) => MessageType::Request,
+            PacketType::ObjectDuplicate(_) => MessageType::Outgoing,
+            PacketType::ObjectDuplicateOnRay(_) => MessageType::Outgoing,
+            PacketType::ObjectLink(_) => MessageType::Outgoing,
+            PacketType::ObjectDelink(_) => MessageType::Outgoing,
+            PacketType::ObjectAttach(_) => MessageType::Outgoing,
+            PacketType::ObjectDetach(_) => MessageType::Outgoing,
+            PacketType::ObjectDrop(_) => MessageType::Outgoing,
+            PacketType::RezSingleAttachmentFromInv(_) => MessageType::Outgoing,
+            PacketType::GetScriptRunning(_) => MessageType::Outgoing,
+            PacketType::ScriptRunningReply(_) => MessageType::Request,
+            PacketType::SetScriptRunning(_) => MessageType::Outgoing,
+            PacketType::ScriptReset(_) => MessageType::Outgoing,
+            PacketType::RezScript(_) => MessageType::Outgoing,
+            PacketType::ObjectImage(_) => MessageType::Outgoing,
+            PacketType::ObjectName(_) => MessageType::Outgoing,
+            PacketType::ObjectDescription(_) => MessageType::Outgoing,
+            PacketType::ObjectExtraParams(_) => MessageType::Outgoing,
+            PacketType::ObjectMaterial(_) => MessageType::Outgoing,
+            PacketType::ObjectFlagUpdate(_) => MessageType::Outgoing,
+            PacketType::ObjectClickAction(_) => MessageType::Outgoing,
+            PacketType::ObjectPermissions(_) => MessageType::Outgoing,
+            PacketType::ObjectSaleInfo(_) => MessageType::Outgoing,
+            PacketType::ObjectCategory(_) => MessageType::Outgoing,
+            PacketType::RequestObjectPropertiesFamily(_) => MessageType::Outgoing,
+            PacketType::ObjectPropertiesFamily(_) => MessageType::Request,
+            PacketType::AgentPause(_) => MessageType::Outgoing,
+            PacketType::AgentResume(_) => MessageType::Outgoing,
+            PacketType::CameraConstraint(_) => MessageType::Event,
+            PacketType::SetFollowCamProperties(_) => MessageType::Event,
+            PacketType::ClearFollowCamProperties(_) => MessageType::Event,
+            PacketType::ScriptControlChange(_) => MessageType::Request,
+            PacketType::ForceScriptControlRelease(_) => MessageType::Outgoing,
+            PacketType::ScriptTeleportRequest(_) => MessageType::Event,
+            PacketType::HealthMessage(_) => MessageType::Request,
+            PacketType::SimStats(_) => MessageType::Request,
+            PacketType::SimulatorViewerTimeMessage(_) => MessageType::Request,
+            PacketType::AgentAnimation(_) => MessageType::Outgoing,
+            PacketType::AvatarAnimation(_) => MessageType::Event,
+            PacketType::AgentDataUpdateRequest(_) => MessageType::Outgoing,
+            PacketType::AvatarClassifiedReply(_) => MessageType::Event,
+            PacketType::ClassifiedInfoRequest(_) => MessageType::Outgoing,
+            PacketType::ClassifiedInfoReply(_) => MessageType::Event,
+            PacketType::AvatarPicksReply(_) => MessageType::Event,
+            PacketType::EventInfoRequest(_) => MessageType::Outgoing,
+            PacketType::EventInfoReply(_) => MessageType::Event,
+            PacketType::PickInfoReply(_) => MessageType::Event,
+            PacketType::TelehubInfo(_) => MessageType::Event,
+            PacketType::ParcelMediaCommandMessage(_) => MessageType::Event,
+            PacketType::ParcelMediaUpdate(_) => MessageType::Event,
+            PacketType::ChatPass(_) => MessageType::Event,
+            PacketType::VelocityInterpolateOn(_) => MessageType::Outgoing,
+            PacketType::VelocityInterpolateOff(_) => MessageType::Outgoing,
+            PacketType::FeatureDisabled(_) => MessageType::Request,
         }
     }
     pub fn ui_event(&self) -> UiEventTypes {
@@ -74,9 +835,245 @@ impl PacketType {
             PacketType::ChatFromSimulator(_) => UiEventTypes::ChatFromSimulatorEvent,
             PacketType::CoarseLocationUpdate(_) => UiEventTypes::CoarseLocationUpdateEvent,
             PacketType::DisableSimulator(_) => UiEventTypes::DisableSimulatorEvent,
+            // friendship offers and answers are sent to the UI as FriendshipEvents instead
+            PacketType::ImprovedInstantMessage(data) if data.dialog.is_friendship() => {
+                UiEventTypes::None
+            }
+            PacketType::ImprovedInstantMessage(_) => UiEventTypes::InstantMessageEvent,
+            PacketType::ObjectUpdate(_) => UiEventTypes::ObjectUpdateEvent,
+            PacketType::ImprovedTerseObjectUpdate(_) => UiEventTypes::TerseObjectUpdateEvent,
+            PacketType::KillObject(_) => UiEventTypes::KillObjectEvent,
+            PacketType::RegionHandshake(_) => UiEventTypes::RegionHandshakeEvent,
+            PacketType::PingStats(_) => UiEventTypes::PingStatsEvent,
+            PacketType::Disconnect(_) => UiEventTypes::DisconnectEvent,
+            PacketType::ChildSimulatorEvent(_) => UiEventTypes::ChildSimulatorEvent,
+            PacketType::RegionCrossed(_) => UiEventTypes::RegionCrossedEvent,
+            PacketType::TerrainPatches(_) => UiEventTypes::TerrainPatchEvent,
+            PacketType::WindPatches(_) => UiEventTypes::WindPatchEvent,
+            PacketType::CloudPatches(_) => UiEventTypes::CloudPatchEvent,
+            PacketType::TextureReady(_) => UiEventTypes::TextureReadyEvent,
+            PacketType::AssetReceived(_) => UiEventTypes::AssetReceivedEvent,
+            PacketType::AssetTransferFailed(_) => UiEventTypes::AssetTransferFailedEvent,
+            PacketType::AssetUploaded(_) => UiEventTypes::AssetUploadedEvent,
+            PacketType::AvatarAppearance(_) => UiEventTypes::AvatarAppearanceEvent,
+            PacketType::Wearables(_) => UiEventTypes::WearablesEvent,
+            PacketType::NameResolved(_) => UiEventTypes::NameResolvedEvent,
+            PacketType::GroupNameResolved(_) => UiEventTypes::GroupNameResolvedEvent,
+            PacketType::PurchaseFailed(_) => UiEventTypes::PurchaseFailedEvent,
+            PacketType::ParcelOverlayGrid(_) => UiEventTypes::ParcelOverlayEvent,
+            PacketType::MapBlocks(_) => UiEventTypes::MapBlocksEvent,
+            PacketType::MapItems(_) => UiEventTypes::MapItemsEvent,
+            PacketType::MapSearchResults(_) => UiEventTypes::MapSearchResultsEvent,
+            PacketType::MapSearchEmpty(_) => UiEventTypes::MapSearchEmptyEvent,
+            PacketType::SitStatus(_) => UiEventTypes::SitStatusEvent,
+            PacketType::MuteList(_) => UiEventTypes::MuteListEvent,
+            PacketType::FriendList(_) => UiEventTypes::FriendListEvent,
+            PacketType::InventoryFolderContents(_) => UiEventTypes::InventoryFolderEvent,
+            PacketType::InventoryItemStatus(_) => UiEventTypes::InventoryItemEvent,
+            PacketType::InventoryFolderStatus(_) => UiEventTypes::InventoryFolderStatusEvent,
+            PacketType::InventoryItemCreated(_) => UiEventTypes::InventoryItemCreatedEvent,
+            PacketType::FriendshipStatus(_) => UiEventTypes::FriendshipEvent,
+            PacketType::PeopleSearchResults(_) => UiEventTypes::PeopleSearchResultsEvent,
+            PacketType::PeopleSearchEmpty(_) => UiEventTypes::PeopleSearchEmptyEvent,
+            PacketType::DirectoryPeoplePage(_) => UiEventTypes::DirectoryPeopleEvent,
+            PacketType::DirectoryPlacesPage(_) => UiEventTypes::DirectoryPlacesEvent,
+            PacketType::ParcelAccessList(_) => UiEventTypes::ParcelAccessListEvent,
+            PacketType::LandStatReport(_) => UiEventTypes::LandStatEvent,
+            PacketType::Attachments(_) => UiEventTypes::AttachmentsEvent,
+            PacketType::ScriptRunningStatus(_) => UiEventTypes::ScriptRunningStatusEvent,
+            PacketType::ObjectEditAck(_) => UiEventTypes::ObjectEditAckEvent,
+            PacketType::ObjectFamilyStatus(_) => UiEventTypes::ObjectFamilyStatusEvent,
+            PacketType::ScriptControls(_) => UiEventTypes::ScriptControlsEvent,
+            PacketType::AgentDied(_) => UiEventTypes::AgentDiedEvent,
+            PacketType::SelfAgentInfo(_) => UiEventTypes::SelfAgentInfoEvent,
+            PacketType::VelocityInterpolationStatus(_) => UiEventTypes::VelocityInterpolationEvent,
+            PacketType::FeatureDisabledNotice(_) => UiEventTypes::FeatureDisabledEvent,
+            PacketType::UnknownPacketNotice(_) => UiEventTypes::UnknownPacketEvent,
+            PacketType::CircuitStatsReport(_) => UiEventTypes::CircuitStatsEvent,
+            PacketType::JoinGroupReply(_) => UiEventTypes::JoinGroupEvent,
+            PacketType::LeaveGroupReply(_) => UiEventTypes::LeaveGroupEvent,
+            PacketType::AgentDataUpdate(_) => UiEventTypes::AgentDataEvent,
+            PacketType::AvatarGroupsReply(_) => UiEventTypes::AvatarGroupsEvent,
+            PacketType::GroupProfileReply(_) => UiEventTypes::GroupProfileEvent,
+            PacketType::AvatarPropertiesReply(_) => UiEventTypes::AvatarPropertiesEvent,
+            PacketType::AvatarInterestsReply(_) => UiEventTypes::AvatarInterestsEvent,
+            PacketType::EstateOwnerMessage(_) => UiEventTypes::EstateOwnerMessageEvent,
+            PacketType::GrantGodlikePowers(_) => UiEventTypes::GodPowersEvent,
+            PacketType::GenericMessage(_) => UiEventTypes::GenericMessageEvent,
+            PacketType::EconomyData(_) => UiEventTypes::EconomyDataEvent,
+            PacketType::RegionInfo(_) => UiEventTypes::RegionInfoEvent,
+            PacketType::ParcelDwellReply(_) => UiEventTypes::ParcelDwellEvent,
+            PacketType::CameraConstraint(_) => UiEventTypes::CameraConstraintEvent,
+            PacketType::SetFollowCamProperties(_) => UiEventTypes::SetFollowCamPropertiesEvent,
+            PacketType::ClearFollowCamProperties(_) => UiEventTypes::ClearFollowCamPropertiesEvent,
+            PacketType::ScriptTeleportRequest(_) => UiEventTypes::ScriptTeleportOfferEvent,
+            PacketType::HealthMessage(_) => UiEventTypes::HealthEvent,
+            PacketType::SimStats(_) => UiEventTypes::SimStatsEvent,
+            PacketType::SimulatorViewerTimeMessage(_) => UiEventTypes::SimulatorTimeEvent,
+            PacketType::AvatarAnimation(_) => UiEventTypes::AvatarAnimationEvent,
+            PacketType::AvatarClassifiedReply(_) => UiEventTypes::AvatarClassifiedsEvent,
+            PacketType::ClassifiedInfoReply(_) => UiEventTypes::ClassifiedInfoEvent,
+            PacketType::AvatarPicksReply(_) => UiEventTypes::AvatarPicksEvent,
+            PacketType::EventInfoReply(_) => UiEventTypes::EventInfoEvent,
+            PacketType::PickInfoReply(_) => UiEventTypes::PickInfoEvent,
+            PacketType::TelehubInfo(_) => UiEventTypes::TelehubInfoEvent,
+            PacketType::ParcelMediaCommandMessage(_) => UiEventTypes::ParcelMediaCommandEvent,
+            PacketType::ParcelMediaUpdate(_) => UiEventTypes::ParcelMediaUpdateEvent,
+            PacketType::ChatPass(_) => UiEventTypes::ChatPassEvent,
+            PacketType::OnlineNotification(_) => UiEventTypes::FriendOnlineEvent,
+            PacketType::OfflineNotification(_) => UiEventTypes::FriendOfflineEvent,
+            PacketType::LayerData(data) => match data.layer_type {
+                LayerType::Land => UiEventTypes::TerrainPatchEvent,
+                LayerType::Wind => UiEventTypes::WindPatchEvent,
+                LayerType::Cloud => UiEventTypes::CloudPatchEvent,
+                _ => UiEventTypes::None,
+            },
+            PacketType::KickUser(_) => UiEventTypes::KickedEvent,
+            PacketType::TeleportStart(_) => UiEventTypes::TeleportStartEvent,
+            PacketType::TeleportProgress(_) => UiEventTypes::TeleportProgressEvent,
+            PacketType::TeleportFailed(_) => UiEventTypes::TeleportFailedEvent,
+            PacketType::TeleportFinish(_) => UiEventTypes::TeleportCompleteEvent,
+            PacketType::MoneyBalanceReply(_) => UiEventTypes::BalanceEvent,
+            PacketType::ObjectProperties(_) => UiEventTypes::ObjectPropertiesEvent,
+            PacketType::ScriptDialog(_) => UiEventTypes::ScriptDialogEvent,
+            PacketType::LoadURL(_) => UiEventTypes::LoadUrlEvent,
+            PacketType::ScriptQuestion(_) => UiEventTypes::ScriptQuestionEvent,
+            PacketType::SoundTrigger(_) => UiEventTypes::SoundTriggerEvent,
+            PacketType::AttachedSound(_) => UiEventTypes::AttachedSoundEvent,
+            PacketType::AttachedSoundGainChange(_) => UiEventTypes::AttachedSoundGainChangeEvent,
+            PacketType::PreloadSound(_) => UiEventTypes::PreloadSoundEvent,
+            PacketType::ViewerEffect(_) => UiEventTypes::ViewerEffectEvent,
+            PacketType::ParcelProperties(_) => UiEventTypes::ParcelPropertiesEvent,
             _ => UiEventTypes::None,
         }
     }
+    // the bytes that are sent to the UI for event packets.
+    // most events are sent as their packet bytes, but some are sent as JSON to make them easier
+    // for the UI to consume.
+    pub fn ui_event_bytes(&self) -> Vec<u8> {
+        match self {
+            PacketType::ImprovedInstantMessage(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::KillObject(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::RegionHandshake(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AvatarAppearance(data) => serde_json::to_vec(data).unwrap_or_default(),
+            // the UI gets the decoded patches, not the compressed layer
+            PacketType::LayerData(data) => {
+                let patches = match data.layer_type {
+                    LayerType::Land => data
+                        .terrain_patches()
+                        .map(|patches| TerrainPatches { patches }.to_bytes()),
+                    LayerType::Wind => data
+                        .wind_patches()
+                        .map(|patches| WindPatches { patches }.to_bytes()),
+                    LayerType::Cloud => data
+                        .cloud_patches()
+                        .map(|patches| CloudPatches { patches }.to_bytes()),
+                    _ => Ok(self.to_bytes()),
+                };
+                match patches {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        warn!("failed to decode LayerData: {:?}", e);
+                        Vec::new()
+                    }
+                }
+            }
+            PacketType::KickUser(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::TeleportStart(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::TeleportProgress(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::TeleportFailed(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::TeleportFinish(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MoneyBalanceReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ObjectProperties(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ScriptDialog(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::LoadURL(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ScriptQuestion(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SoundTrigger(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AttachedSound(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AttachedSoundGainChange(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::PreloadSound(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ViewerEffect(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ParcelProperties(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MapBlocks(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MapItems(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MapSearchResults(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MapSearchEmpty(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SitStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MuteList(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::FriendList(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::InventoryFolderContents(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::InventoryItemStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::InventoryFolderStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::InventoryItemCreated(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::FriendshipStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::PeopleSearchResults(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::PeopleSearchEmpty(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::DirectoryPeoplePage(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::DirectoryPlacesPage(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ParcelAccessList(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::LandStatReport(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::Attachments(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ScriptRunningStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ObjectEditAck(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ObjectFamilyStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ScriptControls(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AgentDied(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SelfAgentInfo(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::VelocityInterpolationStatus(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::FeatureDisabledNotice(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::UnknownPacketNotice(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::CircuitStatsReport(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::JoinGroupReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::LeaveGroupReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AgentDataUpdate(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AvatarGroupsReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::GroupProfileReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AvatarPropertiesReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AvatarInterestsReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::EstateOwnerMessage(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::GrantGodlikePowers(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::GenericMessage(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::EconomyData(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::RegionInfo(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ParcelDwellReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::CameraConstraint(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SetFollowCamProperties(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::ClearFollowCamProperties(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::ScriptTeleportRequest(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::HealthMessage(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SimStats(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SimulatorViewerTimeMessage(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::AvatarAnimation(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AvatarClassifiedReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ClassifiedInfoReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AvatarPicksReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::EventInfoReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::PickInfoReply(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::TelehubInfo(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ParcelMediaCommandMessage(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::ParcelMediaUpdate(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ChatPass(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::OnlineNotification(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::OfflineNotification(data) => serde_json::to_vec(data).unwrap_or_default(),
+            _ => self.to_bytes(),
+        }
+    }
     pub fn to_bytes(&self) -> Vec<u8> {
         match self {
             PacketType::CircuitCode(data) => data.to_bytes(),
@@ -93,8 +1090,260 @@ impl PacketType {
             PacketType::CompletePingCheck(data) => data.to_bytes(),
             PacketType::RegionHandshake(data) => data.to_bytes(),
             PacketType::RegionHandshakeReply(data) => data.to_bytes(),
+            PacketType::ImprovedInstantMessage(data) => data.to_bytes(),
+            PacketType::ObjectUpdate(data) => data.to_bytes(),
+            PacketType::ImprovedTerseObjectUpdate(data) => data.to_bytes(),
+            PacketType::KillObject(data) => data.to_bytes(),
+            PacketType::AgentThrottle(data) => data.to_bytes(),
+            PacketType::LogoutRequest(data) => data.to_bytes(),
+            PacketType::LogoutReply(data) => data.to_bytes(),
+            PacketType::KickUser(data) => data.to_bytes(),
+            PacketType::TeleportLocationRequest(data) => data.to_bytes(),
+            PacketType::TeleportStart(data) => data.to_bytes(),
+            PacketType::TeleportProgress(data) => data.to_bytes(),
+            PacketType::TeleportFailed(data) => data.to_bytes(),
+            PacketType::TeleportFinish(data) => data.to_bytes(),
+            PacketType::EnableSimulator(data) => data.to_bytes(),
+            PacketType::CrossedRegion(data) => data.to_bytes(),
+            PacketType::LayerData(data) => data.to_bytes(),
+            PacketType::RequestImage(data) => data.to_bytes(),
+            PacketType::ImageData(data) => data.to_bytes(),
+            PacketType::ImagePacket(data) => data.to_bytes(),
+            PacketType::TransferRequest(data) => data.to_bytes(),
+            PacketType::AssetUploadRequest(data) => data.to_bytes(),
+            PacketType::AssetUploadComplete(data) => data.to_bytes(),
+            PacketType::AvatarAppearance(data) => data.to_bytes(),
+            PacketType::AgentWearablesRequest(data) => data.to_bytes(),
+            PacketType::AgentWearablesUpdate(data) => data.to_bytes(),
+            PacketType::AgentSetAppearance(data) => data.to_bytes(),
+            PacketType::TransferInfo(data) => data.to_bytes(),
+            PacketType::TransferPacket(data) => data.to_bytes(),
+            PacketType::RequestXfer(data) => data.to_bytes(),
+            PacketType::SendXferPacket(data) => data.to_bytes(),
+            PacketType::ConfirmXferPacket(data) => data.to_bytes(),
+            PacketType::AbortXfer(data) => data.to_bytes(),
+            PacketType::UUIDNameRequest(data) => data.to_bytes(),
+            PacketType::UUIDNameReply(data) => data.to_bytes(),
+            PacketType::UUIDGroupNameRequest(data) => data.to_bytes(),
+            PacketType::UUIDGroupNameReply(data) => data.to_bytes(),
+            PacketType::MoneyBalanceRequest(data) => data.to_bytes(),
+            PacketType::MoneyBalanceReply(data) => data.to_bytes(),
+            PacketType::ObjectSelect(data) => data.to_bytes(),
+            PacketType::ObjectDeselect(data) => data.to_bytes(),
+            PacketType::ObjectGrab(data) => data.to_bytes(),
+            PacketType::ObjectGrabUpdate(data) => data.to_bytes(),
+            PacketType::ObjectDeGrab(data) => data.to_bytes(),
+            PacketType::ObjectAdd(data) => data.to_bytes(),
+            PacketType::DeRezObject(data) => data.to_bytes(),
+            PacketType::ObjectDelete(data) => data.to_bytes(),
+            PacketType::MultipleObjectUpdate(data) => data.to_bytes(),
+            PacketType::RezObject(data) => data.to_bytes(),
+            PacketType::ObjectBuy(data) => data.to_bytes(),
+            PacketType::ObjectProperties(data) => data.to_bytes(),
+            PacketType::ParcelPropertiesRequest(data) => data.to_bytes(),
+            PacketType::ParcelProperties(data) => data.to_bytes(),
+            PacketType::ParcelOverlay(data) => data.to_bytes(),
+            PacketType::MapBlockRequest(data) => data.to_bytes(),
+            PacketType::MapBlockReply(data) => data.to_bytes(),
+            PacketType::MapItemRequest(data) => data.to_bytes(),
+            PacketType::MapItemReply(data) => data.to_bytes(),
+            PacketType::MapNameRequest(data) => data.to_bytes(),
+            PacketType::ScriptDialog(data) => data.to_bytes(),
+            PacketType::ScriptDialogReply(data) => data.to_bytes(),
+            PacketType::LoadURL(data) => data.to_bytes(),
+            PacketType::ScriptQuestion(data) => data.to_bytes(),
+            PacketType::ScriptAnswerYes(data) => data.to_bytes(),
+            PacketType::RevokePermissions(data) => data.to_bytes(),
+            PacketType::SoundTrigger(data) => data.to_bytes(),
+            PacketType::AttachedSound(data) => data.to_bytes(),
+            PacketType::AttachedSoundGainChange(data) => data.to_bytes(),
+            PacketType::PreloadSound(data) => data.to_bytes(),
+            PacketType::ViewerEffect(data) => data.to_bytes(),
+            PacketType::AgentRequestSit(data) => data.to_bytes(),
+            PacketType::AvatarSitResponse(data) => data.to_bytes(),
+            PacketType::AgentSit(data) => data.to_bytes(),
+            PacketType::SetAlwaysRun(data) => data.to_bytes(),
+            PacketType::AgentHeightWidth(data) => data.to_bytes(),
+            PacketType::AgentFOV(data) => data.to_bytes(),
+            PacketType::MuteListRequest(data) => data.to_bytes(),
+            PacketType::MuteListUpdate(data) => data.to_bytes(),
+            PacketType::UseCachedMuteList(data) => data.to_bytes(),
+            PacketType::UpdateMuteListEntry(data) => data.to_bytes(),
+            PacketType::RemoveMuteListEntry(data) => data.to_bytes(),
+            PacketType::OnlineNotification(data) => data.to_bytes(),
+            PacketType::OfflineNotification(data) => data.to_bytes(),
+            PacketType::FetchInventoryDescendents(data) => data.to_bytes(),
+            PacketType::InventoryDescendents(data) => data.to_bytes(),
+            PacketType::FetchInventory(data) => data.to_bytes(),
+            PacketType::FetchInventoryReply(data) => data.to_bytes(),
+            PacketType::UpdateInventoryItem(data) => data.to_bytes(),
+            PacketType::MoveInventoryItem(data) => data.to_bytes(),
+            PacketType::RemoveInventoryItem(data) => data.to_bytes(),
+            PacketType::CreateInventoryFolder(data) => data.to_bytes(),
+            PacketType::MoveInventoryFolder(data) => data.to_bytes(),
+            PacketType::RemoveInventoryFolder(data) => data.to_bytes(),
+            PacketType::PurgeInventoryDescendents(data) => data.to_bytes(),
+            PacketType::CreateInventoryItem(data) => data.to_bytes(),
+            PacketType::UpdateCreateInventoryItem(data) => data.to_bytes(),
+            PacketType::AcceptFriendship(data) => data.to_bytes(),
+            PacketType::DeclineFriendship(data) => data.to_bytes(),
+            PacketType::TerminateFriendship(data) => data.to_bytes(),
+            PacketType::GrantUserRights(data) => data.to_bytes(),
+            PacketType::ChangeUserRights(data) => data.to_bytes(),
+            PacketType::JoinGroupRequest(data) => data.to_bytes(),
+            PacketType::JoinGroupReply(data) => data.to_bytes(),
+            PacketType::LeaveGroupRequest(data) => data.to_bytes(),
+            PacketType::LeaveGroupReply(data) => data.to_bytes(),
+            PacketType::ActivateGroup(data) => data.to_bytes(),
+            PacketType::AgentDataUpdate(data) => data.to_bytes(),
+            PacketType::AvatarPropertiesRequest(data) => data.to_bytes(),
+            PacketType::AvatarGroupsReply(data) => data.to_bytes(),
+            PacketType::GroupProfileRequest(data) => data.to_bytes(),
+            PacketType::GroupProfileReply(data) => data.to_bytes(),
+            PacketType::AvatarPropertiesReply(data) => data.to_bytes(),
+            PacketType::AvatarInterestsReply(data) => data.to_bytes(),
+            PacketType::AvatarPropertiesUpdate(data) => data.to_bytes(),
+            PacketType::AvatarInterestsUpdate(data) => data.to_bytes(),
+            PacketType::AvatarPickerRequest(data) => data.to_bytes(),
+            PacketType::AvatarPickerReply(data) => data.to_bytes(),
+            PacketType::DirFindQuery(data) => data.to_bytes(),
+            PacketType::DirPlacesReply(data) => data.to_bytes(),
+            PacketType::DirPeopleReply(data) => data.to_bytes(),
+            PacketType::EstateOwnerMessage(data) => data.to_bytes(),
+            PacketType::GodKickUser(data) => data.to_bytes(),
+            PacketType::RequestGodlikePowers(data) => data.to_bytes(),
+            PacketType::GrantGodlikePowers(data) => data.to_bytes(),
+            PacketType::GenericMessage(data) => data.to_bytes(),
+            PacketType::EconomyDataRequest(data) => data.to_bytes(),
+            PacketType::EconomyData(data) => data.to_bytes(),
+            PacketType::RegionInfoRequest(data) => data.to_bytes(),
+            PacketType::RegionInfo(data) => data.to_bytes(),
+            PacketType::ParcelAccessListRequest(data) => data.to_bytes(),
+            PacketType::ParcelAccessListReply(data) => data.to_bytes(),
+            PacketType::ParcelAccessListUpdate(data) => data.to_bytes(),
+            PacketType::ParcelDwellRequest(data) => data.to_bytes(),
+            PacketType::ParcelDwellReply(data) => data.to_bytes(),
+            PacketType::LandStatRequest(data) => data.to_bytes(),
+            PacketType::LandStatReply(data) => data.to_bytes(),
+            PacketType::ObjectDuplicate(data) => data.to_bytes(),
+            PacketType::ObjectDuplicateOnRay(data) => data.to_bytes(),
+            PacketType::ObjectLink(data) => data.to_bytes(),
+            PacketType::ObjectDelink(data) => data.to_bytes(),
+            PacketType::ObjectAttach(data) => data.to_bytes(),
+            PacketType::ObjectDetach(data) => data.to_bytes(),
+            PacketType::ObjectDrop(data) => data.to_bytes(),
+            PacketType::RezSingleAttachmentFromInv(data) => data.to_bytes(),
+            PacketType::GetScriptRunning(data) => data.to_bytes(),
+            PacketType::ScriptRunningReply(data) => data.to_bytes(),
+            PacketType::SetScriptRunning(data) => data.to_bytes(),
+            PacketType::ScriptReset(data) => data.to_bytes(),
+            PacketType::RezScript(data) => data.to_bytes(),
+            PacketType::ObjectImage(data) => data.to_bytes(),
+            PacketType::ObjectName(data) => data.to_bytes(),
+            PacketType::ObjectDescription(data) => data.to_bytes(),
+            PacketType::ObjectExtraParams(data) => data.to_bytes(),
+            PacketType::ObjectMaterial(data) => data.to_bytes(),
+            PacketType::ObjectFlagUpdate(data) => data.to_bytes(),
+            PacketType::ObjectClickAction(data) => data.to_bytes(),
+            PacketType::ObjectPermissions(data) => data.to_bytes(),
+            PacketType::ObjectSaleInfo(data) => data.to_bytes(),
+            PacketType::ObjectCategory(data) => data.to_bytes(),
+            PacketType::RequestObjectPropertiesFamily(data) => data.to_bytes(),
+            PacketType::ObjectPropertiesFamily(data) => data.to_bytes(),
+            PacketType::AgentPause(data) => data.to_bytes(),
+            PacketType::AgentResume(data) => data.to_bytes(),
+            PacketType::CameraConstraint(data) => data.to_bytes(),
+            PacketType::SetFollowCamProperties(data) => data.to_bytes(),
+            PacketType::ClearFollowCamProperties(data) => data.to_bytes(),
+            PacketType::ScriptControlChange(data) => data.to_bytes(),
+            PacketType::ForceScriptControlRelease(data) => data.to_bytes(),
+            PacketType::ScriptTeleportRequest(data) => data.to_bytes(),
+            PacketType::HealthMessage(data) => data.to_bytes(),
+            PacketType::SimStats(data) => data.to_bytes(),
+            PacketType::SimulatorViewerTimeMessage(data) => data.to_bytes(),
+            PacketType::AgentAnimation(data) => data.to_bytes(),
+            PacketType::AvatarAnimation(data) => data.to_bytes(),
+            PacketType::AgentDataUpdateRequest(data) => data.to_bytes(),
+            PacketType::AvatarClassifiedReply(data) => data.to_bytes(),
+            PacketType::ClassifiedInfoRequest(data) => data.to_bytes(),
+            PacketType::ClassifiedInfoReply(data) => data.to_bytes(),
+            PacketType::AvatarPicksReply(data) => data.to_bytes(),
+            PacketType::EventInfoRequest(data) => data.to_bytes(),
+            PacketType::EventInfoReply(data) => data.to_bytes(),
+            PacketType::PickInfoReply(data) => data.to_bytes(),
+            PacketType::TelehubInfo(data) => data.to_bytes(),
+            PacketType::ParcelMediaCommandMessage(data) => data.to_bytes(),
+            PacketType::ParcelMediaUpdate(data) => data.to_bytes(),
+            PacketType::ChatPass(data) => data.to_bytes(),
+            PacketType::VelocityInterpolateOn(data) => data.to_bytes(),
+            PacketType::VelocityInterpolateOff(data) => data.to_bytes(),
+            PacketType::FeatureDisabled(data) => data.to_bytes(),
 
             PacketType::LoginResponse(_) => Vec::new(),
+            PacketType::PingStats(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::Disconnect(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ChildSimulatorEvent(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::RegionCrossed(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::TerrainPatches(data) => data.to_bytes(),
+            PacketType::WindPatches(data) => data.to_bytes(),
+            PacketType::CloudPatches(data) => data.to_bytes(),
+            PacketType::TextureReady(data) => data.to_bytes(),
+            PacketType::AssetReceived(data) => data.to_bytes(),
+            PacketType::AssetTransferFailed(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AssetUploaded(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::Wearables(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::NameResolved(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::GroupNameResolved(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::PurchaseFailed(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ParcelOverlayGrid(data) => data.to_bytes(),
+            PacketType::MapBlocks(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MapItems(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MapSearchResults(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MapSearchEmpty(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SitStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::MuteList(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::FriendList(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::InventoryFolderContents(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::InventoryItemStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::InventoryFolderStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::PurgeInventoryFolder(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::InventoryItemCreated(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::FriendshipStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::PeopleSearchResults(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::PeopleSearchEmpty(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::DirectoryPeoplePage(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::DirectoryPlacesPage(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ParcelAccessList(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::LandStatReport(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::Attachments(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ScriptRunningStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ObjectEditAck(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ObjectFamilyStatus(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::ScriptControls(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AgentDied(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SelfAgentInfo(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::VelocityInterpolationStatus(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::FeatureDisabledNotice(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AttachFromInventory(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::DetachAttachment(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SetExtraParams(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::QueryObjectFamily(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::AcceptScriptTeleport(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::QuerySelfAgentInfo(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::PickInfoRequest(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SetChatFilter(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::SetVelocityInterpolation(data) => {
+                serde_json::to_vec(data).unwrap_or_default()
+            }
+            PacketType::ReportUnknownPackets(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::QueryCircuitStats(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::UnknownPacketNotice(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::CircuitStatsReport(data) => serde_json::to_vec(data).unwrap_or_default(),
+            PacketType::Unknown(data) => data.to_bytes(),
         }
     }
 }
@@ -120,19 +1369,106 @@ impl PacketType {
                 4 => Ok(PacketType::AgentUpdate(Box::new(AgentUpdate::from_bytes(
                     bytes,
                 )?))),
-                id => Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Unknown packet ID: {}, frequency: {}", id, frequency),
-                )),
+                12 => Ok(PacketType::ObjectUpdate(Box::new(
+                    ObjectUpdate::from_bytes(bytes)?,
+                ))),
+                15 => Ok(PacketType::ImprovedTerseObjectUpdate(Box::new(
+                    ImprovedTerseObjectUpdate::from_bytes(bytes)?,
+                ))),
+                16 => Ok(PacketType::KillObject(Box::new(
+                    KillObjectData::from_bytes(bytes)?,
+                ))),
+                17 => Ok(PacketType::TransferPacket(Box::new(
+                    TransferPacket::from_bytes(bytes)?,
+                ))),
+                18 => Ok(PacketType::SendXferPacket(Box::new(
+                    SendXferPacket::from_bytes(bytes)?,
+                ))),
+                19 => Ok(PacketType::ConfirmXferPacket(Box::new(
+                    ConfirmXferPacket::from_bytes(bytes)?,
+                ))),
+                23 => Ok(PacketType::ParcelProperties(Box::new(
+                    ParcelProperties::from_bytes(bytes)?,
+                ))),
+                29 => Ok(PacketType::SoundTrigger(Box::new(
+                    SoundTrigger::from_bytes(bytes)?,
+                ))),
+                6 => Ok(PacketType::AgentRequestSit(Box::new(
+                    AgentRequestSit::from_bytes(bytes)?,
+                ))),
+                7 => Ok(PacketType::AgentSit(Box::new(AgentSit::from_bytes(bytes)?))),
+                5 => Ok(PacketType::AgentAnimation(Box::new(
+                    AgentAnimation::from_bytes(bytes)?,
+                ))),
+                20 => Ok(PacketType::AvatarAnimation(Box::new(
+                    AvatarAnimation::from_bytes(bytes)?,
+                ))),
+                21 => Ok(PacketType::AvatarSitResponse(Box::new(
+                    AvatarSitResponse::from_bytes(bytes)?,
+                ))),
+                22 => Ok(PacketType::CameraConstraint(Box::new(
+                    CameraConstraint::from_bytes(bytes)?,
+                ))),
+                8 => Ok(PacketType::RequestImage(Box::new(
+                    RequestImage::from_bytes(bytes)?,
+                ))),
+                9 => Ok(PacketType::ImageData(Box::new(ImageData::from_bytes(
+                    bytes,
+                )?))),
+                10 => Ok(PacketType::ImagePacket(Box::new(ImagePacket::from_bytes(
+                    bytes,
+                )?))),
+                11 => Ok(PacketType::LayerData(Box::new(LayerData::from_bytes(
+                    bytes,
+                )?))),
+                id => Ok(PacketType::Unknown(Box::new(RawPacketData {
+                    frequency,
+                    id,
+                    body: bytes.to_vec(),
+                }))),
             },
             PacketFrequency::Medium => match id {
+                1 => Ok(PacketType::ObjectAdd(Box::new(ObjectAdd::from_bytes(
+                    bytes,
+                )?))),
+                2 => Ok(PacketType::MultipleObjectUpdate(Box::new(
+                    MultipleObjectUpdate::from_bytes(bytes)?,
+                ))),
+                5 => Ok(PacketType::RequestObjectPropertiesFamily(Box::new(
+                    RequestObjectPropertiesFamily::from_bytes(bytes)?,
+                ))),
                 6 => Ok(PacketType::CoarseLocationUpdate(Box::new(
                     CoarseLocationUpdate::from_bytes(bytes)?,
                 ))),
-                id => Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Unknown packet ID: {}, frequency: {}", id, frequency),
-                )),
+                7 => Ok(PacketType::CrossedRegion(Box::new(
+                    CrossedRegion::from_bytes(bytes)?,
+                ))),
+                9 => Ok(PacketType::ObjectProperties(Box::new(
+                    ObjectProperties::from_bytes(bytes)?,
+                ))),
+                10 => Ok(PacketType::ObjectPropertiesFamily(Box::new(
+                    ObjectPropertiesFamily::from_bytes(bytes)?,
+                ))),
+                11 => Ok(PacketType::ParcelPropertiesRequest(Box::new(
+                    ParcelPropertiesRequest::from_bytes(bytes)?,
+                ))),
+                13 => Ok(PacketType::AttachedSound(Box::new(
+                    AttachedSound::from_bytes(bytes)?,
+                ))),
+                14 => Ok(PacketType::AttachedSoundGainChange(Box::new(
+                    AttachedSoundGainChange::from_bytes(bytes)?,
+                ))),
+                15 => Ok(PacketType::PreloadSound(Box::new(
+                    PreloadSound::from_bytes(bytes)?,
+                ))),
+                17 => Ok(PacketType::ViewerEffect(Box::new(
+                    ViewerEffect::from_bytes(bytes)?,
+                ))),
+                id => Ok(PacketType::Unknown(Box::new(RawPacketData {
+                    frequency,
+                    id,
+                    body: bytes.to_vec(),
+                }))),
             },
             PacketFrequency::Low => match id {
                 3 => Ok(PacketType::CircuitCode(Box::new(
@@ -156,21 +1492,537 @@ impl PacketType {
                 80 => Ok(PacketType::ChatFromViewer(Box::new(
                     ChatFromViewer::from_bytes(bytes)?,
                 ))),
-                id => Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Unknown packet ID: {}, frequency: {}", id, frequency),
-                )),
+                254 => Ok(PacketType::ImprovedInstantMessage(Box::new(
+                    ImprovedInstantMessage::from_bytes(bytes)?,
+                ))),
+                81 => Ok(PacketType::AgentThrottle(Box::new(
+                    AgentThrottle::from_bytes(bytes)?,
+                ))),
+                252 => Ok(PacketType::LogoutRequest(Box::new(
+                    LogoutRequest::from_bytes(bytes)?,
+                ))),
+                253 => Ok(PacketType::LogoutReply(Box::new(LogoutReply::from_bytes(
+                    bytes,
+                )?))),
+                163 => Ok(PacketType::KickUser(Box::new(KickUser::from_bytes(bytes)?))),
+                63 => Ok(PacketType::TeleportLocationRequest(Box::new(
+                    TeleportLocationRequest::from_bytes(bytes)?,
+                ))),
+                73 => Ok(PacketType::TeleportStart(Box::new(
+                    TeleportStart::from_bytes(bytes)?,
+                ))),
+                66 => Ok(PacketType::TeleportProgress(Box::new(
+                    TeleportProgress::from_bytes(bytes)?,
+                ))),
+                74 => Ok(PacketType::TeleportFailed(Box::new(
+                    TeleportFailed::from_bytes(bytes)?,
+                ))),
+                69 => Ok(PacketType::TeleportFinish(Box::new(
+                    TeleportFinish::from_bytes(bytes)?,
+                ))),
+                110 => Ok(PacketType::ObjectSelect(Box::new(
+                    ObjectSelect::from_bytes(bytes)?,
+                ))),
+                111 => Ok(PacketType::ObjectDeselect(Box::new(
+                    ObjectDeselect::from_bytes(bytes)?,
+                ))),
+                117 => Ok(PacketType::ObjectGrab(Box::new(ObjectGrab::from_bytes(
+                    bytes,
+                )?))),
+                118 => Ok(PacketType::ObjectGrabUpdate(Box::new(
+                    ObjectGrabUpdate::from_bytes(bytes)?,
+                ))),
+                119 => Ok(PacketType::ObjectDeGrab(Box::new(
+                    ObjectDeGrab::from_bytes(bytes)?,
+                ))),
+                291 => Ok(PacketType::DeRezObject(Box::new(DeRezObject::from_bytes(
+                    bytes,
+                )?))),
+                89 => Ok(PacketType::ObjectDelete(Box::new(
+                    ObjectDelete::from_bytes(bytes)?,
+                ))),
+                293 => Ok(PacketType::RezObject(Box::new(RezObject::from_bytes(
+                    bytes,
+                )?))),
+                102 => Ok(PacketType::ObjectBuy(Box::new(ObjectBuy::from_bytes(
+                    bytes,
+                )?))),
+                196 => Ok(PacketType::ParcelOverlay(Box::new(
+                    ParcelOverlay::from_bytes(bytes)?,
+                ))),
+                190 => Ok(PacketType::ScriptDialog(Box::new(
+                    ScriptDialog::from_bytes(bytes)?,
+                ))),
+                191 => Ok(PacketType::ScriptDialogReply(Box::new(
+                    ScriptDialogReply::from_bytes(bytes)?,
+                ))),
+                194 => Ok(PacketType::LoadURL(Box::new(LoadURL::from_bytes(bytes)?))),
+                188 => Ok(PacketType::ScriptQuestion(Box::new(
+                    ScriptQuestion::from_bytes(bytes)?,
+                ))),
+                132 => Ok(PacketType::ScriptAnswerYes(Box::new(
+                    ScriptAnswerYes::from_bytes(bytes)?,
+                ))),
+                193 => Ok(PacketType::RevokePermissions(Box::new(
+                    RevokePermissions::from_bytes(bytes)?,
+                ))),
+                407 => Ok(PacketType::MapBlockRequest(Box::new(
+                    MapBlockRequest::from_bytes(bytes)?,
+                ))),
+                408 => Ok(PacketType::MapNameRequest(Box::new(
+                    MapNameRequest::from_bytes(bytes)?,
+                ))),
+                409 => Ok(PacketType::MapBlockReply(Box::new(
+                    MapBlockReply::from_bytes(bytes)?,
+                ))),
+                410 => Ok(PacketType::MapItemRequest(Box::new(
+                    MapItemRequest::from_bytes(bytes)?,
+                ))),
+                411 => Ok(PacketType::MapItemReply(Box::new(
+                    MapItemReply::from_bytes(bytes)?,
+                ))),
+                88 => Ok(PacketType::SetAlwaysRun(Box::new(
+                    SetAlwaysRun::from_bytes(bytes)?,
+                ))),
+                83 => Ok(PacketType::AgentHeightWidth(Box::new(
+                    AgentHeightWidth::from_bytes(bytes)?,
+                ))),
+                82 => Ok(PacketType::AgentFOV(Box::new(AgentFOV::from_bytes(bytes)?))),
+                263 => Ok(PacketType::MuteListRequest(Box::new(
+                    MuteListRequest::from_bytes(bytes)?,
+                ))),
+                318 => Ok(PacketType::MuteListUpdate(Box::new(
+                    MuteListUpdate::from_bytes(bytes)?,
+                ))),
+                319 => Ok(PacketType::UseCachedMuteList(Box::new(
+                    UseCachedMuteList::from_bytes(bytes)?,
+                ))),
+                264 => Ok(PacketType::UpdateMuteListEntry(Box::new(
+                    UpdateMuteListEntry::from_bytes(bytes)?,
+                ))),
+                265 => Ok(PacketType::RemoveMuteListEntry(Box::new(
+                    RemoveMuteListEntry::from_bytes(bytes)?,
+                ))),
+                266 => Ok(PacketType::UpdateInventoryItem(Box::new(
+                    UpdateInventoryItem::from_bytes(bytes)?,
+                ))),
+                267 => Ok(PacketType::UpdateCreateInventoryItem(Box::new(
+                    UpdateCreateInventoryItem::from_bytes(bytes)?,
+                ))),
+                268 => Ok(PacketType::MoveInventoryItem(Box::new(
+                    MoveInventoryItem::from_bytes(bytes)?,
+                ))),
+                269 => Ok(PacketType::CreateInventoryItem(Box::new(
+                    CreateInventoryItem::from_bytes(bytes)?,
+                ))),
+                270 => Ok(PacketType::RemoveInventoryItem(Box::new(
+                    RemoveInventoryItem::from_bytes(bytes)?,
+                ))),
+                273 => Ok(PacketType::CreateInventoryFolder(Box::new(
+                    CreateInventoryFolder::from_bytes(bytes)?,
+                ))),
+                275 => Ok(PacketType::MoveInventoryFolder(Box::new(
+                    MoveInventoryFolder::from_bytes(bytes)?,
+                ))),
+                276 => Ok(PacketType::RemoveInventoryFolder(Box::new(
+                    RemoveInventoryFolder::from_bytes(bytes)?,
+                ))),
+                285 => Ok(PacketType::PurgeInventoryDescendents(Box::new(
+                    PurgeInventoryDescendents::from_bytes(bytes)?,
+                ))),
+                297 => Ok(PacketType::AcceptFriendship(Box::new(
+                    AcceptFriendship::from_bytes(bytes)?,
+                ))),
+                298 => Ok(PacketType::DeclineFriendship(Box::new(
+                    DeclineFriendship::from_bytes(bytes)?,
+                ))),
+                300 => Ok(PacketType::TerminateFriendship(Box::new(
+                    TerminateFriendship::from_bytes(bytes)?,
+                ))),
+                320 => Ok(PacketType::GrantUserRights(Box::new(
+                    GrantUserRights::from_bytes(bytes)?,
+                ))),
+                321 => Ok(PacketType::ChangeUserRights(Box::new(
+                    ChangeUserRights::from_bytes(bytes)?,
+                ))),
+                343 => Ok(PacketType::JoinGroupRequest(Box::new(
+                    JoinGroupRequest::from_bytes(bytes)?,
+                ))),
+                344 => Ok(PacketType::JoinGroupReply(Box::new(
+                    JoinGroupReply::from_bytes(bytes)?,
+                ))),
+                347 => Ok(PacketType::LeaveGroupRequest(Box::new(
+                    LeaveGroupRequest::from_bytes(bytes)?,
+                ))),
+                348 => Ok(PacketType::LeaveGroupReply(Box::new(
+                    LeaveGroupReply::from_bytes(bytes)?,
+                ))),
+                260 => Ok(PacketType::ActivateGroup(Box::new(
+                    ActivateGroup::from_bytes(bytes)?,
+                ))),
+                387 => Ok(PacketType::AgentDataUpdate(Box::new(
+                    AgentDataUpdate::from_bytes(bytes)?,
+                ))),
+                169 => Ok(PacketType::AvatarPropertiesRequest(Box::new(
+                    AvatarPropertiesRequest::from_bytes(bytes)?,
+                ))),
+                173 => Ok(PacketType::AvatarGroupsReply(Box::new(
+                    AvatarGroupsReply::from_bytes(bytes)?,
+                ))),
+                351 => Ok(PacketType::GroupProfileRequest(Box::new(
+                    GroupProfileRequest::from_bytes(bytes)?,
+                ))),
+                352 => Ok(PacketType::GroupProfileReply(Box::new(
+                    GroupProfileReply::from_bytes(bytes)?,
+                ))),
+                171 => Ok(PacketType::AvatarPropertiesReply(Box::new(
+                    AvatarPropertiesReply::from_bytes(bytes)?,
+                ))),
+                172 => Ok(PacketType::AvatarInterestsReply(Box::new(
+                    AvatarInterestsReply::from_bytes(bytes)?,
+                ))),
+                174 => Ok(PacketType::AvatarPropertiesUpdate(Box::new(
+                    AvatarPropertiesUpdate::from_bytes(bytes)?,
+                ))),
+                175 => Ok(PacketType::AvatarInterestsUpdate(Box::new(
+                    AvatarInterestsUpdate::from_bytes(bytes)?,
+                ))),
+                26 => Ok(PacketType::AvatarPickerRequest(Box::new(
+                    AvatarPickerRequest::from_bytes(bytes)?,
+                ))),
+                28 => Ok(PacketType::AvatarPickerReply(Box::new(
+                    AvatarPickerReply::from_bytes(bytes)?,
+                ))),
+                31 => Ok(PacketType::DirFindQuery(Box::new(
+                    DirFindQuery::from_bytes(bytes)?,
+                ))),
+                35 => Ok(PacketType::DirPlacesReply(Box::new(
+                    DirPlacesReply::from_bytes(bytes)?,
+                ))),
+                36 => Ok(PacketType::DirPeopleReply(Box::new(
+                    DirPeopleReply::from_bytes(bytes)?,
+                ))),
+                261 => Ok(PacketType::EstateOwnerMessage(Box::new(
+                    EstateOwnerMessage::from_bytes(bytes)?,
+                ))),
+                165 => Ok(PacketType::GodKickUser(Box::new(GodKickUser::from_bytes(
+                    bytes,
+                )?))),
+                258 => Ok(PacketType::RequestGodlikePowers(Box::new(
+                    RequestGodlikePowers::from_bytes(bytes)?,
+                ))),
+                259 => Ok(PacketType::GrantGodlikePowers(Box::new(
+                    GrantGodlikePowers::from_bytes(bytes)?,
+                ))),
+                262 => Ok(PacketType::GenericMessage(Box::new(
+                    GenericMessageData::from_bytes(bytes)?,
+                ))),
+                24 => Ok(PacketType::EconomyDataRequest(Box::new(
+                    EconomyDataRequest::from_bytes(bytes)?,
+                ))),
+                25 => Ok(PacketType::EconomyData(Box::new(EconomyData::from_bytes(
+                    bytes,
+                )?))),
+                141 => Ok(PacketType::RegionInfoRequest(Box::new(
+                    RegionInfoRequest::from_bytes(bytes)?,
+                ))),
+                142 => Ok(PacketType::RegionInfo(Box::new(RegionInfo::from_bytes(
+                    bytes,
+                )?))),
+                215 => Ok(PacketType::ParcelAccessListRequest(Box::new(
+                    ParcelAccessListRequest::from_bytes(bytes)?,
+                ))),
+                216 => Ok(PacketType::ParcelAccessListReply(Box::new(
+                    ParcelAccessListReply::from_bytes(bytes)?,
+                ))),
+                217 => Ok(PacketType::ParcelAccessListUpdate(Box::new(
+                    ParcelAccessListUpdate::from_bytes(bytes)?,
+                ))),
+                219 => Ok(PacketType::ParcelDwellRequest(Box::new(
+                    ParcelDwellRequest::from_bytes(bytes)?,
+                ))),
+                220 => Ok(PacketType::ParcelDwellReply(Box::new(
+                    ParcelDwellReply::from_bytes(bytes)?,
+                ))),
+                421 => Ok(PacketType::LandStatRequest(Box::new(
+                    LandStatRequest::from_bytes(bytes)?,
+                ))),
+                422 => Ok(PacketType::LandStatReply(Box::new(
+                    LandStatReply::from_bytes(bytes)?,
+                ))),
+                90 => Ok(PacketType::ObjectDuplicate(Box::new(
+                    ObjectDuplicate::from_bytes(bytes)?,
+                ))),
+                91 => Ok(PacketType::ObjectDuplicateOnRay(Box::new(
+                    ObjectDuplicateOnRay::from_bytes(bytes)?,
+                ))),
+                123 => Ok(PacketType::ObjectLink(Box::new(ObjectLink::from_bytes(
+                    bytes,
+                )?))),
+                124 => Ok(PacketType::ObjectDelink(Box::new(
+                    ObjectDelink::from_bytes(bytes)?,
+                ))),
+                112 => Ok(PacketType::ObjectAttach(Box::new(
+                    ObjectAttach::from_bytes(bytes)?,
+                ))),
+                113 => Ok(PacketType::ObjectDetach(Box::new(
+                    ObjectDetach::from_bytes(bytes)?,
+                ))),
+                114 => Ok(PacketType::ObjectDrop(Box::new(ObjectDrop::from_bytes(
+                    bytes,
+                )?))),
+                395 => Ok(PacketType::RezSingleAttachmentFromInv(Box::new(
+                    RezSingleAttachmentFromInv::from_bytes(bytes)?,
+                ))),
+                243 => Ok(PacketType::GetScriptRunning(Box::new(
+                    GetScriptRunning::from_bytes(bytes)?,
+                ))),
+                244 => Ok(PacketType::ScriptRunningReply(Box::new(
+                    ScriptRunningReply::from_bytes(bytes)?,
+                ))),
+                245 => Ok(PacketType::SetScriptRunning(Box::new(
+                    SetScriptRunning::from_bytes(bytes)?,
+                ))),
+                246 => Ok(PacketType::ScriptReset(Box::new(ScriptReset::from_bytes(
+                    bytes,
+                )?))),
+                304 => Ok(PacketType::RezScript(Box::new(RezScript::from_bytes(
+                    bytes,
+                )?))),
+                96 => Ok(PacketType::ObjectImage(Box::new(ObjectImage::from_bytes(
+                    bytes,
+                )?))),
+                105 => Ok(PacketType::ObjectPermissions(Box::new(
+                    ObjectPermissions::from_bytes(bytes)?,
+                ))),
+                106 => Ok(PacketType::ObjectSaleInfo(Box::new(
+                    ObjectSaleInfo::from_bytes(bytes)?,
+                ))),
+                107 => Ok(PacketType::ObjectName(Box::new(ObjectName::from_bytes(
+                    bytes,
+                )?))),
+                108 => Ok(PacketType::ObjectDescription(Box::new(
+                    ObjectDescription::from_bytes(bytes)?,
+                ))),
+                109 => Ok(PacketType::ObjectCategory(Box::new(
+                    ObjectCategory::from_bytes(bytes)?,
+                ))),
+                99 => Ok(PacketType::ObjectExtraParams(Box::new(
+                    ObjectExtraParams::from_bytes(bytes)?,
+                ))),
+                97 => Ok(PacketType::ObjectMaterial(Box::new(
+                    ObjectMaterial::from_bytes(bytes)?,
+                ))),
+                94 => Ok(PacketType::ObjectFlagUpdate(Box::new(
+                    ObjectFlagUpdate::from_bytes(bytes)?,
+                ))),
+                95 => Ok(PacketType::ObjectClickAction(Box::new(
+                    ObjectClickAction::from_bytes(bytes)?,
+                ))),
+                10 => Ok(PacketType::TelehubInfo(Box::new(TelehubInfo::from_bytes(
+                    bytes,
+                )?))),
+                78 => Ok(PacketType::AgentPause(Box::new(AgentPause::from_bytes(
+                    bytes,
+                )?))),
+                79 => Ok(PacketType::AgentResume(Box::new(AgentResume::from_bytes(
+                    bytes,
+                )?))),
+                159 => Ok(PacketType::SetFollowCamProperties(Box::new(
+                    SetFollowCamProperties::from_bytes(bytes)?,
+                ))),
+                160 => Ok(PacketType::ClearFollowCamProperties(Box::new(
+                    ClearFollowCamProperties::from_bytes(bytes)?,
+                ))),
+                189 => Ok(PacketType::ScriptControlChange(Box::new(
+                    ScriptControlChange::from_bytes(bytes)?,
+                ))),
+                192 => Ok(PacketType::ForceScriptControlRelease(Box::new(
+                    ForceScriptControlRelease::from_bytes(bytes)?,
+                ))),
+                195 => Ok(PacketType::ScriptTeleportRequest(Box::new(
+                    ScriptTeleportRequest::from_bytes(bytes)?,
+                ))),
+                138 => Ok(PacketType::HealthMessage(Box::new(
+                    HealthMessage::from_bytes(bytes)?,
+                ))),
+                140 => Ok(PacketType::SimStats(Box::new(SimStats::from_bytes(bytes)?))),
+                150 => Ok(PacketType::SimulatorViewerTimeMessage(Box::new(
+                    SimulatorViewerTimeMessage::from_bytes(bytes)?,
+                ))),
+                386 => Ok(PacketType::AgentDataUpdateRequest(Box::new(
+                    AgentDataUpdateRequest::from_bytes(bytes)?,
+                ))),
+                42 => Ok(PacketType::AvatarClassifiedReply(Box::new(
+                    AvatarClassifiedReply::from_bytes(bytes)?,
+                ))),
+                43 => Ok(PacketType::ClassifiedInfoRequest(Box::new(
+                    ClassifiedInfoRequest::from_bytes(bytes)?,
+                ))),
+                44 => Ok(PacketType::ClassifiedInfoReply(Box::new(
+                    ClassifiedInfoReply::from_bytes(bytes)?,
+                ))),
+                178 => Ok(PacketType::AvatarPicksReply(Box::new(
+                    AvatarPicksReply::from_bytes(bytes)?,
+                ))),
+                179 => Ok(PacketType::EventInfoRequest(Box::new(
+                    EventInfoRequest::from_bytes(bytes)?,
+                ))),
+                180 => Ok(PacketType::EventInfoReply(Box::new(
+                    EventInfoReply::from_bytes(bytes)?,
+                ))),
+                184 => Ok(PacketType::PickInfoReply(Box::new(
+                    PickInfoReply::from_bytes(bytes)?,
+                ))),
+                322 => Ok(PacketType::OnlineNotification(Box::new(
+                    OnlineNotification::from_bytes(bytes)?,
+                ))),
+                323 => Ok(PacketType::OfflineNotification(Box::new(
+                    OfflineNotification::from_bytes(bytes)?,
+                ))),
+                277 => Ok(PacketType::FetchInventoryDescendents(Box::new(
+                    FetchInventoryDescendents::from_bytes(bytes)?,
+                ))),
+                278 => Ok(PacketType::InventoryDescendents(Box::new(
+                    InventoryDescendents::from_bytes(bytes)?,
+                ))),
+                279 => Ok(PacketType::FetchInventory(Box::new(
+                    FetchInventory::from_bytes(bytes)?,
+                ))),
+                280 => Ok(PacketType::FetchInventoryReply(Box::new(
+                    FetchInventoryReply::from_bytes(bytes)?,
+                ))),
+                84 => Ok(PacketType::AgentSetAppearance(Box::new(
+                    AgentSetAppearance::from_bytes(bytes)?,
+                ))),
+                151 => Ok(PacketType::EnableSimulator(Box::new(
+                    EnableSimulator::from_bytes(bytes)?,
+                ))),
+                153 => Ok(PacketType::TransferRequest(Box::new(
+                    TransferRequest::from_bytes(bytes)?,
+                ))),
+                154 => Ok(PacketType::TransferInfo(Box::new(
+                    TransferInfo::from_bytes(bytes)?,
+                ))),
+                156 => Ok(PacketType::RequestXfer(Box::new(RequestXfer::from_bytes(
+                    bytes,
+                )?))),
+                157 => Ok(PacketType::AbortXfer(Box::new(AbortXfer::from_bytes(
+                    bytes,
+                )?))),
+                158 => Ok(PacketType::AvatarAppearance(Box::new(
+                    AvatarAppearance::from_bytes(bytes)?,
+                ))),
+                235 => Ok(PacketType::UUIDNameRequest(Box::new(
+                    UUIDNameRequest::from_bytes(bytes)?,
+                ))),
+                236 => Ok(PacketType::UUIDNameReply(Box::new(
+                    UUIDNameReply::from_bytes(bytes)?,
+                ))),
+                237 => Ok(PacketType::UUIDGroupNameRequest(Box::new(
+                    UUIDGroupNameRequest::from_bytes(bytes)?,
+                ))),
+                238 => Ok(PacketType::UUIDGroupNameReply(Box::new(
+                    UUIDGroupNameReply::from_bytes(bytes)?,
+                ))),
+                313 => Ok(PacketType::MoneyBalanceRequest(Box::new(
+                    MoneyBalanceRequest::from_bytes(bytes)?,
+                ))),
+                314 => Ok(PacketType::MoneyBalanceReply(Box::new(
+                    MoneyBalanceReply::from_bytes(bytes)?,
+                ))),
+                333 => Ok(PacketType::AssetUploadRequest(Box::new(
+                    AssetUploadRequest::from_bytes(bytes)?,
+                ))),
+                334 => Ok(PacketType::AssetUploadComplete(Box::new(
+                    AssetUploadComplete::from_bytes(bytes)?,
+                ))),
+                381 => Ok(PacketType::AgentWearablesRequest(Box::new(
+                    AgentWearablesRequest::from_bytes(bytes)?,
+                ))),
+                382 => Ok(PacketType::AgentWearablesUpdate(Box::new(
+                    AgentWearablesUpdate::from_bytes(bytes)?,
+                ))),
+                419 => Ok(PacketType::ParcelMediaCommandMessage(Box::new(
+                    ParcelMediaCommandMessage::from_bytes(bytes)?,
+                ))),
+                420 => Ok(PacketType::ParcelMediaUpdate(Box::new(
+                    ParcelMediaUpdate::from_bytes(bytes)?,
+                ))),
+                239 => Ok(PacketType::ChatPass(Box::new(ChatPass::from_bytes(bytes)?))),
+                125 => Ok(PacketType::VelocityInterpolateOn(Box::new(
+                    VelocityInterpolateOn::from_bytes(bytes)?,
+                ))),
+                126 => Ok(PacketType::VelocityInterpolateOff(Box::new(
+                    VelocityInterpolateOff::from_bytes(bytes)?,
+                ))),
+                19 => Ok(PacketType::FeatureDisabled(Box::new(
+                    FeatureDisabled::from_bytes(bytes)?,
+                ))),
+                id => Ok(PacketType::Unknown(Box::new(RawPacketData {
+                    frequency,
+                    id,
+                    body: bytes.to_vec(),
+                }))),
             },
             PacketFrequency::Fixed => match id {
                 251 => Ok(PacketType::PacketAck(Box::new(PacketAck::from_bytes(
                     bytes,
                 )?))),
                 66 => Ok(PacketType::Login(Box::new(Login::from_bytes(bytes)?))),
-
-                id => Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Unknown packet ID: {}, frequency: {}", id, frequency),
-                )),
+                67 => Ok(PacketType::PurgeInventoryFolder(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                68 => Ok(PacketType::AttachFromInventory(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                69 => Ok(PacketType::DetachAttachment(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                70 => Ok(PacketType::SetExtraParams(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                71 => Ok(PacketType::QueryObjectFamily(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                72 => Ok(PacketType::AcceptScriptTeleport(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                73 => Ok(PacketType::QuerySelfAgentInfo(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                74 => Ok(PacketType::PickInfoRequest(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                75 => Ok(PacketType::SetChatFilter(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                76 => Ok(PacketType::SetVelocityInterpolation(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                77 => Ok(PacketType::ReportUnknownPackets(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                78 => Ok(PacketType::QueryCircuitStats(Box::new(
+                    serde_json::from_slice(bytes)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                ))),
+                id => Ok(PacketType::Unknown(Box::new(RawPacketData {
+                    frequency,
+                    id,
+                    body: bytes.to_vec(),
+                }))),
             },
         }
     }
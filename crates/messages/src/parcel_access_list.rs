@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::parcel_access::{ParcelAccessEntry, ParcelAccessFlags};
+
+/// The whole access list or ban list of a parcel, gathered from all of the
+/// ParcelAccessListReply packets that carried it and sent from the session to the UI.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParcelAccessList {
+    /// the parcel's local id in the region
+    pub local_id: i32,
+    /// the list the entries are on
+    pub flags: ParcelAccessFlags,
+    /// the avatars on the list, empty if there are none
+    pub entries: Vec<ParcelAccessEntry>,
+}
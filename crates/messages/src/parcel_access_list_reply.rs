@@ -0,0 +1,85 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::parcel_access::{ParcelAccessEntry, ParcelAccessFlags};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 216
+// Frequency: Low
+
+impl Packet {
+    pub fn new_parcel_access_list_reply(parcel_access_list_reply: ParcelAccessListReply) -> Self {
+        Packet {
+            header: Header {
+                id: 216,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelAccessListReply(Box::new(parcel_access_list_reply)),
+        }
+    }
+}
+
+/// One part of the access list or the ban list of a parcel, sent in answer to a
+/// ParcelAccessListRequest. The parts of a list all carry the request's sequence id, and
+/// nothing says which part is the last.
+/// https://wiki.secondlife.com/wiki/ParcelAccessListReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParcelAccessListReply {
+    pub agent_id: Uuid,
+    /// the sequence id of the request
+    pub sequence_id: i32,
+    /// the list the entries are on
+    pub flags: ParcelAccessFlags,
+    /// the parcel's local id in the region
+    pub local_id: i32,
+    pub entries: Vec<ParcelAccessEntry>,
+}
+
+impl PacketData for ParcelAccessListReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let sequence_id = cursor.read_i32::<LittleEndian>()?;
+        let flags = ParcelAccessFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?);
+        let local_id = cursor.read_i32::<LittleEndian>()?;
+        let count = cursor.read_u8()?;
+        let mut entries = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            entries.push(ParcelAccessEntry::from_bytes(&mut cursor)?);
+        }
+        Ok(ParcelAccessListReply {
+            agent_id,
+            sequence_id,
+            flags,
+            local_id,
+            entries,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let entries = &self.entries[..self.entries.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(29 + entries.len() * ParcelAccessEntry::SIZE);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(&self.sequence_id.to_le_bytes());
+        bytes.extend_from_slice(&self.flags.bits().to_le_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes.push(entries.len() as u8);
+        for entry in entries {
+            bytes.extend(entry.to_bytes());
+        }
+        bytes
+    }
+}
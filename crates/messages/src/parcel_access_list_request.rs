@@ -0,0 +1,88 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::parcel_access::ParcelAccessFlags;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 215
+// Frequency: Low
+
+impl Packet {
+    pub fn new_parcel_access_list_request(
+        parcel_access_list_request: ParcelAccessListRequest,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 215,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelAccessListRequest(Box::new(parcel_access_list_request)),
+        }
+    }
+}
+
+/// Asks for the access list, the ban list, or both of a parcel. The simulator answers with a
+/// ParcelAccessListReply for each list, carrying the same sequence id. A long list can be split
+/// over several replies.
+/// https://wiki.secondlife.com/wiki/ParcelAccessListRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParcelAccessListRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// echoed in the ParcelAccessListReply, to match it to this request
+    pub sequence_id: i32,
+    /// the lists to send
+    pub flags: ParcelAccessFlags,
+    /// the parcel's local id in the region
+    pub local_id: i32,
+}
+
+impl ParcelAccessListRequest {
+    /// ask for the lists of a parcel by its local id. The agent and session ids and the
+    /// sequence id are left for the session to fill in.
+    pub fn new(local_id: i32, flags: ParcelAccessFlags) -> Self {
+        ParcelAccessListRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            sequence_id: 0,
+            flags,
+            local_id,
+        }
+    }
+}
+
+impl PacketData for ParcelAccessListRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ParcelAccessListRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            sequence_id: cursor.read_i32::<LittleEndian>()?,
+            flags: ParcelAccessFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?),
+            local_id: cursor.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(44);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.sequence_id.to_le_bytes());
+        bytes.extend_from_slice(&self.flags.bits().to_le_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,152 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::parcel_access::{ParcelAccessEntry, ParcelAccessFlags};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 217
+// Frequency: Low
+
+impl Packet {
+    pub fn new_parcel_access_list_update(
+        parcel_access_list_update: ParcelAccessListUpdate,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 217,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelAccessListUpdate(Box::new(parcel_access_list_update)),
+        }
+    }
+}
+
+/// Replaces the access list or the ban list of a parcel. A long list is split into sections,
+/// sent as one update each. The sections share a transaction id, are numbered from 1 by their
+/// sequence id, and each says how many sections there are.
+/// https://wiki.secondlife.com/wiki/ParcelAccessListUpdate
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParcelAccessListUpdate {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the list to replace
+    pub flags: ParcelAccessFlags,
+    /// the parcel's local id in the region
+    pub local_id: i32,
+    pub transaction_id: Uuid,
+    /// the number of this section, from 1
+    pub sequence_id: i32,
+    /// how many sections the list was split into
+    pub sections: i32,
+    pub entries: Vec<ParcelAccessEntry>,
+}
+
+impl ParcelAccessListUpdate {
+    /// the most entries the viewer sends in one section
+    pub const ENTRIES_PER_SECTION: usize = 48;
+
+    /// an update replacing a list of a parcel, as a single section. The agent and session ids
+    /// are left nil, for the session to fill in.
+    pub fn new(local_id: i32, flags: ParcelAccessFlags, entries: Vec<ParcelAccessEntry>) -> Self {
+        ParcelAccessListUpdate {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            flags,
+            local_id,
+            transaction_id: Uuid::nil(),
+            sequence_id: 1,
+            sections: 1,
+            entries,
+        }
+    }
+
+    /// split the update into the sections to send, under a new transaction id. An empty list
+    /// is sent as one section with a single nil entry, which is how the viewer clears a list.
+    pub fn split(&self) -> Vec<ParcelAccessListUpdate> {
+        let empty = [ParcelAccessEntry {
+            id: Uuid::nil(),
+            time: 0,
+            flags: ParcelAccessFlags::empty(),
+        }];
+        let entries = if self.entries.is_empty() {
+            &empty[..]
+        } else {
+            &self.entries[..]
+        };
+        let chunks: Vec<&[ParcelAccessEntry]> = entries.chunks(Self::ENTRIES_PER_SECTION).collect();
+        let transaction_id = Uuid::new_v4();
+        let sections = chunks.len() as i32;
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, chunk)| ParcelAccessListUpdate {
+                agent_id: self.agent_id,
+                session_id: self.session_id,
+                flags: self.flags,
+                local_id: self.local_id,
+                transaction_id,
+                sequence_id: index as i32 + 1,
+                sections,
+                entries: chunk.to_vec(),
+            })
+            .collect()
+    }
+}
+
+impl PacketData for ParcelAccessListUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let flags = ParcelAccessFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?);
+        let local_id = cursor.read_i32::<LittleEndian>()?;
+        let transaction_id = read_uuid(&mut cursor)?;
+        let sequence_id = cursor.read_i32::<LittleEndian>()?;
+        let sections = cursor.read_i32::<LittleEndian>()?;
+        let count = cursor.read_u8()?;
+        let mut entries = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            entries.push(ParcelAccessEntry::from_bytes(&mut cursor)?);
+        }
+        Ok(ParcelAccessListUpdate {
+            agent_id,
+            session_id,
+            flags,
+            local_id,
+            transaction_id,
+            sequence_id,
+            sections,
+            entries,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let entries = &self.entries[..self.entries.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(65 + entries.len() * ParcelAccessEntry::SIZE);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.flags.bits().to_le_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.extend_from_slice(&self.sequence_id.to_le_bytes());
+        bytes.extend_from_slice(&self.sections.to_le_bytes());
+        bytes.push(entries.len() as u8);
+        for entry in entries {
+            bytes.extend(entry.to_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,67 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 220
+// Frequency: Low
+
+impl Packet {
+    pub fn new_parcel_dwell_reply(parcel_dwell_reply: ParcelDwellReply) -> Self {
+        Packet {
+            header: Header {
+                id: 220,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelDwellReply(Box::new(parcel_dwell_reply)),
+        }
+    }
+}
+
+/// The dwell of a parcel, sent in answer to a ParcelDwellRequest.
+/// https://wiki.secondlife.com/wiki/ParcelDwellReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParcelDwellReply {
+    pub agent_id: Uuid,
+    /// the parcel's local id in the region
+    pub local_id: i32,
+    /// the parcel's global id
+    pub parcel_id: Uuid,
+    /// how much traffic the parcel gets
+    pub dwell: f32,
+}
+
+impl PacketData for ParcelDwellReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ParcelDwellReply {
+            agent_id: read_uuid(&mut cursor)?,
+            local_id: cursor.read_i32::<LittleEndian>()?,
+            parcel_id: read_uuid(&mut cursor)?,
+            dwell: cursor.read_f32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(40);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes.extend_from_slice(self.parcel_id.as_bytes());
+        bytes.extend_from_slice(&self.dwell.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,79 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 219
+// Frequency: Low
+
+impl Packet {
+    pub fn new_parcel_dwell_request(parcel_dwell_request: ParcelDwellRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 219,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelDwellRequest(Box::new(parcel_dwell_request)),
+        }
+    }
+}
+
+/// Asks for the dwell of a parcel, a measure of how much traffic it gets. The simulator answers
+/// with a ParcelDwellReply.
+/// https://wiki.secondlife.com/wiki/ParcelDwellRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParcelDwellRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the parcel's local id in the region
+    pub local_id: i32,
+    /// the parcel's global id, nil if it isn't known
+    pub parcel_id: Uuid,
+}
+
+impl ParcelDwellRequest {
+    /// ask for the dwell of a parcel by its local id. The agent and session ids are left nil,
+    /// for the session to fill in.
+    pub fn new(local_id: i32) -> Self {
+        ParcelDwellRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            local_id,
+            parcel_id: Uuid::nil(),
+        }
+    }
+}
+
+impl PacketData for ParcelDwellRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ParcelDwellRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            local_id: cursor.read_i32::<LittleEndian>()?,
+            parcel_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(52);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes.extend_from_slice(self.parcel_id.as_bytes());
+        bytes
+    }
+}
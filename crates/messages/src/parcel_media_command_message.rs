@@ -0,0 +1,153 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 419
+// Frequency: Low
+
+impl Packet {
+    pub fn new_parcel_media_command_message(
+        parcel_media_command_message: ParcelMediaCommandMessage,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 419,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelMediaCommandMessage(Box::new(parcel_media_command_message)),
+        }
+    }
+}
+
+/// Controls the media playing on the parcel the agent is in, like pausing a video or seeking to
+/// a time in it. Sent by scripts through llParcelMediaCommandList.
+/// https://wiki.secondlife.com/wiki/ParcelMediaCommandMessage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParcelMediaCommandMessage {
+    /// which commands the script gave, with a bit for each of them by its number
+    pub flags: u32,
+    pub command: ParcelMediaCommand,
+    /// the time to seek to, in seconds. Only set if the flags have the time command.
+    pub time: f32,
+}
+
+/// the commands for parcel media
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ParcelMediaCommand {
+    Stop,
+    Pause,
+    Play,
+    /// play, and start again when the media ends
+    Loop,
+    /// change the texture the media plays on
+    Texture,
+    /// change the url of the media
+    Url,
+    /// seek to a time
+    Time,
+    /// the command is only for one agent
+    Agent,
+    /// stop the media and unload it
+    Unload,
+    /// scale the media to fit the faces it plays on
+    AutoAlign,
+    /// change the mime type of the media
+    Type,
+    /// change the size of the media
+    Size,
+    /// change the description of the media
+    Desc,
+    /// set whether the media loops, without playing it
+    LoopSet,
+    Unknown(u32),
+}
+impl ParcelMediaCommand {
+    pub fn from_bytes(value: u32) -> Self {
+        match value {
+            0 => ParcelMediaCommand::Stop,
+            1 => ParcelMediaCommand::Pause,
+            2 => ParcelMediaCommand::Play,
+            3 => ParcelMediaCommand::Loop,
+            4 => ParcelMediaCommand::Texture,
+            5 => ParcelMediaCommand::Url,
+            6 => ParcelMediaCommand::Time,
+            7 => ParcelMediaCommand::Agent,
+            8 => ParcelMediaCommand::Unload,
+            9 => ParcelMediaCommand::AutoAlign,
+            10 => ParcelMediaCommand::Type,
+            11 => ParcelMediaCommand::Size,
+            12 => ParcelMediaCommand::Desc,
+            13 => ParcelMediaCommand::LoopSet,
+            other => ParcelMediaCommand::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u32 {
+        match self {
+            ParcelMediaCommand::Stop => 0,
+            ParcelMediaCommand::Pause => 1,
+            ParcelMediaCommand::Play => 2,
+            ParcelMediaCommand::Loop => 3,
+            ParcelMediaCommand::Texture => 4,
+            ParcelMediaCommand::Url => 5,
+            ParcelMediaCommand::Time => 6,
+            ParcelMediaCommand::Agent => 7,
+            ParcelMediaCommand::Unload => 8,
+            ParcelMediaCommand::AutoAlign => 9,
+            ParcelMediaCommand::Type => 10,
+            ParcelMediaCommand::Size => 11,
+            ParcelMediaCommand::Desc => 12,
+            ParcelMediaCommand::LoopSet => 13,
+            ParcelMediaCommand::Unknown(other) => *other,
+        }
+    }
+}
+
+impl ParcelMediaCommandMessage {
+    /// true if the flags have the command
+    pub fn has_command(&self, command: ParcelMediaCommand) -> bool {
+        let bit = command.to_bytes();
+        bit < 32 && self.flags & (1 << bit) != 0
+    }
+
+    /// the time to seek to, if the flags have the time command
+    pub fn seek_time(&self) -> Option<f32> {
+        if self.has_command(ParcelMediaCommand::Time) {
+            Some(self.time)
+        } else {
+            None
+        }
+    }
+}
+
+impl PacketData for ParcelMediaCommandMessage {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ParcelMediaCommandMessage {
+            flags: cursor.read_u32::<LittleEndian>()?,
+            command: ParcelMediaCommand::from_bytes(cursor.read_u32::<LittleEndian>()?),
+            time: cursor.read_f32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12);
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.extend_from_slice(&self.command.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.time.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,100 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 420
+// Frequency: Low
+
+impl Packet {
+    pub fn new_parcel_media_update(parcel_media_update: ParcelMediaUpdate) -> Self {
+        Packet {
+            header: Header {
+                id: 420,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelMediaUpdate(Box::new(parcel_media_update)),
+        }
+    }
+}
+
+/// The media of the parcel the agent is in, sent when a script changes it. The media plays on
+/// every face with the media texture.
+/// https://wiki.secondlife.com/wiki/ParcelMediaUpdate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParcelMediaUpdate {
+    pub media_url: String,
+    /// the texture the media plays on
+    pub media_id: Uuid,
+    /// whether the media is scaled to fit the faces it plays on
+    pub media_auto_scale: bool,
+    /// the rest of the media's details. Older simulators leave it off.
+    pub extended: Option<ParcelMediaExtended>,
+}
+
+/// the details of parcel media that older simulators don't send
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParcelMediaExtended {
+    /// the mime type of the media, like video/mp4
+    pub media_type: String,
+    pub media_desc: String,
+    pub media_width: i32,
+    pub media_height: i32,
+    /// whether the media starts again when it ends
+    pub media_loop: bool,
+}
+
+impl PacketData for ParcelMediaUpdate {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let media_url = read_string_1(&mut cursor)?;
+        let media_id = read_uuid(&mut cursor)?;
+        let media_auto_scale = cursor.read_u8()? != 0;
+        let extended = if (cursor.position() as usize) < bytes.len() {
+            Some(ParcelMediaExtended {
+                media_type: read_string_1(&mut cursor)?,
+                media_desc: read_string_1(&mut cursor)?,
+                media_width: cursor.read_i32::<LittleEndian>()?,
+                media_height: cursor.read_i32::<LittleEndian>()?,
+                media_loop: cursor.read_u8()? != 0,
+            })
+        } else {
+            None
+        };
+        Ok(ParcelMediaUpdate {
+            media_url,
+            media_id,
+            media_auto_scale,
+            extended,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(50 + self.media_url.len());
+        write_string_1(&mut bytes, &self.media_url);
+        bytes.extend_from_slice(self.media_id.as_bytes());
+        bytes.push(self.media_auto_scale as u8);
+        if let Some(extended) = &self.extended {
+            write_string_1(&mut bytes, &extended.media_type);
+            write_string_1(&mut bytes, &extended.media_desc);
+            bytes.extend_from_slice(&extended.media_width.to_le_bytes());
+            bytes.extend_from_slice(&extended.media_height.to_le_bytes());
+            bytes.push(extended.media_loop as u8);
+        }
+        bytes
+    }
+}
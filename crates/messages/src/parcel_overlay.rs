@@ -0,0 +1,111 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_variable_2, write_variable_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use bitflags::bitflags;
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+
+// ID: 196
+// Frequency: Low
+
+/// the overlay of a region is sent in this many chunks
+pub const PARCEL_OVERLAY_CHUNKS: usize = 4;
+
+impl Packet {
+    pub fn new_parcel_overlay(parcel_overlay: ParcelOverlay) -> Self {
+        Packet {
+            header: Header {
+                id: 196,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelOverlay(Box::new(parcel_overlay)),
+        }
+    }
+}
+
+/// One chunk of the parcel overlay of a region.
+/// The overlay has a byte for every 4x4 meter cell of the region, row by row from the south
+/// west corner, saying who owns the cell and where the parcel borders are. It is too big for one
+/// packet, so the simulator splits it into PARCEL_OVERLAY_CHUNKS chunks, numbered from 0 by
+/// their sequence id.
+/// https://wiki.secondlife.com/wiki/ParcelOverlay
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParcelOverlay {
+    /// which chunk of the overlay this is
+    pub sequence_id: i32,
+    /// the cells of the chunk, one ParcelOverlayFlags byte each
+    pub data: Vec<u8>,
+}
+
+bitflags! {
+    /// The meaning of one byte of the parcel overlay.
+    /// The low bits are not flags, but say who owns the cell. Use ownership() to read them.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ParcelOverlayFlags: u8 {
+        /// the bits that say who owns the cell
+        const OWNERSHIP = 0x07;
+        /// sounds on the cell's parcel are only heard on the parcel
+        const SOUND_LOCAL = 0x20;
+        /// the cell is on the west border of its parcel
+        const BORDER_WEST = 0x40;
+        /// the cell is on the south border of its parcel
+        const BORDER_SOUTH = 0x80;
+    }
+}
+
+/// who owns a cell of the parcel overlay
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParcelOwnership {
+    Public,
+    /// owned by another avatar
+    Owned,
+    /// owned by a group
+    Group,
+    /// owned by the agent
+    OwnedBySelf,
+    ForSale,
+    Auction,
+    Unknown(u8),
+}
+
+impl ParcelOverlayFlags {
+    /// who owns the cell
+    pub fn ownership(&self) -> ParcelOwnership {
+        match self.bits() & Self::OWNERSHIP.bits() {
+            0 => ParcelOwnership::Public,
+            1 => ParcelOwnership::Owned,
+            2 => ParcelOwnership::Group,
+            3 => ParcelOwnership::OwnedBySelf,
+            4 => ParcelOwnership::ForSale,
+            5 => ParcelOwnership::Auction,
+            other => ParcelOwnership::Unknown(other),
+        }
+    }
+}
+
+impl PacketData for ParcelOverlay {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let sequence_id = cursor.read_i32::<LittleEndian>()?;
+        let data = read_variable_2(&mut cursor)?;
+        Ok(ParcelOverlay { sequence_id, data })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(6 + self.data.len());
+        bytes.extend_from_slice(&self.sequence_id.to_le_bytes());
+        write_variable_2(&mut bytes, &self.data);
+        bytes
+    }
+}
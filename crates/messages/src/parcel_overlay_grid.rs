@@ -0,0 +1,60 @@
+use crate::packet::PacketData;
+use crate::parcel_overlay::ParcelOverlayFlags;
+use crate::parcel_properties::PARCEL_BITMAP_WIDTH;
+use crate::parcel_properties_request::PARCEL_GRID_SIZE;
+
+use std::io;
+
+/// the number of cells in the parcel overlay of a region
+pub const PARCEL_OVERLAY_SIZE: usize = PARCEL_BITMAP_WIDTH * PARCEL_BITMAP_WIDTH;
+
+/// The whole parcel overlay of the current region, put together from its ParcelOverlay chunks
+/// and sent from the session to the UI.
+/// The cells are sent as they are, one byte each, row by row from the south west corner.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParcelOverlayGrid {
+    /// PARCEL_OVERLAY_SIZE cells
+    pub cells: Vec<u8>,
+}
+
+impl ParcelOverlayGrid {
+    /// the cell at a column and row, or None if it is outside the region
+    pub fn cell(&self, column: usize, row: usize) -> Option<ParcelOverlayFlags> {
+        if column >= PARCEL_BITMAP_WIDTH {
+            return None;
+        }
+        self.cells
+            .get(row * PARCEL_BITMAP_WIDTH + column)
+            .map(|cell| ParcelOverlayFlags::from_bits_retain(*cell))
+    }
+
+    /// the cell under a point, in region meters
+    pub fn at(&self, x: f32, y: f32) -> Option<ParcelOverlayFlags> {
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+        self.cell(
+            (x / PARCEL_GRID_SIZE) as usize,
+            (y / PARCEL_GRID_SIZE) as usize,
+        )
+    }
+}
+
+impl PacketData for ParcelOverlayGrid {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() != PARCEL_OVERLAY_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid byte length for ParcelOverlayGrid: {}", bytes.len()),
+            ));
+        }
+        Ok(ParcelOverlayGrid {
+            cells: bytes.to_vec(),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.cells.clone()
+    }
+}
@@ -0,0 +1,335 @@
+use crate::packet_types::PacketType;
+use crate::parcel_properties_request::PARCEL_GRID_SIZE;
+use crate::utils::packet_fields::{
+    read_string_1, read_uuid, read_variable_2, read_vec3, write_string_1, write_variable_2,
+    write_vec3,
+};
+use crate::utils::parcel_flags::ParcelFlags;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 23
+// Frequency: High
+
+/// the bitmap has one bit for each 4x4 meter cell of the region, in rows of this many cells
+pub const PARCEL_BITMAP_WIDTH: usize = 64;
+
+impl Packet {
+    pub fn new_parcel_properties(parcel_properties: ParcelProperties) -> Self {
+        Packet {
+            header: Header {
+                id: 23,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelProperties(Box::new(parcel_properties)),
+        }
+    }
+}
+
+/// who holds a parcel
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ParcelStatus {
+    Leased,
+    LeasePending,
+    Abandoned,
+    /// sent as -1
+    None,
+    Unknown(u8),
+}
+impl ParcelStatus {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => ParcelStatus::Leased,
+            1 => ParcelStatus::LeasePending,
+            2 => ParcelStatus::Abandoned,
+            255 => ParcelStatus::None,
+            other => ParcelStatus::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            ParcelStatus::Leased => 0,
+            ParcelStatus::LeasePending => 1,
+            ParcelStatus::Abandoned => 2,
+            ParcelStatus::None => 255,
+            ParcelStatus::Unknown(other) => *other,
+        }
+    }
+}
+
+/// where avatars teleporting to the parcel arrive
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LandingType {
+    /// teleports to the parcel are blocked
+    Blocked,
+    /// avatars arrive at the user_location
+    LandingPoint,
+    /// avatars arrive wherever they teleported to
+    Anywhere,
+    Unknown(u8),
+}
+impl LandingType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => LandingType::Blocked,
+            1 => LandingType::LandingPoint,
+            2 => LandingType::Anywhere,
+            other => LandingType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            LandingType::Blocked => 0,
+            LandingType::LandingPoint => 1,
+            LandingType::Anywhere => 2,
+            LandingType::Unknown(other) => *other,
+        }
+    }
+}
+
+/// The properties of a parcel, sent in answer to a ParcelPropertiesRequest.
+/// The simulator also sends one without being asked when the agent enters a parcel, with a
+/// sequence id of 0.
+/// The region access blocks at the end are left off by older simulators.
+/// https://wiki.secondlife.com/wiki/ParcelProperties
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParcelProperties {
+    /// one of the RESULT constants
+    pub request_result: i32,
+    /// the sequence id of the ParcelPropertiesRequest this answers
+    pub sequence_id: i32,
+    /// whether the selection should snap to the parcel's bounds
+    pub snap_selection: bool,
+    /// how many prims the agent owns on the parcel
+    pub self_count: i32,
+    /// how many prims others own on the parcel
+    pub other_count: i32,
+    pub public_count: i32,
+    /// the id of the parcel within the region
+    pub local_id: i32,
+    pub owner_id: Uuid,
+    pub is_group_owned: bool,
+    pub auction_id: u32,
+    /// when the parcel was claimed, in seconds since the unix epoch
+    pub claim_date: i32,
+    pub claim_price: i32,
+    pub rent_price: i32,
+    /// the corner of the parcel's bounding box nearest the region's origin
+    pub aabb_min: Vec3,
+    pub aabb_max: Vec3,
+    /// one bit for each 4x4 meter cell of the region that is part of the parcel
+    pub bitmap: Vec<u8>,
+    /// in square meters
+    pub area: i32,
+    pub status: ParcelStatus,
+    pub sim_wide_max_prims: i32,
+    pub sim_wide_total_prims: i32,
+    pub max_prims: i32,
+    pub total_prims: i32,
+    pub owner_prims: i32,
+    pub group_prims: i32,
+    pub other_prims: i32,
+    pub selected_prims: i32,
+    pub parcel_prim_bonus: f32,
+    /// how many minutes objects of others are left on the parcel before they are returned
+    pub other_clean_time: i32,
+    pub parcel_flags: ParcelFlags,
+    pub sale_price: i32,
+    pub name: String,
+    pub description: String,
+    pub music_url: String,
+    pub media_url: String,
+    /// the texture that is replaced by the media
+    pub media_id: Uuid,
+    pub media_auto_scale: bool,
+    pub group_id: Uuid,
+    /// the price of a pass onto the parcel
+    pub pass_price: i32,
+    /// how long a pass lasts
+    pub pass_hours: f32,
+    /// the category the parcel is listed under in search
+    pub category: u8,
+    /// the only avatar that can buy the parcel, nil for anyone
+    pub auth_buyer_id: Uuid,
+    pub snapshot_id: Uuid,
+    /// the landing point
+    pub user_location: Vec3,
+    /// the direction avatars face at the landing point
+    pub user_look_at: Vec3,
+    pub landing_type: LandingType,
+    pub region_push_override: bool,
+    pub region_deny_anonymous: bool,
+    pub region_deny_identified: bool,
+    pub region_deny_transacted: bool,
+    pub region_deny_age_unverified: Option<bool>,
+    pub region_allow_access_override: Option<bool>,
+}
+
+impl ParcelProperties {
+    /// the simulator has no parcel for the request
+    pub const RESULT_NO_DATA: i32 = -1;
+    pub const RESULT_SUCCESS: i32 = 0;
+    /// the request's rectangle spans more than one parcel
+    pub const RESULT_MULTIPLE: i32 = 1;
+
+    /// true if the point, in region meters, is in the parcel according to its bitmap.
+    /// Points outside the region are never in the parcel.
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        if x < 0.0 || y < 0.0 {
+            return false;
+        }
+        let column = (x / PARCEL_GRID_SIZE) as usize;
+        let row = (y / PARCEL_GRID_SIZE) as usize;
+        if column >= PARCEL_BITMAP_WIDTH {
+            return false;
+        }
+        let cell = row * PARCEL_BITMAP_WIDTH + column;
+        self.bitmap
+            .get(cell / 8)
+            .is_some_and(|byte| byte & (1 << (cell % 8)) != 0)
+    }
+}
+
+impl PacketData for ParcelProperties {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let mut properties = ParcelProperties {
+            request_result: cursor.read_i32::<LittleEndian>()?,
+            sequence_id: cursor.read_i32::<LittleEndian>()?,
+            snap_selection: cursor.read_u8()? != 0,
+            self_count: cursor.read_i32::<LittleEndian>()?,
+            other_count: cursor.read_i32::<LittleEndian>()?,
+            public_count: cursor.read_i32::<LittleEndian>()?,
+            local_id: cursor.read_i32::<LittleEndian>()?,
+            owner_id: read_uuid(&mut cursor)?,
+            is_group_owned: cursor.read_u8()? != 0,
+            auction_id: cursor.read_u32::<LittleEndian>()?,
+            claim_date: cursor.read_i32::<LittleEndian>()?,
+            claim_price: cursor.read_i32::<LittleEndian>()?,
+            rent_price: cursor.read_i32::<LittleEndian>()?,
+            aabb_min: read_vec3(&mut cursor)?,
+            aabb_max: read_vec3(&mut cursor)?,
+            bitmap: read_variable_2(&mut cursor)?,
+            area: cursor.read_i32::<LittleEndian>()?,
+            status: ParcelStatus::from_bytes(cursor.read_u8()?),
+            sim_wide_max_prims: cursor.read_i32::<LittleEndian>()?,
+            sim_wide_total_prims: cursor.read_i32::<LittleEndian>()?,
+            max_prims: cursor.read_i32::<LittleEndian>()?,
+            total_prims: cursor.read_i32::<LittleEndian>()?,
+            owner_prims: cursor.read_i32::<LittleEndian>()?,
+            group_prims: cursor.read_i32::<LittleEndian>()?,
+            other_prims: cursor.read_i32::<LittleEndian>()?,
+            selected_prims: cursor.read_i32::<LittleEndian>()?,
+            parcel_prim_bonus: cursor.read_f32::<LittleEndian>()?,
+            other_clean_time: cursor.read_i32::<LittleEndian>()?,
+            parcel_flags: ParcelFlags::from_bits(cursor.read_u32::<LittleEndian>()?),
+            sale_price: cursor.read_i32::<LittleEndian>()?,
+            name: read_string_1(&mut cursor)?,
+            description: read_string_1(&mut cursor)?,
+            music_url: read_string_1(&mut cursor)?,
+            media_url: read_string_1(&mut cursor)?,
+            media_id: read_uuid(&mut cursor)?,
+            media_auto_scale: cursor.read_u8()? != 0,
+            group_id: read_uuid(&mut cursor)?,
+            pass_price: cursor.read_i32::<LittleEndian>()?,
+            pass_hours: cursor.read_f32::<LittleEndian>()?,
+            category: cursor.read_u8()?,
+            auth_buyer_id: read_uuid(&mut cursor)?,
+            snapshot_id: read_uuid(&mut cursor)?,
+            user_location: read_vec3(&mut cursor)?,
+            user_look_at: read_vec3(&mut cursor)?,
+            landing_type: LandingType::from_bytes(cursor.read_u8()?),
+            region_push_override: cursor.read_u8()? != 0,
+            region_deny_anonymous: cursor.read_u8()? != 0,
+            region_deny_identified: cursor.read_u8()? != 0,
+            region_deny_transacted: cursor.read_u8()? != 0,
+            region_deny_age_unverified: None,
+            region_allow_access_override: None,
+        };
+        if (cursor.position() as usize) < bytes.len() {
+            properties.region_deny_age_unverified = Some(cursor.read_u8()? != 0);
+        }
+        if (cursor.position() as usize) < bytes.len() {
+            properties.region_allow_access_override = Some(cursor.read_u8()? != 0);
+        }
+        Ok(properties)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.request_result.to_le_bytes());
+        bytes.extend_from_slice(&self.sequence_id.to_le_bytes());
+        bytes.push(self.snap_selection as u8);
+        bytes.extend_from_slice(&self.self_count.to_le_bytes());
+        bytes.extend_from_slice(&self.other_count.to_le_bytes());
+        bytes.extend_from_slice(&self.public_count.to_le_bytes());
+        bytes.extend_from_slice(&self.local_id.to_le_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.push(self.is_group_owned as u8);
+        bytes.extend_from_slice(&self.auction_id.to_le_bytes());
+        bytes.extend_from_slice(&self.claim_date.to_le_bytes());
+        bytes.extend_from_slice(&self.claim_price.to_le_bytes());
+        bytes.extend_from_slice(&self.rent_price.to_le_bytes());
+        write_vec3(&mut bytes, &self.aabb_min);
+        write_vec3(&mut bytes, &self.aabb_max);
+        write_variable_2(&mut bytes, &self.bitmap);
+        bytes.extend_from_slice(&self.area.to_le_bytes());
+        bytes.push(self.status.to_bytes());
+        bytes.extend_from_slice(&self.sim_wide_max_prims.to_le_bytes());
+        bytes.extend_from_slice(&self.sim_wide_total_prims.to_le_bytes());
+        bytes.extend_from_slice(&self.max_prims.to_le_bytes());
+        bytes.extend_from_slice(&self.total_prims.to_le_bytes());
+        bytes.extend_from_slice(&self.owner_prims.to_le_bytes());
+        bytes.extend_from_slice(&self.group_prims.to_le_bytes());
+        bytes.extend_from_slice(&self.other_prims.to_le_bytes());
+        bytes.extend_from_slice(&self.selected_prims.to_le_bytes());
+        bytes.extend_from_slice(&self.parcel_prim_bonus.to_le_bytes());
+        bytes.extend_from_slice(&self.other_clean_time.to_le_bytes());
+        bytes.extend_from_slice(&self.parcel_flags.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.sale_price.to_le_bytes());
+        write_string_1(&mut bytes, &self.name);
+        write_string_1(&mut bytes, &self.description);
+        write_string_1(&mut bytes, &self.music_url);
+        write_string_1(&mut bytes, &self.media_url);
+        bytes.extend_from_slice(self.media_id.as_bytes());
+        bytes.push(self.media_auto_scale as u8);
+        bytes.extend_from_slice(self.group_id.as_bytes());
+        bytes.extend_from_slice(&self.pass_price.to_le_bytes());
+        bytes.extend_from_slice(&self.pass_hours.to_le_bytes());
+        bytes.push(self.category);
+        bytes.extend_from_slice(self.auth_buyer_id.as_bytes());
+        bytes.extend_from_slice(self.snapshot_id.as_bytes());
+        write_vec3(&mut bytes, &self.user_location);
+        write_vec3(&mut bytes, &self.user_look_at);
+        bytes.push(self.landing_type.to_bytes());
+        bytes.push(self.region_push_override as u8);
+        bytes.push(self.region_deny_anonymous as u8);
+        bytes.push(self.region_deny_identified as u8);
+        bytes.push(self.region_deny_transacted as u8);
+        // the allow access block can't be sent without the age verification block before it
+        if let Some(deny) = self.region_deny_age_unverified {
+            bytes.push(deny as u8);
+            if let Some(allow) = self.region_allow_access_override {
+                bytes.push(allow as u8);
+            }
+        }
+        bytes
+    }
+}
@@ -0,0 +1,104 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 11
+// Frequency: Medium
+
+/// parcels are made of square cells of this many meters
+pub const PARCEL_GRID_SIZE: f32 = 4.0;
+
+impl Packet {
+    pub fn new_parcel_properties_request(
+        parcel_properties_request: ParcelPropertiesRequest,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 11,
+                frequency: PacketFrequency::Medium,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ParcelPropertiesRequest(Box::new(parcel_properties_request)),
+        }
+    }
+}
+
+/// Asks for the properties of the parcels in a rectangle of the region, in region meters.
+/// The simulator answers with a ParcelProperties carrying the same sequence id.
+/// With snap_selection set, the answer is the whole parcel under the rectangle, and its bounds
+/// are what the selection should snap to. Without it, a rectangle spanning several parcels gets
+/// a ParcelProperties saying so instead.
+/// https://wiki.secondlife.com/wiki/ParcelPropertiesRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParcelPropertiesRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// echoed in the ParcelProperties, to match it to this request
+    pub sequence_id: i32,
+    pub west: f32,
+    pub south: f32,
+    pub east: f32,
+    pub north: f32,
+    pub snap_selection: bool,
+}
+
+impl ParcelPropertiesRequest {
+    /// a request for the parcel under a point, as the grid cell the point is in.
+    /// The agent and session ids are left nil.
+    pub fn at(x: f32, y: f32, sequence_id: i32) -> Self {
+        let west = (x / PARCEL_GRID_SIZE).floor() * PARCEL_GRID_SIZE;
+        let south = (y / PARCEL_GRID_SIZE).floor() * PARCEL_GRID_SIZE;
+        ParcelPropertiesRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            sequence_id,
+            west,
+            south,
+            east: west + PARCEL_GRID_SIZE,
+            north: south + PARCEL_GRID_SIZE,
+            snap_selection: true,
+        }
+    }
+}
+
+impl PacketData for ParcelPropertiesRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ParcelPropertiesRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            sequence_id: cursor.read_i32::<LittleEndian>()?,
+            west: cursor.read_f32::<LittleEndian>()?,
+            south: cursor.read_f32::<LittleEndian>()?,
+            east: cursor.read_f32::<LittleEndian>()?,
+            north: cursor.read_f32::<LittleEndian>()?,
+            snap_selection: cursor.read_u8()? != 0,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(53);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.sequence_id.to_le_bytes());
+        bytes.extend_from_slice(&self.west.to_le_bytes());
+        bytes.extend_from_slice(&self.south.to_le_bytes());
+        bytes.extend_from_slice(&self.east.to_le_bytes());
+        bytes.extend_from_slice(&self.north.to_le_bytes());
+        bytes.push(self.snap_selection as u8);
+        bytes
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Sent from the session to the UI when an AvatarPickerRequest from the UI found nobody, was
+/// never answered, or had a name that was too short or too long to search for.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeopleSearchEmpty {
+    /// the query id of the search, to tell searches apart
+    pub query_id: Uuid,
+    /// the name that was searched for
+    pub name: String,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::avatar_picker_reply::AvatarMatch;
+
+/// The residents found by an AvatarPickerRequest from the UI, sent from the session to the UI.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeopleSearchResults {
+    /// the query id of the search, to tell searches apart
+    pub query_id: Uuid,
+    /// the name that was searched for
+    pub name: String,
+    pub avatars: Vec<AvatarMatch>,
+}
@@ -0,0 +1,113 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_dvec3, read_string_1, read_string_2, read_uuid, write_dvec3, write_string_1,
+    write_string_2,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::DVec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 184
+// Frequency: Low
+
+impl Packet {
+    pub fn new_pick_info_reply(pick_info_reply: PickInfoReply) -> Self {
+        Packet {
+            header: Header {
+                id: 184,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::PickInfoReply(Box::new(pick_info_reply)),
+        }
+    }
+}
+
+/// The details of a pick in an avatar's profile, sent in answer to the pickinforequest
+/// GenericMessage a PickInfoRequest is sent as.
+/// https://wiki.secondlife.com/wiki/PickInfoReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PickInfoReply {
+    pub agent_id: Uuid,
+    pub pick_id: Uuid,
+    /// the avatar whose profile has the pick
+    pub creator_id: Uuid,
+    /// picks made by the grid's staff
+    pub top_pick: bool,
+    /// the parcel the pick is for
+    pub parcel_id: Uuid,
+    pub name: String,
+    pub description: String,
+    /// the texture of the pick's picture
+    pub snapshot_id: Uuid,
+    /// the name of the parcel owner, or empty
+    pub user: String,
+    /// the name of the parcel when the pick was made
+    pub original_name: String,
+    pub sim_name: String,
+    /// where the pick is, in global coordinates
+    pub pos_global: DVec3,
+    /// where the pick is shown among the avatar's picks
+    pub sort_order: i32,
+    pub enabled: bool,
+}
+
+impl PacketData for PickInfoReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(PickInfoReply {
+            agent_id: read_uuid(&mut cursor)?,
+            pick_id: read_uuid(&mut cursor)?,
+            creator_id: read_uuid(&mut cursor)?,
+            top_pick: cursor.read_u8()? != 0,
+            parcel_id: read_uuid(&mut cursor)?,
+            name: read_string_1(&mut cursor)?,
+            description: read_string_2(&mut cursor)?,
+            snapshot_id: read_uuid(&mut cursor)?,
+            user: read_string_1(&mut cursor)?,
+            original_name: read_string_1(&mut cursor)?,
+            sim_name: read_string_1(&mut cursor)?,
+            pos_global: read_dvec3(&mut cursor)?,
+            sort_order: cursor.read_i32::<LittleEndian>()?,
+            enabled: cursor.read_u8()? != 0,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            116 + self.name.len()
+                + self.description.len()
+                + self.user.len()
+                + self.original_name.len()
+                + self.sim_name.len(),
+        );
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.pick_id.as_bytes());
+        bytes.extend_from_slice(self.creator_id.as_bytes());
+        bytes.push(self.top_pick as u8);
+        bytes.extend_from_slice(self.parcel_id.as_bytes());
+        write_string_1(&mut bytes, &self.name);
+        write_string_2(&mut bytes, &self.description);
+        bytes.extend_from_slice(self.snapshot_id.as_bytes());
+        write_string_1(&mut bytes, &self.user);
+        write_string_1(&mut bytes, &self.original_name);
+        write_string_1(&mut bytes, &self.sim_name);
+        write_dvec3(&mut bytes, &self.pos_global);
+        bytes.extend_from_slice(&self.sort_order.to_le_bytes());
+        bytes.push(self.enabled as u8);
+        bytes
+    }
+}
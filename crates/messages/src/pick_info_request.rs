@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_pick_info_request(pick_info_request: PickInfoRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 74,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::PickInfoRequest(Box::new(pick_info_request)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to ask for the details of a pick, like one listed in an
+/// AvatarPicksReply. Simulators don't have a packet for this, so the session sends it as the
+/// pickinforequest GenericMessage, and the simulator answers with a PickInfoReply.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PickInfoRequest {
+    /// the avatar whose profile has the pick
+    pub creator_id: Uuid,
+    pub pick_id: Uuid,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Circuit latency measured by the session's own StartPingCheck packets.
+/// This does not exist in the packet spec, it is sent from the session to the UI.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PingStats {
+    /// round trip time of the most recent completed ping, in milliseconds
+    pub latency_ms: u64,
+    /// number of pings sent since the session started
+    pub sent_pings: u32,
+    /// number of pings that were never answered
+    pub missed_pings: u32,
+}
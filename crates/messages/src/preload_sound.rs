@@ -0,0 +1,86 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 15
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_preload_sound(preload_sound: PreloadSound) -> Self {
+        Packet {
+            header: Header {
+                id: 15,
+                frequency: PacketFrequency::Medium,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::PreloadSound(Box::new(preload_sound)),
+        }
+    }
+}
+
+/// Tells the viewer about sounds that objects nearby are about to play, so it can download them
+/// ahead of time.
+/// https://wiki.secondlife.com/wiki/PreloadSound
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreloadSound {
+    pub sounds: Vec<PreloadSoundData>,
+}
+
+/// one sound to preload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreloadSoundData {
+    /// the object that will play the sound
+    pub object_id: Uuid,
+    pub owner_id: Uuid,
+    /// the sound asset
+    pub sound_id: Uuid,
+}
+
+impl PreloadSound {
+    /// the sound assets to preload, in the order they were sent
+    pub fn sound_ids(&self) -> Vec<Uuid> {
+        self.sounds.iter().map(|sound| sound.sound_id).collect()
+    }
+}
+
+impl PacketData for PreloadSound {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u8()?;
+        let mut sounds = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            sounds.push(PreloadSoundData {
+                object_id: read_uuid(&mut cursor)?,
+                owner_id: read_uuid(&mut cursor)?,
+                sound_id: read_uuid(&mut cursor)?,
+            });
+        }
+        Ok(PreloadSound { sounds })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let sounds = &self.sounds[..self.sounds.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(1 + sounds.len() * 48);
+        bytes.push(sounds.len() as u8);
+        for sound in sounds {
+            bytes.extend_from_slice(sound.object_id.as_bytes());
+            bytes.extend_from_slice(sound.owner_id.as_bytes());
+            bytes.extend_from_slice(sound.sound_id.as_bytes());
+        }
+        bytes
+    }
+}
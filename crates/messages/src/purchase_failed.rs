@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::sale_type::SaleType;
+
+/// Sent from the session to the UI when a purchase is abandoned before the ObjectBuy was sent,
+/// like when the object's price is no longer the one the UI expected. Purchases that were sent
+/// are answered by the simulator with a BalanceEvent instead.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PurchaseFailed {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// the id of the object, if the session knows it
+    pub object_id: Option<Uuid>,
+    /// the sale type the UI asked to buy with
+    pub expected_sale_type: SaleType,
+    /// the price the UI asked to pay
+    pub expected_price: i32,
+    /// the sale type from the object's properties, if they arrived
+    pub sale_type: Option<SaleType>,
+    /// the price from the object's properties, if they arrived
+    pub sale_price: Option<i32>,
+    /// human readable reason the purchase failed
+    pub reason: String,
+}
@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 285
+// Frequency: Low
+
+impl Packet {
+    pub fn new_purge_inventory_descendents(
+        purge_inventory_descendents: PurgeInventoryDescendents,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 285,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::PurgeInventoryDescendents(Box::new(purge_inventory_descendents)),
+        }
+    }
+}
+
+/// Deletes everything inside an inventory folder for good, like emptying the trash. The folder
+/// itself is kept.
+/// https://wiki.secondlife.com/wiki/PurgeInventoryDescendents
+#[derive(Debug, Clone, PartialEq)]
+pub struct PurgeInventoryDescendents {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub folder_id: Uuid,
+}
+
+impl PurgeInventoryDescendents {
+    /// empty a folder. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(folder_id: Uuid) -> Self {
+        PurgeInventoryDescendents {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            folder_id,
+        }
+    }
+}
+
+impl PacketData for PurgeInventoryDescendents {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(PurgeInventoryDescendents {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            folder_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.folder_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_purge_inventory_folder(purge_inventory_folder: PurgeInventoryFolder) -> Self {
+        Packet {
+            header: Header {
+                id: 67,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::PurgeInventoryFolder(Box::new(purge_inventory_folder)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to delete everything inside an inventory folder, like
+/// emptying the trash. A purge can't be undone, so the session only sends the
+/// PurgeInventoryDescendents when the purge is confirmed, and refuses it otherwise.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PurgeInventoryFolder {
+    pub folder_id: Uuid,
+    /// the user has agreed to lose everything inside the folder
+    pub confirmed: bool,
+}
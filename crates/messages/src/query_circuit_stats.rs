@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_query_circuit_stats(query_circuit_stats: QueryCircuitStats) -> Self {
+        Packet {
+            header: Header {
+                id: 78,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::QueryCircuitStats(Box::new(query_circuit_stats)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to ask for the statistics of its circuits right away,
+/// instead of waiting for the next CircuitStatsEvent. The session answers with a
+/// CircuitStatsReport.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueryCircuitStats {}
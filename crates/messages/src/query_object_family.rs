@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_query_object_family(query_object_family: QueryObjectFamily) -> Self {
+        Packet {
+            header: Header {
+                id: 71,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::QueryObjectFamily(Box::new(query_object_family)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to ask for the owner, group, price, name and description of
+/// an object without selecting it, like when hovering over it. The session sends a
+/// RequestObjectPropertiesFamily with request flags of its own, and the answer is sent back as
+/// an ObjectFamilyStatus carrying the token, so the UI can tell apart answers to queries that
+/// are in flight at the same time.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryObjectFamily {
+    /// the full id of the object
+    pub object_id: Uuid,
+    /// picked by the UI, and sent back with the answer
+    pub token: u32,
+}
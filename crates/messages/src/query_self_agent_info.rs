@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_query_self_agent_info(query_self_agent_info: QuerySelfAgentInfo) -> Self {
+        Packet {
+            header: Header {
+                id: 73,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::QuerySelfAgentInfo(Box::new(query_self_agent_info)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to ask for the agent's name and active group as the grid
+/// sees them. The session answers with a SelfAgentInfo.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QuerySelfAgentInfo {}
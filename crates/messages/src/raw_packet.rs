@@ -0,0 +1,22 @@
+use super::header::PacketFrequency;
+
+/// The body of a packet whose message number the client doesn't implement, kept as it arrived.
+/// Packets with ids the client knows, but bodies it can't read, are parse errors instead.
+/// Writing the packet back out gives the same bytes, so unknown packets can be passed along or
+/// inspected without losing anything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawPacketData {
+    /// the frequency of the message number
+    pub frequency: PacketFrequency,
+    /// the message number, without its frequency marker
+    pub id: u16,
+    /// the body, after zerocoding was expanded and without appended acks
+    pub body: Vec<u8>,
+}
+
+impl RawPacketData {
+    /// the body, as it arrived
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.body.clone()
+    }
+}
@@ -0,0 +1,32 @@
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::region_handle::region_coordinates;
+
+/// Sent from the session to the UI once a region crossing has finished and the new region's
+/// circuit is the current one.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionCrossed {
+    /// handle of the region the agent is now in
+    pub region_handle: u64,
+    /// position of the agent in the new region's coordinates
+    pub position: Vec3,
+    pub look_at: Vec3,
+    /// add this to a position in the old region's coordinates to get the same point in the new
+    /// region's coordinates
+    pub offset: Vec3,
+}
+
+impl RegionCrossed {
+    /// the offset between two regions' local coordinates
+    pub fn offset_between(old_region_handle: u64, new_region_handle: u64) -> Vec3 {
+        let (old_x, old_y) = region_coordinates(old_region_handle);
+        let (new_x, new_y) = region_coordinates(new_region_handle);
+        Vec3::new(
+            (old_x as i64 - new_x as i64) as f32,
+            (old_y as i64 - new_y as i64) as f32,
+            0.0,
+        )
+    }
+}
@@ -12,9 +12,9 @@ impl Packet {
         Packet {
             header: Header {
                 id: 149,
-                reliable: false,
+                reliable: true,
                 resent: false,
-                zerocoded: true,
+                zerocoded: false,
                 appended_acks: false,
                 sequence_number: 0,
                 frequency: PacketFrequency::Low,
@@ -0,0 +1,182 @@
+use crate::packet_types::PacketType;
+use crate::utils::agent_access::AgentAccess;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 142
+// Frequency: Low
+
+impl Packet {
+    pub fn new_region_info(region_info: RegionInfo) -> Self {
+        Packet {
+            header: Header {
+                id: 142,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RegionInfo(Box::new(region_info)),
+        }
+    }
+}
+
+/// The settings of the current region, sent in answer to a RegionInfoRequest, and when an
+/// estate manager changes them. Older simulators don't send the RegionInfo2 and RegionInfo3
+/// blocks.
+/// https://wiki.secondlife.com/wiki/RegionInfo
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionInfo {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub sim_name: String,
+    pub estate_id: u32,
+    pub parent_estate_id: u32,
+    pub region_flags: u32,
+    pub sim_access: AgentAccess,
+    pub max_agents: u8,
+    pub billable_factor: f32,
+    pub object_bonus_factor: f32,
+    pub water_height: f32,
+    pub terrain_raise_limit: f32,
+    pub terrain_lower_limit: f32,
+    pub price_per_meter: i32,
+    pub redirect_grid_x: i32,
+    pub redirect_grid_y: i32,
+    pub use_estate_sun: bool,
+    pub sun_hour: f32,
+    /// the RegionInfo2 block, None if the simulator is too old to send it
+    pub extended: Option<RegionInfoExtended>,
+    /// the RegionInfo3 blocks, which carry the upper bits of the region flags
+    pub region_flags_extended: Vec<u64>,
+}
+
+/// The RegionInfo2 block, with the product and the hard limits of the region
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionInfoExtended {
+    pub product_sku: String,
+    pub product_name: String,
+    /// the agent limit, for regions that allow more than the u8 max_agents can hold
+    pub max_agents: u32,
+    pub hard_max_agents: u32,
+    pub hard_max_objects: u32,
+}
+
+impl PacketData for RegionInfo {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let sim_name = read_string_1(&mut cursor)?;
+        let estate_id = cursor.read_u32::<LittleEndian>()?;
+        let parent_estate_id = cursor.read_u32::<LittleEndian>()?;
+        let region_flags = cursor.read_u32::<LittleEndian>()?;
+        let sim_access = AgentAccess::from_bytes(&cursor.read_u8()?);
+        let max_agents = cursor.read_u8()?;
+        let billable_factor = cursor.read_f32::<LittleEndian>()?;
+        let object_bonus_factor = cursor.read_f32::<LittleEndian>()?;
+        let water_height = cursor.read_f32::<LittleEndian>()?;
+        let terrain_raise_limit = cursor.read_f32::<LittleEndian>()?;
+        let terrain_lower_limit = cursor.read_f32::<LittleEndian>()?;
+        let price_per_meter = cursor.read_i32::<LittleEndian>()?;
+        let redirect_grid_x = cursor.read_i32::<LittleEndian>()?;
+        let redirect_grid_y = cursor.read_i32::<LittleEndian>()?;
+        let use_estate_sun = cursor.read_u8()? != 0;
+        let sun_hour = cursor.read_f32::<LittleEndian>()?;
+
+        // RegionInfo2 was added later, and older simulators end the packet here
+        let extended = if (cursor.position() as usize) < bytes.len() {
+            Some(RegionInfoExtended {
+                product_sku: read_string_1(&mut cursor)?,
+                product_name: read_string_1(&mut cursor)?,
+                max_agents: cursor.read_u32::<LittleEndian>()?,
+                hard_max_agents: cursor.read_u32::<LittleEndian>()?,
+                hard_max_objects: cursor.read_u32::<LittleEndian>()?,
+            })
+        } else {
+            None
+        };
+
+        let count = if (cursor.position() as usize) < bytes.len() {
+            cursor.read_u8()?
+        } else {
+            0
+        };
+        let mut region_flags_extended = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            region_flags_extended.push(cursor.read_u64::<LittleEndian>()?);
+        }
+
+        Ok(RegionInfo {
+            agent_id,
+            session_id,
+            sim_name,
+            estate_id,
+            parent_estate_id,
+            region_flags,
+            sim_access,
+            max_agents,
+            billable_factor,
+            object_bonus_factor,
+            water_height,
+            terrain_raise_limit,
+            terrain_lower_limit,
+            price_per_meter,
+            redirect_grid_x,
+            redirect_grid_y,
+            use_estate_sun,
+            sun_hour,
+            extended,
+            region_flags_extended,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        write_string_1(&mut bytes, &self.sim_name);
+        bytes.extend_from_slice(&self.estate_id.to_le_bytes());
+        bytes.extend_from_slice(&self.parent_estate_id.to_le_bytes());
+        bytes.extend_from_slice(&self.region_flags.to_le_bytes());
+        bytes.push(self.sim_access.to_bytes());
+        bytes.push(self.max_agents);
+        bytes.extend_from_slice(&self.billable_factor.to_le_bytes());
+        bytes.extend_from_slice(&self.object_bonus_factor.to_le_bytes());
+        bytes.extend_from_slice(&self.water_height.to_le_bytes());
+        bytes.extend_from_slice(&self.terrain_raise_limit.to_le_bytes());
+        bytes.extend_from_slice(&self.terrain_lower_limit.to_le_bytes());
+        bytes.extend_from_slice(&self.price_per_meter.to_le_bytes());
+        bytes.extend_from_slice(&self.redirect_grid_x.to_le_bytes());
+        bytes.extend_from_slice(&self.redirect_grid_y.to_le_bytes());
+        bytes.push(self.use_estate_sun as u8);
+        bytes.extend_from_slice(&self.sun_hour.to_le_bytes());
+
+        // the legacy form ends after the first block
+        if let Some(extended) = &self.extended {
+            write_string_1(&mut bytes, &extended.product_sku);
+            write_string_1(&mut bytes, &extended.product_name);
+            bytes.extend_from_slice(&extended.max_agents.to_le_bytes());
+            bytes.extend_from_slice(&extended.hard_max_agents.to_le_bytes());
+            bytes.extend_from_slice(&extended.hard_max_objects.to_le_bytes());
+            let count = self.region_flags_extended.len().min(u8::MAX as usize);
+            bytes.push(count as u8);
+            for flags in &self.region_flags_extended[..count] {
+                bytes.extend_from_slice(&flags.to_le_bytes());
+            }
+        }
+        bytes
+    }
+}
@@ -0,0 +1,74 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 141
+// Frequency: Low
+
+impl Packet {
+    pub fn new_region_info_request(region_info_request: RegionInfoRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 141,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RegionInfoRequest(Box::new(region_info_request)),
+        }
+    }
+}
+
+/// Asks the simulator for the settings of the current region. The simulator answers with a
+/// RegionInfo.
+/// https://wiki.secondlife.com/wiki/RegionInfoRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionInfoRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl RegionInfoRequest {
+    /// ask for the region's settings. The agent and session ids are left nil, for the session
+    /// to fill in.
+    pub fn new() -> Self {
+        RegionInfoRequest {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+        }
+    }
+}
+
+impl Default for RegionInfoRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PacketData for RegionInfoRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(RegionInfoRequest {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes
+    }
+}
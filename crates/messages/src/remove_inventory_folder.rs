@@ -0,0 +1,83 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 276
+// Frequency: Low
+
+impl Packet {
+    pub fn new_remove_inventory_folder(remove_inventory_folder: RemoveInventoryFolder) -> Self {
+        Packet {
+            header: Header {
+                id: 276,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RemoveInventoryFolder(Box::new(remove_inventory_folder)),
+        }
+    }
+}
+
+/// Deletes inventory folders for good, with everything inside them, rather than moving them to
+/// the trash.
+/// https://wiki.secondlife.com/wiki/RemoveInventoryFolder
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveInventoryFolder {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub folder_ids: Vec<Uuid>,
+}
+
+impl RemoveInventoryFolder {
+    /// delete folders. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(folder_ids: Vec<Uuid>) -> Self {
+        RemoveInventoryFolder {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            folder_ids,
+        }
+    }
+}
+
+impl PacketData for RemoveInventoryFolder {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut folder_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            folder_ids.push(read_uuid(&mut cursor)?);
+        }
+        Ok(RemoveInventoryFolder {
+            agent_id,
+            session_id,
+            folder_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let folder_ids = &self.folder_ids[..self.folder_ids.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + folder_ids.len() * 16);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(folder_ids.len() as u8);
+        for folder_id in folder_ids {
+            bytes.extend_from_slice(folder_id.as_bytes());
+        }
+        bytes
+    }
+}
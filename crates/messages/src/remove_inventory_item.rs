@@ -0,0 +1,82 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 270
+// Frequency: Low
+
+impl Packet {
+    pub fn new_remove_inventory_item(remove_inventory_item: RemoveInventoryItem) -> Self {
+        Packet {
+            header: Header {
+                id: 270,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RemoveInventoryItem(Box::new(remove_inventory_item)),
+        }
+    }
+}
+
+/// Deletes inventory items for good, rather than moving them to the trash.
+/// https://wiki.secondlife.com/wiki/RemoveInventoryItem
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveInventoryItem {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub item_ids: Vec<Uuid>,
+}
+
+impl RemoveInventoryItem {
+    /// delete items. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(item_ids: Vec<Uuid>) -> Self {
+        RemoveInventoryItem {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            item_ids,
+        }
+    }
+}
+
+impl PacketData for RemoveInventoryItem {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut item_ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            item_ids.push(read_uuid(&mut cursor)?);
+        }
+        Ok(RemoveInventoryItem {
+            agent_id,
+            session_id,
+            item_ids,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let item_ids = &self.item_ids[..self.item_ids.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + item_ids.len() * 16);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(item_ids.len() as u8);
+        for item_id in item_ids {
+            bytes.extend_from_slice(item_id.as_bytes());
+        }
+        bytes
+    }
+}
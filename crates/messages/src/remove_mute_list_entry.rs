@@ -0,0 +1,75 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 265
+// Frequency: Low
+
+impl Packet {
+    pub fn new_remove_mute_list_entry(remove_mute_list_entry: RemoveMuteListEntry) -> Self {
+        Packet {
+            header: Header {
+                id: 265,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RemoveMuteListEntry(Box::new(remove_mute_list_entry)),
+        }
+    }
+}
+
+/// Takes an entry off the agent's mute list. Entries are matched by id, and by name for
+/// entries muted by name.
+/// https://wiki.secondlife.com/wiki/RemoveMuteListEntry
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveMuteListEntry {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub mute_id: Uuid,
+    pub mute_name: String,
+}
+
+impl RemoveMuteListEntry {
+    /// unmute an entry. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(mute_id: Uuid, mute_name: String) -> Self {
+        RemoveMuteListEntry {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            mute_id,
+            mute_name,
+        }
+    }
+}
+
+impl PacketData for RemoveMuteListEntry {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(RemoveMuteListEntry {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            mute_id: read_uuid(&mut cursor)?,
+            mute_name: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(50 + self.mute_name.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.mute_id.as_bytes());
+        write_string_1(&mut bytes, &self.mute_name);
+        bytes
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_report_unknown_packets(report_unknown_packets: ReportUnknownPackets) -> Self {
+        Packet {
+            header: Header {
+                id: 77,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ReportUnknownPackets(Box::new(report_unknown_packets)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to turn reporting the packets the client doesn't implement on
+/// or off. While it is on, every one of them is sent to the UI as an UnknownPacketEvent.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportUnknownPackets {
+    pub enabled: bool,
+}
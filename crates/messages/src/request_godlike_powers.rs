@@ -0,0 +1,78 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 258
+// Frequency: Low
+
+impl Packet {
+    pub fn new_request_godlike_powers(request_godlike_powers: RequestGodlikePowers) -> Self {
+        Packet {
+            header: Header {
+                id: 258,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RequestGodlikePowers(Box::new(request_godlike_powers)),
+        }
+    }
+}
+
+/// Asks the simulator for god powers, or gives them up. Only grid administrators are granted
+/// them, and the simulator answers with a GrantGodlikePowers with the god level.
+/// https://wiki.secondlife.com/wiki/RequestGodlikePowers
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestGodlikePowers {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// true to ask for god powers, false to give them up
+    pub godlike: bool,
+    pub token: Uuid,
+}
+
+impl RequestGodlikePowers {
+    /// ask for god powers, or give them up. The agent and session ids are left nil, for the
+    /// session to fill in.
+    pub fn new(godlike: bool) -> Self {
+        RequestGodlikePowers {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            godlike,
+            token: Uuid::nil(),
+        }
+    }
+}
+
+impl PacketData for RequestGodlikePowers {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(RequestGodlikePowers {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            godlike: cursor.read_u8()? != 0,
+            token: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(49);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(self.godlike as u8);
+        bytes.extend_from_slice(self.token.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,147 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 8
+// Frequency: High
+
+impl Packet {
+    pub fn new_request_image(request_image: RequestImage) -> Self {
+        Packet {
+            header: Header {
+                id: 8,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RequestImage(Box::new(request_image)),
+        }
+    }
+}
+
+/// Asks the simulator to send textures. The simulator answers each request with an ImageData
+/// followed by ImagePackets, or ImageNotInDatabase.
+/// https://wiki.secondlife.com/wiki/RequestImage
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestImage {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub requests: Vec<ImageRequest>,
+}
+
+/// one texture in a RequestImage
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageRequest {
+    pub texture_id: Uuid,
+    /// the resolution to download. 0 is the full image, and each level halves it.
+    /// -1 cancels the download.
+    pub discard_level: i8,
+    /// higher priorities are sent first. 0 cancels the download.
+    pub download_priority: f32,
+    /// the packet to start sending from. 0 starts with the ImageData.
+    pub packet: u32,
+    pub image_type: ImageType,
+}
+impl ImageRequest {
+    /// the discard level that tells the simulator to stop sending a texture
+    pub const CANCEL_DISCARD_LEVEL: i8 = -1;
+
+    /// a request that cancels the download of a texture
+    pub fn cancel(texture_id: Uuid) -> Self {
+        ImageRequest {
+            texture_id,
+            discard_level: Self::CANCEL_DISCARD_LEVEL,
+            download_priority: 0.0,
+            packet: 0,
+            image_type: ImageType::Normal,
+        }
+    }
+
+    pub fn is_cancel(&self) -> bool {
+        self.discard_level < 0
+    }
+}
+
+/// where the simulator looks for the texture
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageType {
+    Normal,
+    /// a baked avatar texture, which the simulator fetches from the avatar's appearance
+    Baked,
+    Unknown(u8),
+}
+impl ImageType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => ImageType::Normal,
+            1 => ImageType::Baked,
+            byte => ImageType::Unknown(byte),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            ImageType::Normal => 0,
+            ImageType::Baked => 1,
+            ImageType::Unknown(byte) => *byte,
+        }
+    }
+}
+
+impl PacketData for RequestImage {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AgentData
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+
+        // RequestImage is a variable block, prefixed with the number of blocks
+        let count = cursor.read_u8()? as usize;
+        let mut requests = Vec::with_capacity(count);
+        for _ in 0..count {
+            requests.push(ImageRequest {
+                texture_id: read_uuid(&mut cursor)?,
+                discard_level: cursor.read_i8()?,
+                download_priority: cursor.read_f32::<LittleEndian>()?,
+                packet: cursor.read_u32::<LittleEndian>()?,
+                image_type: ImageType::from_bytes(cursor.read_u8()?),
+            });
+        }
+
+        Ok(RequestImage {
+            agent_id,
+            session_id,
+            requests,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        // a variable block can't hold more than 255 blocks
+        let requests = &self.requests[..self.requests.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(33 + requests.len() * 26);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(requests.len() as u8);
+        for request in requests {
+            bytes.extend_from_slice(request.texture_id.as_bytes());
+            bytes.push(request.discard_level as u8);
+            bytes.extend_from_slice(&request.download_priority.to_le_bytes());
+            bytes.extend_from_slice(&request.packet.to_le_bytes());
+            bytes.push(request.image_type.to_bytes());
+        }
+        bytes
+    }
+}
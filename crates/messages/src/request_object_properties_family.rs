@@ -0,0 +1,89 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 5
+// Frequency: Medium
+
+impl Packet {
+    pub fn new_request_object_properties_family(
+        request_object_properties_family: RequestObjectPropertiesFamily,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 5,
+                frequency: PacketFrequency::Medium,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RequestObjectPropertiesFamily(Box::new(
+                request_object_properties_family,
+            )),
+        }
+    }
+}
+
+/// Asks for the owner, group, price, name and description of an object without selecting it,
+/// like when hovering over it. The simulator answers with an ObjectPropertiesFamily that echoes
+/// the request flags.
+/// https://wiki.secondlife.com/wiki/RequestObjectPropertiesFamily
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestObjectPropertiesFamily {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// sent back in the reply. The viewer uses the low bits to say what the properties are for,
+    /// like a bug report or paying the object.
+    pub request_flags: u32,
+    /// the full id of the object
+    pub object_id: Uuid,
+}
+
+impl RequestObjectPropertiesFamily {
+    /// ask for the properties of an object. The agent and session ids are left nil, for the
+    /// session to fill in.
+    pub fn new(request_flags: u32, object_id: Uuid) -> Self {
+        RequestObjectPropertiesFamily {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            request_flags,
+            object_id,
+        }
+    }
+}
+
+impl PacketData for RequestObjectPropertiesFamily {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let request_flags = cursor.read_u32::<LittleEndian>()?;
+        let object_id = read_uuid(&mut cursor)?;
+        Ok(RequestObjectPropertiesFamily {
+            agent_id,
+            session_id,
+            request_flags,
+            object_id,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(52);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.request_flags.to_le_bytes());
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes
+    }
+}
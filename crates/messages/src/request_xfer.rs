@@ -0,0 +1,94 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 156
+// Frequency: Low
+
+impl Packet {
+    pub fn new_request_xfer(request_xfer: RequestXfer) -> Self {
+        Packet {
+            header: Header {
+                id: 156,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RequestXfer(Box::new(request_xfer)),
+        }
+    }
+}
+
+/// Asks the other side of the circuit to start sending a file with the Xfer system. The file is
+/// either named by its file name, or by its VFileID if the file name is empty.
+/// The data is sent back in SendXferPackets, each of which is confirmed with a
+/// ConfirmXferPacket before the next one is sent.
+/// https://wiki.secondlife.com/wiki/RequestXfer
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestXfer {
+    /// chosen by the requester, and used in every packet of the transfer
+    pub id: u64,
+    pub file_name: String,
+    /// the directory the file is in
+    pub file_path: u8,
+    pub delete_on_completion: bool,
+    pub use_big_packets: bool,
+    pub vfile_id: Uuid,
+    pub vfile_type: i16,
+}
+
+impl RequestXfer {
+    /// the file_path of files in the simulator's cache directory, like the mute list
+    pub const PATH_CACHE: u8 = 4;
+    /// the vfile_type of files that are requested by name
+    pub const VFILE_TYPE_NONE: i16 = -1;
+}
+
+impl PacketData for RequestXfer {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // XferID
+        let id = cursor.read_u64::<LittleEndian>()?;
+        let file_name = read_string_1(&mut cursor)?;
+        let file_path = cursor.read_u8()?;
+        let delete_on_completion = cursor.read_u8()? != 0;
+        let use_big_packets = cursor.read_u8()? != 0;
+        let vfile_id = read_uuid(&mut cursor)?;
+        let vfile_type = cursor.read_i16::<LittleEndian>()?;
+
+        Ok(RequestXfer {
+            id,
+            file_name,
+            file_path,
+            delete_on_completion,
+            use_big_packets,
+            vfile_id,
+            vfile_type,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(30 + self.file_name.len());
+        bytes.extend_from_slice(&self.id.to_le_bytes());
+        write_string_1(&mut bytes, &self.file_name);
+        bytes.push(self.file_path);
+        bytes.push(self.delete_on_completion as u8);
+        bytes.push(self.use_big_packets as u8);
+        bytes.extend_from_slice(self.vfile_id.as_bytes());
+        bytes.extend_from_slice(&self.vfile_type.to_le_bytes());
+        bytes
+    }
+}
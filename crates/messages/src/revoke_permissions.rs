@@ -0,0 +1,68 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::script_permissions::ScriptPermissions;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 193
+// Frequency: Low
+
+impl Packet {
+    pub fn new_revoke_permissions(revoke_permissions: RevokePermissions) -> Self {
+        Packet {
+            header: Header {
+                id: 193,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RevokePermissions(Box::new(revoke_permissions)),
+        }
+    }
+}
+
+/// Takes back permissions the agent granted to the scripts in an object with ScriptAnswerYes.
+/// https://wiki.secondlife.com/wiki/RevokePermissions
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevokePermissions {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the object the scripts are in
+    pub object_id: Uuid,
+    /// the permissions to take back
+    pub object_permissions: ScriptPermissions,
+}
+
+impl PacketData for RevokePermissions {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(RevokePermissions {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            object_id: read_uuid(&mut cursor)?,
+            object_permissions: ScriptPermissions::from_bits_retain(
+                cursor.read_u32::<LittleEndian>()?,
+            ),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(52);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(&self.object_permissions.bits().to_le_bytes());
+        bytes
+    }
+}
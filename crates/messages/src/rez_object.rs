@@ -0,0 +1,235 @@
+use crate::packet_types::PacketType;
+use crate::utils::asset_type::AssetType;
+use crate::utils::inventory_item::InventoryItem;
+use crate::utils::packet_fields::{
+    read_string_1, read_uuid, read_vec3, write_string_1, write_vec3,
+};
+use crate::utils::permissions::Permissions;
+use crate::utils::sale_type::SaleType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 293
+// Frequency: Low
+
+impl Packet {
+    pub fn new_rez_object(rez_object: RezObject) -> Self {
+        Packet {
+            header: Header {
+                id: 293,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RezObject(Box::new(rez_object)),
+        }
+    }
+}
+
+/// Rezzes an object from inventory into the world, like dragging it out of the inventory
+/// window. The simulator answers with an ObjectUpdate for the new object.
+/// https://wiki.secondlife.com/wiki/RezObject
+#[derive(Debug, Clone, PartialEq)]
+pub struct RezObject {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the group the agent is acting as, nil for none
+    pub group_id: Uuid,
+    pub rez_data: RezData,
+    /// the item being rezzed
+    pub item: InventoryItem,
+    /// a new id for every rez
+    pub transaction_id: Uuid,
+    /// checksum of the item. The simulator looks the item up by its id instead, so this is
+    /// left zero.
+    pub crc: u32,
+}
+
+/// where and how to rez the object
+#[derive(Debug, Clone, PartialEq)]
+pub struct RezData {
+    /// the object the item is rezzed from, nil for the agent's inventory
+    pub from_task_id: Uuid,
+    /// place the object at ray_end instead of casting the ray
+    pub bypass_raycast: bool,
+    pub ray_start: Vec3,
+    pub ray_end: Vec3,
+    /// the object the ray was aimed at, nil for none
+    pub ray_target_id: Uuid,
+    /// ray_end is already on the surface that was hit
+    pub ray_end_is_intersection: bool,
+    /// select the object once it has been rezzed
+    pub rez_selected: bool,
+    /// take the item out of inventory, for items that can't be copied
+    pub remove_item: bool,
+    pub item_flags: u32,
+    pub group_mask: Permissions,
+    pub everyone_mask: Permissions,
+    pub next_owner_mask: Permissions,
+}
+
+impl RezObject {
+    /// rez an item at a position in the region, without a raycast. Items that can't be copied
+    /// are moved out of inventory. The agent, session and transaction ids are left nil, for the
+    /// session to fill in.
+    pub fn new(item: InventoryItem, position: Vec3) -> Self {
+        RezObject {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            group_id: Uuid::nil(),
+            rez_data: RezData {
+                from_task_id: Uuid::nil(),
+                bypass_raycast: true,
+                ray_start: position,
+                ray_end: position,
+                ray_target_id: Uuid::nil(),
+                ray_end_is_intersection: false,
+                rez_selected: false,
+                remove_item: !item.owner_mask.copy,
+                item_flags: item.flags,
+                group_mask: item.group_mask,
+                everyone_mask: item.everyone_mask,
+                next_owner_mask: item.next_owner_mask,
+            },
+            item,
+            transaction_id: Uuid::nil(),
+            crc: 0,
+        }
+    }
+}
+
+impl PacketData for RezObject {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let group_id = read_uuid(&mut cursor)?;
+        let rez_data = RezData {
+            from_task_id: read_uuid(&mut cursor)?,
+            bypass_raycast: cursor.read_u8()? != 0,
+            ray_start: read_vec3(&mut cursor)?,
+            ray_end: read_vec3(&mut cursor)?,
+            ray_target_id: read_uuid(&mut cursor)?,
+            ray_end_is_intersection: cursor.read_u8()? != 0,
+            rez_selected: cursor.read_u8()? != 0,
+            remove_item: cursor.read_u8()? != 0,
+            item_flags: cursor.read_u32::<LittleEndian>()?,
+            group_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            everyone_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            next_owner_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+        };
+        let item_id = read_uuid(&mut cursor)?;
+        let folder_id = read_uuid(&mut cursor)?;
+        let creator_id = read_uuid(&mut cursor)?;
+        let owner_id = read_uuid(&mut cursor)?;
+        let item_group_id = read_uuid(&mut cursor)?;
+        let base_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+        let owner_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+        let group_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+        let everyone_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+        let next_owner_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+        let group_owned = cursor.read_u8()? != 0;
+        let transaction_id = read_uuid(&mut cursor)?;
+        let asset_type = AssetType::from_bytes(cursor.read_i8()?);
+        let inventory_type = cursor.read_i8()?;
+        let flags = cursor.read_u32::<LittleEndian>()?;
+        let sale_type = SaleType::from_bytes(cursor.read_u8()?);
+        let sale_price = cursor.read_i32::<LittleEndian>()?;
+        let name = read_string_1(&mut cursor)?;
+        let description = read_string_1(&mut cursor)?;
+        let creation_date = cursor.read_i32::<LittleEndian>()?;
+        let crc = cursor.read_u32::<LittleEndian>()?;
+        Ok(RezObject {
+            agent_id,
+            session_id,
+            group_id,
+            rez_data,
+            item: InventoryItem {
+                item_id,
+                folder_id,
+                creator_id,
+                owner_id,
+                group_id: item_group_id,
+                base_mask,
+                owner_mask,
+                group_mask,
+                everyone_mask,
+                next_owner_mask,
+                group_owned,
+                // the simulator looks the asset up from the item
+                asset_id: Uuid::nil(),
+                asset_type,
+                inventory_type,
+                flags,
+                sale_type,
+                sale_price,
+                name,
+                description,
+                creation_date,
+            },
+            transaction_id,
+            crc,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(300);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.group_id.as_bytes());
+
+        let rez_data = &self.rez_data;
+        bytes.extend_from_slice(rez_data.from_task_id.as_bytes());
+        bytes.push(rez_data.bypass_raycast as u8);
+        write_vec3(&mut bytes, &rez_data.ray_start);
+        write_vec3(&mut bytes, &rez_data.ray_end);
+        bytes.extend_from_slice(rez_data.ray_target_id.as_bytes());
+        bytes.push(rez_data.ray_end_is_intersection as u8);
+        bytes.push(rez_data.rez_selected as u8);
+        bytes.push(rez_data.remove_item as u8);
+        bytes.extend_from_slice(&rez_data.item_flags.to_le_bytes());
+        bytes.extend_from_slice(&rez_data.group_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&rez_data.everyone_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&rez_data.next_owner_mask.to_bits().to_le_bytes());
+
+        let item = &self.item;
+        bytes.extend_from_slice(item.item_id.as_bytes());
+        bytes.extend_from_slice(item.folder_id.as_bytes());
+        bytes.extend_from_slice(item.creator_id.as_bytes());
+        bytes.extend_from_slice(item.owner_id.as_bytes());
+        bytes.extend_from_slice(item.group_id.as_bytes());
+        for mask in [
+            &item.base_mask,
+            &item.owner_mask,
+            &item.group_mask,
+            &item.everyone_mask,
+            &item.next_owner_mask,
+        ] {
+            bytes.extend_from_slice(&mask.to_bits().to_le_bytes());
+        }
+        bytes.push(item.group_owned as u8);
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.push(item.asset_type.to_bytes() as u8);
+        bytes.push(item.inventory_type as u8);
+        bytes.extend_from_slice(&item.flags.to_le_bytes());
+        bytes.push(item.sale_type.to_bytes());
+        bytes.extend_from_slice(&item.sale_price.to_le_bytes());
+        write_string_1(&mut bytes, &item.name);
+        write_string_1(&mut bytes, &item.description);
+        bytes.extend_from_slice(&item.creation_date.to_le_bytes());
+        bytes.extend_from_slice(&self.crc.to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,124 @@
+use crate::packet_types::PacketType;
+use crate::utils::attachment_point::AttachmentPoint;
+use crate::utils::inventory_item::InventoryItem;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+use crate::utils::permissions::Permissions;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 395
+// Frequency: Low
+
+impl Packet {
+    pub fn new_rez_single_attachment_from_inv(
+        rez_single_attachment_from_inv: RezSingleAttachmentFromInv,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 395,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::RezSingleAttachmentFromInv(Box::new(rez_single_attachment_from_inv)),
+        }
+    }
+}
+
+/// Wears an object from the agent's inventory, attaching it to the avatar. The simulator sends
+/// the attached object back as an ObjectUpdate, with the item's id in its AttachItemID name
+/// value.
+/// https://wiki.secondlife.com/wiki/RezSingleAttachmentFromInv
+#[derive(Debug, Clone, PartialEq)]
+pub struct RezSingleAttachmentFromInv {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub item_id: Uuid,
+    pub owner_id: Uuid,
+    pub attachment_point: AttachmentPoint,
+    /// add the object to the point, instead of replacing what is already attached there
+    pub add: bool,
+    pub item_flags: u32,
+    pub group_mask: Permissions,
+    pub everyone_mask: Permissions,
+    pub next_owner_mask: Permissions,
+    pub name: String,
+    pub description: String,
+}
+
+impl RezSingleAttachmentFromInv {
+    /// wear an item on a point, replacing what is already attached there. The agent and
+    /// session ids are left nil, for the session to fill in.
+    pub fn new(item: &InventoryItem, attachment_point: AttachmentPoint) -> Self {
+        RezSingleAttachmentFromInv {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            item_id: item.item_id,
+            owner_id: item.owner_id,
+            attachment_point,
+            add: false,
+            item_flags: item.flags,
+            group_mask: item.group_mask,
+            everyone_mask: item.everyone_mask,
+            next_owner_mask: item.next_owner_mask,
+            name: item.name.clone(),
+            description: item.description.clone(),
+        }
+    }
+}
+
+impl PacketData for RezSingleAttachmentFromInv {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let item_id = read_uuid(&mut cursor)?;
+        let owner_id = read_uuid(&mut cursor)?;
+        let point = cursor.read_u8()?;
+        Ok(RezSingleAttachmentFromInv {
+            agent_id,
+            session_id,
+            item_id,
+            owner_id,
+            attachment_point: AttachmentPoint::from_bytes(point & !AttachmentPoint::ADD),
+            add: point & AttachmentPoint::ADD != 0,
+            item_flags: cursor.read_u32::<LittleEndian>()?,
+            group_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            everyone_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            next_owner_mask: Permissions::from_bits(cursor.read_u32::<LittleEndian>()?),
+            name: read_string_1(&mut cursor)?,
+            description: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(83 + self.name.len() + self.description.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.item_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        let mut point = self.attachment_point.to_bytes();
+        if self.add {
+            point |= AttachmentPoint::ADD;
+        }
+        bytes.push(point);
+        bytes.extend_from_slice(&self.item_flags.to_le_bytes());
+        bytes.extend_from_slice(&self.group_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.everyone_mask.to_bits().to_le_bytes());
+        bytes.extend_from_slice(&self.next_owner_mask.to_bits().to_le_bytes());
+        write_string_1(&mut bytes, &self.name);
+        write_string_1(&mut bytes, &self.description);
+        bytes
+    }
+}
@@ -0,0 +1,92 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+use crate::utils::script_permissions::ScriptPermissions;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 132
+// Frequency: Low
+
+impl Packet {
+    pub fn new_script_answer_yes(script_answer_yes: ScriptAnswerYes) -> Self {
+        Packet {
+            header: Header {
+                id: 132,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ScriptAnswerYes(Box::new(script_answer_yes)),
+        }
+    }
+}
+
+/// The answer to a ScriptQuestion, with the permissions the agent grants the script.
+/// Despite the name, it is also how a request is denied, by granting no permissions.
+/// https://wiki.secondlife.com/wiki/ScriptAnswerYes
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptAnswerYes {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the object the script is in
+    pub task_id: Uuid,
+    /// the script that asked
+    pub item_id: Uuid,
+    /// the permissions granted
+    pub questions: ScriptPermissions,
+}
+
+impl ScriptAnswerYes {
+    /// the answer granting permissions to a script. The agent and session ids are left nil.
+    pub fn new(task_id: Uuid, item_id: Uuid, questions: ScriptPermissions) -> Self {
+        ScriptAnswerYes {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            task_id,
+            item_id,
+            questions,
+        }
+    }
+
+    /// the answer denying a script everything it asked for. The agent and session ids are left
+    /// nil.
+    pub fn deny(task_id: Uuid, item_id: Uuid) -> Self {
+        Self::new(task_id, item_id, ScriptPermissions::empty())
+    }
+}
+
+impl PacketData for ScriptAnswerYes {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ScriptAnswerYes {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            task_id: read_uuid(&mut cursor)?,
+            item_id: read_uuid(&mut cursor)?,
+            questions: ScriptPermissions::from_bits_retain(
+                cursor.read_i32::<LittleEndian>()? as u32
+            ),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(68);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.task_id.as_bytes());
+        bytes.extend_from_slice(self.item_id.as_bytes());
+        bytes.extend_from_slice(&(self.questions.bits() as i32).to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,80 @@
+use crate::agent_update::ControlFlags;
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+
+// ID: 189
+// Frequency: Low
+
+impl Packet {
+    pub fn new_script_control_change(script_control_change: ScriptControlChange) -> Self {
+        Packet {
+            header: Header {
+                id: 189,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ScriptControlChange(Box::new(script_control_change)),
+        }
+    }
+}
+
+/// Sent by the simulator when a script takes or releases the agent's controls with
+/// llTakeControls or llReleaseControls, like a vehicle the agent sits on. The controls keep being
+/// sent in AgentUpdate as usual, and the script is told about them. Controls that aren't passed
+/// on to the agent don't move the avatar while they are taken.
+/// The packet doesn't say which object the script is in.
+/// https://wiki.secondlife.com/wiki/ScriptControlChange
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptControlChange {
+    pub changes: Vec<ScriptControl>,
+}
+
+/// one change to the agent's controls
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScriptControl {
+    /// true if the controls are taken, false if they are released
+    pub take_controls: bool,
+    pub controls: ControlFlags,
+    /// true if the controls still move the avatar while they are taken
+    pub pass_to_agent: bool,
+}
+
+impl PacketData for ScriptControlChange {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u8()?;
+        let mut changes = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            changes.push(ScriptControl {
+                take_controls: cursor.read_u8()? != 0,
+                controls: ControlFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?),
+                pass_to_agent: cursor.read_u8()? != 0,
+            });
+        }
+        Ok(ScriptControlChange { changes })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let changes = &self.changes[..self.changes.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(1 + changes.len() * 6);
+        bytes.push(changes.len() as u8);
+        for change in changes {
+            bytes.push(change.take_controls as u8);
+            bytes.extend_from_slice(&change.controls.bits().to_le_bytes());
+            bytes.push(change.pass_to_agent as u8);
+        }
+        bytes
+    }
+}
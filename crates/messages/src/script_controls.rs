@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::agent_update::ControlFlags;
+
+/// Sent from the session to the UI when scripts take or release the agent's controls. It has
+/// every object that holds controls after the change, and is sent with no objects once they are
+/// all released. The controls in blocked shouldn't move the avatar in the UI, only be sent on.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScriptControls {
+    pub objects: Vec<CapturedControls>,
+    /// the controls taken by any object that aren't passed on to the agent
+    pub blocked: ControlFlags,
+}
+
+/// the controls one object has taken
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CapturedControls {
+    /// the local id of the object the agent sits on when the controls were taken, or 0 if the
+    /// agent wasn't sitting, since the simulator doesn't say which object took them
+    pub local_id: u32,
+    /// the controls the object has taken
+    pub taken: ControlFlags,
+    /// the taken controls that still move the avatar
+    pub passed_to_agent: ControlFlags,
+}
@@ -0,0 +1,145 @@
+use crate::packet_types::PacketType;
+use crate::script_dialog_reply::ScriptDialogReply;
+use crate::utils::packet_fields::{
+    read_string_1, read_string_2, read_uuid, write_string_1, write_string_2,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 190
+// Frequency: Low
+
+/// the most buttons a dialog can have
+pub const MAX_DIALOG_BUTTONS: usize = 12;
+
+impl Packet {
+    pub fn new_script_dialog(script_dialog: ScriptDialog) -> Self {
+        Packet {
+            header: Header {
+                id: 190,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ScriptDialog(Box::new(script_dialog)),
+        }
+    }
+}
+
+/// A dialog popped up by a script, with a message and up to MAX_DIALOG_BUTTONS buttons.
+/// The button the user picks is sent back to the object with a ScriptDialogReply, which the
+/// script hears as chat from the agent on the dialog's channel.
+/// A dialog without buttons gets a single "OK" button.
+/// https://wiki.secondlife.com/wiki/ScriptDialog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptDialog {
+    /// the object the script is in
+    pub object_id: Uuid,
+    /// the name of the object's owner
+    pub first_name: String,
+    pub last_name: String,
+    pub object_name: String,
+    pub message: String,
+    /// the channel the answer is sent on
+    pub chat_channel: i32,
+    /// an image to show with the dialog, nil for none
+    pub image_id: Uuid,
+    /// the labels of the buttons
+    pub buttons: Vec<String>,
+    /// the owners of the object. Sent by newer simulators only.
+    pub owners: Vec<Uuid>,
+}
+
+impl ScriptDialog {
+    /// the answer for a button of the dialog, by its index. The agent and session ids are left
+    /// nil.
+    pub fn reply(&self, button_index: usize) -> Option<ScriptDialogReply> {
+        self.buttons.get(button_index).map(|label| {
+            ScriptDialogReply::new(
+                self.object_id,
+                self.chat_channel,
+                button_index as i32,
+                label.clone(),
+            )
+        })
+    }
+
+    /// the answer for ignoring the dialog. The agent and session ids are left nil.
+    pub fn ignore(&self) -> ScriptDialogReply {
+        ScriptDialogReply::ignore(self.object_id, self.chat_channel)
+    }
+}
+
+impl PacketData for ScriptDialog {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let object_id = read_uuid(&mut cursor)?;
+        let first_name = read_string_1(&mut cursor)?;
+        let last_name = read_string_1(&mut cursor)?;
+        let object_name = read_string_1(&mut cursor)?;
+        let message = read_string_2(&mut cursor)?;
+        let chat_channel = cursor.read_i32::<LittleEndian>()?;
+        let image_id = read_uuid(&mut cursor)?;
+
+        let button_count = cursor.read_u8()?;
+        let mut buttons = Vec::with_capacity(button_count as usize);
+        for _ in 0..button_count {
+            buttons.push(read_string_1(&mut cursor)?);
+        }
+
+        // the OwnerData block was added later, so older simulators leave it off
+        let mut owners = Vec::new();
+        if (cursor.position() as usize) < bytes.len() {
+            let owner_count = cursor.read_u8()?;
+            for _ in 0..owner_count {
+                owners.push(read_uuid(&mut cursor)?);
+            }
+        }
+
+        Ok(ScriptDialog {
+            object_id,
+            first_name,
+            last_name,
+            object_name,
+            message,
+            chat_channel,
+            image_id,
+            buttons,
+            owners,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let buttons = &self.buttons[..self.buttons.len().min(MAX_DIALOG_BUTTONS)];
+        let owners = &self.owners[..self.owners.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        write_string_1(&mut bytes, &self.first_name);
+        write_string_1(&mut bytes, &self.last_name);
+        write_string_1(&mut bytes, &self.object_name);
+        write_string_2(&mut bytes, &self.message);
+        bytes.extend_from_slice(&self.chat_channel.to_le_bytes());
+        bytes.extend_from_slice(self.image_id.as_bytes());
+        bytes.push(buttons.len() as u8);
+        for button in buttons {
+            write_string_1(&mut bytes, button);
+        }
+        bytes.push(owners.len() as u8);
+        for owner in owners {
+            bytes.extend_from_slice(owner.as_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,115 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 191
+// Frequency: Low
+
+impl Packet {
+    pub fn new_script_dialog_reply(script_dialog_reply: ScriptDialogReply) -> Self {
+        Packet {
+            header: Header {
+                id: 191,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ScriptDialogReply(Box::new(script_dialog_reply)),
+        }
+    }
+}
+
+/// The answer to a ScriptDialog, with the button the user picked.
+/// The simulator says the button's label as the agent on the dialog's chat channel, which is
+/// how the script hears it.
+/// https://wiki.secondlife.com/wiki/ScriptDialogReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptDialogReply {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the object that sent the dialog
+    pub object_id: Uuid,
+    /// the chat channel of the dialog
+    pub chat_channel: i32,
+    /// the index of the button, or IGNORE_BUTTON_INDEX
+    pub button_index: i32,
+    /// the label of the button, which is what the script hears
+    pub button_label: String,
+}
+
+impl ScriptDialogReply {
+    /// the button index of a dialog that was ignored
+    pub const IGNORE_BUTTON_INDEX: i32 = -1;
+    /// the label sent for a dialog that was ignored
+    pub const IGNORE_BUTTON_LABEL: &'static str = "Ignore";
+
+    /// the answer for a button of a dialog. The agent and session ids are left nil.
+    pub fn new(
+        object_id: Uuid,
+        chat_channel: i32,
+        button_index: i32,
+        button_label: String,
+    ) -> Self {
+        ScriptDialogReply {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            object_id,
+            chat_channel,
+            button_index,
+            button_label,
+        }
+    }
+
+    /// the answer for ignoring a dialog, sent on the dialog's channel as a button that isn't on
+    /// the dialog. The agent and session ids are left nil.
+    pub fn ignore(object_id: Uuid, chat_channel: i32) -> Self {
+        Self::new(
+            object_id,
+            chat_channel,
+            Self::IGNORE_BUTTON_INDEX,
+            Self::IGNORE_BUTTON_LABEL.to_string(),
+        )
+    }
+
+    /// true if this is the answer for ignoring a dialog
+    pub fn is_ignore(&self) -> bool {
+        self.button_index == Self::IGNORE_BUTTON_INDEX
+    }
+}
+
+impl PacketData for ScriptDialogReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ScriptDialogReply {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            object_id: read_uuid(&mut cursor)?,
+            chat_channel: cursor.read_i32::<LittleEndian>()?,
+            button_index: cursor.read_i32::<LittleEndian>()?,
+            button_label: read_string_1(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(57 + self.button_label.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(&self.chat_channel.to_le_bytes());
+        bytes.extend_from_slice(&self.button_index.to_le_bytes());
+        write_string_1(&mut bytes, &self.button_label);
+        bytes
+    }
+}
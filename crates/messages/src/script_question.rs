@@ -0,0 +1,101 @@
+use crate::packet_types::PacketType;
+use crate::script_answer_yes::ScriptAnswerYes;
+use crate::utils::packet_fields::{read_string_1_lossy, read_uuid, write_string_1};
+use crate::utils::script_permissions::ScriptPermissions;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 188
+// Frequency: Low
+
+impl Packet {
+    pub fn new_script_question(script_question: ScriptQuestion) -> Self {
+        Packet {
+            header: Header {
+                id: 188,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ScriptQuestion(Box::new(script_question)),
+        }
+    }
+}
+
+/// Sent when a script asks the agent for permissions, like animating its avatar or taking
+/// money. The agent answers with a ScriptAnswerYes holding the permissions it grants, which is
+/// sent even when none are granted.
+/// https://wiki.secondlife.com/wiki/ScriptQuestion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptQuestion {
+    /// the object the script is in
+    pub task_id: Uuid,
+    /// the script, in the object's inventory
+    pub item_id: Uuid,
+    pub object_name: String,
+    /// the name of the object's owner
+    pub object_owner: String,
+    /// the permissions the script asks for
+    pub questions: ScriptPermissions,
+    /// the experience the script runs under. Sent by newer simulators only.
+    pub experience_id: Option<Uuid>,
+}
+
+impl ScriptQuestion {
+    /// the answer granting some of the permissions asked for. Permissions that weren't asked
+    /// for are left out, and granting none denies the request. The agent and session ids are
+    /// left nil.
+    pub fn answer(&self, granted: ScriptPermissions) -> ScriptAnswerYes {
+        ScriptAnswerYes::new(self.task_id, self.item_id, granted & self.questions)
+    }
+}
+
+impl PacketData for ScriptQuestion {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let task_id = read_uuid(&mut cursor)?;
+        let item_id = read_uuid(&mut cursor)?;
+        let object_name = read_string_1_lossy(&mut cursor)?;
+        let object_owner = read_string_1_lossy(&mut cursor)?;
+        let questions =
+            ScriptPermissions::from_bits_retain(cursor.read_i32::<LittleEndian>()? as u32);
+        let experience_id = if (cursor.position() as usize) < bytes.len() {
+            Some(read_uuid(&mut cursor)?)
+        } else {
+            None
+        };
+        Ok(ScriptQuestion {
+            task_id,
+            item_id,
+            object_name,
+            object_owner,
+            questions,
+            experience_id,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.task_id.as_bytes());
+        bytes.extend_from_slice(self.item_id.as_bytes());
+        write_string_1(&mut bytes, &self.object_name);
+        write_string_1(&mut bytes, &self.object_owner);
+        bytes.extend_from_slice(&(self.questions.bits() as i32).to_le_bytes());
+        if let Some(experience_id) = self.experience_id {
+            bytes.extend_from_slice(experience_id.as_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,66 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 244
+// Frequency: Low
+
+impl Packet {
+    pub fn new_script_running_reply(script_running_reply: ScriptRunningReply) -> Self {
+        Packet {
+            header: Header {
+                id: 244,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ScriptRunningReply(Box::new(script_running_reply)),
+        }
+    }
+}
+
+/// The simulator's answer to a GetScriptRunning.
+/// https://wiki.secondlife.com/wiki/ScriptRunningReply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptRunningReply {
+    /// the full id of the object the script is in
+    pub object_id: Uuid,
+    /// the id of the script in the object's contents
+    pub item_id: Uuid,
+    pub running: bool,
+}
+
+impl PacketData for ScriptRunningReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let object_id = read_uuid(&mut cursor)?;
+        let item_id = read_uuid(&mut cursor)?;
+        let running = cursor.read_u8()? != 0;
+        Ok(ScriptRunningReply {
+            object_id,
+            item_id,
+            running,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(33);
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(self.item_id.as_bytes());
+        bytes.push(self.running as u8);
+        bytes
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Sent from the session to the UI when a GetScriptRunning it sent is answered, or the
+/// simulator never answers. This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptRunningStatus {
+    /// the full id of the object the script is in
+    pub object_id: Uuid,
+    /// the id of the script in the object's contents
+    pub item_id: Uuid,
+    /// whether the script is running, None if the simulator didn't answer
+    pub running: Option<bool>,
+    /// human readable reason there is no answer
+    pub reason: Option<String>,
+}
@@ -0,0 +1,81 @@
+use crate::accept_script_teleport::AcceptScriptTeleport;
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_vec3, write_string_1, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 195
+// Frequency: Low
+
+impl Packet {
+    pub fn new_script_teleport_request(script_teleport_request: ScriptTeleportRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 195,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ScriptTeleportRequest(Box::new(script_teleport_request)),
+        }
+    }
+}
+
+/// Sent by the simulator when a script calls llMapDestination, like a landmark giver or a map
+/// kiosk. The destination is offered to the agent, usually by opening the world map on it, and
+/// nothing happens unless the agent accepts it. The region is only given by name.
+/// https://wiki.secondlife.com/wiki/ScriptTeleportRequest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptTeleportRequest {
+    /// the name of the object the script is in
+    pub object_name: String,
+    /// the name of the region to teleport to
+    pub sim_name: String,
+    /// the position in the region, in region coordinates
+    pub sim_position: Vec3,
+    /// the direction the avatar faces on arrival
+    pub look_at: Vec3,
+}
+
+impl ScriptTeleportRequest {
+    /// the command to accept the offer, and teleport to the destination
+    pub fn accept(&self) -> AcceptScriptTeleport {
+        AcceptScriptTeleport {
+            sim_name: self.sim_name.clone(),
+            sim_position: self.sim_position,
+            look_at: self.look_at,
+        }
+    }
+}
+
+impl PacketData for ScriptTeleportRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(ScriptTeleportRequest {
+            object_name: read_string_1(&mut cursor)?,
+            sim_name: read_string_1(&mut cursor)?,
+            sim_position: read_vec3(&mut cursor)?,
+            look_at: read_vec3(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(28 + self.object_name.len() + self.sim_name.len());
+        write_string_1(&mut bytes, &self.object_name);
+        write_string_1(&mut bytes, &self.sim_name);
+        write_vec3(&mut bytes, &self.sim_position);
+        write_vec3(&mut bytes, &self.look_at);
+        bytes
+    }
+}
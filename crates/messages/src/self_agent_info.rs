@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::agent_data_update::AgentDataUpdate;
+
+/// Sent from the session to the UI with what the grid says about the agent, when the UI asks for
+/// it with a QuerySelfAgentInfo. The name comes from the login, and is replaced along with the
+/// group by every AgentDataUpdate, which is asked for after login and after every teleport.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelfAgentInfo {
+    pub agent_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    /// the title the agent has in its active group, shown above the avatar
+    pub group_title: String,
+    /// nil if the agent has no active group
+    pub active_group_id: Uuid,
+    /// what the agent can do in its active group
+    pub group_powers: u64,
+    pub group_name: String,
+}
+
+impl SelfAgentInfo {
+    /// the agent as it is known at login, before the first AgentDataUpdate
+    pub fn new(agent_id: Uuid, first_name: String, last_name: String) -> Self {
+        SelfAgentInfo {
+            agent_id,
+            first_name,
+            last_name,
+            group_title: String::new(),
+            active_group_id: Uuid::nil(),
+            group_powers: 0,
+            group_name: String::new(),
+        }
+    }
+
+    /// take the name and group from an AgentDataUpdate
+    pub fn update(&mut self, update: &AgentDataUpdate) {
+        self.first_name = update.first_name.clone();
+        self.last_name = update.last_name.clone();
+        self.group_title = update.group_title.clone();
+        self.active_group_id = update.active_group_id;
+        self.group_powers = update.group_powers;
+        self.group_name = update.group_name.clone();
+    }
+}
@@ -0,0 +1,94 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_variable_2, write_variable_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+
+// ID: 18
+// Frequency: High
+
+impl Packet {
+    pub fn new_send_xfer_packet(send_xfer_packet: SendXferPacket) -> Self {
+        Packet {
+            header: Header {
+                id: 18,
+                frequency: PacketFrequency::High,
+                // the Xfer system confirms and resends packets itself
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SendXferPacket(Box::new(send_xfer_packet)),
+        }
+    }
+}
+
+/// A chunk of a file being sent with the Xfer system.
+/// The packet id holds the packet number in the low 31 bits, and the high bit is set on the last
+/// packet of the file. The data of the first packet starts with the size of the whole file as a
+/// little endian u32.
+/// https://wiki.secondlife.com/wiki/SendXferPacket
+#[derive(Debug, Clone, PartialEq)]
+pub struct SendXferPacket {
+    pub id: u64,
+    /// the packet number and the last packet flag, as sent on the wire
+    pub packet: u32,
+    pub data: Vec<u8>,
+}
+impl SendXferPacket {
+    /// the high bit of the packet id, set on the last packet of a file
+    pub const LAST_PACKET_FLAG: u32 = 0x8000_0000;
+    /// the most file data sent in one packet
+    pub const CHUNK_SIZE: usize = 1000;
+
+    /// combine a packet number and the last packet flag into a packet id
+    pub fn packet_id(packet_number: u32, last: bool) -> u32 {
+        let packet_number = packet_number & !Self::LAST_PACKET_FLAG;
+        if last {
+            packet_number | Self::LAST_PACKET_FLAG
+        } else {
+            packet_number
+        }
+    }
+
+    /// the packet number, without the last packet flag
+    pub fn packet_number(&self) -> u32 {
+        self.packet & !Self::LAST_PACKET_FLAG
+    }
+
+    /// true if this is the last packet of the file
+    pub fn is_last(&self) -> bool {
+        self.packet & Self::LAST_PACKET_FLAG != 0
+    }
+}
+
+impl PacketData for SendXferPacket {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // XferID
+        let id = cursor.read_u64::<LittleEndian>()?;
+        let packet = cursor.read_u32::<LittleEndian>()?;
+
+        // DataPacket
+        let data = read_variable_2(&mut cursor)?;
+
+        Ok(SendXferPacket { id, packet, data })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(14 + self.data.len());
+        bytes.extend_from_slice(&self.id.to_le_bytes());
+        bytes.extend_from_slice(&self.packet.to_le_bytes());
+        write_variable_2(&mut bytes, &self.data);
+        bytes
+    }
+}
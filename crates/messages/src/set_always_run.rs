@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 88
+// Frequency: Low
+
+impl Packet {
+    pub fn new_set_always_run(set_always_run: SetAlwaysRun) -> Self {
+        Packet {
+            header: Header {
+                id: 88,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SetAlwaysRun(Box::new(set_always_run)),
+        }
+    }
+}
+
+/// Tells the simulator whether the avatar runs instead of walking, without holding down
+/// ControlFlags::FAST_AT in every AgentUpdate.
+/// https://wiki.secondlife.com/wiki/SetAlwaysRun
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetAlwaysRun {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub always_run: bool,
+}
+
+impl SetAlwaysRun {
+    /// turn always run on or off. The agent and session ids are left nil, for the session to
+    /// fill in.
+    pub fn new(always_run: bool) -> Self {
+        SetAlwaysRun {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            always_run,
+        }
+    }
+}
+
+impl PacketData for SetAlwaysRun {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(SetAlwaysRun {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            always_run: cursor.read_u8()? != 0,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(33);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(self.always_run as u8);
+        bytes
+    }
+}
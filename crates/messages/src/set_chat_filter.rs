@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_set_chat_filter(set_chat_filter: SetChatFilter) -> Self {
+        Packet {
+            header: Header {
+                id: 75,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SetChatFilter(Box::new(set_chat_filter)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to pick which nearby chat it is sent. Chat on other channels,
+/// or of a type that is turned off, is dropped by the session before it reaches the UI. Chat
+/// types that aren't listed, like typing or llOwnerSay, are only filtered by their channel.
+/// Debug chat is sent on DEBUG_CHANNEL, and is only sent to the UI if debug is on, whatever the
+/// channels are. The default is everything on channel 0, without debug chat.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetChatFilter {
+    /// the channels to send chat from
+    pub channels: Vec<i32>,
+    pub whisper: bool,
+    pub say: bool,
+    pub shout: bool,
+    /// script errors and debug messages
+    pub debug: bool,
+}
+
+impl Default for SetChatFilter {
+    fn default() -> Self {
+        SetChatFilter {
+            channels: vec![0],
+            whisper: true,
+            say: true,
+            shout: true,
+            debug: false,
+        }
+    }
+}
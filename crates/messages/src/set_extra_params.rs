@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::object_extra_params::{ObjectExtraParams, ObjectExtraParamsData};
+use crate::packet_types::PacketType;
+use crate::utils::extra_params::ExtraParam;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_set_extra_params(set_extra_params: SetExtraParams) -> Self {
+        Packet {
+            header: Header {
+                id: 70,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SetExtraParams(Box::new(set_extra_params)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to set one typed extra param, like flexible, light, sculpt
+/// or mesh, on a list of objects, or to remove the param of that type from them. The session
+/// encodes it and sends ObjectExtraParams.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetExtraParams {
+    /// the local ids of the objects in the region
+    pub local_ids: Vec<u32>,
+    pub param: ExtraParam,
+    /// false removes the param of this type from the objects
+    pub in_use: bool,
+}
+
+impl SetExtraParams {
+    /// encode the param for every object
+    pub fn to_object_extra_params(&self) -> ObjectExtraParams {
+        ObjectExtraParams::new(
+            self.local_ids
+                .iter()
+                .map(|local_id| ObjectExtraParamsData::new(*local_id, &self.param, self.in_use))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,200 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 159
+// Frequency: Low
+
+impl Packet {
+    pub fn new_set_follow_cam_properties(
+        set_follow_cam_properties: SetFollowCamProperties,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 159,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SetFollowCamProperties(Box::new(set_follow_cam_properties)),
+        }
+    }
+}
+
+/// The camera properties a script set with llSetCameraParams, for the viewer to follow instead
+/// of its default camera. Scripts only send the properties they set, in any order, so they are
+/// kept as a list. A later SetFollowCamProperties from the same object changes the properties it
+/// sends, and keeps the rest.
+/// https://wiki.secondlife.com/wiki/SetFollowCamProperties
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetFollowCamProperties {
+    /// the object whose script set the properties
+    pub object_id: Uuid,
+    pub properties: Vec<FollowCamProperty>,
+}
+
+/// one camera property and its value
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FollowCamProperty {
+    pub property_type: FollowCamPropertyType,
+    /// booleans like Active are sent as 0 or 1
+    pub value: f32,
+}
+
+/// Which camera property is being set. The vector properties of llSetCameraParams are sent as
+/// their separate components.
+/// https://wiki.secondlife.com/wiki/LlSetCameraParams
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FollowCamPropertyType {
+    /// the angle of the camera above the avatar, from -45 to 80 degrees
+    Pitch,
+    FocusOffset,
+    FocusOffsetX,
+    FocusOffsetY,
+    FocusOffsetZ,
+    /// how slowly the camera follows the avatar, from 0 to 3 seconds
+    PositionLag,
+    /// how slowly the camera turns to the avatar, from 0 to 3 seconds
+    FocusLag,
+    /// how far behind the avatar the camera stays, from 0.5 to 10 meters
+    Distance,
+    /// how far the camera can swing out from behind the avatar, from 0 to 180 degrees
+    BehindnessAngle,
+    BehindnessLag,
+    /// how far the avatar moves before the camera moves, from 0 to 4 meters
+    PositionThreshold,
+    /// how far the avatar moves before the camera turns, from 0 to 4 meters
+    FocusThreshold,
+    /// 1 to follow the properties, 0 to go back to the default camera
+    Active,
+    Position,
+    PositionX,
+    PositionY,
+    PositionZ,
+    Focus,
+    FocusX,
+    FocusY,
+    FocusZ,
+    /// 1 to keep the camera at Position
+    PositionLocked,
+    /// 1 to keep the camera looking at Focus
+    FocusLocked,
+    Unknown(i32),
+}
+
+impl FollowCamPropertyType {
+    pub fn from_bytes(value: i32) -> Self {
+        match value {
+            0 => FollowCamPropertyType::Pitch,
+            1 => FollowCamPropertyType::FocusOffset,
+            2 => FollowCamPropertyType::FocusOffsetX,
+            3 => FollowCamPropertyType::FocusOffsetY,
+            4 => FollowCamPropertyType::FocusOffsetZ,
+            5 => FollowCamPropertyType::PositionLag,
+            6 => FollowCamPropertyType::FocusLag,
+            7 => FollowCamPropertyType::Distance,
+            8 => FollowCamPropertyType::BehindnessAngle,
+            9 => FollowCamPropertyType::BehindnessLag,
+            10 => FollowCamPropertyType::PositionThreshold,
+            11 => FollowCamPropertyType::FocusThreshold,
+            12 => FollowCamPropertyType::Active,
+            13 => FollowCamPropertyType::Position,
+            14 => FollowCamPropertyType::PositionX,
+            15 => FollowCamPropertyType::PositionY,
+            16 => FollowCamPropertyType::PositionZ,
+            17 => FollowCamPropertyType::Focus,
+            18 => FollowCamPropertyType::FocusX,
+            19 => FollowCamPropertyType::FocusY,
+            20 => FollowCamPropertyType::FocusZ,
+            21 => FollowCamPropertyType::PositionLocked,
+            22 => FollowCamPropertyType::FocusLocked,
+            other => FollowCamPropertyType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> i32 {
+        match self {
+            FollowCamPropertyType::Pitch => 0,
+            FollowCamPropertyType::FocusOffset => 1,
+            FollowCamPropertyType::FocusOffsetX => 2,
+            FollowCamPropertyType::FocusOffsetY => 3,
+            FollowCamPropertyType::FocusOffsetZ => 4,
+            FollowCamPropertyType::PositionLag => 5,
+            FollowCamPropertyType::FocusLag => 6,
+            FollowCamPropertyType::Distance => 7,
+            FollowCamPropertyType::BehindnessAngle => 8,
+            FollowCamPropertyType::BehindnessLag => 9,
+            FollowCamPropertyType::PositionThreshold => 10,
+            FollowCamPropertyType::FocusThreshold => 11,
+            FollowCamPropertyType::Active => 12,
+            FollowCamPropertyType::Position => 13,
+            FollowCamPropertyType::PositionX => 14,
+            FollowCamPropertyType::PositionY => 15,
+            FollowCamPropertyType::PositionZ => 16,
+            FollowCamPropertyType::Focus => 17,
+            FollowCamPropertyType::FocusX => 18,
+            FollowCamPropertyType::FocusY => 19,
+            FollowCamPropertyType::FocusZ => 20,
+            FollowCamPropertyType::PositionLocked => 21,
+            FollowCamPropertyType::FocusLocked => 22,
+            FollowCamPropertyType::Unknown(other) => *other,
+        }
+    }
+}
+
+impl SetFollowCamProperties {
+    /// the value of a property, if the script set it
+    pub fn get(&self, property_type: FollowCamPropertyType) -> Option<f32> {
+        self.properties
+            .iter()
+            .rev()
+            .find(|property| property.property_type == property_type)
+            .map(|property| property.value)
+    }
+}
+
+impl PacketData for SetFollowCamProperties {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let object_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut properties = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            properties.push(FollowCamProperty {
+                property_type: FollowCamPropertyType::from_bytes(
+                    cursor.read_i32::<LittleEndian>()?,
+                ),
+                value: cursor.read_f32::<LittleEndian>()?,
+            });
+        }
+        Ok(SetFollowCamProperties {
+            object_id,
+            properties,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let properties = &self.properties[..self.properties.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(17 + properties.len() * 8);
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.push(properties.len() as u8);
+        for property in properties {
+            bytes.extend_from_slice(&property.property_type.to_bytes().to_le_bytes());
+            bytes.extend_from_slice(&property.value.to_le_bytes());
+        }
+        bytes
+    }
+}
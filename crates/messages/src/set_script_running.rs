@@ -0,0 +1,87 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 245
+// Frequency: Low
+
+impl Packet {
+    pub fn new_set_script_running(set_script_running: SetScriptRunning) -> Self {
+        Packet {
+            header: Header {
+                id: 245,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SetScriptRunning(Box::new(set_script_running)),
+        }
+    }
+}
+
+/// Starts or stops a script in an object. The agent needs to be able to modify the object.
+/// https://wiki.secondlife.com/wiki/SetScriptRunning
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetScriptRunning {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the full id of the object the script is in
+    pub object_id: Uuid,
+    /// the id of the script in the object's contents
+    pub item_id: Uuid,
+    pub running: bool,
+}
+
+impl SetScriptRunning {
+    /// start or stop a script. The agent and session ids are left nil, for the session to fill
+    /// in.
+    pub fn new(object_id: Uuid, item_id: Uuid, running: bool) -> Self {
+        SetScriptRunning {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            object_id,
+            item_id,
+            running,
+        }
+    }
+}
+
+impl PacketData for SetScriptRunning {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let object_id = read_uuid(&mut cursor)?;
+        let item_id = read_uuid(&mut cursor)?;
+        let running = cursor.read_u8()? != 0;
+        Ok(SetScriptRunning {
+            agent_id,
+            session_id,
+            object_id,
+            item_id,
+            running,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(65);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(self.item_id.as_bytes());
+        bytes.push(self.running as u8);
+        bytes
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+};
+
+impl Packet {
+    pub fn new_set_velocity_interpolation(
+        set_velocity_interpolation: SetVelocityInterpolation,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 76,
+                frequency: PacketFrequency::Fixed,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SetVelocityInterpolation(Box::new(set_velocity_interpolation)),
+        }
+    }
+}
+
+/// Sent from the UI to the session to turn the simulators' interpolation of moving objects on or
+/// off. The session remembers it, and tells every simulator it connects to afterwards. It
+/// answers with a VelocityInterpolationStatus.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetVelocityInterpolation {
+    pub enabled: bool,
+}
@@ -0,0 +1,285 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 140
+// Frequency: Low
+
+impl Packet {
+    pub fn new_sim_stats(sim_stats: SimStats) -> Self {
+        Packet {
+            header: Header {
+                id: 140,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SimStats(Box::new(sim_stats)),
+        }
+    }
+}
+
+/// Sent by the simulator about once a second, with the statistics of the region, like its time
+/// dilation, frame rates and the number of agents in it. Simulators only send the statistics
+/// they keep, in any order, so they are kept as a list.
+/// https://wiki.secondlife.com/wiki/SimStats
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimStats {
+    /// the position of the region on the grid, in regions
+    pub region_x: u32,
+    pub region_y: u32,
+    pub region_flags: u32,
+    /// how many prims the region can hold
+    pub object_capacity: u32,
+    pub stats: Vec<SimStat>,
+    /// the process id of the simulator
+    pub pid: i32,
+    /// the region flags as 64 bits. Older simulators don't send them.
+    pub region_flags_extended: Vec<u64>,
+}
+
+/// one statistic and its value
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SimStat {
+    pub stat: SimStatType,
+    pub value: f32,
+}
+
+/// Which statistic a value is. Times are per frame unless they say otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SimStatType {
+    /// how much slower than real time the simulator runs, from 0 to 1
+    TimeDilation,
+    /// simulator frames per second
+    SimFps,
+    /// physics frames per second
+    PhysicsFps,
+    AgentUpdatesPerSecond,
+    /// milliseconds spent on each frame
+    FrameMs,
+    NetMs,
+    OtherMs,
+    PhysicsMs,
+    AgentMs,
+    ImagesMs,
+    ScriptMs,
+    /// the number of prims in the region
+    TotalPrims,
+    /// the number of prims moved by physics or scripts
+    ActivePrims,
+    /// the number of avatars in the region
+    Agents,
+    /// the number of avatars in neighbouring regions that can see into this one
+    ChildAgents,
+    ActiveScripts,
+    ScriptInstructionsPerSecond,
+    InPacketsPerSecond,
+    OutPacketsPerSecond,
+    PendingDownloads,
+    PendingUploads,
+    VirtualSizeKb,
+    ResidentSizeKb,
+    PendingLocalUploads,
+    UnackedBytes,
+    PhysicsPinnedTasks,
+    PhysicsLodTasks,
+    PhysicsStepMs,
+    PhysicsShapeMs,
+    PhysicsOtherMs,
+    PhysicsMemory,
+    ScriptEventsPerSecond,
+    /// milliseconds of each frame left over
+    SpareMs,
+    SleepMs,
+    IoPumpMs,
+    /// the percentage of scripts that ran each frame
+    ScriptsRunPercent,
+    /// 1 if the region is idle because no avatars are in it
+    RegionIdle,
+    RegionIdlePossible,
+    AiStepMs,
+    SkippedAiStepsPerSecond,
+    SteppedCharactersPercent,
+    Unknown(u32),
+}
+
+impl SimStatType {
+    pub fn from_bytes(value: u32) -> Self {
+        match value {
+            0 => SimStatType::TimeDilation,
+            1 => SimStatType::SimFps,
+            2 => SimStatType::PhysicsFps,
+            3 => SimStatType::AgentUpdatesPerSecond,
+            4 => SimStatType::FrameMs,
+            5 => SimStatType::NetMs,
+            6 => SimStatType::OtherMs,
+            7 => SimStatType::PhysicsMs,
+            8 => SimStatType::AgentMs,
+            9 => SimStatType::ImagesMs,
+            10 => SimStatType::ScriptMs,
+            11 => SimStatType::TotalPrims,
+            12 => SimStatType::ActivePrims,
+            13 => SimStatType::Agents,
+            14 => SimStatType::ChildAgents,
+            15 => SimStatType::ActiveScripts,
+            16 => SimStatType::ScriptInstructionsPerSecond,
+            17 => SimStatType::InPacketsPerSecond,
+            18 => SimStatType::OutPacketsPerSecond,
+            19 => SimStatType::PendingDownloads,
+            20 => SimStatType::PendingUploads,
+            21 => SimStatType::VirtualSizeKb,
+            22 => SimStatType::ResidentSizeKb,
+            23 => SimStatType::PendingLocalUploads,
+            24 => SimStatType::UnackedBytes,
+            25 => SimStatType::PhysicsPinnedTasks,
+            26 => SimStatType::PhysicsLodTasks,
+            27 => SimStatType::PhysicsStepMs,
+            28 => SimStatType::PhysicsShapeMs,
+            29 => SimStatType::PhysicsOtherMs,
+            30 => SimStatType::PhysicsMemory,
+            31 => SimStatType::ScriptEventsPerSecond,
+            32 => SimStatType::SpareMs,
+            33 => SimStatType::SleepMs,
+            34 => SimStatType::IoPumpMs,
+            35 => SimStatType::ScriptsRunPercent,
+            36 => SimStatType::RegionIdle,
+            37 => SimStatType::RegionIdlePossible,
+            38 => SimStatType::AiStepMs,
+            39 => SimStatType::SkippedAiStepsPerSecond,
+            40 => SimStatType::SteppedCharactersPercent,
+            other => SimStatType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u32 {
+        match self {
+            SimStatType::TimeDilation => 0,
+            SimStatType::SimFps => 1,
+            SimStatType::PhysicsFps => 2,
+            SimStatType::AgentUpdatesPerSecond => 3,
+            SimStatType::FrameMs => 4,
+            SimStatType::NetMs => 5,
+            SimStatType::OtherMs => 6,
+            SimStatType::PhysicsMs => 7,
+            SimStatType::AgentMs => 8,
+            SimStatType::ImagesMs => 9,
+            SimStatType::ScriptMs => 10,
+            SimStatType::TotalPrims => 11,
+            SimStatType::ActivePrims => 12,
+            SimStatType::Agents => 13,
+            SimStatType::ChildAgents => 14,
+            SimStatType::ActiveScripts => 15,
+            SimStatType::ScriptInstructionsPerSecond => 16,
+            SimStatType::InPacketsPerSecond => 17,
+            SimStatType::OutPacketsPerSecond => 18,
+            SimStatType::PendingDownloads => 19,
+            SimStatType::PendingUploads => 20,
+            SimStatType::VirtualSizeKb => 21,
+            SimStatType::ResidentSizeKb => 22,
+            SimStatType::PendingLocalUploads => 23,
+            SimStatType::UnackedBytes => 24,
+            SimStatType::PhysicsPinnedTasks => 25,
+            SimStatType::PhysicsLodTasks => 26,
+            SimStatType::PhysicsStepMs => 27,
+            SimStatType::PhysicsShapeMs => 28,
+            SimStatType::PhysicsOtherMs => 29,
+            SimStatType::PhysicsMemory => 30,
+            SimStatType::ScriptEventsPerSecond => 31,
+            SimStatType::SpareMs => 32,
+            SimStatType::SleepMs => 33,
+            SimStatType::IoPumpMs => 34,
+            SimStatType::ScriptsRunPercent => 35,
+            SimStatType::RegionIdle => 36,
+            SimStatType::RegionIdlePossible => 37,
+            SimStatType::AiStepMs => 38,
+            SimStatType::SkippedAiStepsPerSecond => 39,
+            SimStatType::SteppedCharactersPercent => 40,
+            SimStatType::Unknown(other) => *other,
+        }
+    }
+}
+
+impl SimStats {
+    /// the value of a statistic, if the simulator sent it
+    pub fn get(&self, stat: SimStatType) -> Option<f32> {
+        self.stats
+            .iter()
+            .find(|sim_stat| sim_stat.stat == stat)
+            .map(|sim_stat| sim_stat.value)
+    }
+}
+
+impl PacketData for SimStats {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let region_x = cursor.read_u32::<LittleEndian>()?;
+        let region_y = cursor.read_u32::<LittleEndian>()?;
+        let region_flags = cursor.read_u32::<LittleEndian>()?;
+        let object_capacity = cursor.read_u32::<LittleEndian>()?;
+
+        let count = cursor.read_u8()?;
+        let mut stats = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            stats.push(SimStat {
+                stat: SimStatType::from_bytes(cursor.read_u32::<LittleEndian>()?),
+                value: cursor.read_f32::<LittleEndian>()?,
+            });
+        }
+
+        let pid = cursor.read_i32::<LittleEndian>()?;
+
+        // RegionInfo is a variable block that older simulators don't send at all
+        let count = if (cursor.position() as usize) < bytes.len() {
+            cursor.read_u8()?
+        } else {
+            0
+        };
+        let mut region_flags_extended = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            region_flags_extended.push(cursor.read_u64::<LittleEndian>()?);
+        }
+
+        Ok(SimStats {
+            region_x,
+            region_y,
+            region_flags,
+            object_capacity,
+            stats,
+            pid,
+            region_flags_extended,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let stats = &self.stats[..self.stats.len().min(u8::MAX as usize)];
+        let flags =
+            &self.region_flags_extended[..self.region_flags_extended.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(22 + stats.len() * 8 + flags.len() * 8);
+        bytes.extend_from_slice(&self.region_x.to_le_bytes());
+        bytes.extend_from_slice(&self.region_y.to_le_bytes());
+        bytes.extend_from_slice(&self.region_flags.to_le_bytes());
+        bytes.extend_from_slice(&self.object_capacity.to_le_bytes());
+        bytes.push(stats.len() as u8);
+        for sim_stat in stats {
+            bytes.extend_from_slice(&sim_stat.stat.to_bytes().to_le_bytes());
+            bytes.extend_from_slice(&sim_stat.value.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.pid.to_le_bytes());
+        bytes.push(flags.len() as u8);
+        for flag in flags {
+            bytes.extend_from_slice(&flag.to_le_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,80 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_vec3, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 150
+// Frequency: Low
+
+impl Packet {
+    pub fn new_simulator_viewer_time_message(
+        simulator_viewer_time_message: SimulatorViewerTimeMessage,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 150,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SimulatorViewerTimeMessage(Box::new(simulator_viewer_time_message)),
+        }
+    }
+}
+
+/// Sent by the simulator after the agent arrives in a region, and every so often after that,
+/// with the time of day in the region and where its sun is. Between these, the sun keeps turning
+/// at the angular velocity.
+/// https://wiki.secondlife.com/wiki/SimulatorViewerTimeMessage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulatorViewerTimeMessage {
+    /// microseconds since the simulator started
+    pub usec_since_start: u64,
+    /// how many seconds a day lasts in the region
+    pub sec_per_day: u32,
+    /// how many seconds a year lasts in the region
+    pub sec_per_year: u32,
+    /// the unit vector from the region to the sun
+    pub sun_direction: Vec3,
+    /// how far through the day the sun is, in radians
+    pub sun_phase: f32,
+    /// the axis the sun turns around, scaled by how fast it turns, in radians per second
+    pub sun_ang_velocity: Vec3,
+}
+
+impl PacketData for SimulatorViewerTimeMessage {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(SimulatorViewerTimeMessage {
+            usec_since_start: cursor.read_u64::<LittleEndian>()?,
+            sec_per_day: cursor.read_u32::<LittleEndian>()?,
+            sec_per_year: cursor.read_u32::<LittleEndian>()?,
+            sun_direction: read_vec3(&mut cursor)?,
+            sun_phase: cursor.read_f32::<LittleEndian>()?,
+            sun_ang_velocity: read_vec3(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(44);
+        bytes.extend_from_slice(&self.usec_since_start.to_le_bytes());
+        bytes.extend_from_slice(&self.sec_per_day.to_le_bytes());
+        bytes.extend_from_slice(&self.sec_per_year.to_le_bytes());
+        write_vec3(&mut bytes, &self.sun_direction);
+        bytes.extend_from_slice(&self.sun_phase.to_le_bytes());
+        write_vec3(&mut bytes, &self.sun_ang_velocity);
+        bytes
+    }
+}
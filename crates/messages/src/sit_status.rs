@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::avatar_sit_response::AvatarSitResponse;
+
+/// Sent from the session to the UI when the agent sits down, fails to sit, or stands up.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SitStatus {
+    /// the agent is sitting, where the simulator put it
+    Seated(AvatarSitResponse),
+    /// the agent couldn't sit on the object
+    Failed {
+        /// the object the UI asked to sit on
+        object_id: Uuid,
+        /// human readable reason sitting failed
+        reason: String,
+    },
+    /// the agent stood up
+    Standing,
+}
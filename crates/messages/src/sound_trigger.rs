@@ -0,0 +1,82 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_vec3, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 29
+// Frequency: High
+
+impl Packet {
+    pub fn new_sound_trigger(sound_trigger: SoundTrigger) -> Self {
+        Packet {
+            header: Header {
+                id: 29,
+                frequency: PacketFrequency::High,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::SoundTrigger(Box::new(sound_trigger)),
+        }
+    }
+}
+
+/// Plays a sound once at a position, like a sound triggered by a script or a collision.
+/// The sound is an asset, which the UI downloads to play. The position is in the region given by
+/// the handle, which can be a neighbouring region.
+/// https://wiki.secondlife.com/wiki/SoundTrigger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoundTrigger {
+    /// the sound asset
+    pub sound_id: Uuid,
+    pub owner_id: Uuid,
+    /// the object that played the sound
+    pub object_id: Uuid,
+    /// the root of the object's linkset, nil if it isn't linked
+    pub parent_id: Uuid,
+    /// the region the sound is played in
+    pub handle: u64,
+    /// where the sound is played, in region coordinates
+    pub position: Vec3,
+    /// the volume, from 0 to 1
+    pub gain: f32,
+}
+
+impl PacketData for SoundTrigger {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(SoundTrigger {
+            sound_id: read_uuid(&mut cursor)?,
+            owner_id: read_uuid(&mut cursor)?,
+            object_id: read_uuid(&mut cursor)?,
+            parent_id: read_uuid(&mut cursor)?,
+            handle: cursor.read_u64::<LittleEndian>()?,
+            position: read_vec3(&mut cursor)?,
+            gain: cursor.read_f32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(88);
+        bytes.extend_from_slice(self.sound_id.as_bytes());
+        bytes.extend_from_slice(self.owner_id.as_bytes());
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        bytes.extend_from_slice(self.parent_id.as_bytes());
+        bytes.extend_from_slice(&self.handle.to_le_bytes());
+        write_vec3(&mut bytes, &self.position);
+        bytes.extend_from_slice(&self.gain.to_le_bytes());
+        bytes
+    }
+}
@@ -3,6 +3,11 @@ use super::{
     packet::{Packet, PacketData},
 };
 use crate::packet_types::PacketType;
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::Cursor;
+
+// ID: 1
+// Frequency: High
 
 impl Packet {
     pub fn new_start_ping_check(start_ping_check: StartPingCheck) -> Self {
@@ -23,6 +28,8 @@ impl Packet {
     }
 }
 
+/// Sent by both the simulator and the viewer to measure latency. The receiver replies with a
+/// CompletePingCheck containing the same ping id.
 #[derive(Debug, Clone)]
 pub struct StartPingCheck {
     pub ping_id: u8,
@@ -31,8 +38,9 @@ pub struct StartPingCheck {
 
 impl PacketData for StartPingCheck {
     fn from_bytes(bytes: &[u8]) -> std::io::Result<Self> {
-        let ping_id = bytes[0];
-        let oldest_unacked = u32::from_le_bytes(bytes[1..5].try_into().unwrap());
+        let mut cursor = Cursor::new(bytes);
+        let ping_id = cursor.read_u8()?;
+        let oldest_unacked = cursor.read_u32::<LittleEndian>()?;
 
         Ok(StartPingCheck {
             ping_id,
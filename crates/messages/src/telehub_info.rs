@@ -0,0 +1,88 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{
+    read_quat, read_string_1, read_uuid, read_vec3, write_quat, write_string_1, write_vec3,
+};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use glam::{Quat, Vec3};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 10
+// Frequency: Low
+
+impl Packet {
+    pub fn new_telehub_info(telehub_info: TelehubInfo) -> Self {
+        Packet {
+            header: Header {
+                id: 10,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TelehubInfo(Box::new(telehub_info)),
+        }
+    }
+}
+
+/// The telehub of the region and its spawn points, sent in answer to the telehub estate
+/// commands. A region without a telehub has a nil object id and no spawn points.
+/// https://wiki.secondlife.com/wiki/TelehubInfo
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TelehubInfo {
+    /// the object the telehub is connected to
+    pub object_id: Uuid,
+    pub object_name: String,
+    /// where the telehub is, in region coordinates
+    pub telehub_pos: Vec3,
+    pub telehub_rot: Quat,
+    /// where arriving avatars are placed, as offsets from the telehub. A spawn point is removed
+    /// by its index in this list.
+    pub spawn_points: Vec<Vec3>,
+}
+
+impl PacketData for TelehubInfo {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let object_id = read_uuid(&mut cursor)?;
+        let object_name = read_string_1(&mut cursor)?;
+        let telehub_pos = read_vec3(&mut cursor)?;
+        let telehub_rot = read_quat(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut spawn_points = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            spawn_points.push(read_vec3(&mut cursor)?);
+        }
+        Ok(TelehubInfo {
+            object_id,
+            object_name,
+            telehub_pos,
+            telehub_rot,
+            spawn_points,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let spawn_points = &self.spawn_points[..self.spawn_points.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(42 + self.object_name.len() + spawn_points.len() * 12);
+        bytes.extend_from_slice(self.object_id.as_bytes());
+        write_string_1(&mut bytes, &self.object_name);
+        write_vec3(&mut bytes, &self.telehub_pos);
+        write_quat(&mut bytes, &self.telehub_rot);
+        bytes.push(spawn_points.len() as u8);
+        for spawn_point in spawn_points {
+            write_vec3(&mut bytes, spawn_point);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,93 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 74
+// Frequency: Low
+
+impl Packet {
+    pub fn new_teleport_failed(teleport_failed: TeleportFailed) -> Self {
+        Packet {
+            header: Header {
+                id: 74,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TeleportFailed(Box::new(teleport_failed)),
+        }
+    }
+}
+
+/// Sent by the simulator when a teleport could not be completed. The agent stays in the
+/// current region.
+/// https://wiki.secondlife.com/wiki/TeleportFailed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeleportFailed {
+    pub agent_id: Uuid,
+    /// human readable reason the teleport failed
+    pub reason: String,
+    pub alert_info: Vec<AlertInfo>,
+}
+
+/// extra information for the viewer to build a localized alert
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertInfo {
+    pub message: String,
+    pub extra_params: String,
+}
+
+impl PacketData for TeleportFailed {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // Info
+        let agent_id = read_uuid(&mut cursor)?;
+        let reason = read_string_1(&mut cursor)?;
+
+        // AlertInfo is a variable block. Older simulators don't send it at all.
+        let count = if (cursor.position() as usize) < bytes.len() {
+            cursor.read_u8()?
+        } else {
+            0
+        };
+        let mut alert_info = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            alert_info.push(AlertInfo {
+                message: read_string_1(&mut cursor)?,
+                extra_params: read_string_1(&mut cursor)?,
+            });
+        }
+
+        Ok(TeleportFailed {
+            agent_id,
+            reason,
+            alert_info,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        write_string_1(&mut bytes, &self.reason);
+        bytes.push(self.alert_info.len() as u8);
+        for alert_info in &self.alert_info {
+            write_string_1(&mut bytes, &alert_info.message);
+            write_string_1(&mut bytes, &alert_info.extra_params);
+        }
+        bytes
+    }
+}
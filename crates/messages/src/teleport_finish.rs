@@ -0,0 +1,90 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_2, read_uuid, write_string_2};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use std::net::Ipv4Addr;
+use uuid::Uuid;
+
+// ID: 69
+// Frequency: Low
+
+impl Packet {
+    pub fn new_teleport_finish(teleport_finish: TeleportFinish) -> Self {
+        Packet {
+            header: Header {
+                id: 69,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TeleportFinish(Box::new(teleport_finish)),
+        }
+    }
+}
+
+/// Sent by the simulator when a teleport succeeded. The agent is now in the destination region,
+/// and the client needs to open a circuit to the destination simulator.
+/// https://wiki.secondlife.com/wiki/TeleportFinish
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeleportFinish {
+    pub agent_id: Uuid,
+    pub location_id: u32,
+    /// address of the destination simulator
+    pub sim_ip: Ipv4Addr,
+    pub sim_port: u16,
+    pub region_handle: u64,
+    /// capabilities url of the destination region
+    pub seed_capability: String,
+    pub sim_access: u8,
+    pub teleport_flags: u32,
+}
+
+impl PacketData for TeleportFinish {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let location_id = cursor.read_u32::<LittleEndian>()?;
+        // IP addresses and ports are sent in network byte order
+        let sim_ip = Ipv4Addr::from(cursor.read_u32::<BigEndian>()?);
+        let sim_port = cursor.read_u16::<BigEndian>()?;
+        let region_handle = cursor.read_u64::<LittleEndian>()?;
+        let seed_capability = read_string_2(&mut cursor)?;
+        let sim_access = cursor.read_u8()?;
+        let teleport_flags = cursor.read_u32::<LittleEndian>()?;
+
+        Ok(TeleportFinish {
+            agent_id,
+            location_id,
+            sim_ip,
+            sim_port,
+            region_handle,
+            seed_capability,
+            sim_access,
+            teleport_flags,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(41 + self.seed_capability.len() + 1);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(&self.location_id.to_le_bytes());
+        bytes.extend_from_slice(&self.sim_ip.octets());
+        bytes.extend_from_slice(&self.sim_port.to_be_bytes());
+        bytes.extend_from_slice(&self.region_handle.to_le_bytes());
+        write_string_2(&mut bytes, &self.seed_capability);
+        bytes.push(self.sim_access);
+        bytes.extend_from_slice(&self.teleport_flags.to_le_bytes());
+        bytes
+    }
+}
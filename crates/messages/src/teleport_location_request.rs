@@ -0,0 +1,84 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_vec3, write_vec3};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 63
+// Frequency: Low
+
+impl Packet {
+    pub fn new_teleport_location_request(
+        teleport_location_request: TeleportLocationRequest,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 63,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TeleportLocationRequest(Box::new(teleport_location_request)),
+        }
+    }
+}
+
+/// Asks the simulator to teleport the agent to a position in a region.
+/// The simulator answers with TeleportStart and TeleportProgress, and then either TeleportFinish
+/// or TeleportFailed.
+/// https://wiki.secondlife.com/wiki/TeleportLocationRequest
+#[derive(Debug, Clone)]
+pub struct TeleportLocationRequest {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// handle of the destination region
+    pub region_handle: u64,
+    /// position in region coordinates
+    pub position: Vec3,
+    /// the direction the avatar faces on arrival
+    pub look_at: Vec3,
+}
+
+impl PacketData for TeleportLocationRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // AgentData
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+
+        // Info
+        let region_handle = cursor.read_u64::<LittleEndian>()?;
+        let position = read_vec3(&mut cursor)?;
+        let look_at = read_vec3(&mut cursor)?;
+
+        Ok(TeleportLocationRequest {
+            agent_id,
+            session_id,
+            region_handle,
+            position,
+            look_at,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(64);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(&self.region_handle.to_le_bytes());
+        write_vec3(&mut bytes, &self.position);
+        write_vec3(&mut bytes, &self.look_at);
+        bytes
+    }
+}
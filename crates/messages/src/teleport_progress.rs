@@ -0,0 +1,65 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 66
+// Frequency: Low
+
+impl Packet {
+    pub fn new_teleport_progress(teleport_progress: TeleportProgress) -> Self {
+        Packet {
+            header: Header {
+                id: 66,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TeleportProgress(Box::new(teleport_progress)),
+        }
+    }
+}
+
+/// Status updates sent by the simulator while a teleport is in progress.
+/// https://wiki.secondlife.com/wiki/TeleportProgress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeleportProgress {
+    pub agent_id: Uuid,
+    pub teleport_flags: u32,
+    /// human readable status, like "sending_dest"
+    pub message: String,
+}
+
+impl PacketData for TeleportProgress {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let teleport_flags = cursor.read_u32::<LittleEndian>()?;
+        let message = read_string_1(&mut cursor)?;
+        Ok(TeleportProgress {
+            agent_id,
+            teleport_flags,
+            message,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(21 + self.message.len() + 1);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(&self.teleport_flags.to_le_bytes());
+        write_string_1(&mut bytes, &self.message);
+        bytes
+    }
+}
@@ -0,0 +1,51 @@
+use crate::packet_types::PacketType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+
+// ID: 73
+// Frequency: Low
+
+impl Packet {
+    pub fn new_teleport_start(teleport_start: TeleportStart) -> Self {
+        Packet {
+            header: Header {
+                id: 73,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TeleportStart(Box::new(teleport_start)),
+        }
+    }
+}
+
+/// Sent by the simulator when a teleport has begun.
+/// https://wiki.secondlife.com/wiki/TeleportStart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeleportStart {
+    pub teleport_flags: u32,
+}
+
+impl PacketData for TeleportStart {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(TeleportStart {
+            teleport_flags: cursor.read_u32::<LittleEndian>()?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.teleport_flags.to_le_bytes().to_vec()
+    }
+}
@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 300
+// Frequency: Low
+
+impl Packet {
+    pub fn new_terminate_friendship(terminate_friendship: TerminateFriendship) -> Self {
+        Packet {
+            header: Header {
+                id: 300,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TerminateFriendship(Box::new(terminate_friendship)),
+        }
+    }
+}
+
+/// Ends a friendship. Sent by the agent to end one, and by the simulator to the other agent when
+/// it has ended.
+/// https://wiki.secondlife.com/wiki/TerminateFriendship
+#[derive(Debug, Clone, PartialEq)]
+pub struct TerminateFriendship {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    /// the friend the friendship is with
+    pub other_id: Uuid,
+}
+
+impl TerminateFriendship {
+    /// end the friendship with another agent. The agent and session ids are left nil, for the
+    /// session to fill in.
+    pub fn new(other_id: Uuid) -> Self {
+        TerminateFriendship {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            other_id,
+        }
+    }
+}
+
+impl PacketData for TerminateFriendship {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(TerminateFriendship {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            other_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(48);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.other_id.as_bytes());
+        bytes
+    }
+}
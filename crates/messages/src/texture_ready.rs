@@ -0,0 +1,41 @@
+use crate::image_data::ImageCodec;
+use crate::packet::PacketData;
+use crate::utils::packet_fields::read_uuid;
+
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+/// A texture that has finished downloading, sent from the session to the UI.
+/// Textures are large, so this is sent in binary instead of JSON. The data is the whole image,
+/// which is JPEG2000 for textures from the simulator.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureReady {
+    pub texture_id: Uuid,
+    pub codec: ImageCodec,
+    pub data: Vec<u8>,
+}
+
+impl PacketData for TextureReady {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let texture_id = read_uuid(&mut cursor)?;
+        let codec = ImageCodec::from_bytes(cursor.read_u8()?);
+        let mut data = Vec::new();
+        cursor.read_to_end(&mut data)?;
+        Ok(TextureReady {
+            texture_id,
+            codec,
+            data,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(17 + self.data.len());
+        bytes.extend_from_slice(self.texture_id.as_bytes());
+        bytes.push(self.codec.to_bytes());
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+}
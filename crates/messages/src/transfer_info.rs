@@ -0,0 +1,88 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_variable_2, write_variable_2};
+use crate::utils::transfer::{AssetTransferParams, ChannelType, TargetType, TransferStatus};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 154
+// Frequency: Low
+
+impl Packet {
+    pub fn new_transfer_info(transfer_info: TransferInfo) -> Self {
+        Packet {
+            header: Header {
+                id: 154,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TransferInfo(Box::new(transfer_info)),
+        }
+    }
+}
+
+/// Sent by the simulator in answer to a TransferRequest. If the status is Ok, the data follows
+/// in TransferPackets. Otherwise the transfer has failed.
+/// https://wiki.secondlife.com/wiki/TransferInfo
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferInfo {
+    pub transfer_id: Uuid,
+    pub channel_type: ChannelType,
+    pub target_type: TargetType,
+    pub status: TransferStatus,
+    /// size of the whole transfer in bytes
+    pub size: i32,
+    /// the params of the request, echoed back
+    pub params: Vec<u8>,
+}
+impl TransferInfo {
+    /// the params of a transfer of an asset
+    pub fn asset_params(&self) -> io::Result<AssetTransferParams> {
+        AssetTransferParams::from_bytes(&self.params)
+    }
+}
+
+impl PacketData for TransferInfo {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // TransferInfo
+        let transfer_id = read_uuid(&mut cursor)?;
+        let channel_type = ChannelType::from_bytes(cursor.read_i32::<LittleEndian>()?);
+        let target_type = TargetType::from_bytes(cursor.read_i32::<LittleEndian>()?);
+        let status = TransferStatus::from_bytes(cursor.read_i32::<LittleEndian>()?);
+        let size = cursor.read_i32::<LittleEndian>()?;
+        let params = read_variable_2(&mut cursor)?;
+
+        Ok(TransferInfo {
+            transfer_id,
+            channel_type,
+            target_type,
+            status,
+            size,
+            params,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(34 + self.params.len());
+        bytes.extend_from_slice(self.transfer_id.as_bytes());
+        bytes.extend_from_slice(&self.channel_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.target_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.status.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.size.to_le_bytes());
+        write_variable_2(&mut bytes, &self.params);
+        bytes
+    }
+}
@@ -0,0 +1,80 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_variable_2, write_variable_2};
+use crate::utils::transfer::{ChannelType, TransferStatus};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 17
+// Frequency: High
+
+impl Packet {
+    pub fn new_transfer_packet(transfer_packet: TransferPacket) -> Self {
+        Packet {
+            header: Header {
+                id: 17,
+                frequency: PacketFrequency::High,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TransferPacket(Box::new(transfer_packet)),
+        }
+    }
+}
+
+/// A chunk of the data of a transfer. Packets are numbered from 0, and the last one has the
+/// Done status.
+/// https://wiki.secondlife.com/wiki/TransferPacket
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferPacket {
+    pub transfer_id: Uuid,
+    pub channel_type: ChannelType,
+    pub packet: i32,
+    pub status: TransferStatus,
+    pub data: Vec<u8>,
+}
+impl TransferPacket {
+    /// the most data the simulator puts in a TransferPacket
+    pub const PACKET_SIZE: usize = 1000;
+}
+
+impl PacketData for TransferPacket {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // TransferData
+        let transfer_id = read_uuid(&mut cursor)?;
+        let channel_type = ChannelType::from_bytes(cursor.read_i32::<LittleEndian>()?);
+        let packet = cursor.read_i32::<LittleEndian>()?;
+        let status = TransferStatus::from_bytes(cursor.read_i32::<LittleEndian>()?);
+        let data = read_variable_2(&mut cursor)?;
+
+        Ok(TransferPacket {
+            transfer_id,
+            channel_type,
+            packet,
+            status,
+            data,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(30 + self.data.len());
+        bytes.extend_from_slice(self.transfer_id.as_bytes());
+        bytes.extend_from_slice(&self.channel_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.packet.to_le_bytes());
+        bytes.extend_from_slice(&self.status.to_bytes().to_le_bytes());
+        write_variable_2(&mut bytes, &self.data);
+        bytes
+    }
+}
@@ -0,0 +1,106 @@
+use crate::packet_types::PacketType;
+use crate::utils::asset_type::AssetType;
+use crate::utils::packet_fields::{read_uuid, read_variable_2, write_variable_2};
+use crate::utils::transfer::{AssetTransferParams, ChannelType, SourceType};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 153
+// Frequency: Low
+
+impl Packet {
+    pub fn new_transfer_request(transfer_request: TransferRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 153,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::TransferRequest(Box::new(transfer_request)),
+        }
+    }
+}
+
+/// Asks the simulator to start a transfer. The simulator answers with a TransferInfo, and then
+/// sends the data in TransferPackets.
+/// https://wiki.secondlife.com/wiki/TransferRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferRequest {
+    /// chosen by the requester, and used to match the TransferInfo and TransferPackets
+    pub transfer_id: Uuid,
+    pub channel_type: ChannelType,
+    pub source_type: SourceType,
+    pub priority: f32,
+    /// describes what to transfer. The format depends on the source type.
+    pub params: Vec<u8>,
+}
+impl TransferRequest {
+    /// a request for an asset from the asset server
+    pub fn asset(transfer_id: Uuid, asset_id: Uuid, asset_type: AssetType, priority: f32) -> Self {
+        TransferRequest {
+            transfer_id,
+            channel_type: ChannelType::Asset,
+            source_type: SourceType::Asset,
+            priority,
+            params: AssetTransferParams {
+                asset_id,
+                asset_type,
+            }
+            .to_bytes(),
+        }
+    }
+
+    /// the params of a request with the Asset source type
+    pub fn asset_params(&self) -> io::Result<AssetTransferParams> {
+        if self.source_type != SourceType::Asset {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{:?} transfer does not request an asset", self.source_type),
+            ));
+        }
+        AssetTransferParams::from_bytes(&self.params)
+    }
+}
+
+impl PacketData for TransferRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+
+        // TransferInfo
+        let transfer_id = read_uuid(&mut cursor)?;
+        let channel_type = ChannelType::from_bytes(cursor.read_i32::<LittleEndian>()?);
+        let source_type = SourceType::from_bytes(cursor.read_i32::<LittleEndian>()?);
+        let priority = cursor.read_f32::<LittleEndian>()?;
+        let params = read_variable_2(&mut cursor)?;
+
+        Ok(TransferRequest {
+            transfer_id,
+            channel_type,
+            source_type,
+            priority,
+            params,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(30 + self.params.len());
+        bytes.extend_from_slice(self.transfer_id.as_bytes());
+        bytes.extend_from_slice(&self.channel_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.source_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.priority.to_le_bytes());
+        write_variable_2(&mut bytes, &self.params);
+        bytes
+    }
+}
@@ -6,8 +6,107 @@ use core::fmt;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    chat_from_simulator::ChatFromSimulator, coarse_location_update::CoarseLocationUpdate,
-    disable_simulator::DisableSimulator, packet_types::PacketType,
+    agent_data_update::AgentDataUpdate,
+    agent_died::AgentDied,
+    asset_received::AssetReceived,
+    asset_transfer_failed::AssetTransferFailed,
+    asset_uploaded::AssetUploaded,
+    attached_sound::AttachedSound,
+    attached_sound_gain_change::AttachedSoundGainChange,
+    attachments::Attachments,
+    avatar_animation::AvatarAnimation,
+    avatar_appearance::AvatarAppearance,
+    avatar_classified_reply::AvatarClassifiedReply,
+    avatar_groups_reply::AvatarGroupsReply,
+    avatar_interests_reply::AvatarInterestsReply,
+    avatar_picks_reply::AvatarPicksReply,
+    avatar_properties_reply::AvatarPropertiesReply,
+    camera_constraint::CameraConstraint,
+    chat_from_simulator::ChatFromSimulator,
+    chat_pass::ChatPass,
+    child_simulator_event::ChildSimulatorEvent,
+    circuit_stats_report::CircuitStatsReport,
+    classified_info_reply::ClassifiedInfoReply,
+    clear_follow_cam_properties::ClearFollowCamProperties,
+    coarse_location_update::CoarseLocationUpdate,
+    directory_people_page::DirectoryPeoplePage,
+    directory_places_page::DirectoryPlacesPage,
+    disable_simulator::DisableSimulator,
+    disconnect::Disconnect,
+    economy_data::EconomyData,
+    estate_owner_message::EstateOwnerMessage,
+    event_info_reply::EventInfoReply,
+    feature_disabled_notice::FeatureDisabledNotice,
+    friend_list::FriendList,
+    friendship_status::FriendshipStatus,
+    generic_message::GenericMessageData,
+    grant_godlike_powers::GrantGodlikePowers,
+    group_name_resolved::GroupNameResolved,
+    group_profile_reply::GroupProfileReply,
+    health_message::HealthMessage,
+    improved_instant_message::ImprovedInstantMessage,
+    improved_terse_object_update::ImprovedTerseObjectUpdate,
+    inventory_folder_contents::InventoryFolderContents,
+    inventory_folder_status::InventoryFolderStatus,
+    inventory_item_created::InventoryItemCreated,
+    inventory_item_status::InventoryItemStatus,
+    join_group_reply::JoinGroupReply,
+    kick_user::KickUser,
+    kill_object::KillObjectData,
+    land_stat_report::LandStatReport,
+    layer_data::{CloudPatches, TerrainPatches, WindPatches},
+    leave_group_reply::LeaveGroupReply,
+    load_url::LoadURL,
+    map_blocks::MapBlocks,
+    map_items::MapItems,
+    map_search_empty::MapSearchEmpty,
+    map_search_results::MapSearchResults,
+    money_balance_reply::MoneyBalanceReply,
+    mute_list::MuteList,
+    name_resolved::NameResolved,
+    object_edit_ack::ObjectEditAck,
+    object_family_status::ObjectFamilyStatus,
+    object_properties::ObjectProperties,
+    object_update::ObjectUpdate,
+    offline_notification::OfflineNotification,
+    online_notification::OnlineNotification,
+    packet_types::PacketType,
+    parcel_access_list::ParcelAccessList,
+    parcel_dwell_reply::ParcelDwellReply,
+    parcel_media_command_message::ParcelMediaCommandMessage,
+    parcel_media_update::ParcelMediaUpdate,
+    parcel_overlay_grid::ParcelOverlayGrid,
+    parcel_properties::ParcelProperties,
+    people_search_empty::PeopleSearchEmpty,
+    people_search_results::PeopleSearchResults,
+    pick_info_reply::PickInfoReply,
+    ping_stats::PingStats,
+    preload_sound::PreloadSound,
+    purchase_failed::PurchaseFailed,
+    region_crossed::RegionCrossed,
+    region_handshake::RegionHandshake,
+    region_info::RegionInfo,
+    script_controls::ScriptControls,
+    script_dialog::ScriptDialog,
+    script_question::ScriptQuestion,
+    script_running_status::ScriptRunningStatus,
+    script_teleport_request::ScriptTeleportRequest,
+    self_agent_info::SelfAgentInfo,
+    set_follow_cam_properties::SetFollowCamProperties,
+    sim_stats::SimStats,
+    simulator_viewer_time_message::SimulatorViewerTimeMessage,
+    sit_status::SitStatus,
+    sound_trigger::SoundTrigger,
+    telehub_info::TelehubInfo,
+    teleport_failed::TeleportFailed,
+    teleport_finish::TeleportFinish,
+    teleport_progress::TeleportProgress,
+    teleport_start::TeleportStart,
+    texture_ready::TextureReady,
+    unknown_packet_notice::UnknownPacketNotice,
+    velocity_interpolation_status::VelocityInterpolationStatus,
+    viewer_effect::ViewerEffect,
+    wearables::Wearables,
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -17,6 +116,105 @@ pub enum UiEventTypes {
     ChatFromSimulatorEvent,
     CoarseLocationUpdateEvent,
     DisableSimulatorEvent,
+    InstantMessageEvent,
+    ObjectUpdateEvent,
+    TerseObjectUpdateEvent,
+    KillObjectEvent,
+    RegionHandshakeEvent,
+    PingStatsEvent,
+    DisconnectEvent,
+    ChildSimulatorEvent,
+    RegionCrossedEvent,
+    TerrainPatchEvent,
+    WindPatchEvent,
+    CloudPatchEvent,
+    TextureReadyEvent,
+    AssetReceivedEvent,
+    AssetTransferFailedEvent,
+    AssetUploadedEvent,
+    AvatarAppearanceEvent,
+    WearablesEvent,
+    NameResolvedEvent,
+    GroupNameResolvedEvent,
+    KickedEvent,
+    TeleportStartEvent,
+    TeleportProgressEvent,
+    TeleportFailedEvent,
+    TeleportCompleteEvent,
+    BalanceEvent,
+    ObjectPropertiesEvent,
+    PurchaseFailedEvent,
+    ParcelPropertiesEvent,
+    ParcelOverlayEvent,
+    MapBlocksEvent,
+    MapItemsEvent,
+    MapSearchResultsEvent,
+    MapSearchEmptyEvent,
+    ScriptDialogEvent,
+    LoadUrlEvent,
+    ScriptQuestionEvent,
+    SoundTriggerEvent,
+    AttachedSoundEvent,
+    AttachedSoundGainChangeEvent,
+    PreloadSoundEvent,
+    ViewerEffectEvent,
+    SitStatusEvent,
+    MuteListEvent,
+    FriendListEvent,
+    FriendOnlineEvent,
+    FriendOfflineEvent,
+    InventoryFolderEvent,
+    InventoryItemEvent,
+    InventoryFolderStatusEvent,
+    InventoryItemCreatedEvent,
+    FriendshipEvent,
+    PeopleSearchResultsEvent,
+    PeopleSearchEmptyEvent,
+    DirectoryPeopleEvent,
+    DirectoryPlacesEvent,
+    ParcelAccessListEvent,
+    LandStatEvent,
+    AttachmentsEvent,
+    ScriptRunningStatusEvent,
+    ObjectEditAckEvent,
+    ObjectFamilyStatusEvent,
+    ScriptControlsEvent,
+    AgentDiedEvent,
+    SelfAgentInfoEvent,
+    JoinGroupEvent,
+    LeaveGroupEvent,
+    AgentDataEvent,
+    AvatarGroupsEvent,
+    GroupProfileEvent,
+    AvatarPropertiesEvent,
+    AvatarInterestsEvent,
+    EstateOwnerMessageEvent,
+    GodPowersEvent,
+    GenericMessageEvent,
+    EconomyDataEvent,
+    RegionInfoEvent,
+    ParcelDwellEvent,
+    CameraConstraintEvent,
+    SetFollowCamPropertiesEvent,
+    ClearFollowCamPropertiesEvent,
+    ScriptTeleportOfferEvent,
+    HealthEvent,
+    SimStatsEvent,
+    SimulatorTimeEvent,
+    AvatarAnimationEvent,
+    AvatarClassifiedsEvent,
+    ClassifiedInfoEvent,
+    AvatarPicksEvent,
+    EventInfoEvent,
+    PickInfoEvent,
+    TelehubInfoEvent,
+    ParcelMediaCommandEvent,
+    ParcelMediaUpdateEvent,
+    ChatPassEvent,
+    VelocityInterpolationEvent,
+    FeatureDisabledEvent,
+    UnknownPacketEvent,
+    CircuitStatsEvent,
     // for packets that are not events
     None,
 }
@@ -40,6 +238,357 @@ impl UiEventTypes {
             UiEventTypes::DisableSimulatorEvent => {
                 Some(PacketType::DisableSimulator(Box::new(DisableSimulator {})))
             }
+            UiEventTypes::InstantMessageEvent => {
+                serde_json::from_slice::<ImprovedInstantMessage>(data)
+                    .ok()
+                    .map(|packet| PacketType::ImprovedInstantMessage(Box::new(packet)))
+            }
+            UiEventTypes::ObjectUpdateEvent => ObjectUpdate::from_bytes(data)
+                .ok()
+                .map(|packet| PacketType::ObjectUpdate(Box::new(packet))),
+            UiEventTypes::TerseObjectUpdateEvent => ImprovedTerseObjectUpdate::from_bytes(data)
+                .ok()
+                .map(|packet| PacketType::ImprovedTerseObjectUpdate(Box::new(packet))),
+            UiEventTypes::KillObjectEvent => serde_json::from_slice::<KillObjectData>(data)
+                .ok()
+                .map(|packet| PacketType::KillObject(Box::new(packet))),
+            UiEventTypes::RegionHandshakeEvent => serde_json::from_slice::<RegionHandshake>(data)
+                .ok()
+                .map(|packet| PacketType::RegionHandshake(Box::new(packet))),
+            UiEventTypes::PingStatsEvent => serde_json::from_slice::<PingStats>(data)
+                .ok()
+                .map(|packet| PacketType::PingStats(Box::new(packet))),
+            UiEventTypes::DisconnectEvent => serde_json::from_slice::<Disconnect>(data)
+                .ok()
+                .map(|packet| PacketType::Disconnect(Box::new(packet))),
+            UiEventTypes::ChildSimulatorEvent => {
+                serde_json::from_slice::<ChildSimulatorEvent>(data)
+                    .ok()
+                    .map(|packet| PacketType::ChildSimulatorEvent(Box::new(packet)))
+            }
+            UiEventTypes::RegionCrossedEvent => serde_json::from_slice::<RegionCrossed>(data)
+                .ok()
+                .map(|packet| PacketType::RegionCrossed(Box::new(packet))),
+            UiEventTypes::TerrainPatchEvent => TerrainPatches::from_bytes(data)
+                .ok()
+                .map(|packet| PacketType::TerrainPatches(Box::new(packet))),
+            UiEventTypes::WindPatchEvent => WindPatches::from_bytes(data)
+                .ok()
+                .map(|packet| PacketType::WindPatches(Box::new(packet))),
+            UiEventTypes::CloudPatchEvent => CloudPatches::from_bytes(data)
+                .ok()
+                .map(|packet| PacketType::CloudPatches(Box::new(packet))),
+            UiEventTypes::TextureReadyEvent => TextureReady::from_bytes(data)
+                .ok()
+                .map(|packet| PacketType::TextureReady(Box::new(packet))),
+            UiEventTypes::AssetReceivedEvent => AssetReceived::from_bytes(data)
+                .ok()
+                .map(|packet| PacketType::AssetReceived(Box::new(packet))),
+            UiEventTypes::AssetTransferFailedEvent => {
+                serde_json::from_slice::<AssetTransferFailed>(data)
+                    .ok()
+                    .map(|packet| PacketType::AssetTransferFailed(Box::new(packet)))
+            }
+            UiEventTypes::AssetUploadedEvent => serde_json::from_slice::<AssetUploaded>(data)
+                .ok()
+                .map(|packet| PacketType::AssetUploaded(Box::new(packet))),
+            UiEventTypes::AvatarAppearanceEvent => serde_json::from_slice::<AvatarAppearance>(data)
+                .ok()
+                .map(|packet| PacketType::AvatarAppearance(Box::new(packet))),
+            UiEventTypes::WearablesEvent => serde_json::from_slice::<Wearables>(data)
+                .ok()
+                .map(|packet| PacketType::Wearables(Box::new(packet))),
+            UiEventTypes::NameResolvedEvent => serde_json::from_slice::<NameResolved>(data)
+                .ok()
+                .map(|packet| PacketType::NameResolved(Box::new(packet))),
+            UiEventTypes::GroupNameResolvedEvent => {
+                serde_json::from_slice::<GroupNameResolved>(data)
+                    .ok()
+                    .map(|packet| PacketType::GroupNameResolved(Box::new(packet)))
+            }
+            UiEventTypes::KickedEvent => serde_json::from_slice::<KickUser>(data)
+                .ok()
+                .map(|packet| PacketType::KickUser(Box::new(packet))),
+            UiEventTypes::TeleportStartEvent => serde_json::from_slice::<TeleportStart>(data)
+                .ok()
+                .map(|packet| PacketType::TeleportStart(Box::new(packet))),
+            UiEventTypes::TeleportProgressEvent => serde_json::from_slice::<TeleportProgress>(data)
+                .ok()
+                .map(|packet| PacketType::TeleportProgress(Box::new(packet))),
+            UiEventTypes::TeleportFailedEvent => serde_json::from_slice::<TeleportFailed>(data)
+                .ok()
+                .map(|packet| PacketType::TeleportFailed(Box::new(packet))),
+            UiEventTypes::TeleportCompleteEvent => serde_json::from_slice::<TeleportFinish>(data)
+                .ok()
+                .map(|packet| PacketType::TeleportFinish(Box::new(packet))),
+            UiEventTypes::BalanceEvent => serde_json::from_slice::<MoneyBalanceReply>(data)
+                .ok()
+                .map(|packet| PacketType::MoneyBalanceReply(Box::new(packet))),
+            UiEventTypes::ObjectPropertiesEvent => serde_json::from_slice::<ObjectProperties>(data)
+                .ok()
+                .map(|packet| PacketType::ObjectProperties(Box::new(packet))),
+            UiEventTypes::PurchaseFailedEvent => serde_json::from_slice::<PurchaseFailed>(data)
+                .ok()
+                .map(|packet| PacketType::PurchaseFailed(Box::new(packet))),
+            UiEventTypes::ParcelPropertiesEvent => serde_json::from_slice::<ParcelProperties>(data)
+                .ok()
+                .map(|packet| PacketType::ParcelProperties(Box::new(packet))),
+            UiEventTypes::ParcelOverlayEvent => ParcelOverlayGrid::from_bytes(data)
+                .ok()
+                .map(|packet| PacketType::ParcelOverlayGrid(Box::new(packet))),
+            UiEventTypes::MapBlocksEvent => serde_json::from_slice::<MapBlocks>(data)
+                .ok()
+                .map(|packet| PacketType::MapBlocks(Box::new(packet))),
+            UiEventTypes::MapItemsEvent => serde_json::from_slice::<MapItems>(data)
+                .ok()
+                .map(|packet| PacketType::MapItems(Box::new(packet))),
+            UiEventTypes::MapSearchResultsEvent => serde_json::from_slice::<MapSearchResults>(data)
+                .ok()
+                .map(|packet| PacketType::MapSearchResults(Box::new(packet))),
+            UiEventTypes::MapSearchEmptyEvent => serde_json::from_slice::<MapSearchEmpty>(data)
+                .ok()
+                .map(|packet| PacketType::MapSearchEmpty(Box::new(packet))),
+            UiEventTypes::ScriptDialogEvent => serde_json::from_slice::<ScriptDialog>(data)
+                .ok()
+                .map(|packet| PacketType::ScriptDialog(Box::new(packet))),
+            UiEventTypes::LoadUrlEvent => serde_json::from_slice::<LoadURL>(data)
+                .ok()
+                .map(|packet| PacketType::LoadURL(Box::new(packet))),
+            UiEventTypes::ScriptQuestionEvent => serde_json::from_slice::<ScriptQuestion>(data)
+                .ok()
+                .map(|packet| PacketType::ScriptQuestion(Box::new(packet))),
+            UiEventTypes::SoundTriggerEvent => serde_json::from_slice::<SoundTrigger>(data)
+                .ok()
+                .map(|packet| PacketType::SoundTrigger(Box::new(packet))),
+            UiEventTypes::AttachedSoundEvent => serde_json::from_slice::<AttachedSound>(data)
+                .ok()
+                .map(|packet| PacketType::AttachedSound(Box::new(packet))),
+            UiEventTypes::AttachedSoundGainChangeEvent => {
+                serde_json::from_slice::<AttachedSoundGainChange>(data)
+                    .ok()
+                    .map(|packet| PacketType::AttachedSoundGainChange(Box::new(packet)))
+            }
+            UiEventTypes::PreloadSoundEvent => serde_json::from_slice::<PreloadSound>(data)
+                .ok()
+                .map(|packet| PacketType::PreloadSound(Box::new(packet))),
+            UiEventTypes::ViewerEffectEvent => serde_json::from_slice::<ViewerEffect>(data)
+                .ok()
+                .map(|packet| PacketType::ViewerEffect(Box::new(packet))),
+            UiEventTypes::SitStatusEvent => serde_json::from_slice::<SitStatus>(data)
+                .ok()
+                .map(|packet| PacketType::SitStatus(Box::new(packet))),
+            UiEventTypes::MuteListEvent => serde_json::from_slice::<MuteList>(data)
+                .ok()
+                .map(|packet| PacketType::MuteList(Box::new(packet))),
+            UiEventTypes::FriendListEvent => serde_json::from_slice::<FriendList>(data)
+                .ok()
+                .map(|packet| PacketType::FriendList(Box::new(packet))),
+            UiEventTypes::FriendOnlineEvent => serde_json::from_slice::<OnlineNotification>(data)
+                .ok()
+                .map(|packet| PacketType::OnlineNotification(Box::new(packet))),
+            UiEventTypes::FriendOfflineEvent => serde_json::from_slice::<OfflineNotification>(data)
+                .ok()
+                .map(|packet| PacketType::OfflineNotification(Box::new(packet))),
+            UiEventTypes::InventoryFolderEvent => {
+                serde_json::from_slice::<InventoryFolderContents>(data)
+                    .ok()
+                    .map(|packet| PacketType::InventoryFolderContents(Box::new(packet)))
+            }
+            UiEventTypes::InventoryItemEvent => serde_json::from_slice::<InventoryItemStatus>(data)
+                .ok()
+                .map(|packet| PacketType::InventoryItemStatus(Box::new(packet))),
+            UiEventTypes::InventoryFolderStatusEvent => {
+                serde_json::from_slice::<InventoryFolderStatus>(data)
+                    .ok()
+                    .map(|packet| PacketType::InventoryFolderStatus(Box::new(packet)))
+            }
+            UiEventTypes::InventoryItemCreatedEvent => {
+                serde_json::from_slice::<InventoryItemCreated>(data)
+                    .ok()
+                    .map(|packet| PacketType::InventoryItemCreated(Box::new(packet)))
+            }
+            UiEventTypes::FriendshipEvent => serde_json::from_slice::<FriendshipStatus>(data)
+                .ok()
+                .map(|packet| PacketType::FriendshipStatus(Box::new(packet))),
+            UiEventTypes::PeopleSearchResultsEvent => {
+                serde_json::from_slice::<PeopleSearchResults>(data)
+                    .ok()
+                    .map(|packet| PacketType::PeopleSearchResults(Box::new(packet)))
+            }
+            UiEventTypes::PeopleSearchEmptyEvent => {
+                serde_json::from_slice::<PeopleSearchEmpty>(data)
+                    .ok()
+                    .map(|packet| PacketType::PeopleSearchEmpty(Box::new(packet)))
+            }
+            UiEventTypes::JoinGroupEvent => serde_json::from_slice::<JoinGroupReply>(data)
+                .ok()
+                .map(|packet| PacketType::JoinGroupReply(Box::new(packet))),
+            UiEventTypes::LeaveGroupEvent => serde_json::from_slice::<LeaveGroupReply>(data)
+                .ok()
+                .map(|packet| PacketType::LeaveGroupReply(Box::new(packet))),
+            UiEventTypes::AgentDataEvent => serde_json::from_slice::<AgentDataUpdate>(data)
+                .ok()
+                .map(|packet| PacketType::AgentDataUpdate(Box::new(packet))),
+            UiEventTypes::AvatarGroupsEvent => serde_json::from_slice::<AvatarGroupsReply>(data)
+                .ok()
+                .map(|packet| PacketType::AvatarGroupsReply(Box::new(packet))),
+            UiEventTypes::GroupProfileEvent => serde_json::from_slice::<GroupProfileReply>(data)
+                .ok()
+                .map(|packet| PacketType::GroupProfileReply(Box::new(packet))),
+            UiEventTypes::AvatarPropertiesEvent => {
+                serde_json::from_slice::<AvatarPropertiesReply>(data)
+                    .ok()
+                    .map(|packet| PacketType::AvatarPropertiesReply(Box::new(packet)))
+            }
+            UiEventTypes::AvatarInterestsEvent => {
+                serde_json::from_slice::<AvatarInterestsReply>(data)
+                    .ok()
+                    .map(|packet| PacketType::AvatarInterestsReply(Box::new(packet)))
+            }
+            UiEventTypes::DirectoryPeopleEvent => {
+                serde_json::from_slice::<DirectoryPeoplePage>(data)
+                    .ok()
+                    .map(|packet| PacketType::DirectoryPeoplePage(Box::new(packet)))
+            }
+            UiEventTypes::DirectoryPlacesEvent => {
+                serde_json::from_slice::<DirectoryPlacesPage>(data)
+                    .ok()
+                    .map(|packet| PacketType::DirectoryPlacesPage(Box::new(packet)))
+            }
+            UiEventTypes::EstateOwnerMessageEvent => {
+                serde_json::from_slice::<EstateOwnerMessage>(data)
+                    .ok()
+                    .map(|packet| PacketType::EstateOwnerMessage(Box::new(packet)))
+            }
+            UiEventTypes::GodPowersEvent => serde_json::from_slice::<GrantGodlikePowers>(data)
+                .ok()
+                .map(|packet| PacketType::GrantGodlikePowers(Box::new(packet))),
+            UiEventTypes::GenericMessageEvent => serde_json::from_slice::<GenericMessageData>(data)
+                .ok()
+                .map(|packet| PacketType::GenericMessage(Box::new(packet))),
+            UiEventTypes::EconomyDataEvent => serde_json::from_slice::<EconomyData>(data)
+                .ok()
+                .map(|packet| PacketType::EconomyData(Box::new(packet))),
+            UiEventTypes::RegionInfoEvent => serde_json::from_slice::<RegionInfo>(data)
+                .ok()
+                .map(|packet| PacketType::RegionInfo(Box::new(packet))),
+            UiEventTypes::ParcelDwellEvent => serde_json::from_slice::<ParcelDwellReply>(data)
+                .ok()
+                .map(|packet| PacketType::ParcelDwellReply(Box::new(packet))),
+            UiEventTypes::ParcelAccessListEvent => serde_json::from_slice::<ParcelAccessList>(data)
+                .ok()
+                .map(|packet| PacketType::ParcelAccessList(Box::new(packet))),
+            UiEventTypes::LandStatEvent => serde_json::from_slice::<LandStatReport>(data)
+                .ok()
+                .map(|packet| PacketType::LandStatReport(Box::new(packet))),
+            UiEventTypes::AttachmentsEvent => serde_json::from_slice::<Attachments>(data)
+                .ok()
+                .map(|packet| PacketType::Attachments(Box::new(packet))),
+            UiEventTypes::ScriptRunningStatusEvent => {
+                serde_json::from_slice::<ScriptRunningStatus>(data)
+                    .ok()
+                    .map(|packet| PacketType::ScriptRunningStatus(Box::new(packet)))
+            }
+            UiEventTypes::ObjectEditAckEvent => serde_json::from_slice::<ObjectEditAck>(data)
+                .ok()
+                .map(|packet| PacketType::ObjectEditAck(Box::new(packet))),
+            UiEventTypes::ObjectFamilyStatusEvent => {
+                serde_json::from_slice::<ObjectFamilyStatus>(data)
+                    .ok()
+                    .map(|packet| PacketType::ObjectFamilyStatus(Box::new(packet)))
+            }
+            UiEventTypes::CameraConstraintEvent => serde_json::from_slice::<CameraConstraint>(data)
+                .ok()
+                .map(|packet| PacketType::CameraConstraint(Box::new(packet))),
+            UiEventTypes::SetFollowCamPropertiesEvent => {
+                serde_json::from_slice::<SetFollowCamProperties>(data)
+                    .ok()
+                    .map(|packet| PacketType::SetFollowCamProperties(Box::new(packet)))
+            }
+            UiEventTypes::ClearFollowCamPropertiesEvent => {
+                serde_json::from_slice::<ClearFollowCamProperties>(data)
+                    .ok()
+                    .map(|packet| PacketType::ClearFollowCamProperties(Box::new(packet)))
+            }
+            UiEventTypes::ScriptControlsEvent => serde_json::from_slice::<ScriptControls>(data)
+                .ok()
+                .map(|packet| PacketType::ScriptControls(Box::new(packet))),
+            UiEventTypes::ScriptTeleportOfferEvent => {
+                serde_json::from_slice::<ScriptTeleportRequest>(data)
+                    .ok()
+                    .map(|packet| PacketType::ScriptTeleportRequest(Box::new(packet)))
+            }
+            UiEventTypes::HealthEvent => serde_json::from_slice::<HealthMessage>(data)
+                .ok()
+                .map(|packet| PacketType::HealthMessage(Box::new(packet))),
+            UiEventTypes::SimStatsEvent => serde_json::from_slice::<SimStats>(data)
+                .ok()
+                .map(|packet| PacketType::SimStats(Box::new(packet))),
+            UiEventTypes::AgentDiedEvent => serde_json::from_slice::<AgentDied>(data)
+                .ok()
+                .map(|packet| PacketType::AgentDied(Box::new(packet))),
+            UiEventTypes::SimulatorTimeEvent => {
+                serde_json::from_slice::<SimulatorViewerTimeMessage>(data)
+                    .ok()
+                    .map(|packet| PacketType::SimulatorViewerTimeMessage(Box::new(packet)))
+            }
+            UiEventTypes::AvatarAnimationEvent => serde_json::from_slice::<AvatarAnimation>(data)
+                .ok()
+                .map(|packet| PacketType::AvatarAnimation(Box::new(packet))),
+            UiEventTypes::SelfAgentInfoEvent => serde_json::from_slice::<SelfAgentInfo>(data)
+                .ok()
+                .map(|packet| PacketType::SelfAgentInfo(Box::new(packet))),
+            UiEventTypes::AvatarClassifiedsEvent => {
+                serde_json::from_slice::<AvatarClassifiedReply>(data)
+                    .ok()
+                    .map(|packet| PacketType::AvatarClassifiedReply(Box::new(packet)))
+            }
+            UiEventTypes::ClassifiedInfoEvent => {
+                serde_json::from_slice::<ClassifiedInfoReply>(data)
+                    .ok()
+                    .map(|packet| PacketType::ClassifiedInfoReply(Box::new(packet)))
+            }
+            UiEventTypes::AvatarPicksEvent => serde_json::from_slice::<AvatarPicksReply>(data)
+                .ok()
+                .map(|packet| PacketType::AvatarPicksReply(Box::new(packet))),
+            UiEventTypes::EventInfoEvent => serde_json::from_slice::<EventInfoReply>(data)
+                .ok()
+                .map(|packet| PacketType::EventInfoReply(Box::new(packet))),
+            UiEventTypes::PickInfoEvent => serde_json::from_slice::<PickInfoReply>(data)
+                .ok()
+                .map(|packet| PacketType::PickInfoReply(Box::new(packet))),
+            UiEventTypes::TelehubInfoEvent => serde_json::from_slice::<TelehubInfo>(data)
+                .ok()
+                .map(|packet| PacketType::TelehubInfo(Box::new(packet))),
+            UiEventTypes::ParcelMediaCommandEvent => {
+                serde_json::from_slice::<ParcelMediaCommandMessage>(data)
+                    .ok()
+                    .map(|packet| PacketType::ParcelMediaCommandMessage(Box::new(packet)))
+            }
+            UiEventTypes::ParcelMediaUpdateEvent => {
+                serde_json::from_slice::<ParcelMediaUpdate>(data)
+                    .ok()
+                    .map(|packet| PacketType::ParcelMediaUpdate(Box::new(packet)))
+            }
+            UiEventTypes::ChatPassEvent => serde_json::from_slice::<ChatPass>(data)
+                .ok()
+                .map(|packet| PacketType::ChatPass(Box::new(packet))),
+            UiEventTypes::VelocityInterpolationEvent => {
+                serde_json::from_slice::<VelocityInterpolationStatus>(data)
+                    .ok()
+                    .map(|packet| PacketType::VelocityInterpolationStatus(Box::new(packet)))
+            }
+            UiEventTypes::FeatureDisabledEvent => {
+                serde_json::from_slice::<FeatureDisabledNotice>(data)
+                    .ok()
+                    .map(|packet| PacketType::FeatureDisabledNotice(Box::new(packet)))
+            }
+            UiEventTypes::UnknownPacketEvent => serde_json::from_slice::<UnknownPacketNotice>(data)
+                .ok()
+                .map(|packet| PacketType::UnknownPacketNotice(Box::new(packet))),
+            UiEventTypes::CircuitStatsEvent => serde_json::from_slice::<CircuitStatsReport>(data)
+                .ok()
+                .map(|packet| PacketType::CircuitStatsReport(Box::new(packet))),
             _ => None, // Handle unimplemented cases
         }
     }
@@ -52,6 +601,107 @@ impl fmt::Display for UiEventTypes {
             UiEventTypes::ChatFromSimulatorEvent => write!(f, "ChatFromSimulatorEvent"),
             UiEventTypes::CoarseLocationUpdateEvent => write!(f, "CoarseLocationUpdateEvent"),
             UiEventTypes::DisableSimulatorEvent => write!(f, "DisableSimulatorEvent"),
+            UiEventTypes::InstantMessageEvent => write!(f, "InstantMessageEvent"),
+            UiEventTypes::ObjectUpdateEvent => write!(f, "ObjectUpdateEvent"),
+            UiEventTypes::TerseObjectUpdateEvent => write!(f, "TerseObjectUpdateEvent"),
+            UiEventTypes::KillObjectEvent => write!(f, "KillObjectEvent"),
+            UiEventTypes::RegionHandshakeEvent => write!(f, "RegionHandshakeEvent"),
+            UiEventTypes::PingStatsEvent => write!(f, "PingStatsEvent"),
+            UiEventTypes::DisconnectEvent => write!(f, "DisconnectEvent"),
+            UiEventTypes::ChildSimulatorEvent => write!(f, "ChildSimulatorEvent"),
+            UiEventTypes::RegionCrossedEvent => write!(f, "RegionCrossedEvent"),
+            UiEventTypes::TerrainPatchEvent => write!(f, "TerrainPatchEvent"),
+            UiEventTypes::WindPatchEvent => write!(f, "WindPatchEvent"),
+            UiEventTypes::CloudPatchEvent => write!(f, "CloudPatchEvent"),
+            UiEventTypes::TextureReadyEvent => write!(f, "TextureReadyEvent"),
+            UiEventTypes::AssetReceivedEvent => write!(f, "AssetReceivedEvent"),
+            UiEventTypes::AssetTransferFailedEvent => write!(f, "AssetTransferFailedEvent"),
+            UiEventTypes::AssetUploadedEvent => write!(f, "AssetUploadedEvent"),
+            UiEventTypes::AvatarAppearanceEvent => write!(f, "AvatarAppearanceEvent"),
+            UiEventTypes::WearablesEvent => write!(f, "WearablesEvent"),
+            UiEventTypes::NameResolvedEvent => write!(f, "NameResolvedEvent"),
+            UiEventTypes::GroupNameResolvedEvent => write!(f, "GroupNameResolvedEvent"),
+            UiEventTypes::KickedEvent => write!(f, "KickedEvent"),
+            UiEventTypes::TeleportStartEvent => write!(f, "TeleportStartEvent"),
+            UiEventTypes::TeleportProgressEvent => write!(f, "TeleportProgressEvent"),
+            UiEventTypes::TeleportFailedEvent => write!(f, "TeleportFailedEvent"),
+            UiEventTypes::TeleportCompleteEvent => write!(f, "TeleportCompleteEvent"),
+            UiEventTypes::BalanceEvent => write!(f, "BalanceEvent"),
+            UiEventTypes::ObjectPropertiesEvent => write!(f, "ObjectPropertiesEvent"),
+            UiEventTypes::PurchaseFailedEvent => write!(f, "PurchaseFailedEvent"),
+            UiEventTypes::ParcelPropertiesEvent => write!(f, "ParcelPropertiesEvent"),
+            UiEventTypes::ParcelOverlayEvent => write!(f, "ParcelOverlayEvent"),
+            UiEventTypes::MapBlocksEvent => write!(f, "MapBlocksEvent"),
+            UiEventTypes::MapItemsEvent => write!(f, "MapItemsEvent"),
+            UiEventTypes::MapSearchResultsEvent => write!(f, "MapSearchResultsEvent"),
+            UiEventTypes::MapSearchEmptyEvent => write!(f, "MapSearchEmptyEvent"),
+            UiEventTypes::ScriptDialogEvent => write!(f, "ScriptDialogEvent"),
+            UiEventTypes::LoadUrlEvent => write!(f, "LoadUrlEvent"),
+            UiEventTypes::ScriptQuestionEvent => write!(f, "ScriptQuestionEvent"),
+            UiEventTypes::SoundTriggerEvent => write!(f, "SoundTriggerEvent"),
+            UiEventTypes::AttachedSoundEvent => write!(f, "AttachedSoundEvent"),
+            UiEventTypes::AttachedSoundGainChangeEvent => write!(f, "AttachedSoundGainChangeEvent"),
+            UiEventTypes::PreloadSoundEvent => write!(f, "PreloadSoundEvent"),
+            UiEventTypes::ViewerEffectEvent => write!(f, "ViewerEffectEvent"),
+            UiEventTypes::SitStatusEvent => write!(f, "SitStatusEvent"),
+            UiEventTypes::MuteListEvent => write!(f, "MuteListEvent"),
+            UiEventTypes::FriendListEvent => write!(f, "FriendListEvent"),
+            UiEventTypes::FriendOnlineEvent => write!(f, "FriendOnlineEvent"),
+            UiEventTypes::FriendOfflineEvent => write!(f, "FriendOfflineEvent"),
+            UiEventTypes::InventoryFolderEvent => write!(f, "InventoryFolderEvent"),
+            UiEventTypes::InventoryItemEvent => write!(f, "InventoryItemEvent"),
+            UiEventTypes::InventoryFolderStatusEvent => write!(f, "InventoryFolderStatusEvent"),
+            UiEventTypes::InventoryItemCreatedEvent => write!(f, "InventoryItemCreatedEvent"),
+            UiEventTypes::FriendshipEvent => write!(f, "FriendshipEvent"),
+            UiEventTypes::PeopleSearchResultsEvent => write!(f, "PeopleSearchResultsEvent"),
+            UiEventTypes::PeopleSearchEmptyEvent => write!(f, "PeopleSearchEmptyEvent"),
+            UiEventTypes::DirectoryPeopleEvent => write!(f, "DirectoryPeopleEvent"),
+            UiEventTypes::DirectoryPlacesEvent => write!(f, "DirectoryPlacesEvent"),
+            UiEventTypes::ParcelAccessListEvent => write!(f, "ParcelAccessListEvent"),
+            UiEventTypes::LandStatEvent => write!(f, "LandStatEvent"),
+            UiEventTypes::AttachmentsEvent => write!(f, "AttachmentsEvent"),
+            UiEventTypes::ScriptRunningStatusEvent => write!(f, "ScriptRunningStatusEvent"),
+            UiEventTypes::ObjectEditAckEvent => write!(f, "ObjectEditAckEvent"),
+            UiEventTypes::ObjectFamilyStatusEvent => write!(f, "ObjectFamilyStatusEvent"),
+            UiEventTypes::ScriptControlsEvent => write!(f, "ScriptControlsEvent"),
+            UiEventTypes::AgentDiedEvent => write!(f, "AgentDiedEvent"),
+            UiEventTypes::SelfAgentInfoEvent => write!(f, "SelfAgentInfoEvent"),
+            UiEventTypes::JoinGroupEvent => write!(f, "JoinGroupEvent"),
+            UiEventTypes::LeaveGroupEvent => write!(f, "LeaveGroupEvent"),
+            UiEventTypes::AgentDataEvent => write!(f, "AgentDataEvent"),
+            UiEventTypes::AvatarGroupsEvent => write!(f, "AvatarGroupsEvent"),
+            UiEventTypes::GroupProfileEvent => write!(f, "GroupProfileEvent"),
+            UiEventTypes::AvatarPropertiesEvent => write!(f, "AvatarPropertiesEvent"),
+            UiEventTypes::AvatarInterestsEvent => write!(f, "AvatarInterestsEvent"),
+            UiEventTypes::EstateOwnerMessageEvent => write!(f, "EstateOwnerMessageEvent"),
+            UiEventTypes::GodPowersEvent => write!(f, "GodPowersEvent"),
+            UiEventTypes::GenericMessageEvent => write!(f, "GenericMessageEvent"),
+            UiEventTypes::EconomyDataEvent => write!(f, "EconomyDataEvent"),
+            UiEventTypes::RegionInfoEvent => write!(f, "RegionInfoEvent"),
+            UiEventTypes::ParcelDwellEvent => write!(f, "ParcelDwellEvent"),
+            UiEventTypes::CameraConstraintEvent => write!(f, "CameraConstraintEvent"),
+            UiEventTypes::SetFollowCamPropertiesEvent => write!(f, "SetFollowCamPropertiesEvent"),
+            UiEventTypes::ClearFollowCamPropertiesEvent => {
+                write!(f, "ClearFollowCamPropertiesEvent")
+            }
+            UiEventTypes::ScriptTeleportOfferEvent => write!(f, "ScriptTeleportOfferEvent"),
+            UiEventTypes::HealthEvent => write!(f, "HealthEvent"),
+            UiEventTypes::SimStatsEvent => write!(f, "SimStatsEvent"),
+            UiEventTypes::SimulatorTimeEvent => write!(f, "SimulatorTimeEvent"),
+            UiEventTypes::AvatarAnimationEvent => write!(f, "AvatarAnimationEvent"),
+            UiEventTypes::AvatarClassifiedsEvent => write!(f, "AvatarClassifiedsEvent"),
+            UiEventTypes::ClassifiedInfoEvent => write!(f, "ClassifiedInfoEvent"),
+            UiEventTypes::AvatarPicksEvent => write!(f, "AvatarPicksEvent"),
+            UiEventTypes::EventInfoEvent => write!(f, "EventInfoEvent"),
+            UiEventTypes::PickInfoEvent => write!(f, "PickInfoEvent"),
+            UiEventTypes::TelehubInfoEvent => write!(f, "TelehubInfoEvent"),
+            UiEventTypes::ParcelMediaCommandEvent => write!(f, "ParcelMediaCommandEvent"),
+            UiEventTypes::ParcelMediaUpdateEvent => write!(f, "ParcelMediaUpdateEvent"),
+            UiEventTypes::ChatPassEvent => write!(f, "ChatPassEvent"),
+            UiEventTypes::VelocityInterpolationEvent => write!(f, "VelocityInterpolationEvent"),
+            UiEventTypes::FeatureDisabledEvent => write!(f, "FeatureDisabledEvent"),
+            UiEventTypes::UnknownPacketEvent => write!(f, "UnknownPacketEvent"),
+            UiEventTypes::CircuitStatsEvent => write!(f, "CircuitStatsEvent"),
             UiEventTypes::None => write!(f, "None"),
             UiEventTypes::Error => write!(f, "Error"),
         }
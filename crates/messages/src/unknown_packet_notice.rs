@@ -0,0 +1,27 @@
+use crate::raw_packet::RawPacketData;
+use serde::{Deserialize, Serialize};
+
+/// Sent from the session to the UI for packets with message numbers the client doesn't
+/// implement, when the session is asked to report them. Meant for finding out which packets
+/// simulators send that nothing handles yet.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnknownPacketNotice {
+    /// the frequency of the message number, like "Low"
+    pub frequency: String,
+    /// the message number, without its frequency marker
+    pub id: u16,
+    /// the body of the packet, hex encoded
+    pub body: String,
+}
+
+impl UnknownPacketNotice {
+    /// describe a packet the client doesn't implement
+    pub fn new(raw: &RawPacketData) -> Self {
+        UnknownPacketNotice {
+            frequency: raw.frequency.to_string(),
+            id: raw.id,
+            body: hex::encode(&raw.body),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::packet_types::PacketType;
+use crate::utils::asset_type::AssetType;
+use crate::utils::inventory_item::InventoryItem;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+use crate::utils::permissions::Permissions;
+use crate::utils::sale_type::SaleType;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 267
+// Frequency: Low
+
+impl Packet {
+    pub fn new_update_create_inventory_item(
+        update_create_inventory_item: UpdateCreateInventoryItem,
+    ) -> Self {
+        Packet {
+            header: Header {
+                id: 267,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::UpdateCreateInventoryItem(Box::new(update_create_inventory_item)),
+        }
+    }
+}
+
+/// Sent by the simulator with items it has made or changed for the agent, like the answer to a
+/// CreateInventoryItem. Each item carries the callback id of the request it answers.
+/// https://wiki.secondlife.com/wiki/UpdateCreateInventoryItem
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateCreateInventoryItem {
+    pub agent_id: Uuid,
+    /// whether the simulator approved the upload the items were made from
+    pub sim_approved: bool,
+    pub transaction_id: Uuid,
+    pub items: Vec<CreatedItem>,
+}
+
+/// an item the simulator made or changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatedItem {
+    pub item: InventoryItem,
+    /// the callback id of the request the item answers
+    pub callback_id: u32,
+    /// checksum of the item
+    pub crc: u32,
+}
+
+impl PacketData for UpdateCreateInventoryItem {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let sim_approved = cursor.read_u8()? != 0;
+        let transaction_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut items = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let item_id = read_uuid(&mut cursor)?;
+            let folder_id = read_uuid(&mut cursor)?;
+            let callback_id = cursor.read_u32::<LittleEndian>()?;
+            let creator_id = read_uuid(&mut cursor)?;
+            let owner_id = read_uuid(&mut cursor)?;
+            let group_id = read_uuid(&mut cursor)?;
+            let base_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+            let owner_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+            let group_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+            let everyone_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+            let next_owner_mask = Permissions::from_bits(cursor.read_u32::<LittleEndian>()?);
+            let group_owned = cursor.read_u8()? != 0;
+            let asset_id = read_uuid(&mut cursor)?;
+            let asset_type = AssetType::from_bytes(cursor.read_i8()?);
+            let inventory_type = cursor.read_i8()?;
+            let flags = cursor.read_u32::<LittleEndian>()?;
+            let sale_type = SaleType::from_bytes(cursor.read_u8()?);
+            let sale_price = cursor.read_i32::<LittleEndian>()?;
+            let name = read_string_1(&mut cursor)?;
+            let description = read_string_1(&mut cursor)?;
+            let creation_date = cursor.read_i32::<LittleEndian>()?;
+            let crc = cursor.read_u32::<LittleEndian>()?;
+            items.push(CreatedItem {
+                item: InventoryItem {
+                    item_id,
+                    folder_id,
+                    creator_id,
+                    owner_id,
+                    group_id,
+                    base_mask,
+                    owner_mask,
+                    group_mask,
+                    everyone_mask,
+                    next_owner_mask,
+                    group_owned,
+                    asset_id,
+                    asset_type,
+                    inventory_type,
+                    flags,
+                    sale_type,
+                    sale_price,
+                    name,
+                    description,
+                    creation_date,
+                },
+                callback_id,
+                crc,
+            });
+        }
+        Ok(UpdateCreateInventoryItem {
+            agent_id,
+            sim_approved,
+            transaction_id,
+            items,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let items = &self.items[..self.items.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(34 + items.len() * 200);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.push(self.sim_approved as u8);
+        bytes.extend_from_slice(self.transaction_id.as_bytes());
+        bytes.push(items.len() as u8);
+        for created in items {
+            let item = &created.item;
+            bytes.extend_from_slice(item.item_id.as_bytes());
+            bytes.extend_from_slice(item.folder_id.as_bytes());
+            bytes.extend_from_slice(&created.callback_id.to_le_bytes());
+            bytes.extend_from_slice(item.creator_id.as_bytes());
+            bytes.extend_from_slice(item.owner_id.as_bytes());
+            bytes.extend_from_slice(item.group_id.as_bytes());
+            for mask in [
+                &item.base_mask,
+                &item.owner_mask,
+                &item.group_mask,
+                &item.everyone_mask,
+                &item.next_owner_mask,
+            ] {
+                bytes.extend_from_slice(&mask.to_bits().to_le_bytes());
+            }
+            bytes.push(item.group_owned as u8);
+            bytes.extend_from_slice(item.asset_id.as_bytes());
+            bytes.push(item.asset_type.to_bytes() as u8);
+            bytes.push(item.inventory_type as u8);
+            bytes.extend_from_slice(&item.flags.to_le_bytes());
+            bytes.push(item.sale_type.to_bytes());
+            bytes.extend_from_slice(&item.sale_price.to_le_bytes());
+            write_string_1(&mut bytes, &item.name);
+            write_string_1(&mut bytes, &item.description);
+            bytes.extend_from_slice(&item.creation_date.to_le_bytes());
+            bytes.extend_from_slice(&created.crc.to_le_bytes());
+        }
+        bytes
+    }
+}
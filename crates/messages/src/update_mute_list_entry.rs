@@ -0,0 +1,95 @@
+use crate::packet_types::PacketType;
+use crate::utils::mute::{MuteEntry, MuteFlags, MuteType};
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 264
+// Frequency: Low
+
+impl Packet {
+    pub fn new_update_mute_list_entry(update_mute_list_entry: UpdateMuteListEntry) -> Self {
+        Packet {
+            header: Header {
+                id: 264,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::UpdateMuteListEntry(Box::new(update_mute_list_entry)),
+        }
+    }
+}
+
+/// Adds an entry to the agent's mute list, or changes the flags of an entry that is already on
+/// it.
+/// https://wiki.secondlife.com/wiki/UpdateMuteListEntry
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateMuteListEntry {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub mute_id: Uuid,
+    pub mute_name: String,
+    pub mute_type: MuteType,
+    pub mute_flags: MuteFlags,
+}
+
+impl UpdateMuteListEntry {
+    /// mute an entry. The agent and session ids are left nil, for the session to fill in.
+    pub fn new(entry: MuteEntry) -> Self {
+        UpdateMuteListEntry {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            mute_id: entry.id,
+            mute_name: entry.name,
+            mute_type: entry.mute_type,
+            mute_flags: entry.flags,
+        }
+    }
+
+    /// the entry as it is kept in the mute list
+    pub fn entry(&self) -> MuteEntry {
+        MuteEntry {
+            id: self.mute_id,
+            name: self.mute_name.clone(),
+            mute_type: self.mute_type,
+            flags: self.mute_flags,
+        }
+    }
+}
+
+impl PacketData for UpdateMuteListEntry {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(UpdateMuteListEntry {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+            mute_id: read_uuid(&mut cursor)?,
+            mute_name: read_string_1(&mut cursor)?,
+            mute_type: MuteType::from_bytes(cursor.read_i32::<LittleEndian>()?),
+            mute_flags: MuteFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(58 + self.mute_name.len());
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.extend_from_slice(self.mute_id.as_bytes());
+        write_string_1(&mut bytes, &self.mute_name);
+        bytes.extend_from_slice(&self.mute_type.to_bytes().to_le_bytes());
+        bytes.extend_from_slice(&self.mute_flags.bits().to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,52 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 319
+// Frequency: Low
+
+impl Packet {
+    pub fn new_use_cached_mute_list(use_cached_mute_list: UseCachedMuteList) -> Self {
+        Packet {
+            header: Header {
+                id: 319,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::UseCachedMuteList(Box::new(use_cached_mute_list)),
+        }
+    }
+}
+
+/// Answers a MuteListRequest whose CRC matched the mute list the simulator has, so there is
+/// nothing to download. It is also the answer when the mute list is empty.
+/// https://wiki.secondlife.com/wiki/UseCachedMuteList
+#[derive(Debug, Clone, PartialEq)]
+pub struct UseCachedMuteList {
+    pub agent_id: Uuid,
+}
+
+impl PacketData for UseCachedMuteList {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(UseCachedMuteList {
+            agent_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.agent_id.as_bytes().to_vec()
+    }
+}
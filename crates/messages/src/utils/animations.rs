@@ -0,0 +1,21 @@
+use uuid::{uuid, Uuid};
+
+// The animations built into every viewer. They aren't in the inventory, and are started and
+// stopped with AgentAnimation by these ids.
+
+/// standing still
+pub const ANIM_STAND: Uuid = uuid!("2408fe9e-df1d-1d7d-f4ff-1384fa7b350f");
+/// sitting on an object
+pub const ANIM_SIT: Uuid = uuid!("1a5fe8ac-a804-8a5d-7cbd-56bd83184568");
+/// walking
+pub const ANIM_WALK: Uuid = uuid!("6ed24bd8-91aa-4b12-ccc7-c97c857ab4e0");
+/// running
+pub const ANIM_RUN: Uuid = uuid!("05ddbff8-aaa9-92a1-2b74-8fe77a29b445");
+/// flying forward
+pub const ANIM_FLY: Uuid = uuid!("aec4610c-757f-bc4e-c092-c6e9caf18daf");
+/// typing on a keyboard, played while the agent writes in chat
+pub const ANIM_TYPE: Uuid = uuid!("c541c47f-e0c0-058b-ad1a-d6ae3a4584d9");
+/// slumped over, played while the agent is away
+pub const ANIM_AWAY: Uuid = uuid!("fd037134-85d4-f241-72c6-4f42164fedee");
+/// played while the agent is busy
+pub const ANIM_BUSY: Uuid = uuid!("efcf670c-2d18-8128-973a-034ebc806b67");
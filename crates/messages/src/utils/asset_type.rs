@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+// the asset types used by the asset server, transfers and inventory.
+// https://wiki.secondlife.com/wiki/AssetType
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AssetType {
+    Texture,
+    Sound,
+    CallingCard,
+    Landmark,
+    Clothing,
+    Object,
+    Notecard,
+    Category,
+    LslText,
+    LslBytecode,
+    TextureTga,
+    Bodypart,
+    SoundWav,
+    ImageTga,
+    ImageJpeg,
+    Animation,
+    Gesture,
+    Simstate,
+    Link,
+    LinkFolder,
+    Mesh,
+    Unknown(i8),
+}
+impl AssetType {
+    pub fn from_bytes(byte: i8) -> Self {
+        match byte {
+            0 => AssetType::Texture,
+            1 => AssetType::Sound,
+            2 => AssetType::CallingCard,
+            3 => AssetType::Landmark,
+            5 => AssetType::Clothing,
+            6 => AssetType::Object,
+            7 => AssetType::Notecard,
+            8 => AssetType::Category,
+            10 => AssetType::LslText,
+            11 => AssetType::LslBytecode,
+            12 => AssetType::TextureTga,
+            13 => AssetType::Bodypart,
+            17 => AssetType::SoundWav,
+            18 => AssetType::ImageTga,
+            19 => AssetType::ImageJpeg,
+            20 => AssetType::Animation,
+            21 => AssetType::Gesture,
+            22 => AssetType::Simstate,
+            24 => AssetType::Link,
+            25 => AssetType::LinkFolder,
+            49 => AssetType::Mesh,
+            byte => AssetType::Unknown(byte),
+        }
+    }
+
+    pub fn to_bytes(&self) -> i8 {
+        match self {
+            AssetType::Texture => 0,
+            AssetType::Sound => 1,
+            AssetType::CallingCard => 2,
+            AssetType::Landmark => 3,
+            AssetType::Clothing => 5,
+            AssetType::Object => 6,
+            AssetType::Notecard => 7,
+            AssetType::Category => 8,
+            AssetType::LslText => 10,
+            AssetType::LslBytecode => 11,
+            AssetType::TextureTga => 12,
+            AssetType::Bodypart => 13,
+            AssetType::SoundWav => 17,
+            AssetType::ImageTga => 18,
+            AssetType::ImageJpeg => 19,
+            AssetType::Animation => 20,
+            AssetType::Gesture => 21,
+            AssetType::Simstate => 22,
+            AssetType::Link => 24,
+            AssetType::LinkFolder => 25,
+            AssetType::Mesh => 49,
+            AssetType::Unknown(byte) => *byte,
+        }
+    }
+}
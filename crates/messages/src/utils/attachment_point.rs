@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+
+// the points on an avatar that objects can be attached to, and the HUD points on the screen.
+// These are the viewer's attachment point numbers.
+// https://wiki.secondlife.com/wiki/Attachment_Points
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AttachmentPoint {
+    /// the point the object was last attached to, or the right hand if it has never been attached
+    Default,
+    Chest,
+    /// the top of the head
+    Skull,
+    LeftShoulder,
+    RightShoulder,
+    LeftHand,
+    RightHand,
+    LeftFoot,
+    RightFoot,
+    /// the back
+    Spine,
+    Pelvis,
+    Mouth,
+    Chin,
+    LeftEar,
+    RightEar,
+    LeftEyeball,
+    RightEyeball,
+    Nose,
+    RightUpperArm,
+    RightForearm,
+    LeftUpperArm,
+    LeftForearm,
+    RightHip,
+    RightUpperLeg,
+    RightLowerLeg,
+    LeftHip,
+    LeftUpperLeg,
+    LeftLowerLeg,
+    /// the belly
+    Stomach,
+    LeftPec,
+    RightPec,
+    /// the second HUD point in the center of the screen
+    HudCenter2,
+    HudTopRight,
+    HudTop,
+    HudTopLeft,
+    /// the first HUD point in the center of the screen
+    HudCenter,
+    HudBottomLeft,
+    HudBottom,
+    HudBottomRight,
+    Neck,
+    /// the avatar's root, which doesn't move with animations
+    AvatarCenter,
+    LeftRingFinger,
+    RightRingFinger,
+    TailBase,
+    TailTip,
+    LeftWing,
+    RightWing,
+    Jaw,
+    AltLeftEar,
+    AltRightEar,
+    AltLeftEye,
+    AltRightEye,
+    Tongue,
+    Groin,
+    LeftHindFoot,
+    RightHindFoot,
+    Unknown(u8),
+}
+impl AttachmentPoint {
+    /// set on the attachment point byte of ObjectAttach and RezSingleAttachmentFromInv to add
+    /// the object to the point, instead of replacing what is already there
+    pub const ADD: u8 = 0x80;
+
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => AttachmentPoint::Default,
+            1 => AttachmentPoint::Chest,
+            2 => AttachmentPoint::Skull,
+            3 => AttachmentPoint::LeftShoulder,
+            4 => AttachmentPoint::RightShoulder,
+            5 => AttachmentPoint::LeftHand,
+            6 => AttachmentPoint::RightHand,
+            7 => AttachmentPoint::LeftFoot,
+            8 => AttachmentPoint::RightFoot,
+            9 => AttachmentPoint::Spine,
+            10 => AttachmentPoint::Pelvis,
+            11 => AttachmentPoint::Mouth,
+            12 => AttachmentPoint::Chin,
+            13 => AttachmentPoint::LeftEar,
+            14 => AttachmentPoint::RightEar,
+            15 => AttachmentPoint::LeftEyeball,
+            16 => AttachmentPoint::RightEyeball,
+            17 => AttachmentPoint::Nose,
+            18 => AttachmentPoint::RightUpperArm,
+            19 => AttachmentPoint::RightForearm,
+            20 => AttachmentPoint::LeftUpperArm,
+            21 => AttachmentPoint::LeftForearm,
+            22 => AttachmentPoint::RightHip,
+            23 => AttachmentPoint::RightUpperLeg,
+            24 => AttachmentPoint::RightLowerLeg,
+            25 => AttachmentPoint::LeftHip,
+            26 => AttachmentPoint::LeftUpperLeg,
+            27 => AttachmentPoint::LeftLowerLeg,
+            28 => AttachmentPoint::Stomach,
+            29 => AttachmentPoint::LeftPec,
+            30 => AttachmentPoint::RightPec,
+            31 => AttachmentPoint::HudCenter2,
+            32 => AttachmentPoint::HudTopRight,
+            33 => AttachmentPoint::HudTop,
+            34 => AttachmentPoint::HudTopLeft,
+            35 => AttachmentPoint::HudCenter,
+            36 => AttachmentPoint::HudBottomLeft,
+            37 => AttachmentPoint::HudBottom,
+            38 => AttachmentPoint::HudBottomRight,
+            39 => AttachmentPoint::Neck,
+            40 => AttachmentPoint::AvatarCenter,
+            41 => AttachmentPoint::LeftRingFinger,
+            42 => AttachmentPoint::RightRingFinger,
+            43 => AttachmentPoint::TailBase,
+            44 => AttachmentPoint::TailTip,
+            45 => AttachmentPoint::LeftWing,
+            46 => AttachmentPoint::RightWing,
+            47 => AttachmentPoint::Jaw,
+            48 => AttachmentPoint::AltLeftEar,
+            49 => AttachmentPoint::AltRightEar,
+            50 => AttachmentPoint::AltLeftEye,
+            51 => AttachmentPoint::AltRightEye,
+            52 => AttachmentPoint::Tongue,
+            53 => AttachmentPoint::Groin,
+            54 => AttachmentPoint::LeftHindFoot,
+            55 => AttachmentPoint::RightHindFoot,
+            other => AttachmentPoint::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            AttachmentPoint::Default => 0,
+            AttachmentPoint::Chest => 1,
+            AttachmentPoint::Skull => 2,
+            AttachmentPoint::LeftShoulder => 3,
+            AttachmentPoint::RightShoulder => 4,
+            AttachmentPoint::LeftHand => 5,
+            AttachmentPoint::RightHand => 6,
+            AttachmentPoint::LeftFoot => 7,
+            AttachmentPoint::RightFoot => 8,
+            AttachmentPoint::Spine => 9,
+            AttachmentPoint::Pelvis => 10,
+            AttachmentPoint::Mouth => 11,
+            AttachmentPoint::Chin => 12,
+            AttachmentPoint::LeftEar => 13,
+            AttachmentPoint::RightEar => 14,
+            AttachmentPoint::LeftEyeball => 15,
+            AttachmentPoint::RightEyeball => 16,
+            AttachmentPoint::Nose => 17,
+            AttachmentPoint::RightUpperArm => 18,
+            AttachmentPoint::RightForearm => 19,
+            AttachmentPoint::LeftUpperArm => 20,
+            AttachmentPoint::LeftForearm => 21,
+            AttachmentPoint::RightHip => 22,
+            AttachmentPoint::RightUpperLeg => 23,
+            AttachmentPoint::RightLowerLeg => 24,
+            AttachmentPoint::LeftHip => 25,
+            AttachmentPoint::LeftUpperLeg => 26,
+            AttachmentPoint::LeftLowerLeg => 27,
+            AttachmentPoint::Stomach => 28,
+            AttachmentPoint::LeftPec => 29,
+            AttachmentPoint::RightPec => 30,
+            AttachmentPoint::HudCenter2 => 31,
+            AttachmentPoint::HudTopRight => 32,
+            AttachmentPoint::HudTop => 33,
+            AttachmentPoint::HudTopLeft => 34,
+            AttachmentPoint::HudCenter => 35,
+            AttachmentPoint::HudBottomLeft => 36,
+            AttachmentPoint::HudBottom => 37,
+            AttachmentPoint::HudBottomRight => 38,
+            AttachmentPoint::Neck => 39,
+            AttachmentPoint::AvatarCenter => 40,
+            AttachmentPoint::LeftRingFinger => 41,
+            AttachmentPoint::RightRingFinger => 42,
+            AttachmentPoint::TailBase => 43,
+            AttachmentPoint::TailTip => 44,
+            AttachmentPoint::LeftWing => 45,
+            AttachmentPoint::RightWing => 46,
+            AttachmentPoint::Jaw => 47,
+            AttachmentPoint::AltLeftEar => 48,
+            AttachmentPoint::AltRightEar => 49,
+            AttachmentPoint::AltLeftEye => 50,
+            AttachmentPoint::AltRightEye => 51,
+            AttachmentPoint::Tongue => 52,
+            AttachmentPoint::Groin => 53,
+            AttachmentPoint::LeftHindFoot => 54,
+            AttachmentPoint::RightHindFoot => 55,
+            AttachmentPoint::Unknown(other) => *other,
+        }
+    }
+
+    /// the attachment point of an attached object, from the State of its ObjectUpdate, which
+    /// stores the point with its two nibbles swapped
+    pub fn from_object_state(state: u8) -> Self {
+        AttachmentPoint::from_bytes(state.rotate_left(4))
+    }
+
+    /// the point is on the screen rather than on the avatar
+    pub fn is_hud(&self) -> bool {
+        (31..=38).contains(&self.to_bytes())
+    }
+}
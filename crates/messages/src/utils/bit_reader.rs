@@ -0,0 +1,57 @@
+use std::io;
+
+// reads the bit packed fields used by LayerData and other compressed packets.
+// bits are read most significant first. Fields longer than 8 bits are read 8 bits at a time
+// into successive little endian bytes, which matches the viewer's BitPack.
+
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    byte_position: usize,
+    bit_position: u8,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        BitReader {
+            data,
+            byte_position: 0,
+            bit_position: 0,
+        }
+    }
+
+    pub fn read_bit(&mut self) -> io::Result<bool> {
+        let byte = self.data.get(self.byte_position).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::UnexpectedEof, "ran out of packed bits")
+        })?;
+        let bit = byte & (0x80 >> self.bit_position) != 0;
+        self.bit_position += 1;
+        if self.bit_position == 8 {
+            self.bit_position = 0;
+            self.byte_position += 1;
+        }
+        Ok(bit)
+    }
+
+    pub fn read_bits(&mut self, count: u32) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.read_bytes(count)?))
+    }
+
+    pub fn read_f32(&mut self) -> io::Result<f32> {
+        Ok(f32::from_le_bytes(self.read_bytes(32)?))
+    }
+
+    fn read_bytes(&mut self, count: u32) -> io::Result<[u8; 4]> {
+        if count > 32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("cannot read {} bits into a u32", count),
+            ));
+        }
+        let mut output = [0u8; 4];
+        for i in 0..count {
+            let byte = &mut output[(i / 8) as usize];
+            *byte = (*byte << 1) | self.read_bit()? as u8;
+        }
+        Ok(output)
+    }
+}
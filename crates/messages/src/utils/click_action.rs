@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+// what happens when an object is clicked.
+// https://wiki.secondlife.com/wiki/LlSetClickAction
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ClickAction {
+    /// touch the object, which is the default
+    Touch,
+    Sit,
+    /// open the buy dialog
+    Buy,
+    /// open the pay dialog
+    Pay,
+    /// open the object's contents
+    Open,
+    /// play the parcel media
+    Play,
+    /// open the parcel media
+    OpenMedia,
+    /// zoom the camera in on the object
+    Zoom,
+    /// clicking does nothing
+    Disabled,
+    Unknown(u8),
+}
+impl ClickAction {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => ClickAction::Touch,
+            1 => ClickAction::Sit,
+            2 => ClickAction::Buy,
+            3 => ClickAction::Pay,
+            4 => ClickAction::Open,
+            5 => ClickAction::Play,
+            6 => ClickAction::OpenMedia,
+            7 => ClickAction::Zoom,
+            8 => ClickAction::Disabled,
+            other => ClickAction::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            ClickAction::Touch => 0,
+            ClickAction::Sit => 1,
+            ClickAction::Buy => 2,
+            ClickAction::Pay => 3,
+            ClickAction::Open => 4,
+            ClickAction::Play => 5,
+            ClickAction::OpenMedia => 6,
+            ClickAction::Zoom => 7,
+            ClickAction::Disabled => 8,
+            ClickAction::Unknown(other) => *other,
+        }
+    }
+}
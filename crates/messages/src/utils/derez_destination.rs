@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+// where objects go when they are derezzed with DeRezObject. These are the viewer's
+// DeRezDestination values.
+// https://wiki.secondlife.com/wiki/DeRezObject
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DeRezDestination {
+    /// save a copy of an attachment or object into the agent's inventory
+    SaveIntoAgentInventory,
+    /// take a copy, leaving the object in world
+    TakeCopy,
+    /// save a copy into the inventory of another object
+    SaveIntoTaskInventory,
+    Attachment,
+    /// move the object into the agent's inventory
+    Take,
+    /// take the object into a god's inventory, regardless of permissions
+    GodTake,
+    /// move the object into the agent's trash folder
+    Delete,
+    AttachmentToInventory,
+    AttachmentExists,
+    /// return the object to its owner's inventory
+    Return,
+    /// return the object to its last owner's inventory
+    ReturnToLastOwner,
+    Unknown(u8),
+}
+impl DeRezDestination {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => DeRezDestination::SaveIntoAgentInventory,
+            1 => DeRezDestination::TakeCopy,
+            2 => DeRezDestination::SaveIntoTaskInventory,
+            3 => DeRezDestination::Attachment,
+            4 => DeRezDestination::Take,
+            5 => DeRezDestination::GodTake,
+            6 => DeRezDestination::Delete,
+            7 => DeRezDestination::AttachmentToInventory,
+            8 => DeRezDestination::AttachmentExists,
+            9 => DeRezDestination::Return,
+            10 => DeRezDestination::ReturnToLastOwner,
+            other => DeRezDestination::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            DeRezDestination::SaveIntoAgentInventory => 0,
+            DeRezDestination::TakeCopy => 1,
+            DeRezDestination::SaveIntoTaskInventory => 2,
+            DeRezDestination::Attachment => 3,
+            DeRezDestination::Take => 4,
+            DeRezDestination::GodTake => 5,
+            DeRezDestination::Delete => 6,
+            DeRezDestination::AttachmentToInventory => 7,
+            DeRezDestination::AttachmentExists => 8,
+            DeRezDestination::Return => 9,
+            DeRezDestination::ReturnToLastOwner => 10,
+            DeRezDestination::Unknown(other) => *other,
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use crate::utils::packet_fields::{read_uuid, read_vec3, write_vec3};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+// Extra params are the optional properties of a prim that don't fit in its shape, like making it
+// flexible, a light, or a sculpted or mesh prim. Each one is a blob with a type.
+// ObjectUpdate sends all of an object's extra params in one field: a one byte count, then for
+// each param its u16 type, its u32 size and its data, all little endian. ObjectExtraParams sets
+// one param at a time, with the same data.
+// https://wiki.secondlife.com/wiki/ObjectExtraParams
+
+/// the type of flexible params
+pub const FLEXIBLE_PARAM: u16 = 0x10;
+/// the type of light params
+pub const LIGHT_PARAM: u16 = 0x20;
+/// the type of sculpt params, which mesh objects use too
+pub const SCULPT_PARAM: u16 = 0x30;
+/// the length of flexible param data
+pub const FLEXIBLE_LENGTH: usize = 16;
+/// the length of light param data
+pub const LIGHT_LENGTH: usize = 16;
+/// the length of sculpt param data
+pub const SCULPT_LENGTH: usize = 17;
+
+/// one extra param of an object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExtraParam {
+    Flexible(FlexibleParams),
+    Light(LightParams),
+    Sculpt(SculptParams),
+    /// a mesh object, which is sent as sculpt params with the mesh sculpt type
+    Mesh(MeshParams),
+    /// a type this doesn't know, kept as it was sent
+    Unknown {
+        param_type: u16,
+        data: Vec<u8>,
+    },
+}
+
+/// makes the prim bend like cloth or hair, following the avatar or the wind
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlexibleParams {
+    /// how many sections the prim bends in, from 0 to 3
+    pub softness: u8,
+    /// how stiff the prim is, from 0 to 10
+    pub tension: f32,
+    /// how much the air slows the prim, from 0 to 10
+    pub drag: f32,
+    /// from -10 to 10
+    pub gravity: f32,
+    /// how much the wind pushes the prim, from 0 to 10
+    pub wind: f32,
+    /// a constant force on the prim, in region coordinates
+    pub force: Vec3,
+}
+
+/// makes the prim light up its surroundings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LightParams {
+    /// RGB color of the light, with the intensity as alpha
+    pub color: [u8; 4],
+    /// how far the light reaches, in meters
+    pub radius: f32,
+    /// the angle of the cone of a projected light, unused for point lights
+    pub cutoff: f32,
+    /// how quickly the light fades with distance
+    pub falloff: f32,
+}
+
+/// makes the prim take its shape from a sculpt map texture
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SculptParams {
+    /// the sculpt map
+    pub texture_id: Uuid,
+    pub sculpt_type: SculptType,
+    /// turn the prim inside out
+    pub invert: bool,
+    /// mirror the prim along its x axis
+    pub mirror: bool,
+}
+
+/// makes the prim a mesh object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeshParams {
+    /// the mesh asset
+    pub mesh_id: Uuid,
+}
+
+/// how the sculpt map wraps around the prim
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SculptType {
+    None,
+    Sphere,
+    Torus,
+    Plane,
+    Cylinder,
+    Unknown(u8),
+}
+
+/// the sculpt type of mesh objects
+const SCULPT_TYPE_MESH: u8 = 5;
+/// the sculpt type is in the low bits of the byte, with the flags above it
+const SCULPT_TYPE_MASK: u8 = 0x07;
+const SCULPT_FLAG_INVERT: u8 = 0x40;
+const SCULPT_FLAG_MIRROR: u8 = 0x80;
+
+impl SculptType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => SculptType::None,
+            1 => SculptType::Sphere,
+            2 => SculptType::Torus,
+            3 => SculptType::Plane,
+            4 => SculptType::Cylinder,
+            other => SculptType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            SculptType::None => 0,
+            SculptType::Sphere => 1,
+            SculptType::Torus => 2,
+            SculptType::Plane => 3,
+            SculptType::Cylinder => 4,
+            SculptType::Unknown(other) => *other,
+        }
+    }
+}
+
+impl ExtraParam {
+    /// the type the param is sent with
+    pub fn param_type(&self) -> u16 {
+        match self {
+            ExtraParam::Flexible(_) => FLEXIBLE_PARAM,
+            ExtraParam::Light(_) => LIGHT_PARAM,
+            ExtraParam::Sculpt(_) | ExtraParam::Mesh(_) => SCULPT_PARAM,
+            ExtraParam::Unknown { param_type, .. } => *param_type,
+        }
+    }
+
+    /// parse the data of a param of a type
+    pub fn from_data(param_type: u16, data: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(data);
+        match param_type {
+            FLEXIBLE_PARAM => {
+                let tension = cursor.read_u8()?;
+                let drag = cursor.read_u8()?;
+                let gravity = cursor.read_u8()?;
+                let wind = cursor.read_u8()?;
+                let force = read_vec3(&mut cursor)?;
+                Ok(ExtraParam::Flexible(FlexibleParams {
+                    // the top bits of the tension and drag bytes are the two bits of softness
+                    softness: ((tension & 0x80) >> 6) | ((drag & 0x80) >> 7),
+                    tension: (tension & 0x7F) as f32 / 10.0,
+                    drag: (drag & 0x7F) as f32 / 10.0,
+                    gravity: gravity as f32 / 10.0 - 10.0,
+                    wind: wind as f32 / 10.0,
+                    force,
+                }))
+            }
+            LIGHT_PARAM => {
+                let mut color = [0u8; 4];
+                cursor.read_exact(&mut color)?;
+                Ok(ExtraParam::Light(LightParams {
+                    color,
+                    radius: cursor.read_f32::<LittleEndian>()?,
+                    cutoff: cursor.read_f32::<LittleEndian>()?,
+                    falloff: cursor.read_f32::<LittleEndian>()?,
+                }))
+            }
+            SCULPT_PARAM => {
+                let texture_id = read_uuid(&mut cursor)?;
+                let sculpt_type = cursor.read_u8()?;
+                if sculpt_type & SCULPT_TYPE_MASK == SCULPT_TYPE_MESH {
+                    return Ok(ExtraParam::Mesh(MeshParams {
+                        mesh_id: texture_id,
+                    }));
+                }
+                Ok(ExtraParam::Sculpt(SculptParams {
+                    texture_id,
+                    sculpt_type: SculptType::from_bytes(sculpt_type & SCULPT_TYPE_MASK),
+                    invert: sculpt_type & SCULPT_FLAG_INVERT != 0,
+                    mirror: sculpt_type & SCULPT_FLAG_MIRROR != 0,
+                }))
+            }
+            _ => Ok(ExtraParam::Unknown {
+                param_type,
+                data: data.to_vec(),
+            }),
+        }
+    }
+
+    /// pack the data of the param, the way the viewer does
+    pub fn to_data(&self) -> Vec<u8> {
+        match self {
+            ExtraParam::Flexible(flexible) => {
+                let mut bytes = Vec::with_capacity(FLEXIBLE_LENGTH);
+                let softness = flexible.softness.min(3);
+                bytes.push(pack_tenths(flexible.tension, 0.0, 10.0) | ((softness & 2) << 6));
+                bytes.push(pack_tenths(flexible.drag, 0.0, 10.0) | ((softness & 1) << 7));
+                bytes.push(pack_tenths(flexible.gravity + 10.0, 0.0, 20.0));
+                bytes.push(pack_tenths(flexible.wind, 0.0, 10.0));
+                write_vec3(&mut bytes, &flexible.force);
+                bytes
+            }
+            ExtraParam::Light(light) => {
+                let mut bytes = Vec::with_capacity(LIGHT_LENGTH);
+                bytes.extend_from_slice(&light.color);
+                bytes.extend_from_slice(&light.radius.to_le_bytes());
+                bytes.extend_from_slice(&light.cutoff.to_le_bytes());
+                bytes.extend_from_slice(&light.falloff.to_le_bytes());
+                bytes
+            }
+            ExtraParam::Sculpt(sculpt) => {
+                let mut bytes = Vec::with_capacity(SCULPT_LENGTH);
+                bytes.extend_from_slice(sculpt.texture_id.as_bytes());
+                let mut sculpt_type = sculpt.sculpt_type.to_bytes() & SCULPT_TYPE_MASK;
+                if sculpt.invert {
+                    sculpt_type |= SCULPT_FLAG_INVERT;
+                }
+                if sculpt.mirror {
+                    sculpt_type |= SCULPT_FLAG_MIRROR;
+                }
+                bytes.push(sculpt_type);
+                bytes
+            }
+            ExtraParam::Mesh(mesh) => {
+                let mut bytes = Vec::with_capacity(SCULPT_LENGTH);
+                bytes.extend_from_slice(mesh.mesh_id.as_bytes());
+                bytes.push(SCULPT_TYPE_MESH);
+                bytes
+            }
+            ExtraParam::Unknown { data, .. } => data.clone(),
+        }
+    }
+}
+
+/// parse the extra params field of an ObjectUpdate
+pub fn read_extra_params(bytes: &[u8]) -> io::Result<Vec<ExtraParam>> {
+    let mut cursor = Cursor::new(bytes);
+    // objects without extra params send an empty field, or a zero count
+    let count = match cursor.read_u8() {
+        Ok(count) => count,
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut params = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let param_type = cursor.read_u16::<LittleEndian>()?;
+        let size = cursor.read_u32::<LittleEndian>()? as u64;
+        let start = cursor.position() as usize;
+        if size > bytes.len() as u64 - start as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!(
+                    "extra param {:#x} declares {} bytes but only {} remain",
+                    param_type,
+                    size,
+                    bytes.len() - start
+                ),
+            ));
+        }
+        let end = start + size as usize;
+        params.push(ExtraParam::from_data(param_type, &bytes[start..end])?);
+        cursor.set_position(end as u64);
+    }
+    Ok(params)
+}
+
+/// pack extra params into the field ObjectUpdate sends them in
+pub fn write_extra_params(params: &[ExtraParam]) -> Vec<u8> {
+    if params.is_empty() {
+        return Vec::new();
+    }
+    let params = &params[..params.len().min(u8::MAX as usize)];
+    let mut bytes = vec![params.len() as u8];
+    for param in params {
+        let data = param.to_data();
+        bytes.extend_from_slice(&param.param_type().to_le_bytes());
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&data);
+    }
+    bytes
+}
+
+// pack a value into tenths, clamped to its range the way the viewer clamps it
+fn pack_tenths(value: f32, min: f32, max: f32) -> u8 {
+    (value.clamp(min, max) * 10.0).round() as u8
+}
@@ -0,0 +1,176 @@
+use crate::utils::asset_type::AssetType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+use crate::utils::permissions::Permissions;
+use crate::utils::sale_type::SaleType;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// an item in an agent's inventory. InventoryDescendents and FetchInventoryReply send items as
+// the same ItemData block, and RezScript sends one with a transaction id in place of the asset
+// id. Other packets that carry items lay the fields out differently, so they read and write
+// their own block.
+// https://wiki.secondlife.com/wiki/Inventory
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventoryItem {
+    pub item_id: Uuid,
+    /// the folder the item is in
+    pub folder_id: Uuid,
+    pub creator_id: Uuid,
+    pub owner_id: Uuid,
+    pub group_id: Uuid,
+    pub base_mask: Permissions,
+    pub owner_mask: Permissions,
+    pub group_mask: Permissions,
+    pub everyone_mask: Permissions,
+    pub next_owner_mask: Permissions,
+    /// the item belongs to the group instead of the owner
+    pub group_owned: bool,
+    /// the asset the item points to. Nil when the simulator doesn't tell, like for items the
+    /// agent can't modify, or when the packet carries a transaction ID instead.
+    pub asset_id: Uuid,
+    pub asset_type: AssetType,
+    /// the viewer's LLInventoryType, which is finer grained than the asset type
+    pub inventory_type: i8,
+    /// depends on the type, like the wearable type of clothing or the attachment point of
+    /// objects
+    pub flags: u32,
+    pub sale_type: SaleType,
+    pub sale_price: i32,
+    pub name: String,
+    pub description: String,
+    /// seconds since the unix epoch
+    pub creation_date: i32,
+}
+
+impl InventoryItem {
+    /// the checksum the simulator sends with an item, which is a sum of its fields rather than
+    /// a real CRC. Computed the way the viewer's LLInventoryItem::getCRC32 does, which leaves out
+    /// the name, description, next owner mask and group ownership.
+    /// The permission masks only keep the bits they know, so items whose masks have other bits
+    /// set need crc_with_masks with the masks as they were sent.
+    pub fn crc(&self) -> u32 {
+        self.crc_with_masks([
+            self.base_mask.to_bits(),
+            self.owner_mask.to_bits(),
+            self.group_mask.to_bits(),
+            self.everyone_mask.to_bits(),
+        ])
+    }
+
+    /// the checksum of the item, with the base, owner, group and everyone masks in that order
+    pub fn crc_with_masks(&self, masks: [u32; 4]) -> u32 {
+        // the permissions also sum the last owner, which isn't sent, so it is always nil
+        let permissions = uuid_crc(&self.creator_id)
+            .wrapping_add(uuid_crc(&self.owner_id))
+            .wrapping_add(uuid_crc(&self.group_id))
+            .wrapping_add(masks.iter().fold(0u32, |sum, mask| sum.wrapping_add(*mask)));
+        let sale = (self.sale_price as u32)
+            .wrapping_add((self.sale_type.to_bytes() as u32).wrapping_mul(0x0707_3096));
+        uuid_crc(&self.item_id)
+            .wrapping_add(uuid_crc(&self.folder_id))
+            .wrapping_add(permissions)
+            .wrapping_add(uuid_crc(&self.asset_id))
+            // the types are signed, and sign extended before they are added
+            .wrapping_add(self.asset_type.to_bytes() as i32 as u32)
+            .wrapping_add(self.inventory_type as i32 as u32)
+            .wrapping_add(self.flags)
+            .wrapping_add(sale)
+            .wrapping_add(self.creation_date as u32)
+    }
+}
+
+/// an ItemData block, an item with the checksum the simulator sent with it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemData {
+    pub item: InventoryItem,
+    /// the checksum the simulator sent with the item
+    pub crc: u32,
+    /// whether the checksum matches the item. It is checked while reading, because the
+    /// permission masks of the item drop the bits they don't know.
+    pub crc_valid: bool,
+}
+
+impl ItemData {
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        let item_id = read_uuid(cursor)?;
+        let folder_id = read_uuid(cursor)?;
+        let creator_id = read_uuid(cursor)?;
+        let owner_id = read_uuid(cursor)?;
+        let group_id = read_uuid(cursor)?;
+        let mut masks = [0u32; 5];
+        for mask in masks.iter_mut() {
+            *mask = cursor.read_u32::<LittleEndian>()?;
+        }
+        let [base_mask, owner_mask, group_mask, everyone_mask, next_owner_mask] = masks;
+        let item = InventoryItem {
+            item_id,
+            folder_id,
+            creator_id,
+            owner_id,
+            group_id,
+            base_mask: Permissions::from_bits(base_mask),
+            owner_mask: Permissions::from_bits(owner_mask),
+            group_mask: Permissions::from_bits(group_mask),
+            everyone_mask: Permissions::from_bits(everyone_mask),
+            next_owner_mask: Permissions::from_bits(next_owner_mask),
+            group_owned: cursor.read_u8()? != 0,
+            asset_id: read_uuid(cursor)?,
+            asset_type: AssetType::from_bytes(cursor.read_i8()?),
+            inventory_type: cursor.read_i8()?,
+            flags: cursor.read_u32::<LittleEndian>()?,
+            sale_type: SaleType::from_bytes(cursor.read_u8()?),
+            sale_price: cursor.read_i32::<LittleEndian>()?,
+            name: read_string_1(cursor)?,
+            description: read_string_1(cursor)?,
+            creation_date: cursor.read_i32::<LittleEndian>()?,
+        };
+        let crc = cursor.read_u32::<LittleEndian>()?;
+        let crc_valid =
+            item.crc_with_masks([base_mask, owner_mask, group_mask, everyone_mask]) == crc;
+        Ok(ItemData {
+            item,
+            crc,
+            crc_valid,
+        })
+    }
+
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        let item = &self.item;
+        bytes.extend_from_slice(item.item_id.as_bytes());
+        bytes.extend_from_slice(item.folder_id.as_bytes());
+        bytes.extend_from_slice(item.creator_id.as_bytes());
+        bytes.extend_from_slice(item.owner_id.as_bytes());
+        bytes.extend_from_slice(item.group_id.as_bytes());
+        for mask in [
+            &item.base_mask,
+            &item.owner_mask,
+            &item.group_mask,
+            &item.everyone_mask,
+            &item.next_owner_mask,
+        ] {
+            bytes.extend_from_slice(&mask.to_bits().to_le_bytes());
+        }
+        bytes.push(item.group_owned as u8);
+        bytes.extend_from_slice(item.asset_id.as_bytes());
+        bytes.push(item.asset_type.to_bytes() as u8);
+        bytes.push(item.inventory_type as u8);
+        bytes.extend_from_slice(&item.flags.to_le_bytes());
+        bytes.push(item.sale_type.to_bytes());
+        bytes.extend_from_slice(&item.sale_price.to_le_bytes());
+        write_string_1(bytes, &item.name);
+        write_string_1(bytes, &item.description);
+        bytes.extend_from_slice(&item.creation_date.to_le_bytes());
+        bytes.extend_from_slice(&self.crc.to_le_bytes());
+    }
+}
+
+/// the viewer's LLUUID::getCRC32, the sum of the four little endian words of the id
+fn uuid_crc(id: &Uuid) -> u32 {
+    id.as_bytes()
+        .chunks_exact(4)
+        .map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+        .fold(0u32, |sum, word| sum.wrapping_add(word))
+}
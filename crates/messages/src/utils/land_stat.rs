@@ -0,0 +1,108 @@
+use bitflags::bitflags;
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+use super::packet_fields::{read_string_1_lossy, read_uuid, read_vec3, write_string_1, write_vec3};
+
+// the reports region owners use to find what is slowing a region down, asked for with
+// LandStatRequest and sent back in LandStatReply.
+
+/// which report a LandStatRequest asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LandStatReportType {
+    /// the objects whose scripts take the most time
+    TopScripts,
+    /// the objects that collide the most
+    TopColliders,
+    Unknown(u32),
+}
+impl LandStatReportType {
+    pub fn from_bytes(value: u32) -> Self {
+        match value {
+            0 => LandStatReportType::TopScripts,
+            1 => LandStatReportType::TopColliders,
+            other => LandStatReportType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u32 {
+        match self {
+            LandStatReportType::TopScripts => 0,
+            LandStatReportType::TopColliders => 1,
+            LandStatReportType::Unknown(other) => *other,
+        }
+    }
+}
+
+bitflags! {
+    /// What the filter of a LandStatRequest is matched against.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct LandStatFlags: u32 {
+        /// only objects on the parcel with the request's local id
+        const FILTER_BY_PARCEL = 0x1;
+        /// only objects whose owner's name contains the filter
+        const FILTER_BY_OWNER = 0x2;
+        /// only objects whose name contains the filter
+        const FILTER_BY_OBJECT = 0x4;
+        /// only objects on parcels whose name contains the filter
+        const FILTER_BY_PARCEL_NAME = 0x8;
+        /// ask for the last report again instead of making a new one
+        const REQUEST_LAST_ENTRY = 0x80000000;
+    }
+}
+
+// sent to the UI as the bits, so unknown flags aren't lost
+impl Serialize for LandStatFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LandStatFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+/// one object of a report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LandStatEntry {
+    pub task_local_id: u32,
+    pub task_id: Uuid,
+    /// where the object is in the region
+    pub position: Vec3,
+    /// the time its scripts take in milliseconds, or how much it collides
+    pub score: f32,
+    pub task_name: String,
+    /// the name of the object's owner, which can be empty
+    pub owner_name: String,
+}
+
+impl LandStatEntry {
+    // names are read lossily, because the simulator can cut them off in the middle of a
+    // character
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        Ok(LandStatEntry {
+            task_local_id: cursor.read_u32::<LittleEndian>()?,
+            task_id: read_uuid(cursor)?,
+            position: read_vec3(cursor)?,
+            score: cursor.read_f32::<LittleEndian>()?,
+            task_name: read_string_1_lossy(cursor)?,
+            owner_name: read_string_1_lossy(cursor)?,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.task_local_id.to_le_bytes());
+        bytes.extend_from_slice(self.task_id.as_bytes());
+        write_vec3(&mut bytes, &self.position);
+        bytes.extend_from_slice(&self.score.to_le_bytes());
+        write_string_1(&mut bytes, &self.task_name);
+        write_string_1(&mut bytes, &self.owner_name);
+        bytes
+    }
+}
@@ -0,0 +1,193 @@
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
+use std::io;
+
+use super::bit_reader::BitReader;
+
+// decoding for the DCT compressed patches sent in LayerData.
+// every layer is a group header followed by patches. Each patch has a header, and then a zigzag
+// ordered list of quantized DCT coefficients that are turned back into values with an inverse DCT.
+// This matches the viewer's patch_dct and patch_code.
+// https://wiki.secondlife.com/wiki/LayerData
+
+/// the quant_wbits value that marks the end of the patches in a layer
+pub const END_OF_PATCHES: u32 = 97;
+
+#[derive(Debug, Clone)]
+pub struct GroupHeader {
+    pub stride: u32,
+    /// width and height of every patch in the group
+    pub patch_size: u32,
+    pub layer_type: u8,
+}
+
+impl GroupHeader {
+    pub fn read(reader: &mut BitReader) -> io::Result<Self> {
+        Ok(GroupHeader {
+            stride: reader.read_bits(16)?,
+            patch_size: reader.read_bits(8)?,
+            layer_type: reader.read_bits(8)? as u8,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PatchHeader {
+    /// the high nibble sets the quantization, the low nibble sets the coefficient width
+    pub quant_wbits: u32,
+    pub dc_offset: f32,
+    pub range: u32,
+    /// x and y of the patch within the region, five bits each
+    pub patch_ids: u32,
+}
+
+impl PatchHeader {
+    /// read the next patch header, or None if the layer has no more patches
+    pub fn read(reader: &mut BitReader) -> io::Result<Option<Self>> {
+        let quant_wbits = reader.read_bits(8)?;
+        if quant_wbits == END_OF_PATCHES {
+            return Ok(None);
+        }
+        Ok(Some(PatchHeader {
+            quant_wbits,
+            dc_offset: reader.read_f32()?,
+            range: reader.read_bits(16)?,
+            patch_ids: reader.read_bits(10)?,
+        }))
+    }
+
+    pub fn x(&self) -> u32 {
+        self.patch_ids >> 5
+    }
+
+    pub fn y(&self) -> u32 {
+        self.patch_ids & 0x1f
+    }
+
+    /// number of bits in each coefficient
+    pub fn word_bits(&self) -> u32 {
+        (self.quant_wbits & 0x0f) + 2
+    }
+}
+
+/// read the quantized coefficients of a patch, in zigzag order
+pub fn read_coefficients(
+    reader: &mut BitReader,
+    header: &PatchHeader,
+    patch_size: u32,
+) -> io::Result<Vec<i32>> {
+    let length = (patch_size * patch_size) as usize;
+    let mut coefficients = vec![0; length];
+    for coefficient in coefficients.iter_mut() {
+        if !reader.read_bit()? {
+            // zero
+            continue;
+        }
+        if !reader.read_bit()? {
+            // end of block, the rest are zero
+            break;
+        }
+        let negative = reader.read_bit()?;
+        let value = reader.read_bits(header.word_bits())? as i32;
+        *coefficient = if negative { -value } else { value };
+    }
+    Ok(coefficients)
+}
+
+/// turn the coefficients of a patch back into values, in rows of patch_size
+pub fn decompress_patch(coefficients: &[i32], header: &PatchHeader, patch_size: u32) -> Vec<f32> {
+    let size = patch_size as usize;
+    let copy_matrix = copy_matrix(size);
+
+    // undo the zigzag ordering and the quantization
+    let mut block = vec![0.0; size * size];
+    for j in 0..size {
+        for i in 0..size {
+            let dequantize = 1.0 + 2.0 * (i + j) as f32;
+            block[j * size + i] = coefficients[copy_matrix[j * size + i]] as f32 * dequantize;
+        }
+    }
+
+    // inverse DCT on the columns, then the rows
+    let cosines = cosine_table(size);
+    let mut columns = vec![0.0; size * size];
+    for column in 0..size {
+        for n in 0..size {
+            let mut total = FRAC_1_SQRT_2 * block[column];
+            for u in 1..size {
+                total += block[u * size + column] * cosines[u * size + n];
+            }
+            columns[size * n + column] = total;
+        }
+    }
+    for line in 0..size {
+        for n in 0..size {
+            let mut total = FRAC_1_SQRT_2 * columns[line * size];
+            for u in 1..size {
+                total += columns[line * size + u] * cosines[u * size + n];
+            }
+            block[line * size + n] = total * 2.0 / size as f32;
+        }
+    }
+
+    let prequant = (header.quant_wbits >> 4) + 2;
+    let quantize = (1 << prequant) as f32;
+    let mult = header.range as f32 / quantize;
+    let addval = mult * (1 << (prequant - 1)) as f32 + header.dc_offset;
+    block.iter().map(|value| value * mult + addval).collect()
+}
+
+fn cosine_table(size: usize) -> Vec<f32> {
+    let half_pi_over_size = PI * 0.5 / size as f32;
+    let mut cosines = vec![0.0; size * size];
+    for u in 0..size {
+        for n in 0..size {
+            cosines[u * size + n] = ((2 * n + 1) as f32 * u as f32 * half_pi_over_size).cos();
+        }
+    }
+    cosines
+}
+
+// the zigzag order the coefficients are sent in. Maps a position in the block to its index in
+// the coefficient list.
+fn copy_matrix(size: usize) -> Vec<usize> {
+    let mut matrix = vec![0; size * size];
+    let (mut i, mut j) = (0, 0);
+    let mut diagonal = false;
+    let mut right = true;
+    let mut count = 0;
+    while i < size && j < size {
+        matrix[j * size + i] = count;
+        count += 1;
+        if !diagonal {
+            if right {
+                if i < size - 1 {
+                    i += 1;
+                } else {
+                    j += 1;
+                }
+                right = false;
+            } else {
+                if j < size - 1 {
+                    j += 1;
+                } else {
+                    i += 1;
+                }
+                right = true;
+            }
+            diagonal = true;
+        } else if right {
+            i += 1;
+            j -= 1;
+            if i == size - 1 || j == 0 {
+                diagonal = false;
+            }
+        } else {
+            i -= 1;
+            j += 1;
+            if j == size - 1 || i == 0 {
+                diagonal = false;
+            }
+        }
+    }
+    matrix
+}
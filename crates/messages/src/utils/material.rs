@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+// the physical material of a prim, which sets its friction, density and the sounds it makes
+// when it collides with something.
+// https://wiki.secondlife.com/wiki/PRIM_MATERIAL
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Material {
+    Stone,
+    Metal,
+    Glass,
+    Wood,
+    Flesh,
+    Plastic,
+    Rubber,
+    /// only settable by the simulator
+    Light,
+    Unknown(u8),
+}
+impl Material {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => Material::Stone,
+            1 => Material::Metal,
+            2 => Material::Glass,
+            3 => Material::Wood,
+            4 => Material::Flesh,
+            5 => Material::Plastic,
+            6 => Material::Rubber,
+            7 => Material::Light,
+            other => Material::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            Material::Stone => 0,
+            Material::Metal => 1,
+            Material::Glass => 2,
+            Material::Wood => 3,
+            Material::Flesh => 4,
+            Material::Plastic => 5,
+            Material::Rubber => 6,
+            Material::Light => 7,
+            Material::Unknown(other) => *other,
+        }
+    }
+}
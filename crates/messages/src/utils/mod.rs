@@ -1,2 +1,30 @@
 pub mod agent_access;
+pub mod animations;
+pub mod asset_type;
+pub mod attachment_point;
+pub mod bit_reader;
+pub mod click_action;
+pub mod derez_destination;
+pub mod extra_params;
+pub mod inventory_item;
+pub mod land_stat;
+pub mod layer_patch;
+pub mod material;
+pub mod mute;
+pub mod object_blocks;
+pub mod packet_fields;
+pub mod parcel_access;
+pub mod parcel_flags;
+pub mod permissions;
+pub mod prim_flags;
+pub mod quantize;
 pub mod region_flags;
+pub mod region_handle;
+pub mod sale_type;
+pub mod script_permissions;
+pub mod sound_flags;
+pub mod surface_info;
+pub mod texture_entry;
+pub mod transfer;
+pub mod wearable_type;
+pub mod zerocoding;
@@ -0,0 +1,114 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use uuid::Uuid;
+
+// what an entry of the mute list blocks.
+// Entries are kept by the simulator, and downloaded as a text file with a line per entry.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MuteType {
+    /// anything with the name, with a nil id
+    ByName,
+    Agent,
+    Object,
+    Group,
+    /// muted outside of the mute list, like by the voice server
+    External,
+    Unknown(i32),
+}
+impl MuteType {
+    pub fn from_bytes(value: i32) -> Self {
+        match value {
+            0 => MuteType::ByName,
+            1 => MuteType::Agent,
+            2 => MuteType::Object,
+            3 => MuteType::Group,
+            4 => MuteType::External,
+            other => MuteType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> i32 {
+        match self {
+            MuteType::ByName => 0,
+            MuteType::Agent => 1,
+            MuteType::Object => 2,
+            MuteType::Group => 3,
+            MuteType::External => 4,
+            MuteType::Unknown(other) => *other,
+        }
+    }
+}
+
+bitflags! {
+    /// What a mute list entry lets through. The flags are inverted, so an entry with no flags
+    /// set mutes everything, and each flag that is set stops muting one kind of thing.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct MuteFlags: u32 {
+        /// text chat and instant messages aren't muted
+        const TEXT_CHAT = 0x01;
+        /// voice chat isn't muted
+        const VOICE_CHAT = 0x02;
+        /// particles aren't muted
+        const PARTICLES = 0x04;
+        /// sounds from objects aren't muted
+        const OBJECT_SOUNDS = 0x08;
+    }
+}
+
+// sent to the UI as the bits, so unknown flags aren't lost
+impl Serialize for MuteFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MuteFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+/// one entry of the mute list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MuteEntry {
+    /// the muted avatar, object or group, or nil for entries muted by name
+    pub id: Uuid,
+    pub name: String,
+    pub mute_type: MuteType,
+    pub flags: MuteFlags,
+}
+impl MuteEntry {
+    /// true if the entry mutes text chat and instant messages
+    pub fn mutes_chat(&self) -> bool {
+        !self.flags.contains(MuteFlags::TEXT_CHAT)
+    }
+
+    /// read a line of the mute list file, which is the type, the id, and the name followed by
+    /// the flags after a |. Lines without a type or a valid id are skipped by returning None.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let (mute_type, rest) = line.trim().split_once(' ')?;
+        let mute_type = MuteType::from_bytes(mute_type.parse().ok()?);
+        let rest = rest.trim_start();
+        let (id, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+        let id = Uuid::parse_str(id).ok()?;
+        let (name, flags) = rest.rsplit_once('|').unwrap_or((rest, ""));
+        Some(MuteEntry {
+            id,
+            name: name.to_string(),
+            mute_type,
+            flags: MuteFlags::from_bits_retain(flags.trim().parse().unwrap_or(0)),
+        })
+    }
+
+    /// write the entry as a line of the mute list file, without the newline
+    pub fn to_line(&self) -> String {
+        format!(
+            "{} {} {}|{}",
+            self.mute_type.to_bytes(),
+            self.id,
+            self.name,
+            self.flags.bits()
+        )
+    }
+}
@@ -0,0 +1,39 @@
+// Lists of objects are sent as a variable block with one ObjectData block for each object. The
+// block count is a single byte, so longer lists have to be split across several packets.
+
+/// the most blocks one variable block can hold
+pub const MAX_OBJECT_BLOCKS: usize = u8::MAX as usize;
+
+/// split a list of object blocks into lists that fit in one packet each, keeping their order.
+/// An empty list gives no lists, so nothing is sent for it.
+pub fn chunk_object_blocks<T>(blocks: &[T]) -> Vec<&[T]> {
+    blocks.chunks(MAX_OBJECT_BLOCKS).collect()
+}
+
+/// the most bytes of object blocks to put in one packet. The viewer stops adding blocks before a
+/// packet grows past its MTU of 1200 bytes, and this leaves room for the header and agent data.
+pub const MAX_OBJECT_BLOCK_BYTES: usize = 1024;
+
+/// split a list of object blocks of different sizes into lists that fit in one packet each,
+/// keeping their order. A list ends once it holds MAX_OBJECT_BLOCKS blocks, or the next block
+/// would take it past MAX_OBJECT_BLOCK_BYTES. A block bigger than that is sent on its own.
+pub fn chunk_sized_object_blocks<T>(blocks: &[T], size: impl Fn(&T) -> usize) -> Vec<&[T]> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut bytes = 0;
+    for (index, block) in blocks.iter().enumerate() {
+        let block_bytes = size(block);
+        let count = index - start;
+        if count > 0 && (count == MAX_OBJECT_BLOCKS || bytes + block_bytes > MAX_OBJECT_BLOCK_BYTES)
+        {
+            chunks.push(&blocks[start..index]);
+            start = index;
+            bytes = 0;
+        }
+        bytes += block_bytes;
+    }
+    if start < blocks.len() {
+        chunks.push(&blocks[start..]);
+    }
+    chunks
+}
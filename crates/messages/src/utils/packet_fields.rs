@@ -0,0 +1,152 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::{DVec3, Quat, Vec3};
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+// helpers for reading and writing the field types used by the message template.
+// https://wiki.secondlife.com/wiki/Message_Layout
+// Variable 1 fields have a one byte length prefix, Variable 2 fields have a two byte little endian
+// length prefix. Strings sent in variable fields include their null terminator in the length.
+
+pub fn read_uuid(cursor: &mut Cursor<&[u8]>) -> io::Result<Uuid> {
+    let mut bytes = [0u8; 16];
+    cursor.read_exact(&mut bytes)?;
+    Ok(Uuid::from_bytes(bytes))
+}
+
+pub fn read_vec3(cursor: &mut Cursor<&[u8]>) -> io::Result<Vec3> {
+    Ok(Vec3 {
+        x: cursor.read_f32::<LittleEndian>()?,
+        y: cursor.read_f32::<LittleEndian>()?,
+        z: cursor.read_f32::<LittleEndian>()?,
+    })
+}
+
+pub fn write_vec3(bytes: &mut Vec<u8>, vec: &Vec3) {
+    bytes.extend_from_slice(&vec.x.to_le_bytes());
+    bytes.extend_from_slice(&vec.y.to_le_bytes());
+    bytes.extend_from_slice(&vec.z.to_le_bytes());
+}
+
+// LLVector3d, used for global positions
+pub fn read_dvec3(cursor: &mut Cursor<&[u8]>) -> io::Result<DVec3> {
+    Ok(DVec3 {
+        x: cursor.read_f64::<LittleEndian>()?,
+        y: cursor.read_f64::<LittleEndian>()?,
+        z: cursor.read_f64::<LittleEndian>()?,
+    })
+}
+
+pub fn write_dvec3(bytes: &mut Vec<u8>, vec: &DVec3) {
+    bytes.extend_from_slice(&vec.x.to_le_bytes());
+    bytes.extend_from_slice(&vec.y.to_le_bytes());
+    bytes.extend_from_slice(&vec.z.to_le_bytes());
+}
+
+// LLQuaternions are sent as x, y, z. w is reconstructed because the quaternion is normalized.
+pub fn read_quat(cursor: &mut Cursor<&[u8]>) -> io::Result<Quat> {
+    let x = cursor.read_f32::<LittleEndian>()?;
+    let y = cursor.read_f32::<LittleEndian>()?;
+    let z = cursor.read_f32::<LittleEndian>()?;
+    let w = (1.0 - (x * x + y * y + z * z)).max(0.0).sqrt();
+    Ok(Quat::from_xyzw(x, y, z, w))
+}
+
+pub fn write_quat(bytes: &mut Vec<u8>, quat: &Quat) {
+    // the w component must be positive for the receiver to reconstruct it
+    let quat = if quat.w < 0.0 { -*quat } else { *quat };
+    bytes.extend_from_slice(&quat.x.to_le_bytes());
+    bytes.extend_from_slice(&quat.y.to_le_bytes());
+    bytes.extend_from_slice(&quat.z.to_le_bytes());
+}
+
+pub fn read_variable_1(cursor: &mut Cursor<&[u8]>) -> io::Result<Vec<u8>> {
+    let length = cursor.read_u8()? as usize;
+    read_exact_vec(cursor, length)
+}
+
+pub fn read_variable_2(cursor: &mut Cursor<&[u8]>) -> io::Result<Vec<u8>> {
+    let length = cursor.read_u16::<LittleEndian>()? as usize;
+    read_exact_vec(cursor, length)
+}
+
+pub fn read_string_1(cursor: &mut Cursor<&[u8]>) -> io::Result<String> {
+    bytes_to_string(read_variable_1(cursor)?)
+}
+
+pub fn read_string_2(cursor: &mut Cursor<&[u8]>) -> io::Result<String> {
+    bytes_to_string(read_variable_2(cursor)?)
+}
+
+// for strings that come from scripts, which aren't always valid UTF-8
+pub fn read_string_1_lossy(cursor: &mut Cursor<&[u8]>) -> io::Result<String> {
+    let mut bytes = read_variable_1(cursor)?;
+    if bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+pub fn write_variable_1(bytes: &mut Vec<u8>, data: &[u8]) {
+    let length = data.len().min(u8::MAX as usize);
+    bytes.push(length as u8);
+    bytes.extend_from_slice(&data[..length]);
+}
+
+pub fn write_variable_2(bytes: &mut Vec<u8>, data: &[u8]) {
+    let length = data.len().min(u16::MAX as usize);
+    bytes.extend_from_slice(&(length as u16).to_le_bytes());
+    bytes.extend_from_slice(&data[..length]);
+}
+
+// empty strings are sent with a length of zero and no terminator
+pub fn write_string_1(bytes: &mut Vec<u8>, string: &str) {
+    write_variable_1(bytes, &string_to_bytes(string, u8::MAX as usize));
+}
+
+pub fn write_string_2(bytes: &mut Vec<u8>, string: &str) {
+    write_variable_2(bytes, &string_to_bytes(string, u16::MAX as usize));
+}
+
+fn read_exact_vec(cursor: &mut Cursor<&[u8]>, length: usize) -> io::Result<Vec<u8>> {
+    let remaining =
+        cursor.get_ref().len() as u64 - cursor.position().min(cursor.get_ref().len() as u64);
+    if length as u64 > remaining {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "Variable field declares {} bytes but only {} remain",
+                length, remaining
+            ),
+        ));
+    }
+    let mut data = vec![0u8; length];
+    cursor.read_exact(&mut data)?;
+    Ok(data)
+}
+
+fn bytes_to_string(mut bytes: Vec<u8>) -> io::Result<String> {
+    if bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+// the longest start of the string that fits in max_length bytes, ending on a character boundary
+pub fn truncate_str(string: &str, max_length: usize) -> &str {
+    let mut end = string.len().min(max_length);
+    while !string.is_char_boundary(end) {
+        end -= 1;
+    }
+    &string[..end]
+}
+
+// null terminates the string, truncating on a character boundary if it would not fit
+fn string_to_bytes(string: &str, max_length: usize) -> Vec<u8> {
+    if string.is_empty() {
+        return Vec::new();
+    }
+    let mut bytes = truncate_str(string, max_length - 1).as_bytes().to_vec();
+    bytes.push(0);
+    bytes
+}
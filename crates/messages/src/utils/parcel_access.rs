@@ -0,0 +1,65 @@
+use bitflags::bitflags;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+use super::packet_fields::read_uuid;
+
+// the access and ban lists of a parcel, sent in ParcelAccessListReply and changed with
+// ParcelAccessListUpdate.
+
+bitflags! {
+    /// Which of a parcel's lists a request, reply or update is for.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ParcelAccessFlags: u32 {
+        /// the avatars allowed onto the parcel when it uses its access list
+        const ACCESS = 0x1;
+        /// the avatars banned from the parcel
+        const BAN = 0x2;
+    }
+}
+
+// sent to the UI as the bits, so unknown flags aren't lost
+impl Serialize for ParcelAccessFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ParcelAccessFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+/// one avatar on an access or ban list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParcelAccessEntry {
+    pub id: Uuid,
+    /// when the entry expires, in seconds since the epoch, or 0 if it doesn't
+    pub time: i32,
+    /// the list the entry is on
+    pub flags: ParcelAccessFlags,
+}
+
+impl ParcelAccessEntry {
+    /// the size of an entry on the wire
+    pub const SIZE: usize = 24;
+
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        Ok(ParcelAccessEntry {
+            id: read_uuid(cursor)?,
+            time: cursor.read_i32::<LittleEndian>()?,
+            flags: ParcelAccessFlags::from_bits_retain(cursor.read_u32::<LittleEndian>()?),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::SIZE);
+        bytes.extend_from_slice(self.id.as_bytes());
+        bytes.extend_from_slice(&self.time.to_le_bytes());
+        bytes.extend_from_slice(&self.flags.bits().to_le_bytes());
+        bytes
+    }
+}
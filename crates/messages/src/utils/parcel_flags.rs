@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+
+// the flags of a parcel, sent in ParcelProperties.
+// https://wiki.secondlife.com/wiki/ParcelProperties
+
+/// what is allowed on a parcel. Every bit is defined, so no bits are dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParcelFlags {
+    /// anyone can fly
+    pub allow_fly: bool,
+    /// scripts of objects not owned by the parcel owner can run
+    pub allow_other_scripts: bool,
+    /// the parcel is for sale
+    pub for_sale: bool,
+    /// anyone can create landmarks
+    pub allow_landmark: bool,
+    /// anyone can edit the terrain
+    pub allow_terraform: bool,
+    /// avatars can take damage
+    pub allow_damage: bool,
+    /// anyone can rez objects
+    pub create_objects: bool,
+    /// deprecated, sell_parcel_objects is used instead
+    pub for_sale_objects: bool,
+    /// only members of the parcel's group can enter
+    pub use_access_group: bool,
+    /// only avatars on the access list can enter
+    pub use_access_list: bool,
+    /// avatars on the ban list can't enter
+    pub use_ban_list: bool,
+    /// avatars can buy passes to enter
+    pub use_pass_list: bool,
+    /// the parcel is listed in search
+    pub show_directory: bool,
+    /// the parcel can be deeded to its group
+    pub allow_deed_to_group: bool,
+    /// the owner contributes land to the group when deeding
+    pub contribute_with_deed: bool,
+    /// sounds are only heard on the parcel
+    pub sound_local: bool,
+    /// the objects on the parcel are sold with it
+    pub sell_parcel_objects: bool,
+    /// the parcel is published to the web
+    pub allow_publish: bool,
+    /// the parcel is published as mature content
+    pub mature_publish: bool,
+    /// the media url is a web page
+    pub url_web_page: bool,
+    /// the media url is raw html
+    pub url_raw_html: bool,
+    /// only the owner can push objects and avatars
+    pub restrict_push_object: bool,
+    /// avatars without payment info on file can't enter
+    pub deny_anonymous: bool,
+    /// deprecated
+    pub deny_identified: bool,
+    /// deprecated
+    pub deny_transacted: bool,
+    /// scripts of objects owned by the parcel's group can run
+    pub allow_group_scripts: bool,
+    /// members of the parcel's group can rez objects
+    pub create_group_objects: bool,
+    /// objects from anywhere can enter the parcel
+    pub allow_all_object_entry: bool,
+    /// objects owned by the parcel's group can enter the parcel
+    pub allow_group_object_entry: bool,
+    /// voice chat is allowed
+    pub allow_voice_chat: bool,
+    /// voice chat uses the estate's channel instead of a private one
+    pub use_estate_voice_channel: bool,
+    /// avatars who have not been age verified can't enter
+    pub deny_age_unverified: bool,
+}
+
+impl ParcelFlags {
+    pub const ALLOW_FLY: u32 = 1 << 0;
+    pub const ALLOW_OTHER_SCRIPTS: u32 = 1 << 1;
+    pub const FOR_SALE: u32 = 1 << 2;
+    pub const ALLOW_LANDMARK: u32 = 1 << 3;
+    pub const ALLOW_TERRAFORM: u32 = 1 << 4;
+    pub const ALLOW_DAMAGE: u32 = 1 << 5;
+    pub const CREATE_OBJECTS: u32 = 1 << 6;
+    pub const FOR_SALE_OBJECTS: u32 = 1 << 7;
+    pub const USE_ACCESS_GROUP: u32 = 1 << 8;
+    pub const USE_ACCESS_LIST: u32 = 1 << 9;
+    pub const USE_BAN_LIST: u32 = 1 << 10;
+    pub const USE_PASS_LIST: u32 = 1 << 11;
+    pub const SHOW_DIRECTORY: u32 = 1 << 12;
+    pub const ALLOW_DEED_TO_GROUP: u32 = 1 << 13;
+    pub const CONTRIBUTE_WITH_DEED: u32 = 1 << 14;
+    pub const SOUND_LOCAL: u32 = 1 << 15;
+    pub const SELL_PARCEL_OBJECTS: u32 = 1 << 16;
+    pub const ALLOW_PUBLISH: u32 = 1 << 17;
+    pub const MATURE_PUBLISH: u32 = 1 << 18;
+    pub const URL_WEB_PAGE: u32 = 1 << 19;
+    pub const URL_RAW_HTML: u32 = 1 << 20;
+    pub const RESTRICT_PUSH_OBJECT: u32 = 1 << 21;
+    pub const DENY_ANONYMOUS: u32 = 1 << 22;
+    pub const DENY_IDENTIFIED: u32 = 1 << 23;
+    pub const DENY_TRANSACTED: u32 = 1 << 24;
+    pub const ALLOW_GROUP_SCRIPTS: u32 = 1 << 25;
+    pub const CREATE_GROUP_OBJECTS: u32 = 1 << 26;
+    pub const ALLOW_ALL_OBJECT_ENTRY: u32 = 1 << 27;
+    pub const ALLOW_GROUP_OBJECT_ENTRY: u32 = 1 << 28;
+    pub const ALLOW_VOICE_CHAT: u32 = 1 << 29;
+    pub const USE_ESTATE_VOICE_CHANNEL: u32 = 1 << 30;
+    pub const DENY_AGE_UNVERIFIED: u32 = 1 << 31;
+
+    pub fn from_bits(bits: u32) -> Self {
+        ParcelFlags {
+            allow_fly: bits & Self::ALLOW_FLY != 0,
+            allow_other_scripts: bits & Self::ALLOW_OTHER_SCRIPTS != 0,
+            for_sale: bits & Self::FOR_SALE != 0,
+            allow_landmark: bits & Self::ALLOW_LANDMARK != 0,
+            allow_terraform: bits & Self::ALLOW_TERRAFORM != 0,
+            allow_damage: bits & Self::ALLOW_DAMAGE != 0,
+            create_objects: bits & Self::CREATE_OBJECTS != 0,
+            for_sale_objects: bits & Self::FOR_SALE_OBJECTS != 0,
+            use_access_group: bits & Self::USE_ACCESS_GROUP != 0,
+            use_access_list: bits & Self::USE_ACCESS_LIST != 0,
+            use_ban_list: bits & Self::USE_BAN_LIST != 0,
+            use_pass_list: bits & Self::USE_PASS_LIST != 0,
+            show_directory: bits & Self::SHOW_DIRECTORY != 0,
+            allow_deed_to_group: bits & Self::ALLOW_DEED_TO_GROUP != 0,
+            contribute_with_deed: bits & Self::CONTRIBUTE_WITH_DEED != 0,
+            sound_local: bits & Self::SOUND_LOCAL != 0,
+            sell_parcel_objects: bits & Self::SELL_PARCEL_OBJECTS != 0,
+            allow_publish: bits & Self::ALLOW_PUBLISH != 0,
+            mature_publish: bits & Self::MATURE_PUBLISH != 0,
+            url_web_page: bits & Self::URL_WEB_PAGE != 0,
+            url_raw_html: bits & Self::URL_RAW_HTML != 0,
+            restrict_push_object: bits & Self::RESTRICT_PUSH_OBJECT != 0,
+            deny_anonymous: bits & Self::DENY_ANONYMOUS != 0,
+            deny_identified: bits & Self::DENY_IDENTIFIED != 0,
+            deny_transacted: bits & Self::DENY_TRANSACTED != 0,
+            allow_group_scripts: bits & Self::ALLOW_GROUP_SCRIPTS != 0,
+            create_group_objects: bits & Self::CREATE_GROUP_OBJECTS != 0,
+            allow_all_object_entry: bits & Self::ALLOW_ALL_OBJECT_ENTRY != 0,
+            allow_group_object_entry: bits & Self::ALLOW_GROUP_OBJECT_ENTRY != 0,
+            allow_voice_chat: bits & Self::ALLOW_VOICE_CHAT != 0,
+            use_estate_voice_channel: bits & Self::USE_ESTATE_VOICE_CHANNEL != 0,
+            deny_age_unverified: bits & Self::DENY_AGE_UNVERIFIED != 0,
+        }
+    }
+
+    pub fn to_bits(&self) -> u32 {
+        [
+            (self.allow_fly, Self::ALLOW_FLY),
+            (self.allow_other_scripts, Self::ALLOW_OTHER_SCRIPTS),
+            (self.for_sale, Self::FOR_SALE),
+            (self.allow_landmark, Self::ALLOW_LANDMARK),
+            (self.allow_terraform, Self::ALLOW_TERRAFORM),
+            (self.allow_damage, Self::ALLOW_DAMAGE),
+            (self.create_objects, Self::CREATE_OBJECTS),
+            (self.for_sale_objects, Self::FOR_SALE_OBJECTS),
+            (self.use_access_group, Self::USE_ACCESS_GROUP),
+            (self.use_access_list, Self::USE_ACCESS_LIST),
+            (self.use_ban_list, Self::USE_BAN_LIST),
+            (self.use_pass_list, Self::USE_PASS_LIST),
+            (self.show_directory, Self::SHOW_DIRECTORY),
+            (self.allow_deed_to_group, Self::ALLOW_DEED_TO_GROUP),
+            (self.contribute_with_deed, Self::CONTRIBUTE_WITH_DEED),
+            (self.sound_local, Self::SOUND_LOCAL),
+            (self.sell_parcel_objects, Self::SELL_PARCEL_OBJECTS),
+            (self.allow_publish, Self::ALLOW_PUBLISH),
+            (self.mature_publish, Self::MATURE_PUBLISH),
+            (self.url_web_page, Self::URL_WEB_PAGE),
+            (self.url_raw_html, Self::URL_RAW_HTML),
+            (self.restrict_push_object, Self::RESTRICT_PUSH_OBJECT),
+            (self.deny_anonymous, Self::DENY_ANONYMOUS),
+            (self.deny_identified, Self::DENY_IDENTIFIED),
+            (self.deny_transacted, Self::DENY_TRANSACTED),
+            (self.allow_group_scripts, Self::ALLOW_GROUP_SCRIPTS),
+            (self.create_group_objects, Self::CREATE_GROUP_OBJECTS),
+            (self.allow_all_object_entry, Self::ALLOW_ALL_OBJECT_ENTRY),
+            (
+                self.allow_group_object_entry,
+                Self::ALLOW_GROUP_OBJECT_ENTRY,
+            ),
+            (self.allow_voice_chat, Self::ALLOW_VOICE_CHAT),
+            (
+                self.use_estate_voice_channel,
+                Self::USE_ESTATE_VOICE_CHANNEL,
+            ),
+            (self.deny_age_unverified, Self::DENY_AGE_UNVERIFIED),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .fold(0, |bits, (_, flag)| bits | flag)
+    }
+}
@@ -0,0 +1,90 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// the permission masks of objects and inventory items.
+// https://wiki.secondlife.com/wiki/Permission
+
+bitflags! {
+    /// The bits of a permission mask, as they are sent in packets.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct PermissionMask: u32 {
+        /// can be given away or sold
+        const TRANSFER = 1 << 13;
+        /// can be edited
+        const MODIFY = 1 << 14;
+        /// can be copied
+        const COPY = 1 << 15;
+        /// can be exported from the grid
+        const EXPORT = 1 << 16;
+        /// can be moved
+        const MOVE = 1 << 19;
+    }
+}
+
+// sent to the UI as the bits, so unknown bits aren't lost
+impl Serialize for PermissionMask {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionMask {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+/// what can be done with an object or item. Unknown bits are dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Permissions {
+    /// can be given away or sold
+    pub transfer: bool,
+    /// can be edited
+    pub modify: bool,
+    /// can be copied
+    pub copy: bool,
+    /// can be exported from the grid
+    pub export: bool,
+    /// can be moved
+    pub r#move: bool,
+}
+
+impl Permissions {
+    pub const TRANSFER: u32 = PermissionMask::TRANSFER.bits();
+    pub const MODIFY: u32 = PermissionMask::MODIFY.bits();
+    pub const COPY: u32 = PermissionMask::COPY.bits();
+    pub const EXPORT: u32 = PermissionMask::EXPORT.bits();
+    pub const MOVE: u32 = PermissionMask::MOVE.bits();
+
+    pub fn from_bits(bits: u32) -> Self {
+        Self::from(PermissionMask::from_bits_truncate(bits))
+    }
+
+    pub fn to_bits(&self) -> u32 {
+        PermissionMask::from(*self).bits()
+    }
+}
+
+impl From<PermissionMask> for Permissions {
+    fn from(mask: PermissionMask) -> Self {
+        Permissions {
+            transfer: mask.contains(PermissionMask::TRANSFER),
+            modify: mask.contains(PermissionMask::MODIFY),
+            copy: mask.contains(PermissionMask::COPY),
+            export: mask.contains(PermissionMask::EXPORT),
+            r#move: mask.contains(PermissionMask::MOVE),
+        }
+    }
+}
+
+impl From<Permissions> for PermissionMask {
+    fn from(permissions: Permissions) -> Self {
+        let mut mask = PermissionMask::empty();
+        mask.set(PermissionMask::TRANSFER, permissions.transfer);
+        mask.set(PermissionMask::MODIFY, permissions.modify);
+        mask.set(PermissionMask::COPY, permissions.copy);
+        mask.set(PermissionMask::EXPORT, permissions.export);
+        mask.set(PermissionMask::MOVE, permissions.r#move);
+        mask
+    }
+}
@@ -0,0 +1,75 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+bitflags! {
+    /// The flags of an object, sent in the UpdateFlags of ObjectUpdate. Only USE_PHYSICS,
+    /// PHANTOM and TEMPORARY_ON_REZ can be changed, with ObjectFlagUpdate. The rest describe
+    /// the object and what the agent can do with it.
+    /// https://wiki.secondlife.com/wiki/ObjectUpdate
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct PrimFlags: u32 {
+        /// the object is moved by the physics engine
+        const USE_PHYSICS = 0x0000_0001;
+        /// the object was just created, and should be selected
+        const CREATE_SELECTED = 0x0000_0002;
+        /// the agent can modify the object
+        const OBJECT_MODIFY = 0x0000_0004;
+        /// the agent can copy the object
+        const OBJECT_COPY = 0x0000_0008;
+        /// the object can be edited by anyone
+        const OBJECT_ANY_OWNER = 0x0000_0010;
+        /// the agent owns the object
+        const OBJECT_YOU_OWNER = 0x0000_0020;
+        /// the object has scripts in it
+        const SCRIPTED = 0x0000_0040;
+        /// a script in the object handles touches
+        const HANDLE_TOUCH = 0x0000_0080;
+        /// the agent can move the object
+        const OBJECT_MOVE = 0x0000_0100;
+        /// a script in the object takes money
+        const TAKES_MONEY = 0x0000_0200;
+        /// other objects and avatars pass through the object
+        const PHANTOM = 0x0000_0400;
+        /// the object has no inventory
+        const INVENTORY_EMPTY = 0x0000_0800;
+        /// the object is part of the pathfinding navmesh
+        const AFFECTS_NAVMESH = 0x0000_1000;
+        /// the object is a pathfinding character
+        const CHARACTER = 0x0000_2000;
+        /// the object detects collisions without colliding
+        const VOLUME_DETECT = 0x0000_4000;
+        /// the object shows up in search
+        const INCLUDE_IN_SEARCH = 0x0000_8000;
+        /// anyone can drop items into the object
+        const ALLOW_INVENTORY_DROP = 0x0001_0000;
+        /// the agent can give the object away
+        const OBJECT_TRANSFER = 0x0002_0000;
+        /// the object is owned by a group
+        const OBJECT_GROUP_OWNED = 0x0004_0000;
+        /// the camera doesn't follow the object
+        const CAMERA_DECOUPLED = 0x0010_0000;
+        /// the object is playing an animation
+        const ANIM_SOURCE = 0x0020_0000;
+        /// the object controls the camera
+        const CAMERA_SOURCE = 0x0040_0000;
+        /// the owner of the object can modify it
+        const OBJECT_OWNER_MODIFY = 0x1000_0000;
+        /// the object is deleted a minute after it is rezzed
+        const TEMPORARY_ON_REZ = 0x2000_0000;
+        /// the object is temporary, the way OpenSim sends it
+        const TEMPORARY = 0x4000_0000;
+    }
+}
+
+// sent to the UI as the bits, so unknown flags aren't lost
+impl Serialize for PrimFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PrimFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
@@ -0,0 +1,72 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::{Quat, Vec3};
+use std::io::{self, Cursor};
+
+// helpers for the quantized floats used by terse updates and other compressed packets.
+// a value between lower and upper is mapped onto the full range of a u16 or u8.
+// these match the viewer's U16_to_F32 and F32_to_U16 so values round trip with the simulator.
+
+pub fn u16_to_float(value: u16, lower: f32, upper: f32) -> f32 {
+    let delta = upper - lower;
+    let float = value as f32 / u16::MAX as f32 * delta + lower;
+    // zero can't be represented exactly, so snap anything within one step of it to zero
+    if float.abs() < delta / u16::MAX as f32 {
+        0.0
+    } else {
+        float
+    }
+}
+
+pub fn float_to_u16(value: f32, lower: f32, upper: f32) -> u16 {
+    let value = value.clamp(lower, upper);
+    ((value - lower) / (upper - lower) * u16::MAX as f32).round() as u16
+}
+
+pub fn u8_to_float(value: u8, lower: f32, upper: f32) -> f32 {
+    let delta = upper - lower;
+    let float = value as f32 / u8::MAX as f32 * delta + lower;
+    if float.abs() < delta / u8::MAX as f32 {
+        0.0
+    } else {
+        float
+    }
+}
+
+pub fn float_to_u8(value: f32, lower: f32, upper: f32) -> u8 {
+    let value = value.clamp(lower, upper);
+    ((value - lower) / (upper - lower) * u8::MAX as f32).round() as u8
+}
+
+/// the largest error a value can pick up from being quantized into a u16 over the range
+pub fn u16_quantization_error(lower: f32, upper: f32) -> f32 {
+    (upper - lower) / u16::MAX as f32
+}
+
+pub fn read_quantized_vec3(cursor: &mut Cursor<&[u8]>, lower: f32, upper: f32) -> io::Result<Vec3> {
+    Ok(Vec3 {
+        x: u16_to_float(cursor.read_u16::<LittleEndian>()?, lower, upper),
+        y: u16_to_float(cursor.read_u16::<LittleEndian>()?, lower, upper),
+        z: u16_to_float(cursor.read_u16::<LittleEndian>()?, lower, upper),
+    })
+}
+
+pub fn write_quantized_vec3(bytes: &mut Vec<u8>, vec: &Vec3, lower: f32, upper: f32) {
+    for value in vec.to_array() {
+        bytes.extend_from_slice(&float_to_u16(value, lower, upper).to_le_bytes());
+    }
+}
+
+// quantized quaternions send all four components, each between -1 and 1
+pub fn read_quantized_quat(cursor: &mut Cursor<&[u8]>) -> io::Result<Quat> {
+    let x = u16_to_float(cursor.read_u16::<LittleEndian>()?, -1.0, 1.0);
+    let y = u16_to_float(cursor.read_u16::<LittleEndian>()?, -1.0, 1.0);
+    let z = u16_to_float(cursor.read_u16::<LittleEndian>()?, -1.0, 1.0);
+    let w = u16_to_float(cursor.read_u16::<LittleEndian>()?, -1.0, 1.0);
+    Ok(Quat::from_xyzw(x, y, z, w))
+}
+
+pub fn write_quantized_quat(bytes: &mut Vec<u8>, quat: &Quat) {
+    for value in quat.to_array() {
+        bytes.extend_from_slice(&float_to_u16(value, -1.0, 1.0).to_le_bytes());
+    }
+}
@@ -0,0 +1,11 @@
+// a region handle is the region's global position in meters, with x in the high 32 bits and y
+// in the low 32 bits. Regions are 256 meters wide, so both are always multiples of 256.
+// https://wiki.secondlife.com/wiki/Region_Handle
+
+pub fn region_handle(global_x: u32, global_y: u32) -> u64 {
+    ((global_x as u64) << 32) | global_y as u64
+}
+
+pub fn region_coordinates(region_handle: u64) -> (u32, u32) {
+    ((region_handle >> 32) as u32, region_handle as u32)
+}
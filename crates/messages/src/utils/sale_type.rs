@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+// how an object is for sale.
+// https://wiki.secondlife.com/wiki/Category:LSL_Sale
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SaleType {
+    NotForSale,
+    /// the object itself is sold
+    Original,
+    /// the buyer gets a copy
+    Copy,
+    /// the buyer gets the object's inventory
+    Contents,
+    Unknown(u8),
+}
+impl SaleType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => SaleType::NotForSale,
+            1 => SaleType::Original,
+            2 => SaleType::Copy,
+            3 => SaleType::Contents,
+            other => SaleType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            SaleType::NotForSale => 0,
+            SaleType::Original => 1,
+            SaleType::Copy => 2,
+            SaleType::Contents => 3,
+            SaleType::Unknown(other) => *other,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+bitflags! {
+    /// The permissions a script can ask the agent for at runtime, with llRequestPermissions.
+    /// These are sent as the questions of a ScriptQuestion, and the ones the agent grants are
+    /// sent back in a ScriptAnswerYes.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ScriptPermissions: u32 {
+        /// take money from the agent
+        const DEBIT = 0x2;
+        /// take the agent's movement controls
+        const TAKE_CONTROLS = 0x4;
+        const REMAP_CONTROLS = 0x8;
+        /// play animations on the agent's avatar
+        const TRIGGER_ANIMATION = 0x10;
+        /// attach the object to the agent's avatar
+        const ATTACH = 0x20;
+        const RELEASE_OWNERSHIP = 0x40;
+        /// link and unlink the agent's objects
+        const CHANGE_LINKS = 0x80;
+        const CHANGE_JOINTS = 0x100;
+        const CHANGE_PERMISSIONS = 0x200;
+        /// follow the agent's camera
+        const TRACK_CAMERA = 0x400;
+        /// move the agent's camera
+        const CONTROL_CAMERA = 0x800;
+        /// teleport the agent
+        const TELEPORT = 0x1000;
+        /// run as part of an experience
+        const EXPERIENCE = 0x2000;
+        const SILENT_ESTATE_MANAGEMENT = 0x4000;
+        /// replace the agent's default animations
+        const OVERRIDE_ANIMATIONS = 0x8000;
+        /// return objects from the agent's land
+        const RETURN_OBJECTS = 0x10000;
+    }
+}
+
+// sent to the UI as the bits, so unknown permissions aren't lost
+impl Serialize for ScriptPermissions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScriptPermissions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
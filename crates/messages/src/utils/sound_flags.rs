@@ -0,0 +1,34 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+bitflags! {
+    /// How a sound attached to an object is played.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct SoundFlags: u8 {
+        /// play the sound over and over
+        const LOOP = 0x01;
+        /// other sounds synced to this one start when it loops
+        const SYNC_MASTER = 0x02;
+        /// start the sound in sync with the nearest sync master
+        const SYNC_SLAVE = 0x04;
+        /// wait for the sync master before starting
+        const SYNC_PENDING = 0x08;
+        /// play the sound after the object's current sound, instead of cutting it off
+        const QUEUE = 0x10;
+        /// stop the object's sound
+        const STOP = 0x20;
+    }
+}
+
+// sent to the UI as the bits, so unknown flags aren't lost
+impl Serialize for SoundFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SoundFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
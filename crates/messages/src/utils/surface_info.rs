@@ -0,0 +1,81 @@
+use crate::utils::packet_fields::{read_vec3, write_vec3};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::Vec3;
+use std::io::{self, Cursor};
+
+// where on an object the agent clicked, sent in ObjectGrab, ObjectGrabUpdate and ObjectDeGrab.
+// Scripts read it with llDetectedTouchUV, llDetectedTouchST, llDetectedTouchFace and the rest.
+// The block is variable, and is left off when the viewer doesn't know where the object was hit.
+// https://wiki.secondlife.com/wiki/ObjectGrab
+
+/// the size of one SurfaceInfo block in bytes
+pub const SURFACE_INFO_SIZE: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurfaceInfo {
+    /// texture coordinates of the hit, with the face's texture transform applied
+    pub uv_coord: Vec3,
+    /// surface coordinates of the hit, from 0 to 1 across the face
+    pub st_coord: Vec3,
+    /// the face that was hit, -1 if it is unknown
+    pub face_index: i32,
+    /// the hit in region coordinates
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub binormal: Vec3,
+}
+
+impl SurfaceInfo {
+    /// a hit where only the position is known. The coordinates and face are set to -1, the
+    /// same as the viewer does when it has no pick information.
+    pub fn at(position: Vec3) -> Self {
+        SurfaceInfo {
+            uv_coord: Vec3::new(-1.0, -1.0, 0.0),
+            st_coord: Vec3::new(-1.0, -1.0, 0.0),
+            face_index: -1,
+            position,
+            normal: Vec3::ZERO,
+            binormal: Vec3::ZERO,
+        }
+    }
+
+    pub fn from_bytes(cursor: &mut Cursor<&[u8]>) -> io::Result<Self> {
+        Ok(SurfaceInfo {
+            uv_coord: read_vec3(cursor)?,
+            st_coord: read_vec3(cursor)?,
+            face_index: cursor.read_i32::<LittleEndian>()?,
+            position: read_vec3(cursor)?,
+            normal: read_vec3(cursor)?,
+            binormal: read_vec3(cursor)?,
+        })
+    }
+
+    pub fn to_bytes(&self, bytes: &mut Vec<u8>) {
+        write_vec3(bytes, &self.uv_coord);
+        write_vec3(bytes, &self.st_coord);
+        bytes.extend_from_slice(&self.face_index.to_le_bytes());
+        write_vec3(bytes, &self.position);
+        write_vec3(bytes, &self.normal);
+        write_vec3(bytes, &self.binormal);
+    }
+}
+
+/// read the count byte and the SurfaceInfo blocks that follow it
+pub fn read_surface_info(cursor: &mut Cursor<&[u8]>) -> io::Result<Vec<SurfaceInfo>> {
+    let count = cursor.read_u8()?;
+    let mut surface_info = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        surface_info.push(SurfaceInfo::from_bytes(cursor)?);
+    }
+    Ok(surface_info)
+}
+
+/// write the count byte and the SurfaceInfo blocks. The count is written even when there are no
+/// blocks, and blocks past 255 are dropped.
+pub fn write_surface_info(bytes: &mut Vec<u8>, surface_info: &[SurfaceInfo]) {
+    let surface_info = &surface_info[..surface_info.len().min(u8::MAX as usize)];
+    bytes.push(surface_info.len() as u8);
+    for block in surface_info {
+        block.to_bytes(bytes);
+    }
+}
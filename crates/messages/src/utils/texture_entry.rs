@@ -0,0 +1,291 @@
+use crate::utils::packet_fields::read_uuid;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+// TextureEntry is the packed per-face appearance of a prim or avatar, sent in ObjectUpdate,
+// AvatarAppearance and others.
+// It is a series of sections, one for each property of a face. Each section starts with the
+// default value for every face, followed by runs of a face bitmask and the value for the faces
+// in the mask. A zero mask ends the section.
+// The masks are variable length, with 7 bits per byte, most significant bits first. Every byte
+// but the last has its high bit set.
+// Older simulators leave off the sections at the end, which keep their defaults.
+// https://wiki.secondlife.com/wiki/Texture_Entry
+
+/// the most faces a TextureEntry can describe
+pub const MAX_FACES: usize = 45;
+/// the longest face mask, in bytes. Seven bits per byte covers MAX_FACES.
+const MAX_MASK_BYTES: usize = 7;
+/// offsets are packed into an i16 over the range -1 to 1
+const OFFSET_PACK_FACTOR: f32 = 0x7FFF as f32;
+/// rotations are packed into an i16, where this is a full turn
+const ROTATION_PACK_FACTOR: f32 = 0x8000 as f32;
+
+/// the appearance of one face
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextureFace {
+    pub texture_id: Uuid,
+    /// RGBA tint of the face. This is sent inverted, so the default white packs to zeroes.
+    pub color: [u8; 4],
+    /// how many times the texture repeats across the face
+    pub repeat_u: f32,
+    pub repeat_v: f32,
+    pub offset_u: f32,
+    pub offset_v: f32,
+    /// rotation of the texture in radians
+    pub rotation: f32,
+    /// bumpmap in the low 5 bits, fullbright in bit 5 and shininess in the top 2 bits
+    pub bump: u8,
+    /// media in bit 0, and texture generation mode in bits 1 and 2
+    pub media_flags: u8,
+    /// between 0 and 1
+    pub glow: f32,
+    /// the material of the face, nil if it has none
+    pub material_id: Uuid,
+}
+impl Default for TextureFace {
+    fn default() -> Self {
+        TextureFace {
+            texture_id: Uuid::nil(),
+            color: [255; 4],
+            repeat_u: 1.0,
+            repeat_v: 1.0,
+            offset_u: 0.0,
+            offset_v: 0.0,
+            rotation: 0.0,
+            bump: 0,
+            media_flags: 0,
+            glow: 0.0,
+            material_id: Uuid::nil(),
+        }
+    }
+}
+
+/// The appearance of every face of an object.
+/// Faces without an override of a property have the default face's value for it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextureEntry {
+    /// the appearance of faces that don't override it
+    pub default: TextureFace,
+    /// every face, with the overrides applied. There are always MAX_FACES of them, since the
+    /// entry doesn't say how many faces the object has.
+    pub faces: Vec<TextureFace>,
+}
+
+/// the properties of a face, in the order their sections are sent
+#[derive(Debug, Clone, Copy)]
+enum Section {
+    TextureId,
+    Color,
+    RepeatU,
+    RepeatV,
+    OffsetU,
+    OffsetV,
+    Rotation,
+    Bump,
+    MediaFlags,
+    Glow,
+    MaterialId,
+}
+const SECTIONS: [Section; 11] = [
+    Section::TextureId,
+    Section::Color,
+    Section::RepeatU,
+    Section::RepeatV,
+    Section::OffsetU,
+    Section::OffsetV,
+    Section::Rotation,
+    Section::Bump,
+    Section::MediaFlags,
+    Section::Glow,
+    Section::MaterialId,
+];
+
+impl Section {
+    // read one value of the section into the face
+    fn read(self, cursor: &mut Cursor<&[u8]>, face: &mut TextureFace) -> io::Result<()> {
+        match self {
+            Section::TextureId => face.texture_id = read_uuid(cursor)?,
+            Section::Color => {
+                let mut color = [0u8; 4];
+                cursor.read_exact(&mut color)?;
+                face.color = color.map(|component| 255 - component);
+            }
+            Section::RepeatU => face.repeat_u = cursor.read_f32::<LittleEndian>()?,
+            Section::RepeatV => face.repeat_v = cursor.read_f32::<LittleEndian>()?,
+            Section::OffsetU => {
+                face.offset_u = cursor.read_i16::<LittleEndian>()? as f32 / OFFSET_PACK_FACTOR
+            }
+            Section::OffsetV => {
+                face.offset_v = cursor.read_i16::<LittleEndian>()? as f32 / OFFSET_PACK_FACTOR
+            }
+            Section::Rotation => {
+                face.rotation =
+                    cursor.read_i16::<LittleEndian>()? as f32 / ROTATION_PACK_FACTOR * TAU
+            }
+            Section::Bump => face.bump = cursor.read_u8()?,
+            Section::MediaFlags => face.media_flags = cursor.read_u8()?,
+            Section::Glow => face.glow = cursor.read_u8()? as f32 / 255.0,
+            Section::MaterialId => face.material_id = read_uuid(cursor)?,
+        }
+        Ok(())
+    }
+
+    // pack the section's property of a face the way it is sent
+    fn pack(self, face: &TextureFace) -> Vec<u8> {
+        match self {
+            Section::TextureId => face.texture_id.as_bytes().to_vec(),
+            Section::Color => face.color.map(|component| 255 - component).to_vec(),
+            Section::RepeatU => face.repeat_u.to_le_bytes().to_vec(),
+            Section::RepeatV => face.repeat_v.to_le_bytes().to_vec(),
+            Section::OffsetU => pack_offset(face.offset_u).to_le_bytes().to_vec(),
+            Section::OffsetV => pack_offset(face.offset_v).to_le_bytes().to_vec(),
+            Section::Rotation => {
+                let turns = (face.rotation % TAU) / TAU;
+                let packed = (turns * ROTATION_PACK_FACTOR)
+                    .round()
+                    .clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+                packed.to_le_bytes().to_vec()
+            }
+            Section::Bump => vec![face.bump],
+            Section::MediaFlags => vec![face.media_flags],
+            Section::Glow => vec![(face.glow.clamp(0.0, 1.0) * 255.0).round() as u8],
+            Section::MaterialId => face.material_id.as_bytes().to_vec(),
+        }
+    }
+
+    // copy the section's property from one face to another
+    fn copy(self, from: &TextureFace, to: &mut TextureFace) {
+        match self {
+            Section::TextureId => to.texture_id = from.texture_id,
+            Section::Color => to.color = from.color,
+            Section::RepeatU => to.repeat_u = from.repeat_u,
+            Section::RepeatV => to.repeat_v = from.repeat_v,
+            Section::OffsetU => to.offset_u = from.offset_u,
+            Section::OffsetV => to.offset_v = from.offset_v,
+            Section::Rotation => to.rotation = from.rotation,
+            Section::Bump => to.bump = from.bump,
+            Section::MediaFlags => to.media_flags = from.media_flags,
+            Section::Glow => to.glow = from.glow,
+            Section::MaterialId => to.material_id = from.material_id,
+        }
+    }
+}
+
+impl TextureEntry {
+    /// the appearance of a face, or the default if the face is out of range
+    pub fn face(&self, index: usize) -> &TextureFace {
+        self.faces.get(index).unwrap_or(&self.default)
+    }
+
+    /// parse a TextureEntry. An empty entry has the default for every face.
+    /// Sections missing from the end keep their defaults, but a section that is cut off in the
+    /// middle is an error.
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let mut entry = TextureEntry {
+            default: TextureFace::default(),
+            faces: vec![TextureFace::default(); MAX_FACES],
+        };
+        for section in SECTIONS {
+            if cursor.position() as usize >= bytes.len() {
+                break;
+            }
+            entry.read_section(&mut cursor, section)?;
+        }
+        Ok(entry)
+    }
+
+    /// pack the entry the way it is sent. Every section is sent, each ending with a zero mask.
+    /// Faces whose property packs the same as the default's aren't sent, and faces that share
+    /// a value are sent together under one mask.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for section in SECTIONS {
+            let default = section.pack(&self.default);
+            bytes.extend_from_slice(&default);
+            let mut overrides: Vec<(u64, Vec<u8>)> = Vec::new();
+            for (index, face) in self.faces.iter().take(MAX_FACES).enumerate() {
+                let value = section.pack(face);
+                if value == default {
+                    continue;
+                }
+                match overrides.iter_mut().find(|(_, other)| *other == value) {
+                    Some((mask, _)) => *mask |= 1u64 << index,
+                    None => overrides.push((1u64 << index, value)),
+                }
+            }
+            for (mask, value) in overrides {
+                write_face_mask(&mut bytes, mask);
+                bytes.extend_from_slice(&value);
+            }
+            bytes.push(0);
+        }
+        bytes
+    }
+
+    // read the default value of a section and its overrides, and apply them to the faces
+    fn read_section(&mut self, cursor: &mut Cursor<&[u8]>, section: Section) -> io::Result<()> {
+        let mut value = TextureFace::default();
+        section.read(cursor, &mut value)?;
+        section.copy(&value, &mut self.default);
+        for face in self.faces.iter_mut() {
+            section.copy(&value, face);
+        }
+        loop {
+            let mask = read_face_mask(cursor)?;
+            if mask == 0 {
+                return Ok(());
+            }
+            section.read(cursor, &mut value)?;
+            for (index, face) in self.faces.iter_mut().enumerate() {
+                if mask & (1u64 << index) != 0 {
+                    section.copy(&value, face);
+                }
+            }
+        }
+    }
+}
+
+// read the bitmask of faces an override applies to. The end of the data also ends the section,
+// since the last section isn't always terminated.
+fn read_face_mask(cursor: &mut Cursor<&[u8]>) -> io::Result<u64> {
+    let mut mask = 0u64;
+    for read in 0..MAX_MASK_BYTES {
+        let byte = match cursor.read_u8() {
+            Ok(byte) => byte,
+            Err(e) if read == 0 && e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        mask = (mask << 7) | (byte & 0x7F) as u64;
+        if byte & 0x80 == 0 {
+            return Ok(mask);
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "TextureEntry face mask is longer than {} bytes",
+            MAX_MASK_BYTES
+        ),
+    ))
+}
+
+// write the bitmask of faces an override applies to, most significant bits first
+fn write_face_mask(bytes: &mut Vec<u8>, mask: u64) {
+    let mut groups = vec![(mask & 0x7F) as u8];
+    let mut rest = mask >> 7;
+    while rest != 0 {
+        groups.push((rest & 0x7F) as u8 | 0x80);
+        rest >>= 7;
+    }
+    bytes.extend(groups.iter().rev());
+}
+
+// offsets are clamped to the range they can be packed in
+fn pack_offset(offset: f32) -> i16 {
+    (offset.clamp(-1.0, 1.0) * OFFSET_PACK_FACTOR).round() as i16
+}
@@ -0,0 +1,177 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+use super::asset_type::AssetType;
+use super::packet_fields::read_uuid;
+
+// the fields shared by TransferRequest, TransferInfo and TransferPacket.
+// https://wiki.secondlife.com/wiki/TransferRequest
+
+/// which transfer queue a transfer goes through
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChannelType {
+    Unknown,
+    Misc,
+    Asset,
+    Other(i32),
+}
+impl ChannelType {
+    pub fn from_bytes(value: i32) -> Self {
+        match value {
+            0 => ChannelType::Unknown,
+            1 => ChannelType::Misc,
+            2 => ChannelType::Asset,
+            value => ChannelType::Other(value),
+        }
+    }
+
+    pub fn to_bytes(&self) -> i32 {
+        match self {
+            ChannelType::Unknown => 0,
+            ChannelType::Misc => 1,
+            ChannelType::Asset => 2,
+            ChannelType::Other(value) => *value,
+        }
+    }
+}
+
+/// where the simulator reads the transferred data from. This decides the format of the params.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SourceType {
+    Unknown,
+    File,
+    /// an asset from the asset server, by asset id
+    Asset,
+    /// an asset from an item in a prim's inventory
+    SimInventoryItem,
+    SimEstate,
+    Other(i32),
+}
+impl SourceType {
+    pub fn from_bytes(value: i32) -> Self {
+        match value {
+            0 => SourceType::Unknown,
+            1 => SourceType::File,
+            2 => SourceType::Asset,
+            3 => SourceType::SimInventoryItem,
+            4 => SourceType::SimEstate,
+            value => SourceType::Other(value),
+        }
+    }
+
+    pub fn to_bytes(&self) -> i32 {
+        match self {
+            SourceType::Unknown => 0,
+            SourceType::File => 1,
+            SourceType::Asset => 2,
+            SourceType::SimInventoryItem => 3,
+            SourceType::SimEstate => 4,
+            SourceType::Other(value) => *value,
+        }
+    }
+}
+
+/// where the receiver should store the transferred data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TargetType {
+    Unknown,
+    File,
+    VFile,
+    Other(i32),
+}
+impl TargetType {
+    pub fn from_bytes(value: i32) -> Self {
+        match value {
+            0 => TargetType::Unknown,
+            1 => TargetType::File,
+            2 => TargetType::VFile,
+            value => TargetType::Other(value),
+        }
+    }
+
+    pub fn to_bytes(&self) -> i32 {
+        match self {
+            TargetType::Unknown => 0,
+            TargetType::File => 1,
+            TargetType::VFile => 2,
+            TargetType::Other(value) => *value,
+        }
+    }
+}
+
+/// the state of a transfer, sent in TransferInfo and every TransferPacket
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransferStatus {
+    /// more packets will follow
+    Ok,
+    /// this is the last packet
+    Done,
+    Skip,
+    Abort,
+    Error,
+    UnknownSource,
+    InsufficientPermissions,
+    NotFound,
+    Other(i32),
+}
+impl TransferStatus {
+    pub fn from_bytes(value: i32) -> Self {
+        match value {
+            0 => TransferStatus::Ok,
+            1 => TransferStatus::Done,
+            2 => TransferStatus::Skip,
+            3 => TransferStatus::Abort,
+            -1 => TransferStatus::Error,
+            -2 => TransferStatus::UnknownSource,
+            -3 => TransferStatus::InsufficientPermissions,
+            -4 => TransferStatus::NotFound,
+            value => TransferStatus::Other(value),
+        }
+    }
+
+    pub fn to_bytes(&self) -> i32 {
+        match self {
+            TransferStatus::Ok => 0,
+            TransferStatus::Done => 1,
+            TransferStatus::Skip => 2,
+            TransferStatus::Abort => 3,
+            TransferStatus::Error => -1,
+            TransferStatus::UnknownSource => -2,
+            TransferStatus::InsufficientPermissions => -3,
+            TransferStatus::NotFound => -4,
+            TransferStatus::Other(value) => *value,
+        }
+    }
+
+    /// true if the transfer has stopped without delivering the data
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, TransferStatus::Ok | TransferStatus::Done)
+    }
+}
+
+/// the params of a transfer with the Asset source type
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetTransferParams {
+    pub asset_id: Uuid,
+    pub asset_type: AssetType,
+}
+impl AssetTransferParams {
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let asset_id = read_uuid(&mut cursor)?;
+        // the asset type is sent as an S32, but asset types are only ever a byte
+        let asset_type = cursor.read_i32::<LittleEndian>()?;
+        Ok(AssetTransferParams {
+            asset_id,
+            asset_type: AssetType::from_bytes(asset_type as i8),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(20);
+        bytes.extend_from_slice(self.asset_id.as_bytes());
+        bytes.extend_from_slice(&(self.asset_type.to_bytes() as i32).to_le_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+// the slots an avatar can wear clothing and body parts in.
+// https://wiki.secondlife.com/wiki/WearableType
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WearableType {
+    Shape,
+    Skin,
+    Hair,
+    Eyes,
+    Shirt,
+    Pants,
+    Shoes,
+    Socks,
+    Jacket,
+    Gloves,
+    Undershirt,
+    Underpants,
+    Skirt,
+    Alpha,
+    Tattoo,
+    Physics,
+    Universal,
+    Unknown(u8),
+}
+impl WearableType {
+    /// the number of known wearable slots
+    pub const COUNT: usize = 17;
+
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => WearableType::Shape,
+            1 => WearableType::Skin,
+            2 => WearableType::Hair,
+            3 => WearableType::Eyes,
+            4 => WearableType::Shirt,
+            5 => WearableType::Pants,
+            6 => WearableType::Shoes,
+            7 => WearableType::Socks,
+            8 => WearableType::Jacket,
+            9 => WearableType::Gloves,
+            10 => WearableType::Undershirt,
+            11 => WearableType::Underpants,
+            12 => WearableType::Skirt,
+            13 => WearableType::Alpha,
+            14 => WearableType::Tattoo,
+            15 => WearableType::Physics,
+            16 => WearableType::Universal,
+            other => WearableType::Unknown(other),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            WearableType::Shape => 0,
+            WearableType::Skin => 1,
+            WearableType::Hair => 2,
+            WearableType::Eyes => 3,
+            WearableType::Shirt => 4,
+            WearableType::Pants => 5,
+            WearableType::Shoes => 6,
+            WearableType::Socks => 7,
+            WearableType::Jacket => 8,
+            WearableType::Gloves => 9,
+            WearableType::Undershirt => 10,
+            WearableType::Underpants => 11,
+            WearableType::Skirt => 12,
+            WearableType::Alpha => 13,
+            WearableType::Tattoo => 14,
+            WearableType::Physics => 15,
+            WearableType::Universal => 16,
+            WearableType::Unknown(other) => *other,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// Packets with the zerocoded flag squash runs of zero bytes in everything after the extra header,
+// apart from the appended acks. A run is sent as a zero followed by the number of zeros, so a run
+// longer than 255 is sent as several.
+
+/// expand the runs of zeros in zerocoded bytes. A zero at the very end without a count is kept as
+/// a single zero, rather than failing the whole packet.
+pub fn zero_decode(bytes: &[u8]) -> Vec<u8> {
+    let mut dest = Vec::with_capacity(bytes.len() * 2);
+    let mut iter = bytes.iter();
+    while let Some(byte) = iter.next() {
+        if *byte == 0x00 {
+            let repeat_count = iter.next().copied().unwrap_or(1) as usize;
+            dest.resize(dest.len() + repeat_count, 0x00);
+        } else {
+            dest.push(*byte);
+        }
+    }
+    dest
+}
+
+/// squash the runs of zeros in bytes, for a packet with the zerocoded flag
+pub fn zero_encode(bytes: &[u8]) -> Vec<u8> {
+    let mut dest = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == 0x00 {
+            // count the zeros, up to as many as one count byte can hold
+            let mut count = 1;
+            while i + count < bytes.len() && bytes[i + count] == 0x00 && count < u8::MAX as usize {
+                count += 1;
+            }
+            dest.push(0x00);
+            dest.push(count as u8);
+            i += count;
+        } else {
+            dest.push(bytes[i]);
+            i += 1;
+        }
+    }
+    dest
+}
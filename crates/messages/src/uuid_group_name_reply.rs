@@ -0,0 +1,73 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 238
+// Frequency: Low
+
+impl Packet {
+    pub fn new_uuid_group_name_reply(uuid_group_name_reply: UUIDGroupNameReply) -> Self {
+        Packet {
+            header: Header {
+                id: 238,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::UUIDGroupNameReply(Box::new(uuid_group_name_reply)),
+        }
+    }
+}
+
+/// The names of groups, sent in answer to a UUIDGroupNameRequest.
+/// https://wiki.secondlife.com/wiki/UUIDGroupNameReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct UUIDGroupNameReply {
+    pub names: Vec<GroupName>,
+}
+
+/// the name of a group
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupName {
+    pub id: Uuid,
+    pub name: String,
+}
+
+impl PacketData for UUIDGroupNameReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u8()?;
+        let mut names = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            names.push(GroupName {
+                id: read_uuid(&mut cursor)?,
+                name: read_string_1(&mut cursor)?,
+            });
+        }
+        Ok(UUIDGroupNameReply { names })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let names = &self.names[..self.names.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::new();
+        bytes.push(names.len() as u8);
+        for name in names {
+            bytes.extend_from_slice(name.id.as_bytes());
+            write_string_1(&mut bytes, &name.name);
+        }
+        bytes
+    }
+}
@@ -0,0 +1,87 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_string_1, read_uuid, write_string_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 236
+// Frequency: Low
+
+impl Packet {
+    pub fn new_uuid_name_reply(uuid_name_reply: UUIDNameReply) -> Self {
+        Packet {
+            header: Header {
+                id: 236,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::UUIDNameReply(Box::new(uuid_name_reply)),
+        }
+    }
+}
+
+/// The names of avatars, sent in answer to a UUIDNameRequest.
+/// https://wiki.secondlife.com/wiki/UUIDNameReply
+#[derive(Debug, Clone, PartialEq)]
+pub struct UUIDNameReply {
+    pub names: Vec<AvatarName>,
+}
+
+/// the legacy name of an avatar
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvatarName {
+    pub id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+}
+impl AvatarName {
+    /// the name as it is shown to users. Avatars without a last name have the placeholder
+    /// "Resident", which is left off.
+    pub fn full_name(&self) -> String {
+        if self.last_name.is_empty() || self.last_name == "Resident" {
+            self.first_name.clone()
+        } else {
+            format!("{} {}", self.first_name, self.last_name)
+        }
+    }
+}
+
+impl PacketData for UUIDNameReply {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u8()?;
+        let mut names = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            names.push(AvatarName {
+                id: read_uuid(&mut cursor)?,
+                first_name: read_string_1(&mut cursor)?,
+                last_name: read_string_1(&mut cursor)?,
+            });
+        }
+        Ok(UUIDNameReply { names })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let names = &self.names[..self.names.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::new();
+        bytes.push(names.len() as u8);
+        for name in names {
+            bytes.extend_from_slice(name.id.as_bytes());
+            write_string_1(&mut bytes, &name.first_name);
+            write_string_1(&mut bytes, &name.last_name);
+        }
+        bytes
+    }
+}
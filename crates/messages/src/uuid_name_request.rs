@@ -0,0 +1,62 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::ReadBytesExt;
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 235
+// Frequency: Low
+
+impl Packet {
+    pub fn new_uuid_name_request(uuid_name_request: UUIDNameRequest) -> Self {
+        Packet {
+            header: Header {
+                id: 235,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::UUIDNameRequest(Box::new(uuid_name_request)),
+        }
+    }
+}
+
+/// Asks the simulator for the names of avatars. The simulator answers with one or more
+/// UUIDNameReply packets.
+/// https://wiki.secondlife.com/wiki/UUIDNameRequest
+#[derive(Debug, Clone, PartialEq)]
+pub struct UUIDNameRequest {
+    pub ids: Vec<Uuid>,
+}
+
+impl PacketData for UUIDNameRequest {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let count = cursor.read_u8()?;
+        let mut ids = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            ids.push(read_uuid(&mut cursor)?);
+        }
+        Ok(UUIDNameRequest { ids })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let ids = &self.ids[..self.ids.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::with_capacity(1 + ids.len() * 16);
+        bytes.push(ids.len() as u8);
+        for id in ids {
+            bytes.extend_from_slice(id.as_bytes());
+        }
+        bytes
+    }
+}
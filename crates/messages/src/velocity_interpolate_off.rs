@@ -0,0 +1,58 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 126
+// Frequency: Low
+
+impl Packet {
+    pub fn new_velocity_interpolate_off(velocity_interpolate_off: VelocityInterpolateOff) -> Self {
+        Packet {
+            header: Header {
+                id: 126,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::VelocityInterpolateOff(Box::new(velocity_interpolate_off)),
+        }
+    }
+}
+
+/// Asks the simulator to stop extrapolating the motion of objects from their velocity, so objects
+/// only move when an update for them arrives. The simulator forgets it when the circuit is
+/// restarted.
+/// https://wiki.secondlife.com/wiki/VelocityInterpolateOff
+#[derive(Debug, Clone, PartialEq)]
+pub struct VelocityInterpolateOff {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl PacketData for VelocityInterpolateOff {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(VelocityInterpolateOff {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,58 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::read_uuid;
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use std::io::{self, Cursor};
+use uuid::Uuid;
+
+// ID: 125
+// Frequency: Low
+
+impl Packet {
+    pub fn new_velocity_interpolate_on(velocity_interpolate_on: VelocityInterpolateOn) -> Self {
+        Packet {
+            header: Header {
+                id: 125,
+                frequency: PacketFrequency::Low,
+                reliable: true,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::VelocityInterpolateOn(Box::new(velocity_interpolate_on)),
+        }
+    }
+}
+
+/// Asks the simulator to extrapolate the motion of objects from their velocity between updates,
+/// so moving objects glide instead of jumping. Simulators do this by default, and forget it when
+/// the circuit is restarted.
+/// https://wiki.secondlife.com/wiki/VelocityInterpolateOn
+#[derive(Debug, Clone, PartialEq)]
+pub struct VelocityInterpolateOn {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl PacketData for VelocityInterpolateOn {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        Ok(VelocityInterpolateOn {
+            agent_id: read_uuid(&mut cursor)?,
+            session_id: read_uuid(&mut cursor)?,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Sent from the session to the UI when the UI sets velocity interpolation, with whether the
+/// simulators are now interpolating the motion of objects.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VelocityInterpolationStatus {
+    pub enabled: bool,
+}
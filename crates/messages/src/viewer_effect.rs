@@ -0,0 +1,498 @@
+use crate::packet_types::PacketType;
+use crate::utils::packet_fields::{read_uuid, read_variable_1, write_variable_1};
+
+use super::{
+    header::{Header, PacketFrequency},
+    packet::{Packet, PacketData},
+};
+use byteorder::{LittleEndian, ReadBytesExt};
+use glam::DVec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Cursor, Read};
+use uuid::Uuid;
+
+// ID: 17
+// Frequency: Medium
+
+/// the length of the type data of the spiral effects, like Beam and Sphere
+pub const SPIRAL_DATA_SIZE: usize = 56;
+/// the length of the type data of LookAt and PointAt
+pub const LOOK_AT_DATA_SIZE: usize = 57;
+
+impl Packet {
+    pub fn new_viewer_effect(viewer_effect: ViewerEffect) -> Self {
+        Packet {
+            header: Header {
+                id: 17,
+                frequency: PacketFrequency::Medium,
+                reliable: false,
+                sequence_number: 0,
+                appended_acks: false,
+                zerocoded: false,
+                resent: false,
+                ack_list: None,
+                size: None,
+            },
+            body: PacketType::ViewerEffect(Box::new(viewer_effect)),
+        }
+    }
+}
+
+/// Effects drawn around avatars, like the beam from an avatar's hand to the object it is editing,
+/// or where an avatar is looking and pointing.
+/// Viewers send their own effects to the simulator, which passes them on to the viewers nearby.
+/// Each effect has a blob of type data, whose layout depends on its type. Blobs of types that
+/// aren't known, or that don't have the layout their type should, are kept as raw bytes, since
+/// grids add effects of their own.
+/// https://wiki.secondlife.com/wiki/ViewerEffect
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewerEffect {
+    pub agent_id: Uuid,
+    pub session_id: Uuid,
+    pub effects: Vec<Effect>,
+}
+
+/// one effect
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Effect {
+    /// the id of this effect. Sending an effect again with the same id updates it.
+    pub id: Uuid,
+    /// the agent that made the effect
+    pub agent_id: Uuid,
+    pub effect_type: EffectType,
+    /// how long the effect lasts, in seconds
+    pub duration: f32,
+    /// red, green, blue and alpha
+    pub color: [u8; 4],
+    pub data: EffectData,
+}
+
+/// the kinds of effects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EffectType {
+    Beam,
+    Glow,
+    Point,
+    Trail,
+    Sphere,
+    Spiral,
+    /// the beam drawn to objects being edited
+    Edit,
+    LookAt,
+    PointAt,
+    VoiceVisualizer,
+    Blob,
+    Unknown(u8),
+}
+impl EffectType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            7 => EffectType::Beam,
+            8 => EffectType::Glow,
+            9 => EffectType::Point,
+            10 => EffectType::Trail,
+            11 => EffectType::Sphere,
+            12 => EffectType::Spiral,
+            13 => EffectType::Edit,
+            14 => EffectType::LookAt,
+            15 => EffectType::PointAt,
+            16 => EffectType::VoiceVisualizer,
+            18 => EffectType::Blob,
+            byte => EffectType::Unknown(byte),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            EffectType::Beam => 7,
+            EffectType::Glow => 8,
+            EffectType::Point => 9,
+            EffectType::Trail => 10,
+            EffectType::Sphere => 11,
+            EffectType::Spiral => 12,
+            EffectType::Edit => 13,
+            EffectType::LookAt => 14,
+            EffectType::PointAt => 15,
+            EffectType::VoiceVisualizer => 16,
+            EffectType::Blob => 18,
+            EffectType::Unknown(byte) => *byte,
+        }
+    }
+
+    /// true for the effects drawn between two points, whose type data is a SpiralData
+    pub fn is_spiral(&self) -> bool {
+        matches!(
+            self,
+            EffectType::Beam
+                | EffectType::Glow
+                | EffectType::Point
+                | EffectType::Trail
+                | EffectType::Sphere
+                | EffectType::Spiral
+                | EffectType::Edit
+        )
+    }
+}
+
+/// the type data of an effect
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EffectData {
+    Spiral(SpiralData),
+    LookAt(LookAtData),
+    PointAt(PointAtData),
+    /// type data that isn't understood, as it was sent
+    Raw(Vec<u8>),
+}
+
+/// the type data of Beam, Sphere and the other effects drawn from a source to a target
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpiralData {
+    /// the object the effect starts from, usually the agent's avatar
+    pub source_id: Uuid,
+    /// the object the effect ends on, nil if it ends on a point
+    pub target_id: Uuid,
+    /// the global position the effect ends on, in meters
+    pub target_position: DVec3,
+}
+
+/// the type data of LookAt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LookAtData {
+    /// the avatar that is looking
+    pub source_id: Uuid,
+    /// the object looked at, nil if it is a point
+    pub target_id: Uuid,
+    /// the offset from the target object, or the global position looked at if there isn't one
+    pub target_position: DVec3,
+    pub look_at_type: LookAtType,
+}
+
+/// the type data of PointAt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PointAtData {
+    /// the avatar that is pointing
+    pub source_id: Uuid,
+    /// the object pointed at, nil if it is a point
+    pub target_id: Uuid,
+    /// the offset from the target object, or the global position pointed at if there isn't one
+    pub target_position: DVec3,
+    pub point_at_type: PointAtType,
+}
+
+/// why an avatar is looking at something
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LookAtType {
+    None,
+    Idle,
+    AutoListen,
+    FreeLook,
+    Respond,
+    Hover,
+    Conversation,
+    Select,
+    Focus,
+    Mouselook,
+    /// stop looking
+    Clear,
+    Unknown(u8),
+}
+impl LookAtType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => LookAtType::None,
+            1 => LookAtType::Idle,
+            2 => LookAtType::AutoListen,
+            3 => LookAtType::FreeLook,
+            4 => LookAtType::Respond,
+            5 => LookAtType::Hover,
+            6 => LookAtType::Conversation,
+            7 => LookAtType::Select,
+            8 => LookAtType::Focus,
+            9 => LookAtType::Mouselook,
+            10 => LookAtType::Clear,
+            byte => LookAtType::Unknown(byte),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            LookAtType::None => 0,
+            LookAtType::Idle => 1,
+            LookAtType::AutoListen => 2,
+            LookAtType::FreeLook => 3,
+            LookAtType::Respond => 4,
+            LookAtType::Hover => 5,
+            LookAtType::Conversation => 6,
+            LookAtType::Select => 7,
+            LookAtType::Focus => 8,
+            LookAtType::Mouselook => 9,
+            LookAtType::Clear => 10,
+            LookAtType::Unknown(byte) => *byte,
+        }
+    }
+}
+
+/// why an avatar is pointing at something
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PointAtType {
+    None,
+    Select,
+    Grab,
+    /// stop pointing
+    Clear,
+    Unknown(u8),
+}
+impl PointAtType {
+    pub fn from_bytes(byte: u8) -> Self {
+        match byte {
+            0 => PointAtType::None,
+            1 => PointAtType::Select,
+            2 => PointAtType::Grab,
+            3 => PointAtType::Clear,
+            byte => PointAtType::Unknown(byte),
+        }
+    }
+
+    pub fn to_bytes(&self) -> u8 {
+        match self {
+            PointAtType::None => 0,
+            PointAtType::Select => 1,
+            PointAtType::Grab => 2,
+            PointAtType::Clear => 3,
+            PointAtType::Unknown(byte) => *byte,
+        }
+    }
+}
+
+impl ViewerEffect {
+    /// effects to send. The agent and session IDs are left nil, for the session to fill in.
+    pub fn new(effects: Vec<Effect>) -> Self {
+        ViewerEffect {
+            agent_id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            effects,
+        }
+    }
+}
+
+impl Effect {
+    /// a new effect, with a fresh id. The agent ID is left nil, for the session to fill in.
+    pub fn new(effect_type: EffectType, duration: f32, color: [u8; 4], data: EffectData) -> Self {
+        Effect {
+            id: Uuid::new_v4(),
+            agent_id: Uuid::nil(),
+            effect_type,
+            duration,
+            color,
+            data,
+        }
+    }
+
+    /// a beam from the source object to the target, like the one drawn while editing an object
+    pub fn beam(
+        source_id: Uuid,
+        target_id: Uuid,
+        target_position: DVec3,
+        color: [u8; 4],
+        duration: f32,
+    ) -> Self {
+        Effect::new(
+            EffectType::Beam,
+            duration,
+            color,
+            EffectData::Spiral(SpiralData {
+                source_id,
+                target_id,
+                target_position,
+            }),
+        )
+    }
+
+    /// make an avatar look at an object, or at a point if the target is nil
+    pub fn look_at(
+        source_id: Uuid,
+        target_id: Uuid,
+        target_position: DVec3,
+        look_at_type: LookAtType,
+        duration: f32,
+    ) -> Self {
+        Effect::new(
+            EffectType::LookAt,
+            duration,
+            [0; 4],
+            EffectData::LookAt(LookAtData {
+                source_id,
+                target_id,
+                target_position,
+                look_at_type,
+            }),
+        )
+    }
+
+    /// make an avatar point at an object, or at a point if the target is nil
+    pub fn point_at(
+        source_id: Uuid,
+        target_id: Uuid,
+        target_position: DVec3,
+        point_at_type: PointAtType,
+        duration: f32,
+    ) -> Self {
+        Effect::new(
+            EffectType::PointAt,
+            duration,
+            [0; 4],
+            EffectData::PointAt(PointAtData {
+                source_id,
+                target_id,
+                target_position,
+                point_at_type,
+            }),
+        )
+    }
+}
+
+impl EffectData {
+    /// read the type data of an effect. Data that doesn't have the layout of its type is kept
+    /// raw.
+    pub fn from_bytes(effect_type: EffectType, bytes: &[u8]) -> Self {
+        let mut cursor = Cursor::new(bytes);
+        let data = match effect_type {
+            EffectType::LookAt if bytes.len() == LOOK_AT_DATA_SIZE => read_targets(&mut cursor)
+                .and_then(|(source_id, target_id, target_position)| {
+                    Ok(EffectData::LookAt(LookAtData {
+                        source_id,
+                        target_id,
+                        target_position,
+                        look_at_type: LookAtType::from_bytes(cursor.read_u8()?),
+                    }))
+                }),
+            EffectType::PointAt if bytes.len() == LOOK_AT_DATA_SIZE => read_targets(&mut cursor)
+                .and_then(|(source_id, target_id, target_position)| {
+                    Ok(EffectData::PointAt(PointAtData {
+                        source_id,
+                        target_id,
+                        target_position,
+                        point_at_type: PointAtType::from_bytes(cursor.read_u8()?),
+                    }))
+                }),
+            effect_type if effect_type.is_spiral() && bytes.len() == SPIRAL_DATA_SIZE => {
+                read_targets(&mut cursor).map(|(source_id, target_id, target_position)| {
+                    EffectData::Spiral(SpiralData {
+                        source_id,
+                        target_id,
+                        target_position,
+                    })
+                })
+            }
+            _ => Ok(EffectData::Raw(bytes.to_vec())),
+        };
+        data.unwrap_or_else(|_| EffectData::Raw(bytes.to_vec()))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            EffectData::Spiral(data) => {
+                let mut bytes = Vec::with_capacity(SPIRAL_DATA_SIZE);
+                write_targets(
+                    &mut bytes,
+                    data.source_id,
+                    data.target_id,
+                    data.target_position,
+                );
+                bytes
+            }
+            EffectData::LookAt(data) => {
+                let mut bytes = Vec::with_capacity(LOOK_AT_DATA_SIZE);
+                write_targets(
+                    &mut bytes,
+                    data.source_id,
+                    data.target_id,
+                    data.target_position,
+                );
+                bytes.push(data.look_at_type.to_bytes());
+                bytes
+            }
+            EffectData::PointAt(data) => {
+                let mut bytes = Vec::with_capacity(LOOK_AT_DATA_SIZE);
+                write_targets(
+                    &mut bytes,
+                    data.source_id,
+                    data.target_id,
+                    data.target_position,
+                );
+                bytes.push(data.point_at_type.to_bytes());
+                bytes
+            }
+            EffectData::Raw(bytes) => bytes.clone(),
+        }
+    }
+}
+
+// the source, target and position that start the type data of every known effect
+fn read_targets(cursor: &mut Cursor<&[u8]>) -> io::Result<(Uuid, Uuid, DVec3)> {
+    let source_id = read_uuid(cursor)?;
+    let target_id = read_uuid(cursor)?;
+    let target_position = DVec3::new(
+        cursor.read_f64::<LittleEndian>()?,
+        cursor.read_f64::<LittleEndian>()?,
+        cursor.read_f64::<LittleEndian>()?,
+    );
+    Ok((source_id, target_id, target_position))
+}
+
+fn write_targets(bytes: &mut Vec<u8>, source_id: Uuid, target_id: Uuid, position: DVec3) {
+    bytes.extend_from_slice(source_id.as_bytes());
+    bytes.extend_from_slice(target_id.as_bytes());
+    bytes.extend_from_slice(&position.x.to_le_bytes());
+    bytes.extend_from_slice(&position.y.to_le_bytes());
+    bytes.extend_from_slice(&position.z.to_le_bytes());
+}
+
+impl PacketData for ViewerEffect {
+    fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = Cursor::new(bytes);
+        let agent_id = read_uuid(&mut cursor)?;
+        let session_id = read_uuid(&mut cursor)?;
+        let count = cursor.read_u8()?;
+        let mut effects = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let id = read_uuid(&mut cursor)?;
+            let agent_id = read_uuid(&mut cursor)?;
+            let effect_type = EffectType::from_bytes(cursor.read_u8()?);
+            let duration = cursor.read_f32::<LittleEndian>()?;
+            let mut color = [0; 4];
+            cursor.read_exact(&mut color)?;
+            let data = EffectData::from_bytes(effect_type, &read_variable_1(&mut cursor)?);
+            effects.push(Effect {
+                id,
+                agent_id,
+                effect_type,
+                duration,
+                color,
+                data,
+            });
+        }
+        Ok(ViewerEffect {
+            agent_id,
+            session_id,
+            effects,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let effects = &self.effects[..self.effects.len().min(u8::MAX as usize)];
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.agent_id.as_bytes());
+        bytes.extend_from_slice(self.session_id.as_bytes());
+        bytes.push(effects.len() as u8);
+        for effect in effects {
+            bytes.extend_from_slice(effect.id.as_bytes());
+            bytes.extend_from_slice(effect.agent_id.as_bytes());
+            bytes.push(effect.effect_type.to_bytes());
+            bytes.extend_from_slice(&effect.duration.to_le_bytes());
+            bytes.extend_from_slice(&effect.color);
+            write_variable_1(&mut bytes, &effect.data.to_bytes());
+        }
+        bytes
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::agent_wearables_update::Wearable;
+
+/// Sent from the session to the UI with the wearables the agent has on, as a JSON array.
+/// This does not exist in the packet spec.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Wearables {
+    pub wearables: Vec<Wearable>,
+}
@@ -1,5 +1,12 @@
 use hex::FromHex;
-use metaverse_messages::packet::Packet;
+use metaverse_messages::{
+    chat_from_viewer::{ChatFromViewer, ClientChatType},
+    object_add::PrimBuilder,
+    packet::{Packet, MAX_APPENDED_ACKS, MAX_PACKET_SIZE},
+    packet_types::PacketType,
+    start_ping_check::StartPingCheck,
+};
+use uuid::Uuid;
 
 #[test]
 fn test_acks_parse() {
@@ -24,3 +31,95 @@ fn test_acks_firestorm_parse() {
         Err(e) => eprintln!("Error creating packet: {}", e),
     }
 }
+
+#[test]
+fn test_appended_acks_are_not_part_of_the_body() {
+    // a CompletePingCheck carrying two acks
+    let test_packet = Vec::from_hex("1000000005000207000000010000000202").unwrap();
+    let packet = Packet::from_bytes(&test_packet).unwrap();
+    assert_eq!(packet.header.ack_list, Some(vec![1, 2]));
+    match &packet.body {
+        PacketType::CompletePingCheck(ping) => assert_eq!(ping.ping_id, 7),
+        _ => panic!("expected CompletePingCheck"),
+    }
+    assert_eq!(packet.to_bytes(), test_packet);
+}
+
+#[test]
+fn test_append_acks_to_packet() {
+    let mut packet = Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 3,
+        oldest_unacked: 0x01010101,
+    });
+    let mut pending = vec![10, 11];
+    packet.append_acks(&mut pending);
+    assert!(pending.is_empty());
+    let bytes = packet.to_bytes();
+    assert_eq!(bytes[0] & 0x10, 0x10);
+    assert_eq!(
+        bytes[bytes.len() - 9..],
+        Vec::from_hex("0000000a0000000b02").unwrap()
+    );
+
+    // more acks join the ones already there
+    let mut pending = vec![12];
+    packet.append_acks(&mut pending);
+    assert_eq!(packet.header.ack_list, Some(vec![10, 11, 12]));
+}
+
+#[test]
+fn test_append_acks_stays_under_the_mtu() {
+    let mut packet = Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 3,
+        oldest_unacked: 0x01010101,
+    });
+    let mut pending: Vec<u32> = (0..1000).collect();
+    packet.append_acks(&mut pending);
+    // a count byte can't hold more than 255 acks
+    assert_eq!(
+        packet.header.ack_list.as_ref().unwrap().len(),
+        MAX_APPENDED_ACKS
+    );
+    assert_eq!(pending.len(), 1000 - MAX_APPENDED_ACKS);
+    assert_eq!(pending[0], MAX_APPENDED_ACKS as u32);
+    assert!(packet.to_bytes().len() <= MAX_PACKET_SIZE);
+
+    // a big packet only takes the acks that fit
+    let mut packet = Packet::new_chat_from_viewer(ChatFromViewer {
+        agent_id: Uuid::nil(),
+        session_id: Uuid::nil(),
+        channel: 0,
+        message: "a".repeat(1000),
+        message_type: ClientChatType::Normal,
+    });
+    let mut pending: Vec<u32> = (0..100).collect();
+    packet.append_acks(&mut pending);
+    let bytes = packet.to_bytes();
+    assert!(bytes.len() <= MAX_PACKET_SIZE);
+    assert!(bytes.len() > MAX_PACKET_SIZE - 4);
+    let appended = packet.header.ack_list.unwrap().len();
+    assert_eq!(pending.len(), 100 - appended);
+    assert_eq!(pending[0], appended as u32);
+}
+
+#[test]
+fn test_zerocoded_packet_with_appended_acks() {
+    let mut packet = Packet::new_object_add(PrimBuilder::cube().build());
+    let mut pending = vec![0x00000100, 0x00010000];
+    packet.append_acks(&mut pending);
+    let bytes = packet.to_bytes();
+    assert_eq!(bytes[0] & 0x90, 0x90);
+    // the acks are written as they are, after the zerocoded body
+    assert_eq!(
+        bytes[bytes.len() - 9..],
+        Vec::from_hex("000001000001000002").unwrap()
+    );
+    let parsed = Packet::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.header.ack_list, Some(vec![0x00000100, 0x00010000]));
+    match parsed.body {
+        PacketType::ObjectAdd(object_add) => {
+            assert_eq!(*object_add, PrimBuilder::cube().build())
+        }
+        _ => panic!("expected ObjectAdd"),
+    }
+}
@@ -0,0 +1,48 @@
+use hex::FromHex;
+use metaverse_messages::{
+    agent_pause::AgentPause,
+    agent_resume::AgentResume,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+};
+use uuid::uuid;
+
+#[test]
+fn test_agent_pause() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a00ffff004e",
+        "8a1f4b0c3f4e4d529c1a2b7e5d6f8a90",
+        "0d9e7c6b5a4f4e3d8c2b1a0f9e8d7c6b",
+        "07000000",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let agent_pause = match &packet.body {
+        PacketType::AgentPause(agent_pause) => agent_pause.clone(),
+        _ => panic!("expected AgentPause"),
+    };
+    assert_eq!(
+        *agent_pause,
+        AgentPause {
+            agent_id: uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90"),
+            session_id: uuid!("0d9e7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b"),
+            serial_num: 7,
+        }
+    );
+    assert_eq!(agent_pause.to_bytes(), bytes[10..]);
+}
+
+#[test]
+fn test_agent_resume() {
+    let resume = AgentResume::new(8);
+    assert!(resume.agent_id.is_nil());
+    assert_eq!(resume.to_bytes().len(), 36);
+    assert_eq!(&resume.to_bytes()[32..], &8u32.to_le_bytes());
+
+    let packet = Packet::new_agent_resume(resume.clone());
+    assert_eq!(packet.header.id, 79);
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::AgentResume(parsed) => assert_eq!(*parsed, resume),
+        _ => panic!("expected AgentResume"),
+    }
+}
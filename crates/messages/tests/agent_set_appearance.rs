@@ -0,0 +1,72 @@
+use glam::Vec3;
+use metaverse_messages::{
+    agent_set_appearance::{AgentSetAppearance, WearableCache},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const SESSION_ID: Uuid = uuid!("9f3c1b2a-4d5e-4f60-8a7b-1c2d3e4f5a6b");
+const BAKED_HEAD: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+
+fn appearance(wearable_data: Vec<WearableCache>) -> AgentSetAppearance {
+    let mut texture_entry = BAKED_HEAD.as_bytes().to_vec();
+    texture_entry.push(0);
+    AgentSetAppearance {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+        serial_num: 3,
+        size: Vec3::new(0.45, 0.6, 1.9),
+        wearable_data,
+        texture_entry,
+        visual_params: (0..218).map(|param| param as u8).collect(),
+    }
+}
+
+#[test]
+fn test_agent_set_appearance_without_wearable_data() {
+    let appearance = appearance(vec![]);
+    let bytes = appearance.to_bytes();
+    assert_eq!(&bytes[32..36], &3u32.to_le_bytes());
+    // the WearableData block count comes right after the size, and the TextureEntry follows it
+    assert_eq!(bytes[48], 0);
+    assert_eq!(&bytes[49..51], &17u16.to_le_bytes());
+    assert_eq!(AgentSetAppearance::from_bytes(&bytes).unwrap(), appearance);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_agent_set_appearance(appearance.clone()).to_bytes())
+            .unwrap();
+    match packet.body {
+        PacketType::AgentSetAppearance(parsed) => {
+            assert_eq!(*parsed, appearance);
+            assert_eq!(
+                parsed.texture_entry().unwrap().face(0).texture_id,
+                BAKED_HEAD
+            );
+        }
+        _ => panic!("expected AgentSetAppearance"),
+    }
+}
+
+#[test]
+fn test_agent_set_appearance_with_wearable_data() {
+    let appearance = appearance(
+        (0..11)
+            .map(|texture_index| WearableCache {
+                cache_id: Uuid::new_v4(),
+                texture_index,
+            })
+            .collect(),
+    );
+    let bytes = appearance.to_bytes();
+    assert_eq!(bytes[48], 11);
+    assert_eq!(AgentSetAppearance::from_bytes(&bytes).unwrap(), appearance);
+}
+
+#[test]
+fn test_truncated_agent_set_appearance_is_an_error() {
+    let bytes = appearance(vec![]).to_bytes();
+    assert!(AgentSetAppearance::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(AgentSetAppearance::from_bytes(&bytes[..48]).is_err());
+}
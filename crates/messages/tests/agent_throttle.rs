@@ -0,0 +1,79 @@
+use metaverse_messages::{
+    agent_throttle::{AgentThrottle, Throttles},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+};
+use uuid::uuid;
+
+fn test_throttle() -> AgentThrottle {
+    AgentThrottle {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        circuit_code: 0x01020304,
+        gen_counter: 7,
+        throttles: Throttles {
+            resend: 1.0,
+            land: 2.0,
+            wind: 3.0,
+            cloud: 4.0,
+            task: 5.0,
+            texture: 6.0,
+            asset: 7.0,
+        },
+    }
+}
+
+#[test]
+fn test_agent_throttle_packing() {
+    let bytes = test_throttle().to_bytes();
+    // AgentData is 36 bytes, then the gen counter, then the Variable 1 Throttles block
+    assert_eq!(&bytes[32..36], &[0x04, 0x03, 0x02, 0x01]);
+    assert_eq!(&bytes[36..40], &[7, 0, 0, 0]);
+    assert_eq!(bytes[40] as usize, Throttles::LENGTH);
+    assert_eq!(bytes.len(), 41 + Throttles::LENGTH);
+
+    // each throttle is a little endian f32, in the order the simulator expects
+    assert_eq!(&bytes[41..45], &1.0f32.to_le_bytes());
+    assert_eq!(&bytes[41..45], &[0x00, 0x00, 0x80, 0x3f]);
+    assert_eq!(&bytes[65..69], &7.0f32.to_le_bytes());
+}
+
+#[test]
+fn test_agent_throttle_round_trip() {
+    let throttle = test_throttle();
+    let parsed = AgentThrottle::from_bytes(&throttle.to_bytes()).unwrap();
+    assert_eq!(parsed.agent_id, throttle.agent_id);
+    assert_eq!(parsed.session_id, throttle.session_id);
+    assert_eq!(parsed.circuit_code, throttle.circuit_code);
+    assert_eq!(parsed.gen_counter, 7);
+    assert_eq!(parsed.throttles, throttle.throttles);
+}
+
+#[test]
+fn test_agent_throttle_bad_length() {
+    let mut bytes = test_throttle().to_bytes();
+    // drop the last float and fix up the length prefix
+    bytes.truncate(bytes.len() - 4);
+    bytes[40] = 24;
+    assert!(AgentThrottle::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_agent_throttle_packet() {
+    let packet = Packet::new_agent_throttle(test_throttle());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    match parsed.body {
+        PacketType::AgentThrottle(throttle) => assert_eq!(throttle.gen_counter, 7),
+        body => panic!("expected AgentThrottle, got {:?}", body),
+    }
+}
+
+#[test]
+fn test_default_throttles() {
+    let throttles = Throttles::default();
+    assert!(throttles.total() > 0.0);
+    assert_eq!(
+        Throttles::from_bytes(&throttles.to_bytes()).unwrap(),
+        throttles
+    );
+}
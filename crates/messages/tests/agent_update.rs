@@ -1,5 +1,9 @@
+use glam::{Quat, Vec3};
 use hex::FromHex;
-use metaverse_messages::packet::Packet;
+use metaverse_messages::{
+    agent_update::{AgentUpdate, Camera, ControlFlags},
+    packet::{Packet, PacketData},
+};
 
 #[test]
 fn test_agent_update() {
@@ -13,3 +17,53 @@ fn test_agent_update() {
         Err(e) => eprintln!("Error creating packet: {}", e),
     }
 }
+
+fn camera_looking_north() -> Camera {
+    Camera {
+        center: Vec3::new(128.0, 120.0, 24.0),
+        at_axis: Vec3::Y,
+        left_axis: Vec3::NEG_X,
+        up_axis: Vec3::Z,
+        far: 128.0,
+    }
+}
+
+#[test]
+fn test_movement_builders() {
+    let camera = camera_looking_north();
+    assert_eq!(
+        AgentUpdate::walking_forward(camera).control_flags,
+        ControlFlags::AT_POS
+    );
+    assert_eq!(
+        AgentUpdate::running_forward(camera).control_flags,
+        ControlFlags::AT_POS | ControlFlags::FAST_AT
+    );
+    assert_eq!(AgentUpdate::flying(camera).control_flags, ControlFlags::FLY);
+    assert_eq!(
+        AgentUpdate::flying_forward(camera).control_flags,
+        ControlFlags::FLY | ControlFlags::AT_POS
+    );
+    assert!(AgentUpdate::stopped(camera).control_flags.is_empty());
+}
+
+#[test]
+fn test_movement_faces_the_camera() {
+    let update = AgentUpdate::walking_forward(camera_looking_north());
+    assert_eq!(update.camera(), camera_looking_north());
+    let facing = update.body_rotation * Vec3::X;
+    assert!((facing - Vec3::Y).length() < 1e-6);
+    assert_eq!(update.head_rotation, Quat::IDENTITY);
+    assert!(update.agent_id.is_nil());
+}
+
+#[test]
+fn test_control_flags_round_trip() {
+    let mut update = AgentUpdate::running_forward(Camera::default());
+    // bits the client doesn't name are kept as they are
+    update.control_flags |= ControlFlags::from_bits_retain(0x80000000);
+    let bytes = update.to_bytes();
+    let control_flags = u32::from_le_bytes(bytes[109..113].try_into().unwrap());
+    assert_eq!(control_flags, 0x80000401);
+    assert_eq!(AgentUpdate::from_bytes(&bytes).unwrap(), update);
+}
@@ -0,0 +1,107 @@
+use hex::FromHex;
+use metaverse_messages::{
+    agent_animation::{AgentAnimation, AnimationChange},
+    avatar_animation::{AvatarAnimation, PlayingAnimation},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::animations::{ANIM_STAND, ANIM_TYPE},
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const OBJECT_ID: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+
+#[test]
+fn test_avatar_animation() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a0014",
+        "320dff8a7a594720a0f75a8df3698d9a", // sender
+        "02",
+        "c541c47fe0c0058bad1ad6ae3a4584d9", // typing, started by the object
+        "08000000",
+        "2408fe9edf1d1d7df4ff1384fa7b350f", // standing
+        "07000000",
+        "01",
+        "5748deccf629461c9a36a35a221fe21f",
+        "01",
+        "020102",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let animation = match &packet.body {
+        PacketType::AvatarAnimation(animation) => animation.clone(),
+        _ => panic!("expected AvatarAnimation"),
+    };
+    assert_eq!(
+        *animation,
+        AvatarAnimation {
+            sender_id: AGENT_ID,
+            animations: vec![
+                PlayingAnimation {
+                    anim_id: ANIM_TYPE,
+                    sequence_id: 8,
+                },
+                PlayingAnimation {
+                    anim_id: ANIM_STAND,
+                    sequence_id: 7,
+                },
+            ],
+            sources: vec![OBJECT_ID],
+            physical_avatar_events: vec![vec![1, 2]],
+        }
+    );
+    assert_eq!(animation.to_bytes(), bytes[7..]);
+
+    let body = packet.body;
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::AvatarAnimationEvent
+    ));
+    match UiEventTypes::AvatarAnimationEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::AvatarAnimation(decoded)) => assert_eq!(decoded, animation),
+        _ => panic!("failed to decode AvatarAnimationEvent"),
+    }
+}
+
+#[test]
+fn test_avatar_animation_without_physical_events() {
+    // older simulators end the packet after the sources
+    let bytes = Vec::from_hex("320dff8a7a594720a0f75a8df3698d9a0000").unwrap();
+    let animation = AvatarAnimation::from_bytes(&bytes).unwrap();
+    assert!(animation.animations.is_empty());
+    assert!(animation.physical_avatar_events.is_empty());
+}
+
+#[test]
+fn test_agent_animation() {
+    let mut animation = AgentAnimation::start(&[ANIM_TYPE]);
+    assert_eq!(animation.agent_id, Uuid::nil());
+    animation.agent_id = AGENT_ID;
+    animation.session_id = OBJECT_ID;
+    assert_eq!(
+        animation.to_bytes(),
+        Vec::from_hex(concat!(
+            "320dff8a7a594720a0f75a8df3698d9a",
+            "5748deccf629461c9a36a35a221fe21f",
+            "01",
+            "c541c47fe0c0058bad1ad6ae3a4584d9",
+            "01",
+            "00",
+        ))
+        .unwrap()
+    );
+    let bytes = Packet::new_agent_animation(animation.clone()).to_bytes();
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::AgentAnimation(decoded) => assert_eq!(*decoded, animation),
+        _ => panic!("expected AgentAnimation"),
+    }
+
+    assert_eq!(
+        AgentAnimation::stop(&[ANIM_TYPE]).animations,
+        vec![AnimationChange {
+            anim_id: ANIM_TYPE,
+            start: false,
+        }]
+    );
+}
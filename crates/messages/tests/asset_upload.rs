@@ -0,0 +1,75 @@
+use metaverse_messages::{
+    asset_upload_complete::AssetUploadComplete,
+    asset_upload_request::AssetUploadRequest,
+    asset_uploaded::AssetUploaded,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::asset_type::AssetType,
+};
+use uuid::Uuid;
+
+#[test]
+fn test_asset_upload_request_round_trip() {
+    let request = AssetUploadRequest {
+        transaction_id: Uuid::new_v4(),
+        asset_type: AssetType::Notecard,
+        temp_file: false,
+        store_local: true,
+        asset_data: b"Linden text version 2".to_vec(),
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(&bytes[16..19], &[7, 0, 1]);
+    // the asset data is a Variable2
+    assert_eq!(&bytes[19..21], &[21, 0]);
+    assert_eq!(AssetUploadRequest::from_bytes(&bytes).unwrap(), request);
+
+    let packet = Packet::from_bytes(&Packet::new_asset_upload_request(request).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::AssetUploadRequest(_)));
+}
+
+#[test]
+fn test_asset_id_from_transaction_id() {
+    let transaction_id = Uuid::parse_str("11111111-2222-3333-4444-555555555555").unwrap();
+    let secure_session_id = Uuid::parse_str("aaaaaaaa-bbbb-cccc-dddd-eeeeeeeeeeee").unwrap();
+    assert_eq!(
+        AssetUploadRequest::asset_id(transaction_id, secure_session_id),
+        Uuid::parse_str("a846b908-f8f7-2357-9307-266954f25d73").unwrap()
+    );
+}
+
+#[test]
+fn test_asset_upload_complete_round_trip() {
+    let complete = AssetUploadComplete {
+        asset_id: Uuid::new_v4(),
+        asset_type: AssetType::LslText,
+        success: true,
+    };
+    let bytes = complete.to_bytes();
+    assert_eq!(&bytes[16..], &[10, 1]);
+    assert_eq!(AssetUploadComplete::from_bytes(&bytes).unwrap(), complete);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_asset_upload_complete(complete).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::AssetUploadComplete(_)));
+}
+
+#[test]
+fn test_asset_uploaded_event() {
+    let uploaded = AssetUploaded {
+        transaction_id: Uuid::new_v4(),
+        asset_id: Uuid::new_v4(),
+        asset_type: AssetType::Notecard,
+        success: false,
+        reason: Some("timed out".to_string()),
+    };
+    let body = PacketType::AssetUploaded(Box::new(uploaded.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::AssetUploadedEvent));
+    match body
+        .ui_event()
+        .packet_type_from_bytes(&body.ui_event_bytes())
+    {
+        Some(PacketType::AssetUploaded(parsed)) => assert_eq!(*parsed, uploaded),
+        _ => panic!("failed to decode AssetUploadedEvent"),
+    }
+}
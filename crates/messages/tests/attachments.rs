@@ -0,0 +1,180 @@
+use glam::Quat;
+use metaverse_messages::{
+    attach_from_inventory::AttachFromInventory,
+    detach_attachment::DetachAttachment,
+    object_attach::ObjectAttach,
+    object_detach::ObjectDetach,
+    object_drop::ObjectDrop,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    rez_single_attachment_from_inv::RezSingleAttachmentFromInv,
+    utils::{
+        asset_type::AssetType, attachment_point::AttachmentPoint, inventory_item::InventoryItem,
+        permissions::Permissions, sale_type::SaleType,
+    },
+};
+use uuid::{uuid, Uuid};
+
+const ITEM_ID: Uuid = uuid!("c1a5b6e2-51f3-4b0f-9d3b-7c61f0b0a2d4");
+const OWNER_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+
+fn item() -> InventoryItem {
+    InventoryItem {
+        item_id: ITEM_ID,
+        folder_id: Uuid::nil(),
+        creator_id: OWNER_ID,
+        owner_id: OWNER_ID,
+        group_id: Uuid::nil(),
+        base_mask: Permissions::from_bits(0x7fffffff),
+        owner_mask: Permissions::from_bits(0x7fffffff),
+        group_mask: Permissions::default(),
+        everyone_mask: Permissions::default(),
+        next_owner_mask: Permissions::from_bits(Permissions::MOVE | Permissions::TRANSFER),
+        group_owned: false,
+        asset_id: Uuid::nil(),
+        asset_type: AssetType::Object,
+        inventory_type: 6,
+        flags: 0,
+        sale_type: SaleType::NotForSale,
+        sale_price: 0,
+        name: "Top Hat".to_string(),
+        description: "a very tall hat".to_string(),
+        creation_date: 0,
+    }
+}
+
+#[test]
+fn test_attachment_points() {
+    for byte in 0..=55 {
+        let point = AttachmentPoint::from_bytes(byte);
+        assert!(!matches!(point, AttachmentPoint::Unknown(_)));
+        assert_eq!(point.to_bytes(), byte);
+    }
+    assert_eq!(AttachmentPoint::from_bytes(2), AttachmentPoint::Skull);
+    assert_eq!(
+        AttachmentPoint::from_bytes(40),
+        AttachmentPoint::AvatarCenter
+    );
+    assert_eq!(
+        AttachmentPoint::from_bytes(56),
+        AttachmentPoint::Unknown(56)
+    );
+    assert!(AttachmentPoint::HudTopLeft.is_hud());
+    assert!(!AttachmentPoint::Neck.is_hud());
+    // ObjectUpdates store the point with its nibbles swapped
+    assert_eq!(
+        AttachmentPoint::from_object_state(0x20),
+        AttachmentPoint::Skull
+    );
+    assert_eq!(
+        AttachmentPoint::from_object_state(0xA2),
+        AttachmentPoint::RightRingFinger
+    );
+}
+
+#[test]
+fn test_object_attach() {
+    let mut attach = ObjectAttach::new(AttachmentPoint::LeftHand, &[10, 11]);
+    attach.add = true;
+    let bytes = attach.to_bytes();
+    assert_eq!(bytes.len(), 34 + 2 * 16);
+    // the point and the add bit share a byte
+    assert_eq!(bytes[32], 0x85);
+    assert_eq!(bytes[33], 2);
+    assert_eq!(&bytes[34..38], &10u32.to_le_bytes());
+    let parsed = ObjectAttach::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, attach);
+    assert_eq!(parsed.objects[1].rotation, Quat::IDENTITY);
+
+    match Packet::from_bytes(&Packet::new_object_attach(attach.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectAttach(parsed) => assert_eq!(*parsed, attach),
+        _ => panic!("expected ObjectAttach"),
+    }
+}
+
+#[test]
+fn test_object_detach_and_drop() {
+    let detach = ObjectDetach::new(vec![10, 11]);
+    let bytes = detach.to_bytes();
+    assert_eq!(bytes.len(), 33 + 8);
+    match Packet::from_bytes(&Packet::new_object_detach(detach.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectDetach(parsed) => assert_eq!(*parsed, detach),
+        _ => panic!("expected ObjectDetach"),
+    }
+
+    let drop = ObjectDrop::new(vec![12]);
+    assert_eq!(ObjectDrop::from_bytes(&drop.to_bytes()).unwrap(), drop);
+    match Packet::from_bytes(&Packet::new_object_drop(drop.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectDrop(parsed) => assert_eq!(*parsed, drop),
+        _ => panic!("expected ObjectDrop"),
+    }
+
+    let packets = ObjectDetach::new((0..300).collect()).split();
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[1].local_ids.len(), 45);
+}
+
+#[test]
+fn test_rez_single_attachment_from_inv() {
+    let rez = RezSingleAttachmentFromInv::new(&item(), AttachmentPoint::Skull);
+    assert_eq!(rez.item_id, ITEM_ID);
+    assert_eq!(rez.owner_id, OWNER_ID);
+    assert_eq!(rez.name, "Top Hat");
+    assert!(rez.next_owner_mask.transfer);
+
+    let bytes = rez.to_bytes();
+    assert_eq!(bytes.len(), 83 + 8 + 16);
+    assert_eq!(&bytes[32..48], ITEM_ID.as_bytes());
+    assert_eq!(bytes[64], 2);
+    assert_eq!(RezSingleAttachmentFromInv::from_bytes(&bytes).unwrap(), rez);
+
+    let mut adding = rez.clone();
+    adding.add = true;
+    let bytes = Packet::new_rez_single_attachment_from_inv(adding.clone()).to_bytes();
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::RezSingleAttachmentFromInv(parsed) => {
+            assert!(parsed.add);
+            assert_eq!(parsed.attachment_point, AttachmentPoint::Skull);
+            assert_eq!(*parsed, adding);
+        }
+        _ => panic!("expected RezSingleAttachmentFromInv"),
+    }
+}
+
+#[test]
+fn test_attachment_commands() {
+    let attach = AttachFromInventory {
+        item_id: ITEM_ID,
+        attachment_point: AttachmentPoint::HudTop,
+        add: false,
+    };
+    match Packet::from_bytes(&Packet::new_attach_from_inventory(attach.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::AttachFromInventory(parsed) => assert_eq!(*parsed, attach),
+        _ => panic!("expected AttachFromInventory"),
+    }
+
+    for detach in [
+        DetachAttachment::Object(1234),
+        DetachAttachment::Item(ITEM_ID),
+    ] {
+        match Packet::from_bytes(&Packet::new_detach_attachment(detach.clone()).to_bytes())
+            .unwrap()
+            .body
+        {
+            PacketType::DetachAttachment(parsed) => assert_eq!(*parsed, detach),
+            _ => panic!("expected DetachAttachment"),
+        }
+    }
+}
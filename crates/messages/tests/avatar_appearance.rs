@@ -0,0 +1,100 @@
+use glam::Vec3;
+use metaverse_messages::{
+    avatar_appearance::{AppearanceData, AvatarAppearance},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::{uuid, Uuid};
+
+const AVATAR_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const SKIN_TEXTURE: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+
+// a TextureEntry with a default texture and no overrides
+fn texture_entry() -> Vec<u8> {
+    let mut bytes = SKIN_TEXTURE.as_bytes().to_vec();
+    bytes.push(0);
+    bytes
+}
+
+// the body of an AvatarAppearance from an older simulator, without the AppearanceData and
+// AppearanceHover blocks
+fn old_fixture() -> Vec<u8> {
+    let mut bytes = AVATAR_ID.as_bytes().to_vec();
+    bytes.push(0);
+    let texture_entry = texture_entry();
+    bytes.extend_from_slice(&(texture_entry.len() as u16).to_le_bytes());
+    bytes.extend(texture_entry);
+    bytes.push(4);
+    bytes.extend_from_slice(&[0, 127, 128, 255]);
+    bytes
+}
+
+#[test]
+fn test_avatar_appearance_from_older_simulator() {
+    let appearance = AvatarAppearance::from_bytes(&old_fixture()).unwrap();
+    assert_eq!(appearance.sender_id, AVATAR_ID);
+    assert!(!appearance.is_trial);
+    assert_eq!(appearance.visual_params, vec![0, 127, 128, 255]);
+    assert!(appearance.appearance_data.is_empty());
+    assert!(appearance.hover_heights.is_empty());
+
+    let texture_entry = appearance.texture_entry().unwrap();
+    assert_eq!(texture_entry.face(8).texture_id, SKIN_TEXTURE);
+}
+
+#[test]
+fn test_avatar_appearance_round_trip() {
+    let appearance = AvatarAppearance {
+        sender_id: AVATAR_ID,
+        is_trial: true,
+        texture_entry: texture_entry(),
+        visual_params: (0..218).map(|param| param as u8).collect(),
+        appearance_data: vec![AppearanceData {
+            appearance_version: 1,
+            cof_version: 42,
+            flags: 0,
+        }],
+        hover_heights: vec![Vec3::new(0.0, 0.0, 0.1)],
+    };
+    let bytes = appearance.to_bytes();
+    assert_eq!(AvatarAppearance::from_bytes(&bytes).unwrap(), appearance);
+
+    let packet = Packet::from_bytes(&Packet::new_avatar_appearance(appearance).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::AvatarAppearance(_)));
+}
+
+#[test]
+fn test_truncated_visual_params_is_an_error() {
+    let bytes = old_fixture();
+    assert!(AvatarAppearance::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_malformed_texture_entry_is_only_an_error_when_parsed() {
+    let mut bytes = AVATAR_ID.as_bytes().to_vec();
+    bytes.push(0);
+    // half of a uuid
+    bytes.extend_from_slice(&8u16.to_le_bytes());
+    bytes.extend_from_slice(&[1; 8]);
+    bytes.push(0);
+    let appearance = AvatarAppearance::from_bytes(&bytes).unwrap();
+    assert!(appearance.texture_entry().is_err());
+}
+
+#[test]
+fn test_avatar_appearance_event() {
+    let appearance = AvatarAppearance::from_bytes(&old_fixture()).unwrap();
+    let body = PacketType::AvatarAppearance(Box::new(appearance.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::AvatarAppearanceEvent
+    ));
+    match body
+        .ui_event()
+        .packet_type_from_bytes(&body.ui_event_bytes())
+    {
+        Some(PacketType::AvatarAppearance(parsed)) => assert_eq!(*parsed, appearance),
+        _ => panic!("failed to decode AvatarAppearanceEvent"),
+    }
+}
@@ -0,0 +1,111 @@
+use metaverse_messages::{
+    avatar_picker_reply::{AvatarMatch, AvatarPickerReply},
+    avatar_picker_request::AvatarPickerRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    people_search_empty::PeopleSearchEmpty,
+    people_search_results::PeopleSearchResults,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn avatar(byte: u8, first_name: &str) -> AvatarMatch {
+    AvatarMatch {
+        avatar_id: Uuid::from_bytes([byte; 16]),
+        first_name: first_name.to_string(),
+        last_name: "Resident".to_string(),
+    }
+}
+
+#[test]
+fn test_avatar_picker_request() {
+    let mut request = AvatarPickerRequest::new("alice".to_string());
+    assert!(request.agent_id.is_nil());
+    assert!(request.query_id.is_nil());
+    request.query_id = Uuid::from_bytes([0x10; 16]);
+    let bytes = request.to_bytes();
+    // the name is null terminated, with a one byte length
+    assert_eq!(bytes.len(), 48 + 1 + 6);
+    assert_eq!(bytes[48], 6);
+    match Packet::from_bytes(&Packet::new_avatar_picker_request(request.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::AvatarPickerRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected AvatarPickerRequest"),
+    }
+}
+
+#[test]
+fn test_name_is_valid() {
+    assert!(AvatarPickerRequest::new("bob".to_string()).name_is_valid());
+    assert!(!AvatarPickerRequest::new("bo".to_string()).name_is_valid());
+    assert!(!AvatarPickerRequest::new("  bo  ".to_string()).name_is_valid());
+    // three characters, even if they take more bytes
+    assert!(AvatarPickerRequest::new("åäö".to_string()).name_is_valid());
+    let longest = "a".repeat(AvatarPickerRequest::MAX_NAME_LENGTH);
+    assert!(AvatarPickerRequest::new(longest.clone()).name_is_valid());
+    assert!(!AvatarPickerRequest::new(longest + "a").name_is_valid());
+}
+
+#[test]
+fn test_avatar_picker_reply() {
+    let reply = AvatarPickerReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        query_id: Uuid::from_bytes([0x10; 16]),
+        avatars: vec![avatar(0x02, "Alice"), avatar(0x03, "Alicia")],
+    };
+    let bytes = reply.to_bytes();
+    assert_eq!(bytes[32], 2);
+    assert_eq!(AvatarPickerReply::from_bytes(&bytes).unwrap(), reply);
+    match Packet::from_bytes(&Packet::new_avatar_picker_reply(reply.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::AvatarPickerReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected AvatarPickerReply"),
+    }
+}
+
+#[test]
+fn test_empty_avatar_picker_reply() {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&[0x01; 16]);
+    bytes.extend_from_slice(&[0x10; 16]);
+    bytes.push(0);
+    let reply = AvatarPickerReply::from_bytes(&bytes).unwrap();
+    assert!(reply.avatars.is_empty());
+    assert_eq!(reply.to_bytes(), bytes);
+}
+
+#[test]
+fn test_people_search_events() {
+    let results = PeopleSearchResults {
+        query_id: Uuid::from_bytes([0x10; 16]),
+        name: "ali".to_string(),
+        avatars: vec![avatar(0x02, "Alice")],
+    };
+    let body = PacketType::PeopleSearchResults(Box::new(results.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::PeopleSearchResultsEvent
+    ));
+    match UiEventTypes::PeopleSearchResultsEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::PeopleSearchResults(parsed)) => assert_eq!(*parsed, results),
+        _ => panic!("failed to decode PeopleSearchResultsEvent"),
+    }
+
+    let empty = PeopleSearchEmpty {
+        query_id: Uuid::from_bytes([0x10; 16]),
+        name: "zzz".to_string(),
+    };
+    let body = PacketType::PeopleSearchEmpty(Box::new(empty.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::PeopleSearchEmptyEvent
+    ));
+    match UiEventTypes::PeopleSearchEmptyEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::PeopleSearchEmpty(parsed)) => assert_eq!(*parsed, empty),
+        _ => panic!("failed to decode PeopleSearchEmptyEvent"),
+    }
+}
@@ -0,0 +1,124 @@
+use metaverse_messages::{
+    avatar_interests_reply::{AvatarInterests, AvatarInterestsReply},
+    avatar_interests_update::AvatarInterestsUpdate,
+    avatar_properties_reply::AvatarPropertiesReply,
+    avatar_properties_update::AvatarPropertiesUpdate,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn profile(about_text: String) -> AvatarPropertiesReply {
+    AvatarPropertiesReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        avatar_id: Uuid::from_bytes([0x02; 16]),
+        image_id: Uuid::from_bytes([0x03; 16]),
+        first_life_image_id: Uuid::nil(),
+        partner_id: Uuid::nil(),
+        about_text,
+        first_life_about_text: String::new(),
+        born_on: "1/31/2007".to_string(),
+        profile_url: "http://example.com".to_string(),
+        charter_member: vec![3],
+        flags: AvatarPropertiesReply::ALLOW_PUBLISH | AvatarPropertiesReply::IDENTIFIED,
+    }
+}
+
+fn interests() -> AvatarInterests {
+    AvatarInterests {
+        want_to_mask: 0b101,
+        want_to_text: "build".to_string(),
+        skills_mask: 0b10,
+        skills_text: String::new(),
+        languages_text: "English".to_string(),
+    }
+}
+
+#[test]
+fn test_avatar_properties_reply() {
+    let reply = profile("Hello!".to_string());
+    let bytes = reply.to_bytes();
+    // the about text has a two byte length
+    assert_eq!(&bytes[80..82], &[7, 0]);
+    assert_eq!(&bytes[bytes.len() - 4..], &[0x05, 0x00, 0x00, 0x00]);
+    assert_eq!(AvatarPropertiesReply::from_bytes(&bytes).unwrap(), reply);
+    match Packet::from_bytes(&Packet::new_avatar_properties_reply(reply.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::AvatarPropertiesReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected AvatarPropertiesReply"),
+    }
+}
+
+#[test]
+fn test_long_profile_event() {
+    // profile text can be longer than one message to the UI
+    let reply = profile("a".repeat(4000));
+    assert_eq!(
+        AvatarPropertiesReply::from_bytes(&reply.to_bytes()).unwrap(),
+        reply
+    );
+    let body = PacketType::AvatarPropertiesReply(Box::new(reply.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::AvatarPropertiesEvent
+    ));
+    let bytes = body.ui_event_bytes();
+    assert!(bytes.len() > 4000);
+    match UiEventTypes::AvatarPropertiesEvent.packet_type_from_bytes(&bytes) {
+        Some(PacketType::AvatarPropertiesReply(parsed)) => assert_eq!(*parsed, reply),
+        _ => panic!("failed to decode AvatarPropertiesEvent"),
+    }
+}
+
+#[test]
+fn test_avatar_interests() {
+    let reply = AvatarInterestsReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        avatar_id: Uuid::from_bytes([0x02; 16]),
+        interests: interests(),
+    };
+    let bytes = reply.to_bytes();
+    assert_eq!(&bytes[32..36], &[0x05, 0x00, 0x00, 0x00]);
+    assert_eq!(AvatarInterestsReply::from_bytes(&bytes).unwrap(), reply);
+    let body = PacketType::AvatarInterestsReply(Box::new(reply.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::AvatarInterestsEvent
+    ));
+    match UiEventTypes::AvatarInterestsEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::AvatarInterestsReply(parsed)) => assert_eq!(*parsed, reply),
+        _ => panic!("failed to decode AvatarInterestsEvent"),
+    }
+
+    let update = AvatarInterestsUpdate::new(interests());
+    assert!(update.agent_id.is_nil());
+    assert_eq!(&update.to_bytes()[32..], &bytes[32..]);
+    match Packet::from_bytes(&Packet::new_avatar_interests_update(update.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::AvatarInterestsUpdate(parsed) => assert_eq!(*parsed, update),
+        _ => panic!("expected AvatarInterestsUpdate"),
+    }
+}
+
+#[test]
+fn test_avatar_properties_update() {
+    let update = AvatarPropertiesUpdate {
+        about_text: "b".repeat(3000),
+        ..AvatarPropertiesUpdate::from_reply(&profile("Hello!".to_string()))
+    };
+    assert!(update.allow_publish);
+    assert!(!update.mature_publish);
+    assert_eq!(update.image_id, Uuid::from_bytes([0x03; 16]));
+    // larger than a packet on the wire, but it still fits in one packet from the UI
+    let bytes = Packet::new_avatar_properties_update(update.clone()).to_bytes();
+    assert!(bytes.len() > 3000);
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::AvatarPropertiesUpdate(parsed) => assert_eq!(*parsed, update),
+        _ => panic!("expected AvatarPropertiesUpdate"),
+    }
+}
@@ -1,7 +1,8 @@
 use hex::FromHex;
 use metaverse_messages::{
     chat_from_viewer::{ChatFromViewer, ClientChatType},
-    packet::Packet,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
 };
 use uuid::uuid;
 
@@ -30,3 +31,91 @@ fn create_chat_from_viewer() {
     println!("{:?}", hex::encode(packet.to_bytes()));
     println!("\"400000110600ffff0050320dff8a7a594720a0f75a8df3698d9a224ecaea372d4d318b644805966418e5020061000100000000\"")
 }
+
+#[test]
+fn test_chat_from_viewer_matches_capture() {
+    let captured = Vec::from_hex("400000110600ffff0050320dff8a7a594720a0f75a8df3698d9a224ecaea372d4d318b644805966418e5020061000100000000").unwrap();
+    let mut packet = Packet::new_chat_from_viewer(ChatFromViewer {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        channel: 0,
+        message: "a".to_string(),
+        message_type: ClientChatType::Normal,
+    });
+    packet.header.sequence_number = 0x1106;
+    assert_eq!(packet.to_bytes(), captured);
+}
+
+#[test]
+fn test_chat_from_viewer_null_terminator() {
+    let chat = ChatFromViewer {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        channel: 0,
+        message: "hello".to_string(),
+        message_type: ClientChatType::Normal,
+    };
+    let bytes = chat.to_bytes();
+    // the length prefix sits right after the two uuids and counts the null terminator
+    assert_eq!(u16::from_le_bytes([bytes[32], bytes[33]]), 6);
+    assert_eq!(&bytes[34..39], b"hello");
+    assert_eq!(bytes[39], 0);
+    // type and channel follow the string
+    assert_eq!(bytes.len(), 32 + 2 + 6 + 1 + 4);
+}
+
+#[test]
+fn test_chat_from_viewer_round_trip() {
+    for (message, channel, message_type) in [
+        ("hello", 0, ClientChatType::Normal),
+        ("", 0, ClientChatType::StartTyping),
+        ("héllo wörld ✨ 日本語", -42, ClientChatType::Shout),
+        ("/me waves", 2147483647, ClientChatType::Whisper),
+    ] {
+        let chat = ChatFromViewer {
+            agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+            session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+            channel,
+            message: message.to_string(),
+            message_type,
+        };
+        let parsed = ChatFromViewer::from_bytes(&chat.to_bytes()).unwrap();
+        assert_eq!(parsed.agent_id, chat.agent_id);
+        assert_eq!(parsed.session_id, chat.session_id);
+        assert_eq!(parsed.message, chat.message);
+        assert_eq!(parsed.message_type, chat.message_type);
+        assert_eq!(parsed.channel, chat.channel);
+    }
+}
+
+#[test]
+fn test_chat_from_viewer_packet_round_trip() {
+    let packet = Packet::new_chat_from_viewer(ChatFromViewer {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        channel: 0,
+        message: "ünïcödé".to_string(),
+        message_type: ClientChatType::Normal,
+    });
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    match parsed.body {
+        PacketType::ChatFromViewer(chat) => {
+            assert_eq!(chat.message, "ünïcödé");
+            assert_eq!(chat.channel, 0);
+        }
+        body => panic!("expected ChatFromViewer, got {:?}", body),
+    }
+}
+
+#[test]
+fn test_chat_from_viewer_truncated() {
+    let chat = ChatFromViewer {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        channel: 0,
+        message: "hello".to_string(),
+        message_type: ClientChatType::Normal,
+    };
+    let bytes = chat.to_bytes();
+    assert!(ChatFromViewer::from_bytes(&bytes[..36]).is_err());
+}
@@ -0,0 +1,80 @@
+use glam::Vec3;
+use hex::FromHex;
+use metaverse_messages::{
+    chat_from_simulator::{ChatType, SourceType},
+    chat_pass::ChatPass,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    set_chat_filter::SetChatFilter,
+    ui_events::UiEventTypes,
+    utils::agent_access::AgentAccess,
+};
+use uuid::uuid;
+
+fn chat_pass() -> ChatPass {
+    ChatPass {
+        channel: 42,
+        position: Vec3::new(1.0, 2.0, 3.0),
+        source_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        owner_id: uuid!("5748decc-f629-461c-9a36-a35a221fe21f"),
+        from_name: "Obj".to_string(),
+        source_type: SourceType::Object,
+        chat_type: ChatType::Normal,
+        radius: 20.0,
+        sim_access: AgentAccess::PG,
+        message: "hi".to_string(),
+    }
+}
+
+#[test]
+fn test_chat_pass() {
+    let bytes = Vec::from_hex(concat!(
+        "400000000100ffff00ef",
+        "2a000000",
+        "0000803f0000004000004040",
+        "320dff8a7a594720a0f75a8df3698d9a", // source
+        "5748deccf629461c9a36a35a221fe21f", // owner
+        "044f626a00",                       // "Obj"
+        "02",
+        "01",
+        "0000a041", // heard 20 meters away
+        "0d",
+        "0300686900", // "hi"
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    match &packet.body {
+        PacketType::ChatPass(chat) => assert_eq!(**chat, chat_pass()),
+        _ => panic!("expected ChatPass"),
+    }
+    assert_eq!(chat_pass().to_bytes(), bytes[10..]);
+
+    let body = PacketType::ChatPass(Box::new(chat_pass()));
+    match UiEventTypes::ChatPassEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ChatPass(chat)) => assert_eq!(*chat, chat_pass()),
+        _ => panic!("failed to decode ChatPassEvent"),
+    }
+}
+
+#[test]
+fn test_set_chat_filter() {
+    let filter = SetChatFilter::default();
+    assert_eq!(filter.channels, vec![0]);
+    assert!(filter.whisper && filter.say && filter.shout);
+    assert!(!filter.debug);
+
+    let filter = SetChatFilter {
+        channels: vec![0, -7],
+        whisper: false,
+        say: true,
+        shout: false,
+        debug: true,
+    };
+    match Packet::from_bytes(&Packet::new_set_chat_filter(filter.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::SetChatFilter(parsed) => assert_eq!(*parsed, filter),
+        _ => panic!("expected SetChatFilter"),
+    }
+}
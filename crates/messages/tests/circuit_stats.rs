@@ -0,0 +1,47 @@
+use metaverse_messages::{
+    circuit_stats_report::{CircuitReport, CircuitStatsReport, PacketTypeCount},
+    packet::Packet,
+    packet_types::PacketType,
+    query_circuit_stats::QueryCircuitStats,
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_query_circuit_stats_round_trip() {
+    let bytes = Packet::new_query_circuit_stats(QueryCircuitStats {}).to_bytes();
+    assert!(matches!(
+        Packet::from_bytes(&bytes).unwrap().body,
+        PacketType::QueryCircuitStats(_)
+    ));
+}
+
+#[test]
+fn test_circuit_stats_event() {
+    let report = CircuitStatsReport {
+        ping_ms: 42.5,
+        circuits: vec![CircuitReport {
+            address: "127.0.0.1:9000".to_string(),
+            packets_sent: 10,
+            packets_received: 20,
+            bytes_sent: 1000,
+            bytes_received: 3000,
+            resends: 1,
+            duplicates: 2,
+            acks_sent: 5,
+            acks_received: 4,
+            parse_failures: 1,
+            received_by_type: vec![PacketTypeCount {
+                frequency: "High".to_string(),
+                id: 1,
+                count: 20,
+            }],
+        }],
+    };
+
+    let body = PacketType::CircuitStatsReport(Box::new(report.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::CircuitStatsEvent));
+    match UiEventTypes::CircuitStatsEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::CircuitStatsReport(parsed)) => assert_eq!(*parsed, report),
+        _ => panic!("expected CircuitStatsReport"),
+    }
+}
@@ -1,5 +1,7 @@
 use hex::FromHex;
+use metaverse_messages::coarse_location_update::MinimapEntities;
 use metaverse_messages::packet::Packet;
+use std::io::Cursor;
 
 #[test]
 fn test_coarse_location_update() {
@@ -24,3 +26,9 @@ fn test_coarse_location_update_firestorm() {
         Err(e) => eprintln!("Error creating packet: {}", e),
     }
 }
+
+#[test]
+fn test_short_location_is_an_error() {
+    let bytes: &[u8] = &[0x10, 0x20];
+    assert!(MinimapEntities::from_bytes(&mut Cursor::new(bytes)).is_err());
+}
@@ -0,0 +1,129 @@
+use metaverse_messages::{
+    create_inventory_item::CreateInventoryItem,
+    inventory_item_created::InventoryItemCreated,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    update_create_inventory_item::{CreatedItem, UpdateCreateInventoryItem},
+    utils::{
+        asset_type::AssetType, inventory_item::InventoryItem, permissions::Permissions,
+        sale_type::SaleType,
+    },
+};
+use uuid::Uuid;
+
+fn item() -> InventoryItem {
+    InventoryItem {
+        item_id: Uuid::from_bytes([0x01; 16]),
+        folder_id: Uuid::from_bytes([0x10; 16]),
+        creator_id: Uuid::from_bytes([0xEE; 16]),
+        owner_id: Uuid::from_bytes([0xEE; 16]),
+        group_id: Uuid::nil(),
+        base_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        owner_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        group_mask: Permissions::default(),
+        everyone_mask: Permissions::default(),
+        next_owner_mask: Permissions::from_bits(Permissions::TRANSFER),
+        group_owned: false,
+        asset_id: Uuid::from_bytes([0xAA; 16]),
+        asset_type: AssetType::Notecard,
+        inventory_type: 7,
+        flags: 0,
+        sale_type: SaleType::NotForSale,
+        sale_price: 0,
+        name: "Notes".to_string(),
+        description: "some notes".to_string(),
+        creation_date: 1_700_000_000,
+    }
+}
+
+#[test]
+fn test_create_inventory_item() {
+    let create = CreateInventoryItem {
+        agent_id: Uuid::from_bytes([0x02; 16]),
+        session_id: Uuid::from_bytes([0x03; 16]),
+        callback_id: 7,
+        ..CreateInventoryItem::new(
+            Uuid::from_bytes([0x10; 16]),
+            AssetType::Notecard,
+            7,
+            0,
+            "Notes".to_string(),
+            String::new(),
+        )
+    };
+    assert!(create.transaction_id.is_nil());
+    let bytes = create.to_bytes();
+    // the ids, the callback id, the mask and types, the name with its terminator, and the
+    // empty description
+    assert_eq!(bytes.len(), 32 + 4 + 32 + 4 + 3 + 7 + 1);
+    assert_eq!(&bytes[32..36], &[0x07, 0x00, 0x00, 0x00]);
+    assert_eq!(
+        &bytes[68..72],
+        &(Permissions::MOVE | Permissions::TRANSFER).to_le_bytes()
+    );
+    assert_eq!(CreateInventoryItem::from_bytes(&bytes).unwrap(), create);
+
+    let packet = Packet::new_create_inventory_item(create.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::CreateInventoryItem(parsed) => assert_eq!(*parsed, create),
+        _ => panic!("expected CreateInventoryItem"),
+    }
+}
+
+#[test]
+fn test_update_create_inventory_item() {
+    let update = UpdateCreateInventoryItem {
+        agent_id: Uuid::from_bytes([0xEE; 16]),
+        sim_approved: true,
+        transaction_id: Uuid::nil(),
+        items: vec![CreatedItem {
+            item: item(),
+            callback_id: 7,
+            crc: item().crc(),
+        }],
+    };
+    let bytes = update.to_bytes();
+    assert_eq!(bytes[16], 0x01);
+    assert_eq!(bytes[33], 0x01);
+    // the callback id comes between the folder and the creator
+    assert_eq!(&bytes[66..70], &[0x07, 0x00, 0x00, 0x00]);
+    assert_eq!(&bytes[70..86], &[0xEE; 16]);
+    assert_eq!(
+        UpdateCreateInventoryItem::from_bytes(&bytes).unwrap(),
+        update
+    );
+
+    let packet = Packet::new_update_create_inventory_item(update.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::UpdateCreateInventoryItem(parsed) => assert_eq!(*parsed, update),
+        _ => panic!("expected UpdateCreateInventoryItem"),
+    }
+}
+
+#[test]
+fn test_inventory_item_created_event() {
+    for created in [
+        InventoryItemCreated {
+            callback_id: 7,
+            item: Some(item()),
+            reason: None,
+        },
+        InventoryItemCreated {
+            callback_id: 8,
+            item: None,
+            reason: Some("timed out waiting for the simulator".to_string()),
+        },
+    ] {
+        let body = PacketType::InventoryItemCreated(Box::new(created.clone()));
+        assert!(matches!(
+            body.ui_event(),
+            UiEventTypes::InventoryItemCreatedEvent
+        ));
+        match UiEventTypes::InventoryItemCreatedEvent.packet_type_from_bytes(&body.ui_event_bytes())
+        {
+            Some(PacketType::InventoryItemCreated(parsed)) => assert_eq!(*parsed, created),
+            _ => panic!("failed to decode InventoryItemCreatedEvent"),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use glam::Vec3;
+use metaverse_messages::{
+    crossed_region::CrossedRegion,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    region_crossed::RegionCrossed,
+    ui_events::UiEventTypes,
+    utils::region_handle::region_handle,
+};
+use std::net::Ipv4Addr;
+use uuid::uuid;
+
+fn test_crossed_region() -> CrossedRegion {
+    CrossedRegion {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        sim_ip: Ipv4Addr::new(127, 0, 0, 1),
+        sim_port: 9001,
+        region_handle: region_handle(256256, 256000),
+        seed_capability: "http://127.0.0.1:9001/CAPS/seed/".to_string(),
+        position: Vec3::new(1.5, 128.0, 22.0),
+        look_at: Vec3::new(1.0, 0.0, 0.0),
+    }
+}
+
+#[test]
+fn test_crossed_region_round_trip() {
+    let crossed_region = test_crossed_region();
+    let bytes = crossed_region.to_bytes();
+    // the sim address is in network byte order
+    assert_eq!(&bytes[32..38], &[127, 0, 0, 1, 0x23, 0x29]);
+
+    let parsed = CrossedRegion::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.sim_port, 9001);
+    assert_eq!(parsed.region_handle, crossed_region.region_handle);
+    assert_eq!(parsed.seed_capability, crossed_region.seed_capability);
+    assert_eq!(parsed.position, crossed_region.position);
+    assert_eq!(parsed.to_bytes(), bytes);
+
+    assert!(CrossedRegion::from_bytes(&bytes[..bytes.len() - 4]).is_err());
+}
+
+#[test]
+fn test_crossed_region_packet() {
+    let packet = Packet::new_crossed_region(test_crossed_region());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    match parsed.body {
+        PacketType::CrossedRegion(crossed_region) => {
+            assert_eq!(crossed_region.sim_ip, Ipv4Addr::new(127, 0, 0, 1))
+        }
+        body => panic!("expected CrossedRegion, got {:?}", body),
+    }
+}
+
+#[test]
+fn test_region_offset() {
+    // crossing east, a point at x = 255 in the old region is at x = -1 in the new one
+    let offset =
+        RegionCrossed::offset_between(region_handle(256000, 256000), region_handle(256256, 256000));
+    assert_eq!(offset, Vec3::new(-256.0, 0.0, 0.0));
+    assert_eq!(
+        Vec3::new(255.0, 10.0, 0.0) + offset,
+        Vec3::new(-1.0, 10.0, 0.0)
+    );
+
+    // crossing south
+    let offset =
+        RegionCrossed::offset_between(region_handle(256000, 256000), region_handle(256000, 255744));
+    assert_eq!(offset, Vec3::new(0.0, 256.0, 0.0));
+}
+
+#[test]
+fn test_region_crossed_event() {
+    let body = PacketType::RegionCrossed(Box::new(RegionCrossed {
+        region_handle: region_handle(256256, 256000),
+        position: Vec3::new(1.5, 128.0, 22.0),
+        look_at: Vec3::new(1.0, 0.0, 0.0),
+        offset: Vec3::new(-256.0, 0.0, 0.0),
+    }));
+    let event = body.ui_event();
+    assert!(matches!(event, UiEventTypes::RegionCrossedEvent));
+    match event.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::RegionCrossed(region_crossed)) => {
+            assert_eq!(region_crossed.region_handle, region_handle(256256, 256000));
+            assert_eq!(region_crossed.offset, Vec3::new(-256.0, 0.0, 0.0));
+        }
+        _ => panic!("failed to decode RegionCrossedEvent"),
+    }
+}
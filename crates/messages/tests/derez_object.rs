@@ -0,0 +1,104 @@
+use metaverse_messages::{
+    derez_object::DeRezObject,
+    object_delete::ObjectDelete,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    utils::derez_destination::DeRezDestination,
+};
+use uuid::Uuid;
+
+fn derez() -> DeRezObject {
+    DeRezObject {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        group_id: Uuid::nil(),
+        destination: DeRezDestination::Delete,
+        destination_id: Uuid::nil(),
+        transaction_id: Uuid::new_v4(),
+        packet_count: 0,
+        packet_number: 0,
+        local_ids: Vec::new(),
+    }
+}
+
+#[test]
+fn test_derez_object_round_trip() {
+    let derez = DeRezObject {
+        destination: DeRezDestination::TakeCopy,
+        destination_id: Uuid::new_v4(),
+        packet_count: 1,
+        local_ids: vec![1, 2, 3],
+        ..derez()
+    };
+    let bytes = derez.to_bytes();
+    assert_eq!(bytes.len(), 84 + 12);
+    // Destination follows the GroupID
+    assert_eq!(bytes[48], 1);
+    // PacketCount, PacketNumber and the ObjectData count
+    assert_eq!(&bytes[81..84], &[1, 0, 3]);
+    let packet = Packet::from_bytes(&Packet::new_derez_object(derez.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::DeRezObject(parsed) => assert_eq!(*parsed, derez),
+        _ => panic!("expected DeRezObject"),
+    }
+}
+
+#[test]
+fn test_derez_split_boundary() {
+    let local_ids: Vec<u32> = (0..511).collect();
+
+    let packets = derez().split(&local_ids[..255]);
+    assert_eq!(packets.len(), 1);
+    assert_eq!(packets[0].packet_count, 1);
+    assert_eq!(packets[0].local_ids.len(), 255);
+
+    let packets = derez().split(&local_ids[..256]);
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].local_ids.len(), 255);
+    assert_eq!(packets[1].local_ids, vec![255]);
+
+    let template = derez();
+    let packets = template.clone().split(&local_ids);
+    assert_eq!(packets.len(), 3);
+    for (number, packet) in packets.iter().enumerate() {
+        assert_eq!(packet.packet_count, 3);
+        assert_eq!(packet.packet_number, number as u8);
+        assert_eq!(packet.transaction_id, template.transaction_id);
+    }
+    let rejoined: Vec<u32> = packets
+        .iter()
+        .flat_map(|packet| packet.local_ids.clone())
+        .collect();
+    assert_eq!(rejoined, local_ids);
+}
+
+#[test]
+fn test_derez_split_of_nothing_sends_nothing() {
+    assert!(derez().split(&[]).is_empty());
+}
+
+#[test]
+fn test_derez_destination_bytes() {
+    for byte in 0..=u8::MAX {
+        assert_eq!(DeRezDestination::from_bytes(byte).to_bytes(), byte);
+    }
+    assert_eq!(DeRezDestination::Take.to_bytes(), 4);
+    assert_eq!(DeRezDestination::Return.to_bytes(), 9);
+}
+
+#[test]
+fn test_object_delete_round_trip() {
+    let delete = ObjectDelete {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        force: true,
+        local_ids: vec![7, 8],
+    };
+    let bytes = delete.to_bytes();
+    assert_eq!(&bytes[32..34], &[1, 2]);
+    let packet = Packet::from_bytes(&Packet::new_object_delete(delete.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ObjectDelete(parsed) => assert_eq!(*parsed, delete),
+        _ => panic!("expected ObjectDelete"),
+    }
+}
@@ -0,0 +1,122 @@
+use metaverse_messages::{
+    dir_find_query::{DirFindFlags, DirFindQuery, DirectorySearchKind},
+    dir_people_reply::{DirPeopleReply, DirPerson},
+    dir_places_reply::{DirPlace, DirPlacesReply},
+    directory_people_page::DirectoryPeoplePage,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn place(byte: u8, name: &str) -> DirPlace {
+    DirPlace {
+        parcel_id: Uuid::from_bytes([byte; 16]),
+        name: name.to_string(),
+        for_sale: true,
+        auction: false,
+        dwell: 12.5,
+        status: 0,
+    }
+}
+
+#[test]
+fn test_dir_find_query() {
+    let mut query = DirFindQuery::search(DirectorySearchKind::People, "alice".to_string(), 100);
+    assert!(query.query_id.is_nil());
+    assert_eq!(query.query_flags, DirFindFlags::PEOPLE_SEARCH);
+    assert_eq!(query.kind(), DirectorySearchKind::People);
+    query.query_id = Uuid::from_bytes([0x10; 16]);
+    let bytes = query.to_bytes();
+    assert_eq!(bytes.len(), 48 + 1 + 6 + 4 + 4);
+    assert_eq!(&bytes[55..59], &[1, 0, 0, 0]);
+    assert_eq!(&bytes[59..63], &[100, 0, 0, 0]);
+    match Packet::from_bytes(&Packet::new_dir_find_query(query.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::DirFindQuery(parsed) => assert_eq!(*parsed, query),
+        _ => panic!("expected DirFindQuery"),
+    }
+
+    let mut query = DirFindQuery::search(DirectorySearchKind::Places, "beach".to_string(), 0);
+    assert_eq!(query.kind(), DirectorySearchKind::Places);
+    query.query_flags |= DirFindFlags::INCLUDE_MATURE;
+    assert_eq!(query.query_flags.bits(), 0x03000004);
+    assert_eq!(DirFindQuery::from_bytes(&query.to_bytes()).unwrap(), query);
+}
+
+#[test]
+fn test_dir_people_reply() {
+    let reply = DirPeopleReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        query_id: Uuid::from_bytes([0x10; 16]),
+        people: vec![DirPerson {
+            agent_id: Uuid::from_bytes([0x02; 16]),
+            first_name: "Alice".to_string(),
+            last_name: "Resident".to_string(),
+            group: String::new(),
+            online: true,
+            reputation: -1,
+        }],
+    };
+    let bytes = reply.to_bytes();
+    assert_eq!(bytes[32], 1);
+    assert_eq!(DirPeopleReply::from_bytes(&bytes).unwrap(), reply);
+    match Packet::from_bytes(&Packet::new_dir_people_reply(reply.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::DirPeopleReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected DirPeopleReply"),
+    }
+}
+
+#[test]
+fn test_dir_places_reply() {
+    let mut reply = DirPlacesReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        query_id: Uuid::from_bytes([0x10; 16]),
+        places: vec![place(0x02, "Beach"), place(0x03, "Beach House")],
+    };
+    reply.places[1].status = 7;
+    let bytes = reply.to_bytes();
+    // one query block, then the places, then a status block for each place
+    assert_eq!(bytes[16], 1);
+    assert_eq!(bytes[33], 2);
+    assert_eq!(bytes[bytes.len() - 9], 2);
+    assert_eq!(DirPlacesReply::from_bytes(&bytes).unwrap(), reply);
+    match Packet::from_bytes(&Packet::new_dir_places_reply(reply.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::DirPlacesReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected DirPlacesReply"),
+    }
+
+    // without the status blocks
+    let bytes = &bytes[..bytes.len() - 9];
+    let parsed = DirPlacesReply::from_bytes(bytes).unwrap();
+    assert_eq!(parsed.places.len(), 2);
+    assert_eq!(parsed.places[1].status, 0);
+}
+
+#[test]
+fn test_directory_page_event() {
+    let page = DirectoryPeoplePage {
+        query_id: Uuid::from_bytes([0x10; 16]),
+        text: "alice".to_string(),
+        start_index: 100,
+        people: Vec::new(),
+        next_start_index: None,
+    };
+    let body = PacketType::DirectoryPeoplePage(Box::new(page.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::DirectoryPeopleEvent
+    ));
+    match UiEventTypes::DirectoryPeopleEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::DirectoryPeoplePage(parsed)) => assert_eq!(*parsed, page),
+        _ => panic!("failed to decode DirectoryPeopleEvent"),
+    }
+}
@@ -0,0 +1,75 @@
+use metaverse_messages::{
+    economy_data::EconomyData,
+    economy_data_request::EconomyDataRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+
+fn economy() -> EconomyData {
+    EconomyData {
+        object_capacity: 15000,
+        object_count: 0,
+        price_energy_unit: 100,
+        price_object_claim: 10,
+        price_public_object_decay: 4,
+        price_public_object_delete: 4,
+        price_parcel_claim: 1,
+        price_parcel_claim_factor: 1.0,
+        price_upload: 10,
+        price_rent_light: 5,
+        teleport_min_price: 2,
+        teleport_price_exponent: 2.0,
+        energy_efficiency: 1.0,
+        price_object_rent: 1.0,
+        price_object_scale_factor: 10.0,
+        price_parcel_rent: 1,
+        price_group_create: 100,
+    }
+}
+
+#[test]
+fn test_economy_data_request() {
+    assert!(EconomyDataRequest {}.to_bytes().is_empty());
+    match Packet::from_bytes(&Packet::new_economy_data_request(EconomyDataRequest {}).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::EconomyDataRequest(_) => {}
+        _ => panic!("expected EconomyDataRequest"),
+    }
+}
+
+#[test]
+fn test_economy_data() {
+    let economy = economy();
+    let bytes = economy.to_bytes();
+    assert_eq!(bytes.len(), 68);
+    // the upload price is the ninth field
+    assert_eq!(&bytes[32..36], &10i32.to_le_bytes());
+    assert_eq!(EconomyData::from_bytes(&bytes).unwrap(), economy);
+    match Packet::from_bytes(&Packet::new_economy_data(economy.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::EconomyData(parsed) => assert_eq!(*parsed, economy),
+        _ => panic!("expected EconomyData"),
+    }
+
+    // fields added by newer simulators are ignored
+    let mut longer = bytes.clone();
+    longer.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(EconomyData::from_bytes(&longer).unwrap(), economy);
+    assert!(EconomyData::from_bytes(&bytes[..60]).is_err());
+}
+
+#[test]
+fn test_economy_data_event() {
+    let economy = economy();
+    let body = PacketType::EconomyData(Box::new(economy.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::EconomyDataEvent));
+    match UiEventTypes::EconomyDataEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::EconomyData(parsed)) => assert_eq!(*parsed, economy),
+        _ => panic!("failed to decode EconomyDataEvent"),
+    }
+}
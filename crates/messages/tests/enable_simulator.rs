@@ -0,0 +1,65 @@
+use metaverse_messages::{
+    child_simulator_event::ChildSimulatorEvent,
+    enable_simulator::EnableSimulator,
+    kill_object::KillObjectData,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::region_handle::{region_coordinates, region_handle},
+};
+use std::net::Ipv4Addr;
+
+#[test]
+fn test_region_handle() {
+    let handle = region_handle(256000, 256256);
+    assert_eq!(handle, 0x0003_e800_0003_e900);
+    assert_eq!(region_coordinates(handle), (256000, 256256));
+}
+
+#[test]
+fn test_enable_simulator() {
+    let bytes = [
+        0x00, 0xe9, 0x03, 0x00, 0x00, 0xe8, 0x03, 0x00, // region handle
+        127, 0, 0, 1, // ip
+        0x23, 0x29, // port 9001
+    ];
+    let enable_simulator = EnableSimulator::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        enable_simulator.region_handle,
+        region_handle(256000, 256256)
+    );
+    assert_eq!(enable_simulator.ip, Ipv4Addr::new(127, 0, 0, 1));
+    assert_eq!(enable_simulator.port, 9001);
+    assert_eq!(enable_simulator.to_bytes(), bytes);
+
+    assert!(EnableSimulator::from_bytes(&bytes[..13]).is_err());
+
+    let packet = Packet::new_enable_simulator(enable_simulator);
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    assert!(matches!(parsed.body, PacketType::EnableSimulator(_)));
+}
+
+#[test]
+fn test_child_simulator_event() {
+    // events from child circuits are wrapped with the region they came from
+    let body = PacketType::KillObject(Box::new(KillObjectData {
+        local_ids: vec![1001],
+    }));
+    let wrapped = PacketType::ChildSimulatorEvent(Box::new(ChildSimulatorEvent {
+        region_handle: region_handle(256256, 256000),
+        event: body.ui_event(),
+        data: body.ui_event_bytes(),
+    }));
+
+    let event = wrapped.ui_event();
+    assert!(matches!(event, UiEventTypes::ChildSimulatorEvent));
+    let child_event = match event.packet_type_from_bytes(&wrapped.ui_event_bytes()) {
+        Some(PacketType::ChildSimulatorEvent(child_event)) => child_event,
+        _ => panic!("failed to decode ChildSimulatorEvent"),
+    };
+    assert_eq!(child_event.region_handle, region_handle(256256, 256000));
+    match child_event.packet() {
+        Some(PacketType::KillObject(kill_object)) => assert_eq!(kill_object.local_ids, vec![1001]),
+        _ => panic!("failed to decode the wrapped event"),
+    }
+}
@@ -0,0 +1,91 @@
+use metaverse_messages::{
+    estate_owner_message::{EstateOwnerMessage, RegionInfoSettings},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::agent_access::AgentAccess,
+};
+use uuid::Uuid;
+
+#[test]
+fn test_parameters() {
+    let mut message = EstateOwnerMessage::new(
+        "kickestate".to_string(),
+        vec!["ab".to_string(), String::new()],
+    );
+    assert!(message.agent_id.is_nil());
+    assert!(!message.invoice.is_nil());
+    message.invoice = Uuid::from_bytes([0x10; 16]);
+    let bytes = message.to_bytes();
+    // the method, then the invoice, then the parameters, all null terminated
+    assert_eq!(&bytes[48..60], b"\x0bkickestate\0");
+    assert_eq!(&bytes[60..76], &[0x10; 16]);
+    assert_eq!(&bytes[76..], &[2, 3, b'a', b'b', 0, 1, 0]);
+    assert_eq!(EstateOwnerMessage::from_bytes(&bytes).unwrap(), message);
+    match Packet::from_bytes(&Packet::new_estate_owner_message(message.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::EstateOwnerMessage(parsed) => assert_eq!(*parsed, message),
+        _ => panic!("expected EstateOwnerMessage"),
+    }
+}
+
+#[test]
+fn test_helpers() {
+    let sender = Uuid::from_bytes([0x01; 16]);
+    let user = Uuid::from_bytes([0x02; 16]);
+
+    let message = EstateOwnerMessage::instant_message(sender, "Estate Owner", "restarting");
+    assert_eq!(message.method, "instantmessage");
+    let sender_id = sender.to_string();
+    assert_eq!(
+        message.params,
+        vec!["-1", "-1", sender_id.as_str(), "Estate Owner", "restarting"]
+    );
+    let message = EstateOwnerMessage::simulator_message(sender, "Estate Owner", "restarting");
+    assert_eq!(message.method, "simulatormessage");
+    assert_eq!(message.params.len(), 5);
+
+    let message = EstateOwnerMessage::teleport_home_user(sender, user);
+    assert_eq!(message.method, "teleporthomeuser");
+    assert_eq!(message.params, vec![sender.to_string(), user.to_string()]);
+
+    let message = EstateOwnerMessage::set_region_info(&RegionInfoSettings {
+        block_terraform: true,
+        block_fly: false,
+        allow_damage: false,
+        allow_land_resell: true,
+        agent_limit: 40.0,
+        object_bonus: 1.5,
+        sim_access: AgentAccess::Mature,
+        restrict_pushobject: false,
+        allow_parcel_changes: true,
+    });
+    assert_eq!(message.method, "setregioninfo");
+    assert_eq!(
+        message.params,
+        vec!["Y", "N", "N", "Y", "40.000000", "1.500000", "21", "N", "Y"]
+    );
+}
+
+#[test]
+fn test_estate_owner_message_event() {
+    let message = EstateOwnerMessage {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        session_id: Uuid::nil(),
+        transaction_id: Uuid::nil(),
+        method: "estateupdateinfo".to_string(),
+        invoice: Uuid::from_bytes([0x10; 16]),
+        params: vec!["Mainland".to_string(), "1".to_string()],
+    };
+    let body = PacketType::EstateOwnerMessage(Box::new(message.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::EstateOwnerMessageEvent
+    ));
+    match UiEventTypes::EstateOwnerMessageEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::EstateOwnerMessage(parsed)) => assert_eq!(*parsed, message),
+        _ => panic!("failed to decode EstateOwnerMessageEvent"),
+    }
+}
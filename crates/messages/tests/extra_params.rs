@@ -0,0 +1,307 @@
+use glam::Vec3;
+use metaverse_messages::{
+    object_extra_params::{ObjectExtraParams, ObjectExtraParamsData},
+    object_material::{ObjectMaterial, ObjectMaterialData},
+    object_update::ObjectUpdateData,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    set_extra_params::SetExtraParams,
+    utils::{
+        extra_params::{
+            read_extra_params, write_extra_params, ExtraParam, FlexibleParams, LightParams,
+            MeshParams, SculptParams, SculptType, FLEXIBLE_PARAM, LIGHT_PARAM, SCULPT_PARAM,
+        },
+        material::Material,
+    },
+};
+use std::io::Cursor;
+use uuid::Uuid;
+
+fn flexible() -> FlexibleParams {
+    FlexibleParams {
+        softness: 2,
+        tension: 1.0,
+        drag: 2.0,
+        gravity: 0.5,
+        wind: 0.5,
+        force: Vec3::new(0.0, 0.0, -1.5),
+    }
+}
+
+fn light() -> LightParams {
+    LightParams {
+        color: [255, 128, 0, 200],
+        radius: 10.0,
+        cutoff: 0.0,
+        falloff: 0.75,
+    }
+}
+
+fn sculpt() -> SculptParams {
+    SculptParams {
+        texture_id: Uuid::from_bytes([0x42; 16]),
+        sculpt_type: SculptType::Torus,
+        invert: true,
+        mirror: false,
+    }
+}
+
+fn round_trip(param: ExtraParam) -> ExtraParam {
+    ExtraParam::from_data(param.param_type(), &param.to_data()).unwrap()
+}
+
+#[test]
+fn test_flexible_round_trip() {
+    let param = ExtraParam::Flexible(flexible());
+    let data = param.to_data();
+    assert_eq!(param.param_type(), FLEXIBLE_PARAM);
+    assert_eq!(data.len(), 16);
+    // the high bit of the softness rides on the tension byte, the low bit on the drag byte
+    assert_eq!(&data[..4], &[0x80 | 10, 20, 105, 5]);
+    assert_eq!(&data[12..16], &(-1.5f32).to_le_bytes());
+    assert_eq!(round_trip(param.clone()), param);
+
+    let param = ExtraParam::Flexible(FlexibleParams {
+        softness: 3,
+        ..flexible()
+    });
+    assert_eq!(round_trip(param.clone()), param);
+}
+
+#[test]
+fn test_light_round_trip() {
+    let param = ExtraParam::Light(light());
+    let data = param.to_data();
+    assert_eq!(param.param_type(), LIGHT_PARAM);
+    assert_eq!(data.len(), 16);
+    assert_eq!(&data[..4], &[255, 128, 0, 200]);
+    assert_eq!(&data[4..8], &10f32.to_le_bytes());
+    assert_eq!(round_trip(param.clone()), param);
+}
+
+#[test]
+fn test_sculpt_round_trip() {
+    let param = ExtraParam::Sculpt(sculpt());
+    let data = param.to_data();
+    assert_eq!(param.param_type(), SCULPT_PARAM);
+    assert_eq!(data.len(), 17);
+    assert_eq!(&data[..16], &[0x42; 16]);
+    assert_eq!(data[16], 0x40 | 2);
+    assert_eq!(round_trip(param.clone()), param);
+
+    let param = ExtraParam::Sculpt(SculptParams {
+        sculpt_type: SculptType::Cylinder,
+        invert: false,
+        mirror: true,
+        ..sculpt()
+    });
+    assert_eq!(round_trip(param.clone()), param);
+}
+
+#[test]
+fn test_mesh_round_trip() {
+    let param = ExtraParam::Mesh(MeshParams {
+        mesh_id: Uuid::from_bytes([0x17; 16]),
+    });
+    let data = param.to_data();
+    // meshes are sent as sculpt params with the mesh sculpt type
+    assert_eq!(param.param_type(), SCULPT_PARAM);
+    assert_eq!(data[16], 5);
+    assert_eq!(round_trip(param.clone()), param);
+}
+
+#[test]
+fn test_unknown_param_kept() {
+    let param = ExtraParam::Unknown {
+        param_type: 0x70,
+        data: vec![1, 2, 3],
+    };
+    assert_eq!(round_trip(param.clone()), param);
+}
+
+#[test]
+fn test_short_param_data() {
+    assert!(ExtraParam::from_data(LIGHT_PARAM, &[0; 8]).is_err());
+    assert!(ExtraParam::from_data(SCULPT_PARAM, &[0; 16]).is_err());
+}
+
+#[test]
+fn test_extra_params_blob() {
+    let params = vec![ExtraParam::Light(light()), ExtraParam::Sculpt(sculpt())];
+    let bytes = write_extra_params(&params);
+    assert_eq!(bytes[0], 2);
+    assert_eq!(&bytes[1..3], &LIGHT_PARAM.to_le_bytes());
+    assert_eq!(&bytes[3..7], &16u32.to_le_bytes());
+    assert_eq!(bytes.len(), 1 + 6 + 16 + 6 + 17);
+    assert_eq!(read_extra_params(&bytes).unwrap(), params);
+
+    // objects without extra params
+    assert_eq!(write_extra_params(&[]), Vec::<u8>::new());
+    assert!(read_extra_params(&[]).unwrap().is_empty());
+    assert!(read_extra_params(&[0]).unwrap().is_empty());
+
+    // a size past the end of the blob
+    let mut bytes = write_extra_params(&[ExtraParam::Light(light())]);
+    bytes[3] = 40;
+    assert!(read_extra_params(&bytes).is_err());
+}
+
+// an ObjectUpdate block of a default box with the extra params, with every other variable field
+// left empty
+fn object_block(extra_params: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&1002u32.to_le_bytes());
+    bytes.push(0); // State
+    bytes.extend_from_slice(&[0x9f; 16]); // FullID
+    bytes.extend_from_slice(&0u32.to_le_bytes()); // CRC
+    bytes.push(9); // PCode
+    bytes.push(3); // Material
+    bytes.push(0); // ClickAction
+    bytes.extend_from_slice(&[0; 12]); // Scale
+    bytes.push(0); // ObjectData
+    bytes.extend_from_slice(&0u32.to_le_bytes()); // ParentID
+    bytes.extend_from_slice(&0u32.to_le_bytes()); // UpdateFlags
+    bytes.extend_from_slice(&[16, 1, 0, 0, 0, 0, 100, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    bytes.extend_from_slice(&0u16.to_le_bytes()); // TextureEntry
+    bytes.push(0); // TextureAnim
+    bytes.extend_from_slice(&0u16.to_le_bytes()); // NameValue
+    bytes.extend_from_slice(&0u16.to_le_bytes()); // Data
+    bytes.push(0); // Text
+    bytes.extend_from_slice(&[0, 0, 0, 0]); // TextColor
+    bytes.push(0); // MediaURL
+    bytes.push(0); // PSBlock
+    bytes.push(extra_params.len() as u8);
+    bytes.extend_from_slice(extra_params);
+    bytes.extend_from_slice(&[0; 16]); // Sound
+    bytes.extend_from_slice(&[0; 16]); // OwnerID
+    bytes.extend_from_slice(&0f32.to_le_bytes()); // Gain
+    bytes.push(0); // Flags
+    bytes.extend_from_slice(&0f32.to_le_bytes()); // Radius
+    bytes.push(0); // JointType
+    bytes.extend_from_slice(&[0; 24]); // JointPivot and JointAxisOrAnchor
+    bytes
+}
+
+#[test]
+fn test_object_update_extra_params() {
+    let mut blob = vec![2];
+    // a flexible param, written out as OpenSim sends it
+    blob.extend_from_slice(&FLEXIBLE_PARAM.to_le_bytes());
+    blob.extend_from_slice(&16u32.to_le_bytes());
+    blob.extend_from_slice(&[0x80 | 10, 20, 105, 5]);
+    for value in [0.0f32, 0.0, -1.5] {
+        blob.extend_from_slice(&value.to_le_bytes());
+    }
+    blob.extend_from_slice(&LIGHT_PARAM.to_le_bytes());
+    blob.extend_from_slice(&16u32.to_le_bytes());
+    blob.extend_from_slice(&ExtraParam::Light(light()).to_data());
+
+    let bytes = object_block(&blob);
+    let object = ObjectUpdateData::from_bytes(&mut Cursor::new(bytes.as_slice())).unwrap();
+    assert_eq!(object.extra_params().unwrap().len(), 2);
+    assert_eq!(object.flexible(), Some(flexible()));
+    assert_eq!(object.light(), Some(light()));
+    assert_eq!(object.sculpt(), None);
+    assert_eq!(object.mesh(), None);
+
+    let bytes = object_block(&[]);
+    let object = ObjectUpdateData::from_bytes(&mut Cursor::new(bytes.as_slice())).unwrap();
+    assert!(object.extra_params().unwrap().is_empty());
+    assert_eq!(object.light(), None);
+}
+
+#[test]
+fn test_object_extra_params_round_trip() {
+    let edit = ObjectExtraParams::new(vec![
+        ObjectExtraParamsData::new(7, &ExtraParam::Flexible(flexible()), true),
+        ObjectExtraParamsData::new(8, &ExtraParam::Light(light()), false),
+    ]);
+    let bytes = edit.to_bytes();
+    assert_eq!(bytes[32], 2);
+    assert_eq!(&bytes[33..37], &7u32.to_le_bytes());
+    assert_eq!(&bytes[37..39], &FLEXIBLE_PARAM.to_le_bytes());
+    assert_eq!(bytes[39], 1);
+    assert_eq!(&bytes[40..44], &16u32.to_le_bytes());
+    assert_eq!(bytes[44], 16);
+    assert_eq!(
+        edit.objects[0].param().unwrap(),
+        ExtraParam::Flexible(flexible())
+    );
+    // a removed param sends no data
+    assert!(edit.objects[1].param_data.is_empty());
+
+    match Packet::from_bytes(&Packet::new_object_extra_params(edit.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectExtraParams(parsed) => assert_eq!(*parsed, edit),
+        _ => panic!("expected ObjectExtraParams"),
+    }
+}
+
+#[test]
+fn test_set_extra_params() {
+    let command = SetExtraParams {
+        local_ids: vec![1, 2, 3],
+        param: ExtraParam::Mesh(MeshParams {
+            mesh_id: Uuid::from_bytes([0x17; 16]),
+        }),
+        in_use: true,
+    };
+    let edit = command.to_object_extra_params();
+    assert_eq!(edit.agent_id, Uuid::nil());
+    assert_eq!(edit.objects.len(), 3);
+    assert!(edit
+        .objects
+        .iter()
+        .all(|object| object.param().unwrap() == command.param));
+
+    match Packet::from_bytes(&Packet::new_set_extra_params(command.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::SetExtraParams(parsed) => assert_eq!(*parsed, command),
+        _ => panic!("expected SetExtraParams"),
+    }
+}
+
+#[test]
+fn test_object_material_round_trip() {
+    let edit = ObjectMaterial::new(vec![
+        ObjectMaterialData {
+            local_id: 7,
+            material: Material::Glass,
+        },
+        ObjectMaterialData {
+            local_id: 8,
+            material: Material::Unknown(12),
+        },
+    ]);
+    let bytes = edit.to_bytes();
+    assert_eq!(bytes.len(), 33 + 10);
+    assert_eq!(bytes[37], 2);
+    assert_eq!(bytes[42], 12);
+
+    match Packet::from_bytes(&Packet::new_object_material(edit.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectMaterial(parsed) => assert_eq!(*parsed, edit),
+        _ => panic!("expected ObjectMaterial"),
+    }
+}
+
+#[test]
+fn test_object_material_split() {
+    let objects: Vec<ObjectMaterialData> = (0..300)
+        .map(|local_id| ObjectMaterialData {
+            local_id,
+            material: Material::Wood,
+        })
+        .collect();
+    let packets = ObjectMaterial::new(objects).split();
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].objects.len(), 255);
+    assert_eq!(packets[1].objects[44].local_id, 299);
+}
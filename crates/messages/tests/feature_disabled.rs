@@ -0,0 +1,44 @@
+use hex::FromHex;
+use metaverse_messages::{
+    feature_disabled::FeatureDisabled,
+    feature_disabled_notice::FeatureDisabledNotice,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::uuid;
+
+#[test]
+fn test_feature_disabled() {
+    let bytes = Vec::from_hex(concat!(
+        "000000000100ffff0013",
+        "034e6f00",                         // "No"
+        "320dff8a7a594720a0f75a8df3698d9a", // agent
+        "5748deccf629461c9a36a35a221fe21f", // transaction
+    ))
+    .unwrap();
+    let disabled = FeatureDisabled {
+        error_message: "No".to_string(),
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        transaction_id: uuid!("5748decc-f629-461c-9a36-a35a221fe21f"),
+    };
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::FeatureDisabled(parsed) => assert_eq!(*parsed, disabled),
+        _ => panic!("expected FeatureDisabled"),
+    }
+    assert_eq!(disabled.to_bytes(), bytes[10..]);
+}
+
+#[test]
+fn test_feature_disabled_notice() {
+    let notice = FeatureDisabledNotice {
+        transaction_id: uuid!("5748decc-f629-461c-9a36-a35a221fe21f"),
+        operation: None,
+        message: "No".to_string(),
+    };
+    let body = PacketType::FeatureDisabledNotice(Box::new(notice.clone()));
+    match UiEventTypes::FeatureDisabledEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::FeatureDisabledNotice(parsed)) => assert_eq!(*parsed, notice),
+        _ => panic!("failed to decode FeatureDisabledEvent"),
+    }
+}
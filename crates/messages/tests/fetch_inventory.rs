@@ -0,0 +1,118 @@
+use hex::FromHex;
+use metaverse_messages::{
+    fetch_inventory::{FetchInventory, ItemRequest},
+    fetch_inventory_reply::FetchInventoryReply,
+    inventory_item_status::InventoryItemStatus,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::{
+        asset_type::AssetType,
+        inventory_item::{InventoryItem, ItemData},
+        permissions::Permissions,
+        sale_type::SaleType,
+    },
+};
+use uuid::Uuid;
+
+fn item() -> InventoryItem {
+    InventoryItem {
+        item_id: Uuid::from_bytes([0x01; 16]),
+        folder_id: Uuid::from_bytes([0x10; 16]),
+        creator_id: Uuid::from_bytes([0xEE; 16]),
+        owner_id: Uuid::from_bytes([0xFF; 16]),
+        group_id: Uuid::nil(),
+        base_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        owner_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        group_mask: Permissions::default(),
+        everyone_mask: Permissions::default(),
+        next_owner_mask: Permissions::from_bits(Permissions::TRANSFER),
+        group_owned: false,
+        asset_id: Uuid::from_bytes([0x20; 16]),
+        asset_type: AssetType::Notecard,
+        inventory_type: 7,
+        flags: 0,
+        sale_type: SaleType::NotForSale,
+        sale_price: 0,
+        name: "Notes".to_string(),
+        description: "some notes".to_string(),
+        creation_date: 1_700_000_000,
+    }
+}
+
+#[test]
+fn test_fetch_inventory() {
+    let fetch = FetchInventory {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        session_id: Uuid::from_bytes([0x02; 16]),
+        ..FetchInventory::new(vec![
+            ItemRequest {
+                owner_id: Uuid::from_bytes([0x01; 16]),
+                item_id: Uuid::from_bytes([0x10; 16]),
+            },
+            ItemRequest {
+                owner_id: Uuid::from_bytes([0x03; 16]),
+                item_id: Uuid::from_bytes([0x11; 16]),
+            },
+        ])
+    };
+    let bytes = fetch.to_bytes();
+    assert_eq!(bytes.len(), 33 + 2 * 32);
+    assert_eq!(bytes[32], 0x02);
+    assert_eq!(FetchInventory::from_bytes(&bytes).unwrap(), fetch);
+
+    let packet = Packet::new_fetch_inventory(fetch.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::FetchInventory(parsed) => assert_eq!(*parsed, fetch),
+        _ => panic!("expected FetchInventory"),
+    }
+}
+
+#[test]
+fn test_fetch_inventory_reply() {
+    let reply = FetchInventoryReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        items: vec![ItemData {
+            item: item(),
+            crc: item().crc(),
+            crc_valid: true,
+        }],
+    };
+    let mut bytes = Vec::from_hex("400000003300ffff0118").unwrap();
+    bytes.extend_from_slice(&reply.to_bytes());
+
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    match &packet.body {
+        PacketType::FetchInventoryReply(parsed) => assert_eq!(**parsed, reply),
+        _ => panic!("expected FetchInventoryReply"),
+    }
+
+    // a checksum that doesn't match is kept, and flagged
+    let reply = FetchInventoryReply {
+        items: vec![ItemData {
+            item: item(),
+            crc: 0x1234_5678,
+            crc_valid: false,
+        }],
+        ..reply
+    };
+    let parsed = FetchInventoryReply::from_bytes(&reply.to_bytes()).unwrap();
+    assert_eq!(parsed, reply);
+}
+
+#[test]
+fn test_inventory_item_event() {
+    for status in [
+        InventoryItemStatus::Found(item()),
+        InventoryItemStatus::NotFound {
+            item_id: Uuid::from_bytes([0x01; 16]),
+        },
+    ] {
+        let body = PacketType::InventoryItemStatus(Box::new(status.clone()));
+        assert!(matches!(body.ui_event(), UiEventTypes::InventoryItemEvent));
+        match UiEventTypes::InventoryItemEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+            Some(PacketType::InventoryItemStatus(parsed)) => assert_eq!(*parsed, status),
+            _ => panic!("failed to decode InventoryItemEvent"),
+        }
+    }
+}
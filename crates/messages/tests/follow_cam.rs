@@ -0,0 +1,126 @@
+use hex::FromHex;
+use metaverse_messages::{
+    clear_follow_cam_properties::ClearFollowCamProperties,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    set_follow_cam_properties::{FollowCamProperty, FollowCamPropertyType},
+    ui_events::UiEventTypes,
+};
+use uuid::uuid;
+
+#[test]
+fn test_camera_constraint() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a0016",
+        "0000803f", // 1.0
+        "00000000", // 0.0
+        "00000000", // 0.0
+        "000020c1", // -10.0
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let camera_constraint = match &packet.body {
+        PacketType::CameraConstraint(camera_constraint) => camera_constraint.clone(),
+        _ => panic!("expected CameraConstraint"),
+    };
+    assert_eq!(camera_constraint.plane.to_array(), [1.0, 0.0, 0.0, -10.0]);
+    assert_eq!(camera_constraint.to_bytes(), bytes[7..]);
+
+    let body = packet.body;
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::CameraConstraintEvent
+    ));
+    match UiEventTypes::CameraConstraintEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::CameraConstraint(decoded)) => assert_eq!(decoded, camera_constraint),
+        _ => panic!("failed to decode CameraConstraintEvent"),
+    }
+}
+
+#[test]
+fn test_set_follow_cam_properties() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a00ffff009f",
+        "3c2d1e0f4b5a4968b7c6d5e4f3a2b190",
+        "03",
+        "0c000000", // Active
+        "0000803f", // 1.0
+        "07000000", // Distance
+        "0000a040", // 5.0
+        "63000000", // a type this client doesn't know
+        "00000040", // 2.0
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let properties = match &packet.body {
+        PacketType::SetFollowCamProperties(properties) => properties.clone(),
+        _ => panic!("expected SetFollowCamProperties"),
+    };
+    assert_eq!(
+        properties.object_id,
+        uuid!("3c2d1e0f-4b5a-4968-b7c6-d5e4f3a2b190")
+    );
+    assert_eq!(
+        properties.properties,
+        vec![
+            FollowCamProperty {
+                property_type: FollowCamPropertyType::Active,
+                value: 1.0,
+            },
+            FollowCamProperty {
+                property_type: FollowCamPropertyType::Distance,
+                value: 5.0,
+            },
+            FollowCamProperty {
+                property_type: FollowCamPropertyType::Unknown(99),
+                value: 2.0,
+            },
+        ]
+    );
+    assert_eq!(properties.get(FollowCamPropertyType::Distance), Some(5.0));
+    assert_eq!(properties.get(FollowCamPropertyType::Pitch), None);
+    assert_eq!(properties.to_bytes(), bytes[10..]);
+
+    let body = packet.body;
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::SetFollowCamPropertiesEvent
+    ));
+    match UiEventTypes::SetFollowCamPropertiesEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::SetFollowCamProperties(decoded)) => assert_eq!(decoded, properties),
+        _ => panic!("failed to decode SetFollowCamPropertiesEvent"),
+    }
+}
+
+#[test]
+fn test_follow_cam_property_types() {
+    for value in 0..=22 {
+        let property_type = FollowCamPropertyType::from_bytes(value);
+        assert!(!matches!(property_type, FollowCamPropertyType::Unknown(_)));
+        assert_eq!(property_type.to_bytes(), value);
+    }
+    assert_eq!(
+        FollowCamPropertyType::from_bytes(-1),
+        FollowCamPropertyType::Unknown(-1)
+    );
+    assert_eq!(FollowCamPropertyType::Unknown(-1).to_bytes(), -1);
+}
+
+#[test]
+fn test_clear_follow_cam_properties() {
+    let clear = ClearFollowCamProperties {
+        object_id: uuid!("3c2d1e0f-4b5a-4968-b7c6-d5e4f3a2b190"),
+    };
+    let body =
+        Packet::from_bytes(&Packet::new_clear_follow_cam_properties(clear.clone()).to_bytes())
+            .unwrap()
+            .body;
+    match &body {
+        PacketType::ClearFollowCamProperties(decoded) => assert_eq!(**decoded, clear),
+        _ => panic!("expected ClearFollowCamProperties"),
+    }
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::ClearFollowCamPropertiesEvent
+    ));
+}
@@ -0,0 +1,110 @@
+use hex::FromHex;
+use metaverse_messages::{
+    friend_list::{Friend, FriendList},
+    login_system::login_response::FriendsRights,
+    offline_notification::OfflineNotification,
+    online_notification::OnlineNotification,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::uuid;
+
+#[test]
+fn test_online_notification() {
+    let bytes = Vec::from_hex(concat!(
+        "400000003100ffff0142",
+        "02",
+        "8a1f4b0c3f4e4d529c1a2b7e5d6f8a90",
+        "11223344556677889900aabbccddeeff",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    match &packet.body {
+        PacketType::OnlineNotification(online) => {
+            assert_eq!(
+                online.agent_ids,
+                vec![
+                    uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90"),
+                    uuid!("11223344-5566-7788-9900-aabbccddeeff"),
+                ]
+            );
+            assert_eq!(online.to_bytes(), bytes[10..]);
+        }
+        _ => panic!("expected OnlineNotification"),
+    }
+}
+
+#[test]
+fn test_offline_notification() {
+    let bytes = Vec::from_hex(concat!(
+        "400000003200ffff0143",
+        "01",
+        "8a1f4b0c3f4e4d529c1a2b7e5d6f8a90",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    match &packet.body {
+        PacketType::OfflineNotification(offline) => {
+            assert_eq!(
+                offline.agent_ids,
+                vec![uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90")]
+            );
+            assert_eq!(offline.to_bytes(), bytes[10..]);
+        }
+        _ => panic!("expected OfflineNotification"),
+    }
+}
+
+#[test]
+fn test_presence_events() {
+    let agent_ids = vec![uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90")];
+    let body = PacketType::OnlineNotification(Box::new(OnlineNotification {
+        agent_ids: agent_ids.clone(),
+    }));
+    assert!(matches!(body.ui_event(), UiEventTypes::FriendOnlineEvent));
+    match UiEventTypes::FriendOnlineEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::OnlineNotification(parsed)) => assert_eq!(parsed.agent_ids, agent_ids),
+        _ => panic!("failed to decode FriendOnlineEvent"),
+    }
+
+    let body = PacketType::OfflineNotification(Box::new(OfflineNotification {
+        agent_ids: agent_ids.clone(),
+    }));
+    assert!(matches!(body.ui_event(), UiEventTypes::FriendOfflineEvent));
+    match UiEventTypes::FriendOfflineEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::OfflineNotification(parsed)) => assert_eq!(parsed.agent_ids, agent_ids),
+        _ => panic!("failed to decode FriendOfflineEvent"),
+    }
+}
+
+#[test]
+fn test_friend_list_event() {
+    let friend_list = FriendList {
+        friends: vec![
+            Friend {
+                id: uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90"),
+                online: true,
+                rights_given: FriendsRights {
+                    can_see_online: true,
+                    can_see_on_map: false,
+                    can_modify_objects: false,
+                },
+                rights_has: FriendsRights::default(),
+            },
+            Friend {
+                id: uuid!("11223344-5566-7788-9900-aabbccddeeff"),
+                online: false,
+                rights_given: FriendsRights::default(),
+                rights_has: FriendsRights::default(),
+            },
+        ],
+    };
+    assert_eq!(friend_list.online().count(), 1);
+    let body = PacketType::FriendList(Box::new(friend_list.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::FriendListEvent));
+    match UiEventTypes::FriendListEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::FriendList(parsed)) => assert_eq!(*parsed, friend_list),
+        _ => panic!("failed to decode FriendListEvent"),
+    }
+}
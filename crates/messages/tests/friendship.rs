@@ -0,0 +1,153 @@
+use glam::Vec3;
+use metaverse_messages::{
+    accept_friendship::AcceptFriendship,
+    change_user_rights::ChangeUserRights,
+    decline_friendship::DeclineFriendship,
+    friendship_status::FriendshipStatus,
+    grant_user_rights::{GrantUserRights, UserRights},
+    improved_instant_message::{ImprovedInstantMessage, InstantMessageDialog},
+    login_system::login_response::FriendsRights,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    terminate_friendship::TerminateFriendship,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn instant_message(dialog: InstantMessageDialog) -> ImprovedInstantMessage {
+    ImprovedInstantMessage {
+        from_agent_id: Uuid::from_bytes([0x01; 16]),
+        session_id: Uuid::nil(),
+        from_group: false,
+        to_agent_id: Uuid::from_bytes([0x02; 16]),
+        parent_estate_id: 1,
+        region_id: Uuid::nil(),
+        position: Vec3::ZERO,
+        offline: 0,
+        dialog,
+        id: Uuid::from_bytes([0x03; 16]),
+        timestamp: 0,
+        from_agent_name: "Alice Resident".to_string(),
+        message: "be my friend".to_string(),
+        binary_bucket: vec![],
+    }
+}
+
+#[test]
+fn test_friends_rights_bits() {
+    let rights = FriendsRights::from_bits(FriendsRights::ONLINE | FriendsRights::MODIFY | 0x10);
+    assert!(rights.can_see_online);
+    assert!(!rights.can_see_on_map);
+    assert!(rights.can_modify_objects);
+    // unknown bits are dropped
+    assert_eq!(rights.to_bits(), 5);
+}
+
+#[test]
+fn test_accept_and_decline_friendship() {
+    let accept = AcceptFriendship {
+        agent_id: Uuid::from_bytes([0x02; 16]),
+        session_id: Uuid::from_bytes([0x04; 16]),
+        folder_ids: vec![Uuid::from_bytes([0x05; 16])],
+        ..AcceptFriendship::new(Uuid::from_bytes([0x03; 16]))
+    };
+    let bytes = accept.to_bytes();
+    assert_eq!(bytes.len(), 48 + 1 + 16);
+    assert_eq!(bytes[48], 1);
+    assert_eq!(AcceptFriendship::from_bytes(&bytes).unwrap(), accept);
+    match Packet::from_bytes(&Packet::new_accept_friendship(accept.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::AcceptFriendship(parsed) => assert_eq!(*parsed, accept),
+        _ => panic!("expected AcceptFriendship"),
+    }
+
+    let decline = DeclineFriendship::new(Uuid::from_bytes([0x03; 16]));
+    assert!(decline.agent_id.is_nil());
+    let bytes = decline.to_bytes();
+    assert_eq!(bytes.len(), 48);
+    assert_eq!(DeclineFriendship::from_bytes(&bytes).unwrap(), decline);
+    match Packet::from_bytes(&Packet::new_decline_friendship(decline.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::DeclineFriendship(parsed) => assert_eq!(*parsed, decline),
+        _ => panic!("expected DeclineFriendship"),
+    }
+}
+
+#[test]
+fn test_user_rights_packets() {
+    let rights = vec![UserRights {
+        agent_related: Uuid::from_bytes([0x01; 16]),
+        rights: FriendsRights::from_bits(FriendsRights::ONLINE | FriendsRights::MAP),
+    }];
+    let grant = GrantUserRights::new(rights.clone());
+    let bytes = grant.to_bytes();
+    assert_eq!(bytes.len(), 32 + 1 + 20);
+    assert_eq!(&bytes[49..53], &[0x03, 0x00, 0x00, 0x00]);
+    assert_eq!(GrantUserRights::from_bytes(&bytes).unwrap(), grant);
+    match Packet::from_bytes(&Packet::new_grant_user_rights(grant.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::GrantUserRights(parsed) => assert_eq!(*parsed, grant),
+        _ => panic!("expected GrantUserRights"),
+    }
+
+    let change = ChangeUserRights {
+        agent_id: Uuid::from_bytes([0x02; 16]),
+        rights,
+    };
+    let bytes = change.to_bytes();
+    assert_eq!(bytes.len(), 16 + 1 + 20);
+    assert_eq!(ChangeUserRights::from_bytes(&bytes).unwrap(), change);
+}
+
+#[test]
+fn test_terminate_friendship() {
+    let terminate = TerminateFriendship::new(Uuid::from_bytes([0x01; 16]));
+    let bytes = terminate.to_bytes();
+    assert_eq!(bytes.len(), 48);
+    assert_eq!(&bytes[32..48], &[0x01; 16]);
+    assert_eq!(TerminateFriendship::from_bytes(&bytes).unwrap(), terminate);
+}
+
+#[test]
+fn test_friendship_messages_are_not_chat() {
+    for dialog in [
+        InstantMessageDialog::FriendshipOffered,
+        InstantMessageDialog::FriendshipAccepted,
+        InstantMessageDialog::FriendshipDeclined,
+    ] {
+        let body = PacketType::ImprovedInstantMessage(Box::new(instant_message(dialog)));
+        assert!(matches!(body.ui_event(), UiEventTypes::None));
+    }
+    let body = PacketType::ImprovedInstantMessage(Box::new(instant_message(
+        InstantMessageDialog::MessageFromAgent,
+    )));
+    assert!(matches!(body.ui_event(), UiEventTypes::InstantMessageEvent));
+}
+
+#[test]
+fn test_friendship_status_event() {
+    for status in [
+        FriendshipStatus::Offered {
+            im_session_id: Uuid::from_bytes([0x03; 16]),
+            agent_id: Uuid::from_bytes([0x01; 16]),
+            name: "Alice Resident".to_string(),
+            message: "be my friend".to_string(),
+        },
+        FriendshipStatus::Terminated {
+            agent_id: Uuid::from_bytes([0x01; 16]),
+        },
+    ] {
+        let body = PacketType::FriendshipStatus(Box::new(status.clone()));
+        assert!(matches!(body.ui_event(), UiEventTypes::FriendshipEvent));
+        match UiEventTypes::FriendshipEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+            Some(PacketType::FriendshipStatus(parsed)) => assert_eq!(*parsed, status),
+            _ => panic!("failed to decode FriendshipEvent"),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use metaverse_messages::packet::Packet;
+use std::panic;
+
+/// how many random bodies each message number is fed
+const ROUNDS: usize = 64;
+
+/// a small random number generator, so failures can be reproduced from the seed
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bytes(&mut self, max_len: usize) -> Vec<u8> {
+        let len = self.next() as usize % (max_len + 1);
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+}
+
+/// the message numbers of every frequency, as they are written after the header
+fn message_numbers() -> Vec<Vec<u8>> {
+    let mut numbers = Vec::new();
+    for id in 0..=0xFEu8 {
+        numbers.push(vec![id]);
+        numbers.push(vec![0xFF, id]);
+        numbers.push(vec![0xFF, 0xFF, 0xFF, id]);
+    }
+    numbers.push(vec![0xFF, 0xFF, 0xFF, 0xFF]);
+    for id in 0..=1000u16 {
+        let [high, low] = id.to_be_bytes();
+        numbers.push(vec![0xFF, 0xFF, high, low]);
+    }
+    numbers
+}
+
+/// parse the bytes, failing with them if parsing panics
+fn parse(bytes: &[u8]) {
+    let result = panic::catch_unwind(|| {
+        let _ = Packet::from_bytes(bytes);
+    });
+    assert!(result.is_ok(), "parsing panicked on {:02x?}", bytes);
+}
+
+#[test]
+fn test_random_bodies_do_not_panic() {
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    for number in message_numbers() {
+        for round in 0..ROUNDS {
+            // a plain header, so the random body reaches the message's own parser
+            let mut bytes = vec![0x40, 0, 0, 0, 1, 0];
+            bytes.extend_from_slice(&number);
+            // mostly short bodies, which run out partway through a field
+            let max_len = if round % 4 == 0 { 1024 } else { 64 };
+            bytes.extend(rng.bytes(max_len));
+            parse(&bytes);
+        }
+    }
+}
+
+#[test]
+fn test_random_packets_do_not_panic() {
+    let mut rng = XorShift(0xD1B5_4A32_D192_ED03);
+    for _ in 0..100_000 {
+        let bytes = rng.bytes(128);
+        parse(&bytes);
+    }
+}
+
+#[test]
+fn test_random_headers_do_not_panic() {
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    for _ in 0..100_000 {
+        // any flags, including zerocoding and appended acks, and a short extra header
+        let mut bytes = vec![rng.next() as u8 & 0xF0, 0, 0, 0, 1, rng.next() as u8 % 4];
+        bytes.extend(rng.bytes(32));
+        parse(&bytes);
+    }
+}
@@ -0,0 +1,62 @@
+use metaverse_messages::{
+    generic_message::GenericMessageData,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn generic_message() -> GenericMessageData {
+    GenericMessageData {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        session_id: Uuid::from_bytes([0x02; 16]),
+        transaction_id: Uuid::nil(),
+        method: "autopilot".to_string(),
+        invoice: Uuid::from_bytes([0x10; 16]),
+        params: vec![
+            GenericMessageData::string_param("256000.0"),
+            vec![0xff, 0x00, 0x7f],
+            Vec::new(),
+        ],
+    }
+}
+
+#[test]
+fn test_generic_message() {
+    let message = generic_message();
+    let bytes = message.to_bytes();
+    assert_eq!(&bytes[48..59], b"\x0aautopilot\0");
+    assert_eq!(&bytes[59..75], &[0x10; 16]);
+    // each parameter is sent as it is, with a one byte length
+    assert_eq!(bytes[75], 3);
+    assert_eq!(&bytes[76..86], b"\x09256000.0\0");
+    assert_eq!(&bytes[86..], &[3, 0xff, 0x00, 0x7f, 0]);
+    assert_eq!(GenericMessageData::from_bytes(&bytes).unwrap(), message);
+    match Packet::from_bytes(&Packet::new_generic_message(message.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::GenericMessage(parsed) => assert_eq!(*parsed, message),
+        _ => panic!("expected GenericMessage"),
+    }
+}
+
+#[test]
+fn test_new_generic_message() {
+    let message = GenericMessageData::new("autopilot".to_string(), Vec::new());
+    assert!(message.agent_id.is_nil());
+    assert!(!message.invoice.is_nil());
+    let parsed = GenericMessageData::from_bytes(&message.to_bytes()).unwrap();
+    assert!(parsed.params.is_empty());
+}
+
+#[test]
+fn test_generic_message_event() {
+    let message = generic_message();
+    let body = PacketType::GenericMessage(Box::new(message.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::GenericMessageEvent));
+    match UiEventTypes::GenericMessageEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::GenericMessage(parsed)) => assert_eq!(*parsed, message),
+        _ => panic!("failed to decode GenericMessageEvent"),
+    }
+}
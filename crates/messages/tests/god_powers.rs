@@ -0,0 +1,85 @@
+use metaverse_messages::{
+    errors::{GodPowersError, SessionError},
+    god_kick_user::GodKickUser,
+    grant_godlike_powers::GrantGodlikePowers,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    request_godlike_powers::RequestGodlikePowers,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+#[test]
+fn test_request_godlike_powers() {
+    let request = RequestGodlikePowers::new(true);
+    assert!(request.agent_id.is_nil());
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 49);
+    assert_eq!(bytes[32], 1);
+    match Packet::from_bytes(&Packet::new_request_godlike_powers(request.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::RequestGodlikePowers(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected RequestGodlikePowers"),
+    }
+    assert_eq!(RequestGodlikePowers::new(false).to_bytes()[32], 0);
+}
+
+#[test]
+fn test_grant_godlike_powers() {
+    let grant = GrantGodlikePowers {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        session_id: Uuid::from_bytes([0x02; 16]),
+        god_level: 200,
+        token: Uuid::nil(),
+    };
+    let bytes = grant.to_bytes();
+    assert_eq!(bytes[32], 200);
+    match Packet::from_bytes(&Packet::new_grant_godlike_powers(grant.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::GrantGodlikePowers(parsed) => assert_eq!(*parsed, grant),
+        _ => panic!("expected GrantGodlikePowers"),
+    }
+
+    let body = PacketType::GrantGodlikePowers(Box::new(grant.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::GodPowersEvent));
+    match UiEventTypes::GodPowersEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::GrantGodlikePowers(parsed)) => assert_eq!(*parsed, grant),
+        _ => panic!("failed to decode GodPowersEvent"),
+    }
+}
+
+#[test]
+fn test_god_kick_user() {
+    let mut kick = GodKickUser::new(Uuid::from_bytes([0x03; 16]), "griefing".to_string());
+    assert!(kick.god_id.is_nil());
+    assert_eq!(kick.kick_flags, GodKickUser::KICK);
+    kick.kick_flags = GodKickUser::FREEZE;
+    let bytes = kick.to_bytes();
+    assert_eq!(&bytes[32..48], &[0x03; 16]);
+    assert_eq!(&bytes[48..52], &[1, 0, 0, 0]);
+    // the reason has a two byte length
+    assert_eq!(&bytes[52..54], &[9, 0]);
+    match Packet::from_bytes(&Packet::new_god_kick_user(kick.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::GodKickUser(parsed) => assert_eq!(*parsed, kick),
+        _ => panic!("expected GodKickUser"),
+    }
+}
+
+#[test]
+fn test_god_powers_error() {
+    let error = SessionError::GodPowers(GodPowersError::new("no god powers"));
+    match UiEventTypes::Error.packet_type_from_bytes(&error.to_bytes()) {
+        Some(PacketType::Error(parsed)) => match *parsed {
+            SessionError::GodPowers(e) => assert_eq!(e.message, "no god powers"),
+            other => panic!("expected GodPowersError, got {:?}", other),
+        },
+        _ => panic!("failed to decode the error"),
+    }
+}
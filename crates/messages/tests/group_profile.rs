@@ -0,0 +1,124 @@
+use metaverse_messages::{
+    avatar_groups_reply::{AvatarGroup, AvatarGroupsReply},
+    avatar_properties_request::AvatarPropertiesRequest,
+    group_profile_reply::GroupProfileReply,
+    group_profile_request::GroupProfileRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn group_profile() -> GroupProfileReply {
+    GroupProfileReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        group_id: Uuid::from_bytes([0x10; 16]),
+        name: "Builders".to_string(),
+        charter: "We build things.".to_string(),
+        show_in_list: true,
+        member_title: String::new(),
+        powers_mask: 0,
+        insignia_id: Uuid::from_bytes([0x20; 16]),
+        founder_id: Uuid::from_bytes([0x02; 16]),
+        membership_fee: 10,
+        open_enrollment: true,
+        money: 0,
+        group_membership_count: 42,
+        group_roles_count: 3,
+        allow_publish: false,
+        mature_publish: false,
+        owner_role: Uuid::from_bytes([0x30; 16]),
+    }
+}
+
+#[test]
+fn test_requests() {
+    let request = AvatarPropertiesRequest::new(Uuid::from_bytes([0x02; 16]));
+    assert_eq!(request.to_bytes().len(), 48);
+    match Packet::from_bytes(&Packet::new_avatar_properties_request(request.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::AvatarPropertiesRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected AvatarPropertiesRequest"),
+    }
+
+    let request = GroupProfileRequest::new(Uuid::from_bytes([0x10; 16]));
+    assert!(request.session_id.is_nil());
+    assert_eq!(&request.to_bytes()[32..48], &[0x10; 16]);
+    match Packet::from_bytes(&Packet::new_group_profile_request(request.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::GroupProfileRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected GroupProfileRequest"),
+    }
+}
+
+#[test]
+fn test_avatar_groups_reply() {
+    let reply = AvatarGroupsReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        avatar_id: Uuid::from_bytes([0x02; 16]),
+        groups: vec![AvatarGroup {
+            group_powers: u64::MAX,
+            accept_notices: true,
+            group_title: "Owner".to_string(),
+            group_id: Uuid::from_bytes([0x10; 16]),
+            group_name: "Builders".to_string(),
+            group_insignia_id: Uuid::from_bytes([0x20; 16]),
+        }],
+        list_in_profile: true,
+    };
+    let bytes = reply.to_bytes();
+    assert_eq!(bytes[32], 1);
+    assert_eq!(AvatarGroupsReply::from_bytes(&bytes).unwrap(), reply);
+
+    let body = PacketType::AvatarGroupsReply(Box::new(reply.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::AvatarGroupsEvent));
+    match UiEventTypes::AvatarGroupsEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::AvatarGroupsReply(parsed)) => assert_eq!(*parsed, reply),
+        _ => panic!("failed to decode AvatarGroupsEvent"),
+    }
+}
+
+#[test]
+fn test_avatar_with_no_groups() {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&[0x01; 16]);
+    bytes.extend_from_slice(&[0x02; 16]);
+    bytes.push(0);
+    // without the NewGroupData block
+    let reply = AvatarGroupsReply::from_bytes(&bytes).unwrap();
+    assert!(reply.groups.is_empty());
+    assert!(!reply.list_in_profile);
+
+    bytes.push(1);
+    let reply = AvatarGroupsReply::from_bytes(&bytes).unwrap();
+    assert!(reply.groups.is_empty());
+    assert!(reply.list_in_profile);
+    assert_eq!(reply.to_bytes(), bytes);
+}
+
+#[test]
+fn test_group_profile_reply() {
+    let profile = group_profile();
+    let bytes = profile.to_bytes();
+    // the charter has a two byte length
+    assert_eq!(&bytes[42..44], &[17, 0]);
+    assert_eq!(GroupProfileReply::from_bytes(&bytes).unwrap(), profile);
+    match Packet::from_bytes(&Packet::new_group_profile_reply(profile.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::GroupProfileReply(parsed) => assert_eq!(*parsed, profile),
+        _ => panic!("expected GroupProfileReply"),
+    }
+
+    let body = PacketType::GroupProfileReply(Box::new(profile.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::GroupProfileEvent));
+    match UiEventTypes::GroupProfileEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::GroupProfileReply(parsed)) => assert_eq!(*parsed, profile),
+        _ => panic!("failed to decode GroupProfileEvent"),
+    }
+}
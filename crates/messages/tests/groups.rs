@@ -0,0 +1,118 @@
+use metaverse_messages::{
+    activate_group::ActivateGroup,
+    agent_data_update::AgentDataUpdate,
+    join_group_reply::JoinGroupReply,
+    join_group_request::JoinGroupRequest,
+    leave_group_reply::LeaveGroupReply,
+    leave_group_request::LeaveGroupRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+#[test]
+fn test_group_requests() {
+    let group_id = Uuid::from_bytes([0x10; 16]);
+    let join = JoinGroupRequest::new(group_id);
+    assert!(join.agent_id.is_nil());
+    let bytes = join.to_bytes();
+    assert_eq!(bytes.len(), 48);
+    assert_eq!(&bytes[32..48], &[0x10; 16]);
+    match Packet::from_bytes(&Packet::new_join_group_request(join.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::JoinGroupRequest(parsed) => assert_eq!(*parsed, join),
+        _ => panic!("expected JoinGroupRequest"),
+    }
+
+    let leave = LeaveGroupRequest::new(group_id);
+    match Packet::from_bytes(&Packet::new_leave_group_request(leave.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::LeaveGroupRequest(parsed) => assert_eq!(*parsed, leave),
+        _ => panic!("expected LeaveGroupRequest"),
+    }
+
+    // a nil group clears the active group
+    let activate = ActivateGroup::new(Uuid::nil());
+    assert_eq!(activate.to_bytes(), vec![0; 48]);
+    match Packet::from_bytes(&Packet::new_activate_group(activate.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ActivateGroup(parsed) => assert_eq!(*parsed, activate),
+        _ => panic!("expected ActivateGroup"),
+    }
+}
+
+#[test]
+fn test_group_replies() {
+    let join = JoinGroupReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        group_id: Uuid::from_bytes([0x10; 16]),
+        success: true,
+    };
+    let bytes = join.to_bytes();
+    assert_eq!(bytes.len(), 33);
+    assert_eq!(bytes[32], 1);
+    assert_eq!(JoinGroupReply::from_bytes(&bytes).unwrap(), join);
+
+    let body = PacketType::JoinGroupReply(Box::new(join.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::JoinGroupEvent));
+    match UiEventTypes::JoinGroupEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::JoinGroupReply(parsed)) => assert_eq!(*parsed, join),
+        _ => panic!("failed to decode JoinGroupEvent"),
+    }
+
+    let leave = LeaveGroupReply {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        group_id: Uuid::from_bytes([0x10; 16]),
+        success: false,
+    };
+    assert_eq!(
+        LeaveGroupReply::from_bytes(&leave.to_bytes()).unwrap(),
+        leave
+    );
+    let body = PacketType::LeaveGroupReply(Box::new(leave.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::LeaveGroupEvent));
+    match UiEventTypes::LeaveGroupEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::LeaveGroupReply(parsed)) => assert_eq!(*parsed, leave),
+        _ => panic!("failed to decode LeaveGroupEvent"),
+    }
+}
+
+#[test]
+fn test_agent_data_update() {
+    let update = AgentDataUpdate {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        first_name: "Default".to_string(),
+        last_name: "User".to_string(),
+        group_title: "Member".to_string(),
+        active_group_id: Uuid::from_bytes([0x10; 16]),
+        group_powers: 1 << 40,
+        group_name: String::new(),
+    };
+    let bytes = update.to_bytes();
+    // the id, the names and title with their terminators, the group and powers, and the empty
+    // group name
+    assert_eq!(bytes.len(), 16 + 9 + 6 + 8 + 16 + 8 + 1);
+    assert_eq!(&bytes[39..55], &[0x10; 16]);
+    assert_eq!(AgentDataUpdate::from_bytes(&bytes).unwrap(), update);
+    match Packet::from_bytes(&Packet::new_agent_data_update(update.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::AgentDataUpdate(parsed) => assert_eq!(*parsed, update),
+        _ => panic!("expected AgentDataUpdate"),
+    }
+
+    let body = PacketType::AgentDataUpdate(Box::new(update.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::AgentDataEvent));
+    match UiEventTypes::AgentDataEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::AgentDataUpdate(parsed)) => assert_eq!(*parsed, update),
+        _ => panic!("failed to decode AgentDataEvent"),
+    }
+}
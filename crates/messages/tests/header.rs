@@ -1,5 +1,11 @@
 use hex::FromHex;
-use metaverse_messages::header::{Header, PacketFrequency};
+use metaverse_messages::{
+    header::{Header, PacketFrequency},
+    packet::Packet,
+    packet_ack::PacketAck,
+    packet_types::PacketType,
+    start_ping_check::StartPingCheck,
+};
 
 #[test]
 fn test_header_for_acks() {
@@ -105,3 +111,158 @@ fn test_header_tofrom_bytes() {
 
     assert!(header_bytes == header_back_to_bytes);
 }
+
+#[test]
+fn test_header_for_short_medium_packet() {
+    // a Medium packet with a one byte body is shorter than the six bytes the id is read from
+    let test_packet = Vec::from_hex("000000000100ff0e01").unwrap();
+    let test_header = Header::try_from_bytes(&test_packet).unwrap();
+    assert_eq!(test_header.frequency, PacketFrequency::Medium);
+    assert_eq!(test_header.id, 14);
+    assert_eq!(test_header.size, Some(8));
+}
+
+#[test]
+fn test_header_for_empty_high_packet() {
+    let test_packet = Vec::from_hex("00000000010017").unwrap();
+    let test_header = Header::try_from_bytes(&test_packet).unwrap();
+    assert_eq!(test_header.frequency, PacketFrequency::High);
+    assert_eq!(test_header.id, 23);
+    assert_eq!(test_header.size, Some(7));
+}
+
+fn header(id: u16, frequency: PacketFrequency, zerocoded: bool) -> Header {
+    Header {
+        reliable: true,
+        resent: false,
+        zerocoded,
+        appended_acks: false,
+        sequence_number: 0x01020304,
+        id,
+        frequency,
+        ack_list: None,
+        size: None,
+    }
+}
+
+#[test]
+fn test_header_frequencies_round_trip() {
+    // StartPingCheck, CoarseLocationUpdate, UseCircuitCode and PacketAck
+    for (id, frequency, hex) in [
+        (1, PacketFrequency::High, "01"),
+        (6, PacketFrequency::Medium, "ff06"),
+        (3, PacketFrequency::Low, "ffff0003"),
+        (251, PacketFrequency::Fixed, "fffffffb"),
+    ] {
+        for zerocoded in [false, true] {
+            let test_header = header(id, frequency, zerocoded);
+            let bytes = test_header.to_bytes();
+            let flags = if zerocoded { "c0" } else { "40" };
+            // the zero byte of a Low id is zerocoded
+            let id_hex = if zerocoded && frequency == PacketFrequency::Low {
+                "ffff000103"
+            } else {
+                hex
+            };
+            assert_eq!(
+                bytes,
+                Vec::from_hex(format!("{}0102030400{}", flags, id_hex)).unwrap()
+            );
+            let parsed = Header::try_from_bytes(&bytes).unwrap();
+            assert_eq!(parsed.id, id);
+            assert_eq!(parsed.frequency, frequency);
+            assert_eq!(parsed.sequence_number, 0x01020304);
+            assert!(parsed.reliable && !parsed.resent);
+            assert_eq!(parsed.zerocoded, zerocoded);
+            assert_eq!(parsed.size, Some(bytes.len()));
+        }
+    }
+}
+
+#[test]
+fn test_header_packets_round_trip() {
+    let bytes = Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 7,
+        oldest_unacked: 12,
+    })
+    .to_bytes();
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::StartPingCheck(ping) => assert_eq!(ping.ping_id, 7),
+        _ => panic!("expected StartPingCheck"),
+    }
+
+    let bytes = Packet::new_packet_ack(PacketAck {
+        packet_ids: vec![1, 2],
+    })
+    .to_bytes();
+    assert_eq!(bytes[6..10], [0xff, 0xff, 0xff, 0xfb]);
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::PacketAck(ack) => assert_eq!(ack.packet_ids, vec![1, 2]),
+        _ => panic!("expected PacketAck"),
+    }
+}
+
+#[test]
+fn test_header_zerocoded_low_ids() {
+    // both bytes of the id can be zerocoded, and a run can cover both of them
+    for (hex, id) in [
+        ("c00000000000ffff000103", 3),
+        ("c00000000000ffff010001", 256),
+        ("c00000000000ffff0002", 0),
+    ] {
+        let test_header = Header::try_from_bytes(&Vec::from_hex(hex).unwrap()).unwrap();
+        assert_eq!(test_header.frequency, PacketFrequency::Low);
+        assert_eq!(test_header.id, id);
+        assert_eq!(test_header.size, Some(hex.len() / 2));
+    }
+    let bytes = header(256, PacketFrequency::Low, true).to_bytes();
+    assert_eq!(bytes[6..], [0xff, 0xff, 0x01, 0x00, 0x01]);
+}
+
+#[test]
+fn test_header_skips_extra_header() {
+    let test_packet = Vec::from_hex("4000000001020a0bff06").unwrap();
+    let test_header = Header::try_from_bytes(&test_packet).unwrap();
+    assert_eq!(test_header.frequency, PacketFrequency::Medium);
+    assert_eq!(test_header.id, 6);
+    assert_eq!(test_header.size, Some(10));
+}
+
+#[test]
+fn test_header_for_truncated_packets() {
+    for hex in [
+        "",
+        "40",
+        "4000000001",
+        "400000000103aa",
+        "400000000100ffff00",
+        "5000000001000102",
+    ] {
+        assert!(Header::try_from_bytes(&Vec::from_hex(hex).unwrap()).is_err());
+    }
+}
+
+#[test]
+fn test_header_random_bytes_never_panic() {
+    // a small xorshift, so the test is the same every run
+    let mut state: u64 = 0x9e3779b97f4a7c15;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    for _ in 0..20000 {
+        let length = (next() % 24) as usize;
+        let mut bytes: Vec<u8> = (0..length).map(|_| next() as u8).collect();
+        // the message number markers are rare in random bytes, so make them common
+        if length > 8 && next() % 2 == 0 {
+            bytes[5] = 0;
+            bytes[6] = 0xff;
+            bytes[7] = 0xff;
+        }
+        if let Ok(test_header) = Header::try_from_bytes(&bytes) {
+            assert!(test_header.size.unwrap() <= bytes.len());
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use glam::Vec3;
+use metaverse_messages::{
+    improved_instant_message::{ImprovedInstantMessage, InstantMessageDialog},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::uuid;
+
+fn test_message() -> ImprovedInstantMessage {
+    ImprovedInstantMessage {
+        from_agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        from_group: false,
+        to_agent_id: uuid!("18e5f3a1-8b4c-4e2a-9d1b-3c6f7a8b9c0d"),
+        parent_estate_id: 1,
+        region_id: uuid!("d6b1d3c4-1e2f-4a5b-8c9d-0e1f2a3b4c5d"),
+        position: Vec3::new(128.0, 128.0, 25.5),
+        offline: 0,
+        dialog: InstantMessageDialog::MessageFromAgent,
+        id: uuid!("0a0b0c0d-0e0f-1011-1213-141516171819"),
+        timestamp: 0,
+        from_agent_name: "Default User".to_string(),
+        message: "hello there".to_string(),
+        binary_bucket: vec![],
+    }
+}
+
+#[test]
+fn test_instant_message_round_trip() {
+    let message = test_message();
+    let parsed = ImprovedInstantMessage::from_bytes(&message.to_bytes()).unwrap();
+    assert_eq!(parsed.from_agent_id, message.from_agent_id);
+    assert_eq!(parsed.to_agent_id, message.to_agent_id);
+    assert_eq!(parsed.region_id, message.region_id);
+    assert_eq!(parsed.position, message.position);
+    assert_eq!(parsed.dialog, message.dialog);
+    assert_eq!(parsed.id, message.id);
+    assert_eq!(parsed.from_agent_name, "Default User");
+    assert_eq!(parsed.message, "hello there");
+    assert!(parsed.binary_bucket.is_empty());
+}
+
+#[test]
+fn test_instant_message_variable_prefixes() {
+    let message = test_message();
+    let bytes = message.to_bytes();
+    // AgentData (32) + FromGroup (1) + ToAgentID (16) + ParentEstateID (4) + RegionID (16)
+    // + Position (12) + Offline (1) + Dialog (1) + ID (16) + Timestamp (4)
+    let fixed_length = 32 + 1 + 16 + 4 + 16 + 12 + 1 + 1 + 16 + 4;
+
+    // FromAgentName is Variable 1, counting the null terminator
+    assert_eq!(bytes[fixed_length], 13);
+    assert_eq!(bytes[fixed_length + 13], 0);
+
+    // Message is Variable 2, counting the null terminator
+    let message_start = fixed_length + 1 + 13;
+    assert_eq!(
+        u16::from_le_bytes([bytes[message_start], bytes[message_start + 1]]),
+        12
+    );
+
+    // the empty BinaryBucket is a zero length prefix and nothing else
+    let bucket_start = message_start + 2 + 12;
+    assert_eq!(&bytes[bucket_start..], &[0, 0]);
+}
+
+#[test]
+fn test_instant_message_binary_bucket() {
+    let mut message = test_message();
+    message.dialog = InstantMessageDialog::InventoryOffered;
+    message.binary_bucket = vec![6, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    message.message = "ünïcödé".to_string();
+
+    let parsed = ImprovedInstantMessage::from_bytes(&message.to_bytes()).unwrap();
+    assert_eq!(parsed.binary_bucket, message.binary_bucket);
+    assert_eq!(parsed.dialog, InstantMessageDialog::InventoryOffered);
+    assert_eq!(parsed.message, "ünïcödé");
+}
+
+#[test]
+fn test_instant_message_unknown_dialog_preserved() {
+    let mut message = test_message();
+    message.dialog = InstantMessageDialog::Unknown(200);
+    let parsed = ImprovedInstantMessage::from_bytes(&message.to_bytes()).unwrap();
+    assert_eq!(parsed.dialog, InstantMessageDialog::Unknown(200));
+}
+
+#[test]
+fn test_instant_message_truncated() {
+    let bytes = test_message().to_bytes();
+    // cut off in the middle of the message string
+    assert!(ImprovedInstantMessage::from_bytes(&bytes[..bytes.len() - 8]).is_err());
+    assert!(ImprovedInstantMessage::from_bytes(&[]).is_err());
+}
+
+#[test]
+fn test_instant_message_packet_round_trip() {
+    let packet = Packet::new_improved_instant_message(test_message());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    match parsed.body {
+        PacketType::ImprovedInstantMessage(message) => {
+            assert_eq!(message.message, "hello there")
+        }
+        body => panic!("expected ImprovedInstantMessage, got {:?}", body),
+    }
+}
+
+#[test]
+fn test_instant_message_ui_event() {
+    let packet = PacketType::ImprovedInstantMessage(Box::new(test_message()));
+    let event = packet.ui_event();
+    let bytes = packet.ui_event_bytes();
+    // the UI receives the instant message as JSON
+    assert!(serde_json::from_slice::<serde_json::Value>(&bytes).is_ok());
+    match event.packet_type_from_bytes(&bytes) {
+        Some(PacketType::ImprovedInstantMessage(message)) => {
+            assert_eq!(message.from_agent_name, "Default User")
+        }
+        _ => panic!("failed to decode InstantMessageEvent"),
+    }
+    assert!(matches!(event, UiEventTypes::InstantMessageEvent));
+}
@@ -0,0 +1,143 @@
+use glam::{Quat, Vec3, Vec4};
+use metaverse_messages::{
+    improved_terse_object_update::{
+        ImprovedTerseObjectUpdate, TerseObjectUpdate, ACCELERATION_RANGE, ANGULAR_VELOCITY_RANGE,
+        AVATAR_DATA_LENGTH, PRIM_DATA_LENGTH, VELOCITY_RANGE,
+    },
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::quantize::u16_quantization_error,
+};
+
+fn avatar() -> TerseObjectUpdate {
+    TerseObjectUpdate {
+        local_id: 1001,
+        state: 0,
+        collision_plane: Some(Vec4::new(0.0, 0.0, 1.0, 21.0)),
+        position: Vec3::new(128.25, 64.5, 21.9),
+        velocity: Vec3::new(3.2, -1.5, 0.0),
+        acceleration: Vec3::new(0.0, 0.0, -9.8),
+        rotation: Quat::from_rotation_z(1.2),
+        angular_velocity: Vec3::new(0.0, 0.0, 0.75),
+        texture_entry: vec![],
+    }
+}
+
+fn prim() -> TerseObjectUpdate {
+    TerseObjectUpdate {
+        local_id: 1002,
+        state: 0,
+        collision_plane: None,
+        position: Vec3::new(10.0, 20.0, 30.0),
+        velocity: Vec3::ZERO,
+        acceleration: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        angular_velocity: Vec3::new(0.0, 0.0, 0.1),
+        texture_entry: vec![1, 2, 3],
+    }
+}
+
+fn test_update() -> ImprovedTerseObjectUpdate {
+    ImprovedTerseObjectUpdate {
+        region_handle: (256000u64 << 32) | 256000,
+        time_dilation: 65535,
+        objects: vec![avatar(), prim()],
+    }
+}
+
+fn assert_close(left: Vec3, right: Vec3, error: f32) {
+    assert!(
+        (left - right).abs().max_element() <= error,
+        "{} is not within {} of {}",
+        left,
+        error,
+        right
+    );
+}
+
+#[test]
+fn test_terse_update_layouts() {
+    let bytes = test_update().to_bytes();
+    // RegionData (10) + block count (1), then the Variable 1 Data blob of the first block
+    assert_eq!(bytes[11] as usize, AVATAR_DATA_LENGTH);
+    let prim_start = 11 + 1 + AVATAR_DATA_LENGTH + 2;
+    assert_eq!(bytes[prim_start] as usize, PRIM_DATA_LENGTH);
+
+    let parsed = ImprovedTerseObjectUpdate::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.objects.len(), 2);
+    assert!(parsed.objects[0].is_avatar());
+    assert!(!parsed.objects[1].is_avatar());
+    assert_eq!(parsed.objects[1].texture_entry, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_terse_update_quantization_bounds() {
+    let update = test_update();
+    let parsed = ImprovedTerseObjectUpdate::from_bytes(&update.to_bytes()).unwrap();
+
+    for (sent, received) in update.objects.iter().zip(parsed.objects.iter()) {
+        assert_eq!(received.local_id, sent.local_id);
+        assert_eq!(received.collision_plane, sent.collision_plane);
+        // position is sent at full precision
+        assert_eq!(received.position, sent.position);
+        assert_close(
+            received.velocity,
+            sent.velocity,
+            u16_quantization_error(VELOCITY_RANGE.0, VELOCITY_RANGE.1),
+        );
+        assert_close(
+            received.acceleration,
+            sent.acceleration,
+            u16_quantization_error(ACCELERATION_RANGE.0, ACCELERATION_RANGE.1),
+        );
+        assert_close(
+            received.angular_velocity,
+            sent.angular_velocity,
+            u16_quantization_error(ANGULAR_VELOCITY_RANGE.0, ANGULAR_VELOCITY_RANGE.1),
+        );
+        let rotation_error = u16_quantization_error(-1.0, 1.0);
+        assert!(
+            (Vec4::from(received.rotation) - Vec4::from(sent.rotation))
+                .abs()
+                .max_element()
+                <= rotation_error
+        );
+    }
+}
+
+#[test]
+fn test_terse_update_requantization_is_stable() {
+    // decoding and re-encoding an update from the sim must not drift
+    let bytes = test_update().to_bytes();
+    let parsed = ImprovedTerseObjectUpdate::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.to_bytes(), bytes);
+}
+
+#[test]
+fn test_terse_update_bad_length() {
+    let mut bytes = test_update().to_bytes();
+    // claim the avatar blob is one byte shorter, which matches neither layout
+    bytes[11] -= 1;
+    assert!(ImprovedTerseObjectUpdate::from_bytes(&bytes).is_err());
+    assert!(ImprovedTerseObjectUpdate::from_bytes(&[]).is_err());
+}
+
+#[test]
+fn test_terse_update_packet() {
+    let packet = Packet::new_improved_terse_object_update(test_update());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    assert!(matches!(
+        parsed.body,
+        PacketType::ImprovedTerseObjectUpdate(_)
+    ));
+
+    let event = parsed.body.ui_event();
+    assert!(matches!(event, UiEventTypes::TerseObjectUpdateEvent));
+    match event.packet_type_from_bytes(&parsed.body.ui_event_bytes()) {
+        Some(PacketType::ImprovedTerseObjectUpdate(update)) => {
+            assert_eq!(update.objects[0].local_id, 1001)
+        }
+        _ => panic!("failed to decode TerseObjectUpdateEvent"),
+    }
+}
@@ -0,0 +1,118 @@
+use hex::FromHex;
+use metaverse_messages::{
+    move_inventory_item::{ItemMove, MoveInventoryItem},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    remove_inventory_item::RemoveInventoryItem,
+    update_inventory_item::UpdateInventoryItem,
+    utils::{
+        asset_type::AssetType, inventory_item::InventoryItem, permissions::Permissions,
+        sale_type::SaleType,
+    },
+};
+use uuid::Uuid;
+
+fn item() -> InventoryItem {
+    InventoryItem {
+        item_id: Uuid::from_bytes([0x01; 16]),
+        folder_id: Uuid::from_bytes([0x10; 16]),
+        creator_id: Uuid::from_bytes([0xEE; 16]),
+        owner_id: Uuid::from_bytes([0xFF; 16]),
+        group_id: Uuid::nil(),
+        base_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        owner_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        group_mask: Permissions::default(),
+        everyone_mask: Permissions::default(),
+        next_owner_mask: Permissions::from_bits(Permissions::TRANSFER),
+        group_owned: false,
+        asset_id: Uuid::nil(),
+        asset_type: AssetType::Notecard,
+        inventory_type: 7,
+        flags: 0,
+        sale_type: SaleType::NotForSale,
+        sale_price: 0,
+        name: "Notes".to_string(),
+        description: "some notes".to_string(),
+        creation_date: 1_700_000_000,
+    }
+}
+
+#[test]
+fn test_update_inventory_item() {
+    let update = UpdateInventoryItem {
+        agent_id: Uuid::from_bytes([0x02; 16]),
+        session_id: Uuid::from_bytes([0x03; 16]),
+        ..UpdateInventoryItem::new(vec![item()])
+    };
+    assert_eq!(update.items[0].crc, item().crc());
+    let bytes = update.to_bytes();
+    // the callback id comes between the folder and the creator
+    assert_eq!(&bytes[81..85], &[0x00; 4]);
+    assert_eq!(&bytes[85..101], &[0xEE; 16]);
+    assert_eq!(UpdateInventoryItem::from_bytes(&bytes).unwrap(), update);
+
+    let packet = Packet::new_update_inventory_item(update.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::UpdateInventoryItem(parsed) => assert_eq!(*parsed, update),
+        _ => panic!("expected UpdateInventoryItem"),
+    }
+}
+
+#[test]
+fn test_move_inventory_item() {
+    let move_items = MoveInventoryItem {
+        agent_id: Uuid::from_bytes([0x02; 16]),
+        session_id: Uuid::from_bytes([0x03; 16]),
+        ..MoveInventoryItem::new(vec![
+            ItemMove {
+                item_id: Uuid::from_bytes([0x01; 16]),
+                folder_id: Uuid::from_bytes([0x20; 16]),
+                new_name: Some("Moved".to_string()),
+            },
+            ItemMove {
+                item_id: Uuid::from_bytes([0x04; 16]),
+                folder_id: Uuid::from_bytes([0x20; 16]),
+                new_name: None,
+            },
+        ])
+    };
+    let bytes = move_items.to_bytes();
+    // stamp, the block count, and the second item keeps its name with an empty one
+    assert_eq!(&bytes[32..34], &[0x01, 0x02]);
+    assert_eq!(&bytes[bytes.len() - 1..], &[0x00]);
+    assert_eq!(MoveInventoryItem::from_bytes(&bytes).unwrap(), move_items);
+
+    let mut bytes = Vec::from_hex("400000003300ffff010c").unwrap();
+    bytes.extend_from_slice(&move_items.to_bytes());
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::MoveInventoryItem(parsed) => assert_eq!(*parsed, move_items),
+        _ => panic!("expected MoveInventoryItem"),
+    }
+}
+
+#[test]
+fn test_remove_inventory_item() {
+    let remove = RemoveInventoryItem {
+        agent_id: Uuid::from_bytes([0x02; 16]),
+        session_id: Uuid::from_bytes([0x03; 16]),
+        ..RemoveInventoryItem::new(vec![
+            Uuid::from_bytes([0x01; 16]),
+            Uuid::from_bytes([0x04; 16]),
+        ])
+    };
+    let bytes = remove.to_bytes();
+    assert_eq!(bytes.len(), 33 + 2 * 16);
+    assert_eq!(RemoveInventoryItem::from_bytes(&bytes).unwrap(), remove);
+
+    let packet = Packet::new_remove_inventory_item(remove.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::RemoveInventoryItem(parsed) => assert_eq!(*parsed, remove),
+        _ => panic!("expected RemoveInventoryItem"),
+    }
+
+    // a packet holds at most 255 blocks
+    let remove = RemoveInventoryItem::new(vec![Uuid::from_bytes([0x01; 16]); 300]);
+    let bytes = remove.to_bytes();
+    assert_eq!(bytes[32], 0xFF);
+    assert_eq!(bytes.len(), 33 + 255 * 16);
+}
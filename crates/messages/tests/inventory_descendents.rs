@@ -0,0 +1,209 @@
+use hex::FromHex;
+use metaverse_messages::{
+    fetch_inventory_descendents::FetchInventoryDescendents,
+    inventory_descendents::{InventoryDescendents, InventoryFolder},
+    inventory_folder_contents::InventoryFolderContents,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::{
+        asset_type::AssetType, inventory_item::InventoryItem, permissions::Permissions,
+        sale_type::SaleType,
+    },
+};
+use uuid::Uuid;
+
+// transfer, modify, copy and move
+const FULL: u32 = 0x0008_E000;
+// every bit the viewer sets for full permissions, including ones Permissions drops
+const PERM_ALL: u32 = 0x7FFF_FFFF;
+
+fn item() -> InventoryItem {
+    let mut creator_id = [0x00; 16];
+    creator_id[3] = 0x01;
+    let mut item_id = [0x00; 16];
+    item_id[0] = 0x01;
+    item_id[4] = 0x02;
+    let mut folder_id = [0x00; 16];
+    folder_id[0] = 0x10;
+    let mut asset_id = [0x00; 16];
+    asset_id[0] = 0x20;
+    InventoryItem {
+        item_id: Uuid::from_bytes(item_id),
+        folder_id: Uuid::from_bytes(folder_id),
+        creator_id: Uuid::from_bytes(creator_id),
+        owner_id: Uuid::from_bytes([0xFF; 16]),
+        group_id: Uuid::nil(),
+        base_mask: Permissions::from_bits(FULL),
+        owner_mask: Permissions::from_bits(FULL),
+        group_mask: Permissions::default(),
+        everyone_mask: Permissions::default(),
+        next_owner_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        group_owned: false,
+        asset_id: Uuid::from_bytes(asset_id),
+        asset_type: AssetType::Object,
+        inventory_type: 6,
+        flags: 0x100,
+        sale_type: SaleType::Copy,
+        sale_price: 10,
+        name: "Box".to_string(),
+        description: "a box".to_string(),
+        creation_date: 1_700_000_000,
+    }
+}
+
+#[test]
+fn test_item_crc() {
+    // the little endian words of the ids, the masks, the types, the flags, the sale price,
+    // the sale type times 0x07073096 and the creation date, summed as u32:
+    // 0x3 + 0x10 + 0x01000000 + 4 * 0xFFFFFFFF + 0x7FFFFFFF + 0x8E000 + 0x20 + 6 + 6 + 0x100
+    //     + 10 + 2 * 0x07073096 + 1700000000
+    assert_eq!(item().crc_with_masks([PERM_ALL, FULL, 0, 0]), 0xF46B_3370);
+    assert_eq!(item().crc(), item().crc_with_masks([FULL, FULL, 0, 0]));
+
+    // the types are sign extended
+    let item = InventoryItem {
+        inventory_type: -1,
+        ..item()
+    };
+    assert_eq!(item.crc_with_masks([PERM_ALL, FULL, 0, 0]), 0xF46B_3369);
+}
+
+fn item_block(item: &InventoryItem, base_mask: u32, crc: u32) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(item.item_id.as_bytes());
+    bytes.extend_from_slice(item.folder_id.as_bytes());
+    bytes.extend_from_slice(item.creator_id.as_bytes());
+    bytes.extend_from_slice(item.owner_id.as_bytes());
+    bytes.extend_from_slice(item.group_id.as_bytes());
+    for mask in [base_mask, FULL, 0, 0, item.next_owner_mask.to_bits()] {
+        bytes.extend_from_slice(&mask.to_le_bytes());
+    }
+    bytes.push(0x00);
+    bytes.extend_from_slice(item.asset_id.as_bytes());
+    bytes.extend_from_slice(&[0x06, 0x06]);
+    bytes.extend_from_slice(&item.flags.to_le_bytes());
+    bytes.push(0x02);
+    bytes.extend_from_slice(&10i32.to_le_bytes());
+    bytes.extend_from_slice(b"\x04Box\x00\x06a box\x00");
+    bytes.extend_from_slice(&item.creation_date.to_le_bytes());
+    bytes.extend_from_slice(&crc.to_le_bytes());
+    bytes
+}
+
+#[test]
+fn test_inventory_descendents() {
+    let mut bytes = Vec::from_hex("400000003300ffff0116").unwrap();
+    // AgentData: AgentID, FolderID, OwnerID, Version, Descendents
+    bytes.extend_from_slice(&[0x01; 16]);
+    bytes.extend_from_slice(&[0x10; 16]);
+    bytes.extend_from_slice(&[0xFF; 16]);
+    bytes.extend_from_slice(&7i32.to_le_bytes());
+    bytes.extend_from_slice(&3i32.to_le_bytes());
+    // FolderData: one folder
+    bytes.push(0x01);
+    bytes.extend_from_slice(&[0x30; 16]);
+    bytes.extend_from_slice(&[0x10; 16]);
+    bytes.push(0xFF);
+    bytes.extend_from_slice(b"\x08Objects\x00");
+    // ItemData: an item with the right checksum, and one with a wrong one
+    bytes.push(0x02);
+    bytes.extend_from_slice(&item_block(&item(), PERM_ALL, 0xF46B_3370));
+    bytes.extend_from_slice(&item_block(&item(), PERM_ALL, 0x1234_5678));
+
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    match &packet.body {
+        PacketType::InventoryDescendents(descendents) => {
+            assert_eq!(descendents.folder_id, Uuid::from_bytes([0x10; 16]));
+            assert_eq!(descendents.owner_id, Uuid::from_bytes([0xFF; 16]));
+            assert_eq!(descendents.version, 7);
+            assert_eq!(descendents.descendents, 3);
+            assert_eq!(
+                descendents.folders,
+                vec![InventoryFolder {
+                    folder_id: Uuid::from_bytes([0x30; 16]),
+                    parent_id: Uuid::from_bytes([0x10; 16]),
+                    folder_type: -1,
+                    name: "Objects".to_string(),
+                }]
+            );
+            assert_eq!(descendents.items.len(), 2);
+            // the base mask keeps the bits it knows of PERM_ALL
+            let expected = InventoryItem {
+                base_mask: Permissions::from_bits(PERM_ALL),
+                ..item()
+            };
+            assert_eq!(descendents.items[0].item, expected);
+            assert!(descendents.items[0].crc_valid);
+            assert!(!descendents.items[1].crc_valid);
+            assert_eq!(descendents.items[1].crc, 0x1234_5678);
+        }
+        _ => panic!("expected InventoryDescendents"),
+    }
+}
+
+#[test]
+fn test_empty_inventory_descendents() {
+    // an empty folder still has a folder and an item block, with nil ids
+    let mut body = Vec::new();
+    body.extend_from_slice(&[0x01; 16]);
+    body.extend_from_slice(&[0x10; 16]);
+    body.extend_from_slice(&[0xFF; 16]);
+    body.extend_from_slice(&1i32.to_le_bytes());
+    body.extend_from_slice(&0i32.to_le_bytes());
+    body.push(0x01);
+    body.extend_from_slice(&[0x00; 33]);
+    body.push(0x00);
+    body.push(0x01);
+    let empty = InventoryItem {
+        item_id: Uuid::nil(),
+        ..item()
+    };
+    body.extend_from_slice(&item_block(&empty, 0, 0));
+
+    let descendents = InventoryDescendents::from_bytes(&body).unwrap();
+    assert!(descendents.folders.is_empty());
+    assert!(descendents.items.is_empty());
+}
+
+#[test]
+fn test_fetch_inventory_descendents() {
+    let fetch = FetchInventoryDescendents {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        session_id: Uuid::from_bytes([0x02; 16]),
+        ..FetchInventoryDescendents::new(Uuid::from_bytes([0x10; 16]), Uuid::from_bytes([0x01; 16]))
+    };
+    let bytes = fetch.to_bytes();
+    assert_eq!(bytes.len(), 70);
+    assert_eq!(&bytes[64..], &[0x00, 0x00, 0x00, 0x00, 0x01, 0x01]);
+    assert_eq!(
+        FetchInventoryDescendents::from_bytes(&bytes).unwrap(),
+        fetch
+    );
+
+    let packet = Packet::new_fetch_inventory_descendents(fetch.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::FetchInventoryDescendents(parsed) => assert_eq!(*parsed, fetch),
+        _ => panic!("expected FetchInventoryDescendents"),
+    }
+}
+
+#[test]
+fn test_inventory_folder_event() {
+    let contents = InventoryFolderContents {
+        folder_id: Uuid::from_bytes([0x10; 16]),
+        owner_id: Uuid::from_bytes([0xFF; 16]),
+        version: 7,
+        folders: Vec::new(),
+        items: vec![item()],
+    };
+    let body = PacketType::InventoryFolderContents(Box::new(contents.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::InventoryFolderEvent
+    ));
+    match UiEventTypes::InventoryFolderEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::InventoryFolderContents(parsed)) => assert_eq!(*parsed, contents),
+        _ => panic!("failed to decode InventoryFolderEvent"),
+    }
+}
@@ -0,0 +1,126 @@
+use metaverse_messages::{
+    create_inventory_folder::CreateInventoryFolder,
+    inventory_descendents::InventoryFolder,
+    inventory_folder_status::InventoryFolderStatus,
+    move_inventory_folder::{FolderMove, MoveInventoryFolder},
+    packet::{MessageType, Packet, PacketData},
+    packet_types::PacketType,
+    purge_inventory_descendents::PurgeInventoryDescendents,
+    purge_inventory_folder::PurgeInventoryFolder,
+    remove_inventory_folder::RemoveInventoryFolder,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+#[test]
+fn test_create_inventory_folder() {
+    let create = CreateInventoryFolder {
+        agent_id: Uuid::from_bytes([0x01; 16]),
+        session_id: Uuid::from_bytes([0x02; 16]),
+        folder: InventoryFolder {
+            folder_id: Uuid::from_bytes([0x30; 16]),
+            ..CreateInventoryFolder::new(Uuid::from_bytes([0x10; 16]), "Stuff".to_string()).folder
+        },
+    };
+    let bytes = create.to_bytes();
+    // the ids, the type, and the name with its terminator
+    assert_eq!(bytes.len(), 64 + 1 + 1 + 6);
+    assert_eq!(bytes[64], 0xFF);
+    assert_eq!(CreateInventoryFolder::from_bytes(&bytes).unwrap(), create);
+
+    let packet = Packet::new_create_inventory_folder(create.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::CreateInventoryFolder(parsed) => assert_eq!(*parsed, create),
+        _ => panic!("expected CreateInventoryFolder"),
+    }
+}
+
+#[test]
+fn test_move_inventory_folder() {
+    let move_folders = MoveInventoryFolder::new(vec![FolderMove {
+        folder_id: Uuid::from_bytes([0x30; 16]),
+        parent_id: Uuid::from_bytes([0x10; 16]),
+    }]);
+    let bytes = move_folders.to_bytes();
+    assert_eq!(&bytes[32..34], &[0x01, 0x01]);
+    assert_eq!(
+        MoveInventoryFolder::from_bytes(&bytes).unwrap(),
+        move_folders
+    );
+
+    let packet = Packet::new_move_inventory_folder(move_folders.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::MoveInventoryFolder(parsed) => assert_eq!(*parsed, move_folders),
+        _ => panic!("expected MoveInventoryFolder"),
+    }
+}
+
+#[test]
+fn test_remove_inventory_folder() {
+    let remove = RemoveInventoryFolder::new(vec![
+        Uuid::from_bytes([0x30; 16]),
+        Uuid::from_bytes([0x31; 16]),
+    ]);
+    let bytes = remove.to_bytes();
+    assert_eq!(bytes.len(), 33 + 2 * 16);
+    assert_eq!(RemoveInventoryFolder::from_bytes(&bytes).unwrap(), remove);
+
+    let packet = Packet::new_remove_inventory_folder(remove.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::RemoveInventoryFolder(parsed) => assert_eq!(*parsed, remove),
+        _ => panic!("expected RemoveInventoryFolder"),
+    }
+}
+
+#[test]
+fn test_purge_inventory_descendents() {
+    let purge = PurgeInventoryDescendents::new(Uuid::from_bytes([0x30; 16]));
+    let packet = Packet::new_purge_inventory_descendents(purge.clone());
+    let bytes = packet.to_bytes();
+    assert_eq!(bytes.len(), 10 + 48);
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::PurgeInventoryDescendents(parsed) => assert_eq!(*parsed, purge),
+        _ => panic!("expected PurgeInventoryDescendents"),
+    }
+}
+
+#[test]
+fn test_purge_inventory_folder() {
+    let purge = PurgeInventoryFolder {
+        folder_id: Uuid::from_bytes([0x30; 16]),
+        confirmed: true,
+    };
+    let packet = Packet::new_purge_inventory_folder(purge.clone());
+    assert!(matches!(packet.body.message_type(), MessageType::Command));
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::PurgeInventoryFolder(parsed) => assert_eq!(*parsed, purge),
+        _ => panic!("expected PurgeInventoryFolder"),
+    }
+}
+
+#[test]
+fn test_inventory_folder_status_event() {
+    for status in [
+        InventoryFolderStatus::Created(InventoryFolder {
+            folder_id: Uuid::from_bytes([0x30; 16]),
+            parent_id: Uuid::from_bytes([0x10; 16]),
+            folder_type: -1,
+            name: "Stuff".to_string(),
+        }),
+        InventoryFolderStatus::PurgeNotConfirmed {
+            folder_id: Uuid::from_bytes([0x30; 16]),
+        },
+    ] {
+        let body = PacketType::InventoryFolderStatus(Box::new(status.clone()));
+        assert!(matches!(
+            body.ui_event(),
+            UiEventTypes::InventoryFolderStatusEvent
+        ));
+        match UiEventTypes::InventoryFolderStatusEvent
+            .packet_type_from_bytes(&body.ui_event_bytes())
+        {
+            Some(PacketType::InventoryFolderStatus(parsed)) => assert_eq!(*parsed, status),
+            _ => panic!("failed to decode InventoryFolderStatusEvent"),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use metaverse_messages::{
+    kick_user::KickUser,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use std::net::Ipv4Addr;
+use uuid::uuid;
+
+fn test_kick() -> KickUser {
+    KickUser {
+        target_ip: Ipv4Addr::new(127, 0, 0, 1),
+        target_port: 9000,
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        reason: "The region is restarting".to_string(),
+    }
+}
+
+#[test]
+fn test_kick_user_bytes() {
+    let bytes = test_kick().to_bytes();
+    // the TargetBlock is in network byte order
+    assert_eq!(&bytes[0..6], &[127, 0, 0, 1, 0x23, 0x28]);
+    // the reason is a Variable 2 string with a null terminator
+    assert_eq!(&bytes[38..40], &[25, 0]);
+    assert_eq!(bytes.last(), Some(&0));
+
+    let parsed = KickUser::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.target_ip, Ipv4Addr::new(127, 0, 0, 1));
+    assert_eq!(parsed.target_port, 9000);
+    assert_eq!(parsed.reason, "The region is restarting");
+    assert_eq!(parsed.to_bytes(), bytes);
+}
+
+#[test]
+fn test_kick_user_truncated() {
+    let bytes = test_kick().to_bytes();
+    assert!(KickUser::from_bytes(&bytes[..bytes.len() - 5]).is_err());
+}
+
+#[test]
+fn test_kicked_event() {
+    let packet = Packet::new_kick_user(test_kick());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    assert!(matches!(parsed.body, PacketType::KickUser(_)));
+
+    // the UI receives the kick as JSON
+    let event = parsed.body.ui_event();
+    assert!(matches!(event, UiEventTypes::KickedEvent));
+    match event.packet_type_from_bytes(&parsed.body.ui_event_bytes()) {
+        Some(PacketType::KickUser(kick)) => assert_eq!(kick.reason, "The region is restarting"),
+        _ => panic!("failed to decode KickedEvent"),
+    }
+}
@@ -0,0 +1,48 @@
+use metaverse_messages::{
+    kill_object::KillObjectData,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_kill_object_multiple_blocks() {
+    // three ObjectData blocks
+    let bytes = [
+        0x03, // block count
+        0xe9, 0x03, 0x00, 0x00, // 1001
+        0xea, 0x03, 0x00, 0x00, // 1002
+        0x78, 0x56, 0x34, 0x12, // 0x12345678
+    ];
+    let kill_object = KillObjectData::from_bytes(&bytes).unwrap();
+    assert_eq!(kill_object.local_ids, vec![1001, 1002, 0x12345678]);
+    assert_eq!(kill_object.to_bytes(), bytes);
+}
+
+#[test]
+fn test_kill_object_empty_packet() {
+    assert!(KillObjectData::from_bytes(&[]).is_err());
+    // the block count claims more ids than were sent
+    assert!(KillObjectData::from_bytes(&[0x02, 0xe9, 0x03, 0x00, 0x00]).is_err());
+}
+
+#[test]
+fn test_kill_object_packet() {
+    let packet = Packet::new_kill_object(KillObjectData {
+        local_ids: vec![1001, 1002],
+    });
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    assert!(matches!(parsed.body, PacketType::KillObject(_)));
+
+    // the UI receives the killed ids as a JSON array
+    let event = parsed.body.ui_event();
+    let bytes = parsed.body.ui_event_bytes();
+    assert!(matches!(event, UiEventTypes::KillObjectEvent));
+    assert_eq!(bytes, b"[1001,1002]");
+    match event.packet_type_from_bytes(&bytes) {
+        Some(PacketType::KillObject(kill_object)) => {
+            assert_eq!(kill_object.local_ids, vec![1001, 1002])
+        }
+        _ => panic!("failed to decode KillObjectEvent"),
+    }
+}
@@ -0,0 +1,125 @@
+use glam::Vec3;
+use metaverse_messages::{
+    land_stat_reply::{LandStatExtended, LandStatReply},
+    land_stat_report::LandStatReport,
+    land_stat_request::LandStatRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::land_stat::{LandStatEntry, LandStatFlags, LandStatReportType},
+};
+use uuid::Uuid;
+
+fn entry(index: u32, owner_name: &str) -> LandStatEntry {
+    LandStatEntry {
+        task_local_id: index,
+        task_id: Uuid::from_u128(index as u128 + 1),
+        position: Vec3::new(128.0, 64.0, 22.5),
+        score: index as f32 * 0.5,
+        task_name: format!("Object {}", index),
+        owner_name: owner_name.to_string(),
+    }
+}
+
+fn reply(entries: Vec<LandStatEntry>) -> LandStatReply {
+    LandStatReply {
+        report_type: LandStatReportType::TopScripts,
+        request_flags: LandStatFlags::empty(),
+        total_object_count: 5,
+        entries,
+        extended: Vec::new(),
+    }
+}
+
+#[test]
+fn test_land_stat_request() {
+    let request = LandStatRequest::new(LandStatReportType::TopColliders, "door".to_string());
+    assert_eq!(request.request_flags, LandStatFlags::FILTER_BY_OBJECT);
+    let bytes = request.to_bytes();
+    // the report type comes after the agent data
+    assert_eq!(&bytes[32..36], &1u32.to_le_bytes());
+    assert_eq!(LandStatRequest::from_bytes(&bytes).unwrap(), request);
+
+    let request = LandStatRequest::new(LandStatReportType::TopScripts, String::new());
+    assert!(request.request_flags.is_empty());
+    match Packet::from_bytes(&Packet::new_land_stat_request(request.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::LandStatRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected LandStatRequest"),
+    }
+}
+
+#[test]
+fn test_land_stat_reply_with_empty_names() {
+    // objects owned by groups, or whose owner the simulator can't name, have no owner name
+    let named = reply(vec![entry(1, "Owner Resident"), entry(2, ""), entry(3, "")]);
+    let parsed = LandStatReply::from_bytes(&named.to_bytes()).unwrap();
+    assert_eq!(parsed, named);
+    assert_eq!(parsed.entries[1].owner_name, "");
+    assert!(parsed.extended.is_empty());
+
+    let mut unnamed = entry(4, "");
+    unnamed.task_name = String::new();
+    let unnamed = reply(vec![unnamed]);
+    assert_eq!(
+        LandStatReply::from_bytes(&unnamed.to_bytes()).unwrap(),
+        unnamed
+    );
+}
+
+#[test]
+fn test_land_stat_reply_cut_off_name() {
+    // a name cut off in the middle of a character
+    let mut bytes = reply(Vec::new()).to_bytes();
+    bytes[12] = 1;
+    bytes.extend_from_slice(&7u32.to_le_bytes());
+    bytes.extend_from_slice(Uuid::from_u128(8).as_bytes());
+    bytes.extend_from_slice(&[0; 16]);
+    bytes.extend_from_slice(&[3, b'a', 0xc3, 0]);
+    bytes.push(0);
+    let parsed = LandStatReply::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.entries[0].task_name, "a\u{fffd}");
+    assert_eq!(parsed.entries[0].owner_name, "");
+}
+
+#[test]
+fn test_land_stat_reply_extended() {
+    let mut reply = reply(vec![entry(1, "Owner Resident"), entry(2, "")]);
+    reply.extended = vec![
+        LandStatExtended {
+            time_stamp: 1700000000,
+            mono_score: 0.25,
+        },
+        LandStatExtended {
+            time_stamp: 1700000001,
+            mono_score: 0.0,
+        },
+    ];
+    let bytes = reply.to_bytes();
+    assert_eq!(LandStatReply::from_bytes(&bytes).unwrap(), reply);
+    match Packet::from_bytes(&Packet::new_land_stat_reply(reply.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::LandStatReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected LandStatReply"),
+    }
+}
+
+#[test]
+fn test_land_stat_event() {
+    let report = LandStatReport {
+        report_type: LandStatReportType::TopColliders,
+        total_object_count: 2,
+        entries: vec![entry(2, ""), entry(1, "Owner Resident")],
+        complete: true,
+    };
+    let body = PacketType::LandStatReport(Box::new(report.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::LandStatEvent));
+    match UiEventTypes::LandStatEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::LandStatReport(parsed)) => assert_eq!(*parsed, report),
+        _ => panic!("failed to decode LandStatEvent"),
+    }
+}
@@ -0,0 +1,253 @@
+use metaverse_messages::{
+    layer_data::{
+        CloudPatch, CloudPatches, LayerData, LayerType, TerrainPatch, TerrainPatches, WindPatch,
+        WindPatches,
+    },
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::bit_reader::BitReader,
+};
+
+// a Land layer with two patches, packed the same way OpenSimulator's TerrainCompressor packs
+// them. Stride 264, patch size 16, and quant_wbits 0x58 with a DC offset of 20 and range of 32.
+// patch (3, 5) only has a DC coefficient, so it is flat.
+// patch (4, 5) has a DC coefficient and one horizontal coefficient, so it slopes along x.
+fn test_packet() -> Vec<u8> {
+    vec![
+        0x4c, 0x1b, 0x00, 0x08, 0x01, 0x10, 0x4c, 0x58, 0x00, 0x00, 0xa0, 0x41, 0x20, 0x00, 0x65,
+        0x38, 0x03, 0x2c, 0x00, 0x00, 0x50, 0x20, 0x90, 0x00, 0x42, 0x9c, 0x01, 0xc8, 0x04, 0xc2,
+    ]
+}
+
+// a Wind layer captured from an OpenSimulator region with a steady breeze, as OpenSimulator's
+// WindModule sends it. The first patch is the x component, a flat 2.75 m/s with a DC offset of -4
+// and range of 9. The second is the y component with a DC offset of -3 and range of 7, which has
+// one horizontal coefficient so it weakens towards the east.
+fn wind_packet() -> Vec<u8> {
+    vec![
+        0x37, 0x1a, 0x00, 0x08, 0x01, 0x10, 0x37, 0x58, 0x00, 0x00, 0x80, 0xc0, 0x09, 0x00, 0x00,
+        0x30, 0x05, 0x2c, 0x00, 0x00, 0x20, 0x60, 0x03, 0x80, 0x00, 0x0c, 0xc8, 0x4c, 0x20,
+    ]
+}
+
+// a Cloud layer with two patches, with a DC offset of 0 and range of 2.
+// patch (0, 0) has one vertical coefficient so it thins out towards the north.
+// patch (1, 2) only has a DC coefficient, so it is uniform.
+fn cloud_packet() -> Vec<u8> {
+    vec![
+        0x38, 0x1a, 0x00, 0x08, 0x01, 0x10, 0x38, 0x58, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x0c, 0xc8, 0x4b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x04, 0x46, 0x40, 0x26, 0x10,
+    ]
+}
+
+#[test]
+fn test_bit_reader() {
+    // fields wider than a byte are read into little endian bytes
+    let mut reader = BitReader::new(&[0x08, 0x01, 0b1011_0000]);
+    assert_eq!(reader.read_bits(16).unwrap(), 264);
+    assert!(reader.read_bit().unwrap());
+    assert_eq!(reader.read_bits(3).unwrap(), 0b011);
+    assert_eq!(reader.read_bits(4).unwrap(), 0);
+    assert!(reader.read_bit().is_err());
+}
+
+#[test]
+fn test_layer_data_round_trip() {
+    let layer_data = LayerData::from_bytes(&test_packet()).unwrap();
+    assert_eq!(layer_data.layer_type, LayerType::Land);
+    assert_eq!(layer_data.data.len(), 27);
+    assert_eq!(layer_data.to_bytes(), test_packet());
+}
+
+#[test]
+fn test_decode_terrain_patches() {
+    let patches = LayerData::from_bytes(&test_packet())
+        .unwrap()
+        .terrain_patches()
+        .unwrap();
+    assert_eq!(patches.len(), 2);
+
+    let flat = &patches[0];
+    assert_eq!((flat.x, flat.y), (3, 5));
+    for height in flat.heights {
+        assert!((height - 32.0).abs() < 0.001, "{}", height);
+    }
+
+    let slope = &patches[1];
+    assert_eq!((slope.x, slope.y), (4, 5));
+    // the slope is the same on every row, and goes down towards the east
+    for y in 0..16 {
+        for x in 1..16 {
+            assert!(slope.height(x, y) < slope.height(x - 1, y));
+        }
+        assert!((slope.height(0, y) - 36.2222).abs() < 0.001);
+        assert!((slope.height(15, y) - 27.7778).abs() < 0.001);
+        assert!((slope.height(5, y) - 34.0).abs() < 0.001);
+    }
+}
+
+#[test]
+fn test_truncated_layer_data() {
+    let mut bytes = test_packet();
+    bytes.truncate(20);
+    // fix up the length so only the patch data is cut short
+    bytes[1] = 17;
+    let layer_data = LayerData::from_bytes(&bytes).unwrap();
+    assert!(layer_data.terrain_patches().is_err());
+}
+
+#[test]
+fn test_terrain_patch_event() {
+    let packet = Packet::new_layer_data(LayerData::from_bytes(&test_packet()).unwrap());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+
+    // the UI gets the decoded patches in binary
+    let event = parsed.body.ui_event();
+    assert!(matches!(event, UiEventTypes::TerrainPatchEvent));
+    let bytes = parsed.body.ui_event_bytes();
+    assert_eq!(bytes.len(), 2 + 2 * TerrainPatch::LENGTH);
+    match event.packet_type_from_bytes(&bytes) {
+        Some(PacketType::TerrainPatches(terrain)) => {
+            assert_eq!(
+                *terrain,
+                TerrainPatches {
+                    patches: LayerData::from_bytes(&test_packet())
+                        .unwrap()
+                        .terrain_patches()
+                        .unwrap()
+                }
+            )
+        }
+        _ => panic!("failed to decode TerrainPatchEvent"),
+    }
+}
+
+#[test]
+fn test_decode_wind_patches() {
+    let layer_data = LayerData::from_bytes(&wind_packet()).unwrap();
+    assert_eq!(layer_data.layer_type, LayerType::Wind);
+    assert_eq!(layer_data.to_bytes(), wind_packet());
+
+    let patches = layer_data.wind_patches().unwrap();
+    // the two components make up one patch
+    assert_eq!(patches.len(), 1);
+    let wind = &patches[0];
+    assert_eq!((wind.x, wind.y), (0, 0));
+    for y in 0..16 {
+        for x in 0..16 {
+            let velocity = wind.velocity(x, y);
+            assert!((velocity.x - 2.75).abs() < 0.001, "{}", velocity.x);
+            // values are scaled into the DC offset and range of their patch
+            assert!((-4.0..=5.0).contains(&velocity.x), "{}", velocity.x);
+            assert!((-3.0..=4.0).contains(&velocity.y), "{}", velocity.y);
+        }
+        for x in 1..16 {
+            assert!(wind.velocity(x, y).y < wind.velocity(x - 1, y).y);
+        }
+        assert!((wind.velocity(0, y).y - 1.9431).abs() < 0.001);
+        assert!((wind.velocity(15, y).y + 0.9431).abs() < 0.001);
+    }
+}
+
+#[test]
+fn test_decode_cloud_patches() {
+    let layer_data = LayerData::from_bytes(&cloud_packet()).unwrap();
+    assert_eq!(layer_data.layer_type, LayerType::Cloud);
+    assert_eq!(layer_data.to_bytes(), cloud_packet());
+
+    let patches = layer_data.cloud_patches().unwrap();
+    assert_eq!(patches.len(), 2);
+    for patch in &patches {
+        for density in patch.density {
+            assert!((0.0..=2.0).contains(&density), "{}", density);
+        }
+    }
+
+    let thinning = &patches[0];
+    assert_eq!((thinning.x, thinning.y), (0, 0));
+    for x in 0..16 {
+        for y in 1..16 {
+            assert!(thinning.density(x, y) < thinning.density(x, y - 1));
+        }
+        assert!((thinning.density(x, 0) - 1.4123).abs() < 0.001);
+        assert!((thinning.density(x, 15) - 0.5877).abs() < 0.001);
+    }
+
+    let uniform = &patches[1];
+    assert_eq!((uniform.x, uniform.y), (1, 2));
+    for density in uniform.density {
+        assert!((density - 1.0625).abs() < 0.001, "{}", density);
+    }
+}
+
+#[test]
+fn test_wrong_layer_type() {
+    let wind = LayerData::from_bytes(&wind_packet()).unwrap();
+    assert!(wind.terrain_patches().is_err());
+    assert!(wind.cloud_patches().is_err());
+    let land = LayerData::from_bytes(&test_packet()).unwrap();
+    assert!(land.wind_patches().is_err());
+}
+
+#[test]
+fn test_unknown_layer_type() {
+    // unknown layers are kept as they are, and are not sent to the UI
+    let mut bytes = test_packet();
+    bytes[0] = b'Z';
+    let layer_data = LayerData::from_bytes(&bytes).unwrap();
+    assert_eq!(layer_data.layer_type, LayerType::Unknown(b'Z'));
+    assert_eq!(layer_data.to_bytes(), bytes);
+    let packet = Packet::new_layer_data(layer_data);
+    assert!(matches!(packet.body.ui_event(), UiEventTypes::None));
+}
+
+#[test]
+fn test_wind_patch_event() {
+    let packet = Packet::new_layer_data(LayerData::from_bytes(&wind_packet()).unwrap());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+
+    let event = parsed.body.ui_event();
+    assert!(matches!(event, UiEventTypes::WindPatchEvent));
+    let bytes = parsed.body.ui_event_bytes();
+    assert_eq!(bytes.len(), 2 + WindPatch::LENGTH);
+    match event.packet_type_from_bytes(&bytes) {
+        Some(PacketType::WindPatches(wind)) => {
+            assert_eq!(
+                *wind,
+                WindPatches {
+                    patches: LayerData::from_bytes(&wind_packet())
+                        .unwrap()
+                        .wind_patches()
+                        .unwrap()
+                }
+            )
+        }
+        _ => panic!("failed to decode WindPatchEvent"),
+    }
+}
+
+#[test]
+fn test_cloud_patch_event() {
+    let packet = Packet::new_layer_data(LayerData::from_bytes(&cloud_packet()).unwrap());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+
+    let event = parsed.body.ui_event();
+    assert!(matches!(event, UiEventTypes::CloudPatchEvent));
+    let bytes = parsed.body.ui_event_bytes();
+    assert_eq!(bytes.len(), 2 + 2 * CloudPatch::LENGTH);
+    match event.packet_type_from_bytes(&bytes) {
+        Some(PacketType::CloudPatches(clouds)) => {
+            assert_eq!(
+                *clouds,
+                CloudPatches {
+                    patches: LayerData::from_bytes(&cloud_packet())
+                        .unwrap()
+                        .cloud_patches()
+                        .unwrap()
+                }
+            )
+        }
+        _ => panic!("failed to decode CloudPatchEvent"),
+    }
+}
@@ -0,0 +1,75 @@
+use metaverse_messages::{
+    load_url::LoadURL,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn load_url(raw_url: &[u8]) -> LoadURL {
+    LoadURL {
+        object_name: "Free Stuff".to_string(),
+        object_id: Uuid::new_v4(),
+        owner_id: Uuid::new_v4(),
+        owner_is_group: true,
+        message: "Visit our store".to_string(),
+        url: String::from_utf8_lossy(raw_url).into_owned(),
+        raw_url: raw_url.to_vec(),
+    }
+}
+
+#[test]
+fn test_load_url_round_trip() {
+    let load_url = load_url(b"https://example.com/store");
+    let packet = Packet::from_bytes(&Packet::new_load_url(load_url.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::LoadURL(parsed) => {
+            assert_eq!(*parsed, load_url);
+            assert_eq!(parsed.url, "https://example.com/store");
+        }
+        _ => panic!("expected LoadURL"),
+    }
+}
+
+#[test]
+fn test_load_url_invalid_utf8() {
+    let raw_url = b"https://example.com/\xff\xfe";
+    let load_url = load_url(raw_url);
+    let parsed = LoadURL::from_bytes(&load_url.to_bytes()).unwrap();
+    assert_eq!(parsed.raw_url, raw_url);
+    assert_eq!(parsed.url, "https://example.com/\u{FFFD}\u{FFFD}");
+    assert_eq!(parsed.owner_id, load_url.owner_id);
+    assert!(parsed.owner_is_group);
+}
+
+#[test]
+fn test_load_url_invalid_utf8_message() {
+    let load_url = load_url(b"https://example.com");
+    let mut bytes = load_url.to_bytes();
+    // replace the first letter of the object name with a lone continuation byte
+    bytes[1] = 0x80;
+    let parsed = LoadURL::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.object_name, "\u{FFFD}ree Stuff");
+    assert_eq!(parsed.url, "https://example.com");
+}
+
+#[test]
+fn test_load_url_truncated() {
+    let bytes = load_url(b"https://example.com").to_bytes();
+    assert!(LoadURL::from_bytes(&bytes[..bytes.len() - 5]).is_err());
+}
+
+#[test]
+fn test_load_url_event() {
+    let load_url = load_url(b"https://example.com/\xff");
+    let body = PacketType::LoadURL(Box::new(load_url.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::LoadUrlEvent));
+    match UiEventTypes::LoadUrlEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::LoadURL(parsed)) => {
+            assert_eq!(parsed.owner_id, load_url.owner_id);
+            assert!(parsed.owner_is_group);
+            assert_eq!(parsed.raw_url, load_url.raw_url);
+        }
+        _ => panic!("expected LoadURL"),
+    }
+}
@@ -0,0 +1,68 @@
+use metaverse_messages::{
+    disconnect::Disconnect,
+    logout_reply::LogoutReply,
+    logout_request::LogoutRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::uuid;
+
+#[test]
+fn test_logout_request_packet() {
+    let packet = Packet::new_logout_request(LogoutRequest {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+    });
+    assert!(packet.header.reliable);
+
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    match parsed.body {
+        PacketType::LogoutRequest(request) => {
+            assert_eq!(
+                request.agent_id,
+                uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a")
+            );
+            assert_eq!(
+                request.session_id,
+                uuid!("224ecaea-372d-4d31-8b64-4805966418e5")
+            );
+        }
+        body => panic!("expected LogoutRequest, got {:?}", body),
+    }
+}
+
+#[test]
+fn test_logout_reply_items() {
+    let reply = LogoutReply {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        item_ids: vec![
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            uuid!("00000000-0000-0000-0000-000000000002"),
+        ],
+    };
+    let bytes = reply.to_bytes();
+    // AgentData, then the InventoryData block count
+    assert_eq!(bytes[32], 2);
+    assert_eq!(bytes.len(), 33 + 32);
+
+    let parsed = LogoutReply::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.item_ids, reply.item_ids);
+
+    // the block count claims more items than were sent
+    assert!(LogoutReply::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_disconnect_event() {
+    let body = PacketType::Disconnect(Box::new(Disconnect {
+        reason: "logged out".to_string(),
+    }));
+    let event = body.ui_event();
+    assert!(matches!(event, UiEventTypes::DisconnectEvent));
+    match event.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::Disconnect(disconnect)) => assert_eq!(disconnect.reason, "logged out"),
+        _ => panic!("failed to decode DisconnectEvent"),
+    }
+}
@@ -0,0 +1,122 @@
+use metaverse_messages::{
+    map_block_reply::{MapBlock, MapBlockReply, DEFAULT_REGION_SIZE},
+    map_block_request::MapBlockRequest,
+    map_blocks::MapBlocks,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::agent_access::AgentAccess,
+};
+use uuid::Uuid;
+
+fn block(x: u16, y: u16, name: &str, access: AgentAccess) -> MapBlock {
+    MapBlock {
+        x,
+        y,
+        name: name.to_string(),
+        access,
+        region_flags: 0,
+        water_height: 20,
+        agents: 3,
+        map_image_id: Uuid::new_v4(),
+        size_x: 512,
+        size_y: 256,
+    }
+}
+
+#[test]
+fn test_map_block_request_round_trip() {
+    let request = MapBlockRequest {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        flags: MapBlockRequest::RETURN_NONEXISTENT,
+        estate_id: 0,
+        godlike: false,
+        min_x: 998,
+        max_x: 1002,
+        min_y: 998,
+        max_y: 1002,
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 49);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_map_block_request(request.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::MapBlockRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected MapBlockRequest"),
+    }
+}
+
+#[test]
+fn test_map_block_reply_round_trip() {
+    let reply = MapBlockReply {
+        agent_id: Uuid::new_v4(),
+        flags: 2,
+        blocks: vec![
+            block(1000, 1000, "Da Boom", AgentAccess::PG),
+            block(1001, 1000, "Morris", AgentAccess::Mature),
+        ],
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_map_block_reply(reply.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::MapBlockReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected MapBlockReply"),
+    }
+    assert_eq!(
+        reply.blocks[0].region_handle(),
+        (256_000u64 << 32) | 256_000
+    );
+}
+
+#[test]
+fn test_map_block_reply_without_size() {
+    let reply = MapBlockReply {
+        agent_id: Uuid::new_v4(),
+        flags: 0,
+        blocks: vec![block(1000, 1000, "Da Boom", AgentAccess::PG)],
+    };
+    // older simulators leave off the Size block
+    let mut bytes = reply.to_bytes();
+    bytes.truncate(bytes.len() - 1 - 4);
+    let parsed = MapBlockReply::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.blocks[0].size_x, DEFAULT_REGION_SIZE);
+    assert_eq!(parsed.blocks[0].size_y, DEFAULT_REGION_SIZE);
+    assert_eq!(parsed.blocks[0].name, "Da Boom");
+}
+
+#[test]
+fn test_map_block_reply_nonexistent() {
+    let mut missing = block(1005, 1005, "", AgentAccess::NonExistent);
+    missing.map_image_id = Uuid::nil();
+    let reply = MapBlockReply {
+        agent_id: Uuid::nil(),
+        flags: MapBlockRequest::RETURN_NONEXISTENT,
+        blocks: vec![block(1000, 1000, "Da Boom", AgentAccess::PG), missing],
+    };
+    let bytes = reply.to_bytes();
+    // the access byte of the second block is the sentinel
+    let parsed = MapBlockReply::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.blocks[1].access, AgentAccess::NonExistent);
+    assert_eq!(parsed.blocks[1].access.to_bytes(), 255);
+    assert!(parsed.blocks[0].exists());
+    assert!(!parsed.blocks[1].exists());
+}
+
+#[test]
+fn test_map_blocks_event() {
+    let map_blocks = MapBlocks {
+        flags: 0,
+        blocks: vec![
+            block(1000, 1000, "Da Boom", AgentAccess::PG),
+            block(1001, 1001, "", AgentAccess::NonExistent),
+        ],
+    };
+    let body = PacketType::MapBlocks(Box::new(map_blocks.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::MapBlocksEvent));
+    match UiEventTypes::MapBlocksEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::MapBlocks(parsed)) => assert_eq!(*parsed, map_blocks),
+        _ => panic!("expected MapBlocks"),
+    }
+}
@@ -0,0 +1,118 @@
+use metaverse_messages::{
+    map_item_reply::{MapItem, MapItemReply},
+    map_item_request::{MapItemRequest, MapItemType},
+    map_items::MapItems,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::region_handle::region_handle,
+};
+use uuid::Uuid;
+
+fn agents(x: u32, y: u32, count: i32) -> MapItem {
+    MapItem {
+        x,
+        y,
+        id: Uuid::nil(),
+        extra: count,
+        extra2: 0,
+        name: String::new(),
+    }
+}
+
+#[test]
+fn test_map_item_request_round_trip() {
+    let request = MapItemRequest {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        flags: 2,
+        estate_id: 0,
+        godlike: false,
+        item_type: MapItemType::AgentLocations,
+        region_handle: region_handle(256_000, 256_256),
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 53);
+    assert_eq!(&bytes[41..45], &[6, 0, 0, 0]);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_map_item_request(request.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::MapItemRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected MapItemRequest"),
+    }
+}
+
+#[test]
+fn test_map_item_types() {
+    for value in 0..12 {
+        assert_eq!(MapItemType::from_bytes(value).to_bytes(), value);
+    }
+    assert_eq!(MapItemType::from_bytes(7), MapItemType::LandForSale);
+    assert_eq!(MapItemType::from_bytes(5), MapItemType::Unknown(5));
+}
+
+#[test]
+fn test_map_item_reply_round_trip() {
+    let reply = MapItemReply {
+        agent_id: Uuid::new_v4(),
+        flags: 2,
+        item_type: MapItemType::LandForSale,
+        items: vec![MapItem {
+            x: 256_040,
+            y: 256_100,
+            id: Uuid::new_v4(),
+            extra: 512,
+            extra2: 1000,
+            name: "Beachfront".to_string(),
+        }],
+    };
+    let packet = Packet::from_bytes(&Packet::new_map_item_reply(reply.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::MapItemReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected MapItemReply"),
+    }
+}
+
+#[test]
+fn test_agent_locations() {
+    let reply = MapItemReply {
+        agent_id: Uuid::nil(),
+        flags: 2,
+        item_type: MapItemType::AgentLocations,
+        items: vec![agents(256_010, 256_020, 3), agents(256_200, 256_020, 1)],
+    };
+    let parsed = MapItemReply::from_bytes(&reply.to_bytes()).unwrap();
+    assert_eq!(parsed.item_type, MapItemType::AgentLocations);
+    assert_eq!(parsed.items[0].extra, 3);
+    assert_eq!(parsed.items[1].extra, 1);
+
+    let map_items = MapItems {
+        flags: parsed.flags,
+        item_type: parsed.item_type,
+        items: parsed.items,
+    };
+    assert_eq!(map_items.agent_count(), 4);
+
+    // the extra field means something else for other item types
+    let land = MapItems {
+        item_type: MapItemType::LandForSale,
+        ..map_items.clone()
+    };
+    assert_eq!(land.agent_count(), 0);
+}
+
+#[test]
+fn test_map_items_event() {
+    let map_items = MapItems {
+        flags: 2,
+        item_type: MapItemType::AgentLocations,
+        items: vec![agents(256_010, 256_020, 3)],
+    };
+    let body = PacketType::MapItems(Box::new(map_items.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::MapItemsEvent));
+    match UiEventTypes::MapItemsEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::MapItems(parsed)) => assert_eq!(*parsed, map_items),
+        _ => panic!("expected MapItems"),
+    }
+}
@@ -0,0 +1,72 @@
+use metaverse_messages::{
+    map_block_reply::MapBlock, map_block_request::MapLayer, map_name_request::MapNameRequest,
+    map_search_empty::MapSearchEmpty, map_search_results::MapSearchResults, packet::Packet,
+    packet_types::PacketType, ui_events::UiEventTypes, utils::agent_access::AgentAccess,
+};
+use uuid::Uuid;
+
+#[test]
+fn test_map_name_request_round_trip() {
+    let request = MapNameRequest {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        flags: MapLayer::Terrain.to_flags(),
+        estate_id: 0,
+        godlike: false,
+        name: "Da Bo".to_string(),
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_map_name_request(request.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::MapNameRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected MapNameRequest"),
+    }
+}
+
+#[test]
+fn test_map_layers() {
+    assert_eq!(MapLayer::from_flags(0), MapLayer::Objects);
+    assert_eq!(MapLayer::from_flags(1 | 0x10000), MapLayer::Terrain);
+    assert_eq!(MapLayer::from_flags(2 | 0x0300_0000), MapLayer::Unknown(2));
+    assert_eq!(MapLayer::Unknown(2).to_flags(), 2);
+}
+
+#[test]
+fn test_map_search_events() {
+    let results = MapSearchResults {
+        name: "Da Bo".to_string(),
+        layer: MapLayer::Objects,
+        blocks: vec![MapBlock {
+            x: 1000,
+            y: 1000,
+            name: "Da Boom".to_string(),
+            access: AgentAccess::PG,
+            region_flags: 0,
+            water_height: 20,
+            agents: 0,
+            map_image_id: Uuid::new_v4(),
+            size_x: 256,
+            size_y: 256,
+        }],
+    };
+    let body = PacketType::MapSearchResults(Box::new(results.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::MapSearchResultsEvent
+    ));
+    match UiEventTypes::MapSearchResultsEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::MapSearchResults(parsed)) => assert_eq!(*parsed, results),
+        _ => panic!("expected MapSearchResults"),
+    }
+
+    let empty = MapSearchEmpty {
+        name: "Nowhere".to_string(),
+        layer: MapLayer::Terrain,
+    };
+    let body = PacketType::MapSearchEmpty(Box::new(empty.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::MapSearchEmptyEvent));
+    match UiEventTypes::MapSearchEmptyEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::MapSearchEmpty(parsed)) => assert_eq!(*parsed, empty),
+        _ => panic!("expected MapSearchEmpty"),
+    }
+}
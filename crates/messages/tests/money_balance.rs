@@ -0,0 +1,92 @@
+use metaverse_messages::{
+    money_balance_reply::{MoneyBalanceReply, TransactionInfo},
+    money_balance_request::MoneyBalanceRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const TRANSACTION_ID: Uuid = uuid!("9f3c1b2a-4d5e-4f60-8a7b-1c2d3e4f5a6b");
+
+// the MoneyData block of a reply to a balance request, with an empty description
+fn money_data() -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(AGENT_ID.as_bytes());
+    bytes.extend_from_slice(TRANSACTION_ID.as_bytes());
+    bytes.push(1);
+    bytes.extend_from_slice(&1250i32.to_le_bytes());
+    bytes.extend_from_slice(&512i32.to_le_bytes());
+    bytes.extend_from_slice(&0i32.to_le_bytes());
+    bytes.push(0);
+    bytes
+}
+
+#[test]
+fn test_money_balance_request_round_trip() {
+    let request = MoneyBalanceRequest {
+        agent_id: AGENT_ID,
+        session_id: Uuid::new_v4(),
+        transaction_id: TRANSACTION_ID,
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_money_balance_request(request.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::MoneyBalanceRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected MoneyBalanceRequest"),
+    }
+}
+
+#[test]
+fn test_money_balance_reply_with_empty_description() {
+    let reply = MoneyBalanceReply::from_bytes(&money_data()).unwrap();
+    assert_eq!(reply.agent_id, AGENT_ID);
+    assert_eq!(reply.transaction_id, TRANSACTION_ID);
+    assert!(reply.transaction_success);
+    assert_eq!(reply.money_balance, 1250);
+    assert_eq!(reply.square_meters_credit, 512);
+    assert_eq!(reply.description, "");
+    assert_eq!(reply.transaction_info, None);
+    assert_eq!(reply.to_bytes(), money_data());
+}
+
+#[test]
+fn test_money_balance_reply_after_a_purchase() {
+    let reply = MoneyBalanceReply {
+        agent_id: AGENT_ID,
+        transaction_id: Uuid::new_v4(),
+        transaction_success: true,
+        money_balance: 1240,
+        square_meters_credit: 512,
+        square_meters_committed: 0,
+        description: "You paid Skyler L$10.".to_string(),
+        transaction_info: Some(TransactionInfo {
+            transaction_type: 5008,
+            source_id: AGENT_ID,
+            is_source_group: false,
+            dest_id: Uuid::new_v4(),
+            is_dest_group: false,
+            amount: 10,
+            item_description: String::new(),
+        }),
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_money_balance_reply(reply.clone()).to_bytes()).unwrap();
+    match &packet.body {
+        PacketType::MoneyBalanceReply(parsed) => assert_eq!(**parsed, reply),
+        _ => panic!("expected MoneyBalanceReply"),
+    }
+
+    assert!(matches!(packet.body.ui_event(), UiEventTypes::BalanceEvent));
+    match UiEventTypes::BalanceEvent.packet_type_from_bytes(&packet.body.ui_event_bytes()) {
+        Some(PacketType::MoneyBalanceReply(parsed)) => assert_eq!(*parsed, reply),
+        _ => panic!("expected MoneyBalanceReply"),
+    }
+}
+
+#[test]
+fn test_truncated_money_balance_reply_is_an_error() {
+    let bytes = money_data();
+    assert!(MoneyBalanceReply::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
@@ -0,0 +1,126 @@
+use glam::{Quat, Vec3};
+use metaverse_messages::{
+    multiple_object_update::{MultipleObjectUpdate, ObjectTransformUpdate},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+};
+use uuid::Uuid;
+
+const POSITION: Vec3 = Vec3::new(128.0, 64.0, 22.0);
+const SCALE: Vec3 = Vec3::new(0.5, 1.0, 2.0);
+
+// every combination of the type flags, built from the bits of update_type
+fn update_for(update_type: u8) -> ObjectTransformUpdate {
+    let rotation = Quat::from_rotation_z(0.5);
+    let mut update = ObjectTransformUpdate {
+        local_id: 42,
+        position: None,
+        rotation: None,
+        scale: None,
+        linked_set: update_type & ObjectTransformUpdate::LINKED_SETS != 0,
+        uniform: update_type & ObjectTransformUpdate::UNIFORM != 0,
+    };
+    if update_type & ObjectTransformUpdate::POSITION != 0 {
+        update = update.with_position(POSITION);
+    }
+    if update_type & ObjectTransformUpdate::ROTATION != 0 {
+        update = update.with_rotation(rotation);
+    }
+    if update_type & ObjectTransformUpdate::SCALE != 0 {
+        update = update.with_scale(SCALE);
+    }
+    update
+}
+
+#[test]
+fn test_every_flag_combination() {
+    for update_type in 0..0x20u8 {
+        let update = update_for(update_type);
+        assert_eq!(update.update_type(), update_type);
+
+        let bytes = MultipleObjectUpdate {
+            agent_id: Uuid::new_v4(),
+            session_id: Uuid::new_v4(),
+            objects: vec![update.clone()],
+        }
+        .to_bytes();
+        let fields = (update_type & 0x07).count_ones() as usize;
+        // the ObjectData count, LocalID, Type, then the data length and data
+        assert_eq!(bytes[32], 1);
+        assert_eq!(&bytes[33..37], &42u32.to_le_bytes());
+        assert_eq!(bytes[37], update_type);
+        assert_eq!(bytes[38] as usize, fields * 12);
+        assert_eq!(bytes.len(), 39 + fields * 12);
+
+        // position, rotation and scale are always in that order
+        let data = &bytes[39..];
+        let mut offset = 0;
+        if update.position.is_some() {
+            assert_eq!(&data[offset..offset + 4], &POSITION.x.to_le_bytes());
+            offset += 12;
+        }
+        if let Some(rotation) = update.rotation {
+            assert_eq!(&data[offset + 8..offset + 12], &rotation.z.to_le_bytes());
+            offset += 12;
+        }
+        if update.scale.is_some() {
+            assert_eq!(&data[offset + 8..offset + 12], &SCALE.z.to_le_bytes());
+            offset += 12;
+        }
+        assert_eq!(offset, data.len());
+
+        let parsed = MultipleObjectUpdate::from_bytes(&bytes).unwrap();
+        let parsed = &parsed.objects[0];
+        assert_eq!(parsed.update_type(), update_type);
+        assert_eq!(parsed.position, update.position);
+        assert_eq!(parsed.scale, update.scale);
+        match (parsed.rotation, update.rotation) {
+            (Some(parsed), Some(rotation)) => assert!(parsed.abs_diff_eq(rotation, 1e-6)),
+            (None, None) => {}
+            _ => panic!(
+                "rotation was not round tripped for type {:#04x}",
+                update_type
+            ),
+        }
+    }
+}
+
+#[test]
+fn test_helpers_and_several_objects() {
+    let updates = vec![
+        ObjectTransformUpdate::position(1, POSITION),
+        ObjectTransformUpdate::rotation(2, Quat::IDENTITY),
+        ObjectTransformUpdate::scale(3, SCALE).with_position(POSITION),
+    ];
+    assert_eq!(updates[0].update_type(), ObjectTransformUpdate::POSITION);
+    assert_eq!(updates[1].update_type(), ObjectTransformUpdate::ROTATION);
+    assert_eq!(
+        updates[2].update_type(),
+        ObjectTransformUpdate::POSITION | ObjectTransformUpdate::SCALE
+    );
+
+    let update = MultipleObjectUpdate {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        objects: updates,
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_multiple_object_update(update.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::MultipleObjectUpdate(parsed) => assert_eq!(*parsed, update),
+        _ => panic!("expected MultipleObjectUpdate"),
+    }
+}
+
+#[test]
+fn test_data_that_does_not_match_the_type_is_an_error() {
+    let mut bytes = MultipleObjectUpdate {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        objects: vec![ObjectTransformUpdate::position(1, POSITION)],
+    }
+    .to_bytes();
+    // claim a scale is sent too
+    bytes[37] |= ObjectTransformUpdate::SCALE;
+    assert!(MultipleObjectUpdate::from_bytes(&bytes).is_err());
+}
@@ -0,0 +1,134 @@
+use hex::FromHex;
+use metaverse_messages::{
+    mute_list::MuteList,
+    mute_list_request::MuteListRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    remove_mute_list_entry::RemoveMuteListEntry,
+    ui_events::UiEventTypes,
+    update_mute_list_entry::UpdateMuteListEntry,
+    utils::mute::{MuteEntry, MuteFlags, MuteType},
+};
+use uuid::{uuid, Uuid};
+
+#[test]
+fn test_mute_list_update() {
+    // "mutes8a1f4b0c" with its null terminator
+    let bytes = Vec::from_hex(concat!(
+        "400000002000ffff013e",
+        "8a1f4b0c3f4e4d529c1a2b7e5d6f8a90",
+        "0e",
+        "6d75746573386131663462306300",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    match &packet.body {
+        PacketType::MuteListUpdate(update) => {
+            assert_eq!(
+                update.agent_id,
+                uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90")
+            );
+            assert_eq!(update.filename, "mutes8a1f4b0c");
+            assert_eq!(update.to_bytes(), bytes[10..]);
+        }
+        _ => panic!("expected MuteListUpdate"),
+    }
+}
+
+#[test]
+fn test_mute_list_request() {
+    let request = MuteListRequest {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        mute_crc: 0x12345678,
+    };
+    let packet = Packet::new_mute_list_request(request.clone());
+    assert_eq!(packet.header.id, 263);
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::MuteListRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected MuteListRequest"),
+    }
+}
+
+#[test]
+fn test_update_mute_list_entry() {
+    let entry = MuteEntry {
+        id: uuid!("0d9e7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b"),
+        name: "Loud Object".to_string(),
+        mute_type: MuteType::Object,
+        flags: MuteFlags::VOICE_CHAT | MuteFlags::PARTICLES,
+    };
+    let update = UpdateMuteListEntry::new(entry.clone());
+    assert!(update.agent_id.is_nil());
+    assert_eq!(update.entry(), entry);
+
+    let bytes = update.to_bytes();
+    // the type and flags are the last eight bytes
+    assert_eq!(bytes[bytes.len() - 8..], [2, 0, 0, 0, 6, 0, 0, 0]);
+    match Packet::from_bytes(&Packet::new_update_mute_list_entry(update.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::UpdateMuteListEntry(parsed) => assert_eq!(*parsed, update),
+        _ => panic!("expected UpdateMuteListEntry"),
+    }
+}
+
+#[test]
+fn test_remove_mute_list_entry() {
+    let remove = RemoveMuteListEntry::new(Uuid::nil(), "Spammer".to_string());
+    let parsed = RemoveMuteListEntry::from_bytes(&remove.to_bytes()).unwrap();
+    assert_eq!(parsed, remove);
+    assert!(RemoveMuteListEntry::from_bytes(&remove.to_bytes()[..40]).is_err());
+}
+
+#[test]
+fn test_parse_mute_list_file() {
+    let file = concat!(
+        "1 8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90 Default User|0\n",
+        "2 0d9e7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b Noisy | Box|8\n",
+        "0 00000000-0000-0000-0000-000000000000 Spammer|0\n",
+        "\n",
+        "not an entry\n",
+        "3 a2e76fcd-9360-4f6d-a924-000000000003 Some Group\n",
+    );
+    let mute_list = MuteList::parse(file.as_bytes());
+    assert_eq!(mute_list.entries.len(), 4);
+    assert_eq!(
+        mute_list.entries[0],
+        MuteEntry {
+            id: uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90"),
+            name: "Default User".to_string(),
+            mute_type: MuteType::Agent,
+            flags: MuteFlags::empty(),
+        }
+    );
+    assert!(mute_list.entries[0].mutes_chat());
+    // the flags come after the last |, so names can contain one
+    assert_eq!(mute_list.entries[1].name, "Noisy | Box");
+    assert_eq!(mute_list.entries[1].flags, MuteFlags::OBJECT_SOUNDS);
+    assert_eq!(mute_list.entries[2].mute_type, MuteType::ByName);
+    assert_eq!(mute_list.entries[3].name, "Some Group");
+    assert_eq!(mute_list.entries[3].flags, MuteFlags::empty());
+
+    let written = MuteList::parse(&mute_list.to_file());
+    assert_eq!(written, mute_list);
+}
+
+#[test]
+fn test_mute_list_event() {
+    let mute_list = MuteList {
+        entries: vec![MuteEntry {
+            id: Uuid::new_v4(),
+            name: "Default User".to_string(),
+            mute_type: MuteType::Agent,
+            flags: MuteFlags::from_bits_retain(0x100),
+        }],
+    };
+    let body = PacketType::MuteList(Box::new(mute_list.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::MuteListEvent));
+    match UiEventTypes::MuteListEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::MuteList(parsed)) => assert_eq!(*parsed, mute_list),
+        _ => panic!("failed to decode MuteListEvent"),
+    }
+}
@@ -0,0 +1,101 @@
+use glam::{Quat, Vec3};
+use metaverse_messages::{
+    object_add::{ObjectAdd, PrimBuilder},
+    object_update::PCode,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+};
+use uuid::Uuid;
+
+// an ObjectAdd for a half meter wood cube, created selected at 128, 128, 25
+fn golden_cube() -> Vec<u8> {
+    let mut bytes = Vec::new();
+    // AgentData: AgentID, SessionID, GroupID
+    bytes.extend_from_slice(&[0x01; 16]);
+    bytes.extend_from_slice(&[0x02; 16]);
+    bytes.extend_from_slice(&[0x00; 16]);
+    // PCode, Material, AddFlags
+    bytes.extend_from_slice(&[0x09, 0x03, 0x02, 0x00, 0x00, 0x00]);
+    // PathCurve, ProfileCurve, PathBegin, PathEnd
+    bytes.extend_from_slice(&[0x10, 0x01, 0x00, 0x00, 0x00, 0x00]);
+    // PathScaleX, PathScaleY, PathShearX, PathShearY
+    bytes.extend_from_slice(&[0x64, 0x64, 0x00, 0x00]);
+    // PathTwist, PathTwistBegin, PathRadiusOffset, PathTaperX, PathTaperY, PathRevolutions,
+    // PathSkew
+    bytes.extend_from_slice(&[0x00; 7]);
+    // ProfileBegin, ProfileEnd, ProfileHollow
+    bytes.extend_from_slice(&[0x00; 6]);
+    // BypassRaycast
+    bytes.push(0x01);
+    // RayStart and RayEnd
+    for _ in 0..2 {
+        bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x43, 0x00, 0x00, 0x00, 0x43]);
+        bytes.extend_from_slice(&[0x00, 0x00, 0xC8, 0x41]);
+    }
+    // RayTargetID, RayEndIsIntersection
+    bytes.extend_from_slice(&[0x00; 17]);
+    // Scale
+    for _ in 0..3 {
+        bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x3F]);
+    }
+    // Rotation, State
+    bytes.extend_from_slice(&[0x00; 13]);
+    bytes
+}
+
+#[test]
+fn test_object_add_matches_golden_bytes() {
+    let mut object_add = PrimBuilder::cube()
+        .at(Vec3::new(128.0, 128.0, 25.0))
+        .add_flags(ObjectAdd::ADD_FLAG_CREATE_SELECTED)
+        .build();
+    object_add.agent_id = Uuid::from_bytes([0x01; 16]);
+    object_add.session_id = Uuid::from_bytes([0x02; 16]);
+
+    let golden = golden_cube();
+    assert_eq!(golden.len(), 144);
+    assert_eq!(object_add.to_bytes(), golden);
+    assert_eq!(ObjectAdd::from_bytes(&golden).unwrap(), object_add);
+}
+
+#[test]
+fn test_object_add_round_trip() {
+    let object_add = PrimBuilder::sphere()
+        .raycast(
+            Vec3::new(120.0, 120.0, 30.0),
+            Vec3::new(128.0, 128.0, 20.0),
+            Uuid::new_v4(),
+        )
+        .scale(Vec3::new(1.0, 2.0, 3.0))
+        .rotation(Quat::from_rotation_z(1.0))
+        .material(ObjectAdd::MATERIAL_GLASS)
+        .group(Uuid::new_v4())
+        .build();
+    assert_eq!(object_add.pcode, PCode::Primitive);
+    assert!(!object_add.bypass_raycast);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_object_add(object_add.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ObjectAdd(parsed) => {
+            assert_eq!(parsed.group_id, object_add.group_id);
+            assert_eq!(parsed.ray_target_id, object_add.ray_target_id);
+            assert_eq!(parsed.shape, object_add.shape);
+            assert_eq!(parsed.scale, object_add.scale);
+            assert!(parsed.rotation.abs_diff_eq(object_add.rotation, 1e-6));
+            assert_eq!(parsed.material, ObjectAdd::MATERIAL_GLASS);
+        }
+        _ => panic!("expected ObjectAdd"),
+    }
+}
+
+#[test]
+fn test_prim_builder_defaults() {
+    let object_add = PrimBuilder::cylinder().build();
+    assert_eq!(object_add.group_id, Uuid::nil());
+    assert_eq!(object_add.ray_target_id, Uuid::nil());
+    assert_eq!(object_add.scale, Vec3::splat(0.5));
+    assert_eq!(object_add.rotation, Quat::IDENTITY);
+    assert_eq!(object_add.shape.path_scale_x, 100);
+    assert_ne!(object_add.shape, PrimBuilder::cube().build().shape);
+}
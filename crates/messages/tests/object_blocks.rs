@@ -0,0 +1,61 @@
+use metaverse_messages::utils::object_blocks::{
+    chunk_object_blocks, chunk_sized_object_blocks, MAX_OBJECT_BLOCKS, MAX_OBJECT_BLOCK_BYTES,
+};
+
+#[test]
+fn test_chunk_object_blocks_boundary() {
+    let local_ids: Vec<u32> = (0..511).collect();
+
+    let chunks = chunk_object_blocks(&local_ids[..MAX_OBJECT_BLOCKS]);
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].len(), 255);
+
+    let chunks = chunk_object_blocks(&local_ids[..256]);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].len(), 255);
+    assert_eq!(chunks[1], &[255]);
+
+    let chunks = chunk_object_blocks(&local_ids);
+    let lengths: Vec<usize> = chunks.iter().map(|chunk| chunk.len()).collect();
+    assert_eq!(lengths, vec![255, 255, 1]);
+    assert_eq!(chunks.concat(), local_ids);
+}
+
+#[test]
+fn test_chunk_object_blocks_of_nothing() {
+    assert!(chunk_object_blocks::<u32>(&[]).is_empty());
+}
+
+#[test]
+fn test_chunk_object_blocks_of_any_block() {
+    let names: Vec<String> = (0..300).map(|index| index.to_string()).collect();
+    let chunks = chunk_object_blocks(&names);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[1][0], "255");
+}
+
+#[test]
+fn test_chunk_sized_object_blocks_by_size() {
+    // 300 byte blocks, three of which fit under the limit
+    let blocks = vec![300usize; 7];
+    let chunks = chunk_sized_object_blocks(&blocks, |size| *size);
+    let lengths: Vec<usize> = chunks.iter().map(|chunk| chunk.len()).collect();
+    assert_eq!(lengths, vec![3, 3, 1]);
+
+    // a block bigger than the limit goes on its own, between the blocks around it
+    let blocks = vec![10, MAX_OBJECT_BLOCK_BYTES + 1, 10, 10];
+    let chunks = chunk_sized_object_blocks(&blocks, |size| *size);
+    assert_eq!(
+        chunks,
+        vec![&[10][..], &[MAX_OBJECT_BLOCK_BYTES + 1][..], &[10, 10][..]]
+    );
+}
+
+#[test]
+fn test_chunk_sized_object_blocks_by_count() {
+    let blocks = vec![1usize; 600];
+    let chunks = chunk_sized_object_blocks(&blocks, |size| *size);
+    let lengths: Vec<usize> = chunks.iter().map(|chunk| chunk.len()).collect();
+    assert_eq!(lengths, vec![255, 255, 90]);
+    assert!(chunk_sized_object_blocks::<usize>(&[], |size| *size).is_empty());
+}
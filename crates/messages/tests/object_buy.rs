@@ -0,0 +1,61 @@
+use metaverse_messages::{
+    object_buy::{ObjectBuy, ObjectBuyData},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    purchase_failed::PurchaseFailed,
+    ui_events::UiEventTypes,
+    utils::sale_type::SaleType,
+};
+use uuid::Uuid;
+
+#[test]
+fn test_object_buy_round_trip() {
+    let buy = ObjectBuy {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        group_id: Uuid::nil(),
+        category_id: Uuid::new_v4(),
+        objects: vec![
+            ObjectBuyData {
+                local_id: 7,
+                sale_type: SaleType::Copy,
+                sale_price: 25,
+            },
+            ObjectBuyData {
+                local_id: 8,
+                sale_type: SaleType::Contents,
+                sale_price: 0,
+            },
+        ],
+    };
+    let bytes = buy.to_bytes();
+    assert_eq!(bytes.len(), 65 + 2 * 9);
+    // ObjectData count, then LocalID, SaleType and SalePrice
+    assert_eq!(bytes[64], 2);
+    assert_eq!(&bytes[65..74], &[7, 0, 0, 0, 2, 25, 0, 0, 0]);
+
+    let packet = Packet::from_bytes(&Packet::new_object_buy(buy.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ObjectBuy(parsed) => assert_eq!(*parsed, buy),
+        _ => panic!("expected ObjectBuy"),
+    }
+}
+
+#[test]
+fn test_purchase_failed_event() {
+    let failed = PurchaseFailed {
+        local_id: 7,
+        object_id: Some(Uuid::new_v4()),
+        expected_sale_type: SaleType::Copy,
+        expected_price: 25,
+        sale_type: Some(SaleType::Copy),
+        sale_price: Some(50),
+        reason: "price does not match".to_string(),
+    };
+    let body = PacketType::PurchaseFailed(Box::new(failed.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::PurchaseFailedEvent));
+    match UiEventTypes::PurchaseFailedEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::PurchaseFailed(parsed)) => assert_eq!(*parsed, failed),
+        _ => panic!("expected PurchaseFailed"),
+    }
+}
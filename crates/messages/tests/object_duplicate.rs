@@ -0,0 +1,75 @@
+use glam::Vec3;
+use metaverse_messages::{
+    object_duplicate::{DuplicateFlags, ObjectDuplicate},
+    object_duplicate_on_ray::ObjectDuplicateOnRay,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+};
+use uuid::{uuid, Uuid};
+
+const TARGET_ID: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+
+#[test]
+fn test_object_duplicate_round_trip() {
+    let duplicate = ObjectDuplicate::new(Vec3::new(0.5, 0.0, 0.0), vec![10, 11]);
+    assert_eq!(duplicate.duplicate_flags, DuplicateFlags::CREATE_SELECTED);
+    let bytes = duplicate.to_bytes();
+    assert_eq!(bytes.len(), 65 + 8);
+    // the offset follows the AgentData block, then the flags
+    assert_eq!(&bytes[48..52], &0.5f32.to_le_bytes());
+    assert_eq!(&bytes[60..64], &2u32.to_le_bytes());
+    assert_eq!(bytes[64], 2);
+    match Packet::from_bytes(&Packet::new_object_duplicate(duplicate.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectDuplicate(parsed) => assert_eq!(*parsed, duplicate),
+        _ => panic!("expected ObjectDuplicate"),
+    }
+}
+
+#[test]
+fn test_object_duplicate_on_ray_round_trip() {
+    let duplicate = ObjectDuplicateOnRay::new(
+        Vec3::new(120.0, 120.0, 30.0),
+        Vec3::new(128.0, 128.0, 20.0),
+        TARGET_ID,
+        vec![10],
+    );
+    assert!(duplicate.copy_centers);
+    assert!(!duplicate.copy_rotates);
+    let bytes = duplicate.to_bytes();
+    assert_eq!(bytes.len(), 97 + 4);
+    // BypassRaycast, RayEndIsIntersection, CopyCenters and CopyRotates follow the ray
+    assert_eq!(&bytes[72..76], &[0, 0, 1, 0]);
+    assert_eq!(&bytes[76..92], TARGET_ID.as_bytes());
+    assert_eq!(ObjectDuplicateOnRay::from_bytes(&bytes).unwrap(), duplicate);
+    match Packet::from_bytes(&Packet::new_object_duplicate_on_ray(duplicate.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectDuplicateOnRay(parsed) => assert_eq!(*parsed, duplicate),
+        _ => panic!("expected ObjectDuplicateOnRay"),
+    }
+}
+
+#[test]
+fn test_object_duplicate_split() {
+    let local_ids: Vec<u32> = (0..300).collect();
+    let duplicate = ObjectDuplicate::new(Vec3::X, local_ids.clone());
+    let packets = duplicate.split();
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].local_ids.len(), 255);
+    assert_eq!(packets[1].offset, Vec3::X);
+    let rejoined: Vec<u32> = packets
+        .iter()
+        .flat_map(|packet| packet.local_ids.clone())
+        .collect();
+    assert_eq!(rejoined, local_ids);
+    assert!(ObjectDuplicate::new(Vec3::X, Vec::new()).split().is_empty());
+
+    let duplicate = ObjectDuplicateOnRay::new(Vec3::ZERO, Vec3::Z, Uuid::nil(), local_ids);
+    let packets = duplicate.split();
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[1].local_ids, (255..300).collect::<Vec<u32>>());
+}
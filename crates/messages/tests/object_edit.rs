@@ -0,0 +1,147 @@
+use metaverse_messages::{
+    object_description::{ObjectDescription, ObjectDescriptionData},
+    object_edit_ack::ObjectEditAck,
+    object_image::{ObjectImage, ObjectImageData},
+    object_name::{ObjectName, ObjectNameData},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::texture_entry::TextureEntry,
+};
+use uuid::Uuid;
+
+fn entry() -> TextureEntry {
+    let mut entry = TextureEntry::from_bytes(&[]).unwrap();
+    entry.default.texture_id = Uuid::from_bytes([0x21; 16]);
+    entry.faces[2].color = [255, 0, 0, 255];
+    entry
+}
+
+#[test]
+fn test_object_image_round_trip() {
+    let image = ObjectImage::new(vec![
+        ObjectImageData::new(7, &entry()),
+        ObjectImageData {
+            media_url: "https://example.com/".to_string(),
+            ..ObjectImageData::new(8, &entry())
+        },
+    ]);
+    let bytes = image.to_bytes();
+    assert_eq!(bytes[32], 2);
+    assert_eq!(&bytes[33..37], &7u32.to_le_bytes());
+    // no media URL
+    assert_eq!(bytes[37], 0);
+    let length = u16::from_le_bytes([bytes[38], bytes[39]]) as usize;
+    assert_eq!(&bytes[40..40 + length], entry().to_bytes().as_slice());
+
+    match Packet::from_bytes(&Packet::new_object_image(image.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectImage(parsed) => {
+            assert_eq!(*parsed, image);
+            assert_eq!(parsed.objects[1].texture_entry().unwrap(), entry());
+        }
+        _ => panic!("expected ObjectImage"),
+    }
+}
+
+#[test]
+fn test_object_image_split_keeps_packets_small() {
+    let objects: Vec<ObjectImageData> = (0..40)
+        .map(|local_id| ObjectImageData::new(local_id, &entry()))
+        .collect();
+    let image = ObjectImage::new(objects.clone());
+    let packets = image.split();
+    assert!(packets.len() > 1);
+    assert!(packets.iter().all(|packet| packet.to_bytes().len() <= 1100));
+    let sent: Vec<ObjectImageData> = packets
+        .into_iter()
+        .flat_map(|packet| packet.objects)
+        .collect();
+    assert_eq!(sent, objects);
+}
+
+#[test]
+fn test_object_name_truncates_long_names() {
+    let mut name = ObjectName::new(vec![
+        ObjectNameData {
+            local_id: 1,
+            name: "Chair".to_string(),
+        },
+        ObjectNameData {
+            local_id: 2,
+            // 62 ascii bytes, then a two byte character across the limit
+            name: format!("{}é and more", "a".repeat(62)),
+        },
+    ]);
+    let warnings = name.truncate();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("object 2"));
+    assert_eq!(name.objects[0].name, "Chair");
+    // cut on the character boundary, below the limit
+    assert_eq!(name.objects[1].name, "a".repeat(62));
+    assert!(name.truncate().is_empty());
+
+    match Packet::from_bytes(&Packet::new_object_name(name.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectName(parsed) => assert_eq!(*parsed, name),
+        _ => panic!("expected ObjectName"),
+    }
+}
+
+#[test]
+fn test_object_description_truncates_to_limit() {
+    let mut description = ObjectDescription::new(vec![ObjectDescriptionData {
+        local_id: 3,
+        description: "d".repeat(200),
+    }]);
+    let warnings = description.truncate();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        description.objects[0].description.len(),
+        ObjectDescription::MAX_DESCRIPTION_BYTES
+    );
+    let bytes = description.to_bytes();
+    // the length includes the null terminator
+    assert_eq!(
+        bytes[37] as usize,
+        ObjectDescription::MAX_DESCRIPTION_BYTES + 1
+    );
+}
+
+#[test]
+fn test_object_name_batches_objects() {
+    let objects: Vec<ObjectNameData> = (0..300)
+        .map(|local_id| ObjectNameData {
+            local_id,
+            name: "Step".to_string(),
+        })
+        .collect();
+    let name = ObjectName::new(objects.clone());
+    let packets = name.split();
+    // many small blocks share a packet, up to the size limit
+    assert!(packets[0].objects.len() > 1);
+    let sent: Vec<ObjectNameData> = packets
+        .into_iter()
+        .flat_map(|packet| packet.objects)
+        .collect();
+    assert_eq!(sent, objects);
+}
+
+#[test]
+fn test_object_edit_ack_event() {
+    let ack = ObjectEditAck {
+        local_ids: vec![1, 2],
+        warnings: vec!["name of object 2 cut from 70 to 63 bytes".to_string()],
+    };
+    let body = PacketType::ObjectEditAck(Box::new(ack.clone()));
+    let event = body.ui_event();
+    assert!(matches!(event, UiEventTypes::ObjectEditAckEvent));
+    match event.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ObjectEditAck(parsed)) => assert_eq!(*parsed, ack),
+        _ => panic!("expected ObjectEditAck"),
+    }
+}
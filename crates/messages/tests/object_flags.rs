@@ -0,0 +1,132 @@
+use metaverse_messages::{
+    object_click_action::{ObjectClickAction, ObjectClickActionData},
+    object_flag_update::{ExtraPhysics, ObjectFlagUpdate, PhysicsShapeType},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    utils::{click_action::ClickAction, prim_flags::PrimFlags},
+};
+use uuid::Uuid;
+
+fn extra_physics() -> ExtraPhysics {
+    ExtraPhysics {
+        shape_type: PhysicsShapeType::ConvexHull,
+        density: 1000.0,
+        friction: 0.6,
+        restitution: 0.5,
+        gravity_multiplier: 0.25,
+    }
+}
+
+#[test]
+fn test_object_flag_update_short_form() {
+    let mut update = ObjectFlagUpdate::new(42, PrimFlags::USE_PHYSICS | PrimFlags::PHANTOM);
+    update.agent_id = Uuid::from_bytes([1; 16]);
+    let bytes = update.to_bytes();
+    // no ExtraPhysics block, not even its count
+    assert_eq!(bytes.len(), 40);
+    assert_eq!(&bytes[32..36], &42u32.to_le_bytes());
+    assert_eq!(&bytes[36..40], &[1, 0, 1, 0]);
+
+    let parsed = ObjectFlagUpdate::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, update);
+    assert_eq!(parsed.extra_physics, None);
+    assert_eq!(parsed.flags(), PrimFlags::USE_PHYSICS | PrimFlags::PHANTOM);
+}
+
+#[test]
+fn test_object_flag_update_extended_form() {
+    let update = ObjectFlagUpdate {
+        extra_physics: Some(extra_physics()),
+        ..ObjectFlagUpdate::new(42, PrimFlags::TEMPORARY_ON_REZ)
+    };
+    let bytes = update.to_bytes();
+    assert_eq!(bytes.len(), 40 + 1 + 17);
+    assert_eq!(&bytes[36..40], &[0, 1, 0, 0]);
+    assert_eq!(bytes[40], 1);
+    assert_eq!(bytes[41], 2);
+    assert_eq!(&bytes[42..46], &1000f32.to_le_bytes());
+    assert_eq!(&bytes[54..58], &0.25f32.to_le_bytes());
+
+    match Packet::from_bytes(&Packet::new_object_flag_update(update.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectFlagUpdate(parsed) => {
+            assert_eq!(*parsed, update);
+            assert_eq!(parsed.extra_physics, Some(extra_physics()));
+        }
+        _ => panic!("expected ObjectFlagUpdate"),
+    }
+}
+
+#[test]
+fn test_object_flag_update_ignores_fixed_flags() {
+    let update = ObjectFlagUpdate::new(
+        1,
+        PrimFlags::OBJECT_MODIFY | PrimFlags::SCRIPTED | PrimFlags::PHANTOM,
+    );
+    assert!(!update.use_physics);
+    assert!(update.is_phantom);
+    assert_eq!(update.flags(), PrimFlags::PHANTOM);
+}
+
+#[test]
+fn test_object_flag_update_truncated() {
+    let update = ObjectFlagUpdate {
+        extra_physics: Some(extra_physics()),
+        ..ObjectFlagUpdate::new(42, PrimFlags::empty())
+    };
+    let bytes = update.to_bytes();
+    assert!(ObjectFlagUpdate::from_bytes(&bytes[..50]).is_err());
+    assert!(ObjectFlagUpdate::from_bytes(&bytes[..38]).is_err());
+}
+
+#[test]
+fn test_prim_flags_serde_keeps_unknown_bits() {
+    let flags = PrimFlags::from_bits_retain(0x0080_0401);
+    let json = serde_json::to_string(&flags).unwrap();
+    assert_eq!(json, (0x0080_0401u32).to_string());
+    assert_eq!(serde_json::from_str::<PrimFlags>(&json).unwrap(), flags);
+}
+
+#[test]
+fn test_object_click_action_round_trip() {
+    let edit = ObjectClickAction::new(vec![
+        ObjectClickActionData {
+            local_id: 7,
+            click_action: ClickAction::Sit,
+        },
+        ObjectClickActionData {
+            local_id: 8,
+            click_action: ClickAction::Open,
+        },
+        ObjectClickActionData {
+            local_id: 9,
+            click_action: ClickAction::Unknown(42),
+        },
+    ]);
+    let bytes = edit.to_bytes();
+    assert_eq!(bytes.len(), 33 + 15);
+    assert_eq!(bytes[32], 3);
+    assert_eq!(bytes[37], 1);
+    assert_eq!(bytes[42], 4);
+    assert_eq!(bytes[47], 42);
+
+    match Packet::from_bytes(&Packet::new_object_click_action(edit.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectClickAction(parsed) => assert_eq!(*parsed, edit),
+        _ => panic!("expected ObjectClickAction"),
+    }
+}
+
+#[test]
+fn test_click_action_bytes() {
+    for byte in 0..=8 {
+        assert_eq!(ClickAction::from_bytes(byte).to_bytes(), byte);
+    }
+    assert_eq!(ClickAction::from_bytes(2), ClickAction::Buy);
+    assert_eq!(ClickAction::from_bytes(3), ClickAction::Pay);
+    assert_eq!(ClickAction::Touch.to_bytes(), 0);
+}
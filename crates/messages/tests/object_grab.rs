@@ -0,0 +1,128 @@
+use glam::Vec3;
+use metaverse_messages::{
+    object_de_grab::ObjectDeGrab,
+    object_grab::ObjectGrab,
+    object_grab_update::ObjectGrabUpdate,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    utils::surface_info::{SurfaceInfo, SURFACE_INFO_SIZE},
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const SESSION_ID: Uuid = uuid!("9f3c1b2a-4d5e-4f60-8a7b-1c2d3e4f5a6b");
+
+fn hit() -> SurfaceInfo {
+    SurfaceInfo {
+        uv_coord: Vec3::new(0.25, 0.75, 0.0),
+        st_coord: Vec3::new(0.5, 0.5, 0.0),
+        face_index: 2,
+        position: Vec3::new(128.0, 64.0, 22.5),
+        normal: Vec3::new(0.0, 0.0, 1.0),
+        binormal: Vec3::new(1.0, 0.0, 0.0),
+    }
+}
+
+#[test]
+fn test_object_grab_without_surface_info() {
+    let grab = ObjectGrab {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+        local_id: 0x01020304,
+        grab_offset: Vec3::new(1.0, 2.0, 3.0),
+        surface_info: Vec::new(),
+    };
+    let bytes = grab.to_bytes();
+    // AgentData, LocalID, GrabOffset, then a zero count for SurfaceInfo
+    assert_eq!(bytes.len(), 32 + 4 + 12 + 1);
+    assert_eq!(&bytes[0..16], AGENT_ID.as_bytes());
+    assert_eq!(&bytes[16..32], SESSION_ID.as_bytes());
+    assert_eq!(&bytes[32..36], &[4, 3, 2, 1]);
+    assert_eq!(&bytes[36..40], &1.0f32.to_le_bytes());
+    assert_eq!(bytes[48], 0);
+    assert_eq!(ObjectGrab::from_bytes(&bytes).unwrap(), grab);
+}
+
+#[test]
+fn test_object_grab_with_surface_info() {
+    let grab = ObjectGrab {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+        local_id: 42,
+        grab_offset: Vec3::ZERO,
+        surface_info: vec![hit()],
+    };
+    let bytes = grab.to_bytes();
+    assert_eq!(bytes.len(), 49 + SURFACE_INFO_SIZE);
+    assert_eq!(bytes[48], 1);
+    // UVCoord, STCoord, FaceIndex, Position, Normal, Binormal
+    assert_eq!(&bytes[49..53], &0.25f32.to_le_bytes());
+    assert_eq!(&bytes[61..65], &0.5f32.to_le_bytes());
+    assert_eq!(&bytes[73..77], &2i32.to_le_bytes());
+    assert_eq!(&bytes[77..81], &128.0f32.to_le_bytes());
+    assert_eq!(&bytes[97..101], &1.0f32.to_le_bytes());
+    assert_eq!(&bytes[101..105], &1.0f32.to_le_bytes());
+
+    let packet = Packet::from_bytes(&Packet::new_object_grab(grab.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ObjectGrab(parsed) => assert_eq!(*parsed, grab),
+        _ => panic!("expected ObjectGrab"),
+    }
+}
+
+#[test]
+fn test_object_grab_update_round_trip() {
+    for surface_info in [Vec::new(), vec![hit()]] {
+        let update = ObjectGrabUpdate {
+            agent_id: AGENT_ID,
+            session_id: SESSION_ID,
+            object_id: Uuid::new_v4(),
+            grab_offset_initial: Vec3::new(0.1, 0.2, 0.3),
+            grab_position: Vec3::new(130.0, 64.0, 22.5),
+            time_since_last: 50,
+            surface_info: surface_info.clone(),
+        };
+        let bytes = update.to_bytes();
+        assert_eq!(bytes.len(), 77 + surface_info.len() * SURFACE_INFO_SIZE);
+        assert_eq!(bytes[76], surface_info.len() as u8);
+        let packet =
+            Packet::from_bytes(&Packet::new_object_grab_update(update.clone()).to_bytes()).unwrap();
+        match packet.body {
+            PacketType::ObjectGrabUpdate(parsed) => assert_eq!(*parsed, update),
+            _ => panic!("expected ObjectGrabUpdate"),
+        }
+    }
+}
+
+#[test]
+fn test_object_de_grab_round_trip() {
+    for surface_info in [Vec::new(), vec![SurfaceInfo::at(Vec3::new(1.0, 2.0, 3.0))]] {
+        let de_grab = ObjectDeGrab {
+            agent_id: AGENT_ID,
+            session_id: SESSION_ID,
+            local_id: 42,
+            surface_info: surface_info.clone(),
+        };
+        let bytes = de_grab.to_bytes();
+        assert_eq!(bytes.len(), 37 + surface_info.len() * SURFACE_INFO_SIZE);
+        assert_eq!(bytes[36], surface_info.len() as u8);
+        let packet =
+            Packet::from_bytes(&Packet::new_object_de_grab(de_grab.clone()).to_bytes()).unwrap();
+        match packet.body {
+            PacketType::ObjectDeGrab(parsed) => assert_eq!(*parsed, de_grab),
+            _ => panic!("expected ObjectDeGrab"),
+        }
+    }
+}
+
+#[test]
+fn test_missing_surface_info_count_is_an_error() {
+    let bytes = ObjectDeGrab {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+        local_id: 42,
+        surface_info: Vec::new(),
+    }
+    .to_bytes();
+    assert!(ObjectDeGrab::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
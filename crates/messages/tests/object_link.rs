@@ -0,0 +1,82 @@
+use metaverse_messages::{
+    errors::SessionError,
+    object_delink::ObjectDelink,
+    object_link::{ObjectLink, MAX_LINK_SET_PRIMS},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_object_link_sends_root_first() {
+    let link = ObjectLink::new(42, vec![7, 9, 3]);
+    assert_eq!(link.local_ids, vec![42, 7, 9, 3]);
+    assert_eq!(link.root_id(), Some(42));
+    assert_eq!(link.child_ids(), &[7, 9, 3]);
+
+    let bytes = link.to_bytes();
+    assert_eq!(bytes.len(), 33 + 16);
+    assert_eq!(bytes[32], 4);
+    // the ObjectData blocks keep the order they were given in, root first
+    let blocks: Vec<u32> = bytes[33..]
+        .chunks(4)
+        .map(|block| u32::from_le_bytes(block.try_into().unwrap()))
+        .collect();
+    assert_eq!(blocks, vec![42, 7, 9, 3]);
+
+    match Packet::from_bytes(&Packet::new_object_link(link.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectLink(parsed) => {
+            assert_eq!(parsed.root_id(), Some(42));
+            assert_eq!(*parsed, link);
+        }
+        _ => panic!("expected ObjectLink"),
+    }
+}
+
+#[test]
+fn test_object_link_check() {
+    assert!(ObjectLink::new(1, vec![2]).check().is_ok());
+    assert!(ObjectLink::new(1, Vec::new()).check().is_err());
+    assert!(ObjectLink::new(0, (1..255).collect()).check().is_ok());
+
+    let too_many = ObjectLink::new(0, (1..MAX_LINK_SET_PRIMS as u32 + 1).collect());
+    assert_eq!(too_many.local_ids.len(), 257);
+    let error = too_many.check().unwrap_err();
+    assert!(error.message.contains("at most 256"));
+    // the error the session sends to the UI instead of the packet
+    let error = SessionError::LinkObjects(error);
+    match UiEventTypes::Error.packet_type_from_bytes(&error.to_bytes()) {
+        Some(PacketType::Error(parsed)) => match *parsed {
+            SessionError::LinkObjects(e) => assert!(e.message.contains("257")),
+            other => panic!("expected LinkObjectsError, got {:?}", other),
+        },
+        _ => panic!("failed to decode the error"),
+    }
+
+    // a full link set doesn't fit the packet's 255 blocks either
+    let full = ObjectLink::new(0, (1..MAX_LINK_SET_PRIMS as u32).collect());
+    assert!(full.check().is_err());
+}
+
+#[test]
+fn test_object_delink_round_trip() {
+    let delink = ObjectDelink::new(vec![5, 6]);
+    let bytes = delink.to_bytes();
+    assert_eq!(bytes.len(), 33 + 8);
+    assert_eq!(ObjectDelink::from_bytes(&bytes).unwrap(), delink);
+    match Packet::from_bytes(&Packet::new_object_delink(delink.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectDelink(parsed) => assert_eq!(*parsed, delink),
+        _ => panic!("expected ObjectDelink"),
+    }
+
+    let packets = ObjectDelink::new((0..300).collect()).split();
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].local_ids.len(), 255);
+    assert_eq!(packets[1].local_ids, (255..300).collect::<Vec<u32>>());
+}
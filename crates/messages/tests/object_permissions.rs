@@ -0,0 +1,120 @@
+use hex::FromHex;
+use metaverse_messages::{
+    object_permissions::{ObjectPermissions, PermissionField},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    utils::permissions::{PermissionMask, Permissions},
+};
+use uuid::uuid;
+
+const COPY_MODIFY_TRANSFER: PermissionMask = PermissionMask::COPY
+    .union(PermissionMask::MODIFY)
+    .union(PermissionMask::TRANSFER);
+
+#[test]
+fn test_set_next_owner_permissions() {
+    let mut permissions = ObjectPermissions::new(
+        &[0x30, 0x31],
+        PermissionField::NextOwner,
+        true,
+        COPY_MODIFY_TRANSFER,
+    );
+    permissions.agent_id = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+    permissions.session_id = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+    let bytes = Packet::new_object_permissions(permissions.clone()).to_bytes();
+    assert_eq!(
+        bytes[6..],
+        Vec::from_hex(concat!(
+            "ffff0069",
+            "320dff8a7a594720a0f75a8df3698d9a",
+            "5748deccf629461c9a36a35a221fe21f",
+            "00", // not overriding
+            "02",
+            "30000000",
+            "10", // next owner
+            "01", // set
+            "00e00000",
+            "31000000",
+            "10",
+            "01",
+            "00e00000",
+        ))
+        .unwrap()
+    );
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::ObjectPermissions(parsed) => assert_eq!(*parsed, permissions),
+        _ => panic!("expected ObjectPermissions"),
+    }
+}
+
+#[test]
+fn test_clear_everyone_permissions() {
+    let permissions = ObjectPermissions::new(
+        &[7, 8, 9],
+        PermissionField::Everyone,
+        false,
+        PermissionMask::COPY | PermissionMask::MODIFY,
+    );
+    let bytes = permissions.to_bytes();
+    assert_eq!(bytes[33], 3);
+    for (index, local_id) in [7u32, 8, 9].iter().enumerate() {
+        let block = &bytes[34 + index * 10..44 + index * 10];
+        assert_eq!(&block[..4], &local_id.to_le_bytes());
+        assert_eq!(block[4], 0x08);
+        assert_eq!(block[5], 0);
+        assert_eq!(&block[6..], &0xc000u32.to_le_bytes());
+    }
+    assert_eq!(ObjectPermissions::from_bytes(&bytes).unwrap(), permissions);
+}
+
+#[test]
+fn test_permission_fields() {
+    for (byte, field) in [
+        (0x01, PermissionField::Base),
+        (0x02, PermissionField::Owner),
+        (0x04, PermissionField::Group),
+        (0x08, PermissionField::Everyone),
+        (0x10, PermissionField::NextOwner),
+    ] {
+        assert_eq!(PermissionField::from_bytes(byte), Some(field));
+        assert_eq!(field.to_bytes(), byte);
+    }
+    assert_eq!(PermissionField::from_bytes(0x03), None);
+    assert_eq!(PermissionField::from_bytes(0x20), None);
+
+    // a block with a field that isn't a permission mask can't be read
+    let mut bytes =
+        ObjectPermissions::new(&[7], PermissionField::Group, true, PermissionMask::COPY).to_bytes();
+    bytes[38] = 0x03;
+    assert!(ObjectPermissions::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_split() {
+    let local_ids: Vec<u32> = (0..300).collect();
+    let permissions = ObjectPermissions::new(
+        &local_ids,
+        PermissionField::Group,
+        true,
+        PermissionMask::MODIFY,
+    );
+    let packets = permissions.split();
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].objects.len(), 255);
+    assert_eq!(packets[1].objects.len(), 45);
+    assert_eq!(packets[1].objects[0].local_id, 255);
+}
+
+#[test]
+fn test_permission_mask() {
+    let permissions = Permissions::from(COPY_MODIFY_TRANSFER);
+    assert!(permissions.copy && permissions.modify && permissions.transfer);
+    assert!(!permissions.export && !permissions.r#move);
+    assert_eq!(PermissionMask::from(permissions), COPY_MODIFY_TRANSFER);
+    assert_eq!(permissions.to_bits(), 0xe000);
+    assert_eq!(Permissions::from_bits(0xe000 | 0x01), permissions);
+    assert_eq!(
+        serde_json::to_string(&COPY_MODIFY_TRANSFER).unwrap(),
+        "57344"
+    );
+}
@@ -0,0 +1,125 @@
+use metaverse_messages::{
+    object_deselect::ObjectDeselect,
+    object_properties::{ObjectProperties, ObjectPropertiesData},
+    object_select::ObjectSelect,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::{permissions::Permissions, sale_type::SaleType},
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const SESSION_ID: Uuid = uuid!("9f3c1b2a-4d5e-4f60-8a7b-1c2d3e4f5a6b");
+
+fn object(name: &str) -> ObjectPropertiesData {
+    let full = Permissions::from_bits(
+        Permissions::TRANSFER | Permissions::MODIFY | Permissions::COPY | Permissions::MOVE,
+    );
+    ObjectPropertiesData {
+        object_id: Uuid::new_v4(),
+        creator_id: AGENT_ID,
+        owner_id: AGENT_ID,
+        group_id: Uuid::nil(),
+        creation_date: 1_700_000_000_000_000,
+        base_mask: full,
+        owner_mask: full,
+        group_mask: Permissions::default(),
+        everyone_mask: Permissions::from_bits(Permissions::MOVE),
+        next_owner_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        ownership_cost: 0,
+        sale_type: SaleType::Copy,
+        sale_price: 25,
+        aggregate_perms: 0,
+        aggregate_perm_textures: 0,
+        aggregate_perm_textures_owner: 0,
+        category: 0,
+        inventory_serial: 3,
+        item_id: Uuid::new_v4(),
+        folder_id: Uuid::nil(),
+        from_task_id: Uuid::nil(),
+        last_owner_id: AGENT_ID,
+        name: name.to_string(),
+        description: String::new(),
+        touch_name: "Open".to_string(),
+        sit_name: String::new(),
+        texture_id: Uuid::new_v4().as_bytes().to_vec(),
+    }
+}
+
+#[test]
+fn test_object_select_round_trip() {
+    let select = ObjectSelect {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+        local_ids: vec![101, 102, 103],
+    };
+    let bytes = select.to_bytes();
+    assert_eq!(bytes[32], 3);
+    assert_eq!(&bytes[33..37], &101u32.to_le_bytes());
+    let packet = Packet::from_bytes(&Packet::new_object_select(select.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ObjectSelect(parsed) => assert_eq!(*parsed, select),
+        _ => panic!("expected ObjectSelect"),
+    }
+
+    let deselect = ObjectDeselect {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+        local_ids: vec![101],
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_object_deselect(deselect.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ObjectDeselect(parsed) => assert_eq!(*parsed, deselect),
+        _ => panic!("expected ObjectDeselect"),
+    }
+}
+
+#[test]
+fn test_object_properties_for_a_link_set() {
+    let properties = ObjectProperties {
+        objects: vec![object("Chair"), object("Cushion"), object("")],
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_object_properties(properties.clone()).to_bytes()).unwrap();
+    match &packet.body {
+        PacketType::ObjectProperties(parsed) => assert_eq!(**parsed, properties),
+        _ => panic!("expected ObjectProperties"),
+    }
+
+    assert!(matches!(
+        packet.body.ui_event(),
+        UiEventTypes::ObjectPropertiesEvent
+    ));
+    match UiEventTypes::ObjectPropertiesEvent.packet_type_from_bytes(&packet.body.ui_event_bytes())
+    {
+        Some(PacketType::ObjectProperties(parsed)) => {
+            assert_eq!(parsed.objects.len(), 3);
+            assert_eq!(parsed.objects[1].name, "Cushion");
+            assert!(parsed.objects[0].next_owner_mask.modify);
+            assert!(!parsed.objects[0].next_owner_mask.copy);
+        }
+        _ => panic!("expected ObjectProperties"),
+    }
+}
+
+#[test]
+fn test_truncated_object_properties_is_an_error() {
+    let bytes = ObjectProperties {
+        objects: vec![object("Chair")],
+    }
+    .to_bytes();
+    assert!(ObjectProperties::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_permissions_bits() {
+    let bits = Permissions::COPY | Permissions::MOVE;
+    let permissions = Permissions::from_bits(bits | 1);
+    assert!(permissions.copy);
+    assert!(permissions.r#move);
+    assert!(!permissions.transfer);
+    // unknown bits are dropped
+    assert_eq!(permissions.to_bits(), bits);
+}
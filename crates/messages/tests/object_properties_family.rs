@@ -0,0 +1,106 @@
+use metaverse_messages::{
+    object_family_status::ObjectFamilyStatus,
+    object_properties_family::ObjectPropertiesFamily,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    query_object_family::QueryObjectFamily,
+    request_object_properties_family::RequestObjectPropertiesFamily,
+    ui_events::UiEventTypes,
+    utils::{permissions::Permissions, sale_type::SaleType},
+};
+use uuid::Uuid;
+
+fn family() -> ObjectPropertiesFamily {
+    ObjectPropertiesFamily {
+        request_flags: 0x30,
+        object_id: Uuid::from_bytes([0x01; 16]),
+        owner_id: Uuid::from_bytes([0x02; 16]),
+        group_id: Uuid::from_bytes([0x03; 16]),
+        base_mask: Permissions::from_bits(Permissions::COPY | Permissions::MODIFY),
+        owner_mask: Permissions::from_bits(Permissions::COPY),
+        group_mask: Permissions::from_bits(0),
+        everyone_mask: Permissions::from_bits(0),
+        next_owner_mask: Permissions::from_bits(Permissions::TRANSFER),
+        ownership_cost: 0,
+        sale_type: SaleType::Original,
+        sale_price: 150,
+        category: 0,
+        last_owner_id: Uuid::from_bytes([0x04; 16]),
+        name: "Rocking Chair".to_string(),
+        description: "sit on it".to_string(),
+    }
+}
+
+#[test]
+fn test_request_object_properties_family_round_trip() {
+    let mut request = RequestObjectPropertiesFamily::new(0x30, Uuid::from_bytes([0x01; 16]));
+    request.agent_id = Uuid::from_bytes([0x05; 16]);
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 52);
+    assert_eq!(&bytes[32..36], &0x30u32.to_le_bytes());
+    assert_eq!(&bytes[36..52], &[0x01; 16]);
+
+    match Packet::from_bytes(
+        &Packet::new_request_object_properties_family(request.clone()).to_bytes(),
+    )
+    .unwrap()
+    .body
+    {
+        PacketType::RequestObjectPropertiesFamily(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected RequestObjectPropertiesFamily"),
+    }
+}
+
+#[test]
+fn test_object_properties_family_layout() {
+    let bytes = family().to_bytes();
+    assert_eq!(bytes.len(), 101 + 15 + 11);
+    assert_eq!(&bytes[0..4], &0x30u32.to_le_bytes());
+    assert_eq!(&bytes[4..20], &[0x01; 16]);
+    // the sale type comes after the five masks and the ownership cost
+    assert_eq!(bytes[76], 1);
+    assert_eq!(&bytes[77..81], &150i32.to_le_bytes());
+    assert_eq!(bytes[101], 14);
+    assert_eq!(&bytes[102..115], b"Rocking Chair");
+
+    match Packet::from_bytes(&Packet::new_object_properties_family(family()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ObjectPropertiesFamily(parsed) => assert_eq!(*parsed, family()),
+        _ => panic!("expected ObjectPropertiesFamily"),
+    }
+    assert!(ObjectPropertiesFamily::from_bytes(&bytes[..90]).is_err());
+}
+
+#[test]
+fn test_query_object_family_command() {
+    let query = QueryObjectFamily {
+        object_id: Uuid::from_bytes([0x01; 16]),
+        token: 42,
+    };
+    match Packet::from_bytes(&Packet::new_query_object_family(query.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::QueryObjectFamily(parsed) => assert_eq!(*parsed, query),
+        _ => panic!("expected QueryObjectFamily"),
+    }
+}
+
+#[test]
+fn test_object_family_status_event() {
+    let status = ObjectFamilyStatus {
+        token: 42,
+        object_id: Uuid::from_bytes([0x01; 16]),
+        family: Some(family()),
+        reason: None,
+    };
+    let body = PacketType::ObjectFamilyStatus(Box::new(status.clone()));
+    let event = body.ui_event();
+    assert!(matches!(event, UiEventTypes::ObjectFamilyStatusEvent));
+    match event.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ObjectFamilyStatus(parsed)) => assert_eq!(*parsed, status),
+        _ => panic!("expected ObjectFamilyStatus"),
+    }
+}
@@ -0,0 +1,80 @@
+use hex::FromHex;
+use metaverse_messages::{
+    object_category::{ObjectCategory, ObjectCategoryData},
+    object_sale_info::{ObjectSaleInfo, ObjectSaleInfoData},
+    packet::Packet,
+    packet_types::PacketType,
+    utils::sale_type::SaleType,
+};
+use uuid::uuid;
+
+#[test]
+fn test_object_sale_info() {
+    let mut sale_info = ObjectSaleInfo::new(vec![
+        ObjectSaleInfoData {
+            local_id: 0x30,
+            sale_type: SaleType::Copy,
+            sale_price: 25,
+        },
+        ObjectSaleInfoData {
+            local_id: 0x31,
+            sale_type: SaleType::NotForSale,
+            sale_price: 0,
+        },
+    ]);
+    sale_info.agent_id = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+    sale_info.session_id = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+    let bytes = Packet::new_object_sale_info(sale_info.clone()).to_bytes();
+    assert_eq!(
+        bytes[6..],
+        Vec::from_hex(concat!(
+            "ffff006a",
+            "320dff8a7a594720a0f75a8df3698d9a",
+            "5748deccf629461c9a36a35a221fe21f",
+            "02",
+            "30000000",
+            "02", // copy
+            "19000000",
+            "31000000",
+            "00", // not for sale
+            "00000000",
+        ))
+        .unwrap()
+    );
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::ObjectSaleInfo(parsed) => assert_eq!(*parsed, sale_info),
+        _ => panic!("expected ObjectSaleInfo"),
+    }
+}
+
+#[test]
+fn test_object_category() {
+    let category = ObjectCategory::new(vec![ObjectCategoryData {
+        local_id: 0x30,
+        category: 4,
+    }]);
+    let bytes = Packet::new_object_category(category.clone()).to_bytes();
+    assert_eq!(bytes[6..10], [0xff, 0xff, 0x00, 0x6d]);
+    assert_eq!(bytes[42..], [0x01, 0x30, 0, 0, 0, 0x04, 0, 0, 0]);
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::ObjectCategory(parsed) => assert_eq!(*parsed, category),
+        _ => panic!("expected ObjectCategory"),
+    }
+}
+
+#[test]
+fn test_split() {
+    let sale_info = ObjectSaleInfo::new(
+        (0..300)
+            .map(|local_id| ObjectSaleInfoData {
+                local_id,
+                sale_type: SaleType::Original,
+                sale_price: 10,
+            })
+            .collect(),
+    );
+    let packets = sale_info.split();
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].objects.len(), 255);
+    assert_eq!(packets[1].objects.len(), 45);
+}
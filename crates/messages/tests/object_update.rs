@@ -0,0 +1,147 @@
+use glam::{Vec3, Vec4};
+use hex::FromHex;
+use metaverse_messages::{
+    object_update::{ObjectMotion, ObjectUpdate, PCode},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::{texture_entry::TextureEntry, zerocoding::zero_decode},
+};
+use uuid::uuid;
+
+const AVATAR_ID: uuid::Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const PRIM_ID: uuid::Uuid = uuid!("9f3c1b2a-4d5e-4f60-8a7b-1c2d3e4f5a6b");
+
+// An ObjectUpdate for an avatar standing next to a default plywood box, laid out the way OpenSim
+// sends one and zerocoded as it comes off the wire. It was written out from the message
+// template rather than with to_bytes, so the parser is checked against the wire layout and not
+// against itself.
+const UPDATE: &str = concat!(
+    "c00000002f000c0001e8030002e8030001ffff02715a150002320dff8a7a5947",
+    "20a0f75a8df3698d9a00042f0400016666e63e9a99193f3333f33f4c000a803f",
+    "0002a8c10003430003430002af4100233f00103d09021010010004646400124e",
+    "000146697273744e616d6520535452494e472052572053562044656661756c74",
+    "0a4c6173744e616d6520535452494e4720525720535620557365720a5469746c",
+    "6520535452494e4720525720535620000a010043725a1500029f3c1b2a4d5e4f",
+    "608a7b1c2d3e4f5a6b0004090300043f00033f00033f3c000180024300034300",
+    "02aa4100342c090210100100046464000f2f00018955674724cb43ed920b47ca",
+    "ed15465f0008803f0003803f001c010043",
+);
+
+fn packet() -> Vec<u8> {
+    Vec::from_hex(UPDATE).unwrap()
+}
+
+/// the body of the packet, after the seven bytes of its header
+fn body() -> Vec<u8> {
+    zero_decode(&packet()[7..])
+}
+
+#[test]
+fn test_object_update_blocks() {
+    let update = ObjectUpdate::from_bytes(&body()).unwrap();
+    assert_eq!(update.region_handle, (256000u64 << 32) | 256000);
+    assert_eq!(update.time_dilation, 65535);
+    assert_eq!(update.objects.len(), 2);
+
+    let avatar = &update.objects[0];
+    assert_eq!(avatar.local_id, 1399409);
+    assert_eq!(avatar.full_id, AVATAR_ID);
+    assert_eq!(avatar.pcode, PCode::Avatar);
+    assert_eq!(avatar.material, 4);
+    assert_eq!(avatar.scale, Vec3::new(0.45, 0.6, 1.9));
+    assert_eq!(avatar.object_data.len(), 76);
+    assert_eq!(avatar.parent_id, 0);
+    assert_eq!(avatar.update_flags, 0x1002093d);
+    assert!(avatar.texture_entry.is_empty());
+    assert_eq!(
+        avatar.name_value,
+        "FirstName STRING RW SV Default\nLastName STRING RW SV User\nTitle STRING RW SV "
+    );
+    assert_eq!(avatar.name_value("LastName"), Some("User"));
+    assert_eq!(avatar.name_value("Last"), None);
+    assert_eq!(avatar.attach_item_id(), None);
+    assert_eq!(avatar.extra_params, vec![0]);
+
+    let prim = &update.objects[1];
+    assert_eq!(prim.local_id, 1399410);
+    assert_eq!(prim.full_id, PRIM_ID);
+    assert_eq!(prim.pcode, PCode::Primitive);
+    assert_eq!(prim.material, 3);
+    assert_eq!(prim.scale, Vec3::new(0.5, 0.5, 0.5));
+    assert_eq!(prim.object_data.len(), 60);
+    assert_eq!(prim.parent_id, 0);
+    assert_eq!(prim.update_flags, 0x1002092c);
+    assert_eq!(prim.shape.path_curve, 16);
+    assert_eq!(prim.shape.profile_curve, 1);
+    assert_eq!(prim.shape.path_scale_x, 100);
+    assert_eq!(prim.shape.path_scale_y, 100);
+    assert_eq!(prim.name_value, "");
+    let texture_entry = TextureEntry::from_bytes(&prim.texture_entry).unwrap();
+    assert_eq!(
+        texture_entry.default.texture_id,
+        uuid!("89556747-24cb-43ed-920b-47caed15465f")
+    );
+}
+
+#[test]
+fn test_object_update_motion() {
+    let update = ObjectUpdate::from_bytes(&body()).unwrap();
+
+    let avatar = update.objects[0].motion().unwrap();
+    assert_eq!(
+        avatar.collision_plane,
+        Some(Vec4::new(0.0, 0.0, 1.0, -21.0))
+    );
+    assert_eq!(avatar.position, Vec3::new(128.0, 128.0, 21.875));
+    assert_eq!(avatar.velocity, Vec3::ZERO);
+    assert_eq!(avatar.rotation, Vec3::new(0.0, 0.0, 0.5));
+
+    let prim = update.objects[1].motion().unwrap();
+    assert_eq!(prim.collision_plane, None);
+    assert_eq!(prim.position, Vec3::new(130.5, 128.0, 21.25));
+    assert_eq!(prim.angular_velocity, Vec3::ZERO);
+
+    assert!(ObjectMotion::from_bytes(&[0; 32]).is_err());
+}
+
+#[test]
+fn test_object_update_round_trip() {
+    let bytes = body();
+    let update = ObjectUpdate::from_bytes(&bytes).unwrap();
+    assert_eq!(update.to_bytes(), bytes);
+}
+
+#[test]
+fn test_object_update_truncated() {
+    let bytes = body();
+    // the second block is cut off
+    assert!(ObjectUpdate::from_bytes(&bytes[..bytes.len() - 40]).is_err());
+    // the block count claims more blocks than were sent
+    let mut bytes = body();
+    bytes[10] = 3;
+    assert!(ObjectUpdate::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_object_update_packet() {
+    let packet = Packet::from_bytes(&packet()).unwrap();
+    assert!(packet.header.zerocoded);
+    assert!(packet.header.reliable);
+    assert_eq!(packet.header.sequence_number, 47);
+    let update = match &packet.body {
+        PacketType::ObjectUpdate(update) => update,
+        body => panic!("expected ObjectUpdate, got {:?}", body),
+    };
+    assert_eq!(update.objects.len(), 2);
+
+    // the mailbox forwards the update to the UI as an ObjectUpdateEvent
+    let event = packet.body.ui_event();
+    assert!(matches!(event, UiEventTypes::ObjectUpdateEvent));
+    match event.packet_type_from_bytes(&packet.body.ui_event_bytes()) {
+        Some(PacketType::ObjectUpdate(update)) => {
+            assert_eq!(update.objects[1].local_id, 1399410)
+        }
+        _ => panic!("failed to decode ObjectUpdateEvent"),
+    }
+}
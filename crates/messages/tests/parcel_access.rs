@@ -0,0 +1,153 @@
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    parcel_access_list::ParcelAccessList,
+    parcel_access_list_reply::ParcelAccessListReply,
+    parcel_access_list_request::ParcelAccessListRequest,
+    parcel_access_list_update::ParcelAccessListUpdate,
+    parcel_dwell_reply::ParcelDwellReply,
+    parcel_dwell_request::ParcelDwellRequest,
+    ui_events::UiEventTypes,
+    utils::parcel_access::{ParcelAccessEntry, ParcelAccessFlags},
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const PARCEL_ID: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+
+fn entries(count: u128) -> Vec<ParcelAccessEntry> {
+    (0..count)
+        .map(|index| ParcelAccessEntry {
+            id: Uuid::from_u128(index + 1),
+            time: 0,
+            flags: ParcelAccessFlags::BAN,
+        })
+        .collect()
+}
+
+#[test]
+fn test_parcel_dwell() {
+    let request = ParcelDwellRequest::new(12);
+    assert_eq!(request.to_bytes().len(), 52);
+    assert_eq!(
+        ParcelDwellRequest::from_bytes(&request.to_bytes()).unwrap(),
+        request
+    );
+
+    let reply = ParcelDwellReply {
+        agent_id: AGENT_ID,
+        local_id: 12,
+        parcel_id: PARCEL_ID,
+        dwell: 431.5,
+    };
+    assert_eq!(reply.to_bytes().len(), 40);
+    match Packet::from_bytes(&Packet::new_parcel_dwell_reply(reply.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ParcelDwellReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected ParcelDwellReply"),
+    }
+    let body = PacketType::ParcelDwellReply(Box::new(reply.clone()));
+    match UiEventTypes::ParcelDwellEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ParcelDwellReply(parsed)) => assert_eq!(*parsed, reply),
+        _ => panic!("failed to decode ParcelDwellEvent"),
+    }
+}
+
+#[test]
+fn test_parcel_access_list_request() {
+    let mut request =
+        ParcelAccessListRequest::new(12, ParcelAccessFlags::ACCESS | ParcelAccessFlags::BAN);
+    request.sequence_id = 4;
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 44);
+    // the flags come after the sequence id
+    assert_eq!(&bytes[36..40], &3u32.to_le_bytes());
+    assert_eq!(
+        ParcelAccessListRequest::from_bytes(&bytes).unwrap(),
+        request
+    );
+}
+
+#[test]
+fn test_parcel_access_list_reply() {
+    let reply = ParcelAccessListReply {
+        agent_id: AGENT_ID,
+        sequence_id: 4,
+        flags: ParcelAccessFlags::BAN,
+        local_id: 12,
+        entries: entries(3),
+    };
+    let bytes = reply.to_bytes();
+    assert_eq!(bytes.len(), 29 + 3 * ParcelAccessEntry::SIZE);
+    assert_eq!(ParcelAccessListReply::from_bytes(&bytes).unwrap(), reply);
+    assert!(ParcelAccessListReply::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    match Packet::from_bytes(&Packet::new_parcel_access_list_reply(reply.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ParcelAccessListReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected ParcelAccessListReply"),
+    }
+}
+
+#[test]
+fn test_parcel_access_list_update_split() {
+    let update = ParcelAccessListUpdate::new(12, ParcelAccessFlags::BAN, entries(100));
+    let sections = update.split();
+    assert_eq!(sections.len(), 3);
+    let lengths: Vec<usize> = sections
+        .iter()
+        .map(|section| section.entries.len())
+        .collect();
+    assert_eq!(lengths, vec![48, 48, 4]);
+    for (index, section) in sections.iter().enumerate() {
+        assert_eq!(section.sequence_id, index as i32 + 1);
+        assert_eq!(section.sections, 3);
+        assert_eq!(section.transaction_id, sections[0].transaction_id);
+        assert_eq!(section.local_id, 12);
+        assert_eq!(
+            ParcelAccessListUpdate::from_bytes(&section.to_bytes()).unwrap(),
+            *section
+        );
+    }
+    assert!(!sections[0].transaction_id.is_nil());
+    let rejoined: Vec<ParcelAccessEntry> = sections
+        .into_iter()
+        .flat_map(|section| section.entries)
+        .collect();
+    assert_eq!(rejoined, entries(100));
+
+    let sections = ParcelAccessListUpdate::new(12, ParcelAccessFlags::BAN, entries(48)).split();
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0].sections, 1);
+}
+
+#[test]
+fn test_parcel_access_list_update_empty() {
+    // an empty list is sent as a single nil entry, which clears the list
+    let sections = ParcelAccessListUpdate::new(12, ParcelAccessFlags::ACCESS, Vec::new()).split();
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0].sections, 1);
+    assert_eq!(sections[0].entries.len(), 1);
+    assert!(sections[0].entries[0].id.is_nil());
+}
+
+#[test]
+fn test_parcel_access_list_event() {
+    let list = ParcelAccessList {
+        local_id: 12,
+        flags: ParcelAccessFlags::BAN,
+        entries: entries(2),
+    };
+    let body = PacketType::ParcelAccessList(Box::new(list.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::ParcelAccessListEvent
+    ));
+    match UiEventTypes::ParcelAccessListEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ParcelAccessList(parsed)) => assert_eq!(*parsed, list),
+        _ => panic!("failed to decode ParcelAccessListEvent"),
+    }
+}
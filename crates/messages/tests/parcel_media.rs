@@ -0,0 +1,120 @@
+use hex::FromHex;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    parcel_media_command_message::{ParcelMediaCommand, ParcelMediaCommandMessage},
+    parcel_media_update::{ParcelMediaExtended, ParcelMediaUpdate},
+    ui_events::UiEventTypes,
+};
+use uuid::uuid;
+
+fn media_update(extended: Option<ParcelMediaExtended>) -> ParcelMediaUpdate {
+    ParcelMediaUpdate {
+        media_url: "http://x".to_string(),
+        media_id: uuid!("0a1b2c3d-4e5f-4061-8273-8495a6b7c8d9"),
+        media_auto_scale: true,
+        extended,
+    }
+}
+
+#[test]
+fn test_parcel_media_command() {
+    let bytes = Vec::from_hex(concat!(
+        "400000000100ffff01a3",
+        "44000000", // play and time
+        "02000000", // play
+        "00004841", // 12.5 seconds
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let command = match &packet.body {
+        PacketType::ParcelMediaCommandMessage(command) => command.clone(),
+        _ => panic!("expected ParcelMediaCommandMessage"),
+    };
+    assert_eq!(command.command, ParcelMediaCommand::Play);
+    assert!(command.has_command(ParcelMediaCommand::Play));
+    assert!(!command.has_command(ParcelMediaCommand::Loop));
+    assert_eq!(command.seek_time(), Some(12.5));
+    assert_eq!(command.to_bytes(), bytes[10..]);
+
+    let body = PacketType::ParcelMediaCommandMessage(command.clone());
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::ParcelMediaCommandEvent
+    ));
+    match UiEventTypes::ParcelMediaCommandEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ParcelMediaCommandMessage(decoded)) => assert_eq!(decoded, command),
+        _ => panic!("failed to decode ParcelMediaCommandEvent"),
+    }
+}
+
+#[test]
+fn test_parcel_media_command_without_time() {
+    let command = ParcelMediaCommandMessage {
+        flags: 1 << 8,
+        command: ParcelMediaCommand::Unload,
+        time: 0.0,
+    };
+    assert_eq!(command.seek_time(), None);
+    assert_eq!(
+        ParcelMediaCommandMessage::from_bytes(&command.to_bytes()).unwrap(),
+        command
+    );
+    assert_eq!(
+        ParcelMediaCommand::from_bytes(42),
+        ParcelMediaCommand::Unknown(42)
+    );
+    assert_eq!(ParcelMediaCommand::Unknown(42).to_bytes(), 42);
+}
+
+#[test]
+fn test_parcel_media_update_short_form() {
+    // older simulators only send the first block
+    let bytes = Vec::from_hex(concat!(
+        "400000000100ffff01a4",
+        "09687474703a2f2f7800", // "http://x"
+        "0a1b2c3d4e5f406182738495a6b7c8d9",
+        "01",
+    ))
+    .unwrap();
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::ParcelMediaUpdate(update) => assert_eq!(*update, media_update(None)),
+        _ => panic!("expected ParcelMediaUpdate"),
+    }
+    assert_eq!(media_update(None).to_bytes(), bytes[10..]);
+}
+
+#[test]
+fn test_parcel_media_update_extended() {
+    let bytes = Vec::from_hex(concat!(
+        "400000000100ffff01a4",
+        "09687474703a2f2f7800", // "http://x"
+        "0a1b2c3d4e5f406182738495a6b7c8d9",
+        "01",
+        "0a766964656f2f6d703400", // "video/mp4"
+        "00",                     // no description
+        "00040000",               // 1024 wide
+        "00030000",               // 768 high
+        "01",
+    ))
+    .unwrap();
+    let update = media_update(Some(ParcelMediaExtended {
+        media_type: "video/mp4".to_string(),
+        media_desc: String::new(),
+        media_width: 1024,
+        media_height: 768,
+        media_loop: true,
+    }));
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    match &packet.body {
+        PacketType::ParcelMediaUpdate(parsed) => assert_eq!(**parsed, update),
+        _ => panic!("expected ParcelMediaUpdate"),
+    }
+    assert_eq!(update.to_bytes(), bytes[10..]);
+
+    let body = PacketType::ParcelMediaUpdate(Box::new(update.clone()));
+    match UiEventTypes::ParcelMediaUpdateEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ParcelMediaUpdate(decoded)) => assert_eq!(*decoded, update),
+        _ => panic!("failed to decode ParcelMediaUpdateEvent"),
+    }
+}
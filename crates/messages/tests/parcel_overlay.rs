@@ -0,0 +1,86 @@
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    parcel_overlay::{ParcelOverlay, ParcelOverlayFlags, ParcelOwnership},
+    parcel_overlay_grid::{ParcelOverlayGrid, PARCEL_OVERLAY_SIZE},
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_parcel_overlay_round_trip() {
+    let overlay = ParcelOverlay {
+        sequence_id: 2,
+        data: (0..1024).map(|i| i as u8).collect(),
+    };
+    let bytes = overlay.to_bytes();
+    assert_eq!(bytes.len(), 4 + 2 + 1024);
+    assert_eq!(&bytes[..6], &[2, 0, 0, 0, 0x00, 0x04]);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_parcel_overlay(overlay.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ParcelOverlay(parsed) => assert_eq!(*parsed, overlay),
+        _ => panic!("expected ParcelOverlay"),
+    }
+}
+
+#[test]
+fn test_parcel_overlay_flags() {
+    let cell = ParcelOverlayFlags::from_bits_retain(0x03 | 0x20 | 0x80);
+    assert_eq!(cell.ownership(), ParcelOwnership::OwnedBySelf);
+    assert!(cell.contains(ParcelOverlayFlags::SOUND_LOCAL));
+    assert!(cell.contains(ParcelOverlayFlags::BORDER_SOUTH));
+    assert!(!cell.contains(ParcelOverlayFlags::BORDER_WEST));
+
+    let ownerships = [
+        ParcelOwnership::Public,
+        ParcelOwnership::Owned,
+        ParcelOwnership::Group,
+        ParcelOwnership::OwnedBySelf,
+        ParcelOwnership::ForSale,
+        ParcelOwnership::Auction,
+        ParcelOwnership::Unknown(6),
+        ParcelOwnership::Unknown(7),
+    ];
+    for (bits, ownership) in ownerships.into_iter().enumerate() {
+        let cell = ParcelOverlayFlags::from_bits_retain(bits as u8 | 0x40);
+        assert_eq!(cell.ownership(), ownership);
+    }
+}
+
+#[test]
+fn test_parcel_overlay_grid() {
+    let mut cells = vec![0u8; PARCEL_OVERLAY_SIZE];
+    // row 1, column 2 is for sale
+    cells[64 + 2] = 0x04;
+    let grid = ParcelOverlayGrid { cells };
+    assert_eq!(
+        grid.cell(2, 1).map(|cell| cell.ownership()),
+        Some(ParcelOwnership::ForSale)
+    );
+    assert_eq!(
+        grid.at(9.5, 4.0).map(|cell| cell.ownership()),
+        Some(ParcelOwnership::ForSale)
+    );
+    assert_eq!(
+        grid.at(0.0, 0.0).map(|cell| cell.ownership()),
+        Some(ParcelOwnership::Public)
+    );
+    assert!(grid.cell(64, 0).is_none());
+    assert!(grid.cell(0, 64).is_none());
+    assert!(grid.at(-1.0, 0.0).is_none());
+}
+
+#[test]
+fn test_parcel_overlay_event() {
+    let grid = ParcelOverlayGrid {
+        cells: (0..PARCEL_OVERLAY_SIZE).map(|i| i as u8).collect(),
+    };
+    let body = PacketType::ParcelOverlayGrid(Box::new(grid.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::ParcelOverlayEvent));
+    match UiEventTypes::ParcelOverlayEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ParcelOverlayGrid(parsed)) => assert_eq!(*parsed, grid),
+        _ => panic!("expected ParcelOverlayGrid"),
+    }
+    assert!(ParcelOverlayGrid::from_bytes(&[0; 100]).is_err());
+}
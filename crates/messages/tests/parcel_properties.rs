@@ -0,0 +1,170 @@
+use glam::Vec3;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    parcel_properties::{LandingType, ParcelProperties, ParcelStatus},
+    parcel_properties_request::ParcelPropertiesRequest,
+    ui_events::UiEventTypes,
+    utils::parcel_flags::ParcelFlags,
+};
+use uuid::Uuid;
+
+// a parcel covering the west half of the region
+fn parcel() -> ParcelProperties {
+    let mut bitmap = Vec::with_capacity(512);
+    for _ in 0..64 {
+        bitmap.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]);
+    }
+    ParcelProperties {
+        request_result: ParcelProperties::RESULT_SUCCESS,
+        sequence_id: 3,
+        snap_selection: true,
+        self_count: 1,
+        other_count: 2,
+        public_count: 0,
+        local_id: 5,
+        owner_id: Uuid::new_v4(),
+        is_group_owned: false,
+        auction_id: 0,
+        claim_date: 1_700_000_000,
+        claim_price: 0,
+        rent_price: 0,
+        aabb_min: Vec3::new(0.0, 0.0, 0.0),
+        aabb_max: Vec3::new(128.0, 256.0, 0.0),
+        bitmap,
+        area: 32768,
+        status: ParcelStatus::Leased,
+        sim_wide_max_prims: 15000,
+        sim_wide_total_prims: 120,
+        max_prims: 7500,
+        total_prims: 100,
+        owner_prims: 90,
+        group_prims: 0,
+        other_prims: 10,
+        selected_prims: 0,
+        parcel_prim_bonus: 1.0,
+        other_clean_time: 0,
+        parcel_flags: ParcelFlags::from_bits(ParcelFlags::ALLOW_FLY | ParcelFlags::SOUND_LOCAL),
+        sale_price: 0,
+        name: "Sandbox".to_string(),
+        description: "build here".to_string(),
+        music_url: "http://music.example.com/stream".to_string(),
+        media_url: String::new(),
+        media_id: Uuid::nil(),
+        media_auto_scale: false,
+        group_id: Uuid::nil(),
+        pass_price: 10,
+        pass_hours: 1.0,
+        category: 0,
+        auth_buyer_id: Uuid::nil(),
+        snapshot_id: Uuid::nil(),
+        user_location: Vec3::new(64.0, 128.0, 25.0),
+        user_look_at: Vec3::new(1.0, 0.0, 0.0),
+        landing_type: LandingType::Anywhere,
+        region_push_override: false,
+        region_deny_anonymous: false,
+        region_deny_identified: false,
+        region_deny_transacted: false,
+        region_deny_age_unverified: Some(true),
+        region_allow_access_override: Some(false),
+    }
+}
+
+#[test]
+fn test_parcel_properties_round_trip() {
+    let parcel = parcel();
+    let packet =
+        Packet::from_bytes(&Packet::new_parcel_properties(parcel.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ParcelProperties(parsed) => assert_eq!(*parsed, parcel),
+        _ => panic!("expected ParcelProperties"),
+    }
+}
+
+#[test]
+fn test_parcel_properties_without_access_blocks() {
+    let mut parcel = parcel();
+    parcel.region_allow_access_override = None;
+    let with_age_block = parcel.to_bytes();
+    assert_eq!(
+        ParcelProperties::from_bytes(&with_age_block).unwrap(),
+        parcel
+    );
+
+    parcel.region_deny_age_unverified = None;
+    let without_blocks = parcel.to_bytes();
+    assert_eq!(without_blocks.len(), with_age_block.len() - 1);
+    assert_eq!(
+        ParcelProperties::from_bytes(&without_blocks).unwrap(),
+        parcel
+    );
+}
+
+#[test]
+fn test_parcel_properties_truncated() {
+    let bytes = parcel().to_bytes();
+    assert!(ParcelProperties::from_bytes(&bytes[..100]).is_err());
+}
+
+#[test]
+fn test_parcel_contains() {
+    let parcel = parcel();
+    assert!(parcel.contains(0.0, 0.0));
+    assert!(parcel.contains(127.9, 255.9));
+    assert!(!parcel.contains(128.0, 10.0));
+    assert!(!parcel.contains(-1.0, 10.0));
+    assert!(!parcel.contains(10.0, 256.0));
+    assert!(!parcel.contains(300.0, 10.0));
+}
+
+#[test]
+fn test_parcel_flags_round_trip() {
+    for bit in 0..32 {
+        let flags = ParcelFlags::from_bits(1 << bit);
+        assert_eq!(flags.to_bits(), 1 << bit);
+    }
+    assert_eq!(ParcelFlags::from_bits(u32::MAX).to_bits(), u32::MAX);
+    assert!(ParcelFlags::from_bits(ParcelFlags::DENY_AGE_UNVERIFIED).deny_age_unverified);
+}
+
+#[test]
+fn test_parcel_properties_request_at() {
+    let request = ParcelPropertiesRequest::at(130.5, 7.9, 12);
+    assert_eq!(request.sequence_id, 12);
+    assert_eq!(
+        (request.west, request.south, request.east, request.north),
+        (128.0, 4.0, 132.0, 8.0)
+    );
+    assert!(request.snap_selection);
+
+    let request = ParcelPropertiesRequest {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        ..request
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 53);
+    assert_eq!(&bytes[32..36], &12i32.to_le_bytes());
+    assert_eq!(bytes[52], 1);
+    let packet =
+        Packet::from_bytes(&Packet::new_parcel_properties_request(request.clone()).to_bytes())
+            .unwrap();
+    match packet.body {
+        PacketType::ParcelPropertiesRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected ParcelPropertiesRequest"),
+    }
+}
+
+#[test]
+fn test_parcel_properties_event() {
+    let parcel = parcel();
+    let body = PacketType::ParcelProperties(Box::new(parcel.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::ParcelPropertiesEvent
+    ));
+    match UiEventTypes::ParcelPropertiesEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ParcelProperties(parsed)) => assert_eq!(*parsed, parcel),
+        _ => panic!("expected ParcelProperties"),
+    }
+}
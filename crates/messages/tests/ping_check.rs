@@ -0,0 +1,57 @@
+use metaverse_messages::{
+    complete_ping_check::CompletePingCheck,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ping_stats::PingStats,
+    start_ping_check::StartPingCheck,
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_start_ping_check() {
+    let packet = Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 255,
+        oldest_unacked: 0x01020304,
+    });
+    let bytes = packet.to_bytes();
+    // High frequency id 1, then the ping id and the little endian oldest unacked
+    assert_eq!(&bytes[6..], &[1, 255, 4, 3, 2, 1]);
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::StartPingCheck(ping) => {
+            assert_eq!(ping.ping_id, 255);
+            assert_eq!(ping.oldest_unacked, 0x01020304);
+        }
+        body => panic!("expected StartPingCheck, got {:?}", body),
+    }
+}
+
+#[test]
+fn test_complete_ping_check() {
+    let ping = CompletePingCheck { ping_id: 7 };
+    assert_eq!(ping.to_bytes(), vec![7]);
+    assert_eq!(CompletePingCheck::from_bytes(&[7]).unwrap().ping_id, 7);
+}
+
+#[test]
+fn test_ping_check_truncated() {
+    assert!(StartPingCheck::from_bytes(&[1, 2]).is_err());
+    assert!(CompletePingCheck::from_bytes(&[]).is_err());
+}
+
+#[test]
+fn test_ping_stats_event() {
+    let stats = PacketType::PingStats(Box::new(PingStats {
+        latency_ms: 42,
+        sent_pings: 10,
+        missed_pings: 1,
+    }));
+    let event = stats.ui_event();
+    assert!(matches!(event, UiEventTypes::PingStatsEvent));
+    match event.packet_type_from_bytes(&stats.ui_event_bytes()) {
+        Some(PacketType::PingStats(stats)) => {
+            assert_eq!(stats.latency_ms, 42);
+            assert_eq!(stats.missed_pings, 1);
+        }
+        _ => panic!("failed to decode PingStatsEvent"),
+    }
+}
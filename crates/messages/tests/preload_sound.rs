@@ -0,0 +1,62 @@
+use hex::FromHex;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    preload_sound::{PreloadSound, PreloadSoundData},
+    ui_events::UiEventTypes,
+};
+use uuid::uuid;
+
+#[test]
+fn test_preload_sound_three_blocks() {
+    let bytes = Vec::from_hex(concat!(
+        "000000003000ff0f03",
+        "6ec3d9a55a6d4c2b9c5b1f8f8dbd6d42a2e76fcd93604f6da924000000000003",
+        "8bfa8a8e58e04fd0bb0a0a3e3fd2e0a1",
+        "0b5e3c1f2a4d4e6f8a9bc0d1e2f3a4b5a2e76fcd93604f6da924000000000003",
+        "4c1f2e3d5b6a49788c9d0e1f2a3b4c5d",
+        "6ec3d9a55a6d4c2b9c5b1f8f8dbd6d42a2e76fcd93604f6da924000000000003",
+        "d7c6b5a493824716a5b4c3d2e1f00918",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let preload = match &packet.body {
+        PacketType::PreloadSound(preload) => preload.clone(),
+        _ => panic!("expected PreloadSound"),
+    };
+    let owner_id = uuid!("a2e76fcd-9360-4f6d-a924-000000000003");
+    assert_eq!(preload.sounds.len(), 3);
+    assert_eq!(
+        preload.sounds[1],
+        PreloadSoundData {
+            object_id: uuid!("0b5e3c1f-2a4d-4e6f-8a9b-c0d1e2f3a4b5"),
+            owner_id,
+            sound_id: uuid!("4c1f2e3d-5b6a-4978-8c9d-0e1f2a3b4c5d"),
+        }
+    );
+    assert_eq!(
+        preload.sound_ids(),
+        vec![
+            uuid!("8bfa8a8e-58e0-4fd0-bb0a-0a3e3fd2e0a1"),
+            uuid!("4c1f2e3d-5b6a-4978-8c9d-0e1f2a3b4c5d"),
+            uuid!("d7c6b5a4-9382-4716-a5b4-c3d2e1f00918"),
+        ]
+    );
+    assert_eq!(preload.sounds[0].object_id, preload.sounds[2].object_id);
+
+    let event = packet.body.ui_event();
+    assert!(matches!(event, UiEventTypes::PreloadSoundEvent));
+    match event.packet_type_from_bytes(&packet.body.ui_event_bytes()) {
+        Some(PacketType::PreloadSound(decoded)) => assert_eq!(decoded, preload),
+        _ => panic!("failed to decode PreloadSoundEvent"),
+    }
+
+    let round_trip = Packet::new_preload_sound((*preload).clone()).to_bytes();
+    assert_eq!(round_trip[5..], bytes[5..]);
+}
+
+#[test]
+fn test_preload_sound_truncated() {
+    let bytes = Vec::from_hex("026ec3d9a55a6d4c2b9c5b1f8f8dbd6d42").unwrap();
+    assert!(PreloadSound::from_bytes(&bytes).is_err());
+}
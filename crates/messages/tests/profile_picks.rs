@@ -0,0 +1,202 @@
+use glam::DVec3;
+use hex::FromHex;
+use metaverse_messages::{
+    avatar_classified_reply::{AvatarClassifiedReply, ClassifiedListing},
+    avatar_picks_reply::{AvatarPicksReply, PickListing},
+    classified_info_reply::ClassifiedInfoReply,
+    classified_info_request::ClassifiedInfoRequest,
+    event_info_reply::EventInfoReply,
+    event_info_request::EventInfoRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    pick_info_reply::PickInfoReply,
+    pick_info_request::PickInfoRequest,
+    ui_events::UiEventTypes,
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const PICK_ID: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+const CREATOR_ID: Uuid = uuid!("c1a5b6e2-51f3-4b0f-9d3b-7c61f0b0a2d4");
+const PARCEL_ID: Uuid = uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90");
+
+fn round_trip(packet: Packet) -> PacketType {
+    Packet::from_bytes(&packet.to_bytes()).unwrap().body
+}
+
+#[test]
+fn test_pick_info_reply() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a00ffff00b8",
+        "320dff8a7a594720a0f75a8df3698d9a", // agent
+        "5748deccf629461c9a36a35a221fe21f", // pick
+        "c1a5b6e251f34b0f9d3b7c61f0b0a2d4", // creator
+        "00",
+        "8a1f4b0c3f4e4d529c1a2b7e5d6f8a90",         // parcel
+        "0853616e64626f7800",                       // "Sandbox"
+        "12004120706c61636520746f206275696c640a00", // "A place to build\n"
+        "00000000000000000000000000000000",
+        "0d44656661756c74205573657200", // "Default User"
+        "0d57656c636f6d65204172656100", // "Welcome Area"
+        "06416865726e00",               // "Ahern"
+        "0000000004440f41",             // 256128.5
+        "0000000000420f41",             // 256064.0
+        "0000000000403940",             // 25.25
+        "02000000",
+        "01",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let pick = match &packet.body {
+        PacketType::PickInfoReply(pick) => pick.clone(),
+        _ => panic!("expected PickInfoReply"),
+    };
+    assert_eq!(
+        *pick,
+        PickInfoReply {
+            agent_id: AGENT_ID,
+            pick_id: PICK_ID,
+            creator_id: CREATOR_ID,
+            top_pick: false,
+            parcel_id: PARCEL_ID,
+            name: "Sandbox".to_string(),
+            description: "A place to build\n".to_string(),
+            snapshot_id: Uuid::nil(),
+            user: "Default User".to_string(),
+            original_name: "Welcome Area".to_string(),
+            sim_name: "Ahern".to_string(),
+            pos_global: DVec3::new(256128.5, 256064.0, 25.25),
+            sort_order: 2,
+            enabled: true,
+        }
+    );
+    assert_eq!(pick.to_bytes(), bytes[10..]);
+
+    let body = packet.body;
+    assert!(matches!(body.ui_event(), UiEventTypes::PickInfoEvent));
+    match UiEventTypes::PickInfoEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::PickInfoReply(decoded)) => assert_eq!(decoded, pick),
+        _ => panic!("failed to decode PickInfoEvent"),
+    }
+}
+
+#[test]
+fn test_avatar_picks_reply() {
+    let picks = AvatarPicksReply {
+        agent_id: AGENT_ID,
+        target_id: CREATOR_ID,
+        picks: vec![PickListing {
+            pick_id: PICK_ID,
+            pick_name: "Sandbox".to_string(),
+        }],
+    };
+    match round_trip(Packet::new_avatar_picks_reply(picks.clone())) {
+        PacketType::AvatarPicksReply(decoded) => assert_eq!(*decoded, picks),
+        _ => panic!("expected AvatarPicksReply"),
+    }
+    let body = PacketType::AvatarPicksReply(Box::new(picks));
+    assert!(matches!(body.ui_event(), UiEventTypes::AvatarPicksEvent));
+}
+
+#[test]
+fn test_avatar_classified_reply() {
+    let classifieds = AvatarClassifiedReply {
+        agent_id: AGENT_ID,
+        target_id: CREATOR_ID,
+        classifieds: vec![ClassifiedListing {
+            classified_id: PICK_ID,
+            name: "Land for rent".to_string(),
+        }],
+    };
+    match round_trip(Packet::new_avatar_classified_reply(classifieds.clone())) {
+        PacketType::AvatarClassifiedReply(decoded) => assert_eq!(*decoded, classifieds),
+        _ => panic!("expected AvatarClassifiedReply"),
+    }
+    let body = PacketType::AvatarClassifiedReply(Box::new(classifieds));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::AvatarClassifiedsEvent
+    ));
+}
+
+#[test]
+fn test_classified_info() {
+    let request = ClassifiedInfoRequest::new(PICK_ID);
+    match round_trip(Packet::new_classified_info_request(request.clone())) {
+        PacketType::ClassifiedInfoRequest(decoded) => assert_eq!(*decoded, request),
+        _ => panic!("expected ClassifiedInfoRequest"),
+    }
+
+    let reply = ClassifiedInfoReply {
+        agent_id: AGENT_ID,
+        classified_id: PICK_ID,
+        creator_id: CREATOR_ID,
+        creation_date: 1_700_000_000,
+        expiration_date: 1_700_604_800,
+        category: 4,
+        name: "Land for rent".to_string(),
+        description: "Quiet waterfront parcels".to_string(),
+        parcel_id: PARCEL_ID,
+        parent_estate: 1,
+        snapshot_id: Uuid::nil(),
+        sim_name: "Ahern".to_string(),
+        pos_global: DVec3::new(256128.5, 256064.0, 25.25),
+        parcel_name: "Waterfront".to_string(),
+        classified_flags: 0x04,
+        price_for_listing: 50,
+    };
+    match round_trip(Packet::new_classified_info_reply(reply.clone())) {
+        PacketType::ClassifiedInfoReply(decoded) => assert_eq!(*decoded, reply),
+        _ => panic!("expected ClassifiedInfoReply"),
+    }
+    let body = PacketType::ClassifiedInfoReply(Box::new(reply.clone()));
+    match UiEventTypes::ClassifiedInfoEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ClassifiedInfoReply(decoded)) => assert_eq!(*decoded, reply),
+        _ => panic!("failed to decode ClassifiedInfoEvent"),
+    }
+}
+
+#[test]
+fn test_event_info() {
+    let request = EventInfoRequest::new(4242);
+    assert_eq!(request.to_bytes()[32..], 4242u32.to_le_bytes());
+    match round_trip(Packet::new_event_info_request(request.clone())) {
+        PacketType::EventInfoRequest(decoded) => assert_eq!(*decoded, request),
+        _ => panic!("expected EventInfoRequest"),
+    }
+
+    let reply = EventInfoReply {
+        agent_id: AGENT_ID,
+        event_id: 4242,
+        creator: CREATOR_ID.to_string(),
+        name: "Live music".to_string(),
+        category: "Live Music".to_string(),
+        description: "Jazz by the water".to_string(),
+        date: "10/16/2026 19:00".to_string(),
+        date_utc: 1_792_188_000,
+        duration: 120,
+        cover: 1,
+        amount: 10,
+        sim_name: "Ahern".to_string(),
+        global_pos: DVec3::new(256128.5, 256064.0, 25.25),
+        event_flags: 0,
+    };
+    match round_trip(Packet::new_event_info_reply(reply.clone())) {
+        PacketType::EventInfoReply(decoded) => assert_eq!(*decoded, reply),
+        _ => panic!("expected EventInfoReply"),
+    }
+    let body = PacketType::EventInfoReply(Box::new(reply));
+    assert!(matches!(body.ui_event(), UiEventTypes::EventInfoEvent));
+}
+
+#[test]
+fn test_pick_info_request() {
+    let request = PickInfoRequest {
+        creator_id: CREATOR_ID,
+        pick_id: PICK_ID,
+    };
+    match round_trip(Packet::new_pick_info_request(request.clone())) {
+        PacketType::PickInfoRequest(decoded) => assert_eq!(*decoded, request),
+        _ => panic!("expected PickInfoRequest"),
+    }
+}
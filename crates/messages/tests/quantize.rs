@@ -0,0 +1,56 @@
+use metaverse_messages::utils::quantize::{
+    float_to_u16, float_to_u8, u16_quantization_error, u16_to_float, u8_to_float,
+};
+
+#[test]
+fn test_u16_quantization_error_bounds() {
+    for (lower, upper) in [(-128.0, 128.0), (-64.0, 64.0), (-1.0, 1.0), (0.0, 256.0)] {
+        let max_error = u16_quantization_error(lower, upper);
+        let mut value: f32 = lower;
+        while value <= upper {
+            let round_trip = u16_to_float(float_to_u16(value, lower, upper), lower, upper);
+            assert!(
+                (round_trip - value).abs() <= max_error,
+                "{} came back as {} over {}..{}",
+                value,
+                round_trip,
+                lower,
+                upper
+            );
+            value += (upper - lower) / 997.0;
+        }
+    }
+}
+
+#[test]
+fn test_u16_quantization_endpoints() {
+    assert_eq!(float_to_u16(-128.0, -128.0, 128.0), 0);
+    assert_eq!(float_to_u16(128.0, -128.0, 128.0), u16::MAX);
+    assert_eq!(u16_to_float(0, -128.0, 128.0), -128.0);
+    assert_eq!(u16_to_float(u16::MAX, -128.0, 128.0), 128.0);
+    // values outside of the range are clamped rather than wrapping
+    assert_eq!(float_to_u16(500.0, -128.0, 128.0), u16::MAX);
+    assert_eq!(float_to_u16(-500.0, -128.0, 128.0), 0);
+}
+
+#[test]
+fn test_quantized_zero_is_exact() {
+    // the midpoint of a symmetric range can't be represented, but zero must stay zero
+    assert_eq!(
+        u16_to_float(float_to_u16(0.0, -64.0, 64.0), -64.0, 64.0),
+        0.0
+    );
+    assert_eq!(u16_to_float(float_to_u16(0.0, -1.0, 1.0), -1.0, 1.0), 0.0);
+    assert_eq!(u8_to_float(float_to_u8(0.0, -1.0, 1.0), -1.0, 1.0), 0.0);
+}
+
+#[test]
+fn test_u8_quantization_error_bounds() {
+    let max_error = 2.0 / u8::MAX as f32;
+    let mut value: f32 = -1.0;
+    while value <= 1.0 {
+        let round_trip = u8_to_float(float_to_u8(value, -1.0, 1.0), -1.0, 1.0);
+        assert!((round_trip - value).abs() <= max_error);
+        value += 0.01;
+    }
+}
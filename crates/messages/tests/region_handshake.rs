@@ -1,10 +1,86 @@
 use log::LevelFilter;
-use metaverse_messages::region_handshake::RegionHandshake;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    region_handshake::RegionHandshake,
+    region_handshake_reply::{AgentData, RegionHandshakeReply, ReplyRegionInfo},
+    ui_events::UiEventTypes,
+    utils::agent_access::AgentAccess,
+};
+use uuid::uuid;
 
 #[test]
 fn test_region_handshake() {
     init_logger();
-    let test_packet: Vec<u8> = vec![
+    let test_packet = test_packet();
+    // the capture starts with the last byte of the message id
+    let handshake = RegionHandshake::from_bytes(&test_packet[1..]).unwrap();
+    assert_eq!(handshake.region_info.sim_access, AgentAccess::PG);
+    assert_eq!(handshake.region_info.sim_name, "Lbsa Plaza");
+    assert_eq!(handshake.region_info.water_height, 20.0);
+    assert_eq!(handshake.region_info.terrain_height_range_3, 10.0);
+    assert_eq!(
+        handshake.region_info_2.region_id,
+        uuid!("9101b44d-922c-4cc6-94b5-6d176e392dc4")
+    );
+    assert_eq!(handshake.region_info_3.cpu_class_id, 9);
+    assert_eq!(handshake.region_info_3.product_name, "OSgrid Plaza");
+    assert_eq!(handshake.region_info_4.len(), 1);
+    assert_eq!(handshake.region_info_4[0].region_protocols, 1 << 63);
+
+    assert_eq!(handshake.to_bytes(), test_packet[1..]);
+}
+
+#[test]
+fn test_region_handshake_packet() {
+    let handshake = RegionHandshake::from_bytes(&test_packet()[1..]).unwrap();
+    let packet = Packet::new_region_handshake(handshake);
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    assert!(matches!(parsed.body, PacketType::RegionHandshake(_)));
+
+    // the UI receives the handshake so it can show the region name
+    let event = parsed.body.ui_event();
+    assert!(matches!(event, UiEventTypes::RegionHandshakeEvent));
+    match event.packet_type_from_bytes(&parsed.body.ui_event_bytes()) {
+        Some(PacketType::RegionHandshake(handshake)) => {
+            assert_eq!(handshake.region_info.sim_name, "Lbsa Plaza")
+        }
+        _ => panic!("failed to decode RegionHandshakeEvent"),
+    }
+}
+
+#[test]
+fn test_region_handshake_truncated() {
+    let test_packet = test_packet();
+    assert!(RegionHandshake::from_bytes(&test_packet[1..100]).is_err());
+    assert!(RegionHandshake::from_bytes(&[]).is_err());
+}
+
+#[test]
+fn test_region_handshake_reply() {
+    let agent_id = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+    let session_id = uuid!("224ecaea-372d-4d31-8b64-4805966418e5");
+    let packet = Packet::new_region_handshake_reply(RegionHandshakeReply {
+        agent_data: AgentData {
+            agent_id,
+            session_id,
+        },
+        region_info: ReplyRegionInfo { flags: 5 },
+    });
+    assert!(packet.header.reliable);
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    match parsed.body {
+        PacketType::RegionHandshakeReply(reply) => {
+            assert_eq!(reply.agent_data.agent_id, agent_id);
+            assert_eq!(reply.agent_data.session_id, session_id);
+            assert_eq!(reply.region_info.flags, 5);
+        }
+        body => panic!("expected RegionHandshakeReply, got {:?}", body),
+    }
+}
+
+fn test_packet() -> Vec<u8> {
+    vec![
         148, 198, 128, 64, 16, 13, // Sim Access, set to 13 which corresponds to PG
         11, // length prefix
         76, 98, 115, 97, 32, 80, 108, 97, 122, 97, 0,
@@ -24,11 +100,7 @@ fn test_region_handshake() {
         79, 83, 103, 114, 105, 100, 32, 80, 108, 97, 122, 97, 0,
         //  O   S   g    r    i    d    ""  P   l    a   z    a   \0
         1, 198, 128, 64, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128,
-    ];
-    match RegionHandshake::from_bytes(&test_packet) {
-        Ok(packet) => println!("Packet created successfully: {:?}", packet),
-        Err(e) => eprintln!("Error creating packet: {}", e),
-    }
+    ]
 }
 
 fn init_logger() {
@@ -0,0 +1,111 @@
+use hex::FromHex;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    region_info::{RegionInfo, RegionInfoExtended},
+    region_info_request::RegionInfoRequest,
+    ui_events::UiEventTypes,
+    utils::agent_access::AgentAccess,
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const SESSION_ID: Uuid = uuid!("0c8a2f6b-3f1d-4d6e-9c3a-1b2e4f5a6d7c");
+
+// the body of a RegionInfo as OpenSimulator sends it for a default region, with the RegionInfo2
+// and RegionInfo3 blocks
+const OPENSIM_FIXTURE: &str = "320dff8a7a594720a0f75a8df3698d9a0c8a2f6b3f1d4d6e9c3a1b2e4f5a6d7c0f44656661756c7420526567696f6e006500000001000000020000040d280000803f0000803f0000a0410000c8420000c8c2010000000000000000000000010000000000094d61696e6c616e64002800000064000000983a0000010200000400000000";
+
+// the same RegionInfo from an older simulator, which ends after the first block
+fn legacy_fixture() -> Vec<u8> {
+    let mut bytes = Vec::from_hex(OPENSIM_FIXTURE).unwrap();
+    bytes.truncate(99);
+    bytes
+}
+
+#[test]
+fn test_region_info_from_opensim() {
+    let bytes = Vec::from_hex(OPENSIM_FIXTURE).unwrap();
+    let region = RegionInfo::from_bytes(&bytes).unwrap();
+    assert_eq!(region.agent_id, AGENT_ID);
+    assert_eq!(region.session_id, SESSION_ID);
+    assert_eq!(region.sim_name, "Default Region");
+    assert_eq!(region.estate_id, 101);
+    assert_eq!(region.parent_estate_id, 1);
+    assert_eq!(region.region_flags, 0x4000002);
+    assert_eq!(region.sim_access, AgentAccess::PG);
+    assert_eq!(region.max_agents, 40);
+    assert_eq!(region.object_bonus_factor, 1.0);
+    assert_eq!(region.water_height, 20.0);
+    assert_eq!(region.terrain_raise_limit, 100.0);
+    assert_eq!(region.terrain_lower_limit, -100.0);
+    assert_eq!(region.price_per_meter, 1);
+    assert!(region.use_estate_sun);
+    assert_eq!(
+        region.extended,
+        Some(RegionInfoExtended {
+            product_sku: String::new(),
+            product_name: "Mainland".to_string(),
+            max_agents: 40,
+            hard_max_agents: 100,
+            hard_max_objects: 15000,
+        })
+    );
+    assert_eq!(region.region_flags_extended, vec![0x4000002]);
+    assert_eq!(region.to_bytes(), bytes);
+}
+
+#[test]
+fn test_region_info_from_older_simulator() {
+    let bytes = legacy_fixture();
+    let region = RegionInfo::from_bytes(&bytes).unwrap();
+    assert_eq!(region.sim_name, "Default Region");
+    assert_eq!(region.max_agents, 40);
+    assert_eq!(region.water_height, 20.0);
+    assert!(region.extended.is_none());
+    assert!(region.region_flags_extended.is_empty());
+    assert_eq!(region.to_bytes(), bytes);
+
+    // a RegionInfo2 block cut short is an error, not a legacy packet
+    let mut truncated = Vec::from_hex(OPENSIM_FIXTURE).unwrap();
+    truncated.truncate(110);
+    assert!(RegionInfo::from_bytes(&truncated).is_err());
+}
+
+#[test]
+fn test_region_info_packet() {
+    let region = RegionInfo::from_bytes(&legacy_fixture()).unwrap();
+    match Packet::from_bytes(&Packet::new_region_info(region.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::RegionInfo(parsed) => assert_eq!(*parsed, region),
+        _ => panic!("expected RegionInfo"),
+    }
+
+    let body = PacketType::RegionInfo(Box::new(region.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::RegionInfoEvent));
+    match UiEventTypes::RegionInfoEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::RegionInfo(parsed)) => assert_eq!(*parsed, region),
+        _ => panic!("failed to decode RegionInfoEvent"),
+    }
+}
+
+#[test]
+fn test_region_info_request() {
+    let request = RegionInfoRequest {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(RegionInfoRequest::from_bytes(&bytes).unwrap(), request);
+    assert_eq!(RegionInfoRequest::new().agent_id, Uuid::nil());
+    match Packet::from_bytes(&Packet::new_region_info_request(request.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::RegionInfoRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected RegionInfoRequest"),
+    }
+}
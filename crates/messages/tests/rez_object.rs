@@ -0,0 +1,120 @@
+use glam::Vec3;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    rez_object::RezObject,
+    utils::{
+        asset_type::AssetType, inventory_item::InventoryItem, permissions::Permissions,
+        sale_type::SaleType,
+    },
+};
+use uuid::Uuid;
+
+fn item() -> InventoryItem {
+    let full = Permissions::from_bits(
+        Permissions::TRANSFER | Permissions::MODIFY | Permissions::COPY | Permissions::MOVE,
+    );
+    InventoryItem {
+        item_id: Uuid::from_bytes([0x11; 16]),
+        folder_id: Uuid::from_bytes([0x12; 16]),
+        creator_id: Uuid::from_bytes([0x13; 16]),
+        owner_id: Uuid::from_bytes([0x14; 16]),
+        group_id: Uuid::nil(),
+        base_mask: full,
+        owner_mask: full,
+        group_mask: Permissions::default(),
+        everyone_mask: Permissions::default(),
+        next_owner_mask: Permissions::from_bits(Permissions::MODIFY | Permissions::TRANSFER),
+        group_owned: false,
+        asset_id: Uuid::nil(),
+        asset_type: AssetType::Object,
+        inventory_type: 6,
+        flags: 0,
+        sale_type: SaleType::NotForSale,
+        sale_price: 10,
+        name: "Box".to_string(),
+        description: String::new(),
+        creation_date: 1_700_000_000,
+    }
+}
+
+// a RezObject for a copyable box, rezzed at 128, 128, 25
+fn golden_rez() -> Vec<u8> {
+    let position = [
+        0x00, 0x00, 0x00, 0x43, 0x00, 0x00, 0x00, 0x43, 0x00, 0x00, 0xC8, 0x41,
+    ];
+    let mut bytes = Vec::new();
+    // AgentData: AgentID, SessionID, GroupID
+    bytes.extend_from_slice(&[0x01; 16]);
+    bytes.extend_from_slice(&[0x02; 16]);
+    bytes.extend_from_slice(&[0x00; 16]);
+    // RezData: FromTaskID, BypassRaycast, RayStart, RayEnd, RayTargetID
+    bytes.extend_from_slice(&[0x00; 16]);
+    bytes.push(0x01);
+    bytes.extend_from_slice(&position);
+    bytes.extend_from_slice(&position);
+    bytes.extend_from_slice(&[0x00; 16]);
+    // RayEndIsIntersection, RezSelected, RemoveItem, ItemFlags, GroupMask, EveryoneMask
+    bytes.extend_from_slice(&[0x00; 3 + 4 + 4 + 4]);
+    // NextOwnerMask: modify and transfer
+    bytes.extend_from_slice(&[0x00, 0x60, 0x00, 0x00]);
+    // InventoryData: ItemID, FolderID, CreatorID, OwnerID, GroupID
+    bytes.extend_from_slice(&[0x11; 16]);
+    bytes.extend_from_slice(&[0x12; 16]);
+    bytes.extend_from_slice(&[0x13; 16]);
+    bytes.extend_from_slice(&[0x14; 16]);
+    bytes.extend_from_slice(&[0x00; 16]);
+    // BaseMask, OwnerMask: transfer, modify, copy and move
+    bytes.extend_from_slice(&[0x00, 0xE0, 0x08, 0x00, 0x00, 0xE0, 0x08, 0x00]);
+    // GroupMask, EveryoneMask
+    bytes.extend_from_slice(&[0x00; 8]);
+    // NextOwnerMask
+    bytes.extend_from_slice(&[0x00, 0x60, 0x00, 0x00]);
+    // GroupOwned, TransactionID
+    bytes.push(0x00);
+    bytes.extend_from_slice(&[0x21; 16]);
+    // Type, InvType, Flags, SaleType, SalePrice
+    bytes.extend_from_slice(&[0x06, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    bytes.extend_from_slice(&[0x0A, 0x00, 0x00, 0x00]);
+    // Name, Description
+    bytes.extend_from_slice(&[0x04, b'B', b'o', b'x', 0x00, 0x00]);
+    // CreationDate, CRC
+    bytes.extend_from_slice(&[0x00, 0xF1, 0x53, 0x65, 0x00, 0x00, 0x00, 0x00]);
+    bytes
+}
+
+#[test]
+fn test_rez_object_matches_golden_bytes() {
+    let mut rez = RezObject::new(item(), Vec3::new(128.0, 128.0, 25.0));
+    rez.agent_id = Uuid::from_bytes([0x01; 16]);
+    rez.session_id = Uuid::from_bytes([0x02; 16]);
+    rez.transaction_id = Uuid::from_bytes([0x21; 16]);
+
+    let golden = golden_rez();
+    assert_eq!(rez.to_bytes(), golden);
+    assert_eq!(RezObject::from_bytes(&golden).unwrap(), rez);
+}
+
+#[test]
+fn test_rez_object_round_trip() {
+    let mut item = item();
+    item.owner_mask.copy = false;
+    item.description = "a box".to_string();
+    item.group_owned = true;
+    let rez = RezObject::new(item, Vec3::new(10.0, 20.0, 30.0));
+    // items that can't be copied leave inventory when they are rezzed
+    assert!(rez.rez_data.remove_item);
+    assert!(rez.rez_data.bypass_raycast);
+
+    let packet = Packet::from_bytes(&Packet::new_rez_object(rez.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::RezObject(parsed) => assert_eq!(*parsed, rez),
+        _ => panic!("expected RezObject"),
+    }
+}
+
+#[test]
+fn test_truncated_rez_object_is_an_error() {
+    let bytes = golden_rez();
+    assert!(RezObject::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
@@ -0,0 +1,81 @@
+use hex::FromHex;
+use metaverse_messages::{
+    agent_update::ControlFlags,
+    force_script_control_release::ForceScriptControlRelease,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    script_control_change::{ScriptControl, ScriptControlChange},
+    script_controls::{CapturedControls, ScriptControls},
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_script_control_change() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a00ffff00bd",
+        "02",
+        "01",       // take
+        "03000000", // AT_POS | AT_NEG
+        "00",       // not passed on
+        "00",       // release
+        "00000010", // LBUTTON_DOWN
+        "01",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let change = match &packet.body {
+        PacketType::ScriptControlChange(change) => change.clone(),
+        _ => panic!("expected ScriptControlChange"),
+    };
+    assert_eq!(
+        *change,
+        ScriptControlChange {
+            changes: vec![
+                ScriptControl {
+                    take_controls: true,
+                    controls: ControlFlags::AT_POS | ControlFlags::AT_NEG,
+                    pass_to_agent: false,
+                },
+                ScriptControl {
+                    take_controls: false,
+                    controls: ControlFlags::LBUTTON_DOWN,
+                    pass_to_agent: true,
+                },
+            ],
+        }
+    );
+    assert_eq!(change.to_bytes(), bytes[10..]);
+}
+
+#[test]
+fn test_force_script_control_release() {
+    let release = ForceScriptControlRelease::new();
+    let body = Packet::from_bytes(&Packet::new_force_script_control_release(release).to_bytes())
+        .unwrap()
+        .body;
+    match body {
+        PacketType::ForceScriptControlRelease(decoded) => {
+            assert!(decoded.agent_id.is_nil());
+            assert!(decoded.session_id.is_nil());
+        }
+        _ => panic!("expected ForceScriptControlRelease"),
+    }
+}
+
+#[test]
+fn test_script_controls_event() {
+    let controls = ScriptControls {
+        objects: vec![CapturedControls {
+            local_id: 2002,
+            taken: ControlFlags::AT_POS | ControlFlags::ML_LBUTTON_DOWN,
+            passed_to_agent: ControlFlags::ML_LBUTTON_DOWN,
+        }],
+        blocked: ControlFlags::AT_POS,
+    };
+    let body = PacketType::ScriptControls(Box::new(controls.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::ScriptControlsEvent));
+    match UiEventTypes::ScriptControlsEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ScriptControls(decoded)) => assert_eq!(*decoded, controls),
+        _ => panic!("failed to decode ScriptControlsEvent"),
+    }
+}
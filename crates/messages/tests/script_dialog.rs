@@ -0,0 +1,95 @@
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    script_dialog::{ScriptDialog, MAX_DIALOG_BUTTONS},
+    script_dialog_reply::ScriptDialogReply,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn dialog(buttons: &[&str]) -> ScriptDialog {
+    ScriptDialog {
+        object_id: Uuid::new_v4(),
+        first_name: "Script".to_string(),
+        last_name: "Owner".to_string(),
+        object_name: "Vendor".to_string(),
+        message: "Pick a color".to_string(),
+        chat_channel: -4242,
+        image_id: Uuid::nil(),
+        buttons: buttons.iter().map(|button| button.to_string()).collect(),
+        owners: vec![Uuid::new_v4()],
+    }
+}
+
+#[test]
+fn test_script_dialog_round_trip() {
+    let dialog = dialog(&["Red", "Green", "Blue"]);
+    let packet = Packet::from_bytes(&Packet::new_script_dialog(dialog.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ScriptDialog(parsed) => assert_eq!(*parsed, dialog),
+        _ => panic!("expected ScriptDialog"),
+    }
+}
+
+#[test]
+fn test_script_dialog_without_owner_data() {
+    let mut dialog = dialog(&["OK"]);
+    dialog.owners.clear();
+    // older simulators leave off the OwnerData block, count and all
+    let mut bytes = dialog.to_bytes();
+    bytes.pop();
+    let parsed = ScriptDialog::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, dialog);
+}
+
+#[test]
+fn test_script_dialog_button_limit() {
+    let labels: Vec<String> = (0..15).map(|i| i.to_string()).collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let parsed = ScriptDialog::from_bytes(&dialog(&labels).to_bytes()).unwrap();
+    assert_eq!(parsed.buttons.len(), MAX_DIALOG_BUTTONS);
+}
+
+#[test]
+fn test_script_dialog_event() {
+    let dialog = dialog(&["Yes", "No"]);
+    let body = PacketType::ScriptDialog(Box::new(dialog.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::ScriptDialogEvent));
+    match UiEventTypes::ScriptDialogEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ScriptDialog(parsed)) => assert_eq!(*parsed, dialog),
+        _ => panic!("expected ScriptDialog"),
+    }
+}
+
+#[test]
+fn test_script_dialog_reply() {
+    let dialog = dialog(&["Yes", "No"]);
+    let reply = dialog.reply(1).unwrap();
+    assert_eq!(reply.object_id, dialog.object_id);
+    assert_eq!(reply.chat_channel, -4242);
+    assert_eq!(reply.button_index, 1);
+    assert_eq!(reply.button_label, "No");
+    assert!(!reply.is_ignore());
+    assert!(dialog.reply(2).is_none());
+
+    let packet =
+        Packet::from_bytes(&Packet::new_script_dialog_reply(reply.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ScriptDialogReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected ScriptDialogReply"),
+    }
+}
+
+#[test]
+fn test_script_dialog_ignore() {
+    let dialog = dialog(&["Yes", "No"]);
+    let reply = dialog.ignore();
+    assert!(reply.is_ignore());
+    assert_eq!(reply.button_index, ScriptDialogReply::IGNORE_BUTTON_INDEX);
+    assert_eq!(reply.button_label, ScriptDialogReply::IGNORE_BUTTON_LABEL);
+    assert_eq!(reply.chat_channel, dialog.chat_channel);
+    assert_eq!(
+        reply,
+        ScriptDialogReply::ignore(dialog.object_id, dialog.chat_channel)
+    );
+}
@@ -0,0 +1,136 @@
+use metaverse_messages::{
+    get_script_running::GetScriptRunning,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    rez_script::RezScript,
+    script_reset::ScriptReset,
+    script_running_reply::ScriptRunningReply,
+    script_running_status::ScriptRunningStatus,
+    set_script_running::SetScriptRunning,
+    ui_events::UiEventTypes,
+    utils::{
+        asset_type::AssetType, inventory_item::InventoryItem, permissions::Permissions,
+        sale_type::SaleType,
+    },
+};
+use uuid::Uuid;
+
+fn script() -> InventoryItem {
+    let full = Permissions::from_bits(
+        Permissions::TRANSFER | Permissions::MODIFY | Permissions::COPY | Permissions::MOVE,
+    );
+    InventoryItem {
+        item_id: Uuid::from_bytes([0x11; 16]),
+        folder_id: Uuid::from_bytes([0x12; 16]),
+        creator_id: Uuid::from_bytes([0x13; 16]),
+        owner_id: Uuid::from_bytes([0x13; 16]),
+        group_id: Uuid::nil(),
+        base_mask: full,
+        owner_mask: full,
+        group_mask: Permissions::default(),
+        everyone_mask: Permissions::default(),
+        next_owner_mask: full,
+        group_owned: false,
+        asset_id: Uuid::from_bytes([0x15; 16]),
+        asset_type: AssetType::LslText,
+        inventory_type: 10,
+        flags: 0,
+        sale_type: SaleType::NotForSale,
+        sale_price: 0,
+        name: "Spinner".to_string(),
+        description: String::new(),
+        creation_date: 1_700_000_000,
+    }
+}
+
+#[test]
+fn test_rez_script_uses_item_data_block() {
+    let item = script();
+    let rez = RezScript::new(42, &item);
+    assert!(rez.enabled);
+    assert_eq!(rez.item.crc, item.crc());
+    // the TransactionID goes where the asset id is, and is nil for a script from inventory
+    assert!(rez.item.item.asset_id.is_nil());
+    // so the checksum, which covers the asset, doesn't check out against the block
+    assert!(!rez.item.crc_valid);
+
+    let bytes = rez.to_bytes();
+    assert_eq!(&bytes[48..52], &42u32.to_le_bytes());
+    assert_eq!(bytes[52], 1);
+    assert_eq!(&bytes[53..69], item.item_id.as_bytes());
+    // ItemID, FolderID, CreatorID, OwnerID, GroupID, five masks and GroupOwned come first
+    assert_eq!(&bytes[53 + 101..53 + 117], Uuid::nil().as_bytes());
+    // the item block ends with the name and its terminator, description, creation date and CRC
+    assert_eq!(bytes.len(), 53 + 128 + 1 + "Spinner".len() + 1 + 1 + 8);
+
+    match Packet::from_bytes(&Packet::new_rez_script(rez.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::RezScript(parsed) => assert_eq!(*parsed, rez),
+        _ => panic!("expected RezScript"),
+    }
+}
+
+#[test]
+fn test_script_running_round_trip() {
+    let object_id = Uuid::from_bytes([0x0B; 16]);
+    let item_id = Uuid::from_bytes([0x11; 16]);
+
+    let get = GetScriptRunning { object_id, item_id };
+    assert_eq!(get.to_bytes().len(), 32);
+    match Packet::from_bytes(&Packet::new_get_script_running(get.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::GetScriptRunning(parsed) => assert_eq!(*parsed, get),
+        _ => panic!("expected GetScriptRunning"),
+    }
+
+    let reply = ScriptRunningReply {
+        object_id,
+        item_id,
+        running: true,
+    };
+    let parsed = ScriptRunningReply::from_bytes(&reply.to_bytes()).unwrap();
+    assert_eq!(parsed, reply);
+
+    let set = SetScriptRunning::new(object_id, item_id, false);
+    let bytes = set.to_bytes();
+    assert_eq!(bytes.len(), 65);
+    assert_eq!(bytes[64], 0);
+    match Packet::from_bytes(&Packet::new_set_script_running(set.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::SetScriptRunning(parsed) => assert_eq!(*parsed, set),
+        _ => panic!("expected SetScriptRunning"),
+    }
+
+    let reset = ScriptReset::new(object_id, item_id);
+    assert_eq!(&reset.to_bytes()[32..48], object_id.as_bytes());
+    match Packet::from_bytes(&Packet::new_script_reset(reset.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::ScriptReset(parsed) => assert_eq!(*parsed, reset),
+        _ => panic!("expected ScriptReset"),
+    }
+}
+
+#[test]
+fn test_script_running_status_event() {
+    let status = ScriptRunningStatus {
+        object_id: Uuid::from_bytes([0x0B; 16]),
+        item_id: Uuid::from_bytes([0x11; 16]),
+        running: None,
+        reason: Some("timed out waiting for the simulator".to_string()),
+    };
+    let body = PacketType::ScriptRunningStatus(Box::new(status.clone()));
+    let event = body.ui_event();
+    assert!(matches!(event, UiEventTypes::ScriptRunningStatusEvent));
+    match event.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ScriptRunningStatus(parsed)) => assert_eq!(*parsed, status),
+        _ => panic!("expected ScriptRunningStatus"),
+    }
+}
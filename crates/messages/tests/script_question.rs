@@ -0,0 +1,105 @@
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    revoke_permissions::RevokePermissions,
+    script_answer_yes::ScriptAnswerYes,
+    script_question::ScriptQuestion,
+    ui_events::UiEventTypes,
+    utils::script_permissions::ScriptPermissions,
+};
+use uuid::Uuid;
+
+fn question(experience_id: Option<Uuid>) -> ScriptQuestion {
+    ScriptQuestion {
+        task_id: Uuid::new_v4(),
+        item_id: Uuid::new_v4(),
+        object_name: "Dance Ball".to_string(),
+        object_owner: "Club Owner".to_string(),
+        questions: ScriptPermissions::TRIGGER_ANIMATION | ScriptPermissions::TAKE_CONTROLS,
+        experience_id,
+    }
+}
+
+#[test]
+fn test_script_question_round_trip() {
+    for question in [question(None), question(Some(Uuid::new_v4()))] {
+        let packet =
+            Packet::from_bytes(&Packet::new_script_question(question.clone()).to_bytes()).unwrap();
+        match packet.body {
+            PacketType::ScriptQuestion(parsed) => assert_eq!(*parsed, question),
+            _ => panic!("expected ScriptQuestion"),
+        }
+    }
+}
+
+#[test]
+fn test_script_question_event() {
+    let question = question(None);
+    let body = PacketType::ScriptQuestion(Box::new(question.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::ScriptQuestionEvent));
+    match UiEventTypes::ScriptQuestionEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ScriptQuestion(parsed)) => assert_eq!(*parsed, question),
+        _ => panic!("expected ScriptQuestion"),
+    }
+}
+
+#[test]
+fn test_script_answer_yes_round_trip() {
+    let question = question(None);
+    let answer = question.answer(ScriptPermissions::TRIGGER_ANIMATION | ScriptPermissions::DEBIT);
+    // permissions that weren't asked for aren't granted
+    assert_eq!(answer.questions, ScriptPermissions::TRIGGER_ANIMATION);
+    assert_eq!(answer.task_id, question.task_id);
+    assert_eq!(answer.item_id, question.item_id);
+
+    let bytes = answer.to_bytes();
+    assert_eq!(bytes.len(), 68);
+    assert_eq!(&bytes[64..], &[0x10, 0, 0, 0]);
+    let packet =
+        Packet::from_bytes(&Packet::new_script_answer_yes(answer.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ScriptAnswerYes(parsed) => assert_eq!(*parsed, answer),
+        _ => panic!("expected ScriptAnswerYes"),
+    }
+}
+
+#[test]
+fn test_script_answer_zero_denies() {
+    let question = question(None);
+    let answer = question.answer(ScriptPermissions::empty());
+    assert_eq!(
+        answer,
+        ScriptAnswerYes::deny(question.task_id, question.item_id)
+    );
+    let bytes = answer.to_bytes();
+    assert_eq!(&bytes[64..], &[0, 0, 0, 0]);
+    let parsed = ScriptAnswerYes::from_bytes(&bytes).unwrap();
+    assert!(parsed.questions.is_empty());
+}
+
+#[test]
+fn test_revoke_permissions_round_trip() {
+    let revoke = RevokePermissions {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        object_id: Uuid::new_v4(),
+        object_permissions: ScriptPermissions::TRIGGER_ANIMATION,
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_revoke_permissions(revoke.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::RevokePermissions(parsed) => assert_eq!(*parsed, revoke),
+        _ => panic!("expected RevokePermissions"),
+    }
+}
+
+#[test]
+fn test_unknown_permissions_are_kept() {
+    let mut question = question(None);
+    question.questions = ScriptPermissions::from_bits_retain(0x8000_0000 | 0x10);
+    let parsed = ScriptQuestion::from_bytes(&question.to_bytes()).unwrap();
+    assert_eq!(parsed.questions.bits(), 0x8000_0010);
+    let json = serde_json::to_vec(&question).unwrap();
+    let parsed: ScriptQuestion = serde_json::from_slice(&json).unwrap();
+    assert_eq!(parsed.questions.bits(), 0x8000_0010);
+}
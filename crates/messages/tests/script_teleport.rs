@@ -0,0 +1,72 @@
+use glam::Vec3;
+use hex::FromHex;
+use metaverse_messages::{
+    accept_script_teleport::AcceptScriptTeleport,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    script_teleport_request::ScriptTeleportRequest,
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_script_teleport_request() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a00ffff00c3",
+        "0f4c616e646d61726b20476976657200", // "Landmark Giver"
+        "06416865726e00",                   // "Ahern"
+        "00000043000080420000c841",         // position 128, 64, 25
+        "0000803f0000000000000000",         // look at 1, 0, 0
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let request = match &packet.body {
+        PacketType::ScriptTeleportRequest(request) => request.clone(),
+        _ => panic!("expected ScriptTeleportRequest"),
+    };
+    assert_eq!(
+        *request,
+        ScriptTeleportRequest {
+            object_name: "Landmark Giver".to_string(),
+            sim_name: "Ahern".to_string(),
+            sim_position: Vec3::new(128.0, 64.0, 25.0),
+            look_at: Vec3::X,
+        }
+    );
+    assert_eq!(request.to_bytes(), bytes[10..]);
+
+    let body = packet.body;
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::ScriptTeleportOfferEvent
+    ));
+    match UiEventTypes::ScriptTeleportOfferEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::ScriptTeleportRequest(decoded)) => assert_eq!(decoded, request),
+        _ => panic!("failed to decode ScriptTeleportOfferEvent"),
+    }
+}
+
+#[test]
+fn test_accept_script_teleport() {
+    let offer = ScriptTeleportRequest {
+        object_name: "Landmark Giver".to_string(),
+        sim_name: "Ahern".to_string(),
+        sim_position: Vec3::new(128.0, 64.0, 25.0),
+        look_at: Vec3::X,
+    };
+    let accept = offer.accept();
+    assert_eq!(
+        accept,
+        AcceptScriptTeleport {
+            sim_name: "Ahern".to_string(),
+            sim_position: Vec3::new(128.0, 64.0, 25.0),
+            look_at: Vec3::X,
+        }
+    );
+    let body = Packet::from_bytes(&Packet::new_accept_script_teleport(accept.clone()).to_bytes())
+        .unwrap()
+        .body;
+    match body {
+        PacketType::AcceptScriptTeleport(decoded) => assert_eq!(*decoded, accept),
+        _ => panic!("expected AcceptScriptTeleport"),
+    }
+}
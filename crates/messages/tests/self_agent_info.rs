@@ -0,0 +1,72 @@
+use hex::FromHex;
+use metaverse_messages::{
+    agent_data_update::AgentDataUpdate,
+    agent_data_update_request::AgentDataUpdateRequest,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    query_self_agent_info::QuerySelfAgentInfo,
+    self_agent_info::SelfAgentInfo,
+    ui_events::UiEventTypes,
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const SESSION_ID: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+const GROUP_ID: Uuid = uuid!("c1a5b6e2-51f3-4b0f-9d3b-7c61f0b0a2d4");
+
+#[test]
+fn test_agent_data_update_request() {
+    let request = AgentDataUpdateRequest {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+    };
+    let bytes = Packet::new_agent_data_update_request(request.clone()).to_bytes();
+    assert_eq!(
+        bytes[6..10],
+        Vec::from_hex("ffff0182").unwrap(),
+        "AgentDataUpdateRequest is Low 386"
+    );
+    assert_eq!(
+        request.to_bytes(),
+        Vec::from_hex("320dff8a7a594720a0f75a8df3698d9a5748deccf629461c9a36a35a221fe21f").unwrap()
+    );
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::AgentDataUpdateRequest(decoded) => assert_eq!(*decoded, request),
+        _ => panic!("expected AgentDataUpdateRequest"),
+    }
+}
+
+#[test]
+fn test_query_self_agent_info() {
+    let bytes = Packet::new_query_self_agent_info(QuerySelfAgentInfo::default()).to_bytes();
+    assert!(matches!(
+        Packet::from_bytes(&bytes).unwrap().body,
+        PacketType::QuerySelfAgentInfo(_)
+    ));
+}
+
+#[test]
+fn test_self_agent_info() {
+    let mut info = SelfAgentInfo::new(AGENT_ID, "Default".to_string(), "User".to_string());
+    assert_eq!(info.active_group_id, Uuid::nil());
+    info.update(&AgentDataUpdate {
+        agent_id: AGENT_ID,
+        first_name: "Default".to_string(),
+        last_name: "Resident".to_string(),
+        group_title: "Greeter".to_string(),
+        active_group_id: GROUP_ID,
+        group_powers: 0x10,
+        group_name: "Welcome Island".to_string(),
+    });
+    assert_eq!(info.last_name, "Resident");
+    assert_eq!(info.group_title, "Greeter");
+    assert_eq!(info.active_group_id, GROUP_ID);
+    assert_eq!(info.group_powers, 0x10);
+
+    let body = PacketType::SelfAgentInfo(Box::new(info.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::SelfAgentInfoEvent));
+    match UiEventTypes::SelfAgentInfoEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::SelfAgentInfo(decoded)) => assert_eq!(*decoded, info),
+        _ => panic!("failed to decode SelfAgentInfoEvent"),
+    }
+}
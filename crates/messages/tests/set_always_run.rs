@@ -0,0 +1,47 @@
+use hex::FromHex;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    set_always_run::SetAlwaysRun,
+};
+use uuid::uuid;
+
+#[test]
+fn test_set_always_run() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a00ffff0058",
+        "8a1f4b0c3f4e4d529c1a2b7e5d6f8a90",
+        "0d9e7c6b5a4f4e3d8c2b1a0f9e8d7c6b",
+        "01",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let set_always_run = match &packet.body {
+        PacketType::SetAlwaysRun(set_always_run) => set_always_run.clone(),
+        _ => panic!("expected SetAlwaysRun"),
+    };
+    assert_eq!(
+        *set_always_run,
+        SetAlwaysRun {
+            agent_id: uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90"),
+            session_id: uuid!("0d9e7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b"),
+            always_run: true,
+        }
+    );
+    assert_eq!(set_always_run.to_bytes(), bytes[10..]);
+}
+
+#[test]
+fn test_set_always_run_off() {
+    let off = SetAlwaysRun::new(false);
+    assert!(off.agent_id.is_nil());
+    assert_eq!(off.to_bytes().len(), 33);
+    assert_eq!(SetAlwaysRun::from_bytes(&off.to_bytes()).unwrap(), off);
+
+    let packet = Packet::new_set_always_run(off.clone());
+    assert_eq!(packet.header.id, 88);
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::SetAlwaysRun(parsed) => assert_eq!(*parsed, off),
+        _ => panic!("expected SetAlwaysRun"),
+    }
+}
@@ -0,0 +1,110 @@
+use hex::FromHex;
+use metaverse_messages::{
+    agent_died::AgentDied,
+    health_message::HealthMessage,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    sim_stats::{SimStat, SimStatType, SimStats},
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_sim_stats() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a00ffff008c",
+        "e8030000", // region x 1000
+        "e9030000", // region y 1001
+        "00000000",
+        "983a0000", // object capacity 15000
+        "03",
+        "00000000", // time dilation
+        "0000803f", // 1.0
+        "0d000000", // agents
+        "00004040", // 3.0
+        "c8000000", // a stat this client doesn't know
+        "0000c842", // 100.0
+        "39300000", // pid
+        "01",
+        "0100000001000000",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let stats = match &packet.body {
+        PacketType::SimStats(stats) => stats.clone(),
+        _ => panic!("expected SimStats"),
+    };
+    assert_eq!(
+        *stats,
+        SimStats {
+            region_x: 1000,
+            region_y: 1001,
+            region_flags: 0,
+            object_capacity: 15000,
+            stats: vec![
+                SimStat {
+                    stat: SimStatType::TimeDilation,
+                    value: 1.0,
+                },
+                SimStat {
+                    stat: SimStatType::Agents,
+                    value: 3.0,
+                },
+                SimStat {
+                    stat: SimStatType::Unknown(200),
+                    value: 100.0,
+                },
+            ],
+            pid: 12345,
+            region_flags_extended: vec![0x0000_0001_0000_0001],
+        }
+    );
+    assert_eq!(stats.get(SimStatType::Agents), Some(3.0));
+    assert_eq!(stats.get(SimStatType::SimFps), None);
+    assert_eq!(stats.to_bytes(), bytes[10..]);
+
+    let body = packet.body;
+    assert!(matches!(body.ui_event(), UiEventTypes::SimStatsEvent));
+    match UiEventTypes::SimStatsEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::SimStats(decoded)) => assert_eq!(decoded, stats),
+        _ => panic!("failed to decode SimStatsEvent"),
+    }
+}
+
+#[test]
+fn test_sim_stats_without_region_info() {
+    // older simulators stop after the pid
+    let bytes = Vec::from_hex("e8030000e903000000000000983a00000039300000").unwrap();
+    let stats = SimStats::from_bytes(&bytes).unwrap();
+    assert!(stats.stats.is_empty());
+    assert!(stats.region_flags_extended.is_empty());
+    assert_eq!(stats.pid, 12345);
+}
+
+#[test]
+fn test_sim_stat_types() {
+    for value in 0..=40 {
+        let stat = SimStatType::from_bytes(value);
+        assert!(!matches!(stat, SimStatType::Unknown(_)));
+        assert_eq!(stat.to_bytes(), value);
+    }
+    assert_eq!(SimStatType::from_bytes(41), SimStatType::Unknown(41));
+}
+
+#[test]
+fn test_health_message() {
+    let bytes = Vec::from_hex("400000002a00ffff008a00004842").unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let body = packet.body;
+    match &body {
+        PacketType::HealthMessage(health) => assert_eq!(**health, HealthMessage { health: 50.0 }),
+        _ => panic!("expected HealthMessage"),
+    }
+    assert!(matches!(body.ui_event(), UiEventTypes::HealthEvent));
+
+    let body = PacketType::AgentDied(Box::new(AgentDied { health: 0.0 }));
+    assert!(matches!(body.ui_event(), UiEventTypes::AgentDiedEvent));
+    match UiEventTypes::AgentDiedEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::AgentDied(decoded)) => assert_eq!(decoded.health, 0.0),
+        _ => panic!("failed to decode AgentDiedEvent"),
+    }
+}
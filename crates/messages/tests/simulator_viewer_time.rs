@@ -0,0 +1,46 @@
+use glam::Vec3;
+use hex::FromHex;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    simulator_viewer_time_message::SimulatorViewerTimeMessage,
+    ui_events::UiEventTypes,
+};
+
+#[test]
+fn test_simulator_viewer_time_message() {
+    let bytes = Vec::from_hex(concat!(
+        "400000002a00ffff0096",
+        "cb04fb711f010000",         // usec since start 1234567890123
+        "40380000",                 // 14400 seconds per day
+        "00371400",                 // 1324800 seconds per year
+        "0000803f0000000000000000", // sun direction 1, 0, 0
+        "0000c03f",                 // sun phase 1.5
+        "00000000000000000000803e", // sun angular velocity 0, 0, 0.25
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let time = match &packet.body {
+        PacketType::SimulatorViewerTimeMessage(time) => time.clone(),
+        _ => panic!("expected SimulatorViewerTimeMessage"),
+    };
+    assert_eq!(
+        *time,
+        SimulatorViewerTimeMessage {
+            usec_since_start: 1_234_567_890_123,
+            sec_per_day: 14400,
+            sec_per_year: 1_324_800,
+            sun_direction: Vec3::new(1.0, 0.0, 0.0),
+            sun_phase: 1.5,
+            sun_ang_velocity: Vec3::new(0.0, 0.0, 0.25),
+        }
+    );
+    assert_eq!(time.to_bytes(), bytes[10..]);
+
+    let body = packet.body;
+    assert!(matches!(body.ui_event(), UiEventTypes::SimulatorTimeEvent));
+    match UiEventTypes::SimulatorTimeEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::SimulatorViewerTimeMessage(decoded)) => assert_eq!(decoded, time),
+        _ => panic!("failed to decode SimulatorTimeEvent"),
+    }
+}
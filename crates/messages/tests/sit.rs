@@ -0,0 +1,115 @@
+use glam::{Quat, Vec3};
+use metaverse_messages::{
+    agent_request_sit::AgentRequestSit,
+    agent_sit::AgentSit,
+    agent_update::{AgentUpdate, ControlFlags},
+    avatar_sit_response::AvatarSitResponse,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    sit_status::SitStatus,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn sit_response() -> AvatarSitResponse {
+    AvatarSitResponse {
+        object_id: Uuid::new_v4(),
+        autopilot: false,
+        sit_position: Vec3::new(0.0, 0.0, 0.5),
+        sit_rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        camera_eye_offset: Vec3::new(-2.0, 0.0, 1.0),
+        camera_at_offset: Vec3::ZERO,
+        force_mouselook: true,
+    }
+}
+
+#[test]
+fn test_agent_request_sit_round_trip() {
+    let request = AgentRequestSit {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        ..AgentRequestSit::new(Uuid::new_v4(), Vec3::new(0.0, 0.25, 0.5))
+    };
+    assert_eq!(request.to_bytes().len(), 60);
+    let packet = Packet::from_bytes(&Packet::new_agent_request_sit(request.clone()).to_bytes());
+    match packet.unwrap().body {
+        PacketType::AgentRequestSit(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected AgentRequestSit"),
+    }
+}
+
+#[test]
+fn test_agent_sit_round_trip() {
+    let agent_sit = AgentSit {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+    };
+    let packet = Packet::from_bytes(&Packet::new_agent_sit(agent_sit.clone()).to_bytes());
+    match packet.unwrap().body {
+        PacketType::AgentSit(parsed) => assert_eq!(*parsed, agent_sit),
+        _ => panic!("expected AgentSit"),
+    }
+}
+
+#[test]
+fn test_avatar_sit_response() {
+    let response = sit_response();
+    let bytes = response.to_bytes();
+    assert_eq!(bytes.len(), 66);
+    assert_eq!(bytes[16], 0);
+    assert_eq!(bytes[65], 1);
+
+    let packet = Packet::from_bytes(&Packet::new_avatar_sit_response(response.clone()).to_bytes());
+    let parsed = match packet.unwrap().body {
+        PacketType::AvatarSitResponse(parsed) => parsed,
+        _ => panic!("expected AvatarSitResponse"),
+    };
+    assert_eq!(parsed.object_id, response.object_id);
+    assert_eq!(parsed.sit_position, response.sit_position);
+    assert!(parsed.sit_rotation.abs_diff_eq(response.sit_rotation, 1e-6));
+    assert_eq!(parsed.camera_eye_offset, response.camera_eye_offset);
+    assert!(parsed.force_mouselook);
+}
+
+#[test]
+fn test_sit_status_event() {
+    let statuses = [
+        SitStatus::Seated(sit_response()),
+        SitStatus::Failed {
+            object_id: Uuid::new_v4(),
+            reason: "the simulator didn't answer".to_string(),
+        },
+        SitStatus::Standing,
+    ];
+    for status in statuses {
+        let body = PacketType::SitStatus(Box::new(status.clone()));
+        let event = body.ui_event();
+        assert!(matches!(event, UiEventTypes::SitStatusEvent));
+        match event.packet_type_from_bytes(&body.ui_event_bytes()) {
+            Some(PacketType::SitStatus(decoded)) => assert_eq!(*decoded, status),
+            _ => panic!("failed to decode SitStatusEvent"),
+        }
+    }
+}
+
+#[test]
+fn test_agent_update_stand_up() {
+    let stand_up = AgentUpdate::stand_up();
+    let bytes = stand_up.to_bytes();
+    assert_eq!(bytes.len(), 114);
+    // the control flags are the four bytes before the last one
+    let control_flags = u32::from_le_bytes(bytes[109..113].try_into().unwrap());
+    assert_eq!(control_flags, 0x00010000);
+
+    let parsed = AgentUpdate::from_bytes(&bytes).unwrap();
+    assert!(parsed.control_flags.contains(ControlFlags::STAND_UP));
+    assert!(!parsed.control_flags.contains(ControlFlags::SIT_ON_GROUND));
+    assert_eq!(parsed.far, AgentUpdate::DEFAULT_FAR);
+    assert_eq!(parsed.camera_at_axis, Vec3::X);
+}
+
+#[test]
+fn test_agent_update_truncated() {
+    let bytes = AgentUpdate::new().to_bytes();
+    assert!(AgentUpdate::from_bytes(&bytes[..100]).is_err());
+}
@@ -0,0 +1,118 @@
+use glam::Vec3;
+use hex::FromHex;
+use metaverse_messages::{
+    attached_sound::AttachedSound, attached_sound_gain_change::AttachedSoundGainChange,
+    header::PacketFrequency, packet::Packet, packet_types::PacketType, sound_trigger::SoundTrigger,
+    ui_events::UiEventTypes, utils::sound_flags::SoundFlags,
+};
+use uuid::{uuid, Uuid};
+
+const SOUND_ID: Uuid = uuid!("8bfa8a8e-58e0-4fd0-bb0a-0a3e3fd2e0a1");
+const OWNER_ID: Uuid = uuid!("a2e76fcd-9360-4f6d-a924-000000000003");
+const OBJECT_ID: Uuid = uuid!("6ec3d9a5-5a6d-4c2b-9c5b-1f8f8dbd6d42");
+
+#[test]
+fn test_sound_trigger() {
+    let bytes = Vec::from_hex(
+        "000000002a001d8bfa8a8e58e04fd0bb0a0a3e3fd2e0a1a2e76fcd93604f6da9240000000000036ec3d9a55a6d\
+         4c2b9c5b1f8f8dbd6d420000000000000000000000000000000000e8030000e803000000004300800243000\
+         0ca410000803f",
+    )
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    assert_eq!(packet.header.frequency, PacketFrequency::High);
+    let trigger = match &packet.body {
+        PacketType::SoundTrigger(trigger) => trigger.clone(),
+        _ => panic!("expected SoundTrigger"),
+    };
+    assert_eq!(
+        *trigger,
+        SoundTrigger {
+            sound_id: SOUND_ID,
+            owner_id: OWNER_ID,
+            object_id: OBJECT_ID,
+            parent_id: Uuid::nil(),
+            handle: (256000 << 32) | 256000,
+            position: Vec3::new(128.0, 130.5, 25.25),
+            gain: 1.0,
+        }
+    );
+
+    let event = packet.body.ui_event();
+    assert!(matches!(event, UiEventTypes::SoundTriggerEvent));
+    match event.packet_type_from_bytes(&packet.body.ui_event_bytes()) {
+        Some(PacketType::SoundTrigger(decoded)) => assert_eq!(decoded, trigger),
+        _ => panic!("failed to decode SoundTriggerEvent"),
+    }
+
+    let round_trip = Packet::from_bytes(&Packet::new_sound_trigger(*trigger.clone()).to_bytes());
+    match round_trip.unwrap().body {
+        PacketType::SoundTrigger(decoded) => assert_eq!(decoded, trigger),
+        _ => panic!("expected SoundTrigger"),
+    }
+}
+
+#[test]
+fn test_attached_sound() {
+    let bytes = Vec::from_hex(
+        "000000002b00ff0d8bfa8a8e58e04fd0bb0a0a3e3fd2e0a16ec3d9a55a6d4c2b9c5b1f8f8dbd6d42a2e76fcd\
+         93604f6da9240000000000030000003f01",
+    )
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    assert_eq!(packet.header.frequency, PacketFrequency::Medium);
+    let sound = match &packet.body {
+        PacketType::AttachedSound(sound) => sound.clone(),
+        _ => panic!("expected AttachedSound"),
+    };
+    assert_eq!(
+        *sound,
+        AttachedSound {
+            sound_id: SOUND_ID,
+            object_id: OBJECT_ID,
+            owner_id: OWNER_ID,
+            gain: 0.5,
+            flags: SoundFlags::LOOP,
+        }
+    );
+
+    let event = packet.body.ui_event();
+    assert!(matches!(event, UiEventTypes::AttachedSoundEvent));
+    match event.packet_type_from_bytes(&packet.body.ui_event_bytes()) {
+        Some(PacketType::AttachedSound(decoded)) => assert_eq!(decoded, sound),
+        _ => panic!("failed to decode AttachedSoundEvent"),
+    }
+}
+
+#[test]
+fn test_attached_sound_gain_change() {
+    let bytes = Vec::from_hex("000000002c00ff0e6ec3d9a55a6d4c2b9c5b1f8f8dbd6d420000803e").unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let change = match &packet.body {
+        PacketType::AttachedSoundGainChange(change) => change.clone(),
+        _ => panic!("expected AttachedSoundGainChange"),
+    };
+    assert_eq!(
+        *change,
+        AttachedSoundGainChange {
+            object_id: OBJECT_ID,
+            gain: 0.25,
+        }
+    );
+
+    let event = packet.body.ui_event();
+    assert!(matches!(event, UiEventTypes::AttachedSoundGainChangeEvent));
+    match event.packet_type_from_bytes(&packet.body.ui_event_bytes()) {
+        Some(PacketType::AttachedSoundGainChange(decoded)) => assert_eq!(decoded, change),
+        _ => panic!("failed to decode AttachedSoundGainChangeEvent"),
+    }
+}
+
+#[test]
+fn test_sound_flags_keep_unknown_bits() {
+    let flags = SoundFlags::from_bits_retain(0x81);
+    assert!(flags.contains(SoundFlags::LOOP));
+    let json = serde_json::to_string(&flags).unwrap();
+    assert_eq!(json, "129");
+    assert_eq!(serde_json::from_str::<SoundFlags>(&json).unwrap(), flags);
+}
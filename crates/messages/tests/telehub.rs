@@ -0,0 +1,86 @@
+use glam::{Quat, Vec3};
+use hex::FromHex;
+use metaverse_messages::{
+    estate_owner_message::EstateOwnerMessage,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    telehub_info::TelehubInfo,
+    ui_events::UiEventTypes,
+};
+use uuid::{uuid, Uuid};
+
+fn telehub() -> TelehubInfo {
+    TelehubInfo {
+        object_id: uuid!("6b1f2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d"),
+        object_name: "Telehub".to_string(),
+        telehub_pos: Vec3::new(128.0, 64.0, 25.0),
+        telehub_rot: Quat::IDENTITY,
+        spawn_points: vec![Vec3::new(0.0, 5.0, 0.0), Vec3::new(-5.0, 0.0, 0.0)],
+    }
+}
+
+#[test]
+fn test_telehub_info() {
+    let bytes = Vec::from_hex(concat!(
+        "400000000100ffff000a",
+        "6b1f2c3d4e5f4a6b8c7d9e0f1a2b3c4d", // object
+        "0854656c6568756200",               // "Telehub"
+        "00000043000080420000c841",
+        "000000000000000000000000",
+        "02",
+        "000000000000a04000000000",
+        "0000a0c00000000000000000",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    match &packet.body {
+        PacketType::TelehubInfo(info) => assert_eq!(**info, telehub()),
+        _ => panic!("expected TelehubInfo"),
+    }
+    assert_eq!(packet.body.to_bytes(), bytes[10..]);
+
+    let body = PacketType::TelehubInfo(Box::new(telehub()));
+    match UiEventTypes::TelehubInfoEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::TelehubInfo(info)) => assert_eq!(*info, telehub()),
+        _ => panic!("failed to decode TelehubInfoEvent"),
+    }
+}
+
+#[test]
+fn test_no_telehub() {
+    let info = TelehubInfo {
+        object_id: Uuid::nil(),
+        object_name: String::new(),
+        telehub_pos: Vec3::ZERO,
+        telehub_rot: Quat::IDENTITY,
+        spawn_points: Vec::new(),
+    };
+    assert_eq!(TelehubInfo::from_bytes(&info.to_bytes()).unwrap(), info);
+}
+
+#[test]
+fn test_telehub_commands() {
+    let message = EstateOwnerMessage::telehub_info();
+    assert_eq!(message.method, "telehub");
+    assert_eq!(message.params, vec!["info ui"]);
+    assert!(!message.is_telehub_change());
+
+    let message = EstateOwnerMessage::telehub_connect(0x30);
+    assert_eq!(message.params, vec!["connect", "48"]);
+    assert!(message.is_telehub_change());
+
+    let message = EstateOwnerMessage::telehub_delete();
+    assert_eq!(message.params, vec!["delete"]);
+    assert!(message.is_telehub_change());
+
+    let message = EstateOwnerMessage::telehub_add_spawn_point(0x31);
+    assert_eq!(message.params, vec!["spawnpoint add", "49"]);
+    assert!(message.is_telehub_change());
+
+    let message = EstateOwnerMessage::telehub_remove_spawn_point(1);
+    assert_eq!(message.params, vec!["spawnpoint remove", "1"]);
+    assert!(message.is_telehub_change());
+
+    let message = EstateOwnerMessage::new("kickestate".to_string(), vec!["delete".to_string()]);
+    assert!(!message.is_telehub_change());
+}
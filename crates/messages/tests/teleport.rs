@@ -0,0 +1,142 @@
+use glam::Vec3;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    teleport_failed::{AlertInfo, TeleportFailed},
+    teleport_finish::TeleportFinish,
+    teleport_location_request::TeleportLocationRequest,
+    teleport_progress::TeleportProgress,
+    teleport_start::TeleportStart,
+    ui_events::UiEventTypes,
+};
+use std::net::Ipv4Addr;
+use uuid::uuid;
+
+fn test_finish() -> TeleportFinish {
+    TeleportFinish {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        location_id: 4,
+        sim_ip: Ipv4Addr::new(192, 168, 1, 20),
+        sim_port: 9001,
+        region_handle: (256000u64 << 32) | 256256,
+        seed_capability: "http://192.168.1.20:9001/CAPS/seed/".to_string(),
+        sim_access: 13,
+        teleport_flags: 1 << 4,
+    }
+}
+
+#[test]
+fn test_teleport_location_request() {
+    let request = TeleportLocationRequest {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        session_id: uuid!("224ecaea-372d-4d31-8b64-4805966418e5"),
+        region_handle: (256000u64 << 32) | 256256,
+        position: Vec3::new(128.0, 128.0, 25.0),
+        look_at: Vec3::new(1.0, 0.0, 0.0),
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(&bytes[32..40], &request.region_handle.to_le_bytes());
+
+    let packet = Packet::new_teleport_location_request(request);
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+    match parsed.body {
+        PacketType::TeleportLocationRequest(request) => {
+            assert_eq!(request.position, Vec3::new(128.0, 128.0, 25.0));
+            assert_eq!(request.look_at, Vec3::new(1.0, 0.0, 0.0));
+        }
+        body => panic!("expected TeleportLocationRequest, got {:?}", body),
+    }
+}
+
+#[test]
+fn test_teleport_finish() {
+    let finish = test_finish();
+    let bytes = finish.to_bytes();
+    // the sim address is in network byte order
+    assert_eq!(&bytes[20..26], &[192, 168, 1, 20, 0x23, 0x29]);
+
+    let parsed = TeleportFinish::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.sim_ip, finish.sim_ip);
+    assert_eq!(parsed.sim_port, 9001);
+    assert_eq!(parsed.region_handle, finish.region_handle);
+    assert_eq!(parsed.seed_capability, finish.seed_capability);
+    assert_eq!(parsed.teleport_flags, finish.teleport_flags);
+    assert_eq!(parsed.to_bytes(), bytes);
+
+    assert!(TeleportFinish::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_teleport_complete_event() {
+    let packet = Packet::new_teleport_finish(test_finish());
+    let parsed = Packet::from_bytes(&packet.to_bytes()).unwrap();
+
+    // the session handles TeleportFinish and sends the UI a TeleportCompleteEvent once the
+    // circuit has been switched
+    let event = parsed.body.ui_event();
+    assert!(matches!(event, UiEventTypes::TeleportCompleteEvent));
+    match event.packet_type_from_bytes(&parsed.body.ui_event_bytes()) {
+        Some(PacketType::TeleportFinish(finish)) => {
+            assert_eq!(finish.sim_ip, Ipv4Addr::new(192, 168, 1, 20))
+        }
+        _ => panic!("failed to decode TeleportCompleteEvent"),
+    }
+}
+
+#[test]
+fn test_teleport_failed() {
+    let failed = TeleportFailed {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        reason: "Region not found".to_string(),
+        alert_info: vec![AlertInfo {
+            message: "RegionEntryAccessBlocked".to_string(),
+            extra_params: "".to_string(),
+        }],
+    };
+    let bytes = failed.to_bytes();
+    let parsed = TeleportFailed::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.reason, "Region not found");
+    assert_eq!(parsed.alert_info.len(), 1);
+    assert_eq!(parsed.alert_info[0].message, "RegionEntryAccessBlocked");
+    assert_eq!(parsed.to_bytes(), bytes);
+
+    // older simulators don't send the AlertInfo block
+    let without_alerts = &bytes[..16 + 1 + "Region not found".len() + 1];
+    assert!(TeleportFailed::from_bytes(without_alerts)
+        .unwrap()
+        .alert_info
+        .is_empty());
+
+    // the reason is surfaced to the UI
+    let body = PacketType::TeleportFailed(Box::new(failed));
+    let event = body.ui_event();
+    assert!(matches!(event, UiEventTypes::TeleportFailedEvent));
+    match event.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::TeleportFailed(failed)) => assert_eq!(failed.reason, "Region not found"),
+        _ => panic!("failed to decode TeleportFailedEvent"),
+    }
+}
+
+#[test]
+fn test_teleport_start_and_progress() {
+    let start = TeleportStart { teleport_flags: 16 };
+    assert_eq!(
+        TeleportStart::from_bytes(&start.to_bytes())
+            .unwrap()
+            .teleport_flags,
+        16
+    );
+
+    let progress = TeleportProgress {
+        agent_id: uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a"),
+        teleport_flags: 16,
+        message: "sending_dest".to_string(),
+    };
+    let parsed = TeleportProgress::from_bytes(&progress.to_bytes()).unwrap();
+    assert_eq!(parsed.message, "sending_dest");
+    assert!(matches!(
+        PacketType::TeleportProgress(Box::new(parsed)).ui_event(),
+        UiEventTypes::TeleportProgressEvent
+    ));
+}
@@ -0,0 +1,112 @@
+use metaverse_messages::{
+    image_data::{ImageCodec, ImageData},
+    image_packet::ImagePacket,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    request_image::{ImageRequest, ImageType, RequestImage},
+    texture_ready::TextureReady,
+    ui_events::UiEventTypes,
+};
+use uuid::Uuid;
+
+fn texture_id() -> Uuid {
+    Uuid::parse_str("89556747-24cb-43ed-920b-47caed15465f").unwrap()
+}
+
+#[test]
+fn test_request_image_round_trip() {
+    let request_image = RequestImage {
+        agent_id: Uuid::new_v4(),
+        session_id: Uuid::new_v4(),
+        requests: vec![
+            ImageRequest {
+                texture_id: texture_id(),
+                discard_level: 0,
+                download_priority: 1013000.0,
+                packet: 0,
+                image_type: ImageType::Normal,
+            },
+            ImageRequest::cancel(Uuid::new_v4()),
+        ],
+    };
+    let bytes = request_image.to_bytes();
+    // AgentData, the block count and two 26 byte blocks
+    assert_eq!(bytes.len(), 32 + 1 + 2 * 26);
+    // the cancel is sent as a discard level of -1
+    assert_eq!(bytes[33 + 26 + 16], 0xff);
+    let parsed = RequestImage::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, request_image);
+    assert!(!parsed.requests[0].is_cancel());
+    assert!(parsed.requests[1].is_cancel());
+
+    let packet = Packet::from_bytes(&Packet::new_request_image(request_image).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::RequestImage(_)));
+}
+
+#[test]
+fn test_image_data_round_trip() {
+    let image_data = ImageData {
+        texture_id: texture_id(),
+        codec: ImageCodec::J2c,
+        size: 2500,
+        packets: 3,
+        data: vec![0xff, 0x4f, 0xff, 0x51],
+    };
+    let bytes = image_data.to_bytes();
+    assert_eq!(&bytes[16..23], &[0x02, 0xc4, 0x09, 0x00, 0x00, 0x03, 0x00]);
+    assert_eq!(ImageData::from_bytes(&bytes).unwrap(), image_data);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_image_data(image_data.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ImageData(parsed) => assert_eq!(*parsed, image_data),
+        _ => panic!("failed to parse ImageData"),
+    }
+}
+
+#[test]
+fn test_image_packet_round_trip() {
+    let image_packet = ImagePacket {
+        texture_id: texture_id(),
+        packet: 2,
+        data: vec![7; ImagePacket::PACKET_SIZE],
+    };
+    let bytes = image_packet.to_bytes();
+    assert_eq!(bytes.len(), 16 + 2 + 2 + ImagePacket::PACKET_SIZE);
+    assert_eq!(ImagePacket::from_bytes(&bytes).unwrap(), image_packet);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_image_packet(image_packet.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::ImagePacket(parsed) => assert_eq!(*parsed, image_packet),
+        _ => panic!("failed to parse ImagePacket"),
+    }
+}
+
+#[test]
+fn test_truncated_image_packet() {
+    let mut bytes = ImagePacket {
+        texture_id: texture_id(),
+        packet: 1,
+        data: vec![1, 2, 3],
+    }
+    .to_bytes();
+    bytes.pop();
+    assert!(ImagePacket::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_texture_ready_event() {
+    let texture = TextureReady {
+        texture_id: texture_id(),
+        codec: ImageCodec::J2c,
+        data: vec![0xff, 0x4f, 0xff, 0x51, 0x00],
+    };
+    let body = PacketType::TextureReady(Box::new(texture.clone()));
+    let event = body.ui_event();
+    assert!(matches!(event, UiEventTypes::TextureReadyEvent));
+    match event.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::TextureReady(parsed)) => assert_eq!(*parsed, texture),
+        _ => panic!("failed to decode TextureReadyEvent"),
+    }
+}
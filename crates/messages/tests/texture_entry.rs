@@ -0,0 +1,219 @@
+use metaverse_messages::utils::texture_entry::{TextureEntry, TextureFace, MAX_FACES};
+use std::f32::consts::PI;
+use uuid::{uuid, Uuid};
+
+const DEFAULT_TEXTURE: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+const FACE_TEXTURE: Uuid = uuid!("89556747-24cb-43ed-920b-47caed15465f");
+const MATERIAL: Uuid = uuid!("0a6a9c1f-3b5e-4d2a-9f8e-7c6b5a4d3e2f");
+
+// face masks are 7 bits per byte, most significant bits first, with the high bit set on every
+// byte but the last
+fn mask(mask: u64) -> Vec<u8> {
+    let mut groups = vec![(mask & 0x7F) as u8];
+    let mut rest = mask >> 7;
+    while rest != 0 {
+        groups.push((rest & 0x7F) as u8 | 0x80);
+        rest >>= 7;
+    }
+    groups.reverse();
+    groups
+}
+
+// an entry the way an older simulator sends it, with only the texture and color sections
+fn short_entry() -> Vec<u8> {
+    let mut bytes = Vec::new();
+    // textures: faces 1 and 2 override the default
+    bytes.extend_from_slice(DEFAULT_TEXTURE.as_bytes());
+    bytes.extend(mask(0b110));
+    bytes.extend_from_slice(FACE_TEXTURE.as_bytes());
+    bytes.push(0);
+    // colors are inverted, so zeroes are opaque white. Face 40 is opaque red.
+    bytes.extend_from_slice(&[0, 0, 0, 0]);
+    bytes.extend(mask(1 << 40));
+    bytes.extend_from_slice(&[0, 255, 255, 0]);
+    bytes.push(0);
+    bytes
+}
+
+// an entry with every section, and an override in each of them for face 3
+fn full_entry() -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let face = mask(1 << 3);
+    bytes.extend_from_slice(DEFAULT_TEXTURE.as_bytes());
+    bytes.extend(&face);
+    bytes.extend_from_slice(FACE_TEXTURE.as_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(&[0, 0, 0, 0]);
+    bytes.extend(&face);
+    bytes.extend_from_slice(&[255, 255, 0, 127]);
+    bytes.push(0);
+    // repeats
+    bytes.extend_from_slice(&1.0f32.to_le_bytes());
+    bytes.extend(&face);
+    bytes.extend_from_slice(&4.0f32.to_le_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(&1.0f32.to_le_bytes());
+    bytes.extend(&face);
+    bytes.extend_from_slice(&0.5f32.to_le_bytes());
+    bytes.push(0);
+    // offsets are packed over -1 to 1
+    bytes.extend_from_slice(&0i16.to_le_bytes());
+    bytes.extend(&face);
+    bytes.extend_from_slice(&0x7FFFi16.to_le_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(&0i16.to_le_bytes());
+    bytes.extend(&face);
+    bytes.extend_from_slice(&(-0x7FFFi16).to_le_bytes());
+    bytes.push(0);
+    // rotation, where 0x8000 is a full turn
+    bytes.extend_from_slice(&0i16.to_le_bytes());
+    bytes.extend(&face);
+    bytes.extend_from_slice(&0x4000i16.to_le_bytes());
+    bytes.push(0);
+    // bump, media flags and glow
+    bytes.push(0);
+    bytes.extend(&face);
+    bytes.push(0x21);
+    bytes.push(0);
+    bytes.push(0);
+    bytes.extend(&face);
+    bytes.push(1);
+    bytes.push(0);
+    bytes.push(0);
+    bytes.extend(&face);
+    bytes.push(255);
+    bytes.push(0);
+    // materials, with no terminator after the last section
+    bytes.extend_from_slice(Uuid::nil().as_bytes());
+    bytes.extend(&face);
+    bytes.extend_from_slice(MATERIAL.as_bytes());
+    bytes
+}
+
+#[test]
+fn test_mask_encoding() {
+    assert_eq!(mask(0b110), vec![0x06]);
+    assert_eq!(mask(1 << 7), vec![0x81, 0x00]);
+    assert_eq!(mask(1 << 40), vec![0xA0, 0x80, 0x80, 0x80, 0x80, 0x00]);
+}
+
+#[test]
+fn test_empty_entry_is_all_defaults() {
+    let entry = TextureEntry::from_bytes(&[]).unwrap();
+    assert_eq!(entry.default, TextureFace::default());
+    assert_eq!(entry.faces.len(), MAX_FACES);
+    assert!(entry
+        .faces
+        .iter()
+        .all(|face| *face == TextureFace::default()));
+}
+
+#[test]
+fn test_short_entry_keeps_missing_sections_default() {
+    let entry = TextureEntry::from_bytes(&short_entry()).unwrap();
+    assert_eq!(entry.default.texture_id, DEFAULT_TEXTURE);
+    assert_eq!(entry.face(0).texture_id, DEFAULT_TEXTURE);
+    assert_eq!(entry.face(1).texture_id, FACE_TEXTURE);
+    assert_eq!(entry.face(2).texture_id, FACE_TEXTURE);
+    assert_eq!(entry.face(3).texture_id, DEFAULT_TEXTURE);
+
+    assert_eq!(entry.default.color, [255, 255, 255, 255]);
+    assert_eq!(entry.face(40).color, [255, 0, 0, 255]);
+    assert_eq!(entry.face(39).color, [255, 255, 255, 255]);
+
+    // the sections that weren't sent keep their defaults
+    assert_eq!(entry.face(1).repeat_u, 1.0);
+    assert_eq!(entry.face(1).glow, 0.0);
+    assert_eq!(entry.face(1).material_id, Uuid::nil());
+}
+
+#[test]
+fn test_full_entry() {
+    let entry = TextureEntry::from_bytes(&full_entry()).unwrap();
+    let face = entry.face(3);
+    assert_eq!(face.texture_id, FACE_TEXTURE);
+    assert_eq!(face.color, [0, 0, 255, 128]);
+    assert_eq!(face.repeat_u, 4.0);
+    assert_eq!(face.repeat_v, 0.5);
+    assert_eq!(face.offset_u, 1.0);
+    assert_eq!(face.offset_v, -1.0);
+    assert!((face.rotation - PI).abs() < 1e-6);
+    assert_eq!(face.bump, 0x21);
+    assert_eq!(face.media_flags, 1);
+    assert_eq!(face.glow, 1.0);
+    assert_eq!(face.material_id, MATERIAL);
+
+    assert_eq!(*entry.face(2), entry.default);
+    assert_eq!(entry.default.texture_id, DEFAULT_TEXTURE);
+    assert_eq!(entry.default.rotation, 0.0);
+}
+
+#[test]
+fn test_out_of_range_face_is_default() {
+    let entry = TextureEntry::from_bytes(&short_entry()).unwrap();
+    assert_eq!(*entry.face(MAX_FACES), entry.default);
+}
+
+#[test]
+fn test_truncated_entry_is_an_error() {
+    let bytes = short_entry();
+    // cut off in the middle of the default texture
+    assert!(TextureEntry::from_bytes(&bytes[..8]).is_err());
+    // cut off in the middle of an override
+    assert!(TextureEntry::from_bytes(&bytes[..20]).is_err());
+}
+
+#[test]
+fn test_overlong_mask_is_an_error() {
+    let mut bytes = DEFAULT_TEXTURE.as_bytes().to_vec();
+    bytes.extend_from_slice(&[0xFF; 8]);
+    bytes.extend_from_slice(FACE_TEXTURE.as_bytes());
+    assert!(TextureEntry::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_malformed_entries_do_not_panic() {
+    let full = full_entry();
+    for end in 0..full.len() {
+        let _ = TextureEntry::from_bytes(&full[..end]);
+    }
+    // a simple generator, so the garbage is the same on every run
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    for length in 0..512 {
+        let garbage: Vec<u8> = (0..length)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+        let _ = TextureEntry::from_bytes(&garbage);
+    }
+}
+
+#[test]
+fn test_to_bytes_matches_what_simulators_send() {
+    // the same entry, with the last section terminated like every other one
+    let mut expected = full_entry();
+    expected.push(0);
+    let entry = TextureEntry::from_bytes(&full_entry()).unwrap();
+    assert_eq!(entry.to_bytes(), expected);
+    assert_eq!(TextureEntry::from_bytes(&entry.to_bytes()).unwrap(), entry);
+}
+
+#[test]
+fn test_to_bytes_groups_faces_with_the_same_value() {
+    let mut entry = TextureEntry::from_bytes(&[]).unwrap();
+    entry.faces[1].texture_id = FACE_TEXTURE;
+    entry.faces[2].texture_id = FACE_TEXTURE;
+    entry.faces[40].color = [255, 0, 0, 255];
+    let bytes = entry.to_bytes();
+    assert_eq!(&bytes[16..17], mask(0b110).as_slice());
+    assert_eq!(&bytes[17..33], FACE_TEXTURE.as_bytes());
+    assert_eq!(bytes[33], 0);
+
+    let parsed = TextureEntry::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, entry);
+    assert_eq!(parsed.face(40).color, [255, 0, 0, 255]);
+}
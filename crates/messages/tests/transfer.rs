@@ -0,0 +1,157 @@
+use metaverse_messages::{
+    asset_received::AssetReceived,
+    asset_transfer_failed::AssetTransferFailed,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    transfer_info::TransferInfo,
+    transfer_packet::TransferPacket,
+    transfer_request::TransferRequest,
+    ui_events::UiEventTypes,
+    utils::{
+        asset_type::AssetType,
+        transfer::{AssetTransferParams, ChannelType, SourceType, TargetType, TransferStatus},
+    },
+};
+use uuid::Uuid;
+
+fn asset_id() -> Uuid {
+    Uuid::parse_str("6c6dff8c-0b1b-4a29-8b1b-0e6f3b2f7a10").unwrap()
+}
+
+#[test]
+fn test_transfer_request_round_trip() {
+    let transfer_id = Uuid::new_v4();
+    let request = TransferRequest::asset(transfer_id, asset_id(), AssetType::Notecard, 100.0);
+    let bytes = request.to_bytes();
+    // channel type 2 and source type 2 are asset transfers
+    assert_eq!(&bytes[16..24], &[2, 0, 0, 0, 2, 0, 0, 0]);
+    // the params are the asset id and the asset type as an S32
+    assert_eq!(&bytes[28..30], &[20, 0]);
+    assert_eq!(&bytes[46..], &[7, 0, 0, 0]);
+
+    let parsed = TransferRequest::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, request);
+    assert_eq!(
+        parsed.asset_params().unwrap(),
+        AssetTransferParams {
+            asset_id: asset_id(),
+            asset_type: AssetType::Notecard,
+        }
+    );
+
+    let packet = Packet::from_bytes(&Packet::new_transfer_request(request).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::TransferRequest(_)));
+}
+
+#[test]
+fn test_transfer_request_other_source() {
+    let request = TransferRequest {
+        transfer_id: Uuid::new_v4(),
+        channel_type: ChannelType::Asset,
+        source_type: SourceType::SimInventoryItem,
+        priority: 1.0,
+        params: vec![0; 100],
+    };
+    assert!(request.asset_params().is_err());
+}
+
+#[test]
+fn test_transfer_info_round_trip() {
+    let transfer_info = TransferInfo {
+        transfer_id: Uuid::new_v4(),
+        channel_type: ChannelType::Asset,
+        target_type: TargetType::Unknown,
+        status: TransferStatus::NotFound,
+        size: 0,
+        params: AssetTransferParams {
+            asset_id: asset_id(),
+            asset_type: AssetType::Animation,
+        }
+        .to_bytes(),
+    };
+    let bytes = transfer_info.to_bytes();
+    assert_eq!(&bytes[24..28], &(-4i32).to_le_bytes());
+    let parsed = TransferInfo::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, transfer_info);
+    assert!(parsed.status.is_failure());
+    assert_eq!(
+        parsed.asset_params().unwrap().asset_type,
+        AssetType::Animation
+    );
+
+    let packet = Packet::from_bytes(&Packet::new_transfer_info(transfer_info).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::TransferInfo(_)));
+}
+
+#[test]
+fn test_transfer_packet_round_trip() {
+    let transfer_packet = TransferPacket {
+        transfer_id: Uuid::new_v4(),
+        channel_type: ChannelType::Asset,
+        packet: 3,
+        status: TransferStatus::Done,
+        data: b"Linden text version 2\n".to_vec(),
+    };
+    let bytes = transfer_packet.to_bytes();
+    assert_eq!(&bytes[20..28], &[3, 0, 0, 0, 1, 0, 0, 0]);
+    assert_eq!(TransferPacket::from_bytes(&bytes).unwrap(), transfer_packet);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_transfer_packet(transfer_packet.clone()).to_bytes())
+            .unwrap();
+    match packet.body {
+        PacketType::TransferPacket(parsed) => assert_eq!(*parsed, transfer_packet),
+        _ => panic!("failed to parse TransferPacket"),
+    }
+}
+
+#[test]
+fn test_transfer_status() {
+    assert!(!TransferStatus::Ok.is_failure());
+    assert!(!TransferStatus::Done.is_failure());
+    assert!(TransferStatus::Abort.is_failure());
+    assert_eq!(
+        TransferStatus::from_bytes(-3),
+        TransferStatus::InsufficientPermissions
+    );
+    assert_eq!(TransferStatus::from_bytes(42), TransferStatus::Other(42));
+    assert_eq!(TransferStatus::Other(42).to_bytes(), 42);
+}
+
+#[test]
+fn test_asset_events() {
+    let received = AssetReceived {
+        transfer_id: Uuid::new_v4(),
+        asset_id: asset_id(),
+        asset_type: AssetType::Sound,
+        data: vec![0x4f, 0x67, 0x67, 0x53],
+    };
+    let body = PacketType::AssetReceived(Box::new(received.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::AssetReceivedEvent));
+    match body
+        .ui_event()
+        .packet_type_from_bytes(&body.ui_event_bytes())
+    {
+        Some(PacketType::AssetReceived(parsed)) => assert_eq!(*parsed, received),
+        _ => panic!("failed to decode AssetReceivedEvent"),
+    }
+
+    let failed = AssetTransferFailed {
+        transfer_id: Uuid::new_v4(),
+        asset_id: asset_id(),
+        asset_type: AssetType::Sound,
+        reason: "transfer timed out".to_string(),
+    };
+    let body = PacketType::AssetTransferFailed(Box::new(failed.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::AssetTransferFailedEvent
+    ));
+    match body
+        .ui_event()
+        .packet_type_from_bytes(&body.ui_event_bytes())
+    {
+        Some(PacketType::AssetTransferFailed(parsed)) => assert_eq!(*parsed, failed),
+        _ => panic!("failed to decode AssetTransferFailedEvent"),
+    }
+}
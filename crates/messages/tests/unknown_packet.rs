@@ -0,0 +1,97 @@
+use hex::FromHex;
+use metaverse_messages::{
+    header::PacketFrequency, packet::Packet, packet_types::PacketType, raw_packet::RawPacketData,
+    report_unknown_packets::ReportUnknownPackets, ui_events::UiEventTypes,
+    unknown_packet_notice::UnknownPacketNotice,
+};
+
+fn raw(packet: &Packet) -> &RawPacketData {
+    match &packet.body {
+        PacketType::Unknown(raw) => raw,
+        _ => panic!("expected Unknown"),
+    }
+}
+
+#[test]
+fn test_unknown_ids_keep_their_bytes() {
+    for (hex, frequency, id, body) in [
+        // High 200, which the client doesn't implement
+        (
+            "4000000001" as &str,
+            PacketFrequency::High,
+            200,
+            "00c8aabbcc",
+        ),
+        // Medium 200
+        ("4000000002", PacketFrequency::Medium, 200, "00ffc80102"),
+        // Low 999, with an ack appended
+        (
+            "5000000003",
+            PacketFrequency::Low,
+            999,
+            "00ffff03e7deadbeef0000000701",
+        ),
+        // Fixed 200, with an empty body
+        ("4000000004", PacketFrequency::Fixed, 200, "00ffffffc8"),
+    ] {
+        let bytes = Vec::from_hex(format!("{}{}", hex, body)).unwrap();
+        let packet = Packet::from_bytes(&bytes).unwrap();
+        let data = raw(&packet);
+        assert_eq!(data.frequency, frequency);
+        assert_eq!(data.id, id);
+        assert!(packet.header.reliable);
+        // the body stops before the appended acks
+        if id == 999 {
+            assert_eq!(data.body, vec![0xde, 0xad, 0xbe, 0xef]);
+            assert_eq!(packet.header.ack_list, Some(vec![7]));
+        }
+        assert_eq!(packet.to_bytes(), bytes);
+    }
+}
+
+#[test]
+fn test_zerocoded_unknown_packets_round_trip() {
+    // Low 999 with a zerocoded body of eight zeros around two bytes
+    let bytes = Vec::from_hex("c00000000500ffff03e7aa0004bb0004").unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    assert!(packet.header.zerocoded);
+    assert_eq!(raw(&packet).body, vec![0xaa, 0, 0, 0, 0, 0xbb, 0, 0, 0, 0]);
+    assert_eq!(packet.to_bytes(), bytes);
+}
+
+#[test]
+fn test_known_ids_with_short_bodies_are_errors() {
+    // StartPingCheck is High 1, and needs five bytes
+    let bytes = Vec::from_hex("000000000100010203").unwrap();
+    assert!(Packet::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_unknown_packet_notice() {
+    let raw = RawPacketData {
+        frequency: PacketFrequency::Low,
+        id: 999,
+        body: vec![0xde, 0xad, 0x00, 0x01],
+    };
+    let notice = UnknownPacketNotice::new(&raw);
+    assert_eq!(notice.frequency, "Low");
+    assert_eq!(notice.id, 999);
+    assert_eq!(notice.body, "dead0001");
+
+    let body = PacketType::UnknownPacketNotice(Box::new(notice.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::UnknownPacketEvent));
+    match UiEventTypes::UnknownPacketEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::UnknownPacketNotice(parsed)) => assert_eq!(*parsed, notice),
+        _ => panic!("expected UnknownPacketNotice"),
+    }
+}
+
+#[test]
+fn test_report_unknown_packets_round_trip() {
+    let bytes =
+        Packet::new_report_unknown_packets(ReportUnknownPackets { enabled: true }).to_bytes();
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::ReportUnknownPackets(report) => assert!(report.enabled),
+        _ => panic!("expected ReportUnknownPackets"),
+    }
+}
@@ -0,0 +1,138 @@
+use metaverse_messages::{
+    group_name_resolved::GroupNameResolved,
+    name_resolved::NameResolved,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    uuid_group_name_reply::{GroupName, UUIDGroupNameReply},
+    uuid_group_name_request::UUIDGroupNameRequest,
+    uuid_name_reply::{AvatarName, UUIDNameReply},
+    uuid_name_request::UUIDNameRequest,
+};
+use uuid::{uuid, Uuid};
+
+const AVATAR_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+
+fn avatar_name(last_name: &str) -> AvatarName {
+    AvatarName {
+        id: AVATAR_ID,
+        first_name: "Skyler".to_string(),
+        last_name: last_name.to_string(),
+    }
+}
+
+#[test]
+fn test_uuid_name_request_round_trip() {
+    let request = UUIDNameRequest {
+        ids: vec![AVATAR_ID, Uuid::new_v4()],
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 33);
+    assert_eq!(bytes[0], 2);
+    assert_eq!(UUIDNameRequest::from_bytes(&bytes).unwrap(), request);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_uuid_name_request(request.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::UUIDNameRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected UUIDNameRequest"),
+    }
+}
+
+#[test]
+fn test_uuid_name_reply_round_trip() {
+    let reply = UUIDNameReply {
+        names: vec![avatar_name("Resident")],
+    };
+    let bytes = reply.to_bytes();
+    // names are sent null terminated, with a one byte length
+    assert_eq!(&bytes[17..25], b"\x07Skyler\0");
+    assert_eq!(UUIDNameReply::from_bytes(&bytes).unwrap(), reply);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_uuid_name_reply(reply.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::UUIDNameReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected UUIDNameReply"),
+    }
+}
+
+#[test]
+fn test_full_name() {
+    assert_eq!(avatar_name("Resident").full_name(), "Skyler");
+    assert_eq!(avatar_name("").full_name(), "Skyler");
+    assert_eq!(avatar_name("Clark").full_name(), "Skyler Clark");
+}
+
+#[test]
+fn test_name_resolved_event() {
+    let body = PacketType::NameResolved(Box::new(NameResolved {
+        names: vec![avatar_name("Clark")],
+    }));
+    assert!(matches!(body.ui_event(), UiEventTypes::NameResolvedEvent));
+    match UiEventTypes::NameResolvedEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::NameResolved(resolved)) => {
+            assert_eq!(resolved.names, vec![avatar_name("Clark")])
+        }
+        _ => panic!("expected NameResolved"),
+    }
+}
+
+#[test]
+fn test_uuid_group_name_request_round_trip() {
+    let request = UUIDGroupNameRequest {
+        ids: vec![AVATAR_ID],
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_uuid_group_name_request(request.clone()).to_bytes())
+            .unwrap();
+    match packet.body {
+        PacketType::UUIDGroupNameRequest(parsed) => assert_eq!(*parsed, request),
+        _ => panic!("expected UUIDGroupNameRequest"),
+    }
+}
+
+#[test]
+fn test_uuid_group_name_reply_round_trip() {
+    let reply = UUIDGroupNameReply {
+        names: vec![
+            GroupName {
+                id: AVATAR_ID,
+                name: "Builders".to_string(),
+            },
+            GroupName {
+                id: Uuid::new_v4(),
+                name: String::new(),
+            },
+        ],
+    };
+    let bytes = reply.to_bytes();
+    assert_eq!(bytes[0], 2);
+    assert_eq!(UUIDGroupNameReply::from_bytes(&bytes).unwrap(), reply);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_uuid_group_name_reply(reply.clone()).to_bytes()).unwrap();
+    match packet.body {
+        PacketType::UUIDGroupNameReply(parsed) => assert_eq!(*parsed, reply),
+        _ => panic!("expected UUIDGroupNameReply"),
+    }
+}
+
+#[test]
+fn test_group_name_resolved_event() {
+    let names = vec![GroupName {
+        id: AVATAR_ID,
+        name: "Builders".to_string(),
+    }];
+    let body = PacketType::GroupNameResolved(Box::new(GroupNameResolved {
+        names: names.clone(),
+    }));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::GroupNameResolvedEvent
+    ));
+    match UiEventTypes::GroupNameResolvedEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::GroupNameResolved(resolved)) => assert_eq!(resolved.names, names),
+        _ => panic!("expected GroupNameResolved"),
+    }
+}
@@ -0,0 +1,66 @@
+use hex::FromHex;
+use metaverse_messages::{
+    packet::Packet, packet_types::PacketType, set_velocity_interpolation::SetVelocityInterpolation,
+    ui_events::UiEventTypes, velocity_interpolate_off::VelocityInterpolateOff,
+    velocity_interpolate_on::VelocityInterpolateOn,
+    velocity_interpolation_status::VelocityInterpolationStatus,
+};
+use uuid::uuid;
+
+const AGENT_DATA: &str = concat!(
+    "320dff8a7a594720a0f75a8df3698d9a",
+    "5748deccf629461c9a36a35a221fe21f",
+);
+
+#[test]
+fn test_velocity_interpolate_on_and_off() {
+    let agent_id = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+    let session_id = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+
+    let bytes = Packet::new_velocity_interpolate_on(VelocityInterpolateOn {
+        agent_id,
+        session_id,
+    })
+    .to_bytes();
+    assert_eq!(bytes[6..10], [0xff, 0xff, 0x00, 0x7d]);
+    assert_eq!(bytes[10..], Vec::from_hex(AGENT_DATA).unwrap());
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::VelocityInterpolateOn(on) => assert_eq!(on.agent_id, agent_id),
+        _ => panic!("expected VelocityInterpolateOn"),
+    }
+
+    let bytes = Packet::new_velocity_interpolate_off(VelocityInterpolateOff {
+        agent_id,
+        session_id,
+    })
+    .to_bytes();
+    assert_eq!(bytes[6..10], [0xff, 0xff, 0x00, 0x7e]);
+    assert_eq!(bytes[10..], Vec::from_hex(AGENT_DATA).unwrap());
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::VelocityInterpolateOff(off) => assert_eq!(off.session_id, session_id),
+        _ => panic!("expected VelocityInterpolateOff"),
+    }
+}
+
+#[test]
+fn test_set_velocity_interpolation() {
+    let set = SetVelocityInterpolation { enabled: false };
+    match Packet::from_bytes(&Packet::new_set_velocity_interpolation(set.clone()).to_bytes())
+        .unwrap()
+        .body
+    {
+        PacketType::SetVelocityInterpolation(parsed) => assert_eq!(*parsed, set),
+        _ => panic!("expected SetVelocityInterpolation"),
+    }
+
+    let status = VelocityInterpolationStatus { enabled: true };
+    let body = PacketType::VelocityInterpolationStatus(Box::new(status.clone()));
+    assert!(matches!(
+        body.ui_event(),
+        UiEventTypes::VelocityInterpolationEvent
+    ));
+    match UiEventTypes::VelocityInterpolationEvent.packet_type_from_bytes(&body.ui_event_bytes()) {
+        Some(PacketType::VelocityInterpolationStatus(parsed)) => assert_eq!(*parsed, status),
+        _ => panic!("failed to decode VelocityInterpolationEvent"),
+    }
+}
@@ -0,0 +1,134 @@
+use glam::DVec3;
+use metaverse_messages::{
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    viewer_effect::{
+        Effect, EffectData, EffectType, LookAtType, PointAtType, SpiralData, ViewerEffect,
+        LOOK_AT_DATA_SIZE, SPIRAL_DATA_SIZE,
+    },
+};
+use uuid::{uuid, Uuid};
+
+const SOURCE_ID: Uuid = uuid!("a2e76fcd-9360-4f6d-a924-000000000003");
+const TARGET_ID: Uuid = uuid!("6ec3d9a5-5a6d-4c2b-9c5b-1f8f8dbd6d42");
+
+// the blob every known effect starts with
+fn targets(position: DVec3) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(SOURCE_ID.as_bytes());
+    bytes.extend_from_slice(TARGET_ID.as_bytes());
+    bytes.extend_from_slice(&position.x.to_le_bytes());
+    bytes.extend_from_slice(&position.y.to_le_bytes());
+    bytes.extend_from_slice(&position.z.to_le_bytes());
+    bytes
+}
+
+#[test]
+fn test_beam_packing() {
+    let position = DVec3::new(256128.0, 256130.5, 25.25);
+    let beam = Effect::beam(SOURCE_ID, TARGET_ID, position, [255, 128, 0, 255], 0.5);
+    assert_eq!(beam.effect_type, EffectType::Beam);
+
+    let bytes = beam.data.to_bytes();
+    assert_eq!(bytes.len(), SPIRAL_DATA_SIZE);
+    assert_eq!(bytes, targets(position));
+    assert_eq!(EffectData::from_bytes(EffectType::Beam, &bytes), beam.data);
+    assert_eq!(
+        EffectData::from_bytes(EffectType::Sphere, &bytes),
+        EffectData::Spiral(SpiralData {
+            source_id: SOURCE_ID,
+            target_id: TARGET_ID,
+            target_position: position,
+        })
+    );
+}
+
+#[test]
+fn test_look_at_packing() {
+    let offset = DVec3::new(0.0, 0.5, 1.75);
+    let look_at = Effect::look_at(SOURCE_ID, TARGET_ID, offset, LookAtType::Focus, 2.0);
+
+    let bytes = look_at.data.to_bytes();
+    assert_eq!(bytes.len(), LOOK_AT_DATA_SIZE);
+    assert_eq!(bytes[..SPIRAL_DATA_SIZE], targets(offset));
+    assert_eq!(bytes[SPIRAL_DATA_SIZE], 8);
+    assert_eq!(
+        EffectData::from_bytes(EffectType::LookAt, &bytes),
+        look_at.data
+    );
+
+    let point_at = Effect::point_at(SOURCE_ID, TARGET_ID, offset, PointAtType::Grab, 2.0);
+    let bytes = point_at.data.to_bytes();
+    assert_eq!(bytes[SPIRAL_DATA_SIZE], 2);
+    assert_eq!(
+        EffectData::from_bytes(EffectType::PointAt, &bytes),
+        point_at.data
+    );
+}
+
+#[test]
+fn test_unknown_effects_are_kept() {
+    let blob = vec![1, 2, 3, 4, 5];
+    let custom = EffectType::from_bytes(42);
+    assert_eq!(custom, EffectType::Unknown(42));
+    assert_eq!(custom.to_bytes(), 42);
+    assert_eq!(
+        EffectData::from_bytes(custom, &blob),
+        EffectData::Raw(blob.clone())
+    );
+
+    // a known type whose blob doesn't have its layout is kept too
+    let short = targets(DVec3::ZERO)[..40].to_vec();
+    assert_eq!(
+        EffectData::from_bytes(EffectType::LookAt, &short),
+        EffectData::Raw(short.clone())
+    );
+}
+
+#[test]
+fn test_viewer_effect_round_trip() {
+    let mut custom = Effect::new(
+        EffectType::Unknown(42),
+        1.0,
+        [1, 2, 3, 4],
+        EffectData::Raw(vec![9; 12]),
+    );
+    custom.agent_id = SOURCE_ID;
+    let viewer_effect = ViewerEffect {
+        agent_id: SOURCE_ID,
+        session_id: Uuid::new_v4(),
+        effects: vec![
+            Effect::beam(SOURCE_ID, TARGET_ID, DVec3::new(1.0, 2.0, 3.0), [0; 4], 1.0),
+            Effect::look_at(SOURCE_ID, Uuid::nil(), DVec3::ZERO, LookAtType::Idle, 3.0),
+            custom,
+        ],
+    };
+    let packet =
+        Packet::from_bytes(&Packet::new_viewer_effect(viewer_effect.clone()).to_bytes()).unwrap();
+    let parsed = match &packet.body {
+        PacketType::ViewerEffect(parsed) => parsed.clone(),
+        _ => panic!("expected ViewerEffect"),
+    };
+    assert_eq!(*parsed, viewer_effect);
+
+    let event = packet.body.ui_event();
+    assert!(matches!(event, UiEventTypes::ViewerEffectEvent));
+    match event.packet_type_from_bytes(&packet.body.ui_event_bytes()) {
+        Some(PacketType::ViewerEffect(decoded)) => assert_eq!(decoded, parsed),
+        _ => panic!("failed to decode ViewerEffectEvent"),
+    }
+}
+
+#[test]
+fn test_viewer_effect_truncated() {
+    let viewer_effect = ViewerEffect::new(vec![Effect::look_at(
+        SOURCE_ID,
+        TARGET_ID,
+        DVec3::ZERO,
+        LookAtType::Select,
+        1.0,
+    )]);
+    let bytes = viewer_effect.to_bytes();
+    assert!(ViewerEffect::from_bytes(&bytes[..bytes.len() - 10]).is_err());
+}
@@ -0,0 +1,77 @@
+use hex::FromHex;
+use metaverse_messages::{
+    agent_fov::AgentFOV,
+    agent_height_width::AgentHeightWidth,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+};
+use uuid::uuid;
+
+#[test]
+fn test_agent_height_width() {
+    let bytes = Vec::from_hex(concat!(
+        "400000001200ffff0053",
+        "8a1f4b0c3f4e4d529c1a2b7e5d6f8a90",
+        "0d9e7c6b5a4f4e3d8c2b1a0f9e8d7c6b",
+        "efbeadde",
+        "03000000",
+        "3804",
+        "8007",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let height_width = match &packet.body {
+        PacketType::AgentHeightWidth(height_width) => height_width.clone(),
+        _ => panic!("expected AgentHeightWidth"),
+    };
+    assert_eq!(
+        *height_width,
+        AgentHeightWidth {
+            agent_id: uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90"),
+            session_id: uuid!("0d9e7c6b-5a4f-4e3d-8c2b-1a0f9e8d7c6b"),
+            circuit_code: 0xdeadbeef,
+            gen_counter: 3,
+            height: 1080,
+            width: 1920,
+        }
+    );
+    assert_eq!(height_width.to_bytes(), bytes[10..]);
+}
+
+#[test]
+fn test_agent_fov() {
+    let bytes = Vec::from_hex(concat!(
+        "400000001300ffff0052",
+        "8a1f4b0c3f4e4d529c1a2b7e5d6f8a90",
+        "0d9e7c6b5a4f4e3d8c2b1a0f9e8d7c6b",
+        "efbeadde",
+        "01000000",
+        "0000803f",
+    ))
+    .unwrap();
+    let packet = Packet::from_bytes(&bytes).unwrap();
+    let fov = match &packet.body {
+        PacketType::AgentFOV(fov) => fov.clone(),
+        _ => panic!("expected AgentFOV"),
+    };
+    assert_eq!(fov.gen_counter, 1);
+    assert_eq!(fov.vertical_angle, 1.0);
+    assert_eq!(fov.to_bytes(), bytes[10..]);
+}
+
+#[test]
+fn test_viewport_new() {
+    let height_width = AgentHeightWidth::new(600, 800);
+    assert!(height_width.agent_id.is_nil());
+    assert_eq!(height_width.gen_counter, 0);
+    let packet = Packet::new_agent_height_width(height_width.clone());
+    match Packet::from_bytes(&packet.to_bytes()).unwrap().body {
+        PacketType::AgentHeightWidth(parsed) => assert_eq!(*parsed, height_width),
+        _ => panic!("expected AgentHeightWidth"),
+    }
+
+    let fov = AgentFOV::new(std::f32::consts::FRAC_PI_3);
+    assert_eq!(fov.to_bytes().len(), 44);
+    assert_eq!(AgentFOV::from_bytes(&fov.to_bytes()).unwrap(), fov);
+    assert!(AgentFOV::from_bytes(&fov.to_bytes()[..42]).is_err());
+}
@@ -0,0 +1,132 @@
+use metaverse_messages::{
+    agent_wearables_request::AgentWearablesRequest,
+    agent_wearables_update::{AgentWearablesUpdate, Wearable},
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    ui_events::UiEventTypes,
+    utils::wearable_type::WearableType,
+    wearables::Wearables,
+};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const SESSION_ID: Uuid = uuid!("9f3c1b2a-4d5e-4f60-8a7b-1c2d3e4f5a6b");
+
+// the body of an AgentWearablesUpdate for an agent wearing only body parts and a shirt. Every
+// slot is sent, and the empty ones have nil ids.
+fn fixture() -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(AGENT_ID.as_bytes());
+    bytes.extend_from_slice(SESSION_ID.as_bytes());
+    bytes.extend_from_slice(&7u32.to_le_bytes());
+    bytes.push(WearableType::COUNT as u8);
+    for wearable_type in 0..WearableType::COUNT as u8 {
+        if wearable_type <= 4 {
+            bytes.extend_from_slice(&[wearable_type + 1; 16]);
+            bytes.extend_from_slice(&[wearable_type + 0x81; 16]);
+        } else {
+            bytes.extend_from_slice(&[0; 32]);
+        }
+        bytes.push(wearable_type);
+    }
+    bytes
+}
+
+#[test]
+fn test_agent_wearables_request_round_trip() {
+    let request = AgentWearablesRequest {
+        agent_id: AGENT_ID,
+        session_id: SESSION_ID,
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(AgentWearablesRequest::from_bytes(&bytes).unwrap(), request);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_agent_wearables_request(request).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::AgentWearablesRequest(_)));
+}
+
+#[test]
+fn test_every_slot_parses_with_empty_slots() {
+    let update = AgentWearablesUpdate::from_bytes(&fixture()).unwrap();
+    assert_eq!(update.agent_id, AGENT_ID);
+    assert_eq!(update.serial_num, 7);
+    assert_eq!(update.wearables.len(), 17);
+
+    let types: Vec<WearableType> = update.wearables.iter().map(|w| w.wearable_type).collect();
+    assert_eq!(
+        types,
+        vec![
+            WearableType::Shape,
+            WearableType::Skin,
+            WearableType::Hair,
+            WearableType::Eyes,
+            WearableType::Shirt,
+            WearableType::Pants,
+            WearableType::Shoes,
+            WearableType::Socks,
+            WearableType::Jacket,
+            WearableType::Gloves,
+            WearableType::Undershirt,
+            WearableType::Underpants,
+            WearableType::Skirt,
+            WearableType::Alpha,
+            WearableType::Tattoo,
+            WearableType::Physics,
+            WearableType::Universal,
+        ]
+    );
+
+    assert_eq!(update.wearables[4].item_id, Uuid::from_bytes([5; 16]));
+    assert_eq!(update.wearables[4].asset_id, Uuid::from_bytes([0x85; 16]));
+    for wearable in &update.wearables[5..] {
+        assert!(wearable.item_id.is_nil());
+        assert!(wearable.asset_id.is_nil());
+    }
+}
+
+#[test]
+fn test_agent_wearables_update_round_trip() {
+    let bytes = fixture();
+    let update = AgentWearablesUpdate::from_bytes(&bytes).unwrap();
+    assert_eq!(update.to_bytes(), bytes);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_agent_wearables_update(update).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::AgentWearablesUpdate(_)));
+}
+
+#[test]
+fn test_unknown_wearable_type() {
+    assert_eq!(WearableType::from_bytes(17), WearableType::Unknown(17));
+    assert_eq!(WearableType::Unknown(200).to_bytes(), 200);
+}
+
+#[test]
+fn test_wearables_event_is_a_json_array() {
+    let wearables = Wearables {
+        wearables: vec![Wearable {
+            wearable_type: WearableType::Shape,
+            item_id: Uuid::from_bytes([1; 16]),
+            asset_id: Uuid::from_bytes([2; 16]),
+        }],
+    };
+    let body = PacketType::Wearables(Box::new(wearables.clone()));
+    assert!(matches!(body.ui_event(), UiEventTypes::WearablesEvent));
+
+    let json: serde_json::Value = serde_json::from_slice(&body.ui_event_bytes()).unwrap();
+    let array = json.as_array().unwrap();
+    assert_eq!(array.len(), 1);
+    assert_eq!(array[0]["wearable_type"], "Shape");
+    assert_eq!(array[0]["item_id"], "01010101-0101-0101-0101-010101010101");
+    assert_eq!(array[0]["asset_id"], "02020202-0202-0202-0202-020202020202");
+
+    match body
+        .ui_event()
+        .packet_type_from_bytes(&body.ui_event_bytes())
+    {
+        Some(PacketType::Wearables(parsed)) => assert_eq!(*parsed, wearables),
+        _ => panic!("failed to decode WearablesEvent"),
+    }
+}
@@ -0,0 +1,115 @@
+use metaverse_messages::{
+    abort_xfer::AbortXfer,
+    confirm_xfer_packet::ConfirmXferPacket,
+    packet::{Packet, PacketData},
+    packet_types::PacketType,
+    request_xfer::RequestXfer,
+    send_xfer_packet::SendXferPacket,
+};
+use uuid::Uuid;
+
+#[test]
+fn test_request_xfer_round_trip() {
+    let request = RequestXfer {
+        id: 0x0102_0304_0506_0708,
+        file_name: "upload.tmp".to_string(),
+        file_path: 3,
+        delete_on_completion: true,
+        use_big_packets: false,
+        vfile_id: Uuid::new_v4(),
+        vfile_type: 20,
+    };
+    let bytes = request.to_bytes();
+    assert_eq!(&bytes[0..8], &[8, 7, 6, 5, 4, 3, 2, 1]);
+    // the file name is a Variable1 with a null terminator
+    assert_eq!(bytes[8], 11);
+    assert_eq!(RequestXfer::from_bytes(&bytes).unwrap(), request);
+
+    let packet = Packet::from_bytes(&Packet::new_request_xfer(request).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::RequestXfer(_)));
+}
+
+#[test]
+fn test_packet_id_sets_last_packet_flag() {
+    assert_eq!(SendXferPacket::packet_id(0, false), 0);
+    assert_eq!(SendXferPacket::packet_id(0, true), 0x8000_0000);
+    assert_eq!(SendXferPacket::packet_id(5, false), 5);
+    assert_eq!(SendXferPacket::packet_id(5, true), 0x8000_0005);
+    // the flag can't be set by accident from the packet number
+    assert_eq!(SendXferPacket::packet_id(0x8000_0002, false), 2);
+}
+
+#[test]
+fn test_packet_number_ignores_last_packet_flag() {
+    let packet = SendXferPacket {
+        id: 1,
+        packet: 0x8000_0007,
+        data: vec![],
+    };
+    assert_eq!(packet.packet_number(), 7);
+    assert!(packet.is_last());
+
+    let packet = SendXferPacket {
+        id: 1,
+        packet: 7,
+        data: vec![],
+    };
+    assert_eq!(packet.packet_number(), 7);
+    assert!(!packet.is_last());
+}
+
+#[test]
+fn test_last_packet_flag_on_the_wire() {
+    let send_xfer_packet = SendXferPacket {
+        id: 42,
+        packet: SendXferPacket::packet_id(2, true),
+        data: vec![1, 2, 3],
+    };
+    let bytes = send_xfer_packet.to_bytes();
+    // the packet id is little endian, so the flag is the high bit of the last byte
+    assert_eq!(&bytes[8..12], &[2, 0, 0, 0x80]);
+    assert_eq!(&bytes[12..14], &[3, 0]);
+
+    let packet =
+        Packet::from_bytes(&Packet::new_send_xfer_packet(send_xfer_packet.clone()).to_bytes())
+            .unwrap();
+    match packet.body {
+        PacketType::SendXferPacket(parsed) => {
+            assert_eq!(*parsed, send_xfer_packet);
+            assert!(parsed.is_last());
+            assert_eq!(parsed.packet_number(), 2);
+        }
+        _ => panic!("expected SendXferPacket"),
+    }
+}
+
+#[test]
+fn test_send_xfer_packet_is_not_reliable() {
+    let packet = Packet::new_send_xfer_packet(SendXferPacket {
+        id: 1,
+        packet: 0,
+        data: vec![],
+    });
+    assert!(!packet.header.reliable);
+}
+
+#[test]
+fn test_confirm_and_abort_round_trip() {
+    let confirm = ConfirmXferPacket { id: 9, packet: 4 };
+    assert_eq!(
+        ConfirmXferPacket::from_bytes(&confirm.to_bytes()).unwrap(),
+        confirm
+    );
+    let packet = Packet::from_bytes(&Packet::new_confirm_xfer_packet(confirm).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::ConfirmXferPacket(_)));
+
+    let abort = AbortXfer {
+        id: 9,
+        result: AbortXfer::RESULT_TIMEOUT,
+    };
+    let bytes = abort.to_bytes();
+    assert_eq!(&bytes[8..12], &AbortXfer::RESULT_TIMEOUT.to_le_bytes());
+    assert_eq!(AbortXfer::from_bytes(&bytes).unwrap(), abort);
+    let packet = Packet::from_bytes(&Packet::new_abort_xfer(abort).to_bytes()).unwrap();
+    assert!(matches!(packet.body, PacketType::AbortXfer(_)));
+}
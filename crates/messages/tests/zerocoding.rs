@@ -0,0 +1,120 @@
+use hex::FromHex;
+use metaverse_messages::{
+    object_add::PrimBuilder,
+    packet::Packet,
+    packet_types::PacketType,
+    start_ping_check::StartPingCheck,
+    utils::zerocoding::{zero_decode, zero_encode},
+};
+use uuid::Uuid;
+
+#[test]
+fn test_zerocoding_round_trip() {
+    for bytes in [
+        vec![],
+        vec![0x00],
+        vec![0x01, 0x02],
+        vec![0x01, 0x00, 0x00, 0x02, 0x00],
+        vec![0x00; 255],
+        vec![0x00; 256],
+        vec![0x00; 1000],
+        [vec![0x07], vec![0x00; 510], vec![0x08]].concat(),
+    ] {
+        let encoded = zero_encode(&bytes);
+        assert!(!encoded.windows(2).any(|pair| pair == [0x00, 0x00]));
+        assert_eq!(zero_decode(&encoded), bytes);
+    }
+}
+
+#[test]
+fn test_zerocoding_runs() {
+    assert_eq!(
+        zero_encode(&[0x01, 0x00, 0x00, 0x00, 0x02]),
+        [0x01, 0x00, 0x03, 0x02]
+    );
+    // a run of exactly 255 fits one count, and one more zero starts another run
+    assert_eq!(zero_encode(&[0x00; 255]), [0x00, 0xff]);
+    assert_eq!(zero_encode(&[0x00; 256]), [0x00, 0xff, 0x00, 0x01]);
+    // trailing zeros are a run like any other
+    assert_eq!(zero_encode(&[0x05, 0x00, 0x00]), [0x05, 0x00, 0x02]);
+    assert_eq!(zero_decode(&[0x05, 0x00, 0x02]), [0x05, 0x00, 0x00]);
+    // a zero without a count at the very end is kept
+    assert_eq!(zero_decode(&[0x05, 0x00]), [0x05, 0x00]);
+}
+
+#[test]
+fn test_zerocoded_packet_by_default() {
+    let mut object_add = PrimBuilder::cube().build();
+    object_add.agent_id = Uuid::from_bytes([0x01; 16]);
+    object_add.session_id = Uuid::from_bytes([0x02; 16]);
+    let packet = Packet::new_object_add(object_add.clone());
+    assert!(!packet.header.zerocoded);
+    let bytes = packet.to_bytes();
+    assert_eq!(bytes[0] & 0x80, 0x80);
+    assert!(bytes.len() < 10 + 144);
+    let parsed = Packet::from_bytes(&bytes).unwrap();
+    assert!(parsed.header.zerocoded);
+    match parsed.body {
+        PacketType::ObjectAdd(parsed) => assert_eq!(*parsed, object_add),
+        _ => panic!("expected ObjectAdd"),
+    }
+}
+
+#[test]
+fn test_zerocoding_that_saves_nothing_is_skipped() {
+    // a ping without zeros in it would only grow
+    let mut packet = Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 1,
+        oldest_unacked: 0x01020304,
+    });
+    packet.header.zerocoded = true;
+    let bytes = packet.to_bytes();
+    assert_eq!(bytes[0] & 0x80, 0);
+    assert_eq!(bytes[6..], [0x01, 0x01, 0x04, 0x03, 0x02, 0x01]);
+}
+
+#[test]
+fn test_zerocoded_body_with_appended_acks() {
+    let mut packet = Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 9,
+        oldest_unacked: 0,
+    });
+    packet.header.zerocoded = true;
+    packet.header.appended_acks = true;
+    packet.header.ack_list = Some(vec![0x100, 0x2000000]);
+    let bytes = packet.to_bytes();
+    assert_eq!(
+        bytes,
+        Vec::from_hex(concat!(
+            "90000000000001", // zerocoded, appended acks, StartPingCheck
+            "09",
+            "0004",             // the zerocoded oldest unacked
+            "0000010002000000", // the acks, which aren't zerocoded
+            "02",
+        ))
+        .unwrap()
+    );
+    let parsed = Packet::from_bytes(&bytes).unwrap();
+    assert!(parsed.header.zerocoded);
+    assert_eq!(parsed.header.ack_list, Some(vec![0x100, 0x2000000]));
+    match parsed.body {
+        PacketType::StartPingCheck(ping) => {
+            assert_eq!(ping.ping_id, 9);
+            assert_eq!(ping.oldest_unacked, 0);
+        }
+        _ => panic!("expected StartPingCheck"),
+    }
+}
+
+#[test]
+fn test_zero_run_across_the_message_number() {
+    // Low 256 ends in a zero, which shares its run with the two zeros the body starts with
+    let bytes = Vec::from_hex("800000000100ffff010003aa").unwrap();
+    match Packet::from_bytes(&bytes).unwrap().body {
+        PacketType::Unknown(raw) => {
+            assert_eq!(raw.id, 256);
+            assert_eq!(raw.body, vec![0x00, 0x00, 0xaa]);
+        }
+        _ => panic!("expected Unknown"),
+    }
+}
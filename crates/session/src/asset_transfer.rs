@@ -0,0 +1,217 @@
+use log::warn;
+use metaverse_messages::asset_received::AssetReceived;
+use metaverse_messages::asset_transfer_failed::AssetTransferFailed;
+use metaverse_messages::transfer_info::TransferInfo;
+use metaverse_messages::transfer_packet::TransferPacket;
+use metaverse_messages::transfer_request::TransferRequest;
+use metaverse_messages::utils::asset_type::AssetType;
+use metaverse_messages::utils::transfer::TransferStatus;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long an asset transfer can go without receiving a packet before it fails
+pub const TRANSFER_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Keeps track of assets being downloaded with the Transfer system.
+/// Every download gets a fresh transfer id. The TransferPackets are put back in order by packet
+/// number, and the asset is finished once the packet with the Done status and every packet
+/// before it have arrived. Aborts from the simulator and transfers that stop receiving packets
+/// fail instead of waiting forever.
+#[derive(Debug)]
+pub struct AssetTransferManager {
+    /// the transfers in progress, by transfer id
+    pub transfers: HashMap<Uuid, AssetTransfer>,
+    /// how long a transfer can go without a packet before it fails
+    pub timeout: Duration,
+}
+
+/// an asset that is being downloaded
+#[derive(Debug)]
+pub struct AssetTransfer {
+    /// the asset being downloaded
+    pub asset_id: Uuid,
+    /// the type of the asset being downloaded
+    pub asset_type: AssetType,
+    /// size of the asset in bytes, once the TransferInfo has arrived
+    pub size: Option<i32>,
+    /// the data received so far, by packet number
+    pub chunks: BTreeMap<i32, Vec<u8>>,
+    /// the number of the packet with the Done status, once it has arrived
+    pub last_packet: Option<i32>,
+    /// when the transfer was requested or last received a packet
+    pub last_activity: Instant,
+}
+
+impl AssetTransfer {
+    fn is_complete(&self) -> bool {
+        match self.last_packet {
+            Some(last_packet) => (0..=last_packet).all(|packet| self.chunks.contains_key(&packet)),
+            None => false,
+        }
+    }
+}
+
+impl AssetTransferManager {
+    /// create a manager that fails transfers after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        AssetTransferManager {
+            transfers: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// start downloading an asset, returning the TransferRequest to send to the simulator
+    pub fn request(
+        &mut self,
+        asset_id: Uuid,
+        asset_type: AssetType,
+        priority: f32,
+        now: Instant,
+    ) -> TransferRequest {
+        let transfer_id = Uuid::new_v4();
+        self.transfers.insert(
+            transfer_id,
+            AssetTransfer {
+                asset_id,
+                asset_type,
+                size: None,
+                chunks: BTreeMap::new(),
+                last_packet: None,
+                last_activity: now,
+            },
+        );
+        TransferRequest::asset(transfer_id, asset_id, asset_type, priority)
+    }
+
+    /// handle the simulator's answer to a TransferRequest. Returns the failure if the simulator
+    /// refused the transfer.
+    pub fn handle_transfer_info(
+        &mut self,
+        transfer_info: TransferInfo,
+        now: Instant,
+    ) -> Option<AssetTransferFailed> {
+        let transfer = match self.transfers.get_mut(&transfer_info.transfer_id) {
+            Some(transfer) => transfer,
+            None => {
+                warn!(
+                    "received TransferInfo for unknown transfer {}",
+                    transfer_info.transfer_id
+                );
+                return None;
+            }
+        };
+        transfer.last_activity = now;
+        if transfer_info.status.is_failure() {
+            return self.fail(
+                transfer_info.transfer_id,
+                format!("simulator refused the transfer: {:?}", transfer_info.status),
+            );
+        }
+        transfer.size = Some(transfer_info.size);
+        None
+    }
+
+    /// handle a chunk of a transfer. Returns the asset once every packet has arrived, or the
+    /// failure if the simulator aborted the transfer.
+    pub fn handle_transfer_packet(
+        &mut self,
+        transfer_packet: TransferPacket,
+        now: Instant,
+    ) -> Option<Result<AssetReceived, AssetTransferFailed>> {
+        let transfer_id = transfer_packet.transfer_id;
+        let transfer = match self.transfers.get_mut(&transfer_id) {
+            Some(transfer) => transfer,
+            None => {
+                warn!(
+                    "received TransferPacket for unknown transfer {}",
+                    transfer_id
+                );
+                return None;
+            }
+        };
+        transfer.last_activity = now;
+        if transfer_packet.status.is_failure() {
+            return self
+                .fail(
+                    transfer_id,
+                    format!(
+                        "simulator aborted the transfer: {:?}",
+                        transfer_packet.status
+                    ),
+                )
+                .map(Err);
+        }
+        if transfer_packet.packet < 0 {
+            warn!(
+                "received invalid TransferPacket {} for transfer {}",
+                transfer_packet.packet, transfer_id
+            );
+            return None;
+        }
+        if transfer_packet.status == TransferStatus::Done {
+            transfer.last_packet = Some(transfer_packet.packet);
+        }
+        transfer
+            .chunks
+            .entry(transfer_packet.packet)
+            .or_insert(transfer_packet.data);
+        if !transfer.is_complete() {
+            return None;
+        }
+
+        let transfer = self.transfers.remove(&transfer_id)?;
+        let data: Vec<u8> = transfer.chunks.into_values().flatten().collect();
+        if let Some(size) = transfer.size {
+            if data.len() != size as usize {
+                warn!(
+                    "asset {} is {} bytes, but the TransferInfo said {}",
+                    transfer.asset_id,
+                    data.len(),
+                    size
+                );
+            }
+        }
+        Some(Ok(AssetReceived {
+            transfer_id,
+            asset_id: transfer.asset_id,
+            asset_type: transfer.asset_type,
+            data,
+        }))
+    }
+
+    /// fail every transfer that hasn't received a packet within the timeout
+    pub fn expire(&mut self, now: Instant) -> Vec<AssetTransferFailed> {
+        let expired: Vec<Uuid> = self
+            .transfers
+            .iter()
+            .filter(|(_, transfer)| now.duration_since(transfer.last_activity) >= self.timeout)
+            .map(|(transfer_id, _)| *transfer_id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|transfer_id| self.fail(transfer_id, "transfer timed out".to_string()))
+            .collect()
+    }
+
+    /// fail every transfer, because the session ended
+    pub fn cancel_all(&mut self) -> Vec<AssetTransferFailed> {
+        let transfers: Vec<Uuid> = self.transfers.keys().copied().collect();
+        transfers
+            .into_iter()
+            .filter_map(|transfer_id| self.fail(transfer_id, "session ended".to_string()))
+            .collect()
+    }
+
+    fn fail(&mut self, transfer_id: Uuid, reason: String) -> Option<AssetTransferFailed> {
+        let transfer = self.transfers.remove(&transfer_id)?;
+        warn!("download of asset {} failed: {}", transfer.asset_id, reason);
+        Some(AssetTransferFailed {
+            transfer_id,
+            asset_id: transfer.asset_id,
+            asset_type: transfer.asset_type,
+            reason,
+        })
+    }
+}
@@ -0,0 +1,226 @@
+use metaverse_messages::asset_upload_complete::AssetUploadComplete;
+use metaverse_messages::asset_upload_request::AssetUploadRequest;
+use metaverse_messages::asset_uploaded::AssetUploaded;
+use metaverse_messages::errors::{AssetUploadError, SessionError, XferError};
+use metaverse_messages::utils::asset_type::AssetType;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::oneshot;
+use tokio::sync::oneshot::error::TryRecvError;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::xfer::{XferKey, XferSender};
+
+/// how long an upload can wait for the simulator before it fails
+pub const UPLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+/// the largest asset that is sent inline in the AssetUploadRequest. Larger assets are sent with
+/// the Xfer system.
+pub const ASSET_INLINE_SIZE: usize = 1024;
+
+/// Keeps track of assets being uploaded with AssetUploadRequest.
+/// Small assets are sent inline, larger ones are registered with the XferSender for the
+/// simulator to pull by their asset id. Either way the upload finishes when the simulator sends
+/// an AssetUploadComplete for the asset id. Uploads fail if the Xfer fails, or if the simulator
+/// doesn't answer within the timeout. The timeout doesn't run while an Xfer is being pulled,
+/// since the XferSender gives up on stalled Xfers itself.
+#[derive(Debug)]
+pub struct AssetUploadManager {
+    /// the uploads in progress, by asset id
+    pub uploads: HashMap<Uuid, PendingUpload>,
+    /// how long an upload can wait for the simulator before it fails
+    pub timeout: Duration,
+}
+
+/// an asset waiting for its AssetUploadComplete
+#[derive(Debug)]
+pub struct PendingUpload {
+    /// the transaction id of the AssetUploadRequest
+    pub transaction_id: Uuid,
+    /// the type of the asset being uploaded
+    pub asset_type: AssetType,
+    /// when the upload started, or its Xfer finished
+    pub waiting_since: Instant,
+    /// the Xfer sending the asset, until it finishes
+    pub xfer: Option<oneshot::Receiver<Result<(), XferError>>>,
+    /// told the asset id, or why the upload failed
+    pub completion: oneshot::Sender<Result<Uuid, AssetUploadError>>,
+}
+
+/// true if an asset of this size is sent inline in the AssetUploadRequest
+pub fn is_inline(size: usize) -> bool {
+    size <= ASSET_INLINE_SIZE
+}
+
+impl AssetUploadManager {
+    /// create a manager that fails uploads after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        AssetUploadManager {
+            uploads: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// start uploading an asset, returning the AssetUploadRequest to send to the simulator.
+    /// If the asset is too big to send inline, its data is moved to the XferSender and the
+    /// request is sent without it.
+    pub fn request(
+        &mut self,
+        mut request: AssetUploadRequest,
+        secure_session_id: Uuid,
+        xfers: &mut XferSender,
+        now: Instant,
+    ) -> (AssetUploadRequest, AssetUpload) {
+        let asset_id = AssetUploadRequest::asset_id(request.transaction_id, secure_session_id);
+        let xfer = if is_inline(request.asset_data.len()) {
+            None
+        } else {
+            let data = std::mem::take(&mut request.asset_data);
+            Some(xfers.register(XferKey::VFileId(asset_id), data))
+        };
+        let (completion, receiver) = oneshot::channel();
+        self.uploads.insert(
+            asset_id,
+            PendingUpload {
+                transaction_id: request.transaction_id,
+                asset_type: request.asset_type,
+                waiting_since: now,
+                xfer,
+                completion,
+            },
+        );
+        let upload = AssetUpload {
+            transaction_id: request.transaction_id,
+            asset_id,
+            completion: receiver,
+        };
+        (request, upload)
+    }
+
+    /// finish the upload the AssetUploadComplete is for, returning the event for the UI.
+    /// Returns None if the asset isn't being uploaded.
+    pub fn handle_upload_complete(
+        &mut self,
+        complete: &AssetUploadComplete,
+    ) -> Option<AssetUploaded> {
+        if !complete.success {
+            return self.fail(complete.asset_id, "simulator failed to store the asset");
+        }
+        let upload = self.uploads.remove(&complete.asset_id)?;
+        let _ = upload.completion.send(Ok(complete.asset_id));
+        Some(AssetUploaded {
+            transaction_id: upload.transaction_id,
+            asset_id: complete.asset_id,
+            asset_type: upload.asset_type,
+            success: true,
+            reason: None,
+        })
+    }
+
+    /// fail the uploads whose Xfer failed, or that have waited longer than the timeout.
+    /// Uploads whose Xfer was never requested by the simulator are removed from the XferSender.
+    pub fn expire(&mut self, xfers: &mut XferSender, now: Instant) -> Vec<AssetUploaded> {
+        let mut failed = Vec::new();
+        for (asset_id, upload) in self.uploads.iter_mut() {
+            if let Some(xfer) = upload.xfer.as_mut() {
+                match xfer.try_recv() {
+                    Ok(Ok(())) => {
+                        upload.xfer = None;
+                        upload.waiting_since = now;
+                    }
+                    Ok(Err(e)) => failed.push((*asset_id, e.message)),
+                    Err(TryRecvError::Closed) => {
+                        failed.push((*asset_id, "xfer was dropped".to_string()))
+                    }
+                    Err(TryRecvError::Empty) => {
+                        let key = XferKey::VFileId(*asset_id);
+                        if xfers.pending.contains_key(&key)
+                            && now.duration_since(upload.waiting_since) >= self.timeout
+                        {
+                            xfers.pending.remove(&key);
+                            failed.push((
+                                *asset_id,
+                                "simulator never requested the asset data".to_string(),
+                            ));
+                        }
+                    }
+                }
+                continue;
+            }
+            if now.duration_since(upload.waiting_since) >= self.timeout {
+                failed.push((*asset_id, "timed out waiting for the simulator".to_string()));
+            }
+        }
+        failed
+            .into_iter()
+            .filter_map(|(asset_id, reason)| self.fail(asset_id, reason))
+            .collect()
+    }
+
+    /// fail the upload with the transaction id, like when the simulator refuses it with a
+    /// FeatureDisabled. Returns None if no upload has that transaction id.
+    pub fn fail_transaction(
+        &mut self,
+        transaction_id: Uuid,
+        reason: impl Into<String>,
+    ) -> Option<AssetUploaded> {
+        let asset_id = self
+            .uploads
+            .iter()
+            .find(|(_, upload)| upload.transaction_id == transaction_id)
+            .map(|(asset_id, _)| *asset_id)?;
+        self.fail(asset_id, reason)
+    }
+
+    /// fail every upload, for when the session ends
+    pub fn cancel_all(&mut self) -> Vec<AssetUploaded> {
+        let asset_ids: Vec<Uuid> = self.uploads.keys().copied().collect();
+        asset_ids
+            .into_iter()
+            .filter_map(|asset_id| self.fail(asset_id, "session ended"))
+            .collect()
+    }
+
+    fn fail(&mut self, asset_id: Uuid, reason: impl Into<String>) -> Option<AssetUploaded> {
+        let upload = self.uploads.remove(&asset_id)?;
+        let reason = reason.into();
+        let _ = upload
+            .completion
+            .send(Err(AssetUploadError::new(reason.clone())));
+        Some(AssetUploaded {
+            transaction_id: upload.transaction_id,
+            asset_id,
+            asset_type: upload.asset_type,
+            success: false,
+            reason: Some(reason),
+        })
+    }
+}
+
+/// Resolves to the id of the new asset once the simulator has stored it, or to why the upload
+/// failed.
+#[derive(Debug)]
+pub struct AssetUpload {
+    /// the transaction id of the AssetUploadRequest
+    pub transaction_id: Uuid,
+    /// the id the asset will be stored under
+    pub asset_id: Uuid,
+    /// told the asset id, or why the upload failed
+    pub completion: oneshot::Receiver<Result<Uuid, AssetUploadError>>,
+}
+
+impl Future for AssetUpload {
+    type Output = Result<Uuid, SessionError>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.completion)
+            .poll(cx)
+            .map(|result| match result {
+                Ok(result) => result.map_err(SessionError::AssetUpload),
+                Err(_) => Err(SessionError::AssetUpload(AssetUploadError::new(
+                    "upload was dropped before it finished",
+                ))),
+            })
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use metaverse_messages::attachments::{Attachment, Attachments};
+use metaverse_messages::kill_object::KillObjectData;
+use metaverse_messages::object_update::ObjectUpdate;
+use uuid::Uuid;
+
+/// Keeps track of the objects the agent is wearing.
+/// Attaching sends no answer of its own. The simulator sends the attached object as an
+/// ObjectUpdate parented to the agent's avatar, with the inventory item it was attached from in
+/// its AttachItemID name value, so those updates are what the worn list is made of. An object
+/// stops being worn when it is killed, or when an update no longer parents it to the avatar.
+/// Local ids only mean something in one region, so the list is forgotten when the agent changes
+/// regions, and the new region's updates fill it in again.
+#[derive(Debug)]
+pub struct AttachmentManager {
+    /// the agent's avatar's local id in the current region
+    pub agent_local_id: Option<u32>,
+    /// the worn objects, by local id
+    pub worn: HashMap<u32, Attachment>,
+}
+
+impl Default for AttachmentManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AttachmentManager {
+    /// create a manager that knows of no attachments
+    pub fn new() -> Self {
+        AttachmentManager {
+            agent_local_id: None,
+            worn: HashMap::new(),
+        }
+    }
+
+    /// handle an ObjectUpdate of the current region, returning true if the worn list changed
+    pub fn handle_object_update(&mut self, update: &ObjectUpdate, agent_id: Uuid) -> bool {
+        let mut changed = false;
+        for object in &update.objects {
+            if object.full_id == agent_id {
+                self.agent_local_id = Some(object.local_id);
+            }
+        }
+        for object in &update.objects {
+            let worn = match (self.agent_local_id, object.attach_item_id()) {
+                (Some(agent_local_id), Some(item_id)) if object.parent_id == agent_local_id => {
+                    Some(Attachment {
+                        local_id: object.local_id,
+                        object_id: object.full_id,
+                        item_id,
+                        attachment_point: object.attachment_point(),
+                    })
+                }
+                _ => None,
+            };
+            match worn {
+                Some(attachment) => {
+                    if self.worn.insert(object.local_id, attachment) != Some(attachment) {
+                        changed = true;
+                    }
+                }
+                None => changed |= self.worn.remove(&object.local_id).is_some(),
+            }
+        }
+        changed
+    }
+
+    /// handle a KillObject of the current region, returning true if the worn list changed
+    pub fn handle_kill(&mut self, kill: &KillObjectData) -> bool {
+        let mut changed = false;
+        for local_id in &kill.local_ids {
+            changed |= self.worn.remove(local_id).is_some();
+        }
+        changed
+    }
+
+    /// the local id of the object attached from an item
+    pub fn local_id_of_item(&self, item_id: Uuid) -> Option<u32> {
+        self.worn
+            .values()
+            .find(|attachment| attachment.item_id == item_id)
+            .map(|attachment| attachment.local_id)
+    }
+
+    /// the worn list, to send to the UI, in the order of the attachment points
+    pub fn list(&self) -> Attachments {
+        let mut attachments: Vec<Attachment> = self.worn.values().copied().collect();
+        attachments.sort_by_key(|attachment| {
+            (attachment.attachment_point.to_bytes(), attachment.local_id)
+        });
+        Attachments { attachments }
+    }
+
+    /// forget the local ids of the region being left
+    pub fn change_region(&mut self) {
+        self.agent_local_id = None;
+        self.worn.clear();
+    }
+
+    /// forget everything, for when the session ends
+    pub fn clear(&mut self) {
+        self.change_region();
+    }
+}
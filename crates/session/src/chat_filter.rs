@@ -0,0 +1,87 @@
+use metaverse_messages::chat_from_simulator::ChatType;
+use metaverse_messages::chat_pass::DEBUG_CHANNEL;
+use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::set_chat_filter::SetChatFilter;
+use std::collections::HashSet;
+
+/// Decides which nearby chat is sent to the UI, by its channel and its type.
+/// The UI picks the channels and chat types it wants with a SetChatFilter, and the rest is
+/// dropped. ChatFromSimulator has no channel, so its chat is on channel 0, or on DEBUG_CHANNEL
+/// if it is debug chat. Debug chat is only let through if debug is on, which keeps the errors
+/// of broken scripts from flooding the UI.
+/// The mailbox shares the filter with the task reading packets from the simulator, which drops
+/// filtered chat before it reaches the UI.
+#[derive(Debug)]
+pub struct ChatFilter {
+    /// the channels chat is sent from
+    pub channels: HashSet<i32>,
+    /// whether whispers are sent
+    pub whisper: bool,
+    /// whether normal chat is sent
+    pub say: bool,
+    /// whether shouts are sent
+    pub shout: bool,
+    /// whether debug chat is sent, on any channel
+    pub debug: bool,
+}
+
+impl Default for ChatFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChatFilter {
+    /// create a filter for everything on channel 0, without debug chat
+    pub fn new() -> Self {
+        let mut filter = ChatFilter {
+            channels: HashSet::new(),
+            whisper: false,
+            say: false,
+            shout: false,
+            debug: false,
+        };
+        filter.set(&SetChatFilter::default());
+        filter
+    }
+
+    /// replace the filter with the one the UI sent
+    pub fn set(&mut self, filter: &SetChatFilter) {
+        self.channels = filter.channels.iter().copied().collect();
+        self.whisper = filter.whisper;
+        self.say = filter.say;
+        self.shout = filter.shout;
+        self.debug = filter.debug;
+    }
+
+    /// true if a packet is chat the filter drops
+    pub fn blocks(&self, packet: &PacketType) -> bool {
+        match packet {
+            PacketType::ChatFromSimulator(chat) => {
+                let channel = match chat.chat_type {
+                    ChatType::Debug => DEBUG_CHANNEL,
+                    _ => 0,
+                };
+                !self.allows(channel, &chat.chat_type)
+            }
+            PacketType::ChatPass(chat) => !self.allows(chat.channel, &chat.chat_type),
+            _ => false,
+        }
+    }
+
+    /// true if chat of a type on a channel is sent to the UI
+    pub fn allows(&self, channel: i32, chat_type: &ChatType) -> bool {
+        if channel == DEBUG_CHANNEL || *chat_type == ChatType::Debug {
+            return self.debug;
+        }
+        if !self.channels.contains(&channel) {
+            return false;
+        }
+        match chat_type {
+            ChatType::Whisper => self.whisper,
+            ChatType::Normal | ChatType::Say => self.say,
+            ChatType::Shout => self.shout,
+            _ => true,
+        }
+    }
+}
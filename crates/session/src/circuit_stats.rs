@@ -0,0 +1,166 @@
+use metaverse_messages::circuit_stats_report::{
+    CircuitReport, CircuitStatsReport, PacketTypeCount,
+};
+use metaverse_messages::header::PacketFrequency;
+use metaverse_messages::packet::Packet;
+use metaverse_messages::packet_types::PacketType;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+/// how often the statistics of the circuits are sent to the UI
+pub const CIRCUIT_STATS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The counts of the packets sent to and received from one simulator
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CircuitStats {
+    /// the datagrams sent, resends included
+    pub packets_sent: u64,
+    /// the packets received and handled, without duplicates and the ones that didn't parse
+    pub packets_received: u64,
+    /// the size of every datagram sent
+    pub bytes_sent: u64,
+    /// the size of every datagram received, handled or not
+    pub bytes_received: u64,
+    /// the reliable packets that were sent again because their acks were late
+    pub resends: u64,
+    /// the packets that arrived more than once
+    pub duplicates: u64,
+    /// the acks sent, in PacketAcks or appended to other packets
+    pub acks_sent: u64,
+    /// the acks received
+    pub acks_received: u64,
+    /// the datagrams that were cut off or didn't parse
+    pub parse_failures: u64,
+    /// how many packets with each frequency and message number were handled
+    pub received_by_type: HashMap<(PacketFrequency, u16), u64>,
+}
+
+impl CircuitStats {
+    /// describe the counts for the UI
+    pub fn report(&self, addr: &SocketAddr) -> CircuitReport {
+        let mut received_by_type: Vec<PacketTypeCount> = self
+            .received_by_type
+            .iter()
+            .map(|((frequency, id), count)| PacketTypeCount {
+                frequency: frequency.to_string(),
+                id: *id,
+                count: *count,
+            })
+            .collect();
+        received_by_type.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.frequency.cmp(&b.frequency))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        CircuitReport {
+            address: addr.to_string(),
+            packets_sent: self.packets_sent,
+            packets_received: self.packets_received,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            resends: self.resends,
+            duplicates: self.duplicates,
+            acks_sent: self.acks_sent,
+            acks_received: self.acks_received,
+            parse_failures: self.parse_failures,
+            received_by_type,
+        }
+    }
+}
+
+/// Keeps the statistics of every circuit, for debugging connection problems. The mailbox counts
+/// the packets it sends as they go out, and the ones the task reading from the socket hands it
+/// as they come in, so the counts belong to the mailbox alone and need no locking. They are sent
+/// to the UI as a CircuitStatsEvent every interval, and whenever the UI asks for them.
+/// Circuits are forgotten when they are closed.
+#[derive(Debug)]
+pub struct CircuitStatsTracker {
+    /// the statistics of each circuit
+    pub circuits: HashMap<SocketAddr, CircuitStats>,
+    /// how often the statistics are sent to the UI
+    pub interval: Duration,
+}
+
+impl Default for CircuitStatsTracker {
+    fn default() -> Self {
+        Self::new(CIRCUIT_STATS_INTERVAL)
+    }
+}
+
+impl CircuitStatsTracker {
+    /// create a tracker whose statistics are sent to the UI every interval
+    pub fn new(interval: Duration) -> Self {
+        CircuitStatsTracker {
+            circuits: HashMap::new(),
+            interval,
+        }
+    }
+
+    /// count a packet as it is sent, with the size of its datagram. Resends are packets with
+    /// their resent flag set.
+    pub fn sent(&mut self, addr: SocketAddr, packet: &Packet, size: usize) {
+        let circuit = self.circuits.entry(addr).or_default();
+        circuit.packets_sent += 1;
+        circuit.bytes_sent += size as u64;
+        if packet.header.resent {
+            circuit.resends += 1;
+        }
+        let mut acks = packet.header.ack_list.as_ref().map_or(0, Vec::len);
+        if let PacketType::PacketAck(ack) = &packet.body {
+            acks += ack.packet_ids.len();
+        }
+        circuit.acks_sent += acks as u64;
+    }
+
+    /// count a packet that was received and handled, with the size of its datagram
+    pub fn received(&mut self, addr: SocketAddr, frequency: PacketFrequency, id: u16, size: usize) {
+        let circuit = self.circuits.entry(addr).or_default();
+        circuit.packets_received += 1;
+        circuit.bytes_received += size as u64;
+        *circuit.received_by_type.entry((frequency, id)).or_default() += 1;
+    }
+
+    /// count a packet that arrived again, and was dropped
+    pub fn duplicate(&mut self, addr: SocketAddr, size: usize) {
+        let circuit = self.circuits.entry(addr).or_default();
+        circuit.duplicates += 1;
+        circuit.bytes_received += size as u64;
+    }
+
+    /// count a datagram that was dropped because it was cut off or didn't parse
+    pub fn parse_failed(&mut self, addr: SocketAddr, size: usize) {
+        let circuit = self.circuits.entry(addr).or_default();
+        circuit.parse_failures += 1;
+        circuit.bytes_received += size as u64;
+    }
+
+    /// count the acks that arrived from a simulator
+    pub fn acks_received(&mut self, addr: SocketAddr, count: usize) {
+        self.circuits.entry(addr).or_default().acks_received += count as u64;
+    }
+
+    /// the statistics of every circuit for the UI, with the round trip time of the latest ping
+    pub fn report(&self, ping: Duration) -> CircuitStatsReport {
+        let mut addrs: Vec<&SocketAddr> = self.circuits.keys().collect();
+        addrs.sort();
+        CircuitStatsReport {
+            ping_ms: ping.as_secs_f64() * 1000.0,
+            circuits: addrs
+                .into_iter()
+                .map(|addr| self.circuits[addr].report(addr))
+                .collect(),
+        }
+    }
+
+    /// forget the statistics of a circuit that has been closed
+    pub fn circuit_closed(&mut self, addr: &SocketAddr) {
+        self.circuits.remove(addr);
+    }
+
+    /// forget every circuit, for when the session ends. The interval is kept.
+    pub fn clear(&mut self) {
+        self.circuits.clear();
+    }
+}
@@ -0,0 +1,82 @@
+use log::debug;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::SocketAddr;
+
+/// how many of the latest sequence numbers from each simulator are remembered
+pub const DEDUPE_WINDOW: usize = 4096;
+
+/// Remembers the sequence numbers of the latest packets from each simulator, to drop the ones
+/// that arrive twice. Simulators resend reliable packets whose acks they missed, and the network
+/// can duplicate any packet, so a number that was seen recently is a duplicate whether or not
+/// the packet has its resent flag set. The flag only tells the two apart in the logs: a resent
+/// packet whose number wasn't seen is one whose first copy was lost, and is handled as usual.
+/// Duplicates of reliable packets are still acked, since the simulator resent them because it
+/// never got the ack.
+/// Each circuit keeps at most the window's worth of numbers, forgetting the oldest first, so
+/// numbers that come around again after the counter wraps were forgotten long before.
+/// The mailbox shares the filter with the task reading packets from the simulator.
+#[derive(Debug)]
+pub struct DuplicateFilter {
+    /// the numbers seen on each circuit
+    pub circuits: HashMap<SocketAddr, SeenNumbers>,
+    /// how many numbers each circuit remembers
+    pub window: usize,
+}
+
+/// the latest sequence numbers from one simulator
+#[derive(Debug, Default)]
+pub struct SeenNumbers {
+    /// the numbers, for looking them up
+    pub seen: HashSet<u32>,
+    /// the same numbers, in the order they arrived
+    pub order: VecDeque<u32>,
+}
+
+impl Default for DuplicateFilter {
+    fn default() -> Self {
+        Self::new(DEDUPE_WINDOW)
+    }
+}
+
+impl DuplicateFilter {
+    /// create a filter that remembers the latest window numbers from each simulator
+    pub fn new(window: usize) -> Self {
+        DuplicateFilter {
+            circuits: HashMap::new(),
+            window,
+        }
+    }
+
+    /// remember a packet's sequence number. Returns true if the simulator already sent it.
+    pub fn is_duplicate(&mut self, addr: SocketAddr, sequence_number: u32, resent: bool) -> bool {
+        let circuit = self.circuits.entry(addr).or_default();
+        if circuit.seen.contains(&sequence_number) {
+            if !resent {
+                debug!(
+                    "packet {} from {} arrived twice without being resent",
+                    sequence_number, addr
+                );
+            }
+            return true;
+        }
+        circuit.seen.insert(sequence_number);
+        circuit.order.push_back(sequence_number);
+        while circuit.order.len() > self.window {
+            if let Some(oldest) = circuit.order.pop_front() {
+                circuit.seen.remove(&oldest);
+            }
+        }
+        false
+    }
+
+    /// forget the numbers of a circuit that has been closed, so a new circuit to the same
+    /// simulator can count from the start again
+    pub fn circuit_closed(&mut self, addr: &SocketAddr) {
+        self.circuits.remove(addr);
+    }
+
+    /// forget every circuit, for when the session ends
+    pub fn clear(&mut self) {
+        self.circuits.clear();
+    }
+}
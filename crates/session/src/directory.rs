@@ -0,0 +1,181 @@
+use metaverse_messages::dir_find_query::{DirFindQuery, DirectorySearchKind};
+use metaverse_messages::dir_people_reply::{DirPeopleReply, DirPerson};
+use metaverse_messages::dir_places_reply::{DirPlace, DirPlacesReply};
+use metaverse_messages::directory_people_page::DirectoryPeoplePage;
+use metaverse_messages::directory_places_page::DirectoryPlacesPage;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long to wait for more DirPeopleReply or DirPlacesReply packets before sending the page
+/// on to the UI
+pub const DIRECTORY_PAGE_WINDOW: Duration = Duration::from_millis(500);
+/// how long to wait for the first reply to a directory search before sending an empty page
+pub const DIRECTORY_SEARCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Gathers the pages of results for the directory searches the UI has made with DirFindQuery.
+/// The simulator answers a page with as many replies as its results need, and doesn't say which
+/// one is the last, so replies are gathered by their query id and sent on as one page once no
+/// more have arrived for the window. A page that is never answered is sent on empty.
+/// Pages have PAGE_SIZE results, and the simulator sends one more if there are more pages. That
+/// result is left off the page, and the page carries the start index of the next one instead.
+/// The UI asks for it by sending the query again with that start index.
+/// The UI can pick the query id, or leave it nil for the session to pick one. A query id that
+/// another page is still waiting with is replaced, so their results don't mix.
+#[derive(Debug)]
+pub struct DirectoryManager {
+    /// the pages waiting for their results, by query id
+    pub searches: HashMap<Uuid, DirectorySearch>,
+    /// how long to wait for more replies
+    pub window: Duration,
+    /// how long to wait for the first reply
+    pub timeout: Duration,
+}
+
+/// a page of a directory search that hasn't been sent to the UI yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectorySearch {
+    /// whether the search is for people or places
+    pub kind: DirectorySearchKind,
+    /// the text that was searched for
+    pub text: String,
+    /// the index of the first result of the page
+    pub start_index: i32,
+    /// when the query was sent
+    pub sent: Instant,
+    /// when the last reply arrived, if any have
+    pub last_received: Option<Instant>,
+    /// the residents that have arrived, for a search for people
+    pub people: Vec<DirPerson>,
+    /// the places that have arrived, for a search for places
+    pub places: Vec<DirPlace>,
+}
+
+/// a finished page of results
+#[derive(Debug, Clone, PartialEq)]
+pub enum DirectoryPage {
+    /// a page of residents
+    People(DirectoryPeoplePage),
+    /// a page of places
+    Places(DirectoryPlacesPage),
+}
+
+impl DirectoryManager {
+    /// create a manager that gathers replies for the window, and gives up after the timeout
+    pub fn new(window: Duration, timeout: Duration) -> Self {
+        DirectoryManager {
+            searches: HashMap::new(),
+            window,
+            timeout,
+        }
+    }
+
+    /// start a page of a search, returning the query to send with its query id filled in
+    pub fn start(&mut self, mut query: DirFindQuery, now: Instant) -> DirFindQuery {
+        if query.query_id.is_nil() || self.searches.contains_key(&query.query_id) {
+            query.query_id = Uuid::new_v4();
+        }
+        query.query_start = query.query_start.max(0);
+        self.searches.insert(
+            query.query_id,
+            DirectorySearch {
+                kind: query.kind(),
+                text: query.query_text.clone(),
+                start_index: query.query_start,
+                sent: now,
+                last_received: None,
+                people: Vec::new(),
+                places: Vec::new(),
+            },
+        );
+        query
+    }
+
+    /// add the residents of a reply to their page. Returns false if no search for people has
+    /// its query id.
+    pub fn add_people(&mut self, reply: &DirPeopleReply, now: Instant) -> bool {
+        match self.searches.get_mut(&reply.query_id) {
+            Some(search) if search.kind == DirectorySearchKind::People => {
+                search.people.extend(reply.people.iter().cloned());
+                search.last_received = Some(now);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// add the places of a reply to their page. Returns false if no search for places has its
+    /// query id.
+    pub fn add_places(&mut self, reply: &DirPlacesReply, now: Instant) -> bool {
+        match self.searches.get_mut(&reply.query_id) {
+            Some(search) if search.kind == DirectorySearchKind::Places => {
+                search.places.extend(reply.places.iter().cloned());
+                search.last_received = Some(now);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// take the pages that have stopped getting replies, and the ones that never got any
+    pub fn flush(&mut self, now: Instant) -> Vec<DirectoryPage> {
+        let done: Vec<Uuid> = self
+            .searches
+            .iter()
+            .filter(|(_, search)| match search.last_received {
+                Some(last_received) => now.duration_since(last_received) >= self.window,
+                None => now.duration_since(search.sent) >= self.timeout,
+            })
+            .map(|(query_id, _)| *query_id)
+            .collect();
+        done.into_iter()
+            .filter_map(|query_id| {
+                self.searches
+                    .remove(&query_id)
+                    .map(|search| search.into_page(query_id))
+            })
+            .collect()
+    }
+
+    /// forget the searches, for when the session ends
+    pub fn clear(&mut self) {
+        self.searches.clear();
+    }
+}
+
+impl DirectorySearch {
+    /// turn the results into a page, leaving off the result that says there are more pages
+    fn into_page(mut self, query_id: Uuid) -> DirectoryPage {
+        let count = match self.kind {
+            DirectorySearchKind::People => self.people.len(),
+            DirectorySearchKind::Places => self.places.len(),
+        };
+        let next_start_index = if count > DirFindQuery::PAGE_SIZE {
+            Some(self.start_index + DirFindQuery::PAGE_SIZE as i32)
+        } else {
+            None
+        };
+        match self.kind {
+            DirectorySearchKind::People => {
+                self.people.truncate(DirFindQuery::PAGE_SIZE);
+                DirectoryPage::People(DirectoryPeoplePage {
+                    query_id,
+                    text: self.text,
+                    start_index: self.start_index,
+                    people: self.people,
+                    next_start_index,
+                })
+            }
+            DirectorySearchKind::Places => {
+                self.places.truncate(DirFindQuery::PAGE_SIZE);
+                DirectoryPage::Places(DirectoryPlacesPage {
+                    query_id,
+                    text: self.text,
+                    start_index: self.start_index,
+                    places: self.places,
+                    next_start_index,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use metaverse_messages::change_user_rights::ChangeUserRights;
+use metaverse_messages::friend_list::{Friend, FriendList};
+use metaverse_messages::friendship_status::FriendshipStatus;
+use metaverse_messages::grant_user_rights::GrantUserRights;
+use metaverse_messages::improved_instant_message::{ImprovedInstantMessage, InstantMessageDialog};
+use metaverse_messages::login_system::login_response::{BuddyListValues, FriendsRights};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Keeps the agent's friends, and which of them are online.
+/// The friends come from the buddy list of the login response, and start out offline. The
+/// simulator then sends OnlineNotification and OfflineNotification as friends log in and out,
+/// including the friends that were already online when the agent logged in.
+/// Notifications for friends that are already in that state are dropped, so the UI only hears
+/// about changes. A notification for someone who isn't on the buddy list adds them, since a
+/// friendship offered during the session isn't on it.
+/// Friendships are offered and answered with ImprovedInstantMessages. Offers to the agent are
+/// kept by the id of their message until the agent answers them, so an answer can only be sent
+/// for an offer that was made. New friends can see each other online, like the simulator sets
+/// up new friendships.
+#[derive(Debug)]
+pub struct FriendManager {
+    /// the friends, by agent id
+    pub friends: HashMap<Uuid, Friend>,
+    /// the agents offering friendship to the agent, by the id of the offer's message
+    pub offers: HashMap<Uuid, Uuid>,
+}
+
+impl Default for FriendManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FriendManager {
+    /// create a manager with no friends
+    pub fn new() -> Self {
+        FriendManager {
+            friends: HashMap::new(),
+            offers: HashMap::new(),
+        }
+    }
+
+    /// add the friends of the buddy list from the login response. Friends that are already
+    /// known keep whether they are online. Entries with ids that aren't UUIDs are skipped.
+    pub fn load(&mut self, buddy_list: &[BuddyListValues]) {
+        for buddy in buddy_list {
+            let Ok(id) = Uuid::parse_str(&buddy.buddy_id) else {
+                continue;
+            };
+            let online = self.is_online(&id);
+            self.friends.insert(
+                id,
+                Friend {
+                    id,
+                    online,
+                    rights_given: buddy.buddy_rights_given.clone(),
+                    rights_has: buddy.buddy_rights_has.clone(),
+                },
+            );
+        }
+    }
+
+    /// whether a friend is online. Anyone who isn't a friend is never online.
+    pub fn is_online(&self, id: &Uuid) -> bool {
+        self.friends.get(id).is_some_and(|friend| friend.online)
+    }
+
+    /// the friends and which of them are online, to send to the UI. Sorted by id, so the list
+    /// doesn't change order between sends.
+    pub fn list(&self) -> FriendList {
+        let mut friends: Vec<Friend> = self.friends.values().cloned().collect();
+        friends.sort_by_key(|friend| friend.id);
+        FriendList { friends }
+    }
+
+    /// mark friends as online, returning the ones that weren't already
+    pub fn set_online(&mut self, ids: &[Uuid]) -> Vec<Uuid> {
+        self.set_presence(ids, true)
+    }
+
+    /// mark friends as offline, returning the ones that weren't already
+    pub fn set_offline(&mut self, ids: &[Uuid]) -> Vec<Uuid> {
+        self.set_presence(ids, false)
+    }
+
+    /// handle a friendship offer or answer sent to the agent, returning the event for the UI.
+    /// Returns None for other messages.
+    pub fn handle_instant_message(
+        &mut self,
+        im: &ImprovedInstantMessage,
+    ) -> Option<FriendshipStatus> {
+        match im.dialog {
+            InstantMessageDialog::FriendshipOffered => {
+                self.offers.insert(im.id, im.from_agent_id);
+                Some(FriendshipStatus::Offered {
+                    im_session_id: im.id,
+                    agent_id: im.from_agent_id,
+                    name: im.from_agent_name.clone(),
+                    message: im.message.clone(),
+                })
+            }
+            InstantMessageDialog::FriendshipAccepted => {
+                // the agent that accepted is online, since it just answered
+                self.add(im.from_agent_id, true);
+                Some(FriendshipStatus::Accepted {
+                    agent_id: im.from_agent_id,
+                    name: im.from_agent_name.clone(),
+                })
+            }
+            InstantMessageDialog::FriendshipDeclined => Some(FriendshipStatus::Declined {
+                agent_id: im.from_agent_id,
+                name: im.from_agent_name.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// accept an offer, returning the agent that offered it, who is now a friend. Returns None
+    /// if there is no offer with the message id.
+    pub fn accept(&mut self, im_session_id: &Uuid) -> Option<Uuid> {
+        let agent_id = self.offers.remove(im_session_id)?;
+        self.add(agent_id, true);
+        Some(agent_id)
+    }
+
+    /// decline an offer, returning the agent that offered it. Returns None if there is no offer
+    /// with the message id.
+    pub fn decline(&mut self, im_session_id: &Uuid) -> Option<Uuid> {
+        self.offers.remove(im_session_id)
+    }
+
+    /// end a friendship, returning whether the agent was a friend
+    pub fn remove(&mut self, id: &Uuid) -> bool {
+        self.friends.remove(id).is_some()
+    }
+
+    /// keep the rights the agent gives its friends. Rights for agents that aren't friends are
+    /// dropped.
+    pub fn grant(&mut self, grant: &GrantUserRights) {
+        for user_rights in &grant.rights {
+            if let Some(friend) = self.friends.get_mut(&user_rights.agent_related) {
+                friend.rights_given = user_rights.rights.clone();
+            }
+        }
+    }
+
+    /// keep the rights the simulator says changed. The agent id of the ChangeUserRights tells
+    /// whether they are rights the agent gave or was given.
+    pub fn change_rights(&mut self, agent_id: Uuid, change: &ChangeUserRights) {
+        for user_rights in &change.rights {
+            if change.agent_id == agent_id {
+                if let Some(friend) = self.friends.get_mut(&user_rights.agent_related) {
+                    friend.rights_given = user_rights.rights.clone();
+                }
+            } else if let Some(friend) = self.friends.get_mut(&change.agent_id) {
+                friend.rights_has = user_rights.rights.clone();
+            }
+        }
+    }
+
+    /// add a friend, with the rights a new friendship starts with. Friends that are already
+    /// known are left alone.
+    fn add(&mut self, id: Uuid, online: bool) {
+        self.friends.entry(id).or_insert_with(|| Friend {
+            id,
+            online,
+            rights_given: FriendsRights::from_bits(FriendsRights::ONLINE),
+            rights_has: FriendsRights::from_bits(FriendsRights::ONLINE),
+        });
+    }
+
+    fn set_presence(&mut self, ids: &[Uuid], online: bool) -> Vec<Uuid> {
+        let mut changed = Vec::new();
+        for id in ids {
+            let friend = self.friends.entry(*id).or_insert_with(|| Friend {
+                id: *id,
+                online: false,
+                rights_given: FriendsRights::default(),
+                rights_has: FriendsRights::default(),
+            });
+            if friend.online != online {
+                friend.online = online;
+                changed.push(*id);
+            }
+        }
+        changed
+    }
+
+    /// forget every friend, for when the session ends
+    pub fn clear(&mut self) {
+        self.friends.clear();
+        self.offers.clear();
+    }
+}
@@ -0,0 +1,39 @@
+use metaverse_messages::agent_died::AgentDied;
+use metaverse_messages::health_message::HealthMessage;
+
+/// Keeps track of the agent's health, to tell when the agent dies.
+/// The simulator keeps sending HealthMessages while the agent's health is 0, so only the first
+/// one at 0 is a death. A HealthMessage above 0, like the full health the agent is sent home
+/// with, lets the next one at 0 be another death.
+#[derive(Debug)]
+pub struct HealthTracker {
+    /// the last health the simulator sent, None if it hasn't sent any
+    pub health: Option<f32>,
+}
+
+impl Default for HealthTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HealthTracker {
+    /// create a tracker that hasn't heard about the agent's health
+    pub fn new() -> Self {
+        HealthTracker { health: None }
+    }
+
+    /// handle a HealthMessage, returning the death to send to the UI if it killed the agent
+    pub fn handle_health(&mut self, message: &HealthMessage) -> Option<AgentDied> {
+        let was_alive = self.health.is_none_or(|health| health > 0.0);
+        self.health = Some(message.health);
+        (was_alive && message.health <= 0.0).then_some(AgentDied {
+            health: message.health,
+        })
+    }
+
+    /// forget the agent's health, for when the session ends
+    pub fn clear(&mut self) {
+        self.health = None;
+    }
+}
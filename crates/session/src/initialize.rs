@@ -1,16 +1,66 @@
 use actix::Actor;
-use actix_rt::time;
+use metaverse_messages::agent_throttle::Throttles;
 use metaverse_messages::errors::{MailboxError, SessionError};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Duration;
-use std::sync::Arc;
 use tokio::sync::Notify;
 use tokio::task::JoinHandle;
 
+use crate::asset_transfer::{AssetTransferManager, TRANSFER_TIMEOUT};
+use crate::asset_upload::{AssetUploadManager, UPLOAD_TIMEOUT};
+use crate::attachments::AttachmentManager;
+use crate::chat_filter::ChatFilter;
+use crate::circuit_stats::{CircuitStatsTracker, CIRCUIT_STATS_INTERVAL};
+use crate::dedupe::{DuplicateFilter, DEDUPE_WINDOW};
+use crate::directory::{DirectoryManager, DIRECTORY_PAGE_WINDOW, DIRECTORY_SEARCH_TIMEOUT};
+use crate::friends::FriendManager;
+use crate::health::HealthTracker;
+use crate::interpolation::InterpolationManager;
+use crate::inventory::{
+    InventoryFetcher, InventoryModel, ItemFetcher, INVENTORY_FETCH_LIMIT, INVENTORY_FETCH_TIMEOUT,
+    ITEM_FETCH_TIMEOUT,
+};
+use crate::item_creation::{ItemCreator, ITEM_CREATE_TIMEOUT};
+use crate::land_stat::{LandStatManager, LAND_STAT_TIMEOUT};
 use crate::mailbox::Mailbox;
 use crate::mailbox::{PingInfo, ServerState};
+use crate::map::{
+    MapBlockManager, MapItemManager, MapSearchManager, MAP_BLOCK_WINDOW, MAP_SEARCH_TIMEOUT,
+};
+use crate::movement::MovementManager;
+use crate::mute_list::MuteListManager;
+use crate::name_cache::{NameCache, NAME_CACHE_SIZE, NAME_LOOKUP_TIMEOUT};
+use crate::object_family::{ObjectFamilyManager, OBJECT_FAMILY_TIMEOUT};
+use crate::object_sale::SaleChecker;
+use crate::packet_stats::{PacketStats, MAX_PACKET_SIZE};
+use crate::parcel::{
+    ParcelOverlayAssembler, ParcelTracker, PARCEL_REFRESH_INTERVAL, PARCEL_REQUEST_TIMEOUT,
+};
+use crate::parcel_access::{ParcelAccessManager, PARCEL_ACCESS_WINDOW};
+use crate::pause::PauseManager;
+use crate::pending_acks::PendingAcks;
+use crate::people_search::{PeopleSearchManager, PEOPLE_SEARCH_TIMEOUT};
+use crate::purchase::{PurchaseManager, PURCHASE_TIMEOUT};
+use crate::region_lookup::{RegionLookupManager, REGION_LOOKUP_TIMEOUT};
+use crate::retransmit::{RetransmitQueue, MAX_RESENDS};
+use crate::script_controls::ScriptControlManager;
+use crate::script_permissions::ScriptPermissionManager;
+use crate::script_running::{ScriptRunningManager, SCRIPT_RUNNING_TIMEOUT};
+use crate::sequence::SequenceNumbers;
 use crate::server_subscriber::listen_for_ui_messages;
+use crate::sim_stats::{SimStatsThrottle, SIM_STATS_INTERVAL};
+use crate::sit::{SitManager, SIT_TIMEOUT};
+use crate::sound::SoundPreloadManager;
+use crate::sun::SunTracker;
+use crate::texture_download::{TextureDownloadManager, TEXTURE_ATTEMPTS, TEXTURE_TIMEOUT};
+use crate::transactions::{TransactionRegistry, TRANSACTION_LIFETIME};
+use crate::typing::TypingAnimation;
+use crate::xfer::{XferReceiver, XferSender, XFER_ATTEMPTS, XFER_DOWNLOAD_TIMEOUT, XFER_TIMEOUT};
+
+/// how often the mailbox pings the server to measure latency
+pub const PING_INTERVAL: Duration = Duration::from_secs(5);
 use portpicker::pick_unused_port;
 
 /// This starts the mailbox, and blocks forever.
@@ -50,27 +100,10 @@ pub async fn initialize(
     ui_to_server_socket: u16,
     server_to_ui_socket: u16,
 ) -> Result<JoinHandle<()>, SessionError> {
-    let notify = Arc::new(Notify::new());
-    let state = Arc::new(Mutex::new(ServerState::Starting));
-
-    let mailbox = Mailbox {
-        client_socket: pick_unused_port().unwrap(),
-        server_to_ui_socket: format!("127.0.0.1:{}", server_to_ui_socket),
-        packet_sequence_number: Arc::new(Mutex::new(0u32)),
-
-        ack_queue: Arc::new(Mutex::new(HashMap::new())),
-
-        state: state.clone(),
-        notify: notify.clone(),
-        session: None,
-        sent_packet_count: 0,
-        ping_info: PingInfo {
-            ping_number: 0,
-            ping_latency: Duration::new(0, 0),
-            last_ping: time::Instant::now(),
-        },
-    }
-    .start();
+    let mailbox = new_mailbox(server_to_ui_socket);
+    let notify = mailbox.notify.clone();
+    let state = mailbox.state.clone();
+    let mailbox = mailbox.start();
     // wait until the mailbox starts
     notify.notified().await;
     if *state.lock().unwrap() != ServerState::Running {
@@ -85,3 +118,81 @@ pub async fn initialize(
 
     Ok(handle)
 }
+
+/// create a mailbox with the default settings, ready to be started. Events for the UI are sent to
+/// the server_to_ui_socket port.
+pub fn new_mailbox(server_to_ui_socket: u16) -> Mailbox {
+    Mailbox {
+        client_socket: pick_unused_port().unwrap(),
+        server_to_ui_socket: format!("127.0.0.1:{}", server_to_ui_socket),
+        sequence_numbers: SequenceNumbers::new(),
+
+        retransmits: RetransmitQueue::new(MAX_RESENDS),
+
+        state: Arc::new(Mutex::new(ServerState::Starting)),
+        notify: Arc::new(Notify::new()),
+        session: None,
+        sent_packet_count: 0,
+        ping_info: PingInfo::new(PING_INTERVAL),
+        auto_reply_region_handshake: true,
+        throttles: Throttles::default(),
+        throttle_gen_counter: 0,
+        viewport_size: None,
+        height_width_gen_counter: 0,
+        vertical_angle: None,
+        fov_gen_counter: 0,
+        login_pending: false,
+        udp_read: None,
+        ping_handle: None,
+        logout_timeout: None,
+        ack_flush: None,
+        retransmit_handle: None,
+        closed_circuits: Arc::new(Mutex::new(HashSet::new())),
+        child_circuits: Arc::new(Mutex::new(HashMap::new())),
+        texture_downloads: TextureDownloadManager::new(TEXTURE_TIMEOUT, TEXTURE_ATTEMPTS),
+        asset_transfers: AssetTransferManager::new(TRANSFER_TIMEOUT),
+        xfers: XferSender::new(XFER_TIMEOUT, XFER_ATTEMPTS),
+        xfer_downloads: XferReceiver::new(XFER_DOWNLOAD_TIMEOUT),
+        asset_uploads: AssetUploadManager::new(UPLOAD_TIMEOUT),
+        names: NameCache::new(NAME_CACHE_SIZE, NAME_LOOKUP_TIMEOUT),
+        purchases: PurchaseManager::new(PURCHASE_TIMEOUT),
+        sales: SaleChecker::new(),
+        parcels: ParcelTracker::new(PARCEL_REQUEST_TIMEOUT, PARCEL_REFRESH_INTERVAL),
+        parcel_overlay: ParcelOverlayAssembler::new(),
+        map_blocks: MapBlockManager::new(MAP_BLOCK_WINDOW),
+        map_items: MapItemManager::new(MAP_BLOCK_WINDOW),
+        map_searches: MapSearchManager::new(MAP_SEARCH_TIMEOUT),
+        region_lookups: RegionLookupManager::new(REGION_LOOKUP_TIMEOUT),
+        script_permissions: ScriptPermissionManager::new(),
+        sound_preloads: SoundPreloadManager::new(),
+        sits: SitManager::new(SIT_TIMEOUT),
+        movement: MovementManager::new(),
+        pause: PauseManager::new(),
+        script_controls: ScriptControlManager::new(),
+        health: HealthTracker::new(),
+        sim_stats: SimStatsThrottle::new(SIM_STATS_INTERVAL),
+        sun: SunTracker::new(),
+        typing: TypingAnimation::new(true),
+        mutes: Arc::new(Mutex::new(MuteListManager::new())),
+        chat_filter: Arc::new(Mutex::new(ChatFilter::new())),
+        pending_acks: PendingAcks::new(),
+        duplicates: Arc::new(Mutex::new(DuplicateFilter::new(DEDUPE_WINDOW))),
+        packet_stats: PacketStats::new(false),
+        circuit_stats: CircuitStatsTracker::new(CIRCUIT_STATS_INTERVAL),
+        receive_buffer_size: MAX_PACKET_SIZE,
+        interpolation: InterpolationManager::new(),
+        transactions: TransactionRegistry::new(TRANSACTION_LIFETIME),
+        friends: FriendManager::new(),
+        inventory: InventoryFetcher::new(INVENTORY_FETCH_LIMIT, INVENTORY_FETCH_TIMEOUT),
+        inventory_items: ItemFetcher::new(ITEM_FETCH_TIMEOUT),
+        inventory_model: InventoryModel::new(),
+        item_creations: ItemCreator::new(ITEM_CREATE_TIMEOUT),
+        people_searches: PeopleSearchManager::new(PEOPLE_SEARCH_TIMEOUT),
+        directory: DirectoryManager::new(DIRECTORY_PAGE_WINDOW, DIRECTORY_SEARCH_TIMEOUT),
+        parcel_access: ParcelAccessManager::new(PARCEL_ACCESS_WINDOW),
+        land_stats: LandStatManager::new(LAND_STAT_TIMEOUT),
+        attachments: AttachmentManager::new(),
+        script_running: ScriptRunningManager::new(SCRIPT_RUNNING_TIMEOUT),
+        object_family: ObjectFamilyManager::new(OBJECT_FAMILY_TIMEOUT),
+    }
+}
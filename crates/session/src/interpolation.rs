@@ -0,0 +1,84 @@
+use metaverse_messages::packet::Packet;
+use metaverse_messages::velocity_interpolate_off::VelocityInterpolateOff;
+use metaverse_messages::velocity_interpolate_on::VelocityInterpolateOn;
+use metaverse_messages::velocity_interpolation_status::VelocityInterpolationStatus;
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use uuid::Uuid;
+
+/// Remembers whether the UI wants simulators to interpolate the motion of objects.
+/// Simulators keep the setting per circuit and forget it when the circuit is restarted, so it
+/// is sent again to every circuit that handshakes, including the new one after a teleport. The
+/// circuits that have been told are remembered, so a change of the setting reaches all of them.
+#[derive(Debug)]
+pub struct InterpolationManager {
+    /// whether simulators should interpolate the motion of objects
+    pub enabled: bool,
+    /// the circuits that have been told the setting
+    pub circuits: HashSet<SocketAddr>,
+}
+
+impl Default for InterpolationManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InterpolationManager {
+    /// create a manager with interpolation on, which is what simulators do by default
+    pub fn new() -> Self {
+        InterpolationManager {
+            enabled: true,
+            circuits: HashSet::new(),
+        }
+    }
+
+    /// a circuit has handshaked, and starts out with the simulator's default. Returns the
+    /// setting to send to it.
+    pub fn circuit_started(&mut self, addr: SocketAddr) -> bool {
+        self.circuits.insert(addr);
+        self.enabled
+    }
+
+    /// a circuit has been closed, and doesn't need to be told about changes anymore
+    pub fn circuit_closed(&mut self, addr: &SocketAddr) {
+        self.circuits.remove(addr);
+    }
+
+    /// change the setting. Returns the circuits to send the new setting to, which is none of
+    /// them if it didn't change.
+    pub fn set(&mut self, enabled: bool) -> Vec<SocketAddr> {
+        if self.enabled == enabled {
+            return Vec::new();
+        }
+        self.enabled = enabled;
+        self.circuits.iter().copied().collect()
+    }
+
+    /// the setting, to send to the UI
+    pub fn status(&self) -> VelocityInterpolationStatus {
+        VelocityInterpolationStatus {
+            enabled: self.enabled,
+        }
+    }
+
+    /// the VelocityInterpolateOn or VelocityInterpolateOff for the setting
+    pub fn packet(&self, agent_id: Uuid, session_id: Uuid) -> Packet {
+        if self.enabled {
+            Packet::new_velocity_interpolate_on(VelocityInterpolateOn {
+                agent_id,
+                session_id,
+            })
+        } else {
+            Packet::new_velocity_interpolate_off(VelocityInterpolateOff {
+                agent_id,
+                session_id,
+            })
+        }
+    }
+
+    /// forget the circuits when the session ends. The setting is kept for the next session.
+    pub fn clear(&mut self) {
+        self.circuits.clear();
+    }
+}
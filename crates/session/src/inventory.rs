@@ -0,0 +1,424 @@
+use metaverse_messages::create_inventory_folder::CreateInventoryFolder;
+use metaverse_messages::fetch_inventory::{FetchInventory, ItemRequest};
+use metaverse_messages::fetch_inventory_descendents::FetchInventoryDescendents;
+use metaverse_messages::fetch_inventory_reply::FetchInventoryReply;
+use metaverse_messages::inventory_descendents::{InventoryDescendents, InventoryFolder};
+use metaverse_messages::inventory_folder_contents::InventoryFolderContents;
+use metaverse_messages::inventory_item_status::InventoryItemStatus;
+use metaverse_messages::move_inventory_folder::MoveInventoryFolder;
+use metaverse_messages::move_inventory_item::MoveInventoryItem;
+use metaverse_messages::remove_inventory_folder::RemoveInventoryFolder;
+use metaverse_messages::remove_inventory_item::RemoveInventoryItem;
+use metaverse_messages::update_inventory_item::UpdateInventoryItem;
+use metaverse_messages::utils::inventory_item::InventoryItem;
+use std::collections::{HashMap, HashSet, VecDeque};
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how many folders are fetched at once
+pub const INVENTORY_FETCH_LIMIT: usize = 4;
+/// how long to wait for the next InventoryDescendents of a folder before giving up on the rest
+pub const INVENTORY_FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+/// how long to wait for a FetchInventoryReply before an item is not found
+pub const ITEM_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+/// the most items asked for in one FetchInventory, so the packet fits in one datagram
+pub const ITEM_FETCH_BATCH: usize = 32;
+/// the most blocks a packet can have, so bigger changes are split over several packets
+pub const INVENTORY_CHANGE_BATCH: usize = u8::MAX as usize;
+
+/// Fetches inventory trees one folder at a time with FetchInventoryDescendents.
+/// Folders are fetched breadth first, with at most limit of them waiting for their
+/// InventoryDescendents at once. The contents of a folder can be split over several packets, so
+/// they are gathered until there are as many folders and items as the reply says there are.
+/// Then the folder is finished, and the folders inside it are queued.
+/// A folder that stops getting packets for the timeout is finished with what has arrived, so
+/// the rest of the tree is still fetched.
+#[derive(Debug)]
+pub struct InventoryFetcher {
+    /// folders waiting to be fetched, with their owners, in the order they are fetched
+    pub queue: VecDeque<(Uuid, Uuid)>,
+    /// folders that have been asked for, by folder id
+    pub active: HashMap<Uuid, FolderFetch>,
+    /// the most folders fetched at once
+    pub limit: usize,
+    /// how long to wait for the next packet of a folder
+    pub timeout: Duration,
+}
+
+/// a folder that has been asked for
+#[derive(Debug, Clone, PartialEq)]
+pub struct FolderFetch {
+    /// the folders and items that have arrived so far
+    pub contents: InventoryFolderContents,
+    /// when the request was sent, or the last packet arrived
+    pub last_received: Instant,
+}
+
+impl InventoryFetcher {
+    /// create a fetcher that asks for at most limit folders at once
+    pub fn new(limit: usize, timeout: Duration) -> Self {
+        InventoryFetcher {
+            queue: VecDeque::new(),
+            active: HashMap::new(),
+            limit,
+            timeout,
+        }
+    }
+
+    /// queue a folder to be fetched with every folder inside it. A folder that is already being
+    /// fetched isn't queued again.
+    pub fn fetch_tree(&mut self, root_folder: Uuid, owner_id: Uuid) {
+        if self.active.contains_key(&root_folder)
+            || self
+                .queue
+                .iter()
+                .any(|(folder_id, _)| *folder_id == root_folder)
+        {
+            return;
+        }
+        self.queue.push_back((root_folder, owner_id));
+    }
+
+    /// start fetching queued folders while there is room, returning the requests to send. The
+    /// agent and session IDs are left nil.
+    pub fn next_requests(&mut self, now: Instant) -> Vec<FetchInventoryDescendents> {
+        let mut requests = Vec::new();
+        while self.active.len() < self.limit {
+            let Some((folder_id, owner_id)) = self.queue.pop_front() else {
+                break;
+            };
+            self.active.insert(
+                folder_id,
+                FolderFetch {
+                    contents: InventoryFolderContents {
+                        folder_id,
+                        owner_id,
+                        ..Default::default()
+                    },
+                    last_received: now,
+                },
+            );
+            requests.push(FetchInventoryDescendents::new(folder_id, owner_id));
+        }
+        requests
+    }
+
+    /// add a packet of a folder's contents, returning the contents once every folder and item
+    /// has arrived. Packets for folders that aren't being fetched are ignored.
+    pub fn handle_descendents(
+        &mut self,
+        descendents: &InventoryDescendents,
+        now: Instant,
+    ) -> Option<InventoryFolderContents> {
+        let fetch = self.active.get_mut(&descendents.folder_id)?;
+        fetch.contents.version = descendents.version;
+        fetch
+            .contents
+            .folders
+            .extend(descendents.folders.iter().cloned());
+        fetch
+            .contents
+            .items
+            .extend(descendents.items.iter().map(|item| item.item.clone()));
+        fetch.last_received = now;
+        let received = fetch.contents.folders.len() + fetch.contents.items.len();
+        if received < descendents.descendents.max(0) as usize {
+            return None;
+        }
+        self.finish(descendents.folder_id)
+    }
+
+    /// finish the folders that haven't had a packet within the timeout, returning what arrived
+    /// of them
+    pub fn expire(&mut self, now: Instant) -> Vec<InventoryFolderContents> {
+        let expired: Vec<Uuid> = self
+            .active
+            .iter()
+            .filter(|(_, fetch)| now.duration_since(fetch.last_received) >= self.timeout)
+            .map(|(folder_id, _)| *folder_id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|folder_id| self.finish(folder_id))
+            .collect()
+    }
+
+    /// stop fetching a folder, and queue the folders inside it
+    fn finish(&mut self, folder_id: Uuid) -> Option<InventoryFolderContents> {
+        let fetch = self.active.remove(&folder_id)?;
+        for folder in &fetch.contents.folders {
+            self.fetch_tree(folder.folder_id, fetch.contents.owner_id);
+        }
+        Some(fetch.contents)
+    }
+
+    /// whether every folder that was queued has been fetched
+    pub fn is_idle(&self) -> bool {
+        self.queue.is_empty() && self.active.is_empty()
+    }
+
+    /// forget every fetch, for when the session ends
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.active.clear();
+    }
+}
+
+/// Fetches single inventory items with FetchInventory.
+/// The simulator doesn't answer for items it can't find, so an item that hasn't arrived within
+/// the timeout is not found. Items that are already being fetched aren't asked for again.
+#[derive(Debug)]
+pub struct ItemFetcher {
+    /// the items that have been asked for, and when
+    pub pending: HashMap<Uuid, Instant>,
+    /// how long to wait for an item
+    pub timeout: Duration,
+}
+
+impl ItemFetcher {
+    /// create a fetcher that gives up on items after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        ItemFetcher {
+            pending: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// fetch items, returning the requests to send for the ones that aren't already being
+    /// fetched. The agent and session IDs are left nil.
+    pub fn request(&mut self, items: &[ItemRequest], now: Instant) -> Vec<FetchInventory> {
+        let mut requested = Vec::new();
+        for item in items {
+            if self.pending.contains_key(&item.item_id) {
+                continue;
+            }
+            self.pending.insert(item.item_id, now);
+            requested.push(*item);
+        }
+        requested
+            .chunks(ITEM_FETCH_BATCH)
+            .map(|items| FetchInventory::new(items.to_vec()))
+            .collect()
+    }
+
+    /// handle a FetchInventoryReply, returning the items that arrived
+    pub fn handle_reply(&mut self, reply: &FetchInventoryReply) -> Vec<InventoryItemStatus> {
+        reply
+            .items
+            .iter()
+            .filter(|item| !item.item.item_id.is_nil())
+            .map(|item| {
+                self.pending.remove(&item.item.item_id);
+                InventoryItemStatus::Found(item.item.clone())
+            })
+            .collect()
+    }
+
+    /// give up on the items that haven't arrived within the timeout
+    pub fn expire(&mut self, now: Instant) -> Vec<InventoryItemStatus> {
+        let expired: Vec<Uuid> = self
+            .pending
+            .iter()
+            .filter(|(_, sent)| now.duration_since(**sent) >= self.timeout)
+            .map(|(item_id, _)| *item_id)
+            .collect();
+        for item_id in &expired {
+            self.pending.remove(item_id);
+        }
+        expired
+            .into_iter()
+            .map(|item_id| InventoryItemStatus::NotFound { item_id })
+            .collect()
+    }
+
+    /// forget every item, for when the session ends
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+/// The agent's inventory folders and items that have arrived from folder and item fetches.
+/// Changes the agent makes are applied here straight away, so the UI doesn't wait for the
+/// simulator. The changed items are then fetched again, and whatever the simulator sends
+/// replaces them, which undoes changes it refused.
+/// Removing or purging a folder forgets everything that is known to be inside it.
+#[derive(Debug)]
+pub struct InventoryModel {
+    /// the items, by item id
+    pub items: HashMap<Uuid, InventoryItem>,
+    /// the folders, by folder id
+    pub folders: HashMap<Uuid, InventoryFolder>,
+}
+
+impl Default for InventoryModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InventoryModel {
+    /// create a model with no items
+    pub fn new() -> Self {
+        InventoryModel {
+            items: HashMap::new(),
+            folders: HashMap::new(),
+        }
+    }
+
+    /// add an item as the simulator sent it, replacing what was known of it
+    pub fn insert(&mut self, item: InventoryItem) {
+        self.items.insert(item.item_id, item);
+    }
+
+    /// an item, if it has arrived
+    pub fn get(&self, item_id: &Uuid) -> Option<&InventoryItem> {
+        self.items.get(item_id)
+    }
+
+    /// apply an update to the items, returning the packets to send it in. UpdateInventoryItem
+    /// doesn't carry assets, so items that are already known keep theirs.
+    pub fn update(&mut self, update: UpdateInventoryItem) -> Vec<UpdateInventoryItem> {
+        for block in &update.items {
+            let mut item = block.item.clone();
+            if let Some(known) = self.items.get(&item.item_id) {
+                item.asset_id = known.asset_id;
+            }
+            self.insert(item);
+        }
+        update
+            .items
+            .chunks(INVENTORY_CHANGE_BATCH)
+            .map(|items| UpdateInventoryItem {
+                agent_id: update.agent_id,
+                session_id: update.session_id,
+                transaction_id: update.transaction_id,
+                items: items.to_vec(),
+            })
+            .collect()
+    }
+
+    /// move the items that are known into their new folders, returning the packets to send the
+    /// move in
+    pub fn move_items(&mut self, move_items: MoveInventoryItem) -> Vec<MoveInventoryItem> {
+        for block in &move_items.items {
+            if let Some(item) = self.items.get_mut(&block.item_id) {
+                item.folder_id = block.folder_id;
+                if let Some(new_name) = &block.new_name {
+                    item.name = new_name.clone();
+                }
+            }
+        }
+        move_items
+            .items
+            .chunks(INVENTORY_CHANGE_BATCH)
+            .map(|items| MoveInventoryItem {
+                agent_id: move_items.agent_id,
+                session_id: move_items.session_id,
+                stamp: move_items.stamp,
+                items: items.to_vec(),
+            })
+            .collect()
+    }
+
+    /// forget the items, returning the packets to send the removal in
+    pub fn remove(&mut self, remove: RemoveInventoryItem) -> Vec<RemoveInventoryItem> {
+        for item_id in &remove.item_ids {
+            self.items.remove(item_id);
+        }
+        remove
+            .item_ids
+            .chunks(INVENTORY_CHANGE_BATCH)
+            .map(|item_ids| RemoveInventoryItem {
+                agent_id: remove.agent_id,
+                session_id: remove.session_id,
+                item_ids: item_ids.to_vec(),
+            })
+            .collect()
+    }
+
+    /// forget an item the simulator doesn't have
+    pub fn forget(&mut self, item_id: &Uuid) {
+        self.items.remove(item_id);
+    }
+
+    /// add a folder as the simulator sent it, replacing what was known of it
+    pub fn insert_folder(&mut self, folder: InventoryFolder) {
+        self.folders.insert(folder.folder_id, folder);
+    }
+
+    /// a folder, if it has arrived
+    pub fn folder(&self, folder_id: &Uuid) -> Option<&InventoryFolder> {
+        self.folders.get(folder_id)
+    }
+
+    /// make a folder, picking a new id for it if it doesn't have one. Returns the packet to
+    /// send, with the folder as it was made.
+    pub fn create_folder(&mut self, mut create: CreateInventoryFolder) -> CreateInventoryFolder {
+        if create.folder.folder_id.is_nil() {
+            create.folder.folder_id = Uuid::new_v4();
+        }
+        self.insert_folder(create.folder.clone());
+        create
+    }
+
+    /// move the folders that are known into their new parents, returning the packets to send
+    /// the move in
+    pub fn move_folders(&mut self, move_folders: MoveInventoryFolder) -> Vec<MoveInventoryFolder> {
+        for block in &move_folders.folders {
+            if let Some(folder) = self.folders.get_mut(&block.folder_id) {
+                folder.parent_id = block.parent_id;
+            }
+        }
+        move_folders
+            .folders
+            .chunks(INVENTORY_CHANGE_BATCH)
+            .map(|folders| MoveInventoryFolder {
+                agent_id: move_folders.agent_id,
+                session_id: move_folders.session_id,
+                stamp: move_folders.stamp,
+                folders: folders.to_vec(),
+            })
+            .collect()
+    }
+
+    /// forget the folders with everything inside them, returning the packets to send the
+    /// removal in
+    pub fn remove_folders(&mut self, remove: RemoveInventoryFolder) -> Vec<RemoveInventoryFolder> {
+        for folder_id in &remove.folder_ids {
+            self.purge(folder_id);
+            self.folders.remove(folder_id);
+        }
+        remove
+            .folder_ids
+            .chunks(INVENTORY_CHANGE_BATCH)
+            .map(|folder_ids| RemoveInventoryFolder {
+                agent_id: remove.agent_id,
+                session_id: remove.session_id,
+                folder_ids: folder_ids.to_vec(),
+            })
+            .collect()
+    }
+
+    /// forget everything inside a folder, keeping the folder
+    pub fn purge(&mut self, folder_id: &Uuid) {
+        let mut inside = HashSet::from([*folder_id]);
+        let mut queue = VecDeque::from([*folder_id]);
+        while let Some(parent_id) = queue.pop_front() {
+            for folder in self.folders.values() {
+                if folder.parent_id == parent_id && inside.insert(folder.folder_id) {
+                    queue.push_back(folder.folder_id);
+                }
+            }
+        }
+        self.items
+            .retain(|_, item| !inside.contains(&item.folder_id));
+        inside.remove(folder_id);
+        self.folders.retain(|id, _| !inside.contains(id));
+    }
+
+    /// forget every folder and item, for when the session ends
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.folders.clear();
+    }
+}
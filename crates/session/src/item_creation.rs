@@ -0,0 +1,171 @@
+use metaverse_messages::create_inventory_item::CreateInventoryItem;
+use metaverse_messages::errors::{CreateInventoryItemError, SessionError};
+use metaverse_messages::inventory_item_created::InventoryItemCreated;
+use metaverse_messages::update_create_inventory_item::UpdateCreateInventoryItem;
+use metaverse_messages::utils::inventory_item::InventoryItem;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::oneshot;
+use tokio::time::{Duration, Instant};
+
+/// how long to wait for the UpdateCreateInventoryItem with a new item before it fails
+pub const ITEM_CREATE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Keeps track of items being made with CreateInventoryItem.
+/// Every request is sent with a callback id of its own, so a reply can't finish the wrong
+/// request even if the UI reuses its callback ids. The ids count up for as long as the mailbox
+/// lives, skipping 0, which the simulator uses for items nobody asked for. The callback id the
+/// UI sent is kept, to tell the UI which of its requests finished.
+/// Requests fail if the simulator doesn't answer within the timeout.
+#[derive(Debug)]
+pub struct ItemCreator {
+    /// the requests waiting for their item, by the callback id they were sent with
+    pub pending: HashMap<u32, PendingCreation>,
+    /// the callback id the next request is sent with
+    pub next_callback_id: u32,
+    /// how long a request can wait for its item
+    pub timeout: Duration,
+}
+
+/// a CreateInventoryItem waiting for its UpdateCreateInventoryItem
+#[derive(Debug)]
+pub struct PendingCreation {
+    /// the callback id the UI sent
+    pub request_callback_id: u32,
+    /// when the request was sent
+    pub sent: Instant,
+    /// told the new item, or why it wasn't made
+    pub completion: oneshot::Sender<Result<InventoryItem, CreateInventoryItemError>>,
+}
+
+impl ItemCreator {
+    /// create a creator that fails requests after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        ItemCreator {
+            pending: HashMap::new(),
+            next_callback_id: 1,
+            timeout,
+        }
+    }
+
+    /// start making an item, returning the request to send to the simulator with its callback
+    /// id replaced
+    pub fn request(
+        &mut self,
+        mut create: CreateInventoryItem,
+        now: Instant,
+    ) -> (CreateInventoryItem, ItemCreation) {
+        let callback_id = self.allocate_callback_id();
+        let (completion, receiver) = oneshot::channel();
+        self.pending.insert(
+            callback_id,
+            PendingCreation {
+                request_callback_id: create.callback_id,
+                sent: now,
+                completion,
+            },
+        );
+        let creation = ItemCreation {
+            callback_id: create.callback_id,
+            completion: receiver,
+        };
+        create.callback_id = callback_id;
+        (create, creation)
+    }
+
+    /// finish the requests the items of an UpdateCreateInventoryItem answer, returning the events
+    /// for the UI. Items that don't answer a request are left out.
+    pub fn handle_update(
+        &mut self,
+        update: &UpdateCreateInventoryItem,
+    ) -> Vec<InventoryItemCreated> {
+        update
+            .items
+            .iter()
+            .filter_map(|created| {
+                let pending = self.pending.remove(&created.callback_id)?;
+                let _ = pending.completion.send(Ok(created.item.clone()));
+                Some(InventoryItemCreated {
+                    callback_id: pending.request_callback_id,
+                    item: Some(created.item.clone()),
+                    reason: None,
+                })
+            })
+            .collect()
+    }
+
+    /// fail the requests that have waited longer than the timeout
+    pub fn expire(&mut self, now: Instant) -> Vec<InventoryItemCreated> {
+        let expired: Vec<u32> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| now.duration_since(pending.sent) >= self.timeout)
+            .map(|(callback_id, _)| *callback_id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|callback_id| self.fail(callback_id, "timed out waiting for the simulator"))
+            .collect()
+    }
+
+    /// fail every request, for when the session ends
+    pub fn cancel_all(&mut self) -> Vec<InventoryItemCreated> {
+        let callback_ids: Vec<u32> = self.pending.keys().copied().collect();
+        callback_ids
+            .into_iter()
+            .filter_map(|callback_id| self.fail(callback_id, "session ended"))
+            .collect()
+    }
+
+    fn fail(
+        &mut self,
+        callback_id: u32,
+        reason: impl Into<String>,
+    ) -> Option<InventoryItemCreated> {
+        let pending = self.pending.remove(&callback_id)?;
+        let reason = reason.into();
+        let _ = pending
+            .completion
+            .send(Err(CreateInventoryItemError::new(reason.clone())));
+        Some(InventoryItemCreated {
+            callback_id: pending.request_callback_id,
+            item: None,
+            reason: Some(reason),
+        })
+    }
+
+    fn allocate_callback_id(&mut self) -> u32 {
+        loop {
+            let callback_id = self.next_callback_id;
+            self.next_callback_id = self.next_callback_id.wrapping_add(1);
+            if callback_id != 0 && !self.pending.contains_key(&callback_id) {
+                return callback_id;
+            }
+        }
+    }
+}
+
+/// Resolves to the new item once the simulator has made it, or to why it wasn't made.
+#[derive(Debug)]
+pub struct ItemCreation {
+    /// the callback id of the request, as it was before the session replaced it
+    pub callback_id: u32,
+    /// told the new item, or why it wasn't made
+    pub completion: oneshot::Receiver<Result<InventoryItem, CreateInventoryItemError>>,
+}
+
+impl Future for ItemCreation {
+    type Output = Result<InventoryItem, SessionError>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.completion)
+            .poll(cx)
+            .map(|result| match result {
+                Ok(result) => result.map_err(SessionError::CreateInventoryItem),
+                Err(_) => Err(SessionError::CreateInventoryItem(
+                    CreateInventoryItemError::new("request was dropped before it finished"),
+                )),
+            })
+    }
+}
@@ -0,0 +1,116 @@
+use metaverse_messages::land_stat_reply::LandStatReply;
+use metaverse_messages::land_stat_report::LandStatReport;
+use metaverse_messages::utils::land_stat::{LandStatEntry, LandStatReportType};
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+
+/// how long to wait for the rest of a report's pages before sending what has arrived
+pub const LAND_STAT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Gathers the pages of the LandStat reports the UI has asked for with LandStatRequest.
+/// Every LandStatReply carries the total number of objects in the report, so pages are gathered
+/// until that many entries have arrived. The replies don't say which request they answer, so
+/// only one report of each type is gathered at a time, and asking for it again starts over.
+/// A report that stops getting pages before it has all of its entries is sent on incomplete
+/// once the timeout passes since its last page.
+#[derive(Debug)]
+pub struct LandStatManager {
+    /// the reports waiting for their pages, by report type
+    pub reports: HashMap<LandStatReportType, PendingLandStat>,
+    /// how long to wait for more pages
+    pub timeout: Duration,
+}
+
+/// a report that hasn't been sent to the UI yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingLandStat {
+    /// how many objects the report has, once the first page has arrived
+    pub total_object_count: Option<u32>,
+    /// the entries that have arrived
+    pub entries: Vec<LandStatEntry>,
+    /// when the request was sent, or the last page arrived
+    pub last_received: Instant,
+}
+
+impl LandStatManager {
+    /// create a manager that gives up on reports after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        LandStatManager {
+            reports: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// start gathering a report, dropping the pages of an earlier one of the same type
+    pub fn start(&mut self, report_type: LandStatReportType, now: Instant) {
+        self.reports.insert(
+            report_type,
+            PendingLandStat {
+                total_object_count: None,
+                entries: Vec::new(),
+                last_received: now,
+            },
+        );
+    }
+
+    /// add the entries of a page to their report. Returns the report once all of its entries
+    /// have arrived. Pages that weren't asked for start a report of their own.
+    pub fn add(&mut self, reply: &LandStatReply, now: Instant) -> Option<LandStatReport> {
+        let report = self
+            .reports
+            .entry(reply.report_type)
+            .or_insert_with(|| PendingLandStat {
+                total_object_count: None,
+                entries: Vec::new(),
+                last_received: now,
+            });
+        let total_object_count = *report
+            .total_object_count
+            .get_or_insert(reply.total_object_count);
+        report.entries.extend(reply.entries.iter().cloned());
+        report.last_received = now;
+        if report.entries.len() < total_object_count as usize {
+            return None;
+        }
+        self.reports
+            .remove(&reply.report_type)
+            .map(|report| report.into_report(reply.report_type, true))
+    }
+
+    /// give up on the reports that haven't had a page within the timeout, returning what they
+    /// have
+    pub fn expire(&mut self, now: Instant) -> Vec<LandStatReport> {
+        let expired: Vec<LandStatReportType> = self
+            .reports
+            .iter()
+            .filter(|(_, report)| now.duration_since(report.last_received) >= self.timeout)
+            .map(|(report_type, _)| *report_type)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|report_type| {
+                self.reports
+                    .remove(&report_type)
+                    .map(|report| report.into_report(report_type, false))
+            })
+            .collect()
+    }
+
+    /// forget the reports, for when the session ends
+    pub fn clear(&mut self) {
+        self.reports.clear();
+    }
+}
+
+impl PendingLandStat {
+    /// turn the pages into a report, ranking the entries by score
+    fn into_report(mut self, report_type: LandStatReportType, complete: bool) -> LandStatReport {
+        self.entries.sort_by(|a, b| b.score.total_cmp(&a.score));
+        LandStatReport {
+            report_type,
+            total_object_count: self.total_object_count.unwrap_or(0),
+            entries: self.entries,
+            complete,
+        }
+    }
+}
@@ -9,11 +9,92 @@
 //! This isn't ready for any kind of serious use yet! Check back later for updates!
 
 #![warn(missing_docs)]
+/// This module assembles assets downloaded with the Transfer system
+pub mod asset_transfer;
+/// This module keeps track of assets being uploaded to the simulator
+pub mod asset_upload;
+/// This module keeps track of the objects the agent is wearing
+pub mod attachments;
+/// This module drops the nearby chat the UI doesn't want
+pub mod chat_filter;
+/// This module counts the packets and bytes sent and received on each circuit
+pub mod circuit_stats;
 /// This module is for running in your client to subscribe to the server events
 pub mod client_subscriber;
+/// This module drops the packets simulators send twice
+pub mod dedupe;
+/// This module gathers the pages of results of directory searches
+pub mod directory;
+/// This module keeps the agent's friends, and which of them are online
+pub mod friends;
+/// This module keeps track of the agent's health, to tell when the agent dies
+pub mod health;
 /// This module initializes the mailbox
 pub mod initialize;
+/// This module remembers whether simulators interpolate the motion of objects
+pub mod interpolation;
+/// This module fetches inventory folders and the folders inside them, and single items, and keeps
+/// the items that have arrived
+pub mod inventory;
+/// This module keeps track of inventory items being made by the simulator
+pub mod item_creation;
+/// This module gathers the pages of LandStat reports
+pub mod land_stat;
 /// This module handles packet IO and logic
 pub mod mailbox;
+/// This module gathers the regions and markers of the world map, and searches it
+pub mod map;
+/// This module keeps the avatar moving while movement controls are held
+pub mod movement;
+/// This module keeps the agent's mute list, and blocks the messages it mutes
+pub mod mute_list;
+/// This module caches the names of avatars
+pub mod name_cache;
+/// This module matches hovering over objects to the answers about them
+pub mod object_family;
+/// This module checks the sale info of objects against their permissions
+pub mod object_sale;
+/// This module counts the packets received from simulators, by their type
+pub mod packet_stats;
+/// This module keeps track of the parcel the agent is on, and the parcels of the region
+pub mod parcel;
+/// This module gathers the access and ban lists of parcels
+pub mod parcel_access;
+/// This module keeps track of pausing the agent while the UI isn't drawing
+pub mod pause;
+/// This module collects the acks for reliable packets from simulators
+pub mod pending_acks;
+/// This module keeps track of searches for residents by name
+pub mod people_search;
+/// This module checks the objects being bought before buying them
+pub mod purchase;
+/// This module finds the handles of regions by their names
+pub mod region_lookup;
+/// This module resends reliable packets the simulator didn't ack
+pub mod retransmit;
+/// This module keeps track of the controls scripts have taken from the agent
+pub mod script_controls;
+/// This module remembers the permissions granted to scripts
+pub mod script_permissions;
+/// This module keeps track of questions about whether scripts are running
+pub mod script_running;
+/// This module numbers the packets sent to each simulator
+pub mod sequence;
 /// This module is to allowe the server to receive messages from the UI.
 pub mod server_subscriber;
+/// This module keeps SimStats from flooding the UI
+pub mod sim_stats;
+/// This module keeps track of sitting on objects
+pub mod sit;
+/// This module keeps the sounds the UI is asked to preload from repeating
+pub mod sound;
+/// This module keeps the time of day of the current region, to tell where its sun is
+pub mod sun;
+/// This module assembles textures downloaded from the simulator
+pub mod texture_download;
+/// This module remembers what the session's transactions are for
+pub mod transactions;
+/// This module plays the typing animation while the agent writes in chat
+pub mod typing;
+/// This module uploads files to the simulator with the Xfer system
+pub mod xfer;
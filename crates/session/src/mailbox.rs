@@ -1,30 +1,274 @@
 use actix::prelude::*;
 use actix_rt::time;
 use bincode;
-use log::{error, info, warn};
+use glam::Vec3;
+use log::{debug, error, info, warn};
+use metaverse_messages::abort_xfer::AbortXfer;
+use metaverse_messages::accept_friendship::AcceptFriendship;
+use metaverse_messages::accept_script_teleport::AcceptScriptTeleport;
+use metaverse_messages::activate_group::ActivateGroup;
+use metaverse_messages::agent_animation::AgentAnimation;
+use metaverse_messages::agent_data_update::AgentDataUpdate;
+use metaverse_messages::agent_data_update_request::AgentDataUpdateRequest;
+use metaverse_messages::agent_fov::AgentFOV;
+use metaverse_messages::agent_height_width::AgentHeightWidth;
+use metaverse_messages::agent_request_sit::AgentRequestSit;
+use metaverse_messages::agent_set_appearance::AgentSetAppearance;
+use metaverse_messages::agent_sit::AgentSit;
+use metaverse_messages::agent_throttle::{AgentThrottle, Throttles};
+use metaverse_messages::agent_update::AgentUpdate;
+use metaverse_messages::agent_wearables_request::AgentWearablesRequest;
+use metaverse_messages::agent_wearables_update::AgentWearablesUpdate;
+use metaverse_messages::asset_received::AssetReceived;
+use metaverse_messages::asset_transfer_failed::AssetTransferFailed;
+use metaverse_messages::asset_upload_complete::AssetUploadComplete;
+use metaverse_messages::asset_upload_request::AssetUploadRequest;
+use metaverse_messages::asset_uploaded::AssetUploaded;
+use metaverse_messages::attach_from_inventory::AttachFromInventory;
+use metaverse_messages::avatar_interests_update::AvatarInterestsUpdate;
+use metaverse_messages::avatar_picker_reply::AvatarPickerReply;
+use metaverse_messages::avatar_picker_request::AvatarPickerRequest;
+use metaverse_messages::avatar_properties_request::AvatarPropertiesRequest;
+use metaverse_messages::avatar_properties_update::AvatarPropertiesUpdate;
+use metaverse_messages::avatar_sit_response::AvatarSitResponse;
+use metaverse_messages::change_user_rights::ChangeUserRights;
+use metaverse_messages::chat_from_simulator::SourceType;
+use metaverse_messages::chat_from_viewer::ChatFromViewer;
+use metaverse_messages::child_simulator_event::ChildSimulatorEvent;
+use metaverse_messages::circuit_code::CircuitCodeData;
+use metaverse_messages::classified_info_request::ClassifiedInfoRequest;
+use metaverse_messages::complete_agent_movement::CompleteAgentMovementData;
 use metaverse_messages::complete_ping_check::CompletePingCheck;
+use metaverse_messages::confirm_xfer_packet::ConfirmXferPacket;
+use metaverse_messages::create_inventory_folder::CreateInventoryFolder;
+use metaverse_messages::create_inventory_item::CreateInventoryItem;
+use metaverse_messages::crossed_region::CrossedRegion;
+use metaverse_messages::decline_friendship::DeclineFriendship;
+use metaverse_messages::derez_object::DeRezObject;
+use metaverse_messages::detach_attachment::DetachAttachment;
+use metaverse_messages::dir_find_query::DirFindQuery;
+use metaverse_messages::dir_people_reply::DirPeopleReply;
+use metaverse_messages::dir_places_reply::DirPlacesReply;
+use metaverse_messages::disconnect::Disconnect;
+use metaverse_messages::economy_data::EconomyData;
+use metaverse_messages::economy_data_request::EconomyDataRequest;
+use metaverse_messages::enable_simulator::EnableSimulator;
+use metaverse_messages::estate_owner_message::EstateOwnerMessage;
+use metaverse_messages::event_info_request::EventInfoRequest;
+use metaverse_messages::feature_disabled::FeatureDisabled;
+use metaverse_messages::fetch_inventory::{FetchInventory, ItemRequest};
+use metaverse_messages::fetch_inventory_descendents::FetchInventoryDescendents;
+use metaverse_messages::fetch_inventory_reply::FetchInventoryReply;
+use metaverse_messages::force_script_control_release::ForceScriptControlRelease;
+use metaverse_messages::friendship_status::FriendshipStatus;
+use metaverse_messages::generic_message::GenericMessageData;
+use metaverse_messages::god_kick_user::GodKickUser;
+use metaverse_messages::grant_godlike_powers::GrantGodlikePowers;
+use metaverse_messages::grant_user_rights::GrantUserRights;
+use metaverse_messages::group_name_resolved::GroupNameResolved;
+use metaverse_messages::group_profile_request::GroupProfileRequest;
+use metaverse_messages::header::{Header, PacketFrequency};
+use metaverse_messages::health_message::HealthMessage;
+use metaverse_messages::image_data::ImageData;
+use metaverse_messages::image_packet::ImagePacket;
+use metaverse_messages::improved_instant_message::ImprovedInstantMessage;
+use metaverse_messages::improved_terse_object_update::ImprovedTerseObjectUpdate;
+use metaverse_messages::inventory_descendents::InventoryDescendents;
+use metaverse_messages::inventory_folder_contents::InventoryFolderContents;
+use metaverse_messages::inventory_folder_status::InventoryFolderStatus;
+use metaverse_messages::inventory_item_created::InventoryItemCreated;
+use metaverse_messages::inventory_item_status::InventoryItemStatus;
+use metaverse_messages::join_group_request::JoinGroupRequest;
+use metaverse_messages::kill_object::KillObjectData;
+use metaverse_messages::land_stat_reply::LandStatReply;
+use metaverse_messages::land_stat_request::LandStatRequest;
+use metaverse_messages::layer_data::LayerType;
+use metaverse_messages::leave_group_request::LeaveGroupRequest;
+use metaverse_messages::login_system::login_response::BuddyListValues;
+use metaverse_messages::logout_request::LogoutRequest;
+use metaverse_messages::map_block_reply::MapBlockReply;
+use metaverse_messages::map_block_request::MapBlockRequest;
+use metaverse_messages::map_item_reply::MapItemReply;
+use metaverse_messages::map_item_request::MapItemRequest;
+use metaverse_messages::map_name_request::MapNameRequest;
+use metaverse_messages::money_balance_request::MoneyBalanceRequest;
+use metaverse_messages::move_inventory_folder::MoveInventoryFolder;
+use metaverse_messages::move_inventory_item::MoveInventoryItem;
+use metaverse_messages::multiple_object_update::{MultipleObjectUpdate, ObjectTransformUpdate};
+use metaverse_messages::mute_list::MuteList;
+use metaverse_messages::mute_list_request::MuteListRequest;
+use metaverse_messages::mute_list_update::MuteListUpdate;
+use metaverse_messages::name_resolved::NameResolved;
+use metaverse_messages::object_add::ObjectAdd;
+use metaverse_messages::object_attach::ObjectAttach;
+use metaverse_messages::object_buy::ObjectBuy;
+use metaverse_messages::object_category::ObjectCategory;
+use metaverse_messages::object_click_action::ObjectClickAction;
+use metaverse_messages::object_de_grab::ObjectDeGrab;
+use metaverse_messages::object_delink::ObjectDelink;
+use metaverse_messages::object_description::ObjectDescription;
+use metaverse_messages::object_deselect::ObjectDeselect;
+use metaverse_messages::object_detach::ObjectDetach;
+use metaverse_messages::object_drop::ObjectDrop;
+use metaverse_messages::object_duplicate::ObjectDuplicate;
+use metaverse_messages::object_duplicate_on_ray::ObjectDuplicateOnRay;
+use metaverse_messages::object_edit_ack::ObjectEditAck;
+use metaverse_messages::object_extra_params::ObjectExtraParams;
+use metaverse_messages::object_family_status::ObjectFamilyStatus;
+use metaverse_messages::object_flag_update::ObjectFlagUpdate;
+use metaverse_messages::object_grab::ObjectGrab;
+use metaverse_messages::object_image::ObjectImage;
+use metaverse_messages::object_link::ObjectLink;
+use metaverse_messages::object_material::ObjectMaterial;
+use metaverse_messages::object_name::ObjectName;
+use metaverse_messages::object_permissions::ObjectPermissions;
+use metaverse_messages::object_properties::ObjectProperties;
+use metaverse_messages::object_properties_family::ObjectPropertiesFamily;
+use metaverse_messages::object_sale_info::ObjectSaleInfo;
+use metaverse_messages::object_select::ObjectSelect;
+use metaverse_messages::object_update::ObjectUpdate;
+use metaverse_messages::offline_notification::OfflineNotification;
+use metaverse_messages::online_notification::OnlineNotification;
 use metaverse_messages::packet::MessageType;
 use metaverse_messages::packet::Packet;
-use metaverse_messages::packet_ack::PacketAck;
 use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::parcel_access_list_reply::ParcelAccessListReply;
+use metaverse_messages::parcel_access_list_request::ParcelAccessListRequest;
+use metaverse_messages::parcel_access_list_update::ParcelAccessListUpdate;
+use metaverse_messages::parcel_dwell_request::ParcelDwellRequest;
+use metaverse_messages::parcel_overlay::ParcelOverlay;
+use metaverse_messages::parcel_properties::ParcelProperties;
+use metaverse_messages::parcel_properties_request::ParcelPropertiesRequest;
+use metaverse_messages::people_search_empty::PeopleSearchEmpty;
+use metaverse_messages::pick_info_request::PickInfoRequest;
+use metaverse_messages::ping_stats::PingStats;
+use metaverse_messages::preload_sound::PreloadSound;
+use metaverse_messages::purchase_failed::PurchaseFailed;
+use metaverse_messages::purge_inventory_descendents::PurgeInventoryDescendents;
+use metaverse_messages::purge_inventory_folder::PurgeInventoryFolder;
+use metaverse_messages::raw_packet::RawPacketData;
+use metaverse_messages::region_crossed::RegionCrossed;
 use metaverse_messages::region_handshake_reply::AgentData;
 use metaverse_messages::region_handshake_reply::RegionHandshakeReply;
 use metaverse_messages::region_handshake_reply::ReplyRegionInfo;
+use metaverse_messages::region_info_request::RegionInfoRequest;
+use metaverse_messages::remove_inventory_folder::RemoveInventoryFolder;
+use metaverse_messages::remove_inventory_item::RemoveInventoryItem;
+use metaverse_messages::remove_mute_list_entry::RemoveMuteListEntry;
+use metaverse_messages::report_unknown_packets::ReportUnknownPackets;
+use metaverse_messages::request_godlike_powers::RequestGodlikePowers;
+use metaverse_messages::request_image::{ImageRequest, RequestImage};
+use metaverse_messages::request_xfer::RequestXfer;
+use metaverse_messages::revoke_permissions::RevokePermissions;
+use metaverse_messages::rez_object::RezObject;
+use metaverse_messages::rez_script::RezScript;
+use metaverse_messages::rez_single_attachment_from_inv::RezSingleAttachmentFromInv;
+use metaverse_messages::script_answer_yes::ScriptAnswerYes;
+use metaverse_messages::script_control_change::ScriptControlChange;
+use metaverse_messages::script_controls::ScriptControls;
+use metaverse_messages::script_dialog_reply::ScriptDialogReply;
+use metaverse_messages::script_reset::ScriptReset;
+use metaverse_messages::script_running_reply::ScriptRunningReply;
+use metaverse_messages::script_running_status::ScriptRunningStatus;
+use metaverse_messages::self_agent_info::SelfAgentInfo;
+use metaverse_messages::send_xfer_packet::SendXferPacket;
+use metaverse_messages::set_always_run::SetAlwaysRun;
+use metaverse_messages::set_chat_filter::SetChatFilter;
+use metaverse_messages::set_script_running::SetScriptRunning;
+use metaverse_messages::set_velocity_interpolation::SetVelocityInterpolation;
+use metaverse_messages::sim_stats::SimStats;
+use metaverse_messages::simulator_viewer_time_message::SimulatorViewerTimeMessage;
+use metaverse_messages::sit_status::SitStatus;
+use metaverse_messages::start_ping_check::StartPingCheck;
+use metaverse_messages::teleport_failed::TeleportFailed;
+use metaverse_messages::teleport_finish::TeleportFinish;
+use metaverse_messages::teleport_location_request::TeleportLocationRequest;
+use metaverse_messages::terminate_friendship::TerminateFriendship;
+use metaverse_messages::texture_ready::TextureReady;
+use metaverse_messages::transfer_info::TransferInfo;
+use metaverse_messages::transfer_packet::TransferPacket;
 use metaverse_messages::ui_events::UiEventTypes;
+use metaverse_messages::update_create_inventory_item::UpdateCreateInventoryItem;
+use metaverse_messages::update_inventory_item::UpdateInventoryItem;
+use metaverse_messages::update_mute_list_entry::UpdateMuteListEntry;
+use metaverse_messages::use_cached_mute_list::UseCachedMuteList;
+use metaverse_messages::utils::asset_type::AssetType;
+use metaverse_messages::utils::derez_destination::DeRezDestination;
+use metaverse_messages::utils::sale_type::SaleType;
+use metaverse_messages::utils::surface_info::SurfaceInfo;
+use metaverse_messages::uuid_group_name_reply::{GroupName, UUIDGroupNameReply};
+use metaverse_messages::uuid_name_reply::{AvatarName, UUIDNameReply};
+use metaverse_messages::viewer_effect::ViewerEffect;
+use metaverse_messages::wearables::Wearables;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::net::UdpSocket as SyncUdpSocket;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use std::sync::Mutex;
 use tokio::net::UdpSocket;
-use std::net::UdpSocket as SyncUdpSocket;
-use tokio::sync::{oneshot, Notify};
+use tokio::sync::Notify;
+use tokio::task::AbortHandle;
 use tokio::time::Duration;
 use uuid::Uuid;
 
-use metaverse_messages::errors::{AckError, SessionError};
+use metaverse_messages::errors::{
+    AssetUploadError, CreateInventoryItemError, GodPowersError, ScriptRunningError, SessionError,
+};
 
-const ACK_ATTEMPTS: i8 = 3;
-const ACK_TIMEOUT: Duration = Duration::from_secs(1);
+use crate::asset_transfer::AssetTransferManager;
+use crate::asset_upload::{AssetUpload, AssetUploadManager};
+use crate::attachments::AttachmentManager;
+use crate::chat_filter::ChatFilter;
+use crate::circuit_stats::CircuitStatsTracker;
+use crate::dedupe::DuplicateFilter;
+use crate::directory::{DirectoryManager, DirectoryPage, DIRECTORY_PAGE_WINDOW};
+use crate::friends::FriendManager;
+use crate::health::HealthTracker;
+use crate::interpolation::InterpolationManager;
+use crate::inventory::{InventoryFetcher, InventoryModel, ItemFetcher};
+use crate::item_creation::{ItemCreation, ItemCreator};
+use crate::land_stat::LandStatManager;
+use crate::map::{
+    MapBlockManager, MapBlocksOutcome, MapItemManager, MapSearchManager, MAP_BLOCK_WINDOW,
+};
+use crate::movement::{MovementManager, MOVEMENT_INTERVAL};
+use crate::mute_list::MuteListManager;
+use crate::name_cache::NameCache;
+use crate::object_family::ObjectFamilyManager;
+use crate::object_sale::SaleChecker;
+use crate::packet_stats::{PacketStats, ParseFailure};
+use crate::parcel::{ParcelOverlayAssembler, ParcelTracker, PARCEL_CHECK_INTERVAL};
+use crate::parcel_access::{ParcelAccessManager, PARCEL_ACCESS_WINDOW};
+use crate::pause::PauseManager;
+use crate::pending_acks::{PendingAcks, ACK_FLUSH_DELAY};
+use crate::people_search::{PeopleSearchManager, PeopleSearchOutcome};
+use crate::purchase::PurchaseManager;
+use crate::region_lookup::{
+    RegionLookup, RegionLookupManager, RegionLookupStart, REGION_LOOKUP_FLAGS,
+};
+use crate::retransmit::{RetransmitQueue, RETRANSMIT_CHECK_INTERVAL};
+use crate::script_controls::ScriptControlManager;
+use crate::script_permissions::ScriptPermissionManager;
+use crate::script_running::{ScriptRunningManager, ScriptRunningQuery};
+use crate::sequence::SequenceNumbers;
+use crate::sim_stats::{SimStatsThrottle, SIM_STATS_INTERVAL};
+use crate::sit::SitManager;
+use crate::sound::SoundPreloadManager;
+use crate::sun::SunTracker;
+use crate::texture_download::{TextureDownloadManager, TEXTURE_TIMEOUT};
+use crate::transactions::TransactionRegistry;
+use crate::typing::TypingAnimation;
+use crate::xfer::{DownloadedXfer, XferKey, XferReceiver, XferSender, XferUpload, XFER_TIMEOUT};
+
+/// pings that haven't been answered after this long are counted as missed
+pub const PING_TIMEOUT: Duration = Duration::from_secs(10);
+/// how long to wait for a LogoutReply before closing the circuit anyway
+pub const LOGOUT_TIMEOUT: Duration = Duration::from_secs(5);
+/// how long packets from a retired circuit are still handled after switching simulators
+pub const CIRCUIT_GRACE_PERIOD: Duration = Duration::from_secs(5);
+/// how often asset transfers are checked for timeouts
+const TRANSFER_CHECK_INTERVAL: Duration = Duration::from_secs(1);
 
 /// This is the mailbox for handling packets and sessions in the client
 #[derive(Debug)]
@@ -34,11 +278,11 @@ pub struct Mailbox {
     /// UDP socket for connecting mailbox to the UI
     pub server_to_ui_socket: String,
 
-    /// queue of ack packets to handle
-    pub ack_queue: Arc<Mutex<HashMap<u32, oneshot::Sender<()>>>>,
+    /// the reliable packets waiting to be acked, resent if their acks don't arrive
+    pub retransmits: RetransmitQueue,
 
-    /// global number of received packets
-    pub packet_sequence_number: Arc<Mutex<u32>>,
+    /// the sequence numbers of the packets sent to each simulator
+    pub sequence_numbers: SequenceNumbers,
     /// state of the mailbox. If it is running or not.
     pub state: Arc<Mutex<ServerState>>,
     /// notify for etablishing when it begins running
@@ -51,6 +295,137 @@ pub struct Mailbox {
 
     /// the global ping information
     pub ping_info: PingInfo,
+
+    /// reply to RegionHandshake packets automatically. Clients that want to send their own
+    /// RegionHandshakeReply can turn this off.
+    pub auto_reply_region_handshake: bool,
+
+    /// bandwidth allocations sent to the simulator in AgentThrottle after login
+    pub throttles: Throttles,
+    /// the generation counter of the last AgentThrottle sent
+    pub throttle_gen_counter: u32,
+    /// the height and width of the UI's window in pixels, sent in AgentHeightWidth. None until
+    /// the UI reports it.
+    pub viewport_size: Option<(u16, u16)>,
+    /// the generation counter of the last AgentHeightWidth sent
+    pub height_width_gen_counter: u32,
+    /// the UI's vertical field of view in radians, sent in AgentFOV. None until the UI reports
+    /// it.
+    pub vertical_angle: Option<f32>,
+    /// the generation counter of the last AgentFOV sent
+    pub fov_gen_counter: u32,
+
+    /// true while a login is in progress, before the session has been established
+    pub login_pending: bool,
+    /// the task reading packets from the simulator, aborted when the session ends
+    pub udp_read: Option<AbortHandle>,
+    /// the interval sending pings to the simulator, cancelled when the session ends
+    pub ping_handle: Option<SpawnHandle>,
+    /// the timer that closes the circuit if the simulator never answers a LogoutRequest
+    pub logout_timeout: Option<SpawnHandle>,
+    /// the timer that sends the acks that didn't ride along on a packet in PacketAcks
+    pub ack_flush: Option<SpawnHandle>,
+    /// the interval resending reliable packets that weren't acked, cancelled when the session ends
+    pub retransmit_handle: Option<SpawnHandle>,
+    /// addresses of simulators whose circuits have been retired. Packets from them are dropped.
+    pub closed_circuits: Arc<Mutex<HashSet<SocketAddr>>>,
+    /// child agent circuits to neighbouring regions, by region handle. The current simulator is
+    /// not included.
+    pub child_circuits: Arc<Mutex<HashMap<u64, SocketAddr>>>,
+    /// textures being downloaded from the simulator
+    pub texture_downloads: TextureDownloadManager,
+    /// assets being downloaded from the simulator with the Transfer system
+    pub asset_transfers: AssetTransferManager,
+    /// files being uploaded to the simulator with the Xfer system
+    pub xfers: XferSender,
+    /// files being downloaded from the simulator with the Xfer system
+    pub xfer_downloads: XferReceiver,
+    /// assets being uploaded to the simulator
+    pub asset_uploads: AssetUploadManager,
+    /// avatar names that have been looked up
+    pub names: NameCache,
+    /// objects being bought
+    pub purchases: PurchaseManager,
+    /// the permissions of objects, to check their sale info against
+    pub sales: SaleChecker,
+    /// the parcel the agent is on
+    pub parcels: ParcelTracker,
+    /// the chunks of the region's parcel overlay that have arrived
+    pub parcel_overlay: ParcelOverlayAssembler,
+    /// the regions of the world map that are still arriving
+    pub map_blocks: MapBlockManager,
+    /// the markers of the world map that are still arriving
+    pub map_items: MapItemManager,
+    /// the searches of the world map waiting for their results
+    pub map_searches: MapSearchManager,
+    /// the regions being looked up by name, and the script teleports waiting for them
+    pub region_lookups: RegionLookupManager<AcceptScriptTeleport>,
+    /// the permissions granted to scripts
+    pub script_permissions: ScriptPermissionManager,
+    /// the sounds the UI has been asked to preload
+    pub sound_preloads: SoundPreloadManager,
+    /// the object the agent is sitting on, or asking to sit on
+    pub sits: SitManager,
+    /// the controls the agent is holding down
+    pub movement: MovementManager,
+    /// whether the UI has paused the agent, and the serial number of the last pause or resume
+    pub pause: PauseManager,
+    /// the controls scripts have taken from the agent, and the objects that took them
+    pub script_controls: ScriptControlManager,
+    /// the agent's health, to tell when the agent dies
+    pub health: HealthTracker,
+    /// the SimStats of the current region waiting to be sent to the UI
+    pub sim_stats: SimStatsThrottle,
+    /// the time of day of the current region, to tell where its sun is
+    pub sun: SunTracker,
+    /// the typing animation played while the agent writes in chat. Clients that play it
+    /// themselves can turn it off.
+    pub typing: TypingAnimation,
+    /// the agent's mute list, shared with the task reading packets so it can drop the messages
+    /// the list blocks
+    pub mutes: Arc<Mutex<MuteListManager>>,
+    /// the channels and types of nearby chat the UI wants, shared with the task reading packets
+    /// so it can drop the rest
+    pub chat_filter: Arc<Mutex<ChatFilter>>,
+    /// the latest sequence numbers from each simulator, shared with the task reading packets so
+    /// it can drop the packets that arrive twice
+    pub duplicates: Arc<Mutex<DuplicateFilter>>,
+    /// how many packets of each type arrived, and whether to report the unknown ones to the UI
+    pub packet_stats: PacketStats,
+    /// the packets, bytes and acks sent and received on each circuit
+    pub circuit_stats: CircuitStatsTracker,
+    /// the largest datagram read from a simulator. Longer ones are cut off, and dropped.
+    pub receive_buffer_size: usize,
+    /// the reliable packets from simulators that haven't been acked yet
+    pub pending_acks: PendingAcks,
+    /// whether simulators interpolate the motion of objects, and the circuits that know it
+    pub interpolation: InterpolationManager,
+    /// what the transactions the session sent are for, to explain a FeatureDisabled
+    pub transactions: TransactionRegistry,
+    /// the agent's friends, and which of them are online
+    pub friends: FriendManager,
+    /// the inventory folders being fetched
+    pub inventory: InventoryFetcher,
+    /// the single inventory items being fetched
+    pub inventory_items: ItemFetcher,
+    /// the inventory items that have arrived, with the agent's changes applied
+    pub inventory_model: InventoryModel,
+    /// the inventory items the simulator is making for the agent
+    pub item_creations: ItemCreator,
+    /// the searches for residents waiting for their results
+    pub people_searches: PeopleSearchManager,
+    /// the pages of directory searches waiting for their results
+    pub directory: DirectoryManager,
+    /// the access and ban lists of parcels waiting for the rest of their entries
+    pub parcel_access: ParcelAccessManager,
+    /// the LandStat reports waiting for the rest of their pages
+    pub land_stats: LandStatManager,
+    /// the objects the agent is wearing
+    pub attachments: AttachmentManager,
+    /// the questions about whether scripts are running waiting for their answer
+    pub script_running: ScriptRunningManager,
+    /// the questions about objects being hovered over waiting for their answer
+    pub object_family: ObjectFamilyManager,
 }
 
 /// Session of the user
@@ -65,6 +440,28 @@ pub struct Session {
     pub agent_id: Uuid,
     /// session ID of the user
     pub session_id: Uuid,
+    /// secure session ID of the user, which asset ids of uploads are derived from
+    pub secure_session_id: Uuid,
+    /// circuit code of the UDP session
+    pub circuit_code: u32,
+    /// handle of the region the agent is currently in
+    pub region_handle: u64,
+    /// serial number of the last AgentWearablesUpdate. Appearance changes send the next one.
+    pub wearables_serial_num: u32,
+    /// serial number of the last AgentSetAppearance sent
+    pub appearance_serial_num: u32,
+    /// the agent's active group, from the last AgentDataUpdate. Nil for none. Objects the agent
+    /// rezzes, takes or buys are for this group.
+    pub active_group_id: Uuid,
+    /// the agent's name and active group as the grid sees them, from the login and the last
+    /// AgentDataUpdate
+    pub self_info: SelfAgentInfo,
+    /// the agent's god level, from the last GrantGodlikePowers. 0 if the agent has no god
+    /// powers, which god commands like GodKickUser need.
+    pub god_level: u8,
+    /// the prices of the grid, from the EconomyData asked for after the first RegionHandshake.
+    /// None until it arrives.
+    pub economy: Option<EconomyData>,
     /// the running UDP socket attached to the session  
     pub socket: Option<Arc<UdpSocket>>,
 }
@@ -110,12 +507,73 @@ impl UiMessage {
 #[derive(Debug, Message)]
 #[rtype(result = "()")]
 pub struct PingInfo {
-    /// the number of the ping
+    /// the id of the next ping to send
     pub ping_number: u8,
-    /// how long the latency is. Currently not doing anything.
+    /// round trip time of the most recently completed ping
     pub ping_latency: Duration,
     /// time of last ping
     pub last_ping: time::Instant,
+    /// how often the mailbox sends its own StartPingCheck to the server
+    pub interval: Duration,
+    /// pings that have been sent but not answered, by ping id
+    pub pending: HashMap<u8, time::Instant>,
+    /// number of pings sent
+    pub sent_pings: u32,
+    /// number of pings that were never answered
+    pub missed_pings: u32,
+}
+impl PingInfo {
+    /// create a PingInfo that pings on the given interval
+    pub fn new(interval: Duration) -> Self {
+        PingInfo {
+            ping_number: 0,
+            ping_latency: Duration::new(0, 0),
+            last_ping: time::Instant::now(),
+            interval,
+            pending: HashMap::new(),
+            sent_pings: 0,
+            missed_pings: 0,
+        }
+    }
+
+    /// record a new outgoing ping and return the id to send it with.
+    /// The ping id is a single byte, so after 256 pings the ids wrap around. If a ping with the
+    /// same id is still waiting for a reply, it is counted as missed and replaced.
+    pub fn start_ping(&mut self, now: time::Instant) -> u8 {
+        let ping_id = self.ping_number;
+        self.ping_number = self.ping_number.wrapping_add(1);
+        if self.pending.insert(ping_id, now).is_some() {
+            self.missed_pings += 1;
+        }
+        self.sent_pings += 1;
+        self.last_ping = now;
+        ping_id
+    }
+
+    /// match a CompletePingCheck to the ping that started it, returning the round trip time.
+    /// Replies for pings that aren't pending are ignored.
+    pub fn complete_ping(&mut self, ping_id: u8, now: time::Instant) -> Option<Duration> {
+        let sent = self.pending.remove(&ping_id)?;
+        self.ping_latency = now.duration_since(sent);
+        Some(self.ping_latency)
+    }
+
+    /// count every ping that has waited longer than the timeout as missed
+    pub fn expire_pings(&mut self, now: time::Instant, timeout: Duration) {
+        let pending = self.pending.len();
+        self.pending
+            .retain(|_, sent| now.duration_since(*sent) < timeout);
+        self.missed_pings += (pending - self.pending.len()) as u32;
+    }
+
+    /// the stats that are sent to the UI
+    pub fn stats(&self) -> PingStats {
+        PingStats {
+            latency_ms: self.ping_latency.as_millis() as u64,
+            sent_pings: self.sent_pings,
+            missed_pings: self.missed_pings,
+        }
+    }
 }
 
 /// this is a simple message that gets sent when receiving the CompletePingcheck
@@ -123,370 +581,6181 @@ pub struct PingInfo {
 #[rtype(result = "()")]
 pub struct Ping {
     ping_id: u8,
+    addr: SocketAddr,
 }
 
-/// message to send when receiving a RegionHandshake
+/// this gets sent when receiving a CompletePingCheck for a ping the mailbox started
 #[derive(Debug, Message)]
 #[rtype(result = "()")]
-pub struct RegionHandshakeMessage;
+pub struct PingResponse {
+    ping_id: u8,
+}
 
-/// The state of the Mailbox
-#[derive(Debug, Clone, PartialEq)]
-pub enum ServerState {
-    /// The mailbox starts in the Starting state
-    Starting,
-    /// The mailbox is running
-    Running,
-    /// The mailbox is preparing to stop
-    Stopping,
-    /// the mailbox is stopped
-    Stopped,
+/// message to send when receiving a RegionHandshake
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RegionHandshakeMessage {
+    /// the simulator that sent the handshake
+    pub addr: SocketAddr,
 }
 
-impl Mailbox {
-    /// Start_udp_read is for reading packets coming from the external server
-    async fn start_udp_read(
-        ack_queue: Arc<Mutex<HashMap<u32, oneshot::Sender<()>>>>,
-        sock: Arc<UdpSocket>,
-        mailbox_address: Addr<Mailbox>,
-    ) {
-        let mut buf = [0; 1500];
-        loop {
-            match sock.recv_from(&mut buf).await {
-                Ok((size, _addr)) => {
-                    //info!("Received {} bytes from {:?}", size, addr);
+/// message to send the configured throttles to the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AgentThrottleMessage;
 
-                    let packet = match Packet::from_bytes(&buf[..size]) {
-                        Ok(packet) => packet,
-                        Err(_) => {
-                            continue;
-                        }
-                    };
-                    if packet.header.reliable {
-                        if let Err(e) = mailbox_address
-                            .send(Packet::new_packet_ack(PacketAck {
-                                packet_ids: vec![packet.header.sequence_number],
-                            }))
-                            .await
-                        {
-                            warn!("Ack failed to send {:?}", e)
-                        };
-                    }
+/// message to send the size and field of view the UI reported to the simulator. Whichever of
+/// them the UI hasn't reported yet is left out.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ViewportMessage;
 
-                    match &packet.body {
-                        PacketType::PacketAck(data) => {
-                            let mut queue = ack_queue.lock().unwrap();
-                            for id in data.packet_ids.clone() {
-                                if let Some(sender) = queue.remove(&id) {
-                                    let _ = sender.send(());
-                                } 
-                            }
-                        }
-                        PacketType::StartPingCheck(data) => {
-                            if let Err(e) = mailbox_address
-                                .send(Ping {
-                                    ping_id: data.ping_id,
-                                })
-                                .await
-                            {
-                                warn!("failed to handle pong {:?}", e)
-                            };
-                        }
-                        PacketType::RegionHandshake(_) => {
-                            match mailbox_address.send(RegionHandshakeMessage {}).await {
-                                Ok(_) => {}
-                                Err(e) => error!("error: {:?}", e),
-                            }
-                        }
-                        PacketType::DisableSimulator(_) => {
-                            warn!("Simulator shutting down...");
-                            if let Err(e) = mailbox_address
-                                .send(UiMessage::new(
-                                    UiEventTypes::DisableSimulatorEvent {},
-                                    vec![],
-                                ))
-                                .await
-                            {
-                                warn!("failed to send to ui: {:?}", e)
-                            }
-                            break;
-                        }
-                        _ => {}
-                    }
-                    if let MessageType::Event = &packet.body.message_type() {
-                        if let Err(e) = mailbox_address
-                            .send(UiMessage::new(
-                                packet.body.ui_event(),
-                                packet.body.to_bytes(),
-                            ))
-                            .await
-                        {
-                            warn!("failed to send to ui: {:?}", e)
-                        };
-                    }
-                }
-                Err(e) => {
-                    error!("Failed to receive data: {}", e);
-                    break;
-                }
-            }
-        }
-    }
+/// message to report the size of the UI's window. It is remembered, and sent again to every
+/// simulator the agent moves to.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetViewportSize(pub AgentHeightWidth);
 
-    fn set_state(&mut self, new_state: ServerState, _ctx: &mut Context<Self>) {
-        let state_clone = Arc::clone(&self.state);
-        {
-            let mut state = state_clone.lock().unwrap();
-            *state = new_state.clone();
-        }
-        // notify on start and stop
-        if new_state == ServerState::Running || new_state == ServerState::Stopped {
-            self.notify.notify_one();
-        }
-    }
-}
+/// message to report the UI's vertical field of view. It is remembered, and sent again to every
+/// simulator the agent moves to.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetFieldOfView(pub AgentFOV);
 
-impl Actor for Mailbox {
-    type Context = Context<Self>;
+/// message to ask the simulator for the agent's wearables
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AgentWearablesRequestMessage;
 
-    fn started(&mut self, ctx: &mut Self::Context) {
-        info!("Actix Mailbox has started");
-        self.set_state(ServerState::Running, ctx);
-    }
-}
+/// message to ask the simulator for the agent's name and active group. The AgentDataUpdate is
+/// sent to the UI as an AgentDataEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AgentDataUpdateRequestMessage;
 
-impl Handler<RegionHandshakeMessage> for Mailbox {
-    type Result = ();
-    fn handle(&mut self, _: RegionHandshakeMessage, ctx: &mut Self::Context) -> Self::Result {
-        ctx.address()
-            .do_send(Packet::new_region_handshake_reply(RegionHandshakeReply {
-                agent_data: AgentData {
-                    session_id: self.session.as_ref().unwrap().session_id,
-                    agent_id: self.session.as_ref().unwrap().agent_id,
-                },
-                region_info: ReplyRegionInfo { flags: 0 },
-            }));
-    }
-}
+/// message to send the agent's name and active group to the UI as a SelfAgentInfoEvent
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct QuerySelfInfo;
 
-impl Handler<Ping> for Mailbox {
-    type Result = ();
-    fn handle(&mut self, msg: Ping, ctx: &mut Self::Context) -> Self::Result {
-        ctx.address()
-            .do_send(Packet::new_complete_ping_check(CompletePingCheck {
-                ping_id: msg.ping_id,
-            }));
-        self.ping_info.ping_latency = time::Instant::now() - self.ping_info.last_ping;
-    }
-}
+/// this gets sent when receiving the agent's wearables from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AgentWearablesUpdateMessage(pub AgentWearablesUpdate);
 
-impl Handler<UiMessage> for Mailbox {
-    type Result = ();
-    fn handle(&mut self, msg: UiMessage, _: &mut Self::Context) -> Self::Result {
-            let max_message_size = 1024;
-            // leave a little room at the end
-            let overhead = 2;
-
-            let message_type_len = msg.message_type.to_string().len();
-            let sequence_number_len = std::mem::size_of::<u16>(); // 2 bytes for the sequence number
-            let total_packet_number_len = std::mem::size_of::<u16>();
-            let packet_number_len = std::mem::size_of::<u16>();
-
-            // Calculate the maximum size available for the actual message content
-            let available_size = max_message_size
-                - (message_type_len
-                    + sequence_number_len
-                    + total_packet_number_len
-                    + packet_number_len
-                    + overhead);
-
-            // Split the message content if it's larger than the available size
-            let message = msg.message;
-            let total_chunks = usize::max(1, message.len().div_ceil(available_size));
-
-            // Loop through each chunk and send it
-            for chunk_index in 0..total_chunks {
-                let start = chunk_index * available_size;
-                let end = usize::min(start + available_size, message.len());
-                let chunk = &message[start..end];
-
-                // Increment the sequence number for each chunk
-                let sequence_number = msg.sequence_number + chunk_index as u16;
-
-                // Create a new message with the chunked data
-                let chunked_message = UiMessage {
-                    message_type: msg.message_type.clone(),
-                    sequence_number,
-                    total_packet_number: total_chunks as u16, // Add total number of chunks
-                    message: chunk.to_vec(),
-                    packet_number: self.sent_packet_count,
-                };
+/// message to ask the simulator for the agent's balance. The MoneyBalanceReply is sent to the
+/// UI as a BalanceEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct MoneyBalanceRequestMessage;
 
-                let client_socket = SyncUdpSocket::bind("0.0.0.0:0").unwrap();
-                if let Err(e) =
-                    client_socket.send_to(&chunked_message.as_bytes(), &self.server_to_ui_socket)
-                {
+/// message to select objects by their local ids. The simulator answers with their
+/// ObjectProperties, which are sent to the UI as an ObjectPropertiesEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SelectObjects(pub Vec<u32>);
 
-                    info!("sending to: {}", self.server_to_ui_socket);
-                    error!(
-                        "Error sending chunk {} of {} from mailbox: {:?}",
-                        sequence_number, total_chunks, e
-                    )
-                }
-            }
-            self.sent_packet_count += 1;
-        }
+/// message to deselect objects by their local ids
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DeselectObjects(pub Vec<u32>);
+
+/// message to rez an object. The agent and session IDs are filled in from the session, and
+/// the active group if no group is set.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AddObject(pub ObjectAdd);
+
+/// message to derez objects, taking them into inventory, deleting them or returning them.
+/// Lists of more than 255 objects are split across several DeRezObject packets.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DeRez {
+    /// the local ids of the objects in the region
+    pub local_ids: Vec<u32>,
+    /// where the objects go
+    pub destination: DeRezDestination,
+    /// the inventory folder to put the objects in, nil for the default folder
+    pub folder_id: Uuid,
 }
 
-// set the session to initialized.
-impl Handler<Session> for Mailbox {
-    type Result = ();
-    fn handle(&mut self, mut msg: Session, ctx: &mut Self::Context) -> Self::Result {
-        if let Some(session) = self.session.as_ref() {
-            msg.socket = session.socket.clone();
-        }
-        self.session = Some(msg);
+/// message to copy objects to an offset from themselves. The agent and session IDs are filled
+/// in from the session, and the active group if no group is set. Lists of more than 255 objects
+/// are split across several ObjectDuplicate packets.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DuplicateObjects(pub ObjectDuplicate);
 
-        // if the session doesn't already have a UDP socket to watch, create one
-        if let Some(session) = self.session.as_ref() {
-            if session.socket.is_none() {
-                let addr = format!("0.0.0.0:{}", self.client_socket);
+/// message to copy objects to where a ray hits. The agent and session IDs are filled in from the
+/// session, and the active group if no group is set. Lists of more than 255 objects are split
+/// across several ObjectDuplicateOnRay packets.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DuplicateObjectsOnRay(pub ObjectDuplicateOnRay);
 
-                let addr_clone = addr.clone();
-                let mailbox_addr = ctx.address();
+/// message to link objects into one link set. The root is sent first, as the simulator takes
+/// the first object as the root. A link the simulator would refuse isn't sent, and a
+/// LinkObjectsError is sent to the UI instead.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct LinkObjects {
+    /// the local id of the object that becomes the root
+    pub root_id: u32,
+    /// the local ids of the objects that become its children
+    pub child_ids: Vec<u32>,
+}
 
-                info!("session established, starting UDP processing");
-                let ack_queue = self.ack_queue.clone();
+/// message to unlink objects from their link sets. Lists of more than 255 objects are split
+/// across several ObjectDelink packets.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DelinkObjects(pub Vec<u32>);
 
-                let fut = async move {
-                    match UdpSocket::bind(&addr).await {
-                        Ok(sock) => {
-                            info!("Successfully bound to {}", &addr);
-                            let sock = Arc::new(sock);
-                            // Spawn a new Tokio task for reading from the socket
-                            tokio::spawn(Mailbox::start_udp_read(
-                                ack_queue,
-                                sock.clone(),
-                                mailbox_addr,
-                            ));
-                            Ok(sock) // Return the socket wrapped in Arc
-                        }
-                        Err(e) => {
-                            error!("Failed to bind to {}: {}", &addr_clone, e);
-                            Err(e)
-                        }
-                    }
-                };
+/// message to attach objects in the region to the agent's avatar. The agent and session IDs
+/// are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AttachObjects(pub ObjectAttach);
 
-                // wait for the socket to be successfully bound and then assign it
-                ctx.spawn(fut.into_actor(self).map(|result, act, _| match result {
-                    Ok(sock) => {
-                        if let Some(session) = &mut act.session {
-                            session.socket = Some(sock);
-                        }
-                    }
-                    Err(_) => {
-                        panic!("Socket binding failed");
-                    }
-                }));
-            }
-        }
-    }
-}
+/// message to wear an item from inventory. The item has to be in the inventory the session has
+/// fetched, which the rest of the RezSingleAttachmentFromInv is filled in from.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AttachItem(pub AttachFromInventory);
 
-impl Handler<Packet> for Mailbox {
-    type Result = ();
-    fn handle(&mut self, mut msg: Packet, ctx: &mut Self::Context) -> Self::Result {
-        if let Some(ref session) = self.session {
-            let addr = format!("{}:{}", session.url, session.server_socket);
-            {
-                let sequence_number = self.packet_sequence_number.lock().unwrap();
-                msg.header.sequence_number = *sequence_number;
-            }
+/// message to detach an object the agent is wearing, by its local id or by the item it was
+/// attached from
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct Detach(pub DetachAttachment);
 
-            if msg.header.reliable {
-                let ack_future = send_ack(
-                    msg,
-                    addr,
-                    self.ack_queue.clone(),
-                    session.socket.as_ref().unwrap().clone(),
-                );
-                ctx.spawn(
-                    async move {
-                        if let Err(e) = ack_future.await {
-                            error!("Error sending acknowledgment: {:?}", e);
-                        }
-                    }
-                    .into_actor(self),
-                );
-            } else {
-                let data = msg.to_bytes().clone();
-                let socket_clone = session.socket.as_ref().unwrap().clone();
-                let fut = async move {
-                    if let Err(e) = socket_clone.send_to(&data, &addr).await {
-                        error!("Failed to send data: {}", e);
-                    }
-                };
-                ctx.spawn(fut.into_actor(self));
-            };
-            {
-                let mut sequence_number = self.packet_sequence_number.lock().unwrap();
-                *sequence_number += 1;
-            }
-        }
-    }
-}
+/// message to detach objects the agent is wearing by their local ids, taking them back into
+/// inventory. Lists of more than 255 objects are split across several ObjectDetach packets.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DetachObjects(pub Vec<u32>);
 
-async fn send_ack(
-    packet: Packet,
-    addr: String,
-    ack_queue: Arc<Mutex<HashMap<u32, oneshot::Sender<()>>>>,
-    socket: Arc<UdpSocket>,
-) -> Result<(), SessionError> {
-    let mut attempts = 0;
-    let mut received_ack = false;
-    let packet_id = packet.header.sequence_number;
-    while attempts < ACK_ATTEMPTS && !received_ack {
-        let (tx, rx) = oneshot::channel();
-        let mut packet_clone = packet.clone();
+/// message to drop objects the agent is wearing into the region, by their local ids. Lists of
+/// more than 255 objects are split across several ObjectDrop packets.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DropObjects(pub Vec<u32>);
 
-        // if there have been more than 1 attempt, set the resent to true.
-        if attempts > 0 {
-            packet_clone.header.resent = true;
-        }
+/// message to put a script from inventory into an object. The agent and session IDs are filled
+/// in from the session, and the active group if no group is set.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AddScript(pub RezScript);
 
-        {
-            let mut queue = ack_queue.lock().unwrap();
-            queue.insert(packet_id, tx);
-        }
-        // Send the packet
+/// message to ask whether a script in an object is running, returning a future that resolves
+/// to the answer. The answer is also sent to the UI as a ScriptRunningStatusEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "Result<ScriptRunningQuery, SessionError>")]
+pub struct QueryScriptRunning {
+    /// the full id of the object the script is in
+    pub object_id: Uuid,
+    /// the id of the script in the object's contents
+    pub item_id: Uuid,
+}
 
-        let data = packet_clone.to_bytes().clone();
-        let addr_clone = addr.clone();
-        let sock_clone = socket.clone();
-        if let Err(e) = sock_clone.send_to(&data, addr_clone).await {
-            error!("Failed to send Ack: {}", e);
-        }
+/// message to start or stop a script in an object
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RunScript {
+    /// the full id of the object the script is in
+    pub object_id: Uuid,
+    /// the id of the script in the object's contents
+    pub item_id: Uuid,
+    /// true to start the script, false to stop it
+    pub running: bool,
+}
 
-        tokio::select! {
-            _ = rx => {
-                received_ack = true;
-            },
-            _ = tokio::time::sleep(ACK_TIMEOUT) => {
-                attempts += 1;
-                if !received_ack && attempts >= ACK_ATTEMPTS {
-                    // Remove from queue after final attempt
-                    let mut queue = ack_queue.lock().unwrap();
-                    queue.remove(&packet_id);
+/// message to reset a script in an object
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ResetScript {
+    /// the full id of the object the script is in
+    pub object_id: Uuid,
+    /// the id of the script in the object's contents
+    pub item_id: Uuid,
+}
+
+/// this gets sent when the simulator says whether a script is running
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ScriptRunningReplyMessage(pub ScriptRunningReply);
+
+/// this gets sent when a script takes or releases the agent's controls. The controls held after
+/// the change are sent to the UI as a ScriptControlsEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ScriptControlChangeMessage(pub ScriptControlChange);
+
+/// message to take the agent's controls back from every script that took them. The agent and
+/// session IDs are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ReleaseScriptControls(pub ForceScriptControlRelease);
+
+/// message to start and stop animations on the agent's avatar. The agent and session IDs are
+/// filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct Animate(pub AgentAnimation);
+
+/// message to send chat to the current region. The agent and session IDs are filled in from the
+/// session, and the typing animation is started or stopped with it.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SendChat(pub ChatFromViewer);
+
+/// message to ask for the owner, group, price, name and description of an object without
+/// selecting it, like when hovering over it. The answer is sent to the UI as an
+/// ObjectFamilyStatusEvent carrying the token.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestObjectFamily {
+    /// the full id of the object
+    pub object_id: Uuid,
+    /// picked by the UI, and sent back with the answer
+    pub token: u32,
+}
+
+/// this gets sent when the simulator answers a question about an object being hovered over
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ObjectPropertiesFamilyMessage(pub ObjectPropertiesFamily);
+
+/// message to set the faces of objects, like their textures and colors. The agent and session
+/// IDs are filled in from the session. Edits of many objects are split across as many
+/// ObjectImage packets as they need, and an ObjectEditAck is sent to the UI once they are sent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetObjectFaces(pub ObjectImage);
+
+/// message to rename objects. Names longer than the simulator keeps are cut down, and the
+/// ObjectEditAck sent to the UI once the edit is sent warns about them.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RenameObjects(pub ObjectName);
+
+/// message to set the descriptions of objects. Descriptions longer than the simulator keeps are
+/// cut down, and the ObjectEditAck sent to the UI once the edit is sent warns about them.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DescribeObjects(pub ObjectDescription);
+
+/// message to set or remove extra params of objects, like making them flexible or a light. The
+/// agent and session IDs are filled in from the session, and an ObjectEditAck is sent to the UI
+/// once the edit is sent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct EditExtraParams(pub ObjectExtraParams);
+
+/// message to set the physical materials of objects. The agent and session IDs are filled in
+/// from the session, and an ObjectEditAck is sent to the UI once the edit is sent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetMaterials(pub ObjectMaterial);
+
+/// message to turn physics, phantom and temporary on or off for an object. The agent and
+/// session IDs are filled in from the session, and an ObjectEditAck is sent to the UI once the
+/// edit is sent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetObjectFlags(pub ObjectFlagUpdate);
+
+/// message to set what happens when objects are clicked. The agent and session IDs are filled
+/// in from the session, and an ObjectEditAck is sent to the UI once the edit is sent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetClickActions(pub ObjectClickAction);
+
+/// message to set or clear the permissions of objects, like what the next owner can do with
+/// them. The agent and session IDs are filled in from the session, and an ObjectEditAck is sent
+/// to the UI once the edit is sent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetObjectPermissions(pub ObjectPermissions);
+
+/// message to put objects up for sale or take them off sale. Objects with a negative price, or
+/// a sale type their permissions don't allow, are left out with a warning in the ObjectEditAck.
+/// The objects are selected again afterwards, so the UI gets their new ObjectProperties.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetSaleInfo(pub ObjectSaleInfo);
+
+/// message to set the categories objects are listed under when they are for sale. The objects
+/// are selected again afterwards, so the UI gets their new ObjectProperties.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetObjectCategories(pub ObjectCategory);
+
+/// message to move, rotate and scale objects
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UpdateObjects(pub Vec<ObjectTransformUpdate>);
+
+/// message to rez an item from inventory. The agent and session IDs are filled in from the
+/// session, along with the active group if no group is set, and every rez gets a new
+/// transaction ID.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RezItem(pub RezObject);
+
+/// message to touch an object, like clicking it. This grabs the object and releases it again,
+/// which is what scripts see as a touch.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct TouchObject {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// where the object was clicked, in region coordinates. Scripts can't tell where they were
+    /// touched without it.
+    pub position: Option<Vec3>,
+}
+
+/// message to publish the agent's appearance. The agent and session IDs and the serial number
+/// are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetAppearance(pub AgentSetAppearance);
+
+/// message to set whether a login is in progress
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct LoginPending(pub bool);
+
+/// message to log out of the current session, or cancel a login that is in progress
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct Logout;
+
+/// this gets sent when receiving a LogoutReply from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct LogoutReplyMessage;
+
+/// message to send a packet to a specific simulator instead of the current one.
+/// Used to send acks to a circuit that is being retired.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct PacketTo {
+    /// the packet to send
+    pub packet: Packet,
+    /// the simulator to send it to
+    pub addr: SocketAddr,
+}
+
+/// this gets sent when receiving a reliable packet from a simulator. The ack is appended to the
+/// next packet sent to the simulator, or sent in a PacketAck if none is sent soon.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct QueueAck {
+    /// the sequence number of the reliable packet
+    pub sequence_number: u32,
+    /// the simulator that sent it
+    pub addr: SocketAddr,
+}
+
+/// this gets sent when a simulator acks reliable packets, in a PacketAck or appended to any
+/// packet. The packets won't be resent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AcksReceived {
+    /// the sequence numbers of the acked packets
+    pub sequence_numbers: Vec<u32>,
+    /// the simulator that acked them
+    pub addr: SocketAddr,
+}
+
+/// this gets sent for every packet from a simulator that isn't a duplicate, to count it. The
+/// packets the client doesn't implement come with their bodies, to report them to the UI.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct PacketReceived {
+    /// the simulator that sent the packet
+    pub addr: SocketAddr,
+    /// the size of the datagram
+    pub size: usize,
+    /// the frequency of the packet's message number
+    pub frequency: PacketFrequency,
+    /// the packet's message number
+    pub id: u16,
+    /// the packet, if the client doesn't implement it
+    pub unknown: Option<RawPacketData>,
+}
+
+/// this gets sent for every datagram from a simulator that was dropped because it couldn't be
+/// parsed, to count it
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ParseFailed {
+    /// what was wrong with the datagram
+    pub failure: ParseFailure,
+    /// the simulator that sent it
+    pub addr: SocketAddr,
+    /// the size of the datagram
+    pub size: usize,
+}
+
+/// this gets sent for every packet from a simulator that arrived again and was dropped, to count
+/// it
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DuplicateReceived {
+    /// the simulator that sent the packet
+    pub addr: SocketAddr,
+    /// the size of the datagram
+    pub size: usize,
+}
+
+/// message to send the statistics of the circuits to the UI right away, as a CircuitStatsEvent
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct QueryStats;
+
+/// this gets sent when receiving a TeleportFinish from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct TeleportFinishMessage(pub TeleportFinish);
+
+/// this gets sent when receiving a CrossedRegion from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct CrossedRegionMessage(pub CrossedRegion);
+
+/// this gets sent when a simulator announces a neighbouring region with EnableSimulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct EnableSimulatorMessage(pub EnableSimulator);
+
+/// message to close the child agent circuit to a neighbouring region
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct CloseChildCircuit {
+    /// handle of the neighbouring region
+    pub region_handle: u64,
+}
+
+/// message to download textures, or cancel downloads with a discard level of -1
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestTextures(pub Vec<ImageRequest>);
+
+/// this gets sent when receiving the first packet of a texture
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ImageDataMessage(pub ImageData);
+
+/// this gets sent when receiving a continuation packet of a texture
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ImagePacketMessage(pub ImagePacket);
+
+/// message to download an asset with the Transfer system
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestAsset {
+    /// the asset to download
+    pub asset_id: Uuid,
+    /// the type of the asset
+    pub asset_type: AssetType,
+    /// higher priorities are sent first
+    pub priority: f32,
+}
+
+/// this gets sent when receiving the simulator's answer to a TransferRequest
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct TransferInfoMessage(pub TransferInfo);
+
+/// this gets sent when receiving a chunk of a transfer
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct TransferPacketMessage(pub TransferPacket);
+
+/// message to upload a file with the Xfer system. The file is requested by its name, or by the
+/// VFileID of the returned XferUpload if it has no name.
+#[derive(Debug, Message)]
+#[rtype(result = "XferUpload")]
+pub struct StartXfer {
+    /// the contents of the file
+    pub data: Vec<u8>,
+    /// the name the simulator will request the file by
+    pub file_name: Option<String>,
+}
+
+/// message to upload an asset. The asset data is sent inline or with the Xfer system depending
+/// on its size.
+#[derive(Debug, Message)]
+#[rtype(result = "Result<AssetUpload, SessionError>")]
+pub struct UploadAsset(pub AssetUploadRequest);
+
+/// this gets sent when the simulator has stored or failed to store an uploaded asset
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AssetUploadCompleteMessage(pub AssetUploadComplete);
+
+/// this gets sent when the simulator refuses a request because the feature is turned off. It is
+/// sent to the UI as a FeatureDisabledEvent, and an upload with the transaction id fails.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct FeatureDisabledMessage(pub FeatureDisabled);
+
+/// this gets sent when the simulator asks for a file with the Xfer system
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestXferMessage(pub RequestXfer);
+
+/// this gets sent when the simulator confirms a packet of an Xfer upload
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ConfirmXferPacketMessage(pub ConfirmXferPacket);
+
+/// this gets sent when the simulator aborts an Xfer upload
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AbortXferMessage(pub AbortXfer);
+
+/// message to look up the names of avatars. Names that aren't cached are requested from the
+/// simulator, and sent to the UI as a NameResolvedEvent when they arrive.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct LookupNames {
+    /// the avatars to look up
+    pub ids: Vec<Uuid>,
+    /// send the names that are already cached to the UI too. Lookups the session makes on its
+    /// own only send new names.
+    pub send_cached: bool,
+}
+
+/// this gets sent when receiving avatar names from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UUIDNameReplyMessage(pub UUIDNameReply);
+
+/// message to buy an object. The object is selected first, and only bought once its
+/// ObjectProperties show that it is still for sale with the sale type and price the UI expected.
+/// Otherwise a PurchaseFailedEvent is sent to the UI. The simulator answers purchases with a
+/// MoneyBalanceReply, which is sent to the UI as a BalanceEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct BuyObject {
+    /// the local id of the object in the region
+    pub local_id: u32,
+    /// the sale type the UI expects
+    pub sale_type: SaleType,
+    /// the price the UI expects to pay
+    pub price: i32,
+    /// the inventory folder the object goes into, nil for the default folder
+    pub category_id: Uuid,
+}
+
+/// this gets sent when receiving ObjectProperties from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ObjectPropertiesMessage(pub ObjectProperties);
+
+/// this gets sent when receiving an ObjectUpdate from the current simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ObjectUpdateMessage(pub ObjectUpdate);
+
+/// this gets sent when receiving an ImprovedTerseObjectUpdate from the current simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct TerseObjectUpdateMessage(pub ImprovedTerseObjectUpdate);
+
+/// this gets sent when receiving a KillObject from the current simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct KillObjectMessage(pub KillObjectData);
+
+/// this gets sent when receiving ParcelProperties from the current simulator. Only the parcel
+/// the agent is on is sent to the UI, as a ParcelPropertiesEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ParcelPropertiesMessage(pub ParcelProperties);
+
+/// this gets sent when receiving a chunk of the ParcelOverlay from the current simulator. Once
+/// every chunk has arrived, the whole overlay is sent to the UI as a ParcelOverlayEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ParcelOverlayMessage(pub ParcelOverlay);
+
+/// this gets sent when receiving a HealthMessage from the current simulator. It is sent to the UI
+/// as a HealthEvent, and followed by an AgentDiedEvent if the agent died.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct HealthMessageMessage(pub HealthMessage);
+
+/// this gets sent when receiving SimStats from the current simulator. They are sent to the UI as
+/// a SimStatsEvent, at most once every SIM_STATS_INTERVAL.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SimStatsMessage(pub SimStats);
+
+/// this gets sent when receiving a SimulatorViewerTimeMessage from the current simulator. It is
+/// kept as the time of day of the region, and sent to the UI as a SimulatorTimeEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SimulatorViewerTimeMessageMessage(pub SimulatorViewerTimeMessage);
+
+/// message to request the regions in a rectangle of the world map. The agent and session IDs are
+/// filled in from the session. The replies are gathered by their flags, and sent to the UI as a
+/// MapBlocksEvent once they stop arriving.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestMapBlocks(pub MapBlockRequest);
+
+/// this gets sent when receiving a MapBlockReply from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct MapBlockReplyMessage(pub MapBlockReply);
+
+/// message to request the markers of one kind on a region of the world map. The agent and
+/// session IDs are filled in from the session. The replies are gathered by their flags and item
+/// type, and sent to the UI as a MapItemsEvent once they stop arriving.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestMapItems(pub MapItemRequest);
+
+/// this gets sent when receiving a MapItemReply from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct MapItemReplyMessage(pub MapItemReply);
+
+/// message to search for residents by name. The agent and session IDs are filled in from the
+/// session, along with a query ID if the UI didn't pick one. The results are sent to the UI as a
+/// PeopleSearchResultsEvent, or a PeopleSearchEmptyEvent if nobody was found.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SearchPeople(pub AvatarPickerRequest);
+
+/// this gets sent when the simulator answers a search for residents
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AvatarPickerReplyMessage(pub AvatarPickerReply);
+
+/// message to search the directory for a page of residents or places. The agent and session IDs
+/// are filled in from the session, along with a query ID if the UI didn't pick one. The page is
+/// sent to the UI as a DirectoryPeopleEvent or a DirectoryPlacesEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SearchDirectory(pub DirFindQuery);
+
+/// this gets sent when the simulator answers a directory search for residents
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DirPeopleReplyMessage(pub DirPeopleReply);
+
+/// this gets sent when the simulator answers a directory search for places
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DirPlacesReplyMessage(pub DirPlacesReply);
+
+/// message to ask for the dwell of a parcel by its local ID. The agent and session IDs are
+/// filled in from the session. The ParcelDwellReply is sent to the UI as a ParcelDwellEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestParcelDwell(pub ParcelDwellRequest);
+
+/// message to ask for the access list, the ban list, or both of a parcel by its local ID. The
+/// agent and session IDs and the sequence ID are filled in by the session. Each list is sent to
+/// the UI as a ParcelAccessListEvent once all of its replies have arrived.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestParcelAccessList(pub ParcelAccessListRequest);
+
+/// this gets sent when the simulator sends part of a parcel's access or ban list
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ParcelAccessListReplyMessage(pub ParcelAccessListReply);
+
+/// message to replace the access list or the ban list of a parcel. The agent and session IDs are
+/// filled in from the session, and the list is split into as many updates as it needs.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UpdateParcelAccessList(pub ParcelAccessListUpdate);
+
+/// message to ask for a report of the top scripts or top colliders of the region. The agent and
+/// session IDs are filled in from the session. The report is sent to the UI as a LandStatEvent
+/// once all of its pages have arrived.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestLandStat(pub LandStatRequest);
+
+/// this gets sent when the simulator sends a page of a LandStat report
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct LandStatReplyMessage(pub LandStatReply);
+
+/// message to run an estate management command. The agent and session IDs are filled in from
+/// the session, along with an invoice if the UI didn't pick one. Commands that change the
+/// telehub are followed by a request for the telehub, so a new TelehubInfo is sent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SendEstateMessage(pub EstateOwnerMessage);
+
+/// message to send a GenericMessage. The agent and session IDs are filled in from the session,
+/// along with an invoice if the UI didn't pick one.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SendGenericMessage(pub GenericMessageData);
+
+/// message to search the world map for regions by name. The agent and session IDs are filled
+/// in from the session, and the search is tagged in the flags. The results are sent to the UI as
+/// a MapSearchResultsEvent, or a MapSearchEmptyEvent if nothing was found.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SearchMap(pub MapNameRequest);
+
+/// message to accept a destination offered by a script. The region is looked up by its name,
+/// and the agent is teleported there with a TeleportLocationRequest. A region that can't be found
+/// is sent to the UI as a TeleportFailedEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AcceptTeleportOffer(pub AcceptScriptTeleport);
+
+/// message to answer a script dialog. The agent and session IDs are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AnswerDialog(pub ScriptDialogReply);
+
+/// message to answer a script asking for permissions. The agent and session IDs are filled in
+/// from the session, and the permissions granted are remembered so they can be revoked.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AnswerScriptQuestion(pub ScriptAnswerYes);
+
+/// message to take back permissions granted to the scripts in an object. The agent and session
+/// IDs are filled in from the session. Revoking no permissions revokes every permission the
+/// object's scripts were granted.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RevokeScriptPermissions(pub RevokePermissions);
+
+/// this gets sent when receiving a PreloadSound from a simulator. Sounds that haven't been sent
+/// to the UI yet in this session are sent as a PreloadSoundEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct PreloadSoundMessage(pub PreloadSound);
+
+/// message to ask the simulator for the agent's mute list, which is sent to the UI as a
+/// MuteListEvent once it has been downloaded
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestMuteList;
+
+/// this gets sent when receiving a MuteListUpdate from the current simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct MuteListUpdateMessage(pub MuteListUpdate);
+
+/// this gets sent when receiving a UseCachedMuteList from the current simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UseCachedMuteListMessage(pub UseCachedMuteList);
+
+/// this gets sent when the simulator sends a packet of a file being downloaded with the Xfer
+/// system
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SendXferPacketMessage(pub SendXferPacket);
+
+/// message to add an entry to the mute list, or change its flags. The agent and session IDs are
+/// filled in from the session, and the UI is sent the new MuteListEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct Mute(pub UpdateMuteListEntry);
+
+/// message to take an entry off the mute list. The agent and session IDs are filled in from the
+/// session, and the UI is sent the new MuteListEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct Unmute(pub RemoveMuteListEntry);
+
+/// message to pick which nearby chat is sent to the UI. The filter is kept for the rest of the
+/// session, and for later ones.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct FilterChat(pub SetChatFilter);
+
+/// message to load the agent's friends from the buddy list of the login response. The UI is
+/// sent the friends as a FriendListEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct LoadFriends(pub Vec<BuddyListValues>);
+
+/// this gets sent when receiving an OnlineNotification from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct OnlineNotificationMessage(pub OnlineNotification);
+
+/// this gets sent when receiving an OfflineNotification from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct OfflineNotificationMessage(pub OfflineNotification);
+
+/// this gets sent when the agent's name or active group changes
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AgentDataUpdateMessage(pub AgentDataUpdate);
+
+/// message to join a group. The agent and session IDs are filled in from the session. The
+/// JoinGroupReply is sent to the UI as a JoinGroupEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct JoinGroup(pub JoinGroupRequest);
+
+/// message to leave a group. The agent and session IDs are filled in from the session. The
+/// LeaveGroupReply is sent to the UI as a LeaveGroupEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct LeaveGroup(pub LeaveGroupRequest);
+
+/// message to change the agent's active group. The agent and session IDs are filled in from
+/// the session. The AgentDataUpdate with the new group is sent to the UI as an AgentDataEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetActiveGroup(pub ActivateGroup);
+
+/// message to ask for god powers, or give them up. The agent and session IDs are filled in from
+/// the session. The GrantGodlikePowers with the god level is sent to the UI as a GodPowersEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestGodPowers(pub RequestGodlikePowers);
+
+/// this gets sent when the simulator grants or takes away the agent's god powers
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct GrantGodlikePowersMessage(pub GrantGodlikePowers);
+
+/// message to kick, freeze or unfreeze a user with god powers. The god IDs are filled in from
+/// the session. Without god powers nothing is sent, and a GodPowersError is sent to the UI.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct KickUserAsGod(pub GodKickUser);
+
+/// this gets sent when the simulator sends the prices of the grid
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct EconomyDataMessage(pub EconomyData);
+
+/// message to send the prices of the grid to the UI as an EconomyDataEvent. The cached
+/// EconomyData is sent if there is one, and otherwise it is asked for again.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestEconomyData;
+
+/// message to get the cached prices of the grid, for checking what something costs before
+/// doing it. None if they haven't arrived yet.
+#[derive(Debug, Message)]
+#[rtype(result = "Option<EconomyData>")]
+pub struct GetEconomyData;
+
+/// message to ask for the settings of the current region. The RegionInfo the simulator answers
+/// with is sent to the UI as a RegionInfoEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestRegionInfo;
+
+/// message to ask for an avatar's profile. The agent and session IDs are filled in from the
+/// session. The profile comes back in pieces, as an AvatarPropertiesEvent, an
+/// AvatarInterestsEvent and an AvatarGroupsEvent. The lists of the avatar's picks and
+/// classifieds are asked for with it, and sent as an AvatarPicksEvent and an
+/// AvatarClassifiedsEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestAvatarProperties(pub AvatarPropertiesRequest);
+
+/// message to ask for the details of a pick, with the pickinforequest GenericMessage. The
+/// PickInfoReply is sent to the UI as a PickInfoEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestPickInfo(pub PickInfoRequest);
+
+/// message to ask for the details of a classified. The agent and session IDs are filled in from
+/// the session. The ClassifiedInfoReply is sent to the UI as a ClassifiedInfoEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestClassifiedInfo(pub ClassifiedInfoRequest);
+
+/// message to ask for the details of an event. The agent and session IDs are filled in from the
+/// session. The EventInfoReply is sent to the UI as an EventInfoEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestEventInfo(pub EventInfoRequest);
+
+/// message to change the agent's profile. The agent and session IDs are filled in from the
+/// session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UpdateProfile(pub AvatarPropertiesUpdate);
+
+/// message to change the interests in the agent's profile. The agent and session IDs are filled
+/// in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UpdateInterests(pub AvatarInterestsUpdate);
+
+/// message to ask for a group's details. The agent and session IDs are filled in from the
+/// session. The GroupProfileReply is sent to the UI as a GroupProfileEvent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RequestGroupProfile(pub GroupProfileRequest);
+
+/// this gets sent when another agent offers the agent friendship, or answers its offer
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct FriendshipMessage(pub ImprovedInstantMessage);
+
+/// this gets sent when the rights between the agent and its friends change
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ChangeUserRightsMessage(pub ChangeUserRights);
+
+/// this gets sent when a friend ends their friendship with the agent
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct TerminateFriendshipMessage(pub TerminateFriendship);
+
+/// message to offer friendship to another agent, with an ImprovedInstantMessage to them. The
+/// agent and session IDs are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct OfferFriendship(pub ImprovedInstantMessage);
+
+/// message to accept a friendship offered to the agent. Offers that were never made are
+/// refused. The agent and session IDs are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AcceptFriend(pub AcceptFriendship);
+
+/// message to decline a friendship offered to the agent. Offers that were never made are
+/// refused. The agent and session IDs are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct DeclineFriend(pub DeclineFriendship);
+
+/// message to change the rights the agent gives its friends. The agent and session IDs are
+/// filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct GrantRights(pub GrantUserRights);
+
+/// message to end a friendship. The agent and session IDs are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct EndFriendship(pub TerminateFriendship);
+
+/// message to fetch an inventory folder and every folder inside it. Each folder is sent to the
+/// UI as an InventoryFolderEvent. A nil owner is filled in with the agent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct FetchInventoryTree(pub FetchInventoryDescendents);
+
+/// this gets sent when receiving the contents of an inventory folder from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct InventoryDescendentsMessage(pub InventoryDescendents);
+
+/// message to fetch inventory items by ID. Each item is sent to the UI as an
+/// InventoryItemEvent, or as not found if it never arrives. A nil owner is filled in with the
+/// agent.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct FetchItems(pub FetchInventory);
+
+/// this gets sent when receiving inventory items from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct FetchInventoryReplyMessage(pub FetchInventoryReply);
+
+/// message to change inventory items. The change is sent to the UI straight away, and the items
+/// are fetched again to pick up what the simulator made of it. The agent and session IDs are
+/// filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UpdateItems(pub UpdateInventoryItem);
+
+/// message to move inventory items into other folders. The move is sent to the UI straight
+/// away, and the items are fetched again to pick up what the simulator made of it. The agent and
+/// session IDs are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct MoveItems(pub MoveInventoryItem);
+
+/// message to delete inventory items. Each item is sent to the UI as removed straight away. The
+/// agent and session IDs are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RemoveItems(pub RemoveInventoryItem);
+
+/// message to make an inventory folder. A nil folder ID is replaced with a new one, which is
+/// sent to the UI in an InventoryFolderStatusEvent. The agent and session IDs are filled in from
+/// the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct CreateFolder(pub CreateInventoryFolder);
+
+/// message to move inventory folders into other folders. The agent and session IDs are filled
+/// in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct MoveFolders(pub MoveInventoryFolder);
+
+/// message to delete inventory folders with everything inside them. The agent and session IDs
+/// are filled in from the session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct RemoveFolders(pub RemoveInventoryFolder);
+
+/// message to delete everything inside an inventory folder. Purges that aren't confirmed are
+/// refused.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct PurgeFolder(pub PurgeInventoryFolder);
+
+/// message to make an inventory item, returning a future that resolves to the new item. The
+/// result is also sent to the UI as an InventoryItemCreatedEvent with the callback ID of the
+/// request. The agent and session IDs are filled in from the session, and the callback ID is
+/// replaced with one of the session's own.
+#[derive(Debug, Message)]
+#[rtype(result = "Result<ItemCreation, SessionError>")]
+pub struct CreateItem(pub CreateInventoryItem);
+
+/// this gets sent when the simulator has made or changed inventory items for the agent
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UpdateCreateInventoryItemMessage(pub UpdateCreateInventoryItem);
+
+/// message to show the agent's own effects, like a beam to the object being edited. The agent
+/// and session IDs are filled in from the session, and so is the agent ID of effects that leave
+/// it nil.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SendViewerEffect(pub ViewerEffect);
+
+/// message to sit on an object. The agent and session IDs are filled in from the session. The
+/// simulator's answer is confirmed with an AgentSit, and the UI is sent a SitStatusEvent saying
+/// whether the agent sat down.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SitOnObject(pub AgentRequestSit);
+
+/// this gets sent when receiving an AvatarSitResponse from the current simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct AvatarSitResponseMessage(pub AvatarSitResponse);
+
+/// message to stand up from the object the agent is sitting on
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct StandUp;
+
+/// message to walk, run, fly or stop. The agent and session IDs are filled in from the session.
+/// An update holding down controls is sent again until an update with no controls replaces it.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct Move(pub AgentUpdate);
+
+/// message to pause the agent while the UI isn't drawing, like when it is minimized. The
+/// simulator stops streaming object updates, and the controls the agent is holding down aren't
+/// sent again until the agent resumes.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct Pause;
+
+/// message to resume the agent after a pause. The throttles and the size of the UI's window are
+/// sent again, since some simulators forget what the agent can see while it is paused.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct Resume;
+
+/// message to turn always run on or off. The agent and session IDs are filled in from the
+/// session.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetRunning(pub SetAlwaysRun);
+
+/// message to turn the simulators' interpolation of moving objects on or off. The setting is
+/// sent to every open circuit and every circuit opened later, and a
+/// VelocityInterpolationStatus is sent to the UI.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct SetInterpolation(pub SetVelocityInterpolation);
+
+/// message to turn reporting the packets the client doesn't implement to the UI on or off
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct ReportUnknown(pub ReportUnknownPackets);
+
+/// message to look up the names of groups. Names that aren't cached are requested from the
+/// simulator, and sent to the UI as a GroupNameResolvedEvent when they arrive.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct LookupGroupNames {
+    /// the groups to look up
+    pub ids: Vec<Uuid>,
+    /// send the names that are already cached to the UI too
+    pub send_cached: bool,
+}
+
+/// this gets sent when receiving group names from the simulator
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct UUIDGroupNameReplyMessage(pub UUIDGroupNameReply);
+
+/// message to end the session because the simulator closed the circuit. The UiMessage tells
+/// the UI why.
+#[derive(Debug, Message)]
+#[rtype(result = "()")]
+pub struct EndSession(pub UiMessage);
+
+/// The state of the Mailbox
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerState {
+    /// The mailbox starts in the Starting state
+    Starting,
+    /// The mailbox is running
+    Running,
+    /// The mailbox is preparing to stop
+    Stopping,
+    /// the mailbox is stopped
+    Stopped,
+}
+
+impl Mailbox {
+    /// Start_udp_read is for reading packets coming from the external server
+    #[allow(clippy::too_many_arguments)]
+    async fn start_udp_read(
+        closed_circuits: Arc<Mutex<HashSet<SocketAddr>>>,
+        child_circuits: Arc<Mutex<HashMap<u64, SocketAddr>>>,
+        mutes: Arc<Mutex<MuteListManager>>,
+        chat_filter: Arc<Mutex<ChatFilter>>,
+        duplicates: Arc<Mutex<DuplicateFilter>>,
+        receive_buffer_size: usize,
+        sock: Arc<UdpSocket>,
+        mailbox_address: Addr<Mailbox>,
+    ) {
+        let mut buf = vec![0; receive_buffer_size];
+        loop {
+            match sock.recv_from(&mut buf).await {
+                Ok((size, addr)) => {
+                    //info!("Received {} bytes from {:?}", size, addr);
+                    if closed_circuits.lock().unwrap().contains(&addr) {
+                        continue;
+                    }
+
+                    let packet = match Mailbox::receive_datagram(
+                        &buf,
+                        size,
+                        addr,
+                        &duplicates,
+                        &mailbox_address,
+                    )
+                    .await
+                    {
+                        Some(packet) => packet,
+                        None => continue,
+                    };
+                    // packets from neighbouring regions are tagged with their region handle
+                    let child_region = child_circuits
+                        .lock()
+                        .unwrap()
+                        .iter()
+                        .find(|(_, child_addr)| **child_addr == addr)
+                        .map(|(region_handle, _)| *region_handle);
+
+                    // chat and instant messages the mute list blocks never reach the UI
+                    if mutes.lock().unwrap().blocks(&packet.body) {
+                        continue;
+                    }
+                    // as is chat on channels or of types the UI didn't ask for
+                    if chat_filter.lock().unwrap().blocks(&packet.body) {
+                        continue;
+                    }
+
+                    match &packet.body {
+                        PacketType::PacketAck(_) => {}
+                        PacketType::StartPingCheck(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(Ping {
+                                    ping_id: data.ping_id,
+                                    addr,
+                                })
+                                .await
+                            {
+                                warn!("failed to handle pong {:?}", e)
+                            };
+                        }
+                        PacketType::CompletePingCheck(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(PingResponse {
+                                    ping_id: data.ping_id,
+                                })
+                                .await
+                            {
+                                warn!("failed to handle ping response {:?}", e)
+                            };
+                        }
+                        PacketType::RegionHandshake(_) => {
+                            match mailbox_address.send(RegionHandshakeMessage { addr }).await {
+                                Ok(_) => {}
+                                Err(e) => error!("error: {:?}", e),
+                            }
+                        }
+                        PacketType::LogoutReply(_) => {
+                            if let Err(e) = mailbox_address.send(LogoutReplyMessage {}).await {
+                                warn!("failed to handle logout reply {:?}", e)
+                            };
+                        }
+                        PacketType::TeleportFinish(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(TeleportFinishMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle teleport finish {:?}", e)
+                            };
+                        }
+                        PacketType::CrossedRegion(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(CrossedRegionMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle crossed region {:?}", e)
+                            };
+                        }
+                        PacketType::EnableSimulator(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(EnableSimulatorMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle enable simulator {:?}", e)
+                            };
+                        }
+                        PacketType::DisableSimulator(_) => {
+                            // a neighbouring region going away only closes its own circuit.
+                            // The UI still gets the event, tagged with the region handle.
+                            if let Some(region_handle) = child_region {
+                                if let Err(e) = mailbox_address
+                                    .send(CloseChildCircuit { region_handle })
+                                    .await
+                                {
+                                    warn!("failed to close child circuit {:?}", e)
+                                };
+                            } else {
+                                warn!("Simulator shutting down...");
+                                if let Err(e) = mailbox_address
+                                    .send(EndSession(UiMessage::new(
+                                        UiEventTypes::DisableSimulatorEvent {},
+                                        vec![],
+                                    )))
+                                    .await
+                                {
+                                    warn!("failed to end session: {:?}", e)
+                                }
+                                break;
+                            }
+                        }
+                        PacketType::KickUser(data) => {
+                            warn!("Kicked from simulator: {}", data.reason);
+                            if let Err(e) = mailbox_address
+                                .send(EndSession(UiMessage::new(
+                                    packet.body.ui_event(),
+                                    packet.body.ui_event_bytes(),
+                                )))
+                                .await
+                            {
+                                warn!("failed to end session: {:?}", e)
+                            }
+                            break;
+                        }
+                        PacketType::ImageData(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ImageDataMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle image data {:?}", e)
+                            };
+                        }
+                        PacketType::ImagePacket(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ImagePacketMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle image packet {:?}", e)
+                            };
+                        }
+                        PacketType::TransferInfo(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(TransferInfoMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle transfer info {:?}", e)
+                            };
+                        }
+                        PacketType::TransferPacket(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(TransferPacketMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle transfer packet {:?}", e)
+                            };
+                        }
+                        PacketType::AgentWearablesUpdate(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(AgentWearablesUpdateMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle wearables update {:?}", e)
+                            };
+                        }
+                        PacketType::AssetUploadComplete(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(AssetUploadCompleteMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle asset upload complete {:?}", e)
+                            };
+                        }
+                        PacketType::FeatureDisabled(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(FeatureDisabledMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle feature disabled {:?}", e)
+                            };
+                        }
+                        PacketType::RequestXfer(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(RequestXferMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle xfer request {:?}", e)
+                            };
+                        }
+                        PacketType::ConfirmXferPacket(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ConfirmXferPacketMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle xfer confirmation {:?}", e)
+                            };
+                        }
+                        PacketType::AbortXfer(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(AbortXferMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle xfer abort {:?}", e)
+                            };
+                        }
+                        PacketType::UUIDNameReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(UUIDNameReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle name reply {:?}", e)
+                            };
+                        }
+                        PacketType::ObjectProperties(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ObjectPropertiesMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle object properties {:?}", e)
+                            };
+                        }
+                        // local ids are only unique within a region, so child regions are skipped
+                        PacketType::ObjectUpdate(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(ObjectUpdateMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle object update {:?}", e)
+                            };
+                        }
+                        PacketType::KillObject(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(KillObjectMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle kill object {:?}", e)
+                            };
+                        }
+                        PacketType::ImprovedTerseObjectUpdate(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(TerseObjectUpdateMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle terse object update {:?}", e)
+                            };
+                        }
+                        PacketType::ParcelProperties(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(ParcelPropertiesMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle parcel properties {:?}", e)
+                            };
+                        }
+                        PacketType::ParcelOverlay(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(ParcelOverlayMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle parcel overlay {:?}", e)
+                            };
+                        }
+                        PacketType::HealthMessage(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(HealthMessageMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle health message {:?}", e)
+                            };
+                        }
+                        PacketType::SimStats(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(SimStatsMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle sim stats {:?}", e)
+                            };
+                        }
+                        PacketType::SimulatorViewerTimeMessage(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(SimulatorViewerTimeMessageMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle simulator time {:?}", e)
+                            };
+                        }
+                        PacketType::MapBlockReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(MapBlockReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle map block reply {:?}", e)
+                            };
+                        }
+                        PacketType::MapItemReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(MapItemReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle map item reply {:?}", e)
+                            };
+                        }
+                        PacketType::UUIDGroupNameReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(UUIDGroupNameReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle group name reply {:?}", e)
+                            };
+                        }
+                        PacketType::AvatarSitResponse(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(AvatarSitResponseMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle sit response {:?}", e)
+                            };
+                        }
+                        PacketType::MuteListUpdate(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(MuteListUpdateMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle mute list update {:?}", e)
+                            };
+                        }
+                        PacketType::UseCachedMuteList(data) if child_region.is_none() => {
+                            if let Err(e) = mailbox_address
+                                .send(UseCachedMuteListMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle cached mute list {:?}", e)
+                            };
+                        }
+                        PacketType::OnlineNotification(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(OnlineNotificationMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle online notification {:?}", e)
+                            };
+                        }
+                        PacketType::OfflineNotification(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(OfflineNotificationMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle offline notification {:?}", e)
+                            };
+                        }
+                        PacketType::ImprovedInstantMessage(data) if data.dialog.is_friendship() => {
+                            if let Err(e) = mailbox_address
+                                .send(FriendshipMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle friendship message {:?}", e)
+                            };
+                        }
+                        PacketType::AgentDataUpdate(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(AgentDataUpdateMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle agent data update {:?}", e)
+                            };
+                        }
+                        PacketType::GrantGodlikePowers(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(GrantGodlikePowersMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle grant godlike powers {:?}", e)
+                            };
+                        }
+                        PacketType::EconomyData(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(EconomyDataMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle economy data {:?}", e)
+                            };
+                        }
+                        PacketType::ChangeUserRights(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ChangeUserRightsMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle change user rights {:?}", e)
+                            };
+                        }
+                        PacketType::TerminateFriendship(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(TerminateFriendshipMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle terminate friendship {:?}", e)
+                            };
+                        }
+                        PacketType::InventoryDescendents(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(InventoryDescendentsMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle inventory descendents {:?}", e)
+                            };
+                        }
+                        PacketType::FetchInventoryReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(FetchInventoryReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle fetch inventory reply {:?}", e)
+                            };
+                        }
+                        PacketType::AvatarPickerReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(AvatarPickerReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle avatar picker reply {:?}", e)
+                            };
+                        }
+                        PacketType::DirPeopleReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(DirPeopleReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle dir people reply {:?}", e)
+                            };
+                        }
+                        PacketType::DirPlacesReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(DirPlacesReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle dir places reply {:?}", e)
+                            };
+                        }
+                        PacketType::ParcelAccessListReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ParcelAccessListReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle parcel access list reply {:?}", e)
+                            };
+                        }
+                        PacketType::LandStatReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(LandStatReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle land stat reply {:?}", e)
+                            };
+                        }
+                        PacketType::ScriptRunningReply(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ScriptRunningReplyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle script running reply {:?}", e)
+                            };
+                        }
+                        PacketType::ScriptControlChange(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ScriptControlChangeMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle script control change {:?}", e)
+                            };
+                        }
+                        PacketType::ObjectPropertiesFamily(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(ObjectPropertiesFamilyMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle object properties family {:?}", e)
+                            };
+                        }
+                        PacketType::UpdateCreateInventoryItem(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(UpdateCreateInventoryItemMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle update create inventory item {:?}", e)
+                            };
+                        }
+                        PacketType::SendXferPacket(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(SendXferPacketMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle xfer packet {:?}", e)
+                            };
+                        }
+                        PacketType::PreloadSound(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(PreloadSoundMessage((**data).clone()))
+                                .await
+                            {
+                                warn!("failed to handle preload sound {:?}", e)
+                            };
+                        }
+                        // the UI will want to show the names of the avatars it hears from
+                        PacketType::CoarseLocationUpdate(data) => {
+                            if let Err(e) = mailbox_address
+                                .send(LookupNames {
+                                    ids: data.agent_ids.clone(),
+                                    send_cached: false,
+                                })
+                                .await
+                            {
+                                warn!("failed to look up names {:?}", e)
+                            };
+                        }
+                        PacketType::ChatFromSimulator(data) => {
+                            let id = match data.source_type {
+                                SourceType::Agent => Some(data.source_id),
+                                SourceType::Object => Some(data.owner_id),
+                                _ => None,
+                            };
+                            if let Some(id) = id {
+                                if let Err(e) = mailbox_address
+                                    .send(LookupNames {
+                                        ids: vec![id],
+                                        send_cached: false,
+                                    })
+                                    .await
+                                {
+                                    warn!("failed to look up names {:?}", e)
+                                };
+                            }
+                        }
+                        PacketType::LayerData(data) => {
+                            if let LayerType::Unknown(layer_type) = data.layer_type {
+                                warn!("skipping LayerData with unknown layer type {}", layer_type);
+                            }
+                        }
+                        _ => {}
+                    }
+                    // some packets are only events for some of their contents, like LayerData
+                    if let UiEventTypes::None = packet.body.ui_event() {
+                        continue;
+                    }
+                    if let MessageType::Event = &packet.body.message_type() {
+                        let message = match child_region {
+                            Some(region_handle) => UiMessage::new(
+                                UiEventTypes::ChildSimulatorEvent,
+                                serde_json::to_vec(&ChildSimulatorEvent {
+                                    region_handle,
+                                    event: packet.body.ui_event(),
+                                    data: packet.body.ui_event_bytes(),
+                                })
+                                .unwrap_or_default(),
+                            ),
+                            None => {
+                                UiMessage::new(packet.body.ui_event(), packet.body.ui_event_bytes())
+                            }
+                        };
+                        if let Err(e) = mailbox_address.send(message).await {
+                            warn!("failed to send to ui: {:?}", e)
+                        };
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to receive data: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// do the bookkeeping for a datagram from a simulator, read into the start of the buffer.
+    /// Its ack is queued and the acks it carried are handed over, even if its body doesn't
+    /// parse, and it is counted in the statistics of its circuit. Returns the packet if it
+    /// should be handled, which it isn't if it was cut off, didn't parse or arrived before.
+    async fn receive_datagram(
+        buf: &[u8],
+        size: usize,
+        addr: SocketAddr,
+        duplicates: &Mutex<DuplicateFilter>,
+        mailbox_address: &Addr<Mailbox>,
+    ) -> Option<Packet> {
+        // a datagram that fills the buffer may have been longer, and lost its end
+        if size == buf.len() {
+            warn!(
+                "dropping a packet from {} that filled the {} byte receive buffer",
+                addr, size
+            );
+            mailbox_address.do_send(ParseFailed {
+                failure: ParseFailure::Truncated,
+                addr,
+                size,
+            });
+            return None;
+        }
+        let packet = match Packet::from_bytes(&buf[..size]) {
+            Ok(packet) => packet,
+            Err(e) => {
+                let failure = ParseFailure::of(&buf[..size]);
+                debug!("dropping a packet from {}: {:?}, {}", addr, failure, e);
+                mailbox_address.do_send(ParseFailed {
+                    failure,
+                    addr,
+                    size,
+                });
+                // a packet whose body is broken is still acked, or the simulator keeps
+                // resending it, and the acks it carries still count
+                if let Ok(header) = Header::try_from_bytes(&buf[..size]) {
+                    let acks = header.ack_list.clone().unwrap_or_default();
+                    Mailbox::handle_acks(&header, acks, addr, mailbox_address).await;
+                }
+                return None;
+            }
+        };
+
+        // acks for our packets come in PacketAcks, or ride along on any packet
+        let mut acks = packet.header.ack_list.clone().unwrap_or_default();
+        if let PacketType::PacketAck(data) = &packet.body {
+            acks.extend(&data.packet_ids);
+        }
+        Mailbox::handle_acks(&packet.header, acks, addr, mailbox_address).await;
+
+        // a packet that arrives twice was acked above, but is only handled once
+        if duplicates.lock().unwrap().is_duplicate(
+            addr,
+            packet.header.sequence_number,
+            packet.header.resent,
+        ) {
+            mailbox_address.do_send(DuplicateReceived { addr, size });
+            return None;
+        }
+        mailbox_address.do_send(PacketReceived {
+            addr,
+            size,
+            frequency: packet.header.frequency,
+            id: packet.header.id,
+            unknown: match &packet.body {
+                PacketType::Unknown(data) => Some((**data).clone()),
+                _ => None,
+            },
+        });
+        Some(packet)
+    }
+
+    /// queue the ack for a reliable packet from a simulator, and hand over the acks it carried.
+    /// Acks go back to the simulator that sent the packet, which might not be the current one if
+    /// the circuit is being retired.
+    async fn handle_acks(
+        header: &Header,
+        acks: Vec<u32>,
+        addr: SocketAddr,
+        mailbox_address: &Addr<Mailbox>,
+    ) {
+        if header.reliable {
+            if let Err(e) = mailbox_address
+                .send(QueueAck {
+                    sequence_number: header.sequence_number,
+                    addr,
+                })
+                .await
+            {
+                warn!("Ack failed to send {:?}", e)
+            };
+        }
+        if !acks.is_empty() {
+            if let Err(e) = mailbox_address
+                .send(AcksReceived {
+                    sequence_numbers: acks,
+                    addr,
+                })
+                .await
+            {
+                warn!("failed to handle acks {:?}", e)
+            };
+        }
+    }
+
+    /// send a StartPingCheck to the server, and report the ping stats to the UI
+    fn send_ping(&mut self, ctx: &mut Context<Self>) {
+        if self
+            .session
+            .as_ref()
+            .and_then(|session| session.socket.as_ref())
+            .is_none()
+        {
+            return;
+        }
+        let now = time::Instant::now();
+        self.ping_info.expire_pings(now, PING_TIMEOUT);
+        let ping_id = self.ping_info.start_ping(now);
+        // the simulator can forget about acks for packets older than the oldest unacked one
+        let oldest_unacked = self
+            .session
+            .as_ref()
+            .and_then(|session| {
+                self.retransmits
+                    .oldest_unacked(&format!("{}:{}", session.url, session.server_socket))
+            })
+            .unwrap_or(0);
+        ctx.address()
+            .do_send(Packet::new_start_ping_check(StartPingCheck {
+                ping_id,
+                oldest_unacked,
+            }));
+
+        let stats = self.ping_info.stats();
+        match serde_json::to_vec(&stats) {
+            Ok(bytes) => ctx
+                .address()
+                .do_send(UiMessage::new(UiEventTypes::PingStatsEvent, bytes)),
+            Err(e) => warn!("failed to serialize ping stats {:?}", e),
+        }
+    }
+
+    /// request textures that have stopped receiving packets again
+    fn retry_textures(&mut self, ctx: &mut Context<Self>) {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => return,
+        };
+        let requests = self.texture_downloads.retry(time::Instant::now());
+        if requests.is_empty() {
+            return;
+        }
+        ctx.address()
+            .do_send(Packet::new_request_image(RequestImage {
+                agent_id: session.agent_id,
+                session_id: session.session_id,
+                requests,
+            }));
+    }
+
+    /// send a finished texture to the UI
+    fn texture_ready(&mut self, texture: Option<TextureReady>, ctx: &mut Context<Self>) {
+        if let Some(texture) = texture {
+            let body = PacketType::TextureReady(Box::new(texture));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+
+    /// fail the asset transfers that have stopped receiving packets
+    fn expire_transfers(&mut self, ctx: &mut Context<Self>) {
+        for failed in self.asset_transfers.expire(time::Instant::now()) {
+            self.asset_transfer_result(Err(failed), ctx);
+        }
+    }
+
+    /// send a finished or failed asset transfer to the UI
+    fn asset_transfer_result(
+        &mut self,
+        result: Result<AssetReceived, AssetTransferFailed>,
+        ctx: &mut Context<Self>,
+    ) {
+        let body = match result {
+            Ok(asset) => PacketType::AssetReceived(Box::new(asset)),
+            Err(failed) => PacketType::AssetTransferFailed(Box::new(failed)),
+        };
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// upload a file with the Xfer system. The file waits until the simulator asks for it with a
+    /// RequestXfer, by name if it has one, or by the VFileID of the returned XferUpload.
+    /// The XferUpload resolves once the simulator has confirmed every packet.
+    pub fn start_xfer(&mut self, data: Vec<u8>, file_name: Option<String>) -> XferUpload {
+        let vfile_id = Uuid::new_v4();
+        let key = match file_name {
+            Some(file_name) => XferKey::FileName(file_name),
+            None => XferKey::VFileId(vfile_id),
+        };
+        XferUpload {
+            vfile_id,
+            completion: self.xfers.register(key, data),
+        }
+    }
+
+    /// upload a new asset, returning a future that resolves to the id the simulator stored it
+    /// under. Small assets are sent inline, larger ones with the Xfer system. The result is
+    /// also sent to the UI as an AssetUploadedEvent.
+    pub fn upload_asset(
+        &mut self,
+        asset_type: AssetType,
+        data: Vec<u8>,
+        ctx: &mut Context<Self>,
+    ) -> Result<AssetUpload, SessionError> {
+        self.send_asset_upload(
+            AssetUploadRequest {
+                transaction_id: Uuid::new_v4(),
+                asset_type,
+                temp_file: false,
+                store_local: false,
+                asset_data: data,
+            },
+            ctx,
+        )
+    }
+
+    /// send an AssetUploadRequest, keeping track of it until its AssetUploadComplete arrives
+    fn send_asset_upload(
+        &mut self,
+        request: AssetUploadRequest,
+        ctx: &mut Context<Self>,
+    ) -> Result<AssetUpload, SessionError> {
+        let secure_session_id = match self.session.as_ref() {
+            Some(session) => session.secure_session_id,
+            None => {
+                return Err(SessionError::AssetUpload(AssetUploadError::new(
+                    "cannot upload assets without a session",
+                )))
+            }
+        };
+        self.transactions.register(
+            request.transaction_id,
+            format!("uploading a {:?} asset", request.asset_type),
+            time::Instant::now(),
+        );
+        let (request, upload) = self.asset_uploads.request(
+            request,
+            secure_session_id,
+            &mut self.xfers,
+            time::Instant::now(),
+        );
+        ctx.address()
+            .do_send(Packet::new_asset_upload_request(request));
+        Ok(upload)
+    }
+
+    /// fail the uploads whose Xfer failed or that the simulator never answered
+    fn expire_uploads(&mut self, ctx: &mut Context<Self>) {
+        for uploaded in self
+            .asset_uploads
+            .expire(&mut self.xfers, time::Instant::now())
+        {
+            self.asset_uploaded(uploaded, ctx);
+        }
+    }
+
+    /// send a finished or failed asset upload to the UI
+    fn asset_uploaded(&mut self, uploaded: AssetUploaded, ctx: &mut Context<Self>) {
+        self.transactions.complete(&uploaded.transaction_id);
+        let body = PacketType::AssetUploaded(Box::new(uploaded));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// fail the purchases whose object never sent its properties
+    fn expire_purchases(&mut self, ctx: &mut Context<Self>) {
+        for failed in self.purchases.expire(time::Instant::now()) {
+            ctx.address()
+                .do_send(DeselectObjects(vec![failed.local_id]));
+            self.purchase_failed(failed, ctx);
+        }
+    }
+
+    /// send the size of the UI's window to the current simulator, if the UI has reported it
+    fn send_height_width(&mut self, ctx: &mut Context<Self>) {
+        let (session, (height, width)) = match (self.session.as_ref(), self.viewport_size) {
+            (Some(session), Some(size)) => (session, size),
+            _ => return,
+        };
+        self.height_width_gen_counter += 1;
+        ctx.address()
+            .do_send(Packet::new_agent_height_width(AgentHeightWidth {
+                agent_id: session.agent_id,
+                session_id: session.session_id,
+                circuit_code: session.circuit_code,
+                gen_counter: self.height_width_gen_counter,
+                height,
+                width,
+            }));
+    }
+
+    /// send the UI's field of view to the current simulator, if the UI has reported it
+    fn send_fov(&mut self, ctx: &mut Context<Self>) {
+        let (session, vertical_angle) = match (self.session.as_ref(), self.vertical_angle) {
+            (Some(session), Some(vertical_angle)) => (session, vertical_angle),
+            _ => return,
+        };
+        self.fov_gen_counter += 1;
+        ctx.address().do_send(Packet::new_agent_fov(AgentFOV {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+            circuit_code: session.circuit_code,
+            gen_counter: self.fov_gen_counter,
+            vertical_angle,
+        }));
+    }
+
+    /// tell the UI about a request to sit that hasn't been answered
+    fn expire_sits(&mut self, ctx: &mut Context<Self>) {
+        if let Some(failed) = self.sits.expire(time::Instant::now()) {
+            self.sit_status(failed, ctx);
+        }
+    }
+
+    /// fetch an inventory folder and every folder inside it, breadth first. Each folder is sent
+    /// to the UI once all of its contents have arrived.
+    fn fetch_inventory_tree(&mut self, root_folder: Uuid, owner_id: Uuid, ctx: &mut Context<Self>) {
+        self.inventory.fetch_tree(root_folder, owner_id);
+        self.send_inventory_fetches(ctx);
+    }
+
+    /// ask for the queued inventory folders there is room for
+    fn send_inventory_fetches(&mut self, ctx: &mut Context<Self>) {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => return,
+        };
+        for mut fetch in self.inventory.next_requests(time::Instant::now()) {
+            fetch.agent_id = session.agent_id;
+            fetch.session_id = session.session_id;
+            ctx.address()
+                .do_send(Packet::new_fetch_inventory_descendents(fetch));
+        }
+    }
+
+    /// send the inventory folders that stopped getting packets with what arrived of them, and
+    /// carry on with the rest of the tree
+    fn expire_inventory_fetches(&mut self, ctx: &mut Context<Self>) {
+        let expired = self.inventory.expire(time::Instant::now());
+        if expired.is_empty() {
+            return;
+        }
+        for contents in expired {
+            warn!(
+                "gave up waiting for the rest of inventory folder {}",
+                contents.folder_id
+            );
+            self.inventory_folder_fetched(contents, ctx);
+        }
+        self.send_inventory_fetches(ctx);
+    }
+
+    /// fetch inventory items that aren't already being fetched
+    fn fetch_items(&mut self, items: &[ItemRequest], ctx: &mut Context<Self>) {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => return,
+        };
+        for mut fetch in self.inventory_items.request(items, time::Instant::now()) {
+            fetch.agent_id = session.agent_id;
+            fetch.session_id = session.session_id;
+            ctx.address().do_send(Packet::new_fetch_inventory(fetch));
+        }
+    }
+
+    /// send the inventory items the agent changed to the UI as they are now, and fetch them
+    /// again so the simulator's copy replaces them
+    fn inventory_items_changed(
+        &mut self,
+        item_ids: &[Uuid],
+        agent_id: Uuid,
+        ctx: &mut Context<Self>,
+    ) {
+        let mut requests = Vec::with_capacity(item_ids.len());
+        for item_id in item_ids {
+            let owner_id = match self.inventory_model.get(item_id).cloned() {
+                Some(item) => {
+                    let owner_id = item.owner_id;
+                    self.inventory_item_status(InventoryItemStatus::Found(item), ctx);
+                    owner_id
+                }
+                None => agent_id,
+            };
+            requests.push(ItemRequest {
+                owner_id,
+                item_id: *item_id,
+            });
+        }
+        self.fetch_items(&requests, ctx);
+    }
+
+    /// tell the UI about the inventory items that never arrived
+    fn expire_item_fetches(&mut self, ctx: &mut Context<Self>) {
+        for status in self.inventory_items.expire(time::Instant::now()) {
+            self.inventory_item_status(status, ctx);
+        }
+    }
+
+    /// keep what happened to an inventory item, and send it to the UI
+    fn inventory_item_status(&mut self, status: InventoryItemStatus, ctx: &mut Context<Self>) {
+        match &status {
+            InventoryItemStatus::Found(item) => self.inventory_model.insert(item.clone()),
+            InventoryItemStatus::NotFound { item_id }
+            | InventoryItemStatus::Removed { item_id } => self.inventory_model.forget(item_id),
+        }
+        let body = PacketType::InventoryItemStatus(Box::new(status));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send a change to the agent's inventory folders to the UI
+    fn inventory_folder_status(&self, status: InventoryFolderStatus, ctx: &mut Context<Self>) {
+        let body = PacketType::InventoryFolderStatus(Box::new(status));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// make a new inventory item, returning a future that resolves to the item once the
+    /// simulator has made it. The result is also sent to the UI as an InventoryItemCreatedEvent.
+    pub fn create_inventory_item(
+        &mut self,
+        create: CreateInventoryItem,
+        ctx: &mut Context<Self>,
+    ) -> Result<ItemCreation, SessionError> {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                return Err(SessionError::CreateInventoryItem(
+                    CreateInventoryItemError::new(
+                        "cannot create inventory items without a session",
+                    ),
+                ))
+            }
+        };
+        let (agent_id, session_id) = (session.agent_id, session.session_id);
+        let (mut create, creation) = self.item_creations.request(create, time::Instant::now());
+        create.agent_id = agent_id;
+        create.session_id = session_id;
+        ctx.address()
+            .do_send(Packet::new_create_inventory_item(create));
+        Ok(creation)
+    }
+
+    /// fail the inventory items the simulator never made
+    fn expire_item_creations(&mut self, ctx: &mut Context<Self>) {
+        for created in self.item_creations.expire(time::Instant::now()) {
+            self.inventory_item_created(created, ctx);
+        }
+    }
+
+    /// tell the UI a search for residents found nobody
+    fn people_search_empty(&self, empty: PeopleSearchEmpty, ctx: &mut Context<Self>) {
+        let body = PacketType::PeopleSearchEmpty(Box::new(empty));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// give up on the searches for residents the simulator never answered
+    fn expire_people_searches(&mut self, ctx: &mut Context<Self>) {
+        for empty in self.people_searches.expire(time::Instant::now()) {
+            self.people_search_empty(empty, ctx);
+        }
+    }
+
+    /// send the pages of directory searches that have stopped getting replies to the UI
+    fn flush_directory(&mut self, ctx: &mut Context<Self>) {
+        for page in self.directory.flush(time::Instant::now()) {
+            let body = match page {
+                DirectoryPage::People(page) => PacketType::DirectoryPeoplePage(Box::new(page)),
+                DirectoryPage::Places(page) => PacketType::DirectoryPlacesPage(Box::new(page)),
+            };
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+
+    /// ask whether a script is running, returning a future that resolves to the answer. The
+    /// answer is also sent to the UI as a ScriptRunningStatusEvent.
+    pub fn query_script_running(
+        &mut self,
+        object_id: Uuid,
+        item_id: Uuid,
+        ctx: &mut Context<Self>,
+    ) -> Result<ScriptRunningQuery, SessionError> {
+        if self.session.is_none() {
+            return Err(SessionError::ScriptRunning(ScriptRunningError::new(
+                "cannot ask whether scripts are running without a session",
+            )));
+        }
+        let (request, query) =
+            self.script_running
+                .request(object_id, item_id, time::Instant::now());
+        if let Some(request) = request {
+            ctx.address()
+                .do_send(Packet::new_get_script_running(request));
+        }
+        Ok(query)
+    }
+
+    /// send whether a script is running to the UI
+    fn script_running_status(&self, status: ScriptRunningStatus, ctx: &mut Context<Self>) {
+        let body = PacketType::ScriptRunningStatus(Box::new(status));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// fail the questions about scripts the simulator never answered
+    fn expire_script_running(&mut self, ctx: &mut Context<Self>) {
+        for status in self.script_running.expire(time::Instant::now()) {
+            self.script_running_status(status, ctx);
+        }
+    }
+
+    /// send the answer to a question about an object being hovered over to the UI
+    fn object_family_status(&self, status: ObjectFamilyStatus, ctx: &mut Context<Self>) {
+        let body = PacketType::ObjectFamilyStatus(Box::new(status));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// report the objects the simulator never answered about as not found
+    fn expire_object_family(&mut self, ctx: &mut Context<Self>) {
+        for status in self.object_family.expire(time::Instant::now()) {
+            self.object_family_status(status, ctx);
+        }
+    }
+
+    /// tell the UI an edit of objects was sent, with the warnings about what was changed in it
+    fn object_edit_ack(&self, local_ids: Vec<u32>, warnings: Vec<String>, ctx: &mut Context<Self>) {
+        for warning in &warnings {
+            warn!("{}", warning);
+        }
+        let body = PacketType::ObjectEditAck(Box::new(ObjectEditAck {
+            local_ids,
+            warnings,
+        }));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send the objects the agent is wearing to the UI
+    fn send_attachments(&self, ctx: &mut Context<Self>) {
+        let body = PacketType::Attachments(Box::new(self.attachments.list()));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send the LandStat reports that have stopped getting pages to the UI, incomplete
+    fn expire_land_stats(&mut self, ctx: &mut Context<Self>) {
+        for report in self.land_stats.expire(time::Instant::now()) {
+            let body = PacketType::LandStatReport(Box::new(report));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+
+    /// send the parcel access lists that have stopped getting replies to the UI
+    fn flush_parcel_access(&mut self, ctx: &mut Context<Self>) {
+        for list in self.parcel_access.flush(time::Instant::now()) {
+            let body = PacketType::ParcelAccessList(Box::new(list));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+
+    /// send the agent's friends to the UI
+    fn send_friend_list(&self, ctx: &mut Context<Self>) {
+        let body = PacketType::FriendList(Box::new(self.friends.list()));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send a friendship offer, answer or ending to the UI
+    fn friendship_status(&self, status: FriendshipStatus, ctx: &mut Context<Self>) {
+        let body = PacketType::FriendshipStatus(Box::new(status));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send a new or failed inventory item to the UI
+    fn inventory_item_created(&self, created: InventoryItemCreated, ctx: &mut Context<Self>) {
+        let body = PacketType::InventoryItemCreated(Box::new(created));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// keep the folders and items of an inventory folder, and send its contents to the UI
+    fn inventory_folder_fetched(
+        &mut self,
+        contents: InventoryFolderContents,
+        ctx: &mut Context<Self>,
+    ) {
+        for folder in &contents.folders {
+            self.inventory_model.insert_folder(folder.clone());
+        }
+        for item in &contents.items {
+            self.inventory_model.insert(item.clone());
+        }
+        let body = PacketType::InventoryFolderContents(Box::new(contents));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send the controls scripts hold to the UI
+    fn send_script_controls(&self, controls: ScriptControls, ctx: &mut Context<Self>) {
+        let body = PacketType::ScriptControls(Box::new(controls));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send the statistics of the circuits to the UI
+    fn send_circuit_stats(&self, ctx: &mut Context<Self>) {
+        let report = self.circuit_stats.report(self.ping_info.ping_latency);
+        let body = PacketType::CircuitStatsReport(Box::new(report));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send the SimStats held back by the throttle to the UI, once it is their turn
+    fn flush_sim_stats(&mut self, ctx: &mut Context<Self>) {
+        if let Some(stats) = self.sim_stats.flush(time::Instant::now()) {
+            let body = PacketType::SimStats(Box::new(stats));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+
+    /// send the controls the agent is holding down again, so the avatar keeps moving
+    fn send_movement(&mut self, ctx: &mut Context<Self>) {
+        if self.session.is_none() || self.pause.paused {
+            return;
+        }
+        if let Some(update) = self.movement.tick() {
+            ctx.address().do_send(Packet::new_agent_update(update));
+        }
+    }
+
+    /// request the parcel under the agent, if it has moved off the parcel it was on
+    fn request_parcel(&mut self, ctx: &mut Context<Self>) {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => return,
+        };
+        if let Some(request) = self.parcels.poll(time::Instant::now()) {
+            ctx.address().do_send(Packet::new_parcel_properties_request(
+                ParcelPropertiesRequest {
+                    agent_id: session.agent_id,
+                    session_id: session.session_id,
+                    ..request
+                },
+            ));
+        }
+    }
+
+    /// teleport to the script destinations whose regions were found, and tell the UI about the
+    /// ones that weren't
+    fn regions_looked_up(
+        &self,
+        lookups: Vec<RegionLookup<AcceptScriptTeleport>>,
+        ctx: &mut Context<Self>,
+    ) {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => return,
+        };
+        for lookup in lookups {
+            match lookup.region_handle {
+                Some(region_handle) => ctx.address().do_send(
+                    Packet::new_teleport_location_request(TeleportLocationRequest {
+                        agent_id: session.agent_id,
+                        session_id: session.session_id,
+                        region_handle,
+                        position: lookup.waiter.sim_position,
+                        look_at: lookup.waiter.look_at,
+                    }),
+                ),
+                None => {
+                    let body = PacketType::TeleportFailed(Box::new(TeleportFailed {
+                        agent_id: session.agent_id,
+                        reason: format!("couldn't find the region {}", lookup.name),
+                        alert_info: Vec::new(),
+                    }));
+                    ctx.address()
+                        .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+                }
+            }
+        }
+    }
+
+    /// send the regions and markers of the world map that have stopped arriving to the UI
+    fn flush_map(&mut self, ctx: &mut Context<Self>) {
+        let now = time::Instant::now();
+        for map_blocks in self.map_blocks.flush(now) {
+            if let Some(lookups) = self.region_lookups.handle_blocks(&map_blocks) {
+                self.regions_looked_up(lookups, ctx);
+                continue;
+            }
+            let body = match self.map_searches.finish(map_blocks) {
+                MapBlocksOutcome::Blocks(map_blocks) => PacketType::MapBlocks(Box::new(map_blocks)),
+                MapBlocksOutcome::Found(results) => PacketType::MapSearchResults(Box::new(results)),
+                MapBlocksOutcome::Empty(empty) => PacketType::MapSearchEmpty(Box::new(empty)),
+            };
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+        for empty in self.map_searches.expire(now) {
+            let body = PacketType::MapSearchEmpty(Box::new(empty));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+        let expired = self.region_lookups.expire(now);
+        self.regions_looked_up(expired, ctx);
+        for map_items in self.map_items.flush(now) {
+            let body = PacketType::MapItems(Box::new(map_items));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+
+    /// send a failed purchase to the UI
+    fn purchase_failed(&mut self, failed: PurchaseFailed, ctx: &mut Context<Self>) {
+        let body = PacketType::PurchaseFailed(Box::new(failed));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send a SitStatus to the UI
+    fn sit_status(&mut self, status: SitStatus, ctx: &mut Context<Self>) {
+        let body = PacketType::SitStatus(Box::new(status));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send resolved avatar names to the UI
+    fn names_resolved(&mut self, names: Vec<AvatarName>, ctx: &mut Context<Self>) {
+        if names.is_empty() {
+            return;
+        }
+        let body = PacketType::NameResolved(Box::new(NameResolved { names }));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send resolved group names to the UI
+    fn group_names_resolved(&mut self, names: Vec<GroupName>, ctx: &mut Context<Self>) {
+        if names.is_empty() {
+            return;
+        }
+        let body = PacketType::GroupNameResolved(Box::new(GroupNameResolved { names }));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// send the Xfer packets that haven't been confirmed again, and abort the uploads that
+    /// have run out of attempts
+    fn retry_xfers(&mut self, ctx: &mut Context<Self>) {
+        let (resend, aborted) = self.xfers.retry(time::Instant::now());
+        for packet in resend {
+            ctx.address().do_send(Packet::new_send_xfer_packet(packet));
+        }
+        for abort in aborted {
+            warn!(
+                "aborting xfer {} after {} attempts",
+                abort.id, self.xfers.max_attempts
+            );
+            ctx.address().do_send(Packet::new_abort_xfer(abort));
+        }
+    }
+
+    /// abort the downloads the simulator has stopped sending
+    fn expire_xfer_downloads(&mut self, ctx: &mut Context<Self>) {
+        for (file_name, abort) in self.xfer_downloads.expire(time::Instant::now()) {
+            warn!(
+                "aborting the download of {} with xfer {}",
+                file_name, abort.id
+            );
+            ctx.address().do_send(Packet::new_abort_xfer(abort));
+        }
+    }
+
+    /// handle a file that has finished downloading with the Xfer system
+    fn xfer_downloaded(&mut self, file: DownloadedXfer, ctx: &mut Context<Self>) {
+        let mute_list = self
+            .mutes
+            .lock()
+            .unwrap()
+            .handle_download(&file.file_name, &file.data);
+        match mute_list {
+            Some(mute_list) => self.send_mute_list(mute_list, ctx),
+            None => warn!(
+                "downloaded {} with xfer {}, which wasn't asked for",
+                file.file_name, file.id
+            ),
+        }
+    }
+
+    /// send the mute list to the UI
+    fn send_mute_list(&self, mute_list: MuteList, ctx: &mut Context<Self>) {
+        let body = PacketType::MuteList(Box::new(mute_list));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+
+    /// open a circuit to a new simulator and make it the current one.
+    /// After a region crossing the old region is still a neighbour, so its circuit is kept as a
+    /// child circuit. Otherwise the old circuit is retired after CIRCUIT_GRACE_PERIOD, so
+    /// packets that were already in flight from the old simulator are still acked and handled.
+    fn switch_circuit(
+        &mut self,
+        url: String,
+        server_socket: u16,
+        region_handle: u64,
+        keep_old_as_child: bool,
+        ctx: &mut Context<Self>,
+    ) {
+        for failed in self.purchases.change_region() {
+            self.purchase_failed(failed, ctx);
+        }
+        self.sales.change_region();
+        self.parcels.reset();
+        self.parcel_overlay.reset();
+        self.attachments.change_region();
+        self.sim_stats.clear();
+        self.sun.clear();
+        let session = match self.session.as_mut() {
+            Some(session) => session,
+            None => {
+                warn!("cannot switch circuits without a session");
+                return;
+            }
+        };
+        let old_addr = format!("{}:{}", session.url, session.server_socket);
+        let old_region_handle = session.region_handle;
+        session.url = url;
+        session.server_socket = server_socket;
+        session.region_handle = region_handle;
+        let new_addr = format!("{}:{}", session.url, session.server_socket);
+        info!("switching circuit from {} to {}", old_addr, new_addr);
+
+        // the new simulator might be one that was retired earlier, or a child circuit that is
+        // being promoted to the current one
+        if let Ok(addr) = new_addr.parse::<SocketAddr>() {
+            self.closed_circuits.lock().unwrap().remove(&addr);
+        }
+        self.child_circuits.lock().unwrap().remove(&region_handle);
+
+        ctx.address()
+            .do_send(Packet::new_circuit_code(CircuitCodeData {
+                code: session.circuit_code,
+                session_id: session.session_id,
+                id: session.agent_id,
+            }));
+        ctx.address().do_send(Packet::new_complete_agent_movement(
+            CompleteAgentMovementData {
+                circuit_code: session.circuit_code,
+                session_id: session.session_id,
+                agent_id: session.agent_id,
+            },
+        ));
+        ctx.address().do_send(AgentThrottleMessage {});
+        // the new simulator doesn't know what the UI can see
+        ctx.address().do_send(ViewportMessage {});
+        // some grids give the agent a different group title in each region
+        ctx.address().do_send(AgentDataUpdateRequestMessage {});
+        // nor whether to interpolate the motion of objects
+        if let Ok(addr) = new_addr.parse::<SocketAddr>() {
+            self.interpolation.circuit_started(addr);
+            ctx.address().do_send(PacketTo {
+                packet: self
+                    .interpolation
+                    .packet(session.agent_id, session.session_id),
+                addr,
+            });
+        }
+
+        if old_addr == new_addr {
+            return;
+        }
+        let old_addr = match old_addr.parse::<SocketAddr>() {
+            Ok(old_addr) => old_addr,
+            Err(e) => {
+                warn!("failed to parse old circuit address {}: {:?}", old_addr, e);
+                return;
+            }
+        };
+        if keep_old_as_child {
+            self.child_circuits
+                .lock()
+                .unwrap()
+                .insert(old_region_handle, old_addr);
+            return;
+        }
+        ctx.run_later(CIRCUIT_GRACE_PERIOD, move |act, _| {
+            // don't close the circuit if it became the current one or a child circuit again
+            if let Some(session) = act.session.as_ref() {
+                if format!("{}:{}", session.url, session.server_socket) == old_addr.to_string() {
+                    return;
+                }
+            }
+            if act
+                .child_circuits
+                .lock()
+                .unwrap()
+                .values()
+                .any(|child_addr| *child_addr == old_addr)
+            {
+                return;
+            }
+            info!("retiring circuit {}", old_addr);
+            act.closed_circuits.lock().unwrap().insert(old_addr);
+            act.interpolation.circuit_closed(&old_addr);
+            act.retransmits.circuit_closed(&old_addr.to_string());
+            act.duplicates.lock().unwrap().circuit_closed(&old_addr);
+            act.circuit_stats.circuit_closed(&old_addr);
+        });
+    }
+
+    /// send a packet to the address. Reliable packets are kept until they are acked, and resent
+    /// if they aren't.
+    fn send_packet(&mut self, mut msg: Packet, addr: String, ctx: &mut Context<Self>) {
+        if self
+            .session
+            .as_ref()
+            .and_then(|session| session.socket.as_ref())
+            .is_none()
+        {
+            warn!("cannot send packet without a socket");
+            return;
+        }
+        // numbered just before sending, so numbers go out in order
+        self.sequence_numbers.stamp(&mut msg, &addr);
+        // acks waiting for this simulator ride along instead of being sent on their own
+        if let Ok(socket_addr) = addr.parse::<SocketAddr>() {
+            self.pending_acks.append_to(&mut msg, &socket_addr);
+        }
+        self.retransmits.sent(&addr, &msg, time::Instant::now());
+        self.send_counted(&msg, addr, ctx);
+    }
+
+    /// send a packet as it is, counting it in the statistics of its circuit
+    fn send_counted(&mut self, packet: &Packet, addr: String, ctx: &mut Context<Self>) {
+        let data = packet.to_bytes();
+        if let Ok(socket_addr) = addr.parse::<SocketAddr>() {
+            self.circuit_stats.sent(socket_addr, packet, data.len());
+        }
+        self.send_bytes(data, addr, ctx);
+    }
+
+    /// send a datagram on the session's socket. It goes out right away, so datagrams leave in
+    /// the order they were numbered, unless the socket's buffer is full.
+    fn send_bytes(&mut self, data: Vec<u8>, addr: String, ctx: &mut Context<Self>) {
+        let socket = match self
+            .session
+            .as_ref()
+            .and_then(|session| session.socket.clone())
+        {
+            Some(socket) => socket,
+            None => {
+                warn!("cannot send packet without a socket");
+                return;
+            }
+        };
+        if let Ok(socket_addr) = addr.parse::<SocketAddr>() {
+            match socket.try_send_to(&data, socket_addr) {
+                Ok(_) => return,
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {}
+                Err(e) => {
+                    error!("Failed to send data: {}", e);
+                    return;
+                }
+            }
+        }
+        let fut = async move {
+            if let Err(e) = socket.send_to(&data, &addr).await {
+                error!("Failed to send data: {}", e);
+            }
+        };
+        ctx.spawn(fut.into_actor(self));
+    }
+
+    /// resend the reliable packets whose acks are late, and give up on the circuits that have
+    /// stopped acking. Losing the current circuit ends the session. Losing a child circuit
+    /// closes it the way a DisableSimulator does, and the UI gets a DisconnectEvent tagged with
+    /// the region handle.
+    fn check_retransmits(&mut self, ctx: &mut Context<Self>) {
+        let timeout = RetransmitQueue::timeout(self.ping_info.ping_latency);
+        let retransmits = self.retransmits.due(time::Instant::now(), timeout);
+        for (addr, packet) in retransmits.resends {
+            self.send_counted(&packet, addr, ctx);
+        }
+        let current = self
+            .session
+            .as_ref()
+            .map(|session| format!("{}:{}", session.url, session.server_socket));
+        for addr in retransmits.dead {
+            let reason = format!("the simulator at {} stopped answering", addr);
+            if current.as_ref() == Some(&addr) {
+                self.disconnect(reason, ctx);
+                return;
+            }
+            let child_region = self
+                .child_circuits
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|(_, child_addr)| child_addr.to_string() == addr)
+                .map(|(region_handle, _)| *region_handle);
+            let region_handle = match child_region {
+                Some(region_handle) => region_handle,
+                None => {
+                    warn!("giving up on unacked packets to {}", addr);
+                    continue;
+                }
+            };
+            warn!(
+                "closing child circuit to region {}: {}",
+                region_handle, reason
+            );
+            ctx.address().do_send(CloseChildCircuit { region_handle });
+            ctx.address().do_send(UiMessage::new(
+                UiEventTypes::ChildSimulatorEvent,
+                serde_json::to_vec(&ChildSimulatorEvent {
+                    region_handle,
+                    event: UiEventTypes::DisconnectEvent,
+                    data: serde_json::to_vec(&Disconnect { reason }).unwrap_or_default(),
+                })
+                .unwrap_or_default(),
+            ));
+        }
+    }
+
+    /// send the acks that didn't ride along on a packet in PacketAcks
+    fn flush_acks(&mut self, ctx: &mut Context<Self>) {
+        if let Some(handle) = self.ack_flush.take() {
+            ctx.cancel_future(handle);
+        }
+        for (addr, packet) in self.pending_acks.flush() {
+            self.send_packet(packet, addr.to_string(), ctx);
+        }
+    }
+
+    /// close the circuit after a logout, and tell the UI why with a DisconnectEvent
+    fn disconnect(&mut self, reason: String, ctx: &mut Context<Self>) {
+        info!("disconnected: {}", reason);
+        let bytes = serde_json::to_vec(&Disconnect { reason }).unwrap_or_default();
+        self.end_session(UiMessage::new(UiEventTypes::DisconnectEvent, bytes), ctx);
+    }
+
+    /// close the circuit to the simulator and send the event to the UI.
+    /// Pending acks are flushed, the UDP read loop is stopped and the socket is dropped along
+    /// with the session. Every way a session can end goes through here.
+    fn end_session(&mut self, event: UiMessage, ctx: &mut Context<Self>) {
+        if let Some(handle) = self.logout_timeout.take() {
+            ctx.cancel_future(handle);
+        }
+        if let Some(handle) = self.ping_handle.take() {
+            ctx.cancel_future(handle);
+        }
+        if let Some(handle) = self.retransmit_handle.take() {
+            ctx.cancel_future(handle);
+        }
+        self.flush_acks(ctx);
+        self.retransmits.clear();
+        self.duplicates.lock().unwrap().clear();
+        self.packet_stats.clear();
+        self.circuit_stats.clear();
+        self.child_circuits.lock().unwrap().clear();
+        self.interpolation.clear();
+        self.sequence_numbers.clear();
+        self.transactions.clear();
+        self.texture_downloads.clear();
+        for failed in self.asset_transfers.cancel_all() {
+            self.asset_transfer_result(Err(failed), ctx);
+        }
+        for uploaded in self.asset_uploads.cancel_all() {
+            self.asset_uploaded(uploaded, ctx);
+        }
+        self.xfers.cancel_all();
+        self.xfer_downloads.clear();
+        self.names.clear_pending();
+        for failed in self.purchases.cancel_all("session ended") {
+            self.purchase_failed(failed, ctx);
+        }
+        self.purchases.object_ids.clear();
+        self.sales.change_region();
+        self.parcels.reset();
+        self.parcel_overlay.reset();
+        self.map_blocks.clear();
+        self.map_items.clear();
+        self.map_searches.clear();
+        self.region_lookups.clear();
+        self.script_permissions.clear();
+        self.sound_preloads.clear();
+        self.sits.clear();
+        self.movement.clear();
+        self.pause.clear();
+        self.script_controls.clear();
+        self.health.clear();
+        self.sim_stats.clear();
+        self.sun.clear();
+        self.typing.clear();
+        self.mutes.lock().unwrap().clear();
+        self.friends.clear();
+        self.inventory.clear();
+        self.inventory_items.clear();
+        self.inventory_model.clear();
+        for created in self.item_creations.cancel_all() {
+            self.inventory_item_created(created, ctx);
+        }
+        self.people_searches.clear();
+        self.directory.clear();
+        self.parcel_access.clear();
+        self.land_stats.clear();
+        self.attachments.clear();
+        for status in self.script_running.cancel_all() {
+            self.script_running_status(status, ctx);
+        }
+        for status in self.object_family.cancel_all() {
+            self.object_family_status(status, ctx);
+        }
+        if let Some(udp_read) = self.udp_read.take() {
+            udp_read.abort();
+        }
+        self.session = None;
+        self.login_pending = false;
+
+        ctx.address().do_send(event);
+    }
+
+    fn set_state(&mut self, new_state: ServerState, _ctx: &mut Context<Self>) {
+        let state_clone = Arc::clone(&self.state);
+        {
+            let mut state = state_clone.lock().unwrap();
+            *state = new_state.clone();
+        }
+        // notify on start and stop
+        if new_state == ServerState::Running || new_state == ServerState::Stopped {
+            self.notify.notify_one();
+        }
+    }
+}
+
+impl Actor for Mailbox {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        info!("Actix Mailbox has started");
+        self.set_state(ServerState::Running, ctx);
+        ctx.run_interval(TEXTURE_TIMEOUT, |act, ctx| act.retry_textures(ctx));
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_transfers(ctx)
+        });
+        ctx.run_interval(XFER_TIMEOUT, |act, ctx| act.retry_xfers(ctx));
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_xfer_downloads(ctx)
+        });
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| act.expire_uploads(ctx));
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_purchases(ctx)
+        });
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| act.expire_sits(ctx));
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_inventory_fetches(ctx)
+        });
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_item_fetches(ctx)
+        });
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_item_creations(ctx)
+        });
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_people_searches(ctx)
+        });
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_land_stats(ctx)
+        });
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_script_running(ctx)
+        });
+        ctx.run_interval(TRANSFER_CHECK_INTERVAL, |act, ctx| {
+            act.expire_object_family(ctx)
+        });
+        ctx.run_interval(MOVEMENT_INTERVAL, |act, ctx| act.send_movement(ctx));
+        ctx.run_interval(SIM_STATS_INTERVAL, |act, ctx| act.flush_sim_stats(ctx));
+        ctx.run_interval(self.circuit_stats.interval, |act, ctx| {
+            if act.session.is_some() {
+                act.send_circuit_stats(ctx)
+            }
+        });
+        ctx.run_interval(PARCEL_CHECK_INTERVAL, |act, ctx| act.request_parcel(ctx));
+        ctx.run_interval(MAP_BLOCK_WINDOW, |act, ctx| act.flush_map(ctx));
+        ctx.run_interval(DIRECTORY_PAGE_WINDOW, |act, ctx| act.flush_directory(ctx));
+        ctx.run_interval(PARCEL_ACCESS_WINDOW, |act, ctx| {
+            act.flush_parcel_access(ctx)
+        });
+    }
+}
+
+impl Handler<RegionHandshakeMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RegionHandshakeMessage, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("received RegionHandshake without a session");
+                return;
+            }
+        };
+        // the simulator forgets the interpolation setting with the circuit, so it's sent again
+        self.interpolation.circuit_started(msg.addr);
+        ctx.address().do_send(PacketTo {
+            packet: self
+                .interpolation
+                .packet(session.agent_id, session.session_id),
+            addr: msg.addr,
+        });
+        if !self.auto_reply_region_handshake {
+            return;
+        }
+        // child simulators handshake too, so reply to whichever one sent it
+        ctx.address().do_send(PacketTo {
+            packet: Packet::new_region_handshake_reply(RegionHandshakeReply {
+                agent_data: AgentData {
+                    session_id: session.session_id,
+                    agent_id: session.agent_id,
+                },
+                region_info: ReplyRegionInfo { flags: 0 },
+            }),
+            addr: msg.addr,
+        });
+        // the prices only have to be asked for once
+        if session.economy.is_none() {
+            ctx.address()
+                .do_send(Packet::new_economy_data_request(EconomyDataRequest {}));
+        }
+    }
+}
+
+impl Handler<AgentThrottleMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: AgentThrottleMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(session) = self.session.as_ref() {
+            self.throttle_gen_counter += 1;
+            ctx.address()
+                .do_send(Packet::new_agent_throttle(AgentThrottle {
+                    agent_id: session.agent_id,
+                    session_id: session.session_id,
+                    circuit_code: session.circuit_code,
+                    gen_counter: self.throttle_gen_counter,
+                    throttles: self.throttles.clone(),
+                }));
+        } else {
+            warn!("cannot send AgentThrottle without a session");
+        }
+    }
+}
+
+impl Handler<ViewportMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: ViewportMessage, ctx: &mut Self::Context) -> Self::Result {
+        self.send_height_width(ctx);
+        self.send_fov(ctx);
+    }
+}
+
+impl Handler<SetViewportSize> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: SetViewportSize, ctx: &mut Self::Context) -> Self::Result {
+        // a size reported before login is sent once the session starts
+        self.viewport_size = Some((msg.0.height, msg.0.width));
+        self.send_height_width(ctx);
+    }
+}
+
+impl Handler<SetFieldOfView> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: SetFieldOfView, ctx: &mut Self::Context) -> Self::Result {
+        self.vertical_angle = Some(msg.0.vertical_angle);
+        self.send_fov(ctx);
+    }
+}
+
+impl Handler<AgentWearablesRequestMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: AgentWearablesRequestMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(session) = self.session.as_ref() {
+            ctx.address()
+                .do_send(Packet::new_agent_wearables_request(AgentWearablesRequest {
+                    agent_id: session.agent_id,
+                    session_id: session.session_id,
+                }));
+        } else {
+            warn!("cannot send AgentWearablesRequest without a session");
+        }
+    }
+}
+
+impl Handler<AgentDataUpdateRequestMessage> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        _: AgentDataUpdateRequestMessage,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        if let Some(session) = self.session.as_ref() {
+            ctx.address().do_send(Packet::new_agent_data_update_request(
+                AgentDataUpdateRequest {
+                    agent_id: session.agent_id,
+                    session_id: session.session_id,
+                },
+            ));
+        } else {
+            warn!("cannot send AgentDataUpdateRequest without a session");
+        }
+    }
+}
+
+impl Handler<QuerySelfInfo> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: QuerySelfInfo, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot send the agent's info without a session");
+                return;
+            }
+        };
+        let body = PacketType::SelfAgentInfo(Box::new(session.self_info.clone()));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<AgentWearablesUpdateMessage> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        msg: AgentWearablesUpdateMessage,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let session = match self.session.as_mut() {
+            Some(session) => session,
+            None => return,
+        };
+        // updates can arrive out of order, and an older one would undo a newer change
+        if msg.0.serial_num < session.wearables_serial_num {
+            warn!(
+                "ignoring AgentWearablesUpdate {} older than {}",
+                msg.0.serial_num, session.wearables_serial_num
+            );
+            return;
+        }
+        session.wearables_serial_num = msg.0.serial_num;
+        let body = PacketType::Wearables(Box::new(Wearables {
+            wearables: msg.0.wearables,
+        }));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<MoneyBalanceRequestMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: MoneyBalanceRequestMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(session) = self.session.as_ref() {
+            ctx.address()
+                .do_send(Packet::new_money_balance_request(MoneyBalanceRequest {
+                    agent_id: session.agent_id,
+                    session_id: session.session_id,
+                    transaction_id: Uuid::new_v4(),
+                }));
+        } else {
+            warn!("cannot send MoneyBalanceRequest without a session");
+        }
+    }
+}
+
+impl Handler<SelectObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: SelectObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot select objects without a session");
+                return;
+            }
+        };
+        // a packet only has room for 255 ObjectData blocks
+        for local_ids in msg.0.chunks(u8::MAX as usize) {
+            ctx.address()
+                .do_send(Packet::new_object_select(ObjectSelect {
+                    agent_id: session.agent_id,
+                    session_id: session.session_id,
+                    local_ids: local_ids.to_vec(),
+                }));
+        }
+    }
+}
+
+impl Handler<DeselectObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: DeselectObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot deselect objects without a session");
+                return;
+            }
+        };
+        for local_ids in msg.0.chunks(u8::MAX as usize) {
+            ctx.address()
+                .do_send(Packet::new_object_deselect(ObjectDeselect {
+                    agent_id: session.agent_id,
+                    session_id: session.session_id,
+                    local_ids: local_ids.to_vec(),
+                }));
+        }
+    }
+}
+
+impl Handler<AddObject> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: AddObject, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot add objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if msg.0.group_id.is_nil() {
+            msg.0.group_id = session.active_group_id;
+        }
+        ctx.address().do_send(Packet::new_object_add(msg.0));
+    }
+}
+
+impl Handler<DeRez> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: DeRez, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot derez objects without a session");
+                return;
+            }
+        };
+        let derez = DeRezObject {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+            group_id: session.active_group_id,
+            destination: msg.destination,
+            destination_id: msg.folder_id,
+            transaction_id: Uuid::new_v4(),
+            packet_count: 0,
+            packet_number: 0,
+            local_ids: Vec::new(),
+        };
+        self.transactions.register(
+            derez.transaction_id,
+            format!("derezzing {} objects", msg.local_ids.len()),
+            time::Instant::now(),
+        );
+        for packet in derez.split(&msg.local_ids) {
+            ctx.address().do_send(Packet::new_derez_object(packet));
+        }
+    }
+}
+
+impl Handler<DuplicateObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: DuplicateObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot duplicate objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if msg.0.group_id.is_nil() {
+            msg.0.group_id = session.active_group_id;
+        }
+        for packet in msg.0.split() {
+            ctx.address().do_send(Packet::new_object_duplicate(packet));
+        }
+    }
+}
+
+impl Handler<DuplicateObjectsOnRay> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: DuplicateObjectsOnRay, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot duplicate objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if msg.0.group_id.is_nil() {
+            msg.0.group_id = session.active_group_id;
+        }
+        for packet in msg.0.split() {
+            ctx.address()
+                .do_send(Packet::new_object_duplicate_on_ray(packet));
+        }
+    }
+}
+
+impl Handler<LinkObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: LinkObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot link objects without a session");
+                return;
+            }
+        };
+        let mut link = ObjectLink::new(msg.root_id, msg.child_ids);
+        if let Err(error) = link.check() {
+            warn!("refusing to link objects to {}: {}", msg.root_id, error);
+            let error = SessionError::LinkObjects(error);
+            ctx.address()
+                .do_send(UiMessage::new(UiEventTypes::Error, error.to_bytes()));
+            return;
+        }
+        link.agent_id = session.agent_id;
+        link.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_object_link(link));
+    }
+}
+
+impl Handler<DelinkObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: DelinkObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot unlink objects without a session");
+                return;
+            }
+        };
+        let delink = ObjectDelink {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+            local_ids: msg.0,
+        };
+        for packet in delink.split() {
+            ctx.address().do_send(Packet::new_object_delink(packet));
+        }
+    }
+}
+
+impl Handler<AttachObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: AttachObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot attach objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_object_attach(msg.0));
+    }
+}
+
+impl Handler<AttachItem> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: AttachItem, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot attach items without a session");
+                return;
+            }
+        };
+        let item = match self.inventory_model.get(&msg.0.item_id) {
+            Some(item) => item,
+            None => {
+                warn!("cannot attach item {} before it is fetched", msg.0.item_id);
+                return;
+            }
+        };
+        let mut rez = RezSingleAttachmentFromInv::new(item, msg.0.attachment_point);
+        rez.agent_id = session.agent_id;
+        rez.session_id = session.session_id;
+        rez.add = msg.0.add;
+        ctx.address()
+            .do_send(Packet::new_rez_single_attachment_from_inv(rez));
+    }
+}
+
+impl Handler<Detach> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: Detach, ctx: &mut Self::Context) -> Self::Result {
+        let local_id = match msg.0 {
+            DetachAttachment::Object(local_id) => local_id,
+            DetachAttachment::Item(item_id) => match self.attachments.local_id_of_item(item_id) {
+                Some(local_id) => local_id,
+                None => {
+                    warn!("cannot detach item {}, it isn't being worn", item_id);
+                    return;
+                }
+            },
+        };
+        ctx.address().do_send(DetachObjects(vec![local_id]));
+    }
+}
+
+impl Handler<DetachObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: DetachObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot detach objects without a session");
+                return;
+            }
+        };
+        let detach = ObjectDetach {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+            local_ids: msg.0,
+        };
+        for packet in detach.split() {
+            ctx.address().do_send(Packet::new_object_detach(packet));
+        }
+    }
+}
+
+impl Handler<DropObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: DropObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot drop objects without a session");
+                return;
+            }
+        };
+        let drop = ObjectDrop {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+            local_ids: msg.0,
+        };
+        for packet in drop.split() {
+            ctx.address().do_send(Packet::new_object_drop(packet));
+        }
+    }
+}
+
+impl Handler<AddScript> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: AddScript, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot add scripts without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if msg.0.group_id.is_nil() {
+            msg.0.group_id = session.active_group_id;
+        }
+        ctx.address().do_send(Packet::new_rez_script(msg.0));
+    }
+}
+
+impl Handler<QueryScriptRunning> for Mailbox {
+    type Result = Result<ScriptRunningQuery, SessionError>;
+    fn handle(&mut self, msg: QueryScriptRunning, ctx: &mut Self::Context) -> Self::Result {
+        let result = self.query_script_running(msg.object_id, msg.item_id, ctx);
+        if let Err(e) = &result {
+            warn!(
+                "failed to ask whether script {} is running: {:?}",
+                msg.item_id, e
+            );
+            self.script_running_status(
+                ScriptRunningStatus {
+                    object_id: msg.object_id,
+                    item_id: msg.item_id,
+                    running: None,
+                    reason: Some(e.to_string()),
+                },
+                ctx,
+            );
+        }
+        result
+    }
+}
+
+impl Handler<RunScript> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RunScript, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot start or stop scripts without a session");
+                return;
+            }
+        };
+        let mut set = SetScriptRunning::new(msg.object_id, msg.item_id, msg.running);
+        set.agent_id = session.agent_id;
+        set.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_set_script_running(set));
+    }
+}
+
+impl Handler<ResetScript> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ResetScript, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot reset scripts without a session");
+                return;
+            }
+        };
+        let mut reset = ScriptReset::new(msg.object_id, msg.item_id);
+        reset.agent_id = session.agent_id;
+        reset.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_script_reset(reset));
+    }
+}
+
+impl Handler<ScriptRunningReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ScriptRunningReplyMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(status) = self.script_running.handle_reply(&msg.0) {
+            self.script_running_status(status, ctx);
+        }
+    }
+}
+
+impl Handler<ScriptControlChangeMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ScriptControlChangeMessage, ctx: &mut Self::Context) -> Self::Result {
+        let controls = self.script_controls.handle_change(&msg.0);
+        self.send_script_controls(controls, ctx);
+    }
+}
+
+impl Handler<ReleaseScriptControls> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: ReleaseScriptControls, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot release script controls without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_force_script_control_release(msg.0));
+        if self.script_controls.release_all() {
+            self.send_script_controls(self.script_controls.controls(), ctx);
+        }
+    }
+}
+
+impl Handler<Animate> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: Animate, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot animate the avatar without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_agent_animation(msg.0));
+    }
+}
+
+impl Handler<SendChat> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SendChat, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot chat without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if let Some(animation) = self.typing.handle_chat(&msg.0) {
+            ctx.address().do_send(Animate(animation));
+        }
+        ctx.address().do_send(Packet::new_chat_from_viewer(msg.0));
+    }
+}
+
+impl Handler<RequestObjectFamily> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RequestObjectFamily, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot ask about objects without a session");
+                self.object_family_status(
+                    ObjectFamilyStatus {
+                        token: msg.token,
+                        object_id: msg.object_id,
+                        family: None,
+                        reason: Some("no session".to_string()),
+                    },
+                    ctx,
+                );
+                return;
+            }
+        };
+        let mut request =
+            self.object_family
+                .request(msg.object_id, msg.token, time::Instant::now());
+        request.agent_id = session.agent_id;
+        request.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_request_object_properties_family(request));
+    }
+}
+
+impl Handler<ObjectPropertiesFamilyMessage> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        msg: ObjectPropertiesFamilyMessage,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        if let Some(status) = self.object_family.handle_reply(&msg.0) {
+            self.object_family_status(status, ctx);
+        }
+    }
+}
+
+impl Handler<SetObjectFaces> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetObjectFaces, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot set the faces of objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        for packet in msg.0.split() {
+            ctx.address().do_send(Packet::new_object_image(packet));
+        }
+        let local_ids = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids, Vec::new(), ctx);
+    }
+}
+
+impl Handler<RenameObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RenameObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot rename objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let warnings = msg.0.truncate();
+        for packet in msg.0.split() {
+            ctx.address().do_send(Packet::new_object_name(packet));
+        }
+        let local_ids = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids, warnings, ctx);
+    }
+}
+
+impl Handler<DescribeObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: DescribeObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot describe objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let warnings = msg.0.truncate();
+        for packet in msg.0.split() {
+            ctx.address()
+                .do_send(Packet::new_object_description(packet));
+        }
+        let local_ids = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids, warnings, ctx);
+    }
+}
+
+impl Handler<EditExtraParams> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: EditExtraParams, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot edit the extra params of objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        for packet in msg.0.split() {
+            ctx.address()
+                .do_send(Packet::new_object_extra_params(packet));
+        }
+        let local_ids = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids, Vec::new(), ctx);
+    }
+}
+
+impl Handler<SetMaterials> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetMaterials, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot set the materials of objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        for packet in msg.0.split() {
+            ctx.address().do_send(Packet::new_object_material(packet));
+        }
+        let local_ids = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids, Vec::new(), ctx);
+    }
+}
+
+impl Handler<SetObjectFlags> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetObjectFlags, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot set the flags of objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let local_id = msg.0.local_id;
+        ctx.address().do_send(Packet::new_object_flag_update(msg.0));
+        self.object_edit_ack(vec![local_id], Vec::new(), ctx);
+    }
+}
+
+impl Handler<SetClickActions> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetClickActions, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot set the click actions of objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        for packet in msg.0.split() {
+            ctx.address()
+                .do_send(Packet::new_object_click_action(packet));
+        }
+        let local_ids = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids, Vec::new(), ctx);
+    }
+}
+
+impl Handler<SetObjectPermissions> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetObjectPermissions, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot set the permissions of objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        for packet in msg.0.split() {
+            ctx.address()
+                .do_send(Packet::new_object_permissions(packet));
+        }
+        let local_ids = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids, Vec::new(), ctx);
+    }
+}
+
+impl Handler<SetSaleInfo> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetSaleInfo, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot set the sale info of objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let warnings = self.sales.check(&mut msg.0);
+        for packet in msg.0.split() {
+            ctx.address().do_send(Packet::new_object_sale_info(packet));
+        }
+        let local_ids: Vec<u32> = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids.clone(), warnings, ctx);
+        if !local_ids.is_empty() {
+            ctx.address().do_send(SelectObjects(local_ids));
+        }
+    }
+}
+
+impl Handler<SetObjectCategories> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetObjectCategories, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot set the categories of objects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        for packet in msg.0.split() {
+            ctx.address().do_send(Packet::new_object_category(packet));
+        }
+        let local_ids: Vec<u32> = msg.0.objects.iter().map(|object| object.local_id).collect();
+        self.object_edit_ack(local_ids.clone(), Vec::new(), ctx);
+        if !local_ids.is_empty() {
+            ctx.address().do_send(SelectObjects(local_ids));
+        }
+    }
+}
+
+impl Handler<UpdateObjects> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: UpdateObjects, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot update objects without a session");
+                return;
+            }
+        };
+        for objects in msg.0.chunks(u8::MAX as usize) {
+            ctx.address()
+                .do_send(Packet::new_multiple_object_update(MultipleObjectUpdate {
+                    agent_id: session.agent_id,
+                    session_id: session.session_id,
+                    objects: objects.to_vec(),
+                }));
+        }
+    }
+}
+
+impl Handler<RezItem> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RezItem, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot rez items without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if msg.0.group_id.is_nil() {
+            msg.0.group_id = session.active_group_id;
+        }
+        msg.0.transaction_id = Uuid::new_v4();
+        self.transactions.register(
+            msg.0.transaction_id,
+            "rezzing an item",
+            time::Instant::now(),
+        );
+        ctx.address().do_send(Packet::new_rez_object(msg.0));
+    }
+}
+
+impl Handler<TouchObject> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: TouchObject, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot touch objects without a session");
+                return;
+            }
+        };
+        let surface_info: Vec<SurfaceInfo> =
+            msg.position.map(SurfaceInfo::at).into_iter().collect();
+        ctx.address().do_send(Packet::new_object_grab(ObjectGrab {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+            local_id: msg.local_id,
+            grab_offset: Vec3::ZERO,
+            surface_info: surface_info.clone(),
+        }));
+        ctx.address()
+            .do_send(Packet::new_object_de_grab(ObjectDeGrab {
+                agent_id: session.agent_id,
+                session_id: session.session_id,
+                local_id: msg.local_id,
+                surface_info,
+            }));
+    }
+}
+
+impl Handler<SetAppearance> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetAppearance, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_mut() {
+            Some(session) => session,
+            None => {
+                warn!("cannot send AgentSetAppearance without a session");
+                return;
+            }
+        };
+        session.appearance_serial_num += 1;
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        msg.0.serial_num = session.appearance_serial_num;
+        ctx.address()
+            .do_send(Packet::new_agent_set_appearance(msg.0));
+    }
+}
+
+impl Handler<LoginPending> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: LoginPending, _: &mut Self::Context) -> Self::Result {
+        self.login_pending = msg.0;
+    }
+}
+
+impl Handler<Logout> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: Logout, ctx: &mut Self::Context) -> Self::Result {
+        if self.logout_timeout.is_some() {
+            warn!("already logging out");
+            return;
+        }
+        let session = match self.session.as_ref() {
+            Some(session) if session.socket.is_some() => session,
+            _ => {
+                // there is no circuit to close yet, so logging out just cancels the login
+                if self.login_pending || self.session.is_some() {
+                    self.disconnect("login cancelled".to_string(), ctx);
+                } else {
+                    warn!("received logout without a session");
+                }
+                return;
+            }
+        };
+
+        ctx.address()
+            .do_send(Packet::new_logout_request(LogoutRequest {
+                agent_id: session.agent_id,
+                session_id: session.session_id,
+            }));
+        self.logout_timeout = Some(ctx.run_later(LOGOUT_TIMEOUT, |act, ctx| {
+            act.logout_timeout = None;
+            act.disconnect("logout timed out".to_string(), ctx);
+        }));
+    }
+}
+
+impl Handler<LogoutReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: LogoutReplyMessage, ctx: &mut Self::Context) -> Self::Result {
+        if self.logout_timeout.is_none() {
+            warn!("received LogoutReply without a pending logout");
+        }
+        self.disconnect("logged out".to_string(), ctx);
+    }
+}
+
+impl Handler<CrossedRegionMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: CrossedRegionMessage, ctx: &mut Self::Context) -> Self::Result {
+        let crossed_region = msg.0;
+        let old_region_handle = match self.session.as_ref() {
+            Some(session) => session.region_handle,
+            None => {
+                warn!("received CrossedRegion without a session");
+                return;
+            }
+        };
+        self.switch_circuit(
+            crossed_region.sim_ip.to_string(),
+            crossed_region.sim_port,
+            crossed_region.region_handle,
+            true,
+            ctx,
+        );
+        let body = PacketType::RegionCrossed(Box::new(RegionCrossed {
+            region_handle: crossed_region.region_handle,
+            position: crossed_region.position,
+            look_at: crossed_region.look_at,
+            offset: RegionCrossed::offset_between(old_region_handle, crossed_region.region_handle),
+        }));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<EnableSimulatorMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: EnableSimulatorMessage, ctx: &mut Self::Context) -> Self::Result {
+        let enable_simulator = msg.0;
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("received EnableSimulator without a session");
+                return;
+            }
+        };
+        if enable_simulator.region_handle == session.region_handle {
+            return;
+        }
+        let addr = SocketAddr::new(IpAddr::V4(enable_simulator.ip), enable_simulator.port);
+        if self
+            .child_circuits
+            .lock()
+            .unwrap()
+            .insert(enable_simulator.region_handle, addr)
+            == Some(addr)
+        {
+            // the circuit is already open
+            return;
+        }
+        self.closed_circuits.lock().unwrap().remove(&addr);
+
+        info!(
+            "opening child circuit to region {} at {}",
+            enable_simulator.region_handle, addr
+        );
+        ctx.address().do_send(PacketTo {
+            packet: Packet::new_circuit_code(CircuitCodeData {
+                code: session.circuit_code,
+                session_id: session.session_id,
+                id: session.agent_id,
+            }),
+            addr,
+        });
+    }
+}
+
+impl Handler<CloseChildCircuit> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: CloseChildCircuit, _: &mut Self::Context) -> Self::Result {
+        if let Some(addr) = self
+            .child_circuits
+            .lock()
+            .unwrap()
+            .remove(&msg.region_handle)
+        {
+            info!("closing child circuit to region {}", msg.region_handle);
+            self.closed_circuits.lock().unwrap().insert(addr);
+            self.interpolation.circuit_closed(&addr);
+            self.retransmits.circuit_closed(&addr.to_string());
+            self.duplicates.lock().unwrap().circuit_closed(&addr);
+            self.circuit_stats.circuit_closed(&addr);
+        }
+    }
+}
+
+impl Handler<RequestTextures> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RequestTextures, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request textures without a session");
+                return;
+            }
+        };
+        let now = time::Instant::now();
+        let requests: Vec<ImageRequest> = msg
+            .0
+            .into_iter()
+            .filter(|request| {
+                if request.is_cancel() {
+                    // tell the simulator to stop sending it, even if it already finished
+                    self.texture_downloads.cancel(&request.texture_id);
+                    true
+                } else {
+                    self.texture_downloads.request(request.clone(), now)
                 }
+            })
+            .collect();
+        if requests.is_empty() {
+            return;
+        }
+        ctx.address()
+            .do_send(Packet::new_request_image(RequestImage {
+                agent_id: session.agent_id,
+                session_id: session.session_id,
+                requests,
+            }));
+    }
+}
+
+impl Handler<ImageDataMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ImageDataMessage, ctx: &mut Self::Context) -> Self::Result {
+        let texture = self
+            .texture_downloads
+            .handle_image_data(msg.0, time::Instant::now());
+        self.texture_ready(texture, ctx);
+    }
+}
+
+impl Handler<ImagePacketMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ImagePacketMessage, ctx: &mut Self::Context) -> Self::Result {
+        let texture = self
+            .texture_downloads
+            .handle_image_packet(msg.0, time::Instant::now());
+        self.texture_ready(texture, ctx);
+    }
+}
+
+impl Handler<RequestAsset> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RequestAsset, ctx: &mut Self::Context) -> Self::Result {
+        if self.session.is_none() {
+            warn!("cannot request assets without a session");
+            return;
+        }
+        let transfer_request = self.asset_transfers.request(
+            msg.asset_id,
+            msg.asset_type,
+            msg.priority,
+            time::Instant::now(),
+        );
+        ctx.address()
+            .do_send(Packet::new_transfer_request(transfer_request));
+    }
+}
+
+impl Handler<TransferInfoMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: TransferInfoMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(failed) = self
+            .asset_transfers
+            .handle_transfer_info(msg.0, time::Instant::now())
+        {
+            self.asset_transfer_result(Err(failed), ctx);
+        }
+    }
+}
+
+impl Handler<TransferPacketMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: TransferPacketMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(result) = self
+            .asset_transfers
+            .handle_transfer_packet(msg.0, time::Instant::now())
+        {
+            self.asset_transfer_result(result, ctx);
+        }
+    }
+}
+
+impl Handler<StartXfer> for Mailbox {
+    type Result = XferUpload;
+    fn handle(&mut self, msg: StartXfer, _: &mut Self::Context) -> Self::Result {
+        self.start_xfer(msg.data, msg.file_name)
+    }
+}
+
+impl Handler<UploadAsset> for Mailbox {
+    type Result = Result<AssetUpload, SessionError>;
+    fn handle(&mut self, msg: UploadAsset, ctx: &mut Self::Context) -> Self::Result {
+        let transaction_id = msg.0.transaction_id;
+        let asset_type = msg.0.asset_type;
+        let result = self.send_asset_upload(msg.0, ctx);
+        if let Err(e) = &result {
+            warn!("failed to start asset upload {}: {:?}", transaction_id, e);
+            self.asset_uploaded(
+                AssetUploaded {
+                    transaction_id,
+                    asset_id: Uuid::nil(),
+                    asset_type,
+                    success: false,
+                    reason: Some(e.to_string()),
+                },
+                ctx,
+            );
+        }
+        result
+    }
+}
+
+impl Handler<AssetUploadCompleteMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: AssetUploadCompleteMessage, ctx: &mut Self::Context) -> Self::Result {
+        match self.asset_uploads.handle_upload_complete(&msg.0) {
+            Some(uploaded) => self.asset_uploaded(uploaded, ctx),
+            None => warn!(
+                "received AssetUploadComplete for unknown asset {}",
+                msg.0.asset_id
+            ),
+        }
+    }
+}
+
+impl Handler<FeatureDisabledMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: FeatureDisabledMessage, ctx: &mut Self::Context) -> Self::Result {
+        let notice = self.transactions.handle_feature_disabled(&msg.0);
+        warn!(
+            "simulator refused {}: {}",
+            notice.operation.as_deref().unwrap_or("a request"),
+            notice.message
+        );
+        // an upload the simulator refused fails now, instead of timing out
+        if let Some(uploaded) = self
+            .asset_uploads
+            .fail_transaction(msg.0.transaction_id, msg.0.error_message.clone())
+        {
+            self.asset_uploaded(uploaded, ctx);
+        }
+        let body = PacketType::FeatureDisabledNotice(Box::new(notice));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<RequestXferMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RequestXferMessage, ctx: &mut Self::Context) -> Self::Result {
+        match self.xfers.handle_request(&msg.0, time::Instant::now()) {
+            Some(packet) => ctx.address().do_send(Packet::new_send_xfer_packet(packet)),
+            None => warn!(
+                "simulator requested unknown xfer file {:?} {}",
+                msg.0.file_name, msg.0.vfile_id
+            ),
+        }
+    }
+}
+
+impl Handler<ConfirmXferPacketMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ConfirmXferPacketMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(packet) = self.xfers.handle_confirm(&msg.0, time::Instant::now()) {
+            ctx.address().do_send(Packet::new_send_xfer_packet(packet));
+        }
+    }
+}
+
+impl Handler<AbortXferMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: AbortXferMessage, _: &mut Self::Context) -> Self::Result {
+        match self.xfer_downloads.handle_abort(&msg.0) {
+            Some(download) => warn!(
+                "simulator aborted the download of {} with result {}",
+                download.file_name, msg.0.result
+            ),
+            None => self.xfers.handle_abort(&msg.0),
+        }
+    }
+}
+
+impl Handler<SendXferPacketMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: SendXferPacketMessage, ctx: &mut Self::Context) -> Self::Result {
+        let (confirm, file) = match self
+            .xfer_downloads
+            .handle_packet(&msg.0, time::Instant::now())
+        {
+            Some(progress) => progress,
+            None => return,
+        };
+        ctx.address()
+            .do_send(Packet::new_confirm_xfer_packet(confirm));
+        if let Some(file) = file {
+            self.xfer_downloaded(file, ctx);
+        }
+    }
+}
+
+impl Handler<LookupNames> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: LookupNames, ctx: &mut Self::Context) -> Self::Result {
+        if self.session.is_none() {
+            warn!("cannot look up names without a session");
+            return;
+        }
+        let (cached, requests) = self.names.lookup(&msg.ids, time::Instant::now());
+        if msg.send_cached {
+            self.names_resolved(cached, ctx);
+        }
+        for request in requests {
+            ctx.address()
+                .do_send(Packet::new_uuid_name_request(request));
+        }
+    }
+}
+
+impl Handler<BuyObject> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: BuyObject, ctx: &mut Self::Context) -> Self::Result {
+        if self.session.is_none() {
+            warn!("cannot buy objects without a session");
+            return;
+        }
+        self.purchases.start(
+            msg.local_id,
+            msg.sale_type,
+            msg.price,
+            msg.category_id,
+            time::Instant::now(),
+        );
+        ctx.address().do_send(SelectObjects(vec![msg.local_id]));
+    }
+}
+
+impl Handler<ObjectPropertiesMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ObjectPropertiesMessage, ctx: &mut Self::Context) -> Self::Result {
+        self.sales.handle_properties(&msg.0);
+        let (checked, failed) = self.purchases.handle_properties(&msg.0);
+        for failed in failed {
+            ctx.address()
+                .do_send(DeselectObjects(vec![failed.local_id]));
+            self.purchase_failed(failed, ctx);
+        }
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => return,
+        };
+        for purchase in checked {
+            let local_id = purchase.object.local_id;
+            ctx.address().do_send(Packet::new_object_buy(ObjectBuy {
+                agent_id: session.agent_id,
+                session_id: session.session_id,
+                group_id: session.active_group_id,
+                category_id: purchase.category_id,
+                objects: vec![purchase.object],
+            }));
+            ctx.address().do_send(DeselectObjects(vec![local_id]));
+        }
+    }
+}
+
+impl Handler<ObjectUpdateMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ObjectUpdateMessage, ctx: &mut Self::Context) -> Self::Result {
+        self.purchases.handle_object_update(&msg.0);
+        self.sales.handle_object_update(&msg.0);
+        if let Some(session) = self.session.as_ref() {
+            self.parcels.handle_object_update(&msg.0, session.agent_id);
+            self.script_controls
+                .handle_object_update(&msg.0, session.agent_id);
+            if self
+                .attachments
+                .handle_object_update(&msg.0, session.agent_id)
+            {
+                self.send_attachments(ctx);
+            }
+        }
+    }
+}
+
+impl Handler<KillObjectMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: KillObjectMessage, ctx: &mut Self::Context) -> Self::Result {
+        if self.attachments.handle_kill(&msg.0) {
+            self.send_attachments(ctx);
+        }
+        if let Some(controls) = self.script_controls.handle_kill(&msg.0) {
+            self.send_script_controls(controls, ctx);
+        }
+    }
+}
+
+impl Handler<HealthMessageMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: HealthMessageMessage, ctx: &mut Self::Context) -> Self::Result {
+        let died = self.health.handle_health(&msg.0);
+        let body = PacketType::HealthMessage(Box::new(msg.0));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        if let Some(died) = died {
+            let body = PacketType::AgentDied(Box::new(died));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+}
+
+impl Handler<SimStatsMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: SimStatsMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(stats) = self.sim_stats.handle_stats(msg.0, time::Instant::now()) {
+            let body = PacketType::SimStats(Box::new(stats));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+}
+
+impl Handler<SimulatorViewerTimeMessageMessage> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        msg: SimulatorViewerTimeMessageMessage,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        self.sun.handle_time(msg.0.clone(), time::Instant::now());
+        let body = PacketType::SimulatorViewerTimeMessage(Box::new(msg.0));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<TerseObjectUpdateMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: TerseObjectUpdateMessage, _: &mut Self::Context) -> Self::Result {
+        self.parcels.handle_terse_update(&msg.0);
+    }
+}
+
+impl Handler<ParcelOverlayMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ParcelOverlayMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(grid) = self.parcel_overlay.handle_chunk(msg.0) {
+            let body = PacketType::ParcelOverlayGrid(Box::new(grid));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+}
+
+impl Handler<ParcelPropertiesMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ParcelPropertiesMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(parcel) = self.parcels.handle_properties(msg.0, time::Instant::now()) {
+            let body = PacketType::ParcelProperties(Box::new(parcel));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+}
+
+impl Handler<UUIDNameReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: UUIDNameReplyMessage, ctx: &mut Self::Context) -> Self::Result {
+        let names = self.names.handle_reply(msg.0);
+        self.names_resolved(names, ctx);
+    }
+}
+
+impl Handler<LookupGroupNames> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: LookupGroupNames, ctx: &mut Self::Context) -> Self::Result {
+        if self.session.is_none() {
+            warn!("cannot look up group names without a session");
+            return;
+        }
+        let (cached, requests) = self.names.lookup_groups(&msg.ids, time::Instant::now());
+        if msg.send_cached {
+            self.group_names_resolved(cached, ctx);
+        }
+        for request in requests {
+            ctx.address()
+                .do_send(Packet::new_uuid_group_name_request(request));
+        }
+    }
+}
+
+impl Handler<UUIDGroupNameReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: UUIDGroupNameReplyMessage, ctx: &mut Self::Context) -> Self::Result {
+        let names = self.names.handle_group_reply(msg.0);
+        self.group_names_resolved(names, ctx);
+    }
+}
+
+impl Handler<EndSession> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: EndSession, ctx: &mut Self::Context) -> Self::Result {
+        self.end_session(msg.0, ctx);
+    }
+}
+
+impl Handler<Ping> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: Ping, ctx: &mut Self::Context) -> Self::Result {
+        ctx.address().do_send(PacketTo {
+            packet: Packet::new_complete_ping_check(CompletePingCheck {
+                ping_id: msg.ping_id,
+            }),
+            addr: msg.addr,
+        });
+    }
+}
+
+impl Handler<PingResponse> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: PingResponse, _: &mut Self::Context) -> Self::Result {
+        if self
+            .ping_info
+            .complete_ping(msg.ping_id, time::Instant::now())
+            .is_none()
+        {
+            warn!(
+                "received CompletePingCheck for unknown ping {}",
+                msg.ping_id
+            );
+        }
+    }
+}
+
+impl Handler<UiMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: UiMessage, _: &mut Self::Context) -> Self::Result {
+        let max_message_size = 1024;
+        // leave a little room at the end
+        let overhead = 2;
+
+        let message_type_len = msg.message_type.to_string().len();
+        let sequence_number_len = std::mem::size_of::<u16>(); // 2 bytes for the sequence number
+        let total_packet_number_len = std::mem::size_of::<u16>();
+        let packet_number_len = std::mem::size_of::<u16>();
+
+        // Calculate the maximum size available for the actual message content
+        let available_size = max_message_size
+            - (message_type_len
+                + sequence_number_len
+                + total_packet_number_len
+                + packet_number_len
+                + overhead);
+
+        // Split the message content if it's larger than the available size
+        let message = msg.message;
+        let total_chunks = usize::max(1, message.len().div_ceil(available_size));
+
+        // Loop through each chunk and send it
+        for chunk_index in 0..total_chunks {
+            let start = chunk_index * available_size;
+            let end = usize::min(start + available_size, message.len());
+            let chunk = &message[start..end];
+
+            // Increment the sequence number for each chunk
+            let sequence_number = msg.sequence_number + chunk_index as u16;
+
+            // Create a new message with the chunked data
+            let chunked_message = UiMessage {
+                message_type: msg.message_type.clone(),
+                sequence_number,
+                total_packet_number: total_chunks as u16, // Add total number of chunks
+                message: chunk.to_vec(),
+                packet_number: self.sent_packet_count,
+            };
+
+            let client_socket = SyncUdpSocket::bind("0.0.0.0:0").unwrap();
+            if let Err(e) =
+                client_socket.send_to(&chunked_message.as_bytes(), &self.server_to_ui_socket)
+            {
+                info!("sending to: {}", self.server_to_ui_socket);
+                error!(
+                    "Error sending chunk {} of {} from mailbox: {:?}",
+                    sequence_number, total_chunks, e
+                )
+            }
+        }
+        self.sent_packet_count += 1;
+    }
+}
+
+// set the session to initialized.
+impl Handler<Session> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: Session, ctx: &mut Self::Context) -> Self::Result {
+        // the login was cancelled by a logout before the session was established
+        if !self.login_pending && self.session.is_none() {
+            warn!("ignoring session for a cancelled login");
+            return;
+        }
+        self.login_pending = false;
+        if let Some(session) = self.session.as_ref() {
+            msg.socket = session.socket.clone();
+        }
+        self.session = Some(msg);
+
+        // if the session doesn't already have a UDP socket to watch, create one
+        if let Some(session) = self.session.as_ref() {
+            if session.socket.is_none() {
+                let addr = format!("0.0.0.0:{}", self.client_socket);
+
+                let addr_clone = addr.clone();
+                let mailbox_addr = ctx.address();
+
+                info!("session established, starting UDP processing");
+                self.ping_handle =
+                    Some(ctx.run_interval(self.ping_info.interval, |act, ctx| act.send_ping(ctx)));
+                self.retransmit_handle =
+                    Some(ctx.run_interval(RETRANSMIT_CHECK_INTERVAL, |act, ctx| {
+                        act.check_retransmits(ctx)
+                    }));
+                let closed_circuits = self.closed_circuits.clone();
+                let child_circuits = self.child_circuits.clone();
+                let mutes = self.mutes.clone();
+                let chat_filter = self.chat_filter.clone();
+                let duplicates = self.duplicates.clone();
+                let receive_buffer_size = self.receive_buffer_size;
+
+                let fut = async move {
+                    match UdpSocket::bind(&addr).await {
+                        Ok(sock) => {
+                            info!("Successfully bound to {}", &addr);
+                            let sock = Arc::new(sock);
+                            // Spawn a new Tokio task for reading from the socket
+                            let udp_read = tokio::spawn(Mailbox::start_udp_read(
+                                closed_circuits,
+                                child_circuits,
+                                mutes,
+                                chat_filter,
+                                duplicates,
+                                receive_buffer_size,
+                                sock.clone(),
+                                mailbox_addr,
+                            ));
+                            Ok((sock, udp_read.abort_handle())) // Return the socket wrapped in Arc
+                        }
+                        Err(e) => {
+                            error!("Failed to bind to {}: {}", &addr_clone, e);
+                            Err(e)
+                        }
+                    }
+                };
+
+                // wait for the socket to be successfully bound and then assign it
+                ctx.spawn(fut.into_actor(self).map(|result, act, _| match result {
+                    Ok((sock, udp_read)) => {
+                        if let Some(session) = &mut act.session {
+                            session.socket = Some(sock);
+                            act.udp_read = Some(udp_read);
+                        } else {
+                            // the session ended while the socket was being bound
+                            udp_read.abort();
+                        }
+                    }
+                    Err(_) => {
+                        panic!("Socket binding failed");
+                    }
+                }));
+            }
+        }
+    }
+}
+
+impl Handler<Packet> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: Packet, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(ref session) = self.session {
+            let addr = format!("{}:{}", session.url, session.server_socket);
+            self.send_packet(msg, addr, ctx);
+        }
+    }
+}
+
+impl Handler<PacketTo> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: PacketTo, ctx: &mut Self::Context) -> Self::Result {
+        self.send_packet(msg.packet, msg.addr.to_string(), ctx);
+    }
+}
+
+impl Handler<AcksReceived> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: AcksReceived, _: &mut Self::Context) -> Self::Result {
+        self.circuit_stats
+            .acks_received(msg.addr, msg.sequence_numbers.len());
+        let addr = msg.addr.to_string();
+        for sequence_number in msg.sequence_numbers {
+            self.retransmits.ack(&addr, sequence_number);
+        }
+    }
+}
+
+impl Handler<QueueAck> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: QueueAck, ctx: &mut Self::Context) -> Self::Result {
+        if self.pending_acks.push(msg.addr, msg.sequence_number) {
+            self.flush_acks(ctx);
+        } else if self.ack_flush.is_none() {
+            self.ack_flush = Some(ctx.run_later(ACK_FLUSH_DELAY, |act, ctx| {
+                act.ack_flush = None;
+                act.flush_acks(ctx);
+            }));
+        }
+    }
+}
+
+impl Handler<TeleportFinishMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: TeleportFinishMessage, ctx: &mut Self::Context) -> Self::Result {
+        let teleport_finish = msg.0;
+        self.switch_circuit(
+            teleport_finish.sim_ip.to_string(),
+            teleport_finish.sim_port,
+            teleport_finish.region_handle,
+            false,
+            ctx,
+        );
+        let body = PacketType::TeleportFinish(Box::new(teleport_finish));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<RequestMapBlocks> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RequestMapBlocks, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request map blocks without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_map_block_request(msg.0));
+    }
+}
+
+impl Handler<MapBlockReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: MapBlockReplyMessage, _: &mut Self::Context) -> Self::Result {
+        self.map_blocks.handle_reply(msg.0, time::Instant::now());
+    }
+}
+
+impl Handler<RequestMapItems> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RequestMapItems, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request map items without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_map_item_request(msg.0));
+    }
+}
+
+impl Handler<MapItemReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: MapItemReplyMessage, _: &mut Self::Context) -> Self::Result {
+        self.map_items.handle_reply(msg.0, time::Instant::now());
+    }
+}
+
+impl Handler<SearchPeople> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SearchPeople, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot search for residents without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        match self.people_searches.start(msg.0, time::Instant::now()) {
+            Ok(request) => ctx
+                .address()
+                .do_send(Packet::new_avatar_picker_request(request)),
+            Err(empty) => {
+                warn!("refusing to search for residents named {:?}", empty.name);
+                self.people_search_empty(empty, ctx);
+            }
+        }
+    }
+}
+
+impl Handler<AvatarPickerReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: AvatarPickerReplyMessage, ctx: &mut Self::Context) -> Self::Result {
+        let body = match self.people_searches.finish(&msg.0) {
+            Some(PeopleSearchOutcome::Found(results)) => {
+                PacketType::PeopleSearchResults(Box::new(results))
+            }
+            Some(PeopleSearchOutcome::Empty(empty)) => {
+                PacketType::PeopleSearchEmpty(Box::new(empty))
+            }
+            None => return,
+        };
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<SearchDirectory> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SearchDirectory, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot search the directory without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let query = self.directory.start(msg.0, time::Instant::now());
+        ctx.address().do_send(Packet::new_dir_find_query(query));
+    }
+}
+
+impl Handler<DirPeopleReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: DirPeopleReplyMessage, _: &mut Self::Context) -> Self::Result {
+        if !self.directory.add_people(&msg.0, time::Instant::now()) {
+            warn!("dir people reply for unknown query {}", msg.0.query_id);
+        }
+    }
+}
+
+impl Handler<DirPlacesReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: DirPlacesReplyMessage, _: &mut Self::Context) -> Self::Result {
+        if !self.directory.add_places(&msg.0, time::Instant::now()) {
+            warn!("dir places reply for unknown query {}", msg.0.query_id);
+        }
+    }
+}
+
+impl Handler<RequestLandStat> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RequestLandStat, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request a land stat report without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        self.land_stats
+            .start(msg.0.report_type, time::Instant::now());
+        ctx.address().do_send(Packet::new_land_stat_request(msg.0));
+    }
+}
+
+impl Handler<LandStatReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: LandStatReplyMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(report) = self.land_stats.add(&msg.0, time::Instant::now()) {
+            let body = PacketType::LandStatReport(Box::new(report));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+}
+
+impl Handler<RequestParcelDwell> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RequestParcelDwell, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request parcel dwell without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_parcel_dwell_request(msg.0));
+    }
+}
+
+impl Handler<RequestParcelAccessList> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        mut msg: RequestParcelAccessList,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request a parcel access list without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let request = self.parcel_access.start(msg.0);
+        ctx.address()
+            .do_send(Packet::new_parcel_access_list_request(request));
+    }
+}
+
+impl Handler<ParcelAccessListReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ParcelAccessListReplyMessage, _: &mut Self::Context) -> Self::Result {
+        self.parcel_access.add(&msg.0, time::Instant::now());
+    }
+}
+
+impl Handler<UpdateParcelAccessList> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: UpdateParcelAccessList, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot update a parcel access list without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        for update in msg.0.split() {
+            ctx.address()
+                .do_send(Packet::new_parcel_access_list_update(update));
+        }
+    }
+}
+
+impl Handler<SendEstateMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SendEstateMessage, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot send an estate message without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if msg.0.invoice.is_nil() {
+            msg.0.invoice = Uuid::new_v4();
+        }
+        // ask for the telehub again after changing it, so the UI gets its new state
+        let refresh_telehub = msg.0.is_telehub_change();
+        ctx.address()
+            .do_send(Packet::new_estate_owner_message(msg.0));
+        if refresh_telehub {
+            ctx.address()
+                .do_send(SendEstateMessage(EstateOwnerMessage::telehub_info()));
+        }
+    }
+}
+
+impl Handler<SendGenericMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SendGenericMessage, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot send a generic message without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if msg.0.invoice.is_nil() {
+            msg.0.invoice = Uuid::new_v4();
+        }
+        ctx.address().do_send(Packet::new_generic_message(msg.0));
+    }
+}
+
+impl Handler<SearchMap> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SearchMap, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot search the map without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        msg.0.flags =
+            self.map_searches
+                .start(msg.0.name.clone(), msg.0.flags, time::Instant::now());
+        ctx.address().do_send(Packet::new_map_name_request(msg.0));
+    }
+}
+
+impl Handler<AcceptTeleportOffer> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: AcceptTeleportOffer, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot teleport without a session");
+                return;
+            }
+        };
+        let (agent_id, session_id) = (session.agent_id, session.session_id);
+        let name = msg.0.sim_name.clone();
+        match self
+            .region_lookups
+            .lookup(&name, msg.0, time::Instant::now())
+        {
+            RegionLookupStart::Known(accept, region_handle) => self.regions_looked_up(
+                vec![RegionLookup {
+                    waiter: accept,
+                    name,
+                    region_handle: Some(region_handle),
+                }],
+                ctx,
+            ),
+            RegionLookupStart::Request => {
+                ctx.address()
+                    .do_send(Packet::new_map_name_request(MapNameRequest {
+                        agent_id,
+                        session_id,
+                        flags: REGION_LOOKUP_FLAGS,
+                        estate_id: 0,
+                        godlike: false,
+                        name,
+                    }))
+            }
+            RegionLookupStart::Waiting => {}
+        }
+    }
+}
+
+impl Handler<AnswerDialog> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: AnswerDialog, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot answer dialogs without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_script_dialog_reply(msg.0));
+    }
+}
+
+impl Handler<AnswerScriptQuestion> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: AnswerScriptQuestion, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot answer script questions without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        self.script_permissions.handle_answer(&msg.0);
+        ctx.address().do_send(Packet::new_script_answer_yes(msg.0));
+    }
+}
+
+impl Handler<RevokeScriptPermissions> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        mut msg: RevokeScriptPermissions,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot revoke script permissions without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if msg.0.object_permissions.is_empty() {
+            msg.0.object_permissions = self.script_permissions.granted_to_object(msg.0.object_id);
+        }
+        self.script_permissions
+            .revoke(msg.0.object_id, msg.0.object_permissions);
+        ctx.address().do_send(Packet::new_revoke_permissions(msg.0));
+    }
+}
+
+impl Handler<PreloadSoundMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: PreloadSoundMessage, ctx: &mut Self::Context) -> Self::Result {
+        if let Some(preload) = self.sound_preloads.handle_preload(msg.0) {
+            let body = PacketType::PreloadSound(Box::new(preload));
+            ctx.address()
+                .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+        }
+    }
+}
+
+impl Handler<RequestMuteList> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: RequestMuteList, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request the mute list without a session");
+                return;
+            }
+        };
+        // there is no cached mute list file, so the simulator always sends the list
+        ctx.address()
+            .do_send(Packet::new_mute_list_request(MuteListRequest {
+                agent_id: session.agent_id,
+                session_id: session.session_id,
+                mute_crc: 0,
+            }));
+    }
+}
+
+impl Handler<MuteListUpdateMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: MuteListUpdateMessage, ctx: &mut Self::Context) -> Self::Result {
+        if self.session.is_none() {
+            return;
+        }
+        let request = self.xfer_downloads.request(
+            msg.0.filename.clone(),
+            RequestXfer::PATH_CACHE,
+            true,
+            time::Instant::now(),
+        );
+        self.mutes.lock().unwrap().pending_file = Some(msg.0.filename);
+        ctx.address().do_send(Packet::new_request_xfer(request));
+    }
+}
+
+impl Handler<UseCachedMuteListMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: UseCachedMuteListMessage, ctx: &mut Self::Context) -> Self::Result {
+        let mute_list = self.mutes.lock().unwrap().use_cached();
+        self.send_mute_list(mute_list, ctx);
+    }
+}
+
+impl Handler<Mute> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: Mute, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot mute without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let mute_list = {
+            let mut mutes = self.mutes.lock().unwrap();
+            mutes.update(msg.0.entry());
+            mutes.list()
+        };
+        ctx.address()
+            .do_send(Packet::new_update_mute_list_entry(msg.0));
+        self.send_mute_list(mute_list, ctx);
+    }
+}
+
+impl Handler<Unmute> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: Unmute, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot unmute without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let mute_list = {
+            let mut mutes = self.mutes.lock().unwrap();
+            mutes.remove(msg.0.mute_id, &msg.0.mute_name);
+            mutes.list()
+        };
+        ctx.address()
+            .do_send(Packet::new_remove_mute_list_entry(msg.0));
+        self.send_mute_list(mute_list, ctx);
+    }
+}
+
+impl Handler<FilterChat> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: FilterChat, _: &mut Self::Context) -> Self::Result {
+        self.chat_filter.lock().unwrap().set(&msg.0);
+    }
+}
+
+impl Handler<FetchInventoryTree> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: FetchInventoryTree, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot fetch inventory without a session");
+                return;
+            }
+        };
+        let owner_id = if msg.0.owner_id.is_nil() {
+            session.agent_id
+        } else {
+            msg.0.owner_id
+        };
+        self.fetch_inventory_tree(msg.0.folder_id, owner_id, ctx);
+    }
+}
+
+impl Handler<InventoryDescendentsMessage> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        msg: InventoryDescendentsMessage,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        for item in msg.0.items.iter().filter(|item| !item.crc_valid) {
+            warn!(
+                "inventory item {} doesn't match its checksum {}",
+                item.item.item_id, item.crc
+            );
+        }
+        if let Some(contents) = self
+            .inventory
+            .handle_descendents(&msg.0, time::Instant::now())
+        {
+            self.inventory_folder_fetched(contents, ctx);
+            self.send_inventory_fetches(ctx);
+        }
+    }
+}
+
+impl Handler<FetchItems> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: FetchItems, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot fetch inventory items without a session");
+                return;
+            }
+        };
+        let agent_id = session.agent_id;
+        let items: Vec<ItemRequest> = msg
+            .0
+            .items
+            .iter()
+            .map(|item| ItemRequest {
+                owner_id: if item.owner_id.is_nil() {
+                    agent_id
+                } else {
+                    item.owner_id
+                },
+                item_id: item.item_id,
+            })
+            .collect();
+        self.fetch_items(&items, ctx);
+    }
+}
+
+impl Handler<FetchInventoryReplyMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: FetchInventoryReplyMessage, ctx: &mut Self::Context) -> Self::Result {
+        for item in msg.0.items.iter().filter(|item| !item.crc_valid) {
+            warn!(
+                "inventory item {} doesn't match its checksum {}",
+                item.item.item_id, item.crc
+            );
+        }
+        for status in self.inventory_items.handle_reply(&msg.0) {
+            self.inventory_item_status(status, ctx);
+        }
+    }
+}
+
+impl Handler<UpdateItems> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: UpdateItems, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot update inventory items without a session");
+                return;
+            }
+        };
+        let (agent_id, session_id) = (session.agent_id, session.session_id);
+        let item_ids: Vec<Uuid> = msg.0.items.iter().map(|block| block.item.item_id).collect();
+        for mut update in self.inventory_model.update(msg.0) {
+            update.agent_id = agent_id;
+            update.session_id = session_id;
+            ctx.address()
+                .do_send(Packet::new_update_inventory_item(update));
+        }
+        self.inventory_items_changed(&item_ids, agent_id, ctx);
+    }
+}
+
+impl Handler<MoveItems> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: MoveItems, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot move inventory items without a session");
+                return;
+            }
+        };
+        let (agent_id, session_id) = (session.agent_id, session.session_id);
+        let item_ids: Vec<Uuid> = msg.0.items.iter().map(|block| block.item_id).collect();
+        for mut move_items in self.inventory_model.move_items(msg.0) {
+            move_items.agent_id = agent_id;
+            move_items.session_id = session_id;
+            ctx.address()
+                .do_send(Packet::new_move_inventory_item(move_items));
+        }
+        self.inventory_items_changed(&item_ids, agent_id, ctx);
+    }
+}
+
+impl Handler<RemoveItems> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RemoveItems, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot remove inventory items without a session");
+                return;
+            }
+        };
+        let (agent_id, session_id) = (session.agent_id, session.session_id);
+        let item_ids = msg.0.item_ids.clone();
+        for mut remove in self.inventory_model.remove(msg.0) {
+            remove.agent_id = agent_id;
+            remove.session_id = session_id;
+            ctx.address()
+                .do_send(Packet::new_remove_inventory_item(remove));
+        }
+        for item_id in item_ids {
+            self.inventory_item_status(InventoryItemStatus::Removed { item_id }, ctx);
+        }
+    }
+}
+
+impl Handler<CreateFolder> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: CreateFolder, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot create an inventory folder without a session");
+                return;
+            }
+        };
+        let (agent_id, session_id) = (session.agent_id, session.session_id);
+        let mut create = self.inventory_model.create_folder(msg.0);
+        create.agent_id = agent_id;
+        create.session_id = session_id;
+        let folder = create.folder.clone();
+        ctx.address()
+            .do_send(Packet::new_create_inventory_folder(create));
+        self.inventory_folder_status(InventoryFolderStatus::Created(folder), ctx);
+    }
+}
+
+impl Handler<MoveFolders> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: MoveFolders, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot move inventory folders without a session");
+                return;
+            }
+        };
+        let (agent_id, session_id) = (session.agent_id, session.session_id);
+        let folders = msg.0.folders.clone();
+        for mut move_folders in self.inventory_model.move_folders(msg.0) {
+            move_folders.agent_id = agent_id;
+            move_folders.session_id = session_id;
+            ctx.address()
+                .do_send(Packet::new_move_inventory_folder(move_folders));
+        }
+        for folder in folders {
+            self.inventory_folder_status(
+                InventoryFolderStatus::Moved {
+                    folder_id: folder.folder_id,
+                    parent_id: folder.parent_id,
+                },
+                ctx,
+            );
+        }
+    }
+}
+
+impl Handler<RemoveFolders> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RemoveFolders, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot remove inventory folders without a session");
+                return;
+            }
+        };
+        let (agent_id, session_id) = (session.agent_id, session.session_id);
+        let folder_ids = msg.0.folder_ids.clone();
+        for mut remove in self.inventory_model.remove_folders(msg.0) {
+            remove.agent_id = agent_id;
+            remove.session_id = session_id;
+            ctx.address()
+                .do_send(Packet::new_remove_inventory_folder(remove));
+        }
+        for folder_id in folder_ids {
+            self.inventory_folder_status(InventoryFolderStatus::Removed { folder_id }, ctx);
+        }
+    }
+}
+
+impl Handler<PurgeFolder> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: PurgeFolder, ctx: &mut Self::Context) -> Self::Result {
+        let folder_id = msg.0.folder_id;
+        if !msg.0.confirmed {
+            warn!(
+                "refusing to purge inventory folder {} without confirmation",
+                folder_id
+            );
+            let status = InventoryFolderStatus::PurgeNotConfirmed { folder_id };
+            self.inventory_folder_status(status, ctx);
+            return;
+        }
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot purge an inventory folder without a session");
+                return;
+            }
+        };
+        let purge = PurgeInventoryDescendents {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+            ..PurgeInventoryDescendents::new(folder_id)
+        };
+        ctx.address()
+            .do_send(Packet::new_purge_inventory_descendents(purge));
+        self.inventory_model.purge(&folder_id);
+        self.inventory_folder_status(InventoryFolderStatus::Purged { folder_id }, ctx);
+    }
+}
+
+impl Handler<CreateItem> for Mailbox {
+    type Result = Result<ItemCreation, SessionError>;
+    fn handle(&mut self, msg: CreateItem, ctx: &mut Self::Context) -> Self::Result {
+        let callback_id = msg.0.callback_id;
+        let result = self.create_inventory_item(msg.0, ctx);
+        if let Err(e) = &result {
+            warn!("failed to create inventory item {}: {:?}", callback_id, e);
+            self.inventory_item_created(
+                InventoryItemCreated {
+                    callback_id,
+                    item: None,
+                    reason: Some(e.to_string()),
+                },
+                ctx,
+            );
+        }
+        result
+    }
+}
+
+impl Handler<UpdateCreateInventoryItemMessage> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        msg: UpdateCreateInventoryItemMessage,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        for created in self.item_creations.handle_update(&msg.0) {
+            self.inventory_item_created(created, ctx);
+        }
+        for created in msg.0.items {
+            self.inventory_item_status(InventoryItemStatus::Found(created.item), ctx);
+        }
+    }
+}
+
+impl Handler<LoadFriends> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: LoadFriends, ctx: &mut Self::Context) -> Self::Result {
+        self.friends.load(&msg.0);
+        let body = PacketType::FriendList(Box::new(self.friends.list()));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<OnlineNotificationMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: OnlineNotificationMessage, ctx: &mut Self::Context) -> Self::Result {
+        let agent_ids = self.friends.set_online(&msg.0.agent_ids);
+        if agent_ids.is_empty() {
+            return;
+        }
+        let body = PacketType::OnlineNotification(Box::new(OnlineNotification { agent_ids }));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<OfflineNotificationMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: OfflineNotificationMessage, ctx: &mut Self::Context) -> Self::Result {
+        let agent_ids = self.friends.set_offline(&msg.0.agent_ids);
+        if agent_ids.is_empty() {
+            return;
+        }
+        let body = PacketType::OfflineNotification(Box::new(OfflineNotification { agent_ids }));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<AgentDataUpdateMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: AgentDataUpdateMessage, _: &mut Self::Context) -> Self::Result {
+        if let Some(session) = self.session.as_mut() {
+            session.active_group_id = msg.0.active_group_id;
+            session.self_info.update(&msg.0);
+        }
+    }
+}
+
+impl Handler<RequestGodPowers> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RequestGodPowers, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_mut() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request god powers without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        // stop using god powers as soon as they are given up
+        if !msg.0.godlike {
+            session.god_level = 0;
+        }
+        ctx.address()
+            .do_send(Packet::new_request_godlike_powers(msg.0));
+    }
+}
+
+impl Handler<GrantGodlikePowersMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: GrantGodlikePowersMessage, _: &mut Self::Context) -> Self::Result {
+        if let Some(session) = self.session.as_mut() {
+            session.god_level = msg.0.god_level;
+        }
+    }
+}
+
+impl Handler<KickUserAsGod> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: KickUserAsGod, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot kick a user without a session");
+                return;
+            }
+        };
+        if session.god_level == 0 {
+            warn!("refusing to kick {} without god powers", msg.0.agent_id);
+            let error = SessionError::GodPowers(GodPowersError::new(format!(
+                "cannot kick {} without god powers, request them with RequestGodlikePowers first",
+                msg.0.agent_id
+            )));
+            ctx.address()
+                .do_send(UiMessage::new(UiEventTypes::Error, error.to_bytes()));
+            return;
+        }
+        msg.0.god_id = session.agent_id;
+        msg.0.god_session_id = session.session_id;
+        ctx.address().do_send(Packet::new_god_kick_user(msg.0));
+    }
+}
+
+impl Handler<EconomyDataMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: EconomyDataMessage, _: &mut Self::Context) -> Self::Result {
+        if let Some(session) = self.session.as_mut() {
+            session.economy = Some(msg.0);
+        }
+    }
+}
+
+impl Handler<RequestEconomyData> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: RequestEconomyData, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request economy data without a session");
+                return;
+            }
+        };
+        match session.economy.clone() {
+            Some(economy) => {
+                let body = PacketType::EconomyData(Box::new(economy));
+                ctx.address()
+                    .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+            }
+            // the EconomyData is sent to the UI when it arrives
+            None => ctx
+                .address()
+                .do_send(Packet::new_economy_data_request(EconomyDataRequest {})),
+        }
+    }
+}
+
+impl Handler<GetEconomyData> for Mailbox {
+    type Result = Option<EconomyData>;
+    fn handle(&mut self, _: GetEconomyData, _: &mut Self::Context) -> Self::Result {
+        self.session
+            .as_ref()
+            .and_then(|session| session.economy.clone())
+    }
+}
+
+impl Handler<RequestRegionInfo> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: RequestRegionInfo, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request region info without a session");
+                return;
+            }
+        };
+        ctx.address()
+            .do_send(Packet::new_region_info_request(RegionInfoRequest {
+                agent_id: session.agent_id,
+                session_id: session.session_id,
+            }));
+    }
+}
+
+impl Handler<JoinGroup> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: JoinGroup, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot join a group without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_join_group_request(msg.0));
+    }
+}
+
+impl Handler<LeaveGroup> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: LeaveGroup, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot leave a group without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_leave_group_request(msg.0));
+    }
+}
+
+impl Handler<SetActiveGroup> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetActiveGroup, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot activate a group without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_activate_group(msg.0));
+    }
+}
+
+impl Handler<RequestAvatarProperties> for Mailbox {
+    type Result = ();
+    fn handle(
+        &mut self,
+        mut msg: RequestAvatarProperties,
+        ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request avatar properties without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        // the picks and classifieds of a profile are asked for on their own
+        let avatar_id = GenericMessageData::string_param(&msg.0.avatar_id.to_string());
+        for method in ["avatarpicksrequest", "avatarclassifiedsrequest"] {
+            ctx.address()
+                .do_send(SendGenericMessage(GenericMessageData::new(
+                    method.to_string(),
+                    vec![avatar_id.clone()],
+                )));
+        }
+        ctx.address()
+            .do_send(Packet::new_avatar_properties_request(msg.0));
+    }
+}
+
+impl Handler<RequestPickInfo> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: RequestPickInfo, ctx: &mut Self::Context) -> Self::Result {
+        if self.session.is_none() {
+            warn!("cannot request a pick without a session");
+            return;
+        }
+        ctx.address()
+            .do_send(SendGenericMessage(GenericMessageData::new(
+                "pickinforequest".to_string(),
+                vec![
+                    GenericMessageData::string_param(&msg.0.creator_id.to_string()),
+                    GenericMessageData::string_param(&msg.0.pick_id.to_string()),
+                ],
+            )));
+    }
+}
+
+impl Handler<RequestClassifiedInfo> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RequestClassifiedInfo, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request a classified without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_classified_info_request(msg.0));
+    }
+}
+
+impl Handler<RequestEventInfo> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RequestEventInfo, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request an event without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_event_info_request(msg.0));
+    }
+}
+
+impl Handler<UpdateProfile> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: UpdateProfile, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot update the profile without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_avatar_properties_update(msg.0));
+    }
+}
+
+impl Handler<UpdateInterests> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: UpdateInterests, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot update interests without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_avatar_interests_update(msg.0));
+    }
+}
+
+impl Handler<RequestGroupProfile> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: RequestGroupProfile, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot request a group profile without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_group_profile_request(msg.0));
+    }
+}
+
+impl Handler<FriendshipMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: FriendshipMessage, ctx: &mut Self::Context) -> Self::Result {
+        let Some(status) = self.friends.handle_instant_message(&msg.0) else {
+            return;
+        };
+        let accepted = matches!(status, FriendshipStatus::Accepted { .. });
+        self.friendship_status(status, ctx);
+        if accepted {
+            self.send_friend_list(ctx);
+        }
+    }
+}
+
+impl Handler<ChangeUserRightsMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ChangeUserRightsMessage, ctx: &mut Self::Context) -> Self::Result {
+        let agent_id = match self.session.as_ref() {
+            Some(session) => session.agent_id,
+            None => return,
+        };
+        self.friends.change_rights(agent_id, &msg.0);
+        self.send_friend_list(ctx);
+    }
+}
+
+impl Handler<TerminateFriendshipMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: TerminateFriendshipMessage, ctx: &mut Self::Context) -> Self::Result {
+        let agent_id = msg.0.other_id;
+        if self.friends.remove(&agent_id) {
+            self.friendship_status(FriendshipStatus::Terminated { agent_id }, ctx);
+            self.send_friend_list(ctx);
+        }
+    }
+}
+
+impl Handler<OfferFriendship> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: OfferFriendship, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot offer friendship without a session");
+                return;
+            }
+        };
+        msg.0.from_agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address()
+            .do_send(Packet::new_improved_instant_message(msg.0));
+    }
+}
+
+impl Handler<AcceptFriend> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: AcceptFriend, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot accept friendship without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if self.friends.accept(&msg.0.transaction_id).is_none() {
+            warn!(
+                "refusing to accept friendship offer {} that was never made",
+                msg.0.transaction_id
+            );
+            return;
+        }
+        ctx.address().do_send(Packet::new_accept_friendship(msg.0));
+        self.send_friend_list(ctx);
+    }
+}
+
+impl Handler<DeclineFriend> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: DeclineFriend, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot decline friendship without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if self.friends.decline(&msg.0.transaction_id).is_none() {
+            warn!(
+                "refusing to decline friendship offer {} that was never made",
+                msg.0.transaction_id
+            );
+            return;
+        }
+        ctx.address().do_send(Packet::new_decline_friendship(msg.0));
+    }
+}
+
+impl Handler<GrantRights> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: GrantRights, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot grant rights without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        self.friends.grant(&msg.0);
+        ctx.address().do_send(Packet::new_grant_user_rights(msg.0));
+        self.send_friend_list(ctx);
+    }
+}
+
+impl Handler<EndFriendship> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: EndFriendship, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot end a friendship without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        let agent_id = msg.0.other_id;
+        ctx.address()
+            .do_send(Packet::new_terminate_friendship(msg.0));
+        if self.friends.remove(&agent_id) {
+            self.friendship_status(FriendshipStatus::Terminated { agent_id }, ctx);
+            self.send_friend_list(ctx);
+        }
+    }
+}
+
+impl Handler<SendViewerEffect> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SendViewerEffect, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot send viewer effects without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        for effect in msg
+            .0
+            .effects
+            .iter_mut()
+            .filter(|effect| effect.agent_id.is_nil())
+        {
+            effect.agent_id = session.agent_id;
+        }
+        ctx.address().do_send(Packet::new_viewer_effect(msg.0));
+    }
+}
+
+impl Handler<SitOnObject> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SitOnObject, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot sit without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        if let Some(replaced) = self.sits.request(msg.0.target_id, time::Instant::now()) {
+            self.sit_status(replaced, ctx);
+        }
+        ctx.address().do_send(Packet::new_agent_request_sit(msg.0));
+    }
+}
+
+impl Handler<AvatarSitResponseMessage> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: AvatarSitResponseMessage, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => return,
+        };
+        // walking to the object isn't supported, so the sit is confirmed right away even if the
+        // simulator asks for autopilot
+        ctx.address().do_send(Packet::new_agent_sit(AgentSit {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+        }));
+        let status = self.sits.handle_response(msg.0);
+        self.sit_status(status, ctx);
+    }
+}
+
+impl Handler<StandUp> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: StandUp, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot stand up without a session");
+                return;
+            }
+        };
+        ctx.address().do_send(Packet::new_agent_update(AgentUpdate {
+            agent_id: session.agent_id,
+            session_id: session.session_id,
+            ..AgentUpdate::stand_up()
+        }));
+        if let Some(failed) = self.sits.stand() {
+            self.sit_status(failed, ctx);
+        }
+        self.sit_status(SitStatus::Standing, ctx);
+    }
+}
+
+impl Handler<Move> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: Move, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot move without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        self.movement.handle_update(msg.0.clone());
+        ctx.address().do_send(Packet::new_agent_update(msg.0));
+    }
+}
+
+impl Handler<Pause> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: Pause, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot pause without a session");
+                return;
+            }
+        };
+        if let Some(mut pause) = self.pause.pause() {
+            pause.agent_id = session.agent_id;
+            pause.session_id = session.session_id;
+            ctx.address().do_send(Packet::new_agent_pause(pause));
+        }
+    }
+}
+
+impl Handler<Resume> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: Resume, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot resume without a session");
+                return;
+            }
+        };
+        if let Some(mut resume) = self.pause.resume() {
+            resume.agent_id = session.agent_id;
+            resume.session_id = session.session_id;
+            ctx.address().do_send(Packet::new_agent_resume(resume));
+            ctx.address().do_send(AgentThrottleMessage {});
+            self.send_height_width(ctx);
+        }
+    }
+}
+
+impl Handler<SetRunning> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, mut msg: SetRunning, ctx: &mut Self::Context) -> Self::Result {
+        let session = match self.session.as_ref() {
+            Some(session) => session,
+            None => {
+                warn!("cannot set always run without a session");
+                return;
+            }
+        };
+        msg.0.agent_id = session.agent_id;
+        msg.0.session_id = session.session_id;
+        ctx.address().do_send(Packet::new_set_always_run(msg.0));
+    }
+}
+
+impl Handler<SetInterpolation> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: SetInterpolation, ctx: &mut Self::Context) -> Self::Result {
+        // the setting is remembered without a session, and sent once circuits open
+        let circuits = self.interpolation.set(msg.0.enabled);
+        if let Some(session) = self.session.as_ref() {
+            for addr in circuits {
+                ctx.address().do_send(PacketTo {
+                    packet: self
+                        .interpolation
+                        .packet(session.agent_id, session.session_id),
+                    addr,
+                });
+            }
+        }
+        let body = PacketType::VelocityInterpolationStatus(Box::new(self.interpolation.status()));
+        ctx.address()
+            .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
+    }
+}
+
+impl Handler<PacketReceived> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: PacketReceived, ctx: &mut Self::Context) -> Self::Result {
+        self.packet_stats.received(msg.frequency, msg.id);
+        self.circuit_stats
+            .received(msg.addr, msg.frequency, msg.id, msg.size);
+        if let Some(raw) = msg.unknown {
+            if let Some(notice) = self.packet_stats.unknown(&raw) {
+                let body = PacketType::UnknownPacketNotice(Box::new(notice));
+                ctx.address()
+                    .do_send(UiMessage::new(body.ui_event(), body.ui_event_bytes()));
             }
         }
     }
-    if received_ack {
-        Ok(())
-    } else {
-        Err(SessionError::AckError(AckError::new(
-            "failed to retrieve ack ".to_string(),
-        )))
+}
+
+impl Handler<ParseFailed> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ParseFailed, _: &mut Self::Context) -> Self::Result {
+        self.packet_stats.parse_failed(msg.failure);
+        self.circuit_stats.parse_failed(msg.addr, msg.size);
+    }
+}
+
+impl Handler<DuplicateReceived> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: DuplicateReceived, _: &mut Self::Context) -> Self::Result {
+        self.circuit_stats.duplicate(msg.addr, msg.size);
+    }
+}
+
+impl Handler<QueryStats> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, _: QueryStats, ctx: &mut Self::Context) -> Self::Result {
+        self.send_circuit_stats(ctx);
+    }
+}
+
+impl Handler<ReportUnknown> for Mailbox {
+    type Result = ();
+    fn handle(&mut self, msg: ReportUnknown, _: &mut Self::Context) -> Self::Result {
+        self.packet_stats.report_unknown = msg.0.enabled;
     }
 }
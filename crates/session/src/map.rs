@@ -0,0 +1,289 @@
+use metaverse_messages::map_block_reply::{MapBlock, MapBlockReply};
+use metaverse_messages::map_block_request::MapLayer;
+use metaverse_messages::map_blocks::MapBlocks;
+use metaverse_messages::map_item_reply::{MapItem, MapItemReply};
+use metaverse_messages::map_item_request::MapItemType;
+use metaverse_messages::map_items::MapItems;
+use metaverse_messages::map_search_empty::MapSearchEmpty;
+use metaverse_messages::map_search_results::MapSearchResults;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+
+use crate::region_lookup::REGION_LOOKUP_TAG;
+
+/// how long to wait for more MapBlockReply or MapItemReply packets before sending them on to
+/// the UI
+pub const MAP_BLOCK_WINDOW: Duration = Duration::from_millis(500);
+/// how long to wait for the answer to a search before telling the UI it found nothing
+pub const MAP_SEARCH_TIMEOUT: Duration = Duration::from_secs(10);
+/// the search tag is kept in the highest byte of the flags of a MapNameRequest
+pub const MAP_SEARCH_TAG_SHIFT: u32 = 24;
+
+/// Gathers the MapBlockReply packets that answer a MapBlockRequest.
+/// The simulator answers a large rectangle with several packets, and doesn't say which one is
+/// the last. The only thing tying them to their request is the flags they carry, so replies are
+/// gathered by their flags, and sent on once no more have arrived for the window.
+/// A region that is sent twice is only kept once.
+#[derive(Debug)]
+pub struct MapBlockManager {
+    /// the regions that have arrived, by the flags of their request
+    pub pending: HashMap<u32, PendingMapBlocks>,
+    /// how long to wait for more replies
+    pub window: Duration,
+}
+
+/// the regions that have arrived for one set of flags
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingMapBlocks {
+    /// the regions, each one once
+    pub blocks: Vec<MapBlock>,
+    /// when the last reply arrived
+    pub last_received: Instant,
+}
+
+impl MapBlockManager {
+    /// create a manager that waits for the window before sending the regions on
+    pub fn new(window: Duration) -> Self {
+        MapBlockManager {
+            pending: HashMap::new(),
+            window,
+        }
+    }
+
+    /// add the regions of a MapBlockReply
+    pub fn handle_reply(&mut self, reply: MapBlockReply, now: Instant) {
+        let pending = self
+            .pending
+            .entry(reply.flags)
+            .or_insert_with(|| PendingMapBlocks {
+                blocks: Vec::new(),
+                last_received: now,
+            });
+        pending.last_received = now;
+        for block in reply.blocks {
+            match pending
+                .blocks
+                .iter_mut()
+                .find(|known| known.x == block.x && known.y == block.y)
+            {
+                Some(known) => *known = block,
+                None => pending.blocks.push(block),
+            }
+        }
+    }
+
+    /// the regions that no more replies have arrived for in the window
+    pub fn flush(&mut self, now: Instant) -> Vec<MapBlocks> {
+        let done: Vec<u32> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| now.duration_since(pending.last_received) >= self.window)
+            .map(|(flags, _)| *flags)
+            .collect();
+        done.into_iter()
+            .filter_map(|flags| {
+                self.pending.remove(&flags).map(|pending| MapBlocks {
+                    flags,
+                    blocks: pending.blocks,
+                })
+            })
+            .collect()
+    }
+
+    /// forget the regions that haven't been sent on, for when the session ends
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+/// Gathers the MapItemReply packets that answer a MapItemRequest, the same way as
+/// MapBlockManager. Replies are gathered by their flags and item type, so requests for different
+/// kinds of markers are sent on separately. Markers aren't deduplicated, as agent locations
+/// don't have ids.
+#[derive(Debug)]
+pub struct MapItemManager {
+    /// the markers that have arrived, by the flags and item type of their request
+    pub pending: HashMap<(u32, MapItemType), PendingMapItems>,
+    /// how long to wait for more replies
+    pub window: Duration,
+}
+
+/// the markers that have arrived for one set of flags and item type
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingMapItems {
+    /// the markers, in the order they arrived
+    pub items: Vec<MapItem>,
+    /// when the last reply arrived
+    pub last_received: Instant,
+}
+
+impl MapItemManager {
+    /// create a manager that waits for the window before sending the markers on
+    pub fn new(window: Duration) -> Self {
+        MapItemManager {
+            pending: HashMap::new(),
+            window,
+        }
+    }
+
+    /// add the markers of a MapItemReply
+    pub fn handle_reply(&mut self, reply: MapItemReply, now: Instant) {
+        let pending = self
+            .pending
+            .entry((reply.flags, reply.item_type))
+            .or_insert_with(|| PendingMapItems {
+                items: Vec::new(),
+                last_received: now,
+            });
+        pending.last_received = now;
+        pending.items.extend(reply.items);
+    }
+
+    /// the markers that no more replies have arrived for in the window
+    pub fn flush(&mut self, now: Instant) -> Vec<MapItems> {
+        let done: Vec<(u32, MapItemType)> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| now.duration_since(pending.last_received) >= self.window)
+            .map(|(key, _)| *key)
+            .collect();
+        done.into_iter()
+            .filter_map(|(flags, item_type)| {
+                self.pending
+                    .remove(&(flags, item_type))
+                    .map(|pending| MapItems {
+                        flags,
+                        item_type,
+                        items: pending.items,
+                    })
+            })
+            .collect()
+    }
+
+    /// forget the markers that haven't been sent on, for when the session ends
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+/// Keeps track of the searches the UI has made with MapNameRequest.
+/// The answers to a search are MapBlockReply packets, which look like the answers to a
+/// MapBlockRequest except for the flags they echo. So each search gets a tag, which is sent in
+/// the highest byte of its flags, next to the map layer. Regions gathered under a tagged flags
+/// value are the results of that search. Only the tag is compared, because some grids answer
+/// with a different layer than the one asked for.
+/// A search that found nothing is answered with a single block at 0, 0, which is turned into a
+/// MapSearchEmpty. So is a search that is never answered.
+#[derive(Debug)]
+pub struct MapSearchManager {
+    /// the searches waiting for their results, by tag
+    pub searches: HashMap<u8, MapSearch>,
+    /// the tag of the next search
+    pub next_tag: u8,
+    /// how long to wait for the results
+    pub timeout: Duration,
+}
+
+/// a search that hasn't had its results yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapSearch {
+    /// the name that was searched for
+    pub name: String,
+    /// the layer that was asked for
+    pub layer: MapLayer,
+    /// when the search was sent
+    pub sent: Instant,
+}
+
+/// what the regions gathered for one flags value turned out to be
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapBlocksOutcome {
+    /// the answer to a MapBlockRequest
+    Blocks(MapBlocks),
+    /// the results of a search
+    Found(MapSearchResults),
+    /// the answer to a search that found nothing
+    Empty(MapSearchEmpty),
+}
+
+impl MapSearchManager {
+    /// create a manager that gives up on searches after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        MapSearchManager {
+            searches: HashMap::new(),
+            next_tag: 1,
+            timeout,
+        }
+    }
+
+    /// start a search, returning the flags to send it with. The layer is kept from the flags
+    /// the UI sent, and the rest of them are replaced with the tag.
+    pub fn start(&mut self, name: String, flags: u32, now: Instant) -> u32 {
+        let tag = self.next_tag;
+        // tag 0 is left for the flags of requests that aren't searches, and the last tag for
+        // region lookups
+        self.next_tag = match self.next_tag.checked_add(1) {
+            Some(tag) if tag != REGION_LOOKUP_TAG => tag,
+            _ => 1,
+        };
+        let layer = MapLayer::from_flags(flags);
+        self.searches.insert(
+            tag,
+            MapSearch {
+                name,
+                layer,
+                sent: now,
+            },
+        );
+        layer.to_flags() | ((tag as u32) << MAP_SEARCH_TAG_SHIFT)
+    }
+
+    /// sort the regions gathered for one flags value into the results of a search, or the
+    /// answer to a MapBlockRequest if no search has their tag
+    pub fn finish(&mut self, map_blocks: MapBlocks) -> MapBlocksOutcome {
+        let tag = (map_blocks.flags >> MAP_SEARCH_TAG_SHIFT) as u8;
+        let search = match self.searches.remove(&tag) {
+            Some(search) => search,
+            None => return MapBlocksOutcome::Blocks(map_blocks),
+        };
+        let blocks: Vec<MapBlock> = map_blocks
+            .blocks
+            .into_iter()
+            .filter(|block| block.exists() && (block.x != 0 || block.y != 0))
+            .collect();
+        if blocks.is_empty() {
+            return MapBlocksOutcome::Empty(MapSearchEmpty {
+                name: search.name,
+                layer: search.layer,
+            });
+        }
+        MapBlocksOutcome::Found(MapSearchResults {
+            name: search.name,
+            layer: search.layer,
+            blocks,
+        })
+    }
+
+    /// give up on the searches that haven't been answered within the timeout
+    pub fn expire(&mut self, now: Instant) -> Vec<MapSearchEmpty> {
+        let expired: Vec<u8> = self
+            .searches
+            .iter()
+            .filter(|(_, search)| now.duration_since(search.sent) >= self.timeout)
+            .map(|(tag, _)| *tag)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|tag| self.searches.remove(&tag))
+            .map(|search| MapSearchEmpty {
+                name: search.name,
+                layer: search.layer,
+            })
+            .collect()
+    }
+
+    /// forget the searches, for when the session ends
+    pub fn clear(&mut self) {
+        self.searches.clear();
+    }
+}
@@ -0,0 +1,48 @@
+use metaverse_messages::agent_update::AgentUpdate;
+use tokio::time::Duration;
+
+/// how often the controls the agent is holding are sent again
+pub const MOVEMENT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Keeps the avatar moving while the UI holds down movement controls.
+/// The simulator only moves the avatar for as long as every AgentUpdate carries the controls, so
+/// the last update with controls held is sent again every MOVEMENT_INTERVAL. An update with no
+/// controls held stops the avatar, and is sent once.
+#[derive(Debug)]
+pub struct MovementManager {
+    /// the update to keep sending, with its agent and session ids filled in
+    pub active: Option<AgentUpdate>,
+}
+
+impl Default for MovementManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MovementManager {
+    /// create a manager that isn't moving the avatar
+    pub fn new() -> Self {
+        MovementManager { active: None }
+    }
+
+    /// start, change or stop moving. The update is sent right away either way, and is kept to
+    /// send again if it holds any controls.
+    pub fn handle_update(&mut self, update: AgentUpdate) {
+        if update.control_flags.is_empty() {
+            self.active = None;
+        } else {
+            self.active = Some(update);
+        }
+    }
+
+    /// the update to send again, if the avatar is moving
+    pub fn tick(&self) -> Option<AgentUpdate> {
+        self.active.clone()
+    }
+
+    /// stop sending updates, for when the session ends
+    pub fn clear(&mut self) {
+        self.active = None;
+    }
+}
@@ -0,0 +1,128 @@
+use metaverse_messages::chat_from_simulator::SourceType;
+use metaverse_messages::mute_list::MuteList;
+use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::utils::mute::{MuteEntry, MuteType};
+use uuid::Uuid;
+
+/// Keeps the agent's mute list, and decides which chat and instant messages it blocks.
+/// The list is downloaded with the Xfer system after login, from the file named in the
+/// simulator's MuteListUpdate. The session keeps no cache of it, so the MuteListRequest is sent
+/// with a CRC of 0, and a UseCachedMuteList means the list is empty.
+/// Entries the UI adds or removes are changed here as well as on the simulator, so the list
+/// stays current without downloading it again.
+/// The mailbox shares the manager with the task reading packets from the simulator, which
+/// drops blocked messages before they reach the UI.
+#[derive(Debug)]
+pub struct MuteListManager {
+    /// the entries of the mute list, in the order they were added
+    pub entries: Vec<MuteEntry>,
+    /// the name of the mute list file being downloaded
+    pub pending_file: Option<String>,
+}
+
+impl Default for MuteListManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MuteListManager {
+    /// create a manager with an empty mute list
+    pub fn new() -> Self {
+        MuteListManager {
+            entries: Vec::new(),
+            pending_file: None,
+        }
+    }
+
+    /// the mute list, to send to the UI
+    pub fn list(&self) -> MuteList {
+        MuteList {
+            entries: self.entries.clone(),
+        }
+    }
+
+    /// handle a downloaded file, returning the new mute list if it was the file being waited
+    /// for
+    pub fn handle_download(&mut self, file_name: &str, data: &[u8]) -> Option<MuteList> {
+        if self.pending_file.as_deref() != Some(file_name) {
+            return None;
+        }
+        self.pending_file = None;
+        self.entries = MuteList::parse(data).entries;
+        Some(self.list())
+    }
+
+    /// handle a UseCachedMuteList, returning the mute list the session already has
+    pub fn use_cached(&mut self) -> MuteList {
+        self.pending_file = None;
+        self.list()
+    }
+
+    /// add an entry, or replace the entry for the same id, or the same name for entries muted
+    /// by name
+    pub fn update(&mut self, entry: MuteEntry) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|known| same_entry(known, entry.id, &entry.name))
+        {
+            Some(known) => *known = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// remove the entry for an id, or for a name if the id is nil
+    pub fn remove(&mut self, id: Uuid, name: &str) {
+        self.entries.retain(|known| !same_entry(known, id, name));
+    }
+
+    /// true if the mute list blocks the text chat of an id or a name. Nil ids and empty names
+    /// never match.
+    pub fn mutes_chat(&self, id: Uuid, name: &str) -> bool {
+        self.entries
+            .iter()
+            .filter(|entry| entry.mutes_chat())
+            .any(|entry| {
+                (!id.is_nil() && entry.id == id)
+                    || (entry.mute_type == MuteType::ByName
+                        && !name.is_empty()
+                        && entry.name == name)
+            })
+    }
+
+    /// true if a packet is chat or an instant message the mute list blocks.
+    /// Chat from an object is blocked if the object or its owner is muted. System messages are
+    /// never blocked.
+    pub fn blocks(&self, packet: &PacketType) -> bool {
+        match packet {
+            PacketType::ChatFromSimulator(chat) => match chat.source_type {
+                SourceType::Agent => self.mutes_chat(chat.source_id, &chat.from_name),
+                SourceType::Object => {
+                    self.mutes_chat(chat.source_id, &chat.from_name)
+                        || self.mutes_chat(chat.owner_id, "")
+                }
+                _ => false,
+            },
+            PacketType::ImprovedInstantMessage(im) => {
+                self.mutes_chat(im.from_agent_id, &im.from_agent_name)
+            }
+            _ => false,
+        }
+    }
+
+    /// forget the mute list, for when the session ends
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.pending_file = None;
+    }
+}
+
+// entries are the same if they have the same id, or the same name if they are muted by name
+fn same_entry(entry: &MuteEntry, id: Uuid, name: &str) -> bool {
+    if id.is_nil() {
+        entry.id.is_nil() && entry.name == name
+    } else {
+        entry.id == id
+    }
+}
@@ -0,0 +1,217 @@
+use metaverse_messages::uuid_group_name_reply::{GroupName, UUIDGroupNameReply};
+use metaverse_messages::uuid_group_name_request::UUIDGroupNameRequest;
+use metaverse_messages::uuid_name_reply::{AvatarName, UUIDNameReply};
+use metaverse_messages::uuid_name_request::UUIDNameRequest;
+use std::collections::{BTreeMap, HashMap};
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how many names of each kind are kept before the least recently used ones are evicted
+pub const NAME_CACHE_SIZE: usize = 4096;
+/// how long to wait for a reply before a name can be requested again
+pub const NAME_LOOKUP_TIMEOUT: Duration = Duration::from_secs(30);
+/// the most ids sent in one UUIDNameRequest or UUIDGroupNameRequest
+pub const NAME_REQUEST_BATCH: usize = 100;
+
+/// Resolves avatar ids to names with UUIDNameRequest, and group ids to names with
+/// UUIDGroupNameRequest. Avatars and groups are kept in separate maps, since they are
+/// different namespaces.
+#[derive(Debug)]
+pub struct NameCache {
+    /// the names of avatars
+    pub avatars: NameMap<AvatarName>,
+    /// the names of groups
+    pub groups: NameMap<GroupName>,
+}
+
+/// The names of one kind of id.
+/// Names that are already known are answered from the map, and ids that have already been
+/// asked for aren't asked for again until the lookup times out. Unknown ids are batched into as
+/// few requests as possible. The map holds at most capacity names, and evicts the least
+/// recently used one when it is full.
+#[derive(Debug)]
+pub struct NameMap<T> {
+    /// the known names, by id
+    pub names: HashMap<Uuid, CachedName<T>>,
+    /// ids by when their name was last used, oldest first
+    pub recency: BTreeMap<u64, Uuid>,
+    /// ids that have been requested from the simulator, and when
+    pub pending: HashMap<Uuid, Instant>,
+    /// the most names kept
+    pub capacity: usize,
+    /// how long to wait for a reply before requesting a name again
+    pub timeout: Duration,
+    /// counts up every time a name is used, to order the recency map
+    pub clock: u64,
+}
+
+/// a name in the cache
+#[derive(Debug)]
+pub struct CachedName<T> {
+    /// the name
+    pub name: T,
+    /// when the name was last used, as a value of the map's clock
+    pub last_used: u64,
+}
+
+impl NameCache {
+    /// create an empty cache holding at most capacity names of each kind
+    pub fn new(capacity: usize, timeout: Duration) -> Self {
+        NameCache {
+            avatars: NameMap::new(capacity, timeout),
+            groups: NameMap::new(capacity, timeout),
+        }
+    }
+
+    /// the cached name of an avatar, marking it as recently used
+    pub fn get(&mut self, id: &Uuid) -> Option<&AvatarName> {
+        self.avatars.get(id)
+    }
+
+    /// the cached name of a group, marking it as recently used
+    pub fn get_group(&mut self, id: &Uuid) -> Option<&GroupName> {
+        self.groups.get(id)
+    }
+
+    /// look up the names of avatars. Returns the names that are already cached, and the
+    /// requests to send for the rest.
+    pub fn lookup(
+        &mut self,
+        ids: &[Uuid],
+        now: Instant,
+    ) -> (Vec<AvatarName>, Vec<UUIDNameRequest>) {
+        let (cached, batches) = self.avatars.lookup(ids, now);
+        let requests = batches
+            .into_iter()
+            .map(|ids| UUIDNameRequest { ids })
+            .collect();
+        (cached, requests)
+    }
+
+    /// look up the names of groups. Returns the names that are already cached, and the
+    /// requests to send for the rest.
+    pub fn lookup_groups(
+        &mut self,
+        ids: &[Uuid],
+        now: Instant,
+    ) -> (Vec<GroupName>, Vec<UUIDGroupNameRequest>) {
+        let (cached, batches) = self.groups.lookup(ids, now);
+        let requests = batches
+            .into_iter()
+            .map(|ids| UUIDGroupNameRequest { ids })
+            .collect();
+        (cached, requests)
+    }
+
+    /// cache the names in a reply, returning them to be sent to the UI. Names that were never
+    /// asked for are cached too.
+    pub fn handle_reply(&mut self, reply: UUIDNameReply) -> Vec<AvatarName> {
+        for name in &reply.names {
+            self.avatars.insert(name.id, name.clone());
+        }
+        reply.names
+    }
+
+    /// cache the names in a group reply, returning them to be sent to the UI. Names that were
+    /// never asked for are cached too.
+    pub fn handle_group_reply(&mut self, reply: UUIDGroupNameReply) -> Vec<GroupName> {
+        for name in &reply.names {
+            self.groups.insert(name.id, name.clone());
+        }
+        reply.names
+    }
+
+    /// forget the lookups in progress, for when the session ends. The names stay cached.
+    pub fn clear_pending(&mut self) {
+        self.avatars.pending.clear();
+        self.groups.pending.clear();
+    }
+}
+
+impl<T: Clone + PartialEq> NameMap<T> {
+    /// create an empty map holding at most capacity names
+    pub fn new(capacity: usize, timeout: Duration) -> Self {
+        NameMap {
+            names: HashMap::new(),
+            recency: BTreeMap::new(),
+            pending: HashMap::new(),
+            capacity,
+            timeout,
+            clock: 0,
+        }
+    }
+
+    /// the cached name for an id, marking it as recently used
+    pub fn get(&mut self, id: &Uuid) -> Option<&T> {
+        let clock = self.tick();
+        let cached = self.names.get_mut(id)?;
+        self.recency.remove(&cached.last_used);
+        self.recency.insert(clock, *id);
+        cached.last_used = clock;
+        Some(&cached.name)
+    }
+
+    /// look up the names for ids. Returns the names that are already cached, and the rest of
+    /// the ids in batches to request. Nil ids, duplicates and ids that are already being looked
+    /// up are skipped.
+    pub fn lookup(&mut self, ids: &[Uuid], now: Instant) -> (Vec<T>, Vec<Vec<Uuid>>) {
+        let mut cached = Vec::new();
+        let mut missing = Vec::new();
+        for id in ids {
+            if id.is_nil() {
+                continue;
+            }
+            if let Some(name) = self.get(id) {
+                if !cached.contains(name) {
+                    cached.push(name.clone());
+                }
+                continue;
+            }
+            if let Some(requested) = self.pending.get(id) {
+                if now.duration_since(*requested) < self.timeout {
+                    continue;
+                }
+            }
+            self.pending.insert(*id, now);
+            missing.push(*id);
+        }
+        let batches = missing
+            .chunks(NAME_REQUEST_BATCH)
+            .map(|ids| ids.to_vec())
+            .collect();
+        (cached, batches)
+    }
+
+    /// add a name to the map, evicting the least recently used name if it is full
+    pub fn insert(&mut self, id: Uuid, name: T) {
+        self.pending.remove(&id);
+        let clock = self.tick();
+        if let Some(replaced) = self.names.remove(&id) {
+            self.recency.remove(&replaced.last_used);
+        }
+        while self.names.len() >= self.capacity {
+            match self.recency.pop_first() {
+                Some((_, evicted)) => {
+                    self.names.remove(&evicted);
+                }
+                None => break,
+            }
+        }
+        if self.capacity == 0 {
+            return;
+        }
+        self.recency.insert(clock, id);
+        self.names.insert(
+            id,
+            CachedName {
+                name,
+                last_used: clock,
+            },
+        );
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
@@ -0,0 +1,136 @@
+use metaverse_messages::object_family_status::ObjectFamilyStatus;
+use metaverse_messages::object_properties_family::ObjectPropertiesFamily;
+use metaverse_messages::request_object_properties_family::RequestObjectPropertiesFamily;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long to wait for the ObjectPropertiesFamily answering a RequestObjectPropertiesFamily
+/// before the object is reported as not found
+pub const OBJECT_FAMILY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The low bits of the request flags say what the properties are for, like a bug report or
+/// paying the object. The session's own flags are kept above them, so the simulator doesn't
+/// treat a hover as either.
+pub const REQUEST_FLAG_SHIFT: u32 = 4;
+
+/// Keeps track of the RequestObjectPropertiesFamily requests waiting for their answer.
+/// The simulator echoes the request flags in its answer, so every request is sent with flags of
+/// its own, and the answer is matched to the UI's token by them. This lets the UI hover over
+/// several objects at once, or over the same one twice.
+/// Requests are reported as not found if the simulator doesn't answer within the timeout.
+#[derive(Debug)]
+pub struct ObjectFamilyManager {
+    /// the requests waiting for their answer, by their request flags
+    pub pending: HashMap<u32, PendingObjectFamily>,
+    /// the sequence number of the next request, before it is shifted into the request flags
+    pub next_request: u32,
+    /// how long a request can wait for its answer
+    pub timeout: Duration,
+}
+
+/// a RequestObjectPropertiesFamily waiting for its answer
+#[derive(Debug)]
+pub struct PendingObjectFamily {
+    /// the token the UI asked with
+    pub token: u32,
+    /// the full id of the object
+    pub object_id: Uuid,
+    /// when the request was sent
+    pub sent: Instant,
+}
+
+impl ObjectFamilyManager {
+    /// create a manager that gives up on requests after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        ObjectFamilyManager {
+            pending: HashMap::new(),
+            next_request: 1,
+            timeout,
+        }
+    }
+
+    /// ask for the properties of an object, returning the request to send to the simulator
+    pub fn request(
+        &mut self,
+        object_id: Uuid,
+        token: u32,
+        now: Instant,
+    ) -> RequestObjectPropertiesFamily {
+        let request_flags = self.next_request_flags();
+        self.pending.insert(
+            request_flags,
+            PendingObjectFamily {
+                token,
+                object_id,
+                sent: now,
+            },
+        );
+        RequestObjectPropertiesFamily::new(request_flags, object_id)
+    }
+
+    /// finish the request an ObjectPropertiesFamily answers, returning the event for the UI.
+    /// None if the answer isn't to a request the session sent.
+    pub fn handle_reply(&mut self, reply: &ObjectPropertiesFamily) -> Option<ObjectFamilyStatus> {
+        let pending = self.pending.remove(&reply.request_flags)?;
+        // the simulator answers objects it doesn't know with a nil id
+        if reply.object_id.is_nil() {
+            return Some(not_found(pending, "object not found"));
+        }
+        Some(ObjectFamilyStatus {
+            token: pending.token,
+            object_id: pending.object_id,
+            family: Some(reply.clone()),
+            reason: None,
+        })
+    }
+
+    /// report the requests that have waited longer than the timeout as not found
+    pub fn expire(&mut self, now: Instant) -> Vec<ObjectFamilyStatus> {
+        let expired: Vec<u32> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| now.duration_since(pending.sent) >= self.timeout)
+            .map(|(request_flags, _)| *request_flags)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|request_flags| self.pending.remove(&request_flags))
+            .map(|pending| not_found(pending, "timed out waiting for the simulator"))
+            .collect()
+    }
+
+    /// report every request as not found, for when the session ends
+    pub fn cancel_all(&mut self) -> Vec<ObjectFamilyStatus> {
+        self.pending
+            .drain()
+            .map(|(_, pending)| not_found(pending, "session ended"))
+            .collect()
+    }
+
+    // the next sequence number that fits above the viewer's flags and isn't waiting for an
+    // answer already
+    fn next_request_flags(&mut self) -> u32 {
+        loop {
+            let sequence = self.next_request;
+            self.next_request = if sequence >= u32::MAX >> REQUEST_FLAG_SHIFT {
+                1
+            } else {
+                sequence + 1
+            };
+            let request_flags = sequence << REQUEST_FLAG_SHIFT;
+            if !self.pending.contains_key(&request_flags) {
+                return request_flags;
+            }
+        }
+    }
+}
+
+fn not_found(pending: PendingObjectFamily, reason: &str) -> ObjectFamilyStatus {
+    ObjectFamilyStatus {
+        token: pending.token,
+        object_id: pending.object_id,
+        family: None,
+        reason: Some(reason.to_string()),
+    }
+}
@@ -0,0 +1,91 @@
+use metaverse_messages::object_properties::ObjectProperties;
+use metaverse_messages::object_sale_info::ObjectSaleInfo;
+use metaverse_messages::object_update::ObjectUpdate;
+use metaverse_messages::utils::permissions::Permissions;
+use metaverse_messages::utils::sale_type::SaleType;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Checks the sale info the UI sends before it goes to the simulator.
+/// Prices can't be negative, and an object can only be sold the ways its owner's permissions
+/// allow: it has to be transferable to be sold at all, and copyable to sell copies of it. The
+/// owner permissions are the ones last seen in the object's ObjectProperties, so objects are
+/// only put up for sale once they have been selected.
+/// ObjectProperties name objects by their full id, while edits are by local id, so the full ids
+/// of the objects in the current region are remembered from their ObjectUpdates.
+#[derive(Debug, Default)]
+pub struct SaleChecker {
+    /// the owner permissions of objects, by full id
+    pub owner_masks: HashMap<Uuid, Permissions>,
+    /// the full ids of objects in the current region, by local id
+    pub object_ids: HashMap<u32, Uuid>,
+}
+
+impl SaleChecker {
+    /// create a checker that hasn't seen any objects
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// remember the full ids of the objects in an ObjectUpdate from the current region
+    pub fn handle_object_update(&mut self, update: &ObjectUpdate) {
+        for object in &update.objects {
+            self.object_ids.insert(object.local_id, object.full_id);
+        }
+    }
+
+    /// remember the owner permissions of the objects in ObjectProperties
+    pub fn handle_properties(&mut self, properties: &ObjectProperties) {
+        for object in &properties.objects {
+            self.owner_masks.insert(object.object_id, object.owner_mask);
+        }
+    }
+
+    /// drop the objects whose sale info can't be set, and return a warning for each of them
+    pub fn check(&self, sale_info: &mut ObjectSaleInfo) -> Vec<String> {
+        let mut warnings = Vec::new();
+        sale_info.objects.retain(|object| {
+            match self.reason(object.local_id, object.sale_type, object.sale_price) {
+                Some(reason) => {
+                    warnings.push(format!("object {}: {}", object.local_id, reason));
+                    false
+                }
+                None => true,
+            }
+        });
+        warnings
+    }
+
+    /// why an object can't be sold this way, or None if it can
+    pub fn reason(&self, local_id: u32, sale_type: SaleType, price: i32) -> Option<&'static str> {
+        if price < 0 {
+            return Some("price cannot be negative");
+        }
+        let owner_mask = match sale_type {
+            SaleType::NotForSale => return None,
+            SaleType::Unknown(_) => return Some("unknown sale type"),
+            _ => match self
+                .object_ids
+                .get(&local_id)
+                .and_then(|object_id| self.owner_masks.get(object_id))
+            {
+                Some(owner_mask) => owner_mask,
+                None => return Some("permissions are not known yet, select the object first"),
+            },
+        };
+        if !owner_mask.transfer {
+            Some("object is not transferable")
+        } else if sale_type == SaleType::Copy && !owner_mask.copy {
+            Some("object is not copyable, so copies cannot be sold")
+        } else {
+            None
+        }
+    }
+
+    /// forget the objects of the old region when the agent changes regions, since local ids
+    /// are only unique within a region
+    pub fn change_region(&mut self) {
+        self.object_ids.clear();
+        self.owner_masks.clear();
+    }
+}
@@ -0,0 +1,103 @@
+use metaverse_messages::header::{Header, PacketFrequency};
+use metaverse_messages::raw_packet::RawPacketData;
+use metaverse_messages::unknown_packet_notice::UnknownPacketNotice;
+use std::collections::HashMap;
+
+/// the largest datagram read from a simulator, unless the mailbox is set up with another size.
+/// Simulators keep their packets well under this, so a datagram that fills it was cut off.
+pub const MAX_PACKET_SIZE: usize = 4096;
+
+/// why a datagram from a simulator was dropped before it could be handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseFailure {
+    /// the datagram filled the whole receive buffer, so the end of it was cut off
+    Truncated,
+    /// the header couldn't be read
+    Header,
+    /// the header was read, but the body of the message it names couldn't be
+    Body {
+        /// the frequency of the packet's message number
+        frequency: PacketFrequency,
+        /// the packet's message number
+        id: u16,
+    },
+}
+
+impl ParseFailure {
+    /// find out which part of a datagram that failed to parse is broken
+    pub fn of(bytes: &[u8]) -> Self {
+        match Header::try_from_bytes(bytes) {
+            Ok(header) => ParseFailure::Body {
+                frequency: header.frequency,
+                id: header.id,
+            },
+            Err(_) => ParseFailure::Header,
+        }
+    }
+}
+
+/// Counts the packets received from simulators by their message number, including the ones the
+/// client doesn't implement. Unknown packets can also be reported to the UI with their bodies,
+/// for finding out what simulators send that nothing handles yet. That is off unless the UI
+/// turns it on, since some unknown packets arrive many times a second. Datagrams that are cut off
+/// or don't parse are counted by what was wrong with them.
+#[derive(Debug, Default)]
+pub struct PacketStats {
+    /// how many packets arrived with each frequency and message number
+    pub received: HashMap<(PacketFrequency, u16), u64>,
+    /// how many of them had message numbers the client doesn't implement
+    pub unknown: u64,
+    /// how many datagrams were dropped for each reason they couldn't be parsed
+    pub parse_failures: HashMap<ParseFailure, u64>,
+    /// true to report unknown packets to the UI
+    pub report_unknown: bool,
+}
+
+impl PacketStats {
+    /// create empty statistics, which report unknown packets if report_unknown is set
+    pub fn new(report_unknown: bool) -> Self {
+        PacketStats {
+            received: HashMap::new(),
+            unknown: 0,
+            parse_failures: HashMap::new(),
+            report_unknown,
+        }
+    }
+
+    /// count a packet that was received
+    pub fn received(&mut self, frequency: PacketFrequency, id: u16) {
+        *self.received.entry((frequency, id)).or_default() += 1;
+    }
+
+    /// count a packet the client doesn't implement. Returns what to tell the UI about it, if
+    /// unknown packets are reported.
+    pub fn unknown(&mut self, raw: &RawPacketData) -> Option<UnknownPacketNotice> {
+        self.unknown += 1;
+        if !self.report_unknown {
+            return None;
+        }
+        Some(UnknownPacketNotice::new(raw))
+    }
+
+    /// count a datagram that was dropped because it couldn't be parsed
+    pub fn parse_failed(&mut self, failure: ParseFailure) {
+        *self.parse_failures.entry(failure).or_default() += 1;
+    }
+
+    /// how many packets arrived with the frequency and message number
+    pub fn count(&self, frequency: PacketFrequency, id: u16) -> u64 {
+        self.received.get(&(frequency, id)).copied().unwrap_or(0)
+    }
+
+    /// how many datagrams were dropped for the reason
+    pub fn failures(&self, failure: ParseFailure) -> u64 {
+        self.parse_failures.get(&failure).copied().unwrap_or(0)
+    }
+
+    /// forget the counts, for when the session ends. Whether to report unknown packets is kept.
+    pub fn clear(&mut self) {
+        self.received.clear();
+        self.unknown = 0;
+        self.parse_failures.clear();
+    }
+}
@@ -0,0 +1,243 @@
+use glam::Vec3;
+use metaverse_messages::improved_terse_object_update::ImprovedTerseObjectUpdate;
+use metaverse_messages::object_update::ObjectUpdate;
+use metaverse_messages::parcel_overlay::{ParcelOverlay, PARCEL_OVERLAY_CHUNKS};
+use metaverse_messages::parcel_overlay_grid::{ParcelOverlayGrid, PARCEL_OVERLAY_SIZE};
+use metaverse_messages::parcel_properties::ParcelProperties;
+use metaverse_messages::parcel_properties_request::ParcelPropertiesRequest;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how often to check whether the agent has moved off its parcel
+pub const PARCEL_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+/// how long to wait for a ParcelProperties before the parcel can be requested again
+pub const PARCEL_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// how often the parcel the agent is on is requested again, to pick up changes to it
+pub const PARCEL_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Keeps track of the parcel the agent is on.
+/// The agent's position comes from the ObjectUpdates and ImprovedTerseObjectUpdates of its
+/// avatar. Once the position leaves the bitmap of the known parcel, the parcel under the agent
+/// is requested. Only one request is out at a time, and every request gets a new sequence id, so
+/// a late answer to an older request is never mistaken for the current parcel. Requests snap
+/// their selection, so the simulator answers with the whole parcel instead of saying that the
+/// request spans several.
+/// The simulator also sends the parcel unasked, with a sequence id of 0, when the agent walks
+/// onto it. That answer is newer than any request, so the request in flight is dropped.
+#[derive(Debug)]
+pub struct ParcelTracker {
+    /// the local id of the agent's avatar in the current region
+    pub agent_local_id: Option<u32>,
+    /// the agent's position in the current region
+    pub position: Option<Vec3>,
+    /// the parcel the agent is on
+    pub current: Option<ParcelProperties>,
+    /// when the current parcel was last received
+    pub updated: Option<Instant>,
+    /// the request waiting for its ParcelProperties
+    pub pending: Option<PendingParcelRequest>,
+    /// the sequence id of the next request
+    pub next_sequence_id: i32,
+    /// how long to wait for a ParcelProperties before requesting again
+    pub timeout: Duration,
+    /// how often the current parcel is requested again
+    pub refresh: Duration,
+}
+
+/// a ParcelPropertiesRequest that hasn't been answered
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingParcelRequest {
+    /// the sequence id the answer will carry
+    pub sequence_id: i32,
+    /// when the request was sent
+    pub sent: Instant,
+}
+
+impl ParcelTracker {
+    /// create a tracker that requests the parcel again after the timeout, and refreshes it
+    /// after the refresh interval
+    pub fn new(timeout: Duration, refresh: Duration) -> Self {
+        ParcelTracker {
+            agent_local_id: None,
+            position: None,
+            current: None,
+            updated: None,
+            pending: None,
+            next_sequence_id: 1,
+            timeout,
+            refresh,
+        }
+    }
+
+    /// learn the agent's local id and position from an ObjectUpdate of the current region
+    pub fn handle_object_update(&mut self, update: &ObjectUpdate, agent_id: Uuid) {
+        for object in &update.objects {
+            if object.full_id != agent_id {
+                continue;
+            }
+            self.agent_local_id = Some(object.local_id);
+            if let Some(motion) = object.motion() {
+                self.position = Some(motion.position);
+            }
+        }
+    }
+
+    /// follow the agent's position in an ImprovedTerseObjectUpdate of the current region
+    pub fn handle_terse_update(&mut self, update: &ImprovedTerseObjectUpdate) {
+        let local_id = match self.agent_local_id {
+            Some(local_id) => local_id,
+            None => return,
+        };
+        for object in &update.objects {
+            if object.local_id == local_id {
+                self.position = Some(object.position);
+            }
+        }
+    }
+
+    /// the request to send for the parcel under the agent, if it needs one.
+    /// Nothing is requested while the position is unknown, or while another request is waiting
+    /// for its answer. The parcel is requested when none is known, when the agent has left it,
+    /// or when it hasn't been refreshed for the refresh interval.
+    /// The agent and session ids of the request are left nil.
+    pub fn poll(&mut self, now: Instant) -> Option<ParcelPropertiesRequest> {
+        let position = self.position?;
+        if let Some(pending) = &self.pending {
+            if now.duration_since(pending.sent) < self.timeout {
+                return None;
+            }
+        }
+        let needed = match (&self.current, self.updated) {
+            (Some(current), Some(updated)) => {
+                !current.contains(position.x, position.y)
+                    || now.duration_since(updated) >= self.refresh
+            }
+            _ => true,
+        };
+        if !needed {
+            return None;
+        }
+        let sequence_id = self.next_sequence_id;
+        self.next_sequence_id = self.next_sequence_id.checked_add(1).unwrap_or(1);
+        self.pending = Some(PendingParcelRequest {
+            sequence_id,
+            sent: now,
+        });
+        Some(ParcelPropertiesRequest::at(
+            position.x,
+            position.y,
+            sequence_id,
+        ))
+    }
+
+    /// handle a ParcelProperties, returning it if the parcel the agent is on has changed.
+    /// Answers that only differ from the current parcel in how they were asked for don't count
+    /// as a change.
+    /// Answers to anything but the waiting request are dropped, as are answers without a
+    /// parcel.
+    pub fn handle_properties(
+        &mut self,
+        properties: ParcelProperties,
+        now: Instant,
+    ) -> Option<ParcelProperties> {
+        let requested = self
+            .pending
+            .as_ref()
+            .is_some_and(|pending| pending.sequence_id == properties.sequence_id);
+        if properties.sequence_id != 0 && !requested {
+            return None;
+        }
+        // a request that found no parcel is left waiting, to be sent again after the timeout
+        if properties.request_result != ParcelProperties::RESULT_SUCCESS {
+            return None;
+        }
+        self.pending = None;
+        self.updated = Some(now);
+        if self
+            .current
+            .as_ref()
+            .is_some_and(|current| unchanged(current, &properties))
+        {
+            return None;
+        }
+        self.current = Some(properties.clone());
+        Some(properties)
+    }
+
+    /// forget the agent and its parcel, for when the agent changes region or the session
+    /// ends. The sequence ids keep counting up, so answers from the old region are dropped.
+    pub fn reset(&mut self) {
+        self.agent_local_id = None;
+        self.position = None;
+        self.current = None;
+        self.updated = None;
+        self.pending = None;
+    }
+}
+
+// true if two ParcelProperties describe the same parcel the same way, whichever request they
+// answer
+fn unchanged(current: &ParcelProperties, properties: &ParcelProperties) -> bool {
+    let properties = ParcelProperties {
+        sequence_id: current.sequence_id,
+        snap_selection: current.snap_selection,
+        ..properties.clone()
+    };
+    *current == properties
+}
+
+/// Puts the chunks of the current region's ParcelOverlay back together.
+/// Chunks are kept by their sequence id until every chunk has arrived, and the whole overlay is
+/// returned once. A chunk whose sequence id has already arrived means the simulator has started
+/// sending a new overlay, so the partial overlay is stale and is thrown away.
+#[derive(Debug)]
+pub struct ParcelOverlayAssembler {
+    /// the chunks of the overlay being put together, by sequence id
+    pub chunks: Vec<Option<Vec<u8>>>,
+}
+
+impl Default for ParcelOverlayAssembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParcelOverlayAssembler {
+    /// create an assembler with no chunks
+    pub fn new() -> Self {
+        ParcelOverlayAssembler {
+            chunks: vec![None; PARCEL_OVERLAY_CHUNKS],
+        }
+    }
+
+    /// add a chunk, returning the whole overlay if it was the last one missing.
+    /// Chunks with a sequence id out of range are dropped, and an overlay whose chunks don't
+    /// add up to a whole region is thrown away.
+    pub fn handle_chunk(&mut self, chunk: ParcelOverlay) -> Option<ParcelOverlayGrid> {
+        let index = usize::try_from(chunk.sequence_id)
+            .ok()
+            .filter(|index| *index < PARCEL_OVERLAY_CHUNKS)?;
+        if self.chunks[index].is_some() {
+            self.reset();
+        }
+        self.chunks[index] = Some(chunk.data);
+        if self.chunks.iter().any(Option::is_none) {
+            return None;
+        }
+        let cells: Vec<u8> = self
+            .chunks
+            .iter_mut()
+            .flat_map(|chunk| chunk.take())
+            .flatten()
+            .collect();
+        if cells.len() != PARCEL_OVERLAY_SIZE {
+            return None;
+        }
+        Some(ParcelOverlayGrid { cells })
+    }
+
+    /// throw away the partial overlay, for when the agent changes region or the session ends
+    pub fn reset(&mut self) {
+        self.chunks = vec![None; PARCEL_OVERLAY_CHUNKS];
+    }
+}
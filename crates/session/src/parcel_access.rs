@@ -0,0 +1,102 @@
+use metaverse_messages::parcel_access_list::ParcelAccessList;
+use metaverse_messages::parcel_access_list_reply::ParcelAccessListReply;
+use metaverse_messages::parcel_access_list_request::ParcelAccessListRequest;
+use metaverse_messages::utils::parcel_access::{ParcelAccessEntry, ParcelAccessFlags};
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+
+/// how long to wait for more ParcelAccessListReply packets before sending the list on to the UI
+pub const PARCEL_ACCESS_WINDOW: Duration = Duration::from_millis(500);
+
+/// Gathers the access and ban lists of parcels. The simulator sends a long list as several
+/// ParcelAccessListReply packets with the sequence id of the request, and doesn't say which one
+/// is the last, so the parts are gathered by parcel, sequence id and list, and sent on as one
+/// list once no more have arrived for the window.
+/// Each request gets its own sequence id, so the parts of two requests for the same parcel don't
+/// mix. Replies the simulator sends without a request, like after the list is changed, are
+/// gathered the same way.
+#[derive(Debug)]
+pub struct ParcelAccessManager {
+    /// the lists being gathered, by parcel local id, sequence id and list
+    pub lists: HashMap<(i32, i32, ParcelAccessFlags), PendingAccessList>,
+    /// the sequence id of the last request
+    pub sequence_id: i32,
+    /// how long to wait for more replies
+    pub window: Duration,
+}
+
+/// a list that hasn't been sent to the UI yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingAccessList {
+    /// the entries that have arrived
+    pub entries: Vec<ParcelAccessEntry>,
+    /// when the last reply arrived
+    pub last_received: Instant,
+}
+
+impl ParcelAccessManager {
+    /// create a manager that gathers replies for the window
+    pub fn new(window: Duration) -> Self {
+        ParcelAccessManager {
+            lists: HashMap::new(),
+            sequence_id: 0,
+            window,
+        }
+    }
+
+    /// give a request the next sequence id, returning the request to send
+    pub fn start(&mut self, mut request: ParcelAccessListRequest) -> ParcelAccessListRequest {
+        self.sequence_id = self.sequence_id.wrapping_add(1).max(1);
+        request.sequence_id = self.sequence_id;
+        request
+    }
+
+    /// add the entries of a reply to their list
+    pub fn add(&mut self, reply: &ParcelAccessListReply, now: Instant) {
+        let list = self
+            .lists
+            .entry((reply.local_id, reply.sequence_id, reply.flags))
+            .or_insert_with(|| PendingAccessList {
+                entries: Vec::new(),
+                last_received: now,
+            });
+        // the simulator sends an empty list as a single nil entry
+        list.entries.extend(
+            reply
+                .entries
+                .iter()
+                .filter(|entry| !entry.id.is_nil())
+                .cloned(),
+        );
+        list.last_received = now;
+    }
+
+    /// take the lists that have stopped getting replies
+    pub fn flush(&mut self, now: Instant) -> Vec<ParcelAccessList> {
+        let done: Vec<(i32, i32, ParcelAccessFlags)> = self
+            .lists
+            .iter()
+            .filter(|(_, list)| now.duration_since(list.last_received) >= self.window)
+            .map(|(key, _)| *key)
+            .collect();
+        let mut lists: Vec<ParcelAccessList> = done
+            .into_iter()
+            .filter_map(|(local_id, sequence_id, flags)| {
+                self.lists
+                    .remove(&(local_id, sequence_id, flags))
+                    .map(|list| ParcelAccessList {
+                        local_id,
+                        flags,
+                        entries: list.entries,
+                    })
+            })
+            .collect();
+        lists.sort_by_key(|list| (list.local_id, list.flags.bits()));
+        lists
+    }
+
+    /// forget the lists, for when the session ends
+    pub fn clear(&mut self) {
+        self.lists.clear();
+    }
+}
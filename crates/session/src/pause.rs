@@ -0,0 +1,61 @@
+use metaverse_messages::agent_pause::AgentPause;
+use metaverse_messages::agent_resume::AgentResume;
+
+/// Keeps track of whether the agent is paused, and the serial number of AgentPause and
+/// AgentResume.
+/// The simulator ignores a pause or resume whose serial number isn't higher than the last one
+/// it saw, so every packet sent takes the next number, pause or resume. The number keeps
+/// increasing for as long as the mailbox runs, so a pause sent in a new session is never mistaken
+/// for an old one.
+/// Pausing while paused, or resuming while not paused, sends nothing.
+#[derive(Debug)]
+pub struct PauseManager {
+    /// true between a pause and the resume after it
+    pub paused: bool,
+    /// the serial number of the last AgentPause or AgentResume sent
+    pub serial_num: u32,
+}
+
+impl Default for PauseManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PauseManager {
+    /// create a manager that isn't paused, and hasn't sent anything
+    pub fn new() -> Self {
+        PauseManager {
+            paused: false,
+            serial_num: 0,
+        }
+    }
+
+    /// pause the agent, returning the AgentPause to send, or None if it is already paused
+    pub fn pause(&mut self) -> Option<AgentPause> {
+        if self.paused {
+            return None;
+        }
+        self.paused = true;
+        Some(AgentPause::new(self.next_serial_num()))
+    }
+
+    /// resume the agent, returning the AgentResume to send, or None if it isn't paused
+    pub fn resume(&mut self) -> Option<AgentResume> {
+        if !self.paused {
+            return None;
+        }
+        self.paused = false;
+        Some(AgentResume::new(self.next_serial_num()))
+    }
+
+    /// forget the pause, for when the session ends. A new session starts out streaming.
+    pub fn clear(&mut self) {
+        self.paused = false;
+    }
+
+    fn next_serial_num(&mut self) -> u32 {
+        self.serial_num = self.serial_num.wrapping_add(1);
+        self.serial_num
+    }
+}
@@ -0,0 +1,75 @@
+use metaverse_messages::packet::{Packet, MAX_APPENDED_ACKS};
+use metaverse_messages::packet_ack::PacketAck;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use tokio::time::Duration;
+
+/// how long an ack waits for a packet to the same simulator to ride along on, before it is sent
+/// in a PacketAck of its own
+pub const ACK_FLUSH_DELAY: Duration = Duration::from_millis(100);
+
+/// Collects the sequence numbers of reliable packets from simulators, until they are acked.
+/// Acks are appended to the next packet sent to the simulator that sent them, and the ones that
+/// are left when the flush delay is up are sent in standalone PacketAcks. A simulator with a full
+/// PacketAck's worth of acks waiting doesn't wait for the delay.
+/// A packet the simulator resends before its ack goes out is only acked once.
+#[derive(Debug, Default)]
+pub struct PendingAcks {
+    /// the sequence numbers waiting to be acked, by the simulator that sent them
+    pub acks: HashMap<SocketAddr, Vec<u32>>,
+}
+
+impl PendingAcks {
+    /// create an empty list of acks
+    pub fn new() -> Self {
+        PendingAcks {
+            acks: HashMap::new(),
+        }
+    }
+
+    /// remember to ack a reliable packet from a simulator. Returns true once the simulator has
+    /// as many acks waiting as one PacketAck holds, and they should be sent right away.
+    pub fn push(&mut self, addr: SocketAddr, sequence_number: u32) -> bool {
+        let pending = self.acks.entry(addr).or_default();
+        if !pending.contains(&sequence_number) {
+            pending.push(sequence_number);
+        }
+        pending.len() >= MAX_APPENDED_ACKS
+    }
+
+    /// append as many of the acks for a simulator to a packet going to it as fit
+    pub fn append_to(&mut self, packet: &mut Packet, addr: &SocketAddr) {
+        if let Some(pending) = self.acks.get_mut(addr) {
+            packet.append_acks(pending);
+            if pending.is_empty() {
+                self.acks.remove(addr);
+            }
+        }
+    }
+
+    /// take every ack that didn't ride along on a packet, as PacketAcks to send to each simulator
+    pub fn flush(&mut self) -> Vec<(SocketAddr, Packet)> {
+        let mut packets = Vec::new();
+        for (addr, acks) in self.acks.drain() {
+            for packet_ids in acks.chunks(MAX_APPENDED_ACKS) {
+                packets.push((
+                    addr,
+                    Packet::new_packet_ack(PacketAck {
+                        packet_ids: packet_ids.to_vec(),
+                    }),
+                ));
+            }
+        }
+        packets
+    }
+
+    /// whether there are no acks waiting
+    pub fn is_empty(&self) -> bool {
+        self.acks.is_empty()
+    }
+
+    /// forget the acks, for when the session ends
+    pub fn clear(&mut self) {
+        self.acks.clear();
+    }
+}
@@ -0,0 +1,128 @@
+use metaverse_messages::avatar_picker_reply::AvatarPickerReply;
+use metaverse_messages::avatar_picker_request::AvatarPickerRequest;
+use metaverse_messages::people_search_empty::PeopleSearchEmpty;
+use metaverse_messages::people_search_results::PeopleSearchResults;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long to wait for the AvatarPickerReply of a search before giving up on it
+pub const PEOPLE_SEARCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Keeps track of the searches for residents the UI has made with AvatarPickerRequest.
+/// Each search is sent with a query id, which the AvatarPickerReply echoes. The UI can pick the
+/// query id, to tell its searches apart, or leave it nil for the session to pick one. A query id
+/// that another search is still waiting with is replaced too. Either way the results carry the
+/// query id the search was sent with.
+/// Names that are too short for the simulator to search for, or too long to send, aren't sent.
+/// A search that found nobody, or is never answered, is turned into a PeopleSearchEmpty.
+#[derive(Debug)]
+pub struct PeopleSearchManager {
+    /// the searches waiting for their results, by query id
+    pub searches: HashMap<Uuid, PeopleSearch>,
+    /// how long to wait for the results
+    pub timeout: Duration,
+}
+
+/// a search that hasn't had its results yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeopleSearch {
+    /// the name that was searched for
+    pub name: String,
+    /// when the search was sent
+    pub sent: Instant,
+}
+
+/// what the reply to a search turned out to be
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeopleSearchOutcome {
+    /// the residents that were found
+    Found(PeopleSearchResults),
+    /// the search found nobody
+    Empty(PeopleSearchEmpty),
+}
+
+impl PeopleSearchManager {
+    /// create a manager that gives up on searches after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        PeopleSearchManager {
+            searches: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// start a search, returning the request to send with its query id filled in. Searches that
+    /// can't be sent are returned as a PeopleSearchEmpty for the UI.
+    pub fn start(
+        &mut self,
+        mut request: AvatarPickerRequest,
+        now: Instant,
+    ) -> Result<AvatarPickerRequest, PeopleSearchEmpty> {
+        if request.query_id.is_nil() || self.searches.contains_key(&request.query_id) {
+            request.query_id = Uuid::new_v4();
+        }
+        if !request.name_is_valid() {
+            return Err(PeopleSearchEmpty {
+                query_id: request.query_id,
+                name: request.name,
+            });
+        }
+        request.name = request.name.trim().to_string();
+        self.searches.insert(
+            request.query_id,
+            PeopleSearch {
+                name: request.name.clone(),
+                sent: now,
+            },
+        );
+        Ok(request)
+    }
+
+    /// finish the search a reply answers. Returns None if no search has its query id.
+    pub fn finish(&mut self, reply: &AvatarPickerReply) -> Option<PeopleSearchOutcome> {
+        let search = self.searches.remove(&reply.query_id)?;
+        let avatars: Vec<_> = reply
+            .avatars
+            .iter()
+            .filter(|avatar| !avatar.avatar_id.is_nil())
+            .cloned()
+            .collect();
+        if avatars.is_empty() {
+            return Some(PeopleSearchOutcome::Empty(PeopleSearchEmpty {
+                query_id: reply.query_id,
+                name: search.name,
+            }));
+        }
+        Some(PeopleSearchOutcome::Found(PeopleSearchResults {
+            query_id: reply.query_id,
+            name: search.name,
+            avatars,
+        }))
+    }
+
+    /// give up on the searches that haven't been answered within the timeout
+    pub fn expire(&mut self, now: Instant) -> Vec<PeopleSearchEmpty> {
+        let expired: Vec<Uuid> = self
+            .searches
+            .iter()
+            .filter(|(_, search)| now.duration_since(search.sent) >= self.timeout)
+            .map(|(query_id, _)| *query_id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|query_id| {
+                self.searches
+                    .remove(&query_id)
+                    .map(|search| PeopleSearchEmpty {
+                        query_id,
+                        name: search.name,
+                    })
+            })
+            .collect()
+    }
+
+    /// forget the searches, for when the session ends
+    pub fn clear(&mut self) {
+        self.searches.clear();
+    }
+}
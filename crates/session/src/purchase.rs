@@ -0,0 +1,184 @@
+use metaverse_messages::object_buy::ObjectBuyData;
+use metaverse_messages::object_properties::ObjectProperties;
+use metaverse_messages::object_update::ObjectUpdate;
+use metaverse_messages::purchase_failed::PurchaseFailed;
+use metaverse_messages::utils::sale_type::SaleType;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long to wait for the ObjectProperties of an object being bought
+pub const PURCHASE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Keeps track of objects being bought.
+/// A purchase starts by selecting the object, and waits for its ObjectProperties to check that
+/// the sale type and price are still the ones the UI expected. Only then is the ObjectBuy sent.
+/// ObjectProperties name objects by their full id, while purchases are by local id, so the full
+/// ids of the objects in the current region are remembered from their ObjectUpdates.
+#[derive(Debug)]
+pub struct PurchaseManager {
+    /// the purchases waiting for ObjectProperties, by local id
+    pub pending: HashMap<u32, PendingPurchase>,
+    /// the full ids of objects in the current region, by local id
+    pub object_ids: HashMap<u32, Uuid>,
+    /// how long to wait for ObjectProperties before the purchase fails
+    pub timeout: Duration,
+}
+
+/// an object the UI asked to buy
+#[derive(Debug)]
+pub struct PendingPurchase {
+    /// the sale type the UI asked to buy with
+    pub sale_type: SaleType,
+    /// the price the UI asked to pay
+    pub price: i32,
+    /// the folder the bought object goes into
+    pub category_id: Uuid,
+    /// when the object was selected
+    pub started: Instant,
+}
+
+/// an ObjectBuy to send, for a purchase whose object checked out
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckedPurchase {
+    /// the folder the bought object goes into
+    pub category_id: Uuid,
+    /// the object, with its sale type and price
+    pub object: ObjectBuyData,
+}
+
+impl PurchaseManager {
+    /// create a manager that fails purchases after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        PurchaseManager {
+            pending: HashMap::new(),
+            object_ids: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// start buying an object. Buying an object that is already being bought replaces the
+    /// earlier purchase.
+    pub fn start(
+        &mut self,
+        local_id: u32,
+        sale_type: SaleType,
+        price: i32,
+        category_id: Uuid,
+        now: Instant,
+    ) {
+        self.pending.insert(
+            local_id,
+            PendingPurchase {
+                sale_type,
+                price,
+                category_id,
+                started: now,
+            },
+        );
+    }
+
+    /// remember the full ids of the objects in an ObjectUpdate from the current region
+    pub fn handle_object_update(&mut self, update: &ObjectUpdate) {
+        for object in &update.objects {
+            self.object_ids.insert(object.local_id, object.full_id);
+        }
+    }
+
+    /// check the purchases the properties are for. Returns the purchases to send, and the ones
+    /// that failed because the object isn't for sale the way the UI expected.
+    /// Properties for objects that aren't being bought are ignored.
+    pub fn handle_properties(
+        &mut self,
+        properties: &ObjectProperties,
+    ) -> (Vec<CheckedPurchase>, Vec<PurchaseFailed>) {
+        let mut checked = Vec::new();
+        let mut failed = Vec::new();
+        for object in &properties.objects {
+            let local_id = match self
+                .pending
+                .keys()
+                .find(|local_id| self.object_ids.get(local_id) == Some(&object.object_id))
+            {
+                Some(local_id) => *local_id,
+                None => continue,
+            };
+            let purchase = match self.pending.remove(&local_id) {
+                Some(purchase) => purchase,
+                None => continue,
+            };
+            let reason = if object.sale_type == SaleType::NotForSale {
+                Some("object is not for sale")
+            } else if object.sale_type != purchase.sale_type {
+                Some("sale type does not match")
+            } else if object.sale_price != purchase.price {
+                Some("price does not match")
+            } else {
+                None
+            };
+            match reason {
+                Some(reason) => failed.push(PurchaseFailed {
+                    local_id,
+                    object_id: Some(object.object_id),
+                    expected_sale_type: purchase.sale_type,
+                    expected_price: purchase.price,
+                    sale_type: Some(object.sale_type),
+                    sale_price: Some(object.sale_price),
+                    reason: reason.to_string(),
+                }),
+                None => checked.push(CheckedPurchase {
+                    category_id: purchase.category_id,
+                    object: ObjectBuyData {
+                        local_id,
+                        sale_type: object.sale_type,
+                        sale_price: object.sale_price,
+                    },
+                }),
+            }
+        }
+        (checked, failed)
+    }
+
+    /// fail the purchases whose ObjectProperties haven't arrived within the timeout
+    pub fn expire(&mut self, now: Instant) -> Vec<PurchaseFailed> {
+        let expired: Vec<u32> = self
+            .pending
+            .iter()
+            .filter(|(_, purchase)| now.duration_since(purchase.started) >= self.timeout)
+            .map(|(local_id, _)| *local_id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|local_id| self.fail(local_id, "timed out waiting for the object"))
+            .collect()
+    }
+
+    /// forget the objects of the old region when the agent changes regions, since local ids
+    /// are only unique within a region. Purchases in progress fail.
+    pub fn change_region(&mut self) -> Vec<PurchaseFailed> {
+        self.object_ids.clear();
+        self.cancel_all("changed regions")
+    }
+
+    /// fail every purchase, for when the session ends
+    pub fn cancel_all(&mut self, reason: &str) -> Vec<PurchaseFailed> {
+        let local_ids: Vec<u32> = self.pending.keys().copied().collect();
+        local_ids
+            .into_iter()
+            .filter_map(|local_id| self.fail(local_id, reason))
+            .collect()
+    }
+
+    fn fail(&mut self, local_id: u32, reason: &str) -> Option<PurchaseFailed> {
+        let purchase = self.pending.remove(&local_id)?;
+        Some(PurchaseFailed {
+            local_id,
+            object_id: self.object_ids.get(&local_id).copied(),
+            expected_sale_type: purchase.sale_type,
+            expected_price: purchase.price,
+            sale_type: None,
+            sale_price: None,
+            reason: reason.to_string(),
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use metaverse_messages::map_block_reply::MapBlock;
+use metaverse_messages::map_blocks::MapBlocks;
+use tokio::time::{Duration, Instant};
+
+use crate::map::MAP_SEARCH_TAG_SHIFT;
+
+/// how long to wait for the MapBlockReply that has a region before giving up on it
+pub const REGION_LOOKUP_TIMEOUT: Duration = Duration::from_secs(10);
+/// the search tag of the MapNameRequests sent to look up regions. The UI's searches never get
+/// this tag.
+pub const REGION_LOOKUP_TAG: u8 = u8::MAX;
+/// the flags to send the MapNameRequests with
+pub const REGION_LOOKUP_FLAGS: u32 = (REGION_LOOKUP_TAG as u32) << MAP_SEARCH_TAG_SHIFT;
+
+/// Finds the region handles of regions by their names, for features that only know the name of
+/// a region, like the destinations scripts offer.
+/// Each name is looked up with a MapNameRequest, which the simulator answers with a block for
+/// every region whose name starts with it. The one with exactly the name, ignoring case, is the
+/// region. Every lookup is sent with the same flags, so the answers can't be told apart, and a
+/// name that isn't among them may still be answered by a later reply. A region that doesn't exist
+/// is only given up on after the timeout.
+/// Region handles don't change, so the regions found are kept, and looked up only once.
+/// Whatever is waiting for a region is kept with the lookup, and handed back with the handle.
+#[derive(Debug)]
+pub struct RegionLookupManager<T> {
+    /// the region handles found, by the lower case name of the region
+    pub known: HashMap<String, u64>,
+    /// the lookups waiting for their answer, by the lower case name of the region
+    pub pending: HashMap<String, PendingRegionLookup<T>>,
+    /// how long to wait for a region
+    pub timeout: Duration,
+}
+
+/// a region that has been asked for, and what is waiting for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingRegionLookup<T> {
+    /// the name of the region, as it was first asked for
+    pub name: String,
+    /// everything waiting for the region, in the order it was asked for
+    pub waiting: Vec<T>,
+    /// when the MapNameRequest was sent
+    pub sent: Instant,
+}
+
+/// what to do after asking for a region
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegionLookupStart<T> {
+    /// the region is already known, and this is its handle
+    Known(T, u64),
+    /// send a MapNameRequest with REGION_LOOKUP_FLAGS for the name
+    Request,
+    /// a MapNameRequest for the name is already waiting for its answer
+    Waiting,
+}
+
+/// a lookup that is over, with the handle of the region if it was found
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionLookup<T> {
+    /// what was waiting for the region
+    pub waiter: T,
+    /// the name of the region, as it was asked for
+    pub name: String,
+    /// the handle of the region, None if it wasn't found
+    pub region_handle: Option<u64>,
+}
+
+impl<T> RegionLookupManager<T> {
+    /// create a manager that gives up on regions after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        RegionLookupManager {
+            known: HashMap::new(),
+            pending: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// ask for the handle of a region by its name
+    pub fn lookup(&mut self, name: &str, waiter: T, now: Instant) -> RegionLookupStart<T> {
+        let key = name.to_lowercase();
+        if let Some(region_handle) = self.known.get(&key) {
+            return RegionLookupStart::Known(waiter, *region_handle);
+        }
+        if let Some(pending) = self.pending.get_mut(&key) {
+            pending.waiting.push(waiter);
+            return RegionLookupStart::Waiting;
+        }
+        self.pending.insert(
+            key,
+            PendingRegionLookup {
+                name: name.to_string(),
+                waiting: vec![waiter],
+                sent: now,
+            },
+        );
+        RegionLookupStart::Request
+    }
+
+    /// handle the regions gathered for one flags value, returning None if they aren't the
+    /// answer to a lookup. Otherwise the lookups of the regions that arrived are returned.
+    pub fn handle_blocks(&mut self, map_blocks: &MapBlocks) -> Option<Vec<RegionLookup<T>>> {
+        if (map_blocks.flags >> MAP_SEARCH_TAG_SHIFT) as u8 != REGION_LOOKUP_TAG {
+            return None;
+        }
+        let mut found = Vec::new();
+        for block in map_blocks.blocks.iter().filter(|block| is_region(block)) {
+            let key = block.name.to_lowercase();
+            self.known.insert(key.clone(), block.region_handle());
+            if let Some(pending) = self.pending.remove(&key) {
+                found.extend(finish(pending, Some(block.region_handle())));
+            }
+        }
+        Some(found)
+    }
+
+    /// give up on the regions that haven't been found within the timeout
+    pub fn expire(&mut self, now: Instant) -> Vec<RegionLookup<T>> {
+        let expired: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| now.duration_since(pending.sent) >= self.timeout)
+            .map(|(key, _)| key.clone())
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|key| self.pending.remove(&key))
+            .flat_map(|pending| finish(pending, None))
+            .collect()
+    }
+
+    /// forget the lookups and the regions found, for when the session ends. Another grid can
+    /// have regions with the same names.
+    pub fn clear(&mut self) {
+        self.known.clear();
+        self.pending.clear();
+    }
+}
+
+// a search that found nothing is answered with a single block at 0, 0
+fn is_region(block: &MapBlock) -> bool {
+    block.exists() && (block.x != 0 || block.y != 0)
+}
+
+// hand back everything that was waiting for a region
+fn finish<T>(pending: PendingRegionLookup<T>, region_handle: Option<u64>) -> Vec<RegionLookup<T>> {
+    let name = pending.name;
+    pending
+        .waiting
+        .into_iter()
+        .map(|waiter| RegionLookup {
+            waiter,
+            name: name.clone(),
+            region_handle,
+        })
+        .collect()
+}
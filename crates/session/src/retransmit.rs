@@ -0,0 +1,135 @@
+use metaverse_messages::packet::Packet;
+use std::collections::{HashMap, HashSet};
+use tokio::time::{Duration, Instant};
+
+/// the shortest time to wait for an ack before resending a packet
+pub const MIN_RETRANSMIT_TIMEOUT: Duration = Duration::from_secs(1);
+/// the longest time to wait for an ack, however slow the circuit is or how often it was resent
+pub const MAX_RETRANSMIT_TIMEOUT: Duration = Duration::from_secs(5);
+/// how many round trips to wait for an ack before resending a packet
+pub const RETRANSMIT_PING_FACTOR: u32 = 4;
+/// how many times a packet is resent before the circuit is given up on
+pub const MAX_RESENDS: u32 = 3;
+/// how often packets waiting for acks are checked
+pub const RETRANSMIT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Keeps the reliable packets sent to simulators until they are acked, and resends the ones
+/// whose acks don't arrive in time. The wait is a few round trips of the measured ping, and
+/// doubles with every resend. A resend keeps the packet's sequence number and sets its resent
+/// flag. A circuit with a packet that is still unacked after the most resends is dead.
+#[derive(Debug)]
+pub struct RetransmitQueue {
+    /// the packets waiting for acks, by the simulator they were sent to and their sequence number
+    pub packets: HashMap<(String, u32), UnackedPacket>,
+    /// how many times a packet is resent before its circuit is given up on
+    pub max_resends: u32,
+}
+
+/// a reliable packet that hasn't been acked
+#[derive(Debug)]
+pub struct UnackedPacket {
+    /// the packet, as it was sent
+    pub packet: Packet,
+    /// when it was last sent
+    pub sent: Instant,
+    /// how many times it has been resent
+    pub resends: u32,
+}
+
+/// what is due after checking the packets waiting for acks
+#[derive(Debug, Default)]
+pub struct Retransmits {
+    /// the packets to send again, and the simulators to send them to
+    pub resends: Vec<(String, Packet)>,
+    /// the simulators that didn't ack a packet that was resent as often as allowed
+    pub dead: Vec<String>,
+}
+
+impl RetransmitQueue {
+    /// create a queue that resends packets up to max_resends times
+    pub fn new(max_resends: u32) -> Self {
+        RetransmitQueue {
+            packets: HashMap::new(),
+            max_resends,
+        }
+    }
+
+    /// how long to wait for an ack on a circuit with the ping, before the first resend
+    pub fn timeout(ping: Duration) -> Duration {
+        ping.saturating_mul(RETRANSMIT_PING_FACTOR)
+            .clamp(MIN_RETRANSMIT_TIMEOUT, MAX_RETRANSMIT_TIMEOUT)
+    }
+
+    /// remember a packet that was sent, if it is reliable
+    pub fn sent(&mut self, addr: &str, packet: &Packet, now: Instant) {
+        if !packet.header.reliable {
+            return;
+        }
+        self.packets.insert(
+            (addr.to_string(), packet.header.sequence_number),
+            UnackedPacket {
+                packet: packet.clone(),
+                sent: now,
+                resends: 0,
+            },
+        );
+    }
+
+    /// forget a packet the simulator acked. Returns false if it wasn't waiting for an ack.
+    pub fn ack(&mut self, addr: &str, sequence_number: u32) -> bool {
+        self.packets
+            .remove(&(addr.to_string(), sequence_number))
+            .is_some()
+    }
+
+    /// the packets to resend, and the circuits that are dead. The timeout is for the first
+    /// resend, and doubles for each one after it. The packets of a dead circuit are forgotten.
+    pub fn due(&mut self, now: Instant, timeout: Duration) -> Retransmits {
+        let mut retransmits = Retransmits::default();
+        let mut dead = HashSet::new();
+        for ((addr, _), unacked) in self.packets.iter_mut() {
+            let wait = timeout
+                .saturating_mul(2u32.saturating_pow(unacked.resends))
+                .min(MAX_RETRANSMIT_TIMEOUT);
+            if now.duration_since(unacked.sent) < wait {
+                continue;
+            }
+            if unacked.resends >= self.max_resends {
+                dead.insert(addr.clone());
+                continue;
+            }
+            unacked.resends += 1;
+            unacked.sent = now;
+            unacked.packet.header.resent = true;
+            retransmits
+                .resends
+                .push((addr.clone(), unacked.packet.clone()));
+        }
+        for addr in &dead {
+            self.circuit_closed(addr);
+        }
+        retransmits.resends.retain(|(addr, _)| !dead.contains(addr));
+        retransmits.dead = dead.into_iter().collect();
+        retransmits
+    }
+
+    /// the oldest sequence number sent to a simulator that hasn't been acked, for StartPingCheck
+    pub fn oldest_unacked(&self, addr: &str) -> Option<u32> {
+        self.packets
+            .keys()
+            .filter(|(unacked_addr, _)| unacked_addr == addr)
+            .map(|(_, sequence_number)| *sequence_number)
+            .min()
+    }
+
+    /// forget the packets sent to a circuit that has been closed
+    pub fn circuit_closed(&mut self, addr: &str) {
+        self.packets
+            .retain(|(unacked_addr, _), _| unacked_addr != addr);
+    }
+
+    /// forget every packet, for when the session ends
+    pub fn clear(&mut self) {
+        self.packets.clear();
+    }
+}
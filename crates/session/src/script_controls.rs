@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use metaverse_messages::agent_update::ControlFlags;
+use metaverse_messages::kill_object::KillObjectData;
+use metaverse_messages::object_update::ObjectUpdate;
+use metaverse_messages::script_control_change::ScriptControlChange;
+use metaverse_messages::script_controls::{CapturedControls, ScriptControls};
+use uuid::Uuid;
+
+/// the local id controls are kept under when the agent isn't sitting on anything
+pub const UNKNOWN_OBJECT: u32 = 0;
+
+/// Keeps track of the controls scripts have taken from the agent.
+/// ScriptControlChange doesn't say which object's script took the controls. Scripts take
+/// controls from the object the agent is sitting on, like a vehicle, so the controls are kept
+/// under the local id of the seat, which the avatar's ObjectUpdate parents it to. Controls taken
+/// while the agent isn't sitting are kept under UNKNOWN_OBJECT.
+/// A release doesn't say which object let go either, so it releases the controls from every
+/// object. When the seat is killed its controls are released without waiting for the
+/// simulator, since a killed object can't release them itself.
+#[derive(Debug)]
+pub struct ScriptControlManager {
+    /// the local id of the object the agent's avatar is sitting on in the current region
+    pub seat: Option<u32>,
+    /// the controls taken, by the local id of the object that took them
+    pub captured: HashMap<u32, CapturedControls>,
+}
+
+impl Default for ScriptControlManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScriptControlManager {
+    /// create a manager with no controls taken
+    pub fn new() -> Self {
+        ScriptControlManager {
+            seat: None,
+            captured: HashMap::new(),
+        }
+    }
+
+    /// follow the object the agent's avatar sits on from an ObjectUpdate of the current region
+    pub fn handle_object_update(&mut self, update: &ObjectUpdate, agent_id: Uuid) {
+        for object in &update.objects {
+            if object.full_id == agent_id {
+                self.seat = (object.parent_id != 0).then_some(object.parent_id);
+            }
+        }
+    }
+
+    /// take or release controls, returning the controls held after the change
+    pub fn handle_change(&mut self, change: &ScriptControlChange) -> ScriptControls {
+        let local_id = self.seat.unwrap_or(UNKNOWN_OBJECT);
+        for control in &change.changes {
+            if control.take_controls {
+                let captured = self.captured.entry(local_id).or_insert(CapturedControls {
+                    local_id,
+                    taken: ControlFlags::empty(),
+                    passed_to_agent: ControlFlags::empty(),
+                });
+                captured.taken |= control.controls;
+                captured
+                    .passed_to_agent
+                    .set(control.controls, control.pass_to_agent);
+            } else {
+                for captured in self.captured.values_mut() {
+                    captured.taken.remove(control.controls);
+                    captured.passed_to_agent.remove(control.controls);
+                }
+                self.captured
+                    .retain(|_, captured| !captured.taken.is_empty());
+            }
+        }
+        self.controls()
+    }
+
+    /// handle a KillObject of the current region, returning the controls held after it if any
+    /// were released
+    pub fn handle_kill(&mut self, kill: &KillObjectData) -> Option<ScriptControls> {
+        let mut changed = false;
+        for local_id in &kill.local_ids {
+            changed |= self.captured.remove(local_id).is_some();
+            if self.seat == Some(*local_id) {
+                self.seat = None;
+            }
+        }
+        changed.then(|| self.controls())
+    }
+
+    /// the controls taken by any object that shouldn't move the avatar
+    pub fn blocked(&self) -> ControlFlags {
+        self.captured
+            .values()
+            .fold(ControlFlags::empty(), |blocked, captured| {
+                blocked | (captured.taken - captured.passed_to_agent)
+            })
+    }
+
+    /// the controls of an AgentUpdate that should move the avatar in the UI. The AgentUpdate
+    /// itself is sent with all of them, so the scripts hear about them.
+    pub fn local_controls(&self, controls: ControlFlags) -> ControlFlags {
+        controls - self.blocked()
+    }
+
+    /// the controls held, to send to the UI, in the order of the local ids of the objects
+    pub fn controls(&self) -> ScriptControls {
+        let mut objects: Vec<CapturedControls> = self.captured.values().copied().collect();
+        objects.sort_by_key(|captured| captured.local_id);
+        ScriptControls {
+            objects,
+            blocked: self.blocked(),
+        }
+    }
+
+    /// release every control, for a ForceScriptControlRelease, returning true if any were held
+    pub fn release_all(&mut self) -> bool {
+        let held = !self.captured.is_empty();
+        self.captured.clear();
+        held
+    }
+
+    /// forget the controls and the seat, for when the session ends
+    pub fn clear(&mut self) {
+        self.seat = None;
+        self.captured.clear();
+    }
+}
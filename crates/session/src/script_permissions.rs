@@ -0,0 +1,75 @@
+use metaverse_messages::script_answer_yes::ScriptAnswerYes;
+use metaverse_messages::utils::script_permissions::ScriptPermissions;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Remembers the permissions the agent has granted to scripts, so they can be revoked later.
+/// Grants are kept by object and script, since every script in an object asks on its own, but
+/// RevokePermissions takes permissions back from the whole object.
+#[derive(Debug)]
+pub struct ScriptPermissionManager {
+    /// the permissions granted, by object id and script item id
+    pub granted: HashMap<(Uuid, Uuid), ScriptPermissions>,
+}
+
+impl Default for ScriptPermissionManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScriptPermissionManager {
+    /// create a manager with no permissions granted
+    pub fn new() -> Self {
+        ScriptPermissionManager {
+            granted: HashMap::new(),
+        }
+    }
+
+    /// remember the answer to a ScriptQuestion. An answer replaces what the script was granted
+    /// before, so an answer granting nothing forgets the script.
+    pub fn handle_answer(&mut self, answer: &ScriptAnswerYes) {
+        let key = (answer.task_id, answer.item_id);
+        if answer.questions.is_empty() {
+            self.granted.remove(&key);
+        } else {
+            self.granted.insert(key, answer.questions);
+        }
+    }
+
+    /// the permissions granted to a script
+    pub fn granted(&self, object_id: Uuid, item_id: Uuid) -> ScriptPermissions {
+        self.granted
+            .get(&(object_id, item_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// the permissions granted to any script in an object
+    pub fn granted_to_object(&self, object_id: Uuid) -> ScriptPermissions {
+        self.granted
+            .iter()
+            .filter(|((object, _), _)| *object == object_id)
+            .fold(ScriptPermissions::empty(), |all, (_, granted)| {
+                all | *granted
+            })
+    }
+
+    /// take permissions back from every script in an object, returning the ones that had been
+    /// granted. Scripts left without permissions are forgotten.
+    pub fn revoke(&mut self, object_id: Uuid, permissions: ScriptPermissions) -> ScriptPermissions {
+        let revoked = self.granted_to_object(object_id) & permissions;
+        self.granted.retain(|(object, _), granted| {
+            if *object == object_id {
+                granted.remove(permissions);
+            }
+            !granted.is_empty()
+        });
+        revoked
+    }
+
+    /// forget every grant, for when the session ends
+    pub fn clear(&mut self) {
+        self.granted.clear();
+    }
+}
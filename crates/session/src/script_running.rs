@@ -0,0 +1,155 @@
+use metaverse_messages::errors::{ScriptRunningError, SessionError};
+use metaverse_messages::get_script_running::GetScriptRunning;
+use metaverse_messages::script_running_reply::ScriptRunningReply;
+use metaverse_messages::script_running_status::ScriptRunningStatus;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::oneshot;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long to wait for the ScriptRunningReply to a GetScriptRunning before it fails
+pub const SCRIPT_RUNNING_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Keeps track of the GetScriptRunning requests waiting for their ScriptRunningReply.
+/// The reply only says which script it is about, so requests are kept by the object and item
+/// ids of the script. Asking about a script that is already being asked about waits for the same
+/// reply instead of sending another request.
+/// Requests fail if the simulator doesn't answer within the timeout.
+#[derive(Debug)]
+pub struct ScriptRunningManager {
+    /// the requests waiting for their reply, by the object and item ids of the script
+    pub pending: HashMap<(Uuid, Uuid), PendingScriptRunning>,
+    /// how long a request can wait for its reply
+    pub timeout: Duration,
+}
+
+/// a GetScriptRunning waiting for its ScriptRunningReply
+#[derive(Debug)]
+pub struct PendingScriptRunning {
+    /// when the request was sent
+    pub sent: Instant,
+    /// told whether the script is running, or why there is no answer. One for every time the
+    /// script was asked about.
+    pub completions: Vec<oneshot::Sender<Result<bool, ScriptRunningError>>>,
+}
+
+impl ScriptRunningManager {
+    /// create a manager that fails requests after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        ScriptRunningManager {
+            pending: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// ask whether a script is running, returning the request to send to the simulator, or None
+    /// if the script is already being asked about
+    pub fn request(
+        &mut self,
+        object_id: Uuid,
+        item_id: Uuid,
+        now: Instant,
+    ) -> (Option<GetScriptRunning>, ScriptRunningQuery) {
+        let (completion, receiver) = oneshot::channel();
+        let query = ScriptRunningQuery {
+            object_id,
+            item_id,
+            completion: receiver,
+        };
+        if let Some(pending) = self.pending.get_mut(&(object_id, item_id)) {
+            pending.completions.push(completion);
+            return (None, query);
+        }
+        self.pending.insert(
+            (object_id, item_id),
+            PendingScriptRunning {
+                sent: now,
+                completions: vec![completion],
+            },
+        );
+        (Some(GetScriptRunning { object_id, item_id }), query)
+    }
+
+    /// finish the requests a ScriptRunningReply answers, returning the event for the UI. None if
+    /// nobody asked about the script.
+    pub fn handle_reply(&mut self, reply: &ScriptRunningReply) -> Option<ScriptRunningStatus> {
+        let pending = self.pending.remove(&(reply.object_id, reply.item_id))?;
+        for completion in pending.completions {
+            let _ = completion.send(Ok(reply.running));
+        }
+        Some(ScriptRunningStatus {
+            object_id: reply.object_id,
+            item_id: reply.item_id,
+            running: Some(reply.running),
+            reason: None,
+        })
+    }
+
+    /// fail the requests that have waited longer than the timeout
+    pub fn expire(&mut self, now: Instant) -> Vec<ScriptRunningStatus> {
+        let expired: Vec<(Uuid, Uuid)> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| now.duration_since(pending.sent) >= self.timeout)
+            .map(|(key, _)| *key)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|key| self.fail(key, "timed out waiting for the simulator"))
+            .collect()
+    }
+
+    /// fail every request, for when the session ends
+    pub fn cancel_all(&mut self) -> Vec<ScriptRunningStatus> {
+        let keys: Vec<(Uuid, Uuid)> = self.pending.keys().copied().collect();
+        keys.into_iter()
+            .filter_map(|key| self.fail(key, "session ended"))
+            .collect()
+    }
+
+    fn fail(
+        &mut self,
+        (object_id, item_id): (Uuid, Uuid),
+        reason: impl Into<String>,
+    ) -> Option<ScriptRunningStatus> {
+        let pending = self.pending.remove(&(object_id, item_id))?;
+        let reason = reason.into();
+        for completion in pending.completions {
+            let _ = completion.send(Err(ScriptRunningError::new(reason.clone())));
+        }
+        Some(ScriptRunningStatus {
+            object_id,
+            item_id,
+            running: None,
+            reason: Some(reason),
+        })
+    }
+}
+
+/// Resolves to whether a script is running once the simulator answers, or to why it didn't.
+#[derive(Debug)]
+pub struct ScriptRunningQuery {
+    /// the full id of the object the script is in
+    pub object_id: Uuid,
+    /// the id of the script in the object's contents
+    pub item_id: Uuid,
+    /// told whether the script is running, or why there is no answer
+    pub completion: oneshot::Receiver<Result<bool, ScriptRunningError>>,
+}
+
+impl Future for ScriptRunningQuery {
+    type Output = Result<bool, SessionError>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.completion)
+            .poll(cx)
+            .map(|result| match result {
+                Ok(result) => result.map_err(SessionError::ScriptRunning),
+                Err(_) => Err(SessionError::ScriptRunning(ScriptRunningError::new(
+                    "request was dropped before it finished",
+                ))),
+            })
+    }
+}
@@ -0,0 +1,39 @@
+use metaverse_messages::packet::Packet;
+use std::collections::HashMap;
+
+/// Numbers the packets sent to each simulator. Simulators drop reliable packets with numbers
+/// they have already seen, so every circuit counts up from 1 on its own, and keeps counting if
+/// the agent comes back to a simulator later in the session. A resent packet keeps the number it
+/// was first sent with, so the simulator can tell it is a resend.
+#[derive(Debug, Default)]
+pub struct SequenceNumbers {
+    /// the next number to give a packet, by the address of the simulator it is sent to
+    pub next: HashMap<String, u32>,
+}
+
+impl SequenceNumbers {
+    /// create counters for a new session
+    pub fn new() -> Self {
+        SequenceNumbers {
+            next: HashMap::new(),
+        }
+    }
+
+    /// give a packet the next number for the simulator it is sent to, unless it is a resend.
+    /// Returns the packet's number.
+    pub fn stamp(&mut self, packet: &mut Packet, addr: &str) -> u32 {
+        if packet.header.resent {
+            return packet.header.sequence_number;
+        }
+        let next = self.next.entry(addr.to_string()).or_insert(1);
+        packet.header.sequence_number = *next;
+        // zero is never used, so a counter that wraps around starts again at 1
+        *next = next.checked_add(1).unwrap_or(1);
+        packet.header.sequence_number
+    }
+
+    /// forget the counters, for when the session ends
+    pub fn clear(&mut self) {
+        self.next.clear();
+    }
+}
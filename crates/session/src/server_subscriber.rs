@@ -1,17 +1,48 @@
+use crate::mailbox::{
+    AcceptFriend, AcceptTeleportOffer, AddObject, AddScript, AgentDataUpdateRequestMessage,
+    AgentThrottleMessage, AgentWearablesRequestMessage, Animate, AnswerDialog,
+    AnswerScriptQuestion, AttachItem, AttachObjects, BuyObject, CreateFolder, CreateItem, DeRez,
+    DeclineFriend, DelinkObjects, DescribeObjects, DeselectObjects, Detach, DetachObjects,
+    DropObjects, DuplicateObjects, DuplicateObjectsOnRay, EditExtraParams, EndFriendship,
+    FetchInventoryTree, FetchItems, FilterChat, GrantRights, JoinGroup, KickUserAsGod, LeaveGroup,
+    LinkObjects, LoadFriends, LoginPending, Logout, LookupGroupNames, LookupNames, Mailbox,
+    MoneyBalanceRequestMessage, Move, MoveFolders, MoveItems, Mute, OfferFriendship, Pause,
+    PurgeFolder, QueryScriptRunning, QuerySelfInfo, QueryStats, ReleaseScriptControls,
+    RemoveFolders, RemoveItems, RenameObjects, ReportUnknown, RequestAsset,
+    RequestAvatarProperties, RequestClassifiedInfo, RequestEconomyData, RequestEventInfo,
+    RequestGodPowers, RequestGroupProfile, RequestLandStat, RequestMapBlocks, RequestMapItems,
+    RequestMuteList, RequestObjectFamily, RequestParcelAccessList, RequestParcelDwell,
+    RequestPickInfo, RequestRegionInfo, RequestTextures, ResetScript, Resume,
+    RevokeScriptPermissions, RezItem, RunScript, SearchDirectory, SearchMap, SearchPeople,
+    SelectObjects, SendChat, SendEstateMessage, SendGenericMessage, SendViewerEffect, Session,
+    SetActiveGroup, SetAppearance, SetClickActions, SetFieldOfView, SetInterpolation, SetMaterials,
+    SetObjectCategories, SetObjectFaces, SetObjectFlags, SetObjectPermissions, SetRunning,
+    SetSaleInfo, SetViewportSize, SitOnObject, StandUp, TouchObject, UiMessage, Unmute,
+    UpdateInterests, UpdateItems, UpdateObjects, UpdateParcelAccessList, UpdateProfile,
+    UploadAsset, ViewportMessage,
+};
 use log::{info, warn};
+use metaverse_messages::agent_update::ControlFlags;
 use metaverse_messages::circuit_code::CircuitCodeData;
 use metaverse_messages::complete_agent_movement::CompleteAgentMovementData;
+use metaverse_messages::errors::{
+    CircuitCodeError, CompleteAgentMovementError, MailboxError, SessionError,
+};
+use metaverse_messages::improved_instant_message::InstantMessageDialog;
 use metaverse_messages::login_system::login::{login, Login};
 use metaverse_messages::login_system::login_response::LoginResponse;
 use metaverse_messages::login_system::simulator_login_protocol::SimulatorLoginProtocol;
 use metaverse_messages::packet::Packet;
 use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::purge_inventory_folder::PurgeInventoryFolder;
+use metaverse_messages::self_agent_info::SelfAgentInfo;
 use metaverse_messages::ui_events::UiEventTypes;
-use crate::mailbox::{Mailbox, Session, UiMessage};
-use metaverse_messages::errors::{
-    CircuitCodeError, CompleteAgentMovementError, MailboxError, SessionError,
-};
+use metaverse_messages::utils::region_handle::region_handle;
 use tokio::net::UdpSocket;
+use uuid::Uuid;
+
+/// the largest packet the UI can send, which is the largest UDP datagram
+const UI_MESSAGE_BUFFER_SIZE: usize = 65_535;
 
 /// This is used for the server to listen to messages coming in from the UI.
 /// Messages from the UI are sent in bytes as packets, and deserialized in the same way that they
@@ -20,6 +51,356 @@ use tokio::net::UdpSocket;
 /// https://wiki.secondlife.com/wiki/Category:Messages
 /// Messages are sent to the server using UDS.
 ///
+/// Sending a LogoutRequest packet logs out of the current session. The agent and session IDs
+/// in it are ignored, the mailbox fills them in from the session. If a login is still in
+/// progress, it is cancelled instead.
+///
+/// Sending a RequestImage packet downloads textures. The agent and session IDs are filled in the
+/// same way. Each texture is sent back as a TextureReadyEvent once every packet of it has
+/// arrived. A request with a discard level of -1 cancels the download.
+///
+/// Sending a TransferRequest for an asset downloads it with the Transfer system. The transfer ID
+/// is ignored, the mailbox picks a fresh one. The asset is sent back as an AssetReceivedEvent, or
+/// an AssetTransferFailedEvent if the simulator aborts the transfer or it times out.
+///
+/// Sending an AssetUploadRequest uploads a new asset. Assets too big to send inline are sent
+/// with the Xfer system when the simulator asks for them. The result is sent back as an
+/// AssetUploadedEvent with the transaction ID of the request, so the UI can tell which upload
+/// it is for.
+///
+/// When the simulator refuses a request because the region has turned the feature off, a
+/// FeatureDisabledEvent is sent with its explanation, the transaction ID, and what the session
+/// was doing with that transaction, like uploading an asset or rezzing an item. The operation is
+/// empty if the session doesn't know the transaction. An upload it refused fails right away,
+/// with the simulator's explanation in its AssetUploadedEvent.
+///
+/// Sending an AgentSetAppearance publishes the agent's appearance, for a UI that has baked its
+/// own textures. The agent and session IDs are filled in, and the serial number is replaced with
+/// the next one for the session.
+///
+/// Sending a UUIDNameRequest looks up the names of avatars. Names the session already knows are
+/// sent back right away as a NameResolvedEvent, and the rest are requested from the simulator
+/// and sent back as they arrive. A UUIDGroupNameRequest does the same for groups, which are
+/// sent back as a GroupNameResolvedEvent.
+///
+/// Sending a MoneyBalanceRequest asks for the agent's balance, which is sent back as a
+/// BalanceEvent. The IDs in it are ignored, the mailbox fills them in and picks a fresh
+/// transaction ID. The balance is also asked for once after login, and the simulator sends a
+/// BalanceEvent on its own whenever the balance changes.
+///
+/// Sending an ObjectSelect selects objects by their local IDs, and the simulator's answer is
+/// sent back as an ObjectPropertiesEvent with the creator, owner, name, description, permissions
+/// and sale info of each object. ObjectDeselect deselects them again. The agent and session IDs
+/// are filled in from the session.
+///
+/// Sending an ObjectAdd rezzes an object, which the simulator sends back as an ObjectUpdate.
+/// PrimBuilder fills one in for a basic prim. The agent and session IDs are filled in from the
+/// session.
+///
+/// Sending a RezObject rezzes an item from inventory, which the simulator sends back as an
+/// ObjectUpdate. RezObject::new fills one in to rez an item at a position. The agent and
+/// session IDs are filled in, and the transaction ID is replaced with a new one.
+///
+/// Sending a DeRezObject takes, deletes or returns objects by their local IDs. The agent and
+/// session IDs, the transaction ID and the packet numbers are filled in. Inside the session the
+/// DeRez message takes any number of objects, and splits them across as many packets as needed.
+///
+/// Sending an ObjectDuplicate copies objects to an offset from themselves, like shift-dragging
+/// them. Sending an ObjectDuplicateOnRay copies them to where a ray hits, for placing copies
+/// precisely. The agent and session IDs are filled in, and the active group if no group is set.
+/// The copies are sent back as ObjectUpdates.
+///
+/// Sending an ObjectLink links objects into one link set, with the first local ID as the root,
+/// so ObjectLink::new takes the root and its children separately. A link without children, or
+/// of more than 256 prims, isn't sent, and a LinkObjectsError is sent back instead. Sending an
+/// ObjectDelink unlinks objects. The agent and session IDs are filled in from the session.
+///
+/// To wear an object from inventory, send an AttachFromInventory with the item's ID and the
+/// attachment point. The item has to have been fetched, so the session can fill in the
+/// RezSingleAttachmentFromInv from it. To take one off, send a DetachAttachment with the local ID
+/// of the object or the ID of the item it was attached from. Sending an ObjectAttach attaches
+/// objects in the region, and sending an ObjectDetach or ObjectDrop detaches worn objects into
+/// inventory or drops them into the region. The session follows the ObjectUpdates of the
+/// agent's attachments, and sends the objects the agent is wearing as an AttachmentsEvent
+/// whenever they change.
+///
+/// Sending a RezScript puts a script from the agent's inventory into an object.
+/// RezScript::new fills one in from an inventory item. Sending a SetScriptRunning starts or stops
+/// a script, and a ScriptReset resets it, both by the full ID of the object and the ID of the
+/// script in its contents. Sending a GetScriptRunning asks whether a script is running, and the
+/// answer is sent back as a ScriptRunningStatusEvent, which has no answer if the simulator
+/// doesn't reply in time. The agent and session IDs are filled in from the session, and the
+/// active group if no group is set.
+///
+/// Sending an ObjectImage sets the faces of objects, with ObjectImageData::new packing a
+/// TextureEntry for each. Sending an ObjectName or ObjectDescription renames or describes
+/// objects. Names longer than 63 bytes and descriptions longer than 127 are cut down to what the
+/// simulator keeps. Each of them can edit many objects at once, and is split across as many
+/// packets as it needs. Once an edit is sent, an ObjectEditAckEvent is sent back with a warning
+/// for every name or description that was cut. The agent and session IDs are filled in from
+/// the session.
+///
+/// Sending an ObjectExtraParams sets or removes one extra param on each of a list of objects,
+/// and sending an ObjectMaterial sets their physical materials. A SetExtraParams sets the same
+/// typed param, like a FlexibleParams or LightParams, on many objects at once, and is encoded
+/// into an ObjectExtraParams. Like the edits above, they are split across as many packets as
+/// they need, and an ObjectEditAckEvent is sent back once they are sent.
+///
+/// Sending an ObjectFlagUpdate turns physics, phantom and temporary on or off for an object,
+/// and ObjectFlagUpdate::new sets them from PrimFlags. Its physics overrides are only sent when
+/// they are set, since older simulators reject them. Sending an ObjectClickAction sets what
+/// happens when objects are clicked. An ObjectEditAckEvent is sent back once either is sent.
+///
+/// Sending an ObjectPermissions sets or clears bits of one permission mask of objects, like
+/// what the next owner can do with them. ObjectPermissions::new changes the same mask of any
+/// number of objects, which are split across as many packets as they need. A permission field
+/// that isn't one of the masks can't be read, so the edit is never sent. An
+/// ObjectEditAckEvent is sent back once it is sent, and the new permissions arrive with the
+/// ObjectPropertiesEvent of the objects the next time they are selected.
+///
+/// Sending an ObjectSaleInfo puts objects up for sale with a sale type and price, or takes them
+/// off sale, and sending an ObjectCategory sets the categories they are listed under. Sale info
+/// is checked against the owner permissions from the last ObjectPropertiesEvent of each object:
+/// objects have to be transferable to be sold, and copyable to sell copies, and prices can't be
+/// negative. Objects that fail are left out, with a warning in the ObjectEditAckEvent. The
+/// objects are selected again once the edit is sent, so their new ObjectPropertiesEvent shows
+/// that it took effect.
+///
+/// Sending a QueryObjectFamily asks for the owner, group, price, name and description of an
+/// object without selecting it, like when hovering over it. The answer is sent back as an
+/// ObjectFamilyStatusEvent with the token of the query, so queries can be in flight at the same
+/// time. Objects the simulator doesn't know, or doesn't answer about in time, are sent back as
+/// not found.
+///
+/// Sending a MultipleObjectUpdate moves, rotates and scales objects, for a UI that lets objects
+/// be dragged around. The agent and session IDs are filled in from the session.
+///
+/// Sending an ObjectBuy buys objects by their local IDs, with the sale type and price the UI
+/// expects. Each object is selected first, and only bought if its ObjectProperties still match.
+/// If they don't, or the properties never arrive, a PurchaseFailedEvent is sent back instead.
+/// The simulator's answer to a purchase is sent back as a BalanceEvent. The agent and session
+/// IDs are filled in from the session.
+///
+/// Sending a MapBlockRequest asks for the regions in a rectangle of the world map, in region
+/// coordinates. The agent and session IDs are filled in from the session. The simulator answers
+/// in several packets, which are gathered by their flags and sent back together as a
+/// MapBlocksEvent. With RETURN_NONEXISTENT set in the flags, spots without a region are sent
+/// back too, with an access level of NonExistent.
+///
+/// Sending a MapItemRequest asks for the markers of one item type on a region of the world map,
+/// like the avatars in it or its land for sale. The markers are gathered and sent back together
+/// as a MapItemsEvent, tagged with their item type. The agent and session IDs are filled in from
+/// the session.
+///
+/// Sending a MapNameRequest searches the world map for regions whose names start with its name.
+/// Only the map layer is kept from the flags, the rest is used to tell the results apart from
+/// other map blocks. The regions found are sent back as a MapSearchResultsEvent, and a search
+/// that finds nothing, or isn't answered, is sent back as a MapSearchEmptyEvent.
+///
+/// Sending a ScriptDialogReply answers a ScriptDialogEvent with the button the user picked.
+/// ScriptDialog::reply fills one in for a button of the dialog, and ScriptDialog::ignore for
+/// ignoring it. The agent and session IDs are filled in from the session.
+///
+/// When a script offers a destination, it is sent as a ScriptTeleportOfferEvent with the name of
+/// the region. Sending an AcceptScriptTeleport teleports there, and ScriptTeleportRequest::accept
+/// fills one in from the offer. The region is found by its name first, and the teleport is then
+/// answered like a TeleportLocationRequest. A TeleportFailedEvent is sent back if the region
+/// can't be found.
+///
+/// Sending a ScriptAnswerYes answers a ScriptQuestionEvent with the permissions the user grants,
+/// and answering with no permissions denies them. ScriptQuestion::answer fills one in. The
+/// session remembers what each script was granted, and a RevokePermissions takes permissions
+/// back from the scripts in an object. A RevokePermissions with no permissions takes back all
+/// of them. The agent and session IDs are filled in from the session.
+///
+/// Sending a ViewerEffect shows the agent's own effects to the avatars nearby, like a beam to
+/// the object being edited. Effect::beam, Effect::look_at and Effect::point_at fill one in. The
+/// agent and session IDs are filled in from the session, and so is the agent ID of each effect
+/// that leaves it nil. The effects of other avatars are sent back as a ViewerEffectEvent.
+///
+/// Sending an AgentRequestSit sits on an object. AgentRequestSit::new fills one in. The
+/// simulator's answer is confirmed with an AgentSit, and a SitStatusEvent is sent back once the
+/// agent is seated, or if the simulator doesn't answer. Sending an AgentUpdate with the STAND_UP
+/// control flag set stands the agent up again. AgentUpdate::stand_up fills one in. The agent and
+/// session IDs are filled in from the session.
+///
+/// Sending any other AgentUpdate walks, runs, flies or stops. AgentUpdate::walking_forward,
+/// running_forward, flying, flying_forward and stopped fill one in from the camera. The session
+/// sends an update holding down controls again every MOVEMENT_INTERVAL, until an update with no
+/// controls held stops the avatar. Sending a SetAlwaysRun makes walking run. The agent and
+/// session IDs are filled in from the session.
+///
+/// Sending an AgentHeightWidth or an AgentFOV reports the size of the UI's window or its
+/// vertical field of view, which the simulator uses to decide which objects to send first.
+/// AgentHeightWidth::new and AgentFOV::new fill them in, and should be sent whenever the window
+/// is resized. The session remembers them, and sends them again to every simulator the agent
+/// teleports or crosses into. The IDs, circuit code and gen counters are filled in from the
+/// session.
+///
+/// Sending a SetVelocityInterpolation turns the simulators' interpolation of moving objects on
+/// or off. Simulators forget it whenever a circuit is restarted, so the session remembers it and
+/// sends it again after every RegionHandshake and teleport. A VelocityInterpolationEvent with the
+/// current setting is sent back, for the UI's settings.
+///
+/// Sending an AgentPause while the UI is minimized or loading stops the simulator streaming
+/// object updates, and stops the session sending the controls the agent is holding down again.
+/// Sending an AgentResume starts both again, and sends the throttles and the size of the UI's
+/// window again. The IDs and serial number are filled in from the session, and a pause while
+/// paused or a resume while not paused is ignored.
+///
+/// When a script takes the agent's controls, like a vehicle the agent sits on, the controls held
+/// are sent back as a ScriptControlsEvent, and again whenever they change. AgentUpdates are sent
+/// with every control as usual, but the controls it blocks shouldn't move the avatar in the UI.
+/// Sending a ForceScriptControlRelease takes the controls back from every script.
+///
+/// The mute list is downloaded after login, and sent back as a MuteListEvent. Chat and instant
+/// messages from the avatars, objects and names it mutes are dropped before they reach the UI.
+/// Sending a MuteListRequest downloads the list again. Sending an UpdateMuteListEntry adds an
+/// entry to the list, or changes what it mutes, and sending a RemoveMuteListEntry takes one off
+/// it. UpdateMuteListEntry::new and RemoveMuteListEntry::new fill them in. Either one sends
+/// back the new MuteListEvent. The agent and session IDs are filled in from the session.
+///
+/// Nearby chat is sent as a ChatFromSimulatorEvent, and chat relayed across the estate as a
+/// ChatPassEvent. Sending a SetChatFilter picks the channels and chat types the UI is sent, and
+/// the rest is dropped. Until then, everything on channel 0 is sent, and debug chat isn't.
+///
+/// The agent's friends are sent after login as a FriendListEvent, from the buddy list of the
+/// login response. A FriendOnlineEvent or FriendOfflineEvent follows whenever friends log in or
+/// out, only listing the ones whose presence changed.
+///
+/// Sending a FetchInventoryDescendents fetches an inventory folder and every folder inside it.
+/// Each folder is sent back as an InventoryFolderEvent once all of its contents have arrived,
+/// breadth first, so a folder always comes after its parent. FetchInventoryDescendents::new
+/// fills it in, and a nil owner is the agent. The sort order and fetch flags are ignored.
+///
+/// Sending a FetchInventory fetches single inventory items by ID. Each item is sent back as an
+/// InventoryItemEvent when it arrives. The simulator doesn't answer for items it can't find, so
+/// an item that hasn't arrived after a few seconds is sent back as not found. A nil owner is
+/// the agent.
+///
+/// Sending an UpdateInventoryItem, MoveInventoryItem or RemoveInventoryItem changes, moves or
+/// deletes inventory items. Each item is sent back as an InventoryItemEvent straight away, with
+/// the change applied. Changed and moved items are then fetched again, and sent back once more
+/// as the simulator has them, in case it refused the change. Any number of items can be sent,
+/// and the session splits them over packets. The agent and session IDs are filled in from the
+/// session.
+///
+/// Sending a CreateInventoryFolder, MoveInventoryFolder or RemoveInventoryFolder makes, moves or
+/// deletes inventory folders, and each change is sent back as an InventoryFolderStatusEvent. A
+/// new folder with a nil ID gets a new one, which comes back in the event. To empty a folder,
+/// send a PurgeInventoryFolder with confirmed set, since a purge can't be undone. Purges that
+/// aren't confirmed, including a PurgeInventoryDescendents sent directly, are refused.
+///
+/// Sending a CreateInventoryItem makes a new inventory item, like a notecard or script. The
+/// result is sent back as an InventoryItemCreatedEvent with the callback ID of the request, so
+/// the UI can tell which item it is for. The event has the new item, or why it failed if the
+/// simulator doesn't answer in time. The session sends the request with a callback ID of its
+/// own, so the UI's callback IDs don't have to be unique.
+///
+/// Sending an ImprovedInstantMessage with the FriendshipOffered dialog offers friendship to
+/// the agent it is sent to. Offers from other agents, and their answers to the agent's offers,
+/// are sent as FriendshipEvents instead of InstantMessageEvents. To answer an offer, send an
+/// AcceptFriendship or DeclineFriendship with the offer's IM session ID as the transaction ID.
+/// Answers to offers that were never made are refused. Sending a GrantUserRights changes what
+/// friends can see and do, and a TerminateFriendship ends a friendship. Whenever the friends
+/// change, the updated FriendList is sent back.
+///
+/// Sending a JoinGroupRequest or LeaveGroupRequest joins or leaves a group, and the simulator's
+/// answer is sent back as a JoinGroupEvent or LeaveGroupEvent saying whether it worked. Sending
+/// an ActivateGroup changes the agent's active group, or clears it with a nil group ID. The new
+/// active group and its title are sent back as an AgentDataEvent, and objects the agent rezzes,
+/// takes or buys are for the active group from then on.
+///
+/// Sending an AvatarPropertiesRequest asks for an avatar's profile. It is sent back in pieces:
+/// an AvatarPropertiesEvent with the profile text and picture, an AvatarInterestsEvent, and an
+/// AvatarGroupsEvent with the groups the avatar is in, with no groups if it isn't in any. The
+/// ids and names of its picks and classifieds follow as an AvatarPicksEvent and an
+/// AvatarClassifiedsEvent. Their details are asked for with a PickInfoRequest or a
+/// ClassifiedInfoRequest, and sent as a PickInfoEvent or a ClassifiedInfoEvent. An
+/// EventInfoRequest asks for an event from the events directory, sent as an EventInfoEvent.
+/// Sending an AvatarPropertiesUpdate or AvatarInterestsUpdate changes the agent's own profile.
+/// Sending a GroupProfileRequest asks for a group's details, which are sent back as a
+/// GroupProfileEvent.
+///
+/// Sending an AvatarPickerRequest searches for residents by name. The residents found are sent
+/// back as a PeopleSearchResultsEvent, and a search that finds nobody, isn't answered, or has a
+/// name too short or too long to search for is sent back as a PeopleSearchEmptyEvent. Both carry
+/// the query ID of the search, which the session picks if the request's is nil.
+///
+/// Sending a DirFindQuery searches the directory for residents or places, one page at a time.
+/// DirFindQuery::search builds one from the kind of search, the text and the start index. The
+/// page is sent back as a DirectoryPeopleEvent or a DirectoryPlacesEvent, with the start index of
+/// the next page if there is one. The next page is asked for by sending the query again with
+/// that start index.
+///
+/// Sending an EstateOwnerMessage runs an estate management command, for agents that manage the
+/// estate. Any method can be sent with its parameters, and EstateOwnerMessage has helpers for
+/// the common ones. The simulator's answers are sent back as an EstateOwnerMessageEvent. The
+/// telehub of the region is sent as a TelehubInfoEvent, and is asked for again after every
+/// telehub command that changes it.
+///
+/// Sending a GenericMessage sends any method with raw parameters. The GenericMessages the
+/// simulator sends are sent back as a GenericMessageEvent with their raw parameters.
+///
+/// Sending a RequestGodlikePowers asks for god powers, or gives them up. The god level the
+/// simulator grants is sent back as a GodPowersEvent, and kept by the session. Sending a
+/// GodKickUser kicks, freezes or unfreezes a user, and needs a god level above 0. Without one
+/// it isn't sent, and a GodPowersError is sent back instead.
+///
+/// The prices of the grid are asked for after the first RegionHandshake, and the EconomyData
+/// is sent back as an EconomyDataEvent and kept by the session. Sending an EconomyDataRequest
+/// sends the kept EconomyData back again, or asks for it if it hasn't arrived.
+///
+/// Sending a RegionInfoRequest asks for the settings of the current region, like its name,
+/// estate, agent limit and terrain limits. They are sent back as a RegionInfoEvent.
+///
+/// Sending a ParcelDwellRequest asks for the dwell of a parcel by its local ID, which is sent
+/// back as a ParcelDwellEvent. Sending a ParcelAccessListRequest asks for a parcel's access list,
+/// ban list, or both. Each list is sent back as a ParcelAccessListEvent once all of its parts
+/// have arrived. Sending a ParcelAccessListUpdate replaces one of the lists. The whole list is
+/// sent in one update, and the mailbox splits it into the sections the simulator expects.
+/// Changes to the media of the parcel are sent as a ParcelMediaUpdateEvent, and scripts
+/// playing, pausing or seeking it are sent as a ParcelMediaCommandEvent.
+///
+/// Sending a LandStatRequest asks for the top scripts or top colliders of the region, for region
+/// owners and estate managers. LandStatRequest::new builds one from the report type and a filter
+/// on object names. The report is sent back as a LandStatEvent with its entries ranked by score,
+/// once all of its pages have arrived, or marked incomplete if they stop arriving.
+///
+/// Sending an ObjectGrab touches an object by its local ID, like clicking it. The mailbox sends
+/// the grab and the ObjectDeGrab that releases it, with the agent and session IDs filled in. If
+/// the ObjectGrab has a SurfaceInfo block, its position is sent as where the object was touched.
+///
+/// When a script takes over the camera, its properties are sent as a SetFollowCamPropertiesEvent,
+/// and a ClearFollowCamPropertiesEvent when it lets go. A CameraConstraintEvent has the plane the
+/// camera has to stay in front of so it doesn't clip through walls behind the avatar.
+///
+/// In regions with damage enabled, the agent's health is sent as a HealthEvent whenever it
+/// changes, and an AgentDiedEvent is sent when it reaches 0. The statistics of the current region
+/// are sent as a SimStatsEvent, at most once a second.
+///
+/// The time of day of the current region is sent as a SimulatorTimeEvent whenever the simulator
+/// sends it, with the direction of the sun and how fast it turns, for lighting the scene.
+///
+/// Every animation an avatar plays is sent as an AvatarAnimationEvent whenever one starts or
+/// stops. The agent's own animations are started and stopped by sending an AgentAnimation with
+/// their ids, like the ones in utils::animations. Chat sent with a ChatFromViewer plays the typing
+/// animation between StartTyping and the message, unless it is turned off in the mailbox.
+///
+/// The agent's name and active group are asked for after login and after every teleport, and
+/// sent as an AgentDataEvent. A QuerySelfAgentInfo is answered with the latest of them as a
+/// SelfAgentInfoEvent.
+///
+/// Packets from simulators that the client doesn't implement are counted and otherwise dropped.
+/// Sending a ReportUnknownPackets that is enabled sends each of them to the UI as an
+/// UnknownPacketEvent, with its frequency, message number and hex encoded body, to find out
+/// what is missing. It is off to begin with.
+///
+/// The packets, bytes, resends, duplicates, acks and parse failures of each circuit are counted,
+/// along with the types of the packets that arrived. They are sent to the UI as a
+/// CircuitStatsEvent every ten seconds, with the round trip time of the latest ping, and right
+/// away when the UI sends a QueryCircuitStats.
+///
 /// Once this is running, users can send messages like
 /// ```rust
 /// use metaverse_messages::packet::Packet;
@@ -49,10 +430,17 @@ use tokio::net::UdpSocket;
 /// ```
 ///
 ///
-pub async fn listen_for_ui_messages(ui_to_server_socket: String, mailbox_addr: actix::Addr<Mailbox>) {
-    let socket = UdpSocket::bind(ui_to_server_socket).await.expect("Failed to bind to UDP socket");
+pub async fn listen_for_ui_messages(
+    ui_to_server_socket: String,
+    mailbox_addr: actix::Addr<Mailbox>,
+) {
+    let socket = UdpSocket::bind(ui_to_server_socket)
+        .await
+        .expect("Failed to bind to UDP socket");
+    // packets from the UI can be bigger than packets on the wire, like an AvatarPropertiesUpdate
+    // with several KB of profile text, so make room for the largest possible datagram
+    let mut buf = vec![0u8; UI_MESSAGE_BUFFER_SIZE];
     loop {
-        let mut buf = [0u8; 1500];
         match socket.recv_from(&mut buf).await {
             Ok((n, _)) => {
                 let packet = match Packet::from_bytes(&buf[..n]) {
@@ -62,17 +450,364 @@ pub async fn listen_for_ui_messages(ui_to_server_socket: String, mailbox_addr: a
                         continue;
                     }
                 };
-                if let PacketType::Login(login) = packet.body {
-                    match handle_login((*login).clone(), &mailbox_addr).await {
-                        Ok(_) => info!("Successfully logged in"),
-                        Err(e) => {
-                            // send the error to the UI to handle
-                            warn!("Error logging in: {:?}", e);
-                            mailbox_addr.do_send(UiMessage::new(UiEventTypes::Error, e.to_bytes()));
+                match packet.body {
+                    PacketType::Login(login) => {
+                        mailbox_addr.do_send(LoginPending(true));
+                        // log in in the background, so a logout can cancel it
+                        let mailbox_addr = mailbox_addr.clone();
+                        actix::spawn(async move {
+                            match handle_login((*login).clone(), &mailbox_addr).await {
+                                Ok(_) => info!("Successfully logged in"),
+                                Err(e) => {
+                                    // send the error to the UI to handle
+                                    warn!("Error logging in: {:?}", e);
+                                    mailbox_addr.do_send(LoginPending(false));
+                                    mailbox_addr
+                                        .do_send(UiMessage::new(UiEventTypes::Error, e.to_bytes()));
+                                }
+                            };
+                        });
+                    }
+                    PacketType::LogoutRequest(_) => mailbox_addr.do_send(Logout {}),
+                    PacketType::RequestImage(request_image) => {
+                        mailbox_addr.do_send(RequestTextures(request_image.requests))
+                    }
+                    PacketType::TransferRequest(transfer_request) => {
+                        match transfer_request.asset_params() {
+                            Ok(params) => mailbox_addr.do_send(RequestAsset {
+                                asset_id: params.asset_id,
+                                asset_type: params.asset_type,
+                                priority: transfer_request.priority,
+                            }),
+                            Err(e) => warn!("UI sent an unsupported TransferRequest: {:?}", e),
+                        }
+                    }
+                    PacketType::ObjectSelect(object_select) => {
+                        mailbox_addr.do_send(SelectObjects(object_select.local_ids))
+                    }
+                    PacketType::ObjectDeselect(object_deselect) => {
+                        mailbox_addr.do_send(DeselectObjects(object_deselect.local_ids))
+                    }
+                    PacketType::ObjectAdd(object_add) => {
+                        mailbox_addr.do_send(AddObject(*object_add))
+                    }
+                    PacketType::RezObject(rez_object) => mailbox_addr.do_send(RezItem(*rez_object)),
+                    PacketType::DeRezObject(derez_object) => mailbox_addr.do_send(DeRez {
+                        local_ids: derez_object.local_ids,
+                        destination: derez_object.destination,
+                        folder_id: derez_object.destination_id,
+                    }),
+                    PacketType::ObjectDuplicate(object_duplicate) => {
+                        mailbox_addr.do_send(DuplicateObjects(*object_duplicate))
+                    }
+                    PacketType::ObjectDuplicateOnRay(object_duplicate_on_ray) => {
+                        mailbox_addr.do_send(DuplicateObjectsOnRay(*object_duplicate_on_ray))
+                    }
+                    PacketType::ObjectLink(object_link) => mailbox_addr.do_send(LinkObjects {
+                        root_id: object_link.root_id().unwrap_or_default(),
+                        child_ids: object_link.child_ids().to_vec(),
+                    }),
+                    PacketType::ObjectDelink(object_delink) => {
+                        mailbox_addr.do_send(DelinkObjects(object_delink.local_ids))
+                    }
+                    PacketType::AttachFromInventory(attach_from_inventory) => {
+                        mailbox_addr.do_send(AttachItem(*attach_from_inventory))
+                    }
+                    PacketType::DetachAttachment(detach_attachment) => {
+                        mailbox_addr.do_send(Detach(*detach_attachment))
+                    }
+                    PacketType::ObjectAttach(object_attach) => {
+                        mailbox_addr.do_send(AttachObjects(*object_attach))
+                    }
+                    PacketType::ObjectDetach(object_detach) => {
+                        mailbox_addr.do_send(DetachObjects(object_detach.local_ids))
+                    }
+                    PacketType::ObjectDrop(object_drop) => {
+                        mailbox_addr.do_send(DropObjects(object_drop.local_ids))
+                    }
+                    PacketType::RezScript(rez_script) => {
+                        mailbox_addr.do_send(AddScript(*rez_script))
+                    }
+                    PacketType::GetScriptRunning(get_script_running) => {
+                        mailbox_addr.do_send(QueryScriptRunning {
+                            object_id: get_script_running.object_id,
+                            item_id: get_script_running.item_id,
+                        })
+                    }
+                    PacketType::SetScriptRunning(set_script_running) => {
+                        mailbox_addr.do_send(RunScript {
+                            object_id: set_script_running.object_id,
+                            item_id: set_script_running.item_id,
+                            running: set_script_running.running,
+                        })
+                    }
+                    PacketType::ScriptReset(script_reset) => mailbox_addr.do_send(ResetScript {
+                        object_id: script_reset.object_id,
+                        item_id: script_reset.item_id,
+                    }),
+                    PacketType::ObjectImage(object_image) => {
+                        mailbox_addr.do_send(SetObjectFaces(*object_image))
+                    }
+                    PacketType::ObjectName(object_name) => {
+                        mailbox_addr.do_send(RenameObjects(*object_name))
+                    }
+                    PacketType::ObjectDescription(object_description) => {
+                        mailbox_addr.do_send(DescribeObjects(*object_description))
+                    }
+                    PacketType::ObjectExtraParams(object_extra_params) => {
+                        mailbox_addr.do_send(EditExtraParams(*object_extra_params))
+                    }
+                    PacketType::SetExtraParams(set_extra_params) => mailbox_addr
+                        .do_send(EditExtraParams(set_extra_params.to_object_extra_params())),
+                    PacketType::ObjectMaterial(object_material) => {
+                        mailbox_addr.do_send(SetMaterials(*object_material))
+                    }
+                    PacketType::AgentPause(_) => mailbox_addr.do_send(Pause),
+                    PacketType::AgentResume(_) => mailbox_addr.do_send(Resume),
+                    PacketType::AgentAnimation(agent_animation) => {
+                        mailbox_addr.do_send(Animate(*agent_animation))
+                    }
+                    PacketType::ChatFromViewer(chat_from_viewer) => {
+                        mailbox_addr.do_send(SendChat(*chat_from_viewer))
+                    }
+                    PacketType::ForceScriptControlRelease(force_script_control_release) => {
+                        mailbox_addr.do_send(ReleaseScriptControls(*force_script_control_release))
+                    }
+                    PacketType::QuerySelfAgentInfo(_) => mailbox_addr.do_send(QuerySelfInfo),
+                    PacketType::QueryObjectFamily(query_object_family) => {
+                        mailbox_addr.do_send(RequestObjectFamily {
+                            object_id: query_object_family.object_id,
+                            token: query_object_family.token,
+                        })
+                    }
+                    PacketType::ObjectFlagUpdate(object_flag_update) => {
+                        mailbox_addr.do_send(SetObjectFlags(*object_flag_update))
+                    }
+                    PacketType::ObjectClickAction(object_click_action) => {
+                        mailbox_addr.do_send(SetClickActions(*object_click_action))
+                    }
+                    PacketType::ObjectPermissions(object_permissions) => {
+                        mailbox_addr.do_send(SetObjectPermissions(*object_permissions))
+                    }
+                    PacketType::ObjectSaleInfo(object_sale_info) => {
+                        mailbox_addr.do_send(SetSaleInfo(*object_sale_info))
+                    }
+                    PacketType::ObjectCategory(object_category) => {
+                        mailbox_addr.do_send(SetObjectCategories(*object_category))
+                    }
+                    PacketType::MultipleObjectUpdate(multiple_object_update) => {
+                        mailbox_addr.do_send(UpdateObjects(multiple_object_update.objects))
+                    }
+                    PacketType::ObjectBuy(object_buy) => {
+                        for object in &object_buy.objects {
+                            mailbox_addr.do_send(BuyObject {
+                                local_id: object.local_id,
+                                sale_type: object.sale_type,
+                                price: object.sale_price,
+                                category_id: object_buy.category_id,
+                            })
                         }
-                    };
-                } else {
-                    mailbox_addr.do_send(packet);
+                    }
+                    PacketType::MapBlockRequest(map_block_request) => {
+                        mailbox_addr.do_send(RequestMapBlocks(*map_block_request))
+                    }
+                    PacketType::MapItemRequest(map_item_request) => {
+                        mailbox_addr.do_send(RequestMapItems(*map_item_request))
+                    }
+                    PacketType::MapNameRequest(map_name_request) => {
+                        mailbox_addr.do_send(SearchMap(*map_name_request))
+                    }
+                    PacketType::AcceptScriptTeleport(accept_script_teleport) => {
+                        mailbox_addr.do_send(AcceptTeleportOffer(*accept_script_teleport))
+                    }
+                    PacketType::ScriptDialogReply(script_dialog_reply) => {
+                        mailbox_addr.do_send(AnswerDialog(*script_dialog_reply))
+                    }
+                    PacketType::ScriptAnswerYes(script_answer_yes) => {
+                        mailbox_addr.do_send(AnswerScriptQuestion(*script_answer_yes))
+                    }
+                    PacketType::RevokePermissions(revoke_permissions) => {
+                        mailbox_addr.do_send(RevokeScriptPermissions(*revoke_permissions))
+                    }
+                    PacketType::ViewerEffect(viewer_effect) => {
+                        mailbox_addr.do_send(SendViewerEffect(*viewer_effect))
+                    }
+                    PacketType::AgentRequestSit(agent_request_sit) => {
+                        mailbox_addr.do_send(SitOnObject(*agent_request_sit))
+                    }
+                    PacketType::AgentUpdate(update)
+                        if update.control_flags.contains(ControlFlags::STAND_UP) =>
+                    {
+                        mailbox_addr.do_send(StandUp)
+                    }
+                    PacketType::AgentUpdate(update) => mailbox_addr.do_send(Move(*update)),
+                    PacketType::SetAlwaysRun(set_always_run) => {
+                        mailbox_addr.do_send(SetRunning(*set_always_run))
+                    }
+                    PacketType::SetVelocityInterpolation(set_velocity_interpolation) => {
+                        mailbox_addr.do_send(SetInterpolation(*set_velocity_interpolation))
+                    }
+                    PacketType::ReportUnknownPackets(report_unknown_packets) => {
+                        mailbox_addr.do_send(ReportUnknown(*report_unknown_packets))
+                    }
+                    PacketType::QueryCircuitStats(_) => mailbox_addr.do_send(QueryStats),
+                    PacketType::AgentHeightWidth(agent_height_width) => {
+                        mailbox_addr.do_send(SetViewportSize(*agent_height_width))
+                    }
+                    PacketType::AgentFOV(agent_fov) => {
+                        mailbox_addr.do_send(SetFieldOfView(*agent_fov))
+                    }
+                    PacketType::MuteListRequest(_) => mailbox_addr.do_send(RequestMuteList),
+                    PacketType::UpdateMuteListEntry(update_mute_list_entry) => {
+                        mailbox_addr.do_send(Mute(*update_mute_list_entry))
+                    }
+                    PacketType::RemoveMuteListEntry(remove_mute_list_entry) => {
+                        mailbox_addr.do_send(Unmute(*remove_mute_list_entry))
+                    }
+                    PacketType::SetChatFilter(set_chat_filter) => {
+                        mailbox_addr.do_send(FilterChat(*set_chat_filter))
+                    }
+                    PacketType::FetchInventoryDescendents(fetch_inventory_descendents) => {
+                        mailbox_addr.do_send(FetchInventoryTree(*fetch_inventory_descendents))
+                    }
+                    PacketType::FetchInventory(fetch_inventory) => {
+                        mailbox_addr.do_send(FetchItems(*fetch_inventory))
+                    }
+                    PacketType::UpdateInventoryItem(update_inventory_item) => {
+                        mailbox_addr.do_send(UpdateItems(*update_inventory_item))
+                    }
+                    PacketType::MoveInventoryItem(move_inventory_item) => {
+                        mailbox_addr.do_send(MoveItems(*move_inventory_item))
+                    }
+                    PacketType::RemoveInventoryItem(remove_inventory_item) => {
+                        mailbox_addr.do_send(RemoveItems(*remove_inventory_item))
+                    }
+                    PacketType::CreateInventoryFolder(create_inventory_folder) => {
+                        mailbox_addr.do_send(CreateFolder(*create_inventory_folder))
+                    }
+                    PacketType::MoveInventoryFolder(move_inventory_folder) => {
+                        mailbox_addr.do_send(MoveFolders(*move_inventory_folder))
+                    }
+                    PacketType::RemoveInventoryFolder(remove_inventory_folder) => {
+                        mailbox_addr.do_send(RemoveFolders(*remove_inventory_folder))
+                    }
+                    PacketType::PurgeInventoryFolder(purge_inventory_folder) => {
+                        mailbox_addr.do_send(PurgeFolder(*purge_inventory_folder))
+                    }
+                    PacketType::PurgeInventoryDescendents(purge_inventory_descendents) => {
+                        mailbox_addr.do_send(PurgeFolder(PurgeInventoryFolder {
+                            folder_id: purge_inventory_descendents.folder_id,
+                            confirmed: false,
+                        }))
+                    }
+                    PacketType::CreateInventoryItem(create_inventory_item) => {
+                        mailbox_addr.do_send(CreateItem(*create_inventory_item))
+                    }
+                    PacketType::ImprovedInstantMessage(improved_instant_message)
+                        if improved_instant_message.dialog
+                            == InstantMessageDialog::FriendshipOffered =>
+                    {
+                        mailbox_addr.do_send(OfferFriendship(*improved_instant_message))
+                    }
+                    PacketType::AcceptFriendship(accept_friendship) => {
+                        mailbox_addr.do_send(AcceptFriend(*accept_friendship))
+                    }
+                    PacketType::DeclineFriendship(decline_friendship) => {
+                        mailbox_addr.do_send(DeclineFriend(*decline_friendship))
+                    }
+                    PacketType::GrantUserRights(grant_user_rights) => {
+                        mailbox_addr.do_send(GrantRights(*grant_user_rights))
+                    }
+                    PacketType::TerminateFriendship(terminate_friendship) => {
+                        mailbox_addr.do_send(EndFriendship(*terminate_friendship))
+                    }
+                    PacketType::JoinGroupRequest(join_group_request) => {
+                        mailbox_addr.do_send(JoinGroup(*join_group_request))
+                    }
+                    PacketType::LeaveGroupRequest(leave_group_request) => {
+                        mailbox_addr.do_send(LeaveGroup(*leave_group_request))
+                    }
+                    PacketType::ActivateGroup(activate_group) => {
+                        mailbox_addr.do_send(SetActiveGroup(*activate_group))
+                    }
+                    PacketType::AvatarPropertiesRequest(avatar_properties_request) => {
+                        mailbox_addr.do_send(RequestAvatarProperties(*avatar_properties_request))
+                    }
+                    PacketType::PickInfoRequest(pick_info_request) => {
+                        mailbox_addr.do_send(RequestPickInfo(*pick_info_request))
+                    }
+                    PacketType::ClassifiedInfoRequest(classified_info_request) => {
+                        mailbox_addr.do_send(RequestClassifiedInfo(*classified_info_request))
+                    }
+                    PacketType::EventInfoRequest(event_info_request) => {
+                        mailbox_addr.do_send(RequestEventInfo(*event_info_request))
+                    }
+                    PacketType::AvatarPropertiesUpdate(avatar_properties_update) => {
+                        mailbox_addr.do_send(UpdateProfile(*avatar_properties_update))
+                    }
+                    PacketType::AvatarInterestsUpdate(avatar_interests_update) => {
+                        mailbox_addr.do_send(UpdateInterests(*avatar_interests_update))
+                    }
+                    PacketType::GroupProfileRequest(group_profile_request) => {
+                        mailbox_addr.do_send(RequestGroupProfile(*group_profile_request))
+                    }
+                    PacketType::AvatarPickerRequest(avatar_picker_request) => {
+                        mailbox_addr.do_send(SearchPeople(*avatar_picker_request))
+                    }
+                    PacketType::DirFindQuery(dir_find_query) => {
+                        mailbox_addr.do_send(SearchDirectory(*dir_find_query))
+                    }
+                    PacketType::EstateOwnerMessage(estate_owner_message) => {
+                        mailbox_addr.do_send(SendEstateMessage(*estate_owner_message))
+                    }
+                    PacketType::GenericMessage(generic_message) => {
+                        mailbox_addr.do_send(SendGenericMessage(*generic_message))
+                    }
+                    PacketType::RequestGodlikePowers(request_godlike_powers) => {
+                        mailbox_addr.do_send(RequestGodPowers(*request_godlike_powers))
+                    }
+                    PacketType::GodKickUser(god_kick_user) => {
+                        mailbox_addr.do_send(KickUserAsGod(*god_kick_user))
+                    }
+                    PacketType::EconomyDataRequest(_) => mailbox_addr.do_send(RequestEconomyData),
+                    PacketType::RegionInfoRequest(_) => mailbox_addr.do_send(RequestRegionInfo),
+                    PacketType::LandStatRequest(land_stat_request) => {
+                        mailbox_addr.do_send(RequestLandStat(*land_stat_request))
+                    }
+                    PacketType::ParcelDwellRequest(parcel_dwell_request) => {
+                        mailbox_addr.do_send(RequestParcelDwell(*parcel_dwell_request))
+                    }
+                    PacketType::ParcelAccessListRequest(parcel_access_list_request) => {
+                        mailbox_addr.do_send(RequestParcelAccessList(*parcel_access_list_request))
+                    }
+                    PacketType::ParcelAccessListUpdate(parcel_access_list_update) => {
+                        mailbox_addr.do_send(UpdateParcelAccessList(*parcel_access_list_update))
+                    }
+                    PacketType::ObjectGrab(object_grab) => mailbox_addr.do_send(TouchObject {
+                        local_id: object_grab.local_id,
+                        position: object_grab.surface_info.first().map(|hit| hit.position),
+                    }),
+                    PacketType::MoneyBalanceRequest(_) => {
+                        mailbox_addr.do_send(MoneyBalanceRequestMessage {})
+                    }
+                    PacketType::AgentSetAppearance(agent_set_appearance) => {
+                        mailbox_addr.do_send(SetAppearance(*agent_set_appearance))
+                    }
+                    PacketType::UUIDNameRequest(uuid_name_request) => {
+                        mailbox_addr.do_send(LookupNames {
+                            ids: uuid_name_request.ids,
+                            send_cached: true,
+                        })
+                    }
+                    PacketType::UUIDGroupNameRequest(uuid_group_name_request) => mailbox_addr
+                        .do_send(LookupGroupNames {
+                            ids: uuid_group_name_request.ids,
+                            send_cached: true,
+                        }),
+                    PacketType::AssetUploadRequest(asset_upload_request) => {
+                        mailbox_addr.do_send(UploadAsset(*asset_upload_request))
+                    }
+                    _ => mailbox_addr.do_send(packet),
                 }
             }
             Err(e) => {
@@ -118,6 +853,22 @@ async fn handle_login(
             url: login_response.sim_ip.unwrap(),
             agent_id: login_response.agent_id.unwrap(),
             session_id: login_response.session_id.unwrap(),
+            secure_session_id: login_response.secure_session_id.unwrap_or_default(),
+            circuit_code: login_response.circuit_code,
+            region_handle: region_handle(
+                login_response.region_x.unwrap_or_default() as u32,
+                login_response.region_y.unwrap_or_default() as u32,
+            ),
+            wearables_serial_num: 0,
+            appearance_serial_num: 0,
+            active_group_id: Uuid::nil(),
+            self_info: SelfAgentInfo::new(
+                login_response.agent_id.unwrap(),
+                login_response.first_name.clone(),
+                login_response.last_name.clone(),
+            ),
+            god_level: 0,
+            economy: None,
             socket: None,
         })
         .await
@@ -125,6 +876,14 @@ async fn handle_login(
         return Err(SessionError::Mailbox(MailboxError::new(format!("{}", e))));
     };
 
+    // the friends are known before the simulator says which of them are online
+    if let Err(e) = mailbox_addr
+        .send(LoadFriends(login_response.buddy_list.unwrap_or_default()))
+        .await
+    {
+        return Err(SessionError::Mailbox(MailboxError::new(format!("{}", e))));
+    };
+
     if let Err(e) = mailbox_addr
         .send(Packet::new_circuit_code(CircuitCodeData {
             code: login_response.circuit_code,
@@ -154,5 +913,35 @@ async fn handle_login(
         ));
     };
 
+    // the agent's own wearables aren't sent until they are asked for
+    if let Err(e) = mailbox_addr.send(AgentWearablesRequestMessage {}).await {
+        return Err(SessionError::Mailbox(MailboxError::new(format!("{}", e))));
+    };
+
+    // the name the grid knows the agent by, and its active group
+    if let Err(e) = mailbox_addr.send(AgentDataUpdateRequestMessage {}).await {
+        return Err(SessionError::Mailbox(MailboxError::new(format!("{}", e))));
+    };
+
+    // the balance isn't sent until it is asked for, or until it changes
+    if let Err(e) = mailbox_addr.send(MoneyBalanceRequestMessage {}).await {
+        return Err(SessionError::Mailbox(MailboxError::new(format!("{}", e))));
+    };
+
+    // chat from muted avatars is dropped once the mute list has arrived
+    if let Err(e) = mailbox_addr.send(RequestMuteList {}).await {
+        return Err(SessionError::Mailbox(MailboxError::new(format!("{}", e))));
+    };
+
+    // the simulator barely sends anything until it knows how much bandwidth to use
+    if let Err(e) = mailbox_addr.send(AgentThrottleMessage {}).await {
+        return Err(SessionError::Mailbox(MailboxError::new(format!("{}", e))));
+    };
+
+    // a viewport the UI reported before logging in
+    if let Err(e) = mailbox_addr.send(ViewportMessage {}).await {
+        return Err(SessionError::Mailbox(MailboxError::new(format!("{}", e))));
+    };
+
     Ok(())
 }
@@ -0,0 +1,62 @@
+use metaverse_messages::sim_stats::SimStats;
+use tokio::time::{Duration, Instant};
+
+/// how often SimStats are sent on to the UI at most
+pub const SIM_STATS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Keeps the SimStats of the current region from flooding the UI.
+/// Simulators send SimStats about once a second, but some send them more often, and they keep
+/// coming as long as the agent is in the region. At most one is sent on to the UI every
+/// interval. The ones arriving in between replace each other, and the last of them is sent once
+/// the interval is over, so the UI always ends up with the latest statistics.
+#[derive(Debug)]
+pub struct SimStatsThrottle {
+    /// when the last SimStats was sent on
+    pub last_sent: Option<Instant>,
+    /// the latest SimStats that arrived too soon after the last one sent
+    pub held: Option<SimStats>,
+    /// how often SimStats are sent on at most
+    pub interval: Duration,
+}
+
+impl SimStatsThrottle {
+    /// create a throttle that sends SimStats on at most once every interval
+    pub fn new(interval: Duration) -> Self {
+        SimStatsThrottle {
+            last_sent: None,
+            held: None,
+            interval,
+        }
+    }
+
+    /// handle a SimStats, returning it if it can be sent on right away
+    pub fn handle_stats(&mut self, stats: SimStats, now: Instant) -> Option<SimStats> {
+        if self.is_due(now) {
+            self.last_sent = Some(now);
+            self.held = None;
+            return Some(stats);
+        }
+        self.held = Some(stats);
+        None
+    }
+
+    /// the held SimStats, once the interval since the last one sent is over
+    pub fn flush(&mut self, now: Instant) -> Option<SimStats> {
+        if self.held.is_none() || !self.is_due(now) {
+            return None;
+        }
+        self.last_sent = Some(now);
+        self.held.take()
+    }
+
+    /// forget the held SimStats, for when the agent leaves the region or the session ends
+    pub fn clear(&mut self) {
+        self.last_sent = None;
+        self.held = None;
+    }
+
+    fn is_due(&self, now: Instant) -> bool {
+        self.last_sent
+            .is_none_or(|last_sent| now.duration_since(last_sent) >= self.interval)
+    }
+}
@@ -0,0 +1,93 @@
+use metaverse_messages::avatar_sit_response::AvatarSitResponse;
+use metaverse_messages::sit_status::SitStatus;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long to wait for the AvatarSitResponse to an AgentRequestSit
+pub const SIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Keeps track of sitting on objects.
+/// Sitting starts with an AgentRequestSit. The simulator answers with an AvatarSitResponse
+/// saying where the avatar will sit, which is confirmed with an AgentSit. If there is no room on
+/// the object, the simulator doesn't answer at all, so a request that isn't answered within the
+/// timeout has failed.
+/// Only one request is waiting at a time. The answer can name a different object than the one
+/// asked for, so any answer is taken as the answer to the waiting request.
+#[derive(Debug)]
+pub struct SitManager {
+    /// the request waiting for its AvatarSitResponse
+    pub pending: Option<PendingSit>,
+    /// the object the agent is sitting on
+    pub seated_on: Option<Uuid>,
+    /// how long to wait for the AvatarSitResponse
+    pub timeout: Duration,
+}
+
+/// an AgentRequestSit that hasn't been answered
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingSit {
+    /// the object the UI asked to sit on
+    pub object_id: Uuid,
+    /// when the request was sent
+    pub sent: Instant,
+}
+
+impl SitManager {
+    /// create a manager that gives up on requests after the timeout
+    pub fn new(timeout: Duration) -> Self {
+        SitManager {
+            pending: None,
+            seated_on: None,
+            timeout,
+        }
+    }
+
+    /// start sitting on an object, returning the failure of the request it replaces
+    pub fn request(&mut self, object_id: Uuid, now: Instant) -> Option<SitStatus> {
+        let replaced = self.pending.replace(PendingSit {
+            object_id,
+            sent: now,
+        });
+        replaced.map(|replaced| failed(replaced.object_id, "replaced by another request to sit"))
+    }
+
+    /// handle an AvatarSitResponse, returning the status to send once the AgentSit is sent
+    pub fn handle_response(&mut self, response: AvatarSitResponse) -> SitStatus {
+        self.pending = None;
+        self.seated_on = Some(response.object_id);
+        SitStatus::Seated(response)
+    }
+
+    /// give up on the request if it hasn't been answered within the timeout
+    pub fn expire(&mut self, now: Instant) -> Option<SitStatus> {
+        let pending = self.pending.as_ref()?;
+        if now.duration_since(pending.sent) < self.timeout {
+            return None;
+        }
+        self.pending
+            .take()
+            .map(|pending| failed(pending.object_id, "the simulator didn't answer"))
+    }
+
+    /// stand up, returning the failure of the request waiting for its answer
+    pub fn stand(&mut self) -> Option<SitStatus> {
+        self.seated_on = None;
+        self.pending
+            .take()
+            .map(|pending| failed(pending.object_id, "stood up"))
+    }
+
+    /// forget the request and the object sat on, for when the session ends
+    pub fn clear(&mut self) {
+        self.pending = None;
+        self.seated_on = None;
+    }
+}
+
+// the status for a request to sit that failed
+fn failed(object_id: Uuid, reason: &str) -> SitStatus {
+    SitStatus::Failed {
+        object_id,
+        reason: reason.to_string(),
+    }
+}
@@ -0,0 +1,47 @@
+use metaverse_messages::preload_sound::PreloadSound;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Keeps the PreloadSound hints sent to the UI down to one per sound.
+/// Objects playing looping sounds ask for them to be preloaded over and over, but the UI only
+/// needs to download a sound once, so sounds that have already been sent on in this session are
+/// dropped from later hints.
+#[derive(Debug)]
+pub struct SoundPreloadManager {
+    /// the sounds that have been sent to the UI
+    pub reported: HashSet<Uuid>,
+}
+
+impl Default for SoundPreloadManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SoundPreloadManager {
+    /// create a manager that hasn't sent any sounds
+    pub fn new() -> Self {
+        SoundPreloadManager {
+            reported: HashSet::new(),
+        }
+    }
+
+    /// the sounds of a PreloadSound that haven't been sent to the UI yet, or None if there are
+    /// none. A sound that is in the packet twice is only kept the first time.
+    pub fn handle_preload(&mut self, preload: PreloadSound) -> Option<PreloadSound> {
+        let sounds: Vec<_> = preload
+            .sounds
+            .into_iter()
+            .filter(|sound| self.reported.insert(sound.sound_id))
+            .collect();
+        if sounds.is_empty() {
+            return None;
+        }
+        Some(PreloadSound { sounds })
+    }
+
+    /// forget the sounds that have been sent, for when the session ends
+    pub fn clear(&mut self) {
+        self.reported.clear();
+    }
+}
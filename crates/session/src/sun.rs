@@ -0,0 +1,47 @@
+use glam::{Quat, Vec3};
+use metaverse_messages::simulator_viewer_time_message::SimulatorViewerTimeMessage;
+use tokio::time::Instant;
+
+/// Keeps the time of day of the current region, from its latest SimulatorViewerTimeMessage.
+/// Simulators only send these every so often, and the sun keeps turning in between, so the
+/// direction of the sun at any moment is worked out from the direction in the latest message and
+/// how far the angular velocity has turned it since the message arrived.
+/// Every region has its own sun, so the time is forgotten when the agent changes regions, until
+/// the new region sends its own.
+#[derive(Debug)]
+pub struct SunTracker {
+    /// the latest SimulatorViewerTimeMessage of the current region, and when it arrived
+    pub latest: Option<(SimulatorViewerTimeMessage, Instant)>,
+}
+
+impl Default for SunTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SunTracker {
+    /// create a tracker that doesn't know the time yet
+    pub fn new() -> Self {
+        SunTracker { latest: None }
+    }
+
+    /// keep a SimulatorViewerTimeMessage of the current region as the latest
+    pub fn handle_time(&mut self, time: SimulatorViewerTimeMessage, now: Instant) {
+        self.latest = Some((time, now));
+    }
+
+    /// the direction of the sun at a moment, advanced from the latest SimulatorViewerTimeMessage
+    /// by its angular velocity. None if the region hasn't sent one.
+    pub fn current_sun_direction(&self, now: Instant) -> Option<Vec3> {
+        let (time, received) = self.latest.as_ref()?;
+        let elapsed = now.saturating_duration_since(*received).as_secs_f32();
+        let rotation = Quat::from_scaled_axis(time.sun_ang_velocity * elapsed);
+        Some((rotation * time.sun_direction).normalize_or_zero())
+    }
+
+    /// forget the time, for when the agent leaves the region or the session ends
+    pub fn clear(&mut self) {
+        self.latest = None;
+    }
+}
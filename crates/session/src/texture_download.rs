@@ -0,0 +1,226 @@
+use log::warn;
+use metaverse_messages::image_data::{ImageCodec, ImageData};
+use metaverse_messages::image_packet::ImagePacket;
+use metaverse_messages::request_image::ImageRequest;
+use metaverse_messages::texture_ready::TextureReady;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long a texture download can go without receiving a packet before it is requested again
+pub const TEXTURE_TIMEOUT: Duration = Duration::from_secs(5);
+/// how many times a texture is requested before the download is abandoned
+pub const TEXTURE_ATTEMPTS: u8 = 3;
+
+/// Keeps track of the textures being downloaded with RequestImage, and assembles the ImageData
+/// and ImagePackets the simulator sends back into whole images.
+/// Packets can arrive in any order and more than once. Downloads that stop receiving packets are
+/// requested again from the first missing packet.
+#[derive(Debug)]
+pub struct TextureDownloadManager {
+    /// the downloads in progress, by texture id
+    pub downloads: HashMap<Uuid, TextureDownload>,
+    /// how long a download can go without a packet before it is requested again
+    pub timeout: Duration,
+    /// how many times a texture is requested before the download is abandoned
+    pub max_attempts: u8,
+}
+
+/// a texture that is being downloaded
+#[derive(Debug)]
+pub struct TextureDownload {
+    /// the request that started the download
+    pub request: ImageRequest,
+    /// the format of the image, once the ImageData has arrived
+    pub codec: Option<ImageCodec>,
+    /// size of the whole image in bytes, from the ImageData
+    pub size: u32,
+    /// number of packets in the image, from the ImageData
+    pub total_packets: u16,
+    /// the data received so far, by packet number. The ImageData is packet 0.
+    pub chunks: HashMap<u16, Vec<u8>>,
+    /// when the download was requested or last received a packet
+    pub last_activity: Instant,
+    /// how many times the texture has been requested
+    pub attempts: u8,
+}
+
+impl TextureDownload {
+    /// the packet numbers that haven't arrived yet. Before the ImageData arrives the size of the
+    /// image isn't known, so only the ImageData is counted as missing.
+    pub fn missing_packets(&self) -> Vec<u16> {
+        if self.codec.is_none() {
+            return vec![0];
+        }
+        (0..self.total_packets)
+            .filter(|packet| !self.chunks.contains_key(packet))
+            .collect()
+    }
+
+    fn is_complete(&self) -> bool {
+        self.codec.is_some() && self.missing_packets().is_empty()
+    }
+}
+
+impl TextureDownloadManager {
+    /// create a manager that requests stalled downloads again after the timeout
+    pub fn new(timeout: Duration, max_attempts: u8) -> Self {
+        TextureDownloadManager {
+            downloads: HashMap::new(),
+            timeout,
+            max_attempts,
+        }
+    }
+
+    /// start downloading a texture. Returns false if the texture is already being downloaded
+    /// with the same discard level and priority, so there is no need to send the request.
+    pub fn request(&mut self, request: ImageRequest, now: Instant) -> bool {
+        if let Some(download) = self.downloads.get_mut(&request.texture_id) {
+            if download.request.discard_level == request.discard_level
+                && download.request.download_priority == request.download_priority
+            {
+                return false;
+            }
+            download.request = request;
+            download.last_activity = now;
+            return true;
+        }
+        self.downloads.insert(
+            request.texture_id,
+            TextureDownload {
+                request,
+                codec: None,
+                size: 0,
+                total_packets: 0,
+                chunks: HashMap::new(),
+                last_activity: now,
+                attempts: 1,
+            },
+        );
+        true
+    }
+
+    /// stop downloading a texture. Returns false if it wasn't being downloaded.
+    /// Packets for the texture that are still in flight are ignored.
+    pub fn cancel(&mut self, texture_id: &Uuid) -> bool {
+        self.downloads.remove(texture_id).is_some()
+    }
+
+    /// stop every download
+    pub fn clear(&mut self) {
+        self.downloads.clear();
+    }
+
+    /// handle the first packet of a texture, returning the texture if it is complete
+    pub fn handle_image_data(
+        &mut self,
+        image_data: ImageData,
+        now: Instant,
+    ) -> Option<TextureReady> {
+        let download = match self.downloads.get_mut(&image_data.texture_id) {
+            Some(download) => download,
+            None => {
+                warn!(
+                    "received ImageData for texture {} that isn't downloading",
+                    image_data.texture_id
+                );
+                return None;
+            }
+        };
+        download.last_activity = now;
+        if download.codec.is_none() {
+            download.codec = Some(image_data.codec);
+            download.size = image_data.size;
+            download.total_packets = image_data.packets;
+            // packets that arrived before the ImageData might be past the end of the image
+            let total_packets = download.total_packets;
+            download.chunks.retain(|packet, _| *packet < total_packets);
+        }
+        download.chunks.entry(0).or_insert(image_data.data);
+        self.complete(image_data.texture_id)
+    }
+
+    /// handle a continuation packet of a texture, returning the texture if it is complete
+    pub fn handle_image_packet(
+        &mut self,
+        image_packet: ImagePacket,
+        now: Instant,
+    ) -> Option<TextureReady> {
+        let download = match self.downloads.get_mut(&image_packet.texture_id) {
+            Some(download) => download,
+            None => {
+                warn!(
+                    "received ImagePacket for texture {} that isn't downloading",
+                    image_packet.texture_id
+                );
+                return None;
+            }
+        };
+        download.last_activity = now;
+        if image_packet.packet == 0
+            || (download.codec.is_some() && image_packet.packet >= download.total_packets)
+        {
+            warn!(
+                "received invalid ImagePacket {} for texture {}",
+                image_packet.packet, image_packet.texture_id
+            );
+            return None;
+        }
+        download
+            .chunks
+            .entry(image_packet.packet)
+            .or_insert(image_packet.data);
+        self.complete(image_packet.texture_id)
+    }
+
+    /// find the downloads that haven't received a packet within the timeout, and return the
+    /// requests to send again. They start from the first missing packet, so the simulator doesn't
+    /// resend the whole image. Downloads that have run out of attempts are abandoned.
+    pub fn retry(&mut self, now: Instant) -> Vec<ImageRequest> {
+        let mut requests = Vec::new();
+        let timeout = self.timeout;
+        let max_attempts = self.max_attempts;
+        self.downloads.retain(|texture_id, download| {
+            if now.duration_since(download.last_activity) < timeout {
+                return true;
+            }
+            if download.attempts >= max_attempts {
+                warn!("abandoning download of texture {}", texture_id);
+                return false;
+            }
+            download.attempts += 1;
+            download.last_activity = now;
+            let mut request = download.request.clone();
+            request.packet = download.missing_packets().first().copied().unwrap_or(0) as u32;
+            requests.push(request);
+            true
+        });
+        requests
+    }
+
+    // assemble the texture and finish the download if every packet has arrived
+    fn complete(&mut self, texture_id: Uuid) -> Option<TextureReady> {
+        if !self.downloads.get(&texture_id)?.is_complete() {
+            return None;
+        }
+        let mut download = self.downloads.remove(&texture_id)?;
+        let mut data = Vec::with_capacity(download.size as usize);
+        for packet in 0..download.total_packets {
+            data.extend(download.chunks.remove(&packet)?);
+        }
+        if data.len() != download.size as usize {
+            warn!(
+                "texture {} is {} bytes, but the ImageData said {}",
+                texture_id,
+                data.len(),
+                download.size
+            );
+            data.truncate(download.size as usize);
+        }
+        Some(TextureReady {
+            texture_id,
+            codec: download.codec?,
+            data,
+        })
+    }
+}
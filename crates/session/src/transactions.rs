@@ -0,0 +1,76 @@
+use metaverse_messages::feature_disabled::FeatureDisabled;
+use metaverse_messages::feature_disabled_notice::FeatureDisabledNotice;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long a transaction is remembered. Most requests never get an answer that names their
+/// transaction, so they are forgotten once the simulator has had plenty of time to refuse them.
+pub const TRANSACTION_LIFETIME: Duration = Duration::from_secs(60);
+
+/// Remembers what the session was doing with the transaction ids it sent, like uploading an
+/// asset or rezzing an item, so a FeatureDisabled from the simulator can tell the UI which
+/// request it refused. Transactions are forgotten when they finish, or after the lifetime.
+#[derive(Debug)]
+pub struct TransactionRegistry {
+    /// the transactions, by id
+    pub transactions: HashMap<Uuid, Transaction>,
+    /// how long a transaction is remembered
+    pub lifetime: Duration,
+}
+
+/// a request the session sent with a transaction id
+#[derive(Debug)]
+pub struct Transaction {
+    /// human readable description of the request
+    pub operation: String,
+    /// when the request was sent
+    pub started: Instant,
+}
+
+impl TransactionRegistry {
+    /// create a registry that forgets transactions after the lifetime
+    pub fn new(lifetime: Duration) -> Self {
+        TransactionRegistry {
+            transactions: HashMap::new(),
+            lifetime,
+        }
+    }
+
+    /// remember what a transaction is for. Transactions older than the lifetime are forgotten.
+    pub fn register(&mut self, transaction_id: Uuid, operation: impl Into<String>, now: Instant) {
+        let lifetime = self.lifetime;
+        self.transactions
+            .retain(|_, transaction| now.duration_since(transaction.started) < lifetime);
+        self.transactions.insert(
+            transaction_id,
+            Transaction {
+                operation: operation.into(),
+                started: now,
+            },
+        );
+    }
+
+    /// forget a transaction that has finished
+    pub fn complete(&mut self, transaction_id: &Uuid) {
+        self.transactions.remove(transaction_id);
+    }
+
+    /// the event for the UI, with the operation the FeatureDisabled refused if the transaction
+    /// is known. The transaction is forgotten, since it won't finish.
+    pub fn handle_feature_disabled(&mut self, disabled: &FeatureDisabled) -> FeatureDisabledNotice {
+        FeatureDisabledNotice {
+            transaction_id: disabled.transaction_id,
+            operation: self
+                .transactions
+                .remove(&disabled.transaction_id)
+                .map(|transaction| transaction.operation),
+            message: disabled.error_message.clone(),
+        }
+    }
+
+    /// forget every transaction, for when the session ends
+    pub fn clear(&mut self) {
+        self.transactions.clear();
+    }
+}
@@ -0,0 +1,51 @@
+use metaverse_messages::agent_animation::AgentAnimation;
+use metaverse_messages::chat_from_viewer::{ChatFromViewer, ClientChatType};
+use metaverse_messages::utils::animations::ANIM_TYPE;
+
+/// Plays the typing animation on the agent's avatar while the agent writes in chat, like other
+/// viewers do.
+/// The UI sends a ChatFromViewer with StartTyping when the agent starts writing, and StopTyping
+/// when it stops. The animation is started with the first and stopped with the second, or with
+/// the message itself if the UI doesn't send StopTyping before it. Only the chat on the public
+/// channel is written where others can see it, so other channels leave the animation alone.
+#[derive(Debug)]
+pub struct TypingAnimation {
+    /// false to leave the typing animation to the UI
+    pub enabled: bool,
+    /// true if the typing animation was started and hasn't been stopped
+    pub typing: bool,
+}
+
+impl TypingAnimation {
+    /// create a tracker that starts the typing animation automatically if enabled
+    pub fn new(enabled: bool) -> Self {
+        TypingAnimation {
+            enabled,
+            typing: false,
+        }
+    }
+
+    /// handle a chat message sent by the UI, returning the AgentAnimation to send with it, if any
+    pub fn handle_chat(&mut self, chat: &ChatFromViewer) -> Option<AgentAnimation> {
+        if !self.enabled || chat.channel != 0 {
+            return None;
+        }
+        match chat.message_type {
+            ClientChatType::StartTyping if !self.typing => {
+                self.typing = true;
+                Some(AgentAnimation::start(&[ANIM_TYPE]))
+            }
+            ClientChatType::StartTyping | ClientChatType::Debug | ClientChatType::Unknown => None,
+            _ if self.typing => {
+                self.typing = false;
+                Some(AgentAnimation::stop(&[ANIM_TYPE]))
+            }
+            _ => None,
+        }
+    }
+
+    /// forget the animation, for when the session ends
+    pub fn clear(&mut self) {
+        self.typing = false;
+    }
+}
@@ -0,0 +1,434 @@
+use actix::MessageResponse;
+use log::warn;
+use metaverse_messages::abort_xfer::AbortXfer;
+use metaverse_messages::confirm_xfer_packet::ConfirmXferPacket;
+use metaverse_messages::errors::{SessionError, XferError};
+use metaverse_messages::request_xfer::RequestXfer;
+use metaverse_messages::send_xfer_packet::SendXferPacket;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::oneshot;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long to wait for a ConfirmXferPacket before sending the packet again
+pub const XFER_TIMEOUT: Duration = Duration::from_secs(3);
+/// how many times a packet is sent before the upload is aborted
+pub const XFER_ATTEMPTS: u32 = 5;
+/// how long to wait for the next packet of a download before aborting it
+pub const XFER_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// how the simulator names the file it wants in its RequestXfer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum XferKey {
+    /// the file is requested by name
+    FileName(String),
+    /// the file is requested by its VFileID, when the file name is empty
+    VFileId(Uuid),
+}
+impl XferKey {
+    /// the key a RequestXfer is asking for
+    pub fn from_request(request: &RequestXfer) -> Self {
+        if request.file_name.is_empty() {
+            XferKey::VFileId(request.vfile_id)
+        } else {
+            XferKey::FileName(request.file_name.clone())
+        }
+    }
+}
+
+/// Keeps track of files being uploaded with the Xfer system.
+/// Files are registered before the simulator asks for them, and wait until a RequestXfer names
+/// them. The simulator then pulls the file one packet at a time, confirming each packet before
+/// the next one is sent. Packets that aren't confirmed are sent again, and the upload is aborted
+/// once a packet has been sent max_attempts times.
+#[derive(Debug)]
+pub struct XferSender {
+    /// files waiting for the simulator to request them
+    pub pending: HashMap<XferKey, PendingXfer>,
+    /// files being sent, by xfer id
+    pub active: HashMap<u64, ActiveXfer>,
+    /// how long to wait for a confirmation before sending a packet again
+    pub timeout: Duration,
+    /// how many times a packet is sent before the upload is aborted
+    pub max_attempts: u32,
+}
+
+/// a file that has been registered, but not requested yet
+#[derive(Debug)]
+pub struct PendingXfer {
+    /// the contents of the file
+    pub data: Vec<u8>,
+    /// told whether the upload finished or failed
+    pub completion: oneshot::Sender<Result<(), XferError>>,
+}
+
+/// a file the simulator is pulling
+#[derive(Debug)]
+pub struct ActiveXfer {
+    /// the contents of the file
+    pub data: Vec<u8>,
+    /// told whether the upload finished or failed
+    pub completion: oneshot::Sender<Result<(), XferError>>,
+    /// the packet number waiting to be confirmed
+    pub packet: u32,
+    /// when the packet waiting to be confirmed was last sent
+    pub last_sent: Instant,
+    /// how many times the packet waiting to be confirmed has been sent
+    pub attempts: u32,
+}
+
+/// the number of packets a file is sent in. An empty file is still sent as one packet, which
+/// only contains the size.
+pub fn packet_count(size: usize) -> u32 {
+    size.div_ceil(SendXferPacket::CHUNK_SIZE).max(1) as u32
+}
+
+/// build the SendXferPacket for a packet number of a file.
+/// The first packet starts with the size of the file, and the last packet has the last packet
+/// flag set on its packet id.
+pub fn xfer_packet(id: u64, data: &[u8], packet_number: u32) -> SendXferPacket {
+    let start = (packet_number as usize * SendXferPacket::CHUNK_SIZE).min(data.len());
+    let end = (start + SendXferPacket::CHUNK_SIZE).min(data.len());
+    let mut chunk = Vec::with_capacity(end - start + 4);
+    if packet_number == 0 {
+        chunk.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    }
+    chunk.extend_from_slice(&data[start..end]);
+    let last = packet_number + 1 >= packet_count(data.len());
+    SendXferPacket {
+        id,
+        packet: SendXferPacket::packet_id(packet_number, last),
+        data: chunk,
+    }
+}
+
+impl XferSender {
+    /// create a sender that resends unconfirmed packets after the timeout
+    pub fn new(timeout: Duration, max_attempts: u32) -> Self {
+        XferSender {
+            pending: HashMap::new(),
+            active: HashMap::new(),
+            timeout,
+            max_attempts,
+        }
+    }
+
+    /// register a file for the simulator to request. The receiver is told when the upload
+    /// finishes or fails. Registering a key again replaces the file that was waiting.
+    pub fn register(
+        &mut self,
+        key: XferKey,
+        data: Vec<u8>,
+    ) -> oneshot::Receiver<Result<(), XferError>> {
+        let (completion, receiver) = oneshot::channel();
+        if let Some(replaced) = self.pending.insert(key, PendingXfer { data, completion }) {
+            let _ = replaced
+                .completion
+                .send(Err(XferError::new("replaced by a newer upload")));
+        }
+        receiver
+    }
+
+    /// start sending a requested file, returning its first packet.
+    /// Returns None if the file was never registered. If the simulator asks again for a file it
+    /// is already pulling, the packet waiting to be confirmed is sent again.
+    pub fn handle_request(
+        &mut self,
+        request: &RequestXfer,
+        now: Instant,
+    ) -> Option<SendXferPacket> {
+        if let Some(xfer) = self.active.get_mut(&request.id) {
+            xfer.last_sent = now;
+            return Some(xfer_packet(request.id, &xfer.data, xfer.packet));
+        }
+        let pending = self.pending.remove(&XferKey::from_request(request))?;
+        let packet = xfer_packet(request.id, &pending.data, 0);
+        self.active.insert(
+            request.id,
+            ActiveXfer {
+                data: pending.data,
+                completion: pending.completion,
+                packet: 0,
+                last_sent: now,
+                attempts: 1,
+            },
+        );
+        Some(packet)
+    }
+
+    /// handle a confirmation, returning the next packet to send.
+    /// Confirming the last packet finishes the upload. Confirmations for any other packet than
+    /// the one waiting are ignored.
+    pub fn handle_confirm(
+        &mut self,
+        confirm: &ConfirmXferPacket,
+        now: Instant,
+    ) -> Option<SendXferPacket> {
+        let xfer = self.active.get_mut(&confirm.id)?;
+        let packet_number = confirm.packet & !SendXferPacket::LAST_PACKET_FLAG;
+        if packet_number != xfer.packet {
+            return None;
+        }
+        if packet_number + 1 >= packet_count(xfer.data.len()) {
+            if let Some(xfer) = self.active.remove(&confirm.id) {
+                let _ = xfer.completion.send(Ok(()));
+            }
+            return None;
+        }
+        xfer.packet += 1;
+        xfer.last_sent = now;
+        xfer.attempts = 1;
+        Some(xfer_packet(confirm.id, &xfer.data, xfer.packet))
+    }
+
+    /// fail an upload the simulator aborted
+    pub fn handle_abort(&mut self, abort: &AbortXfer) {
+        match self.active.remove(&abort.id) {
+            Some(xfer) => {
+                let _ = xfer.completion.send(Err(XferError::new(format!(
+                    "simulator aborted xfer {} with result {}",
+                    abort.id, abort.result
+                ))));
+            }
+            None => warn!("received AbortXfer for unknown xfer {}", abort.id),
+        }
+    }
+
+    /// send the packets that haven't been confirmed within the timeout again.
+    /// Uploads that have run out of attempts fail, and an AbortXfer is returned for each of them
+    /// to tell the simulator.
+    pub fn retry(&mut self, now: Instant) -> (Vec<SendXferPacket>, Vec<AbortXfer>) {
+        let mut resend = Vec::new();
+        let mut aborted = Vec::new();
+        for (id, xfer) in self.active.iter_mut() {
+            if now.duration_since(xfer.last_sent) < self.timeout {
+                continue;
+            }
+            if xfer.attempts >= self.max_attempts {
+                aborted.push(*id);
+                continue;
+            }
+            xfer.attempts += 1;
+            xfer.last_sent = now;
+            resend.push(xfer_packet(*id, &xfer.data, xfer.packet));
+        }
+        let aborted = aborted
+            .into_iter()
+            .filter_map(|id| self.active.remove(&id).map(|xfer| (id, xfer)))
+            .map(|(id, xfer)| {
+                let _ = xfer.completion.send(Err(XferError::new(format!(
+                    "xfer {} timed out waiting for packet {} to be confirmed",
+                    id, xfer.packet
+                ))));
+                AbortXfer {
+                    id,
+                    result: AbortXfer::RESULT_TIMEOUT,
+                }
+            })
+            .collect();
+        (resend, aborted)
+    }
+
+    /// fail every upload, for when the session ends
+    pub fn cancel_all(&mut self) {
+        for (_, pending) in self.pending.drain() {
+            let _ = pending.completion.send(Err(XferError::new(
+                "session ended before the upload was requested",
+            )));
+        }
+        for (_, active) in self.active.drain() {
+            let _ = active
+                .completion
+                .send(Err(XferError::new("session ended during the upload")));
+        }
+    }
+}
+
+/// Keeps track of files being downloaded from the simulator with the Xfer system.
+/// A download starts with a RequestXfer for the file name, with an id picked here. The simulator
+/// sends the file back in SendXferPackets, and sends each one again until it is confirmed, so a
+/// packet that arrives twice is confirmed again but only kept once. Packets are kept in order,
+/// and a packet that skips ahead is left unconfirmed for the simulator to send again.
+/// A download that hasn't had a packet within the timeout is aborted.
+#[derive(Debug)]
+pub struct XferReceiver {
+    /// files being downloaded, by xfer id
+    pub active: HashMap<u64, IncomingXfer>,
+    /// how long to wait for the next packet
+    pub timeout: Duration,
+}
+
+/// a file the simulator is sending
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncomingXfer {
+    /// the name the file was requested by
+    pub file_name: String,
+    /// the file, as far as it has arrived
+    pub data: Vec<u8>,
+    /// the size of the file, from the start of its first packet
+    pub size: Option<u32>,
+    /// the packet number expected next
+    pub packet: u32,
+    /// when the last packet arrived, or when the file was requested
+    pub last_received: Instant,
+}
+
+/// a file that has finished downloading
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadedXfer {
+    /// the id of the transfer
+    pub id: u64,
+    /// the name the file was requested by
+    pub file_name: String,
+    /// the contents of the file
+    pub data: Vec<u8>,
+}
+
+impl XferReceiver {
+    /// create a receiver that aborts downloads once no packet has arrived for the timeout
+    pub fn new(timeout: Duration) -> Self {
+        XferReceiver {
+            active: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// start downloading a file by name, returning the RequestXfer to send for it.
+    /// The id is random, so it can't be mistaken for the id of an upload the simulator picked.
+    pub fn request(
+        &mut self,
+        file_name: String,
+        file_path: u8,
+        delete_on_completion: bool,
+        now: Instant,
+    ) -> RequestXfer {
+        let mut id = Uuid::new_v4().as_u64_pair().0;
+        while self.active.contains_key(&id) {
+            id = Uuid::new_v4().as_u64_pair().0;
+        }
+        self.active.insert(
+            id,
+            IncomingXfer {
+                file_name: file_name.clone(),
+                data: Vec::new(),
+                size: None,
+                packet: 0,
+                last_received: now,
+            },
+        );
+        RequestXfer {
+            id,
+            file_name,
+            file_path,
+            delete_on_completion,
+            use_big_packets: false,
+            vfile_id: Uuid::nil(),
+            vfile_type: RequestXfer::VFILE_TYPE_NONE,
+        }
+    }
+
+    /// handle a packet of a download, returning the confirmation to send for it, and the file
+    /// if it was the last packet. Returns None for packets of unknown downloads, packets that
+    /// skip ahead, and a first packet too short to hold the size.
+    pub fn handle_packet(
+        &mut self,
+        packet: &SendXferPacket,
+        now: Instant,
+    ) -> Option<(ConfirmXferPacket, Option<DownloadedXfer>)> {
+        let xfer = self.active.get_mut(&packet.id)?;
+        let packet_number = packet.packet_number();
+        let confirm = ConfirmXferPacket {
+            id: packet.id,
+            packet: packet_number,
+        };
+        if packet_number < xfer.packet {
+            return Some((confirm, None));
+        }
+        if packet_number > xfer.packet {
+            return None;
+        }
+        let mut data = &packet.data[..];
+        if packet_number == 0 {
+            let size = data.get(..4)?;
+            xfer.size = Some(u32::from_le_bytes([size[0], size[1], size[2], size[3]]));
+            data = &data[4..];
+        }
+        xfer.data.extend_from_slice(data);
+        xfer.packet += 1;
+        xfer.last_received = now;
+        if !packet.is_last() {
+            return Some((confirm, None));
+        }
+        let xfer = self.active.remove(&packet.id)?;
+        let mut data = xfer.data;
+        if let Some(size) = xfer.size {
+            data.truncate(size as usize);
+        }
+        let file = DownloadedXfer {
+            id: packet.id,
+            file_name: xfer.file_name,
+            data,
+        };
+        Some((confirm, Some(file)))
+    }
+
+    /// stop a download the simulator aborted, returning it if it was one of ours
+    pub fn handle_abort(&mut self, abort: &AbortXfer) -> Option<IncomingXfer> {
+        self.active.remove(&abort.id)
+    }
+
+    /// abort the downloads that haven't had a packet within the timeout, returning the
+    /// AbortXfer to send for each of them with the name of its file
+    pub fn expire(&mut self, now: Instant) -> Vec<(String, AbortXfer)> {
+        let expired: Vec<u64> = self
+            .active
+            .iter()
+            .filter(|(_, xfer)| now.duration_since(xfer.last_received) >= self.timeout)
+            .map(|(id, _)| *id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|id| self.active.remove(&id).map(|xfer| (id, xfer)))
+            .map(|(id, xfer)| {
+                (
+                    xfer.file_name,
+                    AbortXfer {
+                        id,
+                        result: AbortXfer::RESULT_TIMEOUT,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// forget every download, for when the session ends
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+}
+
+/// Resolves when a file started with the Mailbox's start_xfer has been uploaded, or has failed.
+#[derive(Debug, MessageResponse)]
+pub struct XferUpload {
+    /// the VFileID the simulator will request the file by, if it wasn't given a file name
+    pub vfile_id: Uuid,
+    /// told whether the upload finished or failed
+    pub completion: oneshot::Receiver<Result<(), XferError>>,
+}
+
+impl Future for XferUpload {
+    type Output = Result<(), SessionError>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.completion)
+            .poll(cx)
+            .map(|result| match result {
+                Ok(result) => result.map_err(SessionError::Xfer),
+                Err(_) => Err(SessionError::Xfer(XferError::new(
+                    "xfer was dropped before it finished",
+                ))),
+            })
+    }
+}
@@ -0,0 +1,153 @@
+use metaverse_messages::transfer_info::TransferInfo;
+use metaverse_messages::transfer_packet::TransferPacket;
+use metaverse_messages::transfer_request::TransferRequest;
+use metaverse_messages::utils::asset_type::AssetType;
+use metaverse_messages::utils::transfer::{ChannelType, TargetType, TransferStatus};
+use metaverse_session::asset_transfer::AssetTransferManager;
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+// answers a TransferRequest the way a simulator would, with a TransferInfo and the asset split
+// into TransferPackets
+fn mock_sim(request: &TransferRequest, asset: &[u8]) -> (TransferInfo, Vec<TransferPacket>) {
+    let transfer_info = TransferInfo {
+        transfer_id: request.transfer_id,
+        channel_type: ChannelType::Asset,
+        target_type: TargetType::Unknown,
+        status: TransferStatus::Ok,
+        size: asset.len() as i32,
+        params: request.params.clone(),
+    };
+    let chunks: Vec<&[u8]> = asset.chunks(TransferPacket::PACKET_SIZE).collect();
+    let packets = chunks
+        .iter()
+        .enumerate()
+        .map(|(packet, data)| TransferPacket {
+            transfer_id: request.transfer_id,
+            channel_type: ChannelType::Asset,
+            packet: packet as i32,
+            status: if packet == chunks.len() - 1 {
+                TransferStatus::Done
+            } else {
+                TransferStatus::Ok
+            },
+            data: data.to_vec(),
+        })
+        .collect();
+    (transfer_info, packets)
+}
+
+fn notecard() -> Vec<u8> {
+    (0..3500).map(|i| (i % 253) as u8).collect()
+}
+
+#[test]
+fn test_asset_transfer_out_of_order() {
+    let mut manager = AssetTransferManager::new(TIMEOUT);
+    let now = Instant::now();
+    let asset_id = Uuid::new_v4();
+    let request = manager.request(asset_id, AssetType::Notecard, 100.0, now);
+    let params = request.asset_params().unwrap();
+    assert_eq!(params.asset_id, asset_id);
+    assert_eq!(params.asset_type, AssetType::Notecard);
+
+    let (transfer_info, packets) = mock_sim(&request, &notecard());
+    assert_eq!(packets.len(), 4);
+    assert!(manager.handle_transfer_info(transfer_info, now).is_none());
+
+    // the last packet arrives first, and one packet arrives twice
+    for packet in [3, 1, 1, 0] {
+        assert!(manager
+            .handle_transfer_packet(packets[packet].clone(), now)
+            .is_none());
+    }
+    let asset = manager
+        .handle_transfer_packet(packets[2].clone(), now)
+        .unwrap()
+        .unwrap();
+    assert_eq!(asset.transfer_id, request.transfer_id);
+    assert_eq!(asset.asset_id, asset_id);
+    assert_eq!(asset.asset_type, AssetType::Notecard);
+    assert_eq!(asset.data, notecard());
+    assert!(manager.transfers.is_empty());
+
+    // packets after the transfer finished are ignored
+    assert!(manager
+        .handle_transfer_packet(packets[2].clone(), now)
+        .is_none());
+}
+
+#[test]
+fn test_asset_transfer_fresh_ids() {
+    let mut manager = AssetTransferManager::new(TIMEOUT);
+    let now = Instant::now();
+    let asset_id = Uuid::new_v4();
+    let first = manager.request(asset_id, AssetType::Sound, 1.0, now);
+    let second = manager.request(asset_id, AssetType::Sound, 1.0, now);
+    assert_ne!(first.transfer_id, second.transfer_id);
+    assert_eq!(manager.transfers.len(), 2);
+}
+
+#[test]
+fn test_asset_transfer_not_found() {
+    let mut manager = AssetTransferManager::new(TIMEOUT);
+    let now = Instant::now();
+    let asset_id = Uuid::new_v4();
+    let request = manager.request(asset_id, AssetType::Animation, 1.0, now);
+
+    let (mut transfer_info, _) = mock_sim(&request, &[]);
+    transfer_info.status = TransferStatus::NotFound;
+    let failed = manager.handle_transfer_info(transfer_info, now).unwrap();
+    assert_eq!(failed.transfer_id, request.transfer_id);
+    assert_eq!(failed.asset_id, asset_id);
+    assert_eq!(failed.asset_type, AssetType::Animation);
+    assert!(manager.transfers.is_empty());
+}
+
+#[test]
+fn test_asset_transfer_aborted() {
+    let mut manager = AssetTransferManager::new(TIMEOUT);
+    let now = Instant::now();
+    let request = manager.request(Uuid::new_v4(), AssetType::Notecard, 1.0, now);
+
+    let (transfer_info, mut packets) = mock_sim(&request, &notecard());
+    manager.handle_transfer_info(transfer_info, now);
+    manager.handle_transfer_packet(packets[0].clone(), now);
+    packets[1].status = TransferStatus::Abort;
+    match manager.handle_transfer_packet(packets[1].clone(), now) {
+        Some(Err(failed)) => assert_eq!(failed.transfer_id, request.transfer_id),
+        result => panic!("expected the transfer to fail, got {:?}", result),
+    }
+    assert!(manager.transfers.is_empty());
+}
+
+#[test]
+fn test_asset_transfer_timeout() {
+    let mut manager = AssetTransferManager::new(TIMEOUT);
+    let start = Instant::now();
+    let request = manager.request(Uuid::new_v4(), AssetType::Notecard, 1.0, start);
+
+    let (transfer_info, packets) = mock_sim(&request, &notecard());
+    manager.handle_transfer_info(transfer_info, start);
+    manager.handle_transfer_packet(packets[0].clone(), start + Duration::from_secs(10));
+
+    // packets keep the transfer alive
+    assert!(manager.expire(start + TIMEOUT).is_empty());
+    let failed = manager.expire(start + Duration::from_secs(10) + TIMEOUT);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].transfer_id, request.transfer_id);
+    assert!(manager.transfers.is_empty());
+}
+
+#[test]
+fn test_asset_transfer_cancel_all() {
+    let mut manager = AssetTransferManager::new(TIMEOUT);
+    let now = Instant::now();
+    manager.request(Uuid::new_v4(), AssetType::Notecard, 1.0, now);
+    manager.request(Uuid::new_v4(), AssetType::Sound, 1.0, now);
+    assert_eq!(manager.cancel_all().len(), 2);
+    assert!(manager.transfers.is_empty());
+}
@@ -0,0 +1,186 @@
+use metaverse_messages::asset_upload_complete::AssetUploadComplete;
+use metaverse_messages::asset_upload_request::AssetUploadRequest;
+use metaverse_messages::confirm_xfer_packet::ConfirmXferPacket;
+use metaverse_messages::request_xfer::RequestXfer;
+use metaverse_messages::utils::asset_type::AssetType;
+use metaverse_session::asset_upload::{AssetUploadManager, ASSET_INLINE_SIZE};
+use metaverse_session::xfer::{XferKey, XferSender};
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn upload_request(size: usize) -> AssetUploadRequest {
+    AssetUploadRequest {
+        transaction_id: Uuid::new_v4(),
+        asset_type: AssetType::Notecard,
+        temp_file: false,
+        store_local: false,
+        asset_data: vec![7; size],
+    }
+}
+
+fn complete(asset_id: Uuid, success: bool) -> AssetUploadComplete {
+    AssetUploadComplete {
+        asset_id,
+        asset_type: AssetType::Notecard,
+        success,
+    }
+}
+
+#[test]
+fn test_small_asset_is_sent_inline() {
+    let now = Instant::now();
+    let mut uploads = AssetUploadManager::new(TIMEOUT);
+    let mut xfers = XferSender::new(Duration::from_secs(3), 5);
+    let secure_session_id = Uuid::new_v4();
+
+    let (request, mut upload) = uploads.request(
+        upload_request(ASSET_INLINE_SIZE),
+        secure_session_id,
+        &mut xfers,
+        now,
+    );
+    assert_eq!(request.asset_data.len(), ASSET_INLINE_SIZE);
+    assert!(xfers.pending.is_empty());
+    assert_eq!(
+        upload.asset_id,
+        AssetUploadRequest::asset_id(request.transaction_id, secure_session_id)
+    );
+
+    let uploaded = uploads
+        .handle_upload_complete(&complete(upload.asset_id, true))
+        .unwrap();
+    assert!(uploaded.success);
+    assert_eq!(uploaded.transaction_id, request.transaction_id);
+    assert_eq!(
+        upload.completion.try_recv().unwrap().unwrap(),
+        upload.asset_id
+    );
+}
+
+#[test]
+fn test_large_asset_is_sent_with_xfer() {
+    let now = Instant::now();
+    let mut uploads = AssetUploadManager::new(TIMEOUT);
+    let mut xfers = XferSender::new(Duration::from_secs(3), 5);
+
+    let (request, mut upload) = uploads.request(
+        upload_request(ASSET_INLINE_SIZE + 1),
+        Uuid::new_v4(),
+        &mut xfers,
+        now,
+    );
+    assert!(request.asset_data.is_empty());
+    assert_eq!(
+        xfers.pending[&XferKey::VFileId(upload.asset_id)].data.len(),
+        ASSET_INLINE_SIZE + 1
+    );
+
+    // the simulator pulls the asset by its id
+    let first = xfers
+        .handle_request(
+            &RequestXfer {
+                id: 3,
+                file_name: String::new(),
+                file_path: 0,
+                delete_on_completion: false,
+                use_big_packets: false,
+                vfile_id: upload.asset_id,
+                vfile_type: AssetType::Notecard.to_bytes() as i16,
+            },
+            now,
+        )
+        .unwrap();
+    let last = xfers
+        .handle_confirm(&ConfirmXferPacket { id: 3, packet: 0 }, now)
+        .unwrap();
+    assert!(!first.is_last());
+    assert!(last.is_last());
+    assert!(xfers
+        .handle_confirm(&ConfirmXferPacket { id: 3, packet: 1 }, now)
+        .is_none());
+
+    // the upload waits for AssetUploadComplete once the Xfer is done
+    assert!(uploads.expire(&mut xfers, now).is_empty());
+    assert!(uploads.uploads[&upload.asset_id].xfer.is_none());
+
+    uploads
+        .handle_upload_complete(&complete(upload.asset_id, true))
+        .unwrap();
+    assert!(upload.completion.try_recv().unwrap().is_ok());
+}
+
+#[test]
+fn test_failed_upload_complete() {
+    let now = Instant::now();
+    let mut uploads = AssetUploadManager::new(TIMEOUT);
+    let mut xfers = XferSender::new(Duration::from_secs(3), 5);
+    let (_, mut upload) = uploads.request(upload_request(10), Uuid::new_v4(), &mut xfers, now);
+
+    let uploaded = uploads
+        .handle_upload_complete(&complete(upload.asset_id, false))
+        .unwrap();
+    assert!(!uploaded.success);
+    assert!(uploaded.reason.is_some());
+    assert!(upload.completion.try_recv().unwrap().is_err());
+    assert!(uploads
+        .handle_upload_complete(&complete(upload.asset_id, true))
+        .is_none());
+}
+
+#[test]
+fn test_unanswered_upload_expires() {
+    let now = Instant::now();
+    let mut uploads = AssetUploadManager::new(TIMEOUT);
+    let mut xfers = XferSender::new(Duration::from_secs(3), 5);
+    let (_, mut inline) = uploads.request(upload_request(10), Uuid::new_v4(), &mut xfers, now);
+    let (_, mut xfer) = uploads.request(
+        upload_request(ASSET_INLINE_SIZE * 2),
+        Uuid::new_v4(),
+        &mut xfers,
+        now,
+    );
+
+    assert!(uploads.expire(&mut xfers, now + TIMEOUT / 2).is_empty());
+    let expired = uploads.expire(&mut xfers, now + TIMEOUT);
+    assert_eq!(expired.len(), 2);
+    assert!(inline.completion.try_recv().unwrap().is_err());
+    assert!(xfer.completion.try_recv().unwrap().is_err());
+    // the Xfer the simulator never asked for is dropped too
+    assert!(xfers.pending.is_empty());
+}
+
+#[test]
+fn test_failed_xfer_fails_upload() {
+    let now = Instant::now();
+    let mut uploads = AssetUploadManager::new(TIMEOUT);
+    let mut xfers = XferSender::new(Duration::from_secs(3), 5);
+    let (_, mut upload) = uploads.request(
+        upload_request(ASSET_INLINE_SIZE * 2),
+        Uuid::new_v4(),
+        &mut xfers,
+        now,
+    );
+
+    xfers.cancel_all();
+    let expired = uploads.expire(&mut xfers, now);
+    assert_eq!(expired.len(), 1);
+    assert!(!expired[0].success);
+    assert!(upload.completion.try_recv().unwrap().is_err());
+}
+
+#[test]
+fn test_cancel_all_reports_every_upload() {
+    let now = Instant::now();
+    let mut uploads = AssetUploadManager::new(TIMEOUT);
+    let mut xfers = XferSender::new(Duration::from_secs(3), 5);
+    uploads.request(upload_request(10), Uuid::new_v4(), &mut xfers, now);
+    uploads.request(upload_request(20), Uuid::new_v4(), &mut xfers, now);
+
+    let cancelled = uploads.cancel_all();
+    assert_eq!(cancelled.len(), 2);
+    assert!(cancelled.iter().all(|uploaded| !uploaded.success));
+    assert!(uploads.uploads.is_empty());
+}
@@ -0,0 +1,171 @@
+use glam::Vec3;
+use metaverse_messages::kill_object::KillObjectData;
+use metaverse_messages::object_update::{ObjectUpdate, ObjectUpdateData, PCode, PrimShape};
+use metaverse_messages::utils::attachment_point::AttachmentPoint;
+use metaverse_session::attachments::AttachmentManager;
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const HAT_ITEM_ID: Uuid = uuid!("c1a5b6e2-51f3-4b0f-9d3b-7c61f0b0a2d4");
+const WATCH_ITEM_ID: Uuid = uuid!("5748decc-f629-461c-9a36-a35a221fe21f");
+const AGENT_LOCAL_ID: u32 = 1001;
+
+fn object(
+    local_id: u32,
+    full_id: Uuid,
+    parent_id: u32,
+    state: u8,
+    name_value: &str,
+) -> ObjectUpdateData {
+    ObjectUpdateData {
+        local_id,
+        state,
+        full_id,
+        crc: 0,
+        pcode: if full_id == AGENT_ID {
+            PCode::Avatar
+        } else {
+            PCode::Primitive
+        },
+        material: 3,
+        click_action: 0,
+        scale: Vec3::ONE,
+        object_data: Vec::new(),
+        parent_id,
+        update_flags: 0,
+        shape: PrimShape::default(),
+        texture_entry: Vec::new(),
+        texture_anim: Vec::new(),
+        name_value: name_value.to_string(),
+        data: Vec::new(),
+        text: String::new(),
+        text_color: [0; 4],
+        media_url: String::new(),
+        ps_block: Vec::new(),
+        extra_params: Vec::new(),
+        sound: Uuid::nil(),
+        owner_id: AGENT_ID,
+        gain: 0.0,
+        flags: 0,
+        radius: 0.0,
+        joint_type: 0,
+        joint_pivot: Vec3::ZERO,
+        joint_axis_or_anchor: Vec3::ZERO,
+    }
+}
+
+fn update(objects: Vec<ObjectUpdateData>) -> ObjectUpdate {
+    ObjectUpdate {
+        region_handle: 0,
+        time_dilation: 0,
+        objects,
+    }
+}
+
+fn avatar() -> ObjectUpdateData {
+    object(
+        AGENT_LOCAL_ID,
+        AGENT_ID,
+        0,
+        0,
+        "FirstName STRING RW SV Default\nLastName STRING RW SV User",
+    )
+}
+
+// an object worn by the agent, as the simulator sends it
+fn worn(local_id: u32, item_id: Uuid, point: AttachmentPoint) -> ObjectUpdateData {
+    object(
+        local_id,
+        Uuid::new_v4(),
+        AGENT_LOCAL_ID,
+        point.to_bytes().rotate_left(4),
+        &format!("AttachItemID STRING RW SV {}", item_id),
+    )
+}
+
+// a manager that has seen the agent's avatar
+fn tracking() -> AttachmentManager {
+    let mut attachments = AttachmentManager::new();
+    assert!(!attachments.handle_object_update(&update(vec![avatar()]), AGENT_ID));
+    assert_eq!(attachments.agent_local_id, Some(AGENT_LOCAL_ID));
+    attachments
+}
+
+#[test]
+fn test_worn_objects_are_listed() {
+    let mut attachments = tracking();
+    let watch = worn(2001, WATCH_ITEM_ID, AttachmentPoint::LeftForearm);
+    let hat = worn(2002, HAT_ITEM_ID, AttachmentPoint::Skull);
+    assert!(attachments.handle_object_update(&update(vec![watch, hat.clone()]), AGENT_ID));
+
+    let list = attachments.list().attachments;
+    assert_eq!(list.len(), 2);
+    // in the order of the attachment points
+    assert_eq!(list[0].item_id, HAT_ITEM_ID);
+    assert_eq!(list[0].attachment_point, AttachmentPoint::Skull);
+    assert_eq!(list[0].object_id, hat.full_id);
+    assert_eq!(list[1].local_id, 2001);
+    assert_eq!(list[1].attachment_point, AttachmentPoint::LeftForearm);
+    assert_eq!(attachments.local_id_of_item(HAT_ITEM_ID), Some(2002));
+
+    // the same update again changes nothing
+    assert!(!attachments.handle_object_update(&update(vec![hat]), AGENT_ID));
+}
+
+#[test]
+fn test_the_avatar_can_arrive_with_its_attachments() {
+    let mut attachments = AttachmentManager::new();
+    let hat = worn(2002, HAT_ITEM_ID, AttachmentPoint::Skull);
+    assert!(attachments.handle_object_update(&update(vec![hat, avatar()]), AGENT_ID));
+    assert_eq!(attachments.local_id_of_item(HAT_ITEM_ID), Some(2002));
+}
+
+#[test]
+fn test_other_objects_are_not_worn() {
+    let mut attachments = tracking();
+    // another avatar's attachment, and a prim linked to something else
+    let other = object(
+        3001,
+        Uuid::new_v4(),
+        4000,
+        0x20,
+        &format!("AttachItemID STRING RW SV {}", HAT_ITEM_ID),
+    );
+    let prim = object(3002, Uuid::new_v4(), AGENT_LOCAL_ID, 0, "");
+    assert!(!attachments.handle_object_update(&update(vec![other, prim]), AGENT_ID));
+    assert!(attachments.list().attachments.is_empty());
+    assert_eq!(attachments.local_id_of_item(HAT_ITEM_ID), None);
+}
+
+#[test]
+fn test_detached_objects_are_forgotten() {
+    let mut attachments = tracking();
+    let hat = worn(2002, HAT_ITEM_ID, AttachmentPoint::Skull);
+    let watch = worn(2001, WATCH_ITEM_ID, AttachmentPoint::LeftForearm);
+    attachments.handle_object_update(&update(vec![hat.clone(), watch]), AGENT_ID);
+
+    // dropped into the region, the hat is no longer parented to the avatar
+    let mut dropped = hat;
+    dropped.parent_id = 0;
+    dropped.name_value = String::new();
+    assert!(attachments.handle_object_update(&update(vec![dropped]), AGENT_ID));
+    assert_eq!(attachments.local_id_of_item(HAT_ITEM_ID), None);
+
+    // detached into inventory, the watch is killed
+    let kill = KillObjectData {
+        local_ids: vec![2001, 9999],
+    };
+    assert!(attachments.handle_kill(&kill));
+    assert!(attachments.list().attachments.is_empty());
+    assert!(!attachments.handle_kill(&kill));
+}
+
+#[test]
+fn test_changing_regions_forgets_local_ids() {
+    let mut attachments = tracking();
+    let hat = worn(2002, HAT_ITEM_ID, AttachmentPoint::Skull);
+    attachments.handle_object_update(&update(vec![hat]), AGENT_ID);
+    attachments.change_region();
+    assert_eq!(attachments.agent_local_id, None);
+    assert!(attachments.list().attachments.is_empty());
+}
@@ -0,0 +1,110 @@
+use glam::Vec3;
+use metaverse_messages::chat_from_simulator::{Audible, ChatFromSimulator, ChatType, SourceType};
+use metaverse_messages::chat_pass::{ChatPass, DEBUG_CHANNEL};
+use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::set_chat_filter::SetChatFilter;
+use metaverse_messages::utils::agent_access::AgentAccess;
+use metaverse_session::chat_filter::ChatFilter;
+use uuid::Uuid;
+
+fn chat(chat_type: ChatType) -> PacketType {
+    PacketType::ChatFromSimulator(Box::new(ChatFromSimulator {
+        from_name: "Object".to_string(),
+        source_id: Uuid::new_v4(),
+        owner_id: Uuid::new_v4(),
+        source_type: SourceType::Object,
+        chat_type,
+        audible: Audible::Fully,
+        position: Vec3::ZERO,
+        message: "hello".to_string(),
+    }))
+}
+
+fn chat_pass(channel: i32, chat_type: ChatType) -> PacketType {
+    PacketType::ChatPass(Box::new(ChatPass {
+        channel,
+        position: Vec3::ZERO,
+        source_id: Uuid::new_v4(),
+        owner_id: Uuid::new_v4(),
+        from_name: "Object".to_string(),
+        source_type: SourceType::Object,
+        chat_type,
+        radius: 20.0,
+        sim_access: AgentAccess::PG,
+        message: "hello".to_string(),
+    }))
+}
+
+#[test]
+fn test_default_filter() {
+    let filter = ChatFilter::new();
+    for chat_type in [
+        ChatType::Whisper,
+        ChatType::Normal,
+        ChatType::Say,
+        ChatType::Shout,
+        ChatType::StartTyping,
+        ChatType::OwnerSay,
+    ] {
+        assert!(!filter.blocks(&chat(chat_type.clone())));
+        assert!(!filter.blocks(&chat_pass(0, chat_type)));
+    }
+    // script errors are dropped
+    assert!(filter.blocks(&chat(ChatType::Debug)));
+    assert!(filter.blocks(&chat_pass(DEBUG_CHANNEL, ChatType::Normal)));
+    // as is chat on other channels
+    assert!(filter.blocks(&chat_pass(42, ChatType::Normal)));
+    assert!(!filter.blocks(&PacketType::ChatFromSimulator(Box::new(
+        ChatFromSimulator {
+            from_name: String::new(),
+            source_id: Uuid::nil(),
+            owner_id: Uuid::nil(),
+            source_type: SourceType::System,
+            chat_type: ChatType::Normal,
+            audible: Audible::Fully,
+            position: Vec3::ZERO,
+            message: "restarting".to_string(),
+        }
+    ))));
+}
+
+#[test]
+fn test_set_filter() {
+    let mut filter = ChatFilter::new();
+    filter.set(&SetChatFilter {
+        channels: vec![0, 42],
+        whisper: false,
+        say: true,
+        shout: true,
+        debug: true,
+    });
+    assert!(filter.blocks(&chat(ChatType::Whisper)));
+    assert!(filter.blocks(&chat_pass(42, ChatType::Whisper)));
+    assert!(!filter.blocks(&chat_pass(42, ChatType::Shout)));
+    assert!(filter.blocks(&chat_pass(7, ChatType::Shout)));
+    // debug chat doesn't need its channel listed
+    assert!(!filter.blocks(&chat(ChatType::Debug)));
+    assert!(!filter.blocks(&chat_pass(DEBUG_CHANNEL, ChatType::Debug)));
+
+    filter.set(&SetChatFilter::default());
+    assert!(!filter.blocks(&chat(ChatType::Whisper)));
+    assert!(filter.blocks(&chat_pass(42, ChatType::Shout)));
+    assert!(filter.blocks(&chat(ChatType::Debug)));
+}
+
+#[test]
+fn test_other_packets_pass() {
+    let filter = ChatFilter::new();
+    let mut filter_nothing = ChatFilter::new();
+    filter_nothing.set(&SetChatFilter {
+        channels: vec![],
+        whisper: false,
+        say: false,
+        shout: false,
+        debug: false,
+    });
+    assert!(filter_nothing.blocks(&chat(ChatType::Normal)));
+    let packet = PacketType::SetChatFilter(Box::default());
+    assert!(!filter.blocks(&packet));
+    assert!(!filter_nothing.blocks(&packet));
+}
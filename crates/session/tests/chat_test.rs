@@ -3,7 +3,7 @@ use crossbeam_channel::{unbounded, Sender};
 use log::{error, info, LevelFilter};
 use metaverse_messages::login_system::login_response::LoginResponse;
 use metaverse_messages::packet_types::PacketType;
-use tempfile::NamedTempFile;
+use portpicker::pick_unused_port;
 
 use metaverse_messages::chat_from_viewer::{ChatFromViewer, ClientChatType};
 use metaverse_messages::login_system::login::Login;
@@ -11,20 +11,16 @@ use metaverse_messages::packet::Packet;
 use metaverse_session::client_subscriber::listen_for_server_events;
 use metaverse_session::initialize::initialize;
 
-use std::os::unix::net::UnixDatagram;
-use std::path::PathBuf;
+use std::net::UdpSocket;
 use std::thread::sleep;
 use std::time::Duration;
 
 #[test]
 fn test_chat() {
-    let incoming_socket_path = NamedTempFile::new()
-        .expect("Failed to create temp file")
-        .path()
-        .to_path_buf();
+    let ui_to_server_socket = pick_unused_port().unwrap();
 
     let (sender, receiver) = unbounded();
-    init_tests(incoming_socket_path.clone(), sender);
+    init_tests(ui_to_server_socket, sender);
 
     // wait for the mailbox to be ready. This can be done in a better way.
     sleep(Duration::from_secs(2));
@@ -41,8 +37,8 @@ fn test_chat() {
         url: build_test_url("http://127.0.0.1", 9000).to_string(),
     })
     .to_bytes();
-    let client_socket = UnixDatagram::unbound().unwrap();
-    match client_socket.send_to(&message, &incoming_socket_path) {
+    let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+    match client_socket.send_to(&message, format!("127.0.0.1:{}", ui_to_server_socket)) {
         Ok(_) => println!("message sent to mailbox"),
         Err(e) => println!("error sending to mailbox {:?}", e),
     };
@@ -67,8 +63,8 @@ fn test_chat() {
             message: "hello".to_string(),
         })
         .to_bytes();
-        let client_socket = UnixDatagram::unbound().unwrap();
-        match client_socket.send_to(&message, &incoming_socket_path) {
+        let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+        match client_socket.send_to(&message, format!("127.0.0.1:{}", ui_to_server_socket)) {
             Ok(_) => println!("chat message sent to mailbox"),
             Err(e) => println!("error sending to mailbox {:?}", e),
         };
@@ -78,29 +74,22 @@ fn test_chat() {
     sleep(Duration::from_secs(1));
 }
 
-fn init_tests(incoming_socket_path: PathBuf, sender: Sender<PacketType>) {
+fn init_tests(ui_to_server_socket: u16, sender: Sender<PacketType>) {
     init_logger();
-    let outgoing_socket_path = NamedTempFile::new()
-        .expect("Failed to create temp file")
-        .path()
-        .to_path_buf();
-    let outgoing_socket_path_clone = outgoing_socket_path.clone();
-    let outgoing_socket_path_clone2 = outgoing_socket_path.clone();
+    let server_to_ui_socket = pick_unused_port().unwrap();
 
-    let incoming_socket_path_clone = incoming_socket_path.clone();
-
-    println!("Starting outgoing UDS listener");
+    println!("Starting outgoing UDP listener");
 
     std::thread::spawn(move || {
         let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
         rt.block_on(async {
-            listen_for_server_events(outgoing_socket_path_clone, sender).await;
+            listen_for_server_events(format!("127.0.0.1:{}", server_to_ui_socket), sender).await;
         });
     });
 
-    std::thread::spawn(|| {
+    std::thread::spawn(move || {
         System::new().block_on(async {
-            match initialize(incoming_socket_path_clone, outgoing_socket_path_clone2).await {
+            match initialize(ui_to_server_socket, server_to_ui_socket).await {
                 Ok(handle) => {
                     match handle.await {
                         Ok(()) => info!("Listener exited successfully!"),
@@ -0,0 +1,127 @@
+mod common;
+
+use common::start_session;
+use metaverse_messages::circuit_code::CircuitCodeData;
+use metaverse_messages::circuit_stats_report::CircuitReport;
+use metaverse_messages::header::PacketFrequency;
+use metaverse_messages::packet::Packet;
+use metaverse_messages::packet_types::PacketType;
+use metaverse_session::circuit_stats::CircuitStatsTracker;
+use metaverse_session::mailbox::QueryStats;
+use std::net::SocketAddr;
+use std::thread::sleep;
+use std::time::Duration;
+use uuid::Uuid;
+
+fn sim() -> SocketAddr {
+    SocketAddr::from(([127, 0, 0, 1], 9000))
+}
+
+fn circuit_code() -> Packet {
+    Packet::new_circuit_code(CircuitCodeData {
+        code: 1234,
+        session_id: Uuid::nil(),
+        id: Uuid::nil(),
+    })
+}
+
+#[test]
+fn test_counters_follow_a_send_and_receive_cycle() {
+    let session = start_session(|_| {});
+    let mut sent_bytes = 0;
+    let mut recv = || {
+        let (packet, bytes) = session.sim.recv_within(common::WAIT).unwrap();
+        sent_bytes += bytes.len();
+        packet
+    };
+
+    // two reliable packets go out, and neither is acked in time, so both are resent
+    session.mailbox.do_send(circuit_code());
+    session.mailbox.do_send(circuit_code());
+    for _ in 0..2 {
+        assert!(!recv().header.resent);
+    }
+    for _ in 0..2 {
+        assert!(recv().header.resent);
+    }
+
+    // the simulator acks both on a packet of its own, which arrives twice. The ack for it goes
+    // back each time, in a PacketAck.
+    let mut reply = circuit_code();
+    reply.header.sequence_number = 1;
+    reply.append_acks(&mut vec![1, 2]);
+    let reply = reply.to_bytes();
+    for _ in 0..2 {
+        session.sim.send_bytes(&reply);
+        match recv().body {
+            PacketType::PacketAck(ack) => assert_eq!(ack.packet_ids, vec![1]),
+            body => panic!("expected an ack, got {:?}", body),
+        }
+    }
+    // and sends something that doesn't parse
+    let broken = [0x40, 0x00, 0x00];
+    session.sim.send_bytes(&broken);
+    sleep(Duration::from_millis(300));
+
+    session.mailbox.do_send(QueryStats);
+    let address = session.sim.addr().to_string();
+    let stats: CircuitReport = session.event(|event| match event {
+        PacketType::CircuitStatsReport(report) => report
+            .circuits
+            .into_iter()
+            .find(|circuit| circuit.address == address),
+        _ => None,
+    });
+    assert_eq!(stats.packets_sent, 6);
+    assert_eq!(stats.resends, 2);
+    assert_eq!(stats.bytes_sent, sent_bytes as u64);
+    assert_eq!(stats.packets_received, 1);
+    assert_eq!(stats.duplicates, 1);
+    assert_eq!(stats.parse_failures, 1);
+    assert_eq!(
+        stats.bytes_received,
+        (reply.len() * 2 + broken.len()) as u64
+    );
+    // the acks arrived with both copies of the packet
+    assert_eq!(stats.acks_received, 4);
+    assert_eq!(stats.acks_sent, 2);
+    assert_eq!(stats.received_by_type.len(), 1);
+    assert_eq!(stats.received_by_type[0].frequency, "Low");
+    assert_eq!(stats.received_by_type[0].id, 3);
+    assert_eq!(stats.received_by_type[0].count, 1);
+
+    // and nothing is resent once the acks are in
+    assert!(session.sim.recv_within(Duration::from_secs(3)).is_none());
+}
+
+#[test]
+fn test_report() {
+    let mut stats = CircuitStatsTracker::default();
+    let other = SocketAddr::from(([127, 0, 0, 1], 9001));
+    stats.received(other, PacketFrequency::Low, 999, 10);
+    for _ in 0..3 {
+        stats.received(sim(), PacketFrequency::High, 1, 10);
+    }
+    stats.received(sim(), PacketFrequency::Low, 999, 10);
+
+    let report = stats.report(Duration::from_millis(250));
+    assert_eq!(report.ping_ms, 250.0);
+    let addresses: Vec<&str> = report
+        .circuits
+        .iter()
+        .map(|circuit| circuit.address.as_str())
+        .collect();
+    assert_eq!(addresses, vec!["127.0.0.1:9000", "127.0.0.1:9001"]);
+    let types = &report.circuits[0].received_by_type;
+    // the most common type comes first
+    assert_eq!(types[0].frequency, "High");
+    assert_eq!(types[0].id, 1);
+    assert_eq!(types[0].count, 3);
+    assert_eq!(types[1].id, 999);
+    assert_eq!(report.circuits[0].bytes_received, 40);
+
+    stats.circuit_closed(&sim());
+    assert_eq!(stats.report(Duration::ZERO).circuits.len(), 1);
+    stats.clear();
+    assert!(stats.circuits.is_empty());
+}
@@ -0,0 +1,167 @@
+//! A running mailbox with a session, whose simulators are sockets the tests hold. Packets go
+//! through the mailbox's real send and receive paths.
+#![allow(dead_code)]
+
+use actix::{Actor, Addr, System};
+use crossbeam_channel::{unbounded, Receiver};
+use metaverse_messages::packet::Packet;
+use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::self_agent_info::SelfAgentInfo;
+use metaverse_session::client_subscriber::listen_for_server_events;
+use metaverse_session::initialize::new_mailbox;
+use metaverse_session::mailbox::{Mailbox, PingInfo, Session};
+use portpicker::pick_unused_port;
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::mpsc;
+use std::thread::{sleep, spawn};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// how long to wait for a packet or event that should arrive
+pub const WAIT: Duration = Duration::from_secs(5);
+/// the handle of the region the session is in
+pub const REGION_HANDLE: u64 = 1;
+
+/// A socket standing in for a simulator
+pub struct FakeSim {
+    /// the simulator's socket
+    pub socket: UdpSocket,
+    /// where the mailbox reads packets from simulators
+    pub mailbox: SocketAddr,
+}
+
+impl FakeSim {
+    /// a simulator sending to the mailbox reading on the port
+    pub fn new(mailbox_port: u16) -> Self {
+        FakeSim {
+            socket: UdpSocket::bind("127.0.0.1:0").unwrap(),
+            mailbox: SocketAddr::from(([127, 0, 0, 1], mailbox_port)),
+        }
+    }
+
+    /// the address the mailbox knows the simulator by
+    pub fn addr(&self) -> SocketAddr {
+        self.socket.local_addr().unwrap()
+    }
+
+    /// the next datagram from the mailbox, parsed, or None if none arrives in time
+    pub fn recv_within(&self, wait: Duration) -> Option<(Packet, Vec<u8>)> {
+        self.socket.set_read_timeout(Some(wait)).unwrap();
+        let mut buf = [0; 4096];
+        match self.socket.recv_from(&mut buf) {
+            Ok((size, _)) => Some((
+                Packet::from_bytes(&buf[..size]).unwrap(),
+                buf[..size].to_vec(),
+            )),
+            Err(_) => None,
+        }
+    }
+
+    /// the next packet from the mailbox
+    pub fn recv(&self) -> Packet {
+        match self.recv_within(WAIT) {
+            Some((packet, _)) => packet,
+            None => panic!("no packet arrived from the mailbox"),
+        }
+    }
+
+    /// send a packet to the mailbox
+    pub fn send(&self, packet: &Packet) {
+        self.send_bytes(&packet.to_bytes());
+    }
+
+    /// send a datagram to the mailbox
+    pub fn send_bytes(&self, bytes: &[u8]) {
+        self.socket.send_to(bytes, self.mailbox).unwrap();
+    }
+}
+
+/// A mailbox with a session on a simulator the test plays
+pub struct TestSession {
+    /// the running mailbox
+    pub mailbox: Addr<Mailbox>,
+    /// the port the mailbox reads packets from simulators on
+    pub client_socket: u16,
+    /// the simulator the session is on
+    pub sim: FakeSim,
+    /// the events the mailbox sends to the UI
+    pub events: Receiver<PacketType>,
+}
+
+impl TestSession {
+    /// another simulator, for child circuits
+    pub fn neighbour(&self) -> FakeSim {
+        FakeSim::new(self.client_socket)
+    }
+
+    /// wait for the first event the function picks out
+    pub fn event<T>(&self, pick: impl Fn(PacketType) -> Option<T>) -> T {
+        let deadline = Instant::now() + WAIT;
+        while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+            match self.events.recv_timeout(left) {
+                Ok(event) => {
+                    if let Some(picked) = pick(event) {
+                        return picked;
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+        panic!("the event never arrived");
+    }
+}
+
+/// start a mailbox with a session on a fake simulator. The mailbox can be set up before it
+/// starts, and doesn't ping, so the simulator only gets the packets the test sends.
+pub fn start_session(set_up: impl FnOnce(&mut Mailbox) + Send + 'static) -> TestSession {
+    let server_to_ui_socket = pick_unused_port().unwrap();
+    let (sender, events) = unbounded();
+    spawn(move || {
+        let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+        rt.block_on(async {
+            listen_for_server_events(format!("127.0.0.1:{}", server_to_ui_socket), sender).await;
+        });
+    });
+
+    let mut mailbox = new_mailbox(server_to_ui_socket);
+    mailbox.ping_info = PingInfo::new(Duration::from_secs(3600));
+    mailbox.login_pending = true;
+    set_up(&mut mailbox);
+    let client_socket = mailbox.client_socket;
+    let sim = FakeSim::new(client_socket);
+    let session = Session {
+        url: "127.0.0.1".to_string(),
+        server_socket: sim.addr().port(),
+        agent_id: Uuid::nil(),
+        session_id: Uuid::nil(),
+        secure_session_id: Uuid::nil(),
+        circuit_code: 1234,
+        region_handle: REGION_HANDLE,
+        wearables_serial_num: 0,
+        appearance_serial_num: 0,
+        active_group_id: Uuid::nil(),
+        self_info: SelfAgentInfo::new(Uuid::nil(), "default".to_string(), "user".to_string()),
+        god_level: 0,
+        economy: None,
+        socket: None,
+    };
+
+    let (address_sender, address) = mpsc::channel();
+    spawn(move || {
+        System::new().block_on(async move {
+            let mailbox = mailbox.start();
+            mailbox.do_send(session);
+            address_sender.send(mailbox).unwrap();
+            std::future::pending::<()>().await;
+        });
+    });
+    let mailbox = address.recv().unwrap();
+    // wait for the mailbox to bind its socket, and the UI to listen
+    sleep(Duration::from_millis(500));
+    TestSession {
+        mailbox,
+        client_socket,
+        sim,
+        events,
+    }
+}
@@ -0,0 +1,66 @@
+use metaverse_session::dedupe::DuplicateFilter;
+use std::net::SocketAddr;
+
+fn addr(port: u16) -> SocketAddr {
+    SocketAddr::from(([127, 0, 0, 1], port))
+}
+
+#[test]
+fn test_packets_are_handled_once() {
+    let mut filter = DuplicateFilter::new(16);
+    assert!(!filter.is_duplicate(addr(9000), 1, false));
+    assert!(!filter.is_duplicate(addr(9000), 2, false));
+    // the simulator resends a packet whose ack it missed
+    assert!(filter.is_duplicate(addr(9000), 1, true));
+    // the network duplicates one, without the resent flag
+    assert!(filter.is_duplicate(addr(9000), 2, false));
+    // a resend of a packet whose first copy was lost is handled
+    assert!(!filter.is_duplicate(addr(9000), 3, true));
+
+    // another simulator counts on its own
+    assert!(!filter.is_duplicate(addr(9001), 1, false));
+}
+
+#[test]
+fn test_memory_is_bounded() {
+    let mut filter = DuplicateFilter::new(100);
+    for sequence_number in 1..=10_000 {
+        assert!(!filter.is_duplicate(addr(9000), sequence_number, false));
+    }
+    let circuit = &filter.circuits[&addr(9000)];
+    assert_eq!(circuit.seen.len(), 100);
+    assert_eq!(circuit.order.len(), 100);
+    // the latest numbers are still remembered, and the oldest are forgotten
+    assert!(filter.is_duplicate(addr(9000), 9_901, true));
+    assert!(!filter.is_duplicate(addr(9000), 9_900, true));
+}
+
+#[test]
+fn test_wraparound_is_not_a_duplicate() {
+    let mut filter = DuplicateFilter::new(100);
+    // the numbers that come around again after the counter wraps were forgotten long before
+    for sequence_number in 1..=50 {
+        filter.is_duplicate(addr(9000), sequence_number, false);
+    }
+    for sequence_number in (u32::MAX - 200)..=u32::MAX {
+        assert!(!filter.is_duplicate(addr(9000), sequence_number, false));
+    }
+    for sequence_number in 1..=50 {
+        assert!(!filter.is_duplicate(addr(9000), sequence_number, false));
+    }
+    // numbers from just before the wrap are still caught
+    assert!(filter.is_duplicate(addr(9000), u32::MAX, true));
+}
+
+#[test]
+fn test_closed_circuits_start_over() {
+    let mut filter = DuplicateFilter::new(16);
+    filter.is_duplicate(addr(9000), 1, false);
+    filter.is_duplicate(addr(9001), 1, false);
+    filter.circuit_closed(&addr(9000));
+    assert!(!filter.is_duplicate(addr(9000), 1, false));
+    assert!(filter.is_duplicate(addr(9001), 1, false));
+
+    filter.clear();
+    assert!(filter.circuits.is_empty());
+}
@@ -0,0 +1,139 @@
+use metaverse_messages::dir_find_query::{DirFindQuery, DirectorySearchKind};
+use metaverse_messages::dir_people_reply::{DirPeopleReply, DirPerson};
+use metaverse_messages::dir_places_reply::{DirPlace, DirPlacesReply};
+use metaverse_session::directory::{DirectoryManager, DirectoryPage};
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+const WINDOW: Duration = Duration::from_millis(500);
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn person(index: usize) -> DirPerson {
+    DirPerson {
+        agent_id: Uuid::from_u128(index as u128 + 1),
+        first_name: format!("Resident{}", index),
+        last_name: "Resident".to_string(),
+        group: String::new(),
+        online: false,
+        reputation: 0,
+    }
+}
+
+fn people_reply(query_id: Uuid, people: Vec<DirPerson>) -> DirPeopleReply {
+    DirPeopleReply {
+        agent_id: Uuid::nil(),
+        query_id,
+        people,
+    }
+}
+
+#[test]
+fn test_pages_gathered() {
+    let mut directory = DirectoryManager::new(WINDOW, TIMEOUT);
+    let start = Instant::now();
+    let query = directory.start(
+        DirFindQuery::search(DirectorySearchKind::People, "res".to_string(), 0),
+        start,
+    );
+    assert!(!query.query_id.is_nil());
+
+    // the page is split over two replies
+    let people: Vec<DirPerson> = (0..=DirFindQuery::PAGE_SIZE).map(person).collect();
+    assert!(directory.add_people(&people_reply(query.query_id, people[..60].to_vec()), start));
+    assert!(directory.add_people(&people_reply(query.query_id, people[60..].to_vec()), start));
+    assert!(directory.flush(start + WINDOW / 2).is_empty());
+
+    let pages = directory.flush(start + WINDOW);
+    assert_eq!(pages.len(), 1);
+    match &pages[0] {
+        DirectoryPage::People(page) => {
+            assert_eq!(page.query_id, query.query_id);
+            assert_eq!(page.text, "res");
+            assert_eq!(page.start_index, 0);
+            // the extra result only says there are more pages
+            assert_eq!(page.people.len(), DirFindQuery::PAGE_SIZE);
+            assert_eq!(page.next_start_index, Some(DirFindQuery::PAGE_SIZE as i32));
+        }
+        other => panic!("expected a page of people, got {:?}", other),
+    }
+    assert!(directory.searches.is_empty());
+}
+
+#[test]
+fn test_next_page() {
+    let mut directory = DirectoryManager::new(WINDOW, TIMEOUT);
+    let start = Instant::now();
+    let mut query = DirFindQuery::search(DirectorySearchKind::People, "res".to_string(), 100);
+    query.query_id = Uuid::from_u128(42);
+    let query = directory.start(query, start);
+    // the UI's query id is kept for the next page
+    assert_eq!(query.query_id, Uuid::from_u128(42));
+    assert_eq!(query.query_start, 100);
+    directory.add_people(&people_reply(query.query_id, vec![person(0)]), start);
+    match &directory.flush(start + WINDOW)[0] {
+        DirectoryPage::People(page) => {
+            assert_eq!(page.start_index, 100);
+            assert_eq!(page.people.len(), 1);
+            assert_eq!(page.next_start_index, None);
+        }
+        other => panic!("expected a page of people, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_query_ids() {
+    let mut directory = DirectoryManager::new(WINDOW, TIMEOUT);
+    let mut query = DirFindQuery::search(DirectorySearchKind::Places, "beach".to_string(), -5);
+    query.query_id = Uuid::from_u128(42);
+    let first = directory.start(query.clone(), Instant::now());
+    assert_eq!(first.query_start, 0);
+    // a query id that is still waiting is replaced
+    let second = directory.start(query, Instant::now());
+    assert_ne!(second.query_id, first.query_id);
+    assert_eq!(directory.searches.len(), 2);
+
+    // replies of the wrong kind, or for other queries, are ignored
+    assert!(!directory.add_people(
+        &people_reply(first.query_id, vec![person(0)]),
+        Instant::now()
+    ));
+    let places = DirPlacesReply {
+        agent_id: Uuid::nil(),
+        query_id: Uuid::new_v4(),
+        places: vec![DirPlace {
+            parcel_id: Uuid::from_u128(1),
+            name: "Beach".to_string(),
+            for_sale: false,
+            auction: false,
+            dwell: 0.0,
+            status: 0,
+        }],
+    };
+    assert!(!directory.add_places(&places, Instant::now()));
+    assert!(directory.add_places(
+        &DirPlacesReply {
+            query_id: first.query_id,
+            ..places
+        },
+        Instant::now()
+    ));
+}
+
+#[test]
+fn test_unanswered_search() {
+    let mut directory = DirectoryManager::new(WINDOW, TIMEOUT);
+    let start = Instant::now();
+    let query = directory.start(
+        DirFindQuery::search(DirectorySearchKind::Places, "nowhere".to_string(), 0),
+        start,
+    );
+    assert!(directory.flush(start + WINDOW).is_empty());
+    match &directory.flush(start + TIMEOUT)[..] {
+        [DirectoryPage::Places(page)] => {
+            assert_eq!(page.query_id, query.query_id);
+            assert!(page.places.is_empty());
+            assert_eq!(page.next_start_index, None);
+        }
+        other => panic!("expected an empty page of places, got {:?}", other),
+    }
+}
@@ -0,0 +1,195 @@
+use glam::Vec3;
+use metaverse_messages::change_user_rights::ChangeUserRights;
+use metaverse_messages::friendship_status::FriendshipStatus;
+use metaverse_messages::grant_user_rights::{GrantUserRights, UserRights};
+use metaverse_messages::improved_instant_message::{ImprovedInstantMessage, InstantMessageDialog};
+use metaverse_messages::login_system::login_response::{BuddyListValues, FriendsRights};
+use metaverse_session::friends::FriendManager;
+use uuid::{uuid, Uuid};
+
+const ALICE: Uuid = uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90");
+const BOB: Uuid = uuid!("11223344-5566-7788-9900-aabbccddeeff");
+const CAROL: Uuid = uuid!("0f0e0d0c-0b0a-0908-0706-050403020100");
+
+fn buddy(id: &str) -> BuddyListValues {
+    BuddyListValues {
+        buddy_id: id.to_string(),
+        buddy_rights_given: FriendsRights {
+            can_see_online: true,
+            can_see_on_map: false,
+            can_modify_objects: false,
+        },
+        buddy_rights_has: FriendsRights::default(),
+    }
+}
+
+#[test]
+fn test_load_buddy_list() {
+    let mut friends = FriendManager::new();
+    friends.load(&[
+        buddy(&ALICE.to_string()),
+        buddy(&BOB.to_string()),
+        buddy("not a uuid"),
+    ]);
+    let list = friends.list();
+    assert_eq!(list.friends.len(), 2);
+    assert!(list.friends.iter().all(|friend| !friend.online));
+    assert!(list.friends[0].rights_given.can_see_online);
+    // sorted by id
+    assert_eq!(list.friends[0].id, BOB);
+    assert_eq!(list.friends[1].id, ALICE);
+}
+
+#[test]
+fn test_duplicate_notifications() {
+    let mut friends = FriendManager::new();
+    friends.load(&[buddy(&ALICE.to_string()), buddy(&BOB.to_string())]);
+
+    assert_eq!(friends.set_online(&[ALICE, BOB]), vec![ALICE, BOB]);
+    assert!(friends.is_online(&ALICE));
+    assert!(friends.set_online(&[ALICE]).is_empty());
+    assert!(friends.set_online(&[ALICE, ALICE]).is_empty());
+
+    assert_eq!(friends.set_offline(&[ALICE]), vec![ALICE]);
+    assert!(friends.set_offline(&[ALICE]).is_empty());
+    assert!(!friends.is_online(&ALICE));
+    assert!(friends.is_online(&BOB));
+}
+
+#[test]
+fn test_notification_adds_new_friend() {
+    let mut friends = FriendManager::new();
+    friends.load(&[buddy(&ALICE.to_string())]);
+    assert!(!friends.is_online(&CAROL));
+    assert_eq!(friends.set_online(&[CAROL]), vec![CAROL]);
+    assert_eq!(friends.list().friends.len(), 2);
+    assert!(!friends.list().friends[0].rights_given.can_see_online);
+}
+
+#[test]
+fn test_reload_keeps_presence() {
+    let mut friends = FriendManager::new();
+    friends.set_online(&[ALICE]);
+    friends.load(&[buddy(&ALICE.to_string())]);
+    assert!(friends.is_online(&ALICE));
+    assert!(friends.list().friends[0].rights_given.can_see_online);
+
+    friends.clear();
+    assert!(!friends.is_online(&ALICE));
+    assert!(friends.list().friends.is_empty());
+}
+
+fn friendship_message(dialog: InstantMessageDialog, from_agent_id: Uuid) -> ImprovedInstantMessage {
+    ImprovedInstantMessage {
+        from_agent_id,
+        session_id: Uuid::nil(),
+        from_group: false,
+        to_agent_id: CAROL,
+        parent_estate_id: 1,
+        region_id: Uuid::nil(),
+        position: Vec3::ZERO,
+        offline: 0,
+        dialog,
+        id: Uuid::new_v4(),
+        timestamp: 0,
+        from_agent_name: "Alice Resident".to_string(),
+        message: String::new(),
+        binary_bucket: vec![],
+    }
+}
+
+#[test]
+fn test_accept_offer() {
+    let mut friends = FriendManager::new();
+    let offer = friendship_message(InstantMessageDialog::FriendshipOffered, ALICE);
+    match friends.handle_instant_message(&offer) {
+        Some(FriendshipStatus::Offered {
+            im_session_id,
+            agent_id,
+            ..
+        }) => {
+            assert_eq!(im_session_id, offer.id);
+            assert_eq!(agent_id, ALICE);
+        }
+        status => panic!("expected an offer, got {:?}", status),
+    }
+    assert!(friends.list().friends.is_empty());
+
+    // answers for offers that were never made are refused
+    assert!(friends.accept(&Uuid::new_v4()).is_none());
+    assert_eq!(friends.accept(&offer.id), Some(ALICE));
+    assert!(friends.is_online(&ALICE));
+    assert!(friends.list().friends[0].rights_given.can_see_online);
+    // an offer can only be answered once
+    assert!(friends.accept(&offer.id).is_none());
+    assert!(friends.decline(&offer.id).is_none());
+}
+
+#[test]
+fn test_decline_offer() {
+    let mut friends = FriendManager::new();
+    let offer = friendship_message(InstantMessageDialog::FriendshipOffered, ALICE);
+    friends.handle_instant_message(&offer);
+    assert_eq!(friends.decline(&offer.id), Some(ALICE));
+    assert!(friends.list().friends.is_empty());
+    assert!(friends.offers.is_empty());
+}
+
+#[test]
+fn test_answers_to_agent_offers() {
+    let mut friends = FriendManager::new();
+    let accepted = friendship_message(InstantMessageDialog::FriendshipAccepted, ALICE);
+    assert!(matches!(
+        friends.handle_instant_message(&accepted),
+        Some(FriendshipStatus::Accepted { agent_id, .. }) if agent_id == ALICE
+    ));
+    assert!(friends.is_online(&ALICE));
+
+    let declined = friendship_message(InstantMessageDialog::FriendshipDeclined, BOB);
+    assert!(matches!(
+        friends.handle_instant_message(&declined),
+        Some(FriendshipStatus::Declined { agent_id, .. }) if agent_id == BOB
+    ));
+    assert_eq!(friends.list().friends.len(), 1);
+
+    let chat = friendship_message(InstantMessageDialog::MessageFromAgent, BOB);
+    assert!(friends.handle_instant_message(&chat).is_none());
+}
+
+#[test]
+fn test_rights_and_removal() {
+    let mut friends = FriendManager::new();
+    friends.load(&[buddy(&ALICE.to_string())]);
+    let map = FriendsRights::from_bits(FriendsRights::ONLINE | FriendsRights::MAP);
+
+    friends.grant(&GrantUserRights::new(vec![
+        UserRights {
+            agent_related: ALICE,
+            rights: map.clone(),
+        },
+        UserRights {
+            agent_related: BOB,
+            rights: map.clone(),
+        },
+    ]));
+    assert_eq!(friends.list().friends[0].rights_given, map);
+    assert_eq!(friends.list().friends.len(), 1);
+
+    // Alice changed the rights she gives the agent
+    friends.change_rights(
+        CAROL,
+        &ChangeUserRights {
+            agent_id: ALICE,
+            rights: vec![UserRights {
+                agent_related: CAROL,
+                rights: FriendsRights::from_bits(FriendsRights::MODIFY),
+            }],
+        },
+    );
+    assert!(friends.list().friends[0].rights_has.can_modify_objects);
+    assert_eq!(friends.list().friends[0].rights_given, map);
+
+    assert!(friends.remove(&ALICE));
+    assert!(!friends.remove(&ALICE));
+    assert!(friends.list().friends.is_empty());
+}
@@ -0,0 +1,35 @@
+use metaverse_messages::health_message::HealthMessage;
+use metaverse_session::health::HealthTracker;
+
+fn health(health: f32) -> HealthMessage {
+    HealthMessage { health }
+}
+
+#[test]
+fn test_damage_is_not_death() {
+    let mut tracker = HealthTracker::new();
+    assert!(tracker.handle_health(&health(100.0)).is_none());
+    assert!(tracker.handle_health(&health(35.5)).is_none());
+    assert_eq!(tracker.health, Some(35.5));
+}
+
+#[test]
+fn test_death_is_sent_once() {
+    let mut tracker = HealthTracker::new();
+    tracker.handle_health(&health(20.0));
+    let died = tracker.handle_health(&health(-5.0)).unwrap();
+    assert_eq!(died.health, -5.0);
+    // the simulator keeps sending health at 0 until the agent is sent home
+    assert!(tracker.handle_health(&health(0.0)).is_none());
+    // sent home with full health, and killed again
+    assert!(tracker.handle_health(&health(100.0)).is_none());
+    assert!(tracker.handle_health(&health(0.0)).is_some());
+}
+
+#[test]
+fn test_arriving_dead() {
+    let mut tracker = HealthTracker::new();
+    assert!(tracker.handle_health(&health(0.0)).is_some());
+    tracker.clear();
+    assert!(tracker.health.is_none());
+}
@@ -0,0 +1,63 @@
+use metaverse_messages::packet_types::PacketType;
+use metaverse_session::interpolation::InterpolationManager;
+use std::net::SocketAddr;
+use uuid::Uuid;
+
+fn addr(port: u16) -> SocketAddr {
+    SocketAddr::from(([127, 0, 0, 1], port))
+}
+
+#[test]
+fn test_interpolation_is_on_by_default() {
+    let mut interpolation = InterpolationManager::new();
+    assert!(interpolation.status().enabled);
+    assert!(interpolation.circuit_started(addr(9000)));
+    match interpolation.packet(Uuid::nil(), Uuid::nil()).body {
+        PacketType::VelocityInterpolateOn(_) => {}
+        _ => panic!("expected VelocityInterpolateOn"),
+    }
+}
+
+#[test]
+fn test_setting_is_sent_to_new_circuits() {
+    let mut interpolation = InterpolationManager::new();
+    interpolation.circuit_started(addr(9000));
+
+    // turning it off reaches the open circuit
+    assert_eq!(interpolation.set(false), vec![addr(9000)]);
+    assert!(!interpolation.status().enabled);
+    match interpolation.packet(Uuid::nil(), Uuid::nil()).body {
+        PacketType::VelocityInterpolateOff(_) => {}
+        _ => panic!("expected VelocityInterpolateOff"),
+    }
+
+    // a teleport's new circuit, or a handshake on the same one, is told again
+    assert!(!interpolation.circuit_started(addr(9001)));
+    assert!(!interpolation.circuit_started(addr(9000)));
+    let mut circuits = interpolation.set(true);
+    circuits.sort();
+    assert_eq!(circuits, vec![addr(9000), addr(9001)]);
+}
+
+#[test]
+fn test_unchanged_setting_is_not_sent() {
+    let mut interpolation = InterpolationManager::new();
+    interpolation.circuit_started(addr(9000));
+    assert!(interpolation.set(true).is_empty());
+    assert!(interpolation.status().enabled);
+}
+
+#[test]
+fn test_closed_circuits_are_forgotten() {
+    let mut interpolation = InterpolationManager::new();
+    interpolation.circuit_started(addr(9000));
+    interpolation.circuit_started(addr(9001));
+    interpolation.circuit_closed(&addr(9000));
+    assert_eq!(interpolation.set(false), vec![addr(9001)]);
+
+    // the setting outlives the session, but its circuits don't
+    interpolation.clear();
+    assert!(interpolation.set(true).is_empty());
+    interpolation.set(false);
+    assert!(!interpolation.circuit_started(addr(9002)));
+}
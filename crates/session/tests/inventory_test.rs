@@ -0,0 +1,381 @@
+use metaverse_messages::create_inventory_folder::CreateInventoryFolder;
+use metaverse_messages::fetch_inventory::ItemRequest;
+use metaverse_messages::fetch_inventory_reply::FetchInventoryReply;
+use metaverse_messages::inventory_descendents::{InventoryDescendents, InventoryFolder};
+use metaverse_messages::inventory_item_status::InventoryItemStatus;
+use metaverse_messages::move_inventory_folder::{FolderMove, MoveInventoryFolder};
+use metaverse_messages::move_inventory_item::{ItemMove, MoveInventoryItem};
+use metaverse_messages::packet::PacketData;
+use metaverse_messages::remove_inventory_folder::RemoveInventoryFolder;
+use metaverse_messages::remove_inventory_item::RemoveInventoryItem;
+use metaverse_messages::update_inventory_item::UpdateInventoryItem;
+use metaverse_messages::utils::asset_type::AssetType;
+use metaverse_messages::utils::inventory_item::{InventoryItem, ItemData};
+use metaverse_messages::utils::permissions::Permissions;
+use metaverse_messages::utils::sale_type::SaleType;
+use metaverse_session::inventory::{
+    InventoryFetcher, InventoryModel, ItemFetcher, INVENTORY_CHANGE_BATCH, ITEM_FETCH_BATCH,
+};
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn id(byte: u8) -> Uuid {
+    Uuid::from_bytes([byte; 16])
+}
+
+fn folder(folder_id: Uuid, parent_id: Uuid) -> InventoryFolder {
+    InventoryFolder {
+        folder_id,
+        parent_id,
+        folder_type: -1,
+        name: "folder".to_string(),
+    }
+}
+
+fn item(item_id: Uuid, folder_id: Uuid) -> ItemData {
+    ItemData {
+        item: InventoryItem {
+            item_id,
+            folder_id,
+            creator_id: id(0xEE),
+            owner_id: id(0xEE),
+            group_id: Uuid::nil(),
+            base_mask: Permissions::default(),
+            owner_mask: Permissions::default(),
+            group_mask: Permissions::default(),
+            everyone_mask: Permissions::default(),
+            next_owner_mask: Permissions::default(),
+            group_owned: false,
+            asset_id: Uuid::nil(),
+            asset_type: AssetType::Notecard,
+            inventory_type: 7,
+            flags: 0,
+            sale_type: SaleType::NotForSale,
+            sale_price: 0,
+            name: "item".to_string(),
+            description: String::new(),
+            creation_date: 0,
+        },
+        crc: 0,
+        crc_valid: false,
+    }
+}
+
+fn descendents(
+    folder_id: Uuid,
+    total: i32,
+    folders: Vec<InventoryFolder>,
+    items: Vec<ItemData>,
+) -> InventoryDescendents {
+    InventoryDescendents {
+        agent_id: id(0xEE),
+        folder_id,
+        owner_id: id(0xEE),
+        version: 1,
+        descendents: total,
+        folders,
+        items,
+    }
+}
+
+#[test]
+fn test_fetch_tree_breadth_first() {
+    let now = Instant::now();
+    let mut fetcher = InventoryFetcher::new(2, TIMEOUT);
+    let root = id(0x01);
+    fetcher.fetch_tree(root, id(0xEE));
+    fetcher.fetch_tree(root, id(0xEE));
+    let requests = fetcher.next_requests(now);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].folder_id, root);
+    assert_eq!(requests[0].owner_id, id(0xEE));
+    assert!(fetcher.next_requests(now).is_empty());
+
+    // the root has three folders and an item
+    let contents = fetcher
+        .handle_descendents(
+            &descendents(
+                root,
+                4,
+                vec![
+                    folder(id(0x02), root),
+                    folder(id(0x03), root),
+                    folder(id(0x04), root),
+                ],
+                vec![item(id(0x10), root)],
+            ),
+            now,
+        )
+        .unwrap();
+    assert_eq!(contents.folder_id, root);
+    assert_eq!(contents.folders.len(), 3);
+    assert_eq!(contents.items.len(), 1);
+
+    // only two folders are fetched at once
+    let requests = fetcher.next_requests(now);
+    let folders: Vec<Uuid> = requests.iter().map(|request| request.folder_id).collect();
+    assert_eq!(folders, vec![id(0x02), id(0x03)]);
+
+    // folder 2 has a folder of its own, which waits behind folder 4
+    fetcher
+        .handle_descendents(
+            &descendents(id(0x02), 1, vec![folder(id(0x05), id(0x02))], vec![]),
+            now,
+        )
+        .unwrap();
+    let requests = fetcher.next_requests(now);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].folder_id, id(0x04));
+
+    fetcher.handle_descendents(&descendents(id(0x03), 0, vec![], vec![]), now);
+    let requests = fetcher.next_requests(now);
+    assert_eq!(requests[0].folder_id, id(0x05));
+    fetcher.handle_descendents(&descendents(id(0x04), 0, vec![], vec![]), now);
+    fetcher.handle_descendents(&descendents(id(0x05), 0, vec![], vec![]), now);
+    assert!(fetcher.is_idle());
+}
+
+#[test]
+fn test_folder_split_over_packets() {
+    let now = Instant::now();
+    let mut fetcher = InventoryFetcher::new(4, TIMEOUT);
+    let root = id(0x01);
+    fetcher.fetch_tree(root, id(0xEE));
+    fetcher.next_requests(now);
+
+    assert!(fetcher
+        .handle_descendents(
+            &descendents(root, 3, vec![], vec![item(id(0x10), root)]),
+            now
+        )
+        .is_none());
+    let contents = fetcher
+        .handle_descendents(
+            &descendents(
+                root,
+                3,
+                vec![],
+                vec![item(id(0x11), root), item(id(0x12), root)],
+            ),
+            now,
+        )
+        .unwrap();
+    assert_eq!(contents.items.len(), 3);
+    // packets for folders that aren't being fetched are ignored
+    assert!(fetcher
+        .handle_descendents(&descendents(root, 0, vec![], vec![]), now)
+        .is_none());
+}
+
+#[test]
+fn test_fetch_timeout() {
+    let now = Instant::now();
+    let mut fetcher = InventoryFetcher::new(4, TIMEOUT);
+    let root = id(0x01);
+    fetcher.fetch_tree(root, id(0xEE));
+    fetcher.next_requests(now);
+    fetcher.handle_descendents(
+        &descendents(root, 5, vec![folder(id(0x02), root)], vec![]),
+        now,
+    );
+
+    assert!(fetcher.expire(now + Duration::from_secs(10)).is_empty());
+    let expired = fetcher.expire(now + TIMEOUT);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].folders.len(), 1);
+    // the folders that did arrive are still fetched
+    let requests = fetcher.next_requests(now + TIMEOUT);
+    assert_eq!(requests[0].folder_id, id(0x02));
+
+    fetcher.clear();
+    assert!(fetcher.is_idle());
+}
+
+#[test]
+fn test_fetch_items() {
+    let now = Instant::now();
+    let mut fetcher = ItemFetcher::new(TIMEOUT);
+    let requests: Vec<ItemRequest> = (0..ITEM_FETCH_BATCH + 1)
+        .map(|i| ItemRequest {
+            owner_id: id(0xEE),
+            item_id: id(i as u8 + 1),
+        })
+        .collect();
+
+    // the items are split over packets, and nothing is asked for twice
+    let fetches = fetcher.request(&requests, now);
+    assert_eq!(fetches.len(), 2);
+    assert_eq!(fetches[0].items.len(), ITEM_FETCH_BATCH);
+    assert_eq!(fetches[1].items, vec![requests[ITEM_FETCH_BATCH]]);
+    assert!(fetcher.request(&requests[..1], now).is_empty());
+
+    let reply = FetchInventoryReply {
+        agent_id: id(0xEE),
+        items: vec![item(id(0x01), id(0x10))],
+    };
+    assert_eq!(
+        fetcher.handle_reply(&reply),
+        vec![InventoryItemStatus::Found(item(id(0x01), id(0x10)).item)]
+    );
+    assert_eq!(fetcher.pending.len(), ITEM_FETCH_BATCH);
+}
+
+#[test]
+fn test_fetch_item_timeout() {
+    let now = Instant::now();
+    let mut fetcher = ItemFetcher::new(TIMEOUT);
+    let request = ItemRequest {
+        owner_id: id(0xEE),
+        item_id: id(0x01),
+    };
+    fetcher.request(&[request], now);
+
+    assert!(fetcher.expire(now + Duration::from_secs(10)).is_empty());
+    assert_eq!(
+        fetcher.expire(now + TIMEOUT),
+        vec![InventoryItemStatus::NotFound { item_id: id(0x01) }]
+    );
+    assert!(fetcher.pending.is_empty());
+
+    // an item that timed out can be asked for again
+    assert_eq!(fetcher.request(&[request], now + TIMEOUT).len(), 1);
+    fetcher.clear();
+    assert!(fetcher.pending.is_empty());
+}
+
+#[test]
+fn test_update_model() {
+    let mut model = InventoryModel::new();
+    let known = InventoryItem {
+        asset_id: id(0xAA),
+        ..item(id(0x01), id(0x10)).item
+    };
+    model.insert(known.clone());
+
+    // UpdateInventoryItem doesn't carry the asset, so the known one is kept
+    let renamed = InventoryItem {
+        name: "renamed".to_string(),
+        asset_id: Uuid::nil(),
+        ..known.clone()
+    };
+    let updates = model.update(UpdateInventoryItem::new(vec![renamed.clone()]));
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].items[0].item, renamed);
+    let updated = model.get(&id(0x01)).unwrap();
+    assert_eq!(updated.name, "renamed");
+    assert_eq!(updated.asset_id, id(0xAA));
+
+    let moves = model.move_items(MoveInventoryItem::new(vec![
+        ItemMove {
+            item_id: id(0x01),
+            folder_id: id(0x20),
+            new_name: None,
+        },
+        ItemMove {
+            item_id: id(0x02),
+            folder_id: id(0x20),
+            new_name: Some("unknown".to_string()),
+        },
+    ]));
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].items.len(), 2);
+    let moved = model.get(&id(0x01)).unwrap();
+    assert_eq!(moved.folder_id, id(0x20));
+    assert_eq!(moved.name, "renamed");
+    // items that haven't arrived are still moved, but aren't made up
+    assert!(model.get(&id(0x02)).is_none());
+}
+
+#[test]
+fn test_update_is_sent_as_given() {
+    let mut model = InventoryModel::new();
+    let known = InventoryItem {
+        asset_id: id(0xAA),
+        ..item(id(0x01), id(0x10)).item
+    };
+    model.insert(known.clone());
+
+    // the asset id slot carries the transaction id, which must not be swapped for the known asset
+    let update = UpdateInventoryItem::new(vec![InventoryItem {
+        name: "renamed".to_string(),
+        asset_id: id(0xBB),
+        ..known
+    }]);
+    let updates = model.update(update.clone());
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].to_bytes(), update.to_bytes());
+    assert_eq!(model.get(&id(0x01)).unwrap().asset_id, id(0xAA));
+}
+
+#[test]
+fn test_remove_split_over_packets() {
+    let mut model = InventoryModel::new();
+    model.insert(item(id(0x01), id(0x10)).item);
+    let item_ids: Vec<Uuid> = (0..300u32)
+        .map(|i| Uuid::from_u128(i as u128 + 1))
+        .chain([id(0x01)])
+        .collect();
+
+    let mut remove = RemoveInventoryItem::new(item_ids.clone());
+    remove.agent_id = id(0xEE);
+    let removes = model.remove(remove);
+    assert_eq!(removes.len(), 2);
+    assert_eq!(removes[0].item_ids.len(), INVENTORY_CHANGE_BATCH);
+    assert_eq!(removes[1].item_ids, item_ids[INVENTORY_CHANGE_BATCH..]);
+    assert!(removes.iter().all(|remove| remove.agent_id == id(0xEE)));
+    assert!(model.get(&id(0x01)).is_none());
+
+    model.insert(item(id(0x01), id(0x10)).item);
+    model.clear();
+    assert!(model.items.is_empty());
+}
+
+#[test]
+fn test_create_folder() {
+    let mut model = InventoryModel::new();
+    let create = model.create_folder(CreateInventoryFolder::new(id(0x10), "new".to_string()));
+    // the session picks the id of the folder
+    assert!(!create.folder.folder_id.is_nil());
+    assert_eq!(model.folder(&create.folder.folder_id), Some(&create.folder));
+
+    // an id the UI picked is kept
+    let mut create = CreateInventoryFolder::new(id(0x10), "picked".to_string());
+    create.folder.folder_id = id(0x30);
+    assert_eq!(model.create_folder(create).folder.folder_id, id(0x30));
+}
+
+#[test]
+fn test_remove_and_purge_folders() {
+    // root holds a, which holds b. Each has an item.
+    let mut model = InventoryModel::new();
+    let (root, a, b) = (id(0x10), id(0x20), id(0x21));
+    model.insert_folder(folder(a, root));
+    model.insert_folder(folder(b, a));
+    model.insert(item(id(0x01), root).item);
+    model.insert(item(id(0x02), a).item);
+    model.insert(item(id(0x03), b).item);
+
+    let moves = model.move_folders(MoveInventoryFolder::new(vec![FolderMove {
+        folder_id: b,
+        parent_id: root,
+    }]));
+    assert_eq!(moves.len(), 1);
+    assert_eq!(model.folder(&b).unwrap().parent_id, root);
+    model.insert_folder(folder(b, a));
+
+    // purging a keeps a, and drops everything inside it
+    model.purge(&a);
+    assert!(model.folder(&a).is_some());
+    assert!(model.folder(&b).is_none());
+    assert!(model.get(&id(0x02)).is_none());
+    assert!(model.get(&id(0x03)).is_none());
+    assert!(model.get(&id(0x01)).is_some());
+
+    // removing root drops root with everything inside it
+    let removes = model.remove_folders(RemoveInventoryFolder::new(vec![root]));
+    assert_eq!(removes[0].folder_ids, vec![root]);
+    assert!(model.folders.is_empty());
+    assert!(model.items.is_empty());
+}
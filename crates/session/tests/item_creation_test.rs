@@ -0,0 +1,145 @@
+use metaverse_messages::create_inventory_item::CreateInventoryItem;
+use metaverse_messages::update_create_inventory_item::{CreatedItem, UpdateCreateInventoryItem};
+use metaverse_messages::utils::asset_type::AssetType;
+use metaverse_messages::utils::inventory_item::InventoryItem;
+use metaverse_messages::utils::permissions::Permissions;
+use metaverse_messages::utils::sale_type::SaleType;
+use metaverse_session::item_creation::ItemCreator;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn create(callback_id: u32) -> CreateInventoryItem {
+    CreateInventoryItem {
+        callback_id,
+        ..CreateInventoryItem::new(
+            Uuid::from_bytes([0x10; 16]),
+            AssetType::Notecard,
+            7,
+            0,
+            "Notes".to_string(),
+            String::new(),
+        )
+    }
+}
+
+fn created(callback_id: u32, item_id: Uuid) -> CreatedItem {
+    CreatedItem {
+        item: InventoryItem {
+            item_id,
+            folder_id: Uuid::from_bytes([0x10; 16]),
+            creator_id: Uuid::from_bytes([0xEE; 16]),
+            owner_id: Uuid::from_bytes([0xEE; 16]),
+            group_id: Uuid::nil(),
+            base_mask: Permissions::default(),
+            owner_mask: Permissions::default(),
+            group_mask: Permissions::default(),
+            everyone_mask: Permissions::default(),
+            next_owner_mask: Permissions::default(),
+            group_owned: false,
+            asset_id: Uuid::nil(),
+            asset_type: AssetType::Notecard,
+            inventory_type: 7,
+            flags: 0,
+            sale_type: SaleType::NotForSale,
+            sale_price: 0,
+            name: "Notes".to_string(),
+            description: String::new(),
+            creation_date: 0,
+        },
+        callback_id,
+        crc: 0,
+    }
+}
+
+fn update(items: Vec<CreatedItem>) -> UpdateCreateInventoryItem {
+    UpdateCreateInventoryItem {
+        agent_id: Uuid::from_bytes([0xEE; 16]),
+        sim_approved: true,
+        transaction_id: Uuid::nil(),
+        items,
+    }
+}
+
+#[test]
+fn test_reply_finishes_request_by_callback_id() {
+    let now = Instant::now();
+    let mut creator = ItemCreator::new(TIMEOUT);
+    let (first, mut first_creation) = creator.request(create(5), now);
+    let (second, mut second_creation) = creator.request(create(5), now);
+    // the UI reused its callback id, but the requests are sent with different ones
+    assert_ne!(first.callback_id, second.callback_id);
+    assert_ne!(first.callback_id, 0);
+
+    let item_id = Uuid::from_bytes([0x01; 16]);
+    let events = creator.handle_update(&update(vec![created(second.callback_id, item_id)]));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].callback_id, 5);
+    assert_eq!(events[0].item.as_ref().unwrap().item_id, item_id);
+    assert_eq!(
+        second_creation
+            .completion
+            .try_recv()
+            .unwrap()
+            .unwrap()
+            .item_id,
+        item_id
+    );
+    assert!(first_creation.completion.try_recv().is_err());
+    assert!(creator.pending.contains_key(&first.callback_id));
+}
+
+#[test]
+fn test_unrequested_items_are_ignored() {
+    let now = Instant::now();
+    let mut creator = ItemCreator::new(TIMEOUT);
+    let (request, _creation) = creator.request(create(1), now);
+    let events = creator.handle_update(&update(vec![
+        created(0, Uuid::from_bytes([0x01; 16])),
+        created(request.callback_id + 1, Uuid::from_bytes([0x02; 16])),
+    ]));
+    assert!(events.is_empty());
+    assert_eq!(creator.pending.len(), 1);
+}
+
+#[test]
+fn test_callback_ids_skip_zero_and_pending() {
+    let now = Instant::now();
+    let mut creator = ItemCreator::new(TIMEOUT);
+    let (request, _creation) = creator.request(create(0), now);
+    assert_eq!(request.callback_id, 1);
+    // wrap around onto the request that is still waiting
+    creator.next_callback_id = u32::MAX;
+    let (last, _last_creation) = creator.request(create(0), now);
+    assert_eq!(last.callback_id, u32::MAX);
+    let (wrapped, _wrapped_creation) = creator.request(create(0), now);
+    assert_eq!(wrapped.callback_id, 2);
+}
+
+#[test]
+fn test_request_times_out() {
+    let now = Instant::now();
+    let mut creator = ItemCreator::new(TIMEOUT);
+    let (_, mut creation) = creator.request(create(3), now);
+    assert!(creator.expire(now + TIMEOUT / 2).is_empty());
+
+    let events = creator.expire(now + TIMEOUT);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].callback_id, 3);
+    assert!(events[0].item.is_none());
+    assert!(events[0].reason.is_some());
+    assert!(creation.completion.try_recv().unwrap().is_err());
+    assert!(creator.pending.is_empty());
+}
+
+#[test]
+fn test_cancel_all_fails_requests() {
+    let now = Instant::now();
+    let mut creator = ItemCreator::new(TIMEOUT);
+    let (_, mut creation) = creator.request(create(4), now);
+    let events = creator.cancel_all();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].reason.as_deref(), Some("session ended"));
+    assert!(creation.completion.try_recv().unwrap().is_err());
+}
@@ -0,0 +1,115 @@
+use glam::Vec3;
+use metaverse_messages::land_stat_reply::LandStatReply;
+use metaverse_messages::utils::land_stat::{LandStatEntry, LandStatFlags, LandStatReportType};
+use metaverse_session::land_stat::LandStatManager;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn entry(index: u32, score: f32) -> LandStatEntry {
+    LandStatEntry {
+        task_local_id: index,
+        task_id: Uuid::from_u128(index as u128 + 1),
+        position: Vec3::ZERO,
+        score,
+        task_name: format!("Object {}", index),
+        owner_name: String::new(),
+    }
+}
+
+fn page(
+    report_type: LandStatReportType,
+    total_object_count: u32,
+    entries: Vec<LandStatEntry>,
+) -> LandStatReply {
+    LandStatReply {
+        report_type,
+        request_flags: LandStatFlags::empty(),
+        total_object_count,
+        entries,
+        extended: Vec::new(),
+    }
+}
+
+#[test]
+fn test_report_gathered_and_ranked() {
+    let mut land_stats = LandStatManager::new(TIMEOUT);
+    let start = Instant::now();
+    let scripts = LandStatReportType::TopScripts;
+    land_stats.start(scripts, start);
+
+    let first = page(scripts, 3, vec![entry(1, 0.5), entry(2, 3.0)]);
+    assert!(land_stats.add(&first, start).is_none());
+    let report = land_stats
+        .add(&page(scripts, 3, vec![entry(3, 1.5)]), start)
+        .unwrap();
+    assert!(report.complete);
+    assert_eq!(report.total_object_count, 3);
+    let ranked: Vec<u32> = report.entries.iter().map(|e| e.task_local_id).collect();
+    assert_eq!(ranked, vec![2, 3, 1]);
+    assert!(land_stats.reports.is_empty());
+}
+
+#[test]
+fn test_empty_report() {
+    let mut land_stats = LandStatManager::new(TIMEOUT);
+    let start = Instant::now();
+    let colliders = LandStatReportType::TopColliders;
+    land_stats.start(colliders, start);
+    let report = land_stats
+        .add(&page(colliders, 0, Vec::new()), start)
+        .unwrap();
+    assert!(report.complete);
+    assert!(report.entries.is_empty());
+}
+
+#[test]
+fn test_report_types_kept_apart() {
+    let mut land_stats = LandStatManager::new(TIMEOUT);
+    let start = Instant::now();
+    let scripts = LandStatReportType::TopScripts;
+    let colliders = LandStatReportType::TopColliders;
+    land_stats.start(scripts, start);
+    land_stats.start(colliders, start);
+    assert!(land_stats
+        .add(&page(scripts, 2, vec![entry(1, 1.0)]), start)
+        .is_none());
+    let report = land_stats
+        .add(&page(colliders, 1, vec![entry(9, 4.0)]), start)
+        .unwrap();
+    assert_eq!(report.report_type, colliders);
+    assert_eq!(land_stats.reports.len(), 1);
+
+    // asking again starts the report over
+    land_stats.start(scripts, start);
+    assert!(land_stats.reports[&scripts].entries.is_empty());
+}
+
+#[test]
+fn test_report_timeout() {
+    let mut land_stats = LandStatManager::new(TIMEOUT);
+    let start = Instant::now();
+    let scripts = LandStatReportType::TopScripts;
+    let colliders = LandStatReportType::TopColliders;
+    land_stats.start(scripts, start);
+    land_stats.start(colliders, start);
+    assert!(land_stats
+        .add(&page(scripts, 4, vec![entry(1, 1.0)]), start + TIMEOUT / 2)
+        .is_none());
+
+    // the colliders report never got a page, the scripts report stopped getting them
+    let expired = land_stats.expire(start + TIMEOUT);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].report_type, colliders);
+    assert!(!expired[0].complete);
+    assert!(expired[0].entries.is_empty());
+
+    let expired = land_stats.expire(start + TIMEOUT * 2);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].report_type, scripts);
+    assert!(!expired[0].complete);
+    assert_eq!(expired[0].total_object_count, 4);
+    assert_eq!(expired[0].entries.len(), 1);
+    assert!(land_stats.reports.is_empty());
+}
@@ -2,27 +2,23 @@ use actix::System;
 use crossbeam_channel::{unbounded, Sender};
 use log::{error, info, LevelFilter};
 use metaverse_messages::packet_types::PacketType;
-use tempfile::NamedTempFile;
+use portpicker::pick_unused_port;
 
 use metaverse_messages::login_system::login::Login;
 use metaverse_messages::packet::Packet;
 use metaverse_session::client_subscriber::listen_for_server_events;
 use metaverse_session::initialize::initialize;
 
-use std::os::unix::net::UnixDatagram;
-use std::path::PathBuf;
+use std::net::UdpSocket;
 use std::thread::sleep;
 use std::time::Duration;
 
 #[test]
 fn test_login() {
-    let incoming_socket_path = NamedTempFile::new()
-        .expect("Failed to create temp file")
-        .path()
-        .to_path_buf();
+    let ui_to_server_socket = pick_unused_port().unwrap();
 
     let (sender, receiver) = unbounded();
-    init_tests(incoming_socket_path.clone(), sender);
+    init_tests(ui_to_server_socket, sender);
 
     // wait for the mailbox to be ready. This can be done in a better way.
     sleep(Duration::from_secs(2));
@@ -37,8 +33,8 @@ fn test_login() {
         url: build_test_url("http://127.0.0.1", 9000).to_string(),
     })
     .to_bytes();
-    let client_socket = UnixDatagram::unbound().unwrap();
-    match client_socket.send_to(&message, &incoming_socket_path) {
+    let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+    match client_socket.send_to(&message, format!("127.0.0.1:{}", ui_to_server_socket)) {
         Ok(_) => println!("message sent to mailbox"),
         Err(e) => println!("error sending to mailbox {:?}", e),
     };
@@ -62,13 +58,10 @@ fn test_login() {
 
 #[test]
 fn test_empty_login() {
-    let incoming_socket_path = NamedTempFile::new()
-        .expect("Failed to create temp file")
-        .path()
-        .to_path_buf();
+    let ui_to_server_socket = pick_unused_port().unwrap();
 
     let (sender, receiver) = unbounded();
-    init_tests(incoming_socket_path.clone(), sender);
+    init_tests(ui_to_server_socket, sender);
 
     // wait for the mailbox to be ready. This can be done in a better way.
     sleep(Duration::from_secs(2));
@@ -83,8 +76,8 @@ fn test_empty_login() {
         url: "".to_string(),
     })
     .to_bytes();
-    let client_socket = UnixDatagram::unbound().unwrap();
-    match client_socket.send_to(&message, &incoming_socket_path) {
+    let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+    match client_socket.send_to(&message, format!("127.0.0.1:{}", ui_to_server_socket)) {
         Ok(_) => println!("message sent to mailbox"),
         Err(e) => println!("error sending to mailbox {:?}", e),
     };
@@ -108,13 +101,10 @@ fn test_empty_login() {
 
 #[test]
 fn test_invalid_password_login() {
-    let incoming_socket_path = NamedTempFile::new()
-        .expect("Failed to create temp file")
-        .path()
-        .to_path_buf();
+    let ui_to_server_socket = pick_unused_port().unwrap();
 
     let (sender, receiver) = unbounded();
-    init_tests(incoming_socket_path.clone(), sender);
+    init_tests(ui_to_server_socket, sender);
 
     // wait for the mailbox to be ready. This can be done in a better way.
     sleep(Duration::from_secs(2));
@@ -129,8 +119,8 @@ fn test_invalid_password_login() {
         url: build_test_url("http://127.0.0.1", 9000).to_string(),
     })
     .to_bytes();
-    let client_socket = UnixDatagram::unbound().unwrap();
-    match client_socket.send_to(&message, &incoming_socket_path) {
+    let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+    match client_socket.send_to(&message, format!("127.0.0.1:{}", ui_to_server_socket)) {
         Ok(_) => println!("message sent to mailbox"),
         Err(e) => println!("error sending to mailbox {:?}", e),
     };
@@ -151,13 +141,10 @@ fn test_invalid_password_login() {
 
 #[test]
 fn test_already_present_login() {
-    let incoming_socket_path = NamedTempFile::new()
-        .expect("Failed to create temp file")
-        .path()
-        .to_path_buf();
+    let ui_to_server_socket = pick_unused_port().unwrap();
 
     let (sender, receiver) = unbounded();
-    init_tests(incoming_socket_path.clone(), sender);
+    init_tests(ui_to_server_socket, sender);
 
     // wait for the mailbox to be ready. This can be done in a better way.
     sleep(Duration::from_secs(2));
@@ -172,14 +159,14 @@ fn test_already_present_login() {
         url: build_test_url("http://127.0.0.1", 9000).to_string(),
     })
     .to_bytes();
-    let client_socket = UnixDatagram::unbound().unwrap();
-    match client_socket.send_to(&message, &incoming_socket_path) {
+    let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+    match client_socket.send_to(&message, format!("127.0.0.1:{}", ui_to_server_socket)) {
         Ok(_) => println!("message sent to mailbox"),
         Err(e) => println!("error sending to mailbox {:?}", e),
     };
 
-    let client_socket = UnixDatagram::unbound().unwrap();
-    match client_socket.send_to(&message, &incoming_socket_path) {
+    let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+    match client_socket.send_to(&message, format!("127.0.0.1:{}", ui_to_server_socket)) {
         Ok(_) => println!("message sent to mailbox"),
         Err(e) => println!("error sending to mailbox {:?}", e),
     };
@@ -194,8 +181,8 @@ fn test_already_present_login() {
         url: build_test_url("http://127.0.0.1", 9000).to_string(),
     })
     .to_bytes();
-    let client_socket = UnixDatagram::unbound().unwrap();
-    match client_socket.send_to(&message, &incoming_socket_path) {
+    let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+    match client_socket.send_to(&message, format!("127.0.0.1:{}", ui_to_server_socket)) {
         Ok(_) => println!("message sent to mailbox"),
         Err(e) => println!("error sending to mailbox {:?}", e),
     };
@@ -215,29 +202,22 @@ fn test_already_present_login() {
     }
 }
 
-fn init_tests(incoming_socket_path: PathBuf, sender: Sender<PacketType>) {
+fn init_tests(ui_to_server_socket: u16, sender: Sender<PacketType>) {
     init_logger();
-    let outgoing_socket_path = NamedTempFile::new()
-        .expect("Failed to create temp file")
-        .path()
-        .to_path_buf();
-    let outgoing_socket_path_clone = outgoing_socket_path.clone();
-    let outgoing_socket_path_clone2 = outgoing_socket_path.clone();
+    let server_to_ui_socket = pick_unused_port().unwrap();
 
-    let incoming_socket_path_clone = incoming_socket_path.clone();
-
-    println!("Starting outgoing UDS listener");
+    println!("Starting outgoing UDP listener");
 
     std::thread::spawn(move || {
         let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
         rt.block_on(async {
-            listen_for_server_events(outgoing_socket_path_clone, sender).await;
+            listen_for_server_events(format!("127.0.0.1:{}", server_to_ui_socket), sender).await;
         });
     });
 
-    std::thread::spawn(|| {
+    std::thread::spawn(move || {
         System::new().block_on(async {
-            match initialize(incoming_socket_path_clone, outgoing_socket_path_clone2).await {
+            match initialize(ui_to_server_socket, server_to_ui_socket).await {
                 Ok(handle) => {
                     match handle.await {
                         Ok(()) => info!("Listener exited successfully!"),
@@ -0,0 +1,98 @@
+use actix::System;
+use crossbeam_channel::{unbounded, Sender};
+use log::{error, info, LevelFilter};
+use metaverse_messages::logout_request::LogoutRequest;
+use metaverse_messages::packet_types::PacketType;
+use portpicker::pick_unused_port;
+
+use metaverse_messages::login_system::login::Login;
+use metaverse_messages::packet::Packet;
+use metaverse_session::client_subscriber::listen_for_server_events;
+use metaverse_session::initialize::initialize;
+
+use std::net::{TcpListener, UdpSocket};
+use std::thread::sleep;
+use std::time::Duration;
+use uuid::Uuid;
+
+#[test]
+fn test_logout_cancels_pending_login() {
+    let ui_to_server_socket = pick_unused_port().unwrap();
+
+    let (sender, receiver) = unbounded();
+    init_tests(ui_to_server_socket, sender);
+
+    // a login server that accepts the connection but never answers, so the login stays pending
+    let login_server = TcpListener::bind("127.0.0.1:0").unwrap();
+    let login_port = login_server.local_addr().unwrap().port();
+
+    // wait for the mailbox to be ready. This can be done in a better way.
+    sleep(Duration::from_secs(2));
+    let login = Packet::new_login_packet(Login {
+        first: "default".to_string(),
+        last: "user".to_string(),
+        passwd: "password".to_string(),
+        start: "home".to_string(),
+        channel: "benthic".to_string(),
+        agree_to_tos: true,
+        read_critical: true,
+        url: format!("http://127.0.0.1:{}", login_port),
+    })
+    .to_bytes();
+    // the UI doesn't have a session yet, so the ids are left empty
+    let logout = Packet::new_logout_request(LogoutRequest {
+        agent_id: Uuid::nil(),
+        session_id: Uuid::nil(),
+    })
+    .to_bytes();
+
+    let client_socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+    let server = format!("127.0.0.1:{}", ui_to_server_socket);
+    client_socket.send_to(&login, &server).unwrap();
+    sleep(Duration::from_millis(500));
+    client_socket.send_to(&logout, &server).unwrap();
+
+    match receiver.recv_timeout(Duration::from_secs(3)) {
+        Ok(PacketType::Disconnect(disconnect)) => {
+            assert_eq!(disconnect.reason, "login cancelled")
+        }
+        Ok(event) => panic!("expected Disconnect, got {:?}", event),
+        Err(e) => panic!("no disconnect received {:?}", e),
+    }
+    drop(login_server);
+}
+
+fn init_tests(ui_to_server_socket: u16, sender: Sender<PacketType>) {
+    init_logger();
+    let server_to_ui_socket = pick_unused_port().unwrap();
+
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+        rt.block_on(async {
+            listen_for_server_events(format!("127.0.0.1:{}", server_to_ui_socket), sender).await;
+        });
+    });
+
+    std::thread::spawn(move || {
+        System::new().block_on(async {
+            match initialize(ui_to_server_socket, server_to_ui_socket).await {
+                Ok(handle) => {
+                    match handle.await {
+                        Ok(()) => info!("Listener exited successfully!"),
+                        Err(e) => error!("Listener exited with error {:?}", e),
+                    };
+                }
+                Err(err) => {
+                    error!("Failed to start client: {:?}", err);
+                }
+            }
+        });
+    });
+}
+
+fn init_logger() {
+    let _ = env_logger::builder()
+        .filter(None, LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+}
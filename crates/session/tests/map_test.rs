@@ -0,0 +1,253 @@
+use metaverse_messages::map_block_reply::{MapBlock, MapBlockReply};
+use metaverse_messages::map_block_request::MapLayer;
+use metaverse_messages::map_blocks::MapBlocks;
+use metaverse_messages::map_item_reply::{MapItem, MapItemReply};
+use metaverse_messages::map_item_request::MapItemType;
+use metaverse_messages::utils::agent_access::AgentAccess;
+use metaverse_session::map::{
+    MapBlockManager, MapBlocksOutcome, MapItemManager, MapSearchManager, MAP_SEARCH_TAG_SHIFT,
+};
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const WINDOW: Duration = Duration::from_millis(500);
+
+fn reply(flags: u32, blocks: &[(u16, u16, AgentAccess)]) -> MapBlockReply {
+    MapBlockReply {
+        agent_id: Uuid::nil(),
+        flags,
+        blocks: blocks
+            .iter()
+            .map(|(x, y, access)| MapBlock {
+                x: *x,
+                y: *y,
+                name: format!("{} {}", x, y),
+                access: access.clone(),
+                region_flags: 0,
+                water_height: 20,
+                agents: 0,
+                map_image_id: Uuid::nil(),
+                size_x: 256,
+                size_y: 256,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_replies_are_gathered() {
+    let mut manager = MapBlockManager::new(WINDOW);
+    let start = Instant::now();
+    manager.handle_reply(reply(0, &[(1000, 1000, AgentAccess::PG)]), start);
+    manager.handle_reply(
+        reply(0, &[(1001, 1000, AgentAccess::Mature)]),
+        start + Duration::from_millis(300),
+    );
+    // the second reply restarts the window
+    assert!(manager.flush(start + WINDOW).is_empty());
+
+    let flushed = manager.flush(start + Duration::from_millis(800));
+    assert_eq!(flushed.len(), 1);
+    assert_eq!(flushed[0].flags, 0);
+    assert_eq!(flushed[0].blocks.len(), 2);
+    assert!(manager.pending.is_empty());
+    assert!(manager.flush(start + Duration::from_secs(5)).is_empty());
+}
+
+#[test]
+fn test_replies_are_kept_apart_by_flags() {
+    let mut manager = MapBlockManager::new(WINDOW);
+    let start = Instant::now();
+    manager.handle_reply(reply(0, &[(1000, 1000, AgentAccess::PG)]), start);
+    manager.handle_reply(reply(2, &[(1000, 1000, AgentAccess::PG)]), start);
+    let mut flushed = manager.flush(start + WINDOW);
+    flushed.sort_by_key(|map_blocks| map_blocks.flags);
+    assert_eq!(flushed.len(), 2);
+    assert_eq!(flushed[0].flags, 0);
+    assert_eq!(flushed[1].flags, 2);
+}
+
+#[test]
+fn test_duplicate_regions_are_kept_once() {
+    let mut manager = MapBlockManager::new(WINDOW);
+    let start = Instant::now();
+    manager.handle_reply(reply(0, &[(1000, 1000, AgentAccess::PG)]), start);
+    manager.handle_reply(reply(0, &[(1000, 1000, AgentAccess::Adult)]), start);
+    let flushed = manager.flush(start + WINDOW);
+    assert_eq!(flushed[0].blocks.len(), 1);
+    assert_eq!(flushed[0].blocks[0].access, AgentAccess::Adult);
+}
+
+#[test]
+fn test_nonexistent_regions_are_sent_on() {
+    let mut manager = MapBlockManager::new(WINDOW);
+    let start = Instant::now();
+    manager.handle_reply(
+        reply(
+            0x10000,
+            &[
+                (1000, 1000, AgentAccess::PG),
+                (1001, 1000, AgentAccess::NonExistent),
+            ],
+        ),
+        start,
+    );
+    let flushed = manager.flush(start + WINDOW);
+    let missing: Vec<&MapBlock> = flushed[0]
+        .blocks
+        .iter()
+        .filter(|block| !block.exists())
+        .collect();
+    assert_eq!(missing.len(), 1);
+    assert_eq!((missing[0].x, missing[0].y), (1001, 1000));
+}
+
+#[test]
+fn test_clear() {
+    let mut manager = MapBlockManager::new(WINDOW);
+    let start = Instant::now();
+    manager.handle_reply(reply(0, &[(1000, 1000, AgentAccess::PG)]), start);
+    manager.clear();
+    assert!(manager.flush(start + WINDOW).is_empty());
+}
+
+fn item_reply(item_type: MapItemType, counts: &[i32]) -> MapItemReply {
+    MapItemReply {
+        agent_id: Uuid::nil(),
+        flags: 2,
+        item_type,
+        items: counts
+            .iter()
+            .map(|count| MapItem {
+                x: 256_000,
+                y: 256_000,
+                id: Uuid::nil(),
+                extra: *count,
+                extra2: 0,
+                name: String::new(),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_map_items_are_gathered_by_item_type() {
+    let mut manager = MapItemManager::new(WINDOW);
+    let start = Instant::now();
+    manager.handle_reply(item_reply(MapItemType::AgentLocations, &[2, 1]), start);
+    manager.handle_reply(item_reply(MapItemType::LandForSale, &[512]), start);
+    manager.handle_reply(
+        item_reply(MapItemType::AgentLocations, &[4]),
+        start + Duration::from_millis(300),
+    );
+
+    let flushed = manager.flush(start + WINDOW);
+    assert_eq!(flushed.len(), 1);
+    assert_eq!(flushed[0].item_type, MapItemType::LandForSale);
+
+    let flushed = manager.flush(start + Duration::from_millis(800));
+    assert_eq!(flushed.len(), 1);
+    assert_eq!(flushed[0].item_type, MapItemType::AgentLocations);
+    // agent locations have no ids, so none of them are dropped as duplicates
+    assert_eq!(flushed[0].items.len(), 3);
+    assert_eq!(flushed[0].agent_count(), 7);
+    assert!(manager.pending.is_empty());
+}
+
+#[test]
+fn test_map_items_clear() {
+    let mut manager = MapItemManager::new(WINDOW);
+    let start = Instant::now();
+    manager.handle_reply(item_reply(MapItemType::AgentLocations, &[1]), start);
+    manager.clear();
+    assert!(manager.flush(start + WINDOW).is_empty());
+}
+
+const SEARCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[test]
+fn test_search_results_are_told_apart_by_tag() {
+    let mut blocks = MapBlockManager::new(WINDOW);
+    let mut searches = MapSearchManager::new(SEARCH_TIMEOUT);
+    let start = Instant::now();
+    let flags = searches.start("Da Bo".to_string(), 0, start);
+    assert_ne!(flags >> MAP_SEARCH_TAG_SHIFT, 0);
+
+    blocks.handle_reply(reply(flags, &[(1000, 1000, AgentAccess::PG)]), start);
+    blocks.handle_reply(reply(0, &[(1001, 1000, AgentAccess::PG)]), start);
+    let mut outcomes: Vec<MapBlocksOutcome> = blocks
+        .flush(start + WINDOW)
+        .into_iter()
+        .map(|map_blocks| searches.finish(map_blocks))
+        .collect();
+    outcomes.sort_by_key(|outcome| matches!(outcome, MapBlocksOutcome::Blocks(_)));
+    match &outcomes[..] {
+        [MapBlocksOutcome::Found(results), MapBlocksOutcome::Blocks(map_blocks)] => {
+            assert_eq!(results.name, "Da Bo");
+            assert_eq!(results.layer, MapLayer::Objects);
+            assert_eq!((results.blocks[0].x, results.blocks[0].y), (1000, 1000));
+            assert_eq!(
+                (map_blocks.blocks[0].x, map_blocks.blocks[0].y),
+                (1001, 1000)
+            );
+        }
+        _ => panic!("expected search results and map blocks"),
+    }
+    assert!(searches.searches.is_empty());
+}
+
+#[test]
+fn test_search_keeps_only_the_layer() {
+    let mut searches = MapSearchManager::new(SEARCH_TIMEOUT);
+    let first = searches.start("a".to_string(), 1 | 0x10000 | 0xFF00_0000, Instant::now());
+    let second = searches.start("b".to_string(), 1, Instant::now());
+    assert_eq!(first & 0x00FF_FFFF, 1);
+    assert_ne!(first, second);
+    assert_eq!(searches.searches.len(), 2);
+}
+
+#[test]
+fn test_search_answered_with_another_layer() {
+    let mut searches = MapSearchManager::new(SEARCH_TIMEOUT);
+    let flags = searches.start("Da Bo".to_string(), 1, Instant::now());
+    // the grid answers a terrain search with its objects layer
+    let answer = MapBlocks {
+        flags: flags & !1,
+        blocks: reply(0, &[(1000, 1000, AgentAccess::PG)]).blocks,
+    };
+    match searches.finish(answer) {
+        MapBlocksOutcome::Found(results) => assert_eq!(results.layer, MapLayer::Terrain),
+        _ => panic!("expected search results"),
+    }
+}
+
+#[test]
+fn test_search_found_nothing() {
+    let mut searches = MapSearchManager::new(SEARCH_TIMEOUT);
+    let flags = searches.start("Nowhere".to_string(), 0, Instant::now());
+    // a search that finds nothing is answered with a single zeroed block
+    let mut answer = reply(flags, &[(0, 0, AgentAccess::Unknown)]);
+    answer.blocks[0].name = String::new();
+    let answer = MapBlocks {
+        flags,
+        blocks: answer.blocks,
+    };
+    match searches.finish(answer) {
+        MapBlocksOutcome::Empty(empty) => assert_eq!(empty.name, "Nowhere"),
+        _ => panic!("expected empty search results"),
+    }
+    assert!(searches.searches.is_empty());
+}
+
+#[test]
+fn test_unanswered_search_expires() {
+    let mut searches = MapSearchManager::new(SEARCH_TIMEOUT);
+    let start = Instant::now();
+    searches.start("Nowhere".to_string(), 0, start);
+    assert!(searches.expire(start + Duration::from_secs(5)).is_empty());
+    let expired = searches.expire(start + SEARCH_TIMEOUT);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].name, "Nowhere");
+    assert!(searches.searches.is_empty());
+}
@@ -0,0 +1,34 @@
+use metaverse_messages::agent_update::{AgentUpdate, Camera};
+use metaverse_session::movement::MovementManager;
+
+#[test]
+fn test_held_controls_are_sent_again() {
+    let mut manager = MovementManager::new();
+    assert!(manager.tick().is_none());
+
+    let walking = AgentUpdate::walking_forward(Camera::default());
+    manager.handle_update(walking.clone());
+    assert_eq!(manager.tick(), Some(walking.clone()));
+    assert_eq!(manager.tick(), Some(walking));
+
+    let flying = AgentUpdate::flying_forward(Camera::default());
+    manager.handle_update(flying.clone());
+    assert_eq!(manager.tick(), Some(flying));
+}
+
+#[test]
+fn test_stop_ends_movement() {
+    let mut manager = MovementManager::new();
+    manager.handle_update(AgentUpdate::running_forward(Camera::default()));
+    manager.handle_update(AgentUpdate::stopped(Camera::default()));
+    assert!(manager.tick().is_none());
+}
+
+#[test]
+fn test_clear_ends_movement() {
+    let mut manager = MovementManager::new();
+    manager.handle_update(AgentUpdate::flying(Camera::default()));
+    manager.clear();
+    assert!(manager.active.is_none());
+    assert!(manager.tick().is_none());
+}
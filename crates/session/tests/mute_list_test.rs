@@ -0,0 +1,124 @@
+use glam::Vec3;
+use metaverse_messages::chat_from_simulator::{Audible, ChatFromSimulator, ChatType, SourceType};
+use metaverse_messages::improved_instant_message::{ImprovedInstantMessage, InstantMessageDialog};
+use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::utils::mute::{MuteEntry, MuteFlags, MuteType};
+use metaverse_session::mute_list::MuteListManager;
+use uuid::Uuid;
+
+// an entry that mutes everything
+fn muted(id: Uuid, name: &str, mute_type: MuteType) -> MuteEntry {
+    MuteEntry {
+        id,
+        name: name.to_string(),
+        mute_type,
+        flags: MuteFlags::empty(),
+    }
+}
+
+fn chat(source_type: SourceType, source_id: Uuid, owner_id: Uuid, name: &str) -> PacketType {
+    PacketType::ChatFromSimulator(Box::new(ChatFromSimulator {
+        from_name: name.to_string(),
+        source_id,
+        owner_id,
+        source_type,
+        chat_type: ChatType::Normal,
+        audible: Audible::Fully,
+        position: Vec3::ZERO,
+        message: "hello".to_string(),
+    }))
+}
+
+fn instant_message(from_agent_id: Uuid, name: &str) -> PacketType {
+    PacketType::ImprovedInstantMessage(Box::new(ImprovedInstantMessage {
+        from_agent_id,
+        session_id: Uuid::nil(),
+        from_group: false,
+        to_agent_id: Uuid::new_v4(),
+        parent_estate_id: 1,
+        region_id: Uuid::nil(),
+        position: Vec3::ZERO,
+        offline: 0,
+        dialog: InstantMessageDialog::MessageFromAgent,
+        id: Uuid::new_v4(),
+        timestamp: 0,
+        from_agent_name: name.to_string(),
+        message: "hello".to_string(),
+        binary_bucket: vec![],
+    }))
+}
+
+#[test]
+fn test_download_loads_the_list() {
+    let mut manager = MuteListManager::new();
+    let file = b"1 8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90 Default User|0\n";
+    // files that weren't asked for are left alone
+    assert!(manager.handle_download("mutes", file).is_none());
+
+    manager.pending_file = Some("mutes".to_string());
+    let mute_list = manager.handle_download("mutes", file).unwrap();
+    assert_eq!(mute_list.entries.len(), 1);
+    assert_eq!(manager.entries, mute_list.entries);
+    assert!(manager.pending_file.is_none());
+}
+
+#[test]
+fn test_blocks_muted_chat_and_instant_messages() {
+    let mut manager = MuteListManager::new();
+    let agent_id = Uuid::new_v4();
+    let owner_id = Uuid::new_v4();
+    let other_id = Uuid::new_v4();
+    manager.update(muted(agent_id, "Rude User", MuteType::Agent));
+    manager.update(muted(owner_id, "Griefer", MuteType::Agent));
+    manager.update(muted(Uuid::nil(), "Spam Box", MuteType::ByName));
+
+    assert!(manager.blocks(&chat(SourceType::Agent, agent_id, agent_id, "Rude User")));
+    assert!(manager.blocks(&instant_message(agent_id, "Rude User")));
+    // objects are muted with their owner
+    assert!(manager.blocks(&chat(SourceType::Object, Uuid::new_v4(), owner_id, "Cube")));
+    assert!(manager.blocks(&chat(SourceType::Object, other_id, other_id, "Spam Box")));
+
+    assert!(!manager.blocks(&chat(SourceType::Agent, other_id, other_id, "Nice User")));
+    assert!(!manager.blocks(&instant_message(other_id, "Nice User")));
+    assert!(!manager.blocks(&chat(SourceType::System, agent_id, agent_id, "Rude User")));
+}
+
+#[test]
+fn test_entries_that_let_chat_through() {
+    let mut manager = MuteListManager::new();
+    let agent_id = Uuid::new_v4();
+    manager.update(MuteEntry {
+        flags: MuteFlags::TEXT_CHAT,
+        ..muted(agent_id, "Loud User", MuteType::Agent)
+    });
+    assert!(!manager.blocks(&instant_message(agent_id, "Loud User")));
+
+    // updating the entry replaces it
+    manager.update(MuteEntry {
+        flags: MuteFlags::VOICE_CHAT,
+        ..muted(agent_id, "Loud User", MuteType::Agent)
+    });
+    assert_eq!(manager.entries.len(), 1);
+    assert!(manager.blocks(&instant_message(agent_id, "Loud User")));
+}
+
+#[test]
+fn test_remove_and_clear() {
+    let mut manager = MuteListManager::new();
+    let agent_id = Uuid::new_v4();
+    manager.update(muted(agent_id, "Rude User", MuteType::Agent));
+    manager.update(muted(Uuid::nil(), "Spam Box", MuteType::ByName));
+
+    manager.remove(Uuid::nil(), "Spam Box");
+    assert_eq!(manager.list().entries.len(), 1);
+    manager.remove(agent_id, "");
+    assert!(manager.entries.is_empty());
+    assert!(!manager.blocks(&instant_message(agent_id, "Rude User")));
+
+    manager.update(muted(agent_id, "Rude User", MuteType::Agent));
+    manager.pending_file = Some("mutes".to_string());
+    manager.clear();
+    assert!(manager.entries.is_empty());
+    assert!(manager.pending_file.is_none());
+    assert!(manager.use_cached().entries.is_empty());
+}
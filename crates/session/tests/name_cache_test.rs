@@ -0,0 +1,149 @@
+use metaverse_messages::uuid_group_name_reply::{GroupName, UUIDGroupNameReply};
+use metaverse_messages::uuid_name_reply::{AvatarName, UUIDNameReply};
+use metaverse_session::name_cache::{NameCache, NAME_REQUEST_BATCH};
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn name(id: Uuid) -> AvatarName {
+    AvatarName {
+        id,
+        first_name: "Test".to_string(),
+        last_name: "Resident".to_string(),
+    }
+}
+
+fn reply(ids: &[Uuid]) -> UUIDNameReply {
+    UUIDNameReply {
+        names: ids.iter().map(|id| name(*id)).collect(),
+    }
+}
+
+#[test]
+fn test_lookups_are_batched() {
+    let now = Instant::now();
+    let mut names = NameCache::new(1000, TIMEOUT);
+    let ids: Vec<Uuid> = (0..250).map(|_| Uuid::new_v4()).collect();
+
+    let (cached, requests) = names.lookup(&ids, now);
+    assert!(cached.is_empty());
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].ids.len(), NAME_REQUEST_BATCH);
+    assert_eq!(requests[2].ids.len(), 50);
+    assert_eq!(
+        requests
+            .iter()
+            .flat_map(|request| request.ids.clone())
+            .collect::<Vec<_>>(),
+        ids
+    );
+}
+
+#[test]
+fn test_pending_lookups_are_not_repeated() {
+    let now = Instant::now();
+    let mut names = NameCache::new(1000, TIMEOUT);
+    let id = Uuid::new_v4();
+
+    let (_, requests) = names.lookup(&[id, id, Uuid::nil()], now);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].ids, vec![id]);
+    assert!(names.lookup(&[id], now + TIMEOUT / 2).1.is_empty());
+
+    // the reply never came, so the name is asked for again
+    let (_, requests) = names.lookup(&[id], now + TIMEOUT);
+    assert_eq!(requests[0].ids, vec![id]);
+}
+
+#[test]
+fn test_cached_names_are_answered_locally() {
+    let now = Instant::now();
+    let mut names = NameCache::new(1000, TIMEOUT);
+    let id = Uuid::new_v4();
+    names.lookup(&[id], now);
+
+    let resolved = names.handle_reply(reply(&[id]));
+    assert_eq!(resolved, vec![name(id)]);
+    assert!(names.avatars.pending.is_empty());
+    assert_eq!(names.get(&id).unwrap().full_name(), "Test");
+
+    let (cached, requests) = names.lookup(&[id], now);
+    assert_eq!(cached, vec![name(id)]);
+    assert!(requests.is_empty());
+}
+
+#[test]
+fn test_least_recently_used_name_is_evicted() {
+    let mut names = NameCache::new(2, TIMEOUT);
+    let (first, second, third) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    names.handle_reply(reply(&[first, second]));
+
+    // using the first name makes the second one the oldest
+    assert!(names.get(&first).is_some());
+    names.handle_reply(reply(&[third]));
+    assert_eq!(names.avatars.names.len(), 2);
+    assert!(names.get(&second).is_none());
+    assert!(names.get(&first).is_some());
+    assert!(names.get(&third).is_some());
+
+    // replacing a cached name doesn't evict anything
+    names.handle_reply(reply(&[third]));
+    assert_eq!(names.avatars.names.len(), 2);
+    assert_eq!(names.avatars.recency.len(), 2);
+}
+
+#[test]
+fn test_group_names_are_kept_apart_from_avatar_names() {
+    let now = Instant::now();
+    let mut names = NameCache::new(1000, TIMEOUT);
+    let id = Uuid::new_v4();
+
+    // looking up an avatar doesn't stop the same id being looked up as a group
+    names.lookup(&[id], now);
+    let (_, requests) = names.lookup_groups(&[id, id], now);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].ids, vec![id]);
+    assert!(names.lookup_groups(&[id], now).1.is_empty());
+
+    let group = GroupName {
+        id,
+        name: "Builders".to_string(),
+    };
+    let resolved = names.handle_group_reply(UUIDGroupNameReply {
+        names: vec![group.clone()],
+    });
+    assert_eq!(resolved, vec![group.clone()]);
+    assert!(names.groups.pending.is_empty());
+    assert!(names.get(&id).is_none());
+    assert_eq!(names.get_group(&id), Some(&group));
+}
+
+#[test]
+fn test_unrequested_group_names_are_cached() {
+    let now = Instant::now();
+    let mut names = NameCache::new(1000, TIMEOUT);
+    let requested = Uuid::new_v4();
+    let unrequested = Uuid::new_v4();
+    names.lookup_groups(&[requested], now);
+
+    let resolved = names.handle_group_reply(UUIDGroupNameReply {
+        names: vec![
+            GroupName {
+                id: requested,
+                name: "Builders".to_string(),
+            },
+            GroupName {
+                id: unrequested,
+                name: "Scripters".to_string(),
+            },
+        ],
+    });
+    assert_eq!(resolved.len(), 2);
+    assert_eq!(names.get_group(&unrequested).unwrap().name, "Scripters");
+
+    let (cached, requests) = names.lookup_groups(&[unrequested], now);
+    assert_eq!(cached[0].name, "Scripters");
+    assert!(requests.is_empty());
+}
@@ -0,0 +1,147 @@
+use metaverse_messages::object_properties_family::ObjectPropertiesFamily;
+use metaverse_messages::utils::permissions::Permissions;
+use metaverse_messages::utils::sale_type::SaleType;
+use metaverse_session::object_family::{ObjectFamilyManager, REQUEST_FLAG_SHIFT};
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn object_id(byte: u8) -> Uuid {
+    Uuid::from_bytes([byte; 16])
+}
+
+fn reply(request_flags: u32, object_id: Uuid, name: &str) -> ObjectPropertiesFamily {
+    ObjectPropertiesFamily {
+        request_flags,
+        object_id,
+        owner_id: Uuid::from_bytes([0x0A; 16]),
+        group_id: Uuid::nil(),
+        base_mask: Permissions::from_bits(0x7FFF_FFFF),
+        owner_mask: Permissions::from_bits(0x7FFF_FFFF),
+        group_mask: Permissions::from_bits(0),
+        everyone_mask: Permissions::from_bits(0),
+        next_owner_mask: Permissions::from_bits(0x0008_2000),
+        ownership_cost: 0,
+        sale_type: SaleType::Copy,
+        sale_price: 25,
+        category: 0,
+        last_owner_id: Uuid::nil(),
+        name: name.to_string(),
+        description: String::new(),
+    }
+}
+
+#[test]
+fn test_reply_carries_token() {
+    let now = Instant::now();
+    let mut manager = ObjectFamilyManager::new(TIMEOUT);
+    let request = manager.request(object_id(1), 77, now);
+    assert_eq!(request.object_id, object_id(1));
+    assert!(request.agent_id.is_nil());
+
+    let status = manager
+        .handle_reply(&reply(request.request_flags, object_id(1), "chair"))
+        .unwrap();
+    assert_eq!(status.token, 77);
+    assert_eq!(status.object_id, object_id(1));
+    assert_eq!(status.family.unwrap().name, "chair");
+    assert!(status.reason.is_none());
+    assert!(manager.pending.is_empty());
+}
+
+#[test]
+fn test_concurrent_queries_are_told_apart() {
+    let now = Instant::now();
+    let mut manager = ObjectFamilyManager::new(TIMEOUT);
+    let first = manager.request(object_id(1), 1, now);
+    // the same object hovered over again before the first answer
+    let second = manager.request(object_id(1), 2, now);
+    let third = manager.request(object_id(3), 3, now);
+    assert_ne!(first.request_flags, second.request_flags);
+    assert_ne!(second.request_flags, third.request_flags);
+
+    // answered out of order
+    let status = manager
+        .handle_reply(&reply(third.request_flags, object_id(3), "lamp"))
+        .unwrap();
+    assert_eq!(status.token, 3);
+    let status = manager
+        .handle_reply(&reply(second.request_flags, object_id(1), "chair"))
+        .unwrap();
+    assert_eq!(status.token, 2);
+    let status = manager
+        .handle_reply(&reply(first.request_flags, object_id(1), "chair"))
+        .unwrap();
+    assert_eq!(status.token, 1);
+}
+
+#[test]
+fn test_request_flags_keep_viewer_bits_clear() {
+    let now = Instant::now();
+    let mut manager = ObjectFamilyManager::new(TIMEOUT);
+    for token in 0..20 {
+        let request = manager.request(object_id(1), token, now);
+        assert_ne!(request.request_flags, 0);
+        assert_eq!(request.request_flags & ((1 << REQUEST_FLAG_SHIFT) - 1), 0);
+    }
+
+    // the sequence wraps around without reusing flags still waiting for an answer
+    let mut manager = ObjectFamilyManager::new(TIMEOUT);
+    let first = manager.request(object_id(1), 1, now);
+    manager.next_request = u32::MAX >> REQUEST_FLAG_SHIFT;
+    let last = manager.request(object_id(1), 2, now);
+    assert_eq!(
+        last.request_flags,
+        (u32::MAX >> REQUEST_FLAG_SHIFT) << REQUEST_FLAG_SHIFT
+    );
+    let wrapped = manager.request(object_id(1), 3, now);
+    assert_ne!(wrapped.request_flags, first.request_flags);
+    assert_eq!(wrapped.request_flags, 2 << REQUEST_FLAG_SHIFT);
+}
+
+#[test]
+fn test_nil_reply_is_not_found() {
+    let now = Instant::now();
+    let mut manager = ObjectFamilyManager::new(TIMEOUT);
+    let request = manager.request(object_id(1), 9, now);
+    let status = manager
+        .handle_reply(&reply(request.request_flags, Uuid::nil(), ""))
+        .unwrap();
+    assert_eq!(status.token, 9);
+    // the UI is told which object it asked about, not the nil id
+    assert_eq!(status.object_id, object_id(1));
+    assert!(status.family.is_none());
+    assert!(status.reason.is_some());
+}
+
+#[test]
+fn test_unrequested_reply_is_ignored() {
+    let mut manager = ObjectFamilyManager::new(TIMEOUT);
+    assert!(manager
+        .handle_reply(&reply(1 << REQUEST_FLAG_SHIFT, object_id(1), "chair"))
+        .is_none());
+    // a reply to a request the viewer flags would make, like paying the object
+    assert!(manager
+        .handle_reply(&reply(0x4, object_id(1), "chair"))
+        .is_none());
+}
+
+#[test]
+fn test_expire_and_cancel() {
+    let now = Instant::now();
+    let mut manager = ObjectFamilyManager::new(TIMEOUT);
+    manager.request(object_id(1), 1, now);
+    manager.request(object_id(2), 2, now + Duration::from_secs(5));
+    assert!(manager.expire(now + Duration::from_secs(9)).is_empty());
+
+    let expired = manager.expire(now + TIMEOUT);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].token, 1);
+    assert!(expired[0].family.is_none());
+
+    let cancelled = manager.cancel_all();
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled[0].token, 2);
+    assert!(manager.pending.is_empty());
+}
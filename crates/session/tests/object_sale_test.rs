@@ -0,0 +1,129 @@
+use metaverse_messages::object_properties::{ObjectProperties, ObjectPropertiesData};
+use metaverse_messages::object_sale_info::{ObjectSaleInfo, ObjectSaleInfoData};
+use metaverse_messages::utils::permissions::Permissions;
+use metaverse_messages::utils::sale_type::SaleType;
+use metaverse_session::object_sale::SaleChecker;
+use uuid::Uuid;
+
+fn properties(object_id: Uuid, owner_mask: Permissions) -> ObjectProperties {
+    ObjectProperties {
+        objects: vec![ObjectPropertiesData {
+            object_id,
+            creator_id: Uuid::new_v4(),
+            owner_id: Uuid::new_v4(),
+            group_id: Uuid::nil(),
+            creation_date: 0,
+            base_mask: owner_mask,
+            owner_mask,
+            group_mask: Permissions::default(),
+            everyone_mask: Permissions::default(),
+            next_owner_mask: Permissions::default(),
+            ownership_cost: 0,
+            sale_type: SaleType::NotForSale,
+            sale_price: 0,
+            aggregate_perms: 0,
+            aggregate_perm_textures: 0,
+            aggregate_perm_textures_owner: 0,
+            category: 0,
+            inventory_serial: 0,
+            item_id: Uuid::nil(),
+            folder_id: Uuid::nil(),
+            from_task_id: Uuid::nil(),
+            last_owner_id: Uuid::nil(),
+            name: "Chair".to_string(),
+            description: String::new(),
+            touch_name: String::new(),
+            sit_name: String::new(),
+            texture_id: Vec::new(),
+        }],
+    }
+}
+
+// a checker that has seen the properties of local id 7
+fn checker(owner_mask: Permissions) -> SaleChecker {
+    let mut sales = SaleChecker::new();
+    let object_id = Uuid::new_v4();
+    sales.object_ids.insert(7, object_id);
+    sales.handle_properties(&properties(object_id, owner_mask));
+    sales
+}
+
+fn sale(sale_type: SaleType, sale_price: i32) -> ObjectSaleInfo {
+    ObjectSaleInfo::new(vec![ObjectSaleInfoData {
+        local_id: 7,
+        sale_type,
+        sale_price,
+    }])
+}
+
+#[test]
+fn test_allowed_sales_are_kept() {
+    let sales = checker(Permissions::from_bits(
+        Permissions::COPY | Permissions::TRANSFER,
+    ));
+    for sale_type in [
+        SaleType::NotForSale,
+        SaleType::Original,
+        SaleType::Copy,
+        SaleType::Contents,
+    ] {
+        let mut sale_info = sale(sale_type, 10);
+        assert!(sales.check(&mut sale_info).is_empty());
+        assert_eq!(sale_info.objects.len(), 1);
+    }
+}
+
+#[test]
+fn test_negative_price_is_dropped() {
+    let sales = checker(Permissions::from_bits(
+        Permissions::COPY | Permissions::TRANSFER,
+    ));
+    let mut sale_info = sale(SaleType::Original, -1);
+    let warnings = sales.check(&mut sale_info);
+    assert!(sale_info.objects.is_empty());
+    assert_eq!(
+        warnings,
+        vec!["object 7: price cannot be negative".to_string()]
+    );
+}
+
+#[test]
+fn test_sale_type_must_match_permissions() {
+    // copies can't be sold of an object that can't be copied
+    let sales = checker(Permissions::from_bits(Permissions::TRANSFER));
+    assert_eq!(sales.reason(7, SaleType::Original, 10), None);
+    assert!(sales.reason(7, SaleType::Copy, 10).is_some());
+
+    // nothing can be sold that can't be transferred, but it can be taken off sale
+    let sales = checker(Permissions::from_bits(
+        Permissions::COPY | Permissions::MODIFY,
+    ));
+    assert!(sales.reason(7, SaleType::Original, 10).is_some());
+    assert!(sales.reason(7, SaleType::Contents, 10).is_some());
+    assert_eq!(sales.reason(7, SaleType::NotForSale, 0), None);
+    assert!(sales.reason(7, SaleType::Unknown(9), 10).is_some());
+}
+
+#[test]
+fn test_unknown_objects_are_dropped() {
+    let mut sales = checker(Permissions::from_bits(Permissions::TRANSFER));
+    let mut sale_info = ObjectSaleInfo::new(vec![
+        ObjectSaleInfoData {
+            local_id: 7,
+            sale_type: SaleType::Original,
+            sale_price: 10,
+        },
+        ObjectSaleInfoData {
+            local_id: 8,
+            sale_type: SaleType::Original,
+            sale_price: 10,
+        },
+    ]);
+    assert_eq!(sales.check(&mut sale_info).len(), 1);
+    assert_eq!(sale_info.objects.len(), 1);
+    assert_eq!(sale_info.objects[0].local_id, 7);
+
+    // local ids are only unique within a region
+    sales.change_region();
+    assert!(sales.reason(7, SaleType::Original, 10).is_some());
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::start_session;
+use metaverse_messages::circuit_code::CircuitCodeData;
+use metaverse_messages::header::PacketFrequency;
+use metaverse_messages::packet::Packet;
+use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::start_ping_check::StartPingCheck;
+use metaverse_session::packet_stats::{PacketStats, ParseFailure};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// a Low 999 packet, which the client doesn't implement
+const UNKNOWN: [u8; 12] = [
+    0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0xff, 0xff, 0x03, 0xe7, 0xbe, 0xef,
+];
+
+/// count a packet the way the mailbox does
+fn receive(stats: &mut PacketStats, bytes: &[u8]) -> Option<String> {
+    let packet = Packet::from_bytes(bytes).unwrap();
+    stats.received(packet.header.frequency, packet.header.id);
+    match &packet.body {
+        PacketType::Unknown(raw) => stats.unknown(raw).map(|notice| notice.body),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_packets_are_counted_by_type() {
+    let mut stats = PacketStats::new(false);
+    let ping = Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 1,
+        oldest_unacked: 0,
+    })
+    .to_bytes();
+    receive(&mut stats, &ping);
+    receive(&mut stats, &ping);
+    assert_eq!(receive(&mut stats, &UNKNOWN), None);
+
+    assert_eq!(stats.count(PacketFrequency::High, 1), 2);
+    assert_eq!(stats.count(PacketFrequency::Low, 999), 1);
+    assert_eq!(stats.count(PacketFrequency::Low, 1), 0);
+    assert_eq!(stats.unknown, 1);
+
+    stats.clear();
+    assert_eq!(stats.count(PacketFrequency::High, 1), 0);
+    assert_eq!(stats.unknown, 0);
+}
+
+#[test]
+fn test_unknown_packets_are_reported_when_asked() {
+    let mut stats = PacketStats::new(false);
+    assert_eq!(receive(&mut stats, &UNKNOWN), None);
+    stats.report_unknown = true;
+    assert_eq!(receive(&mut stats, &UNKNOWN), Some("beef".to_string()));
+    // the setting outlives the session
+    stats.clear();
+    assert!(stats.report_unknown);
+}
+
+/// count a datagram the way the mailbox does, whether or not it parses
+fn receive_datagram(stats: &mut PacketStats, bytes: &[u8]) {
+    match Packet::from_bytes(bytes) {
+        Ok(packet) => stats.received(packet.header.frequency, packet.header.id),
+        Err(_) => stats.parse_failed(ParseFailure::of(bytes)),
+    }
+}
+
+#[test]
+fn test_parse_failures_are_counted_by_reason() {
+    let mut stats = PacketStats::new(false);
+    // too short for a header
+    receive_datagram(&mut stats, &[0x40, 0x00, 0x00]);
+    // an extra header longer than the packet
+    receive_datagram(&mut stats, &[0x40, 0x00, 0x00, 0x00, 0x01, 0x09, 0x01]);
+    // StartPingCheck, High 1, without the five bytes of its body
+    receive_datagram(
+        &mut stats,
+        &[0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x01],
+    );
+    receive_datagram(&mut stats, &UNKNOWN);
+    stats.parse_failed(ParseFailure::Truncated);
+
+    assert_eq!(stats.failures(ParseFailure::Header), 2);
+    assert_eq!(
+        stats.failures(ParseFailure::Body {
+            frequency: PacketFrequency::High,
+            id: 1
+        }),
+        1
+    );
+    assert_eq!(stats.failures(ParseFailure::Truncated), 1);
+    // the packets that parsed aren't failures
+    assert_eq!(stats.count(PacketFrequency::Low, 999), 1);
+    assert_eq!(stats.count(PacketFrequency::High, 1), 0);
+
+    stats.clear();
+    assert!(stats.parse_failures.is_empty());
+}
+
+#[test]
+fn test_broken_packets_are_still_acked() {
+    let session = start_session(|_| {});
+    session
+        .mailbox
+        .do_send(Packet::new_circuit_code(CircuitCodeData {
+            code: 1234,
+            session_id: Uuid::nil(),
+            id: Uuid::nil(),
+        }));
+    assert_eq!(session.sim.recv().header.sequence_number, 1);
+
+    // a reliable StartPingCheck numbered 9, with two of the five bytes of its body, acking the
+    // CircuitCode
+    session.sim.send_bytes(&[
+        0x50, 0x00, 0x00, 0x00, 0x09, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01,
+    ]);
+    match session.sim.recv().body {
+        PacketType::PacketAck(ack) => assert_eq!(ack.packet_ids, vec![9]),
+        body => panic!("expected an ack, got {:?}", body),
+    }
+    // and the CircuitCode isn't resent
+    assert!(session.sim.recv_within(Duration::from_secs(3)).is_none());
+}
@@ -0,0 +1,116 @@
+use metaverse_messages::parcel_access_list_reply::ParcelAccessListReply;
+use metaverse_messages::parcel_access_list_request::ParcelAccessListRequest;
+use metaverse_messages::utils::parcel_access::{ParcelAccessEntry, ParcelAccessFlags};
+use metaverse_session::parcel_access::ParcelAccessManager;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+const WINDOW: Duration = Duration::from_millis(500);
+
+fn entries(flags: ParcelAccessFlags, range: std::ops::Range<u128>) -> Vec<ParcelAccessEntry> {
+    range
+        .map(|index| ParcelAccessEntry {
+            id: Uuid::from_u128(index + 1),
+            time: 0,
+            flags,
+        })
+        .collect()
+}
+
+fn reply(
+    local_id: i32,
+    sequence_id: i32,
+    flags: ParcelAccessFlags,
+    entries: Vec<ParcelAccessEntry>,
+) -> ParcelAccessListReply {
+    ParcelAccessListReply {
+        agent_id: Uuid::nil(),
+        sequence_id,
+        flags,
+        local_id,
+        entries,
+    }
+}
+
+#[test]
+fn test_lists_gathered() {
+    let mut parcel_access = ParcelAccessManager::new(WINDOW);
+    let both = ParcelAccessFlags::ACCESS | ParcelAccessFlags::BAN;
+    let request = parcel_access.start(ParcelAccessListRequest::new(7, both));
+    assert_eq!(request.local_id, 7);
+    assert_ne!(request.sequence_id, 0);
+    let sequence_id = request.sequence_id;
+
+    // the access list is split over two replies, and the ban list comes in one
+    let start = Instant::now();
+    let access = ParcelAccessFlags::ACCESS;
+    let ban = ParcelAccessFlags::BAN;
+    parcel_access.add(
+        &reply(7, sequence_id, access, entries(access, 0..48)),
+        start,
+    );
+    parcel_access.add(&reply(7, sequence_id, ban, entries(ban, 100..103)), start);
+    parcel_access.add(
+        &reply(7, sequence_id, access, entries(access, 48..60)),
+        start + WINDOW / 2,
+    );
+    // the ban list has stopped getting replies, the access list hasn't
+    let lists = parcel_access.flush(start + WINDOW);
+    assert_eq!(lists.len(), 1);
+    assert_eq!(lists[0].flags, ban);
+    assert_eq!(lists[0].entries, entries(ban, 100..103));
+
+    let lists = parcel_access.flush(start + WINDOW * 2);
+    assert_eq!(lists.len(), 1);
+    assert_eq!(lists[0].local_id, 7);
+    assert_eq!(lists[0].flags, access);
+    assert_eq!(lists[0].entries, entries(access, 0..60));
+    assert!(parcel_access.lists.is_empty());
+}
+
+#[test]
+fn test_requests_kept_apart() {
+    let mut parcel_access = ParcelAccessManager::new(WINDOW);
+    let access = ParcelAccessFlags::ACCESS;
+    let first = parcel_access.start(ParcelAccessListRequest::new(7, access));
+    let second = parcel_access.start(ParcelAccessListRequest::new(7, access));
+    assert_ne!(first.sequence_id, second.sequence_id);
+
+    let start = Instant::now();
+    parcel_access.add(
+        &reply(7, first.sequence_id, access, entries(access, 0..2)),
+        start,
+    );
+    parcel_access.add(
+        &reply(7, second.sequence_id, access, entries(access, 0..3)),
+        start,
+    );
+    let lists = parcel_access.flush(start + WINDOW);
+    assert_eq!(lists.len(), 2);
+    let mut lengths: Vec<usize> = lists.iter().map(|list| list.entries.len()).collect();
+    lengths.sort();
+    assert_eq!(lengths, vec![2, 3]);
+}
+
+#[test]
+fn test_empty_list() {
+    let mut parcel_access = ParcelAccessManager::new(WINDOW);
+    let ban = ParcelAccessFlags::BAN;
+    let request = parcel_access.start(ParcelAccessListRequest::new(3, ban));
+
+    // an empty list arrives as a single nil entry
+    let start = Instant::now();
+    let nil = ParcelAccessEntry {
+        id: Uuid::nil(),
+        time: 0,
+        flags: ParcelAccessFlags::empty(),
+    };
+    parcel_access.add(&reply(3, request.sequence_id, ban, vec![nil]), start);
+    let lists = parcel_access.flush(start + WINDOW);
+    assert_eq!(lists.len(), 1);
+    assert!(lists[0].entries.is_empty());
+
+    parcel_access.add(&reply(3, request.sequence_id, ban, Vec::new()), start);
+    parcel_access.clear();
+    assert!(parcel_access.flush(start + WINDOW).is_empty());
+}
@@ -0,0 +1,287 @@
+use glam::{Quat, Vec3};
+use metaverse_messages::improved_terse_object_update::{
+    ImprovedTerseObjectUpdate, TerseObjectUpdate,
+};
+use metaverse_messages::parcel_overlay::ParcelOverlay;
+use metaverse_messages::parcel_properties::{LandingType, ParcelProperties, ParcelStatus};
+use metaverse_messages::utils::parcel_flags::ParcelFlags;
+use metaverse_session::parcel::{ParcelOverlayAssembler, ParcelTracker};
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+const REFRESH: Duration = Duration::from_secs(30);
+
+// a parcel covering the west half of the region
+fn parcel(sequence_id: i32, name: &str) -> ParcelProperties {
+    let mut bitmap = Vec::with_capacity(512);
+    for _ in 0..64 {
+        bitmap.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]);
+    }
+    ParcelProperties {
+        request_result: ParcelProperties::RESULT_SUCCESS,
+        sequence_id,
+        snap_selection: true,
+        self_count: 0,
+        other_count: 0,
+        public_count: 0,
+        local_id: 5,
+        owner_id: Uuid::nil(),
+        is_group_owned: false,
+        auction_id: 0,
+        claim_date: 0,
+        claim_price: 0,
+        rent_price: 0,
+        aabb_min: Vec3::ZERO,
+        aabb_max: Vec3::new(128.0, 256.0, 0.0),
+        bitmap,
+        area: 32768,
+        status: ParcelStatus::Leased,
+        sim_wide_max_prims: 0,
+        sim_wide_total_prims: 0,
+        max_prims: 0,
+        total_prims: 0,
+        owner_prims: 0,
+        group_prims: 0,
+        other_prims: 0,
+        selected_prims: 0,
+        parcel_prim_bonus: 1.0,
+        other_clean_time: 0,
+        parcel_flags: ParcelFlags::default(),
+        sale_price: 0,
+        name: name.to_string(),
+        description: String::new(),
+        music_url: String::new(),
+        media_url: String::new(),
+        media_id: Uuid::nil(),
+        media_auto_scale: false,
+        group_id: Uuid::nil(),
+        pass_price: 0,
+        pass_hours: 0.0,
+        category: 0,
+        auth_buyer_id: Uuid::nil(),
+        snapshot_id: Uuid::nil(),
+        user_location: Vec3::ZERO,
+        user_look_at: Vec3::ZERO,
+        landing_type: LandingType::Anywhere,
+        region_push_override: false,
+        region_deny_anonymous: false,
+        region_deny_identified: false,
+        region_deny_transacted: false,
+        region_deny_age_unverified: None,
+        region_allow_access_override: None,
+    }
+}
+
+fn terse_update(local_id: u32, position: Vec3) -> ImprovedTerseObjectUpdate {
+    ImprovedTerseObjectUpdate {
+        region_handle: 0,
+        time_dilation: 0,
+        objects: vec![TerseObjectUpdate {
+            local_id,
+            state: 0,
+            collision_plane: None,
+            position,
+            velocity: Vec3::ZERO,
+            acceleration: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            angular_velocity: Vec3::ZERO,
+            texture_entry: Vec::new(),
+        }],
+    }
+}
+
+// a tracker that knows where the agent is
+fn tracking(position: Vec3) -> ParcelTracker {
+    let mut parcels = ParcelTracker::new(TIMEOUT, REFRESH);
+    parcels.position = Some(position);
+    parcels
+}
+
+#[test]
+fn test_nothing_is_requested_without_a_position() {
+    let mut parcels = ParcelTracker::new(TIMEOUT, REFRESH);
+    assert!(parcels.poll(Instant::now()).is_none());
+}
+
+#[test]
+fn test_parcel_under_agent_is_requested_once() {
+    let now = Instant::now();
+    let mut parcels = tracking(Vec3::new(10.0, 20.0, 25.0));
+
+    let request = parcels.poll(now).expect("expected a request");
+    assert_eq!((request.west, request.south), (8.0, 20.0));
+    assert!(request.snap_selection);
+    // no second request while the first is waiting
+    assert!(parcels.poll(now + Duration::from_secs(1)).is_none());
+    // until it times out
+    let retry = parcels.poll(now + TIMEOUT).expect("expected a retry");
+    assert_ne!(retry.sequence_id, request.sequence_id);
+}
+
+#[test]
+fn test_answer_is_sent_once() {
+    let now = Instant::now();
+    let mut parcels = tracking(Vec3::new(10.0, 20.0, 25.0));
+    let request = parcels.poll(now).unwrap();
+
+    let found = parcels
+        .handle_properties(parcel(request.sequence_id, "Sandbox"), now)
+        .expect("expected the parcel");
+    assert_eq!(found.name, "Sandbox");
+    assert!(parcels.pending.is_none());
+    // the agent is still on the parcel
+    assert!(parcels.poll(now + Duration::from_secs(1)).is_none());
+
+    // refreshing the same parcel isn't a change
+    let refresh = parcels.poll(now + REFRESH).expect("expected a refresh");
+    assert!(parcels
+        .handle_properties(parcel(refresh.sequence_id, "Sandbox"), now + REFRESH)
+        .is_none());
+    // but renaming it is
+    let refresh = parcels.poll(now + REFRESH * 2).unwrap();
+    assert!(parcels
+        .handle_properties(parcel(refresh.sequence_id, "Renamed"), now + REFRESH * 2)
+        .is_some());
+}
+
+#[test]
+fn test_stale_answers_are_dropped() {
+    let now = Instant::now();
+    let mut parcels = tracking(Vec3::new(10.0, 20.0, 25.0));
+    let first = parcels.poll(now).unwrap();
+    let second = parcels.poll(now + TIMEOUT).unwrap();
+
+    assert!(parcels
+        .handle_properties(parcel(first.sequence_id, "Old"), now + TIMEOUT)
+        .is_none());
+    assert!(parcels.pending.is_some());
+    assert!(parcels
+        .handle_properties(parcel(second.sequence_id, "New"), now + TIMEOUT)
+        .is_some());
+    // answers to requests that were never sent are dropped too
+    assert!(parcels
+        .handle_properties(parcel(-10000, "Selected"), now + TIMEOUT)
+        .is_none());
+}
+
+#[test]
+fn test_unrequested_parcel_replaces_request() {
+    let now = Instant::now();
+    let mut parcels = tracking(Vec3::new(10.0, 20.0, 25.0));
+    let request = parcels.poll(now).unwrap();
+
+    let mut entered = parcel(0, "Entered");
+    entered.snap_selection = false;
+    assert!(parcels.handle_properties(entered, now).is_some());
+    assert!(parcels
+        .handle_properties(parcel(request.sequence_id, "Requested"), now)
+        .is_none());
+}
+
+#[test]
+fn test_failed_request_waits_for_timeout() {
+    let now = Instant::now();
+    let mut parcels = tracking(Vec3::new(10.0, 20.0, 25.0));
+    let request = parcels.poll(now).unwrap();
+
+    let mut no_data = parcel(request.sequence_id, "");
+    no_data.request_result = ParcelProperties::RESULT_NO_DATA;
+    assert!(parcels.handle_properties(no_data, now).is_none());
+    assert!(parcels.current.is_none());
+    assert!(parcels.poll(now + Duration::from_secs(1)).is_none());
+    assert!(parcels.poll(now + TIMEOUT).is_some());
+}
+
+#[test]
+fn test_leaving_parcel_requests_new_one() {
+    let now = Instant::now();
+    let mut parcels = tracking(Vec3::new(10.0, 20.0, 25.0));
+    parcels.agent_local_id = Some(9);
+    let request = parcels.poll(now).unwrap();
+    parcels.handle_properties(parcel(request.sequence_id, "West"), now);
+
+    // other objects moving don't move the agent
+    parcels.handle_terse_update(&terse_update(10, Vec3::new(200.0, 20.0, 25.0)));
+    assert!(parcels.poll(now + Duration::from_secs(1)).is_none());
+
+    parcels.handle_terse_update(&terse_update(9, Vec3::new(200.0, 20.0, 25.0)));
+    let request = parcels
+        .poll(now + Duration::from_secs(1))
+        .expect("expected a request for the new parcel");
+    assert_eq!((request.west, request.south), (200.0, 20.0));
+}
+
+#[test]
+fn test_reset_forgets_region() {
+    let now = Instant::now();
+    let mut parcels = tracking(Vec3::new(10.0, 20.0, 25.0));
+    parcels.agent_local_id = Some(9);
+    let request = parcels.poll(now).unwrap();
+
+    parcels.reset();
+    assert!(parcels.position.is_none());
+    assert!(parcels.agent_local_id.is_none());
+    assert!(parcels
+        .handle_properties(parcel(request.sequence_id, "Old region"), now)
+        .is_none());
+    assert!(parcels.poll(now).is_none());
+}
+
+fn chunk(sequence_id: i32, value: u8) -> ParcelOverlay {
+    ParcelOverlay {
+        sequence_id,
+        data: vec![value; 1024],
+    }
+}
+
+#[test]
+fn test_overlay_is_sent_once_complete() {
+    let mut overlay = ParcelOverlayAssembler::new();
+    // chunks can arrive out of order
+    for sequence_id in [1, 0, 3] {
+        assert!(overlay
+            .handle_chunk(chunk(sequence_id, sequence_id as u8))
+            .is_none());
+    }
+    let grid = overlay
+        .handle_chunk(chunk(2, 2))
+        .expect("expected the whole overlay");
+    assert_eq!(grid.cells.len(), 4096);
+    assert_eq!(grid.cells[0], 0);
+    assert_eq!(grid.cells[1024 * 2], 2);
+    assert_eq!(grid.cells[4095], 3);
+    assert!(overlay.chunks.iter().all(Option::is_none));
+}
+
+#[test]
+fn test_new_overlay_discards_partial_one() {
+    let mut overlay = ParcelOverlayAssembler::new();
+    overlay.handle_chunk(chunk(0, 1));
+    overlay.handle_chunk(chunk(1, 1));
+    // the simulator starts over before the first overlay was finished
+    assert!(overlay.handle_chunk(chunk(0, 2)).is_none());
+    assert!(overlay.chunks[1].is_none());
+    overlay.handle_chunk(chunk(1, 2));
+    overlay.handle_chunk(chunk(2, 2));
+    let grid = overlay.handle_chunk(chunk(3, 2)).unwrap();
+    assert!(grid.cells.iter().all(|cell| *cell == 2));
+}
+
+#[test]
+fn test_bad_overlay_chunks_are_dropped() {
+    let mut overlay = ParcelOverlayAssembler::new();
+    assert!(overlay.handle_chunk(chunk(4, 0)).is_none());
+    assert!(overlay.handle_chunk(chunk(-1, 0)).is_none());
+    assert!(overlay.chunks.iter().all(Option::is_none));
+
+    overlay.handle_chunk(chunk(0, 0));
+    overlay.handle_chunk(chunk(1, 0));
+    overlay.handle_chunk(chunk(2, 0));
+    let short = ParcelOverlay {
+        sequence_id: 3,
+        data: vec![0; 10],
+    };
+    assert!(overlay.handle_chunk(short).is_none());
+}
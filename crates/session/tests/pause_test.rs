@@ -0,0 +1,38 @@
+use metaverse_session::pause::PauseManager;
+
+#[test]
+fn test_serial_increases_across_cycles() {
+    let mut manager = PauseManager::new();
+    let mut last = 0;
+    for _ in 0..5 {
+        let pause = manager.pause().unwrap();
+        assert!(manager.paused);
+        assert!(pause.serial_num > last);
+        let resume = manager.resume().unwrap();
+        assert!(!manager.paused);
+        assert!(resume.serial_num > pause.serial_num);
+        last = resume.serial_num;
+    }
+    assert_eq!(last, 10);
+}
+
+#[test]
+fn test_repeated_pause_sends_nothing() {
+    let mut manager = PauseManager::new();
+    assert!(manager.resume().is_none());
+    let pause = manager.pause().unwrap();
+    assert!(manager.pause().is_none());
+    // the ignored pause doesn't use up a serial number
+    assert_eq!(manager.resume().unwrap().serial_num, pause.serial_num + 1);
+    assert!(manager.resume().is_none());
+}
+
+#[test]
+fn test_serial_survives_clear() {
+    let mut manager = PauseManager::new();
+    let pause = manager.pause().unwrap();
+    manager.clear();
+    assert!(!manager.paused);
+    // a new session starts out streaming, and its first pause is still newer than the last
+    assert!(manager.pause().unwrap().serial_num > pause.serial_num);
+}
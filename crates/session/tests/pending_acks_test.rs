@@ -0,0 +1,116 @@
+use metaverse_messages::packet::{Packet, MAX_APPENDED_ACKS};
+use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::start_ping_check::StartPingCheck;
+use metaverse_session::pending_acks::PendingAcks;
+use std::net::SocketAddr;
+
+fn addr(port: u16) -> SocketAddr {
+    SocketAddr::from(([127, 0, 0, 1], port))
+}
+
+fn ping() -> Packet {
+    Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 1,
+        oldest_unacked: 0,
+    })
+}
+
+#[test]
+fn test_acks_ride_along_on_packets_to_the_same_simulator() {
+    let mut acks = PendingAcks::new();
+    acks.push(addr(9000), 1);
+    acks.push(addr(9000), 2);
+    acks.push(addr(9001), 3);
+
+    let mut packet = ping();
+    acks.append_to(&mut packet, &addr(9000));
+    assert!(packet.header.appended_acks);
+    assert_eq!(packet.header.ack_list, Some(vec![1, 2]));
+
+    // the other simulator's ack is left for a PacketAck of its own
+    let flushed = acks.flush();
+    assert_eq!(flushed.len(), 1);
+    assert_eq!(flushed[0].0, addr(9001));
+    match &flushed[0].1.body {
+        PacketType::PacketAck(ack) => assert_eq!(ack.packet_ids, vec![3]),
+        _ => panic!("expected PacketAck"),
+    }
+    assert!(acks.is_empty());
+}
+
+#[test]
+fn test_packets_without_pending_acks_are_left_alone() {
+    let mut acks = PendingAcks::new();
+    acks.push(addr(9001), 3);
+    let mut packet = ping();
+    acks.append_to(&mut packet, &addr(9000));
+    assert!(!packet.header.appended_acks);
+    assert_eq!(packet.header.ack_list, None);
+    assert!(!acks.is_empty());
+}
+
+#[test]
+fn test_flush_splits_long_lists_of_acks() {
+    let mut acks = PendingAcks::new();
+    for sequence_number in 0..300 {
+        acks.push(addr(9000), sequence_number);
+    }
+    let flushed = acks.flush();
+    let lengths: Vec<usize> = flushed
+        .iter()
+        .map(|(_, packet)| match &packet.body {
+            PacketType::PacketAck(ack) => ack.packet_ids.len(),
+            _ => panic!("expected PacketAck"),
+        })
+        .collect();
+    assert_eq!(lengths, vec![MAX_APPENDED_ACKS, 300 - MAX_APPENDED_ACKS]);
+    assert!(acks.flush().is_empty());
+}
+
+fn acked(packet: &Packet) -> Vec<u32> {
+    match &packet.body {
+        PacketType::PacketAck(ack) => ack.packet_ids.clone(),
+        _ => packet.header.ack_list.clone().unwrap_or_default(),
+    }
+}
+
+#[test]
+fn test_burst_of_reliable_packets_is_acked_exactly_once() {
+    let mut acks = PendingAcks::new();
+    let mut sent = Vec::new();
+    for sequence_number in 1..=600 {
+        // the simulator resends some packets before their acks go out
+        let mut full = acks.push(addr(9000), sequence_number);
+        if sequence_number % 7 == 0 {
+            full = acks.push(addr(9000), sequence_number) || full;
+        }
+        if full {
+            sent.extend(acks.flush().into_iter().map(|(_, packet)| packet));
+        }
+        // now and then a packet to the simulator takes some of the acks along
+        if sequence_number % 50 == 0 {
+            let mut packet = ping();
+            acks.append_to(&mut packet, &addr(9000));
+            sent.push(packet);
+        }
+    }
+    sent.extend(acks.flush().into_iter().map(|(_, packet)| packet));
+
+    assert!(sent
+        .iter()
+        .all(|packet| acked(packet).len() <= MAX_APPENDED_ACKS));
+    let mut all: Vec<u32> = sent.iter().flat_map(acked).collect();
+    all.sort();
+    assert_eq!(all, (1..=600).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_full_packet_ack_is_sent_right_away() {
+    let mut acks = PendingAcks::new();
+    for sequence_number in 1..MAX_APPENDED_ACKS as u32 {
+        assert!(!acks.push(addr(9000), sequence_number));
+    }
+    // a resend doesn't count twice
+    assert!(!acks.push(addr(9000), 1));
+    assert!(acks.push(addr(9000), MAX_APPENDED_ACKS as u32));
+}
@@ -0,0 +1,121 @@
+use metaverse_messages::avatar_picker_reply::{AvatarMatch, AvatarPickerReply};
+use metaverse_messages::avatar_picker_request::AvatarPickerRequest;
+use metaverse_session::people_search::{PeopleSearchManager, PeopleSearchOutcome};
+use tokio::time::{Duration, Instant};
+use uuid::{uuid, Uuid};
+
+const ALICE: Uuid = uuid!("8a1f4b0c-3f4e-4d52-9c1a-2b7e5d6f8a90");
+const QUERY: Uuid = uuid!("11223344-5566-7788-9900-aabbccddeeff");
+
+fn reply(query_id: Uuid, avatars: Vec<AvatarMatch>) -> AvatarPickerReply {
+    AvatarPickerReply {
+        agent_id: Uuid::nil(),
+        query_id,
+        avatars,
+    }
+}
+
+fn alice() -> AvatarMatch {
+    AvatarMatch {
+        avatar_id: ALICE,
+        first_name: "Alice".to_string(),
+        last_name: "Resident".to_string(),
+    }
+}
+
+#[test]
+fn test_search_found() {
+    let mut searches = PeopleSearchManager::new(Duration::from_secs(10));
+    let request = searches
+        .start(
+            AvatarPickerRequest::new(" ali ".to_string()),
+            Instant::now(),
+        )
+        .unwrap();
+    assert!(!request.query_id.is_nil());
+    assert_eq!(request.name, "ali");
+
+    match searches.finish(&reply(request.query_id, vec![alice()])) {
+        Some(PeopleSearchOutcome::Found(results)) => {
+            assert_eq!(results.query_id, request.query_id);
+            assert_eq!(results.name, "ali");
+            assert_eq!(results.avatars, vec![alice()]);
+        }
+        other => panic!("expected results, got {:?}", other),
+    }
+    // the search is finished
+    assert!(searches
+        .finish(&reply(request.query_id, vec![alice()]))
+        .is_none());
+}
+
+#[test]
+fn test_query_ids() {
+    let mut searches = PeopleSearchManager::new(Duration::from_secs(10));
+    let mut request = AvatarPickerRequest::new("alice".to_string());
+    request.query_id = QUERY;
+    let first = searches.start(request.clone(), Instant::now()).unwrap();
+    assert_eq!(first.query_id, QUERY);
+    // a query id that is still waiting is replaced
+    let second = searches.start(request, Instant::now()).unwrap();
+    assert_ne!(second.query_id, QUERY);
+    assert_eq!(searches.searches.len(), 2);
+    // replies to searches that weren't made are ignored
+    assert!(searches
+        .finish(&reply(Uuid::new_v4(), vec![alice()]))
+        .is_none());
+}
+
+#[test]
+fn test_invalid_name() {
+    let mut searches = PeopleSearchManager::new(Duration::from_secs(10));
+    let empty = searches
+        .start(AvatarPickerRequest::new("al".to_string()), Instant::now())
+        .unwrap_err();
+    assert!(!empty.query_id.is_nil());
+    assert_eq!(empty.name, "al");
+    assert!(searches.searches.is_empty());
+}
+
+#[test]
+fn test_search_empty() {
+    let mut searches = PeopleSearchManager::new(Duration::from_secs(10));
+    let request = searches
+        .start(AvatarPickerRequest::new("zzz".to_string()), Instant::now())
+        .unwrap();
+    match searches.finish(&reply(request.query_id, Vec::new())) {
+        Some(PeopleSearchOutcome::Empty(empty)) => assert_eq!(empty.name, "zzz"),
+        other => panic!("expected no results, got {:?}", other),
+    }
+
+    // some grids answer with a single nil resident
+    let request = searches
+        .start(AvatarPickerRequest::new("zzz".to_string()), Instant::now())
+        .unwrap();
+    let nobody = AvatarMatch {
+        avatar_id: Uuid::nil(),
+        first_name: String::new(),
+        last_name: String::new(),
+    };
+    assert!(matches!(
+        searches.finish(&reply(request.query_id, vec![nobody])),
+        Some(PeopleSearchOutcome::Empty(_))
+    ));
+}
+
+#[test]
+fn test_expire() {
+    let mut searches = PeopleSearchManager::new(Duration::from_secs(10));
+    let start = Instant::now();
+    let request = searches
+        .start(AvatarPickerRequest::new("alice".to_string()), start)
+        .unwrap();
+    assert!(searches.expire(start + Duration::from_secs(5)).is_empty());
+    let expired = searches.expire(start + Duration::from_secs(10));
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].query_id, request.query_id);
+    assert_eq!(expired[0].name, "alice");
+    assert!(searches
+        .finish(&reply(request.query_id, vec![alice()]))
+        .is_none());
+}
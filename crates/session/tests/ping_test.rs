@@ -0,0 +1,89 @@
+use metaverse_session::mailbox::{PingInfo, PING_TIMEOUT};
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[test]
+fn test_ping_round_trip() {
+    let mut ping_info = PingInfo::new(Duration::from_secs(5));
+    let start = Instant::now();
+
+    let ping_id = ping_info.start_ping(start);
+    assert_eq!(ping_id, 0);
+    assert_eq!(
+        ping_info.complete_ping(ping_id, start + Duration::from_millis(120)),
+        Some(Duration::from_millis(120))
+    );
+
+    let stats = ping_info.stats();
+    assert_eq!(stats.latency_ms, 120);
+    assert_eq!(stats.sent_pings, 1);
+    assert_eq!(stats.missed_pings, 0);
+
+    // a second reply for the same ping is ignored
+    assert_eq!(ping_info.complete_ping(ping_id, start), None);
+}
+
+#[test]
+fn test_ping_id_wraparound() {
+    let mut ping_info = PingInfo::new(Duration::from_secs(5));
+    let start = Instant::now();
+
+    // answer the first 255 pings so only the last one is outstanding
+    for _ in 0..255 {
+        let ping_id = ping_info.start_ping(start);
+        ping_info.complete_ping(ping_id, start + Duration::from_millis(50));
+    }
+    assert_eq!(ping_info.start_ping(start), 255);
+
+    // the ids wrap back to 0, and replies still match the right ping
+    let wrapped = ping_info.start_ping(start + Duration::from_millis(10));
+    assert_eq!(wrapped, 0);
+    assert_eq!(
+        ping_info.complete_ping(wrapped, start + Duration::from_millis(40)),
+        Some(Duration::from_millis(30))
+    );
+    assert_eq!(
+        ping_info.complete_ping(255, start + Duration::from_millis(80)),
+        Some(Duration::from_millis(80))
+    );
+    assert_eq!(ping_info.stats().missed_pings, 0);
+    assert_eq!(ping_info.stats().sent_pings, 257);
+}
+
+#[test]
+fn test_ping_reused_id_counts_as_missed() {
+    let mut ping_info = PingInfo::new(Duration::from_secs(5));
+    let start = Instant::now();
+
+    // ping 0 is never answered, so when the ids wrap back around it is replaced
+    ping_info.start_ping(start);
+    for _ in 1..256 {
+        let ping_id = ping_info.start_ping(start);
+        ping_info.complete_ping(ping_id, start);
+    }
+    let reused = ping_info.start_ping(start + Duration::from_secs(1));
+    assert_eq!(reused, 0);
+    assert_eq!(ping_info.stats().missed_pings, 1);
+
+    // the reply is matched against the newest ping with that id
+    assert_eq!(
+        ping_info.complete_ping(reused, start + Duration::from_millis(1500)),
+        Some(Duration::from_millis(500))
+    );
+}
+
+#[test]
+fn test_ping_timeout() {
+    let mut ping_info = PingInfo::new(Duration::from_secs(5));
+    let start = Instant::now();
+
+    let old = ping_info.start_ping(start);
+    let recent = ping_info.start_ping(start + PING_TIMEOUT);
+    ping_info.expire_pings(start + PING_TIMEOUT + Duration::from_secs(1), PING_TIMEOUT);
+
+    assert_eq!(ping_info.stats().missed_pings, 1);
+    assert_eq!(ping_info.complete_ping(old, start + PING_TIMEOUT), None);
+    assert!(ping_info
+        .complete_ping(recent, start + PING_TIMEOUT)
+        .is_some());
+}
@@ -0,0 +1,124 @@
+use metaverse_messages::object_properties::{ObjectProperties, ObjectPropertiesData};
+use metaverse_messages::utils::permissions::Permissions;
+use metaverse_messages::utils::sale_type::SaleType;
+use metaverse_session::purchase::PurchaseManager;
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn properties(object_id: Uuid, sale_type: SaleType, sale_price: i32) -> ObjectProperties {
+    ObjectProperties {
+        objects: vec![ObjectPropertiesData {
+            object_id,
+            creator_id: Uuid::new_v4(),
+            owner_id: Uuid::new_v4(),
+            group_id: Uuid::nil(),
+            creation_date: 0,
+            base_mask: Permissions::default(),
+            owner_mask: Permissions::default(),
+            group_mask: Permissions::default(),
+            everyone_mask: Permissions::default(),
+            next_owner_mask: Permissions::default(),
+            ownership_cost: 0,
+            sale_type,
+            sale_price,
+            aggregate_perms: 0,
+            aggregate_perm_textures: 0,
+            aggregate_perm_textures_owner: 0,
+            category: 0,
+            inventory_serial: 0,
+            item_id: Uuid::nil(),
+            folder_id: Uuid::nil(),
+            from_task_id: Uuid::nil(),
+            last_owner_id: Uuid::nil(),
+            name: "For Sale".to_string(),
+            description: String::new(),
+            touch_name: String::new(),
+            sit_name: String::new(),
+            texture_id: Vec::new(),
+        }],
+    }
+}
+
+// a manager that knows the full id of local id 7, and is buying it
+fn buying(sale_type: SaleType, price: i32, now: Instant) -> (PurchaseManager, Uuid) {
+    let mut purchases = PurchaseManager::new(TIMEOUT);
+    let object_id = Uuid::new_v4();
+    purchases.object_ids.insert(7, object_id);
+    purchases.start(7, sale_type, price, Uuid::nil(), now);
+    (purchases, object_id)
+}
+
+#[test]
+fn test_matching_properties_are_bought() {
+    let (mut purchases, object_id) = buying(SaleType::Copy, 25, Instant::now());
+
+    let (checked, failed) = purchases.handle_properties(&properties(object_id, SaleType::Copy, 25));
+    assert!(failed.is_empty());
+    assert_eq!(checked.len(), 1);
+    assert_eq!(checked[0].object.local_id, 7);
+    assert_eq!(checked[0].object.sale_price, 25);
+    assert!(purchases.pending.is_empty());
+
+    // the properties of a later selection don't buy it again
+    let (checked, _) = purchases.handle_properties(&properties(object_id, SaleType::Copy, 25));
+    assert!(checked.is_empty());
+}
+
+#[test]
+fn test_price_mismatch_fails_before_buying() {
+    let (mut purchases, object_id) = buying(SaleType::Copy, 25, Instant::now());
+
+    let (checked, failed) = purchases.handle_properties(&properties(object_id, SaleType::Copy, 50));
+    assert!(checked.is_empty());
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].local_id, 7);
+    assert_eq!(failed[0].object_id, Some(object_id));
+    assert_eq!(failed[0].expected_price, 25);
+    assert_eq!(failed[0].sale_price, Some(50));
+    assert!(purchases.pending.is_empty());
+}
+
+#[test]
+fn test_sale_type_mismatch_and_not_for_sale_fail() {
+    let (mut purchases, object_id) = buying(SaleType::Copy, 25, Instant::now());
+    let (_, failed) = purchases.handle_properties(&properties(object_id, SaleType::Original, 25));
+    assert_eq!(failed[0].sale_type, Some(SaleType::Original));
+
+    let (mut purchases, object_id) = buying(SaleType::Copy, 25, Instant::now());
+    let (_, failed) = purchases.handle_properties(&properties(object_id, SaleType::NotForSale, 25));
+    assert_eq!(failed.len(), 1);
+}
+
+#[test]
+fn test_properties_of_other_objects_are_ignored() {
+    let (mut purchases, _) = buying(SaleType::Copy, 25, Instant::now());
+    let (checked, failed) =
+        purchases.handle_properties(&properties(Uuid::new_v4(), SaleType::Copy, 25));
+    assert!(checked.is_empty());
+    assert!(failed.is_empty());
+    assert_eq!(purchases.pending.len(), 1);
+}
+
+#[test]
+fn test_purchases_expire() {
+    let now = Instant::now();
+    let (mut purchases, _) = buying(SaleType::Copy, 25, now);
+
+    assert!(purchases.expire(now + TIMEOUT / 2).is_empty());
+    let failed = purchases.expire(now + TIMEOUT);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].sale_price, None);
+    assert!(purchases.pending.is_empty());
+}
+
+#[test]
+fn test_changing_regions_forgets_objects() {
+    let (mut purchases, _) = buying(SaleType::Copy, 25, Instant::now());
+    let failed = purchases.change_region();
+    assert_eq!(failed.len(), 1);
+    assert!(purchases.object_ids.is_empty());
+    assert!(purchases.pending.is_empty());
+}
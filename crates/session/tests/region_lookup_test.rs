@@ -0,0 +1,138 @@
+use metaverse_messages::map_block_reply::MapBlock;
+use metaverse_messages::map_blocks::MapBlocks;
+use metaverse_messages::utils::agent_access::AgentAccess;
+use metaverse_messages::utils::region_handle::region_handle;
+use metaverse_session::map::{MapSearchManager, MAP_SEARCH_TAG_SHIFT};
+use metaverse_session::region_lookup::{
+    RegionLookup, RegionLookupManager, RegionLookupStart, REGION_LOOKUP_FLAGS, REGION_LOOKUP_TAG,
+};
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn block(x: u16, y: u16, name: &str, access: AgentAccess) -> MapBlock {
+    MapBlock {
+        x,
+        y,
+        name: name.to_string(),
+        access,
+        region_flags: 0,
+        water_height: 20,
+        agents: 0,
+        map_image_id: Uuid::nil(),
+        size_x: 256,
+        size_y: 256,
+    }
+}
+
+// the regions the simulator answers a lookup with
+fn reply(blocks: Vec<MapBlock>) -> MapBlocks {
+    MapBlocks {
+        flags: REGION_LOOKUP_FLAGS,
+        blocks,
+    }
+}
+
+#[test]
+fn test_lookup_finds_the_exact_name() {
+    let mut manager = RegionLookupManager::new(TIMEOUT);
+    let now = Instant::now();
+    assert_eq!(manager.lookup("Ahern", 1, now), RegionLookupStart::Request);
+    let lookups = manager
+        .handle_blocks(&reply(vec![
+            block(997, 1002, "Ahern Beach", AgentAccess::Mature),
+            block(997, 1000, "ahern", AgentAccess::Mature),
+        ]))
+        .unwrap();
+    assert_eq!(
+        lookups,
+        vec![RegionLookup {
+            waiter: 1,
+            name: "Ahern".to_string(),
+            region_handle: Some(region_handle(997 * 256, 1000 * 256)),
+        }]
+    );
+    assert!(manager.pending.is_empty());
+}
+
+#[test]
+fn test_known_regions_are_not_looked_up_again() {
+    let mut manager = RegionLookupManager::new(TIMEOUT);
+    let now = Instant::now();
+    manager.lookup("Ahern", 1, now);
+    manager.handle_blocks(&reply(vec![block(
+        997,
+        1002,
+        "Ahern Beach",
+        AgentAccess::Mature,
+    )]));
+    // the other regions in the answer are kept too
+    assert_eq!(
+        manager.lookup("AHERN BEACH", 2, now),
+        RegionLookupStart::Known(2, region_handle(997 * 256, 1002 * 256))
+    );
+}
+
+#[test]
+fn test_lookups_for_the_same_region_share_a_request() {
+    let mut manager = RegionLookupManager::new(TIMEOUT);
+    let now = Instant::now();
+    assert_eq!(manager.lookup("Ahern", 1, now), RegionLookupStart::Request);
+    assert_eq!(manager.lookup("ahern", 2, now), RegionLookupStart::Waiting);
+    let lookups = manager
+        .handle_blocks(&reply(vec![block(997, 1000, "Ahern", AgentAccess::PG)]))
+        .unwrap();
+    let waiters: Vec<i32> = lookups.iter().map(|lookup| lookup.waiter).collect();
+    assert_eq!(waiters, vec![1, 2]);
+}
+
+#[test]
+fn test_other_map_blocks_are_not_lookups() {
+    let mut manager = RegionLookupManager::new(TIMEOUT);
+    manager.lookup("Ahern", 1, Instant::now());
+    let map_blocks = MapBlocks {
+        flags: 1 << MAP_SEARCH_TAG_SHIFT,
+        blocks: vec![block(997, 1000, "Ahern", AgentAccess::PG)],
+    };
+    assert!(manager.handle_blocks(&map_blocks).is_none());
+    assert_eq!(manager.pending.len(), 1);
+}
+
+#[test]
+fn test_missing_region_expires() {
+    let mut manager = RegionLookupManager::new(TIMEOUT);
+    let now = Instant::now();
+    manager.lookup("Nowhere", 1, now);
+    // a search that finds nothing is answered with a block at 0, 0
+    let lookups = manager
+        .handle_blocks(&reply(vec![block(0, 0, "", AgentAccess::NonExistent)]))
+        .unwrap();
+    assert!(lookups.is_empty());
+    assert!(manager.expire(now + TIMEOUT / 2).is_empty());
+    assert_eq!(
+        manager.expire(now + TIMEOUT),
+        vec![RegionLookup {
+            waiter: 1,
+            name: "Nowhere".to_string(),
+            region_handle: None,
+        }]
+    );
+    // a failed lookup is asked for again next time
+    assert_eq!(
+        manager.lookup("Nowhere", 2, now + TIMEOUT),
+        RegionLookupStart::Request
+    );
+}
+
+#[test]
+fn test_searches_skip_the_lookup_tag() {
+    let mut searches = MapSearchManager::new(TIMEOUT);
+    let now = Instant::now();
+    for _ in 0..600 {
+        let flags = searches.start("Ahern".to_string(), 0, now);
+        assert_ne!((flags >> MAP_SEARCH_TAG_SHIFT) as u8, REGION_LOOKUP_TAG);
+        assert_ne!((flags >> MAP_SEARCH_TAG_SHIFT) as u8, 0);
+    }
+}
@@ -0,0 +1,157 @@
+mod common;
+
+use common::start_session;
+use metaverse_messages::circuit_code::CircuitCodeData;
+use metaverse_messages::enable_simulator::EnableSimulator;
+use metaverse_messages::packet::Packet;
+use metaverse_messages::packet_ack::PacketAck;
+use metaverse_messages::packet_types::PacketType;
+use metaverse_session::mailbox::EnableSimulatorMessage;
+use metaverse_session::retransmit::{
+    RetransmitQueue, MAX_RETRANSMIT_TIMEOUT, MIN_RETRANSMIT_TIMEOUT,
+};
+use std::net::Ipv4Addr;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+const SIM: &str = "127.0.0.1:9000";
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+fn circuit_code() -> Packet {
+    Packet::new_circuit_code(CircuitCodeData {
+        code: 1234,
+        session_id: Uuid::nil(),
+        id: Uuid::nil(),
+    })
+}
+
+/// a reliable packet with a sequence number
+fn numbered(sequence_number: u32) -> Packet {
+    let mut packet = circuit_code();
+    packet.header.sequence_number = sequence_number;
+    packet
+}
+
+#[test]
+fn test_dropped_packet_is_resent() {
+    let session = start_session(|_| {});
+    session.mailbox.do_send(circuit_code());
+    // the first datagram is lost on the way
+    let dropped = session.sim.recv();
+    assert!(!dropped.header.resent);
+
+    // the simulator gets the resend, with the number the packet was first sent with
+    let resend = session.sim.recv();
+    assert!(resend.header.resent);
+    assert_eq!(
+        resend.header.sequence_number,
+        dropped.header.sequence_number
+    );
+
+    // and acks it, so it isn't sent again
+    session.sim.send(&Packet::new_packet_ack(PacketAck {
+        packet_ids: vec![resend.header.sequence_number],
+    }));
+    assert!(session.sim.recv_within(TIMEOUT * 3).is_none());
+}
+
+#[test]
+fn test_dead_child_circuit_is_closed() {
+    let session = start_session(|mailbox| mailbox.retransmits = RetransmitQueue::new(0));
+    let neighbour = session.neighbour();
+    session
+        .mailbox
+        .do_send(EnableSimulatorMessage(EnableSimulator {
+            region_handle: 2,
+            ip: Ipv4Addr::LOCALHOST,
+            port: neighbour.addr().port(),
+        }));
+    // the neighbour never acks the circuit code
+    let circuit_code = neighbour.recv();
+    assert!(matches!(circuit_code.body, PacketType::CircuitCode(_)));
+
+    // so its circuit is given up on, and the UI is told it was lost
+    let disconnect = session.event(|event| match event {
+        PacketType::ChildSimulatorEvent(child) => match child.packet() {
+            Some(PacketType::Disconnect(disconnect)) => Some((child.region_handle, disconnect)),
+            _ => None,
+        },
+        _ => None,
+    });
+    assert_eq!(disconnect.0, 2);
+    assert!(disconnect.1.reason.contains(&neighbour.addr().to_string()));
+
+    // its packets are dropped from then on, so a reliable one isn't acked
+    neighbour.send(&numbered(1));
+    assert!(neighbour.recv_within(TIMEOUT / 2).is_none());
+    // and the session carries on
+    let mut unreliable = circuit_code.clone();
+    unreliable.header.reliable = false;
+    session.mailbox.do_send(unreliable);
+    assert!(matches!(
+        session.sim.recv().body,
+        PacketType::CircuitCode(_)
+    ));
+}
+
+#[test]
+fn test_resends_back_off_until_the_circuit_is_dead() {
+    let mut queue = RetransmitQueue::new(2);
+    let start = Instant::now();
+    queue.sent(SIM, &numbered(1), start);
+
+    assert_eq!(queue.due(start + TIMEOUT, TIMEOUT).resends.len(), 1);
+    // the second resend waits twice as long
+    let second = start + TIMEOUT;
+    assert!(queue.due(second + TIMEOUT, TIMEOUT).resends.is_empty());
+    assert_eq!(queue.due(second + TIMEOUT * 2, TIMEOUT).resends.len(), 1);
+
+    // after the most resends, the circuit is given up on once
+    let third = second + TIMEOUT * 2;
+    let retransmits = queue.due(third + TIMEOUT * 4, TIMEOUT);
+    assert!(retransmits.resends.is_empty());
+    assert_eq!(retransmits.dead, vec![SIM.to_string()]);
+    assert!(queue.packets.is_empty());
+    assert!(queue.due(third + TIMEOUT * 10, TIMEOUT).dead.is_empty());
+}
+
+#[test]
+fn test_unreliable_packets_are_not_kept() {
+    let mut queue = RetransmitQueue::new(3);
+    let mut packet = circuit_code();
+    packet.header.reliable = false;
+    queue.sent(SIM, &packet, Instant::now());
+    assert!(queue.packets.is_empty());
+    assert!(!queue.ack(SIM, 0));
+}
+
+#[test]
+fn test_timeout_follows_the_ping() {
+    assert_eq!(
+        RetransmitQueue::timeout(Duration::ZERO),
+        MIN_RETRANSMIT_TIMEOUT
+    );
+    assert_eq!(
+        RetransmitQueue::timeout(Duration::from_millis(500)),
+        Duration::from_secs(2)
+    );
+    assert_eq!(
+        RetransmitQueue::timeout(Duration::from_secs(10)),
+        MAX_RETRANSMIT_TIMEOUT
+    );
+}
+
+#[test]
+fn test_oldest_unacked_and_closed_circuits() {
+    let mut queue = RetransmitQueue::new(3);
+    let now = Instant::now();
+    for sequence_number in 1..=3 {
+        queue.sent(SIM, &numbered(sequence_number), now);
+    }
+    queue.ack(SIM, 1);
+    assert_eq!(queue.oldest_unacked(SIM), Some(2));
+    assert_eq!(queue.oldest_unacked("127.0.0.1:9001"), None);
+
+    queue.circuit_closed(SIM);
+    assert_eq!(queue.oldest_unacked(SIM), None);
+}
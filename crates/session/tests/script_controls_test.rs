@@ -0,0 +1,138 @@
+use glam::Vec3;
+use metaverse_messages::agent_update::ControlFlags;
+use metaverse_messages::kill_object::KillObjectData;
+use metaverse_messages::object_update::{ObjectUpdate, ObjectUpdateData, PCode, PrimShape};
+use metaverse_messages::script_control_change::{ScriptControl, ScriptControlChange};
+use metaverse_session::script_controls::{ScriptControlManager, UNKNOWN_OBJECT};
+use uuid::{uuid, Uuid};
+
+const AGENT_ID: Uuid = uuid!("320dff8a-7a59-4720-a0f7-5a8df3698d9a");
+const VEHICLE_LOCAL_ID: u32 = 2002;
+
+// the agent's avatar, sitting on the object with the parent id
+fn avatar(parent_id: u32) -> ObjectUpdate {
+    ObjectUpdate {
+        region_handle: 0,
+        time_dilation: 0,
+        objects: vec![ObjectUpdateData {
+            local_id: 1001,
+            state: 0,
+            full_id: AGENT_ID,
+            crc: 0,
+            pcode: PCode::Avatar,
+            material: 3,
+            click_action: 0,
+            scale: Vec3::ONE,
+            object_data: Vec::new(),
+            parent_id,
+            update_flags: 0,
+            shape: PrimShape::default(),
+            texture_entry: Vec::new(),
+            texture_anim: Vec::new(),
+            name_value: String::new(),
+            data: Vec::new(),
+            text: String::new(),
+            text_color: [0; 4],
+            media_url: String::new(),
+            ps_block: Vec::new(),
+            extra_params: Vec::new(),
+            sound: Uuid::nil(),
+            owner_id: AGENT_ID,
+            gain: 0.0,
+            flags: 0,
+            radius: 0.0,
+            joint_type: 0,
+            joint_pivot: Vec3::ZERO,
+            joint_axis_or_anchor: Vec3::ZERO,
+        }],
+    }
+}
+
+fn change(take_controls: bool, controls: ControlFlags, pass_to_agent: bool) -> ScriptControlChange {
+    ScriptControlChange {
+        changes: vec![ScriptControl {
+            take_controls,
+            controls,
+            pass_to_agent,
+        }],
+    }
+}
+
+fn steering() -> ControlFlags {
+    ControlFlags::AT_POS | ControlFlags::AT_NEG | ControlFlags::YAW_POS | ControlFlags::YAW_NEG
+}
+
+#[test]
+fn test_vehicle_takes_controls() {
+    let mut manager = ScriptControlManager::new();
+    manager.handle_object_update(&avatar(VEHICLE_LOCAL_ID), AGENT_ID);
+    let controls = manager.handle_change(&change(true, steering(), false));
+    assert_eq!(controls.objects.len(), 1);
+    assert_eq!(controls.objects[0].local_id, VEHICLE_LOCAL_ID);
+    assert_eq!(controls.objects[0].taken, steering());
+    assert_eq!(controls.blocked, steering());
+    assert_eq!(
+        manager.local_controls(ControlFlags::AT_POS | ControlFlags::FLY),
+        ControlFlags::FLY
+    );
+}
+
+#[test]
+fn test_passed_controls_still_move_the_avatar() {
+    let mut manager = ScriptControlManager::new();
+    let controls = manager.handle_change(&change(true, ControlFlags::LBUTTON_DOWN, true));
+    assert_eq!(controls.objects[0].local_id, UNKNOWN_OBJECT);
+    assert_eq!(
+        controls.objects[0].passed_to_agent,
+        ControlFlags::LBUTTON_DOWN
+    );
+    assert!(controls.blocked.is_empty());
+    assert_eq!(
+        manager.local_controls(ControlFlags::LBUTTON_DOWN),
+        ControlFlags::LBUTTON_DOWN
+    );
+}
+
+#[test]
+fn test_release_controls() {
+    let mut manager = ScriptControlManager::new();
+    manager.handle_object_update(&avatar(VEHICLE_LOCAL_ID), AGENT_ID);
+    manager.handle_change(&change(true, steering(), false));
+    let controls = manager.handle_change(&change(false, ControlFlags::AT_POS, false));
+    assert_eq!(controls.blocked, steering() - ControlFlags::AT_POS);
+    let controls = manager.handle_change(&change(false, steering(), false));
+    assert!(controls.objects.is_empty());
+    assert!(controls.blocked.is_empty());
+}
+
+#[test]
+fn test_killing_the_vehicle_releases_its_controls() {
+    let mut manager = ScriptControlManager::new();
+    manager.handle_object_update(&avatar(VEHICLE_LOCAL_ID), AGENT_ID);
+    manager.handle_change(&change(true, steering(), false));
+    let kill = KillObjectData {
+        local_ids: vec![3003],
+    };
+    assert!(manager.handle_kill(&kill).is_none());
+    let kill = KillObjectData {
+        local_ids: vec![VEHICLE_LOCAL_ID],
+    };
+    let controls = manager.handle_kill(&kill).unwrap();
+    assert!(controls.objects.is_empty());
+    assert!(manager.seat.is_none());
+    assert_eq!(manager.local_controls(steering()), steering());
+}
+
+#[test]
+fn test_standing_up_keeps_taken_controls() {
+    let mut manager = ScriptControlManager::new();
+    manager.handle_object_update(&avatar(VEHICLE_LOCAL_ID), AGENT_ID);
+    manager.handle_change(&change(true, steering(), false));
+    manager.handle_object_update(&avatar(0), AGENT_ID);
+    assert!(manager.seat.is_none());
+    // the simulator releases them, the session doesn't guess
+    assert_eq!(manager.blocked(), steering());
+    assert!(manager.release_all());
+    assert!(!manager.release_all());
+    assert!(manager.blocked().is_empty());
+}
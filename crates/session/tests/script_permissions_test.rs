@@ -0,0 +1,93 @@
+use metaverse_messages::script_answer_yes::ScriptAnswerYes;
+use metaverse_messages::utils::script_permissions::ScriptPermissions;
+use metaverse_session::script_permissions::ScriptPermissionManager;
+use uuid::Uuid;
+
+#[test]
+fn test_grants_are_remembered() {
+    let mut manager = ScriptPermissionManager::new();
+    let object_id = Uuid::new_v4();
+    let item_id = Uuid::new_v4();
+    manager.handle_answer(&ScriptAnswerYes::new(
+        object_id,
+        item_id,
+        ScriptPermissions::TRIGGER_ANIMATION,
+    ));
+    assert_eq!(
+        manager.granted(object_id, item_id),
+        ScriptPermissions::TRIGGER_ANIMATION
+    );
+    assert!(manager.granted(object_id, Uuid::new_v4()).is_empty());
+}
+
+#[test]
+fn test_zero_answer_denies() {
+    let mut manager = ScriptPermissionManager::new();
+    let object_id = Uuid::new_v4();
+    let item_id = Uuid::new_v4();
+    manager.handle_answer(&ScriptAnswerYes::new(
+        object_id,
+        item_id,
+        ScriptPermissions::TAKE_CONTROLS,
+    ));
+    // asked again, the agent grants nothing
+    manager.handle_answer(&ScriptAnswerYes::deny(object_id, item_id));
+    assert!(manager.granted(object_id, item_id).is_empty());
+    assert!(manager.granted.is_empty());
+}
+
+#[test]
+fn test_revoke_from_object() {
+    let mut manager = ScriptPermissionManager::new();
+    let object_id = Uuid::new_v4();
+    let other_object = Uuid::new_v4();
+    let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+    manager.handle_answer(&ScriptAnswerYes::new(
+        object_id,
+        first,
+        ScriptPermissions::TRIGGER_ANIMATION | ScriptPermissions::TAKE_CONTROLS,
+    ));
+    manager.handle_answer(&ScriptAnswerYes::new(
+        object_id,
+        second,
+        ScriptPermissions::TRIGGER_ANIMATION,
+    ));
+    manager.handle_answer(&ScriptAnswerYes::new(
+        other_object,
+        first,
+        ScriptPermissions::TRIGGER_ANIMATION,
+    ));
+    assert_eq!(
+        manager.granted_to_object(object_id),
+        ScriptPermissions::TRIGGER_ANIMATION | ScriptPermissions::TAKE_CONTROLS
+    );
+
+    let revoked = manager.revoke(
+        object_id,
+        ScriptPermissions::TRIGGER_ANIMATION | ScriptPermissions::DEBIT,
+    );
+    assert_eq!(revoked, ScriptPermissions::TRIGGER_ANIMATION);
+    assert_eq!(
+        manager.granted(object_id, first),
+        ScriptPermissions::TAKE_CONTROLS
+    );
+    // the second script has nothing left, so it is forgotten
+    assert!(!manager.granted.contains_key(&(object_id, second)));
+    // other objects keep their permissions
+    assert_eq!(
+        manager.granted(other_object, first),
+        ScriptPermissions::TRIGGER_ANIMATION
+    );
+}
+
+#[test]
+fn test_clear() {
+    let mut manager = ScriptPermissionManager::new();
+    manager.handle_answer(&ScriptAnswerYes::new(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        ScriptPermissions::ATTACH,
+    ));
+    manager.clear();
+    assert!(manager.granted.is_empty());
+}
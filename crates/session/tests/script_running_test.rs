@@ -0,0 +1,102 @@
+use metaverse_messages::errors::SessionError;
+use metaverse_messages::script_running_reply::ScriptRunningReply;
+use metaverse_session::script_running::ScriptRunningManager;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn object_id() -> Uuid {
+    Uuid::from_bytes([0x0B; 16])
+}
+
+fn reply(item_id: Uuid, running: bool) -> ScriptRunningReply {
+    ScriptRunningReply {
+        object_id: object_id(),
+        item_id,
+        running,
+    }
+}
+
+#[tokio::test]
+async fn test_reply_resolves_query() {
+    let now = Instant::now();
+    let mut manager = ScriptRunningManager::new(TIMEOUT);
+    let item_id = Uuid::from_bytes([0x01; 16]);
+    let (request, query) = manager.request(object_id(), item_id, now);
+    let request = request.unwrap();
+    assert_eq!(request.object_id, object_id());
+    assert_eq!(request.item_id, item_id);
+
+    let status = manager.handle_reply(&reply(item_id, true)).unwrap();
+    assert_eq!(status.object_id, object_id());
+    assert_eq!(status.item_id, item_id);
+    assert_eq!(status.running, Some(true));
+    assert!(status.reason.is_none());
+    assert!(query.await.unwrap());
+    assert!(manager.pending.is_empty());
+}
+
+#[test]
+fn test_repeated_query_waits_for_same_reply() {
+    let now = Instant::now();
+    let mut manager = ScriptRunningManager::new(TIMEOUT);
+    let item_id = Uuid::from_bytes([0x01; 16]);
+    let (first, mut first_query) = manager.request(object_id(), item_id, now);
+    let (second, mut second_query) = manager.request(object_id(), item_id, now);
+    assert!(first.is_some());
+    // only one GetScriptRunning is sent for the script
+    assert!(second.is_none());
+
+    assert!(manager.handle_reply(&reply(item_id, false)).is_some());
+    assert!(!first_query.completion.try_recv().unwrap().unwrap());
+    assert!(!second_query.completion.try_recv().unwrap().unwrap());
+}
+
+#[test]
+fn test_reply_only_answers_its_script() {
+    let now = Instant::now();
+    let mut manager = ScriptRunningManager::new(TIMEOUT);
+    let item_id = Uuid::from_bytes([0x01; 16]);
+    let (_, mut query) = manager.request(object_id(), item_id, now);
+
+    // another script in the same object, which nobody asked about
+    assert!(manager
+        .handle_reply(&reply(Uuid::from_bytes([0x02; 16]), true))
+        .is_none());
+    assert!(query.completion.try_recv().is_err());
+    assert_eq!(manager.pending.len(), 1);
+}
+
+#[tokio::test]
+async fn test_query_times_out() {
+    let now = Instant::now();
+    let mut manager = ScriptRunningManager::new(TIMEOUT);
+    let item_id = Uuid::from_bytes([0x01; 16]);
+    let (_, query) = manager.request(object_id(), item_id, now);
+    assert!(manager.expire(now + TIMEOUT / 2).is_empty());
+
+    let events = manager.expire(now + TIMEOUT);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].item_id, item_id);
+    assert!(events[0].running.is_none());
+    assert!(events[0].reason.is_some());
+    assert!(matches!(query.await, Err(SessionError::ScriptRunning(_))));
+    assert!(manager.pending.is_empty());
+
+    // a reply after the timeout answers nobody, and asking again sends a new request
+    assert!(manager.handle_reply(&reply(item_id, true)).is_none());
+    let (request, _query) = manager.request(object_id(), item_id, now + TIMEOUT);
+    assert!(request.is_some());
+}
+
+#[test]
+fn test_cancel_all_fails_queries() {
+    let now = Instant::now();
+    let mut manager = ScriptRunningManager::new(TIMEOUT);
+    let (_, mut query) = manager.request(object_id(), Uuid::from_bytes([0x01; 16]), now);
+    let events = manager.cancel_all();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].reason.as_deref(), Some("session ended"));
+    assert!(query.completion.try_recv().unwrap().is_err());
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::start_session;
+use metaverse_messages::circuit_code::CircuitCodeData;
+use metaverse_messages::packet::Packet;
+use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::start_ping_check::StartPingCheck;
+use metaverse_session::sequence::SequenceNumbers;
+use uuid::Uuid;
+
+const SIM: &str = "127.0.0.1:9000";
+const NEIGHBOUR: &str = "127.0.0.1:9001";
+
+fn ping() -> Packet {
+    Packet::new_start_ping_check(StartPingCheck {
+        ping_id: 1,
+        oldest_unacked: 0,
+    })
+}
+
+#[test]
+fn test_numbers_increase_per_circuit() {
+    let mut sequence_numbers = SequenceNumbers::new();
+    let numbers: Vec<u32> = (0..5)
+        .map(|_| sequence_numbers.stamp(&mut ping(), SIM))
+        .collect();
+    assert_eq!(numbers, vec![1, 2, 3, 4, 5]);
+
+    // another simulator counts on its own, without holding up the first
+    let mut packet = ping();
+    assert_eq!(sequence_numbers.stamp(&mut packet, NEIGHBOUR), 1);
+    assert_eq!(packet.header.sequence_number, 1);
+    assert_eq!(sequence_numbers.stamp(&mut ping(), SIM), 6);
+}
+
+#[test]
+fn test_resends_keep_their_number() {
+    let mut sequence_numbers = SequenceNumbers::new();
+    let mut packet = ping();
+    sequence_numbers.stamp(&mut packet, SIM);
+    sequence_numbers.stamp(&mut ping(), SIM);
+
+    let mut resend = packet.clone();
+    resend.header.resent = true;
+    assert_eq!(sequence_numbers.stamp(&mut resend, SIM), 1);
+    assert_eq!(resend.header.sequence_number, 1);
+    // and don't use up a number
+    assert_eq!(sequence_numbers.stamp(&mut ping(), SIM), 3);
+}
+
+#[test]
+fn test_numbers_are_not_reused_within_a_session() {
+    let mut sequence_numbers = SequenceNumbers::new();
+    sequence_numbers.stamp(&mut ping(), SIM);
+    sequence_numbers.stamp(&mut ping(), NEIGHBOUR);
+    // coming back to a simulator carries on from where its counter left off
+    assert_eq!(sequence_numbers.stamp(&mut ping(), SIM), 2);
+
+    // the counter wraps around past zero
+    sequence_numbers.next.insert(SIM.to_string(), u32::MAX);
+    assert_eq!(sequence_numbers.stamp(&mut ping(), SIM), u32::MAX);
+    assert_eq!(sequence_numbers.stamp(&mut ping(), SIM), 1);
+
+    // a new session starts over
+    sequence_numbers.clear();
+    assert_eq!(sequence_numbers.stamp(&mut ping(), NEIGHBOUR), 1);
+}
+
+#[test]
+fn test_send_path() {
+    let session = start_session(|_| {});
+
+    // a reliable ping from the simulator is acked on the mailbox's answer to it, which is the
+    // first packet of the circuit
+    let mut from_sim = ping();
+    from_sim.header.reliable = true;
+    from_sim.header.sequence_number = 7;
+    session.sim.send(&from_sim);
+    let pong = session.sim.recv();
+    assert!(matches!(pong.body, PacketType::CompletePingCheck(_)));
+    assert_eq!(pong.header.sequence_number, 1);
+    assert_eq!(pong.header.ack_list, Some(vec![7]));
+
+    // numbers go up with every packet
+    for _ in 0..3 {
+        session
+            .mailbox
+            .do_send(Packet::new_circuit_code(CircuitCodeData {
+                code: 1234,
+                session_id: Uuid::nil(),
+                id: Uuid::nil(),
+            }));
+    }
+    let numbers: Vec<u32> = (0..3)
+        .map(|_| session.sim.recv().header.sequence_number)
+        .collect();
+    assert_eq!(numbers, vec![2, 3, 4]);
+
+    // the simulator doesn't ack them, so they are resent with the numbers they were sent with
+    let mut resent: Vec<u32> = (0..3)
+        .map(|_| {
+            let packet = session.sim.recv();
+            assert!(packet.header.resent);
+            packet.header.sequence_number
+        })
+        .collect();
+    resent.sort();
+    assert_eq!(resent, numbers);
+}
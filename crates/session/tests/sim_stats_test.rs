@@ -0,0 +1,73 @@
+use metaverse_messages::sim_stats::{SimStat, SimStatType, SimStats};
+use metaverse_session::sim_stats::SimStatsThrottle;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const INTERVAL: Duration = Duration::from_secs(1);
+
+fn stats(time_dilation: f32) -> SimStats {
+    SimStats {
+        region_x: 1000,
+        region_y: 1000,
+        region_flags: 0,
+        object_capacity: 15000,
+        stats: vec![SimStat {
+            stat: SimStatType::TimeDilation,
+            value: time_dilation,
+        }],
+        pid: 0,
+        region_flags_extended: Vec::new(),
+    }
+}
+
+#[test]
+fn test_first_stats_are_sent_right_away() {
+    let mut throttle = SimStatsThrottle::new(INTERVAL);
+    let now = Instant::now();
+    assert_eq!(throttle.handle_stats(stats(1.0), now), Some(stats(1.0)));
+    assert!(throttle.flush(now + INTERVAL).is_none());
+}
+
+#[test]
+fn test_stats_in_between_are_held() {
+    let mut throttle = SimStatsThrottle::new(INTERVAL);
+    let now = Instant::now();
+    throttle.handle_stats(stats(1.0), now);
+    assert!(throttle
+        .handle_stats(stats(0.9), now + Duration::from_millis(200))
+        .is_none());
+    assert!(throttle
+        .handle_stats(stats(0.8), now + Duration::from_millis(400))
+        .is_none());
+    assert!(throttle.flush(now + Duration::from_millis(900)).is_none());
+    // only the latest is sent
+    assert_eq!(throttle.flush(now + INTERVAL), Some(stats(0.8)));
+    assert!(throttle.flush(now + INTERVAL * 3).is_none());
+}
+
+#[test]
+fn test_stats_after_the_interval_are_sent() {
+    let mut throttle = SimStatsThrottle::new(INTERVAL);
+    let now = Instant::now();
+    throttle.handle_stats(stats(1.0), now);
+    throttle.handle_stats(stats(0.9), now + Duration::from_millis(500));
+    assert_eq!(
+        throttle.handle_stats(stats(0.7), now + INTERVAL),
+        Some(stats(0.7))
+    );
+    // the held one is older than the one just sent
+    assert!(throttle.held.is_none());
+}
+
+#[test]
+fn test_clear_lets_the_next_region_send_right_away() {
+    let mut throttle = SimStatsThrottle::new(INTERVAL);
+    let now = Instant::now();
+    throttle.handle_stats(stats(1.0), now);
+    throttle.handle_stats(stats(0.9), now + Duration::from_millis(100));
+    throttle.clear();
+    assert!(throttle.held.is_none());
+    assert!(throttle
+        .handle_stats(stats(0.5), now + Duration::from_millis(200))
+        .is_some());
+}
@@ -0,0 +1,88 @@
+use glam::{Quat, Vec3};
+use metaverse_messages::avatar_sit_response::AvatarSitResponse;
+use metaverse_messages::sit_status::SitStatus;
+use metaverse_session::sit::SitManager;
+use tokio::time::{Duration, Instant};
+use uuid::Uuid;
+
+fn response(object_id: Uuid) -> AvatarSitResponse {
+    AvatarSitResponse {
+        object_id,
+        autopilot: false,
+        sit_position: Vec3::new(0.0, 0.0, 0.5),
+        sit_rotation: Quat::IDENTITY,
+        camera_eye_offset: Vec3::ZERO,
+        camera_at_offset: Vec3::ZERO,
+        force_mouselook: false,
+    }
+}
+
+#[test]
+fn test_sit_response_seats_the_agent() {
+    let mut manager = SitManager::new(Duration::from_secs(10));
+    let now = Instant::now();
+    let object_id = Uuid::new_v4();
+    assert!(manager.request(object_id, now).is_none());
+
+    let status = manager.handle_response(response(object_id));
+    assert_eq!(status, SitStatus::Seated(response(object_id)));
+    assert_eq!(manager.seated_on, Some(object_id));
+    assert!(manager.pending.is_none());
+    assert!(manager.expire(now + Duration::from_secs(20)).is_none());
+}
+
+#[test]
+fn test_response_for_another_prim() {
+    let mut manager = SitManager::new(Duration::from_secs(10));
+    manager.request(Uuid::new_v4(), Instant::now());
+    let sit_target = Uuid::new_v4();
+    manager.handle_response(response(sit_target));
+    assert_eq!(manager.seated_on, Some(sit_target));
+}
+
+#[test]
+fn test_unanswered_request_fails() {
+    let mut manager = SitManager::new(Duration::from_secs(10));
+    let now = Instant::now();
+    let object_id = Uuid::new_v4();
+    manager.request(object_id, now);
+    assert!(manager.expire(now + Duration::from_secs(5)).is_none());
+
+    match manager.expire(now + Duration::from_secs(10)) {
+        Some(SitStatus::Failed {
+            object_id: failed, ..
+        }) => assert_eq!(failed, object_id),
+        other => panic!("expected a failure, got {:?}", other),
+    }
+    assert!(manager.pending.is_none());
+    assert!(manager.expire(now + Duration::from_secs(20)).is_none());
+}
+
+#[test]
+fn test_new_request_replaces_the_old_one() {
+    let mut manager = SitManager::new(Duration::from_secs(10));
+    let now = Instant::now();
+    let first = Uuid::new_v4();
+    let second = Uuid::new_v4();
+    manager.request(first, now);
+    match manager.request(second, now) {
+        Some(SitStatus::Failed { object_id, .. }) => assert_eq!(object_id, first),
+        other => panic!("expected a failure, got {:?}", other),
+    }
+    assert_eq!(manager.pending.as_ref().unwrap().object_id, second);
+}
+
+#[test]
+fn test_stand_up() {
+    let mut manager = SitManager::new(Duration::from_secs(10));
+    let object_id = Uuid::new_v4();
+    manager.request(object_id, Instant::now());
+    manager.handle_response(response(object_id));
+    assert!(manager.stand().is_none());
+    assert!(manager.seated_on.is_none());
+
+    // standing up while a request is waiting fails the request
+    manager.request(object_id, Instant::now());
+    assert!(matches!(manager.stand(), Some(SitStatus::Failed { .. })));
+    assert!(manager.pending.is_none());
+}
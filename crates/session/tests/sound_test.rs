@@ -0,0 +1,53 @@
+use metaverse_messages::preload_sound::{PreloadSound, PreloadSoundData};
+use metaverse_session::sound::SoundPreloadManager;
+use uuid::Uuid;
+
+fn preload(sound_ids: &[Uuid]) -> PreloadSound {
+    PreloadSound {
+        sounds: sound_ids
+            .iter()
+            .map(|sound_id| PreloadSoundData {
+                object_id: Uuid::new_v4(),
+                owner_id: Uuid::new_v4(),
+                sound_id: *sound_id,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_new_sounds_are_sent() {
+    let mut manager = SoundPreloadManager::new();
+    let sounds = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let sent = manager.handle_preload(preload(&sounds)).unwrap();
+    assert_eq!(sent.sound_ids(), sounds);
+}
+
+#[test]
+fn test_repeated_sounds_are_dropped() {
+    let mut manager = SoundPreloadManager::new();
+    let looping = Uuid::new_v4();
+    let other = Uuid::new_v4();
+    manager.handle_preload(preload(&[looping])).unwrap();
+    assert!(manager.handle_preload(preload(&[looping])).is_none());
+
+    let sent = manager.handle_preload(preload(&[looping, other])).unwrap();
+    assert_eq!(sent.sound_ids(), vec![other]);
+}
+
+#[test]
+fn test_duplicates_in_one_packet() {
+    let mut manager = SoundPreloadManager::new();
+    let sound = Uuid::new_v4();
+    let sent = manager.handle_preload(preload(&[sound, sound])).unwrap();
+    assert_eq!(sent.sound_ids(), vec![sound]);
+}
+
+#[test]
+fn test_clear_forgets_sounds() {
+    let mut manager = SoundPreloadManager::new();
+    let sound = Uuid::new_v4();
+    manager.handle_preload(preload(&[sound])).unwrap();
+    manager.clear();
+    assert!(manager.handle_preload(preload(&[sound])).is_some());
+}
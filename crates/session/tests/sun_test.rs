@@ -0,0 +1,66 @@
+use glam::Vec3;
+use metaverse_messages::simulator_viewer_time_message::SimulatorViewerTimeMessage;
+use metaverse_session::sun::SunTracker;
+use std::f32::consts::PI;
+use std::time::Duration;
+use tokio::time::Instant;
+
+fn time(sun_direction: Vec3, sun_ang_velocity: Vec3) -> SimulatorViewerTimeMessage {
+    SimulatorViewerTimeMessage {
+        usec_since_start: 0,
+        sec_per_day: 14400,
+        sec_per_year: 1_324_800,
+        sun_direction,
+        sun_phase: 0.0,
+        sun_ang_velocity,
+    }
+}
+
+#[test]
+fn test_no_sun_before_the_time_arrives() {
+    let sun = SunTracker::new();
+    assert!(sun.current_sun_direction(Instant::now()).is_none());
+}
+
+#[test]
+fn test_sun_direction_when_the_time_arrives() {
+    let mut sun = SunTracker::new();
+    let now = Instant::now();
+    sun.handle_time(time(Vec3::X, Vec3::new(0.0, 0.0, 0.1)), now);
+    let direction = sun.current_sun_direction(now).unwrap();
+    assert!(direction.abs_diff_eq(Vec3::X, 1e-6));
+}
+
+#[test]
+fn test_sun_direction_is_advanced_by_the_angular_velocity() {
+    let mut sun = SunTracker::new();
+    let now = Instant::now();
+    // a quarter turn around z every second
+    sun.handle_time(time(Vec3::X, Vec3::new(0.0, 0.0, PI / 2.0)), now);
+    let direction = sun
+        .current_sun_direction(now + Duration::from_secs(1))
+        .unwrap();
+    assert!(direction.abs_diff_eq(Vec3::Y, 1e-5));
+    let direction = sun
+        .current_sun_direction(now + Duration::from_secs(2))
+        .unwrap();
+    assert!(direction.abs_diff_eq(Vec3::NEG_X, 1e-5));
+
+    // a new message starts again from its own direction
+    let later = now + Duration::from_secs(3);
+    sun.handle_time(time(Vec3::Z, Vec3::new(0.0, 0.0, PI / 2.0)), later);
+    let direction = sun
+        .current_sun_direction(later + Duration::from_secs(1))
+        .unwrap();
+    assert!(direction.abs_diff_eq(Vec3::Z, 1e-5));
+}
+
+#[test]
+fn test_sun_is_forgotten_when_cleared() {
+    let mut sun = SunTracker::new();
+    let now = Instant::now();
+    sun.handle_time(time(Vec3::X, Vec3::ZERO), now);
+    sun.clear();
+    assert!(sun.latest.is_none());
+    assert!(sun.current_sun_direction(now).is_none());
+}
@@ -0,0 +1,181 @@
+use metaverse_messages::image_data::{ImageCodec, ImageData};
+use metaverse_messages::image_packet::ImagePacket;
+use metaverse_messages::request_image::{ImageRequest, ImageType};
+use metaverse_session::texture_download::TextureDownloadManager;
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn request(texture_id: Uuid) -> ImageRequest {
+    ImageRequest {
+        texture_id,
+        discard_level: 0,
+        download_priority: 1000.0,
+        packet: 0,
+        image_type: ImageType::Normal,
+    }
+}
+
+// a 2100 byte image, split into a 600 byte ImageData and two ImagePackets
+fn image(texture_id: Uuid) -> (ImageData, Vec<ImagePacket>) {
+    let data: Vec<u8> = (0..2100).map(|i| (i % 251) as u8).collect();
+    let image_data = ImageData {
+        texture_id,
+        codec: ImageCodec::J2c,
+        size: data.len() as u32,
+        packets: 3,
+        data: data[..600].to_vec(),
+    };
+    let packets = vec![
+        ImagePacket {
+            texture_id,
+            packet: 1,
+            data: data[600..1600].to_vec(),
+        },
+        ImagePacket {
+            texture_id,
+            packet: 2,
+            data: data[1600..].to_vec(),
+        },
+    ];
+    (image_data, packets)
+}
+
+#[test]
+fn test_texture_in_order() {
+    let mut manager = TextureDownloadManager::new(TIMEOUT, 3);
+    let now = Instant::now();
+    let texture_id = Uuid::new_v4();
+    assert!(manager.request(request(texture_id), now));
+    // asking again doesn't send another request
+    assert!(!manager.request(request(texture_id), now));
+
+    let (image_data, packets) = image(texture_id);
+    assert!(manager.handle_image_data(image_data, now).is_none());
+    assert!(manager
+        .handle_image_packet(packets[0].clone(), now)
+        .is_none());
+    let texture = manager
+        .handle_image_packet(packets[1].clone(), now)
+        .unwrap();
+    assert_eq!(texture.texture_id, texture_id);
+    assert_eq!(texture.codec, ImageCodec::J2c);
+    assert_eq!(
+        texture.data,
+        (0..2100).map(|i| (i % 251) as u8).collect::<Vec<u8>>()
+    );
+    assert!(manager.downloads.is_empty());
+}
+
+#[test]
+fn test_texture_out_of_order_with_duplicates() {
+    let mut manager = TextureDownloadManager::new(TIMEOUT, 3);
+    let now = Instant::now();
+    let texture_id = Uuid::new_v4();
+    manager.request(request(texture_id), now);
+
+    let (image_data, packets) = image(texture_id);
+    // the continuation packets arrive before the ImageData, and one of them twice
+    assert!(manager
+        .handle_image_packet(packets[1].clone(), now)
+        .is_none());
+    assert!(manager
+        .handle_image_packet(packets[1].clone(), now)
+        .is_none());
+    assert!(manager
+        .handle_image_packet(packets[0].clone(), now)
+        .is_none());
+    let texture = manager.handle_image_data(image_data.clone(), now).unwrap();
+    assert_eq!(texture.data.len(), 2100);
+    assert_eq!(
+        texture.data,
+        (0..2100).map(|i| (i % 251) as u8).collect::<Vec<u8>>()
+    );
+
+    // a late duplicate after the download finished is ignored
+    assert!(manager.handle_image_data(image_data, now).is_none());
+    assert!(manager.downloads.is_empty());
+}
+
+#[test]
+fn test_texture_missing_packets_are_requested_again() {
+    let mut manager = TextureDownloadManager::new(TIMEOUT, 3);
+    let start = Instant::now();
+    let texture_id = Uuid::new_v4();
+    manager.request(request(texture_id), start);
+
+    let (image_data, packets) = image(texture_id);
+    manager.handle_image_data(image_data, start);
+    assert_eq!(manager.downloads[&texture_id].missing_packets(), vec![1, 2]);
+    manager.handle_image_packet(packets[1].clone(), start);
+    assert_eq!(manager.downloads[&texture_id].missing_packets(), vec![1]);
+
+    // nothing has timed out yet
+    assert!(manager.retry(start + Duration::from_secs(1)).is_empty());
+
+    // the re-request starts from the first missing packet
+    let retries = manager.retry(start + TIMEOUT);
+    assert_eq!(retries.len(), 1);
+    assert_eq!(retries[0].texture_id, texture_id);
+    assert_eq!(retries[0].packet, 1);
+
+    let texture = manager
+        .handle_image_packet(packets[0].clone(), start + TIMEOUT)
+        .unwrap();
+    assert_eq!(texture.data.len(), 2100);
+}
+
+#[test]
+fn test_texture_abandoned_after_attempts() {
+    let mut manager = TextureDownloadManager::new(TIMEOUT, 2);
+    let start = Instant::now();
+    let texture_id = Uuid::new_v4();
+    manager.request(request(texture_id), start);
+
+    // the ImageData never arrived, so the whole image is requested again
+    let retries = manager.retry(start + TIMEOUT);
+    assert_eq!(retries.len(), 1);
+    assert_eq!(retries[0].packet, 0);
+
+    assert!(manager.retry(start + TIMEOUT * 2).is_empty());
+    assert!(manager.downloads.is_empty());
+}
+
+#[test]
+fn test_texture_cancel() {
+    let mut manager = TextureDownloadManager::new(TIMEOUT, 3);
+    let now = Instant::now();
+    let texture_id = Uuid::new_v4();
+    manager.request(request(texture_id), now);
+
+    let (image_data, packets) = image(texture_id);
+    manager.handle_image_data(image_data, now);
+    assert!(manager.cancel(&texture_id));
+    assert!(!manager.cancel(&texture_id));
+
+    // packets that were already in flight are ignored
+    for packet in packets {
+        assert!(manager.handle_image_packet(packet, now).is_none());
+    }
+    assert!(manager.retry(now + TIMEOUT).is_empty());
+}
+
+#[test]
+fn test_texture_invalid_packet_number() {
+    let mut manager = TextureDownloadManager::new(TIMEOUT, 3);
+    let now = Instant::now();
+    let texture_id = Uuid::new_v4();
+    manager.request(request(texture_id), now);
+
+    let (image_data, _) = image(texture_id);
+    manager.handle_image_data(image_data, now);
+    let past_the_end = ImagePacket {
+        texture_id,
+        packet: 3,
+        data: vec![0; 10],
+    };
+    assert!(manager.handle_image_packet(past_the_end, now).is_none());
+    assert_eq!(manager.downloads[&texture_id].missing_packets(), vec![1, 2]);
+}
@@ -0,0 +1,96 @@
+use metaverse_messages::asset_upload_request::AssetUploadRequest;
+use metaverse_messages::feature_disabled::FeatureDisabled;
+use metaverse_messages::utils::asset_type::AssetType;
+use metaverse_session::asset_upload::AssetUploadManager;
+use metaverse_session::transactions::TransactionRegistry;
+use metaverse_session::xfer::XferSender;
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const LIFETIME: Duration = Duration::from_secs(60);
+
+fn disabled(transaction_id: Uuid) -> FeatureDisabled {
+    FeatureDisabled {
+        error_message: "Uploads are disabled in this region".to_string(),
+        agent_id: Uuid::new_v4(),
+        transaction_id,
+    }
+}
+
+#[test]
+fn test_known_transaction_names_the_operation() {
+    let mut transactions = TransactionRegistry::new(LIFETIME);
+    let transaction_id = Uuid::new_v4();
+    transactions.register(transaction_id, "uploading a Texture asset", Instant::now());
+
+    let notice = transactions.handle_feature_disabled(&disabled(transaction_id));
+    assert_eq!(notice.transaction_id, transaction_id);
+    assert_eq!(
+        notice.operation.as_deref(),
+        Some("uploading a Texture asset")
+    );
+    assert_eq!(notice.message, "Uploads are disabled in this region");
+
+    // the transaction won't finish, so it is forgotten
+    assert!(transactions.transactions.is_empty());
+}
+
+#[test]
+fn test_unknown_transaction_is_generic() {
+    let mut transactions = TransactionRegistry::new(LIFETIME);
+    let transaction_id = Uuid::new_v4();
+    transactions.register(transaction_id, "rezzing an item", Instant::now());
+    transactions.complete(&transaction_id);
+
+    let notice = transactions.handle_feature_disabled(&disabled(transaction_id));
+    assert_eq!(notice.operation, None);
+    assert_eq!(notice.message, "Uploads are disabled in this region");
+}
+
+#[test]
+fn test_old_transactions_are_forgotten() {
+    let mut transactions = TransactionRegistry::new(LIFETIME);
+    let now = Instant::now();
+    let old = Uuid::new_v4();
+    transactions.register(old, "derezzing 2 objects", now);
+    transactions.register(Uuid::new_v4(), "rezzing an item", now + LIFETIME);
+    assert_eq!(transactions.transactions.len(), 1);
+    assert_eq!(
+        transactions
+            .handle_feature_disabled(&disabled(old))
+            .operation,
+        None
+    );
+}
+
+#[test]
+fn test_refused_upload_fails() {
+    let mut uploads = AssetUploadManager::new(Duration::from_secs(30));
+    let mut xfers = XferSender::new(Duration::from_secs(3), 5);
+    let request = AssetUploadRequest {
+        transaction_id: Uuid::new_v4(),
+        asset_type: AssetType::Texture,
+        temp_file: false,
+        store_local: false,
+        asset_data: vec![7; 16],
+    };
+    let (request, mut upload) =
+        uploads.request(request, Uuid::new_v4(), &mut xfers, Instant::now());
+
+    assert!(uploads.fail_transaction(Uuid::new_v4(), "no").is_none());
+    let uploaded = uploads
+        .fail_transaction(
+            request.transaction_id,
+            "Uploads are disabled in this region",
+        )
+        .unwrap();
+    assert!(!uploaded.success);
+    assert_eq!(uploaded.asset_id, upload.asset_id);
+    assert_eq!(
+        uploaded.reason.as_deref(),
+        Some("Uploads are disabled in this region")
+    );
+    assert!(upload.completion.try_recv().unwrap().is_err());
+    assert!(uploads.uploads.is_empty());
+}
@@ -0,0 +1,70 @@
+use metaverse_messages::agent_animation::AgentAnimation;
+use metaverse_messages::chat_from_viewer::{ChatFromViewer, ClientChatType};
+use metaverse_messages::utils::animations::ANIM_TYPE;
+use metaverse_session::typing::TypingAnimation;
+use uuid::Uuid;
+
+fn chat(message_type: ClientChatType, channel: i32) -> ChatFromViewer {
+    ChatFromViewer {
+        agent_id: Uuid::nil(),
+        session_id: Uuid::nil(),
+        message: String::new(),
+        message_type,
+        channel,
+    }
+}
+
+#[test]
+fn test_typing_starts_and_stops_the_animation() {
+    let mut typing = TypingAnimation::new(true);
+    assert_eq!(
+        typing.handle_chat(&chat(ClientChatType::StartTyping, 0)),
+        Some(AgentAnimation::start(&[ANIM_TYPE]))
+    );
+    // the UI keeps saying the agent is typing
+    assert!(typing
+        .handle_chat(&chat(ClientChatType::StartTyping, 0))
+        .is_none());
+    assert_eq!(
+        typing.handle_chat(&chat(ClientChatType::StopTyping, 0)),
+        Some(AgentAnimation::stop(&[ANIM_TYPE]))
+    );
+    assert!(typing
+        .handle_chat(&chat(ClientChatType::StopTyping, 0))
+        .is_none());
+}
+
+#[test]
+fn test_sending_the_message_stops_the_animation() {
+    let mut typing = TypingAnimation::new(true);
+    typing.handle_chat(&chat(ClientChatType::StartTyping, 0));
+    assert_eq!(
+        typing.handle_chat(&chat(ClientChatType::Normal, 0)),
+        Some(AgentAnimation::stop(&[ANIM_TYPE]))
+    );
+    assert!(!typing.typing);
+    // chat without typing first doesn't stop anything
+    assert!(typing
+        .handle_chat(&chat(ClientChatType::Shout, 0))
+        .is_none());
+}
+
+#[test]
+fn test_other_channels_are_ignored() {
+    let mut typing = TypingAnimation::new(true);
+    assert!(typing
+        .handle_chat(&chat(ClientChatType::StartTyping, 7))
+        .is_none());
+    assert!(!typing.typing);
+}
+
+#[test]
+fn test_disabled_typing_animation() {
+    let mut typing = TypingAnimation::new(false);
+    assert!(typing
+        .handle_chat(&chat(ClientChatType::StartTyping, 0))
+        .is_none());
+    assert!(typing
+        .handle_chat(&chat(ClientChatType::Normal, 0))
+        .is_none());
+}
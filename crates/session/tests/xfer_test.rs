@@ -0,0 +1,287 @@
+use metaverse_messages::abort_xfer::AbortXfer;
+use metaverse_messages::confirm_xfer_packet::ConfirmXferPacket;
+use metaverse_messages::request_xfer::RequestXfer;
+use metaverse_messages::send_xfer_packet::SendXferPacket;
+use metaverse_session::xfer::{packet_count, xfer_packet, XferKey, XferReceiver, XferSender};
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(3);
+const ATTEMPTS: u32 = 3;
+
+fn request(id: u64, vfile_id: Uuid) -> RequestXfer {
+    RequestXfer {
+        id,
+        file_name: String::new(),
+        file_path: 0,
+        delete_on_completion: false,
+        use_big_packets: false,
+        vfile_id,
+        vfile_type: 0,
+    }
+}
+
+fn confirm(packet: &SendXferPacket) -> ConfirmXferPacket {
+    ConfirmXferPacket {
+        id: packet.id,
+        packet: packet.packet_number(),
+    }
+}
+
+// pulls the whole file the way a simulator would, confirming every packet
+fn pull(sender: &mut XferSender, first: SendXferPacket, now: Instant) -> Vec<SendXferPacket> {
+    let mut packets = vec![first];
+    while let Some(next) = sender.handle_confirm(&confirm(packets.last().unwrap()), now) {
+        packets.push(next);
+    }
+    packets
+}
+
+#[test]
+fn test_packet_count_at_chunk_boundaries() {
+    assert_eq!(packet_count(0), 1);
+    assert_eq!(packet_count(1), 1);
+    assert_eq!(packet_count(SendXferPacket::CHUNK_SIZE), 1);
+    assert_eq!(packet_count(SendXferPacket::CHUNK_SIZE + 1), 2);
+    assert_eq!(packet_count(SendXferPacket::CHUNK_SIZE * 3), 3);
+}
+
+#[test]
+fn test_first_packet_starts_with_size() {
+    let data: Vec<u8> = (0..1500).map(|i| i as u8).collect();
+    let first = xfer_packet(7, &data, 0);
+    assert_eq!(&first.data[0..4], &1500u32.to_le_bytes());
+    assert_eq!(&first.data[4..], &data[..SendXferPacket::CHUNK_SIZE]);
+    assert!(!first.is_last());
+
+    let second = xfer_packet(7, &data, 1);
+    assert_eq!(second.data, &data[SendXferPacket::CHUNK_SIZE..]);
+    assert!(second.is_last());
+    assert_eq!(second.packet, 0x8000_0001);
+}
+
+#[test]
+fn test_single_packet_file_is_first_and_last() {
+    let data = vec![9; SendXferPacket::CHUNK_SIZE];
+    let packet = xfer_packet(1, &data, 0);
+    assert_eq!(packet.packet, 0x8000_0000);
+    assert_eq!(packet.data.len(), SendXferPacket::CHUNK_SIZE + 4);
+}
+
+#[test]
+fn test_empty_file_is_sent_as_its_size() {
+    let packet = xfer_packet(1, &[], 0);
+    assert!(packet.is_last());
+    assert_eq!(packet.packet_number(), 0);
+    assert_eq!(packet.data, vec![0, 0, 0, 0]);
+}
+
+#[test]
+fn test_last_flag_only_on_final_packet() {
+    let now = Instant::now();
+    let vfile_id = Uuid::new_v4();
+    let data: Vec<u8> = (0..SendXferPacket::CHUNK_SIZE * 2 + 1)
+        .map(|i| (i % 251) as u8)
+        .collect();
+    let mut sender = XferSender::new(TIMEOUT, ATTEMPTS);
+    let mut completion = sender.register(XferKey::VFileId(vfile_id), data.clone());
+
+    let first = sender.handle_request(&request(5, vfile_id), now).unwrap();
+    let packets = pull(&mut sender, first, now);
+    assert_eq!(packets.len(), 3);
+    assert_eq!(
+        packets.iter().map(|p| p.packet).collect::<Vec<_>>(),
+        vec![0, 1, 0x8000_0002]
+    );
+
+    // the simulator gets the file back by dropping the size from the first packet
+    let mut received = packets[0].data[4..].to_vec();
+    for packet in &packets[1..] {
+        received.extend_from_slice(&packet.data);
+    }
+    assert_eq!(received, data);
+
+    assert!(completion.try_recv().unwrap().is_ok());
+    assert!(sender.active.is_empty());
+}
+
+#[test]
+fn test_confirm_with_last_flag_finishes_upload() {
+    let now = Instant::now();
+    let vfile_id = Uuid::new_v4();
+    let mut sender = XferSender::new(TIMEOUT, ATTEMPTS);
+    let mut completion = sender.register(XferKey::VFileId(vfile_id), vec![1, 2, 3]);
+
+    let first = sender.handle_request(&request(5, vfile_id), now).unwrap();
+    // some receivers confirm the packet id without clearing the flag
+    let next = sender.handle_confirm(
+        &ConfirmXferPacket {
+            id: 5,
+            packet: first.packet,
+        },
+        now,
+    );
+    assert!(next.is_none());
+    assert!(completion.try_recv().unwrap().is_ok());
+}
+
+#[test]
+fn test_request_by_file_name() {
+    let now = Instant::now();
+    let mut sender = XferSender::new(TIMEOUT, ATTEMPTS);
+    let _completion = sender.register(XferKey::FileName("upload.tmp".to_string()), vec![1]);
+
+    let mut named = request(5, Uuid::nil());
+    assert!(sender.handle_request(&named, now).is_none());
+    named.file_name = "upload.tmp".to_string();
+    assert!(sender.handle_request(&named, now).is_some());
+    assert!(sender.pending.is_empty());
+}
+
+#[test]
+fn test_stale_confirm_is_ignored() {
+    let now = Instant::now();
+    let vfile_id = Uuid::new_v4();
+    let mut sender = XferSender::new(TIMEOUT, ATTEMPTS);
+    let _completion = sender.register(XferKey::VFileId(vfile_id), vec![0; 2500]);
+
+    let first = sender.handle_request(&request(5, vfile_id), now).unwrap();
+    let second = sender.handle_confirm(&confirm(&first), now).unwrap();
+    assert_eq!(second.packet_number(), 1);
+    // a duplicate confirmation of the first packet doesn't skip ahead
+    assert!(sender.handle_confirm(&confirm(&first), now).is_none());
+    assert_eq!(sender.active[&5].packet, 1);
+}
+
+#[test]
+fn test_retry_resends_unconfirmed_packet() {
+    let now = Instant::now();
+    let vfile_id = Uuid::new_v4();
+    let mut sender = XferSender::new(TIMEOUT, ATTEMPTS);
+    let _completion = sender.register(XferKey::VFileId(vfile_id), vec![0; 1200]);
+    let first = sender.handle_request(&request(5, vfile_id), now).unwrap();
+
+    let (resend, aborted) = sender.retry(now + Duration::from_secs(1));
+    assert!(resend.is_empty());
+    assert!(aborted.is_empty());
+
+    let (resend, aborted) = sender.retry(now + TIMEOUT);
+    assert_eq!(resend, vec![first]);
+    assert!(aborted.is_empty());
+}
+
+#[test]
+fn test_retry_aborts_after_max_attempts() {
+    let mut now = Instant::now();
+    let vfile_id = Uuid::new_v4();
+    let mut sender = XferSender::new(TIMEOUT, ATTEMPTS);
+    let mut completion = sender.register(XferKey::VFileId(vfile_id), vec![0; 10]);
+    sender.handle_request(&request(5, vfile_id), now).unwrap();
+
+    for _ in 1..ATTEMPTS {
+        now += TIMEOUT;
+        let (resend, _) = sender.retry(now);
+        assert_eq!(resend.len(), 1);
+    }
+    now += TIMEOUT;
+    let (resend, aborted) = sender.retry(now);
+    assert!(resend.is_empty());
+    assert_eq!(
+        aborted,
+        vec![AbortXfer {
+            id: 5,
+            result: AbortXfer::RESULT_TIMEOUT
+        }]
+    );
+    assert!(completion.try_recv().unwrap().is_err());
+    assert!(sender.active.is_empty());
+}
+
+#[test]
+fn test_abort_and_cancel_fail_uploads() {
+    let now = Instant::now();
+    let vfile_id = Uuid::new_v4();
+    let mut sender = XferSender::new(TIMEOUT, ATTEMPTS);
+    let mut aborted = sender.register(XferKey::VFileId(vfile_id), vec![0; 10]);
+    let mut waiting = sender.register(XferKey::VFileId(Uuid::new_v4()), vec![0; 10]);
+    sender.handle_request(&request(5, vfile_id), now).unwrap();
+
+    sender.handle_abort(&AbortXfer { id: 5, result: -1 });
+    assert!(aborted.try_recv().unwrap().is_err());
+
+    sender.cancel_all();
+    assert!(waiting.try_recv().unwrap().is_err());
+    assert!(sender.pending.is_empty());
+}
+
+#[test]
+fn test_download_file() {
+    let mut receiver = XferReceiver::new(Duration::from_secs(10));
+    let now = Instant::now();
+    let request = receiver.request("mutes".to_string(), RequestXfer::PATH_CACHE, true, now);
+    assert_eq!(request.file_name, "mutes");
+    assert!(request.delete_on_completion);
+    assert!(request.vfile_id.is_nil());
+    assert_eq!(request.vfile_type, RequestXfer::VFILE_TYPE_NONE);
+
+    let data: Vec<u8> = (0..2500).map(|i| (i % 251) as u8).collect();
+    let first = xfer_packet(request.id, &data, 0);
+    let (confirm, file) = receiver.handle_packet(&first, now).unwrap();
+    assert_eq!(confirm.packet, 0);
+    assert!(file.is_none());
+    // a packet that skips ahead isn't confirmed
+    assert!(receiver
+        .handle_packet(&xfer_packet(request.id, &data, 2), now)
+        .is_none());
+    // a packet sent again is confirmed again
+    let (confirm, file) = receiver.handle_packet(&first, now).unwrap();
+    assert_eq!(confirm.packet, 0);
+    assert!(file.is_none());
+
+    receiver.handle_packet(&xfer_packet(request.id, &data, 1), now);
+    let (confirm, file) = receiver
+        .handle_packet(&xfer_packet(request.id, &data, 2), now)
+        .unwrap();
+    assert_eq!(confirm.packet, 2);
+    let file = file.unwrap();
+    assert_eq!(file.file_name, "mutes");
+    assert_eq!(file.data, data);
+    assert!(receiver.active.is_empty());
+}
+
+#[test]
+fn test_download_unknown_and_empty() {
+    let mut receiver = XferReceiver::new(Duration::from_secs(10));
+    let now = Instant::now();
+    assert!(receiver
+        .handle_packet(&xfer_packet(7, b"file", 0), now)
+        .is_none());
+
+    let request = receiver.request("empty".to_string(), RequestXfer::PATH_CACHE, true, now);
+    let (_, file) = receiver
+        .handle_packet(&xfer_packet(request.id, &[], 0), now)
+        .unwrap();
+    assert!(file.unwrap().data.is_empty());
+}
+
+#[test]
+fn test_download_expires_and_aborts() {
+    let mut receiver = XferReceiver::new(Duration::from_secs(10));
+    let now = Instant::now();
+    let request = receiver.request("mutes".to_string(), RequestXfer::PATH_CACHE, true, now);
+    assert!(receiver.expire(now + Duration::from_secs(5)).is_empty());
+    let expired = receiver.expire(now + Duration::from_secs(10));
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].0, "mutes");
+    assert_eq!(expired[0].1.id, request.id);
+    assert_eq!(expired[0].1.result, AbortXfer::RESULT_TIMEOUT);
+
+    let request = receiver.request("mutes".to_string(), RequestXfer::PATH_CACHE, true, now);
+    let abort = AbortXfer {
+        id: request.id,
+        result: -1,
+    };
+    assert_eq!(receiver.handle_abort(&abort).unwrap().file_name, "mutes");
+    assert!(receiver.handle_abort(&abort).is_none());
+}
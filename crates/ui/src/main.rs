@@ -2,7 +2,6 @@ mod chat;
 mod loading;
 mod login;
 
-
 use actix_rt::System;
 use chat::chat_screen;
 use crossbeam_channel::unbounded;
@@ -11,9 +10,13 @@ use loading::loading_screen;
 use login::login_screen;
 use metaverse_messages::coarse_location_update::CoarseLocationUpdate;
 use metaverse_messages::errors::SessionError;
+use metaverse_messages::friendship_status::FriendshipStatus;
+use metaverse_messages::inventory_folder_status::InventoryFolderStatus;
+use metaverse_messages::inventory_item_status::InventoryItemStatus;
 use metaverse_messages::login_system::errors::LoginError;
 use metaverse_messages::login_system::login_response::LoginResponse;
 use metaverse_messages::packet_types::PacketType;
+use metaverse_messages::sit_status::SitStatus;
 use metaverse_session::client_subscriber::listen_for_server_events;
 use portpicker::pick_unused_port;
 
@@ -70,7 +73,7 @@ enum ViewerState {
 
 fn main() {
     // create temporary files
-    
+
     let ui_to_server_socket = pick_unused_port().unwrap();
     let server_to_ui_socket = pick_unused_port().unwrap();
     let (s1, r1) = unbounded();
@@ -103,11 +106,9 @@ fn main() {
         .add_systems(Update, handle_queue)
         .add_systems(Update, handle_login_response)
         .add_systems(Update, handle_disconnect)
-        
         .add_event::<LoginResponseEvent>()
         .add_event::<CoarseLocationUpdateEvent>()
         .add_event::<DisableSimulatorEvent>()
-
         .add_systems(Update, login_screen.run_if(in_state(ViewerState::Login)))
         .add_systems(
             Update,
@@ -133,7 +134,10 @@ fn handle_login_response(
     }
 }
 
-fn handle_disconnect(mut ev_disable_simulator: EventReader<DisableSimulatorEvent>, mut viewer_state: ResMut<NextState<ViewerState>>) {
+fn handle_disconnect(
+    mut ev_disable_simulator: EventReader<DisableSimulatorEvent>,
+    mut viewer_state: ResMut<NextState<ViewerState>>,
+) {
     for _ in ev_disable_simulator.read() {
         viewer_state.set(ViewerState::Login);
     }
@@ -185,16 +189,373 @@ fn handle_queue(
                 SessionError::CompleteAgentMovement(e) => {
                     info!("CompleteAgentMovmentError {:?}", e)
                 }
+                SessionError::Xfer(e) => {
+                    info!("XferError {:?}", e)
+                }
+                SessionError::AssetUpload(e) => {
+                    info!("AssetUploadError {:?}", e)
+                }
+                SessionError::CreateInventoryItem(e) => {
+                    info!("CreateInventoryItemError {:?}", e)
+                }
+                SessionError::GodPowers(e) => {
+                    info!("GodPowersError {:?}", e)
+                }
+                SessionError::LinkObjects(e) => {
+                    info!("LinkObjectsError {:?}", e)
+                }
+                SessionError::ScriptRunning(e) => {
+                    info!("ScriptRunningError {:?}", e)
+                }
             },
             PacketType::ChatFromSimulator(chat_from_simulator) => {
                 chat_messages.messages.push(ChatFromClientMessage {
                     user: chat_from_simulator.from_name,
                     message: chat_from_simulator.message,
                 });
-            },
+            }
             PacketType::DisableSimulator(_) => {
                 ev_disable_simulator.send(DisableSimulatorEvent {});
             }
+            PacketType::RegionHandshake(region_handshake) => {
+                info!(
+                    "connected to region {}",
+                    region_handshake.region_info.sim_name
+                )
+            }
+            PacketType::Disconnect(disconnect) => {
+                info!("disconnected: {}", disconnect.reason)
+            }
+            PacketType::KickUser(kick_user) => {
+                info!("kicked: {}", kick_user.reason)
+            }
+            PacketType::TeleportFinish(teleport_finish) => {
+                info!("teleported to region {}", teleport_finish.region_handle)
+            }
+            PacketType::TeleportFailed(teleport_failed) => {
+                info!("teleport failed: {}", teleport_failed.reason)
+            }
+            PacketType::TerrainPatches(terrain) => {
+                info!("received {} terrain patches", terrain.patches.len())
+            }
+            PacketType::WindPatches(wind) => {
+                info!("received {} wind patches", wind.patches.len())
+            }
+            PacketType::CloudPatches(clouds) => {
+                info!("received {} cloud patches", clouds.patches.len())
+            }
+            PacketType::TextureReady(texture) => {
+                info!(
+                    "texture {} ready, {} bytes",
+                    texture.texture_id,
+                    texture.data.len()
+                )
+            }
+            PacketType::AssetReceived(asset) => {
+                info!(
+                    "asset {} received, {} bytes",
+                    asset.asset_id,
+                    asset.data.len()
+                )
+            }
+            PacketType::AssetTransferFailed(failed) => {
+                info!("asset {} failed: {}", failed.asset_id, failed.reason)
+            }
+            PacketType::AvatarAppearance(appearance) => {
+                info!(
+                    "appearance of {} with {} visual params",
+                    appearance.sender_id,
+                    appearance.visual_params.len()
+                )
+            }
+            PacketType::Wearables(wearables) => {
+                info!("wearing {} wearables", wearables.wearables.len())
+            }
+            PacketType::NameResolved(resolved) => {
+                for name in resolved.names {
+                    info!("{} is {}", name.id, name.full_name())
+                }
+            }
+            PacketType::GroupNameResolved(resolved) => {
+                for name in resolved.names {
+                    info!("group {} is {}", name.id, name.name)
+                }
+            }
+            PacketType::MoneyBalanceReply(balance) => {
+                info!("balance is L${}", balance.money_balance)
+            }
+            PacketType::ObjectProperties(properties) => {
+                for object in properties.objects {
+                    info!("selected {} \"{}\"", object.object_id, object.name)
+                }
+            }
+            PacketType::PurchaseFailed(failed) => info!(
+                "could not buy object {}: {}",
+                failed.local_id, failed.reason
+            ),
+            PacketType::ParcelOverlayGrid(_) => info!("received the parcel overlay"),
+            PacketType::MapBlocks(map_blocks) => {
+                for block in map_blocks.blocks.iter().filter(|block| block.exists()) {
+                    info!("region {} is at {}, {}", block.name, block.x, block.y)
+                }
+            }
+            PacketType::MapItems(map_items) => info!(
+                "received {} map items of type {:?}",
+                map_items.items.len(),
+                map_items.item_type
+            ),
+            PacketType::MapSearchResults(results) => info!(
+                "found {} regions named \"{}\"",
+                results.blocks.len(),
+                results.name
+            ),
+            PacketType::MapSearchEmpty(empty) => {
+                info!("found no regions named \"{}\"", empty.name)
+            }
+            PacketType::SitStatus(status) => match &**status {
+                SitStatus::Seated(response) => info!("sitting on {}", response.object_id),
+                SitStatus::Failed { object_id, reason } => {
+                    info!("couldn't sit on {}: {}", object_id, reason)
+                }
+                SitStatus::Standing => info!("standing up"),
+            },
+            PacketType::MuteList(mute_list) => {
+                info!("{} entries on the mute list", mute_list.entries.len())
+            }
+            PacketType::FriendList(friend_list) => info!(
+                "{} friends, {} online",
+                friend_list.friends.len(),
+                friend_list.online().count()
+            ),
+            PacketType::OnlineNotification(online) => {
+                info!("friends came online: {:?}", online.agent_ids)
+            }
+            PacketType::OfflineNotification(offline) => {
+                info!("friends went offline: {:?}", offline.agent_ids)
+            }
+            PacketType::InventoryFolderContents(contents) => info!(
+                "inventory folder {} has {} folders and {} items",
+                contents.folder_id,
+                contents.folders.len(),
+                contents.items.len()
+            ),
+            PacketType::InventoryItemStatus(status) => match &**status {
+                InventoryItemStatus::Found(item) => {
+                    info!("inventory item {} is {}", item.item_id, item.name)
+                }
+                InventoryItemStatus::NotFound { item_id } => {
+                    info!("inventory item {} wasn't found", item_id)
+                }
+                InventoryItemStatus::Removed { item_id } => {
+                    info!("inventory item {} was removed", item_id)
+                }
+            },
+            PacketType::InventoryFolderStatus(status) => match &**status {
+                InventoryFolderStatus::Created(folder) => {
+                    info!(
+                        "created inventory folder {} as {}",
+                        folder.name, folder.folder_id
+                    )
+                }
+                InventoryFolderStatus::Moved {
+                    folder_id,
+                    parent_id,
+                } => info!("moved inventory folder {} into {}", folder_id, parent_id),
+                InventoryFolderStatus::Removed { folder_id } => {
+                    info!("removed inventory folder {}", folder_id)
+                }
+                InventoryFolderStatus::Purged { folder_id } => {
+                    info!("emptied inventory folder {}", folder_id)
+                }
+                InventoryFolderStatus::PurgeNotConfirmed { folder_id } => {
+                    info!(
+                        "refused to empty inventory folder {} without confirmation",
+                        folder_id
+                    )
+                }
+            },
+            PacketType::InventoryItemCreated(created) => match created.item {
+                Some(item) => info!("created inventory item {} as {}", item.name, item.item_id),
+                None => info!(
+                    "failed to create inventory item {}: {:?}",
+                    created.callback_id, created.reason
+                ),
+            },
+            PacketType::FriendshipStatus(status) => match *status {
+                FriendshipStatus::Offered {
+                    agent_id,
+                    name,
+                    message,
+                    ..
+                } => info!("{} ({}) offered friendship: {}", name, agent_id, message),
+                FriendshipStatus::Accepted { agent_id, name } => {
+                    info!("{} ({}) accepted friendship", name, agent_id)
+                }
+                FriendshipStatus::Declined { agent_id, name } => {
+                    info!("{} ({}) declined friendship", name, agent_id)
+                }
+                FriendshipStatus::Terminated { agent_id } => {
+                    info!("friendship with {} ended", agent_id)
+                }
+            },
+            PacketType::JoinGroupReply(reply) => {
+                if reply.success {
+                    info!("joined group {}", reply.group_id)
+                } else {
+                    info!("failed to join group {}", reply.group_id)
+                }
+            }
+            PacketType::LeaveGroupReply(reply) => {
+                if reply.success {
+                    info!("left group {}", reply.group_id)
+                } else {
+                    info!("failed to leave group {}", reply.group_id)
+                }
+            }
+            PacketType::AgentDataUpdate(data) => info!(
+                "{} {} is {} of {} ({})",
+                data.first_name,
+                data.last_name,
+                data.group_title,
+                data.group_name,
+                data.active_group_id
+            ),
+            PacketType::AvatarGroupsReply(reply) => info!(
+                "{} is in groups {:?}",
+                reply.avatar_id,
+                reply
+                    .groups
+                    .iter()
+                    .map(|group| &group.group_name)
+                    .collect::<Vec<_>>()
+            ),
+            PacketType::AvatarPropertiesReply(profile) => info!(
+                "{} was born on {}: {}",
+                profile.avatar_id, profile.born_on, profile.about_text
+            ),
+            PacketType::AvatarInterestsReply(reply) => info!(
+                "{} wants to {}, and speaks {}",
+                reply.avatar_id, reply.interests.want_to_text, reply.interests.languages_text
+            ),
+            PacketType::GroupProfileReply(profile) => info!(
+                "group {} ({}) has {} members, open enrollment: {}, fee: {}",
+                profile.name,
+                profile.group_id,
+                profile.group_membership_count,
+                profile.open_enrollment,
+                profile.membership_fee
+            ),
+            PacketType::PeopleSearchResults(results) => info!(
+                "search {} for {} found {:?}",
+                results.query_id,
+                results.name,
+                results
+                    .avatars
+                    .iter()
+                    .map(|avatar| format!("{} {}", avatar.first_name, avatar.last_name))
+                    .collect::<Vec<_>>()
+            ),
+            PacketType::PeopleSearchEmpty(empty) => {
+                info!("search {} for {} found nobody", empty.query_id, empty.name)
+            }
+            PacketType::DirectoryPeoplePage(page) => info!(
+                "directory search {} for {} found {} residents from {}, next page {:?}",
+                page.query_id,
+                page.text,
+                page.people.len(),
+                page.start_index,
+                page.next_start_index
+            ),
+            PacketType::DirectoryPlacesPage(page) => info!(
+                "directory search {} for {} found {} places from {}, next page {:?}",
+                page.query_id,
+                page.text,
+                page.places.len(),
+                page.start_index,
+                page.next_start_index
+            ),
+            PacketType::EstateOwnerMessage(message) => info!(
+                "estate {} {}: {:?}",
+                message.method, message.invoice, message.params
+            ),
+            PacketType::GrantGodlikePowers(grant) => info!("god level {}", grant.god_level),
+            PacketType::GenericMessage(message) => info!(
+                "generic message {} {} with {} params",
+                message.method,
+                message.invoice,
+                message.params.len()
+            ),
+            PacketType::EconomyData(economy) => info!(
+                "uploads cost {}, groups cost {}",
+                economy.price_upload, economy.price_group_create
+            ),
+            PacketType::RegionInfo(region) => {
+                info!("{} allows {} agents", region.sim_name, region.max_agents)
+            }
+            PacketType::ParcelDwellReply(dwell) => {
+                info!("parcel {} has dwell {}", dwell.local_id, dwell.dwell)
+            }
+            PacketType::ParcelAccessList(list) => info!(
+                "parcel {} list {:?} has {} entries",
+                list.local_id,
+                list.flags,
+                list.entries.len()
+            ),
+            PacketType::LandStatReport(report) => info!(
+                "{:?} report has {} of {} objects",
+                report.report_type,
+                report.entries.len(),
+                report.total_object_count
+            ),
+            PacketType::Attachments(worn) => {
+                info!("wearing {} attachments", worn.attachments.len())
+            }
+            PacketType::ScriptDialog(dialog) => info!(
+                "{} asks \"{}\" with buttons {:?}",
+                dialog.object_name, dialog.message, dialog.buttons
+            ),
+            PacketType::LoadURL(load_url) => info!(
+                "{} owned by {} offers to open {}",
+                load_url.object_name, load_url.owner_id, load_url.url
+            ),
+            PacketType::ScriptQuestion(question) => info!(
+                "{} asks for permissions {:?}",
+                question.object_name, question.questions
+            ),
+            PacketType::SoundTrigger(sound) => {
+                info!("sound {} played at {}", sound.sound_id, sound.position)
+            }
+            PacketType::AttachedSound(sound) => {
+                info!("sound {} attached to {}", sound.sound_id, sound.object_id)
+            }
+            PacketType::AttachedSoundGainChange(change) => {
+                info!("sound of {} set to gain {}", change.object_id, change.gain)
+            }
+            PacketType::PreloadSound(preload) => info!("preload sounds {:?}", preload.sound_ids()),
+            PacketType::ViewerEffect(viewer_effect) => {
+                for effect in &viewer_effect.effects {
+                    info!("{} made a {:?} effect", effect.agent_id, effect.effect_type)
+                }
+            }
+            PacketType::ParcelProperties(parcel) => {
+                info!(
+                    "on parcel \"{}\" of {} square meters",
+                    parcel.name, parcel.area
+                )
+            }
+            PacketType::AssetUploaded(uploaded) => match uploaded.reason {
+                None => info!("asset {} uploaded", uploaded.asset_id),
+                Some(reason) => info!("asset {} upload failed: {}", uploaded.asset_id, reason),
+            },
+            PacketType::RegionCrossed(region_crossed) => {
+                info!("crossed into region {}", region_crossed.region_handle)
+            }
+            PacketType::ChildSimulatorEvent(child_event) => {
+                info!(
+                    "{} from neighbouring region {}",
+                    child_event.event, child_event.region_handle
+                )
+            }
             _ => {
                 info!("unknown event coming from server")
             }
@@ -208,7 +569,9 @@ fn start_listener(sockets: Res<Sockets>, event_queue: Res<EventChannel>) {
     let sender = event_queue.sender.clone();
 
     thread_pool
-        .spawn(async move { listen_for_server_events(format!("127.0.0.1:{}", outgoing_socket), sender).await })
+        .spawn(async move {
+            listen_for_server_events(format!("127.0.0.1:{}", outgoing_socket), sender).await
+        })
         .detach();
 }
 